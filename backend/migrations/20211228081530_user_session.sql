@@ -0,0 +1,10 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS user_session_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    user_id uuid NOT NULL,
+    device_name TEXT NOT NULL,
+    platform TEXT NOT NULL,
+    create_time timestamptz NOT NULL,
+    last_seen timestamptz NOT NULL
+);
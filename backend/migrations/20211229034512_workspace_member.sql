@@ -0,0 +1,8 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS workspace_member_table(
+    workspace_id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    PRIMARY KEY (workspace_id, user_id),
+    role INTEGER NOT NULL,
+    create_time timestamptz NOT NULL
+);
@@ -0,0 +1,10 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS share_link_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    view_id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    permission INTEGER NOT NULL,
+    create_time timestamptz NOT NULL,
+    expire_time timestamptz
+);
@@ -0,0 +1,7 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS view_acl_table(
+    view_id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    PRIMARY KEY (view_id, user_id),
+    create_time timestamptz NOT NULL
+);
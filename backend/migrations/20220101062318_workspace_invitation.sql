@@ -0,0 +1,11 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS workspace_invitation_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    workspace_id uuid NOT NULL,
+    inviter_id uuid NOT NULL,
+    email TEXT NOT NULL,
+    role INTEGER NOT NULL,
+    status INTEGER NOT NULL,
+    create_time timestamptz NOT NULL
+);
@@ -0,0 +1,10 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS view_guest_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    view_id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    email TEXT NOT NULL,
+    permission INTEGER NOT NULL,
+    create_time timestamptz NOT NULL
+);
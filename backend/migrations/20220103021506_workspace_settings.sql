@@ -0,0 +1,4 @@
+-- Add migration script here
+-- The settings that the members of the workspace share, kept as the protobuf
+-- bytes of the WorkspaceSettings.
+ALTER TABLE workspace_table ADD COLUMN IF NOT EXISTS settings BYTEA;
@@ -14,7 +14,7 @@ use crate::{
     context::AppContext,
     services::{
         core::{app::router as app, trash::router as trash, view::router as view, workspace::router as workspace},
-        document::router as doc,
+        document::{cluster::ClusterConnection, router as doc},
         user::router as user,
         web_socket::WSServer,
     },
@@ -83,9 +83,23 @@ fn user_scope() -> Scope {
             .route(web::post().to(user::sign_in_handler))
             .route(web::delete().to(user::sign_out_handler))
         )
+        .service(web::resource("/auth/refresh")
+            .route(web::post().to(user::refresh_token_handler))
+        )
         .service(web::resource("/user")
             .route(web::patch().to(user::set_user_profile_handler))
             .route(web::get().to(user::get_user_profile_handler))
+            .route(web::delete().to(user::delete_user_handler))
+        )
+        .service(web::resource("/user/device")
+            .route(web::get().to(user::read_user_devices_handler))
+            .route(web::delete().to(user::revoke_user_device_handler))
+        )
+        .service(web::resource("/user/avatar")
+            .route(web::post().to(user::set_user_avatar_handler))
+        )
+        .service(web::resource("/user/avatar/{user_id}")
+            .route(web::get().to(user::get_user_avatar_handler))
         )
         .service(web::resource("/register")
             .route(web::post().to(user::register_handler))
@@ -96,6 +110,22 @@ fn user_scope() -> Scope {
             .route(web::get().to(workspace::read_handler))
             .route(web::patch().to(workspace::update_handler))
         )
+        .service(web::resource("/workspace/member")
+            .route(web::get().to(workspace::read_member_handler))
+            .route(web::post().to(workspace::add_member_handler))
+            .route(web::patch().to(workspace::update_member_handler))
+            .route(web::delete().to(workspace::remove_member_handler))
+        )
+        .service(web::resource("/workspace/invitation")
+            .route(web::get().to(workspace::read_invitations_handler))
+            .route(web::post().to(workspace::create_invitation_handler))
+        )
+        .service(web::resource("/workspace/invitation/accept")
+            .route(web::post().to(workspace::accept_invitation_handler))
+        )
+        .service(web::resource("/workspace/invitation/decline")
+            .route(web::post().to(workspace::decline_invitation_handler))
+        )
         .service(web::resource("/workspace_list/{user_id}")
             .route(web::get().to(workspace::workspace_list))
         )
@@ -111,6 +141,30 @@ fn user_scope() -> Scope {
             .route(web::get().to(view::read_handler))
             .route(web::patch().to(view::update_handler))
         )
+        .service(web::resource("/view/access")
+            .route(web::get().to(view::read_access_handler))
+            .route(web::post().to(view::grant_access_handler))
+            .route(web::delete().to(view::revoke_access_handler))
+        )
+        .service(web::resource("/view/guest")
+            .route(web::post().to(view::create_guest_access_handler))
+            .route(web::get().to(view::read_guest_accesses_handler))
+            .route(web::delete().to(view::revoke_guest_access_handler))
+        )
+        .service(web::resource("/guest/view")
+            .route(web::get().to(view::read_guest_view_handler))
+        )
+        .service(web::resource("/guest/doc")
+            .route(web::get().to(view::read_guest_document_handler))
+        )
+        .service(web::resource("/share")
+            .route(web::post().to(view::create_share_link_handler))
+            .route(web::get().to(view::read_share_links_handler))
+            .route(web::delete().to(view::revoke_share_link_handler))
+        )
+        .service(web::resource("/shared/{link_id}")
+            .route(web::get().to(view::read_shared_document_handler))
+        )
         .service(web::resource("/doc")
             .route(web::post().to(doc::create_document_handler))
             .route(web::get().to(doc::read_document_handler))
@@ -138,8 +192,17 @@ pub async fn init_app_context(configuration: &Settings) -> AppContext {
         .await
         .unwrap_or_else(|_| panic!("Failed to connect to Postgres at {:?}.", configuration.database));
 
+    let cluster_connection = match &configuration.redis {
+        None => None,
+        Some(redis) => Some(
+            ClusterConnection::connect(redis)
+                .await
+                .unwrap_or_else(|_| panic!("Failed to connect to Redis at {}.", redis.url)),
+        ),
+    };
+
     let ws_server = WSServer::new().start();
-    AppContext::new(ws_server, pg_pool)
+    AppContext::new(ws_server, pg_pool, cluster_connection)
 }
 
 pub fn identify_service(domain: &str, secret: &str) -> IdentityService<CookieIdentityPolicy> {
@@ -6,6 +6,9 @@ use std::convert::{TryFrom, TryInto};
 pub struct Settings {
     pub database: DatabaseSettings,
     pub application: ApplicationSettings,
+    // The servers behind a load balancer share the documents through it. A
+    // single server doesn't need it.
+    pub redis: Option<RedisSettings>,
 }
 
 // We are using 127.0.0.1 as our host in address, we are instructing our
@@ -34,6 +37,11 @@ pub struct DatabaseSettings {
     pub require_ssl: bool,
 }
 
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct RedisSettings {
+    pub url: String,
+}
+
 impl DatabaseSettings {
     pub fn without_db(&self) -> PgConnectOptions {
         let ssl_mode = if self.require_ssl {
@@ -4,4 +4,13 @@ pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(8);
 pub const PING_TIMEOUT: Duration = Duration::from_secs(60);
 pub const MAX_PAYLOAD_SIZE: usize = 262_144; // max payload size is 256k
 
-pub const IGNORE_ROUTES: [&str; 3] = ["/api/register", "/api/auth", "/ws"];
+// The limits of the document revisions that the clients push over the
+// websocket. A connection may burst to twice its rate, and all the
+// connections of a user share the user's rate.
+pub const MAX_REVISION_PAYLOAD_SIZE: usize = 65_536;
+pub const CONNECTION_REVISIONS_PER_SECOND: u32 = 20;
+pub const USER_REVISIONS_PER_SECOND: u32 = 50;
+
+// The guest routes check the guest token themselves, which the authentication
+// middleware doesn't accept.
+pub const IGNORE_ROUTES: [&str; 5] = ["/api/register", "/api/auth", "/api/shared/", "/api/guest/", "/ws"];
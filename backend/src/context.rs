@@ -6,6 +6,7 @@ use actix::Addr;
 use actix_web::web::Data;
 
 use crate::services::document::{
+    cluster::{ClusterConnection, DocumentCluster},
     persistence::DocumentKVPersistence,
     ws_receiver::{make_document_ws_receiver, DocumentPersistenceImpl},
 };
@@ -23,7 +24,7 @@ pub struct AppContext {
 }
 
 impl AppContext {
-    pub fn new(ws_server: Addr<WSServer>, pg_pool: PgPool) -> Self {
+    pub fn new(ws_server: Addr<WSServer>, pg_pool: PgPool, cluster_connection: Option<ClusterConnection>) -> Self {
         let ws_server = Data::new(ws_server);
         let mut ws_receivers = WebSocketReceivers::new();
 
@@ -33,7 +34,17 @@ impl AppContext {
         let document_persistence = Arc::new(DocumentPersistenceImpl(persistence.clone()));
         let document_manager = Arc::new(ServerDocumentManager::new(document_persistence));
 
-        let document_ws_receiver = make_document_ws_receiver(persistence.clone(), document_manager.clone());
+        let cluster = cluster_connection.map(|connection| {
+            let cluster = Arc::new(DocumentCluster::new(
+                Arc::new(connection),
+                document_manager.clone(),
+                persistence.kv_store(),
+            ));
+            cluster.start();
+            cluster
+        });
+
+        let document_ws_receiver = make_document_ws_receiver(persistence.clone(), document_manager.clone(), cluster);
         ws_receivers.set(WSModule::Doc, document_ws_receiver);
         AppContext {
             ws_server,
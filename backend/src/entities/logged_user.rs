@@ -12,16 +12,20 @@ lazy_static! {
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct LoggedUser {
     pub user_id: String,
+    // Each sign in is a separate session, so signing out or revoking one device
+    // doesn't affect the others.
+    pub session_id: String,
 }
 
 impl std::convert::From<Claim> for LoggedUser {
-    fn from(c: Claim) -> Self { Self { user_id: c.user_id() } }
+    fn from(c: Claim) -> Self { Self::new(c.user_id(), c.session_id()) }
 }
 
 impl LoggedUser {
-    pub fn new(user_id: &str) -> Self {
+    pub fn new(user_id: &str, session_id: &str) -> Self {
         Self {
             user_id: user_id.to_owned(),
+            session_id: session_id.to_owned(),
         }
     }
 
@@ -66,6 +70,42 @@ impl std::convert::TryFrom<&HeaderValue> for LoggedUser {
     }
 }
 
+// The holder of a guest token. It's never accepted where the LoggedUser is
+// expected, so the guest can only reach the view that the token is scoped to.
+#[derive(Debug, Clone)]
+pub struct LoggedGuest {
+    pub access_id: String,
+    pub view_id: String,
+}
+
+impl std::convert::From<Claim> for LoggedGuest {
+    fn from(c: Claim) -> Self {
+        Self {
+            access_id: c.user_id().to_owned(),
+            view_id: c.view_id().to_owned(),
+        }
+    }
+}
+
+impl LoggedGuest {
+    pub fn from_token(token: String) -> Result<Self, ServerError> {
+        let guest: LoggedGuest = Token::decode_guest_token(&token.into())?.into();
+        Ok(guest)
+    }
+}
+
+impl FromRequest for LoggedGuest {
+    type Error = ServerError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        match Token::parser_from_request(request) {
+            Ok(token) => ready(LoggedGuest::from_token(token.0)),
+            Err(err) => ready(Err(err)),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Copy)]
 enum AuthStatus {
     Authorized(DateTime<Utc>),
@@ -101,6 +141,12 @@ impl AuthorizedUsers {
         }
     }
 
+    // Returns true if the session was signed out or revoked. Unlike is_authorized,
+    // it's false for the sessions that are unknown because the server restarted.
+    pub fn is_revoked(&self, user: &LoggedUser) -> bool {
+        matches!(self.0.get(user).map(|status| *status), Some(AuthStatus::NotAuthorized))
+    }
+
     pub fn store_auth(&self, user: LoggedUser, is_auth: bool) {
         let status = if is_auth {
             AuthStatus::Authorized(Utc::now())
@@ -109,4 +155,11 @@ impl AuthorizedUsers {
         };
         self.0.insert(user, status);
     }
+
+    pub fn revoke_all(&self, user_id: &str) {
+        self.0
+            .iter_mut()
+            .filter(|entry| entry.key().user_id == user_id)
+            .for_each(|mut entry| *entry.value_mut() = AuthStatus::NotAuthorized);
+    }
 }
@@ -11,6 +11,10 @@ use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header,
 use serde::{Deserialize, Serialize};
 
 const DEFAULT_ALGORITHM: Algorithm = Algorithm::HS256;
+const AUTH_SUBJECT: &str = "auth";
+const REFRESH_SUBJECT: &str = "refresh";
+const GUEST_SUBJECT: &str = "guest";
+pub const REFRESH_EXPIRED_DURATION_DAYS: i64 = 90;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claim {
@@ -23,21 +27,38 @@ pub struct Claim {
     // expiry
     exp: i64,
     user_id: String,
+    // The tokens issued before the sessions were tracked don't have one.
+    #[serde(default)]
+    session_id: String,
+    // The guest token is scoped to a single view, the user_id of its claim is
+    // the id of the guest access.
+    #[serde(default)]
+    view_id: String,
 }
 
 impl Claim {
-    pub fn with_user_id(user_id: &str) -> Self {
+    pub fn with_user_id(user_id: &str, session_id: &str) -> Self {
+        Self::new(user_id, session_id, AUTH_SUBJECT, EXPIRED_DURATION_DAYS)
+    }
+
+    fn new(user_id: &str, session_id: &str, sub: &str, expired_days: i64) -> Self {
         let domain = domain();
         Self {
             iss: domain,
-            sub: "auth".to_string(),
+            sub: sub.to_string(),
             user_id: user_id.to_string(),
+            session_id: session_id.to_string(),
+            view_id: "".to_string(),
             iat: Local::now().timestamp(),
-            exp: (Local::now() + Duration::days(EXPIRED_DURATION_DAYS)).timestamp(),
+            exp: (Local::now() + Duration::days(expired_days)).timestamp(),
         }
     }
 
-    pub fn user_id(self) -> String { self.user_id }
+    pub fn user_id(&self) -> &str { &self.user_id }
+
+    pub fn session_id(&self) -> &str { &self.session_id }
+
+    pub fn view_id(&self) -> &str { &self.view_id }
 }
 
 // impl From<Claim> for User {
@@ -47,8 +68,28 @@ impl Claim {
 #[derive(From, Into, Clone)]
 pub struct Token(pub String);
 impl Token {
-    pub fn create_token(user_id: &str) -> Result<Self, ServerError> {
-        let claims = Claim::with_user_id(&user_id);
+    pub fn create_token(user_id: &str, session_id: &str) -> Result<Self, ServerError> {
+        Self::encode_claim(Claim::with_user_id(user_id, session_id))
+    }
+
+    // The refresh token lives longer than the token and can only be exchanged for a
+    // new pair of tokens. It's rejected when used to access other api.
+    pub fn create_refresh_token(user_id: &str, session_id: &str) -> Result<Self, ServerError> {
+        Self::encode_claim(Claim::new(
+            user_id,
+            session_id,
+            REFRESH_SUBJECT,
+            REFRESH_EXPIRED_DURATION_DAYS,
+        ))
+    }
+
+    pub fn create_guest_token(access_id: &str, view_id: &str) -> Result<Self, ServerError> {
+        let mut claim = Claim::new(access_id, "", GUEST_SUBJECT, EXPIRED_DURATION_DAYS);
+        claim.view_id = view_id.to_owned();
+        Self::encode_claim(claim)
+    }
+
+    fn encode_claim(claims: Claim) -> Result<Self, ServerError> {
         encode(
             &Header::new(DEFAULT_ALGORITHM),
             &claims,
@@ -58,14 +99,27 @@ impl Token {
         .map_err(|err| ServerError::internal().context(err))
     }
 
-    pub fn decode_token(token: &Self) -> Result<Claim, ServerError> {
-        decode::<Claim>(
+    pub fn decode_token(token: &Self) -> Result<Claim, ServerError> { Self::decode_claim(token, AUTH_SUBJECT) }
+
+    pub fn decode_refresh_token(token: &Self) -> Result<Claim, ServerError> {
+        Self::decode_claim(token, REFRESH_SUBJECT)
+    }
+
+    pub fn decode_guest_token(token: &Self) -> Result<Claim, ServerError> { Self::decode_claim(token, GUEST_SUBJECT) }
+
+    fn decode_claim(token: &Self, sub: &str) -> Result<Claim, ServerError> {
+        let claim = decode::<Claim>(
             &token.0,
             &DecodingKey::from_secret(jwt_secret().as_ref()),
             &Validation::new(DEFAULT_ALGORITHM),
         )
-        .map(|data| Ok(data.claims))
-        .map_err(|err| ServerError::unauthorized().context(err))?
+        .map(|data| data.claims)
+        .map_err(|err| ServerError::unauthorized().context(err))?;
+
+        if claim.sub != sub {
+            return Err(ServerError::unauthorized().context(format!("Unexpected token subject: {}", claim.sub)));
+        }
+        Ok(claim)
     }
 
     pub fn parser_from_request(request: &HttpRequest) -> Result<Self, ServerError> {
@@ -10,3 +10,13 @@ pub struct UserTable {
     pub(crate) create_time: chrono::DateTime<Utc>,
     pub(crate) password: String,
 }
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserSessionTable {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) user_id: uuid::Uuid,
+    pub(crate) device_name: String,
+    pub(crate) platform: String,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+    pub(crate) last_seen: chrono::DateTime<Utc>,
+}
@@ -69,8 +69,10 @@ where
                 match result {
                     Ok(logged_user) => {
                         if cfg!(feature = "ignore_auth") {
-                            authenticate_pass = true;
-                            AUTHORIZED_USERS.store_auth(logged_user, true);
+                            authenticate_pass = !AUTHORIZED_USERS.is_revoked(&logged_user);
+                            if authenticate_pass {
+                                AUTHORIZED_USERS.store_auth(logged_user, true);
+                            }
                         } else {
                             authenticate_pass = AUTHORIZED_USERS.is_authorized(&logged_user);
                             if authenticate_pass {
@@ -27,7 +27,21 @@ pub(crate) async fn create_app(
     let user_id = logged_user.as_uuid()?.to_string();
     let desc = AppDesc::parse(params.take_desc()).map_err(invalid_params)?;
 
-    let (sql, args, app) = NewAppSqlBuilder::new(&user_id, workspace_id.as_ref())
+    let mut builder = NewAppSqlBuilder::new(&user_id, workspace_id.as_ref());
+    // The older clients leave the id to the server. A create that is retried
+    // with the same id returns the app that the first attempt created.
+    if !params.get_app_id().is_empty() {
+        let app_id = check_app_id(params.take_app_id())?;
+        match read_app_table(app_id, transaction).await {
+            Ok(table) if table.user_id == user_id => return Ok(table.into()),
+            Ok(_) => return Err(invalid_params("The app id is used by another app")),
+            Err(e) if e.is_record_not_found() => {},
+            Err(e) => return Err(e),
+        }
+        builder = builder.id(app_id);
+    }
+
+    let (sql, args, app) = builder
         .name(name.as_ref())
         .desc(desc.as_ref())
         .color_style(params.take_color_style())
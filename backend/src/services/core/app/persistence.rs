@@ -55,6 +55,11 @@ impl NewAppSqlBuilder {
         Ok(Self { table })
     }
 
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.table.id = id;
+        self
+    }
+
     pub fn name(mut self, name: &str) -> Self {
         self.table.name = name.to_string();
         self
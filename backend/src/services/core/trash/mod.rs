@@ -1,5 +1,5 @@
 #![allow(clippy::module_inception)]
-mod persistence;
+pub mod persistence;
 pub mod router;
 mod trash;
 
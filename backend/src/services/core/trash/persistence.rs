@@ -1,4 +1,8 @@
-use crate::services::core::{app::persistence::AppTable, view::persistence::ViewTable};
+use crate::services::core::{
+    app::persistence::AppTable,
+    view::persistence::ViewTable,
+    workspace::persistence::WorkspaceTable,
+};
 use flowy_core_data_model::protobuf::{Trash, TrashType};
 
 pub(crate) const TRASH_TABLE: &str = "trash_table";
@@ -18,6 +22,26 @@ impl std::convert::From<AppTable> for Trash {
             modified_time: table.modified_time.timestamp(),
             create_time: table.create_time.timestamp(),
             ty: TrashType::App,
+            deleted_by: "".to_owned(),
+            parent_id: table.workspace_id,
+            position: 0,
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        }
+    }
+}
+
+impl std::convert::From<WorkspaceTable> for Trash {
+    fn from(table: WorkspaceTable) -> Self {
+        Trash {
+            id: table.id.to_string(),
+            name: table.name,
+            modified_time: table.modified_time.timestamp(),
+            create_time: table.create_time.timestamp(),
+            ty: TrashType::Workspace,
+            deleted_by: "".to_owned(),
+            parent_id: "".to_owned(),
+            position: 0,
             unknown_fields: Default::default(),
             cached_size: Default::default(),
         }
@@ -32,6 +56,9 @@ impl std::convert::From<ViewTable> for Trash {
             modified_time: table.modified_time.timestamp(),
             create_time: table.create_time.timestamp(),
             ty: TrashType::View,
+            deleted_by: "".to_owned(),
+            parent_id: table.belong_to_id,
+            position: 0,
             unknown_fields: Default::default(),
             cached_size: Default::default(),
         }
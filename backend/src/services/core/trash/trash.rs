@@ -5,6 +5,7 @@ use crate::{
             app::controller::{delete_app, read_app_table},
             trash::persistence::{TrashTable, TRASH_TABLE},
             view::{delete_view, read_view_table},
+            workspace::{delete_workspace, read_workspace_table},
         },
         document::persistence::DocumentKVPersistence,
     },
@@ -124,6 +125,9 @@ async fn delete_trash_associate_targets(
                 TrashType::App => {
                     let _ = delete_app(transaction as &mut DBTransaction<'_>, id).await;
                 },
+                TrashType::Workspace => {
+                    let _ = delete_workspace(transaction as &mut DBTransaction<'_>, id).await;
+                },
             },
         }
     }
@@ -161,18 +165,22 @@ pub(crate) async fn read_trash(
 
     let mut trash: Vec<Trash> = vec![];
     for table in tables {
-        match TrashType::from_i32(table.ty) {
-            None => log::error!("Parser trash type with value: {} failed", table.ty),
+        let mut item: Trash = match TrashType::from_i32(table.ty) {
+            None => {
+                log::error!("Parser trash type with value: {} failed", table.ty);
+                continue;
+            },
             Some(ty) => match ty {
-                TrashType::Unknown => {},
-                TrashType::View => {
-                    trash.push(read_view_table(table.id, transaction).await?.into());
-                },
-                TrashType::App => {
-                    trash.push(read_app_table(table.id, transaction).await?.into());
-                },
+                TrashType::Unknown => continue,
+                TrashType::View => read_view_table(table.id, transaction).await?.into(),
+                TrashType::App => read_app_table(table.id, transaction).await?.into(),
+                TrashType::Workspace => read_workspace_table(transaction, &table.id).await?.into(),
             },
-        }
+        };
+        // The position in the parent is only known by the client that deleted
+        // it.
+        item.deleted_by = table.user_id;
+        trash.push(item);
     }
 
     let mut repeated_trash = RepeatedTrash::default();
@@ -0,0 +1,295 @@
+use crate::{
+    entities::logged_user::LoggedUser,
+    services::core::{
+        view::persistence::check_view_id,
+        workspace::{check_workspace_owner, read_workspace_role},
+    },
+    util::sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::Utc;
+use flowy_core_data_model::{
+    parser::workspace::WorkspaceMemberId,
+    protobuf::{
+        UpdateViewAccessParams as UpdateViewAccessParamsPB,
+        ViewAccess as ViewAccessPB,
+        WorkspaceRole as WorkspaceRolePB,
+    },
+};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+pub(crate) const VIEW_ACL_TABLE: &str = "view_acl_table";
+
+// The owner of the workspace can access every view. For the other members,
+// the restriction of a view applies to the views nested in it as well.
+pub(crate) async fn is_view_accessible(
+    transaction: &mut DBTransaction<'_>,
+    view_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<bool, ServerError> {
+    if let Some(workspace_id) = read_view_workspace_id(transaction, view_id).await? {
+        if read_workspace_role(transaction, &workspace_id, user_id).await? == Some(WorkspaceRolePB::Owner) {
+            return Ok(true);
+        }
+    }
+
+    let mut id = *view_id;
+    loop {
+        let user_ids = read_acl_user_ids(transaction, &id).await?;
+        if !user_ids.is_empty() && !user_ids.contains(user_id) {
+            return Ok(false);
+        }
+
+        match read_parent_id(transaction, &id).await? {
+            None => return Ok(true),
+            Some(parent_id) => id = parent_id,
+        }
+    }
+}
+
+pub(crate) async fn check_view_accessible(
+    transaction: &mut DBTransaction<'_>,
+    view_id: &Uuid,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    match is_view_accessible(transaction, view_id, &logged_user.as_uuid()?).await? {
+        true => Ok(()),
+        false => Err(ServerError::permission_denied().context("The view is restricted to the other members")),
+    }
+}
+
+// The viewers of the workspace can only read the views, the client hides the
+// editing for them but the server has to reject the changes as well. The
+// members that the view is restricted from can't change or share it either.
+pub(crate) async fn is_view_editable(
+    transaction: &mut DBTransaction<'_>,
+    view_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<bool, ServerError> {
+    let workspace_id = read_existing_view_workspace_id(transaction, view_id).await?;
+    match read_workspace_role(transaction, &workspace_id, user_id).await? {
+        Some(WorkspaceRolePB::Owner) | Some(WorkspaceRolePB::Editor) => {},
+        _ => return Ok(false),
+    }
+    is_view_accessible(transaction, view_id, user_id).await
+}
+
+pub(crate) async fn check_view_editable(
+    transaction: &mut DBTransaction<'_>,
+    view_id: &Uuid,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    match is_view_editable(transaction, view_id, &logged_user.as_uuid()?).await? {
+        true => Ok(()),
+        false => Err(ServerError::permission_denied().context("Only the editors can change the view")),
+    }
+}
+
+// Moving a view changes both of its parents, so the caller needs to edit the
+// new parent as well. The new parent can be an app or another view, and a view
+// can't be nested in itself or in the views nested in it.
+pub(crate) async fn check_view_movable(
+    transaction: &mut DBTransaction<'_>,
+    view_id: &Uuid,
+    belong_to_id: &Uuid,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let _ = check_view_editable(transaction, belong_to_id, logged_user).await?;
+    let workspace_id = read_existing_view_workspace_id(transaction, view_id).await?;
+    if read_existing_view_workspace_id(transaction, belong_to_id).await? != workspace_id {
+        return Err(ServerError::params_invalid().context("The view can't be moved to another workspace"));
+    }
+
+    let mut id = *belong_to_id;
+    loop {
+        if id == *view_id {
+            return Err(ServerError::params_invalid().context("The view can't be moved into itself"));
+        }
+
+        match read_parent_id(transaction, &id).await? {
+            None => return Ok(()),
+            Some(parent_id) => id = parent_id,
+        }
+    }
+}
+
+// The documents of the views have the id of the view. The other documents,
+// e.g. the settings of a user, have the id of the user who owns them as the
+// prefix, and only that user can open them.
+pub(crate) async fn check_document_accessible(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: &str,
+    user_id: &str,
+) -> Result<(), ServerError> {
+    match Uuid::parse_str(doc_id) {
+        Ok(view_id) => match is_view_accessible(transaction, &view_id, &Uuid::parse_str(user_id)?).await? {
+            true => Ok(()),
+            false => Err(ServerError::permission_denied().context("The view is restricted to the other members")),
+        },
+        Err(_) => check_document_owner(doc_id, user_id),
+    }
+}
+
+pub(crate) async fn check_document_editable(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: &str,
+    user_id: &str,
+) -> Result<(), ServerError> {
+    match Uuid::parse_str(doc_id) {
+        Ok(view_id) => match is_view_editable(transaction, &view_id, &Uuid::parse_str(user_id)?).await? {
+            true => Ok(()),
+            false => Err(ServerError::permission_denied().context("Only the editors can change the document")),
+        },
+        Err(_) => check_document_owner(doc_id, user_id),
+    }
+}
+
+pub(crate) async fn read_view_access(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    view_id: Uuid,
+) -> Result<ViewAccessPB, ServerError> {
+    let workspace_id = read_existing_view_workspace_id(transaction, &view_id).await?;
+    if read_workspace_role(transaction, &workspace_id, &logged_user.as_uuid()?)
+        .await?
+        .is_none()
+    {
+        return Err(ServerError::permission_denied().context("Not a member of the workspace"));
+    }
+
+    let user_ids = read_acl_user_ids(transaction, &view_id).await?;
+    let mut view_access = ViewAccessPB::default();
+    view_access.set_view_id(view_id.to_string());
+    view_access.set_user_ids(user_ids.into_iter().map(|user_id| user_id.to_string()).collect());
+    Ok(view_access)
+}
+
+pub(crate) async fn grant_view_access(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    params: UpdateViewAccessParamsPB,
+) -> Result<(), ServerError> {
+    let (view_id, user_id) = parse_access_params(&params)?;
+    let workspace_id = read_existing_view_workspace_id(transaction, &view_id).await?;
+    let _ = check_workspace_owner(transaction, &workspace_id, &logged_user).await?;
+    if read_workspace_role(transaction, &workspace_id, &user_id)
+        .await?
+        .is_none()
+    {
+        return Err(ServerError::params_invalid().context(format!("{} is not a member of the workspace", user_id)));
+    }
+
+    let _ = sqlx::query(
+        "INSERT INTO view_acl_table (view_id, user_id, create_time) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING",
+    )
+    .bind(view_id)
+    .bind(user_id)
+    .bind(Utc::now())
+    .execute(transaction)
+    .await
+    .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+pub(crate) async fn revoke_view_access(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    params: UpdateViewAccessParamsPB,
+) -> Result<(), ServerError> {
+    let (view_id, user_id) = parse_access_params(&params)?;
+    let workspace_id = read_existing_view_workspace_id(transaction, &view_id).await?;
+    let _ = check_workspace_owner(transaction, &workspace_id, &logged_user).await?;
+
+    let (sql, args) = SqlBuilder::delete(VIEW_ACL_TABLE)
+        .and_where_eq("view_id", view_id)
+        .and_where_eq("user_id", user_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+pub(crate) async fn delete_view_acl(transaction: &mut DBTransaction<'_>, view_id: &Uuid) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(VIEW_ACL_TABLE)
+        .and_where_eq("view_id", view_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// The views can be nested, so the belong_to_id is followed until it reaches
+// the app. Returns None if the app of the view doesn't exist.
+pub(crate) async fn read_view_workspace_id(
+    transaction: &mut DBTransaction<'_>,
+    view_id: &Uuid,
+) -> Result<Option<Uuid>, ServerError> {
+    let mut belong_to_id = *view_id;
+    while let Some(parent_id) = read_parent_id(transaction, &belong_to_id).await? {
+        belong_to_id = parent_id;
+    }
+
+    let workspace_id = sqlx::query_as::<Postgres, (String,)>("SELECT workspace_id FROM app_table WHERE id = $1")
+        .bind(belong_to_id)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    match workspace_id {
+        None => Ok(None),
+        Some((workspace_id,)) => Ok(Some(Uuid::parse_str(&workspace_id)?)),
+    }
+}
+
+pub(crate) async fn read_existing_view_workspace_id(
+    transaction: &mut DBTransaction<'_>,
+    view_id: &Uuid,
+) -> Result<Uuid, ServerError> {
+    read_view_workspace_id(transaction, view_id)
+        .await?
+        .ok_or_else(|| ServerError::record_not_found().context(format!("View {} not exist", view_id)))
+}
+
+async fn read_parent_id(transaction: &mut DBTransaction<'_>, view_id: &Uuid) -> Result<Option<Uuid>, ServerError> {
+    let parent_id = sqlx::query_as::<Postgres, (String,)>("SELECT belong_to_id FROM view_table WHERE id = $1")
+        .bind(view_id)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    match parent_id {
+        None => Ok(None),
+        Some((parent_id,)) => Ok(Some(Uuid::parse_str(&parent_id)?)),
+    }
+}
+
+async fn read_acl_user_ids(transaction: &mut DBTransaction<'_>, view_id: &Uuid) -> Result<Vec<Uuid>, ServerError> {
+    let user_ids = sqlx::query_as::<Postgres, (Uuid,)>(
+        "SELECT user_id FROM view_acl_table WHERE view_id = $1 ORDER BY create_time ASC",
+    )
+    .bind(view_id)
+    .fetch_all(transaction)
+    .await
+    .map_err(map_sqlx_error)?
+    .into_iter()
+    .map(|(user_id,)| user_id)
+    .collect::<Vec<Uuid>>();
+    Ok(user_ids)
+}
+
+fn check_document_owner(doc_id: &str, user_id: &str) -> Result<(), ServerError> {
+    match doc_id.strip_prefix(user_id).and_then(|name| name.strip_prefix('_')) {
+        Some(name) if !name.is_empty() => Ok(()),
+        _ => Err(ServerError::permission_denied().context("The document belongs to another user")),
+    }
+}
+
+fn parse_access_params(params: &UpdateViewAccessParamsPB) -> Result<(Uuid, Uuid), ServerError> {
+    let view_id = check_view_id(params.get_view_id().to_owned())?;
+    let user_id = WorkspaceMemberId::parse(params.get_user_id().to_owned()).map_err(invalid_params)?;
+    let user_id = Uuid::parse_str(user_id.as_ref())?;
+    Ok((view_id, user_id))
+}
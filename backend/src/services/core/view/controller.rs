@@ -1,7 +1,17 @@
 use crate::{
     entities::logged_user::LoggedUser,
     services::{
-        core::{trash::read_trash_ids, view::persistence::*},
+        core::{
+            trash::read_trash_ids,
+            view::{
+                check_view_accessible,
+                delete_view_acl,
+                delete_view_guest_accesses,
+                delete_view_share_links,
+                is_view_accessible,
+                persistence::*,
+            },
+        },
         document::persistence::{create_document, delete_document, DocumentKVPersistence},
     },
     util::sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
@@ -30,11 +40,13 @@ pub(crate) async fn update_view(
     name: Option<String>,
     desc: Option<String>,
     thumbnail: Option<String>,
+    belong_to_id: Option<Uuid>,
 ) -> Result<(), ServerError> {
     let (sql, args) = SqlBuilder::update(VIEW_TABLE)
         .add_some_arg("name", name)
         .add_some_arg("description", desc)
         .add_some_arg("thumbnail", thumbnail)
+        .add_some_arg("belong_to_id", belong_to_id)
         .add_some_arg("modified_time", Some(Utc::now()))
         .and_where_eq("id", view_id)
         .build()?;
@@ -60,6 +72,9 @@ pub(crate) async fn delete_view(
             .await
             .map_err(map_sqlx_error)?;
 
+        let _ = delete_view_share_links(transaction, &view_id).await?;
+        let _ = delete_view_acl(transaction, &view_id).await?;
+        let _ = delete_view_guest_accesses(transaction, &view_id).await?;
         let _ = delete_document(kv_store, view_id).await?;
     }
     Ok(())
@@ -78,6 +93,15 @@ pub(crate) async fn create_view(
     let thumbnail = ViewThumbnail::parse(params.thumbnail).map_err(invalid_params)?;
     let desc = ViewDesc::parse(params.desc).map_err(invalid_params)?;
 
+    // A create that is retried with the same id returns the view that the
+    // first attempt created, its document is not written again.
+    match read_view_table(view_id, transaction as &mut DBTransaction<'_>).await {
+        Ok(table) if table.belong_to_id == belong_to_id.as_ref() => return Ok(table.into()),
+        Ok(_) => return Err(invalid_params("The view id is used by another view")),
+        Err(e) if e.is_record_not_found() => {},
+        Err(e) => return Err(e),
+    }
+
     let (sql, args, view) = NewViewSqlBuilder::new(view_id, belong_to_id.as_ref())
         .name(name.as_ref())
         .desc(desc.as_ref())
@@ -113,6 +137,7 @@ pub(crate) async fn read_view(
     if read_trash_ids.contains(&table.id.to_string()) {
         return Err(ServerError::record_not_found());
     }
+    let _ = check_view_accessible(transaction, &table.id, user).await?;
 
     let mut views = RepeatedViewPB::default();
     views.set_items(
@@ -162,7 +187,14 @@ pub(crate) async fn read_view_belong_to_id<'c>(
     let read_trash_ids = read_trash_ids(user, transaction).await?;
     tables.retain(|table| !read_trash_ids.contains(&table.id.to_string()));
 
-    let views = tables.into_iter().map(|table| table.into()).collect::<Vec<ViewPB>>();
+    // The views restricted to the other members are left out.
+    let user_id = user.as_uuid()?;
+    let mut views = vec![];
+    for table in tables {
+        if is_view_accessible(transaction, &table.id, &user_id).await? {
+            views.push(table.into());
+        }
+    }
 
     Ok(views)
 }
@@ -0,0 +1,212 @@
+use crate::{
+    entities::{
+        logged_user::{LoggedGuest, LoggedUser},
+        token::Token,
+    },
+    services::{
+        core::view::{check_view_editable, is_view_in_trash, persistence::check_view_id, read_view_table},
+        document::persistence::{read_document, DocumentKVPersistence},
+    },
+    util::sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::{DateTime, Utc};
+use flowy_collaboration::protobuf::{DocumentId as DocumentIdPB, DocumentInfo as DocumentInfoPB};
+use flowy_core_data_model::{
+    parser::{share::GuestAccessIdentify, workspace::WorkspaceMemberEmail},
+    protobuf::{
+        CreateGuestAccessParams as CreateGuestAccessParamsPB,
+        GuestAccess as GuestAccessPB,
+        RepeatedGuestAccess as RepeatedGuestAccessPB,
+        SharePermission as SharePermissionPB,
+        View as ViewPB,
+    },
+};
+use protobuf::ProtobufEnum;
+use sqlx::{postgres::PgArguments, Postgres};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub(crate) const VIEW_GUEST_TABLE: &str = "view_guest_table";
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(crate) struct ViewGuestTable {
+    pub(crate) id: Uuid,
+    pub(crate) view_id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) email: String,
+    pub(crate) permission: i32,
+    pub(crate) create_time: DateTime<Utc>,
+}
+
+impl ViewGuestTable {
+    pub(crate) fn is_editable(&self) -> bool { self.permission() == SharePermissionPB::Editable }
+
+    fn permission(&self) -> SharePermissionPB {
+        SharePermissionPB::from_i32(self.permission).unwrap_or(SharePermissionPB::ReadOnly)
+    }
+}
+
+// The token is left empty, it's only handed out when the access is created.
+impl std::convert::From<ViewGuestTable> for GuestAccessPB {
+    fn from(table: ViewGuestTable) -> Self {
+        let mut access = GuestAccessPB::default();
+        access.set_permission(table.permission());
+        access.set_id(table.id.to_string());
+        access.set_view_id(table.view_id.to_string());
+        access.set_email(table.email);
+        access.set_create_time(table.create_time.timestamp());
+        access
+    }
+}
+
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn create_guest_access(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    params: CreateGuestAccessParamsPB,
+) -> Result<GuestAccessPB, ServerError> {
+    let view_id = check_view_id(params.get_view_id().to_owned())?;
+    let email = WorkspaceMemberEmail::parse(params.get_email().to_owned()).map_err(invalid_params)?;
+    let _ = check_view_editable(transaction, &view_id, &logged_user).await?;
+
+    let table = ViewGuestTable {
+        id: Uuid::new_v4(),
+        view_id,
+        user_id: logged_user.as_uuid()?,
+        email: email.as_ref().to_owned(),
+        permission: params.get_permission().value(),
+        create_time: Utc::now(),
+    };
+    let (sql, args) = SqlBuilder::create(VIEW_GUEST_TABLE)
+        .add_field_with_arg("id", table.id)
+        .add_field_with_arg("view_id", table.view_id)
+        .add_field_with_arg("user_id", table.user_id)
+        .add_field_with_arg("email", table.email.clone())
+        .add_field_with_arg("permission", table.permission)
+        .add_field_with_arg("create_time", table.create_time)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let token = Token::create_guest_token(&table.id.to_string(), &table.view_id.to_string())?;
+    let mut access: GuestAccessPB = table.into();
+    access.set_token(token.0);
+    Ok(access)
+}
+
+pub(crate) async fn read_guest_accesses(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    view_id: Uuid,
+) -> Result<RepeatedGuestAccessPB, ServerError> {
+    let _ = check_view_editable(transaction, &view_id, &logged_user).await?;
+    let (sql, args) = SqlBuilder::select(VIEW_GUEST_TABLE)
+        .add_field("*")
+        .and_where_eq("view_id", view_id)
+        .build()?;
+    let mut tables = sqlx::query_as_with::<Postgres, ViewGuestTable, PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    tables.sort_by(|a, b| a.create_time.cmp(&b.create_time));
+
+    let mut repeated_access = RepeatedGuestAccessPB::default();
+    repeated_access.set_items(tables.into_iter().map(|table| table.into()).collect());
+    Ok(repeated_access)
+}
+
+// Revoking takes effect on the next request of the guest, the token itself
+// stays valid until it expires but no longer resolves the view.
+pub(crate) async fn revoke_guest_access(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    access_id: &str,
+) -> Result<(), ServerError> {
+    let access_id = GuestAccessIdentify::parse(access_id.to_owned()).map_err(invalid_params)?;
+    let table = read_guest_table(transaction, &Uuid::parse_str(access_id.as_ref())?).await?;
+    if table.user_id != logged_user.as_uuid()? {
+        let _ = check_view_editable(transaction, &table.view_id, &logged_user).await?;
+    }
+
+    let (sql, args) = SqlBuilder::delete(VIEW_GUEST_TABLE)
+        .and_where_eq("id", table.id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+pub(crate) async fn delete_view_guest_accesses(
+    transaction: &mut DBTransaction<'_>,
+    view_id: &Uuid,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(VIEW_GUEST_TABLE)
+        .and_where_eq("view_id", view_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Resolves the access of the guest token. The revoked accesses and the views
+// in the trash are reported as not found.
+pub(crate) async fn check_guest_access(
+    transaction: &mut DBTransaction<'_>,
+    guest: &LoggedGuest,
+) -> Result<ViewGuestTable, ServerError> {
+    let table = read_guest_table(transaction, &Uuid::parse_str(&guest.access_id)?).await?;
+    if table.view_id.to_string() != guest.view_id {
+        return Err(ServerError::unauthorized().context("The guest token doesn't match its access"));
+    }
+
+    if is_view_in_trash(transaction, &table.view_id).await? {
+        return Err(ServerError::record_not_found().context("The view was deleted"));
+    }
+    Ok(table)
+}
+
+// Only the view itself is returned. The parent and the nested views are left
+// out, so the guest can't walk the folder of the workspace.
+pub(crate) async fn read_guest_view(
+    transaction: &mut DBTransaction<'_>,
+    guest: &LoggedGuest,
+) -> Result<ViewPB, ServerError> {
+    let access = check_guest_access(transaction, guest).await?;
+    let table = read_view_table(access.view_id, transaction).await?;
+    let mut view: ViewPB = table.into();
+    view.set_belong_to_id("".to_owned());
+    Ok(view)
+}
+
+pub(crate) async fn read_guest_document(
+    transaction: &mut DBTransaction<'_>,
+    kv_store: &Arc<DocumentKVPersistence>,
+    guest: &LoggedGuest,
+) -> Result<DocumentInfoPB, ServerError> {
+    let access = check_guest_access(transaction, guest).await?;
+    let mut params = DocumentIdPB::new();
+    params.set_doc_id(access.view_id.to_string());
+    read_document(kv_store, params).await
+}
+
+async fn read_guest_table(
+    transaction: &mut DBTransaction<'_>,
+    access_id: &Uuid,
+) -> Result<ViewGuestTable, ServerError> {
+    let (sql, args) = SqlBuilder::select(VIEW_GUEST_TABLE)
+        .add_field("*")
+        .and_where_eq("id", access_id)
+        .build()?;
+    sqlx::query_as_with::<Postgres, ViewGuestTable, PgArguments>(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .ok_or_else(|| ServerError::record_not_found().context(format!("Guest access {} not exist", access_id)))
+}
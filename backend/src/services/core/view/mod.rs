@@ -1,6 +1,12 @@
 #![allow(clippy::module_inception)]
+mod access;
 mod controller;
+mod guest;
 pub mod persistence;
 pub mod router;
+mod share;
 
+pub(crate) use access::*;
 pub(crate) use controller::*;
+pub(crate) use guest::*;
+pub(crate) use share::*;
@@ -1,17 +1,31 @@
 use crate::{
     context::FlowyPersistence,
-    entities::logged_user::LoggedUser,
+    entities::logged_user::{LoggedGuest, LoggedUser},
     services::core::view::{
+        check_view_editable,
+        check_view_movable,
+        create_guest_access,
+        create_share_link,
         create_view,
         delete_view,
+        grant_view_access,
         persistence::{check_view_id, check_view_ids},
+        read_guest_accesses,
+        read_guest_document,
+        read_guest_view,
+        read_share_links,
+        read_shared_document,
         read_view,
+        read_view_access,
+        revoke_guest_access,
+        revoke_share_link,
+        revoke_view_access,
         update_view,
     },
     util::serde_ext::parse_from_payload,
 };
 use actix_web::{
-    web::{Data, Payload},
+    web::{Data, Path, Payload},
     HttpResponse,
 };
 use anyhow::Context;
@@ -22,14 +36,20 @@ use backend_service::{
 use flowy_core_data_model::{
     parser::view::{ViewDesc, ViewName, ViewThumbnail},
     protobuf::{
+        CreateGuestAccessParams as CreateGuestAccessParamsPB,
+        CreateShareLinkParams as CreateShareLinkParamsPB,
         CreateViewParams as CreateViewParamsPB,
+        GuestAccessId as GuestAccessIdPB,
         QueryViewRequest as QueryViewRequestPB,
+        ShareLinkId as ShareLinkIdPB,
+        UpdateViewAccessParams as UpdateViewAccessParamsPB,
         UpdateViewParams as UpdateViewParamsPB,
         ViewId as ViewIdPB,
     },
 };
 use sqlx::PgPool;
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub async fn create_handler(
     payload: Payload,
@@ -71,7 +91,11 @@ pub async fn read_handler(payload: Payload, pool: Data<PgPool>, user: LoggedUser
     Ok(FlowyResponse::success().pb(view)?.into())
 }
 
-pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn update_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: UpdateViewParamsPB = parse_from_payload(payload).await?;
     let view_id = check_view_id(params.view_id.clone())?;
     let name = match params.has_name() {
@@ -93,12 +117,21 @@ pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
         ),
     };
 
+    let belong_to_id = match params.has_belong_to_id() {
+        false => None,
+        true => Some(Uuid::parse_str(params.get_belong_to_id()).map_err(invalid_params)?),
+    };
+
     let mut transaction = pool
         .begin()
         .await
         .context("Failed to acquire a Postgres connection to update app")?;
 
-    let _ = update_view(&mut transaction, view_id, name, desc, thumbnail).await?;
+    let _ = check_view_editable(&mut transaction, &view_id, &logged_user).await?;
+    if let Some(belong_to_id) = &belong_to_id {
+        let _ = check_view_movable(&mut transaction, &view_id, belong_to_id, &logged_user).await?;
+    }
+    let _ = update_view(&mut transaction, view_id, name, desc, thumbnail, belong_to_id).await?;
 
     transaction
         .commit()
@@ -111,6 +144,7 @@ pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
 pub async fn delete_handler(
     payload: Payload,
     persistence: Data<Arc<FlowyPersistence>>,
+    logged_user: LoggedUser,
 ) -> Result<HttpResponse, ServerError> {
     let params: QueryViewRequestPB = parse_from_payload(payload).await?;
     let pool = persistence.pg_pool();
@@ -121,6 +155,9 @@ pub async fn delete_handler(
         .await
         .context("Failed to acquire a Postgres connection to delete view")?;
 
+    for view_id in &view_ids {
+        let _ = check_view_editable(&mut transaction, view_id, &logged_user).await?;
+    }
     let _ = delete_view(&mut transaction, &kv_store, view_ids).await?;
 
     transaction
@@ -130,3 +167,245 @@ pub async fn delete_handler(
 
     Ok(FlowyResponse::success().into())
 }
+
+pub async fn read_access_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdPB = parse_from_payload(payload).await?;
+    let view_id = check_view_id(params.view_id)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read view access")?;
+
+    let view_access = read_view_access(&mut transaction, logged_user, view_id).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read view access.")?;
+
+    Ok(FlowyResponse::success().pb(view_access)?.into())
+}
+
+pub async fn grant_access_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: UpdateViewAccessParamsPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to grant view access")?;
+
+    let _ = grant_view_access(&mut transaction, logged_user, params).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to grant view access.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn revoke_access_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: UpdateViewAccessParamsPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to revoke view access")?;
+
+    let _ = revoke_view_access(&mut transaction, logged_user, params).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to revoke view access.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn create_share_link_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: CreateShareLinkParamsPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to create share link")?;
+
+    let link = create_share_link(&mut transaction, logged_user, params).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to create share link.")?;
+
+    Ok(FlowyResponse::success().pb(link)?.into())
+}
+
+pub async fn read_share_links_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdPB = parse_from_payload(payload).await?;
+    let view_id = check_view_id(params.view_id)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read share links")?;
+
+    let repeated_link = read_share_links(&mut transaction, logged_user, view_id).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read share links.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_link)?.into())
+}
+
+pub async fn revoke_share_link_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ShareLinkIdPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to revoke share link")?;
+
+    let _ = revoke_share_link(&mut transaction, logged_user, params.get_link_id()).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to revoke share link.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+// Anyone who has the link can read the document, so there is no LoggedUser
+// here and the route is excluded from the auth middleware.
+pub async fn read_shared_document_handler(
+    link_id: Path<String>,
+    persistence: Data<Arc<FlowyPersistence>>,
+) -> Result<HttpResponse, ServerError> {
+    let pool = persistence.pg_pool();
+    let kv_store = persistence.kv_store();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read shared document")?;
+
+    let document = read_shared_document(&mut transaction, &kv_store, &link_id).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read shared document.")?;
+
+    Ok(FlowyResponse::success().pb(document)?.into())
+}
+
+pub async fn create_guest_access_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: CreateGuestAccessParamsPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to create guest access")?;
+
+    let access = create_guest_access(&mut transaction, logged_user, params).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to create guest access.")?;
+
+    Ok(FlowyResponse::success().pb(access)?.into())
+}
+
+pub async fn read_guest_accesses_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdPB = parse_from_payload(payload).await?;
+    let view_id = check_view_id(params.view_id)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read guest accesses")?;
+
+    let repeated_access = read_guest_accesses(&mut transaction, logged_user, view_id).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read guest accesses.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_access)?.into())
+}
+
+pub async fn revoke_guest_access_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: GuestAccessIdPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to revoke guest access")?;
+
+    let _ = revoke_guest_access(&mut transaction, logged_user, params.get_access_id()).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to revoke guest access.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+// The guest routes take the guest token instead of the LoggedUser, and they
+// only resolve the view that the token is scoped to.
+pub async fn read_guest_view_handler(pool: Data<PgPool>, guest: LoggedGuest) -> Result<HttpResponse, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read guest view")?;
+
+    let view = read_guest_view(&mut transaction, &guest).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read guest view.")?;
+
+    Ok(FlowyResponse::success().pb(view)?.into())
+}
+
+pub async fn read_guest_document_handler(
+    persistence: Data<Arc<FlowyPersistence>>,
+    guest: LoggedGuest,
+) -> Result<HttpResponse, ServerError> {
+    let pool = persistence.pg_pool();
+    let kv_store = persistence.kv_store();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read guest document")?;
+
+    let document = read_guest_document(&mut transaction, &kv_store, &guest).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read guest document.")?;
+
+    Ok(FlowyResponse::success().pb(document)?.into())
+}
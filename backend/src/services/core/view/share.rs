@@ -0,0 +1,220 @@
+use crate::{
+    entities::logged_user::LoggedUser,
+    services::{
+        core::view::{check_view_editable, persistence::check_view_id, read_view_table},
+        document::persistence::{read_document, DocumentKVPersistence},
+    },
+    util::sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::{DateTime, TimeZone, Utc};
+use flowy_collaboration::protobuf::DocumentId as DocumentIdPB;
+use flowy_core_data_model::{
+    parser::share::{ShareLinkExpireTime, ShareLinkIdentify},
+    protobuf::{
+        CreateShareLinkParams as CreateShareLinkParamsPB,
+        RepeatedShareLink as RepeatedShareLinkPB,
+        ShareLink as ShareLinkPB,
+        SharePermission as SharePermissionPB,
+        SharedDocument as SharedDocumentPB,
+    },
+};
+use protobuf::ProtobufEnum;
+use sqlx::{postgres::PgArguments, Postgres};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub(crate) const SHARE_LINK_TABLE: &str = "share_link_table";
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(crate) struct ShareLinkTable {
+    pub(crate) id: Uuid,
+    pub(crate) view_id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) permission: i32,
+    pub(crate) create_time: DateTime<Utc>,
+    pub(crate) expire_time: Option<DateTime<Utc>>,
+}
+
+impl ShareLinkTable {
+    fn is_expired(&self) -> bool {
+        match self.expire_time {
+            None => false,
+            Some(expire_time) => expire_time <= Utc::now(),
+        }
+    }
+
+    fn permission(&self) -> SharePermissionPB {
+        SharePermissionPB::from_i32(self.permission).unwrap_or(SharePermissionPB::ReadOnly)
+    }
+}
+
+// The url is left empty, the client makes it from the server address that
+// it connects to.
+impl std::convert::From<ShareLinkTable> for ShareLinkPB {
+    fn from(table: ShareLinkTable) -> Self {
+        let mut link = ShareLinkPB::default();
+        link.set_permission(table.permission());
+        link.set_id(table.id.to_string());
+        link.set_view_id(table.view_id.to_string());
+        link.set_create_time(table.create_time.timestamp());
+        if let Some(expire_time) = table.expire_time {
+            link.set_expire_time(expire_time.timestamp());
+        }
+        link
+    }
+}
+
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn create_share_link(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    params: CreateShareLinkParamsPB,
+) -> Result<ShareLinkPB, ServerError> {
+    let view_id = check_view_id(params.get_view_id().to_owned())?;
+    let expire_time = match params.has_expire_time() {
+        false => None,
+        true => ShareLinkExpireTime::parse(Some(params.get_expire_time()))
+            .map_err(invalid_params)?
+            .0
+            .map(|time| Utc.timestamp(time, 0)),
+    };
+    let _ = check_view_editable(transaction, &view_id, &logged_user).await?;
+
+    let table = ShareLinkTable {
+        id: Uuid::new_v4(),
+        view_id,
+        user_id: logged_user.as_uuid()?,
+        permission: params.get_permission().value(),
+        create_time: Utc::now(),
+        expire_time,
+    };
+    let (sql, args) = SqlBuilder::create(SHARE_LINK_TABLE)
+        .add_field_with_arg("id", table.id)
+        .add_field_with_arg("view_id", table.view_id)
+        .add_field_with_arg("user_id", table.user_id)
+        .add_field_with_arg("permission", table.permission)
+        .add_field_with_arg("create_time", table.create_time)
+        .add_field_with_arg("expire_time", table.expire_time)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(table.into())
+}
+
+pub(crate) async fn read_share_links(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    view_id: Uuid,
+) -> Result<RepeatedShareLinkPB, ServerError> {
+    let _ = check_view_editable(transaction, &view_id, &logged_user).await?;
+    let (sql, args) = SqlBuilder::select(SHARE_LINK_TABLE)
+        .add_field("*")
+        .and_where_eq("view_id", view_id)
+        .build()?;
+    let mut tables = sqlx::query_as_with::<Postgres, ShareLinkTable, PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    tables.sort_by(|a, b| a.create_time.cmp(&b.create_time));
+
+    let mut repeated_link = RepeatedShareLinkPB::default();
+    repeated_link.set_items(tables.into_iter().map(|table| table.into()).collect());
+    Ok(repeated_link)
+}
+
+// The creator of the link can always revoke it, and so can anyone who is
+// allowed to edit the view.
+pub(crate) async fn revoke_share_link(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    link_id: &str,
+) -> Result<(), ServerError> {
+    let table = read_share_link_table(transaction, link_id).await?;
+    if table.user_id != logged_user.as_uuid()? {
+        let _ = check_view_editable(transaction, &table.view_id, &logged_user).await?;
+    }
+
+    let (sql, args) = SqlBuilder::delete(SHARE_LINK_TABLE)
+        .and_where_eq("id", table.id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+pub(crate) async fn delete_view_share_links(
+    transaction: &mut DBTransaction<'_>,
+    view_id: &Uuid,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(SHARE_LINK_TABLE)
+        .and_where_eq("view_id", view_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Serves the document of the link to anyone, so the expired links and the
+// views in the trash are reported as not found.
+#[tracing::instrument(skip(transaction, kv_store), err)]
+pub(crate) async fn read_shared_document(
+    transaction: &mut DBTransaction<'_>,
+    kv_store: &Arc<DocumentKVPersistence>,
+    link_id: &str,
+) -> Result<SharedDocumentPB, ServerError> {
+    let link = read_share_link_table(transaction, link_id).await?;
+    if link.is_expired() {
+        return Err(ServerError::record_not_found().context("The share link is expired"));
+    }
+
+    if is_view_in_trash(transaction, &link.view_id).await? {
+        return Err(ServerError::record_not_found().context("The shared view was deleted"));
+    }
+
+    let view = read_view_table(link.view_id, transaction).await?;
+    let mut params = DocumentIdPB::new();
+    params.set_doc_id(view.id.to_string());
+    let document = read_document(kv_store, params).await?;
+
+    let mut shared_document = SharedDocumentPB::default();
+    shared_document.set_view_id(view.id.to_string());
+    shared_document.set_name(view.name);
+    shared_document.set_permission(link.permission());
+    shared_document.set_delta_json(document.text);
+    Ok(shared_document)
+}
+
+async fn read_share_link_table(
+    transaction: &mut DBTransaction<'_>,
+    link_id: &str,
+) -> Result<ShareLinkTable, ServerError> {
+    let link_id = ShareLinkIdentify::parse(link_id.to_owned()).map_err(invalid_params)?;
+    let link_id = Uuid::parse_str(link_id.as_ref())?;
+    let (sql, args) = SqlBuilder::select(SHARE_LINK_TABLE)
+        .add_field("*")
+        .and_where_eq("id", link_id)
+        .build()?;
+    sqlx::query_as_with::<Postgres, ShareLinkTable, PgArguments>(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .ok_or_else(|| ServerError::record_not_found().context(format!("Share link {} not exist", link_id)))
+}
+
+pub(crate) async fn is_view_in_trash(transaction: &mut DBTransaction<'_>, view_id: &Uuid) -> Result<bool, ServerError> {
+    let in_trash = sqlx::query_as::<Postgres, (Uuid,)>("SELECT id FROM trash_table WHERE id = $1")
+        .bind(view_id)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .is_some();
+    Ok(in_trash)
+}
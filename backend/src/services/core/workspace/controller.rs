@@ -1,8 +1,13 @@
-use super::persistence::NewWorkspaceBuilder;
+use super::{
+    invitation::delete_workspace_invitations,
+    member::{delete_workspace_members, role_from_i32},
+    persistence::NewWorkspaceBuilder,
+};
 use crate::{
     entities::logged_user::LoggedUser,
     services::core::{
         app::{controller::read_app, persistence::AppTable},
+        trash::read_trash_ids,
         workspace::persistence::*,
     },
     util::sqlx_ext::*,
@@ -11,19 +16,45 @@ use anyhow::Context;
 use backend_service::errors::{invalid_params, ServerError};
 use flowy_core_data_model::{
     parser::workspace::WorkspaceIdentify,
-    protobuf::{RepeatedApp as RepeatedAppPB, RepeatedWorkspace as RepeatedWorkspacePB, Workspace as WorkspacePB},
+    protobuf::{
+        RepeatedApp as RepeatedAppPB,
+        RepeatedWorkspace as RepeatedWorkspacePB,
+        Workspace as WorkspacePB,
+        WorkspaceRole as WorkspaceRolePB,
+    },
 };
 use sqlx::{postgres::PgArguments, Postgres};
 use uuid::Uuid;
 
 pub(crate) async fn create_workspace(
     transaction: &mut DBTransaction<'_>,
+    workspace_id: Option<Uuid>,
     name: &str,
     desc: &str,
     logged_user: LoggedUser,
 ) -> Result<WorkspacePB, ServerError> {
     let user_id = logged_user.as_uuid()?.to_string();
-    let (sql, args, workspace) = NewWorkspaceBuilder::new(&user_id).name(name).desc(desc).build()?;
+    let mut builder = NewWorkspaceBuilder::new(&user_id);
+    // The older clients leave the id to the server. A create that is replayed
+    // with the same id returns the workspace that the first attempt created.
+    if let Some(workspace_id) = workspace_id {
+        let (sql, args) = SqlBuilder::select(WORKSPACE_TABLE)
+            .add_field("*")
+            .and_where_eq("id", workspace_id)
+            .build()?;
+        let table = sqlx::query_as_with::<Postgres, WorkspaceTable, PgArguments>(&sql, args)
+            .fetch_optional(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+        match table {
+            Some(table) if table.user_id == user_id => return Ok(table.into()),
+            Some(_) => return Err(invalid_params("The workspace id is used by another workspace")),
+            None => {},
+        }
+        builder = builder.id(workspace_id);
+    }
+
+    let (sql, args, workspace) = builder.name(name).desc(desc).build()?;
 
     let _ = sqlx::query_with(&sql, args)
         .execute(transaction)
@@ -38,10 +69,12 @@ pub(crate) async fn update_workspace(
     workspace_id: Uuid,
     name: Option<String>,
     desc: Option<String>,
+    settings: Option<Vec<u8>>,
 ) -> Result<(), ServerError> {
     let (sql, args) = SqlBuilder::update(WORKSPACE_TABLE)
         .add_some_arg("name", name)
         .add_some_arg("description", desc)
+        .add_some_arg("settings", settings)
         .and_where_eq("id", workspace_id)
         .build()?;
 
@@ -57,6 +90,8 @@ pub(crate) async fn delete_workspace(
     transaction: &mut DBTransaction<'_>,
     workspace_id: Uuid,
 ) -> Result<(), ServerError> {
+    let _ = delete_workspace_members(transaction, &workspace_id).await?;
+    let _ = delete_workspace_invitations(transaction, &workspace_id).await?;
     let (sql, args) = SqlBuilder::delete(WORKSPACE_TABLE)
         .and_where_eq("id", workspace_id)
         .build()?;
@@ -75,27 +110,42 @@ pub async fn read_workspaces(
     workspace_id: Option<String>,
     logged_user: LoggedUser,
 ) -> Result<RepeatedWorkspacePB, ServerError> {
-    let user_id = logged_user.as_uuid()?.to_string();
-
-    let mut builder = SqlBuilder::select(WORKSPACE_TABLE)
-        .add_field("*")
-        .and_where_eq("user_id", &user_id);
-
+    let user_id = logged_user.as_uuid()?;
+
+    // The workspaces that were shared with the user are returned along with the
+    // ones the user created.
+    let mut sql = r#"
+        SELECT workspace_table.*, workspace_member_table.role FROM workspace_table
+        LEFT JOIN workspace_member_table
+        ON workspace_member_table.workspace_id = workspace_table.id AND workspace_member_table.user_id = $2
+        WHERE (workspace_table.user_id = $1 OR workspace_member_table.user_id IS NOT NULL)
+    "#
+    .to_owned();
+    let workspace_id = match workspace_id {
+        None => None,
+        Some(workspace_id) => {
+            sql.push_str(" AND workspace_table.id = $3");
+            Some(check_workspace_id(workspace_id)?)
+        },
+    };
+
+    let mut query = sqlx::query_as::<Postgres, WorkspaceTableWithRole>(&sql)
+        .bind(user_id.to_string())
+        .bind(user_id);
     if let Some(workspace_id) = workspace_id {
-        let workspace_id = check_workspace_id(workspace_id)?;
-        builder = builder.and_where_eq("id", workspace_id);
+        query = query.bind(workspace_id);
     }
-
-    let (sql, args) = builder.build()?;
-    let tables = sqlx::query_as_with::<Postgres, WorkspaceTable, PgArguments>(&sql, args)
+    let mut tables = query
         .fetch_all(transaction as &mut DBTransaction<'_>)
         .await
         .map_err(map_sqlx_error)?;
+    let trash_ids = read_trash_ids(&logged_user, transaction as &mut DBTransaction<'_>).await?;
+    tables.retain(|table| !trash_ids.contains(&table.table.id.to_string()));
 
     let mut repeated_workspace = RepeatedWorkspacePB::default();
     let mut workspaces = vec![];
     // Opti: combine the query
-    for table in tables {
+    for WorkspaceTableWithRole { table, role } in tables {
         let apps = read_workspace_apps(
             &logged_user,
             transaction as &mut DBTransaction<'_>,
@@ -105,8 +155,13 @@ pub async fn read_workspaces(
         .context("Get workspace app")
         .unwrap_or_default();
 
+        let role = match role {
+            Some(role) if table.user_id != user_id.to_string() => role_from_i32(role),
+            _ => WorkspaceRolePB::Owner,
+        };
         let mut workspace: WorkspacePB = table.into();
         workspace.set_apps(apps);
+        workspace.set_role(role);
         workspaces.push(workspace);
     }
 
@@ -114,6 +169,13 @@ pub async fn read_workspaces(
     Ok(repeated_workspace)
 }
 
+#[derive(sqlx::FromRow)]
+struct WorkspaceTableWithRole {
+    #[sqlx(flatten)]
+    table: WorkspaceTable,
+    role: Option<i32>,
+}
+
 #[tracing::instrument(skip(transaction, user), fields(app_count), err)]
 async fn read_workspace_apps<'c>(
     user: &LoggedUser,
@@ -0,0 +1,262 @@
+use crate::{
+    entities::{logged_user::LoggedUser, user::UserTable},
+    services::core::workspace::{
+        check_workspace_owner,
+        insert_workspace_member,
+        read_workspace_role,
+        read_workspace_table,
+        read_workspaces,
+        role_from_i32,
+    },
+    util::sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::{DateTime, Utc};
+use flowy_core_data_model::{
+    parser::workspace::{InvitationIdentify, WorkspaceMemberEmail},
+    protobuf::{
+        CreateInvitationParams as CreateInvitationParamsPB,
+        InvitationId as InvitationIdPB,
+        InvitationStatus as InvitationStatusPB,
+        RepeatedWorkspaceInvitation as RepeatedWorkspaceInvitationPB,
+        Workspace as WorkspacePB,
+        WorkspaceInvitation as WorkspaceInvitationPB,
+    },
+};
+use protobuf::ProtobufEnum;
+use sqlx::{postgres::PgArguments, Postgres};
+use uuid::Uuid;
+
+pub(crate) const WORKSPACE_INVITATION_TABLE: &str = "workspace_invitation_table";
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(crate) struct WorkspaceInvitationTable {
+    pub(crate) id: Uuid,
+    pub(crate) workspace_id: Uuid,
+    pub(crate) inviter_id: Uuid,
+    pub(crate) email: String,
+    pub(crate) role: i32,
+    pub(crate) status: i32,
+    pub(crate) create_time: DateTime<Utc>,
+}
+
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn create_invitation(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    params: CreateInvitationParamsPB,
+) -> Result<WorkspaceInvitationPB, ServerError> {
+    let workspace_id = Uuid::parse_str(params.get_workspace_id())?;
+    let email = WorkspaceMemberEmail::parse(params.get_email().to_owned()).map_err(invalid_params)?;
+    let _ = check_workspace_owner(transaction, &workspace_id, &logged_user).await?;
+
+    let invitee = read_user_by_email(transaction, email.as_ref()).await?;
+    if let Some(invitee) = invitee {
+        if read_workspace_role(transaction, &workspace_id, &invitee.id)
+            .await?
+            .is_some()
+        {
+            return Err(ServerError::params_invalid().context(format!("{} is already a member", email.as_ref())));
+        }
+    }
+
+    // Inviting the same email again replaces the pending invitation, so the
+    // latest role wins.
+    let (sql, args) = SqlBuilder::delete(WORKSPACE_INVITATION_TABLE)
+        .and_where_eq("workspace_id", workspace_id)
+        .and_where_eq("email", email.as_ref().to_owned())
+        .and_where_eq("status", InvitationStatusPB::Pending.value())
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let table = WorkspaceInvitationTable {
+        id: Uuid::new_v4(),
+        workspace_id,
+        inviter_id: logged_user.as_uuid()?,
+        email: email.as_ref().to_owned(),
+        role: params.get_role().value(),
+        status: InvitationStatusPB::Pending.value(),
+        create_time: Utc::now(),
+    };
+    let (sql, args) = SqlBuilder::create(WORKSPACE_INVITATION_TABLE)
+        .add_field_with_arg("id", table.id)
+        .add_field_with_arg("workspace_id", table.workspace_id)
+        .add_field_with_arg("inviter_id", table.inviter_id)
+        .add_field_with_arg("email", table.email.clone())
+        .add_field_with_arg("role", table.role)
+        .add_field_with_arg("status", table.status)
+        .add_field_with_arg("create_time", table.create_time)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    make_invitation_pb(transaction, table).await
+}
+
+// The invitations are addressed to the email of the user, so the ones sent
+// before the user signed up are included.
+pub(crate) async fn read_invitations(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+) -> Result<RepeatedWorkspaceInvitationPB, ServerError> {
+    let user = read_logged_user(transaction, &logged_user).await?;
+    let (sql, args) = SqlBuilder::select(WORKSPACE_INVITATION_TABLE)
+        .add_field("*")
+        .and_where_eq("email", user.email)
+        .and_where_eq("status", InvitationStatusPB::Pending.value())
+        .build()?;
+    let mut tables = sqlx::query_as_with::<Postgres, WorkspaceInvitationTable, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+    tables.sort_by(|a, b| a.create_time.cmp(&b.create_time));
+
+    let mut invitations = vec![];
+    for table in tables {
+        invitations.push(make_invitation_pb(transaction, table).await?);
+    }
+
+    let mut repeated_invitation = RepeatedWorkspaceInvitationPB::default();
+    repeated_invitation.set_items(invitations.into());
+    Ok(repeated_invitation)
+}
+
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn accept_invitation(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    params: InvitationIdPB,
+) -> Result<WorkspacePB, ServerError> {
+    let invitation = read_pending_invitation(transaction, &logged_user, params.get_invitation_id()).await?;
+    let user_id = logged_user.as_uuid()?;
+    let _ = insert_workspace_member(
+        transaction,
+        &invitation.workspace_id,
+        &user_id,
+        role_from_i32(invitation.role),
+    )
+    .await?;
+    let _ = update_invitation_status(transaction, &invitation.id, InvitationStatusPB::Accepted).await?;
+
+    let workspace_id = invitation.workspace_id.to_string();
+    let mut repeated_workspace = read_workspaces(transaction, Some(workspace_id.clone()), logged_user).await?;
+    if repeated_workspace.get_items().is_empty() {
+        return Err(ServerError::record_not_found().context(format!("Workspace {} not exist", workspace_id)));
+    }
+    Ok(repeated_workspace.take_items().remove(0))
+}
+
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn decline_invitation(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    params: InvitationIdPB,
+) -> Result<(), ServerError> {
+    let invitation = read_pending_invitation(transaction, &logged_user, params.get_invitation_id()).await?;
+    update_invitation_status(transaction, &invitation.id, InvitationStatusPB::Declined).await
+}
+
+pub(crate) async fn delete_workspace_invitations(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &Uuid,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(WORKSPACE_INVITATION_TABLE)
+        .and_where_eq("workspace_id", workspace_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// The invitation that was sent to another email is reported as not found, so
+// the ids can't be probed by the other users.
+async fn read_pending_invitation(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: &LoggedUser,
+    invitation_id: &str,
+) -> Result<WorkspaceInvitationTable, ServerError> {
+    let invitation_id = InvitationIdentify::parse(invitation_id.to_owned()).map_err(invalid_params)?;
+    let invitation_id = Uuid::parse_str(invitation_id.as_ref())?;
+    let user = read_logged_user(transaction, logged_user).await?;
+    let (sql, args) = SqlBuilder::select(WORKSPACE_INVITATION_TABLE)
+        .add_field("*")
+        .and_where_eq("id", invitation_id)
+        .and_where_eq("email", user.email)
+        .and_where_eq("status", InvitationStatusPB::Pending.value())
+        .build()?;
+    sqlx::query_as_with::<Postgres, WorkspaceInvitationTable, PgArguments>(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .ok_or_else(|| ServerError::record_not_found().context(format!("Invitation {} not exist", invitation_id)))
+}
+
+async fn update_invitation_status(
+    transaction: &mut DBTransaction<'_>,
+    invitation_id: &Uuid,
+    status: InvitationStatusPB,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::update(WORKSPACE_INVITATION_TABLE)
+        .add_field_with_arg("status", status.value())
+        .and_where_eq("id", invitation_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+async fn read_logged_user(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: &LoggedUser,
+) -> Result<UserTable, ServerError> {
+    sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE id = $1")
+        .bind(logged_user.as_uuid()?)
+        .fetch_one(transaction)
+        .await
+        .map_err(map_sqlx_error)
+}
+
+async fn read_user_by_email(
+    transaction: &mut DBTransaction<'_>,
+    email: &str,
+) -> Result<Option<UserTable>, ServerError> {
+    sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE email = $1")
+        .bind(email)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)
+}
+
+async fn make_invitation_pb(
+    transaction: &mut DBTransaction<'_>,
+    table: WorkspaceInvitationTable,
+) -> Result<WorkspaceInvitationPB, ServerError> {
+    let workspace = read_workspace_table(transaction, &table.workspace_id).await?;
+    let inviter_name = sqlx::query_as::<Postgres, (String,)>("SELECT name FROM user_table WHERE id = $1")
+        .bind(table.inviter_id)
+        .fetch_optional(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(|(name,)| name)
+        .unwrap_or_default();
+
+    let mut invitation = WorkspaceInvitationPB::default();
+    invitation.set_id(table.id.to_string());
+    invitation.set_workspace_id(table.workspace_id.to_string());
+    invitation.set_workspace_name(workspace.name);
+    invitation.set_inviter_name(inviter_name);
+    invitation.set_email(table.email);
+    invitation.set_role(role_from_i32(table.role));
+    invitation.set_status(InvitationStatusPB::from_i32(table.status).unwrap_or(InvitationStatusPB::Pending));
+    invitation.set_create_time(table.create_time.timestamp());
+    Ok(invitation)
+}
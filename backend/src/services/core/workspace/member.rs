@@ -0,0 +1,281 @@
+use crate::{
+    entities::{logged_user::LoggedUser, user::UserTable},
+    services::core::workspace::persistence::{WorkspaceTable, WORKSPACE_TABLE},
+    util::sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::Utc;
+use flowy_core_data_model::{
+    parser::workspace::{WorkspaceMemberEmail, WorkspaceMemberId},
+    protobuf::{
+        AddWorkspaceMemberParams as AddWorkspaceMemberParamsPB,
+        RemoveWorkspaceMemberParams as RemoveWorkspaceMemberParamsPB,
+        RepeatedWorkspaceMember as RepeatedWorkspaceMemberPB,
+        UpdateWorkspaceMemberParams as UpdateWorkspaceMemberParamsPB,
+        WorkspaceMember as WorkspaceMemberPB,
+        WorkspaceRole as WorkspaceRolePB,
+    },
+};
+use protobuf::ProtobufEnum;
+use sqlx::{postgres::PgArguments, Postgres};
+use uuid::Uuid;
+
+pub(crate) const WORKSPACE_MEMBER_TABLE: &str = "workspace_member_table";
+
+// The creator of the workspace is its owner and isn't stored in the member
+// table, so the owner can't be removed or demoted.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn read_workspace_role(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<Option<WorkspaceRolePB>, ServerError> {
+    let workspace = read_workspace_table(transaction, workspace_id).await?;
+    if workspace.user_id == user_id.to_string() {
+        return Ok(Some(WorkspaceRolePB::Owner));
+    }
+
+    let role = sqlx::query_as::<Postgres, (i32,)>(
+        "SELECT role FROM workspace_member_table WHERE workspace_id = $1 AND user_id = $2",
+    )
+    .bind(workspace_id)
+    .bind(user_id)
+    .fetch_optional(transaction)
+    .await
+    .map_err(map_sqlx_error)?
+    .map(|(role,)| role_from_i32(role));
+    Ok(role)
+}
+
+pub(crate) async fn check_workspace_owner(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &Uuid,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    match read_workspace_role(transaction, workspace_id, &logged_user.as_uuid()?).await? {
+        Some(WorkspaceRolePB::Owner) => Ok(()),
+        _ => Err(ServerError::permission_denied().context("Only the owner can manage the workspace")),
+    }
+}
+
+pub(crate) async fn read_workspace_members(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    logged_user: LoggedUser,
+) -> Result<RepeatedWorkspaceMemberPB, ServerError> {
+    if read_workspace_role(transaction, &workspace_id, &logged_user.as_uuid()?)
+        .await?
+        .is_none()
+    {
+        return Err(ServerError::permission_denied().context("Not a member of the workspace"));
+    }
+
+    let workspace = read_workspace_table(transaction, &workspace_id).await?;
+    let owner_id = Uuid::parse_str(&workspace.user_id)?;
+    let owner = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE id = $1")
+        .bind(owner_id)
+        .fetch_one(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut members = vec![make_member_pb(
+        &workspace_id,
+        &owner.id,
+        owner.name,
+        owner.email,
+        WorkspaceRolePB::Owner,
+    )];
+    let rows = sqlx::query_as::<Postgres, (Uuid, i32, String, String)>(
+        r#"
+            SELECT workspace_member_table.user_id, workspace_member_table.role, user_table.name, user_table.email
+            FROM workspace_member_table JOIN user_table ON user_table.id = workspace_member_table.user_id
+            WHERE workspace_member_table.workspace_id = $1
+            ORDER BY workspace_member_table.create_time ASC
+        "#,
+    )
+    .bind(workspace_id)
+    .fetch_all(transaction as &mut DBTransaction<'_>)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    for (user_id, role, name, email) in rows {
+        members.push(make_member_pb(
+            &workspace_id,
+            &user_id,
+            name,
+            email,
+            role_from_i32(role),
+        ));
+    }
+
+    let mut repeated_member = RepeatedWorkspaceMemberPB::default();
+    repeated_member.set_items(members.into());
+    Ok(repeated_member)
+}
+
+pub(crate) async fn add_workspace_member(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    params: AddWorkspaceMemberParamsPB,
+) -> Result<WorkspaceMemberPB, ServerError> {
+    let workspace_id = Uuid::parse_str(params.get_workspace_id())?;
+    let email = WorkspaceMemberEmail::parse(params.get_email().to_owned()).map_err(invalid_params)?;
+    let _ = check_workspace_owner(transaction, &workspace_id, &logged_user).await?;
+
+    let user = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE email = $1")
+        .bind(email.as_ref())
+        .fetch_optional(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?
+        .ok_or_else(|| ServerError::record_not_found().context(format!("{} not exist", email.as_ref())))?;
+
+    let workspace = read_workspace_table(transaction, &workspace_id).await?;
+    if workspace.user_id == user.id.to_string() {
+        return Err(ServerError::params_invalid().context("The owner is already a member of the workspace"));
+    }
+
+    let _ = insert_workspace_member(transaction, &workspace_id, &user.id, params.get_role()).await?;
+    Ok(make_member_pb(
+        &workspace_id,
+        &user.id,
+        user.name,
+        user.email,
+        params.get_role(),
+    ))
+}
+
+pub(crate) async fn update_workspace_member(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    params: UpdateWorkspaceMemberParamsPB,
+) -> Result<(), ServerError> {
+    let workspace_id = Uuid::parse_str(params.get_workspace_id())?;
+    let user_id = parse_member_id(params.get_user_id())?;
+    let _ = check_workspace_owner(transaction, &workspace_id, &logged_user).await?;
+
+    let (sql, args) = SqlBuilder::update(WORKSPACE_MEMBER_TABLE)
+        .add_field_with_arg("role", params.get_role().value())
+        .and_where_eq("workspace_id", workspace_id)
+        .and_where_eq("user_id", user_id)
+        .build()?;
+    let result = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(ServerError::record_not_found().context(format!("Member {} not exist", user_id)));
+    }
+    Ok(())
+}
+
+// The owner can remove any member, and the other members can only leave the
+// workspace by removing themselves.
+pub(crate) async fn remove_workspace_member(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: LoggedUser,
+    params: RemoveWorkspaceMemberParamsPB,
+) -> Result<(), ServerError> {
+    let workspace_id = Uuid::parse_str(params.get_workspace_id())?;
+    let user_id = parse_member_id(params.get_user_id())?;
+    if user_id != logged_user.as_uuid()? {
+        let _ = check_workspace_owner(transaction, &workspace_id, &logged_user).await?;
+    }
+
+    if !delete_member(transaction, &workspace_id, &user_id).await? {
+        return Err(ServerError::record_not_found().context(format!("Member {} not exist", user_id)));
+    }
+    Ok(())
+}
+
+pub(crate) async fn delete_workspace_members(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &Uuid,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(WORKSPACE_MEMBER_TABLE)
+        .and_where_eq("workspace_id", workspace_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Adding an existing member again replaces the role.
+pub(crate) async fn insert_workspace_member(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &Uuid,
+    user_id: &Uuid,
+    role: WorkspaceRolePB,
+) -> Result<(), ServerError> {
+    let _ = delete_member(transaction, workspace_id, user_id).await?;
+    let (sql, args) = SqlBuilder::create(WORKSPACE_MEMBER_TABLE)
+        .add_field_with_arg("workspace_id", *workspace_id)
+        .add_field_with_arg("user_id", *user_id)
+        .add_field_with_arg("role", role.value())
+        .add_field_with_arg("create_time", Utc::now())
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+async fn delete_member(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<bool, ServerError> {
+    let (sql, args) = SqlBuilder::delete(WORKSPACE_MEMBER_TABLE)
+        .and_where_eq("workspace_id", workspace_id)
+        .and_where_eq("user_id", user_id)
+        .build()?;
+    let result = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub(crate) async fn read_workspace_table(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &Uuid,
+) -> Result<WorkspaceTable, ServerError> {
+    let (sql, args) = SqlBuilder::select(WORKSPACE_TABLE)
+        .add_field("*")
+        .and_where_eq("id", workspace_id)
+        .build()?;
+    sqlx::query_as_with::<Postgres, WorkspaceTable, PgArguments>(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .ok_or_else(|| ServerError::record_not_found().context(format!("Workspace {} not exist", workspace_id)))
+}
+
+fn parse_member_id(user_id: &str) -> Result<Uuid, ServerError> {
+    let user_id = WorkspaceMemberId::parse(user_id.to_owned()).map_err(invalid_params)?;
+    let user_id = Uuid::parse_str(user_id.as_ref())?;
+    Ok(user_id)
+}
+
+pub(crate) fn role_from_i32(role: i32) -> WorkspaceRolePB {
+    WorkspaceRolePB::from_i32(role).unwrap_or(WorkspaceRolePB::Viewer)
+}
+
+fn make_member_pb(
+    workspace_id: &Uuid,
+    user_id: &Uuid,
+    name: String,
+    email: String,
+    role: WorkspaceRolePB,
+) -> WorkspaceMemberPB {
+    let mut member = WorkspaceMemberPB::default();
+    member.set_workspace_id(workspace_id.to_string());
+    member.set_user_id(user_id.to_string());
+    member.set_name(name);
+    member.set_email(email);
+    member.set_role(role);
+    member
+}
@@ -1,6 +1,10 @@
 #![allow(clippy::module_inception)]
 mod controller;
+mod invitation;
+mod member;
 pub mod persistence;
 pub mod router;
 
 pub use controller::*;
+pub(crate) use invitation::*;
+pub(crate) use member::*;
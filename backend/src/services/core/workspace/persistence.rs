@@ -1,7 +1,11 @@
 use crate::util::sqlx_ext::SqlBuilder;
 use backend_service::errors::{invalid_params, ServerError};
 use chrono::{DateTime, NaiveDateTime, Utc};
-use flowy_core_data_model::{parser::workspace::WorkspaceIdentify, protobuf::Workspace as WorkspacePB};
+use flowy_core_data_model::{
+    parser::workspace::WorkspaceIdentify,
+    protobuf::{Workspace as WorkspacePB, WorkspaceSettings as WorkspaceSettingsPB},
+};
+use protobuf::Message;
 use sqlx::postgres::PgArguments;
 use uuid::Uuid;
 
@@ -21,6 +25,7 @@ impl NewWorkspaceBuilder {
             modified_time: time,
             create_time: time,
             user_id: user_id.to_string(),
+            settings: None,
         };
         Self { table }
     }
@@ -37,11 +42,17 @@ impl NewWorkspaceBuilder {
             modified_time,
             create_time,
             user_id: user_id.to_string(),
+            settings: None,
         };
 
         Ok(Self { table })
     }
 
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.table.id = id;
+        self
+    }
+
     pub fn name(mut self, name: &str) -> Self {
         self.table.name = name.to_string();
         self
@@ -84,6 +95,7 @@ pub struct WorkspaceTable {
     pub(crate) modified_time: chrono::DateTime<Utc>,
     pub(crate) create_time: chrono::DateTime<Utc>,
     pub(crate) user_id: String,
+    pub(crate) settings: Option<Vec<u8>>,
 }
 impl std::convert::From<WorkspaceTable> for WorkspacePB {
     fn from(table: WorkspaceTable) -> Self {
@@ -93,6 +105,12 @@ impl std::convert::From<WorkspaceTable> for WorkspacePB {
         workspace.set_desc(table.description.clone());
         workspace.set_modified_time(table.modified_time.timestamp());
         workspace.set_create_time(table.create_time.timestamp());
+        if let Some(bytes) = &table.settings {
+            match WorkspaceSettingsPB::parse_from_bytes(bytes) {
+                Ok(settings) => workspace.set_settings(settings),
+                Err(e) => log::error!("Parse the settings of workspace {} failed: {:?}", table.id, e),
+            }
+        }
         workspace
     }
 }
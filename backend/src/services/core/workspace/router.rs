@@ -1,11 +1,21 @@
 use crate::{
     entities::logged_user::LoggedUser,
     services::core::workspace::{
+        accept_invitation,
+        add_workspace_member,
+        check_workspace_owner,
+        create_invitation,
         create_workspace,
+        decline_invitation,
         delete_workspace,
         persistence::check_workspace_id,
+        read_invitations,
+        read_workspace_members,
+        read_workspace_role,
         read_workspaces,
+        remove_workspace_member,
         update_workspace,
+        update_workspace_member,
     },
     util::serde_ext::parse_from_payload,
 };
@@ -21,11 +31,19 @@ use backend_service::{
 use flowy_core_data_model::{
     parser::workspace::{WorkspaceDesc, WorkspaceName},
     protobuf::{
+        AddWorkspaceMemberParams as AddWorkspaceMemberParamsPB,
+        CreateInvitationParams as CreateInvitationParamsPB,
         CreateWorkspaceParams as CreateWorkspaceParamsPB,
+        InvitationId as InvitationIdPB,
+        QueryWorkspaceMembersParams as QueryWorkspaceMembersParamsPB,
+        RemoveWorkspaceMemberParams as RemoveWorkspaceMemberParamsPB,
+        UpdateWorkspaceMemberParams as UpdateWorkspaceMemberParamsPB,
         UpdateWorkspaceParams as UpdateWorkspaceParamsPB,
         WorkspaceId as WorkspaceIdPB,
+        WorkspaceRole as WorkspaceRolePB,
     },
 };
+use protobuf::Message;
 use sqlx::PgPool;
 
 pub async fn create_handler(
@@ -36,11 +54,22 @@ pub async fn create_handler(
     let params: CreateWorkspaceParamsPB = parse_from_payload(payload).await?;
     let name = WorkspaceName::parse(params.get_name().to_owned()).map_err(invalid_params)?;
     let desc = WorkspaceDesc::parse(params.get_desc().to_owned()).map_err(invalid_params)?;
+    let workspace_id = match params.get_workspace_id() {
+        "" => None,
+        workspace_id => Some(check_workspace_id(workspace_id.to_owned())?),
+    };
     let mut transaction = pool
         .begin()
         .await
         .context("Failed to acquire a Postgres connection to create workspace")?;
-    let workspace = create_workspace(&mut transaction, name.as_ref(), desc.as_ref(), logged_user).await?;
+    let workspace = create_workspace(
+        &mut transaction,
+        workspace_id,
+        name.as_ref(),
+        desc.as_ref(),
+        logged_user,
+    )
+    .await?;
     transaction
         .commit()
         .await
@@ -78,7 +107,7 @@ pub async fn read_handler(
 pub async fn delete_handler(
     payload: Payload,
     pool: Data<PgPool>,
-    _logged_user: LoggedUser,
+    logged_user: LoggedUser,
 ) -> Result<HttpResponse, ServerError> {
     let params: WorkspaceIdPB = parse_from_payload(payload).await?;
     let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
@@ -87,6 +116,7 @@ pub async fn delete_handler(
         .await
         .context("Failed to acquire a Postgres connection to delete workspace")?;
 
+    let _ = check_workspace_owner(&mut transaction, &workspace_id, &logged_user).await?;
     let _ = delete_workspace(&mut transaction, workspace_id).await?;
     transaction
         .commit()
@@ -99,7 +129,7 @@ pub async fn delete_handler(
 pub async fn update_handler(
     payload: Payload,
     pool: Data<PgPool>,
-    _logged_user: LoggedUser,
+    logged_user: LoggedUser,
 ) -> Result<HttpResponse, ServerError> {
     let params: UpdateWorkspaceParamsPB = parse_from_payload(payload).await?;
     let workspace_id = check_workspace_id(params.get_id().to_owned())?;
@@ -123,12 +153,23 @@ pub async fn update_handler(
         },
     };
 
+    let settings = match params.has_settings() {
+        false => None,
+        true => Some(params.get_settings().write_to_bytes()?),
+    };
+
     let mut transaction = pool
         .begin()
         .await
         .context("Failed to acquire a Postgres connection to update workspace")?;
 
-    let _ = update_workspace(&mut transaction, workspace_id, name, desc).await?;
+    // Only the owner changes the settings that every member shares.
+    match read_workspace_role(&mut transaction, &workspace_id, &logged_user.as_uuid()?).await? {
+        Some(WorkspaceRolePB::Owner) => {},
+        Some(WorkspaceRolePB::Editor) if settings.is_none() => {},
+        _ => return Err(ServerError::permission_denied().context("The workspace is read-only")),
+    }
+    let _ = update_workspace(&mut transaction, workspace_id, name, desc, settings).await?;
 
     transaction
         .commit()
@@ -152,3 +193,162 @@ pub async fn workspace_list(pool: Data<PgPool>, logged_user: LoggedUser) -> Resu
 
     Ok(FlowyResponse::success().pb(repeated_workspace)?.into())
 }
+
+pub async fn read_member_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: QueryWorkspaceMembersParamsPB = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read workspace members")?;
+
+    let repeated_member = read_workspace_members(&mut transaction, workspace_id, logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read workspace members.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_member)?.into())
+}
+
+pub async fn add_member_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: AddWorkspaceMemberParamsPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to add workspace member")?;
+
+    let member = add_workspace_member(&mut transaction, logged_user, params).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to add workspace member.")?;
+
+    Ok(FlowyResponse::success().pb(member)?.into())
+}
+
+pub async fn update_member_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: UpdateWorkspaceMemberParamsPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to update workspace member")?;
+
+    let _ = update_workspace_member(&mut transaction, logged_user, params).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to update workspace member.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn remove_member_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: RemoveWorkspaceMemberParamsPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to remove workspace member")?;
+
+    let _ = remove_workspace_member(&mut transaction, logged_user, params).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to remove workspace member.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn create_invitation_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: CreateInvitationParamsPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to create workspace invitation")?;
+
+    let invitation = create_invitation(&mut transaction, logged_user, params).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to create workspace invitation.")?;
+
+    Ok(FlowyResponse::success().pb(invitation)?.into())
+}
+
+pub async fn read_invitations_handler(
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read workspace invitations")?;
+
+    let repeated_invitation = read_invitations(&mut transaction, logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read workspace invitations.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_invitation)?.into())
+}
+
+pub async fn accept_invitation_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: InvitationIdPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to accept workspace invitation")?;
+
+    let workspace = accept_invitation(&mut transaction, logged_user, params).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to accept workspace invitation.")?;
+
+    Ok(FlowyResponse::success().pb(workspace)?.into())
+}
+
+pub async fn decline_invitation_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: InvitationIdPB = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to decline workspace invitation")?;
+
+    let _ = decline_invitation(&mut transaction, logged_user, params).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to decline workspace invitation.")?;
+
+    Ok(FlowyResponse::success().into())
+}
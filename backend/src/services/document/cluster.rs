@@ -0,0 +1,636 @@
+use crate::{
+    config::RedisSettings,
+    services::{
+        document::persistence::DocumentKVPersistence,
+        web_socket::{entities::Socket, WSUser, WebSocketMessage},
+    },
+    util::{serde_ext::parse_from_bytes, user_ext::uuid},
+};
+use async_trait::async_trait;
+use backend_service::errors::{internal_error, Result};
+use bytes::Bytes;
+use dashmap::DashMap;
+use flowy_collaboration::{
+    entities::ws::DocumentServerWSData,
+    protobuf::{
+        DocumentClientWSData as DocumentClientWSDataPB,
+        DocumentClientWSDataType as DocumentClientWSDataTypePB,
+    },
+    sync::{RevisionUser, ServerDocumentManager, SyncResponse},
+};
+use futures::stream::StreamExt;
+use redis::{aio::MultiplexedConnection, Script};
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
+
+// The owner renews the leases of its documents every third of it, so a
+// document is taken over within this time after its owner went down.
+const LEASE_DURATION: Duration = Duration::from_secs(15);
+
+// The owner gives up the documents that nobody edited for this long.
+const IDLE_DOCUMENT_TIMEOUT: Duration = Duration::from_secs(120);
+
+// Returns the owner of the document, after taking the lease if nobody holds
+// it or renewing it if the caller does.
+const ACQUIRE_SCRIPT: &str = r#"
+local owner = redis.call('GET', KEYS[1])
+if not owner then
+    redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2])
+    return ARGV[1]
+end
+if owner == ARGV[1] then
+    redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return owner
+"#;
+
+const RELEASE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+pub struct ClusterConnection {
+    client: redis::Client,
+    conn: MultiplexedConnection,
+}
+
+impl ClusterConnection {
+    pub async fn connect(settings: &RedisSettings) -> Result<Self> {
+        let client = redis::Client::open(settings.url.as_str()).map_err(internal_error)?;
+        let conn = client
+            .get_multiplexed_tokio_connection()
+            .await
+            .map_err(internal_error)?;
+        Ok(Self { client, conn })
+    }
+}
+
+// The leases of the documents and the channels of the servers. Redis in
+// production, the servers of a cluster must share the same transport.
+#[async_trait]
+pub trait ClusterTransport: Send + Sync {
+    // Returns the owner of the lease, see ACQUIRE_SCRIPT.
+    async fn acquire(&self, key: &str, instance_id: &str, duration: Duration) -> Result<String>;
+
+    async fn release(&self, key: &str, instance_id: &str) -> Result<()>;
+
+    async fn publish(&self, channel: &str, payload: String) -> Result<()>;
+
+    async fn subscribe(&self, channel: &str) -> Result<mpsc::UnboundedReceiver<String>>;
+}
+
+#[async_trait]
+impl ClusterTransport for ClusterConnection {
+    async fn acquire(&self, key: &str, instance_id: &str, duration: Duration) -> Result<String> {
+        let mut conn = self.conn.clone();
+        Script::new(ACQUIRE_SCRIPT)
+            .key(key)
+            .arg(instance_id)
+            .arg(duration.as_millis() as u64)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(internal_error)
+    }
+
+    async fn release(&self, key: &str, instance_id: &str) -> Result<()> {
+        let mut conn = self.conn.clone();
+        let _: i64 = Script::new(RELEASE_SCRIPT)
+            .key(key)
+            .arg(instance_id)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(internal_error)?;
+        Ok(())
+    }
+
+    async fn publish(&self, channel: &str, payload: String) -> Result<()> {
+        let mut conn = self.conn.clone();
+        let _: i64 = redis::cmd("PUBLISH")
+            .arg(channel)
+            .arg(payload)
+            .query_async(&mut conn)
+            .await
+            .map_err(internal_error)?;
+        Ok(())
+    }
+
+    async fn subscribe(&self, channel: &str) -> Result<mpsc::UnboundedReceiver<String>> {
+        let mut pubsub = self
+            .client
+            .get_async_connection()
+            .await
+            .map_err(internal_error)?
+            .into_pubsub();
+        let _ = pubsub.subscribe(channel).await.map_err(internal_error)?;
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut messages = pubsub.on_message();
+            while let Some(msg) = messages.next().await {
+                match msg.get_payload::<String>() {
+                    Ok(payload) => {
+                        if sender.send(payload).is_err() {
+                            break;
+                        }
+                    },
+                    Err(e) => log::error!("Read the cluster message failed: {}", e),
+                }
+            }
+        });
+        Ok(receiver)
+    }
+}
+
+pub enum DocumentOwner {
+    Local,
+    Remote(String),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum ClusterMessage {
+    // The client data that another server received for a document of this
+    // one.
+    Relay {
+        instance_id: String,
+        connection_id: String,
+        user_id: String,
+        data: Vec<u8>,
+    },
+    // The response of the owner to the relayed data, for the socket of the
+    // connection.
+    Reply {
+        connection_id: String,
+        data: Vec<u8>,
+    },
+}
+
+struct OwnedDocument {
+    lease_until: Instant,
+    used_at: Instant,
+}
+
+// Lets the servers behind a load balancer edit the same documents. Each
+// document is opened by one server at a time, the owner, which holds its lease
+// in Redis. The other servers relay the client data of the document to the
+// owner over pub/sub, and send the owner's responses back to their clients.
+pub struct DocumentCluster {
+    instance_id: String,
+    transport: Arc<dyn ClusterTransport>,
+    doc_manager: Arc<ServerDocumentManager>,
+    kv_store: Arc<DocumentKVPersistence>,
+    owned: DashMap<String, OwnedDocument>,
+    // The sockets of the connections whose data was relayed to another
+    // server, they receive the replies.
+    sockets: DashMap<String, Socket>,
+}
+
+impl DocumentCluster {
+    pub fn new(
+        transport: Arc<dyn ClusterTransport>,
+        doc_manager: Arc<ServerDocumentManager>,
+        kv_store: Arc<DocumentKVPersistence>,
+    ) -> Self {
+        Self {
+            instance_id: uuid(),
+            transport,
+            doc_manager,
+            kv_store,
+            owned: DashMap::new(),
+            sockets: DashMap::new(),
+        }
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        tokio::spawn(listen_cluster_messages(self.clone()));
+        tokio::spawn(renew_leases(self.clone()));
+    }
+
+    pub async fn owner_of(&self, doc_id: &str) -> Result<DocumentOwner> {
+        if let Some(mut document) = self.owned.get_mut(doc_id) {
+            if document.lease_until > Instant::now() + LEASE_DURATION / 2 {
+                document.used_at = Instant::now();
+                return Ok(DocumentOwner::Local);
+            }
+        }
+
+        let owner = self.acquire(doc_id).await?;
+        if owner == self.instance_id {
+            let now = Instant::now();
+            let document = OwnedDocument {
+                lease_until: now + LEASE_DURATION,
+                used_at: now,
+            };
+            // A document that was owned by another server before may be
+            // changed, so the opened one is read again.
+            if self.owned.insert(doc_id.to_owned(), document).is_none() {
+                self.doc_manager.close_document(doc_id).await;
+            }
+            Ok(DocumentOwner::Local)
+        } else {
+            if self.owned.remove(doc_id).is_some() {
+                self.doc_manager.close_document(doc_id).await;
+            }
+            Ok(DocumentOwner::Remote(owner))
+        }
+    }
+
+    pub async fn relay(&self, owner: &str, user: &WSUser, socket: Socket, data: Bytes) -> Result<()> {
+        let connection_id = user.connection_id().to_owned();
+        self.sockets.insert(connection_id.clone(), socket);
+        let msg = ClusterMessage::Relay {
+            instance_id: self.instance_id.clone(),
+            connection_id,
+            user_id: user.id().to_owned(),
+            data: data.to_vec(),
+        };
+        self.publish(owner, &msg).await
+    }
+
+    async fn acquire(&self, doc_id: &str) -> Result<String> {
+        self.transport
+            .acquire(&lease_key(doc_id), &self.instance_id, LEASE_DURATION)
+            .await
+    }
+
+    async fn release(&self, doc_id: &str) -> Result<()> {
+        self.transport.release(&lease_key(doc_id), &self.instance_id).await
+    }
+
+    async fn publish(&self, instance_id: &str, msg: &ClusterMessage) -> Result<()> {
+        let payload = serde_json::to_string(msg).map_err(internal_error)?;
+        self.transport.publish(&instance_channel(instance_id), payload).await
+    }
+
+    async fn handle_message(self: &Arc<Self>, msg: ClusterMessage) -> Result<()> {
+        match msg {
+            ClusterMessage::Relay {
+                instance_id,
+                connection_id,
+                user_id,
+                data,
+            } => {
+                let client_data = parse_from_bytes::<DocumentClientWSDataPB>(&data)?;
+                // The lease may have run out while the data was relayed. The
+                // client sends it again since it isn't acked.
+                if let DocumentOwner::Remote(owner) = self.owner_of(&client_data.doc_id).await? {
+                    tracing::warn!(
+                        "Document:{} is owned by {} now, drop the relayed data",
+                        client_data.doc_id,
+                        owner
+                    );
+                    return Ok(());
+                }
+
+                let user = Arc::new(RelayedDocUser {
+                    cluster: self.clone(),
+                    instance_id,
+                    connection_id,
+                    user_id,
+                });
+                let result = match &client_data.ty {
+                    DocumentClientWSDataTypePB::ClientPushRev => {
+                        self.doc_manager.handle_client_revisions(user, client_data).await
+                    },
+                    DocumentClientWSDataTypePB::ClientPing => {
+                        self.doc_manager.handle_client_ping(user, client_data).await
+                    },
+                };
+                result.map_err(internal_error)
+            },
+            ClusterMessage::Reply { connection_id, data } => {
+                match self.sockets.get(&connection_id) {
+                    None => tracing::debug!("The connection {} of the reply is closed", connection_id),
+                    Some(socket) => {
+                        let msg = WebSocketMessage(Bytes::from(data));
+                        let _ = socket.try_send(msg).map_err(internal_error)?;
+                    },
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+async fn listen_cluster_messages(cluster: Arc<DocumentCluster>) {
+    let channel = instance_channel(&cluster.instance_id);
+    let mut messages = match cluster.transport.subscribe(&channel).await {
+        Ok(messages) => messages,
+        Err(e) => {
+            log::error!("Subscribe to the cluster failed: {}", e);
+            return;
+        },
+    };
+
+    while let Some(payload) = messages.recv().await {
+        let msg = match serde_json::from_str::<ClusterMessage>(&payload).map_err(internal_error) {
+            Ok(msg) => msg,
+            Err(e) => {
+                log::error!("Parse the cluster message failed: {}", e);
+                continue;
+            },
+        };
+
+        let cluster = cluster.clone();
+        tokio::spawn(async move {
+            if let Err(e) = cluster.handle_message(msg).await {
+                log::error!("Handle the cluster message failed: {}", e);
+            }
+        });
+    }
+}
+
+// Renews the leases of the documents that are edited and releases the idle
+// ones, and forgets the sockets of the closed connections.
+async fn renew_leases(cluster: Arc<DocumentCluster>) {
+    let mut interval = tokio::time::interval(LEASE_DURATION / 3);
+    loop {
+        interval.tick().await;
+        let doc_ids = cluster
+            .owned
+            .iter()
+            .map(|document| (document.key().clone(), document.used_at))
+            .collect::<Vec<_>>();
+        for (doc_id, used_at) in doc_ids {
+            if used_at.elapsed() > IDLE_DOCUMENT_TIMEOUT {
+                cluster.owned.remove(&doc_id);
+                cluster.doc_manager.close_document(&doc_id).await;
+                if let Err(e) = cluster.release(&doc_id).await {
+                    log::error!("Release the document {} failed: {}", doc_id, e);
+                }
+                continue;
+            }
+
+            match cluster.acquire(&doc_id).await {
+                Ok(owner) if owner == cluster.instance_id => {
+                    if let Some(mut document) = cluster.owned.get_mut(&doc_id) {
+                        document.lease_until = Instant::now() + LEASE_DURATION;
+                    }
+                },
+                Ok(owner) => {
+                    tracing::warn!("Document:{} was taken over by {}", doc_id, owner);
+                    cluster.owned.remove(&doc_id);
+                    cluster.doc_manager.close_document(&doc_id).await;
+                },
+                Err(e) => log::error!("Renew the lease of {} failed: {}", doc_id, e),
+            }
+        }
+        cluster.sockets.retain(|_, socket| socket.connected());
+    }
+}
+
+// The user of a connection of another server. The responses are sent back to
+// that server, which owns the socket.
+struct RelayedDocUser {
+    cluster: Arc<DocumentCluster>,
+    instance_id: String,
+    connection_id: String,
+    user_id: String,
+}
+
+impl std::fmt::Debug for RelayedDocUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RelayedDocUser")
+            .field("instance_id", &self.instance_id)
+            .field("connection_id", &self.connection_id)
+            .field("user_id", &self.user_id)
+            .finish()
+    }
+}
+
+impl RelayedDocUser {
+    fn reply(&self, data: DocumentServerWSData) {
+        let msg: WebSocketMessage = data.into();
+        let reply = ClusterMessage::Reply {
+            connection_id: self.connection_id.clone(),
+            data: msg.0.to_vec(),
+        };
+        let cluster = self.cluster.clone();
+        let instance_id = self.instance_id.clone();
+        tokio::spawn(async move {
+            if let Err(e) = cluster.publish(&instance_id, &reply).await {
+                log::error!("[RelayedDocUser]: {}", e);
+            }
+        });
+    }
+}
+
+impl RevisionUser for RelayedDocUser {
+    fn user_id(&self) -> String { self.user_id.clone() }
+
+    fn receive(&self, resp: SyncResponse) {
+        match resp {
+            SyncResponse::Pull(data) | SyncResponse::Push(data) | SyncResponse::Ack(data) => self.reply(data),
+            SyncResponse::NewRevision(mut repeated_revision) => {
+                let kv_store = self.cluster.kv_store.clone();
+                tokio::task::spawn(async move {
+                    let revisions = repeated_revision.take_items().into();
+                    match kv_store.batch_set_revision(revisions).await {
+                        Ok(_) => {},
+                        Err(e) => log::error!("{}", e),
+                    }
+                });
+            },
+        }
+    }
+}
+
+fn lease_key(doc_id: &str) -> String { format!("flowy:doc_owner:{}", doc_id) }
+
+fn instance_channel(instance_id: &str) -> String { format!("flowy:instance:{}", instance_id) }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entities::logged_user::LoggedUser,
+        services::{
+            document::{
+                cluster::{ClusterTransport, DocumentCluster, DocumentOwner},
+                persistence::DocumentKVPersistence,
+            },
+            kv::PostgresKV,
+            web_socket::{WSUser, WebSocketMessage},
+        },
+    };
+    use actix::{Actor, Context, Handler};
+    use async_trait::async_trait;
+    use backend_service::errors::Result;
+    use bytes::Bytes;
+    use flowy_collaboration::{
+        entities::ws::{DocumentClientWSData, DocumentServerWSData, DocumentServerWSDataType},
+        errors::CollaborateError,
+        protobuf::{RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
+        sync::{DocumentPersistence, RevisionStore, RevisionStorePersistence, ServerDocumentManager},
+    };
+    use lib_infra::future::BoxResultFuture;
+    use lib_ot::{core::DeltaBuilder, rich_text::RichTextDelta};
+    use lib_ws::WebSocketRawMessage;
+    use parking_lot::Mutex;
+    use sqlx::PgPool;
+    use std::{
+        collections::HashMap,
+        convert::{TryFrom, TryInto},
+        sync::Arc,
+        time::Duration,
+    };
+    use tokio::sync::mpsc;
+
+    // Stands for Redis, the leases don't expire.
+    #[derive(Default)]
+    struct MemoryTransport {
+        leases: Mutex<HashMap<String, String>>,
+        channels: Mutex<HashMap<String, mpsc::UnboundedSender<String>>>,
+    }
+
+    #[async_trait]
+    impl ClusterTransport for MemoryTransport {
+        async fn acquire(&self, key: &str, instance_id: &str, _duration: Duration) -> Result<String> {
+            let mut leases = self.leases.lock();
+            Ok(leases
+                .entry(key.to_owned())
+                .or_insert_with(|| instance_id.to_owned())
+                .clone())
+        }
+
+        async fn release(&self, key: &str, instance_id: &str) -> Result<()> {
+            let mut leases = self.leases.lock();
+            if leases.get(key).map(|owner| owner == instance_id).unwrap_or(false) {
+                leases.remove(key);
+            }
+            Ok(())
+        }
+
+        async fn publish(&self, channel: &str, payload: String) -> Result<()> {
+            if let Some(sender) = self.channels.lock().get(channel) {
+                let _ = sender.send(payload);
+            }
+            Ok(())
+        }
+
+        async fn subscribe(&self, channel: &str) -> Result<mpsc::UnboundedReceiver<String>> {
+            let (sender, receiver) = mpsc::unbounded_channel();
+            self.channels.lock().insert(channel.to_owned(), sender);
+            Ok(receiver)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct MemoryRevisionStore(Mutex<Vec<RevisionPB>>);
+
+    impl RevisionStore for MemoryRevisionStore {
+        fn set_revisions(&self, revisions: Vec<RevisionPB>) -> BoxResultFuture<(), CollaborateError> {
+            self.0.lock().extend(revisions);
+            Box::pin(async { Ok(()) })
+        }
+
+        fn get_revisions(
+            &self,
+            doc_id: &str,
+            rev_ids: Option<Vec<i64>>,
+        ) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError> {
+            let revisions = self
+                .0
+                .lock()
+                .iter()
+                .filter(|revision| revision.doc_id == doc_id)
+                .filter(|revision| {
+                    rev_ids
+                        .as_ref()
+                        .map_or(true, |rev_ids| rev_ids.contains(&revision.rev_id))
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            Box::pin(async move { Ok(revisions) })
+        }
+
+        fn delete_revisions(&self, _doc_id: &str, _rev_ids: Option<Vec<i64>>) -> BoxResultFuture<(), CollaborateError> {
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    struct TestSocket(mpsc::UnboundedSender<WebSocketMessage>);
+
+    impl Actor for TestSocket {
+        type Context = Context<Self>;
+    }
+
+    impl Handler<WebSocketMessage> for TestSocket {
+        type Result = ();
+
+        fn handle(&mut self, msg: WebSocketMessage, _ctx: &mut Self::Context) { let _ = self.0.send(msg); }
+    }
+
+    fn revision(base_rev_id: i64, rev_id: i64, delta: RichTextDelta) -> RevisionPB {
+        let mut revision = RevisionPB::new();
+        revision.set_doc_id("doc".to_owned());
+        revision.set_base_rev_id(base_rev_id);
+        revision.set_rev_id(rev_id);
+        revision.set_delta_data(delta.to_bytes().to_vec());
+        revision
+    }
+
+    // The servers share the database, the revisions are never saved here since
+    // the ping doesn't change the document.
+    fn make_cluster(
+        transport: Arc<MemoryTransport>,
+        persistence: Arc<dyn DocumentPersistence>,
+    ) -> Arc<DocumentCluster> {
+        let pg_pool = PgPool::connect_lazy("postgres://localhost/flowy").unwrap();
+        let kv_store = Arc::new(DocumentKVPersistence::new(Arc::new(PostgresKV { pg_pool })));
+        let doc_manager = Arc::new(ServerDocumentManager::new(persistence));
+        let cluster = Arc::new(DocumentCluster::new(transport, doc_manager, kv_store));
+        cluster.start();
+        cluster
+    }
+
+    #[actix_rt::test]
+    async fn cluster_relays_to_the_owner_and_replies_to_the_socket() {
+        let store = Arc::new(MemoryRevisionStore::default());
+        let mut repeated_revision = RepeatedRevisionPB::new();
+        repeated_revision.set_items(
+            vec![
+                revision(0, 1, DeltaBuilder::new().insert("a").build()),
+                revision(1, 2, DeltaBuilder::new().retain(1).insert("b").build()),
+            ]
+            .into(),
+        );
+        let persistence: Arc<dyn DocumentPersistence> = Arc::new(RevisionStorePersistence::new(store));
+        let _ = persistence.create_doc("doc", repeated_revision).await.unwrap();
+
+        let transport = Arc::new(MemoryTransport::default());
+        let node_a = make_cluster(transport.clone(), persistence.clone());
+        let node_b = make_cluster(transport, persistence);
+        // Let the nodes subscribe to their channels.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(matches!(node_b.owner_of("doc").await.unwrap(), DocumentOwner::Local));
+        let owner = match node_a.owner_of("doc").await.unwrap() {
+            DocumentOwner::Remote(owner) => owner,
+            DocumentOwner::Local => panic!("The document is owned by the other node"),
+        };
+        assert_eq!(owner, node_b.instance_id);
+
+        // The client of node A is one revision behind, the owner pushes the
+        // missing one to the socket of node A.
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let socket = TestSocket(sender).start().recipient();
+        let user = WSUser::new(LoggedUser::new("user", "session"));
+        let data: Bytes = DocumentClientWSData::ping("doc", 1).try_into().unwrap();
+        let _ = node_a.relay(&owner, &user, socket, data).await.unwrap();
+
+        let msg = tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let raw = WebSocketRawMessage::try_from(msg.0).unwrap();
+        let server_data = DocumentServerWSData::try_from(Bytes::from(raw.data)).unwrap();
+        assert_eq!(server_data.doc_id, "doc");
+        assert_eq!(server_data.ty, DocumentServerWSDataType::ServerPushRev);
+        assert!(node_a.owned.get("doc").is_none());
+    }
+}
@@ -1,6 +1,8 @@
 #![allow(clippy::module_inception)]
 
+pub mod cluster;
 pub mod persistence;
+pub(crate) mod rate_limit;
 pub(crate) mod router;
 pub(crate) mod ws_actor;
 pub(crate) mod ws_receiver;
@@ -2,7 +2,6 @@ use crate::{
     services::kv::{KVStore, KeyValue},
     util::serde_ext::parse_from_bytes,
 };
-use anyhow::Context;
 use backend_service::errors::{internal_error, ServerError};
 use bytes::Bytes;
 use flowy_collaboration::{
@@ -36,7 +35,6 @@ pub async fn read_document(
     kv_store: &Arc<DocumentKVPersistence>,
     params: DocumentId,
 ) -> Result<DocumentInfo, ServerError> {
-    let _ = Uuid::parse_str(&params.doc_id).context("Parse document id to uuid failed")?;
     let revisions = kv_store.batch_get_revisions(&params.doc_id, None).await?;
     make_doc_from_revisions(&params.doc_id, revisions)
 }
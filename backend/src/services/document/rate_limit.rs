@@ -0,0 +1,198 @@
+use crate::config::{CONNECTION_REVISIONS_PER_SECOND, MAX_REVISION_PAYLOAD_SIZE, USER_REVISIONS_PER_SECOND};
+use dashmap::DashMap;
+use flowy_collaboration::entities::ws::{BackpressureReason, DocumentBackpressure};
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+
+// The buckets that weren't used for this long are full again, so they are
+// dropped instead of kept for the connections that are gone.
+const IDLE_BUCKET_TIMEOUT: Duration = Duration::from_secs(60);
+
+// The client can't make a revision smaller by sending it again, so it stops
+// syncing the document. The older clients resend it after this long, which
+// doesn't flood the server with it.
+const PAYLOAD_TOO_LARGE_RETRY: Duration = Duration::from_secs(30);
+
+pub(crate) struct RevisionRateLimiter {
+    connections: DashMap<String, TokenBucket>,
+    users: DashMap<String, TokenBucket>,
+    last_prune: Mutex<Instant>,
+}
+
+impl RevisionRateLimiter {
+    pub(crate) fn new() -> Self {
+        Self {
+            connections: DashMap::new(),
+            users: DashMap::new(),
+            last_prune: Mutex::new(Instant::now()),
+        }
+    }
+
+    // Takes the revisions from the buckets of the connection and the user. If
+    // either bucket doesn't have enough of them, nothing is taken and the
+    // client is told how long to wait.
+    pub(crate) fn check(
+        &self,
+        connection_id: &str,
+        user_id: &str,
+        revision_count: usize,
+        payload_size: usize,
+    ) -> Result<(), DocumentBackpressure> {
+        if payload_size > MAX_REVISION_PAYLOAD_SIZE {
+            return Err(DocumentBackpressure {
+                reason: BackpressureReason::PayloadTooLarge,
+                retry_after_millis: PAYLOAD_TOO_LARGE_RETRY.as_millis() as i64,
+                limit: MAX_REVISION_PAYLOAD_SIZE as i64,
+            });
+        }
+
+        self.prune_idle_buckets();
+        let now = Instant::now();
+        let count = revision_count as f64;
+        let mut connection = self
+            .connections
+            .entry(connection_id.to_owned())
+            .or_insert_with(|| TokenBucket::new(CONNECTION_REVISIONS_PER_SECOND, now));
+        let mut user = self
+            .users
+            .entry(user_id.to_owned())
+            .or_insert_with(|| TokenBucket::new(USER_REVISIONS_PER_SECOND, now));
+        connection.refill(now);
+        user.refill(now);
+
+        let connection_wait = connection.wait_for(count);
+        let user_wait = user.wait_for(count);
+        if connection_wait > Duration::from_secs(0) || user_wait > Duration::from_secs(0) {
+            let (wait, limit) = match connection_wait >= user_wait {
+                true => (connection_wait, CONNECTION_REVISIONS_PER_SECOND),
+                false => (user_wait, USER_REVISIONS_PER_SECOND),
+            };
+            return Err(DocumentBackpressure {
+                reason: BackpressureReason::TooManyRevisions,
+                retry_after_millis: wait.as_millis().max(1) as i64,
+                limit: limit as i64,
+            });
+        }
+
+        connection.take(count);
+        user.take(count);
+        Ok(())
+    }
+
+    fn prune_idle_buckets(&self) {
+        let mut last_prune = self.last_prune.lock();
+        if last_prune.elapsed() < IDLE_BUCKET_TIMEOUT {
+            return;
+        }
+        *last_prune = Instant::now();
+        self.connections
+            .retain(|_, bucket| bucket.updated_at.elapsed() < IDLE_BUCKET_TIMEOUT);
+        self.users
+            .retain(|_, bucket| bucket.updated_at.elapsed() < IDLE_BUCKET_TIMEOUT);
+    }
+}
+
+struct TokenBucket {
+    tokens: f64,
+    rate: f64,
+    capacity: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(per_second: u32, now: Instant) -> Self {
+        let rate = per_second as f64;
+        Self {
+            tokens: rate * 2.0,
+            rate,
+            capacity: rate * 2.0,
+            updated_at: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated_at = now;
+    }
+
+    // A message with more revisions than the bucket holds waits until the
+    // bucket is full, or it would never be accepted.
+    fn wait_for(&self, count: f64) -> Duration {
+        let needed = count.min(self.capacity) - self.tokens;
+        if needed <= 0.0 {
+            return Duration::from_secs(0);
+        }
+        Duration::from_secs_f64(needed / self.rate)
+    }
+
+    fn take(&mut self, count: f64) { self.tokens = (self.tokens - count).max(0.0); }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::{CONNECTION_REVISIONS_PER_SECOND, MAX_REVISION_PAYLOAD_SIZE},
+        services::document::rate_limit::{RevisionRateLimiter, TokenBucket},
+    };
+    use flowy_collaboration::entities::ws::BackpressureReason;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn token_bucket_bursts_to_twice_the_rate() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(10, now);
+        assert_eq!(bucket.wait_for(20.0), Duration::from_secs(0));
+        bucket.take(20.0);
+        assert_eq!(bucket.wait_for(5.0), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn token_bucket_refills_up_to_the_capacity() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(10, now);
+        bucket.take(20.0);
+        bucket.refill(now + Duration::from_secs(1));
+        assert_eq!(bucket.wait_for(10.0), Duration::from_secs(0));
+        assert_eq!(bucket.wait_for(11.0), Duration::from_millis(100));
+
+        bucket.refill(now + Duration::from_secs(60));
+        assert_eq!(bucket.wait_for(20.0), Duration::from_secs(0));
+        assert_eq!(bucket.wait_for(21.0), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn token_bucket_waits_until_full_for_large_message() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(10, now);
+        bucket.take(20.0);
+        assert_eq!(bucket.wait_for(100.0), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn rate_limiter_rejects_too_large_payload() {
+        let limiter = RevisionRateLimiter::new();
+        let backpressure = limiter
+            .check("connection", "user", 1, MAX_REVISION_PAYLOAD_SIZE + 1)
+            .unwrap_err();
+        assert_eq!(backpressure.reason, BackpressureReason::PayloadTooLarge);
+        assert_eq!(backpressure.limit, MAX_REVISION_PAYLOAD_SIZE as i64);
+        assert!(limiter
+            .check("connection", "user", 1, MAX_REVISION_PAYLOAD_SIZE)
+            .is_ok());
+    }
+
+    #[test]
+    fn rate_limiter_takes_nothing_when_rejected() {
+        let limiter = RevisionRateLimiter::new();
+        let burst = CONNECTION_REVISIONS_PER_SECOND as usize * 2;
+        assert!(limiter.check("connection", "user", burst, 0).is_ok());
+        let backpressure = limiter.check("connection", "user", 1, 0).unwrap_err();
+        assert_eq!(backpressure.reason, BackpressureReason::TooManyRevisions);
+        assert_eq!(backpressure.limit, CONNECTION_REVISIONS_PER_SECOND as i64);
+        assert!(backpressure.retry_after_millis > 0);
+
+        // The user still has the revisions that the other connection can take.
+        assert!(limiter.check("other connection", "user", 1, 0).is_ok());
+    }
+}
@@ -1,12 +1,17 @@
 use crate::{
     context::FlowyPersistence,
-    services::document::persistence::{create_document, read_document, reset_document},
+    entities::logged_user::LoggedUser,
+    services::{
+        core::view::check_document_accessible,
+        document::persistence::{create_document, read_document, reset_document},
+    },
     util::serde_ext::parse_from_payload,
 };
 use actix_web::{
     web::{Data, Payload},
     HttpResponse,
 };
+use anyhow::Context;
 use backend_service::{errors::ServerError, response::FlowyResponse};
 use flowy_collaboration::{
     protobuf::{
@@ -28,12 +33,24 @@ pub async fn create_document_handler(
     Ok(FlowyResponse::success().into())
 }
 
-#[tracing::instrument(level = "debug", skip(payload, persistence), err)]
+#[tracing::instrument(level = "debug", skip(payload, persistence, logged_user), err)]
 pub async fn read_document_handler(
     payload: Payload,
     persistence: Data<Arc<FlowyPersistence>>,
+    logged_user: LoggedUser,
 ) -> Result<HttpResponse, ServerError> {
     let params: DocumentIdPB = parse_from_payload(payload).await?;
+    let mut transaction = persistence
+        .pg_pool()
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read document")?;
+    let _ = check_document_accessible(&mut transaction, params.get_doc_id(), &logged_user.user_id).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read document.")?;
+
     let kv_store = persistence.kv_store();
     let doc = read_document(&kv_store, params).await?;
     let response = FlowyResponse::success().pb(doc)?;
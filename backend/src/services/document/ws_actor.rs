@@ -1,13 +1,22 @@
 use crate::{
     context::FlowyPersistence,
-    services::web_socket::{entities::Socket, WSClientData, WSUser, WebSocketMessage},
+    services::{
+        core::view::{check_document_accessible, check_document_editable},
+        document::{
+            cluster::{DocumentCluster, DocumentOwner},
+            rate_limit::RevisionRateLimiter,
+        },
+        web_socket::{entities::Socket, WSClientData, WSUser, WebSocketMessage},
+    },
     util::serde_ext::{md5, parse_from_bytes},
 };
 use actix_rt::task::spawn_blocking;
+use anyhow::Context;
 use async_stream::stream;
 use backend_service::errors::{internal_error, Result, ServerError};
 
 use flowy_collaboration::{
+    entities::ws::DocumentServerWSDataBuilder,
     protobuf::{
         DocumentClientWSData as DocumentClientWSDataPB,
         DocumentClientWSDataType as DocumentClientWSDataTypePB,
@@ -30,13 +39,21 @@ pub enum WSActorMessage {
 pub struct DocumentWebSocketActor {
     receiver: Option<mpsc::Receiver<WSActorMessage>>,
     doc_manager: Arc<ServerDocumentManager>,
+    rate_limiter: RevisionRateLimiter,
+    cluster: Option<Arc<DocumentCluster>>,
 }
 
 impl DocumentWebSocketActor {
-    pub fn new(receiver: mpsc::Receiver<WSActorMessage>, manager: Arc<ServerDocumentManager>) -> Self {
+    pub fn new(
+        receiver: mpsc::Receiver<WSActorMessage>,
+        manager: Arc<ServerDocumentManager>,
+        cluster: Option<Arc<DocumentCluster>>,
+    ) -> Self {
         Self {
             receiver: Some(receiver),
             doc_manager: manager,
+            rate_limiter: RevisionRateLimiter::new(),
+            cluster,
         }
     }
 
@@ -72,6 +89,8 @@ impl DocumentWebSocketActor {
 
     async fn handle_client_data(&self, client_data: WSClientData, persistence: Arc<FlowyPersistence>) -> Result<()> {
         let WSClientData { user, socket, data } = client_data;
+        let payload_size = data.len();
+        let raw_data = data.clone();
         let document_client_data = spawn_blocking(move || parse_from_bytes::<DocumentClientWSDataPB>(&data))
             .await
             .map_err(internal_error)??;
@@ -83,6 +102,52 @@ impl DocumentWebSocketActor {
             document_client_data.ty
         );
 
+        if let Some(scope) = user.guest_scope() {
+            if scope.view_id != document_client_data.doc_id {
+                return Err(ServerError::permission_denied().context("The guest can't sync this document"));
+            }
+
+            if document_client_data.ty == DocumentClientWSDataTypePB::ClientPushRev && !scope.editable {
+                return Err(ServerError::permission_denied().context("The guest can only read this document"));
+            }
+        }
+
+        // The guests were checked against the scope of their token, the members
+        // against the access list of the view or the owner of the document. The
+        // pushed revisions need the role that can edit the view as well.
+        if user.guest_scope().is_none() {
+            let editing = document_client_data.ty == DocumentClientWSDataTypePB::ClientPushRev;
+            let _ = check_document_access(&persistence, &user, &document_client_data.doc_id, editing).await?;
+        }
+
+        // The revisions over the limits are dropped, the client resends them
+        // after the backpressure passed since they weren't acked. A revision
+        // that is too large makes the client stop syncing the document.
+        if document_client_data.ty == DocumentClientWSDataTypePB::ClientPushRev {
+            let revision_count = document_client_data.get_revisions().get_items().len();
+            let result = self
+                .rate_limiter
+                .check(user.connection_id(), user.id(), revision_count, payload_size);
+            if let Err(backpressure) = result {
+                tracing::warn!(
+                    "[DocumentWebSocketActor]: {} exceeds the limit of {:?}",
+                    user.id(),
+                    backpressure
+                );
+                let data =
+                    DocumentServerWSDataBuilder::build_backpressure_message(&document_client_data.doc_id, backpressure);
+                let msg: WebSocketMessage = data.into();
+                let _ = socket.try_send(msg).map_err(internal_error)?;
+                return Ok(());
+            }
+        }
+
+        if let Some(cluster) = &self.cluster {
+            if let DocumentOwner::Remote(owner) = cluster.owner_of(&document_client_data.doc_id).await? {
+                return cluster.relay(&owner, &user, socket, raw_data).await;
+            }
+        }
+
         let user = Arc::new(ServerDocUser {
             user,
             socket,
@@ -110,6 +175,28 @@ impl DocumentWebSocketActor {
     }
 }
 
+async fn check_document_access(
+    persistence: &FlowyPersistence,
+    user: &WSUser,
+    doc_id: &str,
+    editing: bool,
+) -> Result<()> {
+    let mut transaction = persistence
+        .pg_pool()
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to check the document access")?;
+    let _ = match editing {
+        true => check_document_editable(&mut transaction, doc_id, user.id()).await,
+        false => check_document_accessible(&mut transaction, doc_id, user.id()).await,
+    }?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to check the document access.")?;
+    Ok(())
+}
+
 #[allow(dead_code)]
 fn verify_md5(revision: &RevisionPB) -> Result<()> {
     if md5(&revision.delta_data) != revision.md5 {
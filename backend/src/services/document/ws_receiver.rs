@@ -2,6 +2,7 @@ use crate::{
     context::FlowyPersistence,
     services::{
         document::{
+            cluster::DocumentCluster,
             persistence::{create_document, read_document, revisions_to_key_value_items},
             ws_actor::{DocumentWebSocketActor, WSActorMessage},
         },
@@ -32,9 +33,10 @@ use tokio::sync::{mpsc, oneshot};
 pub fn make_document_ws_receiver(
     persistence: Arc<FlowyPersistence>,
     document_manager: Arc<ServerDocumentManager>,
+    cluster: Option<Arc<DocumentCluster>>,
 ) -> Arc<DocumentWebSocketReceiver> {
     let (ws_sender, rx) = tokio::sync::mpsc::channel(100);
-    let actor = DocumentWebSocketActor::new(rx, document_manager);
+    let actor = DocumentWebSocketActor::new(rx, document_manager, cluster);
     tokio::task::spawn(actor.run());
 
     Arc::new(DocumentWebSocketReceiver::new(persistence, ws_sender))
@@ -4,6 +4,24 @@ use crate::{
         token::Token,
         user::UserTable,
     },
+    services::{
+        core::{
+            app::persistence::APP_TABLE,
+            trash::persistence::TRASH_TABLE,
+            view::{delete_view, SHARE_LINK_TABLE, VIEW_ACL_TABLE, VIEW_GUEST_TABLE},
+            workspace::persistence::WORKSPACE_TABLE,
+        },
+        document::persistence::DocumentKVPersistence,
+        kv::PostgresKV,
+        user::session::{
+            create_session,
+            delete_session,
+            make_user_device_pb,
+            read_sessions,
+            touch_session,
+            USER_SESSION_TABLE,
+        },
+    },
     util::{
         sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
         user_ext::{hash_password, verify_password},
@@ -14,19 +32,29 @@ use backend_service::{
     errors::{invalid_params, ErrorCode, ServerError},
     response::FlowyResponse,
 };
+use bytes::Bytes;
 use chrono::Utc;
 use flowy_user_data_model::{
-    parser::{UserEmail, UserName, UserPassword},
+    entities::MAX_AVATAR_SIZE,
+    parser::{UserEmail, UserId, UserName, UserPassword},
     protobuf::{
+        RefreshTokenParams as RefreshTokenParamsPB,
+        RefreshTokenResponse as RefreshTokenResponsePB,
+        RepeatedUserDevice as RepeatedUserDevicePB,
+        RevokeDeviceParams as RevokeDeviceParamsPB,
         SignInParams as SignInParamsPB,
         SignInResponse as SignInResponsePB,
         SignUpParams as SignUpParamsPB,
         SignUpResponse as SignUpResponsePB,
         UpdateUserParams as UpdateUserParamsPB,
+        UploadAvatarParams as UploadAvatarParamsPB,
+        UserAvatar as UserAvatarPB,
         UserProfile as UserProfilePB,
     },
 };
 use sqlx::{PgPool, Postgres};
+use std::sync::Arc;
+use uuid::Uuid;
 
 pub async fn sign_in(pool: &PgPool, params: SignInParamsPB) -> Result<SignInResponsePB, ServerError> {
     let email = UserEmail::parse(params.email).map_err(|e| ServerError::params_invalid().context(e))?;
@@ -38,13 +66,17 @@ pub async fn sign_in(pool: &PgPool, params: SignInParamsPB) -> Result<SignInResp
         .context("Failed to acquire a Postgres connection to sign in")?;
 
     let user = check_user_password(&mut transaction, email.as_ref(), password.as_ref()).await?;
+    let session_id = create_session(&mut transaction, &user.id, &params.device_name, &params.platform)
+        .await?
+        .to_string();
     transaction
         .commit()
         .await
         .context("Failed to commit SQL transaction to sign in.")?;
 
-    let token = Token::create_token(&user.id.to_string())?;
-    let logged_user = LoggedUser::new(&user.id.to_string());
+    let token = Token::create_token(&user.id.to_string(), &session_id)?;
+    let refresh_token = Token::create_refresh_token(&user.id.to_string(), &session_id)?;
+    let logged_user = LoggedUser::new(&user.id.to_string(), &session_id);
 
     AUTHORIZED_USERS.store_auth(logged_user, true);
     let mut response_data = SignInResponsePB::default();
@@ -52,15 +84,115 @@ pub async fn sign_in(pool: &PgPool, params: SignInParamsPB) -> Result<SignInResp
     response_data.set_name(user.name);
     response_data.set_email(user.email);
     response_data.set_token(token.into());
+    response_data.set_refresh_token(refresh_token.into());
+
+    Ok(response_data)
+}
+
+pub async fn refresh_token(pool: &PgPool, params: RefreshTokenParamsPB) -> Result<RefreshTokenResponsePB, ServerError> {
+    let claim = Token::decode_refresh_token(&Token(params.refresh_token))?;
+    let mut logged_user = LoggedUser::from(claim);
+    let id = logged_user.as_uuid()?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to refresh token")?;
 
+    let _ = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE id = $1")
+        .bind(id)
+        .fetch_one(&mut transaction)
+        .await
+        .map_err(|err| ServerError::unauthorized().context(err))?;
+
+    if logged_user.session_id.is_empty() {
+        // The token was issued before the sessions were tracked.
+        let session_id = create_session(&mut transaction, &id, "", "unknown").await?;
+        logged_user.session_id = session_id.to_string();
+    } else if !touch_session(&mut transaction, &id, &logged_user.session_id).await? {
+        return Err(ServerError::unauthorized().context("The session was revoked"));
+    }
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to refresh token.")?;
+
+    let user_id = id.to_string();
+    let token = Token::create_token(&user_id, &logged_user.session_id)?;
+    let refresh_token = Token::create_refresh_token(&user_id, &logged_user.session_id)?;
+    AUTHORIZED_USERS.store_auth(logged_user, true);
+
+    let mut response_data = RefreshTokenResponsePB::default();
+    response_data.set_token(token.into());
+    response_data.set_refresh_token(refresh_token.into());
     Ok(response_data)
 }
 
-pub async fn sign_out(logged_user: LoggedUser) -> Result<FlowyResponse, ServerError> {
+pub async fn sign_out(pool: &PgPool, logged_user: LoggedUser) -> Result<FlowyResponse, ServerError> {
+    if let Ok(session_id) = Uuid::parse_str(&logged_user.session_id) {
+        let mut transaction = pool
+            .begin()
+            .await
+            .context("Failed to acquire a Postgres connection to sign out")?;
+        let _ = delete_session(&mut transaction, &logged_user.as_uuid()?, &session_id).await?;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit SQL transaction to sign out.")?;
+    }
+
     AUTHORIZED_USERS.store_auth(logged_user, false);
     Ok(FlowyResponse::success())
 }
 
+pub(crate) async fn read_user_devices(pool: &PgPool, logged_user: LoggedUser) -> Result<FlowyResponse, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read user devices")?;
+
+    let sessions = read_sessions(&mut transaction, &logged_user.as_uuid()?).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read user devices.")?;
+
+    let items = sessions
+        .into_iter()
+        .map(|table| make_user_device_pb(table, &logged_user.session_id))
+        .collect::<Vec<_>>();
+    let mut repeated_device = RepeatedUserDevicePB::default();
+    repeated_device.set_items(items.into());
+    FlowyResponse::success().pb(repeated_device)
+}
+
+// Revoking a session removes it, so its refresh token is rejected, and marks it
+// unauthorized, so its token is rejected and its websocket is closed.
+pub(crate) async fn revoke_user_device(
+    pool: &PgPool,
+    logged_user: LoggedUser,
+    params: RevokeDeviceParamsPB,
+) -> Result<FlowyResponse, ServerError> {
+    let session_id = Uuid::parse_str(&params.session_id).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to revoke user device")?;
+
+    if !delete_session(&mut transaction, &logged_user.as_uuid()?, &session_id).await? {
+        return Err(ServerError::record_not_found().context(format!("Session {} not exist", session_id)));
+    }
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to revoke user device.")?;
+
+    AUTHORIZED_USERS.store_auth(LoggedUser::new(&logged_user.user_id, &params.session_id), false);
+    Ok(FlowyResponse::success())
+}
+
 pub async fn register_user(pool: &PgPool, params: SignUpParamsPB) -> Result<FlowyResponse, ServerError> {
     let name = UserName::parse(params.name).map_err(|e| ServerError::params_invalid().context(e))?;
     let email = UserEmail::parse(params.email).map_err(|e| ServerError::params_invalid().context(e))?;
@@ -72,11 +204,22 @@ pub async fn register_user(pool: &PgPool, params: SignUpParamsPB) -> Result<Flow
         .context("Failed to acquire a Postgres connection to register user")?;
 
     let _ = is_email_exist(&mut transaction, email.as_ref()).await?;
-    let response_data = insert_new_user(&mut transaction, name.as_ref(), email.as_ref(), password.as_ref())
+    let user_id = insert_new_user(&mut transaction, name.as_ref(), email.as_ref(), password.as_ref())
         .await
         .context("Failed to insert user")?;
-
-    let logged_user = LoggedUser::new(&response_data.user_id);
+    let session_id = create_session(&mut transaction, &user_id, &params.device_name, &params.platform)
+        .await?
+        .to_string();
+
+    let user_id = user_id.to_string();
+    let mut response_data = SignUpResponsePB::default();
+    response_data.set_user_id(user_id.clone());
+    response_data.set_name(name.as_ref().to_owned());
+    response_data.set_email(email.as_ref().to_owned());
+    response_data.set_token(Token::create_token(&user_id, &session_id)?.into());
+    response_data.set_refresh_token(Token::create_refresh_token(&user_id, &session_id)?.into());
+
+    let logged_user = LoggedUser::new(&user_id, &session_id);
     AUTHORIZED_USERS.store_auth(logged_user, true);
 
     transaction
@@ -87,6 +230,118 @@ pub async fn register_user(pool: &PgPool, params: SignUpParamsPB) -> Result<Flow
     FlowyResponse::success().pb(response_data)
 }
 
+// Deletes the user and everything the user owns: workspaces, apps, views with
+// their documents, trash and the avatar.
+pub(crate) async fn delete_user(
+    pool: &PgPool,
+    kv_store: &Arc<DocumentKVPersistence>,
+    logged_user: LoggedUser,
+) -> Result<FlowyResponse, ServerError> {
+    let id = logged_user.as_uuid()?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to delete user")?;
+
+    let view_ids = sqlx::query_as::<Postgres, (Uuid,)>(
+        "SELECT id FROM view_table WHERE belong_to_id IN (SELECT id::text FROM app_table WHERE user_id = $1)",
+    )
+    .bind(&logged_user.user_id)
+    .fetch_all(&mut transaction)
+    .await
+    .map_err(map_sqlx_error)?
+    .into_iter()
+    .map(|(view_id,)| view_id)
+    .collect::<Vec<Uuid>>();
+    let _ = delete_view(&mut transaction, kv_store, view_ids).await?;
+
+    for table in &[USER_SESSION_TABLE, SHARE_LINK_TABLE, VIEW_ACL_TABLE, VIEW_GUEST_TABLE] {
+        let (sql, args) = SqlBuilder::delete(table).and_where_eq("user_id", &id).build()?;
+        let _ = sqlx::query_with(&sql, args)
+            .execute(&mut transaction)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+
+    // Leaves the workspaces shared with the user, and removes the members of
+    // the workspaces the user owns.
+    let _ = sqlx::query(
+        "DELETE FROM workspace_member_table WHERE user_id = $1 OR workspace_id IN (SELECT id FROM workspace_table \
+         WHERE user_id = $2)",
+    )
+    .bind(&id)
+    .bind(&logged_user.user_id)
+    .execute(&mut transaction)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    // Removes the invitations sent by the user along with the ones sent to the
+    // email of the user.
+    let _ = sqlx::query(
+        "DELETE FROM workspace_invitation_table WHERE inviter_id = $1 OR email = (SELECT email FROM user_table WHERE \
+         id = $1)",
+    )
+    .bind(&id)
+    .execute(&mut transaction)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    for table in &[APP_TABLE, WORKSPACE_TABLE, TRASH_TABLE] {
+        let (sql, args) = SqlBuilder::delete(table)
+            .and_where_eq("user_id", &logged_user.user_id)
+            .build()?;
+        let _ = sqlx::query_with(&sql, args)
+            .execute(&mut transaction)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+
+    let (sql, args) = SqlBuilder::delete("user_table").and_where_eq("id", &id).build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to delete user.")?;
+
+    let avatar_store = PostgresKV { pg_pool: pool.clone() };
+    let _ = avatar_store.remove(&avatar_key(&id.to_string())).await?;
+    AUTHORIZED_USERS.revoke_all(&logged_user.user_id);
+    Ok(FlowyResponse::success())
+}
+
+pub(crate) async fn set_user_avatar(
+    kv_store: &PostgresKV,
+    logged_user: LoggedUser,
+    params: UploadAvatarParamsPB,
+) -> Result<FlowyResponse, ServerError> {
+    if params.data.is_empty() || params.data.len() > MAX_AVATAR_SIZE {
+        return Err(ServerError::params_invalid().context("Invalid avatar size"));
+    }
+
+    let key = avatar_key(&logged_user.as_uuid()?.to_string());
+    let _ = kv_store.set(&key, Bytes::from(params.data)).await?;
+    Ok(FlowyResponse::success())
+}
+
+pub(crate) async fn get_user_avatar(kv_store: &PostgresKV, user_id: String) -> Result<FlowyResponse, ServerError> {
+    let user_id = UserId::parse(user_id).map_err(|e| ServerError::params_invalid().context(e))?;
+    match kv_store.get(&avatar_key(user_id.as_ref())).await? {
+        None => Err(ServerError::record_not_found().context(format!("{}'s avatar not exist", user_id.as_ref()))),
+        Some(data) => {
+            let mut avatar = UserAvatarPB::default();
+            avatar.set_user_id(user_id.0);
+            avatar.set_data(data.to_vec());
+            FlowyResponse::success().pb(avatar)
+        },
+    }
+}
+
+fn avatar_key(user_id: &str) -> String { format!("avatar:{}", user_id) }
+
 pub(crate) async fn get_user_profile(
     pool: &PgPool,
     token: Token,
@@ -103,6 +358,7 @@ pub(crate) async fn get_user_profile(
         .fetch_one(&mut transaction)
         .await
         .map_err(|err| ServerError::internal().context(err))?;
+    let _ = touch_session(&mut transaction, &id, &logged_user.session_id).await?;
 
     transaction
         .commit()
@@ -211,9 +467,8 @@ async fn insert_new_user(
     name: &str,
     email: &str,
     password: &str,
-) -> Result<SignUpResponsePB, ServerError> {
+) -> Result<Uuid, ServerError> {
     let uuid = uuid::Uuid::new_v4();
-    let token = Token::create_token(&uuid.to_string())?;
     let password = hash_password(password)?;
     let _ = sqlx::query!(
         r#"
@@ -230,11 +485,5 @@ async fn insert_new_user(
     .await
     .map_err(|e| ServerError::internal().context(e))?;
 
-    let mut response = SignUpResponsePB::default();
-    response.set_user_id(uuid.to_string());
-    response.set_name(name.to_string());
-    response.set_email(email.to_string());
-    response.set_token(token.into());
-
-    Ok(response)
+    Ok(uuid)
 }
@@ -2,3 +2,4 @@ pub use controller::*;
 
 mod controller;
 pub mod router;
+mod session;
@@ -1,21 +1,41 @@
 use crate::{
+    context::FlowyPersistence,
     entities::{logged_user::LoggedUser, token::Token},
-    services::user::{get_user_profile, register_user, set_user_profile, sign_in, sign_out},
+    services::{
+        kv::PostgresKV,
+        user::{
+            delete_user,
+            get_user_avatar,
+            get_user_profile,
+            read_user_devices,
+            refresh_token,
+            register_user,
+            revoke_user_device,
+            set_user_avatar,
+            set_user_profile,
+            sign_in,
+            sign_out,
+        },
+    },
     util::serde_ext::parse_from_payload,
 };
 use actix_identity::Identity;
 use actix_web::{
-    web::{Data, Payload},
+    web::{Data, Path, Payload},
     HttpRequest,
     HttpResponse,
 };
 use backend_service::{errors::ServerError, response::FlowyResponse};
 use flowy_user_data_model::protobuf::{
+    RefreshTokenParams as RefreshTokenParamsPB,
+    RevokeDeviceParams as RevokeDeviceParamsPB,
     SignInParams as SignInParamsPB,
     SignUpParams as SignUpParamsPB,
     UpdateUserParams as UpdateUserParamsPB,
+    UploadAvatarParams as UploadAvatarParamsPB,
 };
 use sqlx::PgPool;
+use std::sync::Arc;
 
 pub async fn sign_in_handler(payload: Payload, id: Identity, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
     let params: SignInParamsPB = parse_from_payload(payload).await?;
@@ -25,10 +45,39 @@ pub async fn sign_in_handler(payload: Payload, id: Identity, pool: Data<PgPool>)
     Ok(response.into())
 }
 
-pub async fn sign_out_handler(logged_user: LoggedUser, id: Identity) -> Result<HttpResponse, ServerError> {
+pub async fn refresh_token_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+    let params: RefreshTokenParamsPB = parse_from_payload(payload).await?;
+    let data = refresh_token(pool.get_ref(), params).await?;
+    let response = FlowyResponse::success().pb(data)?;
+    Ok(response.into())
+}
+
+pub async fn sign_out_handler(
+    logged_user: LoggedUser,
+    id: Identity,
+    pool: Data<PgPool>,
+) -> Result<HttpResponse, ServerError> {
     id.forget();
 
-    let response = sign_out(logged_user).await?;
+    let response = sign_out(pool.get_ref(), logged_user).await?;
+    Ok(response.into())
+}
+
+pub async fn read_user_devices_handler(
+    logged_user: LoggedUser,
+    pool: Data<PgPool>,
+) -> Result<HttpResponse, ServerError> {
+    let response = read_user_devices(pool.get_ref(), logged_user).await?;
+    Ok(response.into())
+}
+
+pub async fn revoke_user_device_handler(
+    logged_user: LoggedUser,
+    pool: Data<PgPool>,
+    payload: Payload,
+) -> Result<HttpResponse, ServerError> {
+    let params: RevokeDeviceParamsPB = parse_from_payload(payload).await?;
+    let response = revoke_user_device(pool.get_ref(), logged_user, params).await?;
     Ok(response.into())
 }
 
@@ -65,3 +114,40 @@ pub async fn change_password(
 ) -> Result<HttpResponse, ServerError> {
     unimplemented!()
 }
+
+pub async fn set_user_avatar_handler(
+    logged_user: LoggedUser,
+    persistence: Data<Arc<FlowyPersistence>>,
+    payload: Payload,
+) -> Result<HttpResponse, ServerError> {
+    let params: UploadAvatarParamsPB = parse_from_payload(payload).await?;
+    let kv_store = PostgresKV {
+        pg_pool: persistence.pg_pool(),
+    };
+    let response = set_user_avatar(&kv_store, logged_user, params).await?;
+    Ok(response.into())
+}
+
+// Read with the token like the upload, the avatars aren't public.
+pub async fn get_user_avatar_handler(
+    _logged_user: LoggedUser,
+    user_id: Path<String>,
+    persistence: Data<Arc<FlowyPersistence>>,
+) -> Result<HttpResponse, ServerError> {
+    let kv_store = PostgresKV {
+        pg_pool: persistence.pg_pool(),
+    };
+    let response = get_user_avatar(&kv_store, user_id.into_inner()).await?;
+    Ok(response.into())
+}
+
+pub async fn delete_user_handler(
+    logged_user: LoggedUser,
+    persistence: Data<Arc<FlowyPersistence>>,
+    id: Identity,
+) -> Result<HttpResponse, ServerError> {
+    let kv_store = persistence.kv_store();
+    let response = delete_user(&persistence.pg_pool(), &kv_store, logged_user).await?;
+    id.forget();
+    Ok(response.into())
+}
@@ -0,0 +1,111 @@
+use crate::{
+    entities::user::UserSessionTable,
+    util::sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::ServerError;
+use chrono::Utc;
+use flowy_user_data_model::protobuf::UserDevice as UserDevicePB;
+use sqlx::{postgres::PgArguments, Postgres};
+use uuid::Uuid;
+
+pub(crate) const USER_SESSION_TABLE: &str = "user_session_table";
+
+pub(crate) async fn create_session(
+    transaction: &mut DBTransaction<'_>,
+    user_id: &Uuid,
+    device_name: &str,
+    platform: &str,
+) -> Result<Uuid, ServerError> {
+    // The old clients don't send the device name, so fall back to the platform.
+    let device_name = match device_name.trim() {
+        "" => platform.trim(),
+        name => name,
+    };
+    let session_id = Uuid::new_v4();
+    let time = Utc::now();
+    let (sql, args) = SqlBuilder::create(USER_SESSION_TABLE)
+        .add_field_with_arg("id", session_id)
+        .add_field_with_arg("user_id", user_id)
+        .add_field_with_arg("device_name", device_name)
+        .add_field_with_arg("platform", platform)
+        .add_field_with_arg("create_time", time)
+        .add_field_with_arg("last_seen", time)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(session_id)
+}
+
+// Returns false if the session doesn't exist anymore, i.e. it was signed out or
+// revoked.
+pub(crate) async fn touch_session(
+    transaction: &mut DBTransaction<'_>,
+    user_id: &Uuid,
+    session_id: &str,
+) -> Result<bool, ServerError> {
+    let session_id = match Uuid::parse_str(session_id) {
+        Ok(session_id) => session_id,
+        Err(_) => return Ok(false),
+    };
+
+    let (sql, args) = SqlBuilder::update(USER_SESSION_TABLE)
+        .add_field_with_arg("last_seen", Utc::now())
+        .and_where_eq("id", session_id)
+        .and_where_eq("user_id", user_id)
+        .build()?;
+
+    let result = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub(crate) async fn read_sessions(
+    transaction: &mut DBTransaction<'_>,
+    user_id: &Uuid,
+) -> Result<Vec<UserSessionTable>, ServerError> {
+    let (sql, args) = SqlBuilder::select(USER_SESSION_TABLE)
+        .add_field("*")
+        .and_where_eq("user_id", user_id)
+        .build()?;
+
+    let mut sessions = sqlx::query_as_with::<Postgres, UserSessionTable, PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    sessions.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+    Ok(sessions)
+}
+
+// Returns false if the session doesn't exist or belongs to another user.
+pub(crate) async fn delete_session(
+    transaction: &mut DBTransaction<'_>,
+    user_id: &Uuid,
+    session_id: &Uuid,
+) -> Result<bool, ServerError> {
+    let (sql, args) = SqlBuilder::delete(USER_SESSION_TABLE)
+        .and_where_eq("id", session_id)
+        .and_where_eq("user_id", user_id)
+        .build()?;
+
+    let result = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub(crate) fn make_user_device_pb(table: UserSessionTable, current_session_id: &str) -> UserDevicePB {
+    let session_id = table.id.to_string();
+    let mut device = UserDevicePB::default();
+    device.set_is_current(session_id == current_session_id);
+    device.set_session_id(session_id);
+    device.set_device_name(table.device_name);
+    device.set_platform(table.platform);
+    device.set_last_seen(table.last_seen.timestamp());
+    device
+}
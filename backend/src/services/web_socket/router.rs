@@ -1,6 +1,9 @@
 use crate::{
-    entities::logged_user::LoggedUser,
-    services::web_socket::{WSClient, WSServer, WSUser, WebSocketReceivers},
+    entities::logged_user::{LoggedGuest, LoggedUser, AUTHORIZED_USERS},
+    services::{
+        core::view::check_guest_access,
+        web_socket::{WSClient, WSServer, WSUser, WebSocketReceivers},
+    },
 };
 use actix::Addr;
 use actix_web::{
@@ -11,6 +14,9 @@ use actix_web::{
     HttpResponse,
 };
 use actix_web_actors::ws;
+use anyhow::Context;
+use backend_service::errors::ServerError;
+use sqlx::PgPool;
 
 #[rustfmt::skip]
 //                   WsClient
@@ -37,11 +43,20 @@ pub async fn establish_ws_connection(
     token: Path<String>,
     server: Data<Addr<WSServer>>,
     ws_receivers: Data<WebSocketReceivers>,
+    pool: Data<PgPool>,
 ) -> Result<HttpResponse, Error> {
     tracing::info!("establish_ws_connection");
-    match LoggedUser::from_token(token.clone()) {
-        Ok(user) => {
-            let ws_user = WSUser::new(user);
+    let ws_user = match LoggedUser::from_token(token.clone()) {
+        Ok(user) if AUTHORIZED_USERS.is_revoked(&user) => return Ok(HttpResponse::Unauthorized().finish()),
+        Ok(user) => Ok(WSUser::new(user)),
+        Err(e) => match LoggedGuest::from_token(token.clone()) {
+            Ok(guest) => read_guest_ws_user(&pool, guest).await,
+            Err(_) => Err(e),
+        },
+    };
+
+    match ws_user {
+        Ok(ws_user) => {
             let client = WSClient::new(ws_user, server.get_ref().clone(), ws_receivers);
             let result = ws::start(client, &request, payload);
             match result {
@@ -61,3 +76,17 @@ pub async fn establish_ws_connection(
         },
     }
 }
+
+async fn read_guest_ws_user(pool: &PgPool, guest: LoggedGuest) -> Result<WSUser, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to check guest access")?;
+    let access = check_guest_access(&mut transaction, &guest).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to check guest access.")?;
+
+    Ok(WSUser::guest(guest, access.is_editable()))
+}
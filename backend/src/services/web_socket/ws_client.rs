@@ -1,11 +1,12 @@
 use crate::{
     config::{HEARTBEAT_INTERVAL, PING_TIMEOUT},
-    entities::logged_user::LoggedUser,
+    entities::logged_user::{LoggedGuest, LoggedUser, AUTHORIZED_USERS},
     services::web_socket::{
         entities::{Connect, Disconnect, Socket},
         WSServer,
         WebSocketMessage,
     },
+    util::user_ext::uuid,
 };
 use actix::*;
 use actix_web::web::Data;
@@ -39,12 +40,47 @@ impl WebSocketReceivers {
 #[derive(Debug)]
 pub struct WSUser {
     inner: LoggedUser,
+    guest_scope: Option<GuestScope>,
+    connection_id: String,
+}
+
+// The connection of a guest can only sync the document of the view that the
+// guest token is scoped to.
+#[derive(Debug)]
+pub struct GuestScope {
+    pub view_id: String,
+    pub editable: bool,
 }
 
 impl WSUser {
-    pub fn new(inner: LoggedUser) -> Self { Self { inner } }
+    pub fn new(inner: LoggedUser) -> Self {
+        Self {
+            inner,
+            guest_scope: None,
+            connection_id: uuid(),
+        }
+    }
+
+    pub fn guest(guest: LoggedGuest, editable: bool) -> Self {
+        Self {
+            inner: LoggedUser::new(&guest.access_id, ""),
+            guest_scope: Some(GuestScope {
+                view_id: guest.view_id,
+                editable,
+            }),
+            connection_id: uuid(),
+        }
+    }
 
     pub fn id(&self) -> &str { &self.inner.user_id }
+
+    pub fn guest_scope(&self) -> Option<&GuestScope> { self.guest_scope.as_ref() }
+
+    // Identifies the websocket connection, the user id is the same for all the
+    // connections of the user.
+    pub fn connection_id(&self) -> &str { &self.connection_id }
+
+    pub fn is_revoked(&self) -> bool { AUTHORIZED_USERS.is_revoked(&self.inner) }
 }
 
 pub struct WSClientData {
@@ -72,7 +108,8 @@ impl WSClient {
 
     fn hb(&self, ctx: &mut ws::WebsocketContext<Self>) {
         ctx.run_interval(HEARTBEAT_INTERVAL, |client, ctx| {
-            if Instant::now().duration_since(client.hb) > PING_TIMEOUT {
+            // Close the connection of the signed out or revoked session.
+            if Instant::now().duration_since(client.hb) > PING_TIMEOUT || client.user.is_revoked() {
                 client.server.do_send(Disconnect {
                     sid: client.user.id().into(),
                 });
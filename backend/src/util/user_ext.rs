@@ -1,7 +1,6 @@
 use backend_service::errors::{ErrorCode, ServerError};
 use bcrypt::{hash, verify, DEFAULT_COST};
 
-#[allow(dead_code)]
 pub fn uuid() -> String { uuid::Uuid::new_v4().to_string() }
 
 pub fn hash_password(plain: &str) -> Result<String, ServerError> {
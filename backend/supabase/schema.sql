@@ -0,0 +1,84 @@
+-- The tables of the Supabase backend of the client, run it in the SQL editor
+-- of the project. Every row belongs to the user who created it, the row level
+-- security hides the rows of the others.
+CREATE TABLE IF NOT EXISTS af_workspace(
+    id TEXT NOT NULL PRIMARY KEY,
+    owner uuid NOT NULL DEFAULT auth.uid() REFERENCES auth.users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    create_time BIGINT NOT NULL,
+    modified_time BIGINT NOT NULL,
+    -- The WorkspaceSettings that the clients share, with the enums as numbers.
+    settings JSONB
+);
+ALTER TABLE af_workspace ADD COLUMN IF NOT EXISTS settings JSONB;
+
+CREATE TABLE IF NOT EXISTS af_app(
+    id TEXT NOT NULL PRIMARY KEY,
+    owner uuid NOT NULL DEFAULT auth.uid() REFERENCES auth.users(id) ON DELETE CASCADE,
+    workspace_id TEXT NOT NULL REFERENCES af_workspace(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    color_style TEXT NOT NULL DEFAULT '',
+    version BIGINT NOT NULL DEFAULT 0,
+    create_time BIGINT NOT NULL,
+    modified_time BIGINT NOT NULL
+);
+
+-- A view belongs to an app or to another view, so it has no foreign key.
+CREATE TABLE IF NOT EXISTS af_view(
+    id TEXT NOT NULL PRIMARY KEY,
+    owner uuid NOT NULL DEFAULT auth.uid() REFERENCES auth.users(id) ON DELETE CASCADE,
+    belong_to_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    thumbnail TEXT NOT NULL DEFAULT '',
+    view_type INTEGER NOT NULL DEFAULT 0,
+    version BIGINT NOT NULL DEFAULT 0,
+    create_time BIGINT NOT NULL,
+    modified_time BIGINT NOT NULL
+);
+
+-- The content of the document as a delta in json, and the id of the last
+-- revision that was pushed.
+CREATE TABLE IF NOT EXISTS af_document(
+    id TEXT NOT NULL PRIMARY KEY,
+    owner uuid NOT NULL DEFAULT auth.uid() REFERENCES auth.users(id) ON DELETE CASCADE,
+    data TEXT NOT NULL DEFAULT '',
+    rev_id BIGINT NOT NULL DEFAULT 0,
+    base_rev_id BIGINT NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS af_trash(
+    id TEXT NOT NULL PRIMARY KEY,
+    owner uuid NOT NULL DEFAULT auth.uid() REFERENCES auth.users(id) ON DELETE CASCADE,
+    ty INTEGER NOT NULL
+);
+
+ALTER TABLE af_workspace ENABLE ROW LEVEL SECURITY;
+ALTER TABLE af_app ENABLE ROW LEVEL SECURITY;
+ALTER TABLE af_view ENABLE ROW LEVEL SECURITY;
+ALTER TABLE af_document ENABLE ROW LEVEL SECURITY;
+ALTER TABLE af_trash ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY af_workspace_owner ON af_workspace FOR ALL USING (owner = auth.uid()) WITH CHECK (owner = auth.uid());
+CREATE POLICY af_app_owner ON af_app FOR ALL USING (owner = auth.uid()) WITH CHECK (owner = auth.uid());
+CREATE POLICY af_view_owner ON af_view FOR ALL USING (owner = auth.uid()) WITH CHECK (owner = auth.uid());
+CREATE POLICY af_document_owner ON af_document FOR ALL USING (owner = auth.uid()) WITH CHECK (owner = auth.uid());
+CREATE POLICY af_trash_owner ON af_trash FOR ALL USING (owner = auth.uid()) WITH CHECK (owner = auth.uid());
+
+-- The trash with the names and the times of the views and the apps in it, who
+-- deleted them and from which parent. It runs with the rights of the caller,
+-- so the policies above apply.
+CREATE OR REPLACE VIEW af_trash_item WITH (security_invoker = true) AS
+    SELECT t.id, t.ty, v.name, v.create_time, v.modified_time,
+        t.owner::text AS deleted_by, v.belong_to_id AS parent_id
+    FROM af_trash t JOIN af_view v ON v.id = t.id
+    UNION ALL
+    SELECT t.id, t.ty, a.name, a.create_time, a.modified_time,
+        t.owner::text AS deleted_by, a.workspace_id AS parent_id
+    FROM af_trash t JOIN af_app a ON a.id = t.id
+    UNION ALL
+    SELECT t.id, t.ty, w.name, w.create_time, w.modified_time,
+        t.owner::text AS deleted_by, '' AS parent_id
+    FROM af_trash t JOIN af_workspace w ON w.id = t.id;
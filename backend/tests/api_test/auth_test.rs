@@ -1,6 +1,14 @@
-use crate::util::helper::{spawn_user_server, TestUserServer};
+use crate::util::helper::{spawn_server, spawn_user_server, TestUserServer};
 use backend_service::errors::ErrorCode;
-use flowy_user_data_model::entities::{SignInParams, SignUpParams, SignUpResponse, UpdateUserParams};
+use flowy_user_data_model::entities::{
+    RefreshTokenParams,
+    RevokeDeviceParams,
+    SignInParams,
+    SignUpParams,
+    SignUpResponse,
+    UpdateUserParams,
+    UploadAvatarParams,
+};
 
 #[actix_rt::test]
 async fn user_register() {
@@ -37,6 +45,8 @@ async fn user_sign_in() {
         email: email.to_string(),
         password: password.to_string(),
         name: "rust".to_string(),
+        device_name: "".to_string(),
+        platform: "test".to_string(),
     };
     let _ = app.sign_in(params).await.unwrap();
 }
@@ -73,6 +83,8 @@ async fn user_update_password() {
         email: email.to_string(),
         password: password.to_string(),
         name: "rust".to_string(),
+        device_name: "".to_string(),
+        platform: "test".to_string(),
     };
 
     match server.sign_in(sign_in_params).await {
@@ -83,6 +95,34 @@ async fn user_update_password() {
     }
 }
 
+#[actix_rt::test]
+async fn user_refresh_token() {
+    let mut server: TestUserServer = spawn_server().await.into();
+    let sign_up_resp = server.register_user().await;
+    server.user_id = Some(sign_up_resp.user_id);
+    let params = RefreshTokenParams {
+        refresh_token: sign_up_resp.refresh_token,
+    };
+    let resp = server.refresh_token(params).await.unwrap();
+    assert!(!resp.refresh_token.is_empty());
+
+    server.user_token = Some(resp.token);
+    let user = server.get_user_profile().await;
+    assert_eq!(user.id, server.user_id());
+}
+
+#[actix_rt::test]
+async fn user_refresh_token_with_access_token() {
+    let server = TestUserServer::new().await;
+    let params = RefreshTokenParams {
+        refresh_token: server.user_token().to_owned(),
+    };
+    match server.refresh_token(params).await {
+        Ok(_) => panic!("The token can't be used as the refresh token"),
+        Err(e) => assert_eq!(e.code, ErrorCode::UserUnauthorized),
+    }
+}
+
 #[actix_rt::test]
 async fn user_update_name() {
     let server = TestUserServer::new().await;
@@ -106,6 +146,49 @@ async fn user_update_email() {
     assert_eq!(user.email, email);
 }
 
+#[actix_rt::test]
+async fn user_read_devices() {
+    let server = TestUserServer::new().await;
+    let devices = server.read_user_devices().await;
+    assert_eq!(devices.items.len(), 1);
+    assert_eq!(devices.items[0].device_name, "annie's laptop");
+    assert!(devices.items[0].is_current);
+}
+
+#[actix_rt::test]
+async fn user_revoke_device() {
+    let mut server: TestUserServer = spawn_server().await.into();
+    let sign_up_resp = server.register_user().await;
+    let sign_in_resp = server
+        .sign_in(SignInParams {
+            email: sign_up_resp.email.clone(),
+            password: "HelloAppFlowy123!".to_string(),
+            name: "".to_string(),
+            device_name: "annie's phone".to_string(),
+            platform: "test".to_string(),
+        })
+        .await
+        .unwrap();
+    server.user_token = Some(sign_in_resp.token);
+
+    let devices = server.read_user_devices().await;
+    assert_eq!(devices.items.len(), 2);
+    let laptop = devices.items.iter().find(|device| !device.is_current).unwrap();
+    let params = RevokeDeviceParams {
+        session_id: laptop.session_id.clone(),
+    };
+    server.revoke_user_device(params).await.unwrap();
+    assert_eq!(server.read_user_devices().await.items.len(), 1);
+
+    let params = RefreshTokenParams {
+        refresh_token: sign_up_resp.refresh_token,
+    };
+    match server.refresh_token(params).await {
+        Ok(_) => panic!("The refresh token of the revoked session can't be used"),
+        Err(e) => assert_eq!(e.code, ErrorCode::UserUnauthorized),
+    }
+}
+
 #[allow(dead_code)]
 async fn sign_up_user(server: &TestUserServer) -> SignUpResponse {
     let email = "annie@appflowy.io";
@@ -119,8 +202,29 @@ async fn register_user(server: &TestUserServer, email: &str, password: &str) ->
         email: email.to_string(),
         name: "annie".to_string(),
         password: password.to_string(),
+        device_name: "".to_string(),
+        platform: "test".to_string(),
     };
 
     let response = server.register(params).await;
     response
 }
+
+#[actix_rt::test]
+async fn user_read_avatar_requires_token() {
+    let server = TestUserServer::new().await;
+    let params = UploadAvatarParams { data: vec![1, 2, 3] };
+    server.upload_avatar(params).await.unwrap();
+
+    let avatar = server
+        .read_avatar(server.user_token(), server.user_id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(avatar.data, vec![1, 2, 3]);
+
+    match server.read_avatar("", server.user_id()).await {
+        Ok(_) => panic!("The avatar can't be read without a token"),
+        Err(e) => assert_eq!(e.code, ErrorCode::UserUnauthorized),
+    }
+}
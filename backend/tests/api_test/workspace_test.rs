@@ -1,6 +1,16 @@
 #![allow(clippy::all)]
 
 use crate::util::helper::{ViewTest, *};
+use backend_service::{
+    errors::ErrorCode,
+    http_request::{
+        create_guest_access_request,
+        create_share_link_request,
+        delete_view_request,
+        read_workspaces_request,
+        update_view_request,
+    },
+};
 use flowy_collaboration::{
     document::{Document, PlainDoc},
     entities::{
@@ -10,10 +20,24 @@ use flowy_collaboration::{
 };
 use flowy_core_data_model::entities::{
     app::{AppId, UpdateAppParams},
+    share::{CreateGuestAccessParams, CreateShareLinkParams, GuestAccessId, ShareLinkId, SharePermission},
     trash::{RepeatedTrashId, TrashId, TrashType},
-    view::{RepeatedViewId, UpdateViewParams, ViewId},
-    workspace::{CreateWorkspaceParams, UpdateWorkspaceParams, WorkspaceId},
+    view::{RepeatedViewId, UpdateViewAccessParams, UpdateViewParams, ViewId},
+    workspace::{
+        AddWorkspaceMemberParams,
+        CreateInvitationParams,
+        CreateWorkspaceParams,
+        InvitationId,
+        QueryWorkspaceMembersParams,
+        RemoveWorkspaceMemberParams,
+        UpdateWorkspaceMemberParams,
+        UpdateWorkspaceParams,
+        WorkspaceId,
+        WorkspaceRole,
+    },
 };
+use flowy_document::{errors::ErrorCode as FlowyErrorCode, server::read_doc_request};
+use flowy_user_data_model::entities::SignUpParams;
 
 #[actix_rt::test]
 async fn workspace_create() {
@@ -53,6 +77,7 @@ async fn workspace_update() {
         id: test.workspace.id.clone(),
         name: Some(new_name.to_string()),
         desc: Some(new_desc.to_string()),
+        settings: None,
     };
     test.server.update_workspace(update_params).await;
     let read_params = WorkspaceId::new(Some(test.workspace.id.clone()));
@@ -76,6 +101,205 @@ async fn workspace_delete() {
     assert_eq!(repeated_workspace.len(), 0);
 }
 
+#[actix_rt::test]
+async fn workspace_member_add_and_read() {
+    let test = WorkspaceTest::new().await;
+    let member_server = register_member(&test.server).await;
+    let member = test
+        .server
+        .add_workspace_member(AddWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Editor,
+        })
+        .await
+        .unwrap();
+    assert_eq!(member.user_id, member_server.user_id());
+
+    let params = QueryWorkspaceMembersParams {
+        workspace_id: test.workspace.id.clone(),
+    };
+    let members = test.server.read_workspace_members(params).await;
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].role, WorkspaceRole::Owner);
+    assert_eq!(members[1].role, WorkspaceRole::Editor);
+
+    let read_params = WorkspaceId::new(Some(test.workspace.id.clone()));
+    let workspaces = member_server.read_workspaces(read_params).await;
+    assert_eq!(workspaces.len(), 1);
+    assert_eq!(workspaces[0].role, WorkspaceRole::Editor);
+}
+
+#[actix_rt::test]
+async fn workspace_member_update_and_remove() {
+    let test = WorkspaceTest::new().await;
+    let member_server = register_member(&test.server).await;
+    let _ = test
+        .server
+        .add_workspace_member(AddWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Editor,
+        })
+        .await
+        .unwrap();
+
+    let _ = test
+        .server
+        .update_workspace_member(UpdateWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            user_id: member_server.user_id().to_owned(),
+            role: WorkspaceRole::Viewer,
+        })
+        .await
+        .unwrap();
+    let read_params = WorkspaceId::new(Some(test.workspace.id.clone()));
+    let workspaces = member_server.read_workspaces(read_params.clone()).await;
+    assert_eq!(workspaces[0].role, WorkspaceRole::Viewer);
+
+    let _ = test
+        .server
+        .remove_workspace_member(RemoveWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            user_id: member_server.user_id().to_owned(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(member_server.read_workspaces(read_params).await.len(), 0);
+}
+
+#[actix_rt::test]
+async fn workspace_member_viewer_permission_denied() {
+    let test = WorkspaceTest::new().await;
+    let member_server = register_member(&test.server).await;
+    let _ = test
+        .server
+        .add_workspace_member(AddWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Viewer,
+        })
+        .await
+        .unwrap();
+
+    let result = member_server
+        .update_workspace_member(UpdateWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            user_id: member_server.user_id().to_owned(),
+            role: WorkspaceRole::Editor,
+        })
+        .await;
+    match result {
+        Ok(_) => panic!("The viewer can't change the role"),
+        Err(e) => assert_eq!(e.code, ErrorCode::PermissionDenied),
+    }
+}
+
+#[actix_rt::test]
+async fn workspace_invitation_accept() {
+    let test = WorkspaceTest::new().await;
+    let invitation = test
+        .server
+        .create_invitation(CreateInvitationParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Editor,
+        })
+        .await
+        .unwrap();
+
+    // The invitation waits for the user to sign up with the email.
+    let member_server = register_member(&test.server).await;
+    let invitations = member_server.read_invitations().await;
+    assert_eq!(invitations.len(), 1);
+    assert_eq!(invitations[0].id, invitation.id);
+    assert_eq!(invitations[0].workspace_name, test.workspace.name);
+
+    let workspace = member_server
+        .accept_invitation(InvitationId {
+            invitation_id: invitation.id.clone(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(workspace.id, test.workspace.id);
+    assert_eq!(workspace.role, WorkspaceRole::Editor);
+    assert!(member_server.read_invitations().await.is_empty());
+
+    let params = QueryWorkspaceMembersParams {
+        workspace_id: test.workspace.id.clone(),
+    };
+    assert_eq!(test.server.read_workspace_members(params).await.len(), 2);
+}
+
+#[actix_rt::test]
+async fn workspace_invitation_decline() {
+    let test = WorkspaceTest::new().await;
+    let member_server = register_member(&test.server).await;
+    let invitation = test
+        .server
+        .create_invitation(CreateInvitationParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Viewer,
+        })
+        .await
+        .unwrap();
+
+    let params = InvitationId {
+        invitation_id: invitation.id.clone(),
+    };
+    member_server.decline_invitation(params.clone()).await;
+    assert!(member_server.read_invitations().await.is_empty());
+    match member_server.accept_invitation(params).await {
+        Ok(_) => panic!("The declined invitation can't be accepted"),
+        Err(e) => assert_eq!(e.code, ErrorCode::RecordNotFound),
+    }
+}
+
+#[actix_rt::test]
+async fn workspace_invitation_for_existing_member() {
+    let test = WorkspaceTest::new().await;
+    let _ = register_member(&test.server).await;
+    let _ = test
+        .server
+        .add_workspace_member(AddWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Editor,
+        })
+        .await
+        .unwrap();
+
+    let result = test
+        .server
+        .create_invitation(CreateInvitationParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Viewer,
+        })
+        .await;
+    match result {
+        Ok(_) => panic!("The member can't be invited again"),
+        Err(e) => assert_eq!(e.code, ErrorCode::ParamsInvalid),
+    }
+}
+
+async fn register_member(server: &TestUserServer) -> TestUserServer {
+    let mut member_server: TestUserServer = server.inner.clone().into();
+    let response = member_server
+        .register(SignUpParams {
+            email: "nathan@appflowy.io".to_string(),
+            name: "nathan".to_string(),
+            password: "HelloAppFlowy123!".to_string(),
+            device_name: "".to_string(),
+            platform: "test".to_string(),
+        })
+        .await;
+    member_server.user_token = Some(response.token);
+    member_server.user_id = Some(response.user_id);
+    member_server
+}
+
 #[actix_rt::test]
 async fn app_create() {
     let test = AppTest::new().await;
@@ -183,6 +407,292 @@ async fn view_delete() {
     assert_eq!(trash_ids.contains(&test.view.id), true);
 }
 
+#[actix_rt::test]
+async fn view_access_restricted_to_granted_members() {
+    let test = ViewTest::new().await;
+    let member_server = register_member(&test.server).await;
+    let _ = test
+        .server
+        .add_workspace_member(AddWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Editor,
+        })
+        .await
+        .unwrap();
+    assert_eq!(read_app_view_count(&member_server, &test.app.id).await, 1);
+
+    // Restricting the view to the owner hides it from the member
+    let access_params = UpdateViewAccessParams {
+        view_id: test.view.id.clone(),
+        user_id: test.server.user_id().to_owned(),
+    };
+    let _ = test.server.grant_view_access(access_params.clone()).await.unwrap();
+    let view_access = test.server.read_view_access(test.view.id.clone().into()).await;
+    assert_eq!(view_access.user_ids, vec![test.server.user_id().to_owned()]);
+    assert_eq!(read_app_view_count(&member_server, &test.app.id).await, 0);
+
+    let _ = test.server.revoke_view_access(access_params).await.unwrap();
+    assert_eq!(read_app_view_count(&member_server, &test.app.id).await, 1);
+}
+
+#[actix_rt::test]
+async fn view_share_by_restricted_member_permission_denied() {
+    let test = ViewTest::new().await;
+    let member_server = register_member(&test.server).await;
+    let _ = test
+        .server
+        .add_workspace_member(AddWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Editor,
+        })
+        .await
+        .unwrap();
+    let _ = test
+        .server
+        .grant_view_access(UpdateViewAccessParams {
+            view_id: test.view.id.clone(),
+            user_id: test.server.user_id().to_owned(),
+        })
+        .await
+        .unwrap();
+
+    let url = format!("{}/api/share", member_server.http_addr());
+    let params = CreateShareLinkParams {
+        view_id: test.view.id.clone(),
+        permission: SharePermission::ReadOnly,
+        expire_time: None,
+    };
+    match create_share_link_request(member_server.user_token(), params, &url).await {
+        Ok(_) => panic!("The restricted member can't share the view"),
+        Err(e) => assert_eq!(e.code, ErrorCode::PermissionDenied),
+    }
+
+    let url = format!("{}/api/view/guest", member_server.http_addr());
+    let params = CreateGuestAccessParams {
+        view_id: test.view.id.clone(),
+        email: "guest@appflowy.io".to_owned(),
+        permission: SharePermission::ReadOnly,
+    };
+    match create_guest_access_request(member_server.user_token(), params, &url).await {
+        Ok(_) => panic!("The restricted member can't invite a guest to the view"),
+        Err(e) => assert_eq!(e.code, ErrorCode::PermissionDenied),
+    }
+}
+
+#[actix_rt::test]
+async fn view_update_by_viewer_permission_denied() {
+    let test = ViewTest::new().await;
+    let member_server = register_member(&test.server).await;
+    let _ = test
+        .server
+        .add_workspace_member(AddWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Viewer,
+        })
+        .await
+        .unwrap();
+
+    let url = format!("{}/api/view", member_server.http_addr());
+    let params = UpdateViewParams::new(&test.view.id).name("viewer name");
+    match update_view_request(member_server.user_token(), params, &url).await {
+        Ok(_) => panic!("The viewer can't update the view"),
+        Err(e) => assert_eq!(e.code, ErrorCode::PermissionDenied),
+    }
+
+    let params = RepeatedViewId {
+        items: vec![test.view.id.clone()],
+    };
+    match delete_view_request(member_server.user_token(), params, &url).await {
+        Ok(_) => panic!("The viewer can't delete the view"),
+        Err(e) => assert_eq!(e.code, ErrorCode::PermissionDenied),
+    }
+
+    let read_params: ViewId = test.view.id.clone().into();
+    let view = test.server.read_view(read_params).await.unwrap();
+    assert_eq!(view.name, test.view.name);
+}
+
+#[actix_rt::test]
+async fn view_move_params_invalid() {
+    let test = ViewTest::new().await;
+    let nested_view = create_test_view(&test.server, &test.view.id).await;
+    let url = format!("{}/api/view", test.server.http_addr());
+
+    // The view can't be nested in the views that are nested in it
+    let params = UpdateViewParams {
+        belong_to_id: Some(nested_view.id.clone()),
+        ..UpdateViewParams::new(&test.view.id)
+    };
+    match update_view_request(test.server.user_token(), params, &url).await {
+        Ok(_) => panic!("The view can't be moved into itself"),
+        Err(e) => assert_eq!(e.code, ErrorCode::ParamsInvalid),
+    }
+
+    let workspace = create_test_workspace(&test.server).await;
+    let app = create_test_app(&test.server, &workspace.id).await;
+    let params = UpdateViewParams {
+        belong_to_id: Some(app.id.clone()),
+        ..UpdateViewParams::new(&test.view.id)
+    };
+    match update_view_request(test.server.user_token(), params, &url).await {
+        Ok(_) => panic!("The view can't be moved to another workspace"),
+        Err(e) => assert_eq!(e.code, ErrorCode::ParamsInvalid),
+    }
+
+    // Moving it to another app of the workspace is fine
+    let other_app = create_test_app(&test.server, &test.workspace.id).await;
+    let params = UpdateViewParams {
+        belong_to_id: Some(other_app.id.clone()),
+        ..UpdateViewParams::new(&test.view.id)
+    };
+    test.server.update_view(params).await;
+    assert_eq!(read_app_view_count(&test.server, &other_app.id).await, 1);
+}
+
+async fn read_app_view_count(server: &TestUserServer, app_id: &str) -> usize {
+    let app = server.read_app(AppId::new(app_id)).await.unwrap();
+    app.belongings.len()
+}
+
+#[actix_rt::test]
+async fn view_access_grant_by_member_permission_denied() {
+    let test = ViewTest::new().await;
+    let member_server = register_member(&test.server).await;
+    let _ = test
+        .server
+        .add_workspace_member(AddWorkspaceMemberParams {
+            workspace_id: test.workspace.id.clone(),
+            email: "nathan@appflowy.io".to_string(),
+            role: WorkspaceRole::Editor,
+        })
+        .await
+        .unwrap();
+
+    let result = member_server
+        .grant_view_access(UpdateViewAccessParams {
+            view_id: test.view.id.clone(),
+            user_id: member_server.user_id().to_owned(),
+        })
+        .await;
+    match result {
+        Ok(_) => panic!("Only the owner can restrict the view"),
+        Err(e) => assert_eq!(e.code, ErrorCode::PermissionDenied),
+    }
+}
+
+#[actix_rt::test]
+async fn view_share_link() {
+    let test = ViewTest::new().await;
+    let params = CreateShareLinkParams {
+        view_id: test.view.id.clone(),
+        permission: SharePermission::ReadOnly,
+        expire_time: None,
+    };
+    let link = test.server.create_share_link(params).await;
+    assert_eq!(link.view_id, test.view.id);
+
+    let links = test.server.read_share_links(test.view.id.clone().into()).await;
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].id, link.id);
+
+    // the shared document is read without the token
+    let document = test.server.read_shared_document(&link.id).await.unwrap();
+    assert_eq!(document.view_id, test.view.id);
+    assert_eq!(document.name, test.view.name);
+    assert_eq!(document.permission, SharePermission::ReadOnly);
+}
+
+#[actix_rt::test]
+async fn view_share_link_revoke() {
+    let test = ViewTest::new().await;
+    let params = CreateShareLinkParams {
+        view_id: test.view.id.clone(),
+        permission: SharePermission::ReadOnly,
+        expire_time: None,
+    };
+    let link = test.server.create_share_link(params).await;
+    test.server
+        .revoke_share_link(ShareLinkId {
+            link_id: link.id.clone(),
+        })
+        .await;
+
+    let links = test.server.read_share_links(test.view.id.clone().into()).await;
+    assert_eq!(links.is_empty(), true);
+    match test.server.read_shared_document(&link.id).await {
+        Ok(_) => panic!("The revoked link should not be readable"),
+        Err(e) => assert_eq!(e.code, ErrorCode::RecordNotFound),
+    }
+}
+
+#[actix_rt::test]
+async fn view_share_link_after_moving_to_trash() {
+    let test = ViewTest::new().await;
+    let params = CreateShareLinkParams {
+        view_id: test.view.id.clone(),
+        permission: SharePermission::ReadOnly,
+        expire_time: None,
+    };
+    let link = test.server.create_share_link(params).await;
+    test.server.create_view_trash(&test.view.id).await;
+
+    match test.server.read_shared_document(&link.id).await {
+        Ok(_) => panic!("The view in the trash should not be readable"),
+        Err(e) => assert_eq!(e.code, ErrorCode::RecordNotFound),
+    }
+}
+
+#[actix_rt::test]
+async fn view_guest_access() {
+    let test = ViewTest::new().await;
+    let params = CreateGuestAccessParams {
+        view_id: test.view.id.clone(),
+        email: "guest@appflowy.io".to_owned(),
+        permission: SharePermission::ReadOnly,
+    };
+    let access = test.server.create_guest_access(params).await;
+    assert_eq!(access.view_id, test.view.id);
+    assert_eq!(access.token.is_empty(), false);
+
+    let view = test.server.read_guest_view(&access.token).await.unwrap();
+    assert_eq!(view.id, test.view.id);
+    assert_eq!(view.belong_to_id.is_empty(), true);
+
+    let document = test.server.read_guest_document(&access.token).await.unwrap();
+    assert_eq!(document.doc_id, test.view.id);
+
+    // the guest token can't be used to read the workspaces
+    let url = format!("{}/api/workspace", test.server.http_addr());
+    match read_workspaces_request(&access.token, WorkspaceId::new(None), &url).await {
+        Ok(_) => panic!("The guest should not read the workspaces"),
+        Err(e) => assert_eq!(e.code, ErrorCode::UserUnauthorized),
+    }
+}
+
+#[actix_rt::test]
+async fn view_guest_access_revoke() {
+    let test = ViewTest::new().await;
+    let params = CreateGuestAccessParams {
+        view_id: test.view.id.clone(),
+        email: "guest@appflowy.io".to_owned(),
+        permission: SharePermission::Editable,
+    };
+    let access = test.server.create_guest_access(params).await;
+    test.server
+        .revoke_guest_access(GuestAccessId {
+            access_id: access.id.clone(),
+        })
+        .await;
+
+    match test.server.read_guest_view(&access.token).await {
+        Ok(_) => panic!("The revoked access should not be readable"),
+        Err(e) => assert_eq!(e.code, ErrorCode::RecordNotFound),
+    }
+}
+
 #[actix_rt::test]
 async fn trash_delete() {
     let test = ViewTest::new().await;
@@ -215,6 +725,7 @@ async fn workspace_list_read() {
         let params = CreateWorkspaceParams {
             name: format!("{} workspace", i),
             desc: format!("This is my {} workspace", i),
+            workspace_id: "".to_string(),
         };
         let _ = server.create_workspace(params).await;
     }
@@ -266,6 +777,32 @@ async fn doc_create() {
     assert_eq!(doc.unwrap().text, document.to_json());
 }
 
+#[actix_rt::test]
+async fn doc_read_of_another_user_permission_denied() {
+    let server = TestUserServer::new().await;
+    let member_server = register_member(&server).await;
+    let doc_id = format!("{}_settings", server.user_id());
+    let mut document = Document::new::<PlainDoc>();
+    let bytes = document.insert(0, "{}").unwrap().to_bytes();
+    let md5 = md5(&bytes);
+    let revision = Revision::new(&doc_id, 0, 1, bytes, server.user_id(), md5);
+    server
+        .create_doc(CreateDocParams {
+            id: doc_id.clone(),
+            revisions: RepeatedRevision::new(vec![revision]),
+        })
+        .await;
+
+    let doc = server.read_doc(DocumentId { doc_id: doc_id.clone() }).await;
+    assert_eq!(doc.is_some(), true);
+
+    let url = format!("{}/api/doc", member_server.http_addr());
+    match read_doc_request(member_server.user_token(), DocumentId { doc_id }, &url).await {
+        Ok(_) => panic!("The settings of another user can't be read"),
+        Err(e) => assert_eq!(e.code, FlowyErrorCode::WorkspacePermissionDenied.value()),
+    }
+}
+
 #[actix_rt::test]
 async fn doc_delete() {
     let test = ViewTest::new().await;
@@ -40,6 +40,12 @@ impl TestUserServer {
         Ok(resp)
     }
 
+    pub async fn refresh_token(&self, params: RefreshTokenParams) -> Result<RefreshTokenResponse, ServerError> {
+        let url = format!("{}/api/auth/refresh", self.http_addr());
+        let resp = user_refresh_token_request(params, &url).await?;
+        Ok(resp)
+    }
+
     pub async fn sign_out(&self) {
         let url = format!("{}/api/auth", self.http_addr());
         let _ = user_sign_out_request(self.user_token(), &url).await.unwrap();
@@ -55,6 +61,28 @@ impl TestUserServer {
         user_profile
     }
 
+    pub async fn read_user_devices(&self) -> RepeatedUserDevice {
+        let url = format!("{}/api/user/device", self.http_addr());
+        read_user_devices_request(self.user_token(), &url).await.unwrap()
+    }
+
+    pub async fn revoke_user_device(&self, params: RevokeDeviceParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/user/device", self.http_addr());
+        let _ = revoke_user_device_request(self.user_token(), params, &url).await?;
+        Ok(())
+    }
+
+    pub async fn upload_avatar(&self, params: UploadAvatarParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/user/avatar", self.http_addr());
+        let _ = upload_avatar_request(self.user_token(), params, &url).await?;
+        Ok(())
+    }
+
+    pub async fn read_avatar(&self, token: &str, user_id: &str) -> Result<Option<UserAvatar>, ServerError> {
+        let url = format!("{}/api/user/avatar/{}", self.http_addr(), user_id);
+        read_avatar_request(token, &url).await
+    }
+
     pub async fn update_user_profile(&self, params: UpdateUserParams) -> Result<(), ServerError> {
         let url = format!("{}/api/user", self.http_addr());
         let _ = update_user_profile_request(self.user_token(), params, &url).await?;
@@ -83,6 +111,55 @@ impl TestUserServer {
         delete_workspace_request(self.user_token(), params, &url).await.unwrap();
     }
 
+    pub async fn read_workspace_members(&self, params: QueryWorkspaceMembersParams) -> RepeatedWorkspaceMember {
+        let url = format!("{}/api/workspace/member", self.http_addr());
+        read_workspace_members_request(self.user_token(), params, &url)
+            .await
+            .unwrap()
+    }
+
+    pub async fn add_workspace_member(&self, params: AddWorkspaceMemberParams) -> Result<WorkspaceMember, ServerError> {
+        let url = format!("{}/api/workspace/member", self.http_addr());
+        let member = add_workspace_member_request(self.user_token(), params, &url).await?;
+        Ok(member)
+    }
+
+    pub async fn update_workspace_member(&self, params: UpdateWorkspaceMemberParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/workspace/member", self.http_addr());
+        let _ = update_workspace_member_request(self.user_token(), params, &url).await?;
+        Ok(())
+    }
+
+    pub async fn remove_workspace_member(&self, params: RemoveWorkspaceMemberParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/workspace/member", self.http_addr());
+        let _ = remove_workspace_member_request(self.user_token(), params, &url).await?;
+        Ok(())
+    }
+
+    pub async fn create_invitation(&self, params: CreateInvitationParams) -> Result<WorkspaceInvitation, ServerError> {
+        let url = format!("{}/api/workspace/invitation", self.http_addr());
+        let invitation = create_invitation_request(self.user_token(), params, &url).await?;
+        Ok(invitation)
+    }
+
+    pub async fn read_invitations(&self) -> RepeatedWorkspaceInvitation {
+        let url = format!("{}/api/workspace/invitation", self.http_addr());
+        read_invitations_request(self.user_token(), &url).await.unwrap()
+    }
+
+    pub async fn accept_invitation(&self, params: InvitationId) -> Result<Workspace, ServerError> {
+        let url = format!("{}/api/workspace/invitation/accept", self.http_addr());
+        let workspace = accept_invitation_request(self.user_token(), params, &url).await?;
+        Ok(workspace)
+    }
+
+    pub async fn decline_invitation(&self, params: InvitationId) {
+        let url = format!("{}/api/workspace/invitation/decline", self.http_addr());
+        decline_invitation_request(self.user_token(), params, &url)
+            .await
+            .unwrap();
+    }
+
     pub async fn create_app(&self, params: CreateAppParams) -> App {
         let url = format!("{}/api/app", self.http_addr());
         let app = create_app_request(self.user_token(), params, &url).await.unwrap();
@@ -127,6 +204,71 @@ impl TestUserServer {
         delete_view_request(self.user_token(), params, &url).await.unwrap();
     }
 
+    pub async fn read_view_access(&self, params: ViewId) -> ViewAccess {
+        let url = format!("{}/api/view/access", self.http_addr());
+        read_view_access_request(self.user_token(), params, &url).await.unwrap()
+    }
+
+    pub async fn grant_view_access(&self, params: UpdateViewAccessParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/view/access", self.http_addr());
+        let _ = grant_view_access_request(self.user_token(), params, &url).await?;
+        Ok(())
+    }
+
+    pub async fn revoke_view_access(&self, params: UpdateViewAccessParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/view/access", self.http_addr());
+        let _ = revoke_view_access_request(self.user_token(), params, &url).await?;
+        Ok(())
+    }
+
+    pub async fn create_share_link(&self, params: CreateShareLinkParams) -> ShareLink {
+        let url = format!("{}/api/share", self.http_addr());
+        create_share_link_request(self.user_token(), params, &url)
+            .await
+            .unwrap()
+    }
+
+    pub async fn read_share_links(&self, params: ViewId) -> RepeatedShareLink {
+        let url = format!("{}/api/share", self.http_addr());
+        read_share_links_request(self.user_token(), params, &url).await.unwrap()
+    }
+
+    pub async fn revoke_share_link(&self, params: ShareLinkId) {
+        let url = format!("{}/api/share", self.http_addr());
+        revoke_share_link_request(self.user_token(), params, &url)
+            .await
+            .unwrap();
+    }
+
+    pub async fn read_shared_document(&self, link_id: &str) -> Result<SharedDocument, ServerError> {
+        let url = format!("{}/api/shared/{}", self.http_addr(), link_id);
+        read_shared_document_request(&url).await
+    }
+
+    pub async fn create_guest_access(&self, params: CreateGuestAccessParams) -> GuestAccess {
+        let url = format!("{}/api/view/guest", self.http_addr());
+        create_guest_access_request(self.user_token(), params, &url)
+            .await
+            .unwrap()
+    }
+
+    pub async fn revoke_guest_access(&self, params: GuestAccessId) {
+        let url = format!("{}/api/view/guest", self.http_addr());
+        revoke_guest_access_request(self.user_token(), params, &url)
+            .await
+            .unwrap();
+    }
+
+    pub async fn read_guest_view(&self, guest_token: &str) -> Result<View, ServerError> {
+        let url = format!("{}/api/guest/view", self.http_addr());
+        read_guest_view_request(guest_token, &url).await
+    }
+
+    pub async fn read_guest_document(&self, guest_token: &str) -> Result<DocumentInfo, ServerError> {
+        let url = format!("{}/api/guest/doc", self.http_addr());
+        read_guest_document_request(guest_token, &url).await
+    }
+
     pub async fn create_view_trash(&self, view_id: &str) {
         let identifier = TrashId {
             id: view_id.to_string(),
@@ -167,6 +309,8 @@ impl TestUserServer {
             email: "annie@appflowy.io".to_string(),
             name: "annie".to_string(),
             password: "HelloAppFlowy123!".to_string(),
+            device_name: "annie's laptop".to_string(),
+            platform: "test".to_string(),
         };
 
         self.register(params).await
@@ -288,6 +432,7 @@ pub async fn create_test_workspace(server: &TestUserServer) -> Workspace {
     let params = CreateWorkspaceParams {
         name: "My first workspace".to_string(),
         desc: "This is my first workspace".to_string(),
+        workspace_id: "".to_string(),
     };
 
     let workspace = server.create_workspace(params).await;
@@ -300,6 +445,7 @@ pub async fn create_test_app(server: &TestUserServer, workspace_id: &str) -> App
         name: "My first app".to_string(),
         desc: "This is my first app".to_string(),
         color_style: ColorStyle::default(),
+        app_id: uuid_string(),
     };
 
     let app = server.create_app(params).await;
@@ -89,6 +89,68 @@ typedef _set_stream_port_Dart = int Function(
   int port,
 );
 
+/// C function `set_stream_protocol_version`.
+int set_stream_protocol_version(int version) {
+  return _set_stream_protocol_version(version);
+}
+
+final _set_stream_protocol_version_Dart _set_stream_protocol_version =
+    _dl.lookupFunction<_set_stream_protocol_version_C, _set_stream_protocol_version_Dart>('set_stream_protocol_version');
+typedef _set_stream_protocol_version_C = Int32 Function(
+  Int32 version,
+);
+typedef _set_stream_protocol_version_Dart = int Function(
+  int version,
+);
+
+/// C function `subscribe_notification`.
+int subscribe_notification(
+  Pointer<ffi.Utf8> id,
+) {
+  return _subscribe_notification(id);
+}
+
+final _subscribe_notification_Dart _subscribe_notification =
+    _dl.lookupFunction<_subscribe_notification_C, _subscribe_notification_Dart>('subscribe_notification');
+typedef _subscribe_notification_C = Int32 Function(
+  Pointer<ffi.Utf8> id,
+);
+typedef _subscribe_notification_Dart = int Function(
+  Pointer<ffi.Utf8> id,
+);
+
+/// C function `unsubscribe_notification`.
+int unsubscribe_notification(
+  Pointer<ffi.Utf8> id,
+) {
+  return _unsubscribe_notification(id);
+}
+
+final _unsubscribe_notification_Dart _unsubscribe_notification =
+    _dl.lookupFunction<_unsubscribe_notification_C, _unsubscribe_notification_Dart>('unsubscribe_notification');
+typedef _unsubscribe_notification_C = Int32 Function(
+  Pointer<ffi.Utf8> id,
+);
+typedef _unsubscribe_notification_Dart = int Function(
+  Pointer<ffi.Utf8> id,
+);
+
+/// C function `set_notification_filter`.
+int set_notification_filter(
+  int enabled,
+) {
+  return _set_notification_filter(enabled);
+}
+
+final _set_notification_filter_Dart _set_notification_filter =
+    _dl.lookupFunction<_set_notification_filter_C, _set_notification_filter_Dart>('set_notification_filter');
+typedef _set_notification_filter_C = Int32 Function(
+  Int32 enabled,
+);
+typedef _set_notification_filter_Dart = int Function(
+  int enabled,
+);
+
 /// C function `link_me_please`.
 void link_me_please() {
   _link_me_please();
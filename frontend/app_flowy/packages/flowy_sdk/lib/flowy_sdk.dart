@@ -7,6 +7,10 @@ import 'dart:ffi';
 import 'ffi.dart' as ffi;
 import 'package:ffi/ffi.dart';
 
+// Bump it with NOTIFY_PROTOCOL_VERSION of the dart-notify crate once the
+// listeners can read the new payloads.
+const int notifyProtocolVersion = 2;
+
 class FlowySDK {
   static const MethodChannel _channel = MethodChannel('flowy_sdk');
   static Future<String> get platformVersion async {
@@ -21,6 +25,7 @@ class FlowySDK {
   Future<void> init(Directory sdkDir) async {
     final port = RustStreamReceiver.shared.port;
     ffi.set_stream_port(port);
+    ffi.set_stream_protocol_version(notifyProtocolVersion);
 
     ffi.store_dart_post_cobject(NativeApi.postCObject);
     ffi.init_sdk(sdkDir.path.toNativeUtf8());
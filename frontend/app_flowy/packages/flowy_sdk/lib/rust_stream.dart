@@ -2,7 +2,9 @@ import 'dart:isolate';
 import 'dart:async';
 import 'dart:typed_data';
 import 'dart:ffi';
+import 'package:ffi/ffi.dart';
 import 'package:flowy_log/flowy_log.dart';
+import 'ffi.dart' as ffi;
 import 'protobuf/dart-notify/subject.pb.dart';
 
 typedef ObserverCallback = void Function(SubscribeObject observable);
@@ -34,6 +36,25 @@ class RustStreamReceiver {
     RustStreamReceiver.shared.observable.stream.listen(callback);
   }
 
+  // After the filter is turned on, only the notifications of the subscribed
+  // ids are sent by the rust side. Remember to subscribe to the user token
+  // too, the user and workspace list notifications are sent with it.
+  static void setFiltered(bool filtered) {
+    ffi.set_notification_filter(filtered ? 1 : 0);
+  }
+
+  static void subscribe(String id) {
+    final ptr = id.toNativeUtf8();
+    ffi.subscribe_notification(ptr);
+    malloc.free(ptr);
+  }
+
+  static void unsubscribe(String id) {
+    final ptr = id.toNativeUtf8();
+    ffi.unsubscribe_notification(ptr);
+    malloc.free(ptr);
+  }
+
   void streamCallback(Uint8List bytes) {
     try {
       final observable = SubscribeObject.fromBuffer(bytes);
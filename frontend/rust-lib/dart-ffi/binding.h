@@ -3,6 +3,10 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+typedef void (*EventCallback)(int64_t context, const uint8_t *data, uintptr_t len);
+
+typedef void (*NotificationCallback)(const uint8_t *data, uintptr_t len);
+
 int64_t init_sdk(char *path);
 
 void async_event(int64_t port, const uint8_t *input, uintptr_t len);
@@ -11,4 +15,16 @@ const uint8_t *sync_event(const uint8_t *input, uintptr_t len);
 
 int32_t set_stream_port(int64_t port);
 
-void link_me_please(void);
\ No newline at end of file
+int32_t set_stream_protocol_version(int32_t version);
+
+int32_t subscribe_notification(char *id);
+
+int32_t unsubscribe_notification(char *id);
+
+int32_t set_notification_filter(int32_t enabled);
+
+void link_me_please(void);
+
+void native_async_event(int64_t context, const uint8_t *input, uintptr_t len, EventCallback callback);
+
+int32_t native_set_notification_callback(NotificationCallback callback);
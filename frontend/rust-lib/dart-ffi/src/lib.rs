@@ -1,6 +1,7 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 mod c;
 mod model;
+mod native;
 mod protobuf;
 mod util;
 
@@ -62,10 +63,64 @@ pub extern "C" fn sync_event(input: *const u8, len: usize) -> *const u8 {
 
 #[no_mangle]
 pub extern "C" fn set_stream_port(port: i64) -> i32 {
+    // The subscriptions of the previous isolate are dropped with its port.
+    dart_notify::dart::DartSubscriptions::clear();
     dart_notify::dart::DartStreamSender::set_port(port);
     0
 }
 
+#[no_mangle]
+pub extern "C" fn set_stream_protocol_version(version: i32) -> i32 {
+    dart_notify::dart::DartStreamSender::set_protocol_version(version);
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn subscribe_notification(id: *mut c_char) -> i32 {
+    match notification_id(id) {
+        Some(id) => {
+            dart_notify::dart::DartSubscriptions::subscribe(id);
+            0
+        },
+        None => -1,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn unsubscribe_notification(id: *mut c_char) -> i32 {
+    match notification_id(id) {
+        Some(id) => {
+            dart_notify::dart::DartSubscriptions::unsubscribe(id);
+            0
+        },
+        None => -1,
+    }
+}
+
+// A panic can't unwind into dart, so the invalid ids are logged and reported
+// with the return code instead.
+fn notification_id<'a>(id: *mut c_char) -> Option<&'a str> {
+    if id.is_null() {
+        log::error!("[FFI]: The notification id is null");
+        return None;
+    }
+
+    let c_str: &CStr = unsafe { CStr::from_ptr(id) };
+    match c_str.to_str() {
+        Ok(id) => Some(id),
+        Err(e) => {
+            log::error!("[FFI]: The notification id is not valid utf-8: {:?}", e);
+            None
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn set_notification_filter(enabled: i32) -> i32 {
+    dart_notify::dart::DartSubscriptions::set_filtered(enabled != 0);
+    0
+}
+
 #[inline(never)]
 #[no_mangle]
 pub extern "C" fn link_me_please() {}
@@ -0,0 +1,35 @@
+use crate::{
+    dispatch,
+    model::{FFIRequest, FFIResponse},
+};
+use dart_notify::native::{NativeStreamSender, NotificationCallback};
+use lib_dispatch::prelude::*;
+
+/// Receives the bytes of the `FFIResponse` with the `context` that was passed
+/// to `native_async_event`. The bytes are only valid during the call.
+pub type EventCallback = extern "C" fn(context: i64, data: *const u8, len: usize);
+
+// The C ABI for the frontends that aren't built with flutter. The requests
+// and the responses are the same protobuf bytes as the dart channel, only
+// the responses are delivered through the callbacks instead of the ports.
+#[no_mangle]
+pub extern "C" fn native_async_event(context: i64, input: *const u8, len: usize, callback: EventCallback) {
+    let request: ModuleRequest = FFIRequest::from_u8_pointer(input, len).into();
+    log::trace!("[FFI]: {} Native Async Event: {:?}", &request.id, &request.event);
+
+    let _ = EventDispatcher::async_send_with_callback(dispatch(), request, move |resp: EventResponse| {
+        Box::pin(async move {
+            match FFIResponse::from(resp).into_bytes() {
+                Ok(bytes) => callback(context, bytes.as_ptr(), bytes.len()),
+                Err(e) => log::error!("[FFI]: Serialize native response failed: {:?}", e),
+            }
+        })
+    });
+}
+
+/// Passing a null callback stops posting the notifications.
+#[no_mangle]
+pub extern "C" fn native_set_notification_callback(callback: Option<NotificationCallback>) -> i32 {
+    NativeStreamSender::set_callback(callback);
+    0
+}
@@ -0,0 +1,227 @@
+use crate::{dart::DartStreamSender, entities::SubscribeObject, native::NativeStreamSender};
+use lazy_static::lazy_static;
+use std::{
+    collections::HashMap,
+    sync::{Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+// The notifications of the same entity sent within the window are merged into
+// the latest one.
+const COALESCE_WINDOW: Duration = Duration::from_millis(50);
+
+lazy_static! {
+    static ref PENDING_NOTIFICATIONS: (Mutex<PendingNotifications>, Condvar) =
+        (Mutex::new(PendingNotifications::default()), Condvar::new());
+}
+
+// The source, the type and the id of the notification.
+type NotificationKey = (String, i32, String);
+
+struct CoalesceWindow {
+    deadline: Instant,
+    held: Option<SubscribeObject>,
+}
+
+#[derive(Default)]
+struct PendingNotifications {
+    windows: HashMap<NotificationKey, CoalesceWindow>,
+    poster_started: bool,
+}
+
+impl PendingNotifications {
+    // The first notification of an entity is posted at once and opens the
+    // window, the ones sent during the window replace each other.
+    fn push(&mut self, subject: SubscribeObject, now: Instant) -> Option<SubscribeObject> {
+        let key = (subject.source.clone(), subject.ty, subject.id.clone());
+        match self.windows.get_mut(&key) {
+            Some(window) if now < window.deadline => {
+                window.held = Some(subject);
+                None
+            },
+            _ => {
+                let window = CoalesceWindow {
+                    deadline: now + COALESCE_WINDOW,
+                    held: None,
+                };
+                self.windows.insert(key, window);
+                Some(subject)
+            },
+        }
+    }
+
+    // The held notification that is posted at the end of a window opens the
+    // next one, so an entity that keeps changing is posted once per window.
+    fn take_due(&mut self, now: Instant) -> Vec<SubscribeObject> {
+        let mut due = vec![];
+        self.windows.retain(|_, window| {
+            if window.deadline > now {
+                return true;
+            }
+
+            match window.held.take() {
+                None => false,
+                Some(subject) => {
+                    due.push((window.deadline, subject));
+                    window.deadline = now + COALESCE_WINDOW;
+                    true
+                },
+            }
+        });
+        due.sort_by_key(|(deadline, _)| *deadline);
+        due.into_iter().map(|(_, subject)| subject).collect()
+    }
+
+    fn take_all(&mut self) -> Vec<SubscribeObject> {
+        let mut held = self
+            .windows
+            .drain()
+            .flat_map(|(_, window)| window.held.map(|subject| (window.deadline, subject)))
+            .collect::<Vec<_>>();
+        held.sort_by_key(|(deadline, _)| *deadline);
+        held.into_iter().map(|(_, subject)| subject).collect()
+    }
+
+    fn next_deadline(&self) -> Option<Instant> { self.windows.values().map(|window| window.deadline).min() }
+}
+
+/// Posts the notifications that are held in the windows, so none of them is
+/// lost when the app quits before the windows end.
+pub fn flush_notifications() {
+    let subjects = match PENDING_NOTIFICATIONS.0.lock() {
+        Ok(mut pending) => pending.take_all(),
+        Err(e) => {
+            log::error!("Get pending notifications lock fail. {:?}", e);
+            vec![]
+        },
+    };
+    subjects.into_iter().for_each(post);
+}
+
+// The anonymous notifications carry the ids of many entities in their payload,
+// so they are never merged.
+pub(crate) fn post_or_hold(subject: SubscribeObject) {
+    if subject.id.is_empty() {
+        post(subject);
+        return;
+    }
+
+    let (lock, condvar) = &*PENDING_NOTIFICATIONS;
+    let subject = match lock.lock() {
+        Ok(mut pending) => {
+            let subject = pending.push(subject, Instant::now());
+            if subject.is_some() && !pending.poster_started {
+                match std::thread::Builder::new()
+                    .name("dart-notify".to_owned())
+                    .spawn(post_held_notifications)
+                {
+                    Ok(_) => pending.poster_started = true,
+                    Err(e) => log::error!("Start the dart notify thread fail. {:?}", e),
+                }
+            }
+            condvar.notify_one();
+            subject
+        },
+        Err(e) => {
+            log::error!("Get pending notifications lock fail. {:?}", e);
+            Some(subject)
+        },
+    };
+
+    if let Some(subject) = subject {
+        post(subject);
+    }
+}
+
+fn post_held_notifications() {
+    let (lock, condvar) = &*PENDING_NOTIFICATIONS;
+    loop {
+        let mut pending = match lock.lock() {
+            Ok(pending) => pending,
+            Err(e) => {
+                log::error!("Get pending notifications lock fail. {:?}", e);
+                return;
+            },
+        };
+
+        let now = Instant::now();
+        let next_deadline = pending.next_deadline();
+        let poisoned = match next_deadline {
+            None => condvar.wait(pending).is_err(),
+            Some(deadline) if deadline > now => condvar.wait_timeout(pending, deadline - now).is_err(),
+            Some(_) => {
+                let subjects = pending.take_due(now);
+                drop(pending);
+                subjects.into_iter().for_each(post);
+                false
+            },
+        };
+
+        if poisoned {
+            log::error!("Wait for pending notifications fail.");
+            return;
+        }
+    }
+}
+
+fn post(subject: SubscribeObject) {
+    if let Err(error) = NativeStreamSender::post(&subject) {
+        log::error!("Send observable subject to native failed: {}", error);
+    }
+
+    match DartStreamSender::post(subject) {
+        Ok(_) => {},
+        Err(error) => log::error!("Send observable subject failed: {}", error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PendingNotifications, COALESCE_WINDOW};
+    use crate::entities::SubscribeObject;
+    use std::time::{Duration, Instant};
+
+    fn subject(ty: i32, id: &str, payload: u8) -> SubscribeObject {
+        SubscribeObject {
+            source: "Workspace".to_owned(),
+            ty,
+            id: id.to_owned(),
+            payload: Some(vec![payload]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn coalesce_within_window() {
+        let mut pending = PendingNotifications::default();
+        let now = Instant::now();
+        assert!(pending.push(subject(1, "view_1", 1), now).is_some());
+        assert!(pending.push(subject(1, "view_1", 2), now).is_none());
+        assert!(pending.push(subject(1, "view_1", 3), now).is_none());
+        assert!(pending.take_due(now).is_empty());
+
+        let subjects = pending.take_due(now + COALESCE_WINDOW);
+        assert_eq!(subjects.len(), 1);
+        assert_eq!(subjects[0].payload, Some(vec![3]));
+
+        // The window without the held notification is closed afterwards
+        assert!(pending.take_due(now + COALESCE_WINDOW * 2).is_empty());
+        assert!(pending.next_deadline().is_none());
+        assert!(pending
+            .push(subject(1, "view_1", 4), now + COALESCE_WINDOW * 2)
+            .is_some());
+    }
+
+    #[test]
+    fn coalesce_per_id_and_type() {
+        let mut pending = PendingNotifications::default();
+        let now = Instant::now();
+        assert!(pending.push(subject(1, "view_1", 1), now).is_some());
+        assert!(pending.push(subject(2, "view_1", 1), now).is_some());
+        assert!(pending.push(subject(1, "view_2", 1), now).is_some());
+        assert!(pending
+            .push(subject(1, "view_1", 2), now + Duration::from_millis(1))
+            .is_none());
+        assert_eq!(pending.take_all().len(), 1);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::entities::SubscribeObject;
+use crate::{entities::SubscribeObject, LEGACY_NOTIFY_PROTOCOL_VERSION};
 use bytes::Bytes;
 use lazy_static::lazy_static;
 use std::{convert::TryInto, sync::RwLock};
@@ -10,14 +10,23 @@ lazy_static! {
 pub struct DartStreamSender {
     #[allow(dead_code)]
     isolate: Option<allo_isolate::Isolate>,
+    protocol_version: i32,
 }
 
 impl DartStreamSender {
-    fn new() -> Self { Self { isolate: None } }
+    fn new() -> Self {
+        Self {
+            isolate: None,
+            protocol_version: LEGACY_NOTIFY_PROTOCOL_VERSION,
+        }
+    }
 
+    // The client declares its version after setting the port, so the new port
+    // starts from the legacy version.
     fn inner_set_port(&mut self, port: i64) {
         log::info!("Setup rust to flutter stream with port {}", port);
         self.isolate = Some(allo_isolate::Isolate::new(port));
+        self.protocol_version = LEGACY_NOTIFY_PROTOCOL_VERSION;
     }
 
     #[allow(dead_code)]
@@ -42,6 +51,26 @@ impl DartStreamSender {
         }
     }
 
+    pub fn set_protocol_version(version: i32) {
+        match DART_STREAM_SENDER.write() {
+            Ok(mut stream) => {
+                log::info!("Setup rust to flutter stream with protocol version {}", version);
+                stream.protocol_version = version;
+            },
+            Err(e) => {
+                let msg = format!("Get rust to flutter stream lock fail. {:?}", e);
+                log::error!("{:?}", msg);
+            },
+        }
+    }
+
+    pub fn protocol_version() -> i32 {
+        match DART_STREAM_SENDER.read() {
+            Ok(stream) => stream.protocol_version,
+            Err(_) => LEGACY_NOTIFY_PROTOCOL_VERSION,
+        }
+    }
+
     pub fn post(_observable_subject: SubscribeObject) -> Result<(), String> {
         #[cfg(feature = "dart")]
         match DART_STREAM_SENDER.read() {
@@ -0,0 +1,114 @@
+use lazy_static::lazy_static;
+use std::{collections::HashMap, sync::RwLock};
+
+lazy_static! {
+    static ref DART_SUBSCRIPTIONS: RwLock<DartSubscriptions> = RwLock::new(DartSubscriptions::new());
+}
+
+// The subscriptions are counted per id, because the client may listen to the
+// same workspace, app or view from many places. The notifications are only
+// filtered by the subscriptions after the client turned the filter on, the
+// listeners that never subscribed keep receiving all of them otherwise. The
+// anonymous notifications are never filtered.
+pub struct DartSubscriptions {
+    filtered: bool,
+    counts: HashMap<String, usize>,
+}
+
+impl DartSubscriptions {
+    fn new() -> Self {
+        Self {
+            filtered: false,
+            counts: HashMap::new(),
+        }
+    }
+
+    fn inner_subscribe(&mut self, id: &str) { *self.counts.entry(id.to_owned()).or_insert(0) += 1; }
+
+    fn inner_unsubscribe(&mut self, id: &str) {
+        if let Some(count) = self.counts.get_mut(id) {
+            *count -= 1;
+            if *count == 0 {
+                self.counts.remove(id);
+            }
+        }
+    }
+
+    fn inner_clear(&mut self) {
+        self.filtered = false;
+        self.counts.clear();
+    }
+
+    fn inner_is_subscribed(&self, id: &str) -> bool { id.is_empty() || !self.filtered || self.counts.contains_key(id) }
+
+    pub fn set_filtered(filtered: bool) {
+        match DART_SUBSCRIPTIONS.write() {
+            Ok(mut subscriptions) => subscriptions.filtered = filtered,
+            Err(e) => log::error!("Get dart subscriptions lock fail. {:?}", e),
+        }
+    }
+
+    pub fn subscribe(id: &str) {
+        match DART_SUBSCRIPTIONS.write() {
+            Ok(mut subscriptions) => subscriptions.inner_subscribe(id),
+            Err(e) => log::error!("Get dart subscriptions lock fail. {:?}", e),
+        }
+    }
+
+    pub fn unsubscribe(id: &str) {
+        match DART_SUBSCRIPTIONS.write() {
+            Ok(mut subscriptions) => subscriptions.inner_unsubscribe(id),
+            Err(e) => log::error!("Get dart subscriptions lock fail. {:?}", e),
+        }
+    }
+
+    pub fn clear() {
+        match DART_SUBSCRIPTIONS.write() {
+            Ok(mut subscriptions) => subscriptions.inner_clear(),
+            Err(e) => log::error!("Get dart subscriptions lock fail. {:?}", e),
+        }
+    }
+
+    pub fn is_subscribed(id: &str) -> bool {
+        match DART_SUBSCRIPTIONS.read() {
+            Ok(subscriptions) => subscriptions.inner_is_subscribed(id),
+            Err(e) => {
+                log::error!("Get dart subscriptions lock fail. {:?}", e);
+                true
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DartSubscriptions;
+
+    #[test]
+    fn subscriptions_unfiltered_by_default() {
+        let mut subscriptions = DartSubscriptions::new();
+        subscriptions.inner_subscribe("view_1");
+        assert!(subscriptions.inner_is_subscribed("view_1"));
+        assert!(subscriptions.inner_is_subscribed("view_2"));
+    }
+
+    #[test]
+    fn subscriptions_filtered() {
+        let mut subscriptions = DartSubscriptions::new();
+        subscriptions.filtered = true;
+        assert!(!subscriptions.inner_is_subscribed("view_1"));
+        assert!(subscriptions.inner_is_subscribed(""));
+
+        subscriptions.inner_subscribe("view_1");
+        subscriptions.inner_subscribe("view_1");
+        subscriptions.inner_unsubscribe("view_1");
+        assert!(subscriptions.inner_is_subscribed("view_1"));
+        assert!(!subscriptions.inner_is_subscribed("view_2"));
+
+        subscriptions.inner_unsubscribe("view_1");
+        assert!(!subscriptions.inner_is_subscribed("view_1"));
+
+        subscriptions.inner_clear();
+        assert!(subscriptions.inner_is_subscribed("view_2"));
+    }
+}
@@ -0,0 +1,13 @@
+use flowy_derive::ProtoBuf;
+
+// The payload of the notification that stands for the change of many
+// entities, so the client can refresh them at once.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct BatchedChange {
+    #[pb(index = 1)]
+    pub ids: Vec<String>,
+}
+
+impl std::convert::From<Vec<String>> for BatchedChange {
+    fn from(ids: Vec<String>) -> Self { BatchedChange { ids } }
+}
@@ -1,3 +1,5 @@
+mod batch;
 mod subject;
 
+pub use batch::*;
 pub use subject::*;
@@ -17,6 +17,9 @@ pub struct SubscribeObject {
 
     #[pb(index = 5, one_of)]
     pub error: Option<Vec<u8>>,
+
+    #[pb(index = 6)]
+    pub version: i32,
 }
 
 impl std::fmt::Display for SubscribeObject {
@@ -42,6 +45,7 @@ impl std::default::Default for SubscribeObject {
             id: "".to_string(),
             payload: None,
             error: None,
+            version: 0,
         }
     }
 }
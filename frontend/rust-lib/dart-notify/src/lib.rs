@@ -1,15 +1,30 @@
 use bytes::Bytes;
 
+mod coalesce;
 pub mod dart;
 pub mod entities;
+pub mod native;
 mod protobuf;
 
-use crate::{dart::DartStreamSender, entities::SubscribeObject};
+pub use coalesce::flush_notifications;
+
+use crate::{
+    coalesce::post_or_hold,
+    dart::{DartStreamSender, DartSubscriptions},
+    entities::SubscribeObject,
+};
 use lib_dispatch::prelude::ToBytes;
 
+// The version of the notification payloads. The clients that never declare
+// their version are treated as version 1, the one before the payloads were
+// versioned.
+pub const NOTIFY_PROTOCOL_VERSION: i32 = 2;
+pub const LEGACY_NOTIFY_PROTOCOL_VERSION: i32 = 1;
+
 pub struct DartNotifyBuilder {
     id: String,
     payload: Option<Bytes>,
+    downgrades: Vec<(i32, Bytes)>,
     error: Option<Bytes>,
     source: String,
     ty: i32,
@@ -21,6 +36,7 @@ impl DartNotifyBuilder {
             id: id.to_owned(),
             ty: ty.into(),
             payload: None,
+            downgrades: vec![],
             error: None,
             source: source.to_owned(),
         }
@@ -40,6 +56,22 @@ impl DartNotifyBuilder {
         self
     }
 
+    /// Sets the payload for the clients of the `version` or older, used when
+    /// the payload of the notification is changed in a way that the older
+    /// clients can't deserialize.
+    pub fn downgrade_payload<T>(mut self, version: i32, payload: T) -> Self
+    where
+        T: ToBytes,
+    {
+        match payload.into_bytes() {
+            Ok(bytes) => self.downgrades.push((version, bytes)),
+            Err(e) => {
+                log::error!("Set observable downgrade payload failed: {:?}", e);
+            },
+        }
+        self
+    }
+
     pub fn error<T>(mut self, error: T) -> Self
     where
         T: ToBytes,
@@ -54,21 +86,79 @@ impl DartNotifyBuilder {
     }
 
     pub fn send(self) {
-        let payload = self.payload.map(|bytes| bytes.to_vec());
+        if !DartSubscriptions::is_subscribed(&self.id) {
+            return;
+        }
 
-        let error = self.error.map(|bytes| bytes.to_vec());
+        post_or_hold(self.build(DartStreamSender::protocol_version()));
+    }
 
-        let subject = SubscribeObject {
+    fn build(self, client_version: i32) -> SubscribeObject {
+        let (version, payload) = select_payload(client_version, self.payload, self.downgrades);
+        SubscribeObject {
             source: self.source,
             ty: self.ty,
             id: self.id,
-            payload,
-            error,
-        };
+            payload: payload.map(|bytes| bytes.to_vec()),
+            version,
+            error: self.error.map(|bytes| bytes.to_vec()),
+        }
+    }
+}
 
-        match DartStreamSender::post(subject) {
-            Ok(_) => {},
-            Err(error) => log::error!("Send observable subject failed: {}", error),
+// Picks the closest downgrade that the client understands, the payload is
+// sent as is if the notification has no downgrade for the client.
+fn select_payload(
+    client_version: i32,
+    payload: Option<Bytes>,
+    mut downgrades: Vec<(i32, Bytes)>,
+) -> (i32, Option<Bytes>) {
+    if client_version < NOTIFY_PROTOCOL_VERSION {
+        downgrades.sort_by_key(|(version, _)| *version);
+        if let Some((version, bytes)) = downgrades.into_iter().find(|(version, _)| *version >= client_version) {
+            return (version, Some(bytes));
         }
     }
+    (NOTIFY_PROTOCOL_VERSION, payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entities::BatchedChange, DartNotifyBuilder, LEGACY_NOTIFY_PROTOCOL_VERSION, NOTIFY_PROTOCOL_VERSION};
+    use bytes::Bytes;
+    use std::convert::TryInto;
+
+    #[test]
+    fn notification_versioned() {
+        let change = BatchedChange::from(vec!["view_2".to_owned()]);
+        let bytes: Bytes = change.clone().try_into().unwrap();
+        let subject = DartNotifyBuilder::new("view_1", 31, "Workspace")
+            .payload(change)
+            .build(NOTIFY_PROTOCOL_VERSION);
+        assert_eq!(subject.version, NOTIFY_PROTOCOL_VERSION);
+        assert_eq!(subject.id, "view_1");
+        assert_eq!(subject.ty, 31);
+        assert_eq!(subject.payload, Some(bytes.to_vec()));
+        assert_eq!(subject.error, None);
+    }
+
+    #[test]
+    fn notification_downgraded_for_legacy_client() {
+        let change = BatchedChange::from(vec!["view_2".to_owned()]);
+        let legacy_change = BatchedChange::from(vec!["view_3".to_owned()]);
+        let legacy_bytes: Bytes = legacy_change.clone().try_into().unwrap();
+        let builder = || {
+            DartNotifyBuilder::new("view_1", 31, "Workspace")
+                .payload(change.clone())
+                .downgrade_payload(LEGACY_NOTIFY_PROTOCOL_VERSION, legacy_change.clone())
+        };
+
+        let subject = builder().build(LEGACY_NOTIFY_PROTOCOL_VERSION);
+        assert_eq!(subject.version, LEGACY_NOTIFY_PROTOCOL_VERSION);
+        assert_eq!(subject.payload, Some(legacy_bytes.to_vec()));
+
+        let subject = builder().build(NOTIFY_PROTOCOL_VERSION);
+        assert_eq!(subject.version, NOTIFY_PROTOCOL_VERSION);
+        assert_ne!(subject.payload, Some(legacy_bytes.to_vec()));
+    }
 }
@@ -0,0 +1,43 @@
+use crate::entities::SubscribeObject;
+use bytes::Bytes;
+use lazy_static::lazy_static;
+use std::{convert::TryInto, sync::RwLock};
+
+/// Receives the bytes of the `SubscribeObject`. The bytes are only valid
+/// during the call, the receiver must copy them if it keeps them.
+pub type NotificationCallback = extern "C" fn(data: *const u8, len: usize);
+
+lazy_static! {
+    static ref NATIVE_STREAM_SENDER: RwLock<NativeStreamSender> = RwLock::new(NativeStreamSender { callback: None });
+}
+
+// Posts the notifications to the frontends that embed the library through
+// the C ABI instead of the dart isolate.
+pub struct NativeStreamSender {
+    callback: Option<NotificationCallback>,
+}
+
+impl NativeStreamSender {
+    pub fn set_callback(callback: Option<NotificationCallback>) {
+        match NATIVE_STREAM_SENDER.write() {
+            Ok(mut sender) => sender.callback = callback,
+            Err(e) => log::error!("Get native stream lock fail. {:?}", e),
+        }
+    }
+
+    pub fn post(observable_subject: &SubscribeObject) -> Result<(), String> {
+        let callback = match NATIVE_STREAM_SENDER.read() {
+            Ok(sender) => sender.callback,
+            Err(e) => return Err(format!("Get native stream lock fail. {:?}", e)),
+        };
+
+        if let Some(callback) = callback {
+            let bytes: Bytes = observable_subject
+                .clone()
+                .try_into()
+                .map_err(|e| format!("Serialize observable subject fail. {:?}", e))?;
+            callback(bytes.as_ptr(), bytes.len());
+        }
+        Ok(())
+    }
+}
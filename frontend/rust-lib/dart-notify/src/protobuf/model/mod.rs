@@ -3,3 +3,6 @@
 
 mod subject;
 pub use subject::*;
+
+mod batch;
+pub use batch::*;
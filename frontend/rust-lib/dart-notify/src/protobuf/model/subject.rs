@@ -29,6 +29,7 @@ pub struct SubscribeObject {
     pub source: ::std::string::String,
     pub ty: i32,
     pub id: ::std::string::String,
+    pub version: i32,
     // message oneof groups
     pub one_of_payload: ::std::option::Option<SubscribeObject_oneof_one_of_payload>,
     pub one_of_error: ::std::option::Option<SubscribeObject_oneof_one_of_error>,
@@ -222,6 +223,21 @@ impl SubscribeObject {
             ::std::vec::Vec::new()
         }
     }
+
+    // int32 version = 6;
+
+
+    pub fn get_version(&self) -> i32 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: i32) {
+        self.version = v;
+    }
 }
 
 impl ::protobuf::Message for SubscribeObject {
@@ -258,6 +274,13 @@ impl ::protobuf::Message for SubscribeObject {
                     }
                     self.one_of_error = ::std::option::Option::Some(SubscribeObject_oneof_one_of_error::error(is.read_bytes()?));
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.version = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -279,6 +302,9 @@ impl ::protobuf::Message for SubscribeObject {
         if !self.id.is_empty() {
             my_size += ::protobuf::rt::string_size(3, &self.id);
         }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
         if let ::std::option::Option::Some(ref v) = self.one_of_payload {
             match v {
                 &SubscribeObject_oneof_one_of_payload::payload(ref v) => {
@@ -308,6 +334,9 @@ impl ::protobuf::Message for SubscribeObject {
         if !self.id.is_empty() {
             os.write_string(3, &self.id)?;
         }
+        if self.version != 0 {
+            os.write_int32(6, self.version)?;
+        }
         if let ::std::option::Option::Some(ref v) = self.one_of_payload {
             match v {
                 &SubscribeObject_oneof_one_of_payload::payload(ref v) => {
@@ -385,6 +414,11 @@ impl ::protobuf::Message for SubscribeObject {
                 SubscribeObject::has_error,
                 SubscribeObject::get_error,
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "version",
+                |m: &SubscribeObject| { &m.version },
+                |m: &mut SubscribeObject| { &mut m.version },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<SubscribeObject>(
                 "SubscribeObject",
                 fields,
@@ -406,6 +440,7 @@ impl ::protobuf::Clear for SubscribeObject {
         self.id.clear();
         self.one_of_payload = ::std::option::Option::None;
         self.one_of_error = ::std::option::Option::None;
+        self.version = 0;
         self.unknown_fields.clear();
     }
 }
@@ -423,29 +458,13 @@ impl ::protobuf::reflect::ProtobufValue for SubscribeObject {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\rsubject.proto\"\x9f\x01\n\x0fSubscribeObject\x12\x16\n\x06source\x18\
-    \x01\x20\x01(\tR\x06source\x12\x0e\n\x02ty\x18\x02\x20\x01(\x05R\x02ty\
-    \x12\x0e\n\x02id\x18\x03\x20\x01(\tR\x02id\x12\x1a\n\x07payload\x18\x04\
-    \x20\x01(\x0cH\0R\x07payload\x12\x16\n\x05error\x18\x05\x20\x01(\x0cH\
-    \x01R\x05errorB\x10\n\x0eone_of_payloadB\x0e\n\x0cone_of_errorJ\xf3\x02\
-    \n\x06\x12\x04\0\0\x08\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\
-    \0\x12\x04\x02\0\x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x17\n\x0b\n\
-    \x04\x04\0\x02\0\x12\x03\x03\x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
-    \x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x11\n\x0c\n\x05\
-    \x04\0\x02\0\x03\x12\x03\x03\x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\
-    \x04\x04\x11\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\t\n\x0c\n\x05\
-    \x04\0\x02\x01\x01\x12\x03\x04\n\x0c\n\x0c\n\x05\x04\0\x02\x01\x03\x12\
-    \x03\x04\x0f\x10\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x12\n\x0c\n\
-    \x05\x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\
-    \x12\x03\x05\x0b\r\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x10\x11\n\
-    \x0b\n\x04\x04\0\x08\0\x12\x03\x06\x04/\n\x0c\n\x05\x04\0\x08\0\x01\x12\
-    \x03\x06\n\x18\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x1b-\n\x0c\n\x05\
-    \x04\0\x02\x03\x05\x12\x03\x06\x1b\x20\n\x0c\n\x05\x04\0\x02\x03\x01\x12\
-    \x03\x06!(\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06+,\n\x0b\n\x04\x04\0\
-    \x08\x01\x12\x03\x07\x04+\n\x0c\n\x05\x04\0\x08\x01\x01\x12\x03\x07\n\
-    \x16\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x19)\n\x0c\n\x05\x04\0\x02\
-    \x04\x05\x12\x03\x07\x19\x1e\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\
-    \x1f$\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07'(b\x06proto3\
+    \n\rsubject.proto\"\xc7\x01\n\x0fSubscribeObject\x12\x18\n\x06source\x18\
+    \x01\x20\x01(\tR\x06sourceB\0\x12\x10\n\x02ty\x18\x02\x20\x01(\x05R\x02t\
+    yB\0\x12\x10\n\x02id\x18\x03\x20\x01(\tR\x02idB\0\x12\x1c\n\x07payload\
+    \x18\x04\x20\x01(\x0cH\0R\x07payloadB\0\x12\x18\n\x05error\x18\x05\x20\
+    \x01(\x0cH\x01R\x05errorB\0\x12\x1a\n\x07version\x18\x06\x20\x01(\x05R\
+    \x07versionB\0B\x10\n\x0eone_of_payloadB\x0e\n\x0cone_of_error:\0B\0b\
+    \x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,97 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use flowy_core::entities::app::QueryAppRequest;
+use flowy_test::{helper::*, FlowySDKTest};
+use tokio::runtime::Runtime;
+
+// The sizes are kept small enough for the whole suite to finish in a few
+// minutes, the largest one is where the regressions usually show up.
+const VIEW_COUNTS: &[usize] = &[10, 100, 500];
+const REVISION_COUNTS: &[usize] = &[10, 100, 500];
+const BULK_VIEW_COUNTS: &[usize] = &[10, 50];
+
+fn folder_open(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("folder_open");
+    for &count in VIEW_COUNTS {
+        let (sdk, app_id) = rt.block_on(async {
+            let sdk = FlowySDKTest::default();
+            let _ = sdk.init_user().await;
+            let test = ViewTest::new(&sdk).await;
+            for _ in 1..count {
+                let _ = create_view(&sdk, &test.app.id).await;
+            }
+            (sdk, test.app.id)
+        });
+
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| {
+            b.iter(|| {
+                let request = QueryAppRequest {
+                    app_ids: vec![app_id.clone()],
+                };
+                rt.block_on(read_app(&sdk, request))
+            })
+        });
+    }
+    group.finish();
+}
+
+// Every iteration closes the document, so the revisions are read from the
+// disk and composed again when it's opened.
+fn document_open(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("document_open");
+    for &count in REVISION_COUNTS {
+        let (sdk, view_id) = rt.block_on(async {
+            let sdk = FlowySDKTest::default();
+            let _ = sdk.init_user().await;
+            let test = ViewTest::new(&sdk).await;
+            let editor = sdk.document_ctx.controller.open_document(&test.view.id).await.unwrap();
+            for index in 0..count {
+                editor.insert(index, "a").await.unwrap();
+            }
+            sdk.flush().await.unwrap();
+            (sdk, test.view.id)
+        });
+
+        let controller = sdk.document_ctx.controller.clone();
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| {
+            b.iter(|| {
+                controller.close_document(&view_id).unwrap();
+                rt.block_on(controller.open_document(&view_id)).unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+// Creates the views of an app one after another, it's the write path that the
+// bulk operations go through.
+fn bulk_create_views(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let sdk = FlowySDKTest::default();
+    let test = rt.block_on(async {
+        let _ = sdk.init_user().await;
+        ViewTest::new(&sdk).await
+    });
+
+    let mut group = c.benchmark_group("bulk_create_views");
+    for &count in BULK_VIEW_COUNTS {
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter_batched(
+                || rt.block_on(create_app(&sdk, "App", "", &test.workspace.id)),
+                |app| {
+                    rt.block_on(async {
+                        for _ in 0..count {
+                            let _ = create_view(&sdk, &app.id).await;
+                        }
+                    })
+                },
+                BatchSize::PerIteration,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, folder_open, document_open, bulk_create_views);
+criterion_main!(benches);
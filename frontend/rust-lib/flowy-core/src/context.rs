@@ -1,25 +1,39 @@
 use std::{collections::HashMap, sync::Arc};
 
-use chrono::Utc;
-use lazy_static::lazy_static;
+use futures::Stream;
 use parking_lot::RwLock;
+use tokio::sync::Mutex;
 
 use flowy_collaboration::document::default::{initial_delta, initial_read_me};
 use flowy_core_data_model::{entities::view::CreateViewParams, user_default};
-use flowy_net::entities::NetworkType;
+use flowy_database::slow_log::SlowLogTransaction;
+use flowy_net::{entities::NetworkType, services::ws_conn::WSConnectState};
+use lib_infra::{clock::RuntimeEnv, metrics};
 
 use crate::{
-    entities::workspace::RepeatedWorkspace,
-    errors::{FlowyError, FlowyResult},
+    entities::{diagnostics::HealthReport, workspace::RepeatedWorkspace},
+    errors::{internal_error, FlowyError, FlowyResult},
+    folder_change::{FolderChange, FolderChangeNotifier},
     module::{WorkspaceDatabase, WorkspaceUser},
     notify::{send_dart_notification, WorkspaceNotification},
-    services::{server::Server, AppController, TrashController, ViewController, WorkspaceController},
+    services::{
+        cache::invalidate_folder_cache,
+        diagnostics::health::*,
+        server::Server,
+        workspace::{folder_sync::FolderSyncer, skeleton::invalidate_workspace_skeleton, sql::WorkspaceTableSql},
+        AppController,
+        AuditLogController,
+        BackupController,
+        CloudOutbox,
+        EventLogController,
+        OperationJournal,
+        TrashController,
+        ViewController,
+        WebhookController,
+        WorkspaceController,
+    },
 };
 
-lazy_static! {
-    static ref INIT_WORKSPACE: RwLock<HashMap<String, bool>> = RwLock::new(HashMap::new());
-}
-
 pub struct CoreContext {
     pub user: Arc<dyn WorkspaceUser>,
     pub(crate) server: Server,
@@ -28,6 +42,20 @@ pub struct CoreContext {
     pub(crate) app_controller: Arc<AppController>,
     pub(crate) view_controller: Arc<ViewController>,
     pub(crate) trash_controller: Arc<TrashController>,
+    pub(crate) webhook_controller: Arc<WebhookController>,
+    pub(crate) event_log_controller: Arc<EventLogController>,
+    pub(crate) audit_log_controller: Arc<AuditLogController>,
+    pub(crate) backup_controller: Arc<BackupController>,
+    folder_syncer: Arc<FolderSyncer>,
+    journal: Arc<OperationJournal>,
+    outbox: Arc<CloudOutbox>,
+    folder_change_notifier: FolderChangeNotifier,
+    pub(crate) env: RuntimeEnv,
+    ws_state: RwLock<WSConnectState>,
+    // The gates of the users whose workspace was initialized, keyed by the
+    // user id. The callers that come while the workspace is being initialized
+    // wait on the gate until it's done.
+    init_gates: RwLock<HashMap<String, Arc<Mutex<bool>>>>,
 }
 
 impl CoreContext {
@@ -39,11 +67,16 @@ impl CoreContext {
         app_controller: Arc<AppController>,
         view_controller: Arc<ViewController>,
         trash_controller: Arc<TrashController>,
+        webhook_controller: Arc<WebhookController>,
+        event_log_controller: Arc<EventLogController>,
+        audit_log_controller: Arc<AuditLogController>,
+        backup_controller: Arc<BackupController>,
+        folder_syncer: Arc<FolderSyncer>,
+        journal: Arc<OperationJournal>,
+        outbox: Arc<CloudOutbox>,
+        folder_change_notifier: FolderChangeNotifier,
+        env: RuntimeEnv,
     ) -> Self {
-        if let Ok(token) = user.token() {
-            INIT_WORKSPACE.write().insert(token, false);
-        }
-
         Self {
             user,
             server,
@@ -52,26 +85,119 @@ impl CoreContext {
             app_controller,
             view_controller,
             trash_controller,
+            webhook_controller,
+            event_log_controller,
+            audit_log_controller,
+            backup_controller,
+            folder_syncer,
+            journal,
+            outbox,
+            folder_change_notifier,
+            env,
+            ws_state: RwLock::new(WSConnectState::Init),
+            init_gates: RwLock::new(HashMap::new()),
         }
     }
 
+    // The calls that couldn't reach the server are replayed whenever it may
+    // be reachable again.
     pub fn network_state_changed(&self, new_type: NetworkType) {
         match new_type {
             NetworkType::UnknownNetworkType => {},
-            NetworkType::Wifi => {},
-            NetworkType::Cell => {},
-            NetworkType::Ethernet => {},
+            NetworkType::Wifi | NetworkType::Cell | NetworkType::Ethernet => self.outbox.spawn_replay(),
+        }
+    }
+
+    pub fn ws_state_changed(&self, state: WSConnectState) {
+        if state == WSConnectState::Connected {
+            self.outbox.spawn_replay();
         }
+        *self.ws_state.write() = state;
     }
 
-    pub async fn user_did_sign_in(&self, token: &str) -> FlowyResult<()> {
+    /// The changes of the workspaces, the apps and the views that are made
+    /// after the subscription.
+    pub fn subscribe_folder_changes(&self) -> impl Stream<Item = FolderChange> {
+        self.folder_change_notifier.subscribe()
+    }
+
+    // Runs every check even if one of them fails, so the report shows all the
+    // broken parts at once.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn health_check(&self) -> HealthReport {
+        let is_initialized = match self.user.user_id() {
+            Ok(user_id) => self.is_initialized(&user_id),
+            Err(_) => false,
+        };
+        let ws_state = self.ws_state.read().clone();
+        let pending_revisions = metrics::gauge(metrics::SYNC_QUEUE_DEPTH);
+        let checks = vec![
+            check_database(&self.database),
+            check_workspace(is_initialized),
+            check_web_socket(&ws_state),
+            check_server(&self.server).await,
+            check_sync_queue(pending_revisions),
+        ];
+        HealthReport::new(checks, pending_revisions, self.env.timestamp())
+    }
+
+    // Signing in with another account doesn't sign out the previous one, so
+    // what is left of the other users is reset first.
+    pub async fn user_did_sign_in(&self, _token: &str) -> FlowyResult<()> {
         log::debug!("workspace initialize after sign in");
-        let _ = self.init(token).await?;
+        let user_id = self.user.user_id()?;
+        let other_user_ids = self
+            .init_gates
+            .read()
+            .keys()
+            .filter(|id| **id != user_id)
+            .cloned()
+            .collect::<Vec<String>>();
+        for other_user_id in other_user_ids {
+            self.reset(&other_user_id).await;
+        }
+        let _ = self.init().await?;
         Ok(())
     }
 
-    pub async fn user_did_logout(&self) {
-        // TODO: (nathan) do something here
+    // The user may be signed out by the server, e.g. the session was revoked from
+    // another device, so the opened documents are closed before they try to sync
+    // with the invalid token.
+    pub async fn user_did_logout(&self, user_id: &str) { self.reset(user_id).await; }
+
+    // Tears down what the controllers kept for the user, so the workspace is
+    // initialized again from the database of the next user that signs in,
+    // even if it's the same one.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn reset(&self, user_id: &str) {
+        // The documents would be saved to the database of the other user once
+        // another account signed in, their unsaved revisions are dropped.
+        let is_other_user = matches!(self.user.user_id(), Ok(current) if current != user_id);
+        if !is_other_user {
+            if let Err(e) = self.view_controller.flush_views().await {
+                tracing::error!("Save the documents before reset failed: {}", e);
+            }
+        }
+        self.view_controller.close_all_views();
+        self.webhook_controller.reset();
+        invalidate_workspace_skeleton();
+        invalidate_folder_cache();
+        self.init_gates.write().remove(user_id);
+    }
+
+    // Saves what is pending before the app quits: the revisions of the open
+    // documents are flushed to the disk, the documents are closed, the held
+    // notifications are posted and the database is closed. The documents are
+    // closed even if the flush fails, the error is returned afterwards.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn shutdown(&self) -> FlowyResult<()> {
+        let flushed = self.view_controller.flush_views().await;
+        self.view_controller.close_all_views();
+        dart_notify::flush_notifications();
+        if let Err(e) = self.database.close() {
+            tracing::error!("Close the database before shutdown failed: {}", e);
+        }
+        flushed
     }
 
     pub async fn user_session_expired(&self) {
@@ -80,39 +206,109 @@ impl CoreContext {
 
     pub async fn user_did_sign_up(&self, _token: &str) -> FlowyResult<()> {
         log::debug!("Create user default workspace");
-        let time = Utc::now();
-        let mut workspace = user_default::create_default_workspace(time);
-        let apps = workspace.take_apps().into_inner();
-        let cloned_workspace = workspace.clone();
-
-        let _ = self.workspace_controller.create_workspace_on_local(workspace).await?;
-        for mut app in apps {
-            let app_id = app.id.clone();
-            let views = app.take_belongings().into_inner();
-            let _ = self.app_controller.create_app_on_local(app).await?;
-            for (index, view) in views.into_iter().enumerate() {
-                let view_data = if index == 0 {
-                    initial_read_me().to_json()
-                } else {
-                    initial_delta().to_json()
-                };
-                self.view_controller.set_latest_view(&view);
-                let params = CreateViewParams {
-                    belong_to_id: app_id.clone(),
-                    name: view.name,
-                    desc: view.desc,
-                    thumbnail: "".to_string(),
-                    view_type: view.view_type,
-                    view_data,
-                    view_id: view.id.clone(),
-                };
-                let _ = self.view_controller.create_view_from_params(params).await?;
+        self.create_default_workspace(false).await
+    }
+
+    pub async fn user_did_sign_up_anonymously(&self, _token: &str) -> FlowyResult<()> {
+        log::debug!("Create anonymous user default workspace");
+        self.create_default_workspace(true).await
+    }
+
+    // The anonymous user's database was moved to the new user's directory before
+    // this gets called, so only the ownership of the workspaces needs to be
+    // rewritten. The folder is uploaded with the ids it has on this device:
+    // the workspaces first, then their apps and the views in one batch.
+    // Unsynced document revisions are pushed by the document web socket once
+    // it's connected.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn user_did_upgrade_anonymous(&self, old_user_id: &str, token: &str) -> FlowyResult<()> {
+        let user_id = self.user.user_id()?;
+        {
+            let conn = &*self.database.db_connection()?;
+            conn.timed_transaction::<_, FlowyError, _>("core.user_did_upgrade_anonymous", || {
+                let _ = WorkspaceTableSql::transfer_workspaces(old_user_id, &user_id, conn)?;
+                Ok(())
+            })?;
+        }
+
+        // The workspace of the anonymous user is the one of the new user now.
+        self.init_gates.write().remove(old_user_id);
+        let _ = self.init().await?;
+        let apps = self.workspace_controller.upload_local_workspaces().await?;
+        let _ = self.view_controller.upload_local_folder(apps).await?;
+
+        let conn = self.database.db_connection()?;
+        let repeated_workspace = self
+            .workspace_controller
+            .read_local_workspaces(None, &user_id, &*conn)?;
+        send_dart_notification(token, WorkspaceNotification::WorkspaceListUpdated)
+            .payload(repeated_workspace)
+            .send();
+        Ok(())
+    }
+
+    // The new user starts with the workspaces of the backup that was selected
+    // before the sign up, e.g. of another device, or with the default one. A
+    // backup that can't be restored falls back to the default workspace, the
+    // account exists already.
+    async fn create_default_workspace(&self, local_only: bool) -> FlowyResult<()> {
+        let time = self.env.clock.now();
+        let backup_controller = self.backup_controller.clone();
+        let restored = tokio::task::spawn_blocking(move || backup_controller.take_restore())
+            .await
+            .map_err(internal_error)?;
+        let (workspaces, mut documents) = match restored {
+            None => (vec![user_default::create_default_workspace(time)], HashMap::new()),
+            Some(Ok(folder)) => (folder.workspaces, folder.documents),
+            Some(Err(e)) => {
+                tracing::error!("Restore the backup failed: {}", e);
+                let token = self.user.token()?;
+                send_dart_notification(&token, WorkspaceNotification::BackupRestoreFailed)
+                    .error(e)
+                    .send();
+                (vec![user_default::create_default_workspace(time)], HashMap::new())
+            },
+        };
+
+        let mut cloned_workspaces = vec![];
+        for mut workspace in workspaces {
+            let apps = workspace.take_apps().into_inner();
+            cloned_workspaces.push(workspace.clone());
+
+            let _ = self.workspace_controller.create_workspace_on_local(workspace).await?;
+            for mut app in apps {
+                let views = app.take_belongings().into_inner();
+                let _ = self.app_controller.create_app_on_local(app).await?;
+                // The restored views are flattened, a view that belongs to
+                // another one comes after it.
+                for (index, view) in views.into_iter().enumerate() {
+                    let view_data = match documents.remove(&view.id) {
+                        Some(view_data) => view_data,
+                        None if index == 0 => initial_read_me().to_json(),
+                        None => initial_delta().to_json(),
+                    };
+                    self.view_controller.set_latest_view(&view);
+                    let params = CreateViewParams {
+                        belong_to_id: view.belong_to_id.clone(),
+                        name: view.name,
+                        desc: view.desc,
+                        thumbnail: "".to_string(),
+                        view_type: view.view_type,
+                        view_data,
+                        view_id: view.id.clone(),
+                    };
+                    if local_only {
+                        let _ = self.view_controller.create_local_view_from_params(params).await?;
+                    } else {
+                        let _ = self.view_controller.create_view_from_params(params).await?;
+                    }
+                }
             }
         }
 
         let token = self.user.token()?;
         let repeated_workspace = RepeatedWorkspace {
-            items: vec![cloned_workspace],
+            items: cloned_workspaces,
         };
 
         send_dart_notification(&token, WorkspaceNotification::UserCreateWorkspace)
@@ -120,22 +316,90 @@ impl CoreContext {
             .send();
 
         tracing::debug!("Create default workspace after sign up");
-        let _ = self.init(&token).await?;
+        let _ = self.init().await?;
         Ok(())
     }
 
-    async fn init(&self, token: &str) -> Result<(), FlowyError> {
-        if let Some(is_init) = INIT_WORKSPACE.read().get(token) {
-            if *is_init {
-                return Ok(());
-            }
+    // Waits for the workspace to be initialized, the handlers that run right
+    // after the user signed in may come before the initialization finished.
+    pub(crate) async fn wait_for_init(&self) -> FlowyResult<()> { self.init().await }
+
+    async fn init(&self) -> Result<(), FlowyError> {
+        let gate = self.init_gate(&self.user.user_id()?);
+        let mut is_init = gate.lock().await;
+        if *is_init {
+            return Ok(());
         }
-        INIT_WORKSPACE.write().insert(token.to_owned(), true);
+        *is_init = true;
+
+        // The controllers only subscribe to the trash events here, it's cheap
+        // enough to do it in order. The slow work is deferred.
         let _ = self.workspace_controller.init()?;
         let _ = self.app_controller.init()?;
         let _ = self.view_controller.init()?;
         let _ = self.trash_controller.init()?;
+        drop(is_init);
 
+        self.spawn_deferred_init();
         Ok(())
     }
+
+    fn init_gate(&self, user_id: &str) -> Arc<Mutex<bool>> {
+        self.init_gates
+            .write()
+            .entry(user_id.to_owned())
+            .or_insert_with(|| Arc::new(Mutex::new(false)))
+            .clone()
+    }
+
+    // Returns false while the workspace is being initialized.
+    fn is_initialized(&self, user_id: &str) -> bool {
+        match self.init_gates.read().get(user_id) {
+            None => false,
+            Some(gate) => gate.try_lock().map(|is_init| *is_init).unwrap_or(false),
+        }
+    }
+
+    // The work that the workspace doesn't need to be shown runs after the
+    // gate was opened, so the waiting readers aren't blocked by it.
+    fn spawn_deferred_init(&self) {
+        let folder_syncer = self.folder_syncer.clone();
+        let event_log_controller = self.event_log_controller.clone();
+        let view_controller = self.view_controller.clone();
+        let journal = self.journal.clone();
+        self.backup_controller.start();
+        self.outbox.spawn_replay();
+        let preload_view_controller = view_controller.clone();
+        tokio::spawn(async move {
+            if let Err(e) = preload_view_controller.preload_latest_view().await {
+                tracing::error!("Preload the latest view failed: {}", e);
+            }
+        });
+        tokio::spawn(async move {
+            if let Err(e) = journal.rollback_incomplete().await {
+                tracing::error!("Roll back the incomplete operations failed: {}", e);
+            }
+            // The folder, the trash included, is pulled from the server once
+            // at startup to catch up with the changes of the other devices.
+            // The pruning doesn't wait for it.
+            tokio::spawn(async move {
+                if let Err(e) = folder_syncer.sync().await {
+                    tracing::error!("Sync the folder with the server failed: {}", e);
+                }
+            });
+            if let Err(e) = prune_blocking(move || event_log_controller.prune()).await {
+                tracing::error!("Prune the event log failed: {}", e);
+            }
+            if let Err(e) = view_controller.prune_revisions().await {
+                tracing::error!("Prune the revisions failed: {}", e);
+            }
+        });
+    }
+}
+
+async fn prune_blocking<F>(f: F) -> FlowyResult<()>
+where
+    F: FnOnce() -> FlowyResult<()> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f).await.map_err(internal_error)?
 }
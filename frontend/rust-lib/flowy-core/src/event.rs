@@ -5,77 +5,353 @@ use strum_macros::Display;
 #[event_err = "FlowyError"]
 pub enum WorkspaceEvent {
     #[event(input = "CreateWorkspaceRequest", output = "Workspace")]
-    CreateWorkspace   = 0,
+    CreateWorkspace      = 0,
 
     #[event(output = "CurrentWorkspaceSetting")]
-    ReadCurWorkspace  = 1,
+    ReadCurWorkspace     = 1,
 
     #[event(input = "QueryWorkspaceRequest", output = "RepeatedWorkspace")]
-    ReadWorkspaces    = 2,
+    ReadWorkspaces       = 2,
 
     #[event(input = "QueryWorkspaceRequest")]
-    DeleteWorkspace   = 3,
+    DeleteWorkspace      = 3,
 
     #[event(input = "QueryWorkspaceRequest", output = "Workspace")]
-    OpenWorkspace     = 4,
+    OpenWorkspace        = 4,
 
     #[event(input = "QueryWorkspaceRequest", output = "RepeatedApp")]
-    ReadWorkspaceApps = 5,
+    ReadWorkspaceApps    = 5,
+
+    #[event(input = "QueryWorkspaceMembersRequest", output = "RepeatedWorkspaceMember")]
+    ReadWorkspaceMembers = 6,
+
+    #[event(input = "AddWorkspaceMemberRequest", output = "WorkspaceMember")]
+    AddWorkspaceMember   = 7,
+
+    #[event(input = "UpdateWorkspaceMemberRequest")]
+    UpdateWorkspaceMember = 8,
+
+    #[event(input = "RemoveWorkspaceMemberRequest")]
+    RemoveWorkspaceMember = 9,
+
+    #[event(input = "CreateInvitationRequest", output = "WorkspaceInvitation")]
+    CreateInvitation     = 10,
+
+    #[event(output = "RepeatedWorkspaceInvitation")]
+    ReadInvitations      = 11,
+
+    #[event(input = "QueryInvitationRequest", output = "Workspace")]
+    AcceptInvitation     = 12,
+
+    #[event(input = "QueryInvitationRequest")]
+    DeclineInvitation    = 13,
+
+    #[event(input = "QueryRevisionRetentionRequest", output = "RevisionRetentionSetting")]
+    ReadRevisionRetention = 14,
+
+    #[event(input = "UpdateRevisionRetentionRequest", output = "RevisionRetentionSetting")]
+    UpdateRevisionRetention = 15,
+
+    #[event(input = "QueryWorkspaceRequest")]
+    CloseWorkspace       = 16,
+
+    #[event(output = "RepeatedWorkspace")]
+    ReadOpenedWorkspaces = 17,
+
+    #[event(input = "QueryWorkspaceRequest", output = "CurrentWorkspaceSetting")]
+    ReadWorkspaceSetting = 18,
+
+    #[event(input = "QueryWorkspaceSettingsRequest", output = "WorkspaceSettings")]
+    ReadWorkspaceSettings = 19,
+
+    #[event(input = "UpdateWorkspaceSettingsRequest", output = "WorkspaceSettings")]
+    UpdateWorkspaceSettings = 20,
 
     #[event(input = "CreateAppRequest", output = "App")]
-    CreateApp         = 101,
+    CreateApp            = 101,
 
     #[event(input = "QueryAppRequest")]
-    DeleteApp         = 102,
+    DeleteApp            = 102,
 
     #[event(input = "QueryAppRequest", output = "App")]
-    ReadApp           = 103,
+    ReadApp              = 103,
 
     #[event(input = "UpdateAppRequest")]
-    UpdateApp         = 104,
+    UpdateApp            = 104,
+
+    #[event(input = "QueryAppRequest", output = "App")]
+    DuplicateApp         = 105,
 
     #[event(input = "CreateViewRequest", output = "View")]
-    CreateView        = 201,
+    CreateView           = 201,
 
     #[event(input = "QueryViewRequest", output = "View")]
-    ReadView          = 202,
+    ReadView             = 202,
 
     #[event(input = "UpdateViewRequest", output = "View")]
-    UpdateView        = 203,
+    UpdateView           = 203,
 
     #[event(input = "QueryViewRequest")]
-    DeleteView        = 204,
+    DeleteView           = 204,
 
-    #[event(input = "QueryViewRequest")]
-    DuplicateView     = 205,
+    #[event(input = "DuplicateViewRequest")]
+    DuplicateView        = 205,
 
     #[event()]
-    CopyLink          = 206,
+    CopyLink             = 206,
 
     #[event(input = "QueryViewRequest", output = "DocumentDelta")]
-    OpenView          = 207,
+    OpenView             = 207,
 
     #[event(input = "QueryViewRequest")]
-    CloseView         = 208,
+    CloseView            = 208,
+
+    #[event(input = "QueryViewRequest", output = "ViewAccess")]
+    ReadViewAccess       = 209,
+
+    #[event(input = "UpdateViewAccessRequest", output = "ViewAccess")]
+    GrantViewAccess      = 210,
+
+    #[event(input = "UpdateViewAccessRequest", output = "ViewAccess")]
+    RevokeViewAccess     = 211,
+
+    #[event(input = "MoveViewRequest", output = "View")]
+    MoveView             = 212,
+
+    #[event(input = "QueryViewRequest", output = "ViewTags")]
+    ReadViewTags         = 213,
+
+    #[event(input = "UpdateViewTagsRequest", output = "ViewTags")]
+    UpdateViewTags       = 214,
+
+    #[event(input = "QueryAttachmentRequest", output = "Attachment")]
+    ReadAttachment       = 215,
+
+    #[event(input = "MoveItemsRequest", output = "RepeatedItemMoved")]
+    MoveItems            = 216,
+
+    #[event(input = "CreateViewCheckpointRequest", output = "ViewCheckpoint")]
+    CreateViewCheckpoint = 217,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedViewCheckpoint")]
+    ReadViewCheckpoints  = 218,
+
+    #[event(input = "QueryViewCheckpointRequest", output = "DocumentDelta")]
+    RestoreViewCheckpoint = 219,
+
+    #[event(input = "QueryViewCheckpointRequest")]
+    DeleteViewCheckpoint = 220,
+
+    #[event(
+        input = "ExportQuarantinedRevisionsRequest",
+        output = "ExportQuarantinedRevisionsResult"
+    )]
+    ExportQuarantinedRevisions = 221,
 
     #[event(output = "RepeatedTrash")]
-    ReadTrash         = 300,
+    ReadTrash            = 300,
 
     #[event(input = "TrashId")]
-    PutbackTrash      = 301,
+    PutbackTrash         = 301,
 
     #[event(input = "RepeatedTrashId")]
-    DeleteTrash       = 302,
+    DeleteTrash          = 302,
 
     #[event()]
-    RestoreAll        = 303,
+    RestoreAll           = 303,
 
     #[event()]
-    DeleteAll         = 304,
+    DeleteAll            = 304,
 
     #[event(input = "DocumentDelta", output = "DocumentDelta")]
-    ApplyDocDelta     = 400,
+    ApplyDocDelta        = 400,
+
+    #[event(input = "QueryCodeBlockRequest", output = "CodeBlockTokens")]
+    ReadCodeBlockTokens  = 401,
+
+    #[event(input = "QueryViewRequest", output = "CollapsedToggles")]
+    ReadCollapsedToggles = 402,
+
+    #[event(input = "UpdateToggleStateRequest")]
+    UpdateToggleState    = 403,
 
     #[event(input = "ExportRequest", output = "ExportData")]
-    ExportDocument    = 500,
+    ExportDocument       = 500,
+
+    #[event(input = "ExportUserDataRequest", output = "ExportUserDataResult")]
+    ExportUserData       = 501,
+
+    #[event(input = "CreateShareLinkRequest", output = "ShareLink")]
+    CreateShareLink      = 502,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedShareLink")]
+    ReadShareLinks       = 503,
+
+    #[event(input = "QueryShareLinkRequest")]
+    RevokeShareLink      = 504,
+
+    #[event(input = "QueryShareLinkRequest", output = "SharedDocument")]
+    ReadSharedDocument   = 505,
+
+    #[event(input = "CreateGuestAccessRequest", output = "GuestAccess")]
+    CreateGuestAccess    = 506,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedGuestAccess")]
+    ReadGuestAccesses    = 507,
+
+    #[event(input = "QueryGuestAccessRequest")]
+    RevokeGuestAccess    = 508,
+
+    #[event(input = "ImportCsvRequest", output = "View")]
+    ImportCsv            = 509,
+
+    #[event(input = "ExportGridRequest", output = "ExportGridResult")]
+    ExportGrid           = 510,
+
+    #[event(input = "ImportNotionRequest", output = "ImportNotionResult")]
+    ImportNotion         = 511,
+
+    #[event(input = "ImportEnexRequest", output = "RepeatedView")]
+    ImportEnex           = 512,
+
+    #[event(input = "ImportVaultRequest", output = "RepeatedApp")]
+    ImportVault          = 513,
+
+    #[event(input = "ExportOpmlRequest", output = "ExportOpmlResult")]
+    ExportOpml           = 514,
+
+    #[event(input = "ImportOpmlRequest", output = "RepeatedApp")]
+    ImportOpml           = 515,
+
+    #[event(input = "ExportCalendarRequest", output = "ExportCalendarResult")]
+    ExportCalendar       = 516,
+
+    #[event(input = "QueryGridRequest")]
+    UnsubscribeCalendar  = 517,
+
+    #[event(input = "ExportSiteRequest", output = "ExportSiteResult")]
+    ExportSite           = 518,
+
+    #[event(input = "CreateWebhookRequest", output = "Webhook")]
+    CreateWebhook        = 600,
+
+    #[event(output = "RepeatedWebhook")]
+    ReadWebhooks         = 601,
+
+    #[event(input = "QueryWebhookRequest")]
+    DeleteWebhook        = 602,
+
+    #[event(input = "ReadEventLogRequest", output = "EventLogPage")]
+    ReadEventLog         = 700,
+
+    #[event(input = "ReadAuditLogRequest", output = "AuditLogPage")]
+    ReadAuditLog         = 701,
+
+    #[event(output = "Metrics")]
+    ReadMetrics          = 800,
+
+    #[event(output = "HealthReport")]
+    CheckHealth          = 801,
+
+    #[event(input = "ExportDiagnosticsRequest", output = "ExportDiagnosticsResult")]
+    ExportDiagnostics    = 802,
+
+    #[event()]
+    Shutdown             = 803,
+
+    #[event(input = "QueryGridRequest", output = "Grid")]
+    ReadGrid             = 900,
+
+    #[event(input = "CreateFieldRequest", output = "Field")]
+    CreateField          = 901,
+
+    #[event(input = "UpdateFieldRequest", output = "Field")]
+    UpdateField          = 902,
+
+    #[event(input = "QueryFieldRequest")]
+    DeleteField          = 903,
+
+    #[event(input = "CreateRowRequest", output = "Row")]
+    CreateRow            = 904,
+
+    #[event(input = "QueryRowRequest")]
+    DeleteRow            = 905,
+
+    #[event(input = "UpdateCellRequest", output = "Cell")]
+    UpdateCell           = 906,
+
+    #[event(input = "QueryGridRequest", output = "Board")]
+    ReadBoard            = 907,
+
+    #[event(input = "MoveCardRequest", output = "CardMoved")]
+    MoveCard             = 908,
+
+    #[event(input = "QueryCalendarEventsRequest", output = "RepeatedCalendarEvent")]
+    ReadCalendarEvents   = 909,
+
+    #[event(input = "MoveCalendarEventRequest", output = "CalendarEvent")]
+    MoveCalendarEvent    = 910,
+
+    #[event(input = "QueryGridRequest", output = "RepeatedRow")]
+    ReadGridRows         = 911,
+
+    #[event(input = "UpdateGridSettingRequest")]
+    UpdateGridSetting    = 912,
+
+    #[event(input = "QueryChecklistRequest", output = "Checklist")]
+    ReadChecklist        = 1000,
+
+    #[event(input = "CreateChecklistItemRequest", output = "ChecklistItem")]
+    CreateChecklistItem  = 1001,
+
+    #[event(input = "UpdateChecklistItemRequest", output = "ChecklistItem")]
+    UpdateChecklistItem  = 1002,
+
+    #[event(input = "QueryChecklistItemRequest")]
+    DeleteChecklistItem  = 1003,
+
+    #[event(input = "QueryChecklistItemRequest", output = "ChecklistItem")]
+    ToggleChecklistItem  = 1004,
+
+    #[event(input = "MoveChecklistItemRequest")]
+    MoveChecklistItem    = 1005,
+
+    #[event(input = "CompleteChecklistItemsRequest")]
+    CompleteChecklistItems = 1006,
+
+    #[event(input = "SearchRequest", output = "RepeatedWorkspaceSearchResult")]
+    Search               = 1100,
+
+    #[event(input = "UnfurlRequest", output = "LinkPreview")]
+    UnfurlLink           = 1200,
+
+    #[event(output = "UnfurlSetting")]
+    ReadUnfurlSetting    = 1201,
+
+    #[event(input = "UnfurlSetting", output = "UnfurlSetting")]
+    UpdateUnfurlSetting  = 1202,
+
+    #[event(output = "BackupSetting")]
+    ReadBackupSetting    = 1300,
+
+    #[event(input = "UpdateBackupSettingRequest", output = "BackupSetting")]
+    UpdateBackupSetting  = 1301,
+
+    #[event(output = "BackupResult")]
+    RunBackup            = 1302,
+
+    #[event(input = "QueryBackupsRequest", output = "RepeatedBackupManifest")]
+    ReadBackups          = 1303,
+
+    #[event(input = "SelectBackupRestoreRequest")]
+    SelectBackupRestore  = 1304,
+
+    #[event(input = "QueryWorkspaceRequest", output = "AttachmentStorage")]
+    ReadAttachmentStorage = 1400,
+
+    #[event(input = "UpdateAttachmentStorageRequest", output = "AttachmentStorage")]
+    UpdateAttachmentStorage = 1401,
+
+    #[event(input = "QueryWorkspaceRequest", output = "AttachmentSyncResult")]
+    SyncAttachments      = 1402,
 }
@@ -1,17 +1,54 @@
 use crate::{
     context::CoreContext,
-    errors::FlowyError,
+    errors::{internal_error, FlowyError},
     notify::{send_dart_notification, WorkspaceNotification},
     services::{
+        export::{CalendarExporter, GridExporter, OpmlExporter, SiteExporter, UserDataExporter},
         get_current_workspace,
+        import::{CsvImporter, EnexImporter, NotionImporter, OpmlImporter, VaultImporter},
         read_local_workspace_apps,
-        workspace::sql::{WorkspaceTable, WorkspaceTableSql},
+        workspace::{
+            setting::save_workspace_settings,
+            skeleton::{read_workspace_skeleton, save_workspace_skeleton},
+            sql::{WorkspaceTable, WorkspaceTableSql},
+        },
     },
 };
 use flowy_core_data_model::entities::{
-    view::View,
+    app::RepeatedApp,
+    grid::{GridId, QueryGridRequest},
+    share::{
+        ExportCalendarParams,
+        ExportCalendarRequest,
+        ExportCalendarResult,
+        ExportGridParams,
+        ExportGridRequest,
+        ExportGridResult,
+        ExportOpmlParams,
+        ExportOpmlRequest,
+        ExportOpmlResult,
+        ExportSiteParams,
+        ExportSiteRequest,
+        ExportSiteResult,
+        ExportUserDataParams,
+        ExportUserDataRequest,
+        ExportUserDataResult,
+        ImportCsvParams,
+        ImportCsvRequest,
+        ImportEnexParams,
+        ImportEnexRequest,
+        ImportNotionParams,
+        ImportNotionRequest,
+        ImportNotionResult,
+        ImportOpmlParams,
+        ImportOpmlRequest,
+        ImportVaultParams,
+        ImportVaultRequest,
+    },
+    view::{RepeatedView, View},
     workspace::{CurrentWorkspaceSetting, QueryWorkspaceRequest, RepeatedWorkspace, WorkspaceId},
 };
+use flowy_database::slow_log::SlowLogTransaction;
 use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
 use std::{convert::TryInto, sync::Arc};
 
@@ -21,12 +58,13 @@ pub(crate) async fn read_workspaces_handler(
     core: Unit<Arc<CoreContext>>,
 ) -> DataResult<RepeatedWorkspace, FlowyError> {
     let params: WorkspaceId = data.into_inner().try_into()?;
+    let _ = core.wait_for_init().await?;
     let user_id = core.user.user_id()?;
     let conn = &*core.database.db_connection()?;
     let workspace_controller = core.workspace_controller.clone();
 
     let trash_controller = core.trash_controller.clone();
-    let workspaces = conn.immediate_transaction::<_, FlowyError, _>(|| {
+    let workspaces = conn.timed_transaction::<_, FlowyError, _>("workspace.read_workspaces_handler", || {
         let mut workspaces = workspace_controller.read_local_workspaces(params.workspace_id.clone(), &user_id, conn)?;
         for workspace in workspaces.iter_mut() {
             let apps = read_local_workspace_apps(&workspace.id, trash_controller.clone(), conn)?.into_inner();
@@ -44,20 +82,175 @@ pub(crate) async fn read_workspaces_handler(
 pub async fn read_cur_workspace_handler(
     core: Unit<Arc<CoreContext>>,
 ) -> DataResult<CurrentWorkspaceSetting, FlowyError> {
+    let _ = core.wait_for_init().await?;
     let workspace_id = get_current_workspace()?;
+    let setting = read_workspace_setting(core, workspace_id)?;
+    data_result(setting)
+}
+
+// The setting of the workspace that is opened in a window, the windows don't
+// share the current workspace.
+#[tracing::instrument(skip(data, core), err)]
+pub(crate) async fn read_workspace_setting_handler(
+    data: Data<QueryWorkspaceRequest>,
+    core: Unit<Arc<CoreContext>>,
+) -> DataResult<CurrentWorkspaceSetting, FlowyError> {
+    let params: WorkspaceId = data.into_inner().try_into()?;
+    let _ = core.wait_for_init().await?;
+    let workspace_id = match params.workspace_id {
+        Some(workspace_id) => workspace_id,
+        None => get_current_workspace()?,
+    };
+    let setting = read_workspace_setting(core, workspace_id)?;
+    data_result(setting)
+}
+
+fn read_workspace_setting(
+    core: Unit<Arc<CoreContext>>,
+    workspace_id: String,
+) -> Result<CurrentWorkspaceSetting, FlowyError> {
     let user_id = core.user.user_id()?;
     let params = WorkspaceId {
         workspace_id: Some(workspace_id.clone()),
     };
-    let conn = &*core.database.db_connection()?;
-    let workspace = core
-        .workspace_controller
-        .read_local_workspace(workspace_id, &user_id, conn)?;
+    // The apps come with the workspace, so the sidebar can be shown right away.
+    // Their views are read when the app is opened.
+    let workspace = match read_workspace_skeleton(&workspace_id) {
+        Some(workspace) => workspace,
+        None => {
+            let conn = &*core.database.db_connection()?;
+            let mut workspace = core
+                .workspace_controller
+                .read_local_workspace(workspace_id, &user_id, conn)?;
+            workspace.apps = read_local_workspace_apps(&workspace.id, core.trash_controller.clone(), conn)?;
+            save_workspace_skeleton(workspace.clone());
+            workspace
+        },
+    };
 
-    let latest_view: Option<View> = core.view_controller.latest_visit_view().unwrap_or(None);
+    let latest_view: Option<View> = core.view_controller.latest_visit_view_in(&workspace.id).unwrap_or(None);
     let setting = CurrentWorkspaceSetting { workspace, latest_view };
     let _ = read_workspaces_on_server(core, user_id, params);
-    data_result(setting)
+    Ok(setting)
+}
+
+#[tracing::instrument(skip(data, exporter), err)]
+pub(crate) async fn export_user_data_handler(
+    data: Data<ExportUserDataRequest>,
+    exporter: Unit<Arc<UserDataExporter>>,
+) -> DataResult<ExportUserDataResult, FlowyError> {
+    let params: ExportUserDataParams = data.into_inner().try_into()?;
+    let exporter = exporter.get_ref().clone();
+    let result = tokio::task::spawn_blocking(move || exporter.export(params))
+        .await
+        .map_err(internal_error)??;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(data, exporter), err)]
+pub(crate) async fn export_grid_handler(
+    data: Data<ExportGridRequest>,
+    exporter: Unit<Arc<GridExporter>>,
+) -> DataResult<ExportGridResult, FlowyError> {
+    let params: ExportGridParams = data.into_inner().try_into()?;
+    let result = exporter.export(params).await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(data, exporter), err)]
+pub(crate) async fn export_opml_handler(
+    data: Data<ExportOpmlRequest>,
+    exporter: Unit<Arc<OpmlExporter>>,
+) -> DataResult<ExportOpmlResult, FlowyError> {
+    let params: ExportOpmlParams = data.into_inner().try_into()?;
+    let result = exporter.export(params).await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(data, exporter), err)]
+pub(crate) async fn export_calendar_handler(
+    data: Data<ExportCalendarRequest>,
+    exporter: Unit<Arc<CalendarExporter>>,
+) -> DataResult<ExportCalendarResult, FlowyError> {
+    let params: ExportCalendarParams = data.into_inner().try_into()?;
+    let result = exporter.export(params).await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(data, exporter), err)]
+pub(crate) async fn unsubscribe_calendar_handler(
+    data: Data<QueryGridRequest>,
+    exporter: Unit<Arc<CalendarExporter>>,
+) -> Result<(), FlowyError> {
+    let params: GridId = data.into_inner().try_into()?;
+    exporter.unsubscribe(params);
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, exporter), err)]
+pub(crate) async fn export_site_handler(
+    data: Data<ExportSiteRequest>,
+    exporter: Unit<Arc<SiteExporter>>,
+) -> DataResult<ExportSiteResult, FlowyError> {
+    let params: ExportSiteParams = data.into_inner().try_into()?;
+    let result = exporter.export(params).await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(data, importer), err)]
+pub(crate) async fn import_csv_handler(
+    data: Data<ImportCsvRequest>,
+    importer: Unit<Arc<CsvImporter>>,
+) -> DataResult<View, FlowyError> {
+    let params: ImportCsvParams = data.into_inner().try_into()?;
+    let view = importer.import_csv(params).await?;
+    data_result(view)
+}
+
+#[tracing::instrument(skip(data, importer), err)]
+pub(crate) async fn import_notion_handler(
+    data: Data<ImportNotionRequest>,
+    importer: Unit<Arc<NotionImporter>>,
+) -> DataResult<ImportNotionResult, FlowyError> {
+    let params: ImportNotionParams = data.into_inner().try_into()?;
+    let result = importer.import_notion(params).await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(data, importer), err)]
+pub(crate) async fn import_enex_handler(
+    data: Data<ImportEnexRequest>,
+    importer: Unit<Arc<EnexImporter>>,
+) -> DataResult<RepeatedView, FlowyError> {
+    let params: ImportEnexParams = data.into_inner().try_into()?;
+    let views = importer.import_enex(params).await?;
+    data_result(views)
+}
+
+#[tracing::instrument(skip(data, importer), err)]
+pub(crate) async fn import_vault_handler(
+    data: Data<ImportVaultRequest>,
+    importer: Unit<Arc<VaultImporter>>,
+) -> DataResult<RepeatedApp, FlowyError> {
+    let params: ImportVaultParams = data.into_inner().try_into()?;
+    let apps = importer.import_vault(params).await?;
+    data_result(apps)
+}
+
+#[tracing::instrument(skip(data, importer), err)]
+pub(crate) async fn import_opml_handler(
+    data: Data<ImportOpmlRequest>,
+    importer: Unit<Arc<OpmlImporter>>,
+) -> DataResult<RepeatedApp, FlowyError> {
+    let params: ImportOpmlParams = data.into_inner().try_into()?;
+    let apps = importer.import_opml(params).await?;
+    data_result(apps)
+}
+
+#[tracing::instrument(skip(core), err)]
+pub(crate) async fn shutdown_handler(core: Unit<Arc<CoreContext>>) -> Result<(), FlowyError> {
+    let _ = core.shutdown().await?;
+    Ok(())
 }
 
 #[tracing::instrument(level = "debug", skip(core), err)]
@@ -66,6 +259,10 @@ fn read_workspaces_on_server(
     user_id: String,
     params: WorkspaceId,
 ) -> Result<(), FlowyError> {
+    // The anonymous user's folder only exists on this device.
+    if core.user.is_anonymous() {
+        return Ok(());
+    }
     let (token, server) = (core.user.token()?, core.server.clone());
     let app_ctrl = core.app_controller.clone();
     let view_ctrl = core.view_controller.clone();
@@ -74,7 +271,7 @@ fn read_workspaces_on_server(
     tokio::spawn(async move {
         // Opti: handle the error and retry?
         let workspaces = server.read_workspace(&token, params).await?;
-        let _ = (&*conn).immediate_transaction::<_, FlowyError, _>(|| {
+        let _ = (&*conn).timed_transaction::<_, FlowyError, _>("workspace.read_workspaces_on_server", || {
             tracing::debug!("Save {} workspace", workspaces.len());
             for workspace in &workspaces.items {
                 let m_workspace = workspace.clone();
@@ -82,6 +279,9 @@ fn read_workspaces_on_server(
                 let workspace_table = WorkspaceTable::new(m_workspace, &user_id);
 
                 let _ = WorkspaceTableSql::create_workspace(workspace_table, &*conn)?;
+                if let Some(settings) = &workspace.settings {
+                    let _ = save_workspace_settings(&workspace.id, settings, &*conn)?;
+                }
                 tracing::debug!("Save {} apps", apps.len());
                 for app in apps {
                     let views = app.belongings.clone().into_inner();
@@ -0,0 +1,93 @@
+use futures::Stream;
+use tokio::sync::broadcast;
+
+// The subscribers that fall this far behind miss the oldest changes.
+const FOLDER_CHANGE_CAPACITY: usize = 256;
+
+/// A change of the folder structure. It's sent to the Rust subscribers, e.g.
+/// a search index, that follow the folder without going through the dart
+/// notifications.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FolderChange {
+    WorkspaceCreated {
+        workspace_id: String,
+    },
+    WorkspaceUpdated {
+        workspace_id: String,
+    },
+    WorkspaceTrashed {
+        workspace_id: String,
+    },
+    WorkspaceRestored {
+        workspace_id: String,
+    },
+    WorkspaceDeleted {
+        workspace_id: String,
+    },
+    AppCreated {
+        workspace_id: String,
+        app_id: String,
+    },
+    AppUpdated {
+        app_id: String,
+    },
+    AppTrashed {
+        app_id: String,
+    },
+    AppRestored {
+        app_id: String,
+    },
+    AppDeleted {
+        app_id: String,
+    },
+    ViewCreated {
+        belong_to_id: String,
+        view_id: String,
+    },
+    ViewUpdated {
+        view_id: String,
+    },
+    ViewMoved {
+        view_id: String,
+        from_belong_to_id: String,
+        to_belong_to_id: String,
+    },
+    ViewTrashed {
+        view_id: String,
+    },
+    ViewRestored {
+        view_id: String,
+    },
+    ViewDeleted {
+        view_id: String,
+    },
+}
+
+#[derive(Clone)]
+pub(crate) struct FolderChangeNotifier {
+    sender: broadcast::Sender<FolderChange>,
+}
+
+impl FolderChangeNotifier {
+    pub(crate) fn new() -> Self {
+        let (sender, _) = broadcast::channel(FOLDER_CHANGE_CAPACITY);
+        Self { sender }
+    }
+
+    // The change is dropped if no one subscribed.
+    pub(crate) fn notify(&self, change: FolderChange) { let _ = self.sender.send(change); }
+
+    pub(crate) fn subscribe(&self) -> impl Stream<Item = FolderChange> {
+        futures::stream::unfold(self.sender.subscribe(), |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(change) => return Some((change, rx)),
+                    Err(broadcast::error::RecvError::Lagged(count)) => {
+                        tracing::warn!("The folder change subscriber missed {} changes", count);
+                    },
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+}
@@ -12,8 +12,11 @@ extern crate flowy_database;
 
 pub mod context;
 pub mod event_handler;
+pub mod folder_change;
+pub mod name_rules;
 mod notify;
 pub mod protobuf;
+pub mod retry;
 mod util;
 
 pub mod prelude {
@@ -23,5 +26,5 @@ pub mod prelude {
 }
 
 pub mod errors {
-    pub use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
+    pub use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult, RecoveryHint};
 }
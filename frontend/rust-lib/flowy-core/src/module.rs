@@ -5,15 +5,45 @@ use crate::{
     errors::FlowyError,
     event::WorkspaceEvent,
     event_handler::*,
+    folder_change::FolderChangeNotifier,
+    name_rules::NameRules,
+    retry::{RetryPolicies, ServerRetry},
     services::{
         app::event_handler::*,
-        server::construct_workspace_server,
+        attachment::event_handler::*,
+        audit_log::event_handler::*,
+        backup::event_handler::*,
+        checklist::event_handler::*,
+        diagnostics::event_handler::*,
+        event_log::event_handler::*,
+        export::{CalendarExporter, DiagnosticsExporter, GridExporter, OpmlExporter, SiteExporter, UserDataExporter},
+        grid::event_handler::*,
+        import::{CsvImporter, EnexImporter, NotionImporter, OpmlImporter, VaultImporter},
+        search::event_handler::*,
+        server::{construct_batch_server, construct_workspace_server},
         trash::event_handler::*,
-        view::event_handler::*,
-        workspace::event_handler::*,
+        unfurl::event_handler::*,
+        view::{
+            event_handler::*,
+            processor::{DocumentViewDataProcessor, ViewDataProcessorMap},
+        },
+        webhook::event_handler::*,
+        workspace::{event_handler::*, folder_sync::FolderSyncer},
         AppController,
+        AttachmentController,
+        AuditLogController,
+        BackupController,
+        ChecklistController,
+        CloudOutbox,
+        DiagnosticsController,
+        EventLogController,
+        GridController,
+        OperationJournal,
+        SearchController,
         TrashController,
+        UnfurlController,
         ViewController,
+        WebhookController,
         WorkspaceController,
     },
 };
@@ -21,13 +51,18 @@ use backend_service::configuration::ClientServerConfiguration;
 use flowy_database::DBConnection;
 use flowy_document::context::DocumentContext;
 use lib_dispatch::prelude::*;
+use lib_infra::clock::RuntimeEnv;
 use lib_sqlite::ConnectionPool;
 
+pub const MODULE_NAME: &str = "Flowy-Workspace";
+
 pub trait WorkspaceDeps: WorkspaceUser + WorkspaceDatabase {}
 
 pub trait WorkspaceUser: Send + Sync {
     fn user_id(&self) -> Result<String, FlowyError>;
     fn token(&self) -> Result<String, FlowyError>;
+    fn is_anonymous(&self) -> bool;
+    fn settings_json(&self) -> Result<String, FlowyError>;
 }
 
 pub trait WorkspaceDatabase: Send + Sync {
@@ -35,9 +70,11 @@ pub trait WorkspaceDatabase: Send + Sync {
 
     fn db_connection(&self) -> Result<DBConnection, FlowyError> {
         let pool = self.db_pool()?;
-        let conn = pool.get().map_err(|e| FlowyError::internal().context(e))?;
+        let conn = pool.get().map_err(FlowyError::from)?;
         Ok(conn)
     }
+
+    fn close(&self) -> Result<(), FlowyError>;
 }
 
 pub fn init_core(
@@ -45,31 +82,106 @@ pub fn init_core(
     database: Arc<dyn WorkspaceDatabase>,
     flowy_document: Arc<DocumentContext>,
     server_config: &ClientServerConfiguration,
+    name_rules: NameRules,
+    retry_policies: RetryPolicies,
+    secure_delete: bool,
+    env: RuntimeEnv,
 ) -> Arc<CoreContext> {
     let server = construct_workspace_server(server_config);
+    let batch_server = construct_batch_server(server_config, server.clone());
+    let server_retry = ServerRetry::new(
+        server.clone(),
+        batch_server,
+        server_config,
+        user.clone(),
+        retry_policies,
+        env.clock.clone(),
+    );
+
+    let folder_change_notifier = FolderChangeNotifier::new();
+
+    let outbox = Arc::new(CloudOutbox::new(
+        user.clone(),
+        database.clone(),
+        server.clone(),
+        env.clone(),
+    ));
+
+    let webhook_controller = Arc::new(WebhookController::new(database.clone(), env.clone()));
+
+    let event_log_controller = Arc::new(EventLogController::new(database.clone()));
+
+    let audit_log_controller = Arc::new(AuditLogController::new(database.clone(), user.clone(), env.clone()));
 
-    let trash_controller = Arc::new(TrashController::new(database.clone(), server.clone(), user.clone()));
+    let trash_controller = Arc::new(TrashController::new(
+        user.clone(),
+        database.clone(),
+        server_retry.clone(),
+        webhook_controller.clone(),
+        secure_delete,
+    ));
+
+    let journal = Arc::new(OperationJournal::new(
+        database.clone(),
+        trash_controller.clone(),
+        flowy_document.clone(),
+        env.clone(),
+    ));
+
+    let mut view_data_processors = ViewDataProcessorMap::default();
+    view_data_processors.register(Arc::new(DocumentViewDataProcessor::new(flowy_document.clone())));
 
     let view_controller = Arc::new(ViewController::new(
         user.clone(),
         database.clone(),
         server.clone(),
+        server_retry.clone(),
         trash_controller.clone(),
+        webhook_controller.clone(),
+        audit_log_controller.clone(),
+        folder_change_notifier.clone(),
+        name_rules.clone(),
         flowy_document,
+        view_data_processors,
+        journal.clone(),
+        outbox.clone(),
+        env.clone(),
     ));
 
-    let app_controller = Arc::new(AppController::new(
+    let backup_controller = Arc::new(BackupController::new(
         user.clone(),
+        database.clone(),
+        view_controller.clone(),
+        env.clone(),
+    ));
+
+    let app_controller = Arc::new(AppController::new(
         database.clone(),
         trash_controller.clone(),
-        server.clone(),
+        audit_log_controller.clone(),
+        folder_change_notifier.clone(),
+        name_rules,
+        server_retry.clone(),
+        journal.clone(),
+        env.clone(),
     ));
 
     let workspace_controller = Arc::new(WorkspaceController::new(
         user.clone(),
         database.clone(),
         trash_controller.clone(),
+        audit_log_controller.clone(),
+        folder_change_notifier.clone(),
         server.clone(),
+        server_retry.clone(),
+        outbox.clone(),
+    ));
+
+    let folder_syncer = Arc::new(FolderSyncer::new(
+        user.clone(),
+        database.clone(),
+        workspace_controller.clone(),
+        server_retry,
     ));
 
     Arc::new(CoreContext::new(
@@ -80,16 +192,100 @@ pub fn init_core(
         app_controller,
         view_controller,
         trash_controller,
+        webhook_controller,
+        event_log_controller,
+        audit_log_controller,
+        backup_controller,
+        folder_syncer,
+        journal,
+        outbox,
+        folder_change_notifier,
+        env,
     ))
 }
 
 pub fn create(core: Arc<CoreContext>) -> Module {
+    let user_data_exporter = Arc::new(UserDataExporter::new(
+        core.user.clone(),
+        core.database.clone(),
+        core.view_controller.clone(),
+    ));
+
+    let diagnostics_controller = Arc::new(DiagnosticsController::new(core.database.clone()));
+    let diagnostics_exporter = Arc::new(DiagnosticsExporter::new(
+        core.user.clone(),
+        core.database.clone(),
+        core.view_controller.clone(),
+    ));
+    let grid_controller = Arc::new(GridController::new(core.database.clone(), core.view_controller.clone()));
+    let csv_importer = Arc::new(CsvImporter::new(core.view_controller.clone(), grid_controller.clone()));
+    let notion_importer = Arc::new(NotionImporter::new(
+        core.app_controller.clone(),
+        core.view_controller.clone(),
+        csv_importer.clone(),
+    ));
+    let enex_importer = Arc::new(EnexImporter::new(core.view_controller.clone()));
+    let vault_importer = Arc::new(VaultImporter::new(
+        core.app_controller.clone(),
+        core.view_controller.clone(),
+    ));
+    let grid_exporter = Arc::new(GridExporter::new(grid_controller.clone()));
+    let calendar_exporter = Arc::new(CalendarExporter::new(grid_controller.clone()));
+    let opml_exporter = Arc::new(OpmlExporter::new(core.user.clone(), core.database.clone()));
+    let opml_importer = Arc::new(OpmlImporter::new(
+        core.app_controller.clone(),
+        core.view_controller.clone(),
+    ));
+    let checklist_controller = Arc::new(ChecklistController::new(
+        core.database.clone(),
+        core.view_controller.clone(),
+    ));
+    let site_exporter = Arc::new(SiteExporter::new(
+        core.app_controller.clone(),
+        core.view_controller.clone(),
+        grid_controller.clone(),
+        checklist_controller.clone(),
+    ));
+    let search_controller = Arc::new(SearchController::new(
+        core.user.clone(),
+        core.database.clone(),
+        core.view_controller.clone(),
+        core.trash_controller.clone(),
+    ));
+    let unfurl_controller = Arc::new(UnfurlController::new(core.database.clone(), core.env.clone()));
+    let attachment_controller = Arc::new(AttachmentController::new(
+        core.user.clone(),
+        core.database.clone(),
+        core.env.clone(),
+    ));
+
     let mut module = Module::new()
-        .name("Flowy-Workspace")
+        .name(MODULE_NAME)
         .data(core.workspace_controller.clone())
         .data(core.app_controller.clone())
         .data(core.view_controller.clone())
         .data(core.trash_controller.clone())
+        .data(core.webhook_controller.clone())
+        .data(core.event_log_controller.clone())
+        .data(core.audit_log_controller.clone())
+        .data(core.backup_controller.clone())
+        .data(user_data_exporter)
+        .data(diagnostics_controller)
+        .data(diagnostics_exporter)
+        .data(grid_controller)
+        .data(csv_importer)
+        .data(notion_importer)
+        .data(enex_importer)
+        .data(vault_importer)
+        .data(grid_exporter)
+        .data(calendar_exporter)
+        .data(opml_exporter)
+        .data(site_exporter)
+        .data(opml_importer)
+        .data(checklist_controller)
+        .data(search_controller)
+        .data(unfurl_controller)
+        .data(attachment_controller)
         .data(core.clone());
 
     module = module
@@ -97,13 +293,36 @@ pub fn create(core: Arc<CoreContext>) -> Module {
         .event(WorkspaceEvent::ReadCurWorkspace, read_cur_workspace_handler)
         .event(WorkspaceEvent::ReadWorkspaces, read_workspaces_handler)
         .event(WorkspaceEvent::OpenWorkspace, open_workspace_handler)
-        .event(WorkspaceEvent::ReadWorkspaceApps, read_workspace_apps_handler);
+        .event(WorkspaceEvent::DeleteWorkspace, delete_workspace_handler)
+        .event(WorkspaceEvent::ReadWorkspaceApps, read_workspace_apps_handler)
+        .event(WorkspaceEvent::CloseWorkspace, close_workspace_handler)
+        .event(WorkspaceEvent::ReadOpenedWorkspaces, read_opened_workspaces_handler)
+        .event(WorkspaceEvent::ReadWorkspaceSetting, read_workspace_setting_handler)
+        .event(WorkspaceEvent::ReadWorkspaceMembers, read_workspace_members_handler)
+        .event(WorkspaceEvent::AddWorkspaceMember, add_workspace_member_handler)
+        .event(WorkspaceEvent::UpdateWorkspaceMember, update_workspace_member_handler)
+        .event(WorkspaceEvent::RemoveWorkspaceMember, remove_workspace_member_handler)
+        .event(WorkspaceEvent::CreateInvitation, create_invitation_handler)
+        .event(WorkspaceEvent::ReadInvitations, read_invitations_handler)
+        .event(WorkspaceEvent::AcceptInvitation, accept_invitation_handler)
+        .event(WorkspaceEvent::DeclineInvitation, decline_invitation_handler)
+        .event(WorkspaceEvent::ReadRevisionRetention, read_revision_retention_handler)
+        .event(
+            WorkspaceEvent::UpdateRevisionRetention,
+            update_revision_retention_handler,
+        )
+        .event(WorkspaceEvent::ReadWorkspaceSettings, read_workspace_settings_handler)
+        .event(
+            WorkspaceEvent::UpdateWorkspaceSettings,
+            update_workspace_settings_handler,
+        );
 
     module = module
         .event(WorkspaceEvent::CreateApp, create_app_handler)
         .event(WorkspaceEvent::ReadApp, read_app_handler)
         .event(WorkspaceEvent::UpdateApp, update_app_handler)
-        .event(WorkspaceEvent::DeleteApp, delete_app_handler);
+        .event(WorkspaceEvent::DeleteApp, delete_app_handler)
+        .event(WorkspaceEvent::DuplicateApp, duplicate_app_handler);
 
     module = module
         .event(WorkspaceEvent::CreateView, create_view_handler)
@@ -111,9 +330,28 @@ pub fn create(core: Arc<CoreContext>) -> Module {
         .event(WorkspaceEvent::UpdateView, update_view_handler)
         .event(WorkspaceEvent::DeleteView, delete_view_handler)
         .event(WorkspaceEvent::DuplicateView, duplicate_view_handler)
+        .event(WorkspaceEvent::MoveView, move_view_handler)
+        .event(WorkspaceEvent::MoveItems, move_items_handler)
         .event(WorkspaceEvent::OpenView, open_view_handler)
         .event(WorkspaceEvent::CloseView, close_view_handler)
-        .event(WorkspaceEvent::ApplyDocDelta, document_delta_handler);
+        .event(WorkspaceEvent::ReadViewAccess, read_view_access_handler)
+        .event(WorkspaceEvent::GrantViewAccess, grant_view_access_handler)
+        .event(WorkspaceEvent::RevokeViewAccess, revoke_view_access_handler)
+        .event(WorkspaceEvent::ReadViewTags, read_view_tags_handler)
+        .event(WorkspaceEvent::UpdateViewTags, update_view_tags_handler)
+        .event(WorkspaceEvent::ReadAttachment, read_attachment_handler)
+        .event(WorkspaceEvent::CreateViewCheckpoint, create_view_checkpoint_handler)
+        .event(WorkspaceEvent::ReadViewCheckpoints, read_view_checkpoints_handler)
+        .event(WorkspaceEvent::RestoreViewCheckpoint, restore_view_checkpoint_handler)
+        .event(WorkspaceEvent::DeleteViewCheckpoint, delete_view_checkpoint_handler)
+        .event(
+            WorkspaceEvent::ExportQuarantinedRevisions,
+            export_quarantined_revisions_handler,
+        )
+        .event(WorkspaceEvent::ApplyDocDelta, document_delta_handler)
+        .event(WorkspaceEvent::ReadCodeBlockTokens, read_code_block_tokens_handler)
+        .event(WorkspaceEvent::ReadCollapsedToggles, read_collapsed_toggles_handler)
+        .event(WorkspaceEvent::UpdateToggleState, update_toggle_state_handler);
 
     module = module
         .event(WorkspaceEvent::ReadTrash, read_trash_handler)
@@ -122,7 +360,79 @@ pub fn create(core: Arc<CoreContext>) -> Module {
         .event(WorkspaceEvent::RestoreAll, restore_all_handler)
         .event(WorkspaceEvent::DeleteAll, delete_all_handler);
 
-    module = module.event(WorkspaceEvent::ExportDocument, export_handler);
+    module = module
+        .event(WorkspaceEvent::ExportDocument, export_handler)
+        .event(WorkspaceEvent::ExportUserData, export_user_data_handler)
+        .event(WorkspaceEvent::CreateShareLink, create_share_link_handler)
+        .event(WorkspaceEvent::ReadShareLinks, read_share_links_handler)
+        .event(WorkspaceEvent::RevokeShareLink, revoke_share_link_handler)
+        .event(WorkspaceEvent::ReadSharedDocument, read_shared_document_handler)
+        .event(WorkspaceEvent::CreateGuestAccess, create_guest_access_handler)
+        .event(WorkspaceEvent::ReadGuestAccesses, read_guest_accesses_handler)
+        .event(WorkspaceEvent::RevokeGuestAccess, revoke_guest_access_handler)
+        .event(WorkspaceEvent::ImportCsv, import_csv_handler)
+        .event(WorkspaceEvent::ExportGrid, export_grid_handler)
+        .event(WorkspaceEvent::ImportNotion, import_notion_handler)
+        .event(WorkspaceEvent::ImportEnex, import_enex_handler)
+        .event(WorkspaceEvent::ImportVault, import_vault_handler)
+        .event(WorkspaceEvent::ExportOpml, export_opml_handler)
+        .event(WorkspaceEvent::ImportOpml, import_opml_handler)
+        .event(WorkspaceEvent::ExportCalendar, export_calendar_handler)
+        .event(WorkspaceEvent::UnsubscribeCalendar, unsubscribe_calendar_handler)
+        .event(WorkspaceEvent::ExportSite, export_site_handler);
+
+    module = module
+        .event(WorkspaceEvent::CreateWebhook, create_webhook_handler)
+        .event(WorkspaceEvent::ReadWebhooks, read_webhooks_handler)
+        .event(WorkspaceEvent::DeleteWebhook, delete_webhook_handler)
+        .event(WorkspaceEvent::ReadEventLog, read_event_log_handler)
+        .event(WorkspaceEvent::ReadAuditLog, read_audit_log_handler)
+        .event(WorkspaceEvent::ReadMetrics, read_metrics_handler)
+        .event(WorkspaceEvent::CheckHealth, check_health_handler)
+        .event(WorkspaceEvent::ExportDiagnostics, export_diagnostics_handler)
+        .event(WorkspaceEvent::Shutdown, shutdown_handler);
+
+    module = module
+        .event(WorkspaceEvent::ReadGrid, read_grid_handler)
+        .event(WorkspaceEvent::CreateField, create_field_handler)
+        .event(WorkspaceEvent::UpdateField, update_field_handler)
+        .event(WorkspaceEvent::DeleteField, delete_field_handler)
+        .event(WorkspaceEvent::CreateRow, create_row_handler)
+        .event(WorkspaceEvent::DeleteRow, delete_row_handler)
+        .event(WorkspaceEvent::UpdateCell, update_cell_handler)
+        .event(WorkspaceEvent::ReadBoard, read_board_handler)
+        .event(WorkspaceEvent::MoveCard, move_card_handler)
+        .event(WorkspaceEvent::ReadCalendarEvents, read_calendar_events_handler)
+        .event(WorkspaceEvent::MoveCalendarEvent, move_calendar_event_handler)
+        .event(WorkspaceEvent::ReadGridRows, read_grid_rows_handler)
+        .event(WorkspaceEvent::UpdateGridSetting, update_grid_setting_handler);
+
+    module = module
+        .event(WorkspaceEvent::ReadChecklist, read_checklist_handler)
+        .event(WorkspaceEvent::CreateChecklistItem, create_checklist_item_handler)
+        .event(WorkspaceEvent::UpdateChecklistItem, update_checklist_item_handler)
+        .event(WorkspaceEvent::DeleteChecklistItem, delete_checklist_item_handler)
+        .event(WorkspaceEvent::ToggleChecklistItem, toggle_checklist_item_handler)
+        .event(WorkspaceEvent::MoveChecklistItem, move_checklist_item_handler)
+        .event(WorkspaceEvent::CompleteChecklistItems, complete_checklist_items_handler);
+
+    module = module.event(WorkspaceEvent::Search, search_handler);
+
+    module = module
+        .event(WorkspaceEvent::UnfurlLink, unfurl_link_handler)
+        .event(WorkspaceEvent::ReadUnfurlSetting, read_unfurl_setting_handler)
+        .event(WorkspaceEvent::UpdateUnfurlSetting, update_unfurl_setting_handler)
+        .event(WorkspaceEvent::ReadBackupSetting, read_backup_setting_handler)
+        .event(WorkspaceEvent::UpdateBackupSetting, update_backup_setting_handler)
+        .event(WorkspaceEvent::RunBackup, run_backup_handler)
+        .event(WorkspaceEvent::ReadBackups, read_backups_handler)
+        .event(WorkspaceEvent::SelectBackupRestore, select_backup_restore_handler)
+        .event(WorkspaceEvent::ReadAttachmentStorage, read_attachment_storage_handler)
+        .event(
+            WorkspaceEvent::UpdateAttachmentStorage,
+            update_attachment_storage_handler,
+        )
+        .event(WorkspaceEvent::SyncAttachments, sync_attachments_handler);
 
     module
 }
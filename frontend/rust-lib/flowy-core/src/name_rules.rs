@@ -0,0 +1,70 @@
+use crate::errors::{FlowyError, FlowyResult};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The rules that the names of the apps and the views follow. They are
+/// checked when an app or a view is created, renamed or moved.
+#[derive(Clone, Debug)]
+pub struct NameRules {
+    /// The max number of characters of a name, 256 by default.
+    pub max_len: usize,
+
+    /// The characters that a name can't contain, e.g. '/' to keep the names
+    /// usable as file names. None by default.
+    pub forbidden_chars: Vec<char>,
+
+    /// The apps of a workspace, or the views of the same app or view, can't
+    /// have the same name. The case is ignored. Off by default.
+    pub unique_in_parent: bool,
+
+    /// The imports add a suffix to the names that are used already, e.g.
+    /// "Doc (2)", instead of failing or adding the same name again.
+    pub suffix_duplicates_on_import: bool,
+}
+
+impl std::default::Default for NameRules {
+    fn default() -> Self {
+        NameRules {
+            max_len: 256,
+            forbidden_chars: vec![],
+            unique_in_parent: false,
+            suffix_duplicates_on_import: false,
+        }
+    }
+}
+
+impl NameRules {
+    pub(crate) fn check(&self, name: &str) -> FlowyResult<()> {
+        let len = name.graphemes(true).count();
+        if len > self.max_len {
+            return Err(FlowyError::folder_name_too_long()
+                .context(format!("The name has {} characters, the max is {}", len, self.max_len)));
+        }
+
+        if let Some(c) = name.chars().find(|c| self.forbidden_chars.contains(c)) {
+            return Err(FlowyError::folder_name_forbidden_char().context(format!("The name can't contain '{}'", c)));
+        }
+        Ok(())
+    }
+
+    // `sibling_names` are the names of the other apps or views in the parent.
+    pub(crate) fn check_unique(&self, name: &str, sibling_names: &[String]) -> FlowyResult<()> {
+        if self.unique_in_parent && sibling_names.iter().any(|sibling| same_name(sibling, name)) {
+            return Err(FlowyError::folder_name_exists().context(format!("\"{}\" is used in the parent already", name)));
+        }
+        Ok(())
+    }
+
+    // The first of "Doc", "Doc (2)", "Doc (3)"... that isn't used in the
+    // parent.
+    pub(crate) fn free_name(&self, name: &str, sibling_names: &[String]) -> String {
+        let mut candidate = name.to_owned();
+        let mut n = 2;
+        while sibling_names.iter().any(|sibling| same_name(sibling, &candidate)) {
+            candidate = format!("{} ({})", name, n);
+            n += 1;
+        }
+        candidate
+    }
+}
+
+fn same_name(a: &str, b: &str) -> bool { a.trim().to_lowercase() == b.trim().to_lowercase() }
@@ -12,13 +12,35 @@ pub(crate) enum WorkspaceNotification {
     WorkspaceUpdated     = 12,
     WorkspaceListUpdated = 13,
     WorkspaceAppsChanged = 14,
+    WorkspaceMembersChanged = 15,
+    WorkspaceMemberJoined = 16,
+    OpenedWorkspacesChanged = 17,
+    WorkspaceSettingsChanged = 18,
     AppUpdated           = 21,
     AppViewsChanged      = 24,
+    AppDuplicated        = 25,
     ViewUpdated          = 31,
     ViewDeleted          = 32,
     ViewRestored         = 33,
+    ViewAccessChanged    = 34,
+    ViewsDeleted         = 35,
+    ViewsRestored        = 36,
+    ViewMoved            = 37,
+    ViewTagsChanged      = 38,
+    ItemsMoved           = 39,
+    GridUpdated          = 40,
+    BoardCardMoved       = 41,
+    CalendarEventsChanged = 42,
+    ChecklistUpdated     = 43,
+    GridRowsVisibilityChanged = 44,
+    ShareLinkCreated     = 45,
+    ImportProgress       = 50,
+    BackupFailed         = 51,
+    BackupRestoreFailed  = 52,
+    CloudOperationFailed = 53,
     UserUnauthorized     = 100,
     TrashUpdated         = 1000,
+    TrashRestored        = 1001,
 }
 
 impl std::default::Default for WorkspaceNotification {
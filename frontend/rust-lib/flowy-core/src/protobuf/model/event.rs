@@ -31,10 +31,26 @@ pub enum WorkspaceEvent {
     DeleteWorkspace = 3,
     OpenWorkspace = 4,
     ReadWorkspaceApps = 5,
+    ReadWorkspaceMembers = 6,
+    AddWorkspaceMember = 7,
+    UpdateWorkspaceMember = 8,
+    RemoveWorkspaceMember = 9,
+    CreateInvitation = 10,
+    ReadInvitations = 11,
+    AcceptInvitation = 12,
+    DeclineInvitation = 13,
+    ReadRevisionRetention = 14,
+    UpdateRevisionRetention = 15,
+    CloseWorkspace = 16,
+    ReadOpenedWorkspaces = 17,
+    ReadWorkspaceSetting = 18,
+    ReadWorkspaceSettings = 19,
+    UpdateWorkspaceSettings = 20,
     CreateApp = 101,
     DeleteApp = 102,
     ReadApp = 103,
     UpdateApp = 104,
+    DuplicateApp = 105,
     CreateView = 201,
     ReadView = 202,
     UpdateView = 203,
@@ -43,13 +59,88 @@ pub enum WorkspaceEvent {
     CopyLink = 206,
     OpenView = 207,
     CloseView = 208,
+    ReadViewAccess = 209,
+    GrantViewAccess = 210,
+    RevokeViewAccess = 211,
+    MoveView = 212,
+    ReadViewTags = 213,
+    UpdateViewTags = 214,
+    ReadAttachment = 215,
+    MoveItems = 216,
+    CreateViewCheckpoint = 217,
+    ReadViewCheckpoints = 218,
+    RestoreViewCheckpoint = 219,
+    DeleteViewCheckpoint = 220,
+    ExportQuarantinedRevisions = 221,
     ReadTrash = 300,
     PutbackTrash = 301,
     DeleteTrash = 302,
     RestoreAll = 303,
     DeleteAll = 304,
     ApplyDocDelta = 400,
+    ReadCodeBlockTokens = 401,
+    ReadCollapsedToggles = 402,
+    UpdateToggleState = 403,
     ExportDocument = 500,
+    ExportUserData = 501,
+    CreateShareLink = 502,
+    ReadShareLinks = 503,
+    RevokeShareLink = 504,
+    ReadSharedDocument = 505,
+    CreateGuestAccess = 506,
+    ReadGuestAccesses = 507,
+    RevokeGuestAccess = 508,
+    ImportCsv = 509,
+    ExportGrid = 510,
+    ImportNotion = 511,
+    ImportEnex = 512,
+    ImportVault = 513,
+    ExportOpml = 514,
+    ImportOpml = 515,
+    ExportCalendar = 516,
+    UnsubscribeCalendar = 517,
+    ExportSite = 518,
+    CreateWebhook = 600,
+    ReadWebhooks = 601,
+    DeleteWebhook = 602,
+    ReadEventLog = 700,
+    ReadAuditLog = 701,
+    ReadMetrics = 800,
+    CheckHealth = 801,
+    ExportDiagnostics = 802,
+    Shutdown = 803,
+    ReadGrid = 900,
+    CreateField = 901,
+    UpdateField = 902,
+    DeleteField = 903,
+    CreateRow = 904,
+    DeleteRow = 905,
+    UpdateCell = 906,
+    ReadBoard = 907,
+    MoveCard = 908,
+    ReadCalendarEvents = 909,
+    MoveCalendarEvent = 910,
+    ReadGridRows = 911,
+    UpdateGridSetting = 912,
+    ReadChecklist = 1000,
+    CreateChecklistItem = 1001,
+    UpdateChecklistItem = 1002,
+    DeleteChecklistItem = 1003,
+    ToggleChecklistItem = 1004,
+    MoveChecklistItem = 1005,
+    CompleteChecklistItems = 1006,
+    Search = 1100,
+    UnfurlLink = 1200,
+    ReadUnfurlSetting = 1201,
+    UpdateUnfurlSetting = 1202,
+    ReadBackupSetting = 1300,
+    UpdateBackupSetting = 1301,
+    RunBackup = 1302,
+    ReadBackups = 1303,
+    SelectBackupRestore = 1304,
+    ReadAttachmentStorage = 1400,
+    UpdateAttachmentStorage = 1401,
+    SyncAttachments = 1402,
 }
 
 impl ::protobuf::ProtobufEnum for WorkspaceEvent {
@@ -65,10 +156,26 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             3 => ::std::option::Option::Some(WorkspaceEvent::DeleteWorkspace),
             4 => ::std::option::Option::Some(WorkspaceEvent::OpenWorkspace),
             5 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceApps),
+            6 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceMembers),
+            7 => ::std::option::Option::Some(WorkspaceEvent::AddWorkspaceMember),
+            8 => ::std::option::Option::Some(WorkspaceEvent::UpdateWorkspaceMember),
+            9 => ::std::option::Option::Some(WorkspaceEvent::RemoveWorkspaceMember),
+            10 => ::std::option::Option::Some(WorkspaceEvent::CreateInvitation),
+            11 => ::std::option::Option::Some(WorkspaceEvent::ReadInvitations),
+            12 => ::std::option::Option::Some(WorkspaceEvent::AcceptInvitation),
+            13 => ::std::option::Option::Some(WorkspaceEvent::DeclineInvitation),
+            14 => ::std::option::Option::Some(WorkspaceEvent::ReadRevisionRetention),
+            15 => ::std::option::Option::Some(WorkspaceEvent::UpdateRevisionRetention),
+            16 => ::std::option::Option::Some(WorkspaceEvent::CloseWorkspace),
+            17 => ::std::option::Option::Some(WorkspaceEvent::ReadOpenedWorkspaces),
+            18 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceSetting),
+            19 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceSettings),
+            20 => ::std::option::Option::Some(WorkspaceEvent::UpdateWorkspaceSettings),
             101 => ::std::option::Option::Some(WorkspaceEvent::CreateApp),
             102 => ::std::option::Option::Some(WorkspaceEvent::DeleteApp),
             103 => ::std::option::Option::Some(WorkspaceEvent::ReadApp),
             104 => ::std::option::Option::Some(WorkspaceEvent::UpdateApp),
+            105 => ::std::option::Option::Some(WorkspaceEvent::DuplicateApp),
             201 => ::std::option::Option::Some(WorkspaceEvent::CreateView),
             202 => ::std::option::Option::Some(WorkspaceEvent::ReadView),
             203 => ::std::option::Option::Some(WorkspaceEvent::UpdateView),
@@ -77,13 +184,88 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             206 => ::std::option::Option::Some(WorkspaceEvent::CopyLink),
             207 => ::std::option::Option::Some(WorkspaceEvent::OpenView),
             208 => ::std::option::Option::Some(WorkspaceEvent::CloseView),
+            209 => ::std::option::Option::Some(WorkspaceEvent::ReadViewAccess),
+            210 => ::std::option::Option::Some(WorkspaceEvent::GrantViewAccess),
+            211 => ::std::option::Option::Some(WorkspaceEvent::RevokeViewAccess),
+            212 => ::std::option::Option::Some(WorkspaceEvent::MoveView),
+            213 => ::std::option::Option::Some(WorkspaceEvent::ReadViewTags),
+            214 => ::std::option::Option::Some(WorkspaceEvent::UpdateViewTags),
+            215 => ::std::option::Option::Some(WorkspaceEvent::ReadAttachment),
+            216 => ::std::option::Option::Some(WorkspaceEvent::MoveItems),
+            217 => ::std::option::Option::Some(WorkspaceEvent::CreateViewCheckpoint),
+            218 => ::std::option::Option::Some(WorkspaceEvent::ReadViewCheckpoints),
+            219 => ::std::option::Option::Some(WorkspaceEvent::RestoreViewCheckpoint),
+            220 => ::std::option::Option::Some(WorkspaceEvent::DeleteViewCheckpoint),
+            221 => ::std::option::Option::Some(WorkspaceEvent::ExportQuarantinedRevisions),
             300 => ::std::option::Option::Some(WorkspaceEvent::ReadTrash),
             301 => ::std::option::Option::Some(WorkspaceEvent::PutbackTrash),
             302 => ::std::option::Option::Some(WorkspaceEvent::DeleteTrash),
             303 => ::std::option::Option::Some(WorkspaceEvent::RestoreAll),
             304 => ::std::option::Option::Some(WorkspaceEvent::DeleteAll),
             400 => ::std::option::Option::Some(WorkspaceEvent::ApplyDocDelta),
+            401 => ::std::option::Option::Some(WorkspaceEvent::ReadCodeBlockTokens),
+            402 => ::std::option::Option::Some(WorkspaceEvent::ReadCollapsedToggles),
+            403 => ::std::option::Option::Some(WorkspaceEvent::UpdateToggleState),
             500 => ::std::option::Option::Some(WorkspaceEvent::ExportDocument),
+            501 => ::std::option::Option::Some(WorkspaceEvent::ExportUserData),
+            502 => ::std::option::Option::Some(WorkspaceEvent::CreateShareLink),
+            503 => ::std::option::Option::Some(WorkspaceEvent::ReadShareLinks),
+            504 => ::std::option::Option::Some(WorkspaceEvent::RevokeShareLink),
+            505 => ::std::option::Option::Some(WorkspaceEvent::ReadSharedDocument),
+            506 => ::std::option::Option::Some(WorkspaceEvent::CreateGuestAccess),
+            507 => ::std::option::Option::Some(WorkspaceEvent::ReadGuestAccesses),
+            508 => ::std::option::Option::Some(WorkspaceEvent::RevokeGuestAccess),
+            509 => ::std::option::Option::Some(WorkspaceEvent::ImportCsv),
+            510 => ::std::option::Option::Some(WorkspaceEvent::ExportGrid),
+            511 => ::std::option::Option::Some(WorkspaceEvent::ImportNotion),
+            512 => ::std::option::Option::Some(WorkspaceEvent::ImportEnex),
+            513 => ::std::option::Option::Some(WorkspaceEvent::ImportVault),
+            514 => ::std::option::Option::Some(WorkspaceEvent::ExportOpml),
+            515 => ::std::option::Option::Some(WorkspaceEvent::ImportOpml),
+            516 => ::std::option::Option::Some(WorkspaceEvent::ExportCalendar),
+            517 => ::std::option::Option::Some(WorkspaceEvent::UnsubscribeCalendar),
+            518 => ::std::option::Option::Some(WorkspaceEvent::ExportSite),
+            600 => ::std::option::Option::Some(WorkspaceEvent::CreateWebhook),
+            601 => ::std::option::Option::Some(WorkspaceEvent::ReadWebhooks),
+            602 => ::std::option::Option::Some(WorkspaceEvent::DeleteWebhook),
+            700 => ::std::option::Option::Some(WorkspaceEvent::ReadEventLog),
+            701 => ::std::option::Option::Some(WorkspaceEvent::ReadAuditLog),
+            800 => ::std::option::Option::Some(WorkspaceEvent::ReadMetrics),
+            801 => ::std::option::Option::Some(WorkspaceEvent::CheckHealth),
+            802 => ::std::option::Option::Some(WorkspaceEvent::ExportDiagnostics),
+            803 => ::std::option::Option::Some(WorkspaceEvent::Shutdown),
+            900 => ::std::option::Option::Some(WorkspaceEvent::ReadGrid),
+            901 => ::std::option::Option::Some(WorkspaceEvent::CreateField),
+            902 => ::std::option::Option::Some(WorkspaceEvent::UpdateField),
+            903 => ::std::option::Option::Some(WorkspaceEvent::DeleteField),
+            904 => ::std::option::Option::Some(WorkspaceEvent::CreateRow),
+            905 => ::std::option::Option::Some(WorkspaceEvent::DeleteRow),
+            906 => ::std::option::Option::Some(WorkspaceEvent::UpdateCell),
+            907 => ::std::option::Option::Some(WorkspaceEvent::ReadBoard),
+            908 => ::std::option::Option::Some(WorkspaceEvent::MoveCard),
+            909 => ::std::option::Option::Some(WorkspaceEvent::ReadCalendarEvents),
+            910 => ::std::option::Option::Some(WorkspaceEvent::MoveCalendarEvent),
+            911 => ::std::option::Option::Some(WorkspaceEvent::ReadGridRows),
+            912 => ::std::option::Option::Some(WorkspaceEvent::UpdateGridSetting),
+            1000 => ::std::option::Option::Some(WorkspaceEvent::ReadChecklist),
+            1001 => ::std::option::Option::Some(WorkspaceEvent::CreateChecklistItem),
+            1002 => ::std::option::Option::Some(WorkspaceEvent::UpdateChecklistItem),
+            1003 => ::std::option::Option::Some(WorkspaceEvent::DeleteChecklistItem),
+            1004 => ::std::option::Option::Some(WorkspaceEvent::ToggleChecklistItem),
+            1005 => ::std::option::Option::Some(WorkspaceEvent::MoveChecklistItem),
+            1006 => ::std::option::Option::Some(WorkspaceEvent::CompleteChecklistItems),
+            1100 => ::std::option::Option::Some(WorkspaceEvent::Search),
+            1200 => ::std::option::Option::Some(WorkspaceEvent::UnfurlLink),
+            1201 => ::std::option::Option::Some(WorkspaceEvent::ReadUnfurlSetting),
+            1202 => ::std::option::Option::Some(WorkspaceEvent::UpdateUnfurlSetting),
+            1300 => ::std::option::Option::Some(WorkspaceEvent::ReadBackupSetting),
+            1301 => ::std::option::Option::Some(WorkspaceEvent::UpdateBackupSetting),
+            1302 => ::std::option::Option::Some(WorkspaceEvent::RunBackup),
+            1303 => ::std::option::Option::Some(WorkspaceEvent::ReadBackups),
+            1304 => ::std::option::Option::Some(WorkspaceEvent::SelectBackupRestore),
+            1400 => ::std::option::Option::Some(WorkspaceEvent::ReadAttachmentStorage),
+            1401 => ::std::option::Option::Some(WorkspaceEvent::UpdateAttachmentStorage),
+            1402 => ::std::option::Option::Some(WorkspaceEvent::SyncAttachments),
             _ => ::std::option::Option::None
         }
     }
@@ -96,10 +278,26 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             WorkspaceEvent::DeleteWorkspace,
             WorkspaceEvent::OpenWorkspace,
             WorkspaceEvent::ReadWorkspaceApps,
+            WorkspaceEvent::ReadWorkspaceMembers,
+            WorkspaceEvent::AddWorkspaceMember,
+            WorkspaceEvent::UpdateWorkspaceMember,
+            WorkspaceEvent::RemoveWorkspaceMember,
+            WorkspaceEvent::CreateInvitation,
+            WorkspaceEvent::ReadInvitations,
+            WorkspaceEvent::AcceptInvitation,
+            WorkspaceEvent::DeclineInvitation,
+            WorkspaceEvent::ReadRevisionRetention,
+            WorkspaceEvent::UpdateRevisionRetention,
+            WorkspaceEvent::CloseWorkspace,
+            WorkspaceEvent::ReadOpenedWorkspaces,
+            WorkspaceEvent::ReadWorkspaceSetting,
+            WorkspaceEvent::ReadWorkspaceSettings,
+            WorkspaceEvent::UpdateWorkspaceSettings,
             WorkspaceEvent::CreateApp,
             WorkspaceEvent::DeleteApp,
             WorkspaceEvent::ReadApp,
             WorkspaceEvent::UpdateApp,
+            WorkspaceEvent::DuplicateApp,
             WorkspaceEvent::CreateView,
             WorkspaceEvent::ReadView,
             WorkspaceEvent::UpdateView,
@@ -108,13 +306,88 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             WorkspaceEvent::CopyLink,
             WorkspaceEvent::OpenView,
             WorkspaceEvent::CloseView,
+            WorkspaceEvent::ReadViewAccess,
+            WorkspaceEvent::GrantViewAccess,
+            WorkspaceEvent::RevokeViewAccess,
+            WorkspaceEvent::MoveView,
+            WorkspaceEvent::ReadViewTags,
+            WorkspaceEvent::UpdateViewTags,
+            WorkspaceEvent::ReadAttachment,
+            WorkspaceEvent::MoveItems,
+            WorkspaceEvent::CreateViewCheckpoint,
+            WorkspaceEvent::ReadViewCheckpoints,
+            WorkspaceEvent::RestoreViewCheckpoint,
+            WorkspaceEvent::DeleteViewCheckpoint,
+            WorkspaceEvent::ExportQuarantinedRevisions,
             WorkspaceEvent::ReadTrash,
             WorkspaceEvent::PutbackTrash,
             WorkspaceEvent::DeleteTrash,
             WorkspaceEvent::RestoreAll,
             WorkspaceEvent::DeleteAll,
             WorkspaceEvent::ApplyDocDelta,
+            WorkspaceEvent::ReadCodeBlockTokens,
+            WorkspaceEvent::ReadCollapsedToggles,
+            WorkspaceEvent::UpdateToggleState,
             WorkspaceEvent::ExportDocument,
+            WorkspaceEvent::ExportUserData,
+            WorkspaceEvent::CreateShareLink,
+            WorkspaceEvent::ReadShareLinks,
+            WorkspaceEvent::RevokeShareLink,
+            WorkspaceEvent::ReadSharedDocument,
+            WorkspaceEvent::CreateGuestAccess,
+            WorkspaceEvent::ReadGuestAccesses,
+            WorkspaceEvent::RevokeGuestAccess,
+            WorkspaceEvent::ImportCsv,
+            WorkspaceEvent::ExportGrid,
+            WorkspaceEvent::ImportNotion,
+            WorkspaceEvent::ImportEnex,
+            WorkspaceEvent::ImportVault,
+            WorkspaceEvent::ExportOpml,
+            WorkspaceEvent::ImportOpml,
+            WorkspaceEvent::ExportCalendar,
+            WorkspaceEvent::UnsubscribeCalendar,
+            WorkspaceEvent::ExportSite,
+            WorkspaceEvent::CreateWebhook,
+            WorkspaceEvent::ReadWebhooks,
+            WorkspaceEvent::DeleteWebhook,
+            WorkspaceEvent::ReadEventLog,
+            WorkspaceEvent::ReadAuditLog,
+            WorkspaceEvent::ReadMetrics,
+            WorkspaceEvent::CheckHealth,
+            WorkspaceEvent::ExportDiagnostics,
+            WorkspaceEvent::Shutdown,
+            WorkspaceEvent::ReadGrid,
+            WorkspaceEvent::CreateField,
+            WorkspaceEvent::UpdateField,
+            WorkspaceEvent::DeleteField,
+            WorkspaceEvent::CreateRow,
+            WorkspaceEvent::DeleteRow,
+            WorkspaceEvent::UpdateCell,
+            WorkspaceEvent::ReadBoard,
+            WorkspaceEvent::MoveCard,
+            WorkspaceEvent::ReadCalendarEvents,
+            WorkspaceEvent::MoveCalendarEvent,
+            WorkspaceEvent::ReadGridRows,
+            WorkspaceEvent::UpdateGridSetting,
+            WorkspaceEvent::ReadChecklist,
+            WorkspaceEvent::CreateChecklistItem,
+            WorkspaceEvent::UpdateChecklistItem,
+            WorkspaceEvent::DeleteChecklistItem,
+            WorkspaceEvent::ToggleChecklistItem,
+            WorkspaceEvent::MoveChecklistItem,
+            WorkspaceEvent::CompleteChecklistItems,
+            WorkspaceEvent::Search,
+            WorkspaceEvent::UnfurlLink,
+            WorkspaceEvent::ReadUnfurlSetting,
+            WorkspaceEvent::UpdateUnfurlSetting,
+            WorkspaceEvent::ReadBackupSetting,
+            WorkspaceEvent::UpdateBackupSetting,
+            WorkspaceEvent::RunBackup,
+            WorkspaceEvent::ReadBackups,
+            WorkspaceEvent::SelectBackupRestore,
+            WorkspaceEvent::ReadAttachmentStorage,
+            WorkspaceEvent::UpdateAttachmentStorage,
+            WorkspaceEvent::SyncAttachments,
         ];
         values
     }
@@ -143,72 +416,70 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceEvent {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0bevent.proto*\xcb\x03\n\x0eWorkspaceEvent\x12\x13\n\x0fCreateWorksp\
+    \n\x0bevent.proto*\x9b\x13\n\x0eWorkspaceEvent\x12\x13\n\x0fCreateWorksp\
     ace\x10\0\x12\x14\n\x10ReadCurWorkspace\x10\x01\x12\x12\n\x0eReadWorkspa\
     ces\x10\x02\x12\x13\n\x0fDeleteWorkspace\x10\x03\x12\x11\n\rOpenWorkspac\
-    e\x10\x04\x12\x15\n\x11ReadWorkspaceApps\x10\x05\x12\r\n\tCreateApp\x10e\
-    \x12\r\n\tDeleteApp\x10f\x12\x0b\n\x07ReadApp\x10g\x12\r\n\tUpdateApp\
-    \x10h\x12\x0f\n\nCreateView\x10\xc9\x01\x12\r\n\x08ReadView\x10\xca\x01\
+    e\x10\x04\x12\x15\n\x11ReadWorkspaceApps\x10\x05\x12\x18\n\x14ReadWorksp\
+    aceMembers\x10\x06\x12\x16\n\x12AddWorkspaceMember\x10\x07\x12\x19\n\x15\
+    UpdateWorkspaceMember\x10\x08\x12\x19\n\x15RemoveWorkspaceMember\x10\t\
+    \x12\x14\n\x10CreateInvitation\x10\n\x12\x13\n\x0fReadInvitations\x10\
+    \x0b\x12\x14\n\x10AcceptInvitation\x10\x0c\x12\x15\n\x11DeclineInvitatio\
+    n\x10\r\x12\x19\n\x15ReadRevisionRetention\x10\x0e\x12\x1b\n\x17UpdateRe\
+    visionRetention\x10\x0f\x12\x12\n\x0eCloseWorkspace\x10\x10\x12\x18\n\
+    \x14ReadOpenedWorkspaces\x10\x11\x12\x18\n\x14ReadWorkspaceSetting\x10\
+    \x12\x12\x19\n\x15ReadWorkspaceSettings\x10\x13\x12\x1b\n\x17UpdateWorks\
+    paceSettings\x10\x14\x12\r\n\tCreateApp\x10e\x12\r\n\tDeleteApp\x10f\x12\
+    \x0b\n\x07ReadApp\x10g\x12\r\n\tUpdateApp\x10h\x12\x10\n\x0cDuplicateApp\
+    \x10i\x12\x0f\n\nCreateView\x10\xc9\x01\x12\r\n\x08ReadView\x10\xca\x01\
     \x12\x0f\n\nUpdateView\x10\xcb\x01\x12\x0f\n\nDeleteView\x10\xcc\x01\x12\
     \x12\n\rDuplicateView\x10\xcd\x01\x12\r\n\x08CopyLink\x10\xce\x01\x12\r\
-    \n\x08OpenView\x10\xcf\x01\x12\x0e\n\tCloseView\x10\xd0\x01\x12\x0e\n\tR\
-    eadTrash\x10\xac\x02\x12\x11\n\x0cPutbackTrash\x10\xad\x02\x12\x10\n\x0b\
-    DeleteTrash\x10\xae\x02\x12\x0f\n\nRestoreAll\x10\xaf\x02\x12\x0e\n\tDel\
-    eteAll\x10\xb0\x02\x12\x12\n\rApplyDocDelta\x10\x90\x03\x12\x13\n\x0eExp\
-    ortDocument\x10\xf4\x03J\xab\x08\n\x06\x12\x04\0\0\x1c\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x1c\x01\n\n\n\x03\x05\
-    \0\x01\x12\x03\x02\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x18\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x13\n\x0c\n\x05\x05\0\x02\0\
-    \x02\x12\x03\x03\x16\x17\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x19\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\x05\0\x02\
-    \x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\
-    \x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x12\n\x0c\n\x05\x05\0\
-    \x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\
-    \x04\x18\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x13\n\x0c\n\x05\
-    \x05\0\x02\x03\x02\x12\x03\x06\x16\x17\n\x0b\n\x04\x05\0\x02\x04\x12\x03\
-    \x07\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\x04\x02\x12\x03\x07\x14\x15\n\x0b\n\x04\x05\0\x02\x05\x12\
-    \x03\x08\x04\x1a\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x15\n\x0c\
-    \n\x05\x05\0\x02\x05\x02\x12\x03\x08\x18\x19\n\x0b\n\x04\x05\0\x02\x06\
-    \x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\r\n\x0c\n\
-    \x05\x05\0\x02\x06\x02\x12\x03\t\x10\x13\n\x0b\n\x04\x05\0\x02\x07\x12\
-    \x03\n\x04\x14\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\r\n\x0c\n\x05\
-    \x05\0\x02\x07\x02\x12\x03\n\x10\x13\n\x0b\n\x04\x05\0\x02\x08\x12\x03\
-    \x0b\x04\x12\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x0b\n\x0c\n\
-    \x05\x05\0\x02\x08\x02\x12\x03\x0b\x0e\x11\n\x0b\n\x04\x05\0\x02\t\x12\
-    \x03\x0c\x04\x14\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\r\n\x0c\n\
-    \x05\x05\0\x02\t\x02\x12\x03\x0c\x10\x13\n\x0b\n\x04\x05\0\x02\n\x12\x03\
-    \r\x04\x15\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x0e\n\x0c\n\x05\x05\
-    \0\x02\n\x02\x12\x03\r\x11\x14\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\
-    \x13\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x0c\n\x0c\n\x05\x05\0\
-    \x02\x0b\x02\x12\x03\x0e\x0f\x12\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\
-    \x04\x15\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x0e\n\x0c\n\x05\
-    \x05\0\x02\x0c\x02\x12\x03\x0f\x11\x14\n\x0b\n\x04\x05\0\x02\r\x12\x03\
-    \x10\x04\x15\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\x10\x04\x0e\n\x0c\n\x05\
-    \x05\0\x02\r\x02\x12\x03\x10\x11\x14\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\
-    \x11\x04\x18\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\x11\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\x0e\x02\x12\x03\x11\x14\x17\n\x0b\n\x04\x05\0\x02\x0f\x12\
-    \x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x12\x04\x0c\n\x0c\
-    \n\x05\x05\0\x02\x0f\x02\x12\x03\x12\x0f\x12\n\x0b\n\x04\x05\0\x02\x10\
-    \x12\x03\x13\x04\x13\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x0c\n\
-    \x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\x0f\x12\n\x0b\n\x04\x05\0\x02\
-    \x11\x12\x03\x14\x04\x14\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03\x14\x04\r\
-    \n\x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x14\x10\x13\n\x0b\n\x04\x05\0\x02\
-    \x12\x12\x03\x15\x04\x14\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x15\x04\r\
-    \n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\x15\x10\x13\n\x0b\n\x04\x05\0\x02\
-    \x13\x12\x03\x16\x04\x17\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x16\x04\
-    \x10\n\x0c\n\x05\x05\0\x02\x13\x02\x12\x03\x16\x13\x16\n\x0b\n\x04\x05\0\
-    \x02\x14\x12\x03\x17\x04\x16\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\x17\
-    \x04\x0f\n\x0c\n\x05\x05\0\x02\x14\x02\x12\x03\x17\x12\x15\n\x0b\n\x04\
-    \x05\0\x02\x15\x12\x03\x18\x04\x15\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x03\
-    \x18\x04\x0e\n\x0c\n\x05\x05\0\x02\x15\x02\x12\x03\x18\x11\x14\n\x0b\n\
-    \x04\x05\0\x02\x16\x12\x03\x19\x04\x14\n\x0c\n\x05\x05\0\x02\x16\x01\x12\
-    \x03\x19\x04\r\n\x0c\n\x05\x05\0\x02\x16\x02\x12\x03\x19\x10\x13\n\x0b\n\
-    \x04\x05\0\x02\x17\x12\x03\x1a\x04\x18\n\x0c\n\x05\x05\0\x02\x17\x01\x12\
-    \x03\x1a\x04\x11\n\x0c\n\x05\x05\0\x02\x17\x02\x12\x03\x1a\x14\x17\n\x0b\
-    \n\x04\x05\0\x02\x18\x12\x03\x1b\x04\x19\n\x0c\n\x05\x05\0\x02\x18\x01\
-    \x12\x03\x1b\x04\x12\n\x0c\n\x05\x05\0\x02\x18\x02\x12\x03\x1b\x15\x18b\
-    \x06proto3\
+    \n\x08OpenView\x10\xcf\x01\x12\x0e\n\tCloseView\x10\xd0\x01\x12\x13\n\
+    \x0eReadViewAccess\x10\xd1\x01\x12\x14\n\x0fGrantViewAccess\x10\xd2\x01\
+    \x12\x15\n\x10RevokeViewAccess\x10\xd3\x01\x12\r\n\x08MoveView\x10\xd4\
+    \x01\x12\x11\n\x0cReadViewTags\x10\xd5\x01\x12\x13\n\x0eUpdateViewTags\
+    \x10\xd6\x01\x12\x13\n\x0eReadAttachment\x10\xd7\x01\x12\x0e\n\tMoveItem\
+    s\x10\xd8\x01\x12\x19\n\x14CreateViewCheckpoint\x10\xd9\x01\x12\x18\n\
+    \x13ReadViewCheckpoints\x10\xda\x01\x12\x1a\n\x15RestoreViewCheckpoint\
+    \x10\xdb\x01\x12\x19\n\x14DeleteViewCheckpoint\x10\xdc\x01\x12\x1f\n\x1a\
+    ExportQuarantinedRevisions\x10\xdd\x01\x12\x0e\n\tReadTrash\x10\xac\x02\
+    \x12\x11\n\x0cPutbackTrash\x10\xad\x02\x12\x10\n\x0bDeleteTrash\x10\xae\
+    \x02\x12\x0f\n\nRestoreAll\x10\xaf\x02\x12\x0e\n\tDeleteAll\x10\xb0\x02\
+    \x12\x12\n\rApplyDocDelta\x10\x90\x03\x12\x18\n\x13ReadCodeBlockTokens\
+    \x10\x91\x03\x12\x19\n\x14ReadCollapsedToggles\x10\x92\x03\x12\x16\n\x11\
+    UpdateToggleState\x10\x93\x03\x12\x13\n\x0eExportDocument\x10\xf4\x03\
+    \x12\x13\n\x0eExportUserData\x10\xf5\x03\x12\x14\n\x0fCreateShareLink\
+    \x10\xf6\x03\x12\x13\n\x0eReadShareLinks\x10\xf7\x03\x12\x14\n\x0fRevoke\
+    ShareLink\x10\xf8\x03\x12\x17\n\x12ReadSharedDocument\x10\xf9\x03\x12\
+    \x16\n\x11CreateGuestAccess\x10\xfa\x03\x12\x16\n\x11ReadGuestAccesses\
+    \x10\xfb\x03\x12\x16\n\x11RevokeGuestAccess\x10\xfc\x03\x12\x0e\n\tImpor\
+    tCsv\x10\xfd\x03\x12\x0f\n\nExportGrid\x10\xfe\x03\x12\x11\n\x0cImportNo\
+    tion\x10\xff\x03\x12\x0f\n\nImportEnex\x10\x80\x04\x12\x10\n\x0bImportVa\
+    ult\x10\x81\x04\x12\x0f\n\nExportOpml\x10\x82\x04\x12\x0f\n\nImportOpml\
+    \x10\x83\x04\x12\x13\n\x0eExportCalendar\x10\x84\x04\x12\x18\n\x13Unsubs\
+    cribeCalendar\x10\x85\x04\x12\x0f\n\nExportSite\x10\x86\x04\x12\x12\n\rC\
+    reateWebhook\x10\xd8\x04\x12\x11\n\x0cReadWebhooks\x10\xd9\x04\x12\x12\n\
+    \rDeleteWebhook\x10\xda\x04\x12\x11\n\x0cReadEventLog\x10\xbc\x05\x12\
+    \x11\n\x0cReadAuditLog\x10\xbd\x05\x12\x10\n\x0bReadMetrics\x10\xa0\x06\
+    \x12\x10\n\x0bCheckHealth\x10\xa1\x06\x12\x16\n\x11ExportDiagnostics\x10\
+    \xa2\x06\x12\r\n\x08Shutdown\x10\xa3\x06\x12\r\n\x08ReadGrid\x10\x84\x07\
+    \x12\x10\n\x0bCreateField\x10\x85\x07\x12\x10\n\x0bUpdateField\x10\x86\
+    \x07\x12\x10\n\x0bDeleteField\x10\x87\x07\x12\x0e\n\tCreateRow\x10\x88\
+    \x07\x12\x0e\n\tDeleteRow\x10\x89\x07\x12\x0f\n\nUpdateCell\x10\x8a\x07\
+    \x12\x0e\n\tReadBoard\x10\x8b\x07\x12\r\n\x08MoveCard\x10\x8c\x07\x12\
+    \x17\n\x12ReadCalendarEvents\x10\x8d\x07\x12\x16\n\x11MoveCalendarEvent\
+    \x10\x8e\x07\x12\x11\n\x0cReadGridRows\x10\x8f\x07\x12\x16\n\x11UpdateGr\
+    idSetting\x10\x90\x07\x12\x12\n\rReadChecklist\x10\xe8\x07\x12\x18\n\x13\
+    CreateChecklistItem\x10\xe9\x07\x12\x18\n\x13UpdateChecklistItem\x10\xea\
+    \x07\x12\x18\n\x13DeleteChecklistItem\x10\xeb\x07\x12\x18\n\x13ToggleChe\
+    cklistItem\x10\xec\x07\x12\x16\n\x11MoveChecklistItem\x10\xed\x07\x12\
+    \x1b\n\x16CompleteChecklistItems\x10\xee\x07\x12\x0b\n\x06Search\x10\xcc\
+    \x08\x12\x0f\n\nUnfurlLink\x10\xb0\t\x12\x16\n\x11ReadUnfurlSetting\x10\
+    \xb1\t\x12\x18\n\x13UpdateUnfurlSetting\x10\xb2\t\x12\x16\n\x11ReadBacku\
+    pSetting\x10\x94\n\x12\x18\n\x13UpdateBackupSetting\x10\x95\n\x12\x0e\n\
+    \tRunBackup\x10\x96\n\x12\x10\n\x0bReadBackups\x10\x97\n\x12\x18\n\x13Se\
+    lectBackupRestore\x10\x98\n\x12\x1a\n\x15ReadAttachmentStorage\x10\xf8\n\
+    \x12\x1c\n\x17UpdateAttachmentStorage\x10\xf9\n\x12\x14\n\x0fSyncAttachm\
+    ents\x10\xfa\n\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
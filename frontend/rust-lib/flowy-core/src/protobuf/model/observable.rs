@@ -31,13 +31,35 @@ pub enum WorkspaceNotification {
     WorkspaceUpdated = 12,
     WorkspaceListUpdated = 13,
     WorkspaceAppsChanged = 14,
+    WorkspaceMembersChanged = 15,
+    WorkspaceMemberJoined = 16,
+    OpenedWorkspacesChanged = 17,
+    WorkspaceSettingsChanged = 18,
     AppUpdated = 21,
     AppViewsChanged = 24,
+    AppDuplicated = 25,
     ViewUpdated = 31,
     ViewDeleted = 32,
     ViewRestored = 33,
+    ViewAccessChanged = 34,
+    ViewsDeleted = 35,
+    ViewsRestored = 36,
+    ViewMoved = 37,
+    ViewTagsChanged = 38,
+    ItemsMoved = 39,
+    GridUpdated = 40,
+    BoardCardMoved = 41,
+    CalendarEventsChanged = 42,
+    ChecklistUpdated = 43,
+    GridRowsVisibilityChanged = 44,
+    ShareLinkCreated = 45,
+    ImportProgress = 50,
+    BackupFailed = 51,
+    BackupRestoreFailed = 52,
+    CloudOperationFailed = 53,
     UserUnauthorized = 100,
     TrashUpdated = 1000,
+    TrashRestored = 1001,
 }
 
 impl ::protobuf::ProtobufEnum for WorkspaceNotification {
@@ -53,13 +75,35 @@ impl ::protobuf::ProtobufEnum for WorkspaceNotification {
             12 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceUpdated),
             13 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceListUpdated),
             14 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceAppsChanged),
+            15 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceMembersChanged),
+            16 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceMemberJoined),
+            17 => ::std::option::Option::Some(WorkspaceNotification::OpenedWorkspacesChanged),
+            18 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceSettingsChanged),
             21 => ::std::option::Option::Some(WorkspaceNotification::AppUpdated),
             24 => ::std::option::Option::Some(WorkspaceNotification::AppViewsChanged),
+            25 => ::std::option::Option::Some(WorkspaceNotification::AppDuplicated),
             31 => ::std::option::Option::Some(WorkspaceNotification::ViewUpdated),
             32 => ::std::option::Option::Some(WorkspaceNotification::ViewDeleted),
             33 => ::std::option::Option::Some(WorkspaceNotification::ViewRestored),
+            34 => ::std::option::Option::Some(WorkspaceNotification::ViewAccessChanged),
+            35 => ::std::option::Option::Some(WorkspaceNotification::ViewsDeleted),
+            36 => ::std::option::Option::Some(WorkspaceNotification::ViewsRestored),
+            37 => ::std::option::Option::Some(WorkspaceNotification::ViewMoved),
+            38 => ::std::option::Option::Some(WorkspaceNotification::ViewTagsChanged),
+            39 => ::std::option::Option::Some(WorkspaceNotification::ItemsMoved),
+            40 => ::std::option::Option::Some(WorkspaceNotification::GridUpdated),
+            41 => ::std::option::Option::Some(WorkspaceNotification::BoardCardMoved),
+            42 => ::std::option::Option::Some(WorkspaceNotification::CalendarEventsChanged),
+            43 => ::std::option::Option::Some(WorkspaceNotification::ChecklistUpdated),
+            44 => ::std::option::Option::Some(WorkspaceNotification::GridRowsVisibilityChanged),
+            45 => ::std::option::Option::Some(WorkspaceNotification::ShareLinkCreated),
+            50 => ::std::option::Option::Some(WorkspaceNotification::ImportProgress),
+            51 => ::std::option::Option::Some(WorkspaceNotification::BackupFailed),
+            52 => ::std::option::Option::Some(WorkspaceNotification::BackupRestoreFailed),
+            53 => ::std::option::Option::Some(WorkspaceNotification::CloudOperationFailed),
             100 => ::std::option::Option::Some(WorkspaceNotification::UserUnauthorized),
             1000 => ::std::option::Option::Some(WorkspaceNotification::TrashUpdated),
+            1001 => ::std::option::Option::Some(WorkspaceNotification::TrashRestored),
             _ => ::std::option::Option::None
         }
     }
@@ -72,13 +116,35 @@ impl ::protobuf::ProtobufEnum for WorkspaceNotification {
             WorkspaceNotification::WorkspaceUpdated,
             WorkspaceNotification::WorkspaceListUpdated,
             WorkspaceNotification::WorkspaceAppsChanged,
+            WorkspaceNotification::WorkspaceMembersChanged,
+            WorkspaceNotification::WorkspaceMemberJoined,
+            WorkspaceNotification::OpenedWorkspacesChanged,
+            WorkspaceNotification::WorkspaceSettingsChanged,
             WorkspaceNotification::AppUpdated,
             WorkspaceNotification::AppViewsChanged,
+            WorkspaceNotification::AppDuplicated,
             WorkspaceNotification::ViewUpdated,
             WorkspaceNotification::ViewDeleted,
             WorkspaceNotification::ViewRestored,
+            WorkspaceNotification::ViewAccessChanged,
+            WorkspaceNotification::ViewsDeleted,
+            WorkspaceNotification::ViewsRestored,
+            WorkspaceNotification::ViewMoved,
+            WorkspaceNotification::ViewTagsChanged,
+            WorkspaceNotification::ItemsMoved,
+            WorkspaceNotification::GridUpdated,
+            WorkspaceNotification::BoardCardMoved,
+            WorkspaceNotification::CalendarEventsChanged,
+            WorkspaceNotification::ChecklistUpdated,
+            WorkspaceNotification::GridRowsVisibilityChanged,
+            WorkspaceNotification::ShareLinkCreated,
+            WorkspaceNotification::ImportProgress,
+            WorkspaceNotification::BackupFailed,
+            WorkspaceNotification::BackupRestoreFailed,
+            WorkspaceNotification::CloudOperationFailed,
             WorkspaceNotification::UserUnauthorized,
             WorkspaceNotification::TrashUpdated,
+            WorkspaceNotification::TrashRestored,
         ];
         values
     }
@@ -107,42 +173,25 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceNotification {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10observable.proto*\xa2\x02\n\x15WorkspaceNotification\x12\x0b\n\x07\
+    \n\x10observable.proto*\x92\x06\n\x15WorkspaceNotification\x12\x0b\n\x07\
     Unknown\x10\0\x12\x17\n\x13UserCreateWorkspace\x10\n\x12\x17\n\x13UserDe\
     leteWorkspace\x10\x0b\x12\x14\n\x10WorkspaceUpdated\x10\x0c\x12\x18\n\
     \x14WorkspaceListUpdated\x10\r\x12\x18\n\x14WorkspaceAppsChanged\x10\x0e\
-    \x12\x0e\n\nAppUpdated\x10\x15\x12\x13\n\x0fAppViewsChanged\x10\x18\x12\
+    \x12\x1b\n\x17WorkspaceMembersChanged\x10\x0f\x12\x19\n\x15WorkspaceMemb\
+    erJoined\x10\x10\x12\x1b\n\x17OpenedWorkspacesChanged\x10\x11\x12\x1c\n\
+    \x18WorkspaceSettingsChanged\x10\x12\x12\x0e\n\nAppUpdated\x10\x15\x12\
+    \x13\n\x0fAppViewsChanged\x10\x18\x12\x11\n\rAppDuplicated\x10\x19\x12\
     \x0f\n\x0bViewUpdated\x10\x1f\x12\x0f\n\x0bViewDeleted\x10\x20\x12\x10\n\
-    \x0cViewRestored\x10!\x12\x14\n\x10UserUnauthorized\x10d\x12\x11\n\x0cTr\
-    ashUpdated\x10\xe8\x07J\xbf\x04\n\x06\x12\x04\0\0\x10\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x10\x01\n\n\n\x03\x05\
-    \0\x01\x12\x03\x02\x05\x1a\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x10\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0b\n\x0c\n\x05\x05\0\x02\0\
-    \x02\x12\x03\x03\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x1d\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x17\n\x0c\n\x05\x05\0\x02\
-    \x01\x02\x12\x03\x04\x1a\x1c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\
-    \x1d\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x17\n\x0c\n\x05\x05\0\
-    \x02\x02\x02\x12\x03\x05\x1a\x1c\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\
-    \x04\x1a\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x14\n\x0c\n\x05\
-    \x05\0\x02\x03\x02\x12\x03\x06\x17\x19\n\x0b\n\x04\x05\0\x02\x04\x12\x03\
-    \x07\x04\x1e\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x18\n\x0c\n\
-    \x05\x05\0\x02\x04\x02\x12\x03\x07\x1b\x1d\n\x0b\n\x04\x05\0\x02\x05\x12\
-    \x03\x08\x04\x1e\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x18\n\x0c\
-    \n\x05\x05\0\x02\x05\x02\x12\x03\x08\x1b\x1d\n\x0b\n\x04\x05\0\x02\x06\
-    \x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\x0e\n\x0c\
-    \n\x05\x05\0\x02\x06\x02\x12\x03\t\x11\x13\n\x0b\n\x04\x05\0\x02\x07\x12\
-    \x03\n\x04\x19\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x07\x02\x12\x03\n\x16\x18\n\x0b\n\x04\x05\0\x02\x08\x12\
-    \x03\x0b\x04\x15\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x0f\n\x0c\
-    \n\x05\x05\0\x02\x08\x02\x12\x03\x0b\x12\x14\n\x0b\n\x04\x05\0\x02\t\x12\
-    \x03\x0c\x04\x15\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\x0f\n\x0c\n\
-    \x05\x05\0\x02\t\x02\x12\x03\x0c\x12\x14\n\x0b\n\x04\x05\0\x02\n\x12\x03\
-    \r\x04\x16\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x10\n\x0c\n\x05\x05\
-    \0\x02\n\x02\x12\x03\r\x13\x15\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\
-    \x1b\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x14\n\x0c\n\x05\x05\0\
-    \x02\x0b\x02\x12\x03\x0e\x17\x1a\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\
-    \x04\x18\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x10\n\x0c\n\x05\
-    \x05\0\x02\x0c\x02\x12\x03\x0f\x13\x17b\x06proto3\
+    \x0cViewRestored\x10!\x12\x15\n\x11ViewAccessChanged\x10\"\x12\x10\n\x0c\
+    ViewsDeleted\x10#\x12\x11\n\rViewsRestored\x10$\x12\r\n\tViewMoved\x10%\
+    \x12\x13\n\x0fViewTagsChanged\x10&\x12\x0e\n\nItemsMoved\x10'\x12\x0f\n\
+    \x0bGridUpdated\x10(\x12\x12\n\x0eBoardCardMoved\x10)\x12\x19\n\x15Calen\
+    darEventsChanged\x10*\x12\x14\n\x10ChecklistUpdated\x10+\x12\x1d\n\x19Gr\
+    idRowsVisibilityChanged\x10,\x12\x14\n\x10ShareLinkCreated\x10-\x12\x12\
+    \n\x0eImportProgress\x102\x12\x10\n\x0cBackupFailed\x103\x12\x17\n\x13Ba\
+    ckupRestoreFailed\x104\x12\x18\n\x14CloudOperationFailed\x105\x12\x14\n\
+    \x10UserUnauthorized\x10d\x12\x11\n\x0cTrashUpdated\x10\xe8\x07\x12\x12\
+    \n\rTrashRestored\x10\xe9\x07\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,215 @@
+use crate::{
+    errors::{FlowyError, FlowyResult, RecoveryHint},
+    module::WorkspaceUser,
+    services::server::{BatchServer, BatchServerAdapter, Server, WorkspaceServerMock},
+    util::RetryAction,
+};
+use backend_service::configuration::ClientServerConfiguration;
+use lib_infra::{
+    clock::Clock,
+    retry::{jitter, RetryIf},
+};
+use std::{future::Future, sync::Arc, time::Duration};
+
+/// How many times a failed call to the server is retried and how long it
+/// waits in between. Only the transient errors, e.g. a dropped connection,
+/// are retried.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub max_retries: usize,
+
+    /// The wait before the first retry, it doubles with every retry.
+    pub initial_delay: Duration,
+
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn never() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            initial_delay: Duration::from_millis(0),
+            max_delay: Duration::from_millis(0),
+        }
+    }
+
+    /// The waits before each retry, with a random jitter so the clients that
+    /// lost the connection at the same time don't retry at the same time.
+    pub fn delays(&self) -> impl Iterator<Item = Duration> {
+        let policy = self.clone();
+        (0..self.max_retries as u32).map(move |retry| {
+            let delay = policy
+                .initial_delay
+                .checked_mul(2u32.saturating_pow(retry))
+                .unwrap_or(policy.max_delay);
+            jitter(delay.min(policy.max_delay))
+        })
+    }
+}
+
+/// The retry policies per class of call to the server.
+#[derive(Clone, Debug)]
+pub struct RetryPolicies {
+    /// The reads that the user waits for, e.g. the members of a workspace.
+    pub read: RetryPolicy,
+
+    /// The writes that the user waits for, e.g. creating an app.
+    pub write: RetryPolicy,
+
+    /// The changes that were saved locally and are pushed to the server in the
+    /// background, e.g. renaming a view.
+    pub sync: RetryPolicy,
+}
+
+impl std::default::Default for RetryPolicies {
+    fn default() -> Self {
+        RetryPolicies {
+            read: RetryPolicy {
+                max_retries: 2,
+                initial_delay: Duration::from_millis(200),
+                max_delay: Duration::from_secs(1),
+            },
+            write: RetryPolicy {
+                max_retries: 2,
+                initial_delay: Duration::from_millis(300),
+                max_delay: Duration::from_secs(2),
+            },
+            sync: RetryPolicy {
+                max_retries: 5,
+                initial_delay: Duration::from_secs(1),
+                max_delay: Duration::from_secs(30),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ServerCallClass {
+    Read,
+    Write,
+    Sync,
+}
+
+// Only the idempotent calls go through here. The calls that create something
+// on the server without a client id, e.g. a share link, aren't retried: the
+// server may have created it before the connection dropped.
+//
+// The anonymous user has no account on the server, its calls are answered on
+// the device instead. The folder is uploaded once the user signs up.
+#[derive(Clone)]
+pub(crate) struct ServerRetry {
+    server: Server,
+    batch_server: BatchServer,
+    local_server: Server,
+    local_batch_server: BatchServer,
+    user: Arc<dyn WorkspaceUser>,
+    policies: RetryPolicies,
+    clock: Arc<dyn Clock>,
+}
+
+impl ServerRetry {
+    pub(crate) fn new(
+        server: Server,
+        batch_server: BatchServer,
+        config: &ClientServerConfiguration,
+        user: Arc<dyn WorkspaceUser>,
+        policies: RetryPolicies,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let local_server: Server = Arc::new(WorkspaceServerMock {});
+        let local_batch_server: BatchServer =
+            Arc::new(BatchServerAdapter::new(local_server.clone(), None, config.clone()));
+        Self {
+            server,
+            batch_server,
+            local_server,
+            local_batch_server,
+            user,
+            policies,
+            clock,
+        }
+    }
+
+    pub(crate) fn call<F, Fut, T>(
+        &self,
+        class: ServerCallClass,
+        call: F,
+    ) -> FlowyResult<impl Future<Output = Result<T, FlowyError>> + Send>
+    where
+        F: Fn(String, Server) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, FlowyError>> + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+    {
+        let server = match self.user.is_anonymous() {
+            true => self.local_server.clone(),
+            false => self.server.clone(),
+        };
+        self.retry(class, server, call)
+    }
+
+    // The batches are retried as a whole, their objects have the ids of the
+    // client, so a retried batch doesn't create them twice.
+    pub(crate) fn call_batch<F, Fut, T>(
+        &self,
+        class: ServerCallClass,
+        call: F,
+    ) -> FlowyResult<impl Future<Output = Result<T, FlowyError>> + Send>
+    where
+        F: Fn(String, BatchServer) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, FlowyError>> + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+    {
+        let batch_server = match self.user.is_anonymous() {
+            true => self.local_batch_server.clone(),
+            false => self.batch_server.clone(),
+        };
+        self.retry(class, batch_server, call)
+    }
+
+    // For the calls that can't be answered on the device, e.g. inviting a
+    // member to the workspace.
+    pub(crate) fn check_account(&self) -> FlowyResult<()> {
+        match self.user.is_anonymous() {
+            true => Err(FlowyError::account_required()),
+            false => Ok(()),
+        }
+    }
+
+    fn retry<S, F, Fut, T>(
+        &self,
+        class: ServerCallClass,
+        server: S,
+        call: F,
+    ) -> FlowyResult<impl Future<Output = Result<T, FlowyError>> + Send>
+    where
+        S: Clone + Send + Sync + 'static,
+        F: Fn(String, S) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, FlowyError>> + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+    {
+        // Fails before calling the server if the user signed out.
+        let _ = self.user.token()?;
+        let never = RetryPolicy::never();
+        let policy = match class {
+            _ if self.user.is_anonymous() => &never,
+            ServerCallClass::Read => &self.policies.read,
+            ServerCallClass::Write => &self.policies.write,
+            ServerCallClass::Sync => &self.policies.sync,
+        };
+        let action = RetryAction::new(server, self.user.clone(), call);
+        Ok(RetryIf::spawn_with_clock(
+            policy.delays(),
+            action,
+            is_transient as fn(&FlowyError) -> bool,
+            self.clock.clone(),
+        ))
+    }
+}
+
+fn is_transient(error: &FlowyError) -> bool {
+    let transient = error.recovery_hint() == RecoveryHint::Retryable;
+    if transient {
+        log::warn!("The server call failed with a transient error: {:?}", error);
+    }
+    transient
+}
@@ -4,38 +4,62 @@ use crate::{
         trash::TrashType,
     },
     errors::*,
-    module::{WorkspaceDatabase, WorkspaceUser},
+    folder_change::{FolderChange, FolderChangeNotifier},
+    module::WorkspaceDatabase,
+    name_rules::NameRules,
     notify::*,
+    retry::{ServerCallClass, ServerRetry},
     services::{
         app::sql::{AppTable, AppTableChangeset, AppTableSql},
-        server::Server,
+        event_log::sql::{EventLogTableSql, EventLogType},
+        workspace::role::{check_belonging_editable, check_workspace_editable},
+        AuditAction,
+        AuditLogController,
+        AuditRecord,
+        JournalEntry,
+        OperationJournal,
         TrashController,
         TrashEvent,
+        ViewController,
     },
 };
-use flowy_database::SqliteConnection;
+use flowy_database::{slow_log::SlowLogTransaction, SqliteConnection};
 use futures::{FutureExt, StreamExt};
+use lib_infra::clock::RuntimeEnv;
+use serde_json::json;
 use std::{collections::HashSet, sync::Arc};
 
 pub(crate) struct AppController {
-    user: Arc<dyn WorkspaceUser>,
     database: Arc<dyn WorkspaceDatabase>,
     trash_can: Arc<TrashController>,
-    server: Server,
+    audit_log: Arc<AuditLogController>,
+    folder_change_notifier: FolderChangeNotifier,
+    name_rules: NameRules,
+    server_retry: ServerRetry,
+    journal: Arc<OperationJournal>,
+    env: RuntimeEnv,
 }
 
 impl AppController {
     pub(crate) fn new(
-        user: Arc<dyn WorkspaceUser>,
         database: Arc<dyn WorkspaceDatabase>,
         trash_can: Arc<TrashController>,
-        server: Server,
+        audit_log: Arc<AuditLogController>,
+        folder_change_notifier: FolderChangeNotifier,
+        name_rules: NameRules,
+        server_retry: ServerRetry,
+        journal: Arc<OperationJournal>,
+        env: RuntimeEnv,
     ) -> Self {
         Self {
-            user,
             database,
             trash_can,
-            server,
+            audit_log,
+            folder_change_notifier,
+            name_rules,
+            server_retry,
+            journal,
+            env,
         }
     }
 
@@ -46,17 +70,82 @@ impl AppController {
 
     #[tracing::instrument(level = "debug", skip(self, params), fields(name = %params.name) err)]
     pub(crate) async fn create_app_from_params(&self, params: CreateAppParams) -> Result<App, FlowyError> {
+        // A retried create returns the app that the first attempt created.
+        let created_app = AppTableSql::try_read_app(&params.app_id, &*self.database.db_connection()?)?;
+        if let Some(app_table) = created_app {
+            if app_table.workspace_id != params.workspace_id {
+                return Err(FlowyError::invalid_app_id().context("The app id is used by another app"));
+            }
+            return Ok(app_table.into());
+        }
+        let _ = check_workspace_editable(&params.workspace_id, &*self.database.db_connection()?)?;
+        let _ = self.check_app_name(
+            &params.name,
+            &params.workspace_id,
+            &params.app_id,
+            &*self.database.db_connection()?,
+        )?;
         let app = self.create_app_on_server(params).await?;
         self.create_app_on_local(app).await
     }
 
+    // Creates an app of an import. Its name gets a suffix if the name rules
+    // ask for it and another app of the workspace has the name.
+    pub(crate) async fn create_imported_app(&self, mut params: CreateAppParams) -> Result<App, FlowyError> {
+        if self.name_rules.suffix_duplicates_on_import {
+            let sibling_names =
+                self.read_sibling_names(&params.workspace_id, &params.app_id, &*self.database.db_connection()?)?;
+            params.name = self.name_rules.free_name(&params.name, &sibling_names);
+        }
+        self.create_app_from_params(params).await
+    }
+
+    fn check_app_name(
+        &self,
+        name: &str,
+        workspace_id: &str,
+        app_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
+        let _ = self.name_rules.check(name)?;
+        if self.name_rules.unique_in_parent {
+            let sibling_names = self.read_sibling_names(workspace_id, app_id, conn)?;
+            let _ = self.name_rules.check_unique(name, &sibling_names)?;
+        }
+        Ok(())
+    }
+
+    // The names of the apps of the workspace other than `app_id`, the apps in
+    // the trash are left out.
+    fn read_sibling_names(
+        &self,
+        workspace_id: &str,
+        app_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<String>, FlowyError> {
+        let trash_ids = self.trash_can.read_trash_ids(conn)?;
+        let names = AppTableSql::read_workspace_apps(workspace_id, false, conn)?
+            .into_iter()
+            .filter(|app_table| app_table.id != app_id && !trash_ids.contains(&app_table.id))
+            .map(|app_table| app_table.name)
+            .collect();
+        Ok(names)
+    }
+
     pub(crate) async fn create_app_on_local(&self, app: App) -> Result<App, FlowyError> {
         let conn = &*self.database.db_connection()?;
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
+        conn.timed_transaction::<_, FlowyError, _>("app.create_app_on_local", || {
             let _ = self.save_app(app.clone(), &*conn)?;
+            let _ = EventLogTableSql::append(EventLogType::AppCreated, &app.id, app_log_payload(&app), conn)?;
+            let record = AuditRecord::app(AuditAction::Created, None, Some(&AppTable::new(app.clone())));
+            let _ = self.audit_log.record(record, conn)?;
             let _ = notify_apps_changed(&app.workspace_id, self.trash_can.clone(), conn)?;
             Ok(())
         })?;
+        self.folder_change_notifier.notify(FolderChange::AppCreated {
+            workspace_id: app.workspace_id.clone(),
+            app_id: app.id.clone(),
+        });
 
         Ok(app)
     }
@@ -84,23 +173,99 @@ impl AppController {
         let changeset = AppTableChangeset::new(params.clone());
         let app_id = changeset.id.clone();
         let conn = &*self.database.db_connection()?;
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
+        let _ = check_belonging_editable(&app_id, conn)?;
+        if let Some(name) = &params.name {
+            let workspace_id = AppTableSql::read_app(&app_id, conn)?.workspace_id;
+            let _ = self.check_app_name(name, &workspace_id, &app_id, conn)?;
+        }
+        conn.timed_transaction::<_, FlowyError, _>("app.update_app", || {
+            let before = AppTableSql::read_app(&app_id, conn)?;
             let _ = AppTableSql::update_app(changeset, conn)?;
-            let app: App = AppTableSql::read_app(&app_id, conn)?.into();
+            let after = AppTableSql::read_app(&app_id, conn)?;
+            let record = AuditRecord::app(AuditAction::Updated, Some(&before), Some(&after));
+            let _ = self.audit_log.record(record, conn)?;
+            let app: App = after.into();
+            let _ = EventLogTableSql::append(EventLogType::AppUpdated, &app_id, app_log_payload(&app), conn)?;
             send_dart_notification(&app_id, WorkspaceNotification::AppUpdated)
                 .payload(app)
                 .send();
             Ok(())
         })?;
+        self.folder_change_notifier.notify(FolderChange::AppUpdated { app_id });
 
         let _ = self.update_app_on_server(params)?;
         Ok(())
     }
 
+    // The app is copied with its views, the views in the trash are left out.
+    // The copies that were made are removed if the duplicate fails half way.
+    #[tracing::instrument(level = "debug", skip(self, view_controller), err)]
+    pub(crate) async fn duplicate_app(&self, params: AppId, view_controller: Arc<ViewController>) -> FlowyResult<App> {
+        let mut entry = self.journal.begin("duplicate_app")?;
+        match self.copy_app(&params, &view_controller, &mut entry).await {
+            Ok(app) => {
+                let _ = self.journal.commit(entry)?;
+                let duplicated = AppDuplicated {
+                    source_app_id: params.app_id,
+                    app: app.clone(),
+                };
+                send_dart_notification(&app.workspace_id, WorkspaceNotification::AppDuplicated)
+                    .payload(duplicated)
+                    .send();
+                Ok(app)
+            },
+            Err(e) => {
+                if let Err(rollback_error) = self.journal.rollback(entry).await {
+                    tracing::error!(
+                        "Roll back the duplicate of {} failed: {}",
+                        params.app_id,
+                        rollback_error
+                    );
+                }
+                Err(e)
+            },
+        }
+    }
+
+    async fn copy_app(
+        &self,
+        params: &AppId,
+        view_controller: &ViewController,
+        entry: &mut JournalEntry,
+    ) -> FlowyResult<App> {
+        let app_table = AppTableSql::read_app(&params.app_id, &*self.database.db_connection()?)?;
+        let mut name = format!("{} (copy)", app_table.name);
+        if self.name_rules.unique_in_parent {
+            let sibling_names =
+                self.read_sibling_names(&app_table.workspace_id, &app_table.id, &*self.database.db_connection()?)?;
+            name = self.name_rules.free_name(&name, &sibling_names);
+        }
+        let create_params = CreateAppParams {
+            workspace_id: app_table.workspace_id.clone(),
+            name,
+            desc: app_table.desc.clone(),
+            color_style: ColorStyle {
+                theme_color: app_table.color_style.theme_color.clone(),
+            },
+            app_id: self.env.next_id(),
+        };
+        let _ = self.journal.record_app(entry, &create_params.app_id)?;
+        let mut app = self.create_app_from_params(create_params).await?;
+        app.belongings = view_controller
+            .duplicate_belonging_views(&app_table.id, &app.id, entry)
+            .await?;
+        Ok(app)
+    }
+
+    pub(crate) fn check_app_editable(&self, app_id: &str) -> Result<(), FlowyError> {
+        let conn = self.database.db_connection()?;
+        check_belonging_editable(app_id, &*conn)
+    }
+
     pub(crate) fn read_app_tables(&self, ids: Vec<String>) -> Result<Vec<AppTable>, FlowyError> {
         let conn = &*self.database.db_connection()?;
         let mut app_tables = vec![];
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
+        conn.timed_transaction::<_, FlowyError, _>("app.read_app_tables", || {
             for app_id in ids {
                 app_tables.push(AppTableSql::read_app(&app_id, conn)?);
             }
@@ -114,22 +279,24 @@ impl AppController {
 impl AppController {
     #[tracing::instrument(level = "debug", skip(self), err)]
     async fn create_app_on_server(&self, params: CreateAppParams) -> Result<App, FlowyError> {
-        let token = self.user.token()?;
-        let app = self.server.create_app(&token, params).await?;
+        // The server returns the app that a previous attempt created, so the
+        // create is retried like an update.
+        let create_app = self.server_retry.call(ServerCallClass::Write, move |token, server| {
+            server.create_app(&token, params.clone())
+        })?;
+        let app = create_app.await?;
         Ok(app)
     }
 
     #[tracing::instrument(level = "debug", skip(self), err)]
     fn update_app_on_server(&self, params: UpdateAppParams) -> Result<(), FlowyError> {
-        let token = self.user.token()?;
-        let server = self.server.clone();
+        let update_app = self.server_retry.call(ServerCallClass::Sync, move |token, server| {
+            server.update_app(&token, params.clone())
+        })?;
         tokio::spawn(async move {
-            match server.update_app(&token, params).await {
+            match update_app.await {
                 Ok(_) => {},
-                Err(e) => {
-                    // TODO: retry?
-                    log::error!("Update app failed: {:?}", e);
-                },
+                Err(e) => log::error!("Update app failed: {:?}", e),
             }
         });
         Ok(())
@@ -137,12 +304,12 @@ impl AppController {
 
     #[tracing::instrument(level = "debug", skip(self), err)]
     fn read_app_on_server(&self, params: AppId) -> Result<(), FlowyError> {
-        let token = self.user.token()?;
-        let server = self.server.clone();
+        let read_app = self.server_retry.call(ServerCallClass::Sync, move |token, server| {
+            server.read_app(&token, params.clone())
+        })?;
         let pool = self.database.db_pool()?;
         tokio::spawn(async move {
-            // Opti: retry?
-            match server.read_app(&token, params).await {
+            match read_app.await {
                 Ok(Some(app)) => match pool.get() {
                     Ok(conn) => {
                         let app_table = AppTable::new(app.clone());
@@ -169,6 +336,8 @@ impl AppController {
         let mut rx = self.trash_can.subscribe();
         let database = self.database.clone();
         let trash_can = self.trash_can.clone();
+        let audit_log = self.audit_log.clone();
+        let folder_change_notifier = self.folder_change_notifier.clone();
         let _ = tokio::spawn(async move {
             loop {
                 let mut stream = Box::pin(rx.recv().into_stream().filter_map(|result| async move {
@@ -178,39 +347,78 @@ impl AppController {
                     }
                 }));
                 if let Some(event) = stream.next().await {
-                    handle_trash_event(database.clone(), trash_can.clone(), event).await
+                    handle_trash_event(
+                        database.clone(),
+                        trash_can.clone(),
+                        audit_log.clone(),
+                        folder_change_notifier.clone(),
+                        event,
+                    )
+                    .await
                 }
             }
         });
     }
 }
 
-#[tracing::instrument(level = "trace", skip(database, trash_can))]
-async fn handle_trash_event(database: Arc<dyn WorkspaceDatabase>, trash_can: Arc<TrashController>, event: TrashEvent) {
+#[tracing::instrument(level = "trace", skip(database, trash_can, audit_log, folder_change_notifier))]
+async fn handle_trash_event(
+    database: Arc<dyn WorkspaceDatabase>,
+    trash_can: Arc<TrashController>,
+    audit_log: Arc<AuditLogController>,
+    folder_change_notifier: FolderChangeNotifier,
+    event: TrashEvent,
+) {
     let db_result = database.db_connection();
+    let is_trashed = matches!(event, TrashEvent::NewTrash(..));
     match event {
         TrashEvent::NewTrash(identifiers, ret) | TrashEvent::Putback(identifiers, ret) => {
+            let mut app_ids = vec![];
             let result = || {
                 let conn = &*db_result?;
-                let _ = conn.immediate_transaction::<_, FlowyError, _>(|| {
+                let _ = conn.timed_transaction::<_, FlowyError, _>("app.handle_trash_event", || {
                     for identifier in identifiers.items {
                         let app_table = AppTableSql::read_app(&identifier.id, conn)?;
+                        let record = match is_trashed {
+                            true => AuditRecord::app(AuditAction::Trashed, Some(&app_table), None),
+                            false => AuditRecord::app(AuditAction::Restored, None, Some(&app_table)),
+                        };
+                        let _ = audit_log.record(record, conn)?;
                         let _ = notify_apps_changed(&app_table.workspace_id, trash_can.clone(), conn)?;
+                        app_ids.push(app_table.id);
                     }
                     Ok(())
                 })?;
                 Ok::<(), FlowyError>(())
             };
-            let _ = ret.send(result()).await;
+            let result = result();
+            if result.is_ok() {
+                for app_id in app_ids {
+                    folder_change_notifier.notify(match is_trashed {
+                        true => FolderChange::AppTrashed { app_id },
+                        false => FolderChange::AppRestored { app_id },
+                    });
+                }
+            }
+            let _ = ret.send(result).await;
         },
         TrashEvent::Delete(identifiers, ret) => {
+            let app_ids = identifiers
+                .items
+                .iter()
+                .map(|identifier| identifier.id.clone())
+                .collect::<Vec<String>>();
             let result = || {
                 let conn = &*db_result?;
-                let _ = conn.immediate_transaction::<_, FlowyError, _>(|| {
+                let _ = conn.timed_transaction::<_, FlowyError, _>("app.handle_trash_event", || {
                     let mut notify_ids = HashSet::new();
                     for identifier in identifiers.items {
                         let app_table = AppTableSql::read_app(&identifier.id, conn)?;
                         let _ = AppTableSql::delete_app(&identifier.id, conn)?;
+                        let payload = json!({ "id": identifier.id });
+                        let _ = EventLogTableSql::append(EventLogType::AppDeleted, &identifier.id, payload, conn)?;
+                        let record = AuditRecord::app(AuditAction::Deleted, Some(&app_table), None);
+                        let _ = audit_log.record(record, conn)?;
                         notify_ids.insert(app_table.workspace_id);
                     }
 
@@ -221,13 +429,27 @@ async fn handle_trash_event(database: Arc<dyn WorkspaceDatabase>, trash_can: Arc
                 })?;
                 Ok::<(), FlowyError>(())
             };
-            let _ = ret.send(result()).await;
+            let result = result();
+            if result.is_ok() {
+                for app_id in app_ids {
+                    folder_change_notifier.notify(FolderChange::AppDeleted { app_id });
+                }
+            }
+            let _ = ret.send(result).await;
         },
     }
 }
 
+fn app_log_payload(app: &App) -> serde_json::Value {
+    json!({
+        "id": app.id,
+        "workspace_id": app.workspace_id,
+        "name": app.name,
+    })
+}
+
 #[tracing::instrument(skip(workspace_id, trash_can, conn), err)]
-fn notify_apps_changed(
+pub(crate) fn notify_apps_changed(
     workspace_id: &str,
     trash_can: Arc<TrashController>,
     conn: &SqliteConnection,
@@ -25,6 +25,7 @@ pub(crate) async fn delete_app_handler(
     trash_controller: Unit<Arc<TrashController>>,
 ) -> Result<(), FlowyError> {
     let params: AppId = data.into_inner().try_into()?;
+    let _ = view_controller.check_app_editable(&params.app_id)?;
     let trash = view_controller
         .read_app_tables(vec![params.app_id])?
         .into_iter()
@@ -35,6 +36,19 @@ pub(crate) async fn delete_app_handler(
     Ok(())
 }
 
+#[tracing::instrument(skip(data, app_controller, view_controller), err)]
+pub(crate) async fn duplicate_app_handler(
+    data: Data<QueryAppRequest>,
+    app_controller: Unit<Arc<AppController>>,
+    view_controller: Unit<Arc<ViewController>>,
+) -> DataResult<App, FlowyError> {
+    let params: AppId = data.into_inner().try_into()?;
+    let app = app_controller
+        .duplicate_app(params, view_controller.get_ref().clone())
+        .await?;
+    data_result(app)
+}
+
 #[tracing::instrument(skip(data, controller))]
 pub(crate) async fn update_app_handler(
     data: Data<UpdateAppRequest>,
@@ -4,15 +4,20 @@ use crate::{
         trash::{Trash, TrashType},
         view::RepeatedView,
     },
-    services::workspace::sql::WorkspaceTable,
+    services::{
+        cache::{invalidate_app_cache, read_workspace_apps_through_cache},
+        workspace::{skeleton::invalidate_workspace_skeleton, sql::WorkspaceTable},
+    },
 };
 use diesel::sql_types::Binary;
 use flowy_database::{
     prelude::*,
+    result::OptionalExtension,
     schema::{app_table, app_table::dsl},
+    slow_log::{log_if_slow, statement_shape},
     SqliteConnection,
 };
-use serde::{Deserialize, Serialize, __private::TryFrom};
+use serde::{__private::TryFrom, Deserialize, Serialize};
 use std::convert::TryInto;
 
 use crate::errors::FlowyError;
@@ -21,6 +26,7 @@ pub struct AppTableSql {}
 
 impl AppTableSql {
     pub(crate) fn create_app(app_table: AppTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let workspace_id = app_table.workspace_id.clone();
         match diesel_record_count!(app_table, &app_table.id, conn) {
             0 => diesel_insert_table!(app_table, &app_table, conn),
             _ => {
@@ -28,11 +34,39 @@ impl AppTableSql {
                 diesel_update_table!(app_table, changeset, conn)
             },
         }
+        invalidate_workspace_skeleton();
+        invalidate_app_cache(&workspace_id, conn);
         Ok(())
     }
 
     pub(crate) fn update_app(changeset: AppTableChangeset, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let workspace_id = dsl::app_table
+            .filter(app_table::id.eq(&changeset.id))
+            .select(app_table::workspace_id)
+            .first::<String>(conn)?;
         diesel_update_table!(app_table, changeset, conn);
+        invalidate_workspace_skeleton();
+        invalidate_app_cache(&workspace_id, conn);
+        Ok(())
+    }
+
+    // The apps of a workspace are ordered by the time they were created at, it's
+    // changed to reorder them.
+    pub(crate) fn update_create_time(
+        app_id: &str,
+        create_time: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
+        let workspace_id = dsl::app_table
+            .filter(app_table::id.eq(app_id))
+            .select(app_table::workspace_id)
+            .first::<String>(conn)?;
+        let filter = dsl::app_table.filter(app_table::id.eq(app_id));
+        let _ = diesel::update(filter)
+            .set(app_table::create_time.eq(create_time))
+            .execute(conn)?;
+        invalidate_workspace_skeleton();
+        invalidate_app_cache(&workspace_id, conn);
         Ok(())
     }
 
@@ -42,18 +76,28 @@ impl AppTableSql {
         Ok(app_table)
     }
 
+    // Returns None if the app doesn't exist.
+    pub(crate) fn try_read_app(app_id: &str, conn: &SqliteConnection) -> Result<Option<AppTable>, FlowyError> {
+        let app_table = dsl::app_table
+            .filter(app_table::id.eq(app_id))
+            .first::<AppTable>(conn)
+            .optional()?;
+        Ok(app_table)
+    }
+
     pub(crate) fn read_workspace_apps(
         workspace_id: &str,
         is_trash: bool,
         conn: &SqliteConnection,
     ) -> Result<Vec<AppTable>, FlowyError> {
-        let app_table = dsl::app_table
-            .filter(app_table::workspace_id.eq(workspace_id))
-            .filter(app_table::is_trash.eq(is_trash))
-            .order(app_table::create_time.asc())
-            .load::<AppTable>(conn)?;
-
-        Ok(app_table)
+        read_workspace_apps_through_cache(workspace_id, is_trash, conn, || {
+            let query = dsl::app_table
+                .filter(app_table::workspace_id.eq(workspace_id))
+                .filter(app_table::is_trash.eq(is_trash))
+                .order(app_table::create_time.asc());
+            let app_table = log_if_slow(&statement_shape(&query), || query.load::<AppTable>(conn))?;
+            Ok(app_table)
+        })
     }
 
     pub(crate) fn delete_app(app_id: &str, conn: &SqliteConnection) -> Result<AppTable, FlowyError> {
@@ -61,6 +105,8 @@ impl AppTableSql {
             .filter(app_table::id.eq(app_id))
             .first::<AppTable>(conn)?;
         diesel_delete_table!(app_table, app_id, conn);
+        invalidate_workspace_skeleton();
+        invalidate_app_cache(&app_table.workspace_id, conn);
         Ok(app_table)
     }
 
@@ -124,6 +170,9 @@ impl std::convert::From<AppTable> for Trash {
             modified_time: table.modified_time,
             create_time: table.create_time,
             ty: TrashType::App,
+            deleted_by: "".to_owned(),
+            parent_id: table.workspace_id,
+            position: 0,
         }
     }
 }
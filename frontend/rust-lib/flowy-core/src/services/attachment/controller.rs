@@ -0,0 +1,277 @@
+use crate::{
+    entities::{
+        storage::{AttachmentStorage, AttachmentStorageParams, AttachmentSyncResult},
+        view::{Attachment, AttachmentId},
+    },
+    errors::{ErrorCode, FlowyError, FlowyResult},
+    module::{WorkspaceDatabase, WorkspaceUser},
+    services::{
+        attachment::{
+            s3::{S3Bucket, S3Credentials},
+            sql::{AttachmentUploadTable, AttachmentUploadTableSql},
+        },
+        view::{
+            acl::check_view_accessible,
+            attachment::{AttachmentTable, AttachmentTableSql},
+        },
+        workspace::{controller::get_current_workspace, role::read_belonging_workspace_id},
+    },
+};
+use flowy_database::kv::KV;
+use lib_infra::clock::RuntimeEnv;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+// The attachments up to this size are put in one request, the larger ones are
+// uploaded in parts of this size. S3 takes parts of 5 MiB at least.
+const PART_SIZE: usize = 8 * 1024 * 1024;
+const STORAGE_TIMEOUT: Duration = Duration::from_secs(60);
+
+// Uploads the attachments of a workspace to its S3-compatible storage. The
+// documents only keep the appflowy://attachment/<id> links, the object is
+// found by the id, so the blobs never go through the revisions. The local
+// table is the cache: an attachment that isn't in it is downloaded on the
+// first read, e.g. on another device.
+pub struct AttachmentController {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    client: reqwest::Client,
+    env: RuntimeEnv,
+    // Two syncs at the same time would upload the same parts.
+    syncing: tokio::sync::Mutex<()>,
+}
+
+impl AttachmentController {
+    pub(crate) fn new(user: Arc<dyn WorkspaceUser>, database: Arc<dyn WorkspaceDatabase>, env: RuntimeEnv) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(STORAGE_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            user,
+            database,
+            client,
+            env,
+            syncing: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub(crate) fn read_storage(&self, workspace_id: &str) -> AttachmentStorage {
+        match StorageConfig::read(workspace_id) {
+            None => AttachmentStorage {
+                workspace_id: workspace_id.to_owned(),
+                ..Default::default()
+            },
+            Some(config) => config.into_storage(workspace_id),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(workspace_id = %params.workspace_id), err)]
+    pub(crate) fn update_storage(&self, params: AttachmentStorageParams) -> FlowyResult<AttachmentStorage> {
+        let workspace_id = params.workspace_id.clone();
+        if params.is_removed() {
+            StorageConfig::remove(&workspace_id);
+            return Ok(self.read_storage(&workspace_id));
+        }
+
+        let secret_access_key = match params.secret_access_key.is_empty() {
+            false => params.secret_access_key,
+            true => StorageConfig::read(&workspace_id)
+                .map(|config| config.secret_access_key)
+                .unwrap_or_default(),
+        };
+        let config = StorageConfig {
+            endpoint: params.endpoint,
+            bucket: params.bucket,
+            region: params.region,
+            access_key_id: params.access_key_id,
+            secret_access_key,
+            prefix: params.prefix,
+        };
+        config.save(&workspace_id);
+        Ok(config.into_storage(&workspace_id))
+    }
+
+    // Uploads the attachments of the workspace that aren't in the storage yet.
+    // One that fails is left for the next sync, the others go on.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn sync(&self, workspace_id: &str) -> FlowyResult<AttachmentSyncResult> {
+        let config = StorageConfig::read(workspace_id).ok_or_else(FlowyError::storage_not_configured)?;
+        let bucket = self.bucket(&config);
+        let _syncing = self.syncing.lock().await;
+
+        let tables = {
+            let conn = &*self.database.db_connection()?;
+            let mut tables = vec![];
+            for table in AttachmentTableSql::read_unsynced(conn)? {
+                if read_belonging_workspace_id(&table.view_id, conn)?.as_deref() == Some(workspace_id) {
+                    tables.push(table);
+                }
+            }
+            tables
+        };
+
+        let mut result = AttachmentSyncResult::default();
+        for table in tables {
+            let key = config.object_key(workspace_id, &table.id);
+            match self.upload(&bucket, &key, &table).await {
+                Ok(_) => {
+                    let conn = &*self.database.db_connection()?;
+                    let _ = AttachmentTableSql::set_remote_key(&table.id, &key, conn)?;
+                    result.uploaded_count += 1;
+                },
+                Err(e) => {
+                    tracing::error!("Upload the attachment {} failed: {:?}", table.id, e);
+                    result.pending_count += 1;
+                },
+            }
+        }
+        Ok(result)
+    }
+
+    // Reads the attachment from the cache, or downloads it from the storage of
+    // the current workspace and caches it.
+    pub(crate) async fn read_attachment(&self, params: AttachmentId) -> FlowyResult<Attachment> {
+        let user_id = self.user.user_id()?;
+        let cached = {
+            let conn = &*self.database.db_connection()?;
+            AttachmentTableSql::try_read_attachment(&params.attachment_id, conn)?
+        };
+        if let Some(table) = cached {
+            let _ = check_view_accessible(&table.view_id, &user_id, &*self.database.db_connection()?)?;
+            return Ok(table.into());
+        }
+
+        let workspace_id = get_current_workspace()?;
+        let config = StorageConfig::read(&workspace_id)
+            .ok_or_else(|| FlowyError::record_not_found().context("The attachment isn't on this device"))?;
+        let key = config.object_key(&workspace_id, &params.attachment_id);
+        let object = self.bucket(&config).get_object(&key).await?;
+        let table = AttachmentTable {
+            id: params.attachment_id,
+            view_id: object.metadata.get("view-id").cloned().unwrap_or_default(),
+            name: object.metadata.get("name").cloned().unwrap_or_default(),
+            mime_type: object.content_type,
+            create_time: object
+                .metadata
+                .get("create-time")
+                .and_then(|time| time.parse().ok())
+                .unwrap_or_else(|| self.env.timestamp()),
+            data: object.data,
+            remote_key: key,
+        };
+
+        let conn = &*self.database.db_connection()?;
+        let _ = check_view_accessible(&table.view_id, &user_id, conn)?;
+        let _ = AttachmentTableSql::create_attachment(table.clone(), conn)?;
+        Ok(table.into())
+    }
+
+    fn bucket(&self, config: &StorageConfig) -> S3Bucket {
+        let credentials = S3Credentials {
+            endpoint: config.endpoint.clone(),
+            bucket: config.bucket.clone(),
+            region: config.region.clone(),
+            access_key_id: config.access_key_id.clone(),
+            secret_access_key: config.secret_access_key.clone(),
+        };
+        S3Bucket::new(credentials, self.client.clone(), self.env.clock.clone())
+    }
+
+    async fn upload(&self, bucket: &S3Bucket, key: &str, table: &AttachmentTable) -> FlowyResult<()> {
+        let mut metadata = BTreeMap::new();
+        metadata.insert("view-id".to_owned(), table.view_id.clone());
+        metadata.insert("name".to_owned(), table.name.clone());
+        metadata.insert("create-time".to_owned(), table.create_time.to_string());
+        if table.data.len() <= PART_SIZE {
+            return bucket
+                .put_object(key, &table.mime_type, &metadata, table.data.clone())
+                .await;
+        }
+
+        // An upload of another key was started before the storage changed, it
+        // isn't continued.
+        let saved = AttachmentUploadTableSql::read_upload(&table.id, &*self.database.db_connection()?)?;
+        let mut upload = match saved.filter(|upload| upload.remote_key == key) {
+            Some(upload) => upload,
+            None => {
+                let upload_id = bucket.create_multipart_upload(key, &table.mime_type, &metadata).await?;
+                let upload = AttachmentUploadTable {
+                    attachment_id: table.id.clone(),
+                    remote_key: key.to_owned(),
+                    upload_id,
+                    part_etags: "[]".to_owned(),
+                };
+                let _ = AttachmentUploadTableSql::save_upload(upload.clone(), &*self.database.db_connection()?)?;
+                upload
+            },
+        };
+
+        let mut etags = upload.etags();
+        for (i, part) in table.data.chunks(PART_SIZE).enumerate().skip(etags.len()) {
+            let etag = match bucket.upload_part(key, &upload.upload_id, i + 1, part.to_vec()).await {
+                Ok(etag) => etag,
+                Err(e) => {
+                    // The upload expired or was aborted, the next sync starts
+                    // a new one.
+                    if e.code == ErrorCode::RecordNotFound.value() {
+                        let _ = AttachmentUploadTableSql::delete_upload(&table.id, &*self.database.db_connection()?)?;
+                    }
+                    return Err(e);
+                },
+            };
+            etags.push(etag);
+            upload.set_etags(&etags);
+            let _ = AttachmentUploadTableSql::save_upload(upload.clone(), &*self.database.db_connection()?)?;
+        }
+
+        let _ = bucket.complete_multipart_upload(key, &upload.upload_id, &etags).await?;
+        AttachmentUploadTableSql::delete_upload(&table.id, &*self.database.db_connection()?)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct StorageConfig {
+    endpoint: String,
+    bucket: String,
+    region: String,
+    access_key_id: String,
+    secret_access_key: String,
+    prefix: String,
+}
+
+impl StorageConfig {
+    fn key(workspace_id: &str) -> String { format!("attachment_storage_{}", workspace_id) }
+
+    fn read(workspace_id: &str) -> Option<Self> {
+        KV::get_str(&Self::key(workspace_id)).and_then(|json| serde_json::from_str(&json).ok())
+    }
+
+    fn save(&self, workspace_id: &str) {
+        match serde_json::to_string(self) {
+            Ok(json) => KV::set_str(&Self::key(workspace_id), json),
+            Err(e) => log::error!("Save the attachment storage of {} failed: {:?}", workspace_id, e),
+        }
+    }
+
+    fn remove(workspace_id: &str) { let _ = KV::remove(&Self::key(workspace_id)); }
+
+    fn object_key(&self, workspace_id: &str, attachment_id: &str) -> String {
+        match self.prefix.is_empty() {
+            true => format!("{}/{}", workspace_id, attachment_id),
+            false => format!("{}/{}/{}", self.prefix, workspace_id, attachment_id),
+        }
+    }
+
+    fn into_storage(self, workspace_id: &str) -> AttachmentStorage {
+        AttachmentStorage {
+            workspace_id: workspace_id.to_owned(),
+            endpoint: self.endpoint,
+            bucket: self.bucket,
+            region: self.region,
+            access_key_id: self.access_key_id,
+            has_secret: !self.secret_access_key.is_empty(),
+            prefix: self.prefix,
+        }
+    }
+}
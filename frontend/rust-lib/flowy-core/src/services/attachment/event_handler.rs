@@ -0,0 +1,58 @@
+use crate::{
+    entities::{
+        storage::{AttachmentStorage, AttachmentStorageParams, AttachmentSyncResult, UpdateAttachmentStorageRequest},
+        view::{Attachment, AttachmentId, QueryAttachmentRequest},
+        workspace::{QueryWorkspaceRequest, WorkspaceId},
+    },
+    errors::FlowyError,
+    services::{get_current_workspace, AttachmentController},
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_attachment_handler(
+    data: Data<QueryAttachmentRequest>,
+    controller: Unit<Arc<AttachmentController>>,
+) -> DataResult<Attachment, FlowyError> {
+    let params: AttachmentId = data.into_inner().try_into()?;
+    let attachment = controller.read_attachment(params).await?;
+    data_result(attachment)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_attachment_storage_handler(
+    data: Data<QueryWorkspaceRequest>,
+    controller: Unit<Arc<AttachmentController>>,
+) -> DataResult<AttachmentStorage, FlowyError> {
+    let workspace_id = workspace_id_or_current(data.into_inner())?;
+    data_result(controller.read_storage(&workspace_id))
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_attachment_storage_handler(
+    data: Data<UpdateAttachmentStorageRequest>,
+    controller: Unit<Arc<AttachmentController>>,
+) -> DataResult<AttachmentStorage, FlowyError> {
+    let params: AttachmentStorageParams = data.into_inner().try_into()?;
+    let storage = controller.update_storage(params)?;
+    data_result(storage)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn sync_attachments_handler(
+    data: Data<QueryWorkspaceRequest>,
+    controller: Unit<Arc<AttachmentController>>,
+) -> DataResult<AttachmentSyncResult, FlowyError> {
+    let workspace_id = workspace_id_or_current(data.into_inner())?;
+    let result = controller.sync(&workspace_id).await?;
+    data_result(result)
+}
+
+fn workspace_id_or_current(request: QueryWorkspaceRequest) -> Result<String, FlowyError> {
+    let params: WorkspaceId = request.try_into()?;
+    match params.workspace_id {
+        Some(workspace_id) => Ok(workspace_id),
+        None => get_current_workspace(),
+    }
+}
@@ -0,0 +1,354 @@
+use crate::errors::{FlowyError, FlowyResult};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac, NewMac};
+use lib_infra::clock::Clock;
+use reqwest::{header::HeaderMap, Method, Response, StatusCode, Url};
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeMap, sync::Arc};
+
+const SIGNING_ALGORITHM: &str = "AWS4-HMAC-SHA256";
+const METADATA_PREFIX: &str = "x-amz-meta-";
+
+// The credentials and the address of a bucket of an S3-compatible service.
+#[derive(Clone, Debug)]
+pub(crate) struct S3Credentials {
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+pub(crate) struct S3Object {
+    pub content_type: String,
+    pub metadata: BTreeMap<String, String>,
+    pub data: Vec<u8>,
+}
+
+// The few calls of the S3 api that the attachments need, signed with
+// Signature Version 4. The bucket is in the path instead of the host, which
+// MinIO and the other S3-compatible services accept as well.
+pub(crate) struct S3Bucket {
+    credentials: S3Credentials,
+    client: reqwest::Client,
+    clock: Arc<dyn Clock>,
+}
+
+impl S3Bucket {
+    pub(crate) fn new(credentials: S3Credentials, client: reqwest::Client, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            credentials,
+            client,
+            clock,
+        }
+    }
+
+    // The metadata is sent as x-amz-meta-<name> headers, the values are
+    // percent-encoded since a header can only hold ascii.
+    pub(crate) async fn put_object(
+        &self,
+        key: &str,
+        content_type: &str,
+        metadata: &BTreeMap<String, String>,
+        data: Vec<u8>,
+    ) -> FlowyResult<()> {
+        let headers = object_headers(content_type, metadata);
+        let _ = self.send(Method::PUT, key, &[], headers, data).await?;
+        Ok(())
+    }
+
+    // Returns the id of the upload that the parts are uploaded to.
+    pub(crate) async fn create_multipart_upload(
+        &self,
+        key: &str,
+        content_type: &str,
+        metadata: &BTreeMap<String, String>,
+    ) -> FlowyResult<String> {
+        let headers = object_headers(content_type, metadata);
+        let response = self
+            .send(Method::POST, key, &[("uploads", "")], headers, vec![])
+            .await?;
+        let body = response.text().await.map_err(|e| FlowyError::connection().context(e))?;
+        xml_value(&body, "UploadId")
+            .ok_or_else(|| FlowyError::internal().context("The storage didn't return the id of the upload"))
+    }
+
+    // The part numbers start at 1. Returns the ETag that completes the upload.
+    pub(crate) async fn upload_part(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: usize,
+        data: Vec<u8>,
+    ) -> FlowyResult<String> {
+        let part_number = part_number.to_string();
+        let query = [("partNumber", part_number.as_str()), ("uploadId", upload_id)];
+        let response = self.send(Method::PUT, key, &query, BTreeMap::new(), data).await?;
+        response
+            .headers()
+            .get("etag")
+            .and_then(|etag| etag.to_str().ok())
+            .map(|etag| etag.to_owned())
+            .ok_or_else(|| FlowyError::internal().context("The storage didn't return the ETag of the part"))
+    }
+
+    pub(crate) async fn complete_multipart_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+        etags: &[String],
+    ) -> FlowyResult<()> {
+        let parts = etags
+            .iter()
+            .enumerate()
+            .map(|(i, etag)| {
+                format!(
+                    "<Part><PartNumber>{}</PartNumber><ETag>{}</ETag></Part>",
+                    i + 1,
+                    xml_escape(etag)
+                )
+            })
+            .collect::<String>();
+        let body = format!("<CompleteMultipartUpload>{}</CompleteMultipartUpload>", parts);
+        let response = self
+            .send(
+                Method::POST,
+                key,
+                &[("uploadId", upload_id)],
+                BTreeMap::new(),
+                body.into_bytes(),
+            )
+            .await?;
+
+        // The completion can fail after the status was sent, the error is in
+        // the body then.
+        let body = response.text().await.map_err(|e| FlowyError::connection().context(e))?;
+        if body.contains("<Error>") {
+            return Err(FlowyError::internal().context(format!("Complete the upload failed: {}", body)));
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn get_object(&self, key: &str) -> FlowyResult<S3Object> {
+        let response = self.send(Method::GET, key, &[], BTreeMap::new(), vec![]).await?;
+        let content_type = header_value(response.headers(), "content-type").unwrap_or_default();
+        let metadata = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                let name = name.as_str().strip_prefix(METADATA_PREFIX)?;
+                let value = percent_decode(value.to_str().ok()?);
+                Some((name.to_owned(), value))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let data = response
+            .bytes()
+            .await
+            .map_err(|e| FlowyError::connection().context(e))?
+            .to_vec();
+        Ok(S3Object {
+            content_type,
+            metadata,
+            data,
+        })
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        query: &[(&str, &str)],
+        mut headers: BTreeMap<String, String>,
+        body: Vec<u8>,
+    ) -> FlowyResult<Response> {
+        let endpoint = Url::parse(&self.credentials.endpoint).map_err(|e| FlowyError::internal().context(e))?;
+        let host = match (endpoint.host_str(), endpoint.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_owned(),
+            (None, _) => return Err(FlowyError::internal().context("The endpoint has no host")),
+        };
+
+        let path = format!(
+            "{}/{}/{}",
+            endpoint.path().trim_end_matches('/'),
+            self.credentials.bucket,
+            key
+        );
+        let canonical_uri = uri_encode(&path, false);
+        let mut query = query
+            .iter()
+            .map(|(name, value)| (uri_encode(name, true), uri_encode(value, true)))
+            .collect::<Vec<_>>();
+        query.sort();
+        let canonical_query = query
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let now = self.clock.now();
+        let payload_hash = hex::encode(Sha256::digest(&body));
+        headers.insert("host".to_owned(), host);
+        headers.insert("x-amz-content-sha256".to_owned(), payload_hash.clone());
+        headers.insert("x-amz-date".to_owned(), now.format("%Y%m%dT%H%M%SZ").to_string());
+        let authorization = self.authorization(
+            method.as_str(),
+            &canonical_uri,
+            &canonical_query,
+            &headers,
+            &payload_hash,
+            now,
+        );
+
+        let url = match canonical_query.is_empty() {
+            true => format!("{}://{}{}", endpoint.scheme(), headers["host"], canonical_uri),
+            false => format!(
+                "{}://{}{}?{}",
+                endpoint.scheme(),
+                headers["host"],
+                canonical_uri,
+                canonical_query
+            ),
+        };
+        let mut request = self
+            .client
+            .request(method, &url)
+            .header("authorization", authorization)
+            .body(body);
+        for (name, value) in headers.iter().filter(|(name, _)| name.as_str() != "host") {
+            request = request.header(name.as_str(), value.as_str());
+        }
+
+        let response = request.send().await.map_err(|e| FlowyError::connection().context(e))?;
+        check_status(response).await
+    }
+
+    fn authorization(
+        &self,
+        method: &str,
+        canonical_uri: &str,
+        canonical_query: &str,
+        headers: &BTreeMap<String, String>,
+        payload_hash: &str,
+        now: DateTime<Utc>,
+    ) -> String {
+        let canonical_headers = headers
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+            .collect::<String>();
+        let signed_headers = headers.keys().cloned().collect::<Vec<_>>().join(";");
+        let canonical_request = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}",
+            method, canonical_uri, canonical_query, canonical_headers, signed_headers, payload_hash
+        );
+
+        let date = now.format("%Y%m%d").to_string();
+        let scope = format!("{}/{}/s3/aws4_request", date, self.credentials.region);
+        let string_to_sign = format!(
+            "{}\n{}\n{}\n{}",
+            SIGNING_ALGORITHM,
+            now.format("%Y%m%dT%H%M%SZ"),
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let secret = format!("AWS4{}", self.credentials.secret_access_key);
+        let mut key = hmac_sha256(secret.as_bytes(), date.as_bytes());
+        for part in [self.credentials.region.as_str(), "s3", "aws4_request"].iter() {
+            key = hmac_sha256(&key, part.as_bytes());
+        }
+        let signature = hex::encode(hmac_sha256(&key, string_to_sign.as_bytes()));
+        format!(
+            "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+            SIGNING_ALGORITHM, self.credentials.access_key_id, scope, signed_headers, signature
+        )
+    }
+}
+
+fn object_headers(content_type: &str, metadata: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+    let mut headers = metadata
+        .iter()
+        .map(|(name, value)| (format!("{}{}", METADATA_PREFIX, name), uri_encode(value, true)))
+        .collect::<BTreeMap<_, _>>();
+    if !content_type.is_empty() {
+        headers.insert("content-type".to_owned(), content_type.to_owned());
+    }
+    headers
+}
+
+// A missing object or upload is reported as not found, e.g. the upload of a
+// large attachment expired before its last part was sent.
+async fn check_status(response: Response) -> FlowyResult<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = response.text().await.unwrap_or_default();
+    let error = match status {
+        StatusCode::NOT_FOUND => FlowyError::record_not_found(),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => FlowyError::unauthorized(),
+        _ => FlowyError::internal(),
+    };
+    Err(error.context(format!("The storage responded with {}: {}", status, body)))
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+// Encodes everything but the unreserved characters of RFC 3986, the way the
+// signature expects it.
+fn uri_encode(s: &str, encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|hex| std::str::from_utf8(hex).ok());
+        match (bytes[i], hex.and_then(|hex| u8::from_str_radix(hex, 16).ok())) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            },
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            },
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+fn header_value(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_owned())
+}
+
+fn xml_value(xml: &str, tag: &str) -> Option<String> {
+    let start = xml.find(&format!("<{}>", tag))? + tag.len() + 2;
+    let end = start + xml[start..].find(&format!("</{}>", tag))?;
+    Some(xml[start..end].to_owned())
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,56 @@
+use crate::errors::FlowyError;
+use flowy_database::{
+    prelude::*,
+    result::OptionalExtension,
+    schema::{attachment_upload_table, attachment_upload_table::dsl},
+    SqliteConnection,
+};
+
+// The multipart upload of a large attachment that is in progress. The ETags
+// of the parts that were uploaded are saved after each one, so the next sync
+// goes on from the first part that is missing.
+pub(crate) struct AttachmentUploadTableSql {}
+
+impl AttachmentUploadTableSql {
+    pub(crate) fn save_upload(table: AttachmentUploadTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = diesel::replace_into(attachment_upload_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_upload(
+        attachment_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<AttachmentUploadTable>, FlowyError> {
+        let table = dsl::attachment_upload_table
+            .filter(attachment_upload_table::attachment_id.eq(attachment_id))
+            .first::<AttachmentUploadTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+
+    pub(crate) fn delete_upload(attachment_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::attachment_upload_table.filter(attachment_upload_table::attachment_id.eq(attachment_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "attachment_upload_table"]
+pub(crate) struct AttachmentUploadTable {
+    pub attachment_id: String,
+    pub remote_key: String,
+    pub upload_id: String,
+    // The ETags of the uploaded parts in json, in the order of the parts.
+    pub part_etags: String,
+}
+
+impl AttachmentUploadTable {
+    pub(crate) fn etags(&self) -> Vec<String> { serde_json::from_str(&self.part_etags).unwrap_or_default() }
+
+    pub(crate) fn set_etags(&mut self, etags: &[String]) {
+        self.part_etags = serde_json::to_string(etags).unwrap_or_else(|_| "[]".to_owned());
+    }
+}
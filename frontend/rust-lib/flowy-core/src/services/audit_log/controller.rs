@@ -0,0 +1,182 @@
+use crate::{
+    entities::audit_log::{AuditLogEntry, AuditLogPage, ReadAuditLogParams},
+    errors::FlowyResult,
+    module::{WorkspaceDatabase, WorkspaceUser},
+    services::{
+        app::sql::AppTable,
+        audit_log::sql::{AuditLogTableSql, NewAuditLogTable},
+        view::sql::ViewTable,
+        workspace::{
+            role::{check_workspace_owner, read_belonging_workspace_id},
+            sql::WorkspaceTable,
+        },
+    },
+};
+use flowy_database::SqliteConnection;
+use lib_infra::clock::RuntimeEnv;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditAction {
+    Created,
+    Updated,
+    Moved,
+    Trashed,
+    Restored,
+    Deleted,
+}
+
+impl AuditAction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AuditAction::Created => "created",
+            AuditAction::Updated => "updated",
+            AuditAction::Moved => "moved",
+            AuditAction::Trashed => "trashed",
+            AuditAction::Restored => "restored",
+            AuditAction::Deleted => "deleted",
+        }
+    }
+}
+
+// The trashed and the deleted objects only have a `before`, the created and the
+// restored ones only have an `after`.
+pub(crate) struct AuditRecord {
+    workspace_id: String,
+    action: AuditAction,
+    object_type: &'static str,
+    object_id: String,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+impl AuditRecord {
+    pub(crate) fn workspace(
+        action: AuditAction,
+        before: Option<&WorkspaceTable>,
+        after: Option<&WorkspaceTable>,
+    ) -> Self {
+        let object_id = after.or(before).map(|table| table.id.clone()).unwrap_or_default();
+        let value = |table: &WorkspaceTable| {
+            json!({
+                "id": table.id,
+                "name": table.name,
+                "desc": table.desc,
+            })
+        };
+        AuditRecord {
+            workspace_id: object_id.clone(),
+            action,
+            object_type: "workspace",
+            object_id,
+            before: before.map(value),
+            after: after.map(value),
+        }
+    }
+
+    pub(crate) fn app(action: AuditAction, before: Option<&AppTable>, after: Option<&AppTable>) -> Self {
+        let table = after.or(before);
+        let value = |table: &AppTable| {
+            json!({
+                "id": table.id,
+                "workspace_id": table.workspace_id,
+                "name": table.name,
+                "desc": table.desc,
+            })
+        };
+        AuditRecord {
+            workspace_id: table.map(|table| table.workspace_id.clone()).unwrap_or_default(),
+            action,
+            object_type: "app",
+            object_id: table.map(|table| table.id.clone()).unwrap_or_default(),
+            before: before.map(value),
+            after: after.map(value),
+        }
+    }
+
+    // The workspace is found through the parent of the view, so it must be
+    // called before the parent is deleted.
+    pub(crate) fn view(
+        action: AuditAction,
+        before: Option<&ViewTable>,
+        after: Option<&ViewTable>,
+        conn: &SqliteConnection,
+    ) -> FlowyResult<Self> {
+        let table = after.or(before);
+        let workspace_id = match table {
+            None => None,
+            Some(table) => read_belonging_workspace_id(&table.belong_to_id, conn)?,
+        };
+        let value = |table: &ViewTable| {
+            json!({
+                "id": table.id,
+                "belong_to_id": table.belong_to_id,
+                "name": table.name,
+                "desc": table.desc,
+                "thumbnail": table.thumbnail,
+                "view_type": table.view_type as i32,
+            })
+        };
+        Ok(AuditRecord {
+            workspace_id: workspace_id.unwrap_or_default(),
+            action,
+            object_type: "view",
+            object_id: table.map(|table| table.id.clone()).unwrap_or_default(),
+            before: before.map(value),
+            after: after.map(value),
+        })
+    }
+}
+
+// Records who changed what in the folders, so the owner of a workspace can
+// audit the changes of its members. Only the changes made on this device are
+// recorded.
+pub struct AuditLogController {
+    database: Arc<dyn WorkspaceDatabase>,
+    user: Arc<dyn WorkspaceUser>,
+    env: RuntimeEnv,
+}
+
+impl AuditLogController {
+    pub(crate) fn new(database: Arc<dyn WorkspaceDatabase>, user: Arc<dyn WorkspaceUser>, env: RuntimeEnv) -> Self {
+        Self { database, user, env }
+    }
+
+    // The entry is appended with the connection of the change, so it's committed
+    // or rolled back together with it.
+    pub(crate) fn record(&self, record: AuditRecord, conn: &SqliteConnection) -> FlowyResult<()> {
+        let to_string = |value: Option<Value>| value.map(|value| value.to_string()).unwrap_or_default();
+        let table = NewAuditLogTable {
+            workspace_id: record.workspace_id,
+            actor_id: self.user.user_id()?,
+            action: record.action.name().to_owned(),
+            object_type: record.object_type.to_owned(),
+            object_id: record.object_id,
+            before: to_string(record.before),
+            after: to_string(record.after),
+            create_time: self.env.timestamp(),
+        };
+        AuditLogTableSql::append(table, conn)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn read_audit_log(&self, params: ReadAuditLogParams) -> FlowyResult<AuditLogPage> {
+        let conn = self.database.db_connection()?;
+        let _ = check_workspace_owner(&params.workspace_id, &*conn)?;
+        let mut tables = AuditLogTableSql::read_after(&params.workspace_id, params.cursor, params.limit + 1, &*conn)?;
+        let has_more = tables.len() as i64 > params.limit;
+        tables.truncate(params.limit as usize);
+
+        let next_cursor = tables.last().map(|table| table.seq).unwrap_or(params.cursor);
+        let items = tables
+            .into_iter()
+            .map(|table| table.into())
+            .collect::<Vec<AuditLogEntry>>();
+        Ok(AuditLogPage {
+            items,
+            next_cursor,
+            has_more,
+        })
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{
+    entities::audit_log::{AuditLogPage, ReadAuditLogParams, ReadAuditLogRequest},
+    errors::FlowyError,
+    services::AuditLogController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_audit_log_handler(
+    data: Data<ReadAuditLogRequest>,
+    controller: Unit<Arc<AuditLogController>>,
+) -> DataResult<AuditLogPage, FlowyError> {
+    let params: ReadAuditLogParams = data.into_inner().try_into()?;
+    let page = controller.read_audit_log(params)?;
+    data_result(page)
+}
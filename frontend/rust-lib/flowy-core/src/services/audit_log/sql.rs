@@ -0,0 +1,76 @@
+use crate::{entities::audit_log::AuditLogEntry, errors::FlowyError};
+use flowy_database::{
+    prelude::*,
+    schema::{audit_log_table, audit_log_table::dsl},
+    SqliteConnection,
+};
+
+// The log is append-only: the entries are never updated, and unlike the
+// event log it isn't pruned.
+pub(crate) struct AuditLogTableSql {}
+
+impl AuditLogTableSql {
+    pub(crate) fn append(table: NewAuditLogTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = diesel::insert_into(audit_log_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_after(
+        workspace_id: &str,
+        cursor: i64,
+        limit: i64,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<AuditLogTable>, FlowyError> {
+        let tables = dsl::audit_log_table
+            .filter(audit_log_table::workspace_id.eq(workspace_id))
+            .filter(audit_log_table::seq.gt(cursor))
+            .order(audit_log_table::seq.asc())
+            .limit(limit)
+            .load::<AuditLogTable>(conn)?;
+        Ok(tables)
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable)]
+pub(crate) struct AuditLogTable {
+    pub seq: i64,
+    pub workspace_id: String,
+    pub actor_id: String,
+    pub action: String,
+    pub object_type: String,
+    pub object_id: String,
+    pub before: String,
+    pub after: String,
+    pub create_time: i64,
+}
+
+#[derive(Insertable)]
+#[table_name = "audit_log_table"]
+pub(crate) struct NewAuditLogTable {
+    pub workspace_id: String,
+    pub actor_id: String,
+    pub action: String,
+    pub object_type: String,
+    pub object_id: String,
+    pub before: String,
+    pub after: String,
+    pub create_time: i64,
+}
+
+impl std::convert::From<AuditLogTable> for AuditLogEntry {
+    fn from(table: AuditLogTable) -> Self {
+        AuditLogEntry {
+            seq: table.seq,
+            workspace_id: table.workspace_id,
+            actor_id: table.actor_id,
+            action: table.action,
+            object_type: table.object_type,
+            object_id: table.object_id,
+            before: table.before,
+            after: table.after,
+            create_time: table.create_time,
+        }
+    }
+}
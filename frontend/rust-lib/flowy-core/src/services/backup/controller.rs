@@ -0,0 +1,332 @@
+use crate::{
+    entities::backup::{
+        BackupFailure,
+        BackupFrequency,
+        BackupResult,
+        BackupSetting,
+        BackupSettingParams,
+        QueryBackupsParams,
+        RepeatedBackupManifest,
+    },
+    errors::{FlowyError, FlowyResult},
+    module::{WorkspaceDatabase, WorkspaceUser},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{
+        export::{read_manifest, restore_folder, verify_archive, RestoredFolder, UserDataExporter},
+        ViewController,
+    },
+};
+use flowy_database::kv::KV;
+use lib_infra::clock::RuntimeEnv;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+const DEFAULT_KEEP_COUNT: i64 = 7;
+const RESTORE_BACKUP_PATH: &str = "restore_backup_path";
+
+// A backup that is due runs within this interval, e.g. after the device woke
+// up from sleep.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+// Writes the archive of the user data export to the backup directory on a
+// schedule, and keeps the newest `keep_count` of them. Each backup is read
+// back before the older ones are removed, so a broken archive never replaces
+// a good one.
+pub struct BackupController {
+    user: Arc<dyn WorkspaceUser>,
+    exporter: UserDataExporter,
+    env: RuntimeEnv,
+    is_started: AtomicBool,
+    // The scheduled and the manual backups don't run at the same time, or the
+    // rotation of one could remove the archive that the other is verifying.
+    running: Mutex<()>,
+}
+
+impl BackupController {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        view_controller: Arc<ViewController>,
+        env: RuntimeEnv,
+    ) -> Self {
+        let exporter = UserDataExporter::new(user.clone(), database, view_controller);
+        Self {
+            user,
+            exporter,
+            env,
+            is_started: AtomicBool::new(false),
+            running: Mutex::new(()),
+        }
+    }
+
+    pub(crate) fn read_setting(&self) -> FlowyResult<BackupSetting> {
+        let user_id = self.user.user_id()?;
+        Ok(BackupConfig::read(&user_id).into())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn update_setting(&self, params: BackupSettingParams) -> FlowyResult<BackupSetting> {
+        if params.enabled && !Path::new(&params.dir).is_dir() {
+            return Err(FlowyError::export_path().context(format!("{} is not a directory", params.dir)));
+        }
+
+        let user_id = self.user.user_id()?;
+        let mut config = BackupConfig::read(&user_id);
+        config.enabled = params.enabled;
+        config.dir = params.dir;
+        config.frequency = params.frequency as i32;
+        config.keep_count = params.keep_count;
+        config.save(&user_id);
+        Ok(config.into())
+    }
+
+    // Backs up now, whether the scheduled backups are turned on or not.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn run_backup(&self) -> FlowyResult<BackupResult> {
+        let user_id = self.user.user_id()?;
+        let _running = self.running.lock();
+        let mut config = BackupConfig::read(&user_id);
+        if config.dir.is_empty() {
+            return Err(FlowyError::export_path().context("The backup directory is not set"));
+        }
+        self.backup(&user_id, &mut config)
+    }
+
+    // The archives in the directory that can be restored, the newest first,
+    // e.g. the backups of another device in a synced folder.
+    pub(crate) fn read_backups(&self, params: QueryBackupsParams) -> FlowyResult<RepeatedBackupManifest> {
+        let dir = Path::new(&params.dir);
+        if !dir.is_dir() {
+            return Err(FlowyError::export_path().context(format!("{} is not a directory", params.dir)));
+        }
+
+        let mut manifests = vec![];
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().map_or(true, |extension| extension != "zip") {
+                continue;
+            }
+            match read_manifest(&path) {
+                Ok(manifest) => manifests.push(manifest),
+                Err(e) => tracing::debug!("Skip the archive {:?}: {}", path, e),
+            }
+        }
+        manifests.sort_by(|a, b| b.export_time.cmp(&a.export_time));
+        Ok(RepeatedBackupManifest { items: manifests })
+    }
+
+    // The archive is checked when it's selected, so a broken one is reported
+    // before the sign up instead of being skipped after it.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn select_restore(&self, path: String) -> FlowyResult<()> {
+        if path.trim().is_empty() {
+            let _ = KV::remove(RESTORE_BACKUP_PATH);
+            return Ok(());
+        }
+
+        let _ = read_manifest(Path::new(&path))?;
+        KV::set_str(RESTORE_BACKUP_PATH, path);
+        Ok(())
+    }
+
+    // Takes the archive that was selected before the sign up, it's restored
+    // once, by the first user who signs up on this device afterwards.
+    pub(crate) fn take_restore(&self) -> Option<FlowyResult<RestoredFolder>> {
+        let path = KV::get_str(RESTORE_BACKUP_PATH)?;
+        let _ = KV::remove(RESTORE_BACKUP_PATH);
+        Some(restore_folder(Path::new(&path), &self.env))
+    }
+
+    // Checks whether the backup is due every hour until the app quits. It's
+    // started once, the loop reads the setting of whoever is signed in.
+    pub(crate) fn start(self: &Arc<Self>) {
+        if self.is_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let weak_controller = Arc::downgrade(self);
+        let clock = self.env.clock.clone();
+        tokio::spawn(async move {
+            loop {
+                let controller = match weak_controller.upgrade() {
+                    None => break,
+                    Some(controller) => controller,
+                };
+                let _ = tokio::task::spawn_blocking(move || controller.run_if_due()).await;
+                clock.sleep(CHECK_INTERVAL).await;
+            }
+        });
+    }
+
+    fn run_if_due(&self) {
+        let user_id = match self.user.user_id() {
+            Ok(user_id) => user_id,
+            Err(_) => return,
+        };
+        let _running = self.running.lock();
+        let mut config = BackupConfig::read(&user_id);
+        let period = BackupFrequency::from(config.frequency).period();
+        if !config.enabled || self.env.timestamp() - config.last_backup_time < period {
+            return;
+        }
+        if let Err(e) = self.backup(&user_id, &mut config) {
+            tracing::error!("Scheduled backup failed: {}", e);
+        }
+    }
+
+    // The failure is saved in the setting and sent to the user, the last backup
+    // time stays, so it's tried again at the next check.
+    fn backup(&self, user_id: &str, config: &mut BackupConfig) -> FlowyResult<BackupResult> {
+        let backup_time = self.env.timestamp();
+        let result = self.write_backup(user_id, config, backup_time);
+        match &result {
+            Ok(_) => {
+                config.last_backup_time = backup_time;
+                config.last_error = String::new();
+            },
+            Err(e) => {
+                config.last_error = e.msg.clone();
+                let failure = BackupFailure {
+                    dir: config.dir.clone(),
+                    error: e.msg.clone(),
+                    time: backup_time,
+                };
+                if let Ok(token) = self.user.token() {
+                    send_dart_notification(&token, WorkspaceNotification::BackupFailed)
+                        .payload(failure)
+                        .send();
+                }
+            },
+        }
+        config.save(user_id);
+        result
+    }
+
+    fn write_backup(&self, user_id: &str, config: &BackupConfig, backup_time: i64) -> FlowyResult<BackupResult> {
+        let dir = Path::new(&config.dir);
+        if !dir.is_dir() {
+            return Err(FlowyError::export_path().context(format!("{} is not a directory", config.dir)));
+        }
+
+        let path = backup_path(dir, user_id, backup_time);
+        let document_count = self.exporter.write(&path, user_id, backup_time)?;
+        if let Err(e) = verify_archive(&path, user_id) {
+            let _ = std::fs::remove_file(&path);
+            return Err(e);
+        }
+
+        let _ = rotate_backups(dir, user_id, config.keep_count)?;
+        Ok(BackupResult {
+            path: path.to_string_lossy().to_string(),
+            document_count,
+            backup_time,
+        })
+    }
+}
+
+fn backup_prefix(user_id: &str) -> String { format!("appflowy_backup_{}_", user_id) }
+
+// The name has the time of the backup, followed by a counter if there is one
+// of the same second already.
+fn backup_path(dir: &Path, user_id: &str, backup_time: i64) -> PathBuf {
+    let mut path = dir.join(format!("{}{}.zip", backup_prefix(user_id), backup_time));
+    let mut n = 1;
+    while path.exists() {
+        path = dir.join(format!("{}{}_{}.zip", backup_prefix(user_id), backup_time, n));
+        n += 1;
+    }
+    path
+}
+
+// Removes the oldest backups of the user beyond the count. The other files of
+// the directory, including the backups of the other users, are left alone.
+fn rotate_backups(dir: &Path, user_id: &str, keep_count: i64) -> FlowyResult<()> {
+    let prefix = backup_prefix(user_id);
+    let mut backups = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = match path.file_name().and_then(|name| name.to_str()) {
+            None => continue,
+            Some(name) => name,
+        };
+        let stem = match name.strip_prefix(&prefix).and_then(|name| name.strip_suffix(".zip")) {
+            None => continue,
+            Some(stem) => stem,
+        };
+        let mut parts = stem.splitn(2, '_');
+        let time = parts.next().and_then(|time| time.parse::<i64>().ok());
+        let n = parts.next().map_or(Some(0), |n| n.parse::<i64>().ok());
+        if let (Some(time), Some(n)) = (time, n) {
+            backups.push(((time, n), path));
+        }
+    }
+
+    backups.sort_by(|a, b| b.0.cmp(&a.0));
+    for (_, path) in backups.into_iter().skip(keep_count.max(1) as usize) {
+        if let Err(e) = std::fs::remove_file(&path) {
+            tracing::error!("Remove the backup {:?} failed: {}", path, e);
+        }
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize)]
+struct BackupConfig {
+    enabled: bool,
+    dir: String,
+    frequency: i32,
+    keep_count: i64,
+    last_backup_time: i64,
+    last_error: String,
+}
+
+impl std::default::Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dir: String::new(),
+            frequency: BackupFrequency::Daily as i32,
+            keep_count: DEFAULT_KEEP_COUNT,
+            last_backup_time: 0,
+            last_error: String::new(),
+        }
+    }
+}
+
+impl BackupConfig {
+    fn key(user_id: &str) -> String { format!("backup_setting_{}", user_id) }
+
+    fn read(user_id: &str) -> Self {
+        KV::get_str(&Self::key(user_id))
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, user_id: &str) {
+        match serde_json::to_string(self) {
+            Ok(json) => KV::set_str(&Self::key(user_id), json),
+            Err(e) => log::error!("Save the backup setting of {} failed: {:?}", user_id, e),
+        }
+    }
+}
+
+impl std::convert::From<BackupConfig> for BackupSetting {
+    fn from(config: BackupConfig) -> Self {
+        BackupSetting {
+            enabled: config.enabled,
+            dir: config.dir,
+            frequency: BackupFrequency::from(config.frequency),
+            keep_count: config.keep_count,
+            last_backup_time: config.last_backup_time,
+            last_error: config.last_error,
+        }
+    }
+}
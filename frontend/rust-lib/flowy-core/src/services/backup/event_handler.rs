@@ -0,0 +1,71 @@
+use crate::{
+    entities::backup::{
+        BackupResult,
+        BackupSetting,
+        BackupSettingParams,
+        QueryBackupsParams,
+        QueryBackupsRequest,
+        RepeatedBackupManifest,
+        SelectBackupRestoreRequest,
+        UpdateBackupSettingRequest,
+    },
+    errors::{internal_error, FlowyError},
+    services::BackupController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_backup_setting_handler(
+    controller: Unit<Arc<BackupController>>,
+) -> DataResult<BackupSetting, FlowyError> {
+    let setting = controller.read_setting()?;
+    data_result(setting)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_backup_setting_handler(
+    data: Data<UpdateBackupSettingRequest>,
+    controller: Unit<Arc<BackupController>>,
+) -> DataResult<BackupSetting, FlowyError> {
+    let params: BackupSettingParams = data.into_inner().try_into()?;
+    let setting = controller.update_setting(params)?;
+    data_result(setting)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn run_backup_handler(
+    controller: Unit<Arc<BackupController>>,
+) -> DataResult<BackupResult, FlowyError> {
+    let controller = controller.get_ref().clone();
+    let result = tokio::task::spawn_blocking(move || controller.run_backup())
+        .await
+        .map_err(internal_error)??;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_backups_handler(
+    data: Data<QueryBackupsRequest>,
+    controller: Unit<Arc<BackupController>>,
+) -> DataResult<RepeatedBackupManifest, FlowyError> {
+    let params: QueryBackupsParams = data.into_inner().try_into()?;
+    let controller = controller.get_ref().clone();
+    let manifests = tokio::task::spawn_blocking(move || controller.read_backups(params))
+        .await
+        .map_err(internal_error)??;
+    data_result(manifests)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn select_backup_restore_handler(
+    data: Data<SelectBackupRestoreRequest>,
+    controller: Unit<Arc<BackupController>>,
+) -> Result<(), FlowyError> {
+    let path = data.into_inner().path;
+    let controller = controller.get_ref().clone();
+    let _ = tokio::task::spawn_blocking(move || controller.select_restore(path))
+        .await
+        .map_err(internal_error)??;
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use crate::{
+    errors::FlowyError,
+    services::{app::sql::AppTable, view::sql::ViewTable},
+};
+use flowy_database::{commit_hook::after_commit, connection::TransactionManager, Connection, SqliteConnection};
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+lazy_static! {
+    static ref VIEW_CACHE: TableCache<String, ViewTable> = TableCache::new();
+    static ref BELONGINGS_CACHE: TableCache<String, Vec<ViewTable>> = TableCache::new();
+    static ref WORKSPACE_APPS_CACHE: TableCache<(String, bool), Vec<AppTable>> = TableCache::new();
+}
+
+// Bumped on every write to the view or app table, and again once the write is
+// committed. A read that started before either of them doesn't fill the cache
+// with what it read, the other connections only see the write after the
+// commit.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+// Caches the rows of the hot folder queries, the sidebar reads the same views
+// and apps again and again while it's re-rendered. A write to the view or app
+// table only drops the entries of the rows it touched, so an edit costs the
+// same in a small and in a large workspace.
+struct TableCache<K, T> {
+    entries: RwLock<HashMap<K, T>>,
+}
+
+impl<K, T> TableCache<K, T>
+where
+    K: Eq + Hash,
+    T: Clone,
+{
+    fn new() -> Self {
+        Self {
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn read_through<F>(&self, key: K, conn: &SqliteConnection, load: F) -> Result<T, FlowyError>
+    where
+        F: FnOnce() -> Result<T, FlowyError>,
+    {
+        if let Some(value) = self.entries.read().get(&key) {
+            return Ok(value.clone());
+        }
+
+        let generation = GENERATION.load(Ordering::SeqCst);
+        let value = load()?;
+        // The rows read inside a transaction may be rolled back, so they are
+        // never cached.
+        if conn.transaction_manager().get_transaction_depth() == 0 {
+            let mut entries = self.entries.write();
+            if generation == GENERATION.load(Ordering::SeqCst) {
+                entries.insert(key, value.clone());
+            }
+        }
+        Ok(value)
+    }
+
+    fn remove(&self, key: &K) { self.entries.write().remove(key); }
+
+    fn clear(&self) { self.entries.write().clear(); }
+}
+
+pub(crate) fn read_view_through_cache<F>(
+    view_id: &str,
+    conn: &SqliteConnection,
+    load: F,
+) -> Result<ViewTable, FlowyError>
+where
+    F: FnOnce() -> Result<ViewTable, FlowyError>,
+{
+    VIEW_CACHE.read_through(view_id.to_owned(), conn, load)
+}
+
+pub(crate) fn read_views_through_cache<F>(
+    belong_to_id: &str,
+    conn: &SqliteConnection,
+    load: F,
+) -> Result<Vec<ViewTable>, FlowyError>
+where
+    F: FnOnce() -> Result<Vec<ViewTable>, FlowyError>,
+{
+    BELONGINGS_CACHE.read_through(belong_to_id.to_owned(), conn, load)
+}
+
+pub(crate) fn read_workspace_apps_through_cache<F>(
+    workspace_id: &str,
+    is_trash: bool,
+    conn: &SqliteConnection,
+    load: F,
+) -> Result<Vec<AppTable>, FlowyError>
+where
+    F: FnOnce() -> Result<Vec<AppTable>, FlowyError>,
+{
+    WORKSPACE_APPS_CACHE.read_through((workspace_id.to_owned(), is_trash), conn, load)
+}
+
+// The belong_to_ids are the parents of the view before and after the write,
+// they differ if the view was moved.
+pub(crate) fn invalidate_view_cache(view_id: &str, belong_to_ids: &[String], conn: &SqliteConnection) {
+    let view_id = view_id.to_owned();
+    let belong_to_ids = belong_to_ids.to_vec();
+    invalidate_around_commit(conn, move || {
+        VIEW_CACHE.remove(&view_id);
+        for belong_to_id in &belong_to_ids {
+            BELONGINGS_CACHE.remove(belong_to_id);
+        }
+    });
+}
+
+pub(crate) fn invalidate_app_cache(workspace_id: &str, conn: &SqliteConnection) {
+    let workspace_id = workspace_id.to_owned();
+    invalidate_around_commit(conn, move || {
+        WORKSPACE_APPS_CACHE.remove(&(workspace_id.clone(), false));
+        WORKSPACE_APPS_CACHE.remove(&(workspace_id.clone(), true));
+    });
+}
+
+// The entries are dropped right away so that the writing connection doesn't
+// read them back, and again after the commit since a read on another
+// connection may have cached the rows from before the write in between.
+fn invalidate_around_commit<F>(conn: &SqliteConnection, remove: F)
+where
+    F: Fn() + 'static,
+{
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    remove();
+    after_commit(conn, move || {
+        GENERATION.fetch_add(1, Ordering::SeqCst);
+        remove();
+    });
+}
+
+pub(crate) fn invalidate_folder_cache() {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    VIEW_CACHE.clear();
+    BELONGINGS_CACHE.clear();
+    WORKSPACE_APPS_CACHE.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::FlowyError,
+        services::view::sql::{ViewTable, ViewTableSql, ViewTableType},
+    };
+    use flowy_database::prelude::*;
+    use lib_infra::uuid_string;
+
+    #[test]
+    fn read_during_uncommitted_write_not_cached() {
+        let path = std::env::temp_dir().join(uuid_string());
+        let database = flowy_database::init(path.to_str().unwrap()).unwrap();
+        let writer = database.get_connection().unwrap();
+        let reader = database.get_connection().unwrap();
+        let belong_to_id = uuid_string();
+        let view = ViewTable {
+            id: uuid_string(),
+            belong_to_id: belong_to_id.clone(),
+            name: "view".to_owned(),
+            desc: "".to_owned(),
+            modified_time: 0,
+            create_time: 0,
+            thumbnail: "".to_owned(),
+            view_type: ViewTableType::Docs,
+            version: 0,
+            is_trash: false,
+        };
+
+        let _ = (&*writer)
+            .timed_transaction::<_, FlowyError, _>("cache.test", || {
+                let _ = ViewTableSql::create_view(view.clone(), &*writer)?;
+                // The other connection reads the rows from before the write
+                assert!(ViewTableSql::read_views(&belong_to_id, &*reader)?.is_empty());
+                Ok(())
+            })
+            .unwrap();
+
+        let views = ViewTableSql::read_views(&belong_to_id, &*reader).unwrap();
+        assert_eq!(views, vec![view]);
+    }
+}
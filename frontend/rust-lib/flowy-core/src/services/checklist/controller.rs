@@ -0,0 +1,112 @@
+use crate::{
+    entities::checklist::{
+        Checklist,
+        ChecklistId,
+        ChecklistItem,
+        ChecklistItemId,
+        CompleteChecklistItemsParams,
+        CreateChecklistItemParams,
+        MoveChecklistItemParams,
+        UpdateChecklistItemParams,
+    },
+    errors::{FlowyError, FlowyResult},
+    module::WorkspaceDatabase,
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{
+        checklist::pad::ChecklistPad,
+        view::sql::{ViewTableSql, ViewTableType},
+        ViewController,
+    },
+};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+// The checklists are saved through their documents like the grids, but they
+// don't have fields or cells.
+pub(crate) struct ChecklistController {
+    database: Arc<dyn WorkspaceDatabase>,
+    view_controller: Arc<ViewController>,
+    edit_lock: Mutex<()>,
+}
+
+impl ChecklistController {
+    pub(crate) fn new(database: Arc<dyn WorkspaceDatabase>, view_controller: Arc<ViewController>) -> Self {
+        Self {
+            database,
+            view_controller,
+            edit_lock: Mutex::new(()),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_checklist(&self, params: ChecklistId) -> FlowyResult<Checklist> {
+        let pad = self.open_checklist(&params.checklist_id).await?;
+        Ok(pad.checklist().clone())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_item(&self, params: CreateChecklistItemParams) -> FlowyResult<ChecklistItem> {
+        let checklist_id = params.checklist_id.clone();
+        self.edit_checklist(&checklist_id, |pad| Ok(pad.create_item(params)))
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_item(&self, params: UpdateChecklistItemParams) -> FlowyResult<ChecklistItem> {
+        let checklist_id = params.checklist_id.clone();
+        self.edit_checklist(&checklist_id, |pad| pad.update_item(params)).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn delete_item(&self, params: ChecklistItemId) -> FlowyResult<()> {
+        self.edit_checklist(&params.checklist_id, |pad| pad.delete_item(&params.item_id))
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn toggle_item(&self, params: ChecklistItemId) -> FlowyResult<ChecklistItem> {
+        self.edit_checklist(&params.checklist_id, |pad| pad.toggle_item(&params.item_id))
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn move_item(&self, params: MoveChecklistItemParams) -> FlowyResult<()> {
+        let checklist_id = params.checklist_id.clone();
+        self.edit_checklist(&checklist_id, |pad| pad.move_item(params)).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn complete_items(&self, params: CompleteChecklistItemsParams) -> FlowyResult<()> {
+        self.edit_checklist(&params.checklist_id, |pad| pad.complete_items(&params.item_ids))
+            .await
+    }
+
+    async fn open_checklist(&self, checklist_id: &str) -> FlowyResult<ChecklistPad> {
+        let view_table = ViewTableSql::read_view(checklist_id, &*self.database.db_connection()?)?;
+        if view_table.view_type != ViewTableType::Checklist {
+            return Err(FlowyError::view_type().context("The view is not a checklist"));
+        }
+        let text = self.view_controller.read_view_text(checklist_id).await?;
+        ChecklistPad::from_text(checklist_id, text)
+    }
+
+    // The checklist is small, so the notification carries all of it.
+    async fn edit_checklist<F, T>(&self, checklist_id: &str, f: F) -> FlowyResult<T>
+    where
+        F: FnOnce(&mut ChecklistPad) -> FlowyResult<T>,
+    {
+        let _guard = self.edit_lock.lock().await;
+        let mut pad = self.open_checklist(checklist_id).await?;
+        let value = f(&mut pad)?;
+        let new_text = pad.to_text()?;
+        let _ = self
+            .view_controller
+            .write_view_text(checklist_id, pad.text(), &new_text)
+            .await?;
+
+        send_dart_notification(checklist_id, WorkspaceNotification::ChecklistUpdated)
+            .payload(pad.checklist().clone())
+            .send();
+        Ok(value)
+    }
+}
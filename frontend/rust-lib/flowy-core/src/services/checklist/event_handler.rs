@@ -0,0 +1,92 @@
+use crate::{
+    entities::checklist::{
+        Checklist,
+        ChecklistId,
+        ChecklistItem,
+        ChecklistItemId,
+        CompleteChecklistItemsParams,
+        CompleteChecklistItemsRequest,
+        CreateChecklistItemParams,
+        CreateChecklistItemRequest,
+        MoveChecklistItemParams,
+        MoveChecklistItemRequest,
+        QueryChecklistItemRequest,
+        QueryChecklistRequest,
+        UpdateChecklistItemParams,
+        UpdateChecklistItemRequest,
+    },
+    errors::FlowyError,
+    services::ChecklistController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_checklist_handler(
+    data: Data<QueryChecklistRequest>,
+    controller: Unit<Arc<ChecklistController>>,
+) -> DataResult<Checklist, FlowyError> {
+    let params: ChecklistId = data.into_inner().try_into()?;
+    let checklist = controller.read_checklist(params).await?;
+    data_result(checklist)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_checklist_item_handler(
+    data: Data<CreateChecklistItemRequest>,
+    controller: Unit<Arc<ChecklistController>>,
+) -> DataResult<ChecklistItem, FlowyError> {
+    let params: CreateChecklistItemParams = data.into_inner().try_into()?;
+    let item = controller.create_item(params).await?;
+    data_result(item)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_checklist_item_handler(
+    data: Data<UpdateChecklistItemRequest>,
+    controller: Unit<Arc<ChecklistController>>,
+) -> DataResult<ChecklistItem, FlowyError> {
+    let params: UpdateChecklistItemParams = data.into_inner().try_into()?;
+    let item = controller.update_item(params).await?;
+    data_result(item)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_checklist_item_handler(
+    data: Data<QueryChecklistItemRequest>,
+    controller: Unit<Arc<ChecklistController>>,
+) -> Result<(), FlowyError> {
+    let params: ChecklistItemId = data.into_inner().try_into()?;
+    let _ = controller.delete_item(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn toggle_checklist_item_handler(
+    data: Data<QueryChecklistItemRequest>,
+    controller: Unit<Arc<ChecklistController>>,
+) -> DataResult<ChecklistItem, FlowyError> {
+    let params: ChecklistItemId = data.into_inner().try_into()?;
+    let item = controller.toggle_item(params).await?;
+    data_result(item)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_checklist_item_handler(
+    data: Data<MoveChecklistItemRequest>,
+    controller: Unit<Arc<ChecklistController>>,
+) -> Result<(), FlowyError> {
+    let params: MoveChecklistItemParams = data.into_inner().try_into()?;
+    let _ = controller.move_item(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn complete_checklist_items_handler(
+    data: Data<CompleteChecklistItemsRequest>,
+    controller: Unit<Arc<ChecklistController>>,
+) -> Result<(), FlowyError> {
+    let params: CompleteChecklistItemsParams = data.into_inner().try_into()?;
+    let _ = controller.complete_items(params).await?;
+    Ok(())
+}
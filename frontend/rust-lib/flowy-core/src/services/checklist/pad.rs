@@ -0,0 +1,105 @@
+use crate::{
+    entities::checklist::{
+        Checklist,
+        ChecklistItem,
+        CreateChecklistItemParams,
+        MoveChecklistItemParams,
+        UpdateChecklistItemParams,
+    },
+    errors::{FlowyError, FlowyResult},
+};
+use lib_infra::uuid_string;
+
+// Applies the edits to the checklist that was read from the text of its
+// document.
+pub(crate) struct ChecklistPad {
+    checklist: Checklist,
+    text: String,
+}
+
+impl ChecklistPad {
+    pub(crate) fn from_text(checklist_id: &str, text: String) -> FlowyResult<Self> {
+        let mut checklist: Checklist = serde_json::from_str(text.trim_end_matches('\n'))
+            .map_err(|e| FlowyError::view_data().context(format!("The checklist can't be read: {}", e)))?;
+        checklist.id = checklist_id.to_owned();
+        Ok(Self { checklist, text })
+    }
+
+    pub(crate) fn checklist(&self) -> &Checklist { &self.checklist }
+
+    pub(crate) fn create_item(&mut self, params: CreateChecklistItemParams) -> ChecklistItem {
+        let item = ChecklistItem {
+            id: uuid_string(),
+            title: params.title,
+            done: false,
+            due_date: params.due_date,
+        };
+        self.checklist.items.push(item.clone());
+        item
+    }
+
+    pub(crate) fn update_item(&mut self, params: UpdateChecklistItemParams) -> FlowyResult<ChecklistItem> {
+        let item = self.item_mut(&params.item_id)?;
+        if let Some(title) = params.title {
+            item.title = title;
+        }
+        if let Some(due_date) = params.due_date {
+            item.due_date = due_date;
+        }
+        Ok(item.clone())
+    }
+
+    pub(crate) fn delete_item(&mut self, item_id: &str) -> FlowyResult<()> {
+        let index = self.item_index(item_id)?;
+        self.checklist.items.remove(index);
+        Ok(())
+    }
+
+    pub(crate) fn toggle_item(&mut self, item_id: &str) -> FlowyResult<ChecklistItem> {
+        let item = self.item_mut(item_id)?;
+        item.done = !item.done;
+        Ok(item.clone())
+    }
+
+    pub(crate) fn move_item(&mut self, params: MoveChecklistItemParams) -> FlowyResult<()> {
+        let index = self.item_index(&params.item_id)?;
+        let item = self.checklist.items.remove(index);
+        let to_index = (params.to_index.max(0) as usize).min(self.checklist.items.len());
+        self.checklist.items.insert(to_index, item);
+        Ok(())
+    }
+
+    // Completes all the items if the item_ids is empty.
+    pub(crate) fn complete_items(&mut self, item_ids: &[String]) -> FlowyResult<()> {
+        for item_id in item_ids {
+            let _ = self.item_index(item_id)?;
+        }
+        for item in self.checklist.items.iter_mut() {
+            if item_ids.is_empty() || item_ids.contains(&item.id) {
+                item.done = true;
+            }
+        }
+        Ok(())
+    }
+
+    // The text that the checklist was read from.
+    pub(crate) fn text(&self) -> &str { &self.text }
+
+    pub(crate) fn to_text(&self) -> FlowyResult<String> {
+        let json = serde_json::to_string(&self.checklist).map_err(|e| FlowyError::internal().context(e))?;
+        Ok(format!("{}\n", json))
+    }
+
+    fn item_index(&self, item_id: &str) -> FlowyResult<usize> {
+        self.checklist
+            .items
+            .iter()
+            .position(|item| item.id == item_id)
+            .ok_or_else(|| FlowyError::record_not_found().context("The checklist item doesn't exist"))
+    }
+
+    fn item_mut(&mut self, item_id: &str) -> FlowyResult<&mut ChecklistItem> {
+        let index = self.item_index(item_id)?;
+        Ok(&mut self.checklist.items[index])
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{module::WorkspaceDatabase, services::diagnostics::health::read_database_size};
+use lib_infra::metrics::{self, MetricsSnapshot};
+use std::sync::Arc;
+
+pub struct DiagnosticsController {
+    database: Arc<dyn WorkspaceDatabase>,
+}
+
+impl DiagnosticsController {
+    pub(crate) fn new(database: Arc<dyn WorkspaceDatabase>) -> Self { Self { database } }
+
+    // The size of the database is read when the snapshot is taken instead of
+    // after every write.
+    pub(crate) fn metrics_snapshot(&self) -> MetricsSnapshot {
+        match read_database_size(&self.database) {
+            Ok(size) => metrics::set_gauge(metrics::DATABASE_SIZE, size),
+            Err(e) => log::warn!("Read the size of the database failed: {:?}", e),
+        }
+        metrics::snapshot()
+    }
+}
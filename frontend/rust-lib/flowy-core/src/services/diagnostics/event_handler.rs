@@ -0,0 +1,46 @@
+use crate::{
+    context::CoreContext,
+    entities::diagnostics::{
+        ExportDiagnosticsParams,
+        ExportDiagnosticsRequest,
+        ExportDiagnosticsResult,
+        HealthReport,
+        Metrics,
+    },
+    errors::{internal_error, FlowyError},
+    services::{export::DiagnosticsExporter, DiagnosticsController},
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_metrics_handler(
+    controller: Unit<Arc<DiagnosticsController>>,
+) -> DataResult<Metrics, FlowyError> {
+    let snapshot = controller.metrics_snapshot();
+    let json = serde_json::to_string(&snapshot)?;
+    data_result(Metrics { json })
+}
+
+#[tracing::instrument(skip(core), err)]
+pub(crate) async fn check_health_handler(core: Unit<Arc<CoreContext>>) -> DataResult<HealthReport, FlowyError> {
+    let report = core.health_check().await;
+    data_result(report)
+}
+
+#[tracing::instrument(skip(data, core, controller, exporter), err)]
+pub(crate) async fn export_diagnostics_handler(
+    data: Data<ExportDiagnosticsRequest>,
+    core: Unit<Arc<CoreContext>>,
+    controller: Unit<Arc<DiagnosticsController>>,
+    exporter: Unit<Arc<DiagnosticsExporter>>,
+) -> DataResult<ExportDiagnosticsResult, FlowyError> {
+    let params: ExportDiagnosticsParams = data.into_inner().try_into()?;
+    let health = core.health_check().await;
+    let metrics = controller.metrics_snapshot();
+    let exporter = exporter.get_ref().clone();
+    let result = tokio::task::spawn_blocking(move || exporter.export(params, health, metrics))
+        .await
+        .map_err(internal_error)??;
+    data_result(result)
+}
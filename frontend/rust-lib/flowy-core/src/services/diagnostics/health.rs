@@ -0,0 +1,74 @@
+use crate::{
+    entities::diagnostics::{HealthCheck, HealthStatus},
+    errors::FlowyResult,
+    module::WorkspaceDatabase,
+    services::{get_current_workspace, server::Server},
+};
+use flowy_net::services::ws_conn::WSConnectState;
+use lib_sqlite::PragmaExtension;
+use std::sync::Arc;
+
+// More revisions than this waiting for the ack usually means the sync is
+// stuck rather than slow.
+const MAX_HEALTHY_SYNC_QUEUE_DEPTH: i64 = 100;
+
+pub(crate) fn check_database(database: &Arc<dyn WorkspaceDatabase>) -> HealthCheck {
+    match read_database_size(database) {
+        Ok(size) => HealthCheck::new("database", HealthStatus::Healthy, format!("{} bytes", size)),
+        Err(e) => HealthCheck::new("database", HealthStatus::Unhealthy, e),
+    }
+}
+
+pub(crate) fn read_database_size(database: &Arc<dyn WorkspaceDatabase>) -> FlowyResult<i64> {
+    let conn = database.db_connection()?;
+    let size = conn.pragma_get_database_size(None)?;
+    Ok(size)
+}
+
+pub(crate) fn check_workspace(is_initialized: bool) -> HealthCheck {
+    if !is_initialized {
+        return HealthCheck::new("workspace", HealthStatus::Unhealthy, "The workspace is not initialized");
+    }
+
+    match get_current_workspace() {
+        Ok(workspace_id) => HealthCheck::new("workspace", HealthStatus::Healthy, workspace_id),
+        Err(_) => HealthCheck::new("workspace", HealthStatus::Degraded, "No workspace is opened"),
+    }
+}
+
+pub(crate) fn check_web_socket(state: &WSConnectState) -> HealthCheck {
+    if !cfg!(feature = "http_server") {
+        return HealthCheck::new("web_socket", HealthStatus::Skipped, "Running without the server");
+    }
+
+    match state {
+        WSConnectState::Connected => HealthCheck::new("web_socket", HealthStatus::Healthy, "Connected"),
+        WSConnectState::Init => HealthCheck::new("web_socket", HealthStatus::Degraded, "Not connected yet"),
+        WSConnectState::Connecting => HealthCheck::new("web_socket", HealthStatus::Degraded, "Connecting"),
+        WSConnectState::Disconnected => HealthCheck::new("web_socket", HealthStatus::Unhealthy, "Disconnected"),
+    }
+}
+
+pub(crate) async fn check_server(server: &Server) -> HealthCheck {
+    if !cfg!(feature = "http_server") {
+        return HealthCheck::new("server", HealthStatus::Skipped, "Running without the server");
+    }
+
+    match server.ping().await {
+        Ok(_) => HealthCheck::new("server", HealthStatus::Healthy, "Reachable"),
+        Err(e) => HealthCheck::new("server", HealthStatus::Unhealthy, e),
+    }
+}
+
+pub(crate) fn check_sync_queue(depth: i64) -> HealthCheck {
+    let status = if depth > MAX_HEALTHY_SYNC_QUEUE_DEPTH {
+        HealthStatus::Degraded
+    } else {
+        HealthStatus::Healthy
+    };
+    HealthCheck::new(
+        "sync_queue",
+        status,
+        format!("{} revisions are waiting to be synced", depth),
+    )
+}
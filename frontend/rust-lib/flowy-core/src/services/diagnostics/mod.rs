@@ -0,0 +1,3 @@
+pub mod controller;
+pub mod event_handler;
+pub(crate) mod health;
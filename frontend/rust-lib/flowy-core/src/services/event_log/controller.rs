@@ -0,0 +1,45 @@
+use crate::{
+    entities::event_log::{EventLogEntry, EventLogPage, ReadEventLogParams},
+    errors::FlowyResult,
+    module::WorkspaceDatabase,
+    services::event_log::sql::EventLogTableSql,
+};
+use std::sync::Arc;
+
+// The older entries are dropped when the user opens the workspace, the
+// consumers notice the gap through the `first_seq` of the page.
+const MAX_EVENT_LOG_COUNT: i64 = 10_000;
+
+pub struct EventLogController {
+    database: Arc<dyn WorkspaceDatabase>,
+}
+
+impl EventLogController {
+    pub(crate) fn new(database: Arc<dyn WorkspaceDatabase>) -> Self { Self { database } }
+
+    pub(crate) fn prune(&self) -> FlowyResult<()> {
+        let conn = self.database.db_connection()?;
+        EventLogTableSql::prune(MAX_EVENT_LOG_COUNT, &*conn)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn read_event_log(&self, params: ReadEventLogParams) -> FlowyResult<EventLogPage> {
+        let conn = self.database.db_connection()?;
+        let mut tables = EventLogTableSql::read_after(params.cursor, params.limit + 1, &*conn)?;
+        let has_more = tables.len() as i64 > params.limit;
+        tables.truncate(params.limit as usize);
+
+        let next_cursor = tables.last().map(|table| table.seq).unwrap_or(params.cursor);
+        let first_seq = EventLogTableSql::first_seq(&*conn)?;
+        let items = tables
+            .into_iter()
+            .map(|table| table.into())
+            .collect::<Vec<EventLogEntry>>();
+        Ok(EventLogPage {
+            items,
+            next_cursor,
+            first_seq,
+            has_more,
+        })
+    }
+}
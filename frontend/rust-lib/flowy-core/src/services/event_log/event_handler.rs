@@ -0,0 +1,17 @@
+use crate::{
+    entities::event_log::{EventLogPage, ReadEventLogParams, ReadEventLogRequest},
+    errors::FlowyError,
+    services::EventLogController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_event_log_handler(
+    data: Data<ReadEventLogRequest>,
+    controller: Unit<Arc<EventLogController>>,
+) -> DataResult<EventLogPage, FlowyError> {
+    let params: ReadEventLogParams = data.into_inner().try_into()?;
+    let page = controller.read_event_log(params)?;
+    data_result(page)
+}
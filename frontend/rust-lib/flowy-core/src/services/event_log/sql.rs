@@ -0,0 +1,149 @@
+use crate::{entities::event_log::EventLogEntry, errors::FlowyError};
+use flowy_database::{
+    prelude::*,
+    result::OptionalExtension,
+    schema::{event_log_table, event_log_table::dsl},
+    SqliteConnection,
+};
+use lib_infra::timestamp;
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventLogType {
+    AppCreated,
+    AppUpdated,
+    AppDeleted,
+    ViewCreated,
+    ViewUpdated,
+    ViewTrashed,
+    ViewRestored,
+    ViewDeleted,
+    ViewMoved,
+    DocumentUpdated,
+    TrashEmptied,
+}
+
+impl EventLogType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventLogType::AppCreated => "app.created",
+            EventLogType::AppUpdated => "app.updated",
+            EventLogType::AppDeleted => "app.deleted",
+            EventLogType::ViewCreated => "view.created",
+            EventLogType::ViewUpdated => "view.updated",
+            EventLogType::ViewTrashed => "view.trashed",
+            EventLogType::ViewRestored => "view.restored",
+            EventLogType::ViewDeleted => "view.deleted",
+            EventLogType::ViewMoved => "view.moved",
+            EventLogType::DocumentUpdated => "document.updated",
+            EventLogType::TrashEmptied => "trash.emptied",
+        }
+    }
+}
+
+pub(crate) struct EventLogTableSql {}
+
+impl EventLogTableSql {
+    // The entry is appended with the connection of the change, so it's committed
+    // or rolled back together with it.
+    //
+    // A document receives a delta on nearly every keystroke. The consecutive
+    // updates of the same document are folded into the newest entry, the
+    // consumer that has read the older one just sees it once more.
+    pub(crate) fn append(
+        ty: EventLogType,
+        object_id: &str,
+        payload: Value,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
+        if ty == EventLogType::DocumentUpdated {
+            let last_table = dsl::event_log_table
+                .order(event_log_table::seq.desc())
+                .first::<EventLogTable>(conn)
+                .optional()?;
+            if let Some(last_table) = last_table {
+                if last_table.ty == ty.name() && last_table.object_id == object_id {
+                    let filter = dsl::event_log_table.filter(event_log_table::seq.eq(last_table.seq));
+                    let _ = diesel::delete(filter).execute(conn)?;
+                }
+            }
+        }
+
+        let table = NewEventLogTable {
+            ty: ty.name().to_owned(),
+            object_id: object_id.to_owned(),
+            payload: payload.to_string(),
+            create_time: timestamp(),
+        };
+        let _ = diesel::insert_into(event_log_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_after(
+        cursor: i64,
+        limit: i64,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<EventLogTable>, FlowyError> {
+        let tables = dsl::event_log_table
+            .filter(event_log_table::seq.gt(cursor))
+            .order(event_log_table::seq.asc())
+            .limit(limit)
+            .load::<EventLogTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn first_seq(conn: &SqliteConnection) -> Result<i64, FlowyError> {
+        let seq = dsl::event_log_table
+            .select(event_log_table::seq)
+            .order(event_log_table::seq.asc())
+            .first::<i64>(conn)
+            .optional()?;
+        Ok(seq.unwrap_or(0))
+    }
+
+    // Keeps the newest `count` entries.
+    pub(crate) fn prune(count: i64, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let last_seq = dsl::event_log_table
+            .select(event_log_table::seq)
+            .order(event_log_table::seq.desc())
+            .first::<i64>(conn)
+            .optional()?;
+        if let Some(last_seq) = last_seq {
+            let filter = dsl::event_log_table.filter(event_log_table::seq.le(last_seq - count));
+            let _ = diesel::delete(filter).execute(conn)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable)]
+pub(crate) struct EventLogTable {
+    pub seq: i64,
+    pub ty: String,
+    pub object_id: String,
+    pub payload: String,
+    pub create_time: i64,
+}
+
+#[derive(Insertable)]
+#[table_name = "event_log_table"]
+struct NewEventLogTable {
+    ty: String,
+    object_id: String,
+    payload: String,
+    create_time: i64,
+}
+
+impl std::convert::From<EventLogTable> for EventLogEntry {
+    fn from(table: EventLogTable) -> Self {
+        EventLogEntry {
+            seq: table.seq,
+            ty: table.ty,
+            object_id: table.object_id,
+            payload: table.payload,
+            create_time: table.create_time,
+        }
+    }
+}
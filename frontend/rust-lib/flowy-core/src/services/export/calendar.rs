@@ -0,0 +1,130 @@
+use crate::{
+    entities::{
+        grid::{CalendarEvent, GridId},
+        share::{ExportCalendarParams, ExportCalendarResult},
+    },
+    errors::{internal_error, FlowyResult},
+    services::{export::write_file, GridController},
+};
+use chrono::{NaiveDateTime, Timelike, Utc};
+use std::{io::Write, sync::Arc};
+
+// The lines of an iCalendar file are folded at 75 octets.
+const MAX_LINE_LEN: usize = 75;
+
+// Exports the events of a calendar as an iCalendar file (.ics) that the system
+// calendars can open. The export can also be a subscription: the file is
+// written again after each edit of the calendar, so a calendar app that
+// subscribes to the file follows the calendar.
+pub(crate) struct CalendarExporter {
+    grid_controller: Arc<GridController>,
+}
+
+impl CalendarExporter {
+    pub(crate) fn new(grid_controller: Arc<GridController>) -> Self { Self { grid_controller } }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn export(&self, params: ExportCalendarParams) -> FlowyResult<ExportCalendarResult> {
+        let (name, events) = self.grid_controller.read_all_calendar_events(&params.grid_id).await?;
+        let event_count = events.len() as i64;
+        let path = match params.path {
+            None => {
+                return Ok(ExportCalendarResult {
+                    data: calendar_to_ics(&name, &events).into_bytes(),
+                    path: "".to_owned(),
+                    event_count,
+                })
+            },
+            Some(path) => path,
+        };
+
+        let write_path = path.clone();
+        let _ = tokio::task::spawn_blocking(move || write_calendar_file(&write_path, &name, &events))
+            .await
+            .map_err(internal_error)??;
+        // A new export of the calendar to a file replaces its subscription.
+        match params.subscribe {
+            true => self.grid_controller.subscribe_calendar(&params.grid_id, &path),
+            false => self.grid_controller.unsubscribe_calendar(&params.grid_id),
+        }
+        Ok(ExportCalendarResult {
+            data: vec![],
+            path,
+            event_count,
+        })
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(crate) fn unsubscribe(&self, params: GridId) { self.grid_controller.unsubscribe_calendar(&params.grid_id) }
+}
+
+pub(crate) fn write_calendar_file(path: &str, name: &str, events: &[CalendarEvent]) -> FlowyResult<()> {
+    write_file(path, |writer| {
+        writer.write_all(calendar_to_ics(name, events).as_bytes())?;
+        Ok(())
+    })
+}
+
+fn calendar_to_ics(name: &str, events: &[CalendarEvent]) -> String {
+    let mut ics = String::new();
+    let mut line = |line: String| {
+        ics.push_str(&fold_line(&line));
+        ics.push_str("\r\n");
+    };
+    line("BEGIN:VCALENDAR".to_owned());
+    line("VERSION:2.0".to_owned());
+    line("PRODID:-//AppFlowy//Calendar//EN".to_owned());
+    line("CALSCALE:GREGORIAN".to_owned());
+    line(format!("X-WR-CALNAME:{}", escape_text(name)));
+
+    let stamp = Utc::now().naive_utc().format("%Y%m%dT%H%M%SZ").to_string();
+    for event in events {
+        let date_time = match NaiveDateTime::from_timestamp_opt(event.timestamp, 0) {
+            None => continue,
+            Some(date_time) => date_time,
+        };
+        line("BEGIN:VEVENT".to_owned());
+        // The uid stays the same across the exports, so the calendar apps
+        // update the event instead of adding it again.
+        line(format!("UID:{}@appflowy", event.row_id));
+        line(format!("DTSTAMP:{}", stamp));
+        // The dates without a time are all-day events, like the CSV export
+        // writes them without a time.
+        match date_time.time().num_seconds_from_midnight() {
+            0 => line(format!("DTSTART;VALUE=DATE:{}", date_time.format("%Y%m%d"))),
+            _ => line(format!("DTSTART:{}", date_time.format("%Y%m%dT%H%M%SZ"))),
+        }
+        let title = match event.title.trim().is_empty() {
+            true => "Untitled",
+            false => event.title.as_str(),
+        };
+        line(format!("SUMMARY:{}", escape_text(title)));
+        line("END:VEVENT".to_owned());
+    }
+    line("END:VCALENDAR".to_owned());
+    ics
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+// The continuation lines start with a space, the line is never split inside a
+// character.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > MAX_LINE_LEN {
+            folded.push_str("\r\n ");
+            len = 1;
+        }
+        folded.push(c);
+        len += c.len_utf8();
+    }
+    folded
+}
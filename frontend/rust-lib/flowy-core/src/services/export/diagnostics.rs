@@ -0,0 +1,249 @@
+use crate::{
+    entities::diagnostics::{ExportDiagnosticsParams, ExportDiagnosticsResult, HealthReport, HealthStatus},
+    errors::{FlowyError, FlowyResult},
+    module::{WorkspaceDatabase, WorkspaceUser},
+    services::{
+        export::user_data::{read_folder, ArchiveWriter, FolderData, ViewData},
+        ViewController,
+    },
+};
+use flowy_collaboration::entities::revision::RevisionState;
+use lib_infra::{metrics::MetricsSnapshot, timestamp};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{Seek, Write},
+    path::Path,
+    sync::Arc,
+};
+
+const DIAGNOSTICS_VERSION: i64 = 1;
+
+// Packages what is needed to investigate a bug report into a zip archive:
+//  manifest.json       the export time and what was included
+//  health.json         the health check report
+//  metrics.json        the metrics snapshot
+//  folder_stats.json   the number of workspaces, apps, views and revisions
+//  logs.jsonl          the recent log lines kept by the log ring buffer
+//
+// Nothing that the user typed is included: the folder is reduced to counts,
+// without the ids and the names, the documents are never read, and the log
+// lines that may carry a delta are dropped.
+pub(crate) struct DiagnosticsExporter {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    view_controller: Arc<ViewController>,
+}
+
+impl DiagnosticsExporter {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        view_controller: Arc<ViewController>,
+    ) -> Self {
+        Self {
+            user,
+            database,
+            view_controller,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, health, metrics), err)]
+    pub(crate) fn export(
+        &self,
+        params: ExportDiagnosticsParams,
+        health: HealthReport,
+        metrics: MetricsSnapshot,
+    ) -> FlowyResult<ExportDiagnosticsResult> {
+        let dir = Path::new(&params.dir);
+        if !dir.is_dir() {
+            return Err(FlowyError::export_path());
+        }
+
+        let export_time = timestamp();
+        let path = dir.join(format!("appflowy_diagnostics_{}.zip", export_time));
+        let tmp_path = path.with_extension("zip.tmp");
+        let result = File::create(&tmp_path)
+            .map_err(FlowyError::from)
+            .and_then(|file| self.write_archive(file, export_time, health, metrics));
+        match result {
+            Ok(_) => {
+                let _ = std::fs::rename(&tmp_path, &path)?;
+                Ok(ExportDiagnosticsResult {
+                    path: path.to_string_lossy().to_string(),
+                })
+            },
+            Err(e) => {
+                let _ = std::fs::remove_file(&tmp_path);
+                Err(e)
+            },
+        }
+    }
+
+    fn write_archive<W: Write + Seek>(
+        &self,
+        writer: W,
+        export_time: i64,
+        health: HealthReport,
+        metrics: MetricsSnapshot,
+    ) -> FlowyResult<()> {
+        let mut archive = ArchiveWriter::new(writer);
+        archive.add_json("health.json", &HealthData::from(health))?;
+        archive.add_json("metrics.json", &metrics)?;
+
+        // The folder can't be read before the user signs in, the rest of the
+        // bundle is still useful then.
+        let folder_stats = match self.read_folder_stats() {
+            Ok(stats) => Some(stats),
+            Err(e) => {
+                tracing::warn!("Read the folder stats failed: {:?}", e);
+                None
+            },
+        };
+        if let Some(stats) = &folder_stats {
+            archive.add_json("folder_stats.json", stats)?;
+        }
+
+        let logs = lib_log::export_ring_buffer().map(|logs| strip_document_content(&logs));
+        if let Some(logs) = &logs {
+            archive.add("logs.jsonl", logs.as_bytes())?;
+        }
+
+        let manifest = ManifestData {
+            version: DIAGNOSTICS_VERSION,
+            export_time,
+            folder_stats_included: folder_stats.is_some(),
+            logs_included: logs.is_some(),
+        };
+        archive.add_json("manifest.json", &manifest)?;
+        archive.finish()
+    }
+
+    fn read_folder_stats(&self) -> FlowyResult<FolderStats> {
+        let user_id = self.user.user_id()?;
+        let folder = {
+            let conn = &*self.database.db_connection()?;
+            read_folder(&user_id, conn)?
+        };
+
+        let mut stats = FolderStats::new(&folder);
+        for view_id in folder.view_ids() {
+            let records = self.view_controller.read_revision_records(&view_id)?;
+            if records.is_empty() {
+                continue;
+            }
+
+            let unsynced = records
+                .iter()
+                .filter(|record| record.state != RevisionState::Ack)
+                .count();
+            let size: usize = records.iter().map(|record| record.revision.delta_data.len()).sum();
+            stats.add_document(records.len(), unsynced, size);
+        }
+        Ok(stats)
+    }
+}
+
+// The deltas are logged as json at the debug and trace levels. Only the
+// `insert` and the attributes of the `retain` operations carry what the user
+// typed, so the lines with either key are dropped, as well as the revisions.
+fn strip_document_content(logs: &str) -> String {
+    logs.lines()
+        .filter(|line| !line.contains("insert") && !line.contains("retain") && !line.contains("delta_data"))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+#[derive(Serialize)]
+struct ManifestData {
+    version: i64,
+    export_time: i64,
+    folder_stats_included: bool,
+    logs_included: bool,
+}
+
+#[derive(Serialize)]
+struct HealthData {
+    status: String,
+    pending_revisions: i64,
+    create_time: i64,
+    checks: Vec<HealthCheckData>,
+}
+
+#[derive(Serialize)]
+struct HealthCheckData {
+    name: String,
+    status: String,
+    detail: String,
+}
+
+impl std::convert::From<HealthReport> for HealthData {
+    fn from(report: HealthReport) -> Self {
+        Self {
+            status: health_status_name(&report.status),
+            pending_revisions: report.pending_revisions,
+            create_time: report.create_time,
+            checks: report
+                .checks
+                .into_iter()
+                .map(|check| HealthCheckData {
+                    status: health_status_name(&check.status),
+                    name: check.name,
+                    detail: check.detail,
+                })
+                .collect(),
+        }
+    }
+}
+
+fn health_status_name(status: &HealthStatus) -> String { format!("{:?}", status) }
+
+#[derive(Serialize, Default)]
+struct FolderStats {
+    workspace_count: usize,
+    app_count: usize,
+    view_count: usize,
+    view_count_by_type: BTreeMap<i32, usize>,
+    max_view_depth: usize,
+    trash_count: usize,
+    document_count: usize,
+    revision_count: usize,
+    unsynced_revision_count: usize,
+    revision_bytes: usize,
+    max_document_revision_count: usize,
+}
+
+impl FolderStats {
+    fn new(folder: &FolderData) -> Self {
+        let mut stats = FolderStats {
+            workspace_count: folder.workspaces.len(),
+            trash_count: folder.trash.len(),
+            ..Default::default()
+        };
+        for workspace in &folder.workspaces {
+            stats.app_count += workspace.apps.len();
+            for app in &workspace.apps {
+                stats.add_views(&app.views, 1);
+            }
+        }
+        stats
+    }
+
+    fn add_views(&mut self, views: &[ViewData], depth: usize) {
+        for view in views {
+            self.view_count += 1;
+            *self.view_count_by_type.entry(view.view_type).or_insert(0) += 1;
+            self.max_view_depth = self.max_view_depth.max(depth);
+            self.add_views(&view.belongings, depth + 1);
+        }
+    }
+
+    fn add_document(&mut self, revision_count: usize, unsynced_revision_count: usize, revision_bytes: usize) {
+        self.document_count += 1;
+        self.revision_count += revision_count;
+        self.unsynced_revision_count += unsynced_revision_count;
+        self.revision_bytes += revision_bytes;
+        self.max_document_revision_count = self.max_document_revision_count.max(revision_count);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    entities::{
+        grid::{Field, FieldType, GridId, Row},
+        share::{ExportGridParams, ExportGridResult},
+    },
+    errors::{internal_error, FlowyError, FlowyResult},
+    services::{export::write_file, GridController},
+};
+use chrono::{NaiveDateTime, Timelike};
+use std::{io::Write, sync::Arc};
+
+// Exports the rows of a grid as CSV, or TSV if the delimiter is a tab. The
+// rows are the ones the grid shows: its filter and its sorts are applied. The
+// cells are written the way the CSV import reads them back, e.g. the dates
+// are written as 2021-12-24 instead of timestamps.
+pub(crate) struct GridExporter {
+    grid_controller: Arc<GridController>,
+}
+
+impl GridExporter {
+    pub(crate) fn new(grid_controller: Arc<GridController>) -> Self { Self { grid_controller } }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn export(&self, params: ExportGridParams) -> FlowyResult<ExportGridResult> {
+        let grid_id = GridId {
+            grid_id: params.grid_id.clone(),
+        };
+        let grid = self.grid_controller.read_grid(grid_id.clone()).await?;
+        let rows = self.grid_controller.read_visible_rows(grid_id).await?.items;
+        let fields = match params.field_ids.is_empty() {
+            true => grid.fields,
+            false => params
+                .field_ids
+                .iter()
+                .map(|field_id| {
+                    grid.fields
+                        .iter()
+                        .find(|field| &field.id == field_id)
+                        .cloned()
+                        .ok_or_else(|| FlowyError::grid_record_not_found().context("The field doesn't exist"))
+                })
+                .collect::<FlowyResult<Vec<Field>>>()?,
+        };
+
+        let row_count = rows.len() as i64;
+        match params.path {
+            None => {
+                let mut data = vec![];
+                let _ = write_csv(&mut data, &fields, &rows, params.delimiter)?;
+                Ok(ExportGridResult {
+                    data,
+                    path: "".to_owned(),
+                    row_count,
+                })
+            },
+            Some(path) => {
+                let delimiter = params.delimiter;
+                let write_path = path.clone();
+                let _ = tokio::task::spawn_blocking(move || {
+                    write_file(&write_path, |writer| write_csv(writer, &fields, &rows, delimiter))
+                })
+                .await
+                .map_err(internal_error)??;
+                Ok(ExportGridResult {
+                    data: vec![],
+                    path,
+                    row_count,
+                })
+            },
+        }
+    }
+}
+
+fn write_csv<W: Write>(writer: &mut W, fields: &[Field], rows: &[Row], delimiter: char) -> FlowyResult<()> {
+    let names = fields.iter().map(|field| field.name.clone()).collect::<Vec<String>>();
+    let _ = write_record(writer, &names, delimiter)?;
+    for row in rows {
+        let record = fields
+            .iter()
+            .map(|field| {
+                row.cell(&field.id)
+                    .map(|cell| cell_text(field, &cell.data))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<String>>();
+        let _ = write_record(writer, &record, delimiter)?;
+    }
+    Ok(())
+}
+
+fn write_record<W: Write>(writer: &mut W, record: &[String], delimiter: char) -> FlowyResult<()> {
+    let line = record
+        .iter()
+        .map(|field| quote(field, delimiter))
+        .collect::<Vec<String>>()
+        .join(&delimiter.to_string());
+    writer.write_all(line.as_bytes())?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+// The fields that contain the delimiter, a quote or a line break are quoted,
+// and their quotes are doubled.
+fn quote(field: &str, delimiter: char) -> String {
+    if field.contains(|c| c == delimiter || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+pub(super) fn cell_text(field: &Field, data: &str) -> String {
+    match field.field_type {
+        FieldType::DateTime => data
+            .parse::<i64>()
+            .ok()
+            .and_then(|timestamp| NaiveDateTime::from_timestamp_opt(timestamp, 0))
+            .map(|date_time| match date_time.time().num_seconds_from_midnight() {
+                0 => date_time.format("%Y-%m-%d").to_string(),
+                _ => date_time.format("%Y-%m-%d %H:%M:%S").to_string(),
+            })
+            .unwrap_or_else(|| data.to_owned()),
+        FieldType::SingleSelect => field
+            .select_options
+            .iter()
+            .find(|option| option.id == data)
+            .map(|option| option.name.clone())
+            .unwrap_or_default(),
+        _ => data.to_owned(),
+    }
+}
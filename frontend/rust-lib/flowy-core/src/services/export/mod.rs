@@ -0,0 +1,51 @@
+mod calendar;
+mod diagnostics;
+mod grid;
+mod opml;
+mod site;
+mod user_data;
+
+pub(crate) use calendar::*;
+pub(crate) use diagnostics::*;
+pub(crate) use grid::*;
+pub(crate) use opml::*;
+pub(crate) use site::*;
+pub(crate) use user_data::*;
+
+use crate::errors::{FlowyError, FlowyResult};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+// Writes to a temporary file first, so a failed export doesn't leave a
+// truncated file behind or overwrite the file that was there.
+fn write_file<F>(path: &str, write: F) -> FlowyResult<()>
+where
+    F: FnOnce(&mut BufWriter<File>) -> FlowyResult<()>,
+{
+    let path = Path::new(path);
+    match path.parent() {
+        Some(dir) if dir.as_os_str().is_empty() || dir.is_dir() => {},
+        _ => return Err(FlowyError::export_path()),
+    }
+
+    let tmp_path = path.with_extension("tmp");
+    let result = File::create(&tmp_path).map_err(FlowyError::from).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        let _ = write(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    });
+    match result {
+        Ok(_) => {
+            let _ = std::fs::rename(&tmp_path, path)?;
+            Ok(())
+        },
+        Err(e) => {
+            let _ = std::fs::remove_file(&tmp_path);
+            Err(e)
+        },
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    entities::{
+        share::{ExportOpmlParams, ExportOpmlResult},
+        view::ViewType,
+    },
+    errors::{internal_error, FlowyError, FlowyResult},
+    module::{WorkspaceDatabase, WorkspaceUser},
+    services::export::{
+        user_data::{read_folder, ViewData},
+        write_file,
+    },
+};
+use std::{io::Write, sync::Arc};
+
+// Exports the folder tree of a workspace as an OPML outline: each app is a
+// top-level outline and the views are nested in it. The description of an app
+// or a view is its _note, and the type of a view is kept in _viewType so that
+// the OPML import creates the same kind of view. The apps and the views in the
+// trash are left out.
+pub(crate) struct OpmlExporter {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+}
+
+impl OpmlExporter {
+    pub(crate) fn new(user: Arc<dyn WorkspaceUser>, database: Arc<dyn WorkspaceDatabase>) -> Self {
+        Self { user, database }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn export(&self, params: ExportOpmlParams) -> FlowyResult<ExportOpmlResult> {
+        let folder = {
+            let conn = &*self.database.db_connection()?;
+            read_folder(&self.user.user_id()?, conn)?
+        };
+        let workspace = folder
+            .workspaces
+            .iter()
+            .find(|workspace| workspace.id == params.workspace_id)
+            .ok_or_else(|| FlowyError::record_not_found().context("The workspace doesn't exist"))?;
+
+        let mut opml = OpmlWriter::default();
+        opml.line(0, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        opml.line(0, r#"<opml version="2.0">"#);
+        opml.line(1, "<head>");
+        opml.line(2, &format!("<title>{}</title>", escape(&workspace.name)));
+        opml.line(1, "</head>");
+        opml.line(1, "<body>");
+        for app in workspace.apps.iter().filter(|app| !app.in_trash) {
+            let attributes = outline_attributes(&app.name, &app.desc, None);
+            let views = app.views.iter().filter(|view| !view.in_trash).collect::<Vec<_>>();
+            match views.is_empty() {
+                true => opml.line(2, &format!("<outline {}/>", attributes)),
+                false => {
+                    opml.line(2, &format!("<outline {}>", attributes));
+                    write_views(&mut opml, 3, &views);
+                    opml.line(2, "</outline>");
+                },
+            }
+        }
+        opml.line(1, "</body>");
+        opml.line(0, "</opml>");
+
+        let outline_count = opml.outline_count;
+        match params.path {
+            None => Ok(ExportOpmlResult {
+                data: opml.output.into_bytes(),
+                path: "".to_owned(),
+                outline_count,
+            }),
+            Some(path) => {
+                let write_path = path.clone();
+                let _ = tokio::task::spawn_blocking(move || {
+                    write_file(&write_path, |writer| {
+                        writer.write_all(opml.output.as_bytes())?;
+                        Ok(())
+                    })
+                })
+                .await
+                .map_err(internal_error)??;
+                Ok(ExportOpmlResult {
+                    data: vec![],
+                    path,
+                    outline_count,
+                })
+            },
+        }
+    }
+}
+
+fn write_views(opml: &mut OpmlWriter, depth: usize, views: &[&ViewData]) {
+    for view in views {
+        let view_type = view_type_name(ViewType::from(view.view_type));
+        let attributes = outline_attributes(&view.name, &view.desc, Some(view_type));
+        let belongings = view.belongings.iter().filter(|view| !view.in_trash).collect::<Vec<_>>();
+        if belongings.is_empty() {
+            opml.line(depth, &format!("<outline {}/>", attributes));
+        } else {
+            opml.line(depth, &format!("<outline {}>", attributes));
+            write_views(opml, depth + 1, &belongings);
+            opml.line(depth, "</outline>");
+        }
+    }
+}
+
+// The names of the view types in the _viewType attribute.
+fn view_type_name(view_type: ViewType) -> &'static str {
+    match view_type {
+        ViewType::Blank | ViewType::Doc => "doc",
+        ViewType::Grid => "grid",
+        ViewType::Board => "board",
+        ViewType::Calendar => "calendar",
+        ViewType::Checklist => "checklist",
+    }
+}
+
+fn outline_attributes(name: &str, desc: &str, view_type: Option<&str>) -> String {
+    let mut attributes = format!("text=\"{}\"", escape(name));
+    if !desc.is_empty() {
+        attributes.push_str(&format!(" _note=\"{}\"", escape(desc)));
+    }
+    if let Some(view_type) = view_type {
+        attributes.push_str(&format!(" _viewType=\"{}\"", view_type));
+    }
+    attributes
+}
+
+// Escapes the text of an element or of an attribute. The line breaks are
+// escaped too, they would be read back as spaces otherwise.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\n', "&#10;")
+}
+
+#[derive(Default)]
+struct OpmlWriter {
+    output: String,
+    outline_count: i64,
+}
+
+impl OpmlWriter {
+    fn line(&mut self, depth: usize, line: &str) {
+        if line.starts_with("<outline") {
+            self.outline_count += 1;
+        }
+        self.output.push_str(&"  ".repeat(depth));
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+}
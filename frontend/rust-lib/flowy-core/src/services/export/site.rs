@@ -0,0 +1,349 @@
+use crate::{
+    entities::{
+        app::AppId,
+        checklist::ChecklistId,
+        grid::GridId,
+        share::{ExportSiteParams, ExportSiteResult},
+        view::{AttachmentId, View, ViewType},
+    },
+    errors::{internal_error, FlowyError, FlowyResult},
+    services::{
+        export::grid::cell_text,
+        import::{ATTACHMENT_LINK_PREFIX, VIEW_LINK_PREFIX},
+        AppController,
+        ChecklistController,
+        GridController,
+        ViewController,
+    },
+};
+use flowy_collaboration::document::{delta_to_html, escape_html};
+use lib_ot::{core::Operation, rich_text::RichTextAttributeKey};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+const STYLE: &str = r#"body { margin: 0; font-family: sans-serif; color: #333; line-height: 1.6; }
+nav { padding: 12px 24px; border-bottom: 1px solid #eee; }
+main { max-width: 800px; margin: 0 auto; padding: 24px; }
+a { color: #00bcf0; }
+.description { color: #888; }
+blockquote { margin: 0; padding-left: 16px; border-left: 4px solid #eee; }
+pre { padding: 12px; background: #f7f8fc; overflow-x: auto; }
+table { border-collapse: collapse; }
+th, td { padding: 4px 12px; border: 1px solid #eee; text-align: left; }
+li.checked, li.done { color: #888; text-decoration: line-through; }
+"#;
+
+// Exports the views of an app as a static site that can be published as it
+// is, e.g. as a knowledge base:
+//  index.html                          the tree of the views
+//  <view_id>.html                      a page for each view
+//  attachments/<attachment_id>/<name>  the files that the documents link to
+//  style.css
+//
+// The links between the views of the app lead to their pages, the links to
+// the views outside of the app are dropped. The grids, the boards and the
+// calendars are written as tables of their visible rows.
+pub(crate) struct SiteExporter {
+    app_controller: Arc<AppController>,
+    view_controller: Arc<ViewController>,
+    grid_controller: Arc<GridController>,
+    checklist_controller: Arc<ChecklistController>,
+}
+
+impl SiteExporter {
+    pub(crate) fn new(
+        app_controller: Arc<AppController>,
+        view_controller: Arc<ViewController>,
+        grid_controller: Arc<GridController>,
+        checklist_controller: Arc<ChecklistController>,
+    ) -> Self {
+        Self {
+            app_controller,
+            view_controller,
+            grid_controller,
+            checklist_controller,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn export(&self, params: ExportSiteParams) -> FlowyResult<ExportSiteResult> {
+        if !Path::new(&params.dir).is_dir() {
+            return Err(FlowyError::export_path());
+        }
+        let app = self
+            .app_controller
+            .read_app(AppId {
+                app_id: params.app_id.clone(),
+            })
+            .await?;
+
+        // The views in the order of the tree, with the views nested in each of
+        // them.
+        let mut views = vec![];
+        let mut children: HashMap<String, Vec<View>> = HashMap::new();
+        let mut stack = vec![app.id.clone()];
+        while let Some(belong_to_id) = stack.pop() {
+            let belongings = self.view_controller.read_views_belong_to(&belong_to_id).await?.items;
+            for view in belongings.iter().rev() {
+                stack.push(view.id.clone());
+            }
+            views.extend(belongings.iter().cloned());
+            children.insert(belong_to_id, belongings);
+        }
+        let view_ids = views.iter().map(|view| view.id.clone()).collect::<HashSet<String>>();
+
+        let mut site = Site::default();
+        site.add("style.css", STYLE.as_bytes().to_vec());
+        let index = format!(
+            "<h1>{}</h1>\n{}{}",
+            escape_html(&app.name),
+            description(&app.desc),
+            tree(&app.id, &children)
+        );
+        site.add("index.html", page(&app.name, &app.name, &index).into_bytes());
+        for view in views.iter() {
+            let content = match view.view_type {
+                ViewType::Grid | ViewType::Board | ViewType::Calendar => self.grid_html(&view.id).await?,
+                ViewType::Checklist => self.checklist_html(&view.id).await?,
+                _ => self.document_html(&view.id, &view_ids, &mut site).await?,
+            };
+            let mut body = format!(
+                "<h1>{}</h1>\n{}{}",
+                escape_html(&view.name),
+                description(&view.desc),
+                content
+            );
+            let pages = tree(&view.id, &children);
+            if !pages.is_empty() {
+                body.push_str(&format!("<h2>Pages</h2>\n{}", pages));
+            }
+            site.add(
+                &page_file_name(&view.id),
+                page(&app.name, &view.name, &body).into_bytes(),
+            );
+        }
+
+        let page_count = views.len() as i64;
+        let attachment_count = site.attachment_count;
+        let dir_name = sanitize(&app.name).unwrap_or_else(|| "site".to_owned());
+        let path = Path::new(&params.dir).join(dir_name);
+        let write_path = path.clone();
+        let _ = tokio::task::spawn_blocking(move || site.write(&write_path))
+            .await
+            .map_err(internal_error)??;
+        Ok(ExportSiteResult {
+            path: path.to_string_lossy().to_string(),
+            page_count,
+            attachment_count,
+        })
+    }
+
+    async fn document_html(&self, view_id: &str, view_ids: &HashSet<String>, site: &mut Site) -> FlowyResult<String> {
+        let delta = self.view_controller.read_view_delta(view_id).await?;
+        // The attachments are added to the site first, their links lead to
+        // where they are written.
+        let mut attachment_paths = HashMap::new();
+        for op in delta.ops.iter() {
+            let link = match op {
+                Operation::Insert(insert) => insert.attributes.get(&RichTextAttributeKey::Link),
+                _ => None,
+            };
+            let attachment_id = link
+                .and_then(|link| link.0.as_deref())
+                .and_then(|link| link.strip_prefix(ATTACHMENT_LINK_PREFIX));
+            if let Some(attachment_id) = attachment_id {
+                if attachment_paths.contains_key(attachment_id) {
+                    continue;
+                }
+                let params = AttachmentId {
+                    attachment_id: attachment_id.to_owned(),
+                };
+                // The attachments that were deleted are left out.
+                if let Ok(attachment) = self.view_controller.read_attachment(params) {
+                    let name = sanitize(&attachment.name).unwrap_or_else(|| "file".to_owned());
+                    let path = format!("attachments/{}/{}", attachment.id, name);
+                    site.add(&path, attachment.data);
+                    site.attachment_count += 1;
+                    attachment_paths.insert(attachment.id, path);
+                }
+            }
+        }
+
+        Ok(delta_to_html(&delta, |link| {
+            if let Some(view_id) = link.strip_prefix(VIEW_LINK_PREFIX) {
+                return view_ids.contains(view_id).then(|| page_file_name(view_id));
+            }
+            if let Some(attachment_id) = link.strip_prefix(ATTACHMENT_LINK_PREFIX) {
+                return attachment_paths.get(attachment_id).map(|path| path_href(path));
+            }
+            Some(link.to_owned())
+        }))
+    }
+
+    async fn grid_html(&self, grid_id: &str) -> FlowyResult<String> {
+        let params = GridId {
+            grid_id: grid_id.to_owned(),
+        };
+        let grid = self.grid_controller.read_grid(params.clone()).await?;
+        let rows = self.grid_controller.read_visible_rows(params).await?.items;
+
+        let mut html = "<table>\n<tr>".to_owned();
+        for field in grid.fields.iter() {
+            html.push_str(&format!("<th>{}</th>", escape_html(&field.name)));
+        }
+        html.push_str("</tr>\n");
+        for row in rows.iter() {
+            html.push_str("<tr>");
+            for field in grid.fields.iter() {
+                let data = row.cell(&field.id).map(|cell| cell.data.as_str()).unwrap_or("");
+                html.push_str(&format!("<td>{}</td>", escape_html(&cell_text(field, data))));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</table>\n");
+        Ok(html)
+    }
+
+    async fn checklist_html(&self, checklist_id: &str) -> FlowyResult<String> {
+        let params = ChecklistId {
+            checklist_id: checklist_id.to_owned(),
+        };
+        let checklist = self.checklist_controller.read_checklist(params).await?;
+        let mut html = "<ul>\n".to_owned();
+        for item in checklist.items.iter() {
+            let (class, checked) = match item.done {
+                true => ("done", " checked"),
+                false => ("todo", ""),
+            };
+            html.push_str(&format!(
+                "<li class=\"{}\"><input type=\"checkbox\"{} disabled> {}</li>\n",
+                class,
+                checked,
+                escape_html(&item.title)
+            ));
+        }
+        html.push_str("</ul>\n");
+        Ok(html)
+    }
+}
+
+// The files of the site, they are written together once all the pages are
+// rendered.
+#[derive(Default)]
+struct Site {
+    files: Vec<(String, Vec<u8>)>,
+    attachment_count: i64,
+}
+
+impl Site {
+    fn add(&mut self, path: &str, data: Vec<u8>) { self.files.push((path.to_owned(), data)); }
+
+    // The site is written next to the folder first, the folder of a previous
+    // export is replaced only once all the files are written.
+    fn write(self, path: &Path) -> FlowyResult<()> {
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+        if let Err(e) = self.write_files(&tmp_path) {
+            let _ = std::fs::remove_dir_all(&tmp_path);
+            return Err(e);
+        }
+
+        if path.exists() {
+            let _ = std::fs::remove_dir_all(path)?;
+        }
+        let _ = std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    fn write_files(&self, dir: &Path) -> FlowyResult<()> {
+        if dir.exists() {
+            let _ = std::fs::remove_dir_all(dir)?;
+        }
+        for (path, data) in self.files.iter() {
+            let path = dir.join(path);
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent)?;
+            }
+            let _ = std::fs::write(path, data)?;
+        }
+        Ok(())
+    }
+}
+
+fn page(app_name: &str, title: &str, body: &str) -> String {
+    let title = match title == app_name {
+        true => escape_html(title),
+        false => format!("{} - {}", escape_html(title), escape_html(app_name)),
+    };
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" \
+         content=\"width=device-width, initial-scale=1\">\n<title>{}</title>\n<link rel=\"stylesheet\" \
+         href=\"style.css\">\n</head>\n<body>\n<nav><a href=\"index.html\">{}</a></nav>\n<main>\n{}</main>\n</body>\n</html>\n",
+        title,
+        escape_html(app_name),
+        body
+    )
+}
+
+fn description(desc: &str) -> String {
+    match desc.trim().is_empty() {
+        true => "".to_owned(),
+        false => format!("<p class=\"description\">{}</p>\n", escape_html(desc)),
+    }
+}
+
+// The nested list of the views that belong to `belong_to_id`.
+fn tree(belong_to_id: &str, children: &HashMap<String, Vec<View>>) -> String {
+    let views = match children.get(belong_to_id) {
+        Some(views) if !views.is_empty() => views,
+        _ => return "".to_owned(),
+    };
+    let mut html = "<ul>\n".to_owned();
+    for view in views {
+        html.push_str(&format!(
+            "<li><a href=\"{}\">{}</a>\n{}</li>\n",
+            page_file_name(&view.id),
+            escape_html(&view.name),
+            tree(&view.id, children)
+        ));
+    }
+    html.push_str("</ul>\n");
+    html
+}
+
+fn page_file_name(view_id: &str) -> String { format!("{}.html", view_id) }
+
+// The names of the attachments and of the app may contain characters that
+// the file systems don't allow. None if nothing is left of the name.
+fn sanitize(name: &str) -> Option<String> {
+    let name = name
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect::<String>();
+    match name.trim_matches('.').is_empty() {
+        true => None,
+        false => Some(name),
+    }
+}
+
+// The spaces and the other characters that an href can't contain are
+// percent-encoded.
+fn path_href(path: &str) -> String {
+    let mut href = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => href.push(byte as char),
+            _ => href.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    href
+}
@@ -0,0 +1,524 @@
+use crate::{
+    entities::{
+        app::{App, RepeatedApp},
+        backup::BackupManifest,
+        trash::Trash,
+        view::{RepeatedView, View, ViewType},
+        workspace::{Workspace, WorkspaceRole},
+    },
+    errors::{internal_error, FlowyError, FlowyResult},
+    module::{WorkspaceDatabase, WorkspaceUser},
+    services::{
+        app::sql::AppTableSql,
+        trash::sql::TrashTableSql,
+        view::sql::ViewTableSql,
+        workspace::sql::WorkspaceTableSql,
+        ViewController,
+    },
+};
+use flowy_collaboration::{
+    document::{default::initial_delta, delta_to_markdown},
+    entities::revision::RevisionState,
+    util::make_delta_from_revisions,
+};
+use flowy_core_data_model::entities::share::{ExportUserDataParams, ExportUserDataResult};
+use flowy_database::SqliteConnection;
+use lib_infra::{clock::RuntimeEnv, timestamp};
+use lib_ot::rich_text::RichTextDelta;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{Read, Seek, Write},
+    path::Path,
+    sync::Arc,
+};
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+const EXPORT_VERSION: i64 = 1;
+
+// Packages everything that is stored locally for the current user into a zip
+// archive:
+//  manifest.json               the export time and the documents that were
+//                              never synced to this device
+//  folder.json                 the workspaces, apps and views, including the
+//                              ones in the trash
+//  settings.json               the user settings
+//  documents/<view_id>.md      the document in Markdown
+//  documents/<view_id>.json    the document in delta JSON
+//  revisions/<view_id>.json    the metadata of the document revisions
+//
+// Only the local database is read, so the export works offline.
+pub(crate) struct UserDataExporter {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    view_controller: Arc<ViewController>,
+}
+
+impl UserDataExporter {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        view_controller: Arc<ViewController>,
+    ) -> Self {
+        Self {
+            user,
+            database,
+            view_controller,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn export(&self, params: ExportUserDataParams) -> FlowyResult<ExportUserDataResult> {
+        let dir = Path::new(&params.dir);
+        if !dir.is_dir() {
+            return Err(FlowyError::export_path());
+        }
+
+        let user_id = self.user.user_id()?;
+        let export_time = timestamp();
+        let path = dir.join(format!("appflowy_{}_{}.zip", user_id, export_time));
+        let document_count = self.write(&path, &user_id, export_time)?;
+        Ok(ExportUserDataResult {
+            path: path.to_string_lossy().to_string(),
+            document_count,
+        })
+    }
+
+    // Writes to a temporary file first, so a failed export doesn't leave a
+    // truncated archive behind. Returns the number of documents.
+    pub(crate) fn write(&self, path: &Path, user_id: &str, export_time: i64) -> FlowyResult<i64> {
+        let tmp_path = path.with_extension("zip.tmp");
+        let result = File::create(&tmp_path)
+            .map_err(FlowyError::from)
+            .and_then(|file| self.write_archive(file, user_id, export_time));
+        match result {
+            Ok(document_count) => {
+                let _ = std::fs::rename(&tmp_path, path)?;
+                Ok(document_count)
+            },
+            Err(e) => {
+                let _ = std::fs::remove_file(&tmp_path);
+                Err(e)
+            },
+        }
+    }
+
+    fn write_archive<W: Write + Seek>(&self, writer: W, user_id: &str, export_time: i64) -> FlowyResult<i64> {
+        let folder = {
+            let conn = &*self.database.db_connection()?;
+            read_folder(user_id, conn)?
+        };
+
+        let mut archive = ArchiveWriter::new(writer);
+        archive.add_json("folder.json", &folder)?;
+        let settings = self.user.settings_json()?;
+        archive.add("settings.json", settings.as_bytes())?;
+
+        let mut document_count = 0;
+        let mut missing_documents = vec![];
+        for view_id in folder.view_ids() {
+            let records = self.view_controller.read_revision_records(&view_id)?;
+            if records.is_empty() {
+                // The document was never opened on this device, so only the server
+                // has its content.
+                missing_documents.push(view_id);
+                continue;
+            }
+
+            let revisions = records
+                .iter()
+                .map(|record| RevisionData {
+                    base_rev_id: record.revision.base_rev_id,
+                    rev_id: record.revision.rev_id,
+                    md5: record.revision.md5.clone(),
+                    size: record.revision.delta_data.len(),
+                    synced: record.state == RevisionState::Ack,
+                })
+                .collect::<Vec<_>>();
+            let delta = make_delta_from_revisions(records.into_iter().map(|record| record.revision).collect())
+                .map_err(internal_error)?;
+
+            archive.add(
+                &format!("documents/{}.md", view_id),
+                delta_to_markdown(&delta).as_bytes(),
+            )?;
+            archive.add(&format!("documents/{}.json", view_id), delta.to_json().as_bytes())?;
+            archive.add_json(&format!("revisions/{}.json", view_id), &revisions)?;
+            document_count += 1;
+        }
+
+        let manifest = ManifestData {
+            version: EXPORT_VERSION,
+            user_id: user_id.to_owned(),
+            export_time,
+            document_count,
+            missing_documents,
+        };
+        archive.add_json("manifest.json", &manifest)?;
+        archive.finish()?;
+        Ok(document_count)
+    }
+}
+
+pub(super) fn read_folder(user_id: &str, conn: &SqliteConnection) -> FlowyResult<FolderData> {
+    let trash = TrashTableSql::read_all(conn)?.into_inner();
+    let trash_ids = trash.iter().map(|trash| trash.id.clone()).collect::<Vec<_>>();
+
+    let mut workspaces = vec![];
+    for table in WorkspaceTableSql::read_workspaces(None, user_id, conn)? {
+        let workspace: Workspace = table.into();
+        let mut app_tables = AppTableSql::read_workspace_apps(&workspace.id, false, conn)?;
+        app_tables.extend(AppTableSql::read_workspace_apps(&workspace.id, true, conn)?);
+
+        let mut apps = vec![];
+        for app_table in app_tables {
+            let app: App = app_table.into();
+            let views = read_views(&app.id, &trash_ids, conn)?;
+            apps.push(AppData::new(app, &trash_ids, views));
+        }
+        workspaces.push(WorkspaceData::new(workspace, apps));
+    }
+
+    Ok(FolderData {
+        workspaces,
+        trash: trash.into_iter().map(TrashData::from).collect(),
+    })
+}
+
+fn read_views(belong_to_id: &str, trash_ids: &[String], conn: &SqliteConnection) -> FlowyResult<Vec<ViewData>> {
+    let mut views = vec![];
+    for table in ViewTableSql::read_views(belong_to_id, conn)? {
+        let view: View = table.into();
+        let belongings = read_views(&view.id, trash_ids, conn)?;
+        views.push(ViewData::new(view, trash_ids, belongings));
+    }
+    Ok(views)
+}
+
+pub(super) struct ArchiveWriter<W: Write + Seek> {
+    zip: ZipWriter<W>,
+    options: FileOptions,
+}
+
+impl<W: Write + Seek> ArchiveWriter<W> {
+    pub(super) fn new(writer: W) -> Self {
+        Self {
+            zip: ZipWriter::new(writer),
+            options: FileOptions::default().compression_method(CompressionMethod::Deflated),
+        }
+    }
+
+    pub(super) fn add(&mut self, name: &str, data: &[u8]) -> FlowyResult<()> {
+        let _ = self.zip.start_file(name, self.options).map_err(internal_error)?;
+        let _ = self.zip.write_all(data)?;
+        Ok(())
+    }
+
+    pub(super) fn add_json<T: Serialize>(&mut self, name: &str, value: &T) -> FlowyResult<()> {
+        let json = serde_json::to_string_pretty(value).map_err(internal_error)?;
+        self.add(name, json.as_bytes())
+    }
+
+    pub(super) fn finish(mut self) -> FlowyResult<()> {
+        let _ = self.zip.finish().map_err(internal_error)?;
+        Ok(())
+    }
+}
+
+// Reads the archive back the way a restore would: the manifest must list as
+// many documents as the archive has, and the folder must parse.
+pub(crate) fn read_manifest(path: &Path) -> FlowyResult<BackupManifest> {
+    let mut archive = open_archive(path)?;
+    let manifest = read_checked_manifest(&mut archive)?;
+    let _: FolderData = read_json(&mut archive, "folder.json")?;
+    Ok(BackupManifest {
+        path: path.to_string_lossy().to_string(),
+        user_id: manifest.user_id,
+        export_time: manifest.export_time,
+        document_count: manifest.document_count,
+    })
+}
+
+// Checks that the archive can be restored and is of this user. Returns the
+// number of documents.
+pub(crate) fn verify_archive(path: &Path, user_id: &str) -> FlowyResult<i64> {
+    let manifest = read_manifest(path)?;
+    if manifest.user_id != user_id {
+        return Err(FlowyError::backup_corrupted().context("The manifest is of another user"));
+    }
+    Ok(manifest.document_count)
+}
+
+// The folder of an archive with new ids, so the backup of another account
+// can be restored next to what the server already has. The apps and the
+// views in the trash are left out.
+pub(crate) struct RestoredFolder {
+    pub(crate) workspaces: Vec<Workspace>,
+    // The delta JSON of each view, keyed by the new view id.
+    pub(crate) documents: HashMap<String, String>,
+}
+
+pub(crate) fn restore_folder(path: &Path, env: &RuntimeEnv) -> FlowyResult<RestoredFolder> {
+    let mut archive = open_archive(path)?;
+    let _ = read_checked_manifest(&mut archive)?;
+    let folder: FolderData = read_json(&mut archive, "folder.json")?;
+
+    let mut documents = HashMap::new();
+    let mut workspaces = vec![];
+    for workspace_data in folder.workspaces {
+        let workspace_id = env.next_id();
+        let mut apps = vec![];
+        for app_data in workspace_data.apps.into_iter().filter(|app| !app.in_trash) {
+            let app_id = env.next_id();
+            let mut views = vec![];
+            let _ = restore_views(app_data.views, &app_id, &mut archive, env, &mut views, &mut documents)?;
+            apps.push(App {
+                id: app_id,
+                workspace_id: workspace_id.clone(),
+                name: app_data.name,
+                desc: app_data.desc,
+                belongings: RepeatedView { items: views },
+                version: 0,
+                modified_time: app_data.modified_time,
+                create_time: app_data.create_time,
+            });
+        }
+        workspaces.push(Workspace {
+            id: workspace_id,
+            name: workspace_data.name,
+            desc: workspace_data.desc,
+            apps: RepeatedApp { items: apps },
+            modified_time: workspace_data.modified_time,
+            create_time: workspace_data.create_time,
+            role: WorkspaceRole::Owner,
+            settings: None,
+        });
+    }
+
+    if workspaces.is_empty() {
+        return Err(FlowyError::backup_corrupted().context("The backup has no workspace"));
+    }
+    Ok(RestoredFolder { workspaces, documents })
+}
+
+// Flattens the views of an app, each view comes before its belongings, so
+// it's created first. Only the documents are in the archive, e.g. the rows of
+// a grid are not, and the documents that were never opened on the device that
+// was backed up start empty.
+fn restore_views<R: Read + Seek>(
+    views: Vec<ViewData>,
+    belong_to_id: &str,
+    archive: &mut ZipArchive<R>,
+    env: &RuntimeEnv,
+    restored: &mut Vec<View>,
+    documents: &mut HashMap<String, String>,
+) -> FlowyResult<()> {
+    for view_data in views.into_iter().filter(|view| !view.in_trash) {
+        let view_id = env.next_id();
+        let name = format!("documents/{}.json", view_data.id);
+        let is_in_archive = archive.by_name(&name).is_ok();
+        let document = if is_in_archive {
+            let json = read_text(archive, &name)?;
+            let _ = RichTextDelta::from_json(&json)
+                .map_err(|e| FlowyError::backup_corrupted().context(format!("{}: {}", name, e)))?;
+            json
+        } else {
+            initial_delta().to_json()
+        };
+        documents.insert(view_id.clone(), document);
+        restored.push(View {
+            id: view_id.clone(),
+            belong_to_id: belong_to_id.to_owned(),
+            name: view_data.name,
+            desc: view_data.desc,
+            view_type: ViewType::from(view_data.view_type),
+            version: 0,
+            belongings: RepeatedView::default(),
+            modified_time: view_data.modified_time,
+            create_time: view_data.create_time,
+        });
+        let _ = restore_views(view_data.belongings, &view_id, archive, env, restored, documents)?;
+    }
+    Ok(())
+}
+
+fn open_archive(path: &Path) -> FlowyResult<ZipArchive<File>> {
+    ZipArchive::new(File::open(path)?).map_err(|e| FlowyError::backup_corrupted().context(e))
+}
+
+fn read_checked_manifest<R: Read + Seek>(archive: &mut ZipArchive<R>) -> FlowyResult<ManifestData> {
+    let manifest: ManifestData = read_json(archive, "manifest.json")?;
+    if manifest.version != EXPORT_VERSION {
+        return Err(
+            FlowyError::backup_corrupted().context(format!("The version {} is not supported", manifest.version))
+        );
+    }
+
+    let document_count = archive
+        .file_names()
+        .filter(|name| name.starts_with("documents/") && name.ends_with(".json"))
+        .count() as i64;
+    if document_count != manifest.document_count {
+        return Err(FlowyError::backup_corrupted().context(format!(
+            "The manifest lists {} documents, the archive has {}",
+            manifest.document_count, document_count
+        )));
+    }
+    Ok(manifest)
+}
+
+fn read_json<R: Read + Seek, T: DeserializeOwned>(archive: &mut ZipArchive<R>, name: &str) -> FlowyResult<T> {
+    let json = read_text(archive, name)?;
+    serde_json::from_str(&json).map_err(|e| FlowyError::backup_corrupted().context(format!("{}: {}", name, e)))
+}
+
+fn read_text<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> FlowyResult<String> {
+    let corrupted = |e: &dyn std::fmt::Display| FlowyError::backup_corrupted().context(format!("{}: {}", name, e));
+    let mut text = String::new();
+    let _ = archive
+        .by_name(name)
+        .map_err(|e| corrupted(&e))?
+        .read_to_string(&mut text)
+        .map_err(|e| corrupted(&e))?;
+    Ok(text)
+}
+
+#[derive(Serialize, Deserialize)]
+struct ManifestData {
+    version: i64,
+    user_id: String,
+    export_time: i64,
+    document_count: i64,
+    missing_documents: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct FolderData {
+    pub(super) workspaces: Vec<WorkspaceData>,
+    pub(super) trash: Vec<TrashData>,
+}
+
+impl FolderData {
+    pub(super) fn view_ids(&self) -> Vec<String> {
+        fn collect(views: &[ViewData], ids: &mut Vec<String>) {
+            for view in views {
+                ids.push(view.id.clone());
+                collect(&view.belongings, ids);
+            }
+        }
+
+        let mut ids = vec![];
+        for workspace in &self.workspaces {
+            for app in &workspace.apps {
+                collect(&app.views, &mut ids);
+            }
+        }
+        ids
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct WorkspaceData {
+    pub(super) id: String,
+    pub(super) name: String,
+    desc: String,
+    modified_time: i64,
+    create_time: i64,
+    pub(super) apps: Vec<AppData>,
+}
+
+impl WorkspaceData {
+    fn new(workspace: Workspace, apps: Vec<AppData>) -> Self {
+        Self {
+            id: workspace.id,
+            name: workspace.name,
+            desc: workspace.desc,
+            modified_time: workspace.modified_time,
+            create_time: workspace.create_time,
+            apps,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct AppData {
+    id: String,
+    pub(super) name: String,
+    pub(super) desc: String,
+    modified_time: i64,
+    create_time: i64,
+    pub(super) in_trash: bool,
+    pub(super) views: Vec<ViewData>,
+}
+
+impl AppData {
+    fn new(app: App, trash_ids: &[String], views: Vec<ViewData>) -> Self {
+        Self {
+            in_trash: trash_ids.contains(&app.id),
+            id: app.id,
+            name: app.name,
+            desc: app.desc,
+            modified_time: app.modified_time,
+            create_time: app.create_time,
+            views,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct ViewData {
+    id: String,
+    pub(super) name: String,
+    pub(super) desc: String,
+    pub(super) view_type: i32,
+    modified_time: i64,
+    create_time: i64,
+    pub(super) in_trash: bool,
+    pub(super) belongings: Vec<ViewData>,
+}
+
+impl ViewData {
+    fn new(view: View, trash_ids: &[String], belongings: Vec<ViewData>) -> Self {
+        Self {
+            in_trash: trash_ids.contains(&view.id),
+            id: view.id,
+            name: view.name,
+            desc: view.desc,
+            view_type: view.view_type as i32,
+            modified_time: view.modified_time,
+            create_time: view.create_time,
+            belongings,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct TrashData {
+    id: String,
+    name: String,
+    ty: i32,
+    modified_time: i64,
+    create_time: i64,
+}
+
+impl std::convert::From<Trash> for TrashData {
+    fn from(trash: Trash) -> Self {
+        Self {
+            id: trash.id,
+            name: trash.name,
+            ty: trash.ty as i32,
+            modified_time: trash.modified_time,
+            create_time: trash.create_time,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RevisionData {
+    base_rev_id: i64,
+    rev_id: i64,
+    md5: String,
+    size: usize,
+    synced: bool,
+}
@@ -0,0 +1,398 @@
+use crate::{
+    entities::grid::{
+        Board,
+        CalendarChangeset,
+        CalendarEvent,
+        CalendarRange,
+        CardMoved,
+        Cell,
+        CreateFieldParams,
+        CreateRowParams,
+        Field,
+        FieldId,
+        FieldType,
+        Grid,
+        GridChangeset,
+        GridId,
+        IndexedRow,
+        MoveCalendarEventParams,
+        MoveCardParams,
+        RepeatedCalendarEvent,
+        RepeatedRow,
+        Row,
+        RowId,
+        RowVisibilityChangeset,
+        UpdateCellParams,
+        UpdateFieldParams,
+        UpdateGridSettingParams,
+    },
+    errors::{internal_error, FlowyError, FlowyResult},
+    module::WorkspaceDatabase,
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{
+        export::write_calendar_file,
+        grid::pad::GridPad,
+        view::sql::{ViewTableSql, ViewTableType},
+        ViewController,
+    },
+};
+use flowy_database::kv::KV;
+use parking_lot::RwLock;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
+};
+use tokio::sync::Mutex;
+
+pub(crate) struct GridController {
+    database: Arc<dyn WorkspaceDatabase>,
+    view_controller: Arc<ViewController>,
+    // The edits are read from and written back to the document one at a time,
+    // otherwise two edits could be made against the same text.
+    edit_lock: Mutex<()>,
+    // The last range that was read from each calendar, it's the range the
+    // calendar shows.
+    calendar_ranges: RwLock<HashMap<String, CalendarRange>>,
+}
+
+impl GridController {
+    pub(crate) fn new(database: Arc<dyn WorkspaceDatabase>, view_controller: Arc<ViewController>) -> Self {
+        Self {
+            database,
+            view_controller,
+            edit_lock: Mutex::new(()),
+            calendar_ranges: RwLock::new(HashMap::new()),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_grid(&self, params: GridId) -> FlowyResult<Grid> {
+        let pad = self.open_grid(&params.grid_id).await?;
+        Ok(pad.grid().clone())
+    }
+
+    // The rows that the filter of the grid lets through, in the order of its
+    // sorts.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_visible_rows(&self, params: GridId) -> FlowyResult<RepeatedRow> {
+        let pad = self.open_grid(&params.grid_id).await?;
+        Ok(RepeatedRow {
+            items: pad.visible_rows(),
+        })
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_setting(&self, params: UpdateGridSettingParams) -> FlowyResult<()> {
+        self.edit_grid(&params.grid_id, |pad| pad.update_setting(params.setting))
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_field(&self, params: CreateFieldParams) -> FlowyResult<Field> {
+        let grid_id = params.grid_id.clone();
+        if params.field_type == FieldType::Relation {
+            let _ = self.open_grid(&params.related_grid_id).await?;
+        }
+        let field = self.edit_grid(&grid_id, |pad| pad.create_field(params)).await?;
+        let _ = self.link_grid(&grid_id, &field).await?;
+        Ok(field)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_field(&self, params: UpdateFieldParams) -> FlowyResult<Field> {
+        let grid_id = params.grid_id.clone();
+        if let Some(related_grid_id) = &params.related_grid_id {
+            let _ = self.open_grid(related_grid_id).await?;
+        }
+        let field = self.edit_grid(&grid_id, |pad| pad.update_field(params)).await?;
+        let _ = self.link_grid(&grid_id, &field).await?;
+        Ok(field)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn delete_field(&self, params: FieldId) -> FlowyResult<()> {
+        self.edit_grid(&params.grid_id, |pad| {
+            pad.delete_field(&params.field_id).map(|changeset| (changeset, ()))
+        })
+        .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_row(&self, params: CreateRowParams) -> FlowyResult<Row> {
+        let grid_id = params.grid_id.clone();
+        self.edit_grid(&grid_id, |pad| pad.create_row(params)).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, rows), fields(count = rows.len()), err)]
+    pub(crate) async fn append_rows(&self, grid_id: &str, rows: Vec<Row>) -> FlowyResult<()> {
+        self.edit_grid(grid_id, |pad| pad.append_rows(rows)).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn delete_row(&self, params: RowId) -> FlowyResult<()> {
+        self.edit_grid(&params.grid_id, |pad| {
+            pad.delete_row(&params.row_id).map(|changeset| (changeset, ()))
+        })
+        .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn update_cell(&self, params: UpdateCellParams) -> FlowyResult<Cell> {
+        let grid_id = params.grid_id.clone();
+        self.edit_grid(&grid_id, |pad| pad.update_cell(params)).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_board(&self, params: GridId) -> FlowyResult<Board> {
+        let pad = self.open_grid(&params.grid_id).await?;
+        pad.board()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn move_card(&self, params: MoveCardParams) -> FlowyResult<CardMoved> {
+        let grid_id = params.grid_id.clone();
+        let card_moved = self.edit_grid(&grid_id, |pad| pad.move_card(params)).await?;
+        send_dart_notification(&grid_id, WorkspaceNotification::BoardCardMoved)
+            .payload(card_moved.clone())
+            .send();
+        Ok(card_moved)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_calendar_events(&self, range: CalendarRange) -> FlowyResult<RepeatedCalendarEvent> {
+        let pad = self.open_grid(&range.grid_id).await?;
+        let events = pad.calendar_events(&range)?;
+        self.calendar_ranges.write().insert(range.grid_id.clone(), range);
+        Ok(RepeatedCalendarEvent { items: events })
+    }
+
+    // The name of the calendar and all its events, for the exports.
+    pub(crate) async fn read_all_calendar_events(&self, grid_id: &str) -> FlowyResult<(String, Vec<CalendarEvent>)> {
+        let name = ViewTableSql::read_view(grid_id, &*self.database.db_connection()?)?.name;
+        let pad = self.open_grid(grid_id).await?;
+        let events = pad.calendar_events(&all_time(grid_id))?;
+        Ok((name, events))
+    }
+
+    // The calendar is exported to the file again after each of its edits.
+    pub(crate) fn subscribe_calendar(&self, grid_id: &str, path: &str) {
+        KV::set_str(&calendar_subscription_key(grid_id), path.to_owned());
+    }
+
+    pub(crate) fn unsubscribe_calendar(&self, grid_id: &str) {
+        let key = calendar_subscription_key(grid_id);
+        if KV::get_str(&key).is_some() {
+            let _ = KV::remove(&key);
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn move_calendar_event(&self, params: MoveCalendarEventParams) -> FlowyResult<CalendarEvent> {
+        let grid_id = params.grid_id.clone();
+        self.edit_grid(&grid_id, |pad| pad.move_calendar_event(params)).await
+    }
+
+    // The boards and the calendars are grids too, they only start with a
+    // status or a date field.
+    async fn open_grid(&self, grid_id: &str) -> FlowyResult<GridPad> {
+        let view_table = ViewTableSql::read_view(grid_id, &*self.database.db_connection()?)?;
+        match view_table.view_type {
+            ViewTableType::Grid | ViewTableType::Board | ViewTableType::Calendar => {},
+            _ => return Err(FlowyError::view_type().context("The view is not a grid")),
+        }
+        let text = self.view_controller.read_view_text(grid_id).await?;
+        GridPad::from_text(grid_id, text)
+    }
+
+    // The related grid remembers the grids that link to it, so that their
+    // relations can follow the edits of its rows.
+    async fn link_grid(&self, grid_id: &str, field: &Field) -> FlowyResult<()> {
+        if field.field_type != FieldType::Relation {
+            return Ok(());
+        }
+        self.edit_grid(&field.related_grid_id, |pad| pad.link_grid(grid_id))
+            .await
+    }
+
+    async fn edit_grid<F, T>(&self, grid_id: &str, f: F) -> FlowyResult<T>
+    where
+        F: FnOnce(&mut GridPad) -> FlowyResult<(GridChangeset, T)>,
+    {
+        let _guard = self.edit_lock.lock().await;
+        let (value, changeset, grid) = self.apply_edit(grid_id, f).await?;
+        self.update_linked_grids(grid, changeset).await;
+        Ok(value)
+    }
+
+    // The grids that link to the edited grid follow its edit, then the grids
+    // that link to those, and so on. Each grid is updated once at most, so the
+    // rollups that go around in a circle stop.
+    async fn update_linked_grids(&self, grid: Grid, changeset: GridChangeset) {
+        let mut updated_grid_ids = HashSet::new();
+        let mut queue = VecDeque::from(vec![(grid, changeset)]);
+        while let Some((grid, changeset)) = queue.pop_front() {
+            if changeset.is_empty() {
+                continue;
+            }
+            for linked_grid_id in grid.linked_grid_ids.iter() {
+                if !updated_grid_ids.insert(linked_grid_id.clone()) {
+                    continue;
+                }
+                match self
+                    .apply_edit(linked_grid_id, |pad| pad.update_relations(&grid, &changeset))
+                    .await
+                {
+                    Ok((_, linked_changeset, linked_grid)) => queue.push_back((linked_grid, linked_changeset)),
+                    // The linked grid may have been deleted.
+                    Err(e) => log::error!("Update the relations of {} failed: {:?}", linked_grid_id, e),
+                }
+            }
+        }
+    }
+
+    // The edit goes through the document of the grid, so it's saved and synced
+    // like the edits of the other documents.
+    async fn apply_edit<F, T>(&self, grid_id: &str, f: F) -> FlowyResult<(T, GridChangeset, Grid)>
+    where
+        F: FnOnce(&mut GridPad) -> FlowyResult<(GridChangeset, T)>,
+    {
+        let mut pad = self.open_grid(grid_id).await?;
+        for related_grid_id in pad.related_grid_ids() {
+            // The relations to a deleted grid are kept, they link to nothing.
+            if let Ok(related_pad) = self.open_grid(&related_grid_id).await {
+                pad.set_related_grid(related_pad.grid().clone());
+            }
+        }
+        let range = self.calendar_ranges.read().get(grid_id).cloned();
+        let old_events = range
+            .as_ref()
+            .and_then(|range| pad.calendar_events(range).ok())
+            .unwrap_or_default();
+        let old_rows = pad.visible_rows();
+        let (changeset, value) = f(&mut pad)?;
+        let new_text = pad.to_text()?;
+        if new_text != pad.text() {
+            let _ = self
+                .view_controller
+                .write_view_text(grid_id, pad.text(), &new_text)
+                .await?;
+        }
+
+        if !changeset.is_empty() {
+            send_dart_notification(grid_id, WorkspaceNotification::GridUpdated)
+                .payload(changeset.clone())
+                .send();
+        }
+        notify_row_visibility_changed(grid_id, old_rows, pad.visible_rows());
+        if let Some(range) = range {
+            let new_events = pad.calendar_events(&range).unwrap_or_default();
+            notify_calendar_events_changed(&range, old_events, new_events);
+        }
+        self.update_calendar_subscription(grid_id, &pad).await;
+        Ok((value, changeset, pad.grid().clone()))
+    }
+
+    // The edit is saved already, the subscription file just misses it if it
+    // can't be written.
+    async fn update_calendar_subscription(&self, grid_id: &str, pad: &GridPad) {
+        let path = match KV::get_str(&calendar_subscription_key(grid_id)) {
+            None => return,
+            Some(path) => path,
+        };
+        let events = match pad.calendar_events(&all_time(grid_id)) {
+            Ok(events) => events,
+            // The date field was deleted.
+            Err(_) => vec![],
+        };
+        let name = match self
+            .database
+            .db_connection()
+            .and_then(|conn| ViewTableSql::read_view(grid_id, &*conn))
+        {
+            Ok(view_table) => view_table.name,
+            Err(e) => {
+                log::error!("Update the calendar subscription of {} failed: {:?}", grid_id, e);
+                return;
+            },
+        };
+        let result = tokio::task::spawn_blocking(move || write_calendar_file(&path, &name, &events))
+            .await
+            .map_err(internal_error)
+            .and_then(|result| result);
+        if let Err(e) = result {
+            log::error!("Update the calendar subscription of {} failed: {:?}", grid_id, e);
+        }
+    }
+}
+
+fn calendar_subscription_key(grid_id: &str) -> String { format!("calendar_subscription_{}", grid_id) }
+
+fn all_time(grid_id: &str) -> CalendarRange {
+    CalendarRange {
+        grid_id: grid_id.to_owned(),
+        start: i64::MIN,
+        end: i64::MAX,
+    }
+}
+
+// The rows are compared with what was visible before the edit, the rows that
+// were edited but stayed in place are sent with the GridUpdated notification.
+fn notify_row_visibility_changed(grid_id: &str, old_rows: Vec<Row>, new_rows: Vec<Row>) {
+    let old_row_ids = old_rows.iter().map(|row| row.id.as_str()).collect::<Vec<&str>>();
+    let visible_rows = new_rows
+        .iter()
+        .enumerate()
+        .filter(|(index, row)| old_row_ids.get(*index) != Some(&row.id.as_str()))
+        .map(|(index, row)| IndexedRow {
+            row: row.clone(),
+            index: index as i32,
+        })
+        .collect::<Vec<IndexedRow>>();
+    let hidden_row_ids = old_row_ids
+        .iter()
+        .filter(|row_id| !new_rows.iter().any(|row| row.id == **row_id))
+        .map(|row_id| row_id.to_string())
+        .collect::<Vec<String>>();
+    if visible_rows.is_empty() && hidden_row_ids.is_empty() {
+        return;
+    }
+
+    let changeset = RowVisibilityChangeset {
+        grid_id: grid_id.to_owned(),
+        visible_rows,
+        hidden_row_ids,
+    };
+    send_dart_notification(grid_id, WorkspaceNotification::GridRowsVisibilityChanged)
+        .payload(changeset)
+        .send();
+}
+
+// Only the changes of the events in the range that the calendar shows are sent.
+fn notify_calendar_events_changed(
+    range: &CalendarRange,
+    old_events: Vec<CalendarEvent>,
+    new_events: Vec<CalendarEvent>,
+) {
+    let updated_events = new_events
+        .iter()
+        .filter(|event| !old_events.contains(event))
+        .cloned()
+        .collect::<Vec<CalendarEvent>>();
+    let deleted_row_ids = old_events
+        .iter()
+        .filter(|old_event| !new_events.iter().any(|event| event.row_id == old_event.row_id))
+        .map(|old_event| old_event.row_id.clone())
+        .collect::<Vec<String>>();
+    if updated_events.is_empty() && deleted_row_ids.is_empty() {
+        return;
+    }
+
+    let changeset = CalendarChangeset {
+        grid_id: range.grid_id.clone(),
+        updated_events,
+        deleted_row_ids,
+    };
+    send_dart_notification(&range.grid_id, WorkspaceNotification::CalendarEventsChanged)
+        .payload(changeset)
+        .send();
+}
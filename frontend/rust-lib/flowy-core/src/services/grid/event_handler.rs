@@ -0,0 +1,169 @@
+use crate::{
+    entities::grid::{
+        Board,
+        CalendarEvent,
+        CalendarRange,
+        CardMoved,
+        Cell,
+        CreateFieldParams,
+        CreateFieldRequest,
+        CreateRowParams,
+        CreateRowRequest,
+        Field,
+        FieldId,
+        Grid,
+        GridId,
+        MoveCalendarEventParams,
+        MoveCalendarEventRequest,
+        MoveCardParams,
+        MoveCardRequest,
+        QueryCalendarEventsRequest,
+        QueryFieldRequest,
+        QueryGridRequest,
+        QueryRowRequest,
+        RepeatedCalendarEvent,
+        RepeatedRow,
+        Row,
+        RowId,
+        UpdateCellParams,
+        UpdateCellRequest,
+        UpdateFieldParams,
+        UpdateFieldRequest,
+        UpdateGridSettingParams,
+        UpdateGridSettingRequest,
+    },
+    errors::FlowyError,
+    services::GridController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_grid_handler(
+    data: Data<QueryGridRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Grid, FlowyError> {
+    let params: GridId = data.into_inner().try_into()?;
+    let grid = controller.read_grid(params).await?;
+    data_result(grid)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_field_handler(
+    data: Data<CreateFieldRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Field, FlowyError> {
+    let params: CreateFieldParams = data.into_inner().try_into()?;
+    let field = controller.create_field(params).await?;
+    data_result(field)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_field_handler(
+    data: Data<UpdateFieldRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Field, FlowyError> {
+    let params: UpdateFieldParams = data.into_inner().try_into()?;
+    let field = controller.update_field(params).await?;
+    data_result(field)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_field_handler(
+    data: Data<QueryFieldRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> Result<(), FlowyError> {
+    let params: FieldId = data.into_inner().try_into()?;
+    let _ = controller.delete_field(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_row_handler(
+    data: Data<CreateRowRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Row, FlowyError> {
+    let params: CreateRowParams = data.into_inner().try_into()?;
+    let row = controller.create_row(params).await?;
+    data_result(row)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_row_handler(
+    data: Data<QueryRowRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> Result<(), FlowyError> {
+    let params: RowId = data.into_inner().try_into()?;
+    let _ = controller.delete_row(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_cell_handler(
+    data: Data<UpdateCellRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Cell, FlowyError> {
+    let params: UpdateCellParams = data.into_inner().try_into()?;
+    let cell = controller.update_cell(params).await?;
+    data_result(cell)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_board_handler(
+    data: Data<QueryGridRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Board, FlowyError> {
+    let params: GridId = data.into_inner().try_into()?;
+    let board = controller.read_board(params).await?;
+    data_result(board)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_card_handler(
+    data: Data<MoveCardRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<CardMoved, FlowyError> {
+    let params: MoveCardParams = data.into_inner().try_into()?;
+    let card_moved = controller.move_card(params).await?;
+    data_result(card_moved)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_calendar_events_handler(
+    data: Data<QueryCalendarEventsRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<RepeatedCalendarEvent, FlowyError> {
+    let range: CalendarRange = data.into_inner().try_into()?;
+    let events = controller.read_calendar_events(range).await?;
+    data_result(events)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_calendar_event_handler(
+    data: Data<MoveCalendarEventRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<CalendarEvent, FlowyError> {
+    let params: MoveCalendarEventParams = data.into_inner().try_into()?;
+    let event = controller.move_calendar_event(params).await?;
+    data_result(event)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_grid_rows_handler(
+    data: Data<QueryGridRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<RepeatedRow, FlowyError> {
+    let params: GridId = data.into_inner().try_into()?;
+    let rows = controller.read_visible_rows(params).await?;
+    data_result(rows)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_grid_setting_handler(
+    data: Data<UpdateGridSettingRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> Result<(), FlowyError> {
+    let params: UpdateGridSettingParams = data.into_inner().try_into()?;
+    let _ = controller.update_setting(params).await?;
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use crate::entities::grid::{Field, FieldType, Filter, FilterCondition, FilterGroup, FilterOperator, Grid, Row, Sort};
+use std::cmp::Ordering;
+
+// Returns the rows that the filter of the grid lets through, ordered by its
+// sorts. The rows that compare equal keep their order in the grid.
+pub(crate) fn visible_rows(grid: &Grid) -> Vec<Row> {
+    let mut rows = grid
+        .rows
+        .iter()
+        .filter(|row| match_group(grid, row, &grid.setting.filter))
+        .cloned()
+        .collect::<Vec<Row>>();
+
+    let sorts = grid
+        .setting
+        .sorts
+        .iter()
+        .filter_map(|sort| field(grid, &sort.field_id).map(|field| (field, sort)))
+        .collect::<Vec<(&Field, &Sort)>>();
+    if !sorts.is_empty() {
+        rows.sort_by(|a, b| {
+            sorts
+                .iter()
+                .map(|(field, sort)| compare_cells(field, sort, a, b))
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+    rows
+}
+
+fn field<'a>(grid: &'a Grid, field_id: &str) -> Option<&'a Field> {
+    grid.fields.iter().find(|field| field.id == field_id)
+}
+
+fn cell_data<'a>(row: &'a Row, field: &Field) -> &'a str {
+    row.cell(&field.id).map(|cell| cell.data.as_str()).unwrap_or("")
+}
+
+fn match_group(grid: &Grid, row: &Row, group: &FilterGroup) -> bool {
+    let mut results = group
+        .filters
+        .iter()
+        .map(|filter| match_filter(grid, row, filter))
+        .chain(group.groups.iter().map(|group| match_group(grid, row, group)));
+    match group.operator {
+        FilterOperator::And => results.all(|is_match| is_match),
+        // An empty group matches, like it does with And.
+        FilterOperator::Or => (group.filters.is_empty() && group.groups.is_empty()) || results.any(|is_match| is_match),
+    }
+}
+
+fn match_filter(grid: &Grid, row: &Row, filter: &Filter) -> bool {
+    let field = match field(grid, &filter.field_id) {
+        None => return true,
+        Some(field) => field,
+    };
+    let data = cell_data(row, field);
+    let value = filter.value.as_str();
+    match filter.condition {
+        FilterCondition::IsEmpty => data.is_empty(),
+        FilterCondition::IsNotEmpty => !data.is_empty(),
+        FilterCondition::Is => is_equal(field, data, value),
+        FilterCondition::IsNot => !is_equal(field, data, value),
+        FilterCondition::Contains => display_text(field, data).contains(&value.to_lowercase()),
+        FilterCondition::DoesNotContain => !display_text(field, data).contains(&value.to_lowercase()),
+        FilterCondition::GreaterThan => !data.is_empty() && compare_data(field, data, value) == Ordering::Greater,
+        FilterCondition::LessThan => !data.is_empty() && compare_data(field, data, value) == Ordering::Less,
+    }
+}
+
+fn is_equal(field: &Field, data: &str, value: &str) -> bool {
+    match field.field_type {
+        FieldType::Number | FieldType::DateTime | FieldType::Formula | FieldType::Rollup => {
+            compare_data(field, data, value) == Ordering::Equal
+        },
+        FieldType::SingleSelect => data == value,
+        FieldType::RichText | FieldType::Relation => data.to_lowercase() == value.to_lowercase(),
+    }
+}
+
+// The single select cells are matched by the name of their option.
+fn display_text(field: &Field, data: &str) -> String {
+    match field.field_type {
+        FieldType::SingleSelect => field
+            .select_options
+            .iter()
+            .find(|option| option.id == data)
+            .map(|option| option.name.to_lowercase())
+            .unwrap_or_default(),
+        _ => data.to_lowercase(),
+    }
+}
+
+// The numbers, the dates and the formula results that can't be parsed are
+// compared as text.
+fn compare_data(field: &Field, a: &str, b: &str) -> Ordering {
+    match field.field_type {
+        FieldType::Number | FieldType::DateTime | FieldType::Formula | FieldType::Rollup => {
+            match (a.parse::<f64>(), b.parse::<f64>()) {
+                (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                _ => a.cmp(b),
+            }
+        },
+        // The options are ordered like they are in the field.
+        FieldType::SingleSelect => {
+            let position = |data: &str| field.select_options.iter().position(|option| option.id == data);
+            position(a).cmp(&position(b))
+        },
+        FieldType::RichText | FieldType::Relation => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+fn compare_cells(field: &Field, sort: &Sort, a: &Row, b: &Row) -> Ordering {
+    let (a, b) = (cell_data(a, field), cell_data(b, field));
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => {
+            let ordering = compare_data(field, a, b);
+            match sort.descending {
+                true => ordering.reverse(),
+                false => ordering,
+            }
+        },
+    }
+}
@@ -0,0 +1,302 @@
+use crate::services::grid::formula::parser::{BinaryOp, Expr};
+use std::cmp::Ordering;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const MIN_YEAR: f64 = 1.0;
+const MAX_YEAR: f64 = 9999.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Empty,
+    Number(f64),
+    Text(String),
+}
+
+impl Value {
+    // The numbers are kept as numbers so that the other formulas and the
+    // sorts can read them back, e.g. 3 instead of 3.0.
+    pub(crate) fn to_cell_data(&self) -> String {
+        match self {
+            Value::Empty => "".to_owned(),
+            Value::Number(number) => format_number(*number),
+            Value::Text(text) => text.clone(),
+        }
+    }
+
+    fn to_number(&self) -> Result<f64, String> {
+        match self {
+            Value::Empty => Ok(0.0),
+            Value::Number(number) => Ok(*number),
+            Value::Text(text) => text
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| format!("{} is not a number", text)),
+        }
+    }
+
+    fn to_text(&self) -> String { self.to_cell_data() }
+
+    fn is_true(&self) -> bool {
+        match self {
+            Value::Empty => false,
+            Value::Number(number) => *number != 0.0,
+            Value::Text(text) => !text.is_empty(),
+        }
+    }
+
+    fn is_text(&self) -> bool { matches!(self, Value::Text(text) if text.trim().parse::<f64>().is_err()) }
+}
+
+// `resolve` returns the value of the referenced field in the row that the
+// formula is evaluated for, it fails if the field doesn't exist anymore.
+pub(crate) fn evaluate(expr: &Expr, resolve: &dyn Fn(&str) -> Result<Value, String>) -> Result<Value, String> {
+    let value = match expr {
+        Expr::Number(number) => Value::Number(*number),
+        Expr::Text(text) => Value::Text(text.clone()),
+        Expr::Reference(name) => resolve(name)?,
+        Expr::Negate(expr) => Value::Number(-evaluate(expr, resolve)?.to_number()?),
+        Expr::Binary(op, left, right) => {
+            let left = evaluate(left, resolve)?;
+            let right = evaluate(right, resolve)?;
+            binary(*op, left, right)?
+        },
+        // The branch that is not taken is not evaluated, so it can't fail.
+        Expr::Call(name, args) if name == "IF" => {
+            if !(2..=3).contains(&args.len()) {
+                return Err("IF takes a condition and one or two values".to_owned());
+            }
+            match evaluate(&args[0], resolve)?.is_true() {
+                true => evaluate(&args[1], resolve)?,
+                false => match args.get(2) {
+                    None => Value::Empty,
+                    Some(arg) => evaluate(arg, resolve)?,
+                },
+            }
+        },
+        Expr::Call(name, args) => {
+            let args = args
+                .iter()
+                .map(|arg| evaluate(arg, resolve))
+                .collect::<Result<Vec<Value>, String>>()?;
+            call(name, args)?
+        },
+    };
+    match value {
+        Value::Number(number) if !number.is_finite() => Err("The result is not a finite number".to_owned()),
+        value => Ok(value),
+    }
+}
+
+fn binary(op: BinaryOp, left: Value, right: Value) -> Result<Value, String> {
+    let value = match op {
+        BinaryOp::Add => Value::Number(left.to_number()? + right.to_number()?),
+        BinaryOp::Subtract => Value::Number(left.to_number()? - right.to_number()?),
+        BinaryOp::Multiply => Value::Number(left.to_number()? * right.to_number()?),
+        BinaryOp::Divide => {
+            let divisor = right.to_number()?;
+            if divisor == 0.0 {
+                return Err("Division by zero".to_owned());
+            }
+            Value::Number(left.to_number()? / divisor)
+        },
+        BinaryOp::Concat => Value::Text(format!("{}{}", left.to_text(), right.to_text())),
+        _ => {
+            let ordering = compare(&left, &right);
+            let is_true = match op {
+                BinaryOp::Equal => ordering == Ordering::Equal,
+                BinaryOp::NotEqual => ordering != Ordering::Equal,
+                BinaryOp::Less => ordering == Ordering::Less,
+                BinaryOp::LessOrEqual => ordering != Ordering::Greater,
+                BinaryOp::Greater => ordering == Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            };
+            bool_value(is_true)
+        },
+    };
+    Ok(value)
+}
+
+// The values are compared as numbers unless one of them is a text that is
+// not a number.
+fn compare(left: &Value, right: &Value) -> Ordering {
+    if !left.is_text() && !right.is_text() {
+        if let (Ok(left), Ok(right)) = (left.to_number(), right.to_number()) {
+            return left.partial_cmp(&right).unwrap_or(Ordering::Equal);
+        }
+    }
+    left.to_text().to_lowercase().cmp(&right.to_text().to_lowercase())
+}
+
+fn call(name: &str, args: Vec<Value>) -> Result<Value, String> {
+    let numbers = || {
+        args.iter()
+            .map(|arg| arg.to_number())
+            .collect::<Result<Vec<f64>, String>>()
+    };
+    let arg = |index: usize| {
+        args.get(index)
+            .cloned()
+            .ok_or_else(|| format!("{} expects {} arguments at least", name, index + 1))
+    };
+    let value = match name {
+        "SUM" => Value::Number(numbers()?.iter().sum()),
+        "AVERAGE" => {
+            let numbers = numbers()?;
+            if numbers.is_empty() {
+                return Err("AVERAGE of nothing".to_owned());
+            }
+            Value::Number(numbers.iter().sum::<f64>() / numbers.len() as f64)
+        },
+        "MIN" => Value::Number(numbers()?.into_iter().fold(f64::NAN, f64::min)),
+        "MAX" => Value::Number(numbers()?.into_iter().fold(f64::NAN, f64::max)),
+        "ABS" => Value::Number(arg(0)?.to_number()?.abs()),
+        "ROUND" => {
+            let digits = match args.get(1) {
+                None => 0,
+                Some(digits) => digits.to_number()? as i32,
+            };
+            let factor = 10f64.powi(digits);
+            Value::Number((arg(0)?.to_number()? * factor).round() / factor)
+        },
+        "CONCAT" => Value::Text(args.iter().map(|arg| arg.to_text()).collect()),
+        "LEN" => Value::Number(arg(0)?.to_text().chars().count() as f64),
+        "UPPER" => Value::Text(arg(0)?.to_text().to_uppercase()),
+        "LOWER" => Value::Text(arg(0)?.to_text().to_lowercase()),
+        "TRIM" => Value::Text(arg(0)?.to_text().trim().to_owned()),
+        "ISBLANK" => bool_value(arg(0)? == Value::Empty),
+        // The dates are unix timestamps in seconds, the days are in UTC.
+        "DATE" => {
+            let (year, month, day) = (arg(0)?.to_number()?, arg(1)?.to_number()?, arg(2)?.to_number()?);
+            if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+                return Err(format!("The year must be between {} and {}", MIN_YEAR, MAX_YEAR));
+            }
+            if !(1.0..=12.0).contains(&month) {
+                return Err("The month is out of range".to_owned());
+            }
+            let (year, month) = (year as i64, month as i64);
+            if !(1.0..=days_in_month(year, month) as f64).contains(&day) {
+                return Err("The day is out of range".to_owned());
+            }
+            let seconds = days_from_civil(year, month, day as i64)
+                .checked_mul(SECONDS_PER_DAY)
+                .ok_or_else(|| "The date is out of range".to_owned())?;
+            Value::Number(seconds as f64)
+        },
+        "YEAR" => Value::Number(civil_from_timestamp(arg(0)?.to_number()?).0 as f64),
+        "MONTH" => Value::Number(civil_from_timestamp(arg(0)?.to_number()?).1 as f64),
+        "DAY" => Value::Number(civil_from_timestamp(arg(0)?.to_number()?).2 as f64),
+        "ADD_DAYS" => Value::Number(arg(0)?.to_number()? + arg(1)?.to_number()? * SECONDS_PER_DAY as f64),
+        "DAYS_BETWEEN" => {
+            let (start, end) = (arg(0)?.to_number()?, arg(1)?.to_number()?);
+            Value::Number(((end - start) / SECONDS_PER_DAY as f64).floor())
+        },
+        _ => return Err(format!("{} is not a function", name)),
+    };
+    Ok(value)
+}
+
+pub(crate) fn is_function(name: &str) -> bool {
+    [
+        "IF",
+        "SUM",
+        "AVERAGE",
+        "MIN",
+        "MAX",
+        "ABS",
+        "ROUND",
+        "CONCAT",
+        "LEN",
+        "UPPER",
+        "LOWER",
+        "TRIM",
+        "ISBLANK",
+        "DATE",
+        "YEAR",
+        "MONTH",
+        "DAY",
+        "ADD_DAYS",
+        "DAYS_BETWEEN",
+    ]
+    .contains(&name)
+}
+
+pub(crate) fn format_number(number: f64) -> String {
+    match number.fract() == 0.0 && number.abs() < 1e15 {
+        true => (number as i64).to_string(),
+        false => number.to_string(),
+    }
+}
+
+fn bool_value(is_true: bool) -> Value {
+    match is_true {
+        true => Value::Number(1.0),
+        false => Value::Number(0.0),
+    }
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_timestamp(timestamp: f64) -> (i64, i64, i64) {
+    let days = (timestamp as i64).div_euclid(SECONDS_PER_DAY) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::grid::formula::{
+        eval::{evaluate, Value},
+        parser::parse_formula,
+    };
+
+    fn eval(formula: &str) -> Result<Value, String> {
+        let expr = parse_formula(formula).unwrap();
+        evaluate(&expr, &|name| Err(format!("{} doesn't exist", name)))
+    }
+
+    #[test]
+    fn date_in_range() {
+        assert_eq!(eval("DATE(1970, 1, 1)").unwrap(), Value::Number(0.0));
+        assert_eq!(eval("DATE(2024, 2, 29)").unwrap(), Value::Number(1_709_164_800.0));
+        assert_eq!(eval("YEAR(DATE(9999, 12, 31))").unwrap(), Value::Number(9999.0));
+    }
+
+    #[test]
+    fn date_out_of_range() {
+        assert!(eval("DATE(99999999999999999999, 1, 1)").is_err());
+        assert!(eval("DATE(10000, 1, 1)").is_err());
+        assert!(eval("DATE(-5, 1, 1)").is_err());
+        assert!(eval("DATE(2023, 13, 1)").is_err());
+        assert!(eval("DATE(2023, 2, 29)").is_err());
+        assert!(eval("DATE(2023, 4, 31)").is_err());
+        assert!(eval("DATE(2023, 1, 0)").is_err());
+    }
+}
@@ -0,0 +1,251 @@
+mod eval;
+mod parser;
+
+use crate::{
+    entities::grid::{Cell, Field, FieldType, Grid, Row},
+    errors::{FlowyError, FlowyResult},
+    services::grid::formula::{
+        eval::{evaluate, is_function, Value},
+        parser::{parse_formula, Expr},
+    },
+};
+pub(crate) use eval::format_number;
+pub(crate) use parser::rename_reference;
+use std::collections::{HashMap, HashSet};
+
+// Checks the formula before it's saved to the field. The functions and the
+// referenced fields must exist, and the field can't depend on itself through
+// the other formulas.
+pub(crate) fn check_formula(grid: &Grid, field_id: &str, formula: &str) -> FlowyResult<()> {
+    if formula.trim().is_empty() {
+        return Ok(());
+    }
+
+    let expr = parse_formula(formula)?;
+    let _ = check_functions(&expr)?;
+    let mut referenced_field_ids = vec![];
+    for name in expr.references() {
+        match field_by_name(&grid.fields, name) {
+            None => return Err(FlowyError::formula().context(format!("The field {} doesn't exist", name))),
+            Some(field) => referenced_field_ids.push(field.id.clone()),
+        }
+    }
+
+    let mut dependencies = dependencies(grid);
+    dependencies.insert(field_id.to_owned(), referenced_field_ids);
+    if depends_on(&dependencies, field_id, &[field_id.to_owned()]) {
+        return Err(FlowyError::formula().context("The formula references itself"));
+    }
+    Ok(())
+}
+
+// The formula fields whose cells change when the cells of the field change,
+// including the ones that depend on it through other formulas.
+pub(crate) fn dependents(grid: &Grid, field_id: &str) -> Vec<String> {
+    let dependencies = dependencies(grid);
+    let field_ids = [field_id.to_owned()];
+    grid.fields
+        .iter()
+        .filter(|field| field.field_type == FieldType::Formula)
+        .filter(|field| depends_on(&dependencies, &field.id, &field_ids))
+        .map(|field| field.id.clone())
+        .collect()
+}
+
+// Computes the cells of the formula fields that depend on the changed fields,
+// in the rows or in all the rows if it's None. A changed field that is a
+// formula is computed too. The formulas are computed after the ones they
+// reference, and the ids of the rows whose cells changed are returned.
+pub(crate) fn recalculate(grid: &mut Grid, changed_field_ids: &[String], row_ids: Option<&[String]>) -> Vec<String> {
+    let formulas = ordered_formulas(grid, changed_field_ids);
+    if formulas.is_empty() {
+        return vec![];
+    }
+
+    let fields = grid.fields.clone();
+    let mut updated_row_ids = vec![];
+    for row in grid.rows.iter_mut() {
+        if row_ids.map(|row_ids| !row_ids.contains(&row.id)).unwrap_or(false) {
+            continue;
+        }
+
+        let mut is_updated = false;
+        for (field_id, expr) in formulas.iter() {
+            // The formulas that can't be evaluated for the row leave their
+            // cells empty.
+            let data = expr
+                .as_ref()
+                .and_then(|expr| evaluate(expr, &|name| value_of(&fields, row, name)).ok())
+                .map(|value| value.to_cell_data())
+                .unwrap_or_default();
+            is_updated |= set_cell_data(row, field_id, data);
+        }
+        if is_updated {
+            updated_row_ids.push(row.id.clone());
+        }
+    }
+    updated_row_ids
+}
+
+fn check_functions(expr: &Expr) -> FlowyResult<()> {
+    match expr {
+        Expr::Number(_) | Expr::Text(_) | Expr::Reference(_) => Ok(()),
+        Expr::Negate(expr) => check_functions(expr),
+        Expr::Binary(_, left, right) => {
+            let _ = check_functions(left)?;
+            check_functions(right)
+        },
+        Expr::Call(name, args) => {
+            if !is_function(name) {
+                return Err(FlowyError::formula().context(format!("{} is not a function", name)));
+            }
+            args.iter().try_for_each(check_functions)
+        },
+    }
+}
+
+// The ids of the fields that each formula field references. The references to
+// the fields that don't exist are left out.
+fn dependencies(grid: &Grid) -> HashMap<String, Vec<String>> {
+    grid.fields
+        .iter()
+        .filter(|field| field.field_type == FieldType::Formula)
+        .map(|field| {
+            let referenced_field_ids = parse_formula(&field.formula)
+                .map(|expr| {
+                    expr.references()
+                        .into_iter()
+                        .filter_map(|name| field_by_name(&grid.fields, name))
+                        .map(|field| field.id.clone())
+                        .collect()
+                })
+                .unwrap_or_default();
+            (field.id.clone(), referenced_field_ids)
+        })
+        .collect()
+}
+
+fn depends_on(dependencies: &HashMap<String, Vec<String>>, field_id: &str, field_ids: &[String]) -> bool {
+    let mut visited = HashSet::new();
+    let mut stack = vec![field_id];
+    while let Some(field_id) = stack.pop() {
+        for referenced_field_id in dependencies.get(field_id).into_iter().flatten() {
+            if field_ids.contains(referenced_field_id) {
+                return true;
+            }
+            if visited.insert(referenced_field_id.as_str()) {
+                stack.push(referenced_field_id);
+            }
+        }
+    }
+    false
+}
+
+// The formulas that need to be computed, each one after the formulas it
+// references. The formula is None if it's empty or can't be parsed.
+fn ordered_formulas(grid: &Grid, changed_field_ids: &[String]) -> Vec<(String, Option<Expr>)> {
+    let dependencies = dependencies(grid);
+    let affected_field_ids = grid
+        .fields
+        .iter()
+        .filter(|field| field.field_type == FieldType::Formula)
+        .filter(|field| {
+            changed_field_ids.contains(&field.id) || depends_on(&dependencies, &field.id, changed_field_ids)
+        })
+        .map(|field| field.id.as_str())
+        .collect::<HashSet<&str>>();
+
+    let mut ordered_field_ids = vec![];
+    let mut visited = HashSet::new();
+    for field in grid.fields.iter() {
+        if affected_field_ids.contains(field.id.as_str()) {
+            visit(
+                &field.id,
+                &dependencies,
+                &affected_field_ids,
+                &mut visited,
+                &mut ordered_field_ids,
+            );
+        }
+    }
+
+    ordered_field_ids
+        .into_iter()
+        .map(|field_id| {
+            let expr = grid
+                .fields
+                .iter()
+                .find(|field| field.id == field_id)
+                .filter(|field| !field.formula.trim().is_empty())
+                .and_then(|field| parse_formula(&field.formula).ok());
+            (field_id.to_owned(), expr)
+        })
+        .collect()
+}
+
+fn visit<'a>(
+    field_id: &'a str,
+    dependencies: &'a HashMap<String, Vec<String>>,
+    affected_field_ids: &HashSet<&str>,
+    visited: &mut HashSet<&'a str>,
+    ordered_field_ids: &mut Vec<&'a str>,
+) {
+    if !visited.insert(field_id) {
+        return;
+    }
+    for referenced_field_id in dependencies.get(field_id).into_iter().flatten() {
+        if affected_field_ids.contains(referenced_field_id.as_str()) {
+            visit(
+                referenced_field_id,
+                dependencies,
+                affected_field_ids,
+                visited,
+                ordered_field_ids,
+            );
+        }
+    }
+    ordered_field_ids.push(field_id);
+}
+
+// The first field is used if several fields have the same name.
+fn field_by_name<'a>(fields: &'a [Field], name: &str) -> Option<&'a Field> {
+    fields.iter().find(|field| field.name == name)
+}
+
+fn value_of(fields: &[Field], row: &Row, name: &str) -> Result<Value, String> {
+    let field = field_by_name(fields, name).ok_or_else(|| format!("The field {} doesn't exist", name))?;
+    let data = match row.cell(&field.id) {
+        Some(cell) if !cell.data.is_empty() => cell.data.as_str(),
+        _ => return Ok(Value::Empty),
+    };
+    let value = match field.field_type {
+        FieldType::RichText | FieldType::Relation => Value::Text(data.to_owned()),
+        FieldType::Number | FieldType::DateTime | FieldType::Formula | FieldType::Rollup => data
+            .parse::<f64>()
+            .map(Value::Number)
+            .unwrap_or_else(|_| Value::Text(data.to_owned())),
+        FieldType::SingleSelect => field
+            .select_options
+            .iter()
+            .find(|option| option.id == data)
+            .map(|option| Value::Text(option.name.clone()))
+            .unwrap_or(Value::Empty),
+    };
+    Ok(value)
+}
+
+// Returns true if the data of the cell changed.
+pub(crate) fn set_cell_data(row: &mut Row, field_id: &str, data: String) -> bool {
+    if row.cell(field_id).map(|cell| cell.data.as_str()).unwrap_or("") == data {
+        return false;
+    }
+
+    row.cells.retain(|cell| cell.field_id != field_id);
+    if !data.is_empty() {
+        row.cells.push(Cell {
+            field_id: field_id.to_owned(),
+            data,
+        });
+    }
+    true
+}
@@ -0,0 +1,384 @@
+use crate::errors::{FlowyError, FlowyResult};
+use std::{iter::Peekable, str::Chars};
+
+// The syntax of the formulas:
+//  comparison  concat (("=" | "<>" | "<" | "<=" | ">" | ">=") concat)?
+//  concat      additive ("&" additive)*
+//  additive    term (("+" | "-") term)*
+//  term        unary (("*" | "/") unary)*
+//  unary       "-" unary | primary
+//  primary     number | "text" | {Field name} | NAME(args) | (comparison)
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Expr {
+    Number(f64),
+    Text(String),
+    // The name of the referenced field
+    Reference(String),
+    Negate(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    // The name of the function is upper cased
+    Call(String, Vec<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BinaryOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Concat,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Expr {
+    // The names of the fields that the expression references.
+    pub(crate) fn references(&self) -> Vec<&str> {
+        let mut names = vec![];
+        self.collect_references(&mut names);
+        names
+    }
+
+    fn collect_references<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Expr::Number(_) | Expr::Text(_) => {},
+            Expr::Reference(name) => {
+                if !names.contains(&name.as_str()) {
+                    names.push(name);
+                }
+            },
+            Expr::Negate(expr) => expr.collect_references(names),
+            Expr::Binary(_, left, right) => {
+                left.collect_references(names);
+                right.collect_references(names);
+            },
+            Expr::Call(_, args) => args.iter().for_each(|arg| arg.collect_references(names)),
+        }
+    }
+}
+
+// The expressions are parsed and evaluated recursively, so a deeply nested
+// formula would overflow the stack.
+const MAX_NESTING_DEPTH: usize = 100;
+
+pub(crate) fn parse_formula(formula: &str) -> FlowyResult<Expr> {
+    let tokens = tokenize(formula)?;
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        depth: 0,
+    };
+    let expr = parser.comparison()?;
+    match parser.peek() {
+        None => Ok(expr),
+        Some(token) => Err(invalid(format!("Unexpected {:?}", token))),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Text(String),
+    Reference(String),
+    Name(String),
+    Operator(&'static str),
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+fn tokenize(formula: &str) -> FlowyResult<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = formula.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '0'..='9' | '.' => {
+                let mut number = c.to_string();
+                while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit() || **c == '.') {
+                    number.push(*c);
+                    chars.next();
+                }
+                let number = number
+                    .parse::<f64>()
+                    .map_err(|_| invalid(format!("{} is not a number", number)))?;
+                Token::Number(number)
+            },
+            // Two quotes in a text stand for one.
+            '"' => {
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        None => return Err(invalid("The text is not closed")),
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            text.push('"');
+                            chars.next();
+                        },
+                        Some('"') => break,
+                        Some(c) => text.push(c),
+                    }
+                }
+                Token::Text(text)
+            },
+            '{' => {
+                let name = take_until(&mut chars, '}').ok_or_else(|| invalid("The field reference is not closed"))?;
+                Token::Reference(name.trim().to_owned())
+            },
+            c if c.is_alphabetic() || c == '_' => {
+                let mut name = c.to_string();
+                while let Some(c) = chars.peek().filter(|c| c.is_alphanumeric() || **c == '_') {
+                    name.push(*c);
+                    chars.next();
+                }
+                Token::Name(name.to_uppercase())
+            },
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            ',' => Token::Comma,
+            '+' => Token::Operator("+"),
+            '-' => Token::Operator("-"),
+            '*' => Token::Operator("*"),
+            '/' => Token::Operator("/"),
+            '&' => Token::Operator("&"),
+            '=' => Token::Operator("="),
+            '<' => match chars.peek() {
+                Some('=') => {
+                    chars.next();
+                    Token::Operator("<=")
+                },
+                Some('>') => {
+                    chars.next();
+                    Token::Operator("<>")
+                },
+                _ => Token::Operator("<"),
+            },
+            '>' => match chars.peek() {
+                Some('=') => {
+                    chars.next();
+                    Token::Operator(">=")
+                },
+                _ => Token::Operator(">"),
+            },
+            c => return Err(invalid(format!("Unexpected character {}", c))),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+// Renames the references to the field, the texts of the formula are kept as
+// they are.
+pub(crate) fn rename_reference(formula: &str, old_name: &str, new_name: &str) -> String {
+    let mut renamed = String::new();
+    let mut chars = formula.chars().peekable();
+    let mut is_in_text = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => is_in_text = !is_in_text,
+            '{' if !is_in_text => match take_until(&mut chars, '}') {
+                Some(name) if name.trim() == old_name => {
+                    renamed.push_str(&format!("{{{}}}", new_name));
+                    continue;
+                },
+                Some(name) => {
+                    renamed.push_str(&format!("{{{}}}", name));
+                    continue;
+                },
+                None => {},
+            },
+            _ => {},
+        }
+        renamed.push(c);
+    }
+    renamed
+}
+
+fn take_until(chars: &mut Peekable<Chars>, end: char) -> Option<String> {
+    let mut s = String::new();
+    for c in chars {
+        if c == end {
+            return Some(s);
+        }
+        s.push(c);
+    }
+    None
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    // Every parenthesis, argument list, unary minus and chained operator
+    // nests the expression one level deeper.
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> { self.tokens.get(self.position) }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn nest(&mut self) -> FlowyResult<()> {
+        self.depth += 1;
+        match self.depth > MAX_NESTING_DEPTH {
+            true => Err(invalid("The formula is nested too deeply")),
+            false => Ok(()),
+        }
+    }
+
+    // Consumes the next token if it's one of the operators.
+    fn operator(&mut self, operators: &[&'static str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Operator(operator)) if operators.contains(operator) => {
+                let operator = *operator;
+                self.position += 1;
+                Some(operator)
+            },
+            _ => None,
+        }
+    }
+
+    fn comparison(&mut self) -> FlowyResult<Expr> {
+        let left = self.concat()?;
+        let op = match self.operator(&["=", "<>", "<", "<=", ">", ">="]) {
+            None => return Ok(left),
+            Some("=") => BinaryOp::Equal,
+            Some("<>") => BinaryOp::NotEqual,
+            Some("<") => BinaryOp::Less,
+            Some("<=") => BinaryOp::LessOrEqual,
+            Some(">") => BinaryOp::Greater,
+            Some(_) => BinaryOp::GreaterOrEqual,
+        };
+        let right = self.concat()?;
+        Ok(Expr::Binary(op, Box::new(left), Box::new(right)))
+    }
+
+    fn concat(&mut self) -> FlowyResult<Expr> {
+        let depth = self.depth;
+        let mut expr = self.additive()?;
+        while self.operator(&["&"]).is_some() {
+            self.nest()?;
+            let right = self.additive()?;
+            expr = Expr::Binary(BinaryOp::Concat, Box::new(expr), Box::new(right));
+        }
+        self.depth = depth;
+        Ok(expr)
+    }
+
+    fn additive(&mut self) -> FlowyResult<Expr> {
+        let depth = self.depth;
+        let mut expr = self.term()?;
+        while let Some(operator) = self.operator(&["+", "-"]) {
+            let op = match operator {
+                "+" => BinaryOp::Add,
+                _ => BinaryOp::Subtract,
+            };
+            self.nest()?;
+            let right = self.term()?;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(right));
+        }
+        self.depth = depth;
+        Ok(expr)
+    }
+
+    fn term(&mut self) -> FlowyResult<Expr> {
+        let depth = self.depth;
+        let mut expr = self.unary()?;
+        while let Some(operator) = self.operator(&["*", "/"]) {
+            let op = match operator {
+                "*" => BinaryOp::Multiply,
+                _ => BinaryOp::Divide,
+            };
+            self.nest()?;
+            let right = self.unary()?;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(right));
+        }
+        self.depth = depth;
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> FlowyResult<Expr> {
+        match self.operator(&["-"]) {
+            Some(_) => {
+                let depth = self.depth;
+                self.nest()?;
+                let expr = Expr::Negate(Box::new(self.unary()?));
+                self.depth = depth;
+                Ok(expr)
+            },
+            None => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> FlowyResult<Expr> {
+        let depth = self.depth;
+        let expr = self.nested_primary()?;
+        self.depth = depth;
+        Ok(expr)
+    }
+
+    fn nested_primary(&mut self) -> FlowyResult<Expr> {
+        match self.advance() {
+            Some(Token::Number(number)) => Ok(Expr::Number(number)),
+            Some(Token::Text(text)) => Ok(Expr::Text(text)),
+            Some(Token::Reference(name)) => Ok(Expr::Reference(name)),
+            Some(Token::LeftParen) => {
+                self.nest()?;
+                let expr = self.comparison()?;
+                self.expect(Token::RightParen)?;
+                Ok(expr)
+            },
+            Some(Token::Name(name)) => {
+                self.expect(Token::LeftParen)?;
+                self.nest()?;
+                let mut args = vec![];
+                if self.peek() == Some(&Token::RightParen) {
+                    self.position += 1;
+                    return Ok(Expr::Call(name, args));
+                }
+                loop {
+                    args.push(self.comparison()?);
+                    match self.advance() {
+                        Some(Token::Comma) => continue,
+                        Some(Token::RightParen) => break,
+                        _ => return Err(invalid(format!("The arguments of {} are not closed", name))),
+                    }
+                }
+                Ok(Expr::Call(name, args))
+            },
+            Some(token) => Err(invalid(format!("Unexpected {:?}", token))),
+            None => Err(invalid("The formula ends too early")),
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> FlowyResult<()> {
+        match self.advance() {
+            Some(token) if token == expected => Ok(()),
+            _ => Err(invalid(format!("Expect {:?}", expected))),
+        }
+    }
+}
+
+fn invalid<T: std::fmt::Debug>(msg: T) -> FlowyError { FlowyError::formula().context(msg) }
+
+#[cfg(test)]
+mod tests {
+    use crate::services::grid::formula::parser::{parse_formula, MAX_NESTING_DEPTH};
+
+    #[test]
+    fn parse_nesting_depth_is_capped() {
+        let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert!(parse_formula(&nested(MAX_NESTING_DEPTH)).is_ok());
+        assert!(parse_formula(&nested(100_000)).is_err());
+        assert!(parse_formula(&"-".repeat(100_000)).is_err());
+        assert!(parse_formula(&format!("ABS({})", nested(MAX_NESTING_DEPTH))).is_err());
+        assert!(parse_formula(&vec!["1"; 100_000].join("+")).is_err());
+    }
+}
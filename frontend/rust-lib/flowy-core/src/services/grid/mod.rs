@@ -0,0 +1,6 @@
+pub mod controller;
+pub mod event_handler;
+mod filter;
+mod formula;
+mod pad;
+mod relation;
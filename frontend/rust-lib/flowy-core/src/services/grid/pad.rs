@@ -0,0 +1,613 @@
+use crate::{
+    entities::grid::{
+        Board,
+        BoardGroup,
+        CalendarEvent,
+        CalendarRange,
+        CardMoved,
+        Cell,
+        CreateFieldParams,
+        CreateRowParams,
+        Field,
+        FieldType,
+        Grid,
+        GridChangeset,
+        GridSetting,
+        IndexedRow,
+        MoveCalendarEventParams,
+        MoveCardParams,
+        RollupFunction,
+        RollupSetting,
+        Row,
+        UpdateCellParams,
+        UpdateFieldParams,
+    },
+    errors::{FlowyError, FlowyResult},
+    services::grid::{
+        filter::visible_rows,
+        formula::{check_formula, dependents, recalculate, rename_reference},
+        relation::{
+            recalculate_rollups,
+            related_row_ids,
+            relation_fields_to,
+            remove_related_rows,
+            rollup_fields_of,
+            rows_linked_to,
+        },
+    },
+};
+use std::collections::HashMap;
+
+// Applies the edits to the grid that was read from the text of its document.
+pub(crate) struct GridPad {
+    grid: Grid,
+    text: String,
+    // The grids that the relation fields link to, they are needed to check the
+    // relation cells and to compute the rollups.
+    related_grids: HashMap<String, Grid>,
+}
+
+impl GridPad {
+    pub(crate) fn from_text(grid_id: &str, text: String) -> FlowyResult<Self> {
+        let mut grid: Grid = serde_json::from_str(text.trim_end_matches('\n'))
+            .map_err(|e| FlowyError::view_data().context(format!("The grid can't be read: {}", e)))?;
+        // A copied grid keeps the id of the grid it was copied from.
+        grid.id = grid_id.to_owned();
+        Ok(Self {
+            grid,
+            text,
+            related_grids: HashMap::new(),
+        })
+    }
+
+    pub(crate) fn grid(&self) -> &Grid { &self.grid }
+
+    pub(crate) fn related_grid_ids(&self) -> Vec<String> {
+        let mut related_grid_ids = vec![];
+        for field in self.grid.fields.iter() {
+            if field.field_type == FieldType::Relation && !related_grid_ids.contains(&field.related_grid_id) {
+                related_grid_ids.push(field.related_grid_id.clone());
+            }
+        }
+        related_grid_ids
+    }
+
+    pub(crate) fn set_related_grid(&mut self, grid: Grid) { self.related_grids.insert(grid.id.clone(), grid); }
+
+    // Remembers that the grid has a relation field to this grid.
+    pub(crate) fn link_grid(&mut self, grid_id: &str) -> FlowyResult<(GridChangeset, ())> {
+        if !self
+            .grid
+            .linked_grid_ids
+            .iter()
+            .any(|linked_grid_id| linked_grid_id == grid_id)
+        {
+            self.grid.linked_grid_ids.push(grid_id.to_owned());
+        }
+        Ok((GridChangeset::new(&self.grid.id), ()))
+    }
+
+    // Follows the edit of the related grid: the deleted rows are removed from
+    // the relation cells, and the rollups of the rows that link to the edited
+    // rows are computed again.
+    pub(crate) fn update_relations(
+        &mut self,
+        related_grid: &Grid,
+        related_changeset: &GridChangeset,
+    ) -> FlowyResult<(GridChangeset, ())> {
+        let mut changeset = GridChangeset::new(&self.grid.id);
+        let relation_field_ids = relation_fields_to(&self.grid, &related_grid.id);
+        if relation_field_ids.is_empty() {
+            return Ok((changeset, ()));
+        }
+        self.set_related_grid(related_grid.clone());
+
+        // A moved row is deleted and inserted again.
+        let inserted_row_ids = related_changeset
+            .inserted_rows
+            .iter()
+            .map(|indexed_row| indexed_row.row.id.clone())
+            .collect::<Vec<String>>();
+        let deleted_row_ids = related_changeset
+            .deleted_row_ids
+            .iter()
+            .filter(|row_id| !inserted_row_ids.contains(row_id))
+            .cloned()
+            .collect::<Vec<String>>();
+        let mut row_ids = remove_related_rows(&mut self.grid, &relation_field_ids, &deleted_row_ids);
+        self.push_updated_rows(&mut changeset, row_ids.clone());
+
+        let is_field_changed =
+            !related_changeset.updated_fields.is_empty() || !related_changeset.deleted_field_ids.is_empty();
+        if is_field_changed {
+            self.recalculate_rows(&mut changeset, &relation_field_ids, None);
+        } else {
+            let mut edited_row_ids = inserted_row_ids;
+            edited_row_ids.extend(related_changeset.updated_rows.iter().map(|row| row.id.clone()));
+            row_ids.extend(rows_linked_to(&self.grid, &relation_field_ids, &edited_row_ids));
+            self.recalculate_rows(&mut changeset, &relation_field_ids, Some(row_ids.as_slice()));
+        }
+        Ok((changeset, ()))
+    }
+
+    pub(crate) fn visible_rows(&self) -> Vec<Row> { visible_rows(&self.grid) }
+
+    // The rows are not changed, only the way they are shown.
+    pub(crate) fn update_setting(&mut self, setting: GridSetting) -> FlowyResult<(GridChangeset, ())> {
+        self.grid.setting = setting;
+        Ok((GridChangeset::new(&self.grid.id), ()))
+    }
+
+    pub(crate) fn create_field(&mut self, params: CreateFieldParams) -> FlowyResult<(GridChangeset, Field)> {
+        let mut field = Field::new(&params.name, params.field_type);
+        field.select_options = params.select_options;
+        field.formula = params.formula;
+        field.related_grid_id = params.related_grid_id;
+        field.rollup = params.rollup;
+        let _ = self.check_field(&field)?;
+        self.grid.fields.push(field.clone());
+
+        let mut changeset = GridChangeset::new(&self.grid.id);
+        changeset.updated_fields.push(field.clone());
+        self.recalculate_rows(&mut changeset, &[field.id.clone()], None);
+        Ok((changeset, field))
+    }
+
+    pub(crate) fn update_field(&mut self, params: UpdateFieldParams) -> FlowyResult<(GridChangeset, Field)> {
+        let index = self
+            .grid
+            .fields
+            .iter()
+            .position(|field| field.id == params.field_id)
+            .ok_or_else(|| FlowyError::grid_record_not_found().context("The field doesn't exist"))?;
+        let old_field = self.grid.fields[index].clone();
+        let mut field = old_field.clone();
+        if let Some(name) = params.name {
+            field.name = name;
+        }
+        if let Some(field_type) = params.field_type {
+            field.field_type = field_type;
+        }
+        if let Some(select_options) = params.select_options {
+            field.select_options = select_options.items;
+        }
+        if let Some(formula) = params.formula {
+            field.formula = formula;
+        }
+        if let Some(related_grid_id) = params.related_grid_id {
+            field.related_grid_id = related_grid_id;
+        }
+        if let Some(rollup) = params.rollup {
+            field.rollup = rollup;
+        }
+        let _ = self.check_field(&field)?;
+        self.grid.fields[index] = field.clone();
+
+        // The related rows are only meaningful in the grid they were linked in.
+        let keeps_cell = |cell: &Cell| match field.field_type {
+            FieldType::Relation => {
+                old_field.field_type == FieldType::Relation && old_field.related_grid_id == field.related_grid_id
+            },
+            _ => validate_cell_data(&field, &cell.data).is_ok(),
+        };
+        let mut changeset = GridChangeset::new(&self.grid.id);
+        for row in self.grid.rows.iter_mut() {
+            let len = row.cells.len();
+            row.cells.retain(|cell| cell.field_id != field.id || keeps_cell(cell));
+            if row.cells.len() != len {
+                changeset.updated_rows.push(row.clone());
+            }
+        }
+        changeset.updated_fields.push(field.clone());
+
+        // The formulas keep referencing the field after it's renamed.
+        if field.name != old_field.name {
+            for other_field in self.grid.fields.iter_mut().filter(|other| other.id != field.id) {
+                let formula = rename_reference(&other_field.formula, &old_field.name, &field.name);
+                if formula != other_field.formula {
+                    other_field.formula = formula;
+                    changeset.updated_fields.push(other_field.clone());
+                }
+            }
+        }
+        self.recalculate_rows(&mut changeset, &[field.id.clone()], None);
+        Ok((changeset, field))
+    }
+
+    pub(crate) fn delete_field(&mut self, field_id: &str) -> FlowyResult<GridChangeset> {
+        let mut field_ids = dependents(&self.grid, field_id);
+        field_ids.push(field_id.to_owned());
+        let len = self.grid.fields.len();
+        self.grid.fields.retain(|field| field.id != field_id);
+        if self.grid.fields.len() == len {
+            return Err(FlowyError::grid_record_not_found().context("The field doesn't exist"));
+        }
+
+        let mut changeset = GridChangeset::new(&self.grid.id);
+        for row in self.grid.rows.iter_mut() {
+            let len = row.cells.len();
+            row.cells.retain(|cell| cell.field_id != field_id);
+            if row.cells.len() != len {
+                changeset.updated_rows.push(row.clone());
+            }
+        }
+        changeset.deleted_field_ids.push(field_id.to_owned());
+        // The formulas and the rollups that referenced the field can't be
+        // computed anymore.
+        self.recalculate_rows(&mut changeset, &field_ids, None);
+        Ok(changeset)
+    }
+
+    // The row is inserted after `start_row_id`, or appended if it's not set.
+    pub(crate) fn create_row(&mut self, params: CreateRowParams) -> FlowyResult<(GridChangeset, Row)> {
+        let index = match params.start_row_id {
+            None => self.grid.rows.len(),
+            Some(start_row_id) => self.row_index(&start_row_id)? + 1,
+        };
+        let row = Row::new();
+        let row_id = row.id.clone();
+        self.grid.rows.insert(index, row);
+        let field_ids = self
+            .grid
+            .fields
+            .iter()
+            .map(|field| field.id.clone())
+            .collect::<Vec<String>>();
+        let _ = self.recalculate_cells(&field_ids, Some(&[row_id][..]));
+        let row = self.grid.rows[index].clone();
+
+        let mut changeset = GridChangeset::new(&self.grid.id);
+        changeset.inserted_rows.push(IndexedRow {
+            row: row.clone(),
+            index: index as i32,
+        });
+        Ok((changeset, row))
+    }
+
+    // Appends the rows that were built outside the grid, e.g. by an import.
+    // Their cells are expected to fit the fields already.
+    pub(crate) fn append_rows(&mut self, rows: Vec<Row>) -> FlowyResult<(GridChangeset, ())> {
+        let mut changeset = GridChangeset::new(&self.grid.id);
+        for row in rows {
+            changeset.inserted_rows.push(IndexedRow {
+                row: row.clone(),
+                index: self.grid.rows.len() as i32,
+            });
+            self.grid.rows.push(row);
+        }
+        Ok((changeset, ()))
+    }
+
+    pub(crate) fn delete_row(&mut self, row_id: &str) -> FlowyResult<GridChangeset> {
+        let index = self.row_index(row_id)?;
+        self.grid.rows.remove(index);
+
+        let mut changeset = GridChangeset::new(&self.grid.id);
+        changeset.deleted_row_ids.push(row_id.to_owned());
+        Ok(changeset)
+    }
+
+    // Empty data clears the cell.
+    pub(crate) fn update_cell(&mut self, params: UpdateCellParams) -> FlowyResult<(GridChangeset, Cell)> {
+        let field = self
+            .grid
+            .fields
+            .iter()
+            .find(|field| field.id == params.field_id)
+            .ok_or_else(|| FlowyError::grid_record_not_found().context("The field doesn't exist"))?;
+        let data = match field.field_type {
+            FieldType::Formula | FieldType::Rollup => {
+                return Err(FlowyError::cell_data().context("The cells of the field are computed"));
+            },
+            FieldType::Relation => self.check_related_rows(field, &params.data)?,
+            _ => {
+                let _ = validate_cell_data(field, &params.data)?;
+                params.data
+            },
+        };
+
+        let index = self.row_index(&params.row_id)?;
+        let row = &mut self.grid.rows[index];
+        row.cells.retain(|cell| cell.field_id != params.field_id);
+        let cell = Cell {
+            field_id: params.field_id,
+            data,
+        };
+        if !cell.data.is_empty() {
+            row.cells.push(cell.clone());
+        }
+
+        let mut changeset = GridChangeset::new(&self.grid.id);
+        changeset.updated_rows.push(self.grid.rows[index].clone());
+        self.recalculate_rows(&mut changeset, &[cell.field_id.clone()], Some(&[params.row_id][..]));
+        Ok((changeset, cell))
+    }
+
+    pub(crate) fn board(&self) -> FlowyResult<Board> {
+        let group_field = self.group_field()?;
+        let mut groups = vec![BoardGroup {
+            id: "".to_owned(),
+            name: format!("No {}", group_field.name),
+            color: "".to_owned(),
+            rows: vec![],
+        }];
+        groups.extend(group_field.select_options.iter().map(|option| BoardGroup {
+            id: option.id.clone(),
+            name: option.name.clone(),
+            color: option.color.clone(),
+            rows: vec![],
+        }));
+        for row in self.grid.rows.iter() {
+            let group_id = group_id_of(row, group_field);
+            let index = groups.iter().position(|group| group.id == group_id).unwrap_or(0);
+            groups[index].rows.push(row.clone());
+        }
+
+        Ok(Board {
+            grid_id: self.grid.id.clone(),
+            group_field_id: group_field.id.clone(),
+            groups,
+        })
+    }
+
+    // The card keeps its place among the rows of the grid, so the order of the
+    // cards in a group is the order of their rows. The move is sent as the
+    // deletion and the insertion of the row.
+    pub(crate) fn move_card(&mut self, params: MoveCardParams) -> FlowyResult<(GridChangeset, CardMoved)> {
+        let group_field = self.group_field()?.clone();
+        let _ = validate_cell_data(&group_field, &params.to_group_id)?;
+
+        let index = self.row_index(&params.row_id)?;
+        let mut row = self.grid.rows.remove(index);
+        let from_group_id = group_id_of(&row, &group_field);
+        row.cells.retain(|cell| cell.field_id != group_field.id);
+        if !params.to_group_id.is_empty() {
+            row.cells.push(Cell {
+                field_id: group_field.id.clone(),
+                data: params.to_group_id.clone(),
+            });
+        }
+
+        let is_in_group = |row: &Row| group_id_of(row, &group_field) == params.to_group_id;
+        let to = match &params.before_row_id {
+            None => self
+                .grid
+                .rows
+                .iter()
+                .rposition(is_in_group)
+                .map(|index| index + 1)
+                .unwrap_or_else(|| self.grid.rows.len()),
+            Some(before_row_id) => {
+                let to = self.row_index(before_row_id)?;
+                if !is_in_group(&self.grid.rows[to]) {
+                    return Err(FlowyError::grid_record_not_found().context("The row is not in the group"));
+                }
+                to
+            },
+        };
+        let to_index = self.grid.rows[..to].iter().filter(|row| is_in_group(*row)).count();
+        self.grid.rows.insert(to, row.clone());
+        let _ = self.recalculate_cells(&[group_field.id.clone()], Some(&[row.id.clone()][..]));
+        let row = self.grid.rows[to].clone();
+
+        let mut changeset = GridChangeset::new(&self.grid.id);
+        changeset.deleted_row_ids.push(row.id.clone());
+        changeset.inserted_rows.push(IndexedRow {
+            row: row.clone(),
+            index: to as i32,
+        });
+        let card_moved = CardMoved {
+            grid_id: self.grid.id.clone(),
+            row_id: row.id,
+            from_group_id,
+            to_group_id: params.to_group_id,
+            to_index: to_index as i32,
+        };
+        Ok((changeset, card_moved))
+    }
+
+    // The events are ordered by their timestamps.
+    pub(crate) fn calendar_events(&self, range: &CalendarRange) -> FlowyResult<Vec<CalendarEvent>> {
+        let date_field = self.date_field()?;
+        let mut events = self
+            .grid
+            .rows
+            .iter()
+            .filter_map(|row| self.calendar_event(row, date_field))
+            .filter(|event| range.contains(event.timestamp))
+            .collect::<Vec<CalendarEvent>>();
+        events.sort_by_key(|event| event.timestamp);
+        Ok(events)
+    }
+
+    pub(crate) fn move_calendar_event(
+        &mut self,
+        params: MoveCalendarEventParams,
+    ) -> FlowyResult<(GridChangeset, CalendarEvent)> {
+        let date_field = self.date_field()?.clone();
+        let (changeset, _) = self.update_cell(UpdateCellParams {
+            grid_id: params.grid_id,
+            row_id: params.row_id.clone(),
+            field_id: date_field.id.clone(),
+            data: params.timestamp.to_string(),
+        })?;
+        let row = &self.grid.rows[self.row_index(&params.row_id)?];
+        let event = self
+            .calendar_event(row, &date_field)
+            .ok_or_else(|| FlowyError::internal().context("The event was not moved"))?;
+        Ok((changeset, event))
+    }
+
+    // The text that the grid was read from.
+    pub(crate) fn text(&self) -> &str { &self.text }
+
+    pub(crate) fn to_text(&self) -> FlowyResult<String> {
+        let json = serde_json::to_string(&self.grid).map_err(|e| FlowyError::internal().context(e))?;
+        Ok(format!("{}\n", json))
+    }
+
+    // The related rows must exist, they're kept in the order they were given.
+    fn check_related_rows(&self, relation_field: &Field, data: &str) -> FlowyResult<String> {
+        let row_ids = related_row_ids(data);
+        if row_ids.is_empty() {
+            return Ok("".to_owned());
+        }
+
+        let related_grid = self
+            .related_grids
+            .get(&relation_field.related_grid_id)
+            .ok_or_else(|| FlowyError::grid_record_not_found().context("The related grid doesn't exist"))?;
+        for row_id in row_ids.iter() {
+            if !related_grid.rows.iter().any(|row| row.id == *row_id) {
+                return Err(FlowyError::grid_record_not_found().context("The related row doesn't exist"));
+            }
+        }
+        Ok(row_ids.join(","))
+    }
+
+    fn check_field(&self, field: &Field) -> FlowyResult<()> {
+        match field.field_type {
+            FieldType::Formula => check_formula(&self.grid, &field.id, &field.formula),
+            FieldType::Relation if field.related_grid_id.is_empty() => {
+                Err(FlowyError::grid_record_not_found().context("The relation field has no related grid"))
+            },
+            FieldType::Rollup => self.check_rollup(&field.rollup),
+            _ => Ok(()),
+        }
+    }
+
+    // The target field is only checked if the related grid could be read.
+    fn check_rollup(&self, rollup: &RollupSetting) -> FlowyResult<()> {
+        let relation_field = self
+            .grid
+            .fields
+            .iter()
+            .find(|field| field.id == rollup.relation_field_id && field.field_type == FieldType::Relation)
+            .ok_or_else(|| FlowyError::grid_record_not_found().context("The rollup has no relation field"))?;
+        if rollup.function == RollupFunction::Count {
+            return Ok(());
+        }
+
+        match self.related_grids.get(&relation_field.related_grid_id) {
+            Some(related_grid)
+                if !related_grid
+                    .fields
+                    .iter()
+                    .any(|field| field.id == rollup.target_field_id) =>
+            {
+                Err(FlowyError::grid_record_not_found().context("The target field of the rollup doesn't exist"))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    // Computes the rollups over the changed relation fields and the formulas
+    // that depend on the changed fields, in the rows or in all the rows if it's
+    // None. The ids of the rows whose cells changed are returned.
+    fn recalculate_cells(&mut self, field_ids: &[String], row_ids: Option<&[String]>) -> Vec<String> {
+        let mut changed_field_ids = field_ids.to_vec();
+        changed_field_ids.extend(rollup_fields_of(&self.grid, field_ids));
+        let mut updated_row_ids = recalculate_rollups(&mut self.grid, &self.related_grids, &changed_field_ids, row_ids);
+        updated_row_ids.extend(recalculate(&mut self.grid, &changed_field_ids, row_ids));
+        updated_row_ids
+    }
+
+    // Like recalculate_cells, and adds the rows whose cells changed to the
+    // changeset.
+    fn recalculate_rows(&mut self, changeset: &mut GridChangeset, field_ids: &[String], row_ids: Option<&[String]>) {
+        let updated_row_ids = self.recalculate_cells(field_ids, row_ids);
+        self.push_updated_rows(changeset, updated_row_ids);
+    }
+
+    fn push_updated_rows(&self, changeset: &mut GridChangeset, row_ids: Vec<String>) {
+        for row_id in row_ids {
+            if let Ok(index) = self.row_index(&row_id) {
+                changeset.updated_rows.retain(|row| row.id != row_id);
+                changeset.updated_rows.push(self.grid.rows[index].clone());
+            }
+        }
+    }
+
+    fn row_index(&self, row_id: &str) -> FlowyResult<usize> {
+        self.grid
+            .rows
+            .iter()
+            .position(|row| row.id == row_id)
+            .ok_or_else(|| FlowyError::grid_record_not_found().context("The row doesn't exist"))
+    }
+
+    // Falls back to the first single select field if the group field of the
+    // grid was deleted or never set.
+    fn group_field(&self) -> FlowyResult<&Field> {
+        let single_select_fields = || {
+            self.grid
+                .fields
+                .iter()
+                .filter(|field| field.field_type == FieldType::SingleSelect)
+        };
+        single_select_fields()
+            .find(|field| field.id == self.grid.group_field_id)
+            .or_else(|| single_select_fields().next())
+            .ok_or_else(|| FlowyError::grid_record_not_found().context("There is no single select field to group by"))
+    }
+
+    // Falls back to the first date time field like the group field does.
+    fn date_field(&self) -> FlowyResult<&Field> {
+        let date_time_fields = || {
+            self.grid
+                .fields
+                .iter()
+                .filter(|field| field.field_type == FieldType::DateTime)
+        };
+        date_time_fields()
+            .find(|field| field.id == self.grid.date_field_id)
+            .or_else(|| date_time_fields().next())
+            .ok_or_else(|| FlowyError::grid_record_not_found().context("There is no date time field"))
+    }
+
+    fn calendar_event(&self, row: &Row, date_field: &Field) -> Option<CalendarEvent> {
+        let timestamp = row.cell(&date_field.id)?.data.parse::<i64>().ok()?;
+        let title = self
+            .grid
+            .fields
+            .iter()
+            .find(|field| field.field_type == FieldType::RichText)
+            .and_then(|field| row.cell(&field.id))
+            .map(|cell| cell.data.clone())
+            .unwrap_or_default();
+        Some(CalendarEvent {
+            row_id: row.id.clone(),
+            timestamp,
+            title,
+        })
+    }
+}
+
+// The rows whose option was removed from the field are in the empty group.
+fn group_id_of(row: &Row, group_field: &Field) -> String {
+    match row.cell(&group_field.id) {
+        Some(cell) if group_field.select_options.iter().any(|option| option.id == cell.data) => cell.data.clone(),
+        _ => "".to_owned(),
+    }
+}
+
+pub(crate) fn validate_cell_data(field: &Field, data: &str) -> FlowyResult<()> {
+    if data.is_empty() {
+        return Ok(());
+    }
+
+    let is_valid = match field.field_type {
+        FieldType::RichText => true,
+        FieldType::Number => data.parse::<f64>().map(|n| n.is_finite()).unwrap_or(false),
+        FieldType::DateTime => data.parse::<i64>().is_ok(),
+        FieldType::SingleSelect => field.select_options.iter().any(|option| option.id == data),
+        // The cells are computed, whatever the formula returns is kept.
+        FieldType::Formula | FieldType::Rollup => true,
+        // The related rows are checked against the related grid.
+        FieldType::Relation => true,
+    };
+    match is_valid {
+        true => Ok(()),
+        false => Err(FlowyError::cell_data().context(format!("{} is not a valid {:?}", data, field.field_type))),
+    }
+}
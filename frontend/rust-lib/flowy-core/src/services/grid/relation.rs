@@ -0,0 +1,146 @@
+use crate::{
+    entities::grid::{Field, FieldType, Grid, RollupFunction, Row},
+    services::grid::formula::{format_number, set_cell_data},
+};
+use std::collections::HashMap;
+
+pub(crate) fn related_row_ids(data: &str) -> Vec<&str> {
+    data.split(',')
+        .map(str::trim)
+        .filter(|row_id| !row_id.is_empty())
+        .collect()
+}
+
+// The relation fields of the grid that link to the related grid.
+pub(crate) fn relation_fields_to(grid: &Grid, related_grid_id: &str) -> Vec<String> {
+    grid.fields
+        .iter()
+        .filter(|field| field.field_type == FieldType::Relation && field.related_grid_id == related_grid_id)
+        .map(|field| field.id.clone())
+        .collect()
+}
+
+// The rollup fields that are computed over the relation fields.
+pub(crate) fn rollup_fields_of(grid: &Grid, relation_field_ids: &[String]) -> Vec<String> {
+    grid.fields
+        .iter()
+        .filter(|field| field.field_type == FieldType::Rollup)
+        .filter(|field| relation_field_ids.contains(&field.rollup.relation_field_id))
+        .map(|field| field.id.clone())
+        .collect()
+}
+
+// The rows whose relation cells link to one of the related rows.
+pub(crate) fn rows_linked_to(grid: &Grid, relation_field_ids: &[String], related_row_ids: &[String]) -> Vec<String> {
+    grid.rows
+        .iter()
+        .filter(|row| {
+            relation_field_ids
+                .iter()
+                .filter_map(|field_id| row.cell(field_id))
+                .flat_map(|cell| self::related_row_ids(&cell.data))
+                .any(|row_id| related_row_ids.iter().any(|related_row_id| related_row_id == row_id))
+        })
+        .map(|row| row.id.clone())
+        .collect()
+}
+
+// Removes the deleted rows from the relation cells, and returns the ids of the
+// rows whose cells changed.
+pub(crate) fn remove_related_rows(
+    grid: &mut Grid,
+    relation_field_ids: &[String],
+    deleted_row_ids: &[String],
+) -> Vec<String> {
+    let mut updated_row_ids = vec![];
+    for row in grid.rows.iter_mut() {
+        let mut is_updated = false;
+        for field_id in relation_field_ids {
+            let data = match row.cell(field_id) {
+                None => continue,
+                Some(cell) => related_row_ids(&cell.data)
+                    .into_iter()
+                    .filter(|row_id| !deleted_row_ids.iter().any(|deleted_row_id| deleted_row_id == row_id))
+                    .collect::<Vec<&str>>()
+                    .join(","),
+            };
+            is_updated |= set_cell_data(row, field_id, data);
+        }
+        if is_updated {
+            updated_row_ids.push(row.id.clone());
+        }
+    }
+    updated_row_ids
+}
+
+// Computes the rollup fields in the rows, or in all the rows if it's None, and
+// returns the ids of the rows whose cells changed.
+pub(crate) fn recalculate_rollups(
+    grid: &mut Grid,
+    related_grids: &HashMap<String, Grid>,
+    rollup_field_ids: &[String],
+    row_ids: Option<&[String]>,
+) -> Vec<String> {
+    let rollup_fields = grid
+        .fields
+        .iter()
+        .filter(|field| field.field_type == FieldType::Rollup && rollup_field_ids.contains(&field.id))
+        .cloned()
+        .collect::<Vec<Field>>();
+    if rollup_fields.is_empty() {
+        return vec![];
+    }
+
+    let fields = grid.fields.clone();
+    let mut updated_row_ids = vec![];
+    for row in grid.rows.iter_mut() {
+        if row_ids.map(|row_ids| !row_ids.contains(&row.id)).unwrap_or(false) {
+            continue;
+        }
+
+        let mut is_updated = false;
+        for rollup_field in rollup_fields.iter() {
+            let data = rollup(rollup_field, row, &fields, related_grids);
+            is_updated |= set_cell_data(row, &rollup_field.id, data);
+        }
+        if is_updated {
+            updated_row_ids.push(row.id.clone());
+        }
+    }
+    updated_row_ids
+}
+
+// The rows that were deleted from the related grid, or the related grid
+// itself, are not counted.
+fn rollup(rollup_field: &Field, row: &Row, fields: &[Field], related_grids: &HashMap<String, Grid>) -> String {
+    let setting = &rollup_field.rollup;
+    let related_grid = fields
+        .iter()
+        .find(|field| field.id == setting.relation_field_id && field.field_type == FieldType::Relation)
+        .and_then(|relation_field| related_grids.get(&relation_field.related_grid_id));
+    let related_grid = match related_grid {
+        None => return "".to_owned(),
+        Some(related_grid) => related_grid,
+    };
+
+    let related_rows = row
+        .cell(&setting.relation_field_id)
+        .map(|cell| related_row_ids(&cell.data))
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|row_id| related_grid.rows.iter().find(|row| row.id == row_id))
+        .collect::<Vec<&Row>>();
+    let numbers = || {
+        related_rows
+            .iter()
+            .filter_map(|row| row.cell(&setting.target_field_id))
+            .filter_map(|cell| cell.data.parse::<f64>().ok())
+    };
+    let value = match setting.function {
+        RollupFunction::Count => Some(related_rows.len() as f64),
+        RollupFunction::Sum => Some(numbers().sum()),
+        RollupFunction::Min => numbers().reduce(f64::min),
+        RollupFunction::Max => numbers().reduce(f64::max),
+    };
+    value.map(format_number).unwrap_or_default()
+}
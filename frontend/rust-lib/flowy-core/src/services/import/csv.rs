@@ -0,0 +1,320 @@
+use crate::{
+    entities::{
+        grid::{Cell, Field, FieldType, Grid, Row},
+        share::{ImportCsvParams, ImportProgress},
+        view::{CreateViewParams, View, ViewType},
+    },
+    errors::{internal_error, FlowyError, FlowyResult},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{GridController, ViewController},
+};
+use chrono::{NaiveDate, NaiveDateTime};
+use lib_infra::uuid_string;
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+    sync::Arc,
+};
+
+// The rows are written to the grid in batches, each batch is one edit of its
+// document.
+const ROW_BATCH_SIZE: usize = 500;
+
+// Imports a CSV file as a new grid. The file is read twice, record by record,
+// so it's never held in memory as a whole: the first pass checks the file,
+// counts the records and infers the types of the fields, the second one
+// appends the rows to the grid in batches.
+pub(crate) struct CsvImporter {
+    view_controller: Arc<ViewController>,
+    grid_controller: Arc<GridController>,
+}
+
+impl CsvImporter {
+    pub(crate) fn new(view_controller: Arc<ViewController>, grid_controller: Arc<GridController>) -> Self {
+        Self {
+            view_controller,
+            grid_controller,
+        }
+    }
+
+    // The `ImportProgress` notifications are sent to the app, the import id is
+    // the id of the new grid.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn import_csv(&self, params: ImportCsvParams) -> FlowyResult<View> {
+        let scan_params = params.clone();
+        let scan = tokio::task::spawn_blocking(move || {
+            let reader = CsvReader::open(&scan_params.path, scan_params.delimiter)?;
+            scan_csv(reader, scan_params.has_header)
+        })
+        .await
+        .map_err(internal_error)??;
+
+        // The file was checked by the first pass, so only a change of the file
+        // or a failed write stops the import halfway. The rows that were
+        // written before are kept.
+        let mut reader = CsvReader::open(&params.path, params.delimiter)?;
+        if params.has_header {
+            let _ = reader.read_record()?;
+        }
+        let app_id = params.app_id.clone();
+        self.create_grid(
+            &params.app_id,
+            uuid_string(),
+            view_name(&params.path),
+            &scan,
+            reader,
+            |progress| {
+                send_dart_notification(&app_id, WorkspaceNotification::ImportProgress)
+                    .payload(progress)
+                    .send()
+            },
+        )
+        .await
+    }
+
+    // Creates the grid with the fields of the scan, then appends the records
+    // that are left in the reader as its rows. `on_progress` is called after
+    // each batch.
+    pub(crate) async fn create_grid<R: BufRead>(
+        &self,
+        belong_to_id: &str,
+        view_id: String,
+        name: String,
+        scan: &CsvScan,
+        mut reader: CsvReader<R>,
+        on_progress: impl Fn(ImportProgress),
+    ) -> FlowyResult<View> {
+        let grid = Grid {
+            id: view_id.clone(),
+            fields: scan.fields.clone(),
+            ..Default::default()
+        };
+        let create_params = CreateViewParams::new(
+            belong_to_id.to_owned(),
+            name,
+            "".to_owned(),
+            ViewType::Grid,
+            "".to_owned(),
+            grid.to_delta_string(),
+            view_id,
+        );
+        let view = self.view_controller.create_imported_view(create_params).await?;
+
+        let mut progress = ImportProgress {
+            import_id: view.id.clone(),
+            finished: 0,
+            total: scan.record_count as i64,
+            current_name: view.name.clone(),
+        };
+        loop {
+            let mut rows = Vec::with_capacity(ROW_BATCH_SIZE);
+            while rows.len() < ROW_BATCH_SIZE {
+                match reader.read_record()? {
+                    None => break,
+                    Some(record) => rows.push(make_row(&scan.fields, record)),
+                }
+            }
+            if rows.is_empty() {
+                break;
+            }
+
+            progress.finished += rows.len() as i64;
+            let _ = self.grid_controller.append_rows(&view.id, rows).await?;
+            on_progress(progress.clone());
+        }
+        Ok(view)
+    }
+}
+
+pub(crate) struct CsvScan {
+    fields: Vec<Field>,
+    record_count: usize,
+}
+
+// Reads all the records, the first one is the header if `has_header` is true.
+pub(crate) fn scan_csv<R: BufRead>(mut reader: CsvReader<R>, has_header: bool) -> FlowyResult<CsvScan> {
+    let mut names = match has_header {
+        false => vec![],
+        true => reader.read_record()?.unwrap_or_default(),
+    };
+    let mut field_types: Vec<Option<FieldType>> = vec![None; names.len()];
+    let mut record_count = 0;
+    while let Some(record) = reader.read_record()? {
+        if record.len() > field_types.len() {
+            field_types.resize(record.len(), None);
+        }
+        for (field_type, data) in field_types.iter_mut().zip(record.iter()) {
+            *field_type = infer_field_type(field_type.take(), data);
+        }
+        record_count += 1;
+    }
+
+    names.resize(field_types.len(), "".to_owned());
+    let fields = names
+        .iter()
+        .zip(field_types.into_iter())
+        .enumerate()
+        .map(|(index, (name, field_type))| {
+            let name = match name.trim() {
+                "" => format!("Column {}", index + 1),
+                name => name.to_owned(),
+            };
+            Field::new(&name, field_type.unwrap_or(FieldType::RichText))
+        })
+        .collect();
+    Ok(CsvScan { fields, record_count })
+}
+
+// The type of a field is the one that all its non-empty cells fit, it falls
+// back to RichText if they don't agree.
+fn infer_field_type(field_type: Option<FieldType>, data: &str) -> Option<FieldType> {
+    if data.trim().is_empty() {
+        return field_type;
+    }
+
+    let cell_type = if parse_number(data).is_some() {
+        FieldType::Number
+    } else if parse_date(data).is_some() {
+        FieldType::DateTime
+    } else {
+        FieldType::RichText
+    };
+    match field_type {
+        None => Some(cell_type),
+        Some(field_type) if field_type == cell_type => Some(field_type),
+        Some(_) => Some(FieldType::RichText),
+    }
+}
+
+fn make_row(fields: &[Field], record: Vec<String>) -> Row {
+    let mut row = Row::new();
+    for (field, data) in fields.iter().zip(record.into_iter()) {
+        let data = match field.field_type {
+            FieldType::Number => parse_number(&data).map(|_| data.trim().to_owned()),
+            FieldType::DateTime => parse_date(&data).map(|timestamp| timestamp.to_string()),
+            _ => Some(data),
+        };
+        if let Some(data) = data.filter(|data| !data.is_empty()) {
+            row.cells.push(Cell {
+                field_id: field.id.clone(),
+                data,
+            });
+        }
+    }
+    row
+}
+
+fn parse_number(data: &str) -> Option<f64> { data.trim().parse::<f64>().ok().filter(|n| n.is_finite()) }
+
+// The dates without a time zone are taken as UTC.
+fn parse_date(data: &str) -> Option<i64> {
+    let data = data.trim().trim_end_matches('Z');
+    let date_formats = ["%Y-%m-%d", "%Y/%m/%d"];
+    if let Some(date) = date_formats
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(data, format).ok())
+    {
+        return Some(date.and_hms(0, 0, 0).timestamp());
+    }
+
+    let date_time_formats = [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+    ];
+    date_time_formats
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(data, format).ok())
+        .map(|date_time| date_time.timestamp())
+}
+
+fn view_name(path: &str) -> String {
+    Path::new(path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().trim().to_owned())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "Untitled".to_owned())
+}
+
+// Reads the records of a CSV file one at a time. The fields may be quoted,
+// the quoted fields may contain the delimiter, line breaks and doubled quotes.
+pub(crate) struct CsvReader<R> {
+    reader: R,
+    delimiter: char,
+    line: String,
+    line_number: usize,
+}
+
+impl CsvReader<BufReader<File>> {
+    pub(crate) fn open(path: &str, delimiter: char) -> FlowyResult<Self> {
+        let file = File::open(path).map_err(|e| FlowyError::import_file().context(e))?;
+        Ok(Self::new(BufReader::new(file), delimiter))
+    }
+}
+
+impl<R: BufRead> CsvReader<R> {
+    pub(crate) fn new(reader: R, delimiter: char) -> Self {
+        Self {
+            reader,
+            delimiter,
+            line: String::new(),
+            line_number: 0,
+        }
+    }
+
+    // Returns None at the end of the file. The empty lines are skipped.
+    pub(crate) fn read_record(&mut self) -> FlowyResult<Option<Vec<String>>> {
+        let mut fields = vec![];
+        let mut field = String::new();
+        let mut is_quoted = false;
+        let start_line_number = self.line_number + 1;
+        loop {
+            self.line.clear();
+            let len = self
+                .reader
+                .read_line(&mut self.line)
+                .map_err(|e| FlowyError::import_file().context(e))?;
+            if len == 0 {
+                return match is_quoted {
+                    false => Ok(None),
+                    true => Err(FlowyError::import_file()
+                        .context(format!("The quoted field on line {} is not closed", start_line_number))),
+                };
+            }
+            self.line_number += 1;
+
+            let mut line = self.line.trim_end_matches(|c| c == '\n' || c == '\r');
+            if self.line_number == 1 {
+                line = line.trim_start_matches('\u{feff}');
+            }
+            if !is_quoted && line.is_empty() {
+                continue;
+            }
+
+            let mut chars = line.chars().peekable();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' if is_quoted => match chars.peek() {
+                        Some('"') => {
+                            field.push('"');
+                            chars.next();
+                        },
+                        _ => is_quoted = false,
+                    },
+                    '"' if field.is_empty() => is_quoted = true,
+                    c if c == self.delimiter && !is_quoted => fields.push(std::mem::take(&mut field)),
+                    c => field.push(c),
+                }
+            }
+
+            if is_quoted {
+                field.push('\n');
+                continue;
+            }
+            fields.push(field);
+            return Ok(Some(fields));
+        }
+    }
+}
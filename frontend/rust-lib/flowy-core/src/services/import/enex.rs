@@ -0,0 +1,347 @@
+use crate::{
+    entities::{
+        share::{ImportEnexParams, ImportProgress},
+        view::{Attachment, CreateViewParams, RepeatedView, UpdateViewTagsParams, View, ViewType},
+    },
+    errors::{internal_error, FlowyError, FlowyResult},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{
+        import::{
+            html::{attribute, decode_entities, html_to_markdown},
+            ATTACHMENT_LINK_PREFIX,
+        },
+        ViewController,
+    },
+};
+use chrono::NaiveDateTime;
+use flowy_collaboration::document::markdown_to_delta;
+use flowy_core_data_model::parser::view::ViewTag;
+use lib_infra::{timestamp, uuid_string};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader},
+    sync::Arc,
+};
+
+const NOTE_END_TAG: &str = "</note>";
+
+// Imports the notes of an Evernote export (.enex) as documents of an app. The
+// notes keep their creation time and their tags, and the images and the other
+// files embedded in them are saved as attachments of their view.
+//
+// The export is read note by note, the attachments of a note are written
+// before the next note is read.
+pub(crate) struct EnexImporter {
+    view_controller: Arc<ViewController>,
+}
+
+impl EnexImporter {
+    pub(crate) fn new(view_controller: Arc<ViewController>) -> Self { Self { view_controller } }
+
+    // The `ImportProgress` notifications are sent to the app.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn import_enex(&self, params: ImportEnexParams) -> FlowyResult<RepeatedView> {
+        let path = params.path.clone();
+        let note_count = tokio::task::spawn_blocking(move || {
+            let mut reader = EnexReader::open(&path)?;
+            let mut note_count = 0;
+            while reader.read_note()?.is_some() {
+                note_count += 1;
+            }
+            Ok::<usize, FlowyError>(note_count)
+        })
+        .await
+        .map_err(internal_error)??;
+        if note_count == 0 {
+            return Err(FlowyError::import_file().context("The file doesn't contain any note"));
+        }
+
+        let mut progress = ImportProgress {
+            import_id: uuid_string(),
+            finished: 0,
+            total: note_count as i64,
+            current_name: "".to_owned(),
+        };
+        let mut views = vec![];
+        let mut reader = EnexReader::open(&params.path)?;
+        while let Some(xml) = reader.read_note()? {
+            let note = EnexNote::parse(&xml)?;
+            let view = self.create_note(&params.app_id, note).await?;
+
+            progress.finished += 1;
+            progress.current_name = view.name.clone();
+            send_dart_notification(&params.app_id, WorkspaceNotification::ImportProgress)
+                .payload(progress.clone())
+                .send();
+            views.push(view);
+        }
+        Ok(RepeatedView { items: views })
+    }
+
+    async fn create_note(&self, app_id: &str, note: EnexNote) -> FlowyResult<View> {
+        let view_id = uuid_string();
+        // The media of the content refer to the resources by the MD5 hash of
+        // their data.
+        let mut attachments = HashMap::new();
+        for resource in note.resources {
+            let hash = format!("{:x}", md5::compute(&resource.data));
+            let attachment = Attachment {
+                id: uuid_string(),
+                view_id: view_id.clone(),
+                name: resource.name,
+                mime_type: resource.mime_type,
+                data: resource.data,
+                create_time: note.create_time.unwrap_or_else(timestamp),
+            };
+            attachments.insert(hash, attachment);
+        }
+
+        let markdown = html_to_markdown(&replace_media(&note.content, &attachments));
+        let params = CreateViewParams::new(
+            app_id.to_owned(),
+            note.title,
+            "".to_owned(),
+            ViewType::Doc,
+            "".to_owned(),
+            markdown_to_delta(&markdown).to_json(),
+            view_id.clone(),
+        );
+        let mut view = self.view_controller.create_imported_view(params).await?;
+
+        for (_, attachment) in attachments {
+            let _ = self.view_controller.create_attachment(attachment)?;
+        }
+        if !note.tags.is_empty() {
+            let params = UpdateViewTagsParams {
+                view_id,
+                tags: note.tags,
+            };
+            let _ = self.view_controller.update_view_tags(params)?;
+        }
+        if let Some(create_time) = note.create_time {
+            let _ = self.view_controller.update_view_create_time(&view.id, create_time)?;
+            view.create_time = create_time;
+        }
+        Ok(view)
+    }
+}
+
+struct EnexNote {
+    title: String,
+    // The XHTML of the note, its root element is en-note.
+    content: String,
+    create_time: Option<i64>,
+    tags: Vec<String>,
+    resources: Vec<EnexResource>,
+}
+
+impl EnexNote {
+    fn parse(xml: &str) -> FlowyResult<Self> {
+        // The other elements are looked for outside of the content, which may
+        // contain elements of the same name, e.g. <title>.
+        let (content, xml) = split_content(xml);
+        let title = element(&xml, "title")
+            .map(text)
+            .map(|title| title.trim().to_owned())
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| "Untitled".to_owned());
+        let create_time = element(&xml, "created").and_then(|created| {
+            NaiveDateTime::parse_from_str(text(created).trim(), "%Y%m%dT%H%M%SZ")
+                .ok()
+                .map(|date_time| date_time.timestamp())
+        });
+
+        // The tags that can't be view tags are dropped instead of failing the
+        // import.
+        let mut tags: Vec<String> = vec![];
+        for tag in elements(&xml, "tag") {
+            if let Ok(tag) = ViewTag::parse(text(tag)) {
+                if !tags.contains(&tag.0) {
+                    tags.push(tag.0);
+                }
+            }
+        }
+        let resources = elements(&xml, "resource")
+            .into_iter()
+            .map(EnexResource::parse)
+            .collect::<FlowyResult<Vec<EnexResource>>>()?;
+
+        Ok(EnexNote {
+            title,
+            content: content.map(text).unwrap_or_default(),
+            create_time,
+            tags,
+            resources,
+        })
+    }
+}
+
+struct EnexResource {
+    name: String,
+    mime_type: String,
+    data: Vec<u8>,
+}
+
+impl EnexResource {
+    fn parse(xml: &str) -> FlowyResult<Self> {
+        let data = element(xml, "data")
+            .map(|data| data.chars().filter(|c| !c.is_whitespace()).collect::<String>())
+            .unwrap_or_default();
+        let data = base64::decode(&data).map_err(|e| FlowyError::import_file().context(e))?;
+        let name = element(xml, "file-name")
+            .map(text)
+            .map(|name| name.trim().to_owned())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "Untitled".to_owned());
+        Ok(EnexResource {
+            name,
+            mime_type: element(xml, "mime").map(text).unwrap_or_default(),
+            data,
+        })
+    }
+}
+
+// Replaces the media of the content with the links to their attachments.
+fn replace_media(content: &str, attachments: &HashMap<String, Attachment>) -> String {
+    let mut replaced = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find("<en-media") {
+        replaced.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = match rest.find('>') {
+            None => break,
+            Some(end) => end,
+        };
+        let attachment = attribute(&rest[1..end], "hash").and_then(|hash| attachments.get(&hash.to_lowercase()));
+        if let Some(attachment) = attachment {
+            let name = attachment
+                .name
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            replaced.push_str(&format!(
+                "<a href=\"{}{}\">{}</a>",
+                ATTACHMENT_LINK_PREFIX, attachment.id, name
+            ));
+        }
+        rest = &rest[end + 1..];
+        if let Some(after) = rest.strip_prefix("</en-media>") {
+            rest = after;
+        }
+    }
+    replaced.push_str(rest);
+    replaced
+}
+
+// Returns the inner XML of the content element, and the note without it.
+fn split_content(xml: &str) -> (Option<&str>, String) {
+    let start = match xml.find("<content>") {
+        None => return (None, xml.to_owned()),
+        Some(start) => start,
+    };
+    let inner_start = start + "<content>".len();
+    match xml[inner_start..].find("</content>") {
+        None => (None, xml.to_owned()),
+        Some(len) => {
+            let end = inner_start + len + "</content>".len();
+            let rest = format!("{}{}", &xml[..start], &xml[end..]);
+            (Some(&xml[inner_start..inner_start + len]), rest)
+        },
+    }
+}
+
+fn element<'a>(xml: &'a str, name: &str) -> Option<&'a str> { elements(xml, name).into_iter().next() }
+
+// The inner XML of the elements of the given name, in order. The elements of
+// an export are never nested in an element of the same name.
+fn elements<'a>(xml: &'a str, name: &str) -> Vec<&'a str> {
+    let start_tag = format!("<{}", name);
+    let end_tag = format!("</{}>", name);
+    let mut elements = vec![];
+    let mut rest = xml;
+    while let Some(start) = rest.find(&start_tag) {
+        rest = &rest[start + start_tag.len()..];
+        let end = match rest.find('>') {
+            None => break,
+            Some(end) => end,
+        };
+        let attributes = &rest[..end];
+        rest = &rest[end + 1..];
+        // e.g. <note-attributes> when looking for <note>.
+        if !attributes.is_empty() && !attributes.starts_with(|c: char| c.is_whitespace() || c == '/') {
+            continue;
+        }
+        if attributes.ends_with('/') {
+            elements.push("");
+            continue;
+        }
+
+        match rest.find(&end_tag) {
+            None => break,
+            Some(len) => {
+                elements.push(&rest[..len]);
+                rest = &rest[len + end_tag.len()..];
+            },
+        }
+    }
+    elements
+}
+
+fn text(xml: &str) -> String {
+    let trimmed = xml.trim();
+    match trimmed
+        .strip_prefix("<![CDATA[")
+        .and_then(|text| text.strip_suffix("]]>"))
+    {
+        Some(text) => text.to_owned(),
+        None => decode_entities(xml),
+    }
+}
+
+// Reads the notes of an export one at a time, so the attachments of all the
+// notes are never held in memory together.
+struct EnexReader<R> {
+    reader: R,
+    buffer: String,
+}
+
+impl EnexReader<BufReader<File>> {
+    fn open(path: &str) -> FlowyResult<Self> {
+        let file = File::open(path).map_err(|e| FlowyError::import_file().context(e))?;
+        Ok(Self {
+            reader: BufReader::new(file),
+            buffer: String::new(),
+        })
+    }
+}
+
+impl<R: BufRead> EnexReader<R> {
+    // Returns the XML up to the end of the next note, None at the end of the
+    // file.
+    fn read_note(&mut self) -> FlowyResult<Option<String>> {
+        let mut searched = 0;
+        loop {
+            if let Some(len) = self.buffer[searched..].find(NOTE_END_TAG) {
+                let end = searched + len + NOTE_END_TAG.len();
+                let note = self.buffer[..end].to_owned();
+                self.buffer.replace_range(..end, "");
+                return Ok(Some(note));
+            }
+
+            // Only the new line and the end of the previous one are searched
+            // next, the notes with large attachments are made of many lines.
+            searched = self.buffer.len().saturating_sub(NOTE_END_TAG.len());
+            while !self.buffer.is_char_boundary(searched) {
+                searched -= 1;
+            }
+            let len = self
+                .reader
+                .read_line(&mut self.buffer)
+                .map_err(|e| FlowyError::import_file().context(e))?;
+            if len == 0 {
+                return Ok(None);
+            }
+        }
+    }
+}
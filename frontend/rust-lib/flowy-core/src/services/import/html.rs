@@ -0,0 +1,173 @@
+// Converts HTML to Markdown, e.g. the pages of a Notion export or the notes of
+// an Evernote export. Only the elements that the document can express are
+// kept, the tables are flattened to their text.
+pub(super) fn html_to_markdown(html: &str) -> String {
+    let mut writer = HtmlWriter::default();
+    let mut rest = html;
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                let end = match rest.find('>') {
+                    None => break,
+                    Some(end) => end,
+                };
+                writer.tag(&rest[1..end]);
+                rest = &rest[end + 1..];
+            },
+            Some(start) => {
+                writer.text(&rest[..start]);
+                rest = &rest[start..];
+            },
+            None => {
+                writer.text(rest);
+                rest = "";
+            },
+        }
+    }
+    writer.output
+}
+
+#[derive(Default)]
+struct HtmlWriter {
+    output: String,
+    // The list stack, true for the ordered lists.
+    lists: Vec<bool>,
+    links: Vec<String>,
+    skip_depth: usize,
+    is_in_pre: bool,
+    is_in_code: bool,
+}
+
+impl HtmlWriter {
+    fn tag(&mut self, tag: &str) {
+        let is_end = tag.starts_with('/');
+        let tag = tag.trim_start_matches('/').trim_end_matches('/');
+        let name = tag
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        if matches!(name.as_str(), "head" | "style" | "script") {
+            match is_end {
+                false => self.skip_depth += 1,
+                true => self.skip_depth = self.skip_depth.saturating_sub(1),
+            }
+            return;
+        }
+        if self.skip_depth > 0 {
+            return;
+        }
+
+        match (name.as_str(), is_end) {
+            ("h1", false) => self.start_block("# "),
+            ("h2", false) => self.start_block("## "),
+            ("h3", false) => self.start_block("### "),
+            ("p", _) | ("div", _) | ("tr", _) | ("h1", true) | ("h2", true) | ("h3", true) => self.start_block(""),
+            ("br", _) => self.output.push('\n'),
+            ("blockquote", false) => self.start_block("> "),
+            ("ul", false) => self.lists.push(false),
+            ("ol", false) => self.lists.push(true),
+            ("ul", true) | ("ol", true) => {
+                self.lists.pop();
+                self.start_block("");
+            },
+            ("li", false) => {
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                let marker = match self.lists.last() {
+                    Some(true) => "1. ",
+                    _ => "- ",
+                };
+                self.start_block(&format!("{}{}", indent, marker));
+            },
+            ("pre", false) => {
+                self.start_block("```\n");
+                self.is_in_pre = true;
+            },
+            ("pre", true) => {
+                self.is_in_pre = false;
+                self.output.push_str("\n```\n");
+            },
+            // The checkboxes of the Evernote notes.
+            ("en-todo", false) => match attribute(tag, "checked").as_deref() {
+                Some("true") => self.start_block("- [x] "),
+                _ => self.start_block("- [ ] "),
+            },
+            ("strong", _) | ("b", _) => self.output.push_str("**"),
+            ("em", _) | ("i", _) => self.output.push('_'),
+            ("s", _) | ("del", _) => self.output.push_str("~~"),
+            ("code", _) if !self.is_in_pre => {
+                self.is_in_code = !is_end;
+                self.output.push('`');
+            },
+            ("td", true) | ("th", true) => self.output.push(' '),
+            ("a", false) => {
+                self.links.push(attribute(tag, "href").unwrap_or_default());
+                self.output.push('[');
+            },
+            ("a", true) => {
+                let link = self.links.pop().unwrap_or_default();
+                self.output.push_str(&format!("]({})", link));
+            },
+            _ => {},
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.skip_depth > 0 {
+            return;
+        }
+
+        let text = decode_entities(text);
+        if self.is_in_pre {
+            self.output.push_str(&text);
+            return;
+        }
+        // The spaces around the text are kept, they separate it from the
+        // inline elements next to it.
+        let mut collapsed = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+        if collapsed.is_empty() {
+            collapsed.push(' ');
+        } else {
+            if text.starts_with(char::is_whitespace) {
+                collapsed.insert(0, ' ');
+            }
+            if text.ends_with(char::is_whitespace) {
+                collapsed.push(' ');
+            }
+        }
+        if self.output.is_empty() || self.output.ends_with(|c| matches!(c, ' ' | '\n')) {
+            collapsed = collapsed.trim_start().to_owned();
+        }
+        for c in collapsed.chars() {
+            if !self.is_in_code && is_escapable(c) {
+                self.output.push('\\');
+            }
+            self.output.push(c);
+        }
+    }
+
+    fn start_block(&mut self, marker: &str) {
+        if !self.output.is_empty() && !self.output.ends_with('\n') {
+            self.output.push('\n');
+        }
+        self.output.push_str(marker);
+    }
+}
+
+fn is_escapable(c: char) -> bool { matches!(c, '\\' | '`' | '*' | '_' | '~' | '[' | ']' | '#') }
+
+pub(super) fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let len = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + len]))
+}
+
+pub(crate) fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,45 @@
+mod csv;
+mod enex;
+pub(crate) mod html;
+mod notion;
+mod opml;
+mod vault;
+
+pub(crate) use csv::*;
+pub(crate) use enex::*;
+pub(crate) use notion::*;
+pub(crate) use opml::*;
+pub(crate) use vault::*;
+
+// The links between the imported pages are rewritten to this scheme, followed
+// by the id of the view.
+pub(crate) const VIEW_LINK_PREFIX: &str = "appflowy://view/";
+
+// The documents link to their attachments with this scheme, followed by the
+// id of the attachment.
+pub(crate) const ATTACHMENT_LINK_PREFIX: &str = "appflowy://attachment/";
+
+// The links between the imported pages are relative paths, with their spaces
+// encoded.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = match (bytes[index], s.get(index + 1..index + 3)) {
+            (b'%', Some(hex)) => u8::from_str_radix(hex, 16).ok(),
+            _ => None,
+        };
+        match byte {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            },
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            },
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
@@ -0,0 +1,381 @@
+use crate::{
+    entities::{
+        app::{ColorStyle, CreateAppParams},
+        share::{ImportNotionParams, ImportNotionResult, ImportProgress},
+        view::{CreateViewParams, ViewType},
+    },
+    errors::{FlowyError, FlowyResult},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{
+        import::{
+            csv::{scan_csv, CsvImporter, CsvReader},
+            html::html_to_markdown,
+            percent_decode,
+            VIEW_LINK_PREFIX,
+        },
+        AppController,
+        ViewController,
+    },
+};
+use flowy_collaboration::document::markdown_to_delta;
+use flowy_database::kv::KV;
+use lib_infra::uuid_string;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::Read,
+    path::Path,
+    sync::Arc,
+};
+use zip::ZipArchive;
+
+// Imports the zip file of a Notion export. Every file of the export is a page
+// whose name ends with its Notion id, and the pages nested in it are in the
+// folder of the same name:
+//  Projects 1c2f….md           a page, in Markdown or in HTML
+//  Projects 1c2f…/Tasks 9ab0….csv
+//  Projects 1c2f…/Tasks 9ab0…/Fix the bug 77de….md
+//
+// Each top-level page becomes an app holding the page as its first view, the
+// other pages become views nested in their parent. The databases become grids
+// and the pages of their rows are nested in the grid. The attachments are not
+// imported.
+//
+// The views are created one by one, and what was created is saved after each
+// of them, so an import that was interrupted can be resumed without creating
+// the same page twice.
+pub(crate) struct NotionImporter {
+    app_controller: Arc<AppController>,
+    view_controller: Arc<ViewController>,
+    csv_importer: Arc<CsvImporter>,
+}
+
+impl NotionImporter {
+    pub(crate) fn new(
+        app_controller: Arc<AppController>,
+        view_controller: Arc<ViewController>,
+        csv_importer: Arc<CsvImporter>,
+    ) -> Self {
+        Self {
+            app_controller,
+            view_controller,
+            csv_importer,
+        }
+    }
+
+    // The `ImportProgress` notifications are sent to the workspace.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn import_notion(&self, params: ImportNotionParams) -> FlowyResult<ImportNotionResult> {
+        let (import_id, mut state) = match params.import_id {
+            None => (uuid_string(), ImportState::new(&params)),
+            Some(import_id) => match ImportState::read(&import_id) {
+                None => {
+                    return Err(FlowyError::import_file().context("The import doesn't exist or is finished"));
+                },
+                Some(state) => (import_id, state),
+            },
+        };
+
+        let file = File::open(&state.path).map_err(|e| FlowyError::import_file().context(e))?;
+        let mut archive = ZipArchive::new(file).map_err(|e| FlowyError::import_file().context(e))?;
+        let pages = read_pages(&archive);
+        for page in pages.iter() {
+            if !state.view_ids.contains_key(&page.id) {
+                state.view_ids.insert(page.id.clone(), uuid_string());
+            }
+        }
+        state.save(&import_id);
+
+        let mut progress = ImportProgress {
+            import_id: import_id.clone(),
+            finished: state.finished_page_ids.len() as i64,
+            total: pages.len() as i64,
+            current_name: "".to_owned(),
+        };
+        for page in pages.iter() {
+            if state.finished_page_ids.contains(&page.id) {
+                continue;
+            }
+
+            let _ = self.import_page(page, &mut archive, &mut state, &import_id).await?;
+            state.finished_page_ids.insert(page.id.clone());
+            state.save(&import_id);
+
+            progress.finished += 1;
+            progress.current_name = page.name.clone();
+            send_dart_notification(&state.workspace_id, WorkspaceNotification::ImportProgress)
+                .payload(progress.clone())
+                .send();
+        }
+
+        ImportState::remove(&import_id);
+        Ok(ImportNotionResult {
+            import_id,
+            page_count: pages.len() as i64,
+        })
+    }
+
+    async fn import_page(
+        &self,
+        page: &NotionPage,
+        archive: &mut ZipArchive<File>,
+        state: &mut ImportState,
+        import_id: &str,
+    ) -> FlowyResult<()> {
+        let view_id = state.view_ids[&page.id].clone();
+        // The view may have been created right before the import was
+        // interrupted.
+        if self.view_controller.read_view_tables(vec![view_id.clone()]).is_ok() {
+            return Ok(());
+        }
+
+        let belong_to_id = match &page.parent_id {
+            Some(parent_id) => state.view_ids[parent_id].clone(),
+            None => match state.app_ids.get(&page.id) {
+                Some(app_id) => app_id.clone(),
+                None => {
+                    let params = CreateAppParams {
+                        workspace_id: state.workspace_id.clone(),
+                        name: page.name.clone(),
+                        desc: "".to_owned(),
+                        color_style: ColorStyle::default(),
+                        app_id: uuid_string(),
+                    };
+                    let app = self.app_controller.create_imported_app(params).await?;
+                    state.app_ids.insert(page.id.clone(), app.id.clone());
+                    state.save(import_id);
+                    app.id
+                },
+            },
+        };
+
+        let content = read_entry(archive, &page.entry_name)?;
+        match page.kind {
+            PageKind::Database => {
+                let scan = scan_csv(CsvReader::new(content.as_slice(), ','), true)?;
+                let mut reader = CsvReader::new(content.as_slice(), ',');
+                let _ = reader.read_record()?;
+                let _ = self
+                    .csv_importer
+                    .create_grid(&belong_to_id, view_id, page.name.clone(), &scan, reader, |_| {})
+                    .await?;
+            },
+            PageKind::Markdown | PageKind::Html => {
+                let text = String::from_utf8_lossy(&content);
+                let markdown = match page.kind {
+                    PageKind::Html => html_to_markdown(&text),
+                    _ => text.to_string(),
+                };
+                let markdown = rewrite_links(&remove_title(&markdown, &page.name), &state.view_ids);
+                let params = CreateViewParams::new(
+                    belong_to_id,
+                    page.name.clone(),
+                    "".to_owned(),
+                    ViewType::Doc,
+                    "".to_owned(),
+                    markdown_to_delta(&markdown).to_json(),
+                    view_id,
+                );
+                let _ = self.view_controller.create_imported_view(params).await?;
+            },
+        }
+        Ok(())
+    }
+}
+
+// What was created by the import so far, it's saved until the import is
+// finished.
+#[derive(Serialize, Deserialize)]
+struct ImportState {
+    workspace_id: String,
+    path: String,
+    // The ids of the views that the pages are imported as, by the Notion ids
+    // of the pages. They are chosen before any view is created, so the links
+    // between the pages can be rewritten whatever the order of the pages is.
+    view_ids: HashMap<String, String>,
+    // The apps of the top-level pages.
+    app_ids: HashMap<String, String>,
+    finished_page_ids: HashSet<String>,
+}
+
+impl ImportState {
+    fn new(params: &ImportNotionParams) -> Self {
+        Self {
+            workspace_id: params.workspace_id.clone(),
+            path: params.path.clone(),
+            view_ids: HashMap::new(),
+            app_ids: HashMap::new(),
+            finished_page_ids: HashSet::new(),
+        }
+    }
+
+    fn key(import_id: &str) -> String { format!("notion_import_{}", import_id) }
+
+    fn read(import_id: &str) -> Option<Self> {
+        let json = KV::get_str(&Self::key(import_id))?;
+        serde_json::from_str(&json).ok()
+    }
+
+    fn save(&self, import_id: &str) {
+        match serde_json::to_string(self) {
+            Ok(json) => KV::set_str(&Self::key(import_id), json),
+            Err(e) => log::error!("Save the state of the import {} failed: {:?}", import_id, e),
+        }
+    }
+
+    fn remove(import_id: &str) {
+        if let Err(e) = KV::remove(&Self::key(import_id)) {
+            log::error!("Remove the state of the import {} failed: {:?}", import_id, e);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PageKind {
+    Markdown,
+    Html,
+    Database,
+}
+
+#[derive(Debug, Clone)]
+struct NotionPage {
+    id: String,
+    name: String,
+    entry_name: String,
+    kind: PageKind,
+    parent_id: Option<String>,
+}
+
+// The pages of the export, each one after its parent.
+fn read_pages(archive: &ZipArchive<File>) -> Vec<NotionPage> {
+    let mut pages: HashMap<String, NotionPage> = HashMap::new();
+    let mut parent_names = HashMap::new();
+    for entry_name in archive.file_names() {
+        let path = Path::new(entry_name);
+        let (stem, extension) = match (path.file_stem(), path.extension()) {
+            (Some(stem), Some(extension)) => (stem.to_string_lossy(), extension.to_string_lossy().to_lowercase()),
+            _ => continue,
+        };
+        let kind = match extension.as_str() {
+            "md" => PageKind::Markdown,
+            "html" => PageKind::Html,
+            "csv" => PageKind::Database,
+            _ => continue,
+        };
+
+        // The database is exported twice, the one ending with _all has the
+        // rows that its view filters out too.
+        let is_all = kind == PageKind::Database && stem.ends_with("_all");
+        let stem = stem.trim_end_matches("_all");
+        let (id, name) = match notion_id(stem) {
+            Some(id) => (id.to_lowercase(), stem[..stem.rfind(id).unwrap_or(0)].trim().to_owned()),
+            None => (entry_name.to_owned(), stem.trim().to_owned()),
+        };
+        if let Some(page) = pages.get(&id) {
+            if page.kind == PageKind::Database && !is_all {
+                continue;
+            }
+        }
+
+        let parent_name = path
+            .parent()
+            .and_then(|parent| parent.file_name())
+            .map(|name| name.to_string_lossy().to_string());
+        parent_names.insert(id.clone(), parent_name);
+        pages.insert(
+            id.clone(),
+            NotionPage {
+                id,
+                name: if name.is_empty() { "Untitled".to_owned() } else { name },
+                entry_name: entry_name.to_owned(),
+                kind,
+                parent_id: None,
+            },
+        );
+    }
+
+    // The folder of a page is named after the page, without the extension.
+    for (id, parent_name) in parent_names {
+        let parent_id = parent_name
+            .and_then(|name| notion_id(&name).map(|id| id.to_lowercase()))
+            .filter(|parent_id| parent_id != &id && pages.contains_key(parent_id));
+        if let Some(page) = pages.get_mut(&id) {
+            page.parent_id = parent_id;
+        }
+    }
+
+    let depth = |page: &NotionPage| {
+        let mut depth = 0;
+        let mut parent_id = page.parent_id.as_ref();
+        while let Some(id) = parent_id {
+            depth += 1;
+            if depth > pages.len() {
+                break;
+            }
+            parent_id = pages.get(id).and_then(|parent| parent.parent_id.as_ref());
+        }
+        depth
+    };
+    let mut pages = pages
+        .values()
+        .map(|page| (depth(page), page.clone()))
+        .collect::<Vec<_>>();
+    pages.sort_by(|(depth, page), (other_depth, other)| {
+        depth
+            .cmp(other_depth)
+            .then_with(|| page.entry_name.cmp(&other.entry_name))
+    });
+    pages.into_iter().map(|(_, page)| page).collect()
+}
+
+fn read_entry(archive: &mut ZipArchive<File>, entry_name: &str) -> FlowyResult<Vec<u8>> {
+    let mut entry = archive
+        .by_name(entry_name)
+        .map_err(|e| FlowyError::import_file().context(e))?;
+    let mut content = vec![];
+    let _ = entry.read_to_end(&mut content)?;
+    Ok(content)
+}
+
+// The last group of 32 hex digits, e.g. the id at the end of the name of a
+// page or of a notion.so link.
+fn notion_id(s: &str) -> Option<&str> {
+    s.split(|c: char| !c.is_ascii_hexdigit())
+        .filter(|part| part.len() == 32)
+        .last()
+}
+
+// The Notion pages start with their title, which is the name of the view
+// already.
+fn remove_title(markdown: &str, name: &str) -> String {
+    let mut lines = markdown.lines().skip_while(|line| line.trim().is_empty()).peekable();
+    if lines.peek().map(|line| line.trim()) == Some(&format!("# {}", name)) {
+        lines.next();
+    }
+    lines.map(|line| format!("{}\n", line)).collect()
+}
+
+// Rewrites the targets of the Markdown links that point to the imported
+// pages. The other links are kept.
+fn rewrite_links(markdown: &str, view_ids: &HashMap<String, String>) -> String {
+    let mut rewritten = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+    while let Some(start) = rest.find("](") {
+        let (before, after) = rest.split_at(start + 2);
+        rewritten.push_str(before);
+        rest = after;
+        let end = match rest.find(')') {
+            None => break,
+            Some(end) => end,
+        };
+        let target = &rest[..end];
+        match notion_id(&percent_decode(target)).and_then(|id| view_ids.get(&id.to_lowercase())) {
+            Some(view_id) => rewritten.push_str(&format!("{}{}", VIEW_LINK_PREFIX, view_id)),
+            None => rewritten.push_str(target),
+        }
+        rest = &rest[end..];
+    }
+    rewritten.push_str(rest);
+    rewritten
+}
@@ -0,0 +1,249 @@
+use crate::{
+    entities::{
+        app::{App, ColorStyle, CreateAppParams, RepeatedApp},
+        checklist::Checklist,
+        grid::Grid,
+        share::{ImportOpmlParams, ImportProgress},
+        view::{CreateViewParams, ViewType},
+    },
+    errors::{FlowyError, FlowyResult},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{import::html::decode_entities, AppController, ViewController},
+};
+use flowy_collaboration::document::default::initial_delta_string;
+use lib_infra::uuid_string;
+use std::sync::Arc;
+
+const MAX_NAME_LEN: usize = 256;
+
+// Imports an OPML outline as a folder tree: each top-level outline becomes an
+// app and the outlines nested in it become views. The views are created with
+// the type of their _viewType attribute, the ones without it are documents,
+// and their _note is their description. The outlines of the other tools are
+// imported too, their title is used when they don't have a text.
+pub(crate) struct OpmlImporter {
+    app_controller: Arc<AppController>,
+    view_controller: Arc<ViewController>,
+}
+
+impl OpmlImporter {
+    pub(crate) fn new(app_controller: Arc<AppController>, view_controller: Arc<ViewController>) -> Self {
+        Self {
+            app_controller,
+            view_controller,
+        }
+    }
+
+    // The `ImportProgress` notifications are sent to the workspace.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn import_opml(&self, params: ImportOpmlParams) -> FlowyResult<RepeatedApp> {
+        let opml = std::fs::read_to_string(&params.path).map_err(|e| FlowyError::import_file().context(e))?;
+        let outlines = parse_opml(&opml)?;
+        if outlines.is_empty() {
+            return Err(FlowyError::import_file().context("The file doesn't contain any outline"));
+        }
+
+        let mut progress = ImportProgress {
+            import_id: uuid_string(),
+            finished: 0,
+            total: outlines.iter().map(|outline| outline.count()).sum::<usize>() as i64,
+            current_name: "".to_owned(),
+        };
+        let mut apps = vec![];
+        for outline in outlines {
+            let app = self.create_app(&params.workspace_id, &outline).await?;
+            let mut stack = vec![(app.id.clone(), outline.children)];
+            while let Some((belong_to_id, children)) = stack.pop() {
+                for child in children {
+                    let view_id = self.create_view(&belong_to_id, &child).await?;
+                    progress.finished += 1;
+                    progress.current_name = child.name;
+                    send_dart_notification(&params.workspace_id, WorkspaceNotification::ImportProgress)
+                        .payload(progress.clone())
+                        .send();
+                    stack.push((view_id, child.children));
+                }
+            }
+            apps.push(app);
+        }
+        Ok(RepeatedApp { items: apps })
+    }
+
+    async fn create_app(&self, workspace_id: &str, outline: &Outline) -> FlowyResult<App> {
+        let params = CreateAppParams {
+            workspace_id: workspace_id.to_owned(),
+            name: outline.name.clone(),
+            desc: outline.note.clone(),
+            color_style: ColorStyle::default(),
+            app_id: uuid_string(),
+        };
+        self.app_controller.create_imported_app(params).await
+    }
+
+    async fn create_view(&self, belong_to_id: &str, outline: &Outline) -> FlowyResult<String> {
+        let view_id = uuid_string();
+        let view_data = match outline.view_type {
+            ViewType::Grid => Grid::new(&view_id).to_delta_string(),
+            ViewType::Board => Grid::new_board(&view_id).to_delta_string(),
+            ViewType::Calendar => Grid::new_calendar(&view_id).to_delta_string(),
+            ViewType::Checklist => Checklist::new(&view_id).to_delta_string(),
+            _ => initial_delta_string(),
+        };
+        let params = CreateViewParams::new(
+            belong_to_id.to_owned(),
+            outline.name.clone(),
+            outline.note.clone(),
+            outline.view_type.clone(),
+            "".to_owned(),
+            view_data,
+            view_id,
+        );
+        let view = self.view_controller.create_imported_view(params).await?;
+        Ok(view.id)
+    }
+}
+
+struct Outline {
+    name: String,
+    note: String,
+    view_type: ViewType,
+    children: Vec<Outline>,
+}
+
+impl Outline {
+    fn from_tag(tag: &str) -> Self {
+        let attributes = attributes(tag);
+        let value = |name: &str| {
+            attributes
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+        };
+        let name = value("text")
+            .filter(|text| !text.trim().is_empty())
+            .or_else(|| value("title"))
+            .map(|name| name.trim().chars().take(MAX_NAME_LEN).collect::<String>())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "Untitled".to_owned());
+        let view_type = match value("_viewType").as_deref() {
+            Some("grid") => ViewType::Grid,
+            Some("board") => ViewType::Board,
+            Some("calendar") => ViewType::Calendar,
+            Some("checklist") => ViewType::Checklist,
+            _ => ViewType::Doc,
+        };
+        Outline {
+            name,
+            note: value("_note").unwrap_or_default(),
+            view_type,
+            children: vec![],
+        }
+    }
+
+    // The number of outlines nested in this one.
+    fn count(&self) -> usize { self.children.iter().map(|child| 1 + child.count()).sum() }
+}
+
+// Returns the top-level outlines of the body.
+fn parse_opml(opml: &str) -> FlowyResult<Vec<Outline>> {
+    let body = opml
+        .find("<body")
+        .map(|start| &opml[start..])
+        .ok_or_else(|| FlowyError::import_file().context("The file is not an OPML outline"))?;
+
+    let mut outlines = vec![];
+    // The outlines that are open, the innermost one last.
+    let mut open: Vec<Outline> = vec![];
+    let mut rest = body;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map(|end| &comment[end + 3..]).unwrap_or("");
+            continue;
+        }
+        let end = match tag_end(rest) {
+            None => break,
+            Some(end) => end,
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with("/outline") {
+            if let Some(outline) = open.pop() {
+                match open.last_mut() {
+                    None => outlines.push(outline),
+                    Some(parent) => parent.children.push(outline),
+                }
+            }
+        } else if tag.starts_with("outline") {
+            let outline = Outline::from_tag(&tag["outline".len()..]);
+            if !tag.ends_with('/') {
+                open.push(outline);
+                continue;
+            }
+            match open.last_mut() {
+                None => outlines.push(outline),
+                Some(parent) => parent.children.push(outline),
+            }
+        } else if tag.starts_with("/body") {
+            break;
+        }
+    }
+    // The outlines that are never closed are kept.
+    while let Some(outline) = open.pop() {
+        match open.last_mut() {
+            None => outlines.push(outline),
+            Some(parent) => parent.children.push(outline),
+        }
+    }
+    Ok(outlines)
+}
+
+// The index of the '>' that ends the tag, the ones in the quoted values are
+// skipped.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(index),
+            _ => {},
+        }
+    }
+    None
+}
+
+// The attributes of a tag, their values are quoted with either double or
+// single quotes.
+fn attributes(tag: &str) -> Vec<(String, String)> {
+    let mut attributes = vec![];
+    let mut rest = tag;
+    while let Some(equal) = rest.find('=') {
+        let key = rest[..equal].trim().to_owned();
+        let value = rest[equal + 1..].trim_start();
+        let quote = match value.chars().next() {
+            Some(c) if c == '"' || c == '\'' => c,
+            _ => break,
+        };
+        let len = match value[1..].find(quote) {
+            None => break,
+            Some(len) => len,
+        };
+        attributes.push((key, decode_value(&value[1..len + 1])));
+        rest = &value[len + 2..];
+    }
+    attributes
+}
+
+// The line breaks of the notes are escaped as character references.
+fn decode_value(value: &str) -> String {
+    let value = value
+        .replace("&#10;", "\n")
+        .replace("&#xA;", "\n")
+        .replace("&#xa;", "\n")
+        .replace("&#13;", "")
+        .replace("&#9;", "\t")
+        .replace("&apos;", "'");
+    decode_entities(&value)
+}
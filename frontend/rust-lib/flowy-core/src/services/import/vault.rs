@@ -0,0 +1,426 @@
+use crate::{
+    entities::{
+        app::{App, ColorStyle, CreateAppParams, RepeatedApp},
+        share::{ImportProgress, ImportVaultParams},
+        view::{Attachment, CreateViewParams, ViewType},
+    },
+    errors::{FlowyError, FlowyResult},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{
+        import::{percent_decode, ATTACHMENT_LINK_PREFIX, VIEW_LINK_PREFIX},
+        AppController,
+        ViewController,
+    },
+};
+use flowy_collaboration::document::{default::initial_delta_string, markdown_to_delta};
+use lib_infra::{timestamp, uuid_string};
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+// Imports a folder of Markdown notes, e.g. an Obsidian vault. Each folder of
+// the vault becomes an app, and the folders nested in it become views that
+// hold the notes of the folder. The notes at the root of the vault are
+// imported in an app named after the vault.
+//
+// The wikilinks and the Markdown links to the other notes become links to
+// their views. The files that the notes embed or link to by their path, e.g.
+// the images, are saved as attachments of the note. The hidden folders, like
+// .obsidian, are skipped.
+pub(crate) struct VaultImporter {
+    app_controller: Arc<AppController>,
+    view_controller: Arc<ViewController>,
+}
+
+impl VaultImporter {
+    pub(crate) fn new(app_controller: Arc<AppController>, view_controller: Arc<ViewController>) -> Self {
+        Self {
+            app_controller,
+            view_controller,
+        }
+    }
+
+    // The `ImportProgress` notifications are sent to the workspace.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn import_vault(&self, params: ImportVaultParams) -> FlowyResult<RepeatedApp> {
+        let dir = PathBuf::from(&params.path);
+        if !dir.is_dir() {
+            return Err(FlowyError::import_file().context("The vault is not a folder"));
+        }
+        let vault_name = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Vault".to_owned());
+
+        let mut files = vec![];
+        let root = read_folder(&dir, Path::new(""), &mut files)?;
+        let vault = Vault::new(dir, files);
+        if vault.view_ids.is_empty() {
+            return Err(FlowyError::import_file().context("The vault doesn't contain any note"));
+        }
+
+        let mut progress = ImportProgress {
+            import_id: uuid_string(),
+            finished: 0,
+            total: vault.view_ids.len() as i64,
+            current_name: "".to_owned(),
+        };
+        let mut app_folders: Vec<(String, &[PathBuf], &[VaultFolder])> = root
+            .folders
+            .iter()
+            .map(|folder| (folder.name.clone(), folder.notes.as_slice(), folder.folders.as_slice()))
+            .collect();
+        if !root.notes.is_empty() {
+            app_folders.push((vault_name, root.notes.as_slice(), &[]));
+        }
+
+        let mut apps = vec![];
+        for (name, notes, folders) in app_folders {
+            let app = self.create_app(&params.workspace_id, name).await?;
+            let mut stack = vec![(app.id.clone(), notes, folders)];
+            while let Some((belong_to_id, notes, folders)) = stack.pop() {
+                for note in notes {
+                    let name = self.create_note(&vault, &belong_to_id, note).await?;
+                    progress.finished += 1;
+                    progress.current_name = name;
+                    send_dart_notification(&params.workspace_id, WorkspaceNotification::ImportProgress)
+                        .payload(progress.clone())
+                        .send();
+                }
+                for folder in folders {
+                    let view_id = self.create_folder_view(&belong_to_id, &folder.name).await?;
+                    stack.push((view_id, folder.notes.as_slice(), folder.folders.as_slice()));
+                }
+            }
+            apps.push(app);
+        }
+        Ok(RepeatedApp { items: apps })
+    }
+
+    async fn create_app(&self, workspace_id: &str, name: String) -> FlowyResult<App> {
+        let params = CreateAppParams {
+            workspace_id: workspace_id.to_owned(),
+            name,
+            desc: "".to_owned(),
+            color_style: ColorStyle::default(),
+            app_id: uuid_string(),
+        };
+        self.app_controller.create_imported_app(params).await
+    }
+
+    // The folders nested in an app are empty documents that hold the views of
+    // the notes.
+    async fn create_folder_view(&self, belong_to_id: &str, name: &str) -> FlowyResult<String> {
+        let params = CreateViewParams::new(
+            belong_to_id.to_owned(),
+            name.to_owned(),
+            "".to_owned(),
+            ViewType::Doc,
+            "".to_owned(),
+            initial_delta_string(),
+            uuid_string(),
+        );
+        let view = self.view_controller.create_imported_view(params).await?;
+        Ok(view.id)
+    }
+
+    async fn create_note(&self, vault: &Vault, belong_to_id: &str, note: &Path) -> FlowyResult<String> {
+        let markdown =
+            std::fs::read_to_string(vault.dir.join(note)).map_err(|e| FlowyError::import_file().context(e))?;
+        let mut attachment_ids = HashMap::new();
+        let markdown = vault.convert_note(&markdown, note, &mut attachment_ids);
+
+        let view_id = vault.view_ids[note].clone();
+        let name = file_stem(note);
+        let params = CreateViewParams::new(
+            belong_to_id.to_owned(),
+            name.clone(),
+            "".to_owned(),
+            ViewType::Doc,
+            "".to_owned(),
+            markdown_to_delta(&markdown).to_json(),
+            view_id.clone(),
+        );
+        let _ = self.view_controller.create_imported_view(params).await?;
+
+        for (path, attachment_id) in attachment_ids {
+            let data = std::fs::read(vault.dir.join(&path)).map_err(|e| FlowyError::import_file().context(e))?;
+            let attachment = Attachment {
+                id: attachment_id,
+                view_id: view_id.clone(),
+                name: path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                mime_type: mime_type(&path).to_owned(),
+                data,
+                create_time: timestamp(),
+            };
+            let _ = self.view_controller.create_attachment(attachment)?;
+        }
+        Ok(name)
+    }
+}
+
+struct VaultFolder {
+    name: String,
+    // The paths of the notes are relative to the vault.
+    notes: Vec<PathBuf>,
+    folders: Vec<VaultFolder>,
+}
+
+impl VaultFolder {
+    fn has_notes(&self) -> bool { !self.notes.is_empty() || self.folders.iter().any(|folder| folder.has_notes()) }
+}
+
+// Reads the folder at `relative` and the folders nested in it, the folders
+// without any note are left out. All the files are added to `files`.
+fn read_folder(vault_dir: &Path, relative: &Path, files: &mut Vec<PathBuf>) -> FlowyResult<VaultFolder> {
+    let mut entries = std::fs::read_dir(vault_dir.join(relative))?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    let mut folder = VaultFolder {
+        name: relative
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        notes: vec![],
+        folders: vec![],
+    };
+    for entry in entries {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name.starts_with('.') {
+            continue;
+        }
+
+        let path = relative.join(&file_name);
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            let nested = read_folder(vault_dir, &path, files)?;
+            if nested.has_notes() {
+                folder.folders.push(nested);
+            }
+        } else if file_type.is_file() {
+            if is_note(&path) {
+                folder.notes.push(path.clone());
+            }
+            files.push(path);
+        }
+    }
+    Ok(folder)
+}
+
+struct Vault {
+    dir: PathBuf,
+    files: Vec<PathBuf>,
+    // The ids of the views of the notes, they are chosen before any view is
+    // created so the links between the notes can be rewritten.
+    view_ids: HashMap<PathBuf, String>,
+}
+
+impl Vault {
+    fn new(dir: PathBuf, files: Vec<PathBuf>) -> Self {
+        let view_ids = files
+            .iter()
+            .filter(|path| is_note(path))
+            .map(|path| (path.clone(), uuid_string()))
+            .collect();
+        Self { dir, files, view_ids }
+    }
+
+    // Rewrites the links of the note, the code blocks are kept as they are.
+    // The files that the note links to are added to `attachment_ids`.
+    fn convert_note(&self, markdown: &str, note: &Path, attachment_ids: &mut HashMap<PathBuf, String>) -> String {
+        let mut converted = String::with_capacity(markdown.len());
+        let mut is_in_code_block = false;
+        for line in markdown.lines() {
+            if line.trim_start().starts_with("```") {
+                is_in_code_block = !is_in_code_block;
+            }
+            if is_in_code_block || line.trim_start().starts_with("```") {
+                converted.push_str(line);
+            } else {
+                let line = self.convert_wikilinks(line, note, attachment_ids);
+                converted.push_str(&self.convert_links(&line, note, attachment_ids));
+            }
+            converted.push('\n');
+        }
+        converted
+    }
+
+    // [[Note]], [[Note|text]] and [[Note#Heading]] link to a note, ![[file]]
+    // embeds a file. The wikilinks to the files that are not in the vault
+    // are kept as their text.
+    fn convert_wikilinks(&self, line: &str, note: &Path, attachment_ids: &mut HashMap<PathBuf, String>) -> String {
+        let mut converted = String::with_capacity(line.len());
+        let mut rest = line;
+        while let Some(start) = rest.find("[[") {
+            let len = match rest[start + 2..].find("]]") {
+                None => break,
+                Some(len) => len,
+            };
+            let is_embed = rest[..start].ends_with('!');
+            converted.push_str(&rest[..if is_embed { start - 1 } else { start }]);
+            let inner = &rest[start + 2..start + 2 + len];
+            rest = &rest[start + 2 + len + 2..];
+
+            let (target, alias) = match inner.find('|') {
+                Some(index) => (&inner[..index], Some(&inner[index + 1..])),
+                None => (inner, None),
+            };
+            let path = target.split('#').next().unwrap_or("").trim();
+            let text = match alias {
+                Some(alias) if !is_embed => alias.trim(),
+                _ => target.trim(),
+            };
+            match self.find_file(path, note) {
+                None => converted.push_str(text),
+                Some(path) => {
+                    let link = self.link(&path, attachment_ids);
+                    converted.push_str(&format!("[{}]({})", escape_link_text(text), link));
+                },
+            }
+        }
+        converted.push_str(rest);
+        converted
+    }
+
+    // [text](../Note.md) and ![image](images/a.png). The paths are relative to
+    // the note, or to the vault.
+    fn convert_links(&self, line: &str, note: &Path, attachment_ids: &mut HashMap<PathBuf, String>) -> String {
+        let mut converted = String::with_capacity(line.len());
+        let mut rest = line;
+        while let Some(start) = rest.find("](") {
+            converted.push_str(&rest[..start + 2]);
+            rest = &rest[start + 2..];
+            let end = match rest.find(')') {
+                None => break,
+                Some(end) => end,
+            };
+            let target = rest[..end].trim().trim_start_matches('<').trim_end_matches('>');
+            match self.resolve_path(target, note) {
+                None => converted.push_str(&rest[..end]),
+                Some(path) => {
+                    // The images become links to their attachments.
+                    if !is_note(&path) {
+                        if let Some(index) = converted.rfind('[') {
+                            if converted[..index].ends_with('!') {
+                                converted.remove(index - 1);
+                            }
+                        }
+                    }
+                    converted.push_str(&self.link(&path, attachment_ids));
+                },
+            }
+            rest = &rest[end..];
+        }
+        converted.push_str(rest);
+        converted
+    }
+
+    fn link(&self, path: &Path, attachment_ids: &mut HashMap<PathBuf, String>) -> String {
+        match self.view_ids.get(path) {
+            Some(view_id) => format!("{}{}", VIEW_LINK_PREFIX, view_id),
+            None => {
+                let attachment_id = attachment_ids.entry(path.to_path_buf()).or_insert_with(uuid_string);
+                format!("{}{}", ATTACHMENT_LINK_PREFIX, attachment_id)
+            },
+        }
+    }
+
+    // Finds the file of a wikilink the way Obsidian does: the target is the
+    // name of the file, without .md for the notes, or the end of its path.
+    // The file in the folder of the note wins if there are several.
+    fn find_file(&self, target: &str, note: &Path) -> Option<PathBuf> {
+        if target.is_empty() {
+            return None;
+        }
+
+        let target = target.to_lowercase();
+        let matches = |path: &PathBuf| {
+            let path = path_string(path).to_lowercase();
+            let name = path.strip_suffix(".md").unwrap_or(&path);
+            [path.as_str(), name]
+                .iter()
+                .any(|name| *name == target || name.ends_with(&format!("/{}", target)))
+        };
+        let mut candidates = self.files.iter().filter(|path| matches(path)).collect::<Vec<_>>();
+        candidates.sort_by_key(|path| (path.parent() != note.parent(), path.components().count()));
+        candidates.first().map(|path| path.to_path_buf())
+    }
+
+    fn resolve_path(&self, target: &str, note: &Path) -> Option<PathBuf> {
+        if target.is_empty() || target.contains("://") || target.starts_with('#') || target.starts_with("mailto:") {
+            return None;
+        }
+
+        let target = percent_decode(target);
+        let target = Path::new(&target);
+        let note_dir = note.parent().unwrap_or_else(|| Path::new(""));
+        [note_dir.join(target), target.to_path_buf()]
+            .iter()
+            .filter_map(|path| normalize(path))
+            .find(|path| self.files.contains(path))
+    }
+}
+
+// Resolves the . and .. of a relative path, None if it goes out of the vault.
+fn normalize(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {},
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            },
+            Component::Normal(name) => normalized.push(name),
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(normalized)
+}
+
+fn escape_link_text(text: &str) -> String { text.replace('[', "\\[").replace(']', "\\]") }
+
+fn is_note(path: &Path) -> bool {
+    path.extension()
+        .map(|extension| extension.to_string_lossy().eq_ignore_ascii_case("md"))
+        .unwrap_or(false)
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().trim().to_owned())
+        .filter(|stem| !stem.is_empty())
+        .unwrap_or_else(|| "Untitled".to_owned())
+}
+
+// The path with / as the separator, whatever the platform is.
+fn path_string(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+fn mime_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "pdf" => "application/pdf",
+        "mp3" => "audio/mpeg",
+        "mp4" => "video/mp4",
+        _ => "application/octet-stream",
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    entities::trash::{TrashId, TrashType},
+    errors::{FlowyError, FlowyResult},
+    module::WorkspaceDatabase,
+    services::{
+        app::sql::AppTableSql,
+        journal::sql::{JournalTable, JournalTableSql},
+        view::{acl::ViewAclTableSql, attachment::AttachmentTableSql, sql::ViewTableSql, tag::ViewTagTableSql},
+        TrashController,
+    },
+};
+use flowy_database::slow_log::SlowLogTransaction;
+use flowy_document::context::DocumentContext;
+use lib_infra::{clock::RuntimeEnv, uuid_string};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+// The objects that an operation creates, in the order they were created.
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+struct JournalObjects {
+    app_ids: Vec<String>,
+    view_ids: Vec<String>,
+}
+
+/// An operation that creates several objects, e.g. the duplicate of an app.
+/// Each object is recorded before it's written, so the ones that were written
+/// can be removed if the operation doesn't complete.
+pub(crate) struct JournalEntry {
+    id: String,
+    objects: JournalObjects,
+}
+
+/// Keeps the operations that touch the folder and the revisions of several
+/// documents all or nothing. The entry of an operation stays in the database
+/// until the operation is committed, so the entries of the operations that
+/// were cut off by a crash are rolled back on the next start.
+pub(crate) struct OperationJournal {
+    database: Arc<dyn WorkspaceDatabase>,
+    trash_controller: Arc<TrashController>,
+    document_ctx: Arc<DocumentContext>,
+    env: RuntimeEnv,
+}
+
+impl OperationJournal {
+    pub(crate) fn new(
+        database: Arc<dyn WorkspaceDatabase>,
+        trash_controller: Arc<TrashController>,
+        document_ctx: Arc<DocumentContext>,
+        env: RuntimeEnv,
+    ) -> Self {
+        Self {
+            database,
+            trash_controller,
+            document_ctx,
+            env,
+        }
+    }
+
+    pub(crate) fn begin(&self, name: &str) -> FlowyResult<JournalEntry> {
+        // The entry isn't an object of the folder, so it doesn't take an id of
+        // the generator that the objects get theirs from.
+        let entry = JournalEntry {
+            id: uuid_string(),
+            objects: JournalObjects::default(),
+        };
+        let table = JournalTable {
+            id: entry.id.clone(),
+            name: name.to_owned(),
+            objects: serde_json::to_string(&entry.objects)?,
+            create_time: self.env.timestamp(),
+        };
+        let _ = JournalTableSql::create(table, &*self.database.db_connection()?)?;
+        Ok(entry)
+    }
+
+    pub(crate) fn record_app(&self, entry: &mut JournalEntry, app_id: &str) -> FlowyResult<()> {
+        entry.objects.app_ids.push(app_id.to_owned());
+        self.save(entry)
+    }
+
+    pub(crate) fn record_view(&self, entry: &mut JournalEntry, view_id: &str) -> FlowyResult<()> {
+        entry.objects.view_ids.push(view_id.to_owned());
+        self.save(entry)
+    }
+
+    pub(crate) fn commit(&self, entry: JournalEntry) -> FlowyResult<()> {
+        JournalTableSql::delete(&entry.id, &*self.database.db_connection()?)
+    }
+
+    // The entry is kept if the rollback fails, it's tried again on the next
+    // start.
+    #[tracing::instrument(level = "debug", skip(self, entry), fields(id = %entry.id), err)]
+    pub(crate) async fn rollback(&self, entry: JournalEntry) -> FlowyResult<()> {
+        let _ = self.remove_objects(&entry.objects).await?;
+        JournalTableSql::delete(&entry.id, &*self.database.db_connection()?)
+    }
+
+    pub(crate) async fn rollback_incomplete(&self) -> FlowyResult<()> {
+        let tables = JournalTableSql::read_all(&*self.database.db_connection()?)?;
+        for table in tables {
+            tracing::info!("Roll back the incomplete {} {}", table.name, table.id);
+            let objects: JournalObjects = serde_json::from_str(&table.objects).unwrap_or_default();
+            let _ = self.rollback(JournalEntry { id: table.id, objects }).await?;
+        }
+        Ok(())
+    }
+
+    fn save(&self, entry: &JournalEntry) -> FlowyResult<()> {
+        let objects = serde_json::to_string(&entry.objects)?;
+        JournalTableSql::update_objects(&entry.id, objects, &*self.database.db_connection()?)
+    }
+
+    // The objects that made it to the local database were never in the trash,
+    // so their rows are deleted directly. Their revisions and the copies on
+    // the server are deleted too. The views go first, they belong to the apps.
+    // A recorded object may not have been written at all, or only its
+    // revisions were.
+    async fn remove_objects(&self, objects: &JournalObjects) -> FlowyResult<()> {
+        {
+            let conn = &*self.database.db_connection()?;
+            conn.timed_transaction::<_, FlowyError, _>("journal.remove_objects", || {
+                // The nested views are deleted before the views they belong to.
+                for view_id in objects.view_ids.iter().rev() {
+                    if ViewTableSql::read_belong_to_id(view_id, conn)?.is_some() {
+                        let _ = ViewTableSql::delete_view(view_id, conn)?;
+                        let _ = ViewAclTableSql::delete_view_acl(view_id, conn)?;
+                        let _ = ViewTagTableSql::delete_view_tags(view_id, conn)?;
+                        let _ = AttachmentTableSql::delete_view_attachments(view_id, conn)?;
+                    }
+                }
+                for app_id in objects.app_ids.iter().rev() {
+                    if AppTableSql::try_read_app(app_id, conn)?.is_some() {
+                        let _ = AppTableSql::delete_app(app_id, conn)?;
+                    }
+                }
+                Ok(())
+            })?;
+        }
+
+        for view_id in &objects.view_ids {
+            let _ = self.document_ctx.controller.delete(view_id)?;
+        }
+        let _ = self
+            .document_ctx
+            .controller
+            .delete_revisions(objects.view_ids.clone())
+            .await?;
+
+        let identifiers = objects
+            .view_ids
+            .iter()
+            .map(|id| TrashId {
+                id: id.clone(),
+                ty: TrashType::View,
+            })
+            .chain(objects.app_ids.iter().map(|id| TrashId {
+                id: id.clone(),
+                ty: TrashType::App,
+            }))
+            .collect::<Vec<TrashId>>();
+        if !identifiers.is_empty() {
+            let _ = self.trash_controller.delete_trash_on_server(identifiers)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::errors::FlowyError;
+use flowy_database::{
+    prelude::*,
+    schema::{op_journal_table, op_journal_table::dsl},
+    SqliteConnection,
+};
+
+pub(crate) struct JournalTableSql {}
+
+impl JournalTableSql {
+    pub(crate) fn create(table: JournalTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = diesel::insert_into(op_journal_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn update_objects(id: &str, objects: String, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::op_journal_table.filter(op_journal_table::id.eq(id));
+        let _ = diesel::update(filter)
+            .set(op_journal_table::objects.eq(objects))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_all(conn: &SqliteConnection) -> Result<Vec<JournalTable>, FlowyError> {
+        let tables = dsl::op_journal_table
+            .order(op_journal_table::create_time.asc())
+            .load::<JournalTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn delete(id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::op_journal_table.filter(op_journal_table::id.eq(id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "op_journal_table"]
+pub(crate) struct JournalTable {
+    pub id: String,
+    pub name: String,
+    pub objects: String,
+    pub create_time: i64,
+}
@@ -1,10 +1,37 @@
 pub(crate) use app::controller::*;
+pub(crate) use attachment::controller::*;
+pub(crate) use audit_log::controller::*;
+pub(crate) use backup::controller::*;
+pub(crate) use checklist::controller::*;
+pub(crate) use diagnostics::controller::*;
+pub(crate) use event_log::controller::*;
+pub(crate) use grid::controller::*;
+pub(crate) use journal::controller::*;
+pub(crate) use outbox::controller::*;
+pub(crate) use search::controller::*;
 pub(crate) use trash::controller::*;
+pub(crate) use unfurl::controller::*;
 pub(crate) use view::controller::*;
+pub(crate) use webhook::controller::*;
 pub(crate) use workspace::controller::*;
 
 pub(crate) mod app;
+pub(crate) mod attachment;
+pub(crate) mod audit_log;
+pub(crate) mod backup;
+pub(crate) mod cache;
+pub(crate) mod checklist;
+pub(crate) mod diagnostics;
+pub(crate) mod event_log;
+pub(crate) mod export;
+pub(crate) mod grid;
+pub(crate) mod import;
+pub(crate) mod journal;
+pub(crate) mod outbox;
+pub(crate) mod search;
 pub(crate) mod server;
 pub(crate) mod trash;
+pub(crate) mod unfurl;
 pub(crate) mod view;
+pub(crate) mod webhook;
 pub(crate) mod workspace;
@@ -0,0 +1,190 @@
+use crate::{
+    entities::{share::CreateShareLinkParams, workspace::CreateWorkspaceParams},
+    errors::{internal_error, FlowyError, FlowyResult, RecoveryHint},
+    module::{WorkspaceDatabase, WorkspaceUser},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{
+        outbox::sql::{NewOutboxTable, OutboxTable, OutboxTableSql},
+        server::Server,
+    },
+};
+use bytes::Bytes;
+use lib_infra::clock::RuntimeEnv;
+use std::{
+    convert::{TryFrom, TryInto},
+    sync::Arc,
+};
+
+const CREATE_WORKSPACE: &str = "create_workspace";
+const CREATE_SHARE_LINK: &str = "create_share_link";
+
+/// A call to the server that couldn't reach it. The params are saved as they
+/// were, so the replayed call is the same one.
+#[derive(Debug, Clone)]
+pub(crate) enum OutboxOp {
+    CreateWorkspace(CreateWorkspaceParams),
+    CreateShareLink(CreateShareLinkParams),
+}
+
+impl OutboxOp {
+    fn name(&self) -> &'static str {
+        match self {
+            OutboxOp::CreateWorkspace(_) => CREATE_WORKSPACE,
+            OutboxOp::CreateShareLink(_) => CREATE_SHARE_LINK,
+        }
+    }
+
+    // The id that the notifications of the operation are sent to.
+    fn object_id(&self) -> String {
+        match self {
+            OutboxOp::CreateWorkspace(params) => params.workspace_id.clone(),
+            OutboxOp::CreateShareLink(params) => params.view_id.clone(),
+        }
+    }
+
+    fn to_bytes(&self) -> FlowyResult<Bytes> {
+        let result: Result<Bytes, _> = match self.clone() {
+            OutboxOp::CreateWorkspace(params) => params.try_into(),
+            OutboxOp::CreateShareLink(params) => params.try_into(),
+        };
+        result.map_err(internal_error)
+    }
+
+    fn from_table(table: &OutboxTable) -> FlowyResult<Self> {
+        let bytes = Bytes::from(table.payload.clone());
+        match table.op.as_str() {
+            CREATE_WORKSPACE => {
+                let params = CreateWorkspaceParams::try_from(bytes).map_err(internal_error)?;
+                Ok(OutboxOp::CreateWorkspace(params))
+            },
+            CREATE_SHARE_LINK => {
+                let params = CreateShareLinkParams::try_from(bytes).map_err(internal_error)?;
+                Ok(OutboxOp::CreateShareLink(params))
+            },
+            op => Err(FlowyError::internal().context(format!("Unknown outbox operation: {}", op))),
+        }
+    }
+}
+
+/// Keeps the calls to the server that failed because it couldn't be reached,
+/// e.g. creating a workspace while offline, and replays them once it can be.
+/// They are replayed one at a time in the order they were made, and the
+/// replay stops at the first one that still can't reach the server, so a
+/// call never reaches the server before an older one.
+pub(crate) struct CloudOutbox {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    server: Server,
+    env: RuntimeEnv,
+    replaying: tokio::sync::Mutex<()>,
+}
+
+impl CloudOutbox {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        server: Server,
+        env: RuntimeEnv,
+    ) -> Self {
+        Self {
+            user,
+            database,
+            server,
+            env,
+            replaying: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    // A call that comes while older ones are waiting is queued behind them
+    // instead of being made, the older ones may create what it refers to.
+    pub(crate) fn has_pending(&self) -> FlowyResult<bool> {
+        let user_id = self.user.user_id()?;
+        let table = OutboxTableSql::read_first(&user_id, &*self.database.db_connection()?)?;
+        Ok(table.is_some())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, op), fields(op = %op.name()), err)]
+    pub(crate) fn enqueue(&self, op: OutboxOp) -> FlowyResult<()> {
+        let table = NewOutboxTable {
+            user_id: self.user.user_id()?,
+            op: op.name().to_owned(),
+            object_id: op.object_id(),
+            payload: op.to_bytes()?.to_vec(),
+            create_time: self.env.timestamp(),
+        };
+        OutboxTableSql::create(table, &*self.database.db_connection()?)
+    }
+
+    pub(crate) fn spawn_replay(self: &Arc<Self>) {
+        let outbox = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = outbox.replay().await {
+                tracing::error!("Replay the outbox failed: {}", e);
+            }
+        });
+    }
+
+    // The replays don't overlap, the one that waited takes what was queued
+    // after the other one had finished reading.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn replay(&self) -> FlowyResult<()> {
+        let _replaying = self.replaying.lock().await;
+        let user_id = match self.user.user_id() {
+            Ok(user_id) => user_id,
+            Err(_) => return Ok(()),
+        };
+        // Kept until the anonymous user signs up.
+        if self.user.is_anonymous() {
+            return Ok(());
+        }
+        let token = self.user.token()?;
+        loop {
+            let table = match OutboxTableSql::read_first(&user_id, &*self.database.db_connection()?)? {
+                None => return Ok(()),
+                Some(table) => table,
+            };
+            let result = match OutboxOp::from_table(&table) {
+                Ok(op) => self.send(&token, op).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(_) => {},
+                Err(e) if e.recovery_hint() == RecoveryHint::Retryable => {
+                    tracing::debug!("The outbox operation {} is waiting for the server: {}", table.seq, e);
+                    let conn = &*self.database.db_connection()?;
+                    return OutboxTableSql::record_failure(table.seq, &e.msg, conn);
+                },
+                // Replaying it again wouldn't help, e.g. the view of the link
+                // was deleted on another device meanwhile.
+                Err(e) => {
+                    tracing::error!("Drop the outbox operation {} {}: {}", table.op, table.seq, e);
+                    send_dart_notification(&table.object_id, WorkspaceNotification::CloudOperationFailed)
+                        .error(e)
+                        .send();
+                },
+            }
+            let _ = OutboxTableSql::delete(table.seq, &*self.database.db_connection()?)?;
+        }
+    }
+
+    async fn send(&self, token: &str, op: OutboxOp) -> FlowyResult<()> {
+        match op {
+            // The workspace has the id of the client, the server returns the
+            // existing one if it was created before the connection dropped.
+            OutboxOp::CreateWorkspace(params) => {
+                let _ = self.server.create_workspace(token, params).await?;
+            },
+            // A link has no id of the client, if the connection dropped after
+            // the server created it the replay creates a second one. Both of
+            // them work.
+            OutboxOp::CreateShareLink(params) => {
+                let view_id = params.view_id.clone();
+                let link = self.server.create_share_link(token, params).await?;
+                send_dart_notification(&view_id, WorkspaceNotification::ShareLinkCreated)
+                    .payload(link)
+                    .send();
+            },
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::errors::FlowyError;
+use flowy_database::{
+    prelude::*,
+    schema::{cloud_outbox_table, cloud_outbox_table::dsl},
+    SqliteConnection,
+};
+
+pub(crate) struct OutboxTableSql {}
+
+impl OutboxTableSql {
+    pub(crate) fn create(table: NewOutboxTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = diesel::insert_into(cloud_outbox_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // The oldest operation of the user, it's replayed before the others.
+    pub(crate) fn read_first(user_id: &str, conn: &SqliteConnection) -> Result<Option<OutboxTable>, FlowyError> {
+        let table = dsl::cloud_outbox_table
+            .filter(cloud_outbox_table::user_id.eq(user_id))
+            .order(cloud_outbox_table::seq.asc())
+            .first::<OutboxTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+
+    pub(crate) fn record_failure(seq: i64, error: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::cloud_outbox_table.filter(cloud_outbox_table::seq.eq(seq));
+        let _ = diesel::update(filter)
+            .set((
+                cloud_outbox_table::attempts.eq(cloud_outbox_table::attempts + 1),
+                cloud_outbox_table::last_error.eq(error),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete(seq: i64, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::cloud_outbox_table.filter(cloud_outbox_table::seq.eq(seq));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable)]
+pub(crate) struct OutboxTable {
+    pub seq: i64,
+    pub user_id: String,
+    pub op: String,
+    pub object_id: String,
+    pub payload: Vec<u8>,
+    pub create_time: i64,
+    pub attempts: i32,
+    pub last_error: String,
+}
+
+#[derive(Insertable)]
+#[table_name = "cloud_outbox_table"]
+pub(crate) struct NewOutboxTable {
+    pub user_id: String,
+    pub op: String,
+    pub object_id: String,
+    pub payload: Vec<u8>,
+    pub create_time: i64,
+}
@@ -0,0 +1,184 @@
+use crate::{
+    entities::{
+        search::{RepeatedWorkspaceSearchResult, SearchParams, SearchResultItem, WorkspaceSearchResult},
+        view::{View, ViewType},
+    },
+    errors::{internal_error, FlowyResult},
+    module::{WorkspaceDatabase, WorkspaceUser},
+    services::{
+        app::sql::AppTableSql,
+        get_current_workspace,
+        view::{acl::is_view_accessible, sql::ViewTableSql},
+        workspace::sql::WorkspaceTableSql,
+        TrashController,
+        ViewController,
+    },
+};
+use flowy_collaboration::util::make_delta_from_revisions;
+use flowy_database::SqliteConnection;
+use std::sync::Arc;
+
+// The number of characters that the snippet shows around the match.
+const SNIPPET_CONTEXT: usize = 40;
+
+// The documents are searched through their local revisions, so the documents
+// that were never opened on this device are only found by their names.
+pub(crate) struct SearchController {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    view_controller: Arc<ViewController>,
+    trash_controller: Arc<TrashController>,
+}
+
+impl SearchController {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        view_controller: Arc<ViewController>,
+        trash_controller: Arc<TrashController>,
+    ) -> Self {
+        Self {
+            user,
+            database,
+            view_controller,
+            trash_controller,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), err)]
+    pub(crate) fn search(&self, params: SearchParams) -> FlowyResult<RepeatedWorkspaceSearchResult> {
+        let user_id = self.user.user_id()?;
+        let workspace_id = match params.all_workspaces {
+            true => None,
+            false => Some(get_current_workspace()?),
+        };
+
+        let conn = &*self.database.db_connection()?;
+        let trash_ids = self.trash_controller.read_trash_ids(conn)?;
+        let query = params.query.to_lowercase();
+        let mut items = vec![];
+        for workspace_table in WorkspaceTableSql::read_workspaces(workspace_id, &user_id, conn)? {
+            let mut search = ViewSearch {
+                query: &query,
+                user_id: &user_id,
+                trash_ids: &trash_ids,
+                view_controller: &self.view_controller,
+                name_matches: vec![],
+                content_matches: vec![],
+            };
+            for app_table in AppTableSql::read_workspace_apps(&workspace_table.id, false, conn)? {
+                if !trash_ids.contains(&app_table.id) {
+                    let _ = search.search_views(&app_table.id, conn)?;
+                }
+            }
+
+            let mut workspace_items = search.name_matches;
+            workspace_items.extend(search.content_matches);
+            if !workspace_items.is_empty() {
+                items.push(WorkspaceSearchResult {
+                    workspace_id: workspace_table.id,
+                    workspace_name: workspace_table.name,
+                    items: workspace_items,
+                });
+            }
+        }
+        Ok(RepeatedWorkspaceSearchResult { items })
+    }
+}
+
+// The matches of the names come before the matches of the content.
+struct ViewSearch<'a> {
+    query: &'a str,
+    user_id: &'a str,
+    trash_ids: &'a [String],
+    view_controller: &'a ViewController,
+    name_matches: Vec<SearchResultItem>,
+    content_matches: Vec<SearchResultItem>,
+}
+
+impl<'a> ViewSearch<'a> {
+    fn search_views(&mut self, belong_to_id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
+        for view_table in ViewTableSql::read_views(belong_to_id, conn)? {
+            if self.trash_ids.contains(&view_table.id) || !is_view_accessible(&view_table.id, self.user_id, conn)? {
+                continue;
+            }
+
+            let view: View = view_table.into();
+            if find_ignore_case(&view.name, self.query).is_some() {
+                let snippet = view.name.clone();
+                self.name_matches.push(make_item(&view, true, snippet));
+            } else if view.view_type == ViewType::Doc {
+                if let Some(snippet) = self.search_document(&view.id)? {
+                    self.content_matches.push(make_item(&view, false, snippet));
+                }
+            }
+            let _ = self.search_views(&view.id, conn)?;
+        }
+        Ok(())
+    }
+
+    fn search_document(&self, view_id: &str) -> FlowyResult<Option<String>> {
+        let records = self.view_controller.read_revision_records(view_id)?;
+        if records.is_empty() {
+            return Ok(None);
+        }
+
+        let delta = make_delta_from_revisions(records.into_iter().map(|record| record.revision).collect())
+            .map_err(internal_error)?;
+        let text = delta.apply("").map_err(internal_error)?;
+        Ok(find_ignore_case(&text, self.query).map(|offset| make_snippet(&text, offset)))
+    }
+}
+
+fn make_item(view: &View, name_matched: bool, snippet: String) -> SearchResultItem {
+    SearchResultItem {
+        view_id: view.id.clone(),
+        view_name: view.name.clone(),
+        view_type: view.view_type.clone(),
+        belong_to_id: view.belong_to_id.clone(),
+        name_matched,
+        snippet,
+    }
+}
+
+// Returns the byte offset of the first match in `text`. `query` is lowercased
+// already.
+fn find_ignore_case(text: &str, query: &str) -> Option<usize> {
+    // The lowercase of a char may be more than one char, so the offsets are
+    // kept per char of the lowercased text.
+    let mut offsets = vec![];
+    let mut lowercased = String::with_capacity(text.len());
+    for (offset, c) in text.char_indices() {
+        for lower_c in c.to_lowercase() {
+            offsets.push(offset);
+            lowercased.push(lower_c);
+        }
+    }
+
+    let position = lowercased.find(query)?;
+    Some(offsets[lowercased[..position].chars().count()])
+}
+
+fn make_snippet(text: &str, offset: usize) -> String {
+    let start = text[..offset]
+        .char_indices()
+        .rev()
+        .take(SNIPPET_CONTEXT)
+        .last()
+        .map(|(index, _)| index)
+        .unwrap_or(offset);
+    let end = text[offset..]
+        .char_indices()
+        .nth(SNIPPET_CONTEXT * 2)
+        .map(|(index, _)| offset + index)
+        .unwrap_or_else(|| text.len());
+
+    let mut snippet = text[start..end].split_whitespace().collect::<Vec<&str>>().join(" ");
+    if start > 0 {
+        snippet.insert(0, '…');
+    }
+    if end < text.len() {
+        snippet.push('…');
+    }
+    snippet
+}
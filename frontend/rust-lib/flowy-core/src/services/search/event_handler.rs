@@ -0,0 +1,17 @@
+use crate::{
+    entities::search::{RepeatedWorkspaceSearchResult, SearchParams, SearchRequest},
+    errors::FlowyError,
+    services::SearchController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn search_handler(
+    data: Data<SearchRequest>,
+    controller: Unit<Arc<SearchController>>,
+) -> DataResult<RepeatedWorkspaceSearchResult, FlowyError> {
+    let params: SearchParams = data.into_inner().try_into()?;
+    let result = controller.search(params)?;
+    data_result(result)
+}
@@ -0,0 +1,154 @@
+use crate::{
+    entities::{
+        app::AppId,
+        folder_sync::{
+            BatchCreateParams,
+            BatchCreateResult,
+            BatchReadParams,
+            BatchReadResult,
+            BatchUpdateParams,
+            FolderDelta,
+            FolderSyncParams,
+        },
+        view::ViewId,
+        workspace::WorkspaceId,
+    },
+    errors::FlowyError,
+    services::server::{BatchServer, Server, WorkspaceServerAPIV2},
+};
+use backend_service::configuration::ClientServerConfiguration;
+use lib_infra::future::FutureResult;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+// Sends the batches to the second version of the api, or splits them into the
+// calls of the first version if the server doesn't have it. The server is
+// probed once per base url, which may be changed while the app is running.
+pub(crate) struct BatchServerAdapter {
+    v1: Server,
+    v2: Option<BatchServer>,
+    config: ClientServerConfiguration,
+    probed: Arc<RwLock<Option<(String, bool)>>>,
+}
+
+impl BatchServerAdapter {
+    pub(crate) fn new(v1: Server, v2: Option<BatchServer>, config: ClientServerConfiguration) -> Self {
+        Self {
+            v1,
+            v2,
+            config,
+            probed: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    // None if the batches have to go through the first version. A failed
+    // probe isn't remembered, e.g. the server was unreachable.
+    fn server_v2(&self) -> FutureResult<Option<BatchServer>, FlowyError> {
+        let v2 = match &self.v2 {
+            None => return FutureResult::new(async { Ok(None) }),
+            Some(v2) => v2.clone(),
+        };
+        let base_url = self.config.base_url();
+        let probed = self.probed.clone();
+        FutureResult::new(async move {
+            let cached = probed.read().clone();
+            let supported = match cached {
+                Some((url, supported)) if url == base_url => supported,
+                _ => {
+                    let supported = v2.supports_batch().await?;
+                    if !supported {
+                        tracing::info!("{} doesn't have the batch api, use the first version", base_url);
+                    }
+                    *probed.write() = Some((base_url, supported));
+                    supported
+                },
+            };
+            Ok(if supported { Some(v2) } else { None })
+        })
+    }
+}
+
+impl WorkspaceServerAPIV2 for BatchServerAdapter {
+    fn supports_batch(&self) -> FutureResult<bool, FlowyError> {
+        let server_v2 = self.server_v2();
+        FutureResult::new(async move { Ok(server_v2.await?.is_some()) })
+    }
+
+    fn batch_create(&self, token: &str, params: BatchCreateParams) -> FutureResult<BatchCreateResult, FlowyError> {
+        let (token, v1, server_v2) = (token.to_owned(), self.v1.clone(), self.server_v2());
+        FutureResult::new(async move {
+            if let Some(v2) = server_v2.await? {
+                return v2.batch_create(&token, params).await;
+            }
+
+            // The apps go first, the views of the batch may belong to them.
+            let mut result = BatchCreateResult::default();
+            for app in params.apps {
+                result.apps.items.push(v1.create_app(&token, app).await?);
+            }
+            for view in params.views {
+                result.views.items.push(v1.create_view(&token, view).await?);
+            }
+            Ok(result)
+        })
+    }
+
+    fn batch_read(&self, token: &str, params: BatchReadParams) -> FutureResult<BatchReadResult, FlowyError> {
+        let (token, v1, server_v2) = (token.to_owned(), self.v1.clone(), self.server_v2());
+        FutureResult::new(async move {
+            if let Some(v2) = server_v2.await? {
+                return v2.batch_read(&token, params).await;
+            }
+
+            let mut result = BatchReadResult::default();
+            for app_id in params.app_ids {
+                if let Some(app) = v1.read_app(&token, AppId { app_id }).await? {
+                    result.apps.items.push(app);
+                }
+            }
+            for view_id in params.view_ids {
+                if let Some(view) = v1.read_view(&token, ViewId { view_id }).await? {
+                    result.views.items.push(view);
+                }
+            }
+            Ok(result)
+        })
+    }
+
+    fn batch_update(&self, token: &str, params: BatchUpdateParams) -> FutureResult<(), FlowyError> {
+        let (token, v1, server_v2) = (token.to_owned(), self.v1.clone(), self.server_v2());
+        FutureResult::new(async move {
+            if let Some(v2) = server_v2.await? {
+                return v2.batch_update(&token, params).await;
+            }
+
+            for app in params.apps {
+                let _ = v1.update_app(&token, app).await?;
+            }
+            for view in params.views {
+                let _ = v1.update_view(&token, view).await?;
+            }
+            Ok(())
+        })
+    }
+
+    // The first version has no cursor, the whole folder is read every time.
+    fn sync_folder(&self, token: &str, params: FolderSyncParams) -> FutureResult<FolderDelta, FlowyError> {
+        let (token, v1, server_v2) = (token.to_owned(), self.v1.clone(), self.server_v2());
+        FutureResult::new(async move {
+            if let Some(v2) = server_v2.await? {
+                return v2.sync_folder(&token, params).await;
+            }
+
+            let workspaces = v1.read_workspace(&token, WorkspaceId::new(None)).await?;
+            let trash = v1.read_trash(&token).await?;
+            Ok(FolderDelta {
+                cursor: 0,
+                is_snapshot: true,
+                workspaces,
+                trash,
+                ..FolderDelta::default()
+            })
+        })
+    }
+}
@@ -1,16 +1,56 @@
+mod batch_adapter;
 mod server_api;
 mod server_api_mock;
+mod server_api_supabase;
 
+pub(crate) use batch_adapter::*;
 pub use server_api::*;
 // TODO: ignore mock files in production
 pub use server_api_mock::*;
+pub use server_api_supabase::*;
 
 use crate::{
     entities::{
         app::{App, AppId, CreateAppParams, UpdateAppParams},
+        folder_sync::{
+            BatchCreateParams,
+            BatchCreateResult,
+            BatchReadParams,
+            BatchReadResult,
+            BatchUpdateParams,
+            FolderDelta,
+            FolderSyncParams,
+        },
+        share::{
+            CreateGuestAccessParams,
+            CreateShareLinkParams,
+            GuestAccess,
+            GuestAccessId,
+            RepeatedGuestAccess,
+            RepeatedShareLink,
+            ShareLink,
+            ShareLinkId,
+            SharedDocument,
+        },
         trash::{RepeatedTrash, RepeatedTrashId},
-        view::{CreateViewParams, RepeatedViewId, UpdateViewParams, View, ViewId},
-        workspace::{CreateWorkspaceParams, RepeatedWorkspace, UpdateWorkspaceParams, Workspace, WorkspaceId},
+        view::{CreateViewParams, RepeatedViewId, UpdateViewAccessParams, UpdateViewParams, View, ViewAccess, ViewId},
+        workspace::{
+            AddWorkspaceMemberParams,
+            CreateInvitationParams,
+            CreateWorkspaceParams,
+            InvitationId,
+            QueryWorkspaceMembersParams,
+            RemoveWorkspaceMemberParams,
+            RepeatedWorkspace,
+            RepeatedWorkspaceInvitation,
+            RepeatedWorkspaceMember,
+            UpdateWorkspaceMemberParams,
+            UpdateWorkspaceParams,
+            Workspace,
+            WorkspaceId,
+            WorkspaceInvitation,
+            WorkspaceMember,
+        },
     },
     errors::FlowyError,
 };
@@ -20,9 +60,15 @@ use std::sync::Arc;
 
 pub(crate) type Server = Arc<dyn WorkspaceServerAPI + Send + Sync>;
 
+pub(crate) type BatchServer = Arc<dyn WorkspaceServerAPIV2 + Send + Sync>;
+
 pub trait WorkspaceServerAPI {
     fn init(&self);
 
+    // Succeeds if the server answers at all, the status of the response is not
+    // checked.
+    fn ping(&self) -> FutureResult<(), FlowyError>;
+
     // Workspace
     fn create_workspace(&self, token: &str, params: CreateWorkspaceParams) -> FutureResult<Workspace, FlowyError>;
 
@@ -32,6 +78,38 @@ pub trait WorkspaceServerAPI {
 
     fn delete_workspace(&self, token: &str, params: WorkspaceId) -> FutureResult<(), FlowyError>;
 
+    // Workspace member
+    fn read_workspace_members(
+        &self,
+        token: &str,
+        params: QueryWorkspaceMembersParams,
+    ) -> FutureResult<RepeatedWorkspaceMember, FlowyError>;
+
+    fn add_workspace_member(
+        &self,
+        token: &str,
+        params: AddWorkspaceMemberParams,
+    ) -> FutureResult<WorkspaceMember, FlowyError>;
+
+    fn update_workspace_member(&self, token: &str, params: UpdateWorkspaceMemberParams)
+        -> FutureResult<(), FlowyError>;
+
+    fn remove_workspace_member(&self, token: &str, params: RemoveWorkspaceMemberParams)
+        -> FutureResult<(), FlowyError>;
+
+    // Workspace invitation
+    fn create_invitation(
+        &self,
+        token: &str,
+        params: CreateInvitationParams,
+    ) -> FutureResult<WorkspaceInvitation, FlowyError>;
+
+    fn read_invitations(&self, token: &str) -> FutureResult<RepeatedWorkspaceInvitation, FlowyError>;
+
+    fn accept_invitation(&self, token: &str, params: InvitationId) -> FutureResult<Workspace, FlowyError>;
+
+    fn decline_invitation(&self, token: &str, params: InvitationId) -> FutureResult<(), FlowyError>;
+
     // View
     fn create_view(&self, token: &str, params: CreateViewParams) -> FutureResult<View, FlowyError>;
 
@@ -41,6 +119,33 @@ pub trait WorkspaceServerAPI {
 
     fn update_view(&self, token: &str, params: UpdateViewParams) -> FutureResult<(), FlowyError>;
 
+    // None if the server doesn't keep the access lists of the views.
+    fn read_view_access(&self, token: &str, params: ViewId) -> FutureResult<Option<ViewAccess>, FlowyError>;
+
+    fn grant_view_access(&self, token: &str, params: UpdateViewAccessParams) -> FutureResult<(), FlowyError>;
+
+    fn revoke_view_access(&self, token: &str, params: UpdateViewAccessParams) -> FutureResult<(), FlowyError>;
+
+    // Share link
+    fn create_share_link(&self, token: &str, params: CreateShareLinkParams) -> FutureResult<ShareLink, FlowyError>;
+
+    fn read_share_links(&self, token: &str, params: ViewId) -> FutureResult<RepeatedShareLink, FlowyError>;
+
+    fn revoke_share_link(&self, token: &str, params: ShareLinkId) -> FutureResult<(), FlowyError>;
+
+    fn read_shared_document(&self, params: ShareLinkId) -> FutureResult<SharedDocument, FlowyError>;
+
+    // Guest access
+    fn create_guest_access(
+        &self,
+        token: &str,
+        params: CreateGuestAccessParams,
+    ) -> FutureResult<GuestAccess, FlowyError>;
+
+    fn read_guest_accesses(&self, token: &str, params: ViewId) -> FutureResult<RepeatedGuestAccess, FlowyError>;
+
+    fn revoke_guest_access(&self, token: &str, params: GuestAccessId) -> FutureResult<(), FlowyError>;
+
     // App
     fn create_app(&self, token: &str, params: CreateAppParams) -> FutureResult<App, FlowyError>;
 
@@ -58,12 +163,44 @@ pub trait WorkspaceServerAPI {
     fn read_trash(&self, token: &str) -> FutureResult<RepeatedTrash, FlowyError>;
 }
 
+// The second version of the folder api. The apps and the views are created,
+// read and updated in batches, and the folder is pulled as the changes since
+// the last sync instead of every workspace at each sign in.
+pub trait WorkspaceServerAPIV2 {
+    // False if the server only has the first version, e.g. an older
+    // self-hosted one.
+    fn supports_batch(&self) -> FutureResult<bool, FlowyError>;
+
+    fn batch_create(&self, token: &str, params: BatchCreateParams) -> FutureResult<BatchCreateResult, FlowyError>;
+
+    fn batch_read(&self, token: &str, params: BatchReadParams) -> FutureResult<BatchReadResult, FlowyError>;
+
+    fn batch_update(&self, token: &str, params: BatchUpdateParams) -> FutureResult<(), FlowyError>;
+
+    fn sync_folder(&self, token: &str, params: FolderSyncParams) -> FutureResult<FolderDelta, FlowyError>;
+}
+
 pub(crate) fn construct_workspace_server(
     config: &ClientServerConfiguration,
 ) -> Arc<dyn WorkspaceServerAPI + Send + Sync> {
-    if cfg!(feature = "http_server") {
+    if let Some(supabase) = &config.supabase {
+        Arc::new(WorkspaceSupabaseServer::new(supabase.clone()))
+    } else if cfg!(feature = "http_server") {
         Arc::new(WorkspaceHttpServer::new(config.clone()))
     } else {
         Arc::new(WorkspaceServerMock {})
     }
 }
+
+// The batches go through the first version of the api if the server doesn't
+// have the second one. Supabase doesn't have it, the adapter emulates it.
+pub(crate) fn construct_batch_server(config: &ClientServerConfiguration, server: Server) -> BatchServer {
+    if config.supabase.is_some() {
+        Arc::new(BatchServerAdapter::new(server, None, config.clone()))
+    } else if cfg!(feature = "http_server") {
+        let v2: BatchServer = Arc::new(WorkspaceHttpServer::new(config.clone()));
+        Arc::new(BatchServerAdapter::new(server, Some(v2), config.clone()))
+    } else {
+        Arc::new(BatchServerAdapter::new(server, None, config.clone()))
+    }
+}
@@ -1,16 +1,55 @@
 use crate::{
     entities::{
         app::{App, AppId, CreateAppParams, UpdateAppParams},
+        folder_sync::{
+            BatchCreateParams,
+            BatchCreateResult,
+            BatchReadParams,
+            BatchReadResult,
+            BatchUpdateParams,
+            FolderDelta,
+            FolderSyncParams,
+        },
+        share::{
+            CreateGuestAccessParams,
+            CreateShareLinkParams,
+            GuestAccess,
+            GuestAccessId,
+            RepeatedGuestAccess,
+            RepeatedShareLink,
+            ShareLink,
+            ShareLinkId,
+            SharedDocument,
+        },
         trash::{RepeatedTrash, RepeatedTrashId},
-        view::{CreateViewParams, RepeatedViewId, UpdateViewParams, View, ViewId},
-        workspace::{CreateWorkspaceParams, RepeatedWorkspace, UpdateWorkspaceParams, Workspace, WorkspaceId},
+        view::{CreateViewParams, RepeatedViewId, UpdateViewAccessParams, UpdateViewParams, View, ViewAccess, ViewId},
+        workspace::{
+            AddWorkspaceMemberParams,
+            CreateInvitationParams,
+            CreateWorkspaceParams,
+            InvitationId,
+            QueryWorkspaceMembersParams,
+            RemoveWorkspaceMemberParams,
+            RepeatedWorkspace,
+            RepeatedWorkspaceInvitation,
+            RepeatedWorkspaceMember,
+            UpdateWorkspaceMemberParams,
+            UpdateWorkspaceParams,
+            Workspace,
+            WorkspaceId,
+            WorkspaceInvitation,
+            WorkspaceMember,
+        },
     },
     errors::{ErrorCode, FlowyError},
     notify::{send_dart_notification, WorkspaceNotification},
-    services::server::WorkspaceServerAPI,
+    services::server::{WorkspaceServerAPI, WorkspaceServerAPIV2},
 };
 use backend_service::{configuration::ClientServerConfiguration, http_request::*, middleware::*};
 use lib_infra::future::FutureResult;
+use std::time::Duration;
+
+const PING_TIMEOUT: Duration = Duration::from_secs(5);
 
 pub struct WorkspaceHttpServer {
     config: ClientServerConfiguration,
@@ -33,6 +72,22 @@ impl WorkspaceServerAPI for WorkspaceHttpServer {
         });
     }
 
+    fn ping(&self) -> FutureResult<(), FlowyError> {
+        let url = self.config.base_url();
+        FutureResult::new(async move {
+            let client = reqwest::Client::builder()
+                .timeout(PING_TIMEOUT)
+                .build()
+                .map_err(|e| FlowyError::new(ErrorCode::ConnectError, &e.to_string()))?;
+            let _ = client
+                .get(&url)
+                .send()
+                .await
+                .map_err(|e| FlowyError::new(ErrorCode::ConnectError, &e.to_string()))?;
+            Ok(())
+        })
+    }
+
     fn create_workspace(&self, token: &str, params: CreateWorkspaceParams) -> FutureResult<Workspace, FlowyError> {
         let token = token.to_owned();
         let url = self.config.workspace_url();
@@ -69,6 +124,98 @@ impl WorkspaceServerAPI for WorkspaceHttpServer {
         })
     }
 
+    fn read_workspace_members(
+        &self,
+        token: &str,
+        params: QueryWorkspaceMembersParams,
+    ) -> FutureResult<RepeatedWorkspaceMember, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.workspace_member_url();
+        FutureResult::new(async move {
+            let repeated_member = read_workspace_members_request(&token, params, &url).await?;
+            Ok(repeated_member)
+        })
+    }
+
+    fn add_workspace_member(
+        &self,
+        token: &str,
+        params: AddWorkspaceMemberParams,
+    ) -> FutureResult<WorkspaceMember, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.workspace_member_url();
+        FutureResult::new(async move {
+            let member = add_workspace_member_request(&token, params, &url).await?;
+            Ok(member)
+        })
+    }
+
+    fn update_workspace_member(
+        &self,
+        token: &str,
+        params: UpdateWorkspaceMemberParams,
+    ) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.workspace_member_url();
+        FutureResult::new(async move {
+            let _ = update_workspace_member_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn remove_workspace_member(
+        &self,
+        token: &str,
+        params: RemoveWorkspaceMemberParams,
+    ) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.workspace_member_url();
+        FutureResult::new(async move {
+            let _ = remove_workspace_member_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn create_invitation(
+        &self,
+        token: &str,
+        params: CreateInvitationParams,
+    ) -> FutureResult<WorkspaceInvitation, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.workspace_invitation_url();
+        FutureResult::new(async move {
+            let invitation = create_invitation_request(&token, params, &url).await?;
+            Ok(invitation)
+        })
+    }
+
+    fn read_invitations(&self, token: &str) -> FutureResult<RepeatedWorkspaceInvitation, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.workspace_invitation_url();
+        FutureResult::new(async move {
+            let repeated_invitation = read_invitations_request(&token, &url).await?;
+            Ok(repeated_invitation)
+        })
+    }
+
+    fn accept_invitation(&self, token: &str, params: InvitationId) -> FutureResult<Workspace, FlowyError> {
+        let token = token.to_owned();
+        let url = format!("{}/accept", self.config.workspace_invitation_url());
+        FutureResult::new(async move {
+            let workspace = accept_invitation_request(&token, params, &url).await?;
+            Ok(workspace)
+        })
+    }
+
+    fn decline_invitation(&self, token: &str, params: InvitationId) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = format!("{}/decline", self.config.workspace_invitation_url());
+        FutureResult::new(async move {
+            let _ = decline_invitation_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
     fn create_view(&self, token: &str, params: CreateViewParams) -> FutureResult<View, FlowyError> {
         let token = token.to_owned();
         let url = self.config.view_url();
@@ -105,6 +252,103 @@ impl WorkspaceServerAPI for WorkspaceHttpServer {
         })
     }
 
+    fn read_view_access(&self, token: &str, params: ViewId) -> FutureResult<Option<ViewAccess>, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.view_access_url();
+        FutureResult::new(async move {
+            let view_access = read_view_access_request(&token, params, &url).await?;
+            Ok(Some(view_access))
+        })
+    }
+
+    fn grant_view_access(&self, token: &str, params: UpdateViewAccessParams) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.view_access_url();
+        FutureResult::new(async move {
+            let _ = grant_view_access_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn revoke_view_access(&self, token: &str, params: UpdateViewAccessParams) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.view_access_url();
+        FutureResult::new(async move {
+            let _ = revoke_view_access_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn create_share_link(&self, token: &str, params: CreateShareLinkParams) -> FutureResult<ShareLink, FlowyError> {
+        let token = token.to_owned();
+        let config = self.config.clone();
+        FutureResult::new(async move {
+            let mut link = create_share_link_request(&token, params, &config.share_link_url()).await?;
+            link.url = config.shared_document_url(&link.id);
+            Ok(link)
+        })
+    }
+
+    fn read_share_links(&self, token: &str, params: ViewId) -> FutureResult<RepeatedShareLink, FlowyError> {
+        let token = token.to_owned();
+        let config = self.config.clone();
+        FutureResult::new(async move {
+            let mut repeated_link = read_share_links_request(&token, params, &config.share_link_url()).await?;
+            repeated_link
+                .iter_mut()
+                .for_each(|link| link.url = config.shared_document_url(&link.id));
+            Ok(repeated_link)
+        })
+    }
+
+    fn revoke_share_link(&self, token: &str, params: ShareLinkId) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.share_link_url();
+        FutureResult::new(async move {
+            let _ = revoke_share_link_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn read_shared_document(&self, params: ShareLinkId) -> FutureResult<SharedDocument, FlowyError> {
+        let url = self.config.shared_document_url(&params.link_id);
+        FutureResult::new(async move {
+            let document = read_shared_document_request(&url).await?;
+            Ok(document)
+        })
+    }
+
+    fn create_guest_access(
+        &self,
+        token: &str,
+        params: CreateGuestAccessParams,
+    ) -> FutureResult<GuestAccess, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.view_guest_url();
+        FutureResult::new(async move {
+            let access = create_guest_access_request(&token, params, &url).await?;
+            Ok(access)
+        })
+    }
+
+    fn read_guest_accesses(&self, token: &str, params: ViewId) -> FutureResult<RepeatedGuestAccess, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.view_guest_url();
+        FutureResult::new(async move {
+            let repeated_access = read_guest_accesses_request(&token, params, &url).await?;
+            Ok(repeated_access)
+        })
+    }
+
+    fn revoke_guest_access(&self, token: &str, params: GuestAccessId) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.view_guest_url();
+        FutureResult::new(async move {
+            let _ = revoke_guest_access_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
     fn create_app(&self, token: &str, params: CreateAppParams) -> FutureResult<App, FlowyError> {
         let token = token.to_owned();
         let url = self.config.app_url();
@@ -168,3 +412,59 @@ impl WorkspaceServerAPI for WorkspaceHttpServer {
         })
     }
 }
+
+impl WorkspaceServerAPIV2 for WorkspaceHttpServer {
+    // The older servers don't have the route, any other answer means it's
+    // there, e.g. the unauthorized error of the request without a token.
+    fn supports_batch(&self) -> FutureResult<bool, FlowyError> {
+        let url = self.config.folder_sync_url();
+        FutureResult::new(async move {
+            let client = reqwest::Client::builder()
+                .timeout(PING_TIMEOUT)
+                .build()
+                .map_err(|e| FlowyError::new(ErrorCode::ConnectError, &e.to_string()))?;
+            let response = client
+                .get(&url)
+                .send()
+                .await
+                .map_err(|e| FlowyError::new(ErrorCode::ConnectError, &e.to_string()))?;
+            Ok(response.status() != reqwest::StatusCode::NOT_FOUND)
+        })
+    }
+
+    fn batch_create(&self, token: &str, params: BatchCreateParams) -> FutureResult<BatchCreateResult, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.folder_batch_url();
+        FutureResult::new(async move {
+            let result = batch_create_request(&token, params, &url).await?;
+            Ok(result)
+        })
+    }
+
+    fn batch_read(&self, token: &str, params: BatchReadParams) -> FutureResult<BatchReadResult, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.folder_batch_url();
+        FutureResult::new(async move {
+            let result = batch_read_request(&token, params, &url).await?;
+            Ok(result)
+        })
+    }
+
+    fn batch_update(&self, token: &str, params: BatchUpdateParams) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.folder_batch_url();
+        FutureResult::new(async move {
+            let _ = batch_update_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn sync_folder(&self, token: &str, params: FolderSyncParams) -> FutureResult<FolderDelta, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.folder_sync_url();
+        FutureResult::new(async move {
+            let delta = sync_folder_request(&token, params, &url).await?;
+            Ok(delta)
+        })
+    }
+}
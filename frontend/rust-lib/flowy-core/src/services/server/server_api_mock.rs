@@ -1,9 +1,47 @@
 use crate::{
     entities::{
         app::{App, AppId, CreateAppParams, RepeatedApp, UpdateAppParams},
+        share::{
+            CreateGuestAccessParams,
+            CreateShareLinkParams,
+            GuestAccess,
+            GuestAccessId,
+            RepeatedGuestAccess,
+            RepeatedShareLink,
+            ShareLink,
+            ShareLinkId,
+            SharedDocument,
+        },
         trash::{RepeatedTrash, RepeatedTrashId},
-        view::{CreateViewParams, RepeatedView, RepeatedViewId, UpdateViewParams, View, ViewId},
-        workspace::{CreateWorkspaceParams, RepeatedWorkspace, UpdateWorkspaceParams, Workspace, WorkspaceId},
+        view::{
+            CreateViewParams,
+            RepeatedView,
+            RepeatedViewId,
+            UpdateViewAccessParams,
+            UpdateViewParams,
+            View,
+            ViewAccess,
+            ViewId,
+        },
+        workspace::{
+            AddWorkspaceMemberParams,
+            CreateInvitationParams,
+            CreateWorkspaceParams,
+            InvitationId,
+            InvitationStatus,
+            QueryWorkspaceMembersParams,
+            RemoveWorkspaceMemberParams,
+            RepeatedWorkspace,
+            RepeatedWorkspaceInvitation,
+            RepeatedWorkspaceMember,
+            UpdateWorkspaceMemberParams,
+            UpdateWorkspaceParams,
+            Workspace,
+            WorkspaceId,
+            WorkspaceInvitation,
+            WorkspaceMember,
+            WorkspaceRole,
+        },
     },
     errors::FlowyError,
     services::server::WorkspaceServerAPI,
@@ -15,15 +53,23 @@ pub struct WorkspaceServerMock {}
 impl WorkspaceServerAPI for WorkspaceServerMock {
     fn init(&self) {}
 
+    fn ping(&self) -> FutureResult<(), FlowyError> { FutureResult::new(async { Ok(()) }) }
+
     fn create_workspace(&self, _token: &str, params: CreateWorkspaceParams) -> FutureResult<Workspace, FlowyError> {
         let time = timestamp();
+        let id = match params.workspace_id.is_empty() {
+            true => uuid_string(),
+            false => params.workspace_id,
+        };
         let workspace = Workspace {
-            id: uuid_string(),
+            id,
             name: params.name,
             desc: params.desc,
             apps: RepeatedApp::default(),
             modified_time: time,
             create_time: time,
+            role: WorkspaceRole::Owner,
+            settings: None,
         };
 
         FutureResult::new(async { Ok(workspace) })
@@ -44,6 +90,85 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
         FutureResult::new(async { Ok(()) })
     }
 
+    fn read_workspace_members(
+        &self,
+        _token: &str,
+        _params: QueryWorkspaceMembersParams,
+    ) -> FutureResult<RepeatedWorkspaceMember, FlowyError> {
+        FutureResult::new(async {
+            let repeated_member = RepeatedWorkspaceMember { items: vec![] };
+            Ok(repeated_member)
+        })
+    }
+
+    fn add_workspace_member(
+        &self,
+        _token: &str,
+        params: AddWorkspaceMemberParams,
+    ) -> FutureResult<WorkspaceMember, FlowyError> {
+        let member = WorkspaceMember {
+            workspace_id: params.workspace_id,
+            user_id: uuid_string(),
+            name: "".to_owned(),
+            email: params.email,
+            role: params.role,
+        };
+        FutureResult::new(async { Ok(member) })
+    }
+
+    fn update_workspace_member(
+        &self,
+        _token: &str,
+        _params: UpdateWorkspaceMemberParams,
+    ) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
+    fn remove_workspace_member(
+        &self,
+        _token: &str,
+        _params: RemoveWorkspaceMemberParams,
+    ) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
+    fn create_invitation(
+        &self,
+        _token: &str,
+        params: CreateInvitationParams,
+    ) -> FutureResult<WorkspaceInvitation, FlowyError> {
+        let invitation = WorkspaceInvitation {
+            id: uuid_string(),
+            workspace_id: params.workspace_id,
+            workspace_name: "".to_owned(),
+            inviter_name: "".to_owned(),
+            email: params.email,
+            role: params.role,
+            status: InvitationStatus::Pending,
+            create_time: timestamp(),
+        };
+        FutureResult::new(async { Ok(invitation) })
+    }
+
+    fn read_invitations(&self, _token: &str) -> FutureResult<RepeatedWorkspaceInvitation, FlowyError> {
+        FutureResult::new(async {
+            let repeated_invitation = RepeatedWorkspaceInvitation { items: vec![] };
+            Ok(repeated_invitation)
+        })
+    }
+
+    // The mock server doesn't keep the invitations, so there is nothing to
+    // accept.
+    fn accept_invitation(&self, _token: &str, params: InvitationId) -> FutureResult<Workspace, FlowyError> {
+        FutureResult::new(async move {
+            Err(FlowyError::record_not_found().context(format!("Invitation {} not exist", params.invitation_id)))
+        })
+    }
+
+    fn decline_invitation(&self, _token: &str, _params: InvitationId) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
     fn create_view(&self, _token: &str, params: CreateViewParams) -> FutureResult<View, FlowyError> {
         let time = timestamp();
         let view = View {
@@ -72,10 +197,81 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
         FutureResult::new(async { Ok(()) })
     }
 
+    fn read_view_access(&self, _token: &str, _params: ViewId) -> FutureResult<Option<ViewAccess>, FlowyError> {
+        FutureResult::new(async { Ok(None) })
+    }
+
+    fn grant_view_access(&self, _token: &str, _params: UpdateViewAccessParams) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
+    fn revoke_view_access(&self, _token: &str, _params: UpdateViewAccessParams) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
+    fn create_share_link(&self, _token: &str, params: CreateShareLinkParams) -> FutureResult<ShareLink, FlowyError> {
+        let id = uuid_string();
+        let link = ShareLink {
+            url: format!("mock://shared/{}", id),
+            id,
+            view_id: params.view_id,
+            permission: params.permission,
+            create_time: timestamp(),
+            expire_time: params.expire_time,
+        };
+        FutureResult::new(async { Ok(link) })
+    }
+
+    fn read_share_links(&self, _token: &str, _params: ViewId) -> FutureResult<RepeatedShareLink, FlowyError> {
+        FutureResult::new(async {
+            let repeated_link = RepeatedShareLink { items: vec![] };
+            Ok(repeated_link)
+        })
+    }
+
+    fn revoke_share_link(&self, _token: &str, _params: ShareLinkId) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
+    fn read_shared_document(&self, _params: ShareLinkId) -> FutureResult<SharedDocument, FlowyError> {
+        FutureResult::new(async { Err(FlowyError::record_not_found()) })
+    }
+
+    fn create_guest_access(
+        &self,
+        _token: &str,
+        params: CreateGuestAccessParams,
+    ) -> FutureResult<GuestAccess, FlowyError> {
+        let access = GuestAccess {
+            id: uuid_string(),
+            view_id: params.view_id,
+            email: params.email,
+            permission: params.permission,
+            create_time: timestamp(),
+            token: uuid_string(),
+        };
+        FutureResult::new(async { Ok(access) })
+    }
+
+    fn read_guest_accesses(&self, _token: &str, _params: ViewId) -> FutureResult<RepeatedGuestAccess, FlowyError> {
+        FutureResult::new(async {
+            let repeated_access = RepeatedGuestAccess { items: vec![] };
+            Ok(repeated_access)
+        })
+    }
+
+    fn revoke_guest_access(&self, _token: &str, _params: GuestAccessId) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
     fn create_app(&self, _token: &str, params: CreateAppParams) -> FutureResult<App, FlowyError> {
         let time = timestamp();
+        let id = match params.app_id.is_empty() {
+            true => uuid_string(),
+            false => params.app_id,
+        };
         let app = App {
-            id: uuid_string(),
+            id,
             workspace_id: params.workspace_id,
             name: params.name,
             desc: params.desc,
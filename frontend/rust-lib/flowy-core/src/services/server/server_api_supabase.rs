@@ -0,0 +1,759 @@
+use crate::{
+    entities::{
+        app::{App, AppId, ColorStyle, CreateAppParams, RepeatedApp, UpdateAppParams},
+        share::{
+            CreateGuestAccessParams,
+            CreateShareLinkParams,
+            GuestAccess,
+            GuestAccessId,
+            RepeatedGuestAccess,
+            RepeatedShareLink,
+            ShareLink,
+            ShareLinkId,
+            SharedDocument,
+        },
+        trash::{RepeatedTrash, RepeatedTrashId, Trash, TrashType},
+        view::{
+            CreateViewParams,
+            RepeatedView,
+            RepeatedViewId,
+            UpdateViewAccessParams,
+            UpdateViewParams,
+            View,
+            ViewAccess,
+            ViewId,
+        },
+        workspace::{
+            AddWorkspaceMemberParams,
+            CreateInvitationParams,
+            CreateWorkspaceParams,
+            InvitationId,
+            QueryWorkspaceMembersParams,
+            RemoveWorkspaceMemberParams,
+            RepeatedWorkspace,
+            RepeatedWorkspaceInvitation,
+            RepeatedWorkspaceMember,
+            RetentionKind,
+            UpdateWorkspaceMemberParams,
+            UpdateWorkspaceParams,
+            Workspace,
+            WorkspaceId,
+            WorkspaceInvitation,
+            WorkspaceMember,
+            WorkspaceRole,
+            WorkspaceSettings,
+        },
+    },
+    errors::FlowyError,
+    services::server::WorkspaceServerAPI,
+};
+use backend_service::{configuration::SupabaseConfiguration, supabase::SupabaseClient};
+use lib_infra::{future::FutureResult, timestamp, uuid_string};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::convert::TryFrom;
+
+const WORKSPACE_TABLE: &str = "af_workspace";
+const APP_TABLE: &str = "af_app";
+const VIEW_TABLE: &str = "af_view";
+const DOCUMENT_TABLE: &str = "af_document";
+const TRASH_TABLE: &str = "af_trash";
+// The trash joined with the names of the views, the apps and the workspaces.
+const TRASH_ITEM_VIEW: &str = "af_trash_item";
+
+// The folder in the tables of a Supabase project, see supabase/schema.sql of
+// the backend. The workspaces are private to the user who created them, the
+// sharing features of the AppFlowy server aren't there.
+pub struct WorkspaceSupabaseServer {
+    client: SupabaseClient,
+}
+
+impl WorkspaceSupabaseServer {
+    pub fn new(config: SupabaseConfiguration) -> Self {
+        Self {
+            client: SupabaseClient::new(config),
+        }
+    }
+}
+
+impl WorkspaceServerAPI for WorkspaceSupabaseServer {
+    fn init(&self) {}
+
+    fn ping(&self) -> FutureResult<(), FlowyError> {
+        let client = self.client.clone();
+        FutureResult::new(async move {
+            let _ = client.ping().await?;
+            Ok(())
+        })
+    }
+
+    fn create_workspace(&self, token: &str, params: CreateWorkspaceParams) -> FutureResult<Workspace, FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let time = timestamp();
+            // The id is generated by the client, a retried create upserts the
+            // same row.
+            let row = WorkspaceRow {
+                id: params.workspace_id,
+                name: params.name,
+                description: params.desc,
+                create_time: time,
+                modified_time: time,
+                settings: None,
+            };
+            let _ = client.upsert(&token, WORKSPACE_TABLE, &row).await?;
+            Ok(row.into())
+        })
+    }
+
+    fn read_workspace(&self, token: &str, params: WorkspaceId) -> FutureResult<RepeatedWorkspace, FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let query = match params.workspace_id {
+                None => "order=create_time.asc".to_owned(),
+                Some(workspace_id) => format!("id=eq.{}", workspace_id),
+            };
+            let rows: Vec<WorkspaceRow> = client.select(&token, WORKSPACE_TABLE, &query).await?;
+            let trash_query = format!("ty=eq.{}", TrashType::Workspace as i32);
+            let trash: Vec<TrashRow> = client.select(&token, TRASH_ITEM_VIEW, &trash_query).await?;
+            let mut workspaces = rows
+                .into_iter()
+                .filter(|row| trash.iter().all(|item| item.id != row.id))
+                .map(Workspace::from)
+                .collect::<Vec<_>>();
+            if workspaces.is_empty() {
+                return Ok(RepeatedWorkspace { items: workspaces });
+            }
+            let workspace_ids = workspaces
+                .iter()
+                .map(|workspace| workspace.id.clone())
+                .collect::<Vec<_>>();
+            let mut apps = read_apps(
+                &client,
+                &token,
+                &format!("workspace_id=in.({})", workspace_ids.join(",")),
+            )
+            .await?;
+            for workspace in workspaces.iter_mut() {
+                let (belongings, others) = apps.into_iter().partition(|app| app.workspace_id == workspace.id);
+                workspace.apps = RepeatedApp { items: belongings };
+                apps = others;
+            }
+            Ok(RepeatedWorkspace { items: workspaces })
+        })
+    }
+
+    fn update_workspace(&self, token: &str, params: UpdateWorkspaceParams) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let mut changes = changeset();
+            set_if_some(&mut changes, "name", params.name);
+            set_if_some(&mut changes, "description", params.desc);
+            if let Some(settings) = params.settings {
+                changes.insert("settings".to_owned(), json!(SettingsColumn::from(settings)));
+            }
+            let filter = format!("id=eq.{}", params.id);
+            let _ = client.update(&token, WORKSPACE_TABLE, &filter, &changes).await?;
+            Ok(())
+        })
+    }
+
+    fn delete_workspace(&self, token: &str, params: WorkspaceId) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let workspace_id = params.workspace_id.ok_or_else(FlowyError::workspace_id)?;
+            let _ = client
+                .delete(&token, WORKSPACE_TABLE, &format!("id=eq.{}", workspace_id))
+                .await?;
+            Ok(())
+        })
+    }
+
+    fn read_workspace_members(
+        &self,
+        _token: &str,
+        _params: QueryWorkspaceMembersParams,
+    ) -> FutureResult<RepeatedWorkspaceMember, FlowyError> {
+        unsupported("workspace members")
+    }
+
+    fn add_workspace_member(
+        &self,
+        _token: &str,
+        _params: AddWorkspaceMemberParams,
+    ) -> FutureResult<WorkspaceMember, FlowyError> {
+        unsupported("workspace members")
+    }
+
+    fn update_workspace_member(
+        &self,
+        _token: &str,
+        _params: UpdateWorkspaceMemberParams,
+    ) -> FutureResult<(), FlowyError> {
+        unsupported("workspace members")
+    }
+
+    fn remove_workspace_member(
+        &self,
+        _token: &str,
+        _params: RemoveWorkspaceMemberParams,
+    ) -> FutureResult<(), FlowyError> {
+        unsupported("workspace members")
+    }
+
+    fn create_invitation(
+        &self,
+        _token: &str,
+        _params: CreateInvitationParams,
+    ) -> FutureResult<WorkspaceInvitation, FlowyError> {
+        unsupported("workspace invitations")
+    }
+
+    fn read_invitations(&self, _token: &str) -> FutureResult<RepeatedWorkspaceInvitation, FlowyError> {
+        unsupported("workspace invitations")
+    }
+
+    fn accept_invitation(&self, _token: &str, _params: InvitationId) -> FutureResult<Workspace, FlowyError> {
+        unsupported("workspace invitations")
+    }
+
+    fn decline_invitation(&self, _token: &str, _params: InvitationId) -> FutureResult<(), FlowyError> {
+        unsupported("workspace invitations")
+    }
+
+    // The document of the view is created with it, like the AppFlowy server
+    // does.
+    fn create_view(&self, token: &str, params: CreateViewParams) -> FutureResult<View, FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let time = timestamp();
+            let row = ViewRow {
+                id: match params.view_id.is_empty() {
+                    true => uuid_string(),
+                    false => params.view_id,
+                },
+                belong_to_id: params.belong_to_id,
+                name: params.name,
+                description: params.desc,
+                thumbnail: params.thumbnail,
+                view_type: params.view_type as i32,
+                version: 0,
+                create_time: time,
+                modified_time: time,
+            };
+            let _ = client.upsert(&token, VIEW_TABLE, &row).await?;
+            let document = json!({ "id": row.id, "data": params.view_data, "rev_id": 0, "base_rev_id": 0 });
+            let _ = client.upsert(&token, DOCUMENT_TABLE, &document).await?;
+            Ok(row.into())
+        })
+    }
+
+    fn read_view(&self, token: &str, params: ViewId) -> FutureResult<Option<View>, FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let query = format!("id=eq.{}", params.view_id);
+            let mut view = match read_views(&client, &token, &query).await?.pop() {
+                None => return Ok(None),
+                Some(view) => view,
+            };
+            let belongings = read_views(&client, &token, &format!("belong_to_id=eq.{}", view.id)).await?;
+            view.belongings = RepeatedView { items: belongings };
+            Ok(Some(view))
+        })
+    }
+
+    fn delete_view(&self, token: &str, params: RepeatedViewId) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let filter = format!("id=in.({})", params.items.join(","));
+            let _ = client.delete(&token, VIEW_TABLE, &filter).await?;
+            Ok(())
+        })
+    }
+
+    fn update_view(&self, token: &str, params: UpdateViewParams) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let mut changes = changeset();
+            set_if_some(&mut changes, "name", params.name);
+            set_if_some(&mut changes, "description", params.desc);
+            set_if_some(&mut changes, "thumbnail", params.thumbnail);
+            set_if_some(&mut changes, "belong_to_id", params.belong_to_id);
+            let filter = format!("id=eq.{}", params.view_id);
+            let _ = client.update(&token, VIEW_TABLE, &filter, &changes).await?;
+            Ok(())
+        })
+    }
+
+    fn read_view_access(&self, _token: &str, _params: ViewId) -> FutureResult<Option<ViewAccess>, FlowyError> {
+        FutureResult::new(async { Ok(None) })
+    }
+
+    fn grant_view_access(&self, _token: &str, _params: UpdateViewAccessParams) -> FutureResult<(), FlowyError> {
+        unsupported("view access")
+    }
+
+    fn revoke_view_access(&self, _token: &str, _params: UpdateViewAccessParams) -> FutureResult<(), FlowyError> {
+        unsupported("view access")
+    }
+
+    fn create_share_link(&self, _token: &str, _params: CreateShareLinkParams) -> FutureResult<ShareLink, FlowyError> {
+        unsupported("share links")
+    }
+
+    fn read_share_links(&self, _token: &str, _params: ViewId) -> FutureResult<RepeatedShareLink, FlowyError> {
+        unsupported("share links")
+    }
+
+    fn revoke_share_link(&self, _token: &str, _params: ShareLinkId) -> FutureResult<(), FlowyError> {
+        unsupported("share links")
+    }
+
+    fn read_shared_document(&self, _params: ShareLinkId) -> FutureResult<SharedDocument, FlowyError> {
+        unsupported("share links")
+    }
+
+    fn create_guest_access(
+        &self,
+        _token: &str,
+        _params: CreateGuestAccessParams,
+    ) -> FutureResult<GuestAccess, FlowyError> {
+        unsupported("guest access")
+    }
+
+    fn read_guest_accesses(&self, _token: &str, _params: ViewId) -> FutureResult<RepeatedGuestAccess, FlowyError> {
+        unsupported("guest access")
+    }
+
+    fn revoke_guest_access(&self, _token: &str, _params: GuestAccessId) -> FutureResult<(), FlowyError> {
+        unsupported("guest access")
+    }
+
+    fn create_app(&self, token: &str, params: CreateAppParams) -> FutureResult<App, FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let time = timestamp();
+            let row = AppRow {
+                id: match params.app_id.is_empty() {
+                    true => uuid_string(),
+                    false => params.app_id,
+                },
+                workspace_id: params.workspace_id,
+                name: params.name,
+                description: params.desc,
+                color_style: params.color_style.theme_color,
+                version: 0,
+                create_time: time,
+                modified_time: time,
+            };
+            let _ = client.upsert(&token, APP_TABLE, &row).await?;
+            Ok(row.into())
+        })
+    }
+
+    fn read_app(&self, token: &str, params: AppId) -> FutureResult<Option<App>, FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let app = read_apps(&client, &token, &format!("id=eq.{}", params.app_id))
+                .await?
+                .pop();
+            Ok(app)
+        })
+    }
+
+    fn update_app(&self, token: &str, params: UpdateAppParams) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let mut changes = changeset();
+            set_if_some(&mut changes, "name", params.name);
+            set_if_some(&mut changes, "description", params.desc);
+            set_if_some(
+                &mut changes,
+                "color_style",
+                params.color_style.map(|color_style| color_style.theme_color),
+            );
+            let filter = format!("id=eq.{}", params.app_id);
+            let _ = client.update(&token, APP_TABLE, &filter, &changes).await?;
+            Ok(())
+        })
+    }
+
+    fn delete_app(&self, token: &str, params: AppId) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let _ = client
+                .delete(&token, APP_TABLE, &format!("id=eq.{}", params.app_id))
+                .await?;
+            Ok(())
+        })
+    }
+
+    fn create_trash(&self, token: &str, params: RepeatedTrashId) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let rows = params
+                .items
+                .iter()
+                .map(|item| json!({ "id": item.id, "ty": item.ty.clone() as i32 }))
+                .collect::<Vec<_>>();
+            let _ = client.upsert(&token, TRASH_TABLE, &rows).await?;
+            Ok(())
+        })
+    }
+
+    fn delete_trash(&self, token: &str, params: RepeatedTrashId) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            // PostgREST refuses a delete without a filter.
+            let filter = match params.delete_all {
+                true => "id=not.is.null".to_owned(),
+                false => {
+                    let ids = params.items.iter().map(|item| item.id.clone()).collect::<Vec<_>>();
+                    format!("id=in.({})", ids.join(","))
+                },
+            };
+            let _ = client.delete(&token, TRASH_TABLE, &filter).await?;
+            Ok(())
+        })
+    }
+
+    fn read_trash(&self, token: &str) -> FutureResult<RepeatedTrash, FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let rows: Vec<TrashRow> = client.select(&token, TRASH_ITEM_VIEW, "").await?;
+            let items = rows.into_iter().map(Trash::from).collect::<Vec<_>>();
+            Ok(RepeatedTrash { items })
+        })
+    }
+}
+
+fn unsupported<T>(feature: &str) -> FutureResult<T, FlowyError>
+where
+    T: Send + Sync + 'static,
+{
+    let error = FlowyError::cloud_unsupported().context(format!("Supabase doesn't support the {}", feature));
+    FutureResult::new(async { Err(error) })
+}
+
+// The modified time is set with every change.
+fn changeset() -> Map<String, Value> {
+    let mut changes = Map::new();
+    changes.insert("modified_time".to_owned(), json!(timestamp()));
+    changes
+}
+
+fn set_if_some(changes: &mut Map<String, Value>, column: &str, value: Option<String>) {
+    if let Some(value) = value {
+        changes.insert(column.to_owned(), Value::String(value));
+    }
+}
+
+// The apps with the views that belong to them.
+async fn read_apps(client: &SupabaseClient, token: &str, query: &str) -> Result<Vec<App>, FlowyError> {
+    let rows: Vec<AppRow> = client.select(token, APP_TABLE, query).await?;
+    let mut apps = rows.into_iter().map(App::from).collect::<Vec<_>>();
+    if apps.is_empty() {
+        return Ok(apps);
+    }
+
+    let app_ids = apps.iter().map(|app| app.id.clone()).collect::<Vec<_>>();
+    let mut views = read_views(client, token, &format!("belong_to_id=in.({})", app_ids.join(","))).await?;
+    for app in apps.iter_mut() {
+        let (belongings, others) = views.into_iter().partition(|view| view.belong_to_id == app.id);
+        app.belongings = RepeatedView { items: belongings };
+        views = others;
+    }
+    Ok(apps)
+}
+
+async fn read_views(client: &SupabaseClient, token: &str, query: &str) -> Result<Vec<View>, FlowyError> {
+    let rows: Vec<ViewRow> = client.select(token, VIEW_TABLE, query).await?;
+    Ok(rows.into_iter().map(View::from).collect())
+}
+
+#[derive(Serialize, Deserialize)]
+struct WorkspaceRow {
+    id: String,
+    name: String,
+    description: String,
+    create_time: i64,
+    modified_time: i64,
+    // Left out of the upsert of a created workspace, so a retried create
+    // keeps the settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    settings: Option<SettingsColumn>,
+}
+
+impl std::convert::From<WorkspaceRow> for Workspace {
+    fn from(row: WorkspaceRow) -> Self {
+        Workspace {
+            id: row.id,
+            name: row.name,
+            desc: row.description,
+            apps: RepeatedApp::default(),
+            modified_time: row.modified_time,
+            create_time: row.create_time,
+            role: WorkspaceRole::Owner,
+            settings: row.settings.map(WorkspaceSettings::from),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SettingsColumn {
+    default_view_type: i32,
+    date_format: i32,
+    retention_kind: i32,
+    retention_value: i64,
+    default_member_role: i32,
+}
+
+impl std::convert::From<WorkspaceSettings> for SettingsColumn {
+    fn from(settings: WorkspaceSettings) -> Self {
+        SettingsColumn {
+            default_view_type: settings.default_view_type as i32,
+            date_format: settings.date_format as i32,
+            retention_kind: settings.retention_kind as i32,
+            retention_value: settings.retention_value,
+            default_member_role: settings.default_member_role as i32,
+        }
+    }
+}
+
+impl std::convert::From<SettingsColumn> for WorkspaceSettings {
+    fn from(column: SettingsColumn) -> Self {
+        WorkspaceSettings {
+            default_view_type: column.default_view_type.into(),
+            date_format: DateFormat::from(column.date_format),
+            retention_kind: RetentionKind::from(column.retention_kind),
+            retention_value: column.retention_value,
+            default_member_role: WorkspaceRole::from(column.default_member_role),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct AppRow {
+    id: String,
+    workspace_id: String,
+    name: String,
+    description: String,
+    color_style: String,
+    version: i64,
+    create_time: i64,
+    modified_time: i64,
+}
+
+impl std::convert::From<AppRow> for App {
+    fn from(row: AppRow) -> Self {
+        App {
+            id: row.id,
+            workspace_id: row.workspace_id,
+            name: row.name,
+            desc: row.description,
+            belongings: RepeatedView::default(),
+            version: row.version,
+            modified_time: row.modified_time,
+            create_time: row.create_time,
+            ..App::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ViewRow {
+    id: String,
+    belong_to_id: String,
+    name: String,
+    description: String,
+    thumbnail: String,
+    view_type: i32,
+    version: i64,
+    create_time: i64,
+    modified_time: i64,
+}
+
+impl std::convert::From<ViewRow> for View {
+    fn from(row: ViewRow) -> Self {
+        View {
+            id: row.id,
+            belong_to_id: row.belong_to_id,
+            name: row.name,
+            desc: row.description,
+            view_type: row.view_type.into(),
+            version: row.version,
+            belongings: RepeatedView::default(),
+            modified_time: row.modified_time,
+            create_time: row.create_time,
+            ..View::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TrashRow {
+    id: String,
+    name: String,
+    ty: i32,
+    create_time: i64,
+    modified_time: i64,
+    deleted_by: String,
+    parent_id: String,
+}
+
+impl std::convert::From<TrashRow> for Trash {
+    fn from(row: TrashRow) -> Self {
+        Trash {
+            id: row.id,
+            name: row.name,
+            modified_time: row.modified_time,
+            create_time: row.create_time,
+            ty: TrashType::try_from(row.ty).unwrap_or(TrashType::Unknown),
+            deleted_by: row.deleted_by,
+            parent_id: row.parent_id,
+            position: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entities::{
+            view::ViewType,
+            workspace::{
+                CreateWorkspaceParams,
+                DateFormat,
+                RetentionKind,
+                UpdateWorkspaceParams,
+                WorkspaceId,
+                WorkspaceRole,
+                WorkspaceSettings,
+            },
+        },
+        services::server::{WorkspaceServerAPI, WorkspaceSupabaseServer},
+    };
+    use backend_service::configuration::SupabaseConfiguration;
+    use lib_infra::uuid_string;
+    use serde_json::Value;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+        sync::mpsc,
+    };
+
+    // A PostgREST that answers the requests whose request line starts with one
+    // of the prefixes with its rows, and the others with an empty list. The
+    // request line and the body of the requests are handed to the test.
+    async fn fake_postgrest(
+        responses: &'static [(&'static str, &'static str)],
+    ) -> (SupabaseConfiguration, mpsc::UnboundedReceiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    let (request_line, body) = read_request(&mut stream).await;
+                    let rows = responses
+                        .iter()
+                        .find(|(prefix, _)| request_line.starts_with(prefix))
+                        .map_or("[]", |(_, rows)| *rows);
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                        rows.len(),
+                        rows
+                    );
+                    let _ = tx.send((request_line, body));
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        let config = SupabaseConfiguration {
+            url,
+            anon_key: "anon".to_owned(),
+        };
+        (config, rx)
+    }
+
+    async fn read_request(stream: &mut TcpStream) -> (String, String) {
+        let mut bytes = vec![];
+        let mut buf = [0u8; 1024];
+        loop {
+            let n = stream.read(&mut buf).await.unwrap_or(0);
+            if n == 0 {
+                return (String::new(), String::new());
+            }
+            bytes.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&bytes).to_string();
+            let header_end = match text.find("\r\n\r\n") {
+                None => continue,
+                Some(index) => index + 4,
+            };
+            let content_length = text[..header_end]
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            if bytes.len() >= header_end + content_length {
+                let request_line = text.lines().next().unwrap_or_default().to_owned();
+                return (request_line, text[header_end..header_end + content_length].to_owned());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn supabase_create_workspace_with_client_id() {
+        let (config, mut requests) = fake_postgrest(&[]).await;
+        let server = WorkspaceSupabaseServer::new(config);
+        let params = CreateWorkspaceParams {
+            name: "Workspace".to_owned(),
+            desc: "".to_owned(),
+            workspace_id: uuid_string(),
+        };
+        let workspace = server.create_workspace("token", params.clone()).await.unwrap();
+        assert_eq!(workspace.id, params.workspace_id);
+
+        let (request_line, body) = requests.recv().await.unwrap();
+        assert!(request_line.starts_with("POST /rest/v1/af_workspace "));
+        let row: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(row["id"], params.workspace_id.as_str());
+        assert!(row.get("settings").is_none());
+    }
+
+    #[tokio::test]
+    async fn supabase_update_and_read_workspace_settings() {
+        const WORKSPACE_ROWS: &str = r#"[{"id":"w1","name":"Workspace","description":"","create_time":0,"modified_time":0,"settings":{"default_view_type":2,"date_format":2,"retention_kind":2,"retention_value":30,"default_member_role":2}}]"#;
+        let (config, mut requests) = fake_postgrest(&[("GET /rest/v1/af_workspace?", WORKSPACE_ROWS)]).await;
+        let server = WorkspaceSupabaseServer::new(config);
+        let settings = WorkspaceSettings {
+            default_view_type: ViewType::Grid,
+            date_format: DateFormat::ISO,
+            retention_kind: RetentionKind::LastDays,
+            retention_value: 30,
+            default_member_role: WorkspaceRole::Viewer,
+        };
+        let params = UpdateWorkspaceParams {
+            id: "w1".to_owned(),
+            name: None,
+            desc: None,
+            settings: Some(settings.clone()),
+        };
+        let _ = server.update_workspace("token", params).await.unwrap();
+        let (request_line, body) = requests.recv().await.unwrap();
+        assert!(request_line.starts_with("PATCH /rest/v1/af_workspace?id=eq.w1 "));
+        let changes: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(changes["settings"]["default_view_type"], ViewType::Grid as i32);
+        assert_eq!(changes["settings"]["default_member_role"], WorkspaceRole::Viewer as i32);
+
+        let workspace = server
+            .read_workspace("token", WorkspaceId::new(Some("w1".to_owned())))
+            .await
+            .unwrap()
+            .items
+            .remove(0);
+        assert_eq!(workspace.settings, Some(settings));
+    }
+}
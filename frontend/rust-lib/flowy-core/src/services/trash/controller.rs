@@ -1,31 +1,53 @@
 use crate::{
-    entities::trash::{RepeatedTrash, RepeatedTrashId, Trash, TrashId, TrashType},
+    entities::{
+        trash::{RepeatedTrash, RepeatedTrashId, Trash, TrashId, TrashType},
+        view::UpdateViewParams,
+    },
     errors::{FlowyError, FlowyResult},
     module::{WorkspaceDatabase, WorkspaceUser},
     notify::{send_anonymous_dart_notification, WorkspaceNotification},
-    services::{server::Server, trash::sql::TrashTableSql},
+    retry::{ServerCallClass, ServerRetry},
+    services::{
+        app::sql::AppTableSql,
+        event_log::sql::{EventLogTableSql, EventLogType},
+        reposition,
+        trash::sql::{TrashTable, TrashTableSql},
+        view::sql::{ViewTableChangeset, ViewTableSql},
+        WebhookController,
+    },
 };
 use crossbeam_utils::thread;
-use flowy_database::SqliteConnection;
+use flowy_database::{erase_free_pages, slow_log::SlowLogTransaction, SqliteConnection};
+use serde_json::json;
 use std::{fmt::Formatter, sync::Arc};
 use tokio::sync::{broadcast, mpsc};
 
 pub struct TrashController {
+    user: Arc<dyn WorkspaceUser>,
     pub database: Arc<dyn WorkspaceDatabase>,
     notify: broadcast::Sender<TrashEvent>,
-    server: Server,
-    user: Arc<dyn WorkspaceUser>,
+    server_retry: ServerRetry,
+    webhook: Arc<WebhookController>,
+    secure_delete: bool,
 }
 
 impl TrashController {
-    pub fn new(database: Arc<dyn WorkspaceDatabase>, server: Server, user: Arc<dyn WorkspaceUser>) -> Self {
+    pub fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        server_retry: ServerRetry,
+        webhook: Arc<WebhookController>,
+        secure_delete: bool,
+    ) -> Self {
         let (tx, _) = broadcast::channel(10);
 
         Self {
+            user,
             database,
             notify: tx,
-            server,
-            user,
+            server_retry,
+            webhook,
+            secure_delete,
         }
     }
 
@@ -37,8 +59,10 @@ impl TrashController {
         let trash_table = TrashTableSql::read(trash_id, &*self.database.db_connection()?)?;
         let _ = thread::scope(|_s| {
             let conn = self.database.db_connection()?;
-            conn.immediate_transaction::<_, FlowyError, _>(|| {
+            conn.timed_transaction::<_, FlowyError, _>("trash.putback", || {
                 let _ = TrashTableSql::delete_trash(trash_id, &*conn)?;
+                let trash_ids = self.read_trash_ids(&conn)?;
+                let _ = put_back_in_place(&trash_table, &trash_ids, &conn)?;
                 notify_trash_changed(TrashTableSql::read_all(&conn)?);
                 Ok(())
             })?;
@@ -67,9 +91,25 @@ impl TrashController {
     pub async fn restore_all(&self) -> FlowyResult<()> {
         let repeated_trash = thread::scope(|_s| {
             let conn = self.database.db_connection()?;
-            conn.immediate_transaction::<_, FlowyError, _>(|| {
+            conn.timed_transaction::<_, FlowyError, _>("trash.restore_all", || {
                 let repeated_trash = TrashTableSql::read_all(&*conn)?;
                 let _ = TrashTableSql::delete_all(&*conn)?;
+                // An item that comes before another one in the parent is put
+                // back first, and the ones that are put back later aren't
+                // counted yet.
+                let mut trash_tables = repeated_trash
+                    .items
+                    .iter()
+                    .map(|trash| TrashTable::from(trash.clone()))
+                    .collect::<Vec<_>>();
+                trash_tables.sort_by_key(|trash_table| trash_table.position);
+                for (i, trash_table) in trash_tables.iter().enumerate() {
+                    let pending_ids = trash_tables[i + 1..]
+                        .iter()
+                        .map(|trash_table| trash_table.id.clone())
+                        .collect::<Vec<String>>();
+                    let _ = put_back_in_place(trash_table, &pending_ids, &conn)?;
+                }
                 Ok(repeated_trash)
             })
         })
@@ -80,6 +120,11 @@ impl TrashController {
         let _ = self.notify.send(TrashEvent::Putback(identifiers, tx));
         let _ = rx.recv().await;
 
+        // The restored items are sent at once, so the client puts them back into
+        // their apps without reading the whole folder again.
+        send_anonymous_dart_notification(WorkspaceNotification::TrashRestored)
+            .payload(repeated_trash)
+            .send();
         notify_trash_changed(RepeatedTrash { items: vec![] });
         let _ = self.delete_all_trash_on_server().await?;
         Ok(())
@@ -92,6 +137,15 @@ impl TrashController {
         let _ = self.delete_with_identifiers(trash_identifiers.clone()).await?;
 
         notify_trash_changed(RepeatedTrash { items: vec![] });
+        let ids = trash_identifiers.items.iter().map(|t| t.id.clone()).collect::<Vec<_>>();
+        let payload = json!({ "ids": ids });
+        let _ = EventLogTableSql::append(
+            EventLogType::TrashEmptied,
+            "",
+            payload.clone(),
+            &*self.database.db_connection()?,
+        )?;
+        self.webhook.trash_did_empty(payload);
         let _ = self.delete_all_trash_on_server().await?;
         Ok(())
     }
@@ -107,25 +161,26 @@ impl TrashController {
 
     #[tracing::instrument(level = "debug", skip(self), fields(delete_trash_ids), err)]
     pub async fn delete_with_identifiers(&self, trash_identifiers: RepeatedTrashId) -> FlowyResult<()> {
-        let (tx, mut rx) = mpsc::channel::<FlowyResult<()>>(1);
+        let (tx, rx) = mpsc::channel::<FlowyResult<()>>(1);
         tracing::Span::current().record("delete_trash_ids", &format!("{}", trash_identifiers).as_str());
         let _ = self.notify.send(TrashEvent::Delete(trash_identifiers.clone(), tx));
-
-        match rx.recv().await {
-            None => {},
-            Some(result) => match result {
-                Ok(_) => {},
-                Err(e) => log::error!("{}", e),
-            },
+        for e in wait_for_replies(rx).await {
+            log::error!("{}", e);
         }
 
         let conn = self.database.db_connection()?;
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
+        conn.timed_transaction::<_, FlowyError, _>("trash.delete_with_identifiers", || {
             for trash_identifier in &trash_identifiers.items {
                 let _ = TrashTableSql::delete_trash(&trash_identifier.id, &conn)?;
             }
             Ok(())
         })?;
+
+        // The deleted rows stay in the free pages of the file until they are
+        // reused, and in the write-ahead log.
+        if self.secure_delete {
+            let _ = erase_free_pages(&*conn)?;
+        }
         Ok(())
     }
 
@@ -138,7 +193,8 @@ impl TrashController {
     #[tracing::instrument(name = "add_trash", level = "debug", skip(self, trash), fields(trash_ids), err)]
     pub async fn add<T: Into<Trash>>(&self, trash: Vec<T>) -> Result<(), FlowyError> {
         let (tx, mut rx) = mpsc::channel::<FlowyResult<()>>(1);
-        let repeated_trash = trash.into_iter().map(|t| t.into()).collect::<Vec<Trash>>();
+        let deleted_by = self.user.user_id()?;
+        let mut repeated_trash = trash.into_iter().map(|t| t.into()).collect::<Vec<Trash>>();
         let identifiers = repeated_trash.iter().map(|t| t.into()).collect::<Vec<TrashId>>();
 
         tracing::Span::current().record(
@@ -154,7 +210,14 @@ impl TrashController {
         );
         let _ = thread::scope(|_s| {
             let conn = self.database.db_connection()?;
-            conn.immediate_transaction::<_, FlowyError, _>(|| {
+            conn.timed_transaction::<_, FlowyError, _>("trash.add", || {
+                // The positions are read before any of the items is in the
+                // trash, so the ones deleted together keep their order.
+                let trash_ids = self.read_trash_ids(&conn)?;
+                for trash in repeated_trash.iter_mut() {
+                    trash.deleted_by = deleted_by.clone();
+                    trash.position = read_position(trash, &trash_ids, &conn)?;
+                }
                 let _ = TrashTableSql::create_trash(repeated_trash.clone(), &*conn)?;
                 let _ = self.create_trash_on_server(repeated_trash);
 
@@ -192,12 +255,12 @@ impl TrashController {
 impl TrashController {
     #[tracing::instrument(level = "debug", skip(self, trash), err)]
     fn create_trash_on_server<T: Into<RepeatedTrashId>>(&self, trash: T) -> FlowyResult<()> {
-        let token = self.user.token()?;
-        let trash_identifiers = trash.into();
-        let server = self.server.clone();
-        // TODO: retry?
+        let trash_identifiers: RepeatedTrashId = trash.into();
+        let create_trash = self.server_retry.call(ServerCallClass::Sync, move |token, server| {
+            server.create_trash(&token, trash_identifiers.clone())
+        })?;
         let _ = tokio::spawn(async move {
-            match server.create_trash(&token, trash_identifiers).await {
+            match create_trash.await {
                 Ok(_) => {},
                 Err(e) => log::error!("Create trash failed: {:?}", e),
             }
@@ -206,12 +269,13 @@ impl TrashController {
     }
 
     #[tracing::instrument(level = "debug", skip(self, trash), err)]
-    fn delete_trash_on_server<T: Into<RepeatedTrashId>>(&self, trash: T) -> FlowyResult<()> {
-        let token = self.user.token()?;
-        let trash_identifiers = trash.into();
-        let server = self.server.clone();
+    pub(crate) fn delete_trash_on_server<T: Into<RepeatedTrashId>>(&self, trash: T) -> FlowyResult<()> {
+        let trash_identifiers: RepeatedTrashId = trash.into();
+        let delete_trash = self.server_retry.call(ServerCallClass::Sync, move |token, server| {
+            server.delete_trash(&token, trash_identifiers.clone())
+        })?;
         let _ = tokio::spawn(async move {
-            match server.delete_trash(&token, trash_identifiers).await {
+            match delete_trash.await {
                 Ok(_) => {},
                 Err(e) => log::error!("Delete trash failed: {:?}", e),
             }
@@ -221,20 +285,22 @@ impl TrashController {
 
     #[tracing::instrument(level = "debug", skip(self), err)]
     fn read_trash_on_server(&self) -> FlowyResult<()> {
-        let token = self.user.token()?;
-        let server = self.server.clone();
+        let read_trash = self
+            .server_retry
+            .call(ServerCallClass::Sync, |token, server| server.read_trash(&token))?;
         let pool = self.database.db_pool()?;
 
         tokio::spawn(async move {
-            match server.read_trash(&token).await {
+            match read_trash.await {
                 Ok(repeated_trash) => {
                     tracing::debug!("Remote trash count: {}", repeated_trash.items.len());
                     match pool.get() {
                         Ok(conn) => {
-                            let result = conn.immediate_transaction::<_, FlowyError, _>(|| {
-                                let _ = TrashTableSql::create_trash(repeated_trash.items.clone(), &*conn)?;
-                                TrashTableSql::read_all(&conn)
-                            });
+                            let result =
+                                conn.timed_transaction::<_, FlowyError, _>("trash.read_trash_on_server", || {
+                                    let _ = TrashTableSql::create_trash(repeated_trash.items.clone(), &*conn)?;
+                                    TrashTableSql::read_all(&conn)
+                                });
 
                             match result {
                                 Ok(repeated_trash) => {
@@ -254,14 +320,94 @@ impl TrashController {
 
     #[tracing::instrument(level = "debug", skip(self), err)]
     async fn delete_all_trash_on_server(&self) -> FlowyResult<()> {
-        let token = self.user.token()?;
-        let server = self.server.clone();
-        server.delete_trash(&token, RepeatedTrashId::all()).await
+        self.server_retry
+            .call(ServerCallClass::Write, |token, server| {
+                server.delete_trash(&token, RepeatedTrashId::all())
+            })?
+            .await
     }
 }
 
+// The index among the items of the parent that aren't in the trash.
+fn read_position(trash: &Trash, trash_ids: &[String], conn: &SqliteConnection) -> FlowyResult<i32> {
+    let sibling_ids = read_sibling_times(&trash.ty, &trash.parent_id, trash_ids, conn)?
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect::<Vec<String>>();
+    Ok(sibling_ids.iter().position(|id| id == &trash.id).unwrap_or(0) as i32)
+}
+
+fn read_sibling_times(
+    ty: &TrashType,
+    parent_id: &str,
+    trash_ids: &[String],
+    conn: &SqliteConnection,
+) -> FlowyResult<Vec<(String, i64)>> {
+    let items = match ty {
+        TrashType::View => ViewTableSql::read_views(parent_id, conn)?
+            .into_iter()
+            .map(|view_table| (view_table.id, view_table.create_time))
+            .collect::<Vec<_>>(),
+        TrashType::App => AppTableSql::read_workspace_apps(parent_id, false, conn)?
+            .into_iter()
+            .map(|app_table| (app_table.id, app_table.create_time))
+            .collect::<Vec<_>>(),
+        TrashType::Workspace | TrashType::Unknown => vec![],
+    };
+    Ok(items.into_iter().filter(|(id, _)| !trash_ids.contains(id)).collect())
+}
+
+// Puts the view back into the parent it was deleted from, if it was moved in
+// the meantime and the parent is still there, and the view or the app back at
+// its position. The trash of an older version has no parent.
+fn put_back_in_place(trash_table: &TrashTable, trash_ids: &[String], conn: &SqliteConnection) -> FlowyResult<()> {
+    if trash_table.parent_id.is_empty() {
+        return Ok(());
+    }
+
+    let ty: TrashType = trash_table.ty.into();
+    if ty == TrashType::View {
+        let belong_to_id = ViewTableSql::read_belong_to_id(&trash_table.id, conn)?;
+        let parent_exists = ViewTableSql::read_belong_to_id(&trash_table.parent_id, conn)?.is_some()
+            || AppTableSql::try_read_app(&trash_table.parent_id, conn)?.is_some();
+        if belong_to_id.is_some() && belong_to_id.as_ref() != Some(&trash_table.parent_id) && parent_exists {
+            let params = UpdateViewParams {
+                belong_to_id: Some(trash_table.parent_id.clone()),
+                ..UpdateViewParams::new(&trash_table.id)
+            };
+            let _ = ViewTableSql::update_view(ViewTableChangeset::new(params), conn)?;
+        }
+    }
+
+    let siblings = read_sibling_times(&ty, &trash_table.parent_id, trash_ids, conn)?;
+    if !siblings.iter().any(|(id, _)| id == &trash_table.id) {
+        return Ok(());
+    }
+    for (id, create_time) in reposition(&siblings, &trash_table.id, trash_table.position as usize) {
+        match ty {
+            TrashType::View => ViewTableSql::update_create_time(&id, create_time, conn)?,
+            _ => AppTableSql::update_create_time(&id, create_time, conn)?,
+        }
+    }
+    Ok(())
+}
+
+// The views and the apps are deleted by different subscribers, and the free
+// pages can only be erased after all of them deleted their rows. Every
+// subscriber drops its sender after replying, or right away if none of the
+// items is its type, which closes the channel.
+async fn wait_for_replies(mut rx: mpsc::Receiver<FlowyResult<()>>) -> Vec<FlowyError> {
+    let mut errors = vec![];
+    while let Some(result) = rx.recv().await {
+        if let Err(e) = result {
+            errors.push(e);
+        }
+    }
+    errors
+}
+
 #[tracing::instrument(skip(repeated_trash), fields(n_trash))]
-fn notify_trash_changed(repeated_trash: RepeatedTrash) {
+pub(crate) fn notify_trash_changed(repeated_trash: RepeatedTrash) {
     tracing::Span::current().record("n_trash", &repeated_trash.len());
     send_anonymous_dart_notification(WorkspaceNotification::TrashUpdated)
         .payload(repeated_trash)
@@ -315,3 +461,56 @@ impl TrashEvent {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entities::trash::{RepeatedTrashId, TrashId, TrashType},
+        errors::FlowyResult,
+        services::trash::controller::{wait_for_replies, TrashEvent},
+    };
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+    use tokio::sync::{broadcast, mpsc};
+
+    #[tokio::test]
+    async fn trash_delete_waits_for_every_subscriber() {
+        let (notify, _) = broadcast::channel::<TrashEvent>(10);
+        let deleted = Arc::new(AtomicUsize::new(0));
+        for (ty, delay) in &[(TrashType::View, 50), (TrashType::App, 100), (TrashType::Workspace, 0)] {
+            let (ty, delay) = (ty.clone(), *delay);
+            let mut rx = notify.subscribe();
+            let deleted = deleted.clone();
+            tokio::spawn(async move {
+                if let Some(TrashEvent::Delete(_, ret)) = rx.recv().await.ok().and_then(|event| event.select(ty)) {
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    deleted.fetch_add(1, Ordering::SeqCst);
+                    let _ = ret.send(Ok(())).await;
+                }
+            });
+        }
+
+        let (tx, rx) = mpsc::channel::<FlowyResult<()>>(1);
+        let identifiers = RepeatedTrashId {
+            items: vec![
+                TrashId {
+                    id: "view".to_owned(),
+                    ty: TrashType::View,
+                },
+                TrashId {
+                    id: "app".to_owned(),
+                    ty: TrashType::App,
+                },
+            ],
+            delete_all: false,
+        };
+        let _ = notify.send(TrashEvent::Delete(identifiers, tx));
+        assert!(wait_for_replies(rx).await.is_empty());
+        assert_eq!(deleted.load(Ordering::SeqCst), 2);
+    }
+}
@@ -1,3 +1,3 @@
 pub mod controller;
 pub mod event_handler;
-mod sql;
+pub(crate) mod sql;
@@ -1,11 +1,13 @@
 use crate::{
     entities::trash::{RepeatedTrash, Trash, TrashType},
     errors::FlowyError,
+    services::workspace::skeleton::invalidate_workspace_skeleton,
 };
 use diesel::sql_types::Integer;
 use flowy_database::{
     prelude::*,
     schema::{trash_table, trash_table::dsl},
+    slow_log::{log_if_slow, statement_shape},
     SqliteConnection,
 };
 
@@ -23,18 +25,22 @@ impl TrashTableSql {
                 },
             }
         }
+        invalidate_workspace_skeleton();
 
         Ok(())
     }
 
     pub(crate) fn read_all(conn: &SqliteConnection) -> Result<RepeatedTrash, FlowyError> {
-        let trash_tables = dsl::trash_table.load::<TrashTable>(conn)?;
+        let trash_tables = log_if_slow(&statement_shape(&dsl::trash_table), || {
+            dsl::trash_table.load::<TrashTable>(conn)
+        })?;
         let items = trash_tables.into_iter().map(|t| t.into()).collect::<Vec<Trash>>();
         Ok(RepeatedTrash { items })
     }
 
     pub(crate) fn delete_all(conn: &SqliteConnection) -> Result<(), FlowyError> {
         let _ = diesel::delete(dsl::trash_table).execute(conn)?;
+        invalidate_workspace_skeleton();
         Ok(())
     }
 
@@ -47,6 +53,7 @@ impl TrashTableSql {
 
     pub(crate) fn delete_trash(trash_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
         diesel_delete_table!(trash_table, trash_id, conn);
+        invalidate_workspace_skeleton();
         Ok(())
     }
 }
@@ -60,6 +67,9 @@ pub(crate) struct TrashTable {
     pub modified_time: i64,
     pub create_time: i64,
     pub ty: SqlTrashType,
+    pub deleted_by: String,
+    pub parent_id: String,
+    pub position: i32,
 }
 impl std::convert::From<TrashTable> for Trash {
     fn from(table: TrashTable) -> Self {
@@ -69,6 +79,9 @@ impl std::convert::From<TrashTable> for Trash {
             modified_time: table.modified_time,
             create_time: table.create_time,
             ty: table.ty.into(),
+            deleted_by: table.deleted_by,
+            parent_id: table.parent_id,
+            position: table.position,
         }
     }
 }
@@ -82,10 +95,15 @@ impl std::convert::From<Trash> for TrashTable {
             modified_time: trash.modified_time,
             create_time: trash.create_time,
             ty: trash.ty.into(),
+            deleted_by: trash.deleted_by,
+            parent_id: trash.parent_id,
+            position: trash.position,
         }
     }
 }
 
+// Where the item was deleted from isn't updated, the trash read from the
+// server doesn't know the position.
 #[derive(AsChangeset, Identifiable, Clone, Default, Debug)]
 #[table_name = "trash_table"]
 pub(crate) struct TrashTableChangeset {
@@ -108,9 +126,10 @@ impl std::convert::From<TrashTable> for TrashTableChangeset {
 #[repr(i32)]
 #[sql_type = "Integer"]
 pub(crate) enum SqlTrashType {
-    Unknown = 0,
-    View    = 1,
-    App     = 2,
+    Unknown   = 0,
+    View      = 1,
+    App       = 2,
+    Workspace = 3,
 }
 
 impl std::convert::From<i32> for SqlTrashType {
@@ -119,6 +138,7 @@ impl std::convert::From<i32> for SqlTrashType {
             0 => SqlTrashType::Unknown,
             1 => SqlTrashType::View,
             2 => SqlTrashType::App,
+            3 => SqlTrashType::Workspace,
             _o => SqlTrashType::Unknown,
         }
     }
@@ -132,6 +152,7 @@ impl std::convert::From<SqlTrashType> for TrashType {
             SqlTrashType::Unknown => TrashType::Unknown,
             SqlTrashType::View => TrashType::View,
             SqlTrashType::App => TrashType::App,
+            SqlTrashType::Workspace => TrashType::Workspace,
         }
     }
 }
@@ -142,6 +163,7 @@ impl std::convert::From<TrashType> for SqlTrashType {
             TrashType::Unknown => SqlTrashType::Unknown,
             TrashType::View => SqlTrashType::View,
             TrashType::App => SqlTrashType::App,
+            TrashType::Workspace => SqlTrashType::Workspace,
         }
     }
 }
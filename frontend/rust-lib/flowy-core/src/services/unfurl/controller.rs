@@ -0,0 +1,189 @@
+use crate::{
+    entities::unfurl::{LinkPreview, UnfurlParams, UnfurlSetting},
+    errors::{internal_error, FlowyError, FlowyResult},
+    module::WorkspaceDatabase,
+    services::unfurl::{
+        meta::{parse_link_meta, LinkMeta},
+        sql::{LinkPreviewTable, LinkPreviewTableSql},
+    },
+};
+use flowy_database::kv::KV;
+use lib_infra::clock::RuntimeEnv;
+use reqwest::{
+    header::{ACCEPT, CONTENT_TYPE},
+    redirect::Policy,
+    Url,
+};
+use std::{net::IpAddr, sync::Arc, time::Duration};
+
+const UNFURL_ENABLED: &str = "link_unfurl_enabled";
+const UNFURL_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_REDIRECTS: usize = 5;
+
+// The tags are in the head, the rest of a large page isn't downloaded.
+const MAX_PAGE_LEN: usize = 512 * 1024;
+
+// The previews are fetched again after a week, e.g. the title of the page
+// changed.
+const PREVIEW_TTL: i64 = 7 * 24 * 60 * 60;
+
+// Fetches the title, the description and the image of the embedded links.
+// The fetch never fails the card: the preview of a link that can't be fetched
+// only has its url, and it's fetched again the next time.
+pub struct UnfurlController {
+    database: Arc<dyn WorkspaceDatabase>,
+    client: reqwest::Client,
+    env: RuntimeEnv,
+}
+
+impl UnfurlController {
+    pub(crate) fn new(database: Arc<dyn WorkspaceDatabase>, env: RuntimeEnv) -> Self {
+        let redirect = Policy::custom(|attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS || !is_public_url(attempt.url()) {
+                attempt.stop()
+            } else {
+                attempt.follow()
+            }
+        });
+        let client = reqwest::Client::builder()
+            .timeout(UNFURL_TIMEOUT)
+            .redirect(redirect)
+            .build()
+            .unwrap_or_default();
+        Self { database, client, env }
+    }
+
+    pub(crate) fn read_setting(&self) -> UnfurlSetting {
+        UnfurlSetting {
+            enabled: is_unfurl_enabled(),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn update_setting(&self, setting: UnfurlSetting) -> FlowyResult<UnfurlSetting> {
+        KV::set_bool(UNFURL_ENABLED, setting.enabled);
+        if !setting.enabled {
+            let conn = self.database.db_connection()?;
+            let _ = LinkPreviewTableSql::delete_all(&*conn)?;
+        }
+        Ok(setting)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(url = %params.url), err)]
+    pub(crate) async fn unfurl(&self, params: UnfurlParams) -> FlowyResult<LinkPreview> {
+        let url = params.url;
+        if !is_unfurl_enabled() {
+            return Ok(LinkPreview {
+                url,
+                ..Default::default()
+            });
+        }
+
+        let now = self.env.timestamp();
+        let cached = LinkPreviewTableSql::read_preview(&url, &*self.database.db_connection()?)?;
+        if let Some(table) = cached.filter(|table| now - table.fetch_time < PREVIEW_TTL) {
+            return Ok(table.into());
+        }
+
+        let meta = match self.fetch(&url).await {
+            Ok(meta) => meta,
+            Err(e) => {
+                tracing::debug!("Unfurl {} failed: {:?}", url, e);
+                return Ok(LinkPreview {
+                    url,
+                    ..Default::default()
+                });
+            },
+        };
+        let table = LinkPreviewTable {
+            url,
+            title: meta.title,
+            description: meta.description,
+            image_url: meta.image_url,
+            site_name: meta.site_name,
+            fetch_time: now,
+        };
+        let _ = LinkPreviewTableSql::create_preview(table.clone(), &*self.database.db_connection()?)?;
+        Ok(table.into())
+    }
+
+    async fn fetch(&self, url: &str) -> FlowyResult<LinkMeta> {
+        let url = Url::parse(url).map_err(|e| FlowyError::embed_url_invalid().context(e))?;
+        if !is_public_url(&url) {
+            return Err(FlowyError::embed_url_invalid().context("The url is a local or a private address"));
+        }
+
+        let mut response = self
+            .client
+            .get(url)
+            .header(ACCEPT, "text/html")
+            .send()
+            .await
+            .map_err(internal_error)?;
+        if !response.status().is_success() {
+            return Err(FlowyError::internal().context(format!("The site responded {}", response.status())));
+        }
+
+        // E.g. a pdf or an image, the card only shows the url and the site.
+        let page_url = response.url().clone();
+        let is_html = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map_or(false, |value| value.contains("html"));
+        if !is_html {
+            return Ok(parse_link_meta("", &page_url));
+        }
+
+        let mut page = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(internal_error)? {
+            page.extend_from_slice(&chunk);
+            if page.len() >= MAX_PAGE_LEN {
+                break;
+            }
+        }
+        Ok(parse_link_meta(&String::from_utf8_lossy(&page), &page_url))
+    }
+}
+
+// The previews are turned on until the user turns them off.
+fn is_unfurl_enabled() -> bool { KV::get_bool(UNFURL_ENABLED).unwrap_or(true) }
+
+// A shared document can embed any url and the devices of all its readers
+// fetch it, so the previews never reach the loopback or the private addresses,
+// e.g. the router of a reader. A name that resolves to one isn't caught.
+fn is_public_url(url: &Url) -> bool {
+    if !matches!(url.scheme(), "http" | "https") {
+        return false;
+    }
+    let host = match url.host_str() {
+        None => return false,
+        Some(host) => host.trim_start_matches('[').trim_end_matches(']').to_ascii_lowercase(),
+    };
+    if host == "localhost" || host.ends_with(".localhost") || host.ends_with(".local") {
+        return false;
+    }
+
+    match host.parse::<IpAddr>() {
+        Err(_) => true,
+        Ok(ip) => is_public_ip(ip),
+    }
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            !(ip.is_loopback() || ip.is_private() || ip.is_link_local() || ip.is_unspecified() || ip.is_broadcast())
+        },
+        IpAddr::V6(ip) => {
+            let segments = ip.segments();
+            let is_ipv4_mapped = segments[..5].iter().all(|segment| *segment == 0) && segments[5] == 0xffff;
+            if let Some(ipv4) = ip.to_ipv4().filter(|_| is_ipv4_mapped) {
+                return is_public_ip(IpAddr::V4(ipv4));
+            }
+            let is_unique_local = segments[0] & 0xfe00 == 0xfc00;
+            let is_link_local = segments[0] & 0xffc0 == 0xfe80;
+            !(ip.is_loopback() || ip.is_unspecified() || is_unique_local || is_link_local)
+        },
+    }
+}
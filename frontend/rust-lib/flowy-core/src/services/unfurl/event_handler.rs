@@ -0,0 +1,33 @@
+use crate::{
+    entities::unfurl::{LinkPreview, UnfurlParams, UnfurlRequest, UnfurlSetting},
+    errors::FlowyError,
+    services::UnfurlController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn unfurl_link_handler(
+    data: Data<UnfurlRequest>,
+    controller: Unit<Arc<UnfurlController>>,
+) -> DataResult<LinkPreview, FlowyError> {
+    let params: UnfurlParams = data.into_inner().try_into()?;
+    let preview = controller.unfurl(params).await?;
+    data_result(preview)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_unfurl_setting_handler(
+    controller: Unit<Arc<UnfurlController>>,
+) -> DataResult<UnfurlSetting, FlowyError> {
+    data_result(controller.read_setting())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_unfurl_setting_handler(
+    data: Data<UnfurlSetting>,
+    controller: Unit<Arc<UnfurlController>>,
+) -> DataResult<UnfurlSetting, FlowyError> {
+    let setting = controller.update_setting(data.into_inner())?;
+    data_result(setting)
+}
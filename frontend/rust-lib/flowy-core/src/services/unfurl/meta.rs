@@ -0,0 +1,103 @@
+use crate::services::import::html::decode_entities;
+use reqwest::Url;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct LinkMeta {
+    pub title: String,
+    pub description: String,
+    pub image_url: String,
+    pub site_name: String,
+}
+
+// Reads the Open Graph tags of the page, or the title and the description of
+// the document if it has none. The reading stops at the body, the tags are in
+// the head.
+pub(super) fn parse_link_meta(html: &str, url: &Url) -> LinkMeta {
+    let mut meta = LinkMeta::default();
+    let mut title = String::new();
+    let mut description = String::new();
+
+    // The lowercase has the same byte offsets, only the ascii letters change.
+    let lower = html.to_ascii_lowercase();
+    let mut offset = 0;
+    while let Some(start) = lower[offset..].find('<').map(|i| offset + i) {
+        let end = match lower[start..].find('>') {
+            None => break,
+            Some(i) => start + i,
+        };
+        let tag = &html[start + 1..end];
+        let name = lower[start + 1..end]
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .find(|name| !name.is_empty())
+            .unwrap_or("");
+        match name {
+            "meta" => {
+                let key = attribute(tag, "property").or_else(|| attribute(tag, "name"));
+                if let (Some(key), Some(content)) = (key, attribute(tag, "content")) {
+                    let content = decode_entities(content.trim());
+                    match key.to_ascii_lowercase().as_str() {
+                        "og:title" | "twitter:title" => set_if_empty(&mut meta.title, content),
+                        "og:description" | "twitter:description" => set_if_empty(&mut meta.description, content),
+                        "description" => set_if_empty(&mut description, content),
+                        "og:site_name" => set_if_empty(&mut meta.site_name, content),
+                        "og:image" | "og:image:url" | "twitter:image" => {
+                            set_if_empty(&mut meta.image_url, resolve_url(url, &content))
+                        },
+                        _ => {},
+                    }
+                }
+            },
+            "title" if !lower[start + 1..end].starts_with('/') => {
+                if let Some(len) = lower[end..].find("</title") {
+                    set_if_empty(&mut title, decode_entities(html[end + 1..end + len].trim()));
+                }
+            },
+            "body" => break,
+            _ => {},
+        }
+        offset = end + 1;
+    }
+
+    set_if_empty(&mut meta.title, title);
+    set_if_empty(&mut meta.description, description);
+    set_if_empty(&mut meta.site_name, url.host_str().unwrap_or("").to_owned());
+    meta
+}
+
+fn set_if_empty(field: &mut String, value: String) {
+    if field.is_empty() {
+        *field = value;
+    }
+}
+
+// The relative urls are resolved against the page, which may not be the url
+// of the link after the redirects.
+fn resolve_url(url: &Url, value: &str) -> String {
+    match url.join(value) {
+        Ok(resolved) if matches!(resolved.scheme(), "http" | "https") => resolved.to_string(),
+        _ => String::new(),
+    }
+}
+
+// The value of the attribute in a double, a single or no quote.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let lower = tag.to_ascii_lowercase();
+    let mut offset = 0;
+    while let Some(i) = lower[offset..].find(name).map(|i| offset + i) {
+        offset = i + name.len();
+        let rest = lower[offset..].trim_start();
+        if !lower[..i].ends_with(char::is_whitespace) || !rest.starts_with('=') {
+            continue;
+        }
+
+        let value = tag[tag.len() - rest.len() + 1..].trim_start();
+        let quote = value.chars().next()?;
+        if quote == '"' || quote == '\'' {
+            let len = value[1..].find(quote)?;
+            return Some(&value[1..1 + len]);
+        }
+        let len = value.find(char::is_whitespace).unwrap_or_else(|| value.len());
+        return Some(value[..len].trim_end_matches('/'));
+    }
+    None
+}
@@ -0,0 +1,4 @@
+pub mod controller;
+pub mod event_handler;
+mod meta;
+pub(crate) mod sql;
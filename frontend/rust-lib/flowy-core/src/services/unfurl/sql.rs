@@ -0,0 +1,55 @@
+use crate::{entities::unfurl::LinkPreview, errors::FlowyError};
+use flowy_database::{
+    prelude::*,
+    result::OptionalExtension,
+    schema::{link_preview_table, link_preview_table::dsl},
+    SqliteConnection,
+};
+
+pub(crate) struct LinkPreviewTableSql {}
+
+impl LinkPreviewTableSql {
+    pub(crate) fn create_preview(table: LinkPreviewTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = diesel::replace_into(link_preview_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_preview(url: &str, conn: &SqliteConnection) -> Result<Option<LinkPreviewTable>, FlowyError> {
+        let table = dsl::link_preview_table
+            .filter(link_preview_table::url.eq(url))
+            .first::<LinkPreviewTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+
+    pub(crate) fn delete_all(conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = diesel::delete(dsl::link_preview_table).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "link_preview_table"]
+pub(crate) struct LinkPreviewTable {
+    pub url: String,
+    pub title: String,
+    pub description: String,
+    pub image_url: String,
+    pub site_name: String,
+    pub fetch_time: i64,
+}
+
+impl std::convert::From<LinkPreviewTable> for LinkPreview {
+    fn from(table: LinkPreviewTable) -> Self {
+        LinkPreview {
+            url: table.url,
+            title: table.title,
+            description: table.description,
+            image_url: table.image_url,
+            site_name: table.site_name,
+            fetch_time: table.fetch_time,
+        }
+    }
+}
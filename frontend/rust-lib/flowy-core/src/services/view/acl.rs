@@ -0,0 +1,111 @@
+use crate::{
+    entities::workspace::WorkspaceRole,
+    errors::{FlowyError, FlowyResult},
+    services::workspace::role::{read_belonging_workspace_id, read_workspace_role},
+};
+use flowy_database::{
+    prelude::*,
+    result::OptionalExtension,
+    schema::{view_acl_table, view_acl_table::dsl, view_table},
+    slow_log::SlowLogTransaction,
+    SqliteConnection,
+};
+
+pub(crate) struct ViewAclTableSql {}
+
+impl ViewAclTableSql {
+    pub(crate) fn grant(view_id: &str, user_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let table = ViewAclTable {
+            view_id: view_id.to_owned(),
+            user_id: user_id.to_owned(),
+        };
+        let _ = diesel::replace_into(view_acl_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn revoke(view_id: &str, user_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::view_acl_table
+            .filter(view_acl_table::view_id.eq(view_id))
+            .filter(view_acl_table::user_id.eq(user_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+
+    // Replaces the access list of the view with the one of the server. Returns
+    // false if it was the same already.
+    pub(crate) fn replace(view_id: &str, user_ids: &[String], conn: &SqliteConnection) -> Result<bool, FlowyError> {
+        conn.timed_transaction::<_, FlowyError, _>("view.replace_view_acl", || {
+            let mut old_user_ids = Self::read_user_ids(view_id, conn)?;
+            let mut new_user_ids = user_ids.to_vec();
+            old_user_ids.sort();
+            new_user_ids.sort();
+            if old_user_ids == new_user_ids {
+                return Ok(false);
+            }
+
+            let _ = Self::delete_view_acl(view_id, conn)?;
+            for user_id in &new_user_ids {
+                let _ = Self::grant(view_id, user_id, conn)?;
+            }
+            Ok(true)
+        })
+    }
+
+    pub(crate) fn read_user_ids(view_id: &str, conn: &SqliteConnection) -> Result<Vec<String>, FlowyError> {
+        let user_ids = dsl::view_acl_table
+            .filter(view_acl_table::view_id.eq(view_id))
+            .select(view_acl_table::user_id)
+            .load::<String>(conn)?;
+        Ok(user_ids)
+    }
+
+    pub(crate) fn delete_view_acl(view_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::view_acl_table.filter(view_acl_table::view_id.eq(view_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "view_acl_table"]
+pub(crate) struct ViewAclTable {
+    pub view_id: String,
+    pub user_id: String,
+}
+
+// The owner of the workspace can access every view. For the other members,
+// the restriction of a view applies to the views nested in it as well.
+pub(crate) fn is_view_accessible(view_id: &str, user_id: &str, conn: &SqliteConnection) -> FlowyResult<bool> {
+    if let Some(workspace_id) = read_belonging_workspace_id(view_id, conn)? {
+        if read_workspace_role(&workspace_id, conn)? == WorkspaceRole::Owner {
+            return Ok(true);
+        }
+    }
+
+    let mut id = view_id.to_owned();
+    loop {
+        let user_ids = ViewAclTableSql::read_user_ids(&id, conn)?;
+        if !user_ids.is_empty() && !user_ids.iter().any(|member_id| member_id == user_id) {
+            return Ok(false);
+        }
+
+        match view_table::dsl::view_table
+            .filter(view_table::id.eq(&id))
+            .select(view_table::belong_to_id)
+            .first::<String>(conn)
+            .optional()?
+        {
+            None => return Ok(true),
+            Some(parent_id) => id = parent_id,
+        }
+    }
+}
+
+pub(crate) fn check_view_accessible(view_id: &str, user_id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
+    match is_view_accessible(view_id, user_id, conn)? {
+        true => Ok(()),
+        false => Err(FlowyError::view_access()),
+    }
+}
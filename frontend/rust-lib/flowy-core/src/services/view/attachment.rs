@@ -0,0 +1,79 @@
+use crate::{entities::view::Attachment, errors::FlowyError};
+use flowy_database::{
+    prelude::*,
+    result::OptionalExtension,
+    schema::{attachment_table, attachment_table::dsl},
+    SqliteConnection,
+};
+
+pub(crate) struct AttachmentTableSql {}
+
+impl AttachmentTableSql {
+    pub(crate) fn create_attachment(table: AttachmentTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = diesel::replace_into(attachment_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn try_read_attachment(
+        id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<AttachmentTable>, FlowyError> {
+        let table = dsl::attachment_table
+            .filter(attachment_table::id.eq(id))
+            .first::<AttachmentTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+
+    // The attachments that haven't been uploaded to the storage yet.
+    pub(crate) fn read_unsynced(conn: &SqliteConnection) -> Result<Vec<AttachmentTable>, FlowyError> {
+        let tables = dsl::attachment_table
+            .filter(attachment_table::remote_key.eq(""))
+            .order(attachment_table::create_time.asc())
+            .load::<AttachmentTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn set_remote_key(id: &str, remote_key: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::attachment_table.filter(attachment_table::id.eq(id));
+        let _ = diesel::update(filter)
+            .set(attachment_table::remote_key.eq(remote_key))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_view_attachments(view_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::attachment_table.filter(attachment_table::view_id.eq(view_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "attachment_table"]
+pub(crate) struct AttachmentTable {
+    pub id: String,
+    pub view_id: String,
+    pub name: String,
+    pub mime_type: String,
+    pub data: Vec<u8>,
+    pub create_time: i64,
+    // The key of the object in the storage of the workspace, empty until the
+    // attachment is uploaded.
+    pub remote_key: String,
+}
+
+impl std::convert::From<AttachmentTable> for Attachment {
+    fn from(table: AttachmentTable) -> Self {
+        Attachment {
+            id: table.id,
+            view_id: table.view_id,
+            name: table.name,
+            mime_type: table.mime_type,
+            data: table.data,
+            create_time: table.create_time,
+        }
+    }
+}
@@ -1,40 +1,133 @@
-use bytes::Bytes;
-use flowy_collaboration::entities::{
-    doc::{DocumentDelta, DocumentId},
-    revision::{RepeatedRevision, Revision},
-};
-use flowy_database::SqliteConnection;
+use dart_notify::entities::BatchedChange;
+use flowy_collaboration::entities::doc::{DocumentDelta, DocumentId};
+use flowy_database::{slow_log::SlowLogTransaction, SqliteConnection};
 use futures::{FutureExt, StreamExt};
-use std::{collections::HashSet, sync::Arc};
+use serde_json::json;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use crate::{
     entities::{
+        app::CreateAppParams,
+        folder_sync::{BatchCreateParams, BatchUpdateParams},
         trash::{RepeatedTrashId, TrashType},
-        view::{CreateViewParams, RepeatedView, UpdateViewParams, View, ViewId},
+        view::{
+            Attachment,
+            CodeBlockParams,
+            CodeBlockTokens,
+            CollapsedToggles,
+            CreateViewCheckpointParams,
+            CreateViewParams,
+            DuplicateViewParams,
+            ExportQuarantinedRevisionsParams,
+            ExportQuarantinedRevisionsResult,
+            ItemMoved,
+            MoveItem,
+            MoveItemType,
+            MoveItemsParams,
+            MoveViewParams,
+            RepeatedItemMoved,
+            RepeatedSyntaxToken,
+            RepeatedView,
+            RepeatedViewCheckpoint,
+            SyntaxToken,
+            ToggleStateParams,
+            UpdateViewAccessParams,
+            UpdateViewParams,
+            UpdateViewTagsParams,
+            View,
+            ViewAccess,
+            ViewCheckpoint,
+            ViewCheckpointId,
+            ViewId,
+            ViewMoved,
+            ViewTags,
+            ViewType,
+        },
+        workspace::WorkspaceSettings,
     },
-    errors::{FlowyError, FlowyResult},
+    errors::{internal_error, FlowyError, FlowyResult, RecoveryHint},
+    folder_change::{FolderChange, FolderChangeNotifier},
     module::{WorkspaceDatabase, WorkspaceUser},
-    notify::{send_dart_notification, WorkspaceNotification},
+    name_rules::NameRules,
+    notify::{send_anonymous_dart_notification, send_dart_notification, WorkspaceNotification},
+    retry::{ServerCallClass, ServerRetry},
     services::{
+        app::sql::AppTableSql,
+        cache::invalidate_folder_cache,
+        event_log::sql::{EventLogTableSql, EventLogType},
+        notify_apps_changed,
         server::Server,
-        view::sql::{ViewTable, ViewTableChangeset, ViewTableSql},
+        view::{
+            acl::{check_view_accessible, is_view_accessible, ViewAclTableSql},
+            attachment::{AttachmentTable, AttachmentTableSql},
+            processor::{ViewDataProcessor, ViewDataProcessorMap},
+            sql::{ViewTable, ViewTableChangeset, ViewTableSql},
+            tag::ViewTagTableSql,
+        },
+        workspace::{
+            retention::{read_revision_retention, revision_retention},
+            role::{
+                check_belonging_editable,
+                check_workspace_editable,
+                check_workspace_owner,
+                read_belonging_workspace_id,
+            },
+            setting::read_workspace_settings,
+            skeleton::invalidate_workspace_skeleton,
+        },
+        AuditAction,
+        AuditLogController,
+        AuditRecord,
+        CloudOutbox,
+        JournalEntry,
+        OperationJournal,
+        OutboxOp,
         TrashController,
         TrashEvent,
+        WebhookController,
     },
 };
-use flowy_core_data_model::entities::share::{ExportData, ExportParams};
+use flowy_core_data_model::entities::share::{
+    CreateGuestAccessParams,
+    CreateShareLinkParams,
+    ExportData,
+    ExportParams,
+    GuestAccess,
+    GuestAccessId,
+    RepeatedGuestAccess,
+    RepeatedShareLink,
+    ShareLink,
+    ShareLinkId,
+    SharedDocument,
+};
 use flowy_database::kv::KV;
-use flowy_document::context::DocumentContext;
-use lib_infra::uuid_string;
+use flowy_document::{
+    context::DocumentContext,
+    core::{RevisionCheckpoint, RevisionRecord},
+};
+use lib_infra::clock::RuntimeEnv;
+use lib_ot::rich_text::{RichTextDelta, RichTextDeltaBuilder};
 
 const LATEST_VIEW_ID: &str = "latest_view_id";
 
 pub(crate) struct ViewController {
     user: Arc<dyn WorkspaceUser>,
     server: Server,
+    server_retry: ServerRetry,
     database: Arc<dyn WorkspaceDatabase>,
     trash_controller: Arc<TrashController>,
+    webhook: Arc<WebhookController>,
+    audit_log: Arc<AuditLogController>,
+    folder_change_notifier: FolderChangeNotifier,
+    name_rules: NameRules,
     document_ctx: Arc<DocumentContext>,
+    data_processors: ViewDataProcessorMap,
+    journal: Arc<OperationJournal>,
+    outbox: Arc<CloudOutbox>,
+    env: RuntimeEnv,
 }
 
 impl ViewController {
@@ -42,15 +135,33 @@ impl ViewController {
         user: Arc<dyn WorkspaceUser>,
         database: Arc<dyn WorkspaceDatabase>,
         server: Server,
+        server_retry: ServerRetry,
         trash_can: Arc<TrashController>,
+        webhook: Arc<WebhookController>,
+        audit_log: Arc<AuditLogController>,
+        folder_change_notifier: FolderChangeNotifier,
+        name_rules: NameRules,
         document_ctx: Arc<DocumentContext>,
+        data_processors: ViewDataProcessorMap,
+        journal: Arc<OperationJournal>,
+        outbox: Arc<CloudOutbox>,
+        env: RuntimeEnv,
     ) -> Self {
         Self {
             user,
             server,
+            server_retry,
             database,
             trash_controller: trash_can,
+            webhook,
+            audit_log,
+            folder_change_notifier,
+            name_rules,
             document_ctx,
+            data_processors,
+            journal,
+            outbox,
+            env,
         }
     }
 
@@ -62,32 +173,136 @@ impl ViewController {
 
     #[tracing::instrument(level = "debug", skip(self, params), fields(name = %params.name), err)]
     pub(crate) async fn create_view_from_params(&self, params: CreateViewParams) -> Result<View, FlowyError> {
-        let delta_data = Bytes::from(params.view_data.clone());
-        let user_id = self.user.user_id()?;
-        let repeated_revision: RepeatedRevision =
-            Revision::initial_revision(&user_id, &params.view_id, delta_data).into();
-        let _ = self
-            .document_ctx
-            .controller
-            .save_document(&params.view_id, repeated_revision)
-            .await?;
+        if let Some(view) = self.read_created_view(&params)? {
+            return Ok(view);
+        }
+        let _ = self.check_view_editable(&params.belong_to_id)?;
+        let _ = self.check_view_name(
+            &params.name,
+            &params.belong_to_id,
+            &params.view_id,
+            &*self.database.db_connection()?,
+        )?;
+        let _ = self.save_view_data(&params).await?;
         let view = self.create_view_on_server(params).await?;
         let _ = self.create_view_on_local(view.clone()).await?;
+        self.webhook_view_did_create(&view);
 
         Ok(view)
     }
 
+    // Anonymous users don't have an account on the server, so their views are only
+    // written to the local database until they sign up.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(name = %params.name), err)]
+    pub(crate) async fn create_local_view_from_params(&self, params: CreateViewParams) -> Result<View, FlowyError> {
+        if let Some(view) = self.read_created_view(&params)? {
+            return Ok(view);
+        }
+        let _ = self.check_view_editable(&params.belong_to_id)?;
+        let _ = self.check_view_name(
+            &params.name,
+            &params.belong_to_id,
+            &params.view_id,
+            &*self.database.db_connection()?,
+        )?;
+        let _ = self.save_view_data(&params).await?;
+        let time = self.env.timestamp();
+        let view = View {
+            id: params.view_id,
+            belong_to_id: params.belong_to_id,
+            name: params.name,
+            desc: params.desc,
+            view_type: params.view_type,
+            version: 0,
+            belongings: RepeatedView::default(),
+            modified_time: time,
+            create_time: time,
+        };
+        let _ = self.create_view_on_local(view.clone()).await?;
+        self.webhook_view_did_create(&view);
+
+        Ok(view)
+    }
+
+    // The view of a create that is retried with the same id, e.g. after the
+    // response got lost, is returned as it is instead of being created again.
+    fn read_created_view(&self, params: &CreateViewParams) -> Result<Option<View>, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        match ViewTableSql::read_belong_to_id(&params.view_id, conn)? {
+            None => Ok(None),
+            Some(belong_to_id) if belong_to_id == params.belong_to_id => {
+                let view_table = ViewTableSql::read_view(&params.view_id, conn)?;
+                Ok(Some(view_table.into()))
+            },
+            Some(_) => Err(FlowyError::invalid_view_id().context("The view id is used by another view")),
+        }
+    }
+
+    // Creates a view of an import. Its name gets a suffix if the name rules
+    // ask for it and the name is used already.
+    pub(crate) async fn create_imported_view(&self, mut params: CreateViewParams) -> Result<View, FlowyError> {
+        if self.name_rules.suffix_duplicates_on_import {
+            let sibling_names =
+                self.read_sibling_names(&params.belong_to_id, &params.view_id, &*self.database.db_connection()?)?;
+            params.name = self.name_rules.free_name(&params.name, &sibling_names);
+        }
+        self.create_view_from_params(params).await
+    }
+
+    fn check_view_name(
+        &self,
+        name: &str,
+        belong_to_id: &str,
+        view_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
+        let _ = self.name_rules.check(name)?;
+        if self.name_rules.unique_in_parent {
+            let sibling_names = self.read_sibling_names(belong_to_id, view_id, conn)?;
+            let _ = self.name_rules.check_unique(name, &sibling_names)?;
+        }
+        Ok(())
+    }
+
+    // The names of the views in `belong_to_id` other than `view_id`. The views
+    // in the trash are left out.
+    fn read_sibling_names(
+        &self,
+        belong_to_id: &str,
+        view_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<String>, FlowyError> {
+        let trash_ids = self.trash_controller.read_trash_ids(conn)?;
+        let names = ViewTableSql::read_views(belong_to_id, conn)?
+            .into_iter()
+            .filter(|view_table| view_table.id != view_id && !trash_ids.contains(&view_table.id))
+            .map(|view_table| view_table.name)
+            .collect();
+        Ok(names)
+    }
+
+    fn webhook_view_did_create(&self, view: &View) { self.webhook.view_did_create(view_log_payload(view)); }
+
     pub(crate) async fn create_view_on_local(&self, view: View) -> Result<(), FlowyError> {
         let conn = &*self.database.db_connection()?;
         let trash_can = self.trash_controller.clone();
+        let user_id = self.user.user_id()?;
 
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
+        let change = FolderChange::ViewCreated {
+            belong_to_id: view.belong_to_id.clone(),
+            view_id: view.id.clone(),
+        };
+        conn.timed_transaction::<_, FlowyError, _>("view.create_view_on_local", || {
             let belong_to_id = view.belong_to_id.clone();
+            let _ = EventLogTableSql::append(EventLogType::ViewCreated, &view.id, view_log_payload(&view), conn)?;
+            let record = AuditRecord::view(AuditAction::Created, None, Some(&ViewTable::new(view.clone())), conn)?;
             let _ = self.save_view(view, conn)?;
-            let _ = notify_views_changed(&belong_to_id, trash_can, &conn)?;
+            let _ = self.audit_log.record(record, conn)?;
+            let _ = notify_views_changed(&belong_to_id, &user_id, trash_can, &conn)?;
 
             Ok(())
         })?;
+        self.folder_change_notifier.notify(change);
 
         Ok(())
     }
@@ -107,16 +322,37 @@ impl ViewController {
         if trash_ids.contains(&view_table.id) {
             return Err(FlowyError::record_not_found());
         }
+        // The access list may have been changed by the owner on another device,
+        // the view can be read next time if it was granted meanwhile.
+        let _ = self.read_view_access_on_server(params.clone());
+        let _ = check_view_accessible(&view_table.id, &self.user.user_id()?, &*conn)?;
 
         let view: View = view_table.into();
         let _ = self.read_view_on_server(params);
         Ok(view)
     }
 
+    // The viewers of the workspace can't create, change or delete the views.
+    pub(crate) fn check_view_editable(&self, id: &str) -> Result<(), FlowyError> {
+        let conn = self.database.db_connection()?;
+        check_belonging_editable(id, &*conn)
+    }
+
+    // The view type of the requests that don't set one. The apps and views
+    // that aren't in a workspace yet get the default of the settings.
+    pub(crate) fn default_view_type(&self, belong_to_id: &str) -> Result<ViewType, FlowyError> {
+        let conn = self.database.db_connection()?;
+        let settings = match read_belonging_workspace_id(belong_to_id, &*conn)? {
+            None => WorkspaceSettings::default(),
+            Some(workspace_id) => read_workspace_settings(&workspace_id, &*self.user, &*conn)?,
+        };
+        Ok(settings.default_view_type)
+    }
+
     pub(crate) fn read_view_tables(&self, ids: Vec<String>) -> Result<Vec<ViewTable>, FlowyError> {
         let conn = &*self.database.db_connection()?;
         let mut view_tables = vec![];
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
+        conn.timed_transaction::<_, FlowyError, _>("view.read_view_tables", || {
             for view_id in ids {
                 view_tables.push(ViewTableSql::read_view(&view_id, conn)?);
             }
@@ -129,19 +365,88 @@ impl ViewController {
     #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
     pub(crate) async fn open_view(&self, params: DocumentId) -> Result<DocumentDelta, FlowyError> {
         let doc_id = params.doc_id.clone();
-        let editor = self.document_ctx.controller.open_document(&params.doc_id).await?;
+        let _ = check_view_accessible(&doc_id, &self.user.user_id()?, &*self.database.db_connection()?)?;
+        let processor = self.view_data_processor(&doc_id)?;
+        let document_json = processor.open_view(&doc_id).await?;
 
-        KV::set_str(LATEST_VIEW_ID, doc_id.clone());
-        let document_json = editor.document_json().await?;
+        let _ = save_latest_view(&doc_id, &*self.database.db_connection()?)?;
         Ok(DocumentDelta {
             doc_id,
             delta_json: document_json,
         })
     }
 
+    // The grids keep their content as json in the text of the document.
+    pub(crate) async fn read_view_text(&self, view_id: &str) -> Result<String, FlowyError> {
+        let _ = check_view_accessible(view_id, &self.user.user_id()?, &*self.database.db_connection()?)?;
+        let editor = self.document_ctx.controller.open_document(view_id).await?;
+        editor.document_text().await
+    }
+
+    pub(crate) async fn read_view_delta(&self, view_id: &str) -> Result<RichTextDelta, FlowyError> {
+        let _ = check_view_accessible(view_id, &self.user.user_id()?, &*self.database.db_connection()?)?;
+        let editor = self.document_ctx.controller.open_document(view_id).await?;
+        let delta_json = editor.document_json().await?;
+        RichTextDelta::from_json(&delta_json).map_err(internal_error)
+    }
+
+    // The text is written back as one delta that replaces only the part of the
+    // old text that changed.
+    pub(crate) async fn write_view_text(
+        &self,
+        view_id: &str,
+        old_text: &str,
+        new_text: &str,
+    ) -> Result<(), FlowyError> {
+        let delta = DocumentDelta {
+            doc_id: view_id.to_owned(),
+            delta_json: make_replace_delta_json(old_text, new_text),
+        };
+        let _ = self.receive_document_delta(delta).await?;
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip(self,params), fields(doc_id = %params.doc_id), err)]
     pub(crate) async fn close_view(&self, params: DocumentId) -> Result<(), FlowyError> {
-        let _ = self.document_ctx.controller.close_document(&params.doc_id)?;
+        let _ = self.view_data_processor(&params.doc_id)?.close_view(&params.doc_id)?;
+        Ok(())
+    }
+
+    pub(crate) fn close_all_views(&self) { self.document_ctx.controller.close_all_documents(); }
+
+    pub(crate) async fn flush_views(&self) -> Result<(), FlowyError> { self.document_ctx.controller.flush().await }
+
+    // Squashes the history of the documents that is older than the retention
+    // of their workspace. It runs in the background once the workspace is
+    // opened.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn prune_revisions(&self) -> Result<(), FlowyError> {
+        let workspaces = {
+            let conn = &*self.database.db_connection()?;
+            let mut view_ids: HashMap<String, Vec<String>> = HashMap::new();
+            for view in ViewTableSql::read_all_views(conn)? {
+                if let Some(workspace_id) = read_belonging_workspace_id(&view.belong_to_id, conn)? {
+                    view_ids.entry(workspace_id).or_insert_with(Vec::new).push(view.id);
+                }
+            }
+
+            let mut workspaces = vec![];
+            for (workspace_id, view_ids) in view_ids {
+                let setting = read_revision_retention(&workspace_id, &*self.user, conn)?;
+                workspaces.push((revision_retention(&setting), view_ids));
+            }
+            workspaces
+        };
+
+        let mut pruned = 0;
+        for (retention, view_ids) in workspaces {
+            pruned += self
+                .document_ctx
+                .controller
+                .prune_revisions(view_ids, retention)
+                .await?;
+        }
+        tracing::debug!("Pruned {} revisions", pruned);
         Ok(())
     }
 
@@ -152,45 +457,550 @@ impl ViewController {
                 let _ = KV::remove(LATEST_VIEW_ID);
             }
         }
-        let _ = self.document_ctx.controller.close_document(&params.doc_id)?;
+        if let Some(workspace_id) = read_belonging_workspace_id(&params.doc_id, &*self.database.db_connection()?)? {
+            let key = latest_view_key(&workspace_id);
+            if KV::get_str(&key).as_ref() == Some(&params.doc_id) {
+                let _ = KV::remove(&key);
+            }
+        }
+        let _ = self.view_data_processor(&params.doc_id)?.close_view(&params.doc_id)?;
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
-    pub(crate) async fn duplicate_view(&self, params: DocumentId) -> Result<(), FlowyError> {
-        let view: View = ViewTableSql::read_view(&params.doc_id, &*self.database.db_connection()?)?.into();
-        let editor = self.document_ctx.controller.open_document(&params.doc_id).await?;
-        let document_json = editor.document_json().await?;
-        let duplicate_params = CreateViewParams {
-            belong_to_id: view.belong_to_id.clone(),
-            name: format!("{} (copy)", &view.name),
+    #[tracing::instrument(level = "debug", skip(self, params), fields(view_id = %params.view_id), err)]
+    pub(crate) async fn duplicate_view(&self, params: DuplicateViewParams) -> Result<(), FlowyError> {
+        let view: View = ViewTableSql::read_view(&params.view_id, &*self.database.db_connection()?)?.into();
+        let mut name = format!("{} (copy)", &view.name);
+        if self.name_rules.unique_in_parent {
+            let sibling_names =
+                self.read_sibling_names(&view.belong_to_id, &view.id, &*self.database.db_connection()?)?;
+            name = self.name_rules.free_name(&name, &sibling_names);
+        }
+        let mut entry = self.journal.begin("duplicate_view")?;
+        match self
+            .copy_view(&view, &view.belong_to_id, name, params.with_history, &mut entry)
+            .await
+        {
+            Ok(_) => self.journal.commit(entry),
+            Err(e) => {
+                if let Err(rollback_error) = self.journal.rollback(entry).await {
+                    tracing::error!("Roll back the duplicate of {} failed: {}", view.id, rollback_error);
+                }
+                Err(e)
+            },
+        }
+    }
+
+    // Copies the views that belong to `from_belong_to_id`, together with their
+    // nested views, into `to_belong_to_id`. Returns the copies of the top level
+    // views. Each copy is recorded in the entry before it's created.
+    #[tracing::instrument(level = "debug", skip(self, entry), err)]
+    pub(crate) async fn duplicate_belonging_views(
+        &self,
+        from_belong_to_id: &str,
+        to_belong_to_id: &str,
+        entry: &mut JournalEntry,
+    ) -> Result<RepeatedView, FlowyError> {
+        let mut copies = vec![];
+        let mut belongings = vec![(from_belong_to_id.to_owned(), to_belong_to_id.to_owned())];
+        while let Some((from_id, to_id)) = belongings.pop() {
+            for view in self.read_views_belong_to(&from_id).await?.into_inner() {
+                let copy = self.copy_view(&view, &to_id, view.name.clone(), false, entry).await?;
+                belongings.push((view.id.clone(), copy.id.clone()));
+                if to_id == to_belong_to_id {
+                    copies.push(copy);
+                }
+            }
+        }
+        Ok(RepeatedView { items: copies })
+    }
+
+    // The revisions of the copy with the history are written after it's
+    // created, they are synced once the copy is opened.
+    async fn copy_view(
+        &self,
+        view: &View,
+        belong_to_id: &str,
+        name: String,
+        with_history: bool,
+        entry: &mut JournalEntry,
+    ) -> Result<View, FlowyError> {
+        let view_id = self.env.next_id();
+        let processor = self.data_processors.get(&view.view_type)?;
+        let data_copy = processor.duplicate_view(&view.id, &view_id, with_history).await?;
+        let params = CreateViewParams {
+            belong_to_id: belong_to_id.to_owned(),
+            name,
             desc: view.desc.clone(),
             thumbnail: "".to_owned(),
             view_type: view.view_type.clone(),
-            view_data: document_json,
-            view_id: uuid_string(),
+            view_data: data_copy.view_data,
+            view_id,
         };
+        let _ = self.journal.record_view(entry, &params.view_id)?;
+        let copy = self.create_view_from_params(params).await?;
+        if !data_copy.revisions.is_empty() {
+            let _ = processor.save_revisions(&copy.id, data_copy.revisions).await?;
+        }
+        Ok(copy)
+    }
 
-        let _ = self.create_view_from_params(duplicate_params).await?;
-        Ok(())
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn move_view(&self, params: MoveViewParams) -> Result<View, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let _ = check_belonging_editable(&params.view_id, conn)?;
+        let _ = check_belonging_editable(&params.to_belong_to_id, conn)?;
+        if is_nested_in(&params.to_belong_to_id, &params.view_id, conn)? {
+            return Err(FlowyError::view_move().context("The view can't be moved into its nested views"));
+        }
+
+        let view_table = ViewTableSql::read_view(&params.view_id, conn)?;
+        // The name was checked when it was set, only the new siblings matter.
+        if self.name_rules.unique_in_parent && view_table.belong_to_id != params.to_belong_to_id {
+            let sibling_names = self.read_sibling_names(&params.to_belong_to_id, &params.view_id, conn)?;
+            let _ = self.name_rules.check_unique(&view_table.name, &sibling_names)?;
+        }
+        let from_belong_to_id = view_table.belong_to_id.clone();
+        let update_params = UpdateViewParams {
+            belong_to_id: Some(params.to_belong_to_id.clone()),
+            ..UpdateViewParams::new(&params.view_id)
+        };
+        let changeset = ViewTableChangeset::new(update_params.clone());
+        let view = conn.timed_transaction::<_, FlowyError, _>("view.move_view", || {
+            let _ = ViewTableSql::update_view(changeset, conn)?;
+            let moved_table = ViewTableSql::read_view(&params.view_id, conn)?;
+            let record = AuditRecord::view(AuditAction::Moved, Some(&view_table), Some(&moved_table), conn)?;
+            let _ = self.audit_log.record(record, conn)?;
+            let view: View = moved_table.into();
+            let payload = json!({
+                "id": view.id,
+                "from_belong_to_id": from_belong_to_id,
+                "to_belong_to_id": view.belong_to_id,
+            });
+            let _ = EventLogTableSql::append(EventLogType::ViewMoved, &view.id, payload, conn)?;
+            Ok(view)
+        })?;
+
+        let moved = ViewMoved {
+            view_id: view.id.clone(),
+            from_belong_to_id: from_belong_to_id.clone(),
+            to_belong_to_id: view.belong_to_id.clone(),
+        };
+        send_dart_notification(&view.id, WorkspaceNotification::ViewMoved)
+            .payload(moved)
+            .send();
+        self.folder_change_notifier.notify(FolderChange::ViewMoved {
+            view_id: view.id.clone(),
+            from_belong_to_id: from_belong_to_id.clone(),
+            to_belong_to_id: view.belong_to_id.clone(),
+        });
+
+        let user_id = self.user.user_id()?;
+        for belong_to_id in &[from_belong_to_id, view.belong_to_id.clone()] {
+            let _ = notify_views_changed(belong_to_id, &user_id, self.trash_controller.clone(), conn)?;
+        }
+        let _ = self.update_view_on_server(update_params);
+        Ok(view)
+    }
+
+    // Moves the items in one transaction, either all of them are moved or none.
+    // The parents are notified once all the items are moved.
+    #[tracing::instrument(level = "debug", skip(self, params), err)]
+    pub(crate) async fn move_items(&self, params: MoveItemsParams) -> Result<RepeatedItemMoved, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let result = conn.timed_transaction::<_, FlowyError, _>("view.move_items", || {
+            let trash_ids = self.trash_controller.read_trash_ids(conn)?;
+            let mut moved = vec![];
+            for item in params.items.iter() {
+                let from_parent_id = match item.ty {
+                    MoveItemType::View => self.move_view_item(item, &trash_ids, conn)?,
+                    MoveItemType::App => move_app_item(item, &trash_ids, &self.audit_log, conn)?,
+                };
+                moved.push(ItemMoved {
+                    id: item.id.clone(),
+                    ty: item.ty.clone(),
+                    from_parent_id,
+                    to_parent_id: item.to_parent_id.clone(),
+                    index: 0,
+                });
+            }
+
+            // The later items may have moved the earlier ones within the parent.
+            for item in moved.iter_mut() {
+                let ids = read_ordered_ids(&item.ty, &item.to_parent_id, &trash_ids, conn)?;
+                item.index = ids.iter().position(|id| id == &item.id).unwrap_or(0) as i32;
+            }
+            Ok(moved)
+        });
+        let moved = match result {
+            Ok(moved) => moved,
+            Err(e) => {
+                // The caches may hold what the transaction wrote before it was
+                // rolled back.
+                invalidate_folder_cache();
+                invalidate_workspace_skeleton();
+                return Err(e);
+            },
+        };
+
+        let user_id = self.user.user_id()?;
+        let mut moved_views = vec![];
+        for item in moved.iter() {
+            match item.ty {
+                MoveItemType::View => {
+                    for belong_to_id in &[&item.from_parent_id, &item.to_parent_id] {
+                        let _ = notify_views_changed(belong_to_id, &user_id, self.trash_controller.clone(), conn)?;
+                    }
+                    if item.from_parent_id != item.to_parent_id {
+                        self.folder_change_notifier.notify(FolderChange::ViewMoved {
+                            view_id: item.id.clone(),
+                            from_belong_to_id: item.from_parent_id.clone(),
+                            to_belong_to_id: item.to_parent_id.clone(),
+                        });
+                        moved_views.push(UpdateViewParams {
+                            belong_to_id: Some(item.to_parent_id.clone()),
+                            ..UpdateViewParams::new(&item.id)
+                        });
+                    }
+                },
+                MoveItemType::App => {
+                    let _ = notify_apps_changed(&item.to_parent_id, self.trash_controller.clone(), conn)?;
+                    self.folder_change_notifier.notify(FolderChange::AppUpdated {
+                        app_id: item.id.clone(),
+                    });
+                },
+            }
+        }
+        if !moved_views.is_empty() {
+            let _ = self.batch_update_on_server(BatchUpdateParams {
+                apps: vec![],
+                views: moved_views,
+            });
+        }
+        let moved = RepeatedItemMoved { items: moved };
+        send_anonymous_dart_notification(WorkspaceNotification::ItemsMoved)
+            .payload(moved.clone())
+            .send();
+        Ok(moved)
+    }
+
+    // Returns the parent that the view was in.
+    fn move_view_item(&self, item: &MoveItem, trash_ids: &[String], conn: &SqliteConnection) -> FlowyResult<String> {
+        let _ = check_belonging_editable(&item.id, conn)?;
+        let _ = check_belonging_editable(&item.to_parent_id, conn)?;
+        if is_nested_in(&item.to_parent_id, &item.id, conn)? {
+            return Err(FlowyError::view_move().context("The view can't be moved into its nested views"));
+        }
+
+        let view_table = ViewTableSql::read_view(&item.id, conn)?;
+        if view_table.belong_to_id != item.to_parent_id {
+            if self.name_rules.unique_in_parent {
+                let sibling_names = self.read_sibling_names(&item.to_parent_id, &item.id, conn)?;
+                let _ = self.name_rules.check_unique(&view_table.name, &sibling_names)?;
+            }
+            let update_params = UpdateViewParams {
+                belong_to_id: Some(item.to_parent_id.clone()),
+                ..UpdateViewParams::new(&item.id)
+            };
+            let _ = ViewTableSql::update_view(ViewTableChangeset::new(update_params), conn)?;
+            let moved_table = ViewTableSql::read_view(&item.id, conn)?;
+            let record = AuditRecord::view(AuditAction::Moved, Some(&view_table), Some(&moved_table), conn)?;
+            let _ = self.audit_log.record(record, conn)?;
+            let payload = json!({
+                "id": item.id,
+                "from_belong_to_id": view_table.belong_to_id,
+                "to_belong_to_id": item.to_parent_id,
+            });
+            let _ = EventLogTableSql::append(EventLogType::ViewMoved, &item.id, payload, conn)?;
+        }
+
+        let views = ViewTableSql::read_views(&item.to_parent_id, conn)?
+            .into_iter()
+            .filter(|view_table| !trash_ids.contains(&view_table.id))
+            .map(|view_table| (view_table.id, view_table.create_time))
+            .collect::<Vec<_>>();
+        for (view_id, create_time) in reposition(&views, &item.id, item.index as usize) {
+            let _ = ViewTableSql::update_create_time(&view_id, create_time, conn)?;
+        }
+        Ok(view_table.belong_to_id)
     }
 
     #[tracing::instrument(level = "debug", skip(self, params), err)]
     pub(crate) async fn export_doc(&self, params: ExportParams) -> Result<ExportData, FlowyError> {
-        let editor = self.document_ctx.controller.open_document(&params.doc_id).await?;
-        let delta_json = editor.document_json().await?;
+        let processor = self.view_data_processor(&params.doc_id)?;
+        let data = processor
+            .export_view(&params.doc_id, params.export_type.clone())
+            .await?;
         Ok(ExportData {
-            data: delta_json,
+            data,
             export_type: params.export_type,
         })
     }
 
+    // The link is registered on the server, which serves the document to anyone
+    // who opens the url until the link expires or is revoked.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(view_id = %params.view_id), err)]
+    pub(crate) async fn create_share_link(&self, params: CreateShareLinkParams) -> Result<ShareLink, FlowyError> {
+        let conn = self.database.db_connection()?;
+        let view_table = ViewTableSql::read_view(&params.view_id, &*conn)?;
+        if self.trash_controller.read_trash_ids(&conn)?.contains(&view_table.id) {
+            return Err(FlowyError::record_not_found());
+        }
+        let _ = check_belonging_editable(&view_table.id, &*conn)?;
+        let _ = self.server_retry.check_account()?;
+
+        // The link is sent with a notification once the queued call created
+        // it, the caller is told that it was queued.
+        if self.outbox.has_pending()? {
+            let _ = self.outbox.enqueue(OutboxOp::CreateShareLink(params))?;
+            self.outbox.spawn_replay();
+            return Err(FlowyError::cloud_operation_queued());
+        }
+        let token = self.user.token()?;
+        match self.server.create_share_link(&token, params.clone()).await {
+            Ok(link) => Ok(link),
+            Err(e) if e.recovery_hint() == RecoveryHint::Retryable => {
+                let _ = self.outbox.enqueue(OutboxOp::CreateShareLink(params))?;
+                Err(FlowyError::cloud_operation_queued().context(e.msg))
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    pub(crate) async fn read_share_links(&self, params: ViewId) -> Result<RepeatedShareLink, FlowyError> {
+        let read_links = self.server_retry.call(ServerCallClass::Read, move |token, server| {
+            server.read_share_links(&token, params.clone())
+        })?;
+        let repeated_link = read_links.await?;
+        Ok(repeated_link)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(link_id = %params.link_id), err)]
+    pub(crate) async fn revoke_share_link(&self, params: ShareLinkId) -> Result<(), FlowyError> {
+        let revoke_link = self.server_retry.call(ServerCallClass::Write, move |token, server| {
+            server.revoke_share_link(&token, params.clone())
+        })?;
+        let _ = revoke_link.await?;
+        Ok(())
+    }
+
+    pub(crate) async fn read_shared_document(&self, params: ShareLinkId) -> Result<SharedDocument, FlowyError> {
+        let document = self.server.read_shared_document(params).await?;
+        Ok(document)
+    }
+
+    // The guest gets a token that only resolves the view and its document, so
+    // the rest of the workspace stays hidden from the guest.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(view_id = %params.view_id), err)]
+    pub(crate) async fn create_guest_access(&self, params: CreateGuestAccessParams) -> Result<GuestAccess, FlowyError> {
+        {
+            let conn = &*self.database.db_connection()?;
+            let view_table = ViewTableSql::read_view(&params.view_id, conn)?;
+            if self.trash_controller.read_trash_ids(conn)?.contains(&view_table.id) {
+                return Err(FlowyError::record_not_found());
+            }
+            let _ = check_belonging_editable(&view_table.id, conn)?;
+        }
+
+        let _ = self.server_retry.check_account()?;
+        let token = self.user.token()?;
+        let access = self.server.create_guest_access(&token, params).await?;
+        Ok(access)
+    }
+
+    pub(crate) async fn read_guest_accesses(&self, params: ViewId) -> Result<RepeatedGuestAccess, FlowyError> {
+        let read_accesses = self.server_retry.call(ServerCallClass::Read, move |token, server| {
+            server.read_guest_accesses(&token, params.clone())
+        })?;
+        let repeated_access = read_accesses.await?;
+        Ok(repeated_access)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(access_id = %params.access_id), err)]
+    pub(crate) async fn revoke_guest_access(&self, params: GuestAccessId) -> Result<(), FlowyError> {
+        let revoke_access = self.server_retry.call(ServerCallClass::Write, move |token, server| {
+            server.revoke_guest_access(&token, params.clone())
+        })?;
+        let _ = revoke_access.await?;
+        Ok(())
+    }
+
+    // Returns the local access list, the one of the server is sent with
+    // a notification if it differs.
+    pub(crate) fn read_view_access(&self, params: ViewId) -> Result<ViewAccess, FlowyError> {
+        let conn = self.database.db_connection()?;
+        let user_ids = ViewAclTableSql::read_user_ids(&params.view_id, &*conn)?;
+        let _ = self.read_view_access_on_server(params.clone());
+        Ok(ViewAccess {
+            view_id: params.view_id,
+            user_ids,
+        })
+    }
+
+    // Granting the access to the first member restricts the view, and revoking
+    // the last one opens it to the whole workspace again.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn grant_view_access(&self, params: UpdateViewAccessParams) -> Result<ViewAccess, FlowyError> {
+        let _ = self.check_view_access_manageable(&params.view_id)?;
+        let server_params = params.clone();
+        let grant_access = self.server_retry.call(ServerCallClass::Write, move |token, server| {
+            server.grant_view_access(&token, server_params.clone())
+        })?;
+        let _ = grant_access.await?;
+
+        let _ = ViewAclTableSql::grant(&params.view_id, &params.user_id, &*self.database.db_connection()?)?;
+        self.notify_view_access_changed(&params.view_id)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn revoke_view_access(&self, params: UpdateViewAccessParams) -> Result<ViewAccess, FlowyError> {
+        let _ = self.check_view_access_manageable(&params.view_id)?;
+        let server_params = params.clone();
+        let revoke_access = self.server_retry.call(ServerCallClass::Write, move |token, server| {
+            server.revoke_view_access(&token, server_params.clone())
+        })?;
+        let _ = revoke_access.await?;
+
+        let _ = ViewAclTableSql::revoke(&params.view_id, &params.user_id, &*self.database.db_connection()?)?;
+        self.notify_view_access_changed(&params.view_id)
+    }
+
+    pub(crate) fn read_view_tags(&self, params: ViewId) -> Result<ViewTags, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let _ = check_view_accessible(&params.view_id, &self.user.user_id()?, conn)?;
+        let tags = ViewTagTableSql::read_tags(&params.view_id, conn)?;
+        Ok(ViewTags {
+            view_id: params.view_id,
+            tags,
+        })
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn update_view_tags(&self, params: UpdateViewTagsParams) -> Result<ViewTags, FlowyError> {
+        let _ = self.check_view_editable(&params.view_id)?;
+        let conn = &*self.database.db_connection()?;
+        conn.timed_transaction::<_, FlowyError, _>("view.update_view_tags", || {
+            let _ = ViewTableSql::read_view(&params.view_id, conn)?;
+            ViewTagTableSql::set_tags(&params.view_id, &params.tags, conn)
+        })?;
+
+        let view_tags = self.read_view_tags(params.view_id.into())?;
+        send_dart_notification(&view_tags.view_id, WorkspaceNotification::ViewTagsChanged)
+            .payload(view_tags.clone())
+            .send();
+        Ok(view_tags)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_view_checkpoint(
+        &self,
+        params: CreateViewCheckpointParams,
+    ) -> Result<ViewCheckpoint, FlowyError> {
+        let _ = self.check_view_editable(&params.view_id)?;
+        let checkpoint = self
+            .document_ctx
+            .controller
+            .create_checkpoint(&params.view_id, self.env.next_id(), params.name)
+            .await?;
+        Ok(mk_view_checkpoint(checkpoint))
+    }
+
+    pub(crate) fn read_view_checkpoints(&self, params: ViewId) -> Result<RepeatedViewCheckpoint, FlowyError> {
+        let conn = self.database.db_connection()?;
+        let _ = check_view_accessible(&params.view_id, &self.user.user_id()?, &*conn)?;
+        let items = self
+            .document_ctx
+            .controller
+            .read_checkpoints(&params.view_id)?
+            .into_iter()
+            .map(mk_view_checkpoint)
+            .collect::<Vec<ViewCheckpoint>>();
+        Ok(RepeatedViewCheckpoint { items })
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn restore_view_checkpoint(&self, params: ViewCheckpointId) -> Result<DocumentDelta, FlowyError> {
+        let _ = self.check_view_editable(&params.view_id)?;
+        self.document_ctx
+            .controller
+            .restore_checkpoint(&params.view_id, &params.checkpoint_id)
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn delete_view_checkpoint(&self, params: ViewCheckpointId) -> Result<(), FlowyError> {
+        let _ = self.check_view_editable(&params.view_id)?;
+        self.document_ctx
+            .controller
+            .delete_checkpoint(&params.view_id, &params.checkpoint_id)
+    }
+
+    // Exports the revisions of the view's document that were quarantined when
+    // it was loaded. The file is named after the view and the time, so the
+    // exports don't replace each other.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn export_quarantined_revisions(
+        &self,
+        params: ExportQuarantinedRevisionsParams,
+    ) -> Result<ExportQuarantinedRevisionsResult, FlowyError> {
+        let dir = std::path::Path::new(&params.dir);
+        if !dir.is_dir() {
+            return Err(FlowyError::export_path().context(format!("{} is not a directory", params.dir)));
+        }
+        let conn = self.database.db_connection()?;
+        let _ = check_view_accessible(&params.view_id, &self.user.user_id()?, &*conn)?;
+
+        let file_name = format!("quarantined_revisions_{}_{}.json", params.view_id, self.env.timestamp());
+        let path = dir.join(file_name);
+        let report = self
+            .document_ctx
+            .controller
+            .export_quarantined_revisions(&params.view_id, &path)?;
+        Ok(ExportQuarantinedRevisionsResult {
+            path: path.to_string_lossy().to_string(),
+            revision_count: report.revisions.len() as i64,
+        })
+    }
+
+    pub(crate) fn create_attachment(&self, attachment: Attachment) -> Result<(), FlowyError> {
+        let _ = self.check_view_editable(&attachment.view_id)?;
+        let table = AttachmentTable {
+            id: attachment.id,
+            view_id: attachment.view_id,
+            name: attachment.name,
+            mime_type: attachment.mime_type,
+            data: attachment.data,
+            create_time: attachment.create_time,
+            remote_key: "".to_owned(),
+        };
+        AttachmentTableSql::create_attachment(table, &*self.database.db_connection()?)
+    }
+
+    // The imported views keep the creation time of what they were imported
+    // from. It's only changed locally, the server keeps the time the view was
+    // created at.
+    pub(crate) fn update_view_create_time(&self, view_id: &str, create_time: i64) -> Result<(), FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let user_id = self.user.user_id()?;
+        conn.timed_transaction::<_, FlowyError, _>("view.update_view_create_time", || {
+            let view_table = ViewTableSql::read_view(view_id, conn)?;
+            let _ = ViewTableSql::update_create_time(view_id, create_time, conn)?;
+            notify_views_changed(&view_table.belong_to_id, &user_id, self.trash_controller.clone(), conn)
+        })
+    }
+
+    pub(crate) fn read_revision_records(&self, view_id: &str) -> Result<Vec<RevisionRecord>, FlowyError> {
+        self.document_ctx.controller.read_revision_records(view_id)
+    }
+
     // belong_to_id will be the app_id or view_id.
     #[tracing::instrument(level = "debug", skip(self), err)]
     pub(crate) async fn read_views_belong_to(&self, belong_to_id: &str) -> Result<RepeatedView, FlowyError> {
         // TODO: read from server
         let conn = self.database.db_connection()?;
-        let repeated_view = read_belonging_views_on_local(belong_to_id, self.trash_controller.clone(), &conn)?;
+        let user_id = self.user.user_id()?;
+        let repeated_view =
+            read_belonging_views_on_local(belong_to_id, &user_id, self.trash_controller.clone(), &conn)?;
         Ok(repeated_view)
     }
 
@@ -199,24 +1009,101 @@ impl ViewController {
         let conn = &*self.database.db_connection()?;
         let changeset = ViewTableChangeset::new(params.clone());
         let view_id = changeset.id.clone();
+        let _ = check_belonging_editable(&view_id, conn)?;
+        if let Some(name) = &params.name {
+            let belong_to_id = match &params.belong_to_id {
+                Some(belong_to_id) => belong_to_id.clone(),
+                None => ViewTableSql::read_view(&view_id, conn)?.belong_to_id,
+            };
+            let _ = self.check_view_name(name, &belong_to_id, &view_id, conn)?;
+        }
 
-        let updated_view = conn.immediate_transaction::<_, FlowyError, _>(|| {
+        let updated_view = conn.timed_transaction::<_, FlowyError, _>("view.update_view", || {
+            let before = ViewTableSql::read_view(&view_id, conn)?;
             let _ = ViewTableSql::update_view(changeset, conn)?;
-            let view: View = ViewTableSql::read_view(&view_id, conn)?.into();
+            let after = ViewTableSql::read_view(&view_id, conn)?;
+            let record = AuditRecord::view(AuditAction::Updated, Some(&before), Some(&after), conn)?;
+            let _ = self.audit_log.record(record, conn)?;
+            let view: View = after.into();
+            let _ = EventLogTableSql::append(EventLogType::ViewUpdated, &view_id, view_log_payload(&view), conn)?;
             Ok(view)
         })?;
         send_dart_notification(&view_id, WorkspaceNotification::ViewUpdated)
             .payload(updated_view.clone())
             .send();
+        self.folder_change_notifier
+            .notify(FolderChange::ViewUpdated { view_id });
 
         //
-        let _ = notify_views_changed(&updated_view.belong_to_id, self.trash_controller.clone(), conn)?;
+        let user_id = self.user.user_id()?;
+        let _ = notify_views_changed(
+            &updated_view.belong_to_id,
+            &user_id,
+            self.trash_controller.clone(),
+            conn,
+        )?;
         let _ = self.update_view_on_server(params);
         Ok(updated_view)
     }
 
+    pub(crate) async fn read_code_block_tokens(&self, params: CodeBlockParams) -> Result<CodeBlockTokens, FlowyError> {
+        let _ = check_view_accessible(&params.view_id, &self.user.user_id()?, &*self.database.db_connection()?)?;
+        let (code_block, tokens) = self
+            .document_ctx
+            .controller
+            .read_code_block_tokens(&params.view_id, params.index)
+            .await?;
+        let items = tokens
+            .into_iter()
+            .map(|token| SyntaxToken {
+                start: token.start as i64,
+                len: token.len as i64,
+                scope: token.scope,
+            })
+            .collect();
+        Ok(CodeBlockTokens {
+            start: code_block.start as i64,
+            len: code_block.len as i64,
+            language: code_block.language,
+            tokens: RepeatedSyntaxToken { items },
+        })
+    }
+
+    pub(crate) async fn read_collapsed_toggles(&self, params: ViewId) -> Result<CollapsedToggles, FlowyError> {
+        let _ = check_view_accessible(&params.view_id, &self.user.user_id()?, &*self.database.db_connection()?)?;
+        let toggle_ids = self
+            .document_ctx
+            .controller
+            .read_collapsed_toggles(&params.view_id)
+            .await?;
+        Ok(CollapsedToggles { toggle_ids })
+    }
+
+    // Collapsing a toggle doesn't edit the document, the readers of a view
+    // that they can't edit collapse its toggles too.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn update_toggle_state(&self, params: ToggleStateParams) -> Result<(), FlowyError> {
+        let _ = check_view_accessible(&params.view_id, &self.user.user_id()?, &*self.database.db_connection()?)?;
+        self.document_ctx
+            .controller
+            .set_toggle_collapsed(&params.view_id, &params.toggle_id, params.collapsed)
+    }
+
     pub(crate) async fn receive_document_delta(&self, params: DocumentDelta) -> Result<DocumentDelta, FlowyError> {
+        let _ = self.check_view_editable(&params.doc_id)?;
+        let _ = check_view_accessible(&params.doc_id, &self.user.user_id()?, &*self.database.db_connection()?)?;
         let doc = self.document_ctx.controller.receive_local_delta(params).await?;
+        // The delta is applied already, failing to log it shouldn't fail the edit.
+        let payload = json!({ "doc_id": doc.doc_id });
+        if let Err(e) = EventLogTableSql::append(
+            EventLogType::DocumentUpdated,
+            &doc.doc_id,
+            payload,
+            &*self.database.db_connection()?,
+        ) {
+            log::error!("Append the document update to the event log failed: {:?}", e);
+        }
+        self.webhook.document_did_update(&doc.doc_id);
         Ok(doc)
     }
 
@@ -231,28 +1118,158 @@ impl ViewController {
         }
     }
 
-    pub(crate) fn set_latest_view(&self, view: &View) { KV::set_str(LATEST_VIEW_ID, view.id.clone()); }
+    // The view that was opened last in the workspace. The latest view of the
+    // client from before it was kept for each workspace is used if it's in
+    // this workspace.
+    pub(crate) fn latest_visit_view_in(&self, workspace_id: &str) -> FlowyResult<Option<View>> {
+        let conn = &*self.database.db_connection()?;
+        let view_id = match KV::get_str(&latest_view_key(workspace_id)) {
+            Some(view_id) => view_id,
+            None => match KV::get_str(LATEST_VIEW_ID) {
+                Some(view_id) if read_belonging_workspace_id(&view_id, conn)?.as_deref() == Some(workspace_id) => {
+                    view_id
+                },
+                _ => return Ok(None),
+            },
+        };
+        let view_table = ViewTableSql::read_view(&view_id, conn)?;
+        Ok(Some(view_table.into()))
+    }
+
+    pub(crate) fn set_latest_view(&self, view: &View) {
+        let result = self
+            .database
+            .db_connection()
+            .and_then(|conn| save_latest_view(&view.id, &*conn));
+        if let Err(e) = result {
+            tracing::error!("Save the latest view {} failed: {}", view.id, e);
+        }
+    }
+
+    // Opens the view that was opened last before the app quit, so the client
+    // gets it from the opened editor instead of waiting for it to be loaded.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn preload_latest_view(&self) -> Result<(), FlowyError> {
+        let view = match self.latest_visit_view()? {
+            None => return Ok(()),
+            Some(view) => view,
+        };
+        {
+            let conn = &*self.database.db_connection()?;
+            if self.trash_controller.read_trash_ids(conn)?.contains(&view.id)
+                || !is_view_accessible(&view.id, &self.user.user_id()?, conn)?
+            {
+                return Ok(());
+            }
+        }
+        let _ = self.data_processors.get(&view.view_type)?.open_view(&view.id).await?;
+        Ok(())
+    }
+
+    // Pushes the given apps and every local view, together with its document
+    // content, to the server in one batch. The apps are created first, the
+    // views may belong to them.
+    #[tracing::instrument(level = "debug", skip(self, apps), err)]
+    pub(crate) async fn upload_local_folder(&self, apps: Vec<CreateAppParams>) -> Result<(), FlowyError> {
+        let view_tables = ViewTableSql::read_all_views(&*self.database.db_connection()?)?;
+        let mut views = vec![];
+        for view_table in view_tables {
+            let view: View = view_table.into();
+            let view_data = self.data_processors.get(&view.view_type)?.open_view(&view.id).await?;
+            views.push(CreateViewParams {
+                belong_to_id: view.belong_to_id,
+                name: view.name,
+                desc: view.desc,
+                thumbnail: "".to_owned(),
+                view_type: view.view_type,
+                view_data,
+                view_id: view.id,
+            });
+        }
+        let params = BatchCreateParams { apps, views };
+        if params.is_empty() {
+            return Ok(());
+        }
+        let _ = self
+            .server_retry
+            .call_batch(ServerCallClass::Write, move |token, server| {
+                server.batch_create(&token, params.clone())
+            })?
+            .await?;
+        Ok(())
+    }
 }
 
 impl ViewController {
+    // Only the owner of the workspace decides who can access the views.
+    fn check_view_access_manageable(&self, view_id: &str) -> Result<(), FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let _ = ViewTableSql::read_view(view_id, conn)?;
+        match read_belonging_workspace_id(view_id, conn)? {
+            None => Ok(()),
+            Some(workspace_id) => check_workspace_owner(&workspace_id, conn),
+        }
+    }
+
+    fn notify_view_access_changed(&self, view_id: &str) -> Result<ViewAccess, FlowyError> {
+        let view_access = self.read_view_access(view_id.to_owned().into())?;
+        send_dart_notification(view_id, WorkspaceNotification::ViewAccessChanged)
+            .payload(view_access.clone())
+            .send();
+        Ok(view_access)
+    }
+
+    async fn save_view_data(&self, params: &CreateViewParams) -> Result<(), FlowyError> {
+        let user_id = self.user.user_id()?;
+        let _ = self
+            .data_processors
+            .get(&params.view_type)?
+            .create_view(&user_id, &params.view_id, params.view_data.clone())
+            .await?;
+        Ok(())
+    }
+
+    fn view_data_processor(&self, view_id: &str) -> Result<Arc<dyn ViewDataProcessor>, FlowyError> {
+        let view: View = ViewTableSql::read_view(view_id, &*self.database.db_connection()?)?.into();
+        self.data_processors.get(&view.view_type)
+    }
+
     #[tracing::instrument(skip(self), err)]
     async fn create_view_on_server(&self, params: CreateViewParams) -> Result<View, FlowyError> {
-        let token = self.user.token()?;
-        let view = self.server.create_view(&token, params).await?;
+        // The view id comes from the client, so a retried create doesn't add
+        // a second view.
+        let create_view = self.server_retry.call(ServerCallClass::Write, move |token, server| {
+            server.create_view(&token, params.clone())
+        })?;
+        let view = create_view.await?;
         Ok(view)
     }
 
     #[tracing::instrument(skip(self), err)]
     fn update_view_on_server(&self, params: UpdateViewParams) -> Result<(), FlowyError> {
-        let token = self.user.token()?;
-        let server = self.server.clone();
+        let update_view = self.server_retry.call(ServerCallClass::Sync, move |token, server| {
+            server.update_view(&token, params.clone())
+        })?;
         tokio::spawn(async move {
-            match server.update_view(&token, params).await {
+            match update_view.await {
                 Ok(_) => {},
-                Err(e) => {
-                    // TODO: retry?
-                    log::error!("Update view failed: {:?}", e);
-                },
+                Err(e) => log::error!("Update view failed: {:?}", e),
+            }
+        });
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    fn batch_update_on_server(&self, params: BatchUpdateParams) -> Result<(), FlowyError> {
+        let batch_update = self
+            .server_retry
+            .call_batch(ServerCallClass::Sync, move |token, server| {
+                server.batch_update(&token, params.clone())
+            })?;
+        tokio::spawn(async move {
+            match batch_update.await {
+                Ok(_) => {},
+                Err(e) => log::error!("Update views failed: {:?}", e),
             }
         });
         Ok(())
@@ -260,12 +1277,12 @@ impl ViewController {
 
     #[tracing::instrument(skip(self), err)]
     fn read_view_on_server(&self, params: ViewId) -> Result<(), FlowyError> {
-        let token = self.user.token()?;
-        let server = self.server.clone();
+        let read_view = self.server_retry.call(ServerCallClass::Sync, move |token, server| {
+            server.read_view(&token, params.clone())
+        })?;
         let pool = self.database.db_pool()?;
-        // TODO: Retry with RetryAction?
         tokio::spawn(async move {
-            match server.read_view(&token, params).await {
+            match read_view.await {
                 Ok(Some(view)) => match pool.get() {
                     Ok(conn) => {
                         let view_table = ViewTable::new(view.clone());
@@ -288,11 +1305,43 @@ impl ViewController {
         Ok(())
     }
 
+    // The members only learn about the access lists that the owner changed by
+    // reading them from the server.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    fn read_view_access_on_server(&self, params: ViewId) -> Result<(), FlowyError> {
+        let read_access = self.server_retry.call(ServerCallClass::Sync, move |token, server| {
+            server.read_view_access(&token, params.clone())
+        })?;
+        let pool = self.database.db_pool()?;
+        tokio::spawn(async move {
+            match read_access.await {
+                Ok(Some(view_access)) => match pool.get() {
+                    Ok(conn) => match ViewAclTableSql::replace(&view_access.view_id, &view_access.user_ids, &*conn) {
+                        Ok(false) => {},
+                        Ok(true) => {
+                            send_dart_notification(&view_access.view_id, WorkspaceNotification::ViewAccessChanged)
+                                .payload(view_access)
+                                .send();
+                        },
+                        Err(e) => log::error!("Save view access failed: {:?}", e),
+                    },
+                    Err(e) => log::error!("Require db connection failed: {:?}", e),
+                },
+                Ok(None) => {},
+                Err(e) => log::error!("Read view access failed: {:?}", e),
+            }
+        });
+        Ok(())
+    }
+
     fn listen_trash_can_event(&self) {
         let mut rx = self.trash_controller.subscribe();
+        let user = self.user.clone();
         let database = self.database.clone();
         let document = self.document_ctx.clone();
         let trash_can = self.trash_controller.clone();
+        let audit_log = self.audit_log.clone();
+        let folder_change_notifier = self.folder_change_notifier.clone();
         let _ = tokio::spawn(async move {
             loop {
                 let mut stream = Box::pin(rx.recv().into_stream().filter_map(|result| async move {
@@ -303,29 +1352,58 @@ impl ViewController {
                 }));
 
                 if let Some(event) = stream.next().await {
-                    handle_trash_event(database.clone(), document.clone(), trash_can.clone(), event).await
+                    handle_trash_event(
+                        user.clone(),
+                        database.clone(),
+                        document.clone(),
+                        trash_can.clone(),
+                        audit_log.clone(),
+                        folder_change_notifier.clone(),
+                        event,
+                    )
+                    .await
                 }
             }
         });
     }
 }
 
-#[tracing::instrument(level = "trace", skip(database, context, trash_can))]
+#[tracing::instrument(
+    level = "trace",
+    skip(user, database, context, trash_can, audit_log, folder_change_notifier)
+)]
 async fn handle_trash_event(
+    user: Arc<dyn WorkspaceUser>,
     database: Arc<dyn WorkspaceDatabase>,
     context: Arc<DocumentContext>,
     trash_can: Arc<TrashController>,
+    audit_log: Arc<AuditLogController>,
+    folder_change_notifier: FolderChangeNotifier,
     event: TrashEvent,
 ) {
     let db_result = database.db_connection();
+    let user_id = match user.user_id() {
+        Ok(user_id) => user_id,
+        Err(e) => {
+            log::error!("Handle trash event failed: {:?}", e);
+            return;
+        },
+    };
 
     match event {
         TrashEvent::NewTrash(identifiers, ret) => {
             let result = || {
                 let conn = &*db_result?;
                 let view_tables = read_view_tables(identifiers, conn)?;
+                notify_batched_views(&view_tables, WorkspaceNotification::ViewsDeleted);
                 for view_table in view_tables {
-                    let _ = notify_views_changed(&view_table.belong_to_id, trash_can.clone(), conn)?;
+                    let _ = append_view_log(EventLogType::ViewTrashed, &view_table, conn)?;
+                    let record = AuditRecord::view(AuditAction::Trashed, Some(&view_table), None, conn)?;
+                    let _ = audit_log.record(record, conn)?;
+                    let _ = notify_views_changed(&view_table.belong_to_id, &user_id, trash_can.clone(), conn)?;
+                    folder_change_notifier.notify(FolderChange::ViewTrashed {
+                        view_id: view_table.id.clone(),
+                    });
                     notify_dart(view_table, WorkspaceNotification::ViewDeleted);
                 }
                 Ok::<(), FlowyError>(())
@@ -336,8 +1414,15 @@ async fn handle_trash_event(
             let result = || {
                 let conn = &*db_result?;
                 let view_tables = read_view_tables(identifiers, conn)?;
+                notify_batched_views(&view_tables, WorkspaceNotification::ViewsRestored);
                 for view_table in view_tables {
-                    let _ = notify_views_changed(&view_table.belong_to_id, trash_can.clone(), conn)?;
+                    let _ = append_view_log(EventLogType::ViewRestored, &view_table, conn)?;
+                    let record = AuditRecord::view(AuditAction::Restored, None, Some(&view_table), conn)?;
+                    let _ = audit_log.record(record, conn)?;
+                    let _ = notify_views_changed(&view_table.belong_to_id, &user_id, trash_can.clone(), conn)?;
+                    folder_change_notifier.notify(FolderChange::ViewRestored {
+                        view_id: view_table.id.clone(),
+                    });
                     notify_dart(view_table, WorkspaceNotification::ViewRestored);
                 }
                 Ok::<(), FlowyError>(())
@@ -345,33 +1430,158 @@ async fn handle_trash_event(
             let _ = ret.send(result()).await;
         },
         TrashEvent::Delete(identifiers, ret) => {
+            let view_ids = identifiers
+                .items
+                .iter()
+                .map(|identifier| identifier.id.clone())
+                .collect::<Vec<String>>();
             let result = || {
                 let conn = &*db_result?;
-                let _ = conn.immediate_transaction::<_, FlowyError, _>(|| {
+                let _ = conn.timed_transaction::<_, FlowyError, _>("view.handle_trash_event", || {
                     let mut notify_ids = HashSet::new();
                     for identifier in identifiers.items {
                         let view_table = ViewTableSql::read_view(&identifier.id, conn)?;
+                        let record = AuditRecord::view(AuditAction::Deleted, Some(&view_table), None, conn)?;
+                        let _ = audit_log.record(record, conn)?;
                         let _ = ViewTableSql::delete_view(&identifier.id, conn)?;
+                        let _ = ViewAclTableSql::delete_view_acl(&identifier.id, conn)?;
+                        let _ = ViewTagTableSql::delete_view_tags(&identifier.id, conn)?;
+                        let _ = AttachmentTableSql::delete_view_attachments(&identifier.id, conn)?;
                         let _ = context.controller.delete(&identifier.id)?;
+                        let _ = append_view_log(EventLogType::ViewDeleted, &view_table, conn)?;
                         notify_ids.insert(view_table.belong_to_id);
                     }
 
                     for notify_id in notify_ids {
-                        let _ = notify_views_changed(&notify_id, trash_can.clone(), conn)?;
+                        let _ = notify_views_changed(&notify_id, &user_id, trash_can.clone(), conn)?;
                     }
 
                     Ok(())
                 })?;
                 Ok::<(), FlowyError>(())
             };
-            let _ = ret.send(result()).await;
+            let mut result = result();
+            if result.is_ok() {
+                // The revisions hold every version of the content of the
+                // documents, they are deleted with the views.
+                result = context.controller.delete_revisions(view_ids.clone()).await;
+                for view_id in view_ids {
+                    folder_change_notifier.notify(FolderChange::ViewDeleted { view_id });
+                }
+            }
+            let _ = ret.send(result).await;
         },
     }
 }
 
+// Whether the `id` is the `view_id` itself or one of the views nested in it.
+// The apps aren't moved between the workspaces, the views that belong to them
+// would have to be moved to the other workspace on the server too.
+fn move_app_item(
+    item: &MoveItem,
+    trash_ids: &[String],
+    audit_log: &AuditLogController,
+    conn: &SqliteConnection,
+) -> FlowyResult<String> {
+    let app_table = AppTableSql::read_app(&item.id, conn)?;
+    if app_table.workspace_id != item.to_parent_id {
+        return Err(FlowyError::view_move().context("The apps can only be reordered in their workspace"));
+    }
+    let _ = check_workspace_editable(&app_table.workspace_id, conn)?;
+
+    let apps = AppTableSql::read_workspace_apps(&app_table.workspace_id, false, conn)?
+        .into_iter()
+        .filter(|app_table| !trash_ids.contains(&app_table.id))
+        .map(|app_table| (app_table.id, app_table.create_time))
+        .collect::<Vec<_>>();
+    let repositioned = reposition(&apps, &item.id, item.index as usize);
+    for (app_id, create_time) in &repositioned {
+        let _ = AppTableSql::update_create_time(app_id, *create_time, conn)?;
+    }
+    if !repositioned.is_empty() {
+        let moved_table = AppTableSql::read_app(&item.id, conn)?;
+        let record = AuditRecord::app(AuditAction::Moved, Some(&app_table), Some(&moved_table));
+        let _ = audit_log.record(record, conn)?;
+    }
+    Ok(app_table.workspace_id)
+}
+
+// The apps and the views are ordered by their creation time. To put `id` at
+// `index`, the times of the items are handed out again in the new order. It
+// returns the items whose time changed.
+pub(crate) fn reposition(items: &[(String, i64)], id: &str, index: usize) -> Vec<(String, i64)> {
+    let mut times = items.iter().map(|(_, time)| *time).collect::<Vec<i64>>();
+    times.sort_unstable();
+    // The items created in the same second would keep an arbitrary order.
+    for i in 1..times.len() {
+        if times[i] <= times[i - 1] {
+            times[i] = times[i - 1] + 1;
+        }
+    }
+
+    let mut ids = items
+        .iter()
+        .map(|(item_id, _)| item_id.as_str())
+        .filter(|item_id| *item_id != id)
+        .collect::<Vec<&str>>();
+    ids.insert(index.min(ids.len()), id);
+    ids.into_iter()
+        .zip(times)
+        .filter(|(item_id, time)| !items.contains(&(item_id.to_string(), *time)))
+        .map(|(item_id, time)| (item_id.to_owned(), time))
+        .collect()
+}
+
+fn read_ordered_ids(
+    ty: &MoveItemType,
+    parent_id: &str,
+    trash_ids: &[String],
+    conn: &SqliteConnection,
+) -> FlowyResult<Vec<String>> {
+    let ids = match ty {
+        MoveItemType::View => ViewTableSql::read_views(parent_id, conn)?
+            .into_iter()
+            .map(|view_table| view_table.id)
+            .collect::<Vec<String>>(),
+        MoveItemType::App => AppTableSql::read_workspace_apps(parent_id, false, conn)?
+            .into_iter()
+            .map(|app_table| app_table.id)
+            .collect::<Vec<String>>(),
+    };
+    Ok(ids.into_iter().filter(|id| !trash_ids.contains(id)).collect())
+}
+
+fn is_nested_in(id: &str, view_id: &str, conn: &SqliteConnection) -> FlowyResult<bool> {
+    let mut id = id.to_owned();
+    loop {
+        if id == view_id {
+            return Ok(true);
+        }
+
+        match ViewTableSql::read_belong_to_id(&id, conn)? {
+            None => return Ok(false),
+            Some(belong_to_id) => id = belong_to_id,
+        }
+    }
+}
+
+fn view_log_payload(view: &View) -> serde_json::Value {
+    json!({
+        "id": view.id,
+        "belong_to_id": view.belong_to_id,
+        "name": view.name,
+        "create_time": view.create_time,
+    })
+}
+
+fn append_view_log(ty: EventLogType, view_table: &ViewTable, conn: &SqliteConnection) -> FlowyResult<()> {
+    let view: View = view_table.clone().into();
+    EventLogTableSql::append(ty, &view.id, view_log_payload(&view), conn)
+}
+
 fn read_view_tables(identifiers: RepeatedTrashId, conn: &SqliteConnection) -> Result<Vec<ViewTable>, FlowyError> {
     let mut view_tables = vec![];
-    let _ = conn.immediate_transaction::<_, FlowyError, _>(|| {
+    let _ = conn.timed_transaction::<_, FlowyError, _>("view.read_view_tables", || {
         for identifier in identifiers.items {
             let view_table = ViewTableSql::read_view(&identifier.id, conn)?;
             view_tables.push(view_table);
@@ -381,18 +1591,35 @@ fn read_view_tables(identifiers: RepeatedTrashId, conn: &SqliteConnection) -> Re
     Ok(view_tables)
 }
 
+// Only the removal or the restore of many views is reported in one batch,
+// the client keeps receiving the notification of each view too.
+fn notify_batched_views(view_tables: &[ViewTable], notification: WorkspaceNotification) {
+    if view_tables.len() <= 1 {
+        return;
+    }
+
+    let ids = view_tables
+        .iter()
+        .map(|view_table| view_table.id.clone())
+        .collect::<Vec<String>>();
+    send_anonymous_dart_notification(notification)
+        .payload(BatchedChange::from(ids))
+        .send();
+}
+
 fn notify_dart(view_table: ViewTable, notification: WorkspaceNotification) {
     let view: View = view_table.into();
     send_dart_notification(&view.id, notification).payload(view).send();
 }
 
-#[tracing::instrument(skip(belong_to_id, trash_controller, conn), fields(view_count), err)]
+#[tracing::instrument(skip(belong_to_id, user_id, trash_controller, conn), fields(view_count), err)]
 fn notify_views_changed(
     belong_to_id: &str,
+    user_id: &str,
     trash_controller: Arc<TrashController>,
     conn: &SqliteConnection,
 ) -> FlowyResult<()> {
-    let repeated_view = read_belonging_views_on_local(belong_to_id, trash_controller.clone(), conn)?;
+    let repeated_view = read_belonging_views_on_local(belong_to_id, user_id, trash_controller.clone(), conn)?;
     tracing::Span::current().record("view_count", &format!("{}", repeated_view.len()).as_str());
     send_dart_notification(&belong_to_id, WorkspaceNotification::AppViewsChanged)
         .payload(repeated_view)
@@ -400,8 +1627,10 @@ fn notify_views_changed(
     Ok(())
 }
 
+// The views in the trash and the views that the user can't access are left out.
 fn read_belonging_views_on_local(
     belong_to_id: &str,
+    user_id: &str,
     trash_controller: Arc<TrashController>,
     conn: &SqliteConnection,
 ) -> FlowyResult<RepeatedView> {
@@ -409,10 +1638,64 @@ fn read_belonging_views_on_local(
     let trash_ids = trash_controller.read_trash_ids(conn)?;
     view_tables.retain(|view_table| !trash_ids.contains(&view_table.id));
 
-    let views = view_tables
-        .into_iter()
-        .map(|view_table| view_table.into())
-        .collect::<Vec<View>>();
+    let mut views = vec![];
+    for view_table in view_tables {
+        if is_view_accessible(&view_table.id, user_id, conn)? {
+            views.push(view_table.into());
+        }
+    }
 
     Ok(RepeatedView { items: views })
 }
+
+fn latest_view_key(workspace_id: &str) -> String { format!("{}_{}", LATEST_VIEW_ID, workspace_id) }
+
+// Every window opens the view that was opened last in its own workspace, so
+// the latest view is kept for each workspace too.
+fn save_latest_view(view_id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
+    KV::set_str(LATEST_VIEW_ID, view_id.to_owned());
+    if let Some(workspace_id) = read_belonging_workspace_id(view_id, conn)? {
+        KV::set_str(&latest_view_key(&workspace_id), view_id.to_owned());
+    }
+    Ok(())
+}
+
+// Only the changed middle of the text is deleted and inserted again. The
+// lengths of the delta are counted in utf16 code units.
+fn make_replace_delta_json(old_text: &str, new_text: &str) -> String {
+    let old_chars = old_text.chars().collect::<Vec<char>>();
+    let new_chars = new_text.chars().collect::<Vec<char>>();
+    let prefix = old_chars
+        .iter()
+        .zip(new_chars.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let max_suffix = old_chars.len().min(new_chars.len()) - prefix;
+    let suffix = old_chars
+        .iter()
+        .rev()
+        .zip(new_chars.iter().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let utf16_len = |chars: &[char]| chars.iter().map(|c| c.len_utf16()).sum::<usize>();
+    let inserted = new_chars[prefix..new_chars.len() - suffix].iter().collect::<String>();
+    RichTextDeltaBuilder::new()
+        .retain(utf16_len(&old_chars[..prefix]))
+        .delete(utf16_len(&old_chars[prefix..old_chars.len() - suffix]))
+        .insert(&inserted)
+        .retain(utf16_len(&old_chars[old_chars.len() - suffix..]))
+        .build()
+        .to_json()
+}
+
+fn mk_view_checkpoint(checkpoint: RevisionCheckpoint) -> ViewCheckpoint {
+    ViewCheckpoint {
+        id: checkpoint.id,
+        view_id: checkpoint.doc_id,
+        name: checkpoint.name,
+        rev_id: checkpoint.rev_id,
+        create_time: checkpoint.create_time,
+    }
+}
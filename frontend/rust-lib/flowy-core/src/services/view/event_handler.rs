@@ -2,21 +2,66 @@ use crate::{
     entities::{
         trash::Trash,
         view::{
+            CodeBlockParams,
+            CodeBlockTokens,
+            CollapsedToggles,
+            CreateViewCheckpointParams,
+            CreateViewCheckpointRequest,
             CreateViewParams,
             CreateViewRequest,
+            DuplicateViewParams,
+            DuplicateViewRequest,
+            ExportQuarantinedRevisionsParams,
+            ExportQuarantinedRevisionsRequest,
+            ExportQuarantinedRevisionsResult,
+            MoveItemsParams,
+            MoveItemsRequest,
+            MoveViewParams,
+            MoveViewRequest,
+            QueryCodeBlockRequest,
+            QueryViewCheckpointRequest,
             QueryViewRequest,
+            RepeatedItemMoved,
+            RepeatedViewCheckpoint,
             RepeatedViewId,
+            ToggleStateParams,
+            UpdateToggleStateRequest,
+            UpdateViewAccessParams,
+            UpdateViewAccessRequest,
             UpdateViewParams,
             UpdateViewRequest,
+            UpdateViewTagsParams,
+            UpdateViewTagsRequest,
             View,
+            ViewAccess,
+            ViewCheckpoint,
+            ViewCheckpointId,
             ViewId,
+            ViewTags,
         },
     },
     errors::FlowyError,
     services::{TrashController, ViewController},
 };
 use flowy_collaboration::entities::doc::DocumentDelta;
-use flowy_core_data_model::entities::share::{ExportData, ExportParams, ExportRequest};
+use flowy_core_data_model::entities::share::{
+    CreateGuestAccessParams,
+    CreateGuestAccessRequest,
+    CreateShareLinkParams,
+    CreateShareLinkRequest,
+    ExportData,
+    ExportParams,
+    ExportRequest,
+    GuestAccess,
+    GuestAccessId,
+    QueryGuestAccessRequest,
+    QueryShareLinkRequest,
+    RepeatedGuestAccess,
+    RepeatedShareLink,
+    ShareLink,
+    ShareLinkId,
+    SharedDocument,
+};
 use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
 use std::{convert::TryInto, sync::Arc};
 
@@ -24,7 +69,11 @@ pub(crate) async fn create_view_handler(
     data: Data<CreateViewRequest>,
     controller: Unit<Arc<ViewController>>,
 ) -> DataResult<View, FlowyError> {
-    let params: CreateViewParams = data.into_inner().try_into()?;
+    let mut request = data.into_inner();
+    if request.view_type.is_none() {
+        request.view_type = Some(controller.default_view_type(&request.belong_to_id)?);
+    }
+    let params: CreateViewParams = request.try_into()?;
     let view = controller.create_view_from_params(params).await?;
     data_result(view)
 }
@@ -51,6 +100,132 @@ pub(crate) async fn update_view_handler(
     Ok(())
 }
 
+pub(crate) async fn read_view_access_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewAccess, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let view_access = controller.read_view_access(params)?;
+    data_result(view_access)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn grant_view_access_handler(
+    data: Data<UpdateViewAccessRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewAccess, FlowyError> {
+    let params: UpdateViewAccessParams = data.into_inner().try_into()?;
+    let view_access = controller.grant_view_access(params).await?;
+    data_result(view_access)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn revoke_view_access_handler(
+    data: Data<UpdateViewAccessRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewAccess, FlowyError> {
+    let params: UpdateViewAccessParams = data.into_inner().try_into()?;
+    let view_access = controller.revoke_view_access(params).await?;
+    data_result(view_access)
+}
+
+pub(crate) async fn read_view_tags_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewTags, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let view_tags = controller.read_view_tags(params)?;
+    data_result(view_tags)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_view_tags_handler(
+    data: Data<UpdateViewTagsRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewTags, FlowyError> {
+    let params: UpdateViewTagsParams = data.into_inner().try_into()?;
+    let view_tags = controller.update_view_tags(params)?;
+    data_result(view_tags)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_view_checkpoint_handler(
+    data: Data<CreateViewCheckpointRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewCheckpoint, FlowyError> {
+    let params: CreateViewCheckpointParams = data.into_inner().try_into()?;
+    let checkpoint = controller.create_view_checkpoint(params).await?;
+    data_result(checkpoint)
+}
+
+pub(crate) async fn read_view_checkpoints_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedViewCheckpoint, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let checkpoints = controller.read_view_checkpoints(params)?;
+    data_result(checkpoints)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn restore_view_checkpoint_handler(
+    data: Data<QueryViewCheckpointRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocumentDelta, FlowyError> {
+    let params: ViewCheckpointId = data.into_inner().try_into()?;
+    let doc = controller.restore_view_checkpoint(params).await?;
+    data_result(doc)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_code_block_tokens_handler(
+    data: Data<QueryCodeBlockRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<CodeBlockTokens, FlowyError> {
+    let params: CodeBlockParams = data.into_inner().try_into()?;
+    let tokens = controller.read_code_block_tokens(params).await?;
+    data_result(tokens)
+}
+
+pub(crate) async fn read_collapsed_toggles_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<CollapsedToggles, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let toggles = controller.read_collapsed_toggles(params).await?;
+    data_result(toggles)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_toggle_state_handler(
+    data: Data<UpdateToggleStateRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), FlowyError> {
+    let params: ToggleStateParams = data.into_inner().try_into()?;
+    let _ = controller.update_toggle_state(params)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_view_checkpoint_handler(
+    data: Data<QueryViewCheckpointRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), FlowyError> {
+    let params: ViewCheckpointId = data.into_inner().try_into()?;
+    let _ = controller.delete_view_checkpoint(params)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn export_quarantined_revisions_handler(
+    data: Data<ExportQuarantinedRevisionsRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ExportQuarantinedRevisionsResult, FlowyError> {
+    let params: ExportQuarantinedRevisionsParams = data.into_inner().try_into()?;
+    let result = controller.export_quarantined_revisions(params)?;
+    data_result(result)
+}
+
 pub(crate) async fn document_delta_handler(
     data: Data<DocumentDelta>,
     controller: Unit<Arc<ViewController>>,
@@ -65,6 +240,9 @@ pub(crate) async fn delete_view_handler(
     trash_controller: Unit<Arc<TrashController>>,
 ) -> Result<(), FlowyError> {
     let params: RepeatedViewId = data.into_inner().try_into()?;
+    for view_id in &params.items {
+        let _ = view_controller.check_view_editable(view_id)?;
+    }
     for view_id in &params.items {
         let _ = view_controller.delete_view(view_id.into()).await;
     }
@@ -99,14 +277,34 @@ pub(crate) async fn close_view_handler(
 
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn duplicate_view_handler(
-    data: Data<QueryViewRequest>,
+    data: Data<DuplicateViewRequest>,
     controller: Unit<Arc<ViewController>>,
 ) -> Result<(), FlowyError> {
-    let params: ViewId = data.into_inner().try_into()?;
-    let _ = controller.duplicate_view(params.into()).await?;
+    let params: DuplicateViewParams = data.into_inner().try_into()?;
+    let _ = controller.duplicate_view(params).await?;
     Ok(())
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_view_handler(
+    data: Data<MoveViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let params: MoveViewParams = data.into_inner().try_into()?;
+    let view = controller.move_view(params).await?;
+    data_result(view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_items_handler(
+    data: Data<MoveItemsRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedItemMoved, FlowyError> {
+    let params: MoveItemsParams = data.into_inner().try_into()?;
+    let moved = controller.move_items(params).await?;
+    data_result(moved)
+}
+
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn export_handler(
     data: Data<ExportRequest>,
@@ -116,3 +314,70 @@ pub(crate) async fn export_handler(
     let data = controller.export_doc(params).await?;
     data_result(data)
 }
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_share_link_handler(
+    data: Data<CreateShareLinkRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ShareLink, FlowyError> {
+    let params: CreateShareLinkParams = data.into_inner().try_into()?;
+    let link = controller.create_share_link(params).await?;
+    data_result(link)
+}
+
+pub(crate) async fn read_share_links_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedShareLink, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let repeated_link = controller.read_share_links(params).await?;
+    data_result(repeated_link)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn revoke_share_link_handler(
+    data: Data<QueryShareLinkRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), FlowyError> {
+    let params: ShareLinkId = data.into_inner().try_into()?;
+    let _ = controller.revoke_share_link(params).await?;
+    Ok(())
+}
+
+pub(crate) async fn read_shared_document_handler(
+    data: Data<QueryShareLinkRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<SharedDocument, FlowyError> {
+    let params: ShareLinkId = data.into_inner().try_into()?;
+    let document = controller.read_shared_document(params).await?;
+    data_result(document)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_guest_access_handler(
+    data: Data<CreateGuestAccessRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<GuestAccess, FlowyError> {
+    let params: CreateGuestAccessParams = data.into_inner().try_into()?;
+    let access = controller.create_guest_access(params).await?;
+    data_result(access)
+}
+
+pub(crate) async fn read_guest_accesses_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedGuestAccess, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let repeated_access = controller.read_guest_accesses(params).await?;
+    data_result(repeated_access)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn revoke_guest_access_handler(
+    data: Data<QueryGuestAccessRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), FlowyError> {
+    let params: GuestAccessId = data.into_inner().try_into()?;
+    let _ = controller.revoke_guest_access(params).await?;
+    Ok(())
+}
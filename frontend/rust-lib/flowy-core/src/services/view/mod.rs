@@ -1,3 +1,7 @@
+pub(crate) mod acl;
+pub(crate) mod attachment;
 pub mod controller;
 pub mod event_handler;
-mod sql;
+pub(crate) mod processor;
+pub(crate) mod sql;
+pub(crate) mod tag;
@@ -0,0 +1,155 @@
+use crate::{
+    entities::view::ViewType,
+    errors::{internal_error, FlowyError, FlowyResult},
+};
+use bytes::Bytes;
+use flowy_collaboration::entities::revision::{RepeatedRevision, Revision};
+use flowy_core_data_model::entities::share::ExportType;
+use flowy_document::context::DocumentContext;
+use lib_infra::future::FutureResultSend;
+use std::{collections::HashMap, sync::Arc};
+
+// Creates, opens, copies and exports the data of the views of some types. The
+// ViewController looks up the processor of the type of the view, so a new
+// type of view registers its processor instead of changing the controller.
+pub trait ViewDataProcessor: Send + Sync {
+    fn view_types(&self) -> Vec<ViewType>;
+
+    // Saves the data that the view is created with.
+    fn create_view(&self, user_id: &str, view_id: &str, view_data: String) -> FutureResultSend<(), FlowyError>;
+
+    // The data that is sent to the client when it opens the view.
+    fn open_view(&self, view_id: &str) -> FutureResultSend<String, FlowyError>;
+
+    fn close_view(&self, view_id: &str) -> FlowyResult<()>;
+
+    // The data that the copy of the view is created with. The history of the
+    // copy is saved through `save_revisions` once the copy exists.
+    fn duplicate_view(
+        &self,
+        view_id: &str,
+        to_view_id: &str,
+        with_history: bool,
+    ) -> FutureResultSend<ViewDataCopy, FlowyError>;
+
+    fn save_revisions(&self, view_id: &str, revisions: Vec<Revision>) -> FutureResultSend<(), FlowyError>;
+
+    fn export_view(&self, view_id: &str, export_type: ExportType) -> FutureResultSend<String, FlowyError>;
+}
+
+pub struct ViewDataCopy {
+    pub view_data: String,
+    pub revisions: Vec<Revision>,
+}
+
+#[derive(Default)]
+pub struct ViewDataProcessorMap {
+    processors: HashMap<ViewType, Arc<dyn ViewDataProcessor>>,
+}
+
+impl ViewDataProcessorMap {
+    // The processor that is registered later replaces the one of the same
+    // view type.
+    pub fn register(&mut self, processor: Arc<dyn ViewDataProcessor>) {
+        for view_type in processor.view_types() {
+            self.processors.insert(view_type, processor.clone());
+        }
+    }
+
+    pub(crate) fn get(&self, view_type: &ViewType) -> FlowyResult<Arc<dyn ViewDataProcessor>> {
+        match self.processors.get(view_type) {
+            Some(processor) => Ok(processor.clone()),
+            None => Err(FlowyError::view_type().context(format!("No processor for the view type {:?}", view_type))),
+        }
+    }
+}
+
+// The grids, the boards, the calendars and the checklists keep their json in
+// the text of a document, so their data is processed like the documents.
+pub(crate) struct DocumentViewDataProcessor {
+    document_ctx: Arc<DocumentContext>,
+}
+
+impl DocumentViewDataProcessor {
+    pub(crate) fn new(document_ctx: Arc<DocumentContext>) -> Self { Self { document_ctx } }
+}
+
+impl ViewDataProcessor for DocumentViewDataProcessor {
+    fn view_types(&self) -> Vec<ViewType> {
+        vec![
+            ViewType::Blank,
+            ViewType::Doc,
+            ViewType::Grid,
+            ViewType::Board,
+            ViewType::Calendar,
+            ViewType::Checklist,
+        ]
+    }
+
+    fn create_view(&self, user_id: &str, view_id: &str, view_data: String) -> FutureResultSend<(), FlowyError> {
+        let repeated_revision: RepeatedRevision =
+            Revision::initial_revision(user_id, view_id, Bytes::from(view_data)).into();
+        let view_id = view_id.to_owned();
+        let document_ctx = self.document_ctx.clone();
+        FutureResultSend::new(async move {
+            let _ = document_ctx
+                .controller
+                .save_document(&view_id, repeated_revision)
+                .await?;
+            Ok(())
+        })
+    }
+
+    fn open_view(&self, view_id: &str) -> FutureResultSend<String, FlowyError> {
+        let view_id = view_id.to_owned();
+        let document_ctx = self.document_ctx.clone();
+        FutureResultSend::new(async move {
+            let editor = document_ctx.controller.open_document(&view_id).await?;
+            editor.document_json().await
+        })
+    }
+
+    fn close_view(&self, view_id: &str) -> FlowyResult<()> { self.document_ctx.controller.close_document(view_id) }
+
+    // The copy with the history is created from the first revision of the
+    // view, like the server creates it.
+    fn duplicate_view(
+        &self,
+        view_id: &str,
+        to_view_id: &str,
+        with_history: bool,
+    ) -> FutureResultSend<ViewDataCopy, FlowyError> {
+        let view_id = view_id.to_owned();
+        let to_view_id = to_view_id.to_owned();
+        let document_ctx = self.document_ctx.clone();
+        FutureResultSend::new(async move {
+            let revisions = match with_history {
+                false => vec![],
+                true => document_ctx.controller.copy_revisions(&view_id, &to_view_id).await?,
+            };
+            let view_data = match revisions.first() {
+                Some(revision) => String::from_utf8(revision.delta_data.clone()).map_err(internal_error)?,
+                None => {
+                    let editor = document_ctx.controller.open_document(&view_id).await?;
+                    editor.document_json().await?
+                },
+            };
+            Ok(ViewDataCopy { view_data, revisions })
+        })
+    }
+
+    fn save_revisions(&self, view_id: &str, revisions: Vec<Revision>) -> FutureResultSend<(), FlowyError> {
+        let view_id = view_id.to_owned();
+        let document_ctx = self.document_ctx.clone();
+        FutureResultSend::new(async move {
+            document_ctx
+                .controller
+                .save_document(&view_id, RepeatedRevision::new(revisions))
+                .await
+        })
+    }
+
+    fn export_view(&self, view_id: &str, _export_type: ExportType) -> FutureResultSend<String, FlowyError> {
+        self.open_view(view_id)
+    }
+}
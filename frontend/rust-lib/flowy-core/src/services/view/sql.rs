@@ -4,12 +4,17 @@ use crate::{
         view::{RepeatedView, UpdateViewParams, View, ViewType},
     },
     errors::FlowyError,
-    services::app::sql::AppTable,
+    services::{
+        app::sql::AppTable,
+        cache::{invalidate_view_cache, read_view_through_cache, read_views_through_cache},
+    },
 };
 use diesel::sql_types::Integer;
 use flowy_database::{
     prelude::*,
+    result::OptionalExtension,
     schema::{view_table, view_table::dsl},
+    slow_log::{log_if_slow, statement_shape},
     SqliteConnection,
 };
 use lib_infra::timestamp;
@@ -18,13 +23,17 @@ pub struct ViewTableSql {}
 
 impl ViewTableSql {
     pub(crate) fn create_view(view_table: ViewTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let view_id = view_table.id.clone();
+        let mut belong_to_ids = vec![view_table.belong_to_id.clone()];
         match diesel_record_count!(view_table, &view_table.id, conn) {
             0 => diesel_insert_table!(view_table, &view_table, conn),
             _ => {
+                belong_to_ids.extend(Self::read_belong_to_id(&view_id, conn)?);
                 let changeset = ViewTableChangeset::from_table(view_table);
                 diesel_update_table!(view_table, changeset, conn)
             },
         }
+        invalidate_view_cache(&view_id, &belong_to_ids, conn);
         Ok(())
     }
 
@@ -36,31 +45,74 @@ impl ViewTableSql {
         //     filter = filter.filter(view_table::is_trash.eq(is_trash));
         // }
         // let repeated_view = filter.first::<ViewTable>(conn)?;
-        let view_table = dsl::view_table
-            .filter(view_table::id.eq(view_id))
-            .first::<ViewTable>(conn)?;
-
-        Ok(view_table)
+        read_view_through_cache(view_id, conn, || {
+            let view_table = dsl::view_table
+                .filter(view_table::id.eq(view_id))
+                .first::<ViewTable>(conn)?;
+            Ok(view_table)
+        })
     }
 
     // belong_to_id will be the app_id or view_id.
     pub(crate) fn read_views(belong_to_id: &str, conn: &SqliteConnection) -> Result<Vec<ViewTable>, FlowyError> {
-        let view_tables = dsl::view_table
-            .filter(view_table::belong_to_id.eq(belong_to_id))
-            .order(view_table::create_time.asc())
-            .into_boxed()
-            .load::<ViewTable>(conn)?;
+        read_views_through_cache(belong_to_id, conn, || {
+            let query = dsl::view_table
+                .filter(view_table::belong_to_id.eq(belong_to_id))
+                .order(view_table::create_time.asc())
+                .into_boxed();
+            let view_tables = log_if_slow(&statement_shape(&query), || query.load::<ViewTable>(conn))?;
+            Ok(view_tables)
+        })
+    }
+
+    // Returns None if the id is not a view, e.g. it's the id of an app.
+    pub(crate) fn read_belong_to_id(id: &str, conn: &SqliteConnection) -> Result<Option<String>, FlowyError> {
+        let belong_to_id = dsl::view_table
+            .filter(view_table::id.eq(id))
+            .select(view_table::belong_to_id)
+            .first::<String>(conn)
+            .optional()?;
+        Ok(belong_to_id)
+    }
+
+    pub(crate) fn read_all_views(conn: &SqliteConnection) -> Result<Vec<ViewTable>, FlowyError> {
+        let query = dsl::view_table.order(view_table::create_time.asc());
+        let view_tables = log_if_slow(&statement_shape(&query), || query.load::<ViewTable>(conn))?;
 
         Ok(view_tables)
     }
 
     pub(crate) fn update_view(changeset: ViewTableChangeset, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let view_id = changeset.id.clone();
+        let mut belong_to_ids = changeset.belong_to_id.iter().cloned().collect::<Vec<String>>();
+        belong_to_ids.extend(Self::read_belong_to_id(&view_id, conn)?);
         diesel_update_table!(view_table, changeset, conn);
+        invalidate_view_cache(&view_id, &belong_to_ids, conn);
+        Ok(())
+    }
+
+    pub(crate) fn update_create_time(
+        view_id: &str,
+        create_time: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
+        let belong_to_ids = Self::read_belong_to_id(view_id, conn)?
+            .into_iter()
+            .collect::<Vec<String>>();
+        let filter = dsl::view_table.filter(view_table::id.eq(view_id));
+        let _ = diesel::update(filter)
+            .set(view_table::create_time.eq(create_time))
+            .execute(conn)?;
+        invalidate_view_cache(view_id, &belong_to_ids, conn);
         Ok(())
     }
 
     pub(crate) fn delete_view(view_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let belong_to_ids = Self::read_belong_to_id(view_id, conn)?
+            .into_iter()
+            .collect::<Vec<String>>();
         diesel_delete_table!(view_table, view_id, conn);
+        invalidate_view_cache(view_id, &belong_to_ids, conn);
         Ok(())
     }
 }
@@ -129,6 +181,10 @@ impl ViewTable {
         let view_type = match view.view_type {
             ViewType::Blank => ViewTableType::Docs,
             ViewType::Doc => ViewTableType::Docs,
+            ViewType::Grid => ViewTableType::Grid,
+            ViewType::Board => ViewTableType::Board,
+            ViewType::Calendar => ViewTableType::Calendar,
+            ViewType::Checklist => ViewTableType::Checklist,
         };
 
         ViewTable {
@@ -151,6 +207,10 @@ impl std::convert::From<ViewTable> for View {
     fn from(table: ViewTable) -> Self {
         let view_type = match table.view_type {
             ViewTableType::Docs => ViewType::Doc,
+            ViewTableType::Grid => ViewType::Grid,
+            ViewTableType::Board => ViewType::Board,
+            ViewTableType::Calendar => ViewType::Calendar,
+            ViewTableType::Checklist => ViewType::Checklist,
         };
 
         View {
@@ -175,6 +235,9 @@ impl std::convert::From<ViewTable> for Trash {
             modified_time: table.modified_time,
             create_time: table.create_time,
             ty: TrashType::View,
+            deleted_by: "".to_owned(),
+            parent_id: table.belong_to_id,
+            position: 0,
         }
     }
 }
@@ -186,6 +249,7 @@ pub(crate) struct ViewTableChangeset {
     pub name: Option<String>,
     pub desc: Option<String>,
     pub thumbnail: Option<String>,
+    pub belong_to_id: Option<String>,
     pub modified_time: i64,
 }
 
@@ -196,6 +260,7 @@ impl ViewTableChangeset {
             name: params.name,
             desc: params.desc,
             thumbnail: params.thumbnail,
+            belong_to_id: params.belong_to_id,
             modified_time: timestamp(),
         }
     }
@@ -206,6 +271,7 @@ impl ViewTableChangeset {
             name: Some(table.name),
             desc: Some(table.desc),
             thumbnail: Some(table.thumbnail),
+            belong_to_id: Some(table.belong_to_id),
             modified_time: table.modified_time,
         }
     }
@@ -215,7 +281,11 @@ impl ViewTableChangeset {
 #[repr(i32)]
 #[sql_type = "Integer"]
 pub enum ViewTableType {
-    Docs = 0,
+    Docs      = 0,
+    Grid      = 1,
+    Board     = 2,
+    Calendar  = 3,
+    Checklist = 4,
 }
 
 impl std::default::Default for ViewTableType {
@@ -226,6 +296,10 @@ impl std::convert::From<i32> for ViewTableType {
     fn from(value: i32) -> Self {
         match value {
             0 => ViewTableType::Docs,
+            1 => ViewTableType::Grid,
+            2 => ViewTableType::Board,
+            3 => ViewTableType::Calendar,
+            4 => ViewTableType::Checklist,
             o => {
                 log::error!("Unsupported view type {}, fallback to ViewType::Docs", o);
                 ViewTableType::Docs
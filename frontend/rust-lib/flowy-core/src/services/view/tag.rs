@@ -0,0 +1,47 @@
+use crate::errors::FlowyError;
+use flowy_database::{
+    prelude::*,
+    schema::{view_tag_table, view_tag_table::dsl},
+    SqliteConnection,
+};
+
+pub(crate) struct ViewTagTableSql {}
+
+impl ViewTagTableSql {
+    pub(crate) fn set_tags(view_id: &str, tags: &[String], conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = Self::delete_view_tags(view_id, conn)?;
+        let tables = tags
+            .iter()
+            .map(|tag| ViewTagTable {
+                view_id: view_id.to_owned(),
+                tag: tag.clone(),
+            })
+            .collect::<Vec<ViewTagTable>>();
+        let _ = diesel::insert_into(view_tag_table::table)
+            .values(&tables)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_tags(view_id: &str, conn: &SqliteConnection) -> Result<Vec<String>, FlowyError> {
+        let tags = dsl::view_tag_table
+            .filter(view_tag_table::view_id.eq(view_id))
+            .order(view_tag_table::tag.asc())
+            .select(view_tag_table::tag)
+            .load::<String>(conn)?;
+        Ok(tags)
+    }
+
+    pub(crate) fn delete_view_tags(view_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::view_tag_table.filter(view_tag_table::view_id.eq(view_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "view_tag_table"]
+pub(crate) struct ViewTagTable {
+    pub view_id: String,
+    pub tag: String,
+}
@@ -0,0 +1,210 @@
+use crate::{
+    entities::webhook::{CreateWebhookParams, RepeatedWebhook, Webhook, WebhookId},
+    errors::{FlowyError, FlowyResult},
+    module::WorkspaceDatabase,
+    services::webhook::sql::{WebhookTable, WebhookTableSql},
+};
+use hmac::{Hmac, Mac, NewMac};
+use lib_infra::{
+    clock::RuntimeEnv,
+    retry::{jitter, Action, ExponentialBackoff, Retry},
+};
+use parking_lot::RwLock;
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc, time::Duration};
+
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Flowy-Signature";
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Flowy-Event";
+
+const WEBHOOK_RETRY_COUNT: usize = 5;
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+// A document receives a delta on nearly every keystroke, so the update of the
+// same document is posted at most once in this interval.
+const DOCUMENT_UPDATED_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WebhookEvent {
+    ViewCreated,
+    DocumentUpdated,
+    TrashEmptied,
+}
+
+impl WebhookEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WebhookEvent::ViewCreated => "view.created",
+            WebhookEvent::DocumentUpdated => "document.updated",
+            WebhookEvent::TrashEmptied => "trash.emptied",
+        }
+    }
+
+    fn is_subscribed_by(&self, table: &WebhookTable) -> bool {
+        match self {
+            WebhookEvent::ViewCreated => table.on_view_created,
+            WebhookEvent::DocumentUpdated => table.on_document_updated,
+            WebhookEvent::TrashEmptied => table.on_trash_emptied,
+        }
+    }
+}
+
+pub struct WebhookController {
+    database: Arc<dyn WorkspaceDatabase>,
+    client: reqwest::Client,
+    // The time in milliseconds of the last post per document.
+    document_updated_at: RwLock<HashMap<String, i64>>,
+    env: RuntimeEnv,
+}
+
+impl WebhookController {
+    pub(crate) fn new(database: Arc<dyn WorkspaceDatabase>, env: RuntimeEnv) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            database,
+            client,
+            document_updated_at: RwLock::new(HashMap::new()),
+            env,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(url = %params.url), err)]
+    pub(crate) fn create_webhook(&self, params: CreateWebhookParams) -> FlowyResult<Webhook> {
+        let table = WebhookTable {
+            id: self.env.next_id(),
+            url: params.url,
+            secret: self.env.next_id().replace('-', ""),
+            on_view_created: params.on_view_created,
+            on_document_updated: params.on_document_updated,
+            on_trash_emptied: params.on_trash_emptied,
+            create_time: self.env.timestamp(),
+        };
+        let conn = self.database.db_connection()?;
+        let _ = WebhookTableSql::create_webhook(table.clone(), &*conn)?;
+        Ok(table.into())
+    }
+
+    pub(crate) fn read_webhooks(&self) -> FlowyResult<RepeatedWebhook> {
+        let conn = self.database.db_connection()?;
+        let items = WebhookTableSql::read_all(&*conn)?
+            .into_iter()
+            .map(|table| table.into())
+            .collect::<Vec<Webhook>>();
+        Ok(RepeatedWebhook { items })
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn delete_webhook(&self, params: WebhookId) -> FlowyResult<()> {
+        let conn = self.database.db_connection()?;
+        WebhookTableSql::delete_webhook(&params.webhook_id, &*conn)
+    }
+
+    pub(crate) fn view_did_create(&self, data: Value) { self.post(WebhookEvent::ViewCreated, data); }
+
+    pub(crate) fn document_did_update(&self, doc_id: &str) {
+        {
+            let now = self.env.clock.now().timestamp_millis();
+            let mut updated_at = self.document_updated_at.write();
+            if let Some(last) = updated_at.get(doc_id) {
+                if now - last < DOCUMENT_UPDATED_INTERVAL.as_millis() as i64 {
+                    return;
+                }
+            }
+            updated_at.insert(doc_id.to_owned(), now);
+        }
+        self.post(WebhookEvent::DocumentUpdated, json!({ "doc_id": doc_id }));
+    }
+
+    pub(crate) fn trash_did_empty(&self, data: Value) { self.post(WebhookEvent::TrashEmptied, data); }
+
+    // Forgets when the documents of the previous user were last posted.
+    pub(crate) fn reset(&self) { self.document_updated_at.write().clear(); }
+
+    // The webhooks are posted in the background, a failing url must never block
+    // or fail the folder operation that triggered it.
+    fn post(&self, event: WebhookEvent, data: Value) {
+        let tables = match self
+            .database
+            .db_connection()
+            .and_then(|conn| WebhookTableSql::read_all(&*conn))
+        {
+            Ok(tables) => tables,
+            Err(e) => {
+                log::error!("Read webhooks failed: {:?}", e);
+                return;
+            },
+        };
+
+        let body = json!({
+            "event": event.name(),
+            "timestamp": self.env.timestamp(),
+            "data": data,
+        })
+        .to_string();
+
+        for table in tables.into_iter().filter(|table| event.is_subscribed_by(table)) {
+            let action = WebhookPostAction {
+                client: self.client.clone(),
+                url: table.url.clone(),
+                event,
+                signature: sign_payload(&table.secret, &body),
+                body: body.clone(),
+            };
+            let strategy = ExponentialBackoff::from_millis(500)
+                .max_delay(Duration::from_secs(60))
+                .map(jitter)
+                .take(WEBHOOK_RETRY_COUNT);
+            let retry = Retry::spawn_with_clock(strategy, action, self.env.clock.clone());
+            tokio::spawn(async move {
+                if let Err(e) = retry.await {
+                    log::error!("Post webhook to {} failed: {:?}", table.url, e);
+                }
+            });
+        }
+    }
+}
+
+// The receiver computes the HMAC-SHA256 of the raw body with the secret of
+// the webhook and compares it with the signature header.
+pub fn sign_payload(secret: &str, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+struct WebhookPostAction {
+    client: reqwest::Client,
+    url: String,
+    event: WebhookEvent,
+    signature: String,
+    body: String,
+}
+
+impl Action for WebhookPostAction {
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Item, Self::Error>> + Send>>;
+    type Item = ();
+    type Error = FlowyError;
+
+    fn run(&mut self) -> Self::Future {
+        let request = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_EVENT_HEADER, self.event.name())
+            .header(WEBHOOK_SIGNATURE_HEADER, self.signature.clone())
+            .body(self.body.clone());
+
+        Box::pin(async move {
+            let response = request.send().await.map_err(|e| FlowyError::connection().context(e))?;
+            let status = response.status();
+            if status.is_success() {
+                Ok(())
+            } else {
+                Err(FlowyError::internal().context(format!("The webhook responded with {}", status)))
+            }
+        })
+    }
+}
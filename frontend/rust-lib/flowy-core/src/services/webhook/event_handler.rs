@@ -0,0 +1,42 @@
+use crate::{
+    entities::webhook::{
+        CreateWebhookParams,
+        CreateWebhookRequest,
+        QueryWebhookRequest,
+        RepeatedWebhook,
+        Webhook,
+        WebhookId,
+    },
+    errors::FlowyError,
+    services::WebhookController,
+};
+use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_webhook_handler(
+    data: Data<CreateWebhookRequest>,
+    controller: Unit<Arc<WebhookController>>,
+) -> DataResult<Webhook, FlowyError> {
+    let params: CreateWebhookParams = data.into_inner().try_into()?;
+    let webhook = controller.create_webhook(params)?;
+    data_result(webhook)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_webhooks_handler(
+    controller: Unit<Arc<WebhookController>>,
+) -> DataResult<RepeatedWebhook, FlowyError> {
+    let repeated_webhook = controller.read_webhooks()?;
+    data_result(repeated_webhook)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_webhook_handler(
+    data: Data<QueryWebhookRequest>,
+    controller: Unit<Arc<WebhookController>>,
+) -> Result<(), FlowyError> {
+    let params: WebhookId = data.into_inner().try_into()?;
+    let _ = controller.delete_webhook(params)?;
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::{entities::webhook::Webhook, errors::FlowyError};
+use flowy_database::{
+    prelude::*,
+    schema::{webhook_table, webhook_table::dsl},
+    SqliteConnection,
+};
+
+pub(crate) struct WebhookTableSql {}
+
+impl WebhookTableSql {
+    pub(crate) fn create_webhook(table: WebhookTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = diesel::insert_into(webhook_table::table).values(&table).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_all(conn: &SqliteConnection) -> Result<Vec<WebhookTable>, FlowyError> {
+        let tables = dsl::webhook_table
+            .order(webhook_table::create_time.asc())
+            .load::<WebhookTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn delete_webhook(webhook_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::webhook_table.filter(webhook_table::id.eq(webhook_id));
+        match diesel::delete(filter).execute(conn)? {
+            0 => Err(FlowyError::record_not_found().context(format!("Webhook {} not exist", webhook_id))),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "webhook_table"]
+pub(crate) struct WebhookTable {
+    pub id: String,
+    pub url: String,
+    pub secret: String,
+    pub on_view_created: bool,
+    pub on_document_updated: bool,
+    pub on_trash_emptied: bool,
+    pub create_time: i64,
+}
+
+impl std::convert::From<WebhookTable> for Webhook {
+    fn from(table: WebhookTable) -> Self {
+        Webhook {
+            id: table.id,
+            url: table.url,
+            secret: table.secret,
+            on_view_created: table.on_view_created,
+            on_document_updated: table.on_document_updated,
+            on_trash_emptied: table.on_trash_emptied,
+            create_time: table.create_time,
+        }
+    }
+}
@@ -1,23 +1,51 @@
 use crate::{
+    entities::trash::{RepeatedTrashId, TrashId, TrashType},
     errors::*,
+    folder_change::{FolderChange, FolderChangeNotifier},
     module::{WorkspaceDatabase, WorkspaceUser},
     notify::*,
+    retry::{ServerCallClass, ServerRetry},
     services::{
+        app::sql::AppTableSql,
         read_local_workspace_apps,
         server::Server,
-        workspace::sql::{WorkspaceTable, WorkspaceTableChangeset, WorkspaceTableSql},
+        view::sql::ViewTableSql,
+        workspace::{
+            retention::read_revision_retention,
+            role::{check_workspace_editable, check_workspace_owner},
+            setting::{read_workspace_settings, save_workspace_settings},
+            sql::{WorkspaceTable, WorkspaceTableChangeset, WorkspaceTableSql},
+        },
+        AuditAction,
+        AuditLogController,
+        AuditRecord,
+        CloudOutbox,
+        OutboxOp,
         TrashController,
+        TrashEvent,
+    },
+};
+use flowy_core_data_model::{
+    entities::{
+        app::{ColorStyle, CreateAppParams, RepeatedApp},
+        workspace::*,
     },
+    parser::workspace::DefaultMemberRole,
 };
-use flowy_core_data_model::entities::{app::RepeatedApp, workspace::*};
-use flowy_database::{kv::KV, SqliteConnection};
+use flowy_database::{kv::KV, slow_log::SlowLogTransaction, SqliteConnection};
+use futures::{FutureExt, StreamExt};
+use lib_infra::timestamp;
 use std::sync::Arc;
 
 pub struct WorkspaceController {
     pub user: Arc<dyn WorkspaceUser>,
     pub(crate) database: Arc<dyn WorkspaceDatabase>,
     pub(crate) trash_controller: Arc<TrashController>,
+    audit_log: Arc<AuditLogController>,
+    folder_change_notifier: FolderChangeNotifier,
     server: Server,
+    server_retry: ServerRetry,
+    outbox: Arc<CloudOutbox>,
 }
 
 impl WorkspaceController {
@@ -25,26 +53,65 @@ impl WorkspaceController {
         user: Arc<dyn WorkspaceUser>,
         database: Arc<dyn WorkspaceDatabase>,
         trash_can: Arc<TrashController>,
+        audit_log: Arc<AuditLogController>,
+        folder_change_notifier: FolderChangeNotifier,
         server: Server,
+        server_retry: ServerRetry,
+        outbox: Arc<CloudOutbox>,
     ) -> Self {
         Self {
             user,
             database,
             trash_controller: trash_can,
+            audit_log,
+            folder_change_notifier,
             server,
+            server_retry,
+            outbox,
         }
     }
 
-    pub(crate) fn init(&self) -> Result<(), FlowyError> { Ok(()) }
+    pub(crate) fn init(&self) -> Result<(), FlowyError> {
+        self.listen_trash_controller_event();
+        Ok(())
+    }
 
     pub(crate) async fn create_workspace_from_params(
         &self,
         params: CreateWorkspaceParams,
     ) -> Result<Workspace, FlowyError> {
-        let workspace = self.create_workspace_on_server(params.clone()).await?;
+        let workspace = match self.outbox.has_pending()? {
+            true => {
+                let workspace = self.create_workspace_offline(params)?;
+                self.outbox.spawn_replay();
+                workspace
+            },
+            false => match self.create_workspace_on_server(params.clone()).await {
+                Ok(workspace) => workspace,
+                Err(e) if e.recovery_hint() == RecoveryHint::Retryable => self.create_workspace_offline(params)?,
+                Err(e) => return Err(e),
+            },
+        };
         self.create_workspace_on_local(workspace).await
     }
 
+    // The workspace has the id of the client, so it's created locally now and
+    // the server creates the same one when the outbox is replayed.
+    fn create_workspace_offline(&self, params: CreateWorkspaceParams) -> Result<Workspace, FlowyError> {
+        let _ = self.outbox.enqueue(OutboxOp::CreateWorkspace(params.clone()))?;
+        let time = timestamp();
+        Ok(Workspace {
+            id: params.workspace_id,
+            name: params.name,
+            desc: params.desc,
+            apps: RepeatedApp::default(),
+            modified_time: time,
+            create_time: time,
+            role: WorkspaceRole::Owner,
+            settings: None,
+        })
+    }
+
     pub(crate) async fn create_workspace_on_local(&self, workspace: Workspace) -> Result<Workspace, FlowyError> {
         let user_id = self.user.user_id()?;
         let token = self.user.token()?;
@@ -61,8 +128,10 @@ impl WorkspaceController {
         // immediately. EXCLUSIVE and IMMEDIATE are the same in WAL mode, but in
         // other journaling modes, EXCLUSIVE prevents other database connections from
         // reading the database while the transaction is underway.
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
+        conn.timed_transaction::<_, FlowyError, _>("workspace.create_workspace_on_local", || {
+            let record = AuditRecord::workspace(AuditAction::Created, None, Some(&workspace_table));
             WorkspaceTableSql::create_workspace(workspace_table, conn)?;
+            let _ = self.audit_log.record(record, conn)?;
             let repeated_workspace = self.read_local_workspaces(None, &user_id, conn)?;
             send_dart_notification(&token, WorkspaceNotification::UserCreateWorkspace)
                 .payload(repeated_workspace)
@@ -72,6 +141,10 @@ impl WorkspaceController {
         })?;
 
         set_current_workspace(&workspace.id);
+        self.notify_opened_workspaces_changed();
+        self.folder_change_notifier.notify(FolderChange::WorkspaceCreated {
+            workspace_id: workspace.id.clone(),
+        });
 
         Ok(workspace)
     }
@@ -81,8 +154,13 @@ impl WorkspaceController {
         let changeset = WorkspaceTableChangeset::new(params.clone());
         let workspace_id = changeset.id.clone();
         let conn = &*self.database.db_connection()?;
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
+        let _ = check_workspace_editable(&workspace_id, conn)?;
+        conn.timed_transaction::<_, FlowyError, _>("workspace.update_workspace", || {
+            let before = WorkspaceTableSql::read_workspace(&workspace_id, conn)?;
             let _ = WorkspaceTableSql::update_workspace(changeset, conn)?;
+            let after = WorkspaceTableSql::read_workspace(&workspace_id, conn)?;
+            let record = AuditRecord::workspace(AuditAction::Updated, before.as_ref(), after.as_ref());
+            let _ = self.audit_log.record(record, conn)?;
             let user_id = self.user.user_id()?;
             let workspace = self.read_local_workspace(workspace_id.clone(), &user_id, conn)?;
             send_dart_notification(&workspace_id, WorkspaceNotification::WorkspaceUpdated)
@@ -91,29 +169,29 @@ impl WorkspaceController {
 
             Ok(())
         })?;
+        self.folder_change_notifier
+            .notify(FolderChange::WorkspaceUpdated { workspace_id });
 
         let _ = self.update_workspace_on_server(params)?;
 
         Ok(())
     }
 
-    #[allow(dead_code)]
+    // The workspace goes to the trash, its apps and views stay where they are
+    // and come back with it. They are deleted when the trash is emptied.
+    #[tracing::instrument(level = "debug", skip(self), err)]
     pub(crate) async fn delete_workspace(&self, workspace_id: &str) -> Result<(), FlowyError> {
-        let user_id = self.user.user_id()?;
-        let token = self.user.token()?;
-        let conn = &*self.database.db_connection()?;
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
-            let _ = WorkspaceTableSql::delete_workspace(workspace_id, conn)?;
-            let repeated_workspace = self.read_local_workspaces(None, &user_id, conn)?;
-            send_dart_notification(&token, WorkspaceNotification::UserDeleteWorkspace)
-                .payload(repeated_workspace)
-                .send();
-
-            Ok(())
-        })?;
-
-        let _ = self.delete_workspace_on_server(workspace_id)?;
-        Ok(())
+        let workspace_table = {
+            let conn = &*self.database.db_connection()?;
+            let _ = check_workspace_owner(workspace_id, conn)?;
+            match WorkspaceTableSql::read_workspace(workspace_id, conn)? {
+                None => {
+                    return Err(FlowyError::record_not_found().context(format!("{} workspace not found", workspace_id)))
+                },
+                Some(workspace_table) => workspace_table,
+            }
+        };
+        self.trash_controller.add(vec![workspace_table]).await
     }
 
     pub(crate) async fn open_workspace(&self, params: WorkspaceId) -> Result<Workspace, FlowyError> {
@@ -122,14 +200,226 @@ impl WorkspaceController {
         if let Some(workspace_id) = params.workspace_id {
             let workspace = self.read_local_workspace(workspace_id, &user_id, &*conn)?;
             set_current_workspace(&workspace.id);
+            self.notify_opened_workspaces_changed();
             Ok(workspace)
         } else {
             return Err(FlowyError::workspace_id().context("Opened workspace id should not be empty"));
         }
     }
 
-    pub(crate) async fn read_current_workspace_apps(&self) -> Result<RepeatedApp, FlowyError> {
-        let workspace_id = get_current_workspace()?;
+    // Called when the window of the workspace is closed. The workspace that
+    // was opened before it becomes the current one.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn close_workspace(&self, params: WorkspaceId) -> Result<(), FlowyError> {
+        match params.workspace_id {
+            None => Err(FlowyError::workspace_id().context("Closed workspace id should not be empty")),
+            Some(workspace_id) => {
+                remove_opened_workspace(&workspace_id);
+                self.notify_opened_workspaces_changed();
+                Ok(())
+            },
+        }
+    }
+
+    // The workspaces that are opened in the windows of the client, in the
+    // order they were opened. The ones that were trashed are left out.
+    pub(crate) fn read_opened_workspaces(&self) -> Result<RepeatedWorkspace, FlowyError> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_connection()?;
+        let mut workspaces = vec![];
+        for workspace_id in read_opened_workspace_ids() {
+            let mut repeated_workspace = self.read_local_workspaces(Some(workspace_id), &user_id, conn)?;
+            workspaces.append(&mut repeated_workspace.items);
+        }
+        Ok(RepeatedWorkspace { items: workspaces })
+    }
+
+    fn notify_opened_workspaces_changed(&self) {
+        let token = match self.user.token() {
+            Ok(token) => token,
+            Err(_) => return,
+        };
+        match self.read_opened_workspaces() {
+            Ok(repeated_workspace) => {
+                send_dart_notification(&token, WorkspaceNotification::OpenedWorkspacesChanged)
+                    .payload(repeated_workspace)
+                    .send();
+            },
+            Err(e) => tracing::error!("Read the opened workspaces failed: {}", e),
+        }
+    }
+
+    pub(crate) async fn read_workspace_members(
+        &self,
+        params: QueryWorkspaceMembersParams,
+    ) -> Result<RepeatedWorkspaceMember, FlowyError> {
+        let read_members = self.server_retry.call(ServerCallClass::Read, move |token, server| {
+            server.read_workspace_members(&token, params.clone())
+        })?;
+        let repeated_member = read_members.await?;
+        Ok(repeated_member)
+    }
+
+    // The role of the requests that don't set one. The settings may come from
+    // another device, so the role is checked again.
+    pub(crate) fn default_member_role(&self, workspace_id: &str) -> Result<WorkspaceRole, FlowyError> {
+        let conn = self.database.db_connection()?;
+        let settings = read_workspace_settings(workspace_id, &*self.user, &*conn)?;
+        let role = DefaultMemberRole::parse(settings.default_member_role)?;
+        Ok(role.0)
+    }
+
+    pub(crate) async fn add_workspace_member(
+        &self,
+        params: AddWorkspaceMemberParams,
+    ) -> Result<WorkspaceMember, FlowyError> {
+        let _ = self.server_retry.check_account()?;
+        let _ = check_workspace_owner(&params.workspace_id, &*self.database.db_connection()?)?;
+        let token = self.user.token()?;
+        let workspace_id = params.workspace_id.clone();
+        let member = self.server.add_workspace_member(&token, params).await?;
+        let _ = self.notify_members_changed(&workspace_id).await?;
+        Ok(member)
+    }
+
+    pub(crate) async fn update_workspace_member(&self, params: UpdateWorkspaceMemberParams) -> Result<(), FlowyError> {
+        let _ = check_workspace_owner(&params.workspace_id, &*self.database.db_connection()?)?;
+        let workspace_id = params.workspace_id.clone();
+        let update_member = self.server_retry.call(ServerCallClass::Write, move |token, server| {
+            server.update_workspace_member(&token, params.clone())
+        })?;
+        let _ = update_member.await?;
+        self.notify_members_changed(&workspace_id).await
+    }
+
+    // Any member can leave the workspace, but only the owner can remove the
+    // others.
+    pub(crate) async fn remove_workspace_member(&self, params: RemoveWorkspaceMemberParams) -> Result<(), FlowyError> {
+        if params.user_id != self.user.user_id()? {
+            let _ = check_workspace_owner(&params.workspace_id, &*self.database.db_connection()?)?;
+        }
+        let workspace_id = params.workspace_id.clone();
+        let remove_member = self.server_retry.call(ServerCallClass::Write, move |token, server| {
+            server.remove_workspace_member(&token, params.clone())
+        })?;
+        let _ = remove_member.await?;
+        self.notify_members_changed(&workspace_id).await
+    }
+
+    pub(crate) fn read_revision_retention(
+        &self,
+        params: QueryRevisionRetentionParams,
+    ) -> Result<RevisionRetentionSetting, FlowyError> {
+        let conn = self.database.db_connection()?;
+        read_revision_retention(&params.workspace_id, &*self.user, &*conn)
+    }
+
+    // The retention is only applied on the devices, the revisions on the
+    // server are kept. It's enforced when the workspace is opened next.
+    pub(crate) fn update_revision_retention(
+        &self,
+        setting: RevisionRetentionSetting,
+    ) -> Result<RevisionRetentionSetting, FlowyError> {
+        let _ = self.update_workspace_settings(UpdateWorkspaceSettingsParams {
+            workspace_id: setting.workspace_id.clone(),
+            retention_kind: Some(setting.kind),
+            retention_value: Some(setting.value),
+            ..UpdateWorkspaceSettingsParams::default()
+        })?;
+        Ok(setting)
+    }
+
+    pub(crate) fn read_workspace_settings(
+        &self,
+        params: QueryWorkspaceSettingsParams,
+    ) -> Result<WorkspaceSettings, FlowyError> {
+        let conn = self.database.db_connection()?;
+        read_workspace_settings(&params.workspace_id, &*self.user, &*conn)
+    }
+
+    // The settings are pushed with the workspace, the other devices and the
+    // members get them with the next folder sync.
+    pub(crate) fn update_workspace_settings(
+        &self,
+        params: UpdateWorkspaceSettingsParams,
+    ) -> Result<WorkspaceSettings, FlowyError> {
+        let workspace_id = params.workspace_id.clone();
+        let conn = &*self.database.db_connection()?;
+        let _ = check_workspace_owner(&workspace_id, conn)?;
+        let settings = conn.timed_transaction::<_, FlowyError, _>("workspace.update_workspace_settings", || {
+            let mut settings = read_workspace_settings(&workspace_id, &*self.user, conn)?;
+            params.apply_to(&mut settings);
+            let _ = save_workspace_settings(&workspace_id, &settings, conn)?;
+            Ok(settings)
+        })?;
+        send_dart_notification(&workspace_id, WorkspaceNotification::WorkspaceSettingsChanged)
+            .payload(settings.clone())
+            .send();
+
+        let _ = self.update_workspace_on_server(UpdateWorkspaceParams {
+            id: workspace_id,
+            name: None,
+            desc: None,
+            settings: Some(settings.clone()),
+        })?;
+        Ok(settings)
+    }
+
+    pub(crate) async fn create_invitation(
+        &self,
+        params: CreateInvitationParams,
+    ) -> Result<WorkspaceInvitation, FlowyError> {
+        let _ = self.server_retry.check_account()?;
+        let _ = check_workspace_owner(&params.workspace_id, &*self.database.db_connection()?)?;
+        let token = self.user.token()?;
+        let invitation = self.server.create_invitation(&token, params).await?;
+        Ok(invitation)
+    }
+
+    pub(crate) async fn read_invitations(&self) -> Result<RepeatedWorkspaceInvitation, FlowyError> {
+        let read_invitations = self
+            .server_retry
+            .call(ServerCallClass::Read, |token, server| server.read_invitations(&token))?;
+        let repeated_invitation = read_invitations.await?;
+        Ok(repeated_invitation)
+    }
+
+    // Only the workspace itself is saved here, its apps are synced from the
+    // server the next time the workspaces are read.
+    pub(crate) async fn accept_invitation(&self, params: InvitationId) -> Result<Workspace, FlowyError> {
+        let _ = self.server_retry.check_account()?;
+        let user_id = self.user.user_id()?;
+        let token = self.user.token()?;
+        let workspace = self.server.accept_invitation(&token, params).await?;
+        let workspace_table = WorkspaceTable::new(workspace.clone(), &user_id);
+        let conn = &*self.database.db_connection()?;
+        conn.timed_transaction::<_, FlowyError, _>("workspace.accept_invitation", || {
+            let _ = WorkspaceTableSql::create_workspace(workspace_table, conn)?;
+            let repeated_workspace = self.read_local_workspaces(None, &user_id, conn)?;
+            send_dart_notification(&token, WorkspaceNotification::WorkspaceListUpdated)
+                .payload(repeated_workspace)
+                .send();
+            Ok(())
+        })?;
+
+        let _ = self.notify_member_joined(&workspace.id, &user_id).await?;
+        Ok(workspace)
+    }
+
+    pub(crate) async fn decline_invitation(&self, params: InvitationId) -> Result<(), FlowyError> {
+        let _ = self.server_retry.check_account()?;
+        let token = self.user.token()?;
+        let _ = self.server.decline_invitation(&token, params).await?;
+        Ok(())
+    }
+
+    // Each window reads the apps of its own workspace, the current workspace
+    // is read if the window doesn't say which one.
+    pub(crate) async fn read_workspace_apps(&self, params: WorkspaceId) -> Result<RepeatedApp, FlowyError> {
+        let workspace_id = match params.workspace_id {
+            Some(workspace_id) => workspace_id,
+            None => get_current_workspace()?,
+        };
         let conn = self.database.db_connection()?;
         let repeated_app = self.read_local_apps(&workspace_id, &*conn)?;
         // TODO: read from server
@@ -143,15 +433,7 @@ impl WorkspaceController {
         user_id: &str,
         conn: &SqliteConnection,
     ) -> Result<RepeatedWorkspace, FlowyError> {
-        let workspace_id = workspace_id.to_owned();
-        let workspace_tables = WorkspaceTableSql::read_workspaces(workspace_id, user_id, conn)?;
-
-        let mut workspaces = vec![];
-        for table in workspace_tables {
-            let workspace: Workspace = table.into();
-            workspaces.push(workspace);
-        }
-        Ok(RepeatedWorkspace { items: workspaces })
+        read_local_workspaces(workspace_id, user_id, self.trash_controller.clone(), conn)
     }
 
     pub(crate) fn read_local_workspace(
@@ -176,53 +458,326 @@ impl WorkspaceController {
         let repeated_app = read_local_workspace_apps(workspace_id, self.trash_controller.clone(), conn)?;
         Ok(repeated_app)
     }
+
+    // Creates the workspaces of the user on the server with the ids they have
+    // on this device, e.g. once the anonymous user signed up. Their apps are
+    // returned instead of created here, they go with the views in one batch.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn upload_local_workspaces(&self) -> Result<Vec<CreateAppParams>, FlowyError> {
+        let user_id = self.user.user_id()?;
+        let (workspace_tables, apps) = {
+            let conn = &*self.database.db_connection()?;
+            let workspace_tables = WorkspaceTableSql::read_workspaces(None, &user_id, conn)?;
+            let mut apps = vec![];
+            for workspace_table in &workspace_tables {
+                for is_trash in &[false, true] {
+                    let app_tables = AppTableSql::read_workspace_apps(&workspace_table.id, *is_trash, conn)?;
+                    apps.extend(app_tables.into_iter().map(|app_table| CreateAppParams {
+                        workspace_id: app_table.workspace_id,
+                        name: app_table.name,
+                        desc: app_table.desc,
+                        color_style: ColorStyle {
+                            theme_color: app_table.color_style.theme_color,
+                        },
+                        app_id: app_table.id,
+                    }));
+                }
+            }
+            (workspace_tables, apps)
+        };
+
+        for workspace_table in workspace_tables {
+            let params = CreateWorkspaceParams {
+                name: workspace_table.name,
+                desc: workspace_table.desc,
+                workspace_id: workspace_table.id,
+            };
+            let _ = self.create_workspace_on_server(params).await?;
+        }
+        Ok(apps)
+    }
 }
 
 impl WorkspaceController {
+    // Retried like the other writes, a retry with the same id doesn't create
+    // the workspace twice.
     #[tracing::instrument(level = "debug", skip(self), err)]
     async fn create_workspace_on_server(&self, params: CreateWorkspaceParams) -> Result<Workspace, FlowyError> {
-        let token = self.user.token()?;
-        let workspace = self.server.create_workspace(&token, params).await?;
+        let create_workspace = self.server_retry.call(ServerCallClass::Write, move |token, server| {
+            server.create_workspace(&token, params.clone())
+        })?;
+        let workspace = create_workspace.await?;
         Ok(workspace)
     }
 
+    async fn notify_members_changed(&self, workspace_id: &str) -> Result<(), FlowyError> {
+        let params = QueryWorkspaceMembersParams {
+            workspace_id: workspace_id.to_owned(),
+        };
+        let repeated_member = self.read_workspace_members(params).await?;
+        send_dart_notification(workspace_id, WorkspaceNotification::WorkspaceMembersChanged)
+            .payload(repeated_member)
+            .send();
+        Ok(())
+    }
+
+    async fn notify_member_joined(&self, workspace_id: &str, user_id: &str) -> Result<(), FlowyError> {
+        let params = QueryWorkspaceMembersParams {
+            workspace_id: workspace_id.to_owned(),
+        };
+        let repeated_member = self.read_workspace_members(params).await?;
+        if let Some(member) = repeated_member.iter().find(|member| member.user_id == user_id) {
+            send_dart_notification(workspace_id, WorkspaceNotification::WorkspaceMemberJoined)
+                .payload(member.clone())
+                .send();
+        }
+        send_dart_notification(workspace_id, WorkspaceNotification::WorkspaceMembersChanged)
+            .payload(repeated_member)
+            .send();
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip(self), err)]
     fn update_workspace_on_server(&self, params: UpdateWorkspaceParams) -> Result<(), FlowyError> {
-        let (token, server) = (self.user.token()?, self.server.clone());
+        let update_workspace = self.server_retry.call(ServerCallClass::Sync, move |token, server| {
+            server.update_workspace(&token, params.clone())
+        })?;
         tokio::spawn(async move {
-            match server.update_workspace(&token, params).await {
+            match update_workspace.await {
                 Ok(_) => {},
-                Err(e) => {
-                    // TODO: retry?
-                    log::error!("Update workspace failed: {:?}", e);
-                },
+                Err(e) => log::error!("Update workspace failed: {:?}", e),
             }
         });
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self), err)]
-    fn delete_workspace_on_server(&self, workspace_id: &str) -> Result<(), FlowyError> {
-        let params = WorkspaceId {
-            workspace_id: Some(workspace_id.to_string()),
-        };
-        let (token, server) = (self.user.token()?, self.server.clone());
-        tokio::spawn(async move {
-            match server.delete_workspace(&token, params).await {
-                Ok(_) => {},
-                Err(e) => {
-                    // TODO: retry?
-                    log::error!("Delete workspace failed: {:?}", e);
-                },
+    fn listen_trash_controller_event(&self) {
+        let mut rx = self.trash_controller.subscribe();
+        let user = self.user.clone();
+        let database = self.database.clone();
+        let trash_can = self.trash_controller.clone();
+        let audit_log = self.audit_log.clone();
+        let folder_change_notifier = self.folder_change_notifier.clone();
+        let _ = tokio::spawn(async move {
+            loop {
+                let mut stream = Box::pin(rx.recv().into_stream().filter_map(|result| async move {
+                    match result {
+                        Ok(event) => event.select(TrashType::Workspace),
+                        Err(_e) => None,
+                    }
+                }));
+                if let Some(event) = stream.next().await {
+                    handle_trash_event(
+                        user.clone(),
+                        database.clone(),
+                        trash_can.clone(),
+                        audit_log.clone(),
+                        folder_change_notifier.clone(),
+                        event,
+                    )
+                    .await
+                }
             }
         });
-        Ok(())
     }
 }
 
+#[tracing::instrument(level = "trace", skip(user, database, trash_can, audit_log, folder_change_notifier))]
+async fn handle_trash_event(
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    trash_can: Arc<TrashController>,
+    audit_log: Arc<AuditLogController>,
+    folder_change_notifier: FolderChangeNotifier,
+    event: TrashEvent,
+) {
+    let is_trashed = matches!(event, TrashEvent::NewTrash(..));
+    match event {
+        TrashEvent::NewTrash(identifiers, ret) | TrashEvent::Putback(identifiers, ret) => {
+            let workspace_ids = identifiers
+                .items
+                .iter()
+                .map(|identifier| identifier.id.clone())
+                .collect::<Vec<String>>();
+            let result = || {
+                let user_id = user.user_id()?;
+                let token = user.token()?;
+                let conn = &*database.db_connection()?;
+                conn.timed_transaction::<_, FlowyError, _>("workspace.handle_trash_event", || {
+                    for workspace_id in &workspace_ids {
+                        let workspace_table = WorkspaceTableSql::read_workspace(workspace_id, conn)?;
+                        let record = match is_trashed {
+                            true => AuditRecord::workspace(AuditAction::Trashed, workspace_table.as_ref(), None),
+                            false => AuditRecord::workspace(AuditAction::Restored, None, workspace_table.as_ref()),
+                        };
+                        let _ = audit_log.record(record, conn)?;
+                        // The window of the trashed workspace opens another one,
+                        // the trashed one can't be read anymore.
+                        if is_trashed {
+                            remove_opened_workspace(workspace_id);
+                        }
+                    }
+                    let repeated_workspace = read_local_workspaces(None, &user_id, trash_can.clone(), conn)?;
+                    let ty = match is_trashed {
+                        true => WorkspaceNotification::UserDeleteWorkspace,
+                        false => WorkspaceNotification::WorkspaceListUpdated,
+                    };
+                    send_dart_notification(&token, ty).payload(repeated_workspace).send();
+                    Ok(())
+                })
+            };
+            let result = result();
+            if result.is_ok() {
+                for workspace_id in workspace_ids {
+                    folder_change_notifier.notify(match is_trashed {
+                        true => FolderChange::WorkspaceTrashed { workspace_id },
+                        false => FolderChange::WorkspaceRestored { workspace_id },
+                    });
+                }
+            }
+            let _ = ret.send(result).await;
+        },
+        TrashEvent::Delete(identifiers, ret) => {
+            let mut result = Ok(());
+            for identifier in identifiers.items {
+                result = purge_workspace(&identifier.id, &database, &trash_can, &audit_log).await;
+                if result.is_err() {
+                    break;
+                }
+                folder_change_notifier.notify(FolderChange::WorkspaceDeleted {
+                    workspace_id: identifier.id,
+                });
+            }
+            let _ = ret.send(result).await;
+        },
+    }
+}
+
+// The views and the apps of the workspace are deleted the way the trash
+// deletes them, which also removes the documents and their revisions. The
+// server deletes its copies with the workspace.
+async fn purge_workspace(
+    workspace_id: &str,
+    database: &Arc<dyn WorkspaceDatabase>,
+    trash_can: &Arc<TrashController>,
+    audit_log: &Arc<AuditLogController>,
+) -> FlowyResult<()> {
+    let (app_ids, view_ids) = {
+        let conn = &*database.db_connection()?;
+        let mut app_ids = vec![];
+        for is_trash in [false, true] {
+            for app_table in AppTableSql::read_workspace_apps(workspace_id, is_trash, conn)? {
+                app_ids.push(app_table.id);
+            }
+        }
+        let mut view_ids = vec![];
+        let mut belong_to_ids = app_ids.clone();
+        while let Some(belong_to_id) = belong_to_ids.pop() {
+            for view_table in ViewTableSql::read_views(&belong_to_id, conn)? {
+                belong_to_ids.push(view_table.id.clone());
+                view_ids.push(view_table.id);
+            }
+        }
+        (app_ids, view_ids)
+    };
+
+    // The nested views are deleted before the views they belong to.
+    let views = view_ids
+        .into_iter()
+        .rev()
+        .map(|id| TrashId {
+            id,
+            ty: TrashType::View,
+        })
+        .collect::<Vec<_>>();
+    if !views.is_empty() {
+        let _ = trash_can.delete_with_identifiers(RepeatedTrashId::from(views)).await?;
+    }
+    let apps = app_ids
+        .into_iter()
+        .map(|id| TrashId { id, ty: TrashType::App })
+        .collect::<Vec<_>>();
+    if !apps.is_empty() {
+        let _ = trash_can.delete_with_identifiers(RepeatedTrashId::from(apps)).await?;
+    }
+
+    let conn = &*database.db_connection()?;
+    conn.timed_transaction::<_, FlowyError, _>("workspace.purge_workspace", || {
+        let before = WorkspaceTableSql::read_workspace(workspace_id, conn)?;
+        if before.is_some() {
+            let _ = WorkspaceTableSql::delete_workspace(workspace_id, conn)?;
+        }
+        let record = AuditRecord::workspace(AuditAction::Deleted, before.as_ref(), None);
+        audit_log.record(record, conn)
+    })
+}
+
+// The workspaces in the trash are left out.
+pub(crate) fn read_local_workspaces(
+    workspace_id: Option<String>,
+    user_id: &str,
+    trash_controller: Arc<TrashController>,
+    conn: &SqliteConnection,
+) -> Result<RepeatedWorkspace, FlowyError> {
+    let trash_ids = trash_controller.read_trash_ids(conn)?;
+    let workspaces = WorkspaceTableSql::read_workspaces(workspace_id, user_id, conn)?
+        .into_iter()
+        .filter(|table| !trash_ids.contains(&table.id))
+        .map(Workspace::from)
+        .collect::<Vec<_>>();
+    Ok(RepeatedWorkspace { items: workspaces })
+}
+
 const CURRENT_WORKSPACE_ID: &str = "current_workspace_id";
+const OPENED_WORKSPACE_IDS: &str = "opened_workspace_ids";
+
+// Every window of the client opens a workspace. The current one is the one
+// that was opened last, it's used by the requests that don't say which
+// workspace they are for.
+fn set_current_workspace(workspace_id: &str) {
+    lib_log::set_log_context("workspace_id", workspace_id);
+    KV::set_str(CURRENT_WORKSPACE_ID, workspace_id.to_owned());
+    let mut workspace_ids = read_opened_workspace_ids();
+    workspace_ids.retain(|id| id != workspace_id);
+    workspace_ids.push(workspace_id.to_owned());
+    save_opened_workspace_ids(&workspace_ids);
+}
+
+fn remove_opened_workspace(workspace_id: &str) {
+    let mut workspace_ids = read_opened_workspace_ids();
+    workspace_ids.retain(|id| id != workspace_id);
+    save_opened_workspace_ids(&workspace_ids);
+    if KV::get_str(CURRENT_WORKSPACE_ID).as_deref() == Some(workspace_id) {
+        match workspace_ids.last() {
+            None => {
+                let _ = KV::remove(CURRENT_WORKSPACE_ID);
+            },
+            Some(last_id) => {
+                lib_log::set_log_context("workspace_id", last_id);
+                KV::set_str(CURRENT_WORKSPACE_ID, last_id.clone());
+            },
+        }
+    }
+}
 
-fn set_current_workspace(workspace_id: &str) { KV::set_str(CURRENT_WORKSPACE_ID, workspace_id.to_owned()); }
+// The current workspace of a client from before the windows had their own
+// workspaces is its only opened workspace.
+pub(crate) fn read_opened_workspace_ids() -> Vec<String> {
+    let workspace_ids: Vec<String> = KV::get_str(OPENED_WORKSPACE_IDS)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    match (workspace_ids.is_empty(), KV::get_str(CURRENT_WORKSPACE_ID)) {
+        (true, Some(workspace_id)) => vec![workspace_id],
+        _ => workspace_ids,
+    }
+}
+
+fn save_opened_workspace_ids(workspace_ids: &[String]) {
+    match serde_json::to_string(workspace_ids) {
+        Ok(json) => KV::set_str(OPENED_WORKSPACE_IDS, json),
+        Err(e) => log::error!("Save the opened workspaces failed: {:?}", e),
+    }
+}
 
 pub fn get_current_workspace() -> Result<String, FlowyError> {
     match KV::get_str(CURRENT_WORKSPACE_ID) {
@@ -15,11 +15,13 @@ pub(crate) async fn create_workspace_handler(
     data_result(detail)
 }
 
-#[tracing::instrument(skip(controller), err)]
+#[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn read_workspace_apps_handler(
+    data: Data<QueryWorkspaceRequest>,
     controller: Unit<Arc<WorkspaceController>>,
 ) -> DataResult<RepeatedApp, FlowyError> {
-    let repeated_app = controller.read_current_workspace_apps().await?;
+    let params: WorkspaceId = data.into_inner().try_into()?;
+    let repeated_app = controller.read_workspace_apps(params).await?;
     data_result(repeated_app)
 }
 
@@ -32,3 +34,154 @@ pub(crate) async fn open_workspace_handler(
     let workspaces = controller.open_workspace(params).await?;
     data_result(workspaces)
 }
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn close_workspace_handler(
+    data: Data<QueryWorkspaceRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), FlowyError> {
+    let params: WorkspaceId = data.into_inner().try_into()?;
+    controller.close_workspace(params)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_opened_workspaces_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedWorkspace, FlowyError> {
+    let repeated_workspace = controller.read_opened_workspaces()?;
+    data_result(repeated_workspace)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_workspace_handler(
+    data: Data<QueryWorkspaceRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), FlowyError> {
+    let params: WorkspaceId = data.into_inner().try_into()?;
+    match params.workspace_id {
+        None => Err(FlowyError::workspace_id().context("Deleted workspace id should not be empty")),
+        Some(workspace_id) => controller.delete_workspace(&workspace_id).await,
+    }
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_workspace_members_handler(
+    data: Data<QueryWorkspaceMembersRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedWorkspaceMember, FlowyError> {
+    let params: QueryWorkspaceMembersParams = data.into_inner().try_into()?;
+    let repeated_member = controller.read_workspace_members(params).await?;
+    data_result(repeated_member)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn add_workspace_member_handler(
+    data: Data<AddWorkspaceMemberRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<WorkspaceMember, FlowyError> {
+    let mut request = data.into_inner();
+    if request.role.is_none() {
+        request.role = Some(controller.default_member_role(&request.workspace_id)?);
+    }
+    let params: AddWorkspaceMemberParams = request.try_into()?;
+    let member = controller.add_workspace_member(params).await?;
+    data_result(member)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_workspace_member_handler(
+    data: Data<UpdateWorkspaceMemberRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), FlowyError> {
+    let params: UpdateWorkspaceMemberParams = data.into_inner().try_into()?;
+    let _ = controller.update_workspace_member(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn remove_workspace_member_handler(
+    data: Data<RemoveWorkspaceMemberRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), FlowyError> {
+    let params: RemoveWorkspaceMemberParams = data.into_inner().try_into()?;
+    let _ = controller.remove_workspace_member(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_invitation_handler(
+    data: Data<CreateInvitationRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<WorkspaceInvitation, FlowyError> {
+    let params: CreateInvitationParams = data.into_inner().try_into()?;
+    let invitation = controller.create_invitation(params).await?;
+    data_result(invitation)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_invitations_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedWorkspaceInvitation, FlowyError> {
+    let repeated_invitation = controller.read_invitations().await?;
+    data_result(repeated_invitation)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn accept_invitation_handler(
+    data: Data<QueryInvitationRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<Workspace, FlowyError> {
+    let params: InvitationId = data.into_inner().try_into()?;
+    let workspace = controller.accept_invitation(params).await?;
+    data_result(workspace)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn decline_invitation_handler(
+    data: Data<QueryInvitationRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), FlowyError> {
+    let params: InvitationId = data.into_inner().try_into()?;
+    let _ = controller.decline_invitation(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_revision_retention_handler(
+    data: Data<QueryRevisionRetentionRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RevisionRetentionSetting, FlowyError> {
+    let params: QueryRevisionRetentionParams = data.into_inner().try_into()?;
+    let setting = controller.read_revision_retention(params)?;
+    data_result(setting)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_revision_retention_handler(
+    data: Data<UpdateRevisionRetentionRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RevisionRetentionSetting, FlowyError> {
+    let setting: RevisionRetentionSetting = data.into_inner().try_into()?;
+    let setting = controller.update_revision_retention(setting)?;
+    data_result(setting)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_workspace_settings_handler(
+    data: Data<QueryWorkspaceSettingsRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<WorkspaceSettings, FlowyError> {
+    let params: QueryWorkspaceSettingsParams = data.into_inner().try_into()?;
+    let settings = controller.read_workspace_settings(params)?;
+    data_result(settings)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_workspace_settings_handler(
+    data: Data<UpdateWorkspaceSettingsRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<WorkspaceSettings, FlowyError> {
+    let params: UpdateWorkspaceSettingsParams = data.into_inner().try_into()?;
+    let settings = controller.update_workspace_settings(params)?;
+    data_result(settings)
+}
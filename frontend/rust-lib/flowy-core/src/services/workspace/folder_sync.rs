@@ -0,0 +1,127 @@
+use crate::{
+    entities::folder_sync::{FolderDelta, FolderSyncParams},
+    errors::{FlowyError, FlowyResult},
+    module::{WorkspaceDatabase, WorkspaceUser},
+    notify::{send_dart_notification, WorkspaceNotification},
+    retry::{ServerCallClass, ServerRetry},
+    services::{
+        app::sql::{AppTable, AppTableSql},
+        notify_trash_changed,
+        trash::sql::TrashTableSql,
+        view::sql::{ViewTable, ViewTableSql},
+        workspace::{
+            setting::save_workspace_settings,
+            sql::{WorkspaceTable, WorkspaceTableSql},
+        },
+        WorkspaceController,
+    },
+};
+use flowy_database::{kv::KV, slow_log::SlowLogTransaction, SqliteConnection};
+use std::sync::Arc;
+
+// Pulls what changed in the folder on the other devices since the last sync,
+// in one call instead of reading the workspaces and the trash one by one when
+// the user signs in.
+pub(crate) struct FolderSyncer {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    workspace_controller: Arc<WorkspaceController>,
+    server_retry: ServerRetry,
+}
+
+impl FolderSyncer {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        workspace_controller: Arc<WorkspaceController>,
+        server_retry: ServerRetry,
+    ) -> Self {
+        Self {
+            user,
+            database,
+            workspace_controller,
+            server_retry,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn sync(&self) -> FlowyResult<()> {
+        let user_id = self.user.user_id()?;
+        let token = self.user.token()?;
+        let cursor = KV::get_int(&cursor_key(&user_id)).unwrap_or(0);
+        let delta = self
+            .server_retry
+            .call_batch(ServerCallClass::Sync, move |token, server| {
+                server.sync_folder(&token, FolderSyncParams { cursor })
+            })?
+            .await?;
+        tracing::debug!(
+            "Folder delta of cursor {}: {} workspaces, snapshot: {}",
+            cursor,
+            delta.workspaces.len(),
+            delta.is_snapshot
+        );
+
+        let conn = &*self.database.db_connection()?;
+        let (workspaces, repeated_trash) =
+            conn.timed_transaction::<_, FlowyError, _>("workspace.sync_folder", || {
+                let _ = apply_delta(&delta, &user_id, conn)?;
+                let workspaces = self.workspace_controller.read_local_workspaces(None, &user_id, conn)?;
+                Ok((workspaces, TrashTableSql::read_all(conn)?))
+            })?;
+        // The first version of the api has no cursor, the next sync reads the
+        // whole folder again.
+        KV::set_int(&cursor_key(&user_id), delta.cursor);
+
+        notify_trash_changed(repeated_trash);
+        for workspace in &delta.workspaces.items {
+            if let Some(settings) = &workspace.settings {
+                send_dart_notification(&workspace.id, WorkspaceNotification::WorkspaceSettingsChanged)
+                    .payload(settings.clone())
+                    .send();
+            }
+        }
+        send_dart_notification(&token, WorkspaceNotification::WorkspaceListUpdated)
+            .payload(workspaces)
+            .send();
+        Ok(())
+    }
+}
+
+fn cursor_key(user_id: &str) -> String { format!("folder_sync_cursor_{}", user_id) }
+
+// The objects that are only on this device are kept, even if the delta is a
+// snapshot, they may not have been pushed yet.
+fn apply_delta(delta: &FolderDelta, user_id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
+    for workspace in &delta.workspaces.items {
+        let apps = workspace.apps.clone().into_inner();
+        let _ = WorkspaceTableSql::create_workspace(WorkspaceTable::new(workspace.clone(), user_id), conn)?;
+        if let Some(settings) = &workspace.settings {
+            let _ = save_workspace_settings(&workspace.id, settings, conn)?;
+        }
+        for app in apps {
+            let views = app.belongings.clone().into_inner();
+            if let Err(e) = AppTableSql::create_app(AppTable::new(app), conn) {
+                log::error!("Save the synced app failed: {:?}", e);
+            }
+            for view in views {
+                if let Err(e) = ViewTableSql::create_view(ViewTable::new(view), conn) {
+                    log::error!("Save the synced view failed: {:?}", e);
+                }
+            }
+        }
+    }
+
+    for view_id in &delta.deleted_view_ids {
+        if ViewTableSql::read_view(view_id, conn).is_ok() {
+            let _ = ViewTableSql::delete_view(view_id, conn)?;
+        }
+    }
+    for app_id in &delta.deleted_app_ids {
+        if AppTableSql::read_app(app_id, conn).is_ok() {
+            let _ = AppTableSql::delete_app(app_id, conn)?;
+        }
+    }
+    let _ = TrashTableSql::create_trash(delta.trash.items.clone(), conn)?;
+    Ok(())
+}
@@ -1,3 +1,8 @@
 pub mod controller;
 pub mod event_handler;
+pub(crate) mod folder_sync;
+pub(crate) mod retention;
+pub(crate) mod role;
+pub(crate) mod setting;
+pub(crate) mod skeleton;
 pub(crate) mod sql;
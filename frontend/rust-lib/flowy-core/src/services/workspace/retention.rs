@@ -0,0 +1,88 @@
+use crate::{
+    entities::workspace::{RetentionKind, RevisionRetentionSetting},
+    errors::FlowyError,
+    module::WorkspaceUser,
+};
+use flowy_database::{
+    prelude::*,
+    result::OptionalExtension,
+    schema::{rev_retention_table, rev_retention_table::dsl},
+    SqliteConnection,
+};
+use flowy_document::core::RevisionRetention;
+
+pub(crate) struct RevisionRetentionTableSql {}
+
+impl RevisionRetentionTableSql {
+    pub(crate) fn write(table: RevisionRetentionTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = diesel::replace_into(rev_retention_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(
+        workspace_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<RevisionRetentionTable>, FlowyError> {
+        let table = dsl::rev_retention_table
+            .filter(rev_retention_table::workspace_id.eq(workspace_id))
+            .first::<RevisionRetentionTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "rev_retention_table"]
+pub(crate) struct RevisionRetentionTable {
+    pub workspace_id: String,
+    pub kind: i32,
+    pub value: i64,
+}
+
+impl std::convert::From<RevisionRetentionSetting> for RevisionRetentionTable {
+    fn from(setting: RevisionRetentionSetting) -> Self {
+        RevisionRetentionTable {
+            workspace_id: setting.workspace_id,
+            kind: setting.kind as i32,
+            value: setting.value,
+        }
+    }
+}
+
+// The workspaces without a retention of their own follow the
+// `revision_retention_days` of the user settings.
+pub(crate) fn read_revision_retention(
+    workspace_id: &str,
+    user: &dyn WorkspaceUser,
+    conn: &SqliteConnection,
+) -> Result<RevisionRetentionSetting, FlowyError> {
+    if let Some(table) = RevisionRetentionTableSql::read(workspace_id, conn)? {
+        return Ok(RevisionRetentionSetting {
+            workspace_id: table.workspace_id,
+            kind: RetentionKind::from(table.kind),
+            value: table.value,
+        });
+    }
+
+    let settings: serde_json::Value = serde_json::from_str(&user.settings_json()?)?;
+    let days = settings["revision_retention_days"].as_i64().unwrap_or(0);
+    let kind = match days > 0 {
+        true => RetentionKind::LastDays,
+        false => RetentionKind::All,
+    };
+    Ok(RevisionRetentionSetting {
+        workspace_id: workspace_id.to_owned(),
+        kind,
+        value: days.max(0),
+    })
+}
+
+pub(crate) fn revision_retention(setting: &RevisionRetentionSetting) -> RevisionRetention {
+    match setting.kind {
+        RetentionKind::All => RevisionRetention::All,
+        RetentionKind::LastRevisions => RevisionRetention::LastRevisions(setting.value as usize),
+        RetentionKind::LastDays => RevisionRetention::LastDays(setting.value),
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    entities::workspace::WorkspaceRole,
+    errors::{FlowyError, FlowyResult},
+    services::workspace::sql::WorkspaceTableSql,
+};
+use flowy_database::{
+    prelude::*,
+    result::OptionalExtension,
+    schema::{app_table, view_table},
+    SqliteConnection,
+};
+
+// Returns the role of the current user in the workspace. The workspace that
+// isn't synced to this device yet is treated as owned, because the server
+// checks the permission again when the change is pushed.
+pub(crate) fn read_workspace_role(workspace_id: &str, conn: &SqliteConnection) -> FlowyResult<WorkspaceRole> {
+    match WorkspaceTableSql::read_workspace(workspace_id, conn)? {
+        None => Ok(WorkspaceRole::Owner),
+        Some(table) => Ok(WorkspaceRole::from(table.role)),
+    }
+}
+
+pub(crate) fn check_workspace_editable(workspace_id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
+    match read_workspace_role(workspace_id, conn)?.can_edit() {
+        true => Ok(()),
+        false => Err(FlowyError::workspace_permission().context("The workspace is read-only")),
+    }
+}
+
+pub(crate) fn check_workspace_owner(workspace_id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
+    match read_workspace_role(workspace_id, conn)?.can_manage_members() {
+        true => Ok(()),
+        false => Err(FlowyError::workspace_permission().context("Only the owner can manage the workspace")),
+    }
+}
+
+// The id is either a view id or an app id. The views can be nested, so the
+// belong_to_id is followed until it reaches the app that the views belong to.
+pub(crate) fn read_belonging_workspace_id(id: &str, conn: &SqliteConnection) -> FlowyResult<Option<String>> {
+    let mut belong_to_id = id.to_owned();
+    while let Some(parent_id) = view_table::dsl::view_table
+        .filter(view_table::id.eq(&belong_to_id))
+        .select(view_table::belong_to_id)
+        .first::<String>(conn)
+        .optional()?
+    {
+        belong_to_id = parent_id;
+    }
+
+    let workspace_id = app_table::dsl::app_table
+        .filter(app_table::id.eq(&belong_to_id))
+        .select(app_table::workspace_id)
+        .first::<String>(conn)
+        .optional()?;
+    Ok(workspace_id)
+}
+
+pub(crate) fn check_belonging_editable(id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
+    match read_belonging_workspace_id(id, conn)? {
+        None => Ok(()),
+        Some(workspace_id) => check_workspace_editable(&workspace_id, conn),
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    entities::{
+        view::ViewType,
+        workspace::{DateFormat, RevisionRetentionSetting, WorkspaceRole, WorkspaceSettings},
+    },
+    errors::FlowyError,
+    module::WorkspaceUser,
+    services::workspace::retention::{read_revision_retention, RevisionRetentionTableSql},
+};
+use flowy_database::{
+    prelude::*,
+    result::OptionalExtension,
+    schema::{workspace_setting_table, workspace_setting_table::dsl},
+    SqliteConnection,
+};
+
+pub(crate) struct WorkspaceSettingTableSql {}
+
+impl WorkspaceSettingTableSql {
+    pub(crate) fn write(table: WorkspaceSettingTable, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let _ = diesel::replace_into(workspace_setting_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(
+        workspace_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<WorkspaceSettingTable>, FlowyError> {
+        let table = dsl::workspace_setting_table
+            .filter(workspace_setting_table::workspace_id.eq(workspace_id))
+            .first::<WorkspaceSettingTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "workspace_setting_table"]
+pub(crate) struct WorkspaceSettingTable {
+    pub workspace_id: String,
+    pub default_view_type: i32,
+    pub date_format: i32,
+    pub default_member_role: i32,
+}
+
+impl WorkspaceSettingTable {
+    fn new(workspace_id: &str, settings: &WorkspaceSettings) -> Self {
+        WorkspaceSettingTable {
+            workspace_id: workspace_id.to_owned(),
+            default_view_type: settings.default_view_type.clone() as i32,
+            date_format: settings.date_format as i32,
+            default_member_role: settings.default_member_role as i32,
+        }
+    }
+}
+
+// The retention of the settings is the one that the revisions of the
+// workspace are squashed with, so it's kept in its own table.
+pub(crate) fn read_workspace_settings(
+    workspace_id: &str,
+    user: &dyn WorkspaceUser,
+    conn: &SqliteConnection,
+) -> Result<WorkspaceSettings, FlowyError> {
+    let mut settings = match WorkspaceSettingTableSql::read(workspace_id, conn)? {
+        None => WorkspaceSettings::default(),
+        Some(table) => WorkspaceSettings {
+            default_view_type: ViewType::from(table.default_view_type),
+            date_format: DateFormat::from(table.date_format),
+            default_member_role: WorkspaceRole::from(table.default_member_role),
+            ..WorkspaceSettings::default()
+        },
+    };
+    let retention = read_revision_retention(workspace_id, user, conn)?;
+    settings.retention_kind = retention.kind;
+    settings.retention_value = retention.value;
+    Ok(settings)
+}
+
+pub(crate) fn save_workspace_settings(
+    workspace_id: &str,
+    settings: &WorkspaceSettings,
+    conn: &SqliteConnection,
+) -> Result<(), FlowyError> {
+    let _ = WorkspaceSettingTableSql::write(WorkspaceSettingTable::new(workspace_id, settings), conn)?;
+    let retention = RevisionRetentionSetting {
+        workspace_id: workspace_id.to_owned(),
+        kind: settings.retention_kind,
+        value: settings.retention_value,
+    };
+    let _ = RevisionRetentionTableSql::write(retention.into(), conn)?;
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::entities::workspace::Workspace;
+use bytes::Bytes;
+use flowy_database::kv::KV;
+use std::convert::{TryFrom, TryInto};
+
+const WORKSPACE_SKELETON: &str = "workspace_skeleton";
+
+// The skeleton is the current workspace with its apps but without their views.
+// It's cached in the kv store, so the sidebar can be painted on startup without
+// reading the folder tables. The views of an app are read when the app is
+// opened.
+//
+// Any write to the workspace, app or trash table drops the skeleton, it's
+// rebuilt from the database on the next read.
+pub(crate) fn read_workspace_skeleton(workspace_id: &str) -> Option<Workspace> {
+    let hex_str = KV::get_str(WORKSPACE_SKELETON)?;
+    let bytes = hex::decode(hex_str).ok()?;
+    match Workspace::try_from(Bytes::from(bytes)) {
+        Ok(workspace) if workspace.id == workspace_id => Some(workspace),
+        Ok(_) => None,
+        Err(e) => {
+            log::error!("Deserialize workspace skeleton failed: {:?}", e);
+            None
+        },
+    }
+}
+
+pub(crate) fn save_workspace_skeleton(mut workspace: Workspace) {
+    for app in workspace.apps.iter_mut() {
+        let _ = app.take_belongings();
+    }
+
+    let result: Result<Bytes, _> = workspace.try_into();
+    match result {
+        Ok(bytes) => KV::set_str(WORKSPACE_SKELETON, hex::encode(bytes)),
+        Err(e) => log::error!("Serialize workspace skeleton failed: {:?}", e),
+    }
+}
+
+pub(crate) fn invalidate_workspace_skeleton() {
+    if KV::get_str(WORKSPACE_SKELETON).is_some() {
+        let _ = KV::remove(WORKSPACE_SKELETON);
+    }
+}
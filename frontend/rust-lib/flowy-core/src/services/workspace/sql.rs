@@ -1,14 +1,18 @@
 use crate::{
     entities::{
         app::RepeatedApp,
-        workspace::{UpdateWorkspaceParams, Workspace},
+        trash::{Trash, TrashType},
+        workspace::{UpdateWorkspaceParams, Workspace, WorkspaceRole},
     },
     errors::FlowyError,
+    services::workspace::skeleton::invalidate_workspace_skeleton,
 };
 use diesel::SqliteConnection;
 use flowy_database::{
     prelude::*,
+    result::OptionalExtension,
     schema::{workspace_table, workspace_table::dsl},
+    slow_log::{log_if_slow, statement_shape},
 };
 pub(crate) struct WorkspaceTableSql {}
 
@@ -21,6 +25,7 @@ impl WorkspaceTableSql {
                 diesel_update_table!(workspace_table, changeset, conn);
             },
         }
+        invalidate_workspace_skeleton();
         Ok(())
     }
 
@@ -38,23 +43,50 @@ impl WorkspaceTableSql {
             filter = filter.filter(workspace_table::id.eq(workspace_id));
         };
 
-        let workspaces = filter.load::<WorkspaceTable>(conn)?;
+        let workspaces = log_if_slow(&statement_shape(&filter), || filter.load::<WorkspaceTable>(conn))?;
 
         Ok(workspaces)
     }
 
+    pub(crate) fn read_workspace(
+        workspace_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<WorkspaceTable>, FlowyError> {
+        let workspace = dsl::workspace_table
+            .filter(workspace_table::id.eq(workspace_id))
+            .first::<WorkspaceTable>(conn)
+            .optional()?;
+        Ok(workspace)
+    }
+
+    // Moves every workspace owned by `from_user_id` to `to_user_id`. Used when an
+    // anonymous user signs up and their local data is handed over to the new
+    // account.
+    pub(crate) fn transfer_workspaces(
+        from_user_id: &str,
+        to_user_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<usize, FlowyError> {
+        let count = diesel::update(dsl::workspace_table.filter(workspace_table::user_id.eq(from_user_id)))
+            .set(workspace_table::user_id.eq(to_user_id))
+            .execute(conn)?;
+        invalidate_workspace_skeleton();
+        Ok(count)
+    }
+
     #[allow(dead_code)]
     pub(crate) fn update_workspace(
         changeset: WorkspaceTableChangeset,
         conn: &SqliteConnection,
     ) -> Result<(), FlowyError> {
         diesel_update_table!(workspace_table, changeset, conn);
+        invalidate_workspace_skeleton();
         Ok(())
     }
 
-    #[allow(dead_code)]
     pub(crate) fn delete_workspace(workspace_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
         diesel_delete_table!(workspace_table, workspace_id, conn);
+        invalidate_workspace_skeleton();
         Ok(())
     }
 }
@@ -69,6 +101,7 @@ pub struct WorkspaceTable {
     pub create_time: i64,
     pub user_id: String,
     pub version: i64,
+    pub role: i32,
 }
 
 impl WorkspaceTable {
@@ -82,6 +115,7 @@ impl WorkspaceTable {
             create_time: workspace.create_time,
             user_id: user_id.to_owned(),
             version: 0,
+            role: workspace.role as i32,
         }
     }
 }
@@ -95,6 +129,23 @@ impl std::convert::From<WorkspaceTable> for Workspace {
             apps: RepeatedApp::default(),
             modified_time: table.modified_time,
             create_time: table.create_time,
+            role: WorkspaceRole::from(table.role),
+            settings: None,
+        }
+    }
+}
+
+impl std::convert::From<WorkspaceTable> for Trash {
+    fn from(table: WorkspaceTable) -> Self {
+        Trash {
+            id: table.id,
+            name: table.name,
+            modified_time: table.modified_time,
+            create_time: table.create_time,
+            ty: TrashType::Workspace,
+            deleted_by: "".to_owned(),
+            parent_id: "".to_owned(),
+            position: 0,
         }
     }
 }
@@ -105,6 +156,7 @@ pub struct WorkspaceTableChangeset {
     pub id: String,
     pub name: Option<String>,
     pub desc: Option<String>,
+    pub role: Option<i32>,
 }
 
 impl WorkspaceTableChangeset {
@@ -113,6 +165,7 @@ impl WorkspaceTableChangeset {
             id: params.id,
             name: params.name,
             desc: params.desc,
+            role: None,
         }
     }
 
@@ -121,6 +174,7 @@ impl WorkspaceTableChangeset {
             id: table.id,
             name: Some(table.name),
             desc: Some(table.desc),
+            role: Some(table.role),
         }
     }
 }
@@ -1,5 +1,5 @@
 #![allow(clippy::type_complexity)]
-use crate::{module::WorkspaceUser, services::server::Server};
+use crate::module::WorkspaceUser;
 use lib_infra::retry::Action;
 use pin_project::pin_project;
 use std::{
@@ -10,23 +10,22 @@ use std::{
     task::{Context, Poll},
 };
 
-pub(crate) type Builder<Fut> = Box<dyn Fn(String, Server) -> Fut + Send + Sync>;
+pub(crate) type Builder<S, Fut> = Box<dyn Fn(String, S) -> Fut + Send + Sync>;
 
 #[allow(dead_code)]
-pub(crate) struct RetryAction<Fut, T, E> {
+pub(crate) struct RetryAction<S, Fut, T, E> {
     token: String,
-    server: Server,
+    server: S,
     user: Arc<dyn WorkspaceUser>,
-    builder: Builder<Fut>,
+    builder: Builder<S, Fut>,
     phantom: PhantomData<(T, E)>,
 }
 
-impl<Fut, T, E> RetryAction<Fut, T, E> {
-    #[allow(dead_code)]
-    pub(crate) fn new<F>(server: Server, user: Arc<dyn WorkspaceUser>, builder: F) -> Self
+impl<S, Fut, T, E> RetryAction<S, Fut, T, E> {
+    pub(crate) fn new<F>(server: S, user: Arc<dyn WorkspaceUser>, builder: F) -> Self
     where
         Fut: Future<Output = Result<T, E>> + Send + Sync + 'static,
-        F: Fn(String, Server) -> Fut + Send + Sync + 'static,
+        F: Fn(String, S) -> Fut + Send + Sync + 'static,
     {
         let token = user.token().unwrap_or_else(|_| "".to_owned());
         Self {
@@ -39,8 +38,9 @@ impl<Fut, T, E> RetryAction<Fut, T, E> {
     }
 }
 
-impl<Fut, T, E> Action for RetryAction<Fut, T, E>
+impl<S, Fut, T, E> Action for RetryAction<S, Fut, T, E>
 where
+    S: Clone,
     Fut: Future<Output = Result<T, E>> + Send + Sync + 'static,
     T: Send + Sync + 'static,
     E: Send + Sync + 'static,
@@ -1,9 +1,16 @@
-use flowy_core::entities::{
-    app::QueryAppRequest,
-    trash::{TrashId, TrashType},
-    view::*,
+use flowy_core::{
+    entities::{
+        app::{App, QueryAppRequest},
+        trash::{TrashId, TrashType},
+        view::*,
+        workspace::QueryWorkspaceRequest,
+    },
+    event::WorkspaceEvent::DuplicateApp,
 };
-use flowy_test::helper::*;
+use flowy_database::{prelude::*, schema::op_journal_table};
+use flowy_test::{event_builder::*, helper::*};
+use lib_infra::uuid_string;
+use tokio::time::{sleep, Duration};
 
 #[tokio::test]
 #[should_panic]
@@ -54,7 +61,8 @@ async fn app_create_with_view() {
         name: "View A".to_string(),
         desc: "".to_string(),
         thumbnail: Some("http://1.png".to_string()),
-        view_type: ViewType::Doc,
+        view_type: Some(ViewType::Doc),
+        view_id: None,
     };
 
     let request_b = CreateViewRequest {
@@ -62,7 +70,8 @@ async fn app_create_with_view() {
         name: "View B".to_string(),
         desc: "".to_string(),
         thumbnail: Some("http://1.png".to_string()),
-        view_type: ViewType::Doc,
+        view_type: Some(ViewType::Doc),
+        view_id: None,
     };
 
     let view_a = create_view_with_request(&test.sdk, request_a).await;
@@ -76,3 +85,108 @@ async fn app_create_with_view() {
     assert_eq!(view_from_db.belongings[0], view_a);
     assert_eq!(view_from_db.belongings[1], view_b);
 }
+
+#[tokio::test]
+async fn app_create_view_retried_with_same_id() {
+    let test = AppTest::new().await;
+    let view_id = uuid_string();
+    let request = || CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "View A".to_string(),
+        desc: "".to_string(),
+        thumbnail: None,
+        view_type: Some(ViewType::Doc),
+        view_id: Some(view_id.clone()),
+    };
+    let view = create_view_with_request(&test.sdk, request()).await;
+    let retried_view = create_view_with_request(&test.sdk, request()).await;
+    assert_eq!(view.id, view_id);
+    assert_eq!(retried_view.id, view_id);
+    assert_eq!(retried_view.name, view.name);
+
+    let query = QueryAppRequest {
+        app_ids: vec![test.app.id.clone()],
+    };
+    let belongings = read_app(&test.sdk, query).await.belongings;
+    assert_eq!(belongings.len(), 1);
+}
+
+#[tokio::test]
+async fn app_duplicate_with_views() {
+    let test = AppTest::new().await;
+    let view_a = create_view(&test.sdk, &test.app.id).await;
+    let nested_request = CreateViewRequest {
+        belong_to_id: view_a.id.clone(),
+        name: "Nested View".to_string(),
+        desc: "".to_string(),
+        thumbnail: None,
+        view_type: Some(ViewType::Doc),
+        view_id: None,
+    };
+    let _ = create_view_with_request(&test.sdk, nested_request).await;
+
+    let app = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(DuplicateApp)
+        .request(QueryAppRequest {
+            app_ids: vec![test.app.id.clone()],
+        })
+        .async_send()
+        .await
+        .parse::<App>();
+    assert_ne!(app.id, test.app.id);
+    assert_eq!(app.name, format!("{} (copy)", test.app.name));
+    assert_eq!(app.belongings.len(), 1);
+    assert_ne!(app.belongings[0].id, view_a.id);
+    assert_eq!(app.belongings[0].name, view_a.name);
+
+    let query = QueryAppRequest {
+        app_ids: vec![app.id.clone()],
+    };
+    assert_eq!(read_app(&test.sdk, query).await.belongings, app.belongings);
+}
+
+#[tokio::test]
+async fn app_rollback_incomplete_duplicate() {
+    let test = AppTest::new().await;
+    let view = create_view(&test.sdk, &test.app.id).await;
+
+    // A duplicate of an app that was cut off after its app and its view were
+    // written, it's rolled back when the workspace is initialized again.
+    let objects = format!(r#"{{"app_ids":["{}"],"view_ids":["{}"]}}"#, test.app.id, view.id);
+    let conn = test.sdk.user_session.db_connection().unwrap();
+    let _ = diesel::insert_into(op_journal_table::table)
+        .values((
+            op_journal_table::id.eq(uuid_string()),
+            op_journal_table::name.eq("duplicate_app"),
+            op_journal_table::objects.eq(objects),
+            op_journal_table::create_time.eq(0),
+        ))
+        .execute(&*conn)
+        .unwrap();
+    drop(conn);
+
+    let user_id = test.sdk.user_session.user_id().unwrap();
+    let token = test.sdk.user_session.token().unwrap();
+    test.sdk.core.reset(&user_id).await;
+    test.sdk.core.user_did_sign_in(&token).await.unwrap();
+    for _ in 0..50 {
+        let conn = test.sdk.user_session.db_connection().unwrap();
+        let count = op_journal_table::table.count().get_result::<i64>(&*conn).unwrap();
+        if count == 0 {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let request = QueryWorkspaceRequest::new(Some(test.workspace.id.clone()));
+    let workspace = read_workspace(&test.sdk, request).await.pop().unwrap();
+    assert!(workspace.apps.iter().all(|app| app.id != test.app.id));
+    assert!(read_trash(&test.sdk).await.is_empty());
+    let records = test
+        .sdk
+        .document_ctx
+        .controller
+        .read_revision_records(&view.id)
+        .unwrap();
+    assert!(records.is_empty());
+}
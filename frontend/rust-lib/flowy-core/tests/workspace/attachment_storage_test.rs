@@ -0,0 +1,80 @@
+use flowy_core::{
+    entities::{
+        storage::{AttachmentStorage, UpdateAttachmentStorageRequest},
+        workspace::QueryWorkspaceRequest,
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::{ReadAttachmentStorage, SyncAttachments, UpdateAttachmentStorage},
+};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+
+fn storage_request(workspace_id: &str, secret_access_key: &str) -> UpdateAttachmentStorageRequest {
+    UpdateAttachmentStorageRequest {
+        workspace_id: workspace_id.to_owned(),
+        endpoint: "http://127.0.0.1:9000/".to_owned(),
+        bucket: "appflowy-attachments".to_owned(),
+        region: "".to_owned(),
+        access_key_id: "minio".to_owned(),
+        secret_access_key: secret_access_key.to_owned(),
+        prefix: "/team/".to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn attachment_storage_keeps_the_secret() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let workspace_id = test.workspace.id.clone();
+
+    let storage = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateAttachmentStorage)
+        .request(storage_request(&workspace_id, "secret"))
+        .async_send()
+        .await
+        .parse::<AttachmentStorage>();
+    assert_eq!(storage.endpoint, "http://127.0.0.1:9000");
+    assert_eq!(storage.region, "us-east-1");
+    assert_eq!(storage.prefix, "team");
+    assert!(storage.has_secret);
+
+    // An empty secret keeps the one that is set.
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateAttachmentStorage)
+        .request(storage_request(&workspace_id, ""))
+        .async_send()
+        .await;
+    let storage = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadAttachmentStorage)
+        .request(QueryWorkspaceRequest::new(Some(workspace_id.clone())))
+        .async_send()
+        .await
+        .parse::<AttachmentStorage>();
+    assert!(storage.has_secret);
+    assert_eq!(storage.bucket, "appflowy-attachments");
+}
+
+#[tokio::test]
+async fn attachment_storage_invalid() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let mut request = storage_request(&test.workspace.id, "secret");
+    request.bucket = "Not_A_Bucket".to_owned();
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateAttachmentStorage)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::StorageBucketInvalid.value());
+
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(SyncAttachments)
+        .request(QueryWorkspaceRequest::new(Some(test.workspace.id.clone())))
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::StorageNotConfigured.value());
+}
@@ -0,0 +1,73 @@
+use flowy_core::{
+    entities::{
+        audit_log::{AuditLogPage, ReadAuditLogRequest},
+        view::UpdateViewRequest,
+    },
+    event::WorkspaceEvent::ReadAuditLog,
+};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+
+async fn read_audit_log(sdk: &FlowySDKTest, workspace_id: &str, cursor: i64, limit: i64) -> AuditLogPage {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadAuditLog)
+        .request(ReadAuditLogRequest {
+            workspace_id: workspace_id.to_owned(),
+            cursor,
+            limit,
+        })
+        .async_send()
+        .await
+        .parse::<AuditLogPage>()
+}
+
+#[tokio::test]
+async fn audit_log_record_update_with_actor() {
+    let test = FlowySDKTest::default();
+    let user = test.init_user().await;
+
+    let view_test = ViewTest::new(&test).await;
+    let request = UpdateViewRequest {
+        view_id: view_test.view.id.clone(),
+        name: Some("Renamed".to_owned()),
+        desc: None,
+        thumbnail: None,
+    };
+    update_view(&test, request).await;
+
+    let page = read_audit_log(&test, &view_test.workspace.id, 0, 1000).await;
+    assert!(page
+        .items
+        .iter()
+        .all(|entry| entry.workspace_id == view_test.workspace.id && entry.actor_id == user.id));
+    assert!(page
+        .items
+        .iter()
+        .any(|entry| entry.action == "created" && entry.object_id == view_test.app.id));
+
+    let entry = page
+        .items
+        .iter()
+        .find(|entry| entry.action == "updated" && entry.object_id == view_test.view.id)
+        .unwrap();
+    assert_eq!(entry.object_type, "view");
+    assert!(entry.before.contains(&view_test.view.name));
+    assert!(entry.after.contains("Renamed"));
+}
+
+#[tokio::test]
+async fn audit_log_record_view_deleted() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let view_test = ViewTest::new(&test).await;
+    view_test.delete_views_permanent(vec![view_test.view.id.clone()]).await;
+
+    let page = read_audit_log(&test, &view_test.workspace.id, 0, 1000).await;
+    let actions = page
+        .items
+        .iter()
+        .filter(|entry| entry.object_id == view_test.view.id)
+        .map(|entry| entry.action.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(actions, vec!["created", "trashed", "deleted"]);
+}
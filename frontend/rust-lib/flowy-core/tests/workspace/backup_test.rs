@@ -0,0 +1,132 @@
+use flowy_core::{
+    entities::backup::{
+        BackupFrequency,
+        BackupResult,
+        BackupSetting,
+        QueryBackupsRequest,
+        RepeatedBackupManifest,
+        SelectBackupRestoreRequest,
+        UpdateBackupSettingRequest,
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::{ReadBackups, RunBackup, SelectBackupRestore, UpdateBackupSetting},
+};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+use lib_infra::uuid_string;
+
+#[tokio::test]
+async fn backup_keep_the_newest_ones() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let dir = format!("{}/backup_{}", root_dir(), uuid_string());
+    std::fs::create_dir_all(&dir).unwrap();
+    let setting = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateBackupSetting)
+        .request(UpdateBackupSettingRequest {
+            enabled: true,
+            dir: dir.clone(),
+            frequency: BackupFrequency::Weekly,
+            keep_count: 2,
+        })
+        .async_send()
+        .await
+        .parse::<BackupSetting>();
+    assert_eq!(setting.last_backup_time, 0);
+
+    let mut paths = vec![];
+    for _ in 0..3 {
+        let result = CoreModuleEventBuilder::new(test.sdk.clone())
+            .event(RunBackup)
+            .async_send()
+            .await
+            .parse::<BackupResult>();
+        assert!(result.document_count > 0);
+        paths.push(result.path);
+    }
+
+    let backups = std::fs::read_dir(&dir).unwrap().count();
+    assert_eq!(backups, 2);
+    assert!(!std::path::Path::new(&paths[0]).exists());
+    assert!(std::path::Path::new(&paths[2]).exists());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn backup_setting_invalid() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    for (dir, keep_count, code) in vec![
+        (root_dir(), 0, ErrorCode::BackupKeepCountInvalid),
+        ("".to_owned(), 7, ErrorCode::ExportPathInvalid),
+        (
+            format!("{}/{}", root_dir(), uuid_string()),
+            7,
+            ErrorCode::ExportPathInvalid,
+        ),
+    ] {
+        let error = CoreModuleEventBuilder::new(test.clone())
+            .event(UpdateBackupSetting)
+            .request(UpdateBackupSettingRequest {
+                enabled: true,
+                dir,
+                frequency: BackupFrequency::Daily,
+                keep_count,
+            })
+            .async_send()
+            .await
+            .error();
+        assert_eq!(error.code, code.value());
+    }
+}
+
+// A valid archive isn't selected here, the next sign up of any test would
+// restore it.
+#[tokio::test]
+async fn backup_read_the_restorable_archives() {
+    let test = FlowySDKTest::default();
+    let user = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let dir = format!("{}/backup_{}", root_dir(), uuid_string());
+    std::fs::create_dir_all(&dir).unwrap();
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateBackupSetting)
+        .request(UpdateBackupSettingRequest {
+            enabled: false,
+            dir: dir.clone(),
+            frequency: BackupFrequency::Daily,
+            keep_count: 7,
+        })
+        .async_send()
+        .await;
+    let result = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(RunBackup)
+        .async_send()
+        .await
+        .parse::<BackupResult>();
+    let broken_path = format!("{}/broken.zip", dir);
+    std::fs::write(&broken_path, "not a zip").unwrap();
+
+    let manifests = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadBackups)
+        .request(QueryBackupsRequest { dir: dir.clone() })
+        .async_send()
+        .await
+        .parse::<RepeatedBackupManifest>();
+    assert_eq!(manifests.items.len(), 1);
+    assert_eq!(manifests.items[0].path, result.path);
+    assert_eq!(manifests.items[0].user_id, user.id);
+    assert_eq!(manifests.items[0].document_count, result.document_count);
+
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(SelectBackupRestore)
+        .request(SelectBackupRestoreRequest { path: broken_path })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::BackupCorrupted.value());
+    let _ = std::fs::remove_dir_all(&dir);
+}
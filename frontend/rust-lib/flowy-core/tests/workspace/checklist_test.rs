@@ -0,0 +1,134 @@
+use flowy_core::{
+    entities::{
+        checklist::{
+            Checklist,
+            ChecklistItem,
+            CompleteChecklistItemsRequest,
+            CreateChecklistItemRequest,
+            MoveChecklistItemRequest,
+            QueryChecklistItemRequest,
+            QueryChecklistRequest,
+        },
+        view::{CreateViewRequest, ViewType},
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::{
+        CompleteChecklistItems,
+        CreateChecklistItem,
+        MoveChecklistItem,
+        ReadChecklist,
+        ToggleChecklistItem,
+    },
+};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+
+async fn create_checklist(test: &ViewTest) -> String {
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "Tasks".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: Some(ViewType::Checklist),
+        view_id: None,
+    };
+    create_view_with_request(&test.sdk, request).await.id
+}
+
+async fn create_item(test: &ViewTest, checklist_id: &str, title: &str) -> ChecklistItem {
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateChecklistItem)
+        .request(CreateChecklistItemRequest {
+            checklist_id: checklist_id.to_owned(),
+            title: title.to_owned(),
+            due_date: 0,
+        })
+        .async_send()
+        .await
+        .parse::<ChecklistItem>()
+}
+
+async fn read_checklist(test: &ViewTest, checklist_id: &str) -> Checklist {
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadChecklist)
+        .request(QueryChecklistRequest {
+            checklist_id: checklist_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<Checklist>()
+}
+
+#[tokio::test]
+async fn checklist_toggle_reorder_and_complete() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let checklist_id = create_checklist(&test).await;
+    let first = create_item(&test, &checklist_id, "Write the spec").await;
+    let second = create_item(&test, &checklist_id, "Review the spec").await;
+    let third = create_item(&test, &checklist_id, "Ship it").await;
+
+    let item = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ToggleChecklistItem)
+        .request(QueryChecklistItemRequest {
+            checklist_id: checklist_id.clone(),
+            item_id: first.id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<ChecklistItem>();
+    assert!(item.done);
+
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(MoveChecklistItem)
+        .request(MoveChecklistItemRequest {
+            checklist_id: checklist_id.clone(),
+            item_id: third.id.clone(),
+            to_index: 0,
+        })
+        .async_send()
+        .await
+        .assert_success();
+
+    let checklist = read_checklist(&test, &checklist_id).await;
+    let item_ids = checklist
+        .items
+        .iter()
+        .map(|item| item.id.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(item_ids, vec![third.id.clone(), first.id.clone(), second.id.clone()]);
+
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CompleteChecklistItems)
+        .request(CompleteChecklistItemsRequest {
+            checklist_id: checklist_id.clone(),
+            item_ids: vec![],
+        })
+        .async_send()
+        .await
+        .assert_success();
+    let checklist = read_checklist(&test, &checklist_id).await;
+    assert!(checklist.items.iter().all(|item| item.done));
+}
+
+#[tokio::test]
+async fn checklist_create_item_with_empty_title() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let checklist_id = create_checklist(&test).await;
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateChecklistItem)
+        .request(CreateChecklistItemRequest {
+            checklist_id,
+            title: " ".to_owned(),
+            due_date: 0,
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::ChecklistItemTitleInvalid.value());
+}
@@ -0,0 +1,77 @@
+use flowy_core::{
+    entities::{
+        app::{App, QueryAppRequest},
+        view::QueryViewRequest,
+    },
+    event::WorkspaceEvent::DuplicateApp,
+};
+use flowy_test::{prelude::*, FlowySDKTest};
+use futures::FutureExt;
+use lib_infra::clock::Clock;
+use std::{sync::Arc, time::Duration};
+
+#[tokio::test]
+async fn clock_and_ids_are_injected() {
+    let clock = Arc::new(FakeClock::default());
+    let sdk = FlowySDKTest::with_clock_and_ids(clock.clone(), Arc::new(SequentialIdGenerator::default()));
+    let _ = sdk.init_user().await;
+    let workspace = create_workspace(&sdk, "Workspace", "").await;
+    open_workspace(&sdk, &workspace.id).await;
+    let app = create_app(&sdk, "App", "", &workspace.id).await;
+
+    clock.advance(Duration::from_secs(3600));
+    let view = create_view(&sdk, &app.id).await;
+    assert_eq!(view.create_time, clock.timestamp());
+
+    let duplicated = CoreModuleEventBuilder::new(sdk.clone())
+        .event(DuplicateApp)
+        .request(QueryAppRequest {
+            app_ids: vec![app.id.clone()],
+        })
+        .async_send()
+        .await
+        .parse::<App>();
+    assert_eq!(duplicated.id, "00000000-0000-4000-8000-000000000001");
+}
+
+// The idle editors are closed in the background, the test waits for it.
+#[tokio::test]
+async fn clock_idle_document_is_closed_and_opened_again() {
+    let clock = Arc::new(FakeClock::default());
+    let sdk = FlowySDKTest::with_clock_and_ids(clock.clone(), Arc::new(SequentialIdGenerator::default()));
+    let _ = sdk.init_user().await;
+    let workspace = create_workspace(&sdk, "Workspace", "").await;
+    open_workspace(&sdk, &workspace.id).await;
+    let app = create_app(&sdk, "App", "", &workspace.id).await;
+    let view = create_view(&sdk, &app.id).await;
+    let request = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    let doc = open_view(&sdk, request.clone()).await;
+    let controller = sdk.document_ctx.controller.clone();
+    assert!(controller.is_opened(&view.id));
+
+    clock.advance(Duration::from_secs(11 * 60));
+    for _ in 0..50 {
+        if !controller.is_opened(&view.id) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(!controller.is_opened(&view.id));
+
+    let reopened = open_view(&sdk, request).await;
+    assert_eq!(reopened.text, doc.text);
+    assert!(controller.is_opened(&view.id));
+}
+
+#[test]
+fn clock_sleep_waits_for_advance() {
+    let clock = FakeClock::default();
+    let mut sleep = clock.sleep(Duration::from_secs(1));
+    clock.advance(Duration::from_millis(500));
+    assert!((&mut sleep).now_or_never().is_none());
+
+    clock.advance(Duration::from_millis(500));
+    assert!(sleep.now_or_never().is_some());
+}
@@ -0,0 +1,99 @@
+use flowy_core::{
+    entities::{
+        diagnostics::{ExportDiagnosticsRequest, ExportDiagnosticsResult, HealthReport, HealthStatus, Metrics},
+        workspace::QueryWorkspaceRequest,
+    },
+    event::WorkspaceEvent::{CheckHealth, ExportDiagnostics, ReadMetrics},
+};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+
+#[tokio::test]
+async fn metrics_record_dispatch_latency() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let _ = ViewTest::new(&test).await;
+
+    let metrics = CoreModuleEventBuilder::new(test.clone())
+        .event(ReadMetrics)
+        .async_send()
+        .await
+        .parse::<Metrics>();
+    let json: serde_json::Value = serde_json::from_str(&metrics.json).unwrap();
+    assert!(json["latencies"]["dispatch.CreateView"]["count"].as_u64().unwrap() >= 1);
+    assert!(json["gauges"]["database.size_bytes"].as_i64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn health_check_after_sign_in() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let report = CoreModuleEventBuilder::new(test.clone())
+        .event(CheckHealth)
+        .async_send()
+        .await
+        .parse::<HealthReport>();
+    let database = report.checks.iter().find(|check| check.name == "database").unwrap();
+    assert_eq!(database.status, HealthStatus::Healthy);
+    assert_ne!(report.status, HealthStatus::Unhealthy);
+}
+
+#[tokio::test]
+async fn workspace_initialized_again_after_reset() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let workspace_status = || async {
+        let report = CoreModuleEventBuilder::new(test.clone())
+            .event(CheckHealth)
+            .async_send()
+            .await
+            .parse::<HealthReport>();
+        report
+            .checks
+            .into_iter()
+            .find(|check| check.name == "workspace")
+            .unwrap()
+            .status
+    };
+    let _ = read_workspace(&test, QueryWorkspaceRequest::new(None)).await;
+    assert_ne!(workspace_status().await, HealthStatus::Unhealthy);
+
+    let user_id = test.user_session.user_id().unwrap();
+    test.core.reset(&user_id).await;
+    assert_eq!(workspace_status().await, HealthStatus::Unhealthy);
+
+    let workspaces = read_workspace(&test, QueryWorkspaceRequest::new(None)).await;
+    assert!(!workspaces.is_empty());
+    assert_ne!(workspace_status().await, HealthStatus::Unhealthy);
+}
+
+#[tokio::test]
+async fn diagnostics_bundle_excludes_documents() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+
+    let dir = format!("{}/diagnostics", root_dir());
+    std::fs::create_dir_all(&dir).unwrap();
+    let result = CoreModuleEventBuilder::new(test.clone())
+        .event(ExportDiagnostics)
+        .request(ExportDiagnosticsRequest { dir })
+        .async_send()
+        .await
+        .parse::<ExportDiagnosticsResult>();
+
+    let file = std::fs::File::open(&result.path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let names = archive.file_names().map(|name| name.to_owned()).collect::<Vec<_>>();
+    assert!(names.contains(&"health.json".to_owned()));
+    assert!(names.contains(&"metrics.json".to_owned()));
+    assert!(names.contains(&"folder_stats.json".to_owned()));
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).unwrap();
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut entry, &mut content).unwrap();
+        assert!(!content.contains(&view_test.view.id));
+        assert!(!content.contains(&view_test.view.name));
+    }
+    let _ = std::fs::remove_file(&result.path);
+}
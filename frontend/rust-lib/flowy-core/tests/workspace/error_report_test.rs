@@ -0,0 +1,43 @@
+use flowy_core::{
+    entities::view::QueryViewRequest,
+    errors::{ErrorCode, FlowyError},
+    event::WorkspaceEvent::ReadView,
+};
+use flowy_test::{prelude::*, FlowySDKTest};
+use lib_infra::uuid_string;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+#[derive(Default)]
+struct MockReporter {
+    reports: Mutex<Vec<ErrorReport>>,
+}
+
+impl ErrorReporter for MockReporter {
+    fn report(&self, report: ErrorReport) { self.reports.lock().push(report); }
+}
+
+#[tokio::test]
+async fn error_report_skips_expected_errors() {
+    let reporter = Arc::new(MockReporter::default());
+    let test = FlowySDKTest::with_error_reporter(reporter.clone());
+    let _ = test.init_user().await;
+
+    let error = CoreModuleEventBuilder::new(test.clone())
+        .event(ReadView)
+        .request(QueryViewRequest {
+            view_ids: vec![uuid_string()],
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+    assert!(reporter.reports.lock().is_empty());
+}
+
+#[test]
+fn error_report_internal_errors_are_unexpected() {
+    assert!(is_unexpected(&FlowyError::internal()));
+    assert!(is_unexpected(&FlowyError::delta_invalid()));
+    assert!(!is_unexpected(&FlowyError::view_name()));
+}
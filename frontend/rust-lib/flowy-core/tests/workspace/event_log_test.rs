@@ -0,0 +1,72 @@
+use flowy_core::{
+    entities::event_log::{EventLogPage, ReadEventLogRequest},
+    errors::ErrorCode,
+    event::WorkspaceEvent::ReadEventLog,
+};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+
+async fn read_event_log(sdk: &FlowySDKTest, cursor: i64, limit: i64) -> EventLogPage {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadEventLog)
+        .request(ReadEventLogRequest { cursor, limit })
+        .async_send()
+        .await
+        .parse::<EventLogPage>()
+}
+
+#[tokio::test]
+async fn event_log_record_view_created() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let view_test = ViewTest::new(&test).await;
+    let page = read_event_log(&test, 0, 1000).await;
+    assert!(!page.has_more);
+    assert_eq!(page.next_cursor, page.items.last().unwrap().seq);
+    assert!(page
+        .items
+        .iter()
+        .any(|entry| entry.ty == "view.created" && entry.object_id == view_test.view.id));
+    assert!(page.items.iter().any(|entry| entry.ty == "app.created"));
+}
+
+#[tokio::test]
+async fn event_log_read_with_cursor() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let _ = ViewTest::new(&test).await;
+    let all = read_event_log(&test, 0, 1000).await;
+    assert!(all.items.len() > 1);
+
+    let mut cursor = 0;
+    let mut items = vec![];
+    loop {
+        let page = read_event_log(&test, cursor, 1).await;
+        items.extend(page.items);
+        cursor = page.next_cursor;
+        if !page.has_more {
+            break;
+        }
+    }
+    assert_eq!(items, all.items);
+
+    let page = read_event_log(&test, cursor, 10).await;
+    assert!(page.items.is_empty());
+    assert_eq!(page.next_cursor, cursor);
+}
+
+#[tokio::test]
+async fn event_log_read_with_invalid_limit() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let code = CoreModuleEventBuilder::new(test.clone())
+        .event(ReadEventLog)
+        .request(ReadEventLogRequest { cursor: 0, limit: 0 })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::EventLogLimitInvalid.value());
+}
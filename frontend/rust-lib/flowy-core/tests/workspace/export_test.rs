@@ -0,0 +1,259 @@
+use flowy_core::{
+    entities::{
+        grid::{
+            Grid,
+            GridSetting,
+            MoveCalendarEventRequest,
+            QueryGridRequest,
+            Sort,
+            UpdateCellRequest,
+            UpdateGridSettingRequest,
+        },
+        share::{
+            CsvImportOptions,
+            ExportCalendarRequest,
+            ExportCalendarResult,
+            ExportGridRequest,
+            ExportGridResult,
+            ExportSiteRequest,
+            ExportSiteResult,
+            ExportUserDataRequest,
+            ExportUserDataResult,
+            ImportCsvRequest,
+        },
+        view::{CreateViewRequest, View, ViewType},
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::{
+        ExportCalendar,
+        ExportGrid,
+        ExportSite,
+        ExportUserData,
+        ImportCsv,
+        MoveCalendarEvent,
+        ReadGrid,
+        UpdateCell,
+        UpdateGridSetting,
+    },
+};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+
+#[tokio::test]
+async fn export_user_data_archive() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let dir = format!("{}/export", root_dir());
+    std::fs::create_dir_all(&dir).unwrap();
+    let result = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ExportUserData)
+        .request(ExportUserDataRequest { dir })
+        .async_send()
+        .await
+        .parse::<ExportUserDataResult>();
+
+    assert!(std::path::Path::new(&result.path).exists());
+    assert!(result.document_count > 0);
+    let _ = std::fs::remove_file(&result.path);
+}
+
+#[tokio::test]
+async fn export_user_data_to_invalid_dir() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let code = CoreModuleEventBuilder::new(test.clone())
+        .event(ExportUserData)
+        .request(ExportUserDataRequest {
+            dir: format!("{}/not_exist", root_dir()),
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::ExportPathInvalid.value());
+}
+
+#[tokio::test]
+async fn export_grid_as_tsv_in_sorted_order() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let path = format!("{}/estimates.csv", root_dir());
+    std::fs::write(
+        &path,
+        "Name,Estimate,Due\nWrite,3,2021-12-01\nShip,5,2021-12-24 18:30\n",
+    )
+    .unwrap();
+    let view = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ImportCsv)
+        .request(ImportCsvRequest {
+            app_id: test.app.id.clone(),
+            path: path.clone(),
+            options: CsvImportOptions {
+                delimiter: ",".to_owned(),
+                has_header: true,
+            },
+        })
+        .async_send()
+        .await
+        .parse::<View>();
+    let _ = std::fs::remove_file(&path);
+    let grid = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadGrid)
+        .request(QueryGridRequest {
+            grid_id: view.id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<Grid>();
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateGridSetting)
+        .request(UpdateGridSettingRequest {
+            grid_id: grid.id.clone(),
+            setting: GridSetting {
+                sorts: vec![Sort {
+                    field_id: grid.fields[1].id.clone(),
+                    descending: true,
+                }],
+                ..Default::default()
+            },
+        })
+        .async_send()
+        .await;
+
+    let result = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ExportGrid)
+        .request(ExportGridRequest {
+            grid_id: grid.id.clone(),
+            delimiter: "\t".to_owned(),
+            field_ids: vec![grid.fields[0].id.clone(), grid.fields[2].id.clone()],
+            path: None,
+        })
+        .async_send()
+        .await
+        .parse::<ExportGridResult>();
+    assert_eq!(result.row_count, 2);
+    assert_eq!(
+        String::from_utf8(result.data).unwrap(),
+        "Name\tDue\nShip\t2021-12-24 18:30:00\nWrite\t2021-12-01\n"
+    );
+}
+
+#[tokio::test]
+async fn export_calendar_subscription_follows_edits() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "Release".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: Some(ViewType::Calendar),
+        view_id: None,
+    };
+    let view = create_view_with_request(&test.sdk, request).await;
+    let grid = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadGrid)
+        .request(QueryGridRequest {
+            grid_id: view.id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<Grid>();
+    let row_id = grid.rows[0].id.clone();
+    // The date is 2021-12-24, an all-day event.
+    for (field_id, data) in vec![
+        (&grid.fields[0].id, "Ship, then rest"),
+        (&grid.fields[1].id, "1640304000"),
+    ] {
+        let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+            .event(UpdateCell)
+            .request(UpdateCellRequest {
+                grid_id: grid.id.clone(),
+                row_id: row_id.clone(),
+                field_id: field_id.clone(),
+                data: data.to_owned(),
+            })
+            .async_send()
+            .await
+            .assert_success();
+    }
+
+    let path = format!("{}/release.ics", root_dir());
+    let result = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ExportCalendar)
+        .request(ExportCalendarRequest {
+            grid_id: grid.id.clone(),
+            path: Some(path.clone()),
+            subscribe: true,
+        })
+        .async_send()
+        .await
+        .parse::<ExportCalendarResult>();
+    assert_eq!(result.event_count, 1);
+    let ics = std::fs::read_to_string(&path).unwrap();
+    assert!(ics.contains("X-WR-CALNAME:Release\r\n"));
+    assert!(ics.contains(&format!("UID:{}@appflowy\r\n", row_id)));
+    assert!(ics.contains("DTSTART;VALUE=DATE:20211224\r\n"));
+    assert!(ics.contains("SUMMARY:Ship\\, then rest\r\n"));
+
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(MoveCalendarEvent)
+        .request(MoveCalendarEventRequest {
+            grid_id: grid.id.clone(),
+            row_id,
+            // 2021-12-01 09:00 UTC
+            timestamp: 1638349200,
+        })
+        .async_send()
+        .await
+        .assert_success();
+    let ics = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(ics.contains("DTSTART:20211201T090000Z\r\n"));
+}
+
+#[tokio::test]
+async fn export_app_as_static_site() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "Roadmap <2022>".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: Some(ViewType::Grid),
+        view_id: None,
+    };
+    let grid_view = create_view_with_request(&test.sdk, request).await;
+
+    let dir = format!("{}/site_export", root_dir());
+    std::fs::create_dir_all(&dir).unwrap();
+    let result = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ExportSite)
+        .request(ExportSiteRequest {
+            app_id: test.app.id.clone(),
+            dir: dir.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<ExportSiteResult>();
+    let read = |name: &str| std::fs::read_to_string(std::path::Path::new(&result.path).join(name)).unwrap();
+    let index = read("index.html");
+    let grid_page = read(&format!("{}.html", grid_view.id));
+    let document_page = read(&format!("{}.html", test.view.id));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert!(result.path.ends_with("App"));
+    assert!(result.page_count >= 2);
+    assert!(index.contains(&format!("<a href=\"{}.html\">Roadmap &lt;2022&gt;</a>", grid_view.id)));
+    assert!(grid_page.contains("<table>\n<tr><th>Name</th></tr>"));
+    assert!(document_page.contains(&format!("<h1>{}</h1>", test.view.name)));
+}
@@ -0,0 +1,520 @@
+use flowy_core::{
+    entities::{
+        grid::{
+            Board,
+            CardMoved,
+            CreateFieldRequest,
+            Field,
+            FieldType,
+            Filter,
+            FilterCondition,
+            FilterGroup,
+            Grid,
+            GridSetting,
+            MoveCalendarEventRequest,
+            MoveCardRequest,
+            QueryCalendarEventsRequest,
+            QueryGridRequest,
+            QueryRowRequest,
+            RepeatedCalendarEvent,
+            RepeatedRow,
+            RollupFunction,
+            RollupSetting,
+            Sort,
+            UpdateCellRequest,
+            UpdateFieldRequest,
+            UpdateGridSettingRequest,
+        },
+        view::{CreateViewRequest, ViewType},
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::{
+        CreateField,
+        DeleteRow,
+        MoveCalendarEvent,
+        MoveCard,
+        ReadBoard,
+        ReadCalendarEvents,
+        ReadGrid,
+        ReadGridRows,
+        UpdateCell,
+        UpdateField,
+        UpdateGridSetting,
+    },
+};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+
+async fn create_grid(test: &ViewTest) -> Grid { create_grid_with_type(test, ViewType::Grid).await }
+
+async fn create_grid_with_type(test: &ViewTest, view_type: ViewType) -> Grid {
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "Grid".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: Some(view_type),
+        view_id: None,
+    };
+    let view = create_view_with_request(&test.sdk, request).await;
+    read_grid(test, &view.id).await
+}
+
+async fn read_grid(test: &ViewTest, grid_id: &str) -> Grid {
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadGrid)
+        .request(QueryGridRequest {
+            grid_id: grid_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<Grid>()
+}
+
+async fn create_field(test: &ViewTest, grid_id: &str, field_type: FieldType) -> Field {
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateField)
+        .request(CreateFieldRequest {
+            grid_id: grid_id.to_owned(),
+            name: "Amount".to_owned(),
+            field_type,
+            ..Default::default()
+        })
+        .async_send()
+        .await
+        .parse::<Field>()
+}
+
+#[tokio::test]
+async fn grid_create_field_and_update_cell() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let grid = create_grid(&test).await;
+    assert_eq!(grid.fields.len(), 1);
+    assert_eq!(grid.rows.len(), 3);
+
+    let field = create_field(&test, &grid.id, FieldType::Number).await;
+    let row_id = grid.rows[0].id.clone();
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateCell)
+        .request(UpdateCellRequest {
+            grid_id: grid.id.clone(),
+            row_id: row_id.clone(),
+            field_id: field.id.clone(),
+            data: "12.5".to_owned(),
+        })
+        .async_send()
+        .await
+        .assert_success();
+
+    let grid = read_grid(&test, &grid.id).await;
+    assert_eq!(grid.fields.len(), 2);
+    assert_eq!(grid.rows[0].cell(&field.id).unwrap().data, "12.5");
+
+    // The number doesn't fit a single select field without options.
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateField)
+        .request(UpdateFieldRequest {
+            grid_id: grid.id.clone(),
+            field_id: field.id.clone(),
+            field_type: Some(FieldType::SingleSelect),
+            ..Default::default()
+        })
+        .async_send()
+        .await
+        .assert_success();
+    let grid = read_grid(&test, &grid.id).await;
+    assert!(grid.rows[0].cell(&field.id).is_none());
+}
+
+#[tokio::test]
+async fn grid_update_cell_with_invalid_data() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let grid = create_grid(&test).await;
+    let field = create_field(&test, &grid.id, FieldType::Number).await;
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateCell)
+        .request(UpdateCellRequest {
+            grid_id: grid.id.clone(),
+            row_id: grid.rows[0].id.clone(),
+            field_id: field.id,
+            data: "twelve".to_owned(),
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::CellDataInvalid.value());
+}
+
+#[tokio::test]
+async fn grid_filter_and_sort_rows() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let grid = create_grid(&test).await;
+    let field = create_field(&test, &grid.id, FieldType::Number).await;
+    for (row, data) in grid.rows.iter().zip(["5", "20", "12"].iter()) {
+        let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+            .event(UpdateCell)
+            .request(UpdateCellRequest {
+                grid_id: grid.id.clone(),
+                row_id: row.id.clone(),
+                field_id: field.id.clone(),
+                data: data.to_string(),
+            })
+            .async_send()
+            .await
+            .assert_success();
+    }
+
+    let setting = GridSetting {
+        filter: FilterGroup {
+            filters: vec![Filter {
+                field_id: field.id.clone(),
+                condition: FilterCondition::GreaterThan,
+                value: "10".to_owned(),
+            }],
+            ..Default::default()
+        },
+        sorts: vec![Sort {
+            field_id: field.id.clone(),
+            descending: true,
+        }],
+    };
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateGridSetting)
+        .request(UpdateGridSettingRequest {
+            grid_id: grid.id.clone(),
+            setting: setting.clone(),
+        })
+        .async_send()
+        .await
+        .assert_success();
+
+    let rows = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadGridRows)
+        .request(QueryGridRequest {
+            grid_id: grid.id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedRow>()
+        .items;
+    let row_ids = rows.iter().map(|row| row.id.clone()).collect::<Vec<String>>();
+    assert_eq!(row_ids, vec![grid.rows[1].id.clone(), grid.rows[2].id.clone()]);
+
+    // The setting is saved with the grid, the rows themselves are untouched.
+    let grid = read_grid(&test, &grid.id).await;
+    assert_eq!(grid.setting, setting);
+    assert_eq!(grid.rows.len(), 3);
+}
+
+#[tokio::test]
+async fn grid_formula_field_follows_referenced_cells() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let grid = create_grid(&test).await;
+    let amount_field = create_field(&test, &grid.id, FieldType::Number).await;
+    let total_field = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateField)
+        .request(CreateFieldRequest {
+            grid_id: grid.id.clone(),
+            name: "Total".to_owned(),
+            field_type: FieldType::Formula,
+            formula: "ROUND({Amount} * 1.2, 1) & \" EUR\"".to_owned(),
+            ..Default::default()
+        })
+        .async_send()
+        .await
+        .parse::<Field>();
+
+    let row_id = grid.rows[0].id.clone();
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateCell)
+        .request(UpdateCellRequest {
+            grid_id: grid.id.clone(),
+            row_id: row_id.clone(),
+            field_id: amount_field.id.clone(),
+            data: "12.5".to_owned(),
+        })
+        .async_send()
+        .await
+        .assert_success();
+    let grid = read_grid(&test, &grid.id).await;
+    assert_eq!(grid.rows[0].cell(&total_field.id).unwrap().data, "15 EUR");
+    assert!(grid.rows[1].cell(&total_field.id).is_some());
+
+    // The formula keeps working after the referenced field is renamed.
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateField)
+        .request(UpdateFieldRequest {
+            grid_id: grid.id.clone(),
+            field_id: amount_field.id.clone(),
+            name: Some("Price".to_owned()),
+            ..Default::default()
+        })
+        .async_send()
+        .await
+        .assert_success();
+    let grid = read_grid(&test, &grid.id).await;
+    let total_field = grid.fields.iter().find(|field| field.id == total_field.id).unwrap();
+    assert_eq!(total_field.formula, "ROUND({Price} * 1.2, 1) & \" EUR\"");
+
+    // A formula can't depend on itself, even through another formula.
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateField)
+        .request(UpdateFieldRequest {
+            grid_id: grid.id.clone(),
+            field_id: amount_field.id.clone(),
+            field_type: Some(FieldType::Formula),
+            formula: Some("{Total} + 1".to_owned()),
+            ..Default::default()
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::FormulaInvalid.value());
+}
+
+async fn update_cell(test: &ViewTest, grid_id: &str, row_id: &str, field_id: &str, data: &str) {
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateCell)
+        .request(UpdateCellRequest {
+            grid_id: grid_id.to_owned(),
+            row_id: row_id.to_owned(),
+            field_id: field_id.to_owned(),
+            data: data.to_owned(),
+        })
+        .async_send()
+        .await
+        .assert_success();
+}
+
+#[tokio::test]
+async fn grid_rollup_follows_related_rows() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let orders = create_grid(&test).await;
+    let amount_field = create_field(&test, &orders.id, FieldType::Number).await;
+    let customers = create_grid(&test).await;
+    let create_field_request = |name: &str, field_type: FieldType| CreateFieldRequest {
+        grid_id: customers.id.clone(),
+        name: name.to_owned(),
+        field_type,
+        ..Default::default()
+    };
+    let orders_field = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateField)
+        .request(CreateFieldRequest {
+            related_grid_id: orders.id.clone(),
+            ..create_field_request("Orders", FieldType::Relation)
+        })
+        .async_send()
+        .await
+        .parse::<Field>();
+    let total_field = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateField)
+        .request(CreateFieldRequest {
+            rollup: RollupSetting {
+                relation_field_id: orders_field.id.clone(),
+                target_field_id: amount_field.id.clone(),
+                function: RollupFunction::Sum,
+            },
+            ..create_field_request("Total", FieldType::Rollup)
+        })
+        .async_send()
+        .await
+        .parse::<Field>();
+
+    let customer_id = customers.rows[0].id.clone();
+    let related_row_ids = format!("{},{}", orders.rows[0].id, orders.rows[1].id);
+    update_cell(&test, &customers.id, &customer_id, &orders_field.id, &related_row_ids).await;
+    update_cell(&test, &orders.id, &orders.rows[0].id, &amount_field.id, "5").await;
+    update_cell(&test, &orders.id, &orders.rows[1].id, &amount_field.id, "7").await;
+    let customers = read_grid(&test, &customers.id).await;
+    assert_eq!(customers.rows[0].cell(&total_field.id).unwrap().data, "12");
+
+    // The deleted row is removed from the relation and from the rollup.
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(DeleteRow)
+        .request(QueryRowRequest {
+            grid_id: orders.id.clone(),
+            row_id: orders.rows[1].id.clone(),
+        })
+        .async_send()
+        .await
+        .assert_success();
+    let customers = read_grid(&test, &customers.id).await;
+    assert_eq!(
+        customers.rows[0].cell(&orders_field.id).unwrap().data,
+        orders.rows[0].id
+    );
+    assert_eq!(customers.rows[0].cell(&total_field.id).unwrap().data, "5");
+}
+
+#[tokio::test]
+async fn grid_read_document_view() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadGrid)
+        .request(QueryGridRequest {
+            grid_id: test.view.id.clone(),
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::ViewTypeMismatch.value());
+}
+
+async fn read_board(test: &ViewTest, grid_id: &str) -> Board {
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadBoard)
+        .request(QueryGridRequest {
+            grid_id: grid_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<Board>()
+}
+
+async fn move_card(test: &ViewTest, request: MoveCardRequest) -> CardMoved {
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(MoveCard)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<CardMoved>()
+}
+
+#[tokio::test]
+async fn board_move_cards_between_groups() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let grid = create_grid_with_type(&test, ViewType::Board).await;
+    let board = read_board(&test, &grid.id).await;
+    // The rows without a status and the three status options.
+    assert_eq!(board.groups.len(), 4);
+    assert_eq!(board.groups[0].rows.len(), 3);
+
+    let to_do = board.groups[1].id.clone();
+    let (first, second) = (grid.rows[0].id.clone(), grid.rows[1].id.clone());
+    for row_id in vec![first.clone(), second.clone()] {
+        let _ = move_card(
+            &test,
+            MoveCardRequest {
+                grid_id: grid.id.clone(),
+                row_id,
+                to_group_id: to_do.clone(),
+                before_row_id: None,
+            },
+        )
+        .await;
+    }
+
+    // Puts the second card in front of the first one.
+    let card_moved = move_card(
+        &test,
+        MoveCardRequest {
+            grid_id: grid.id.clone(),
+            row_id: second.clone(),
+            to_group_id: to_do.clone(),
+            before_row_id: Some(first.clone()),
+        },
+    )
+    .await;
+    assert_eq!(card_moved.from_group_id, to_do);
+    assert_eq!(card_moved.to_index, 0);
+
+    let board = read_board(&test, &grid.id).await;
+    let row_ids = board.groups[1]
+        .rows
+        .iter()
+        .map(|row| row.id.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(row_ids, vec![second, first]);
+    assert_eq!(board.groups[0].rows.len(), 1);
+}
+
+async fn read_calendar_events(test: &ViewTest, grid_id: &str, start: i64, end: i64) -> RepeatedCalendarEvent {
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadCalendarEvents)
+        .request(QueryCalendarEventsRequest {
+            grid_id: grid_id.to_owned(),
+            start,
+            end,
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedCalendarEvent>()
+}
+
+#[tokio::test]
+async fn calendar_move_event_to_another_day() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let grid = create_grid_with_type(&test, ViewType::Calendar).await;
+    let day = 24 * 60 * 60;
+    // 2021-12-01 09:00 UTC
+    let timestamp = 1638349200;
+    let row_id = grid.rows[0].id.clone();
+    for timestamp in vec![timestamp, timestamp + day] {
+        let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+            .event(MoveCalendarEvent)
+            .request(MoveCalendarEventRequest {
+                grid_id: grid.id.clone(),
+                row_id: row_id.clone(),
+                timestamp,
+            })
+            .async_send()
+            .await
+            .assert_success();
+    }
+
+    let events = read_calendar_events(&test, &grid.id, timestamp, timestamp + day).await;
+    assert!(events.items.is_empty());
+
+    let events = read_calendar_events(&test, &grid.id, timestamp + day, timestamp + 2 * day).await;
+    assert_eq!(events.items.len(), 1);
+    assert_eq!(events.items[0].row_id, row_id);
+    assert_eq!(events.items[0].timestamp, timestamp + day);
+}
+
+#[tokio::test]
+async fn calendar_read_events_with_invalid_range() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let test = ViewTest::new(&sdk).await;
+
+    let grid = create_grid_with_type(&test, ViewType::Calendar).await;
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadCalendarEvents)
+        .request(QueryCalendarEventsRequest {
+            grid_id: grid.id.clone(),
+            start: 100,
+            end: 0,
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::DateRangeInvalid.value());
+}
@@ -0,0 +1,425 @@
+use flowy_core::{
+    entities::{
+        app::{QueryAppRequest, RepeatedApp},
+        grid::{FieldType, Grid, QueryGridRequest},
+        share::{
+            CsvImportOptions,
+            ExportOpmlRequest,
+            ExportOpmlResult,
+            ImportCsvRequest,
+            ImportEnexRequest,
+            ImportNotionRequest,
+            ImportNotionResult,
+            ImportOpmlRequest,
+            ImportVaultRequest,
+        },
+        view::{Attachment, QueryAttachmentRequest, QueryViewRequest, RepeatedView, View, ViewTags, ViewType},
+        workspace::QueryWorkspaceRequest,
+    },
+    event::WorkspaceEvent::{
+        ExportOpml,
+        ImportCsv,
+        ImportEnex,
+        ImportNotion,
+        ImportOpml,
+        ImportVault,
+        ReadAttachment,
+        ReadGrid,
+        ReadViewTags,
+    },
+    name_rules::NameRules,
+};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+use std::io::Write;
+use zip::{write::FileOptions, ZipWriter};
+
+#[tokio::test]
+async fn import_csv_as_grid() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let path = format!("{}/tasks.csv", root_dir());
+    let csv = "Name,Estimate,Due,Note\n\
+               Write,3,2021-12-01,\"Draft, then \"\"review\"\"\nwith the team\"\n\
+               \n\
+               Ship,1.5,2021-12-24,2\n";
+    std::fs::write(&path, csv).unwrap();
+    let view = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ImportCsv)
+        .request(ImportCsvRequest {
+            app_id: test.app.id.clone(),
+            path: path.clone(),
+            options: CsvImportOptions {
+                delimiter: "".to_owned(),
+                has_header: true,
+            },
+        })
+        .async_send()
+        .await
+        .parse::<View>();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(view.name, "tasks");
+    assert_eq!(view.view_type, ViewType::Grid);
+
+    let grid = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadGrid)
+        .request(QueryGridRequest { grid_id: view.id })
+        .async_send()
+        .await
+        .parse::<Grid>();
+    let field_types = grid
+        .fields
+        .iter()
+        .map(|field| (field.name.as_str(), field.field_type.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        field_types,
+        vec![
+            ("Name", FieldType::RichText),
+            ("Estimate", FieldType::Number),
+            ("Due", FieldType::DateTime),
+            ("Note", FieldType::RichText),
+        ]
+    );
+
+    assert_eq!(grid.rows.len(), 2);
+    let cell_data = |row: usize, field: usize| {
+        grid.rows[row]
+            .cell(&grid.fields[field].id)
+            .map(|cell| cell.data.clone())
+            .unwrap_or_default()
+    };
+    assert_eq!(cell_data(0, 2), "1638316800");
+    assert_eq!(cell_data(0, 3), "Draft, then \"review\"\nwith the team");
+    assert_eq!(cell_data(1, 1), "1.5");
+}
+
+#[tokio::test]
+async fn import_notion_export() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let path = format!("{}/notion.zip", root_dir());
+    let mut writer = ZipWriter::new(std::fs::File::create(&path).unwrap());
+    let entries = [
+        (
+            "Projects 1c2f3d4e5f6a7b8c9d0e1f2a3b4c5d6e.md",
+            "# Projects\n\nSee the [tasks](Projects%201c2f3d4e5f6a7b8c9d0e1f2a3b4c5d6e/Tasks%209ab0c1d2e3f4a5b6c7d8e9f0a1b2c3d4.csv)\n",
+        ),
+        (
+            "Projects 1c2f3d4e5f6a7b8c9d0e1f2a3b4c5d6e/Tasks 9ab0c1d2e3f4a5b6c7d8e9f0a1b2c3d4.csv",
+            "Name,Estimate\nWrite,3\n",
+        ),
+        (
+            "Projects 1c2f3d4e5f6a7b8c9d0e1f2a3b4c5d6e/Tasks 9ab0c1d2e3f4a5b6c7d8e9f0a1b2c3d4_all.csv",
+            "Name,Estimate\nWrite,3\nShip,1\n",
+        ),
+    ];
+    for (name, content) in entries.iter() {
+        writer.start_file(*name, FileOptions::default()).unwrap();
+        writer.write_all(content.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap();
+
+    let result = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ImportNotion)
+        .request(ImportNotionRequest {
+            workspace_id: test.workspace.id.clone(),
+            path: path.clone(),
+            import_id: None,
+        })
+        .async_send()
+        .await
+        .parse::<ImportNotionResult>();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(result.page_count, 2);
+
+    let workspace = read_workspace(
+        &test.sdk,
+        QueryWorkspaceRequest {
+            workspace_id: Some(test.workspace.id.clone()),
+        },
+    )
+    .await
+    .pop()
+    .unwrap();
+    let app = workspace.apps.items.iter().find(|app| app.name == "Projects").unwrap();
+    let app = read_app(
+        &test.sdk,
+        QueryAppRequest {
+            app_ids: vec![app.id.clone()],
+        },
+    )
+    .await;
+    let page = &app.belongings.items[0];
+    assert_eq!(page.view_type, ViewType::Doc);
+
+    let page = read_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![page.id.clone()],
+        },
+    )
+    .await;
+    let database = &page.belongings.items[0];
+    assert_eq!(database.name, "Tasks");
+    assert_eq!(database.view_type, ViewType::Grid);
+
+    let grid = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadGrid)
+        .request(QueryGridRequest {
+            grid_id: database.id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<Grid>();
+    assert_eq!(grid.rows.len(), 2);
+
+    let document = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![page.id.clone()],
+        },
+    )
+    .await;
+    assert!(document.text.contains(&format!("appflowy://view/{}", database.id)));
+}
+
+#[tokio::test]
+async fn import_enex_notes() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let path = format!("{}/notes.enex", root_dir());
+    let enex = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE en-export SYSTEM "http://xml.evernote.com/pub/evernote-export3.dtd">
+<en-export export-date="20220105T101010Z" application="Evernote" version="10.25.6">
+  <note>
+    <title>Groceries &amp; more</title>
+    <created>20211224T093000Z</created>
+    <tag>home</tag>
+    <tag>todo</tag>
+    <content>
+      <![CDATA[<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<!DOCTYPE en-note SYSTEM "http://xml.evernote.com/pub/enml2.dtd">
+<en-note><div><en-todo checked="true"/>Milk</div><div><en-media hash="5d41402abc4b2a76b9719d911017c592" type="image/png"/></div></en-note>]]>
+    </content>
+    <resource>
+      <data encoding="base64">
+aGVs
+bG8=
+      </data>
+      <mime>image/png</mime>
+      <resource-attributes><file-name>list.png</file-name></resource-attributes>
+    </resource>
+  </note>
+</en-export>
+"#;
+    std::fs::write(&path, enex).unwrap();
+    let views = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ImportEnex)
+        .request(ImportEnexRequest {
+            app_id: test.app.id.clone(),
+            path: path.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedView>();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(views.items.len(), 1);
+    let view = &views.items[0];
+    assert_eq!(view.name, "Groceries & more");
+    assert_eq!(view.create_time, 1640338200);
+
+    let view_tags = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadViewTags)
+        .request(QueryViewRequest {
+            view_ids: vec![view.id.clone()],
+        })
+        .async_send()
+        .await
+        .parse::<ViewTags>();
+    assert_eq!(view_tags.tags, vec!["home".to_owned(), "todo".to_owned()]);
+
+    let document = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view.id.clone()],
+        },
+    )
+    .await;
+    assert!(document.text.contains("Milk"));
+    let prefix = "appflowy://attachment/";
+    let start = document.text.find(prefix).unwrap() + prefix.len();
+    let attachment_id = document.text[start..].split('"').next().unwrap().to_owned();
+    let attachment = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadAttachment)
+        .request(QueryAttachmentRequest { attachment_id })
+        .async_send()
+        .await
+        .parse::<Attachment>();
+    assert_eq!(attachment.name, "list.png");
+    assert_eq!(attachment.data, b"hello".to_vec());
+}
+
+#[tokio::test]
+async fn import_obsidian_vault() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let dir = format!("{}/vault", root_dir());
+    let files = [
+        ("Inbox.md", "# Inbox\n"),
+        ("Projects/Plan.md", "See [[Tasks|the tasks]] and ![[diagram.png]]\n"),
+        ("Projects/diagram.png", "png"),
+        ("Projects/Sub/Tasks.md", "Back to [the plan](../Plan.md)\n"),
+        (".obsidian/app.json", "{}"),
+    ];
+    for (path, content) in files.iter() {
+        let path = std::path::Path::new(&dir).join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    let apps = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ImportVault)
+        .request(ImportVaultRequest {
+            workspace_id: test.workspace.id.clone(),
+            path: dir.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedApp>();
+    let _ = std::fs::remove_dir_all(&dir);
+    let names = apps.items.iter().map(|app| app.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["Projects", "vault"]);
+
+    let app = read_app(
+        &test.sdk,
+        QueryAppRequest {
+            app_ids: vec![apps.items[0].id.clone()],
+        },
+    )
+    .await;
+    let plan = app.belongings.iter().find(|view| view.name == "Plan").unwrap().clone();
+    let sub = app.belongings.iter().find(|view| view.name == "Sub").unwrap().clone();
+    let sub = read_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![sub.id.clone()],
+        },
+    )
+    .await;
+    let tasks = &sub.belongings.items[0];
+    assert_eq!(tasks.name, "Tasks");
+
+    let document = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![plan.id.clone()],
+        },
+    )
+    .await;
+    assert!(document.text.contains(&format!("appflowy://view/{}", tasks.id)));
+    let prefix = "appflowy://attachment/";
+    let start = document.text.find(prefix).unwrap() + prefix.len();
+    let attachment_id = document.text[start..].split('"').next().unwrap().to_owned();
+    let attachment = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadAttachment)
+        .request(QueryAttachmentRequest { attachment_id })
+        .async_send()
+        .await
+        .parse::<Attachment>();
+    assert_eq!(attachment.name, "diagram.png");
+    assert_eq!(attachment.mime_type, "image/png");
+
+    let document = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![tasks.id.clone()],
+        },
+    )
+    .await;
+    assert!(document.text.contains(&format!("appflowy://view/{}", plan.id)));
+}
+
+#[tokio::test]
+async fn import_exported_opml() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let result = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ExportOpml)
+        .request(ExportOpmlRequest {
+            workspace_id: test.workspace.id.clone(),
+            path: None,
+        })
+        .async_send()
+        .await
+        .parse::<ExportOpmlResult>();
+    let opml = String::from_utf8(result.data).unwrap();
+    assert!(opml.contains(r#"<outline text="App" _note="AppFlowy GitHub Project">"#));
+
+    let path = format!("{}/folders.opml", root_dir());
+    std::fs::write(&path, opml).unwrap();
+    let apps = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ImportOpml)
+        .request(ImportOpmlRequest {
+            workspace_id: test.workspace.id.clone(),
+            path: path.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedApp>();
+    let _ = std::fs::remove_file(&path);
+    let app = apps.items.iter().find(|app| app.name == "App").unwrap();
+    assert_eq!(app.desc, "AppFlowy GitHub Project");
+
+    let app = read_app(
+        &test.sdk,
+        QueryAppRequest {
+            app_ids: vec![app.id.clone()],
+        },
+    )
+    .await;
+    let view = &app.belongings.items[0];
+    assert_eq!(view.name, test.view.name);
+    assert_eq!(view.view_type, test.view.view_type);
+}
+
+#[tokio::test]
+async fn import_opml_suffixes_duplicate_names() {
+    let test = FlowySDKTest::with_name_rules(NameRules {
+        suffix_duplicates_on_import: true,
+        ..NameRules::default()
+    });
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let opml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<opml version="2.0">
+  <body>
+    <outline text="App"/>
+    <outline text="app"/>
+  </body>
+</opml>
+"#;
+    let path = format!("{}/duplicates.opml", root_dir());
+    std::fs::write(&path, opml).unwrap();
+    let apps = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ImportOpml)
+        .request(ImportOpmlRequest {
+            workspace_id: test.workspace.id.clone(),
+            path: path.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedApp>();
+    let _ = std::fs::remove_file(&path);
+    let names = apps.items.iter().map(|app| app.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["App (2)", "app (3)"]);
+}
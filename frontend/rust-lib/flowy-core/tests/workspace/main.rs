@@ -1,4 +1,19 @@
 mod app_test;
+mod attachment_storage_test;
+mod audit_log_test;
+mod backup_test;
+mod checklist_test;
+mod clock_test;
+mod diagnostics_test;
+mod error_report_test;
+mod event_log_test;
+mod export_test;
+mod grid_test;
+mod import_test;
 // mod helper;
+mod retry_test;
+mod search_test;
+mod unfurl_test;
 mod view_test;
+mod webhook_test;
 mod workspace_test;
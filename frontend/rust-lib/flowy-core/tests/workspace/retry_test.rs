@@ -0,0 +1,16 @@
+use flowy_core::retry::RetryPolicy;
+use std::time::Duration;
+
+#[test]
+fn retry_policy_delays_are_capped() {
+    let policy = RetryPolicy {
+        max_retries: 40,
+        initial_delay: Duration::from_millis(100),
+        max_delay: Duration::from_secs(1),
+    };
+    let delays = policy.delays().collect::<Vec<Duration>>();
+    assert_eq!(delays.len(), 40);
+    assert!(delays.iter().all(|delay| *delay <= Duration::from_secs(1)));
+
+    assert_eq!(RetryPolicy::never().delays().count(), 0);
+}
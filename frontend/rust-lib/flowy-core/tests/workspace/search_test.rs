@@ -0,0 +1,81 @@
+use flowy_collaboration::entities::doc::DocumentDelta;
+use flowy_core::{
+    entities::{
+        search::{RepeatedWorkspaceSearchResult, SearchRequest},
+        view::QueryViewRequest,
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::{ApplyDocDelta, Search},
+};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+
+async fn search(sdk: &FlowySDKTest, query: &str, all_workspaces: bool) -> RepeatedWorkspaceSearchResult {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(Search)
+        .request(SearchRequest {
+            query: query.to_owned(),
+            all_workspaces,
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedWorkspaceSearchResult>()
+}
+
+#[tokio::test]
+async fn search_across_workspaces() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let first = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![first.view.id.clone()],
+    };
+    let _ = open_view(&test, request).await;
+    let _ = CoreModuleEventBuilder::new(test.clone())
+        .event(ApplyDocDelta)
+        .request(DocumentDelta {
+            doc_id: first.view.id.clone(),
+            delta_json: r#"[{"insert":"The quarterly roadmap draft\n"}]"#.to_owned(),
+        })
+        .async_send()
+        .await;
+
+    // Opens the second workspace.
+    let second = ViewTest::new(&test).await;
+    assert!(search(&test, "ROADMAP", false).await.items.is_empty());
+
+    let result = search(&test, "ROADMAP", true).await;
+    assert_eq!(result.items.len(), 1);
+    assert_eq!(result.items[0].workspace_id, first.workspace.id);
+    let item = &result.items[0].items[0];
+    assert_eq!(item.view_id, first.view.id);
+    assert!(!item.name_matched);
+    assert_eq!(item.snippet, "The quarterly roadmap draft");
+
+    let result = search(&test, "view a", true).await;
+    let workspace_ids = result
+        .items
+        .iter()
+        .map(|workspace| workspace.workspace_id.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(workspace_ids, vec![first.workspace.id, second.workspace.id]);
+    assert!(result.items.iter().all(|workspace| workspace.items[0].name_matched));
+}
+
+#[tokio::test]
+async fn search_with_empty_query() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let code = CoreModuleEventBuilder::new(test.clone())
+        .event(Search)
+        .request(SearchRequest {
+            query: "  ".to_owned(),
+            all_workspaces: true,
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::SearchQueryInvalid.value());
+}
@@ -0,0 +1,65 @@
+use flowy_core::{
+    entities::unfurl::{LinkPreview, UnfurlRequest, UnfurlSetting},
+    errors::ErrorCode,
+    event::WorkspaceEvent::{ReadUnfurlSetting, UnfurlLink, UpdateUnfurlSetting},
+};
+use flowy_test::{event_builder::*, FlowySDKTest};
+
+async fn unfurl(test: &FlowySDKTest, url: &str) -> LinkPreview {
+    CoreModuleEventBuilder::new(test.clone())
+        .event(UnfurlLink)
+        .request(UnfurlRequest { url: url.to_owned() })
+        .async_send()
+        .await
+        .parse::<LinkPreview>()
+}
+
+#[tokio::test]
+async fn unfurl_never_fetches_a_private_address() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let preview = unfurl(&test, "http://127.0.0.1:1/admin").await;
+    assert_eq!(preview.url, "http://127.0.0.1:1/admin");
+    assert!(preview.title.is_empty() && preview.image_url.is_empty());
+    assert_eq!(preview.fetch_time, 0);
+
+    let error = CoreModuleEventBuilder::new(test.clone())
+        .event(UnfurlLink)
+        .request(UnfurlRequest {
+            url: "file:///etc/hosts".to_owned(),
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::EmbedUrlInvalid.value());
+}
+
+#[tokio::test]
+async fn unfurl_turned_off() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let setting = CoreModuleEventBuilder::new(test.clone())
+        .event(UpdateUnfurlSetting)
+        .request(UnfurlSetting { enabled: false })
+        .async_send()
+        .await
+        .parse::<UnfurlSetting>();
+    assert!(!setting.enabled);
+
+    let setting = CoreModuleEventBuilder::new(test.clone())
+        .event(ReadUnfurlSetting)
+        .async_send()
+        .await
+        .parse::<UnfurlSetting>();
+    assert!(!setting.enabled);
+    let preview = unfurl(&test, "https://appflowy.io").await;
+    assert!(preview.title.is_empty());
+
+    let _ = CoreModuleEventBuilder::new(test.clone())
+        .event(UpdateUnfurlSetting)
+        .request(UnfurlSetting { enabled: true })
+        .async_send()
+        .await;
+}
@@ -1,9 +1,47 @@
-use flowy_core::entities::{
-    app::QueryAppRequest,
-    trash::{TrashId, TrashType},
-    view::*,
+use diesel::RunQueryDsl;
+use flowy_collaboration::entities::doc::DocumentDelta;
+use flowy_core::{
+    entities::{
+        app::QueryAppRequest,
+        share::{
+            CreateGuestAccessRequest,
+            CreateShareLinkRequest,
+            GuestAccess,
+            QueryGuestAccessRequest,
+            ShareLink,
+            SharePermission,
+        },
+        trash::{TrashId, TrashType},
+        view::*,
+    },
+    errors::{ErrorCode, RecoveryHint},
+    event::WorkspaceEvent::{
+        ApplyDocDelta,
+        CloseView,
+        CreateGuestAccess,
+        CreateShareLink,
+        CreateView,
+        CreateViewCheckpoint,
+        DuplicateView,
+        ExportQuarantinedRevisions,
+        GrantViewAccess,
+        MoveItems,
+        MoveView,
+        ReadView,
+        ReadViewCheckpoints,
+        RestoreViewCheckpoint,
+        RevokeGuestAccess,
+        RevokeViewAccess,
+        Shutdown,
+        UpdateView,
+    },
+    folder_change::FolderChange,
+    name_rules::NameRules,
 };
-use flowy_test::{helper::*, FlowySDKTest};
+use flowy_document::core::{QuarantineReport, RevisionChain, RevisionRetention};
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+use futures::StreamExt;
+use lib_infra::uuid_string;
 
 #[tokio::test]
 #[should_panic]
@@ -19,6 +57,24 @@ async fn view_delete() {
     let _ = read_view(&test.sdk, query).await;
 }
 
+#[tokio::test]
+async fn view_read_missing_has_error_code() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let _ = ViewTest::new(&test).await;
+    let error = CoreModuleEventBuilder::new(test.clone())
+        .event(ReadView)
+        .request(QueryViewRequest {
+            view_ids: vec![uuid_string()],
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+    assert_eq!(error.recovery_hint(), RecoveryHint::NoRecovery);
+}
+
 #[tokio::test]
 async fn view_delete_then_putback() {
     let test = FlowySDKTest::default();
@@ -42,6 +98,49 @@ async fn view_delete_then_putback() {
     assert_eq!(&view, &test.view);
 }
 
+#[tokio::test]
+async fn view_delete_then_putback_in_place() {
+    let test = FlowySDKTest::default();
+    let user = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let _ = create_view(&test.sdk, &test.app.id).await;
+    let _ = create_view(&test.sdk, &test.app.id).await;
+    let query = QueryAppRequest {
+        app_ids: vec![test.app.id.clone()],
+    };
+    let view_ids = read_app(&test.sdk, query.clone())
+        .await
+        .belongings
+        .items
+        .into_iter()
+        .map(|view| view.id)
+        .collect::<Vec<String>>();
+
+    test.delete_views(vec![view_ids[1].clone()]).await;
+    let trash = read_trash(&test.sdk).await;
+    assert_eq!(trash[0].deleted_by, user.id);
+    assert_eq!(trash[0].parent_id, test.app.id);
+    assert_eq!(trash[0].position, 1);
+
+    putback_trash(
+        &test.sdk,
+        TrashId {
+            id: view_ids[1].clone(),
+            ty: TrashType::View,
+        },
+    )
+    .await;
+    let restored_ids = read_app(&test.sdk, query)
+        .await
+        .belongings
+        .items
+        .into_iter()
+        .map(|view| view.id)
+        .collect::<Vec<String>>();
+    assert_eq!(restored_ids, view_ids);
+}
+
 #[tokio::test]
 async fn view_delete_all() {
     let test = FlowySDKTest::default();
@@ -83,6 +182,260 @@ async fn view_delete_all_permanent() {
     assert_eq!(read_trash(&test.sdk).await.len(), 0);
 }
 
+#[tokio::test]
+async fn view_delete_permanent_deletes_revisions() {
+    let test = FlowySDKTest::with_secure_delete();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view_id = test.view.id.clone();
+    let controller = test.sdk.document_ctx.controller.clone();
+    assert!(!controller.read_revision_records(&view_id).unwrap().is_empty());
+
+    test.delete_views_permanent(vec![view_id.clone()]).await;
+    assert!(controller.read_revision_records(&view_id).unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn view_prune_revisions_keeps_content() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = || QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request()).await;
+    for text in &["first", "second"] {
+        let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+            .event(ApplyDocDelta)
+            .request(DocumentDelta {
+                doc_id: test.view.id.clone(),
+                delta_json: format!(r#"[{{"insert":"{} "}}]"#, text),
+            })
+            .async_send()
+            .await;
+    }
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CloseView)
+        .request(request())
+        .async_send()
+        .await;
+
+    let controller = test.sdk.document_ctx.controller.clone();
+    let _ = controller.flush().await.unwrap();
+    let _ = controller
+        .prune_revisions(vec![test.view.id.clone()], RevisionRetention::LastRevisions(1))
+        .await
+        .unwrap();
+    let doc = open_view(&test.sdk, request()).await;
+    assert!(doc.text.contains("second first"));
+}
+
+#[tokio::test]
+async fn view_restore_checkpoint() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let apply_delta = |text: &str| {
+        CoreModuleEventBuilder::new(test.sdk.clone())
+            .event(ApplyDocDelta)
+            .request(DocumentDelta {
+                doc_id: test.view.id.clone(),
+                delta_json: format!(r#"[{{"insert":"{} "}}]"#, text),
+            })
+            .async_send()
+    };
+    let _ = apply_delta("kept").await;
+    let checkpoint = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateViewCheckpoint)
+        .request(CreateViewCheckpointRequest {
+            view_id: test.view.id.clone(),
+            name: "Before rewrite".to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<ViewCheckpoint>();
+    let _ = apply_delta("rewritten").await;
+
+    let checkpoints = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadViewCheckpoints)
+        .request(QueryViewRequest {
+            view_ids: vec![test.view.id.clone()],
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedViewCheckpoint>();
+    assert_eq!(checkpoints.items, vec![checkpoint.clone()]);
+
+    let doc = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(RestoreViewCheckpoint)
+        .request(QueryViewCheckpointRequest {
+            view_id: test.view.id.clone(),
+            checkpoint_id: checkpoint.id,
+        })
+        .async_send()
+        .await
+        .parse::<DocumentDelta>();
+    assert!(doc.delta_json.contains("kept"));
+    assert!(!doc.delta_json.contains("rewritten"));
+}
+
+#[tokio::test]
+async fn view_export_and_replay_revision_chain() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ApplyDocDelta)
+        .request(DocumentDelta {
+            doc_id: test.view.id.clone(),
+            delta_json: r#"[{"insert":"reported "}]"#.to_owned(),
+        })
+        .async_send()
+        .await;
+
+    let path = format!("{}/{}.revisions.json", root_dir(), test.view.id);
+    let controller = test.sdk.document_ctx.controller.clone();
+    let exported = controller
+        .export_revision_chain(&test.view.id, std::path::Path::new(&path))
+        .await
+        .unwrap();
+    let mut chain = RevisionChain::read_from_file(std::path::Path::new(&path)).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(chain.revisions.len(), exported.revisions.len());
+
+    let scratch_dir = format!("{}/scratch_{}", root_dir(), test.view.id);
+    let replay = chain.replay(&scratch_dir).unwrap();
+    assert_eq!(replay.applied_count, replay.revision_count);
+    assert!(replay.failed_rev_id.is_none());
+    assert!(replay.document_json.contains("reported"));
+
+    // The corrupted revision stops the replay.
+    let corrupted_rev_id = {
+        let last = chain.revisions.last_mut().unwrap();
+        last.data = b"{not a delta".to_vec();
+        last.rev_id
+    };
+    let replay = chain.replay(&scratch_dir).unwrap();
+    assert_eq!(replay.failed_rev_id, Some(corrupted_rev_id));
+    assert_eq!(replay.applied_count, replay.revision_count - 1);
+    let _ = std::fs::remove_dir_all(&scratch_dir);
+}
+
+#[tokio::test]
+async fn view_duplicate_with_history() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    for text in &["first", "second"] {
+        let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+            .event(ApplyDocDelta)
+            .request(DocumentDelta {
+                doc_id: test.view.id.clone(),
+                delta_json: format!(r#"[{{"insert":"{} "}}]"#, text),
+            })
+            .async_send()
+            .await;
+    }
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(DuplicateView)
+        .request(DuplicateViewRequest {
+            view_id: test.view.id.clone(),
+            with_history: true,
+        })
+        .async_send()
+        .await;
+
+    let query = QueryAppRequest {
+        app_ids: vec![test.app.id.clone()],
+    };
+    let copy = read_app(&test.sdk, query)
+        .await
+        .belongings
+        .items
+        .into_iter()
+        .find(|view| view.id != test.view.id)
+        .unwrap();
+    let controller = test.sdk.document_ctx.controller.clone();
+    let _ = controller.flush().await.unwrap();
+    let revisions = controller.read_revision_records(&test.view.id).unwrap();
+    let copied = controller.read_revision_records(&copy.id).unwrap();
+    assert!(revisions.len() > 1);
+    assert_eq!(copied.len(), revisions.len());
+    assert!(copied.iter().all(|record| record.revision.doc_id == copy.id));
+
+    let doc = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![copy.id.clone()],
+        },
+    )
+    .await;
+    assert!(doc.text.contains("second first"));
+}
+
+#[tokio::test]
+async fn view_quarantine_corrupted_revision() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = || QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request()).await;
+    for text in &["first", "second"] {
+        let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+            .event(ApplyDocDelta)
+            .request(DocumentDelta {
+                doc_id: test.view.id.clone(),
+                delta_json: format!(r#"[{{"insert":"{} "}}]"#, text),
+            })
+            .async_send()
+            .await;
+    }
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CloseView)
+        .request(request())
+        .async_send()
+        .await;
+    let controller = test.sdk.document_ctx.controller.clone();
+    let _ = controller.flush().await.unwrap();
+
+    // A torn write of the last revision.
+    let conn = test.sdk.user_session.db_connection().unwrap();
+    let _ = diesel::sql_query(format!(
+        "UPDATE rev_table SET data = X'7B6E6F74' WHERE doc_id = '{0}' AND rev_id = (SELECT MAX(rev_id) FROM rev_table WHERE doc_id = '{0}')",
+        test.view.id
+    ))
+    .execute(&*conn)
+    .unwrap();
+
+    let doc = open_view(&test.sdk, request()).await;
+    assert!(doc.text.contains("first"));
+    let quarantined = controller.read_quarantined_revisions(&test.view.id).unwrap();
+    assert_eq!(quarantined.len(), 1);
+    assert_eq!(quarantined[0].data, b"{not".to_vec());
+
+    let result = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ExportQuarantinedRevisions)
+        .request(ExportQuarantinedRevisionsRequest {
+            view_id: test.view.id.clone(),
+            dir: root_dir(),
+        })
+        .async_send()
+        .await
+        .parse::<ExportQuarantinedRevisionsResult>();
+    assert_eq!(result.revision_count, 1);
+    let report = QuarantineReport::read_from_file(std::path::Path::new(&result.path)).unwrap();
+    let _ = std::fs::remove_file(&result.path);
+    assert_eq!(report.revisions, quarantined);
+}
+
 #[tokio::test]
 async fn view_open_doc() {
     let test = FlowySDKTest::default();
@@ -94,3 +447,332 @@ async fn view_open_doc() {
     };
     let _ = open_view(&test.sdk, request).await;
 }
+
+#[tokio::test]
+async fn view_edit_survives_shutdown() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = || QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request()).await;
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ApplyDocDelta)
+        .request(DocumentDelta {
+            doc_id: test.view.id.clone(),
+            delta_json: r#"[{"insert":"last keystrokes"}]"#.to_owned(),
+        })
+        .async_send()
+        .await;
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(Shutdown)
+        .async_send()
+        .await;
+
+    // The document and the database are opened again.
+    let doc = open_view(&test.sdk, request()).await;
+    assert!(doc.text.contains("last keystrokes"));
+}
+
+#[tokio::test]
+async fn view_create_sends_folder_change() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut changes = Box::pin(test.sdk.core.subscribe_folder_changes());
+    let view = create_view(&test.sdk, &test.app.id).await;
+    assert_eq!(
+        changes.next().await.unwrap(),
+        FolderChange::ViewCreated {
+            belong_to_id: test.app.id.clone(),
+            view_id: view.id,
+        }
+    );
+}
+
+#[tokio::test]
+async fn view_create_share_link() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = CreateShareLinkRequest {
+        view_id: test.view.id.clone(),
+        permission: SharePermission::ReadOnly,
+        expire_time: None,
+    };
+    let link = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateShareLink)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<ShareLink>();
+    assert_eq!(link.view_id, test.view.id);
+    assert_eq!(link.url.is_empty(), false);
+}
+
+#[tokio::test]
+async fn view_create_share_link_with_expired_time() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = CreateShareLinkRequest {
+        view_id: test.view.id.clone(),
+        permission: SharePermission::ReadOnly,
+        expire_time: Some(1),
+    };
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateShareLink)
+        .request(request)
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::ShareLinkExpireTimeInvalid.value());
+}
+
+#[tokio::test]
+async fn view_create_guest_access() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = CreateGuestAccessRequest {
+        view_id: test.view.id.clone(),
+        email: "guest@appflowy.io".to_owned(),
+        permission: SharePermission::Editable,
+    };
+    let access = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateGuestAccess)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<GuestAccess>();
+    assert_eq!(access.view_id, test.view.id);
+    assert_eq!(access.email, "guest@appflowy.io");
+    assert_eq!(access.permission, SharePermission::Editable);
+}
+
+#[tokio::test]
+async fn view_revoke_guest_access_with_empty_id() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryGuestAccessRequest {
+        access_id: "".to_owned(),
+    };
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(RevokeGuestAccess)
+        .request(request)
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::GuestAccessIdInvalid.value());
+}
+
+#[tokio::test]
+async fn view_grant_and_revoke_access() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let user_id = uuid_string();
+    let view_access = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(GrantViewAccess)
+        .request(UpdateViewAccessRequest {
+            view_id: test.view.id.clone(),
+            user_id: user_id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<ViewAccess>();
+    assert_eq!(view_access.user_ids, vec![user_id.clone()]);
+
+    // The owner of the workspace can still open the restricted view
+    let query = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, query).await, test.view);
+
+    let view_access = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(RevokeViewAccess)
+        .request(UpdateViewAccessRequest {
+            view_id: test.view.id.clone(),
+            user_id,
+        })
+        .async_send()
+        .await
+        .parse::<ViewAccess>();
+    assert_eq!(view_access.is_restricted(), false);
+}
+
+#[tokio::test]
+async fn view_grant_access_with_empty_user_id() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(GrantViewAccess)
+        .request(UpdateViewAccessRequest {
+            view_id: test.view.id.clone(),
+            user_id: "".to_owned(),
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::UserIdInvalid.value());
+}
+
+#[tokio::test]
+async fn view_move_into_another_app() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let app = create_app(&test.sdk, "Another App", "", &test.workspace.id).await;
+    let view = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(MoveView)
+        .request(MoveViewRequest {
+            view_id: test.view.id.clone(),
+            to_belong_to_id: app.id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<View>();
+    assert_eq!(view.belong_to_id, app.id);
+
+    let query = QueryAppRequest {
+        app_ids: vec![test.app.id.clone()],
+    };
+    assert!(read_app(&test.sdk, query).await.belongings.is_empty());
+
+    let query = QueryAppRequest {
+        app_ids: vec![app.id.clone()],
+    };
+    assert_eq!(read_app(&test.sdk, query).await.belongings[0].id, test.view.id);
+}
+
+#[tokio::test]
+async fn view_move_into_itself() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(MoveView)
+        .request(MoveViewRequest {
+            view_id: test.view.id.clone(),
+            to_belong_to_id: test.view.id.clone(),
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::ViewMoveInvalid.value());
+}
+
+#[tokio::test]
+async fn view_name_follows_name_rules() {
+    let test = FlowySDKTest::with_name_rules(NameRules {
+        forbidden_chars: vec!['/'],
+        unique_in_parent: true,
+        ..NameRules::default()
+    });
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateView)
+        .request(CreateViewRequest {
+            belong_to_id: test.app.id.clone(),
+            name: test.view.name.to_uppercase(),
+            desc: "".to_owned(),
+            thumbnail: None,
+            view_type: Some(ViewType::Doc),
+            view_id: None,
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::NameAlreadyExists.value());
+
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateView)
+        .request(UpdateViewRequest {
+            view_id: test.view.id.clone(),
+            name: Some("Notes/2022".to_owned()),
+            desc: None,
+            thumbnail: None,
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::NameContainsForbiddenCharacters.value());
+}
+
+#[tokio::test]
+async fn view_move_items_at_once() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view_b = create_view(&test.sdk, &test.app.id).await;
+    let view_c = create_view(&test.sdk, &test.app.id).await;
+    let move_item = |view_id: &str, to_parent_id: &str, index: i32| MoveItem {
+        id: view_id.to_owned(),
+        ty: MoveItemType::View,
+        to_parent_id: to_parent_id.to_owned(),
+        index,
+    };
+    let moved = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(MoveItems)
+        .request(MoveItemsRequest {
+            items: vec![
+                move_item(&view_c.id, &test.app.id, 0),
+                move_item(&view_b.id, &test.view.id, 0),
+            ],
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedItemMoved>();
+    assert_eq!(moved.items[0].index, 0);
+    assert_eq!(moved.items[1].from_parent_id, test.app.id);
+    assert_eq!(moved.items[1].to_parent_id, test.view.id);
+
+    // The second move fails, so the first one is rolled back too.
+    let code = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(MoveItems)
+        .request(MoveItemsRequest {
+            items: vec![
+                move_item(&view_c.id, &test.view.id, 0),
+                move_item(&test.view.id, &view_b.id, 0),
+            ],
+        })
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::ViewMoveInvalid.value());
+
+    let query = QueryAppRequest {
+        app_ids: vec![test.app.id.clone()],
+    };
+    let ids = read_app(&test.sdk, query)
+        .await
+        .belongings
+        .items
+        .into_iter()
+        .map(|view| view.id)
+        .collect::<Vec<String>>();
+    assert_eq!(ids, vec![view_c.id, test.view.id.clone()]);
+}
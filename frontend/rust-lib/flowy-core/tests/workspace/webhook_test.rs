@@ -0,0 +1,70 @@
+use flowy_core::{
+    entities::webhook::{CreateWebhookRequest, QueryWebhookRequest, RepeatedWebhook, Webhook},
+    errors::ErrorCode,
+    event::WorkspaceEvent::{CreateWebhook, DeleteWebhook, ReadWebhooks},
+};
+use flowy_test::{event_builder::*, FlowySDKTest};
+
+#[tokio::test]
+async fn webhook_create_read_and_delete() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let request = CreateWebhookRequest {
+        url: "https://example.com/hooks/appflowy".to_owned(),
+        on_view_created: true,
+        on_document_updated: false,
+        on_trash_emptied: true,
+    };
+    let webhook = CoreModuleEventBuilder::new(test.clone())
+        .event(CreateWebhook)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<Webhook>();
+    assert_eq!(webhook.url, "https://example.com/hooks/appflowy");
+    assert!(!webhook.secret.is_empty());
+    assert!(webhook.on_view_created && webhook.on_trash_emptied);
+    assert!(!webhook.on_document_updated);
+
+    let repeated_webhook = CoreModuleEventBuilder::new(test.clone())
+        .event(ReadWebhooks)
+        .async_send()
+        .await
+        .parse::<RepeatedWebhook>();
+    assert_eq!(repeated_webhook.items, vec![webhook.clone()]);
+
+    let _ = CoreModuleEventBuilder::new(test.clone())
+        .event(DeleteWebhook)
+        .request(QueryWebhookRequest { webhook_id: webhook.id })
+        .async_send()
+        .await
+        .assert_success();
+
+    let repeated_webhook = CoreModuleEventBuilder::new(test.clone())
+        .event(ReadWebhooks)
+        .async_send()
+        .await
+        .parse::<RepeatedWebhook>();
+    assert!(repeated_webhook.items.is_empty());
+}
+
+#[tokio::test]
+async fn webhook_create_with_invalid_url() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+
+    let request = CreateWebhookRequest {
+        url: "ftp://example.com".to_owned(),
+        on_view_created: true,
+        ..Default::default()
+    };
+    let code = CoreModuleEventBuilder::new(test.clone())
+        .event(CreateWebhook)
+        .request(request)
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::WebhookUrlInvalid.value());
+}
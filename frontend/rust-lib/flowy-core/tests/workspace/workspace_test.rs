@@ -1,9 +1,38 @@
 use flowy_core::{
-    entities::workspace::{CreateWorkspaceRequest, QueryWorkspaceRequest},
+    entities::{
+        app::QueryAppRequest,
+        trash::{TrashId, TrashType},
+        view::{CreateViewRequest, QueryViewRequest},
+        workspace::{
+            AddWorkspaceMemberRequest,
+            CreateInvitationRequest,
+            CreateWorkspaceRequest,
+            CurrentWorkspaceSetting,
+            DateFormat,
+            InvitationStatus,
+            QueryInvitationRequest,
+            QueryRevisionRetentionRequest,
+            QueryWorkspaceRequest,
+            QueryWorkspaceSettingsRequest,
+            RetentionKind,
+            RevisionRetentionSetting,
+            UpdateRevisionRetentionRequest,
+            UpdateWorkspaceSettingsRequest,
+            WorkspaceInvitation,
+            WorkspaceMember,
+            WorkspaceRole,
+            WorkspaceSettings,
+        },
+    },
     event::WorkspaceEvent::*,
     prelude::*,
 };
 use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+use flowy_user::{
+    entities::{SignUpRequest, UserProfile},
+    event::UserEvent,
+};
+use lib_infra::uuid_string;
 
 #[tokio::test]
 async fn workspace_read_all() {
@@ -39,6 +68,164 @@ async fn workspace_create_with_apps() {
     assert_eq!(&app, workspace_from_db.apps.first_or_crash());
 }
 
+#[tokio::test]
+async fn workspace_read_current_with_apps() {
+    let test = WorkspaceTest::new().await;
+    let app = create_app(&test.sdk, "App A", "AppFlowy GitHub Project", &test.workspace.id).await;
+    let setting = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadCurWorkspace)
+        .async_send()
+        .await
+        .parse::<CurrentWorkspaceSetting>();
+    assert_eq!(setting.workspace.id, test.workspace.id);
+    assert_eq!(&app, setting.workspace.apps.first_or_crash());
+
+    // Creating an app drops the cached skeleton of the workspace.
+    let _ = create_app(&test.sdk, "App B", "AppFlowy GitHub Project", &test.workspace.id).await;
+    let setting = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadCurWorkspace)
+        .async_send()
+        .await
+        .parse::<CurrentWorkspaceSetting>();
+    assert_eq!(setting.workspace.apps.len(), 2);
+    assert!(setting.workspace.apps.iter().all(|app| app.belongings.is_empty()));
+}
+
+#[tokio::test]
+async fn workspace_read_each_opened_workspace() {
+    let test = WorkspaceTest::new().await;
+    let app = create_app(&test.sdk, "App A", "", &test.workspace.id).await;
+    let other = create_workspace(&test.sdk, "Other Workspace", "").await;
+    open_workspace(&test.sdk, &other.id).await;
+
+    for workspace_id in vec![&test.workspace.id, &other.id] {
+        let setting = CoreModuleEventBuilder::new(test.sdk.clone())
+            .event(ReadWorkspaceSetting)
+            .request(QueryWorkspaceRequest::new(Some(workspace_id.clone())))
+            .async_send()
+            .await
+            .parse::<CurrentWorkspaceSetting>();
+        assert_eq!(&setting.workspace.id, workspace_id);
+    }
+    let apps = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadWorkspaceApps)
+        .request(QueryWorkspaceRequest::new(Some(test.workspace.id.clone())))
+        .async_send()
+        .await
+        .parse::<RepeatedApp>();
+    assert_eq!(&app, apps.first_or_crash());
+
+    let read_opened_ids = || async {
+        CoreModuleEventBuilder::new(test.sdk.clone())
+            .event(ReadOpenedWorkspaces)
+            .async_send()
+            .await
+            .parse::<RepeatedWorkspace>()
+            .into_inner()
+            .into_iter()
+            .map(|workspace| workspace.id)
+            .collect::<Vec<String>>()
+    };
+    let opened_ids = read_opened_ids().await;
+    assert!(opened_ids.contains(&test.workspace.id));
+    assert!(opened_ids.contains(&other.id));
+
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CloseWorkspace)
+        .request(QueryWorkspaceRequest::new(Some(other.id.clone())))
+        .async_send()
+        .await;
+    let opened_ids = read_opened_ids().await;
+    assert!(opened_ids.contains(&test.workspace.id));
+    assert!(!opened_ids.contains(&other.id));
+}
+
+// The outbox replays the create of a workspace that was made offline with
+// the id that the client gave it.
+#[tokio::test]
+async fn workspace_create_with_client_id() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let workspace_id = uuid_string();
+    let workspace = CoreModuleEventBuilder::new(sdk.clone())
+        .event(CreateWorkspace)
+        .request(CreateWorkspaceRequest {
+            name: "My workspace".to_owned(),
+            desc: "".to_owned(),
+            workspace_id: Some(workspace_id.clone()),
+        })
+        .async_send()
+        .await
+        .parse::<Workspace>();
+    assert_eq!(workspace.id, workspace_id);
+
+    let error = CoreModuleEventBuilder::new(sdk)
+        .event(CreateWorkspace)
+        .request(CreateWorkspaceRequest {
+            name: "My workspace".to_owned(),
+            desc: "".to_owned(),
+            workspace_id: Some("not a uuid".to_owned()),
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceIdInvalid.value());
+}
+
+// The controllers subscribe to the trash events at startup, so trashing a
+// view updates its app and trashing the app is seen by the workspace.
+#[tokio::test]
+async fn workspace_init_then_trash_view_and_app() {
+    let test = WorkspaceTest::new().await;
+    let app = create_app(&test.sdk, "App A", "", &test.workspace.id).await;
+    let view = create_view(&test.sdk, &app.id).await;
+    delete_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view.id.clone()],
+        },
+    )
+    .await;
+    let query = QueryAppRequest {
+        app_ids: vec![app.id.clone()],
+    };
+    assert!(read_app(&test.sdk, query).await.belongings.is_empty());
+
+    delete_app(&test.sdk, &app.id).await;
+    let request = QueryWorkspaceRequest::new(Some(test.workspace.id.clone()));
+    let workspace = read_workspace(&test.sdk, request).await.pop().unwrap();
+    assert!(workspace.apps.iter().all(|workspace_app| workspace_app.id != app.id));
+    assert_eq!(read_trash(&test.sdk).await.len(), 2);
+}
+
+#[tokio::test]
+async fn workspace_delete_then_putback() {
+    let test = WorkspaceTest::new().await;
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(DeleteWorkspace)
+        .request(QueryWorkspaceRequest::new(Some(test.workspace.id.clone())))
+        .async_send()
+        .await;
+    let workspaces = read_workspace(&test.sdk, QueryWorkspaceRequest::new(None)).await;
+    assert_eq!(workspaces.len(), 1);
+    assert!(workspaces.iter().all(|workspace| workspace.id != test.workspace.id));
+    let trash = read_trash(&test.sdk).await;
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].id, test.workspace.id);
+
+    putback_trash(
+        &test.sdk,
+        TrashId {
+            id: test.workspace.id.clone(),
+            ty: TrashType::Workspace,
+        },
+    )
+    .await;
+    let request = QueryWorkspaceRequest::new(Some(test.workspace.id.clone()));
+    let workspaces = read_workspace(&test.sdk, request).await;
+    assert_eq!(workspaces, vec![test.workspace.clone()]);
+}
+
 #[tokio::test]
 async fn workspace_create_with_invalid_name() {
     for (name, code) in invalid_workspace_name_test_case() {
@@ -46,6 +233,7 @@ async fn workspace_create_with_invalid_name() {
         let request = CreateWorkspaceRequest {
             name,
             desc: "".to_owned(),
+            workspace_id: None,
         };
         assert_eq!(
             CoreModuleEventBuilder::new(sdk)
@@ -67,6 +255,7 @@ async fn workspace_update_with_invalid_name() {
         let request = CreateWorkspaceRequest {
             name,
             desc: "".to_owned(),
+            workspace_id: None,
         };
         assert_eq!(
             CoreModuleEventBuilder::new(sdk.clone())
@@ -81,4 +270,259 @@ async fn workspace_update_with_invalid_name() {
     }
 }
 
+#[tokio::test]
+async fn workspace_add_member() {
+    let test = WorkspaceTest::new().await;
+    let request = AddWorkspaceMemberRequest {
+        workspace_id: test.workspace.id.clone(),
+        email: " nathan@appflowy.io ".to_owned(),
+        role: Some(WorkspaceRole::Viewer),
+    };
+    let member = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(AddWorkspaceMember)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<WorkspaceMember>();
+    assert_eq!(member.email, "nathan@appflowy.io");
+    assert_eq!(member.role, WorkspaceRole::Viewer);
+}
+
+#[tokio::test]
+async fn workspace_add_member_with_invalid_email() {
+    let test = WorkspaceTest::new().await;
+    for (email, code) in vec![
+        ("".to_owned(), ErrorCode::EmailIsEmpty),
+        ("annie".to_owned(), ErrorCode::EmailFormatInvalid),
+    ] {
+        let request = AddWorkspaceMemberRequest {
+            workspace_id: test.workspace.id.clone(),
+            email,
+            role: Some(WorkspaceRole::Editor),
+        };
+        assert_eq!(
+            CoreModuleEventBuilder::new(test.sdk.clone())
+                .event(AddWorkspaceMember)
+                .request(request)
+                .async_send()
+                .await
+                .error()
+                .code,
+            code.value()
+        )
+    }
+}
+
+#[tokio::test]
+async fn workspace_create_invitation() {
+    let test = WorkspaceTest::new().await;
+    let request = CreateInvitationRequest {
+        workspace_id: test.workspace.id.clone(),
+        email: " nathan@appflowy.io ".to_owned(),
+        role: WorkspaceRole::Editor,
+    };
+    let invitation = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateInvitation)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<WorkspaceInvitation>();
+    assert_eq!(invitation.email, "nathan@appflowy.io");
+    assert_eq!(invitation.status, InvitationStatus::Pending);
+}
+
+#[tokio::test]
+async fn workspace_create_invitation_after_anonymous_upgrade() {
+    let sdk = FlowySDKTest::default();
+    let _ = CoreModuleEventBuilder::new(sdk.clone())
+        .event(UserEvent::SignOut)
+        .sync_send();
+    let _ = CoreModuleEventBuilder::new(sdk.clone())
+        .event(UserEvent::SignInAnonymously)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    let workspace = read_workspace(&sdk, QueryWorkspaceRequest::new(None)).await.remove(0);
+    let request = || CreateInvitationRequest {
+        workspace_id: workspace.id.clone(),
+        email: "nathan@appflowy.io".to_owned(),
+        role: WorkspaceRole::Editor,
+    };
+    let error = CoreModuleEventBuilder::new(sdk.clone())
+        .event(CreateInvitation)
+        .request(request())
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::AccountRequired.value());
+
+    let _ = CoreModuleEventBuilder::new(sdk.clone())
+        .event(UserEvent::UpgradeAnonymousUser)
+        .request(SignUpRequest {
+            email: random_email(),
+            name: "annie".to_owned(),
+            password: login_password(),
+        })
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    let workspaces = read_workspace(&sdk, QueryWorkspaceRequest::new(None)).await;
+    assert!(workspaces.iter().any(|upgraded| upgraded.id == workspace.id));
+    let invitation = CoreModuleEventBuilder::new(sdk.clone())
+        .event(CreateInvitation)
+        .request(request())
+        .async_send()
+        .await
+        .parse::<WorkspaceInvitation>();
+    assert_eq!(invitation.workspace_id, workspace.id);
+}
+
+#[tokio::test]
+async fn workspace_accept_invitation_with_empty_id() {
+    let test = WorkspaceTest::new().await;
+    let request = QueryInvitationRequest {
+        invitation_id: "".to_owned(),
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(AcceptInvitation)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::InvitationIdInvalid.value());
+}
+
+async fn read_revision_retention(test: &WorkspaceTest) -> RevisionRetentionSetting {
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadRevisionRetention)
+        .request(QueryRevisionRetentionRequest {
+            workspace_id: test.workspace.id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<RevisionRetentionSetting>()
+}
+
+#[tokio::test]
+async fn workspace_update_revision_retention() {
+    let test = WorkspaceTest::new().await;
+    assert_eq!(read_revision_retention(&test).await.kind, RetentionKind::All);
+
+    let request = UpdateRevisionRetentionRequest {
+        workspace_id: test.workspace.id.clone(),
+        kind: RetentionKind::LastRevisions,
+        value: 20,
+    };
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateRevisionRetention)
+        .request(request)
+        .async_send()
+        .await;
+    let setting = read_revision_retention(&test).await;
+    assert_eq!(setting.kind, RetentionKind::LastRevisions);
+    assert_eq!(setting.value, 20);
+}
+
+#[tokio::test]
+async fn workspace_update_revision_retention_with_zero_days() {
+    let test = WorkspaceTest::new().await;
+    let request = UpdateRevisionRetentionRequest {
+        workspace_id: test.workspace.id.clone(),
+        kind: RetentionKind::LastDays,
+        value: 0,
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateRevisionRetention)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::RevisionRetentionValueInvalid.value());
+}
+
+#[tokio::test]
+async fn workspace_update_settings() {
+    let test = WorkspaceTest::new().await;
+    let request = UpdateWorkspaceSettingsRequest {
+        workspace_id: test.workspace.id.clone(),
+        date_format: Some(DateFormat::ISO),
+        retention_kind: Some(RetentionKind::LastDays),
+        retention_value: Some(30),
+        ..UpdateWorkspaceSettingsRequest::default()
+    };
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateWorkspaceSettings)
+        .request(request)
+        .async_send()
+        .await;
+
+    let settings = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadWorkspaceSettings)
+        .request(QueryWorkspaceSettingsRequest {
+            workspace_id: test.workspace.id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<WorkspaceSettings>();
+    assert_eq!(settings.date_format, DateFormat::ISO);
+    assert_eq!(settings.default_view_type, ViewType::Doc);
+    assert_eq!(settings.default_member_role, WorkspaceRole::Editor);
+    let retention = read_revision_retention(&test).await;
+    assert_eq!(retention.kind, RetentionKind::LastDays);
+    assert_eq!(retention.value, 30);
+
+    let request = UpdateWorkspaceSettingsRequest {
+        workspace_id: test.workspace.id.clone(),
+        default_member_role: Some(WorkspaceRole::Owner),
+        ..UpdateWorkspaceSettingsRequest::default()
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateWorkspaceSettings)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::DefaultMemberRoleInvalid.value());
+}
+
+#[tokio::test]
+async fn workspace_settings_apply_to_new_views_and_members() {
+    let test = AppTest::new().await;
+    let request = UpdateWorkspaceSettingsRequest {
+        workspace_id: test.workspace.id.clone(),
+        default_view_type: Some(ViewType::Grid),
+        default_member_role: Some(WorkspaceRole::Viewer),
+        ..UpdateWorkspaceSettingsRequest::default()
+    };
+    let _ = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(UpdateWorkspaceSettings)
+        .request(request)
+        .async_send()
+        .await;
+
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "Grid".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: None,
+        view_id: None,
+    };
+    let view = create_view_with_request(&test.sdk, request).await;
+    assert_eq!(view.view_type, ViewType::Grid);
+
+    let request = AddWorkspaceMemberRequest {
+        workspace_id: test.workspace.id.clone(),
+        email: "nathan@appflowy.io".to_owned(),
+        role: None,
+    };
+    let member = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(AddWorkspaceMember)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<WorkspaceMember>();
+    assert_eq!(member.role, WorkspaceRole::Viewer);
+}
+
 // TODO 1) delete workspace, but can't delete the last workspace
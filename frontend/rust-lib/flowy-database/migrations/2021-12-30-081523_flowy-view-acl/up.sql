@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE TABLE view_acl_table (
+    view_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    PRIMARY KEY (view_id, user_id)
+);
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE webhook_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    url TEXT NOT NULL DEFAULT '',
+    secret TEXT NOT NULL DEFAULT '',
+    on_view_created BOOLEAN NOT NULL DEFAULT FALSE,
+    on_document_updated BOOLEAN NOT NULL DEFAULT FALSE,
+    on_trash_emptied BOOLEAN NOT NULL DEFAULT FALSE,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
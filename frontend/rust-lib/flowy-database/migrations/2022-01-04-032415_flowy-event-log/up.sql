@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE event_log_table (
+    seq INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    ty TEXT NOT NULL DEFAULT '',
+    object_id TEXT NOT NULL DEFAULT '',
+    payload TEXT NOT NULL DEFAULT '',
+    create_time BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
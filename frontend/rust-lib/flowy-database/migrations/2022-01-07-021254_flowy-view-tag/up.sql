@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE TABLE view_tag_table (
+    view_id TEXT NOT NULL,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (view_id, tag)
+);
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE attachment_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    view_id TEXT NOT NULL DEFAULT '',
+    name TEXT NOT NULL DEFAULT '',
+    mime_type TEXT NOT NULL DEFAULT '',
+    data BLOB NOT NULL,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE audit_log_table (
+    seq INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    workspace_id TEXT NOT NULL DEFAULT '',
+    actor_id TEXT NOT NULL DEFAULT '',
+    action TEXT NOT NULL DEFAULT '',
+    object_type TEXT NOT NULL DEFAULT '',
+    object_id TEXT NOT NULL DEFAULT '',
+    before TEXT NOT NULL DEFAULT '',
+    after TEXT NOT NULL DEFAULT '',
+    create_time BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX audit_log_table_workspace_id ON audit_log_table (workspace_id, seq);
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE rev_table ADD COLUMN create_time BIGINT NOT NULL DEFAULT 0;
+-- The revisions saved before have no time, they count from the migration.
+UPDATE rev_table SET create_time = CAST(strftime('%s', 'now') AS INTEGER);
+CREATE TABLE rev_retention_table (
+    workspace_id TEXT NOT NULL PRIMARY KEY,
+    kind INTEGER NOT NULL DEFAULT 0,
+    value BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
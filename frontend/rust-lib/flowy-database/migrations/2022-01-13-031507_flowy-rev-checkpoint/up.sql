@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE rev_checkpoint_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    doc_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    rev_id BIGINT NOT NULL,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX rev_checkpoint_table_doc_id ON rev_checkpoint_table (doc_id);
\ No newline at end of file
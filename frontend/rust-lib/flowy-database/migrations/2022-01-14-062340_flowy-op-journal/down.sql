@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE op_journal_table;
\ No newline at end of file
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+CREATE TABLE op_journal_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    objects TEXT NOT NULL DEFAULT '',
+    create_time BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE link_preview_table (
+    url TEXT NOT NULL PRIMARY KEY,
+    title TEXT NOT NULL DEFAULT '',
+    description TEXT NOT NULL DEFAULT '',
+    image_url TEXT NOT NULL DEFAULT '',
+    site_name TEXT NOT NULL DEFAULT '',
+    fetch_time BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE TABLE collapsed_toggle_table (
+    doc_id TEXT NOT NULL,
+    toggle_id TEXT NOT NULL,
+    PRIMARY KEY (doc_id, toggle_id)
+);
\ No newline at end of file
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE rev_quarantine_table (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    doc_id TEXT NOT NULL,
+    base_rev_id BIGINT NOT NULL,
+    rev_id BIGINT NOT NULL,
+    data BLOB NOT NULL,
+    md5 TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    quarantine_time BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX rev_quarantine_table_doc_id ON rev_quarantine_table (doc_id);
\ No newline at end of file
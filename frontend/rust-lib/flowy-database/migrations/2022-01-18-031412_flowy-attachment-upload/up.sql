@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE attachment_table ADD COLUMN remote_key TEXT NOT NULL DEFAULT '';
+CREATE TABLE attachment_upload_table (
+    attachment_id TEXT NOT NULL PRIMARY KEY,
+    remote_key TEXT NOT NULL,
+    upload_id TEXT NOT NULL,
+    part_etags TEXT NOT NULL DEFAULT '[]'
+);
\ No newline at end of file
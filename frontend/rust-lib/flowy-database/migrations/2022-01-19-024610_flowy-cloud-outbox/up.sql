@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE cloud_outbox_table (
+    seq INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    user_id TEXT NOT NULL,
+    op TEXT NOT NULL,
+    object_id TEXT NOT NULL,
+    payload BLOB NOT NULL,
+    create_time BIGINT NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT NOT NULL DEFAULT ''
+);
+CREATE INDEX cloud_outbox_table_user_id ON cloud_outbox_table (user_id, seq);
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE trash_table ADD COLUMN deleted_by TEXT NOT NULL DEFAULT '';
+ALTER TABLE trash_table ADD COLUMN parent_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE trash_table ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
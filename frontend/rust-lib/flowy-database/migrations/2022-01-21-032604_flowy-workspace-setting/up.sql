@@ -0,0 +1,7 @@
+-- Your SQL goes here
+CREATE TABLE workspace_setting_table (
+    workspace_id TEXT NOT NULL PRIMARY KEY,
+    default_view_type INTEGER NOT NULL DEFAULT 1,
+    date_format INTEGER NOT NULL DEFAULT 0,
+    default_member_role INTEGER NOT NULL DEFAULT 1
+);
\ No newline at end of file
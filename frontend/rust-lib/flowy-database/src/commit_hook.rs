@@ -0,0 +1,31 @@
+use diesel::{connection::TransactionManager, Connection, SqliteConnection};
+use std::cell::RefCell;
+
+// The transactions run on the thread that started them, so the hooks of the
+// transaction are kept per thread.
+thread_local! {
+    static AFTER_COMMIT_HOOKS: RefCell<Vec<Box<dyn FnOnce()>>> = RefCell::new(vec![]);
+}
+
+/// Runs `f` once the outermost transaction of `conn` is committed, or right
+/// away if `conn` isn't in a transaction. `f` is dropped if the transaction
+/// is rolled back. Only the transactions that are started with
+/// `timed_transaction` run the hooks.
+pub fn after_commit<F>(conn: &SqliteConnection, f: F)
+where
+    F: FnOnce() + 'static,
+{
+    match conn.transaction_manager().get_transaction_depth() {
+        0 => f(),
+        _ => AFTER_COMMIT_HOOKS.with(|hooks| hooks.borrow_mut().push(Box::new(f))),
+    }
+}
+
+pub(crate) fn finish_transaction(committed: bool) {
+    let hooks = AFTER_COMMIT_HOOKS.with(|hooks| hooks.borrow_mut().drain(..).collect::<Vec<_>>());
+    if committed {
+        for hook in hooks {
+            hook();
+        }
+    }
+}
@@ -7,7 +7,9 @@ pub mod kv;
 use lib_sqlite::PoolConfig;
 pub use lib_sqlite::{ConnectionPool, DBConnection, Database};
 
+pub mod commit_hook;
 pub mod schema;
+pub mod slow_log;
 
 #[macro_use]
 pub mod macros;
@@ -24,7 +26,7 @@ pub type Error = diesel::result::Error;
 pub mod prelude {
     pub use diesel::{query_dsl::*, BelongingToDsl, ExpressionMethods, RunQueryDsl};
 
-    pub use super::UserDatabaseConnection;
+    pub use super::{slow_log::SlowLogTransaction, UserDatabaseConnection};
 }
 
 embed_migrations!("../flowy-database/migrations/");
@@ -41,6 +43,15 @@ pub fn init(storage_path: &str) -> Result<Database, io::Error> {
     Ok(database)
 }
 
+/// Rebuilds the database file without the free pages, which may still hold
+/// the deleted rows, and truncates the write-ahead log. It can't be called in
+/// a transaction.
+pub fn erase_free_pages(conn: &SqliteConnection) -> Result<(), Error> {
+    use diesel::connection::SimpleConnection;
+    let _ = conn.batch_execute("VACUUM; PRAGMA wal_checkpoint(TRUNCATE);")?;
+    Ok(())
+}
+
 fn as_io_error<E>(e: E) -> io::Error
 where
     E: Into<lib_sqlite::Error> + Debug,
@@ -13,6 +13,61 @@ table! {
     }
 }
 
+table! {
+    attachment_table (id) {
+        id -> Text,
+        view_id -> Text,
+        name -> Text,
+        mime_type -> Text,
+        data -> Binary,
+        create_time -> BigInt,
+        remote_key -> Text,
+    }
+}
+
+table! {
+    attachment_upload_table (attachment_id) {
+        attachment_id -> Text,
+        remote_key -> Text,
+        upload_id -> Text,
+        part_etags -> Text,
+    }
+}
+
+table! {
+    audit_log_table (seq) {
+        seq -> BigInt,
+        workspace_id -> Text,
+        actor_id -> Text,
+        action -> Text,
+        object_type -> Text,
+        object_id -> Text,
+        before -> Text,
+        after -> Text,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    cloud_outbox_table (seq) {
+        seq -> BigInt,
+        user_id -> Text,
+        op -> Text,
+        object_id -> Text,
+        payload -> Binary,
+        create_time -> BigInt,
+        attempts -> Integer,
+        last_error -> Text,
+    }
+}
+
+table! {
+    collapsed_toggle_table (doc_id, toggle_id) {
+        doc_id -> Text,
+        toggle_id -> Text,
+    }
+}
+
 table! {
     doc_table (id) {
         id -> Text,
@@ -21,6 +76,67 @@ table! {
     }
 }
 
+table! {
+    event_log_table (seq) {
+        seq -> BigInt,
+        ty -> Text,
+        object_id -> Text,
+        payload -> Text,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    link_preview_table (url) {
+        url -> Text,
+        title -> Text,
+        description -> Text,
+        image_url -> Text,
+        site_name -> Text,
+        fetch_time -> BigInt,
+    }
+}
+
+table! {
+    op_journal_table (id) {
+        id -> Text,
+        name -> Text,
+        objects -> Text,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    rev_checkpoint_table (id) {
+        id -> Text,
+        doc_id -> Text,
+        name -> Text,
+        rev_id -> BigInt,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    rev_quarantine_table (id) {
+        id -> Integer,
+        doc_id -> Text,
+        base_rev_id -> BigInt,
+        rev_id -> BigInt,
+        data -> Binary,
+        md5 -> Text,
+        reason -> Text,
+        quarantine_time -> BigInt,
+    }
+}
+
+table! {
+    rev_retention_table (workspace_id) {
+        workspace_id -> Text,
+        kind -> Integer,
+        value -> BigInt,
+    }
+}
+
 table! {
     rev_table (id) {
         id -> Integer,
@@ -30,6 +146,8 @@ table! {
         data -> Binary,
         state -> Integer,
         ty -> Integer,
+        md5 -> Text,
+        create_time -> BigInt,
     }
 }
 
@@ -41,6 +159,9 @@ table! {
         modified_time -> BigInt,
         create_time -> BigInt,
         ty -> Integer,
+        deleted_by -> Text,
+        parent_id -> Text,
+        position -> Integer,
     }
 }
 
@@ -54,6 +175,20 @@ table! {
     }
 }
 
+table! {
+    view_acl_table (view_id, user_id) {
+        view_id -> Text,
+        user_id -> Text,
+    }
+}
+
+table! {
+    view_tag_table (view_id, tag) {
+        view_id -> Text,
+        tag -> Text,
+    }
+}
+
 table! {
     view_table (id) {
         id -> Text,
@@ -69,6 +204,27 @@ table! {
     }
 }
 
+table! {
+    webhook_table (id) {
+        id -> Text,
+        url -> Text,
+        secret -> Text,
+        on_view_created -> Bool,
+        on_document_updated -> Bool,
+        on_trash_emptied -> Bool,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    workspace_setting_table (workspace_id) {
+        workspace_id -> Text,
+        default_view_type -> Integer,
+        date_format -> Integer,
+        default_member_role -> Integer,
+    }
+}
+
 table! {
     workspace_table (id) {
         id -> Text,
@@ -78,15 +234,31 @@ table! {
         create_time -> BigInt,
         user_id -> Text,
         version -> BigInt,
+        role -> Integer,
     }
 }
 
 allow_tables_to_appear_in_same_query!(
     app_table,
+    attachment_table,
+    attachment_upload_table,
+    audit_log_table,
+    cloud_outbox_table,
+    collapsed_toggle_table,
     doc_table,
+    event_log_table,
+    link_preview_table,
+    op_journal_table,
+    rev_checkpoint_table,
+    rev_quarantine_table,
+    rev_retention_table,
     rev_table,
     trash_table,
     user_table,
+    view_acl_table,
+    view_tag_table,
     view_table,
+    webhook_table,
+    workspace_setting_table,
     workspace_table,
 );
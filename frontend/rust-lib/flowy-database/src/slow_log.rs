@@ -0,0 +1,89 @@
+use crate::commit_hook::finish_transaction;
+use diesel::{
+    connection::TransactionManager,
+    debug_query,
+    query_builder::QueryFragment,
+    result::Error,
+    sqlite::Sqlite,
+    Connection,
+    SqliteConnection,
+};
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+const DEFAULT_SLOW_OPERATION_THRESHOLD_MS: u64 = 200;
+
+static SLOW_OPERATION_THRESHOLD_MS: AtomicU64 = AtomicU64::new(DEFAULT_SLOW_OPERATION_THRESHOLD_MS);
+
+pub fn set_slow_operation_threshold(threshold: Duration) {
+    SLOW_OPERATION_THRESHOLD_MS.store(threshold.as_millis() as u64, Ordering::Relaxed);
+}
+
+pub fn slow_operation_threshold() -> Duration {
+    Duration::from_millis(SLOW_OPERATION_THRESHOLD_MS.load(Ordering::Relaxed))
+}
+
+// The sql of the query without the bound values, so the slow log never
+// carries the names or the content that the user typed.
+pub fn statement_shape<Q: QueryFragment<Sqlite>>(query: &Q) -> String {
+    let sql = debug_query::<Sqlite, _>(query).to_string();
+    match sql.find(" -- binds:") {
+        Some(index) => sql[..index].to_owned(),
+        None => sql,
+    }
+}
+
+pub fn log_if_slow<T, F: FnOnce() -> T>(shape: &str, f: F) -> T {
+    let start = Instant::now();
+    let output = f();
+    let elapsed = start.elapsed();
+    if elapsed >= slow_operation_threshold() {
+        log::warn!("Slow database operation took {}ms: {}", elapsed.as_millis(), shape);
+    }
+    output
+}
+
+pub trait SlowLogTransaction {
+    // Same as `immediate_transaction`, the time of the whole transaction is
+    // logged under the name if it's slow, including the time spent waiting
+    // for the lock of the database. The hooks of `after_commit` run once the
+    // outermost transaction is finished.
+    fn timed_transaction<T, E, F>(&self, name: &str, f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+        E: From<Error>;
+}
+
+impl SlowLogTransaction for SqliteConnection {
+    fn timed_transaction<T, E, F>(&self, name: &str, f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+        E: From<Error>,
+    {
+        let result = log_if_slow(&format!("transaction {}", name), || self.immediate_transaction(f));
+        if self.transaction_manager().get_transaction_depth() == 0 {
+            finish_transaction(result.is_ok());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        schema::{view_table, view_table::dsl},
+    };
+
+    #[test]
+    fn statement_shape_without_binds() {
+        let query = dsl::view_table.filter(view_table::belong_to_id.eq("the secret name"));
+        let shape = statement_shape(&query);
+        assert!(shape.starts_with("SELECT"));
+        assert!(shape.contains("`belong_to_id` = ?"));
+        assert!(!shape.contains("the secret name"));
+    }
+}
@@ -3,10 +3,11 @@ use backend_service::configuration::ClientServerConfiguration;
 
 use crate::{
     controller::DocumentController,
-    core::{DocumentWSReceivers, DocumentWebSocket},
+    core::{DocumentWSReceivers, DocumentWebSocket, RevisionSigner},
     server::construct_doc_server,
 };
 use flowy_database::ConnectionPool;
+use lib_infra::clock::Clock;
 use std::sync::Arc;
 
 pub trait DocumentUser: Send + Sync {
@@ -14,6 +15,9 @@ pub trait DocumentUser: Send + Sync {
     fn user_id(&self) -> Result<String, FlowyError>;
     fn token(&self) -> Result<String, FlowyError>;
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
+    // Returns an error if the document content is not allowed to be read, e.g. the
+    // app is locked.
+    fn check_read_permission(&self) -> Result<(), FlowyError>;
 }
 
 pub struct DocumentContext {
@@ -27,9 +31,30 @@ impl DocumentContext {
         ws_receivers: Arc<DocumentWSReceivers>,
         ws_sender: Arc<dyn DocumentWebSocket>,
         server_config: &ClientServerConfiguration,
+        clock: Arc<dyn Clock>,
+        sign_revisions: bool,
     ) -> DocumentContext {
         let server = construct_doc_server(server_config);
-        let doc_ctrl = Arc::new(DocumentController::new(server, user.clone(), ws_receivers, ws_sender));
+        // The revisions are sent unsigned if the key of the device can't be
+        // loaded, the document still syncs.
+        let signer = match sign_revisions {
+            false => None,
+            true => match RevisionSigner::load() {
+                Ok(signer) => Some(Arc::new(signer)),
+                Err(e) => {
+                    tracing::error!("Load the revision signing key failed: {:?}", e);
+                    None
+                },
+            },
+        };
+        let doc_ctrl = Arc::new(DocumentController::new(
+            server,
+            user.clone(),
+            ws_receivers,
+            ws_sender,
+            clock,
+            signer,
+        ));
         Self {
             controller: doc_ctrl,
             user,
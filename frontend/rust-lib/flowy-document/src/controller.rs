@@ -2,9 +2,28 @@ use crate::{
     context::DocumentUser,
     core::{
         edit::ClientDocumentEditor,
-        revision::{DocumentRevisionCache, DocumentRevisionManager, RevisionServer},
+        highlight_code,
+        revision::{
+            create_checkpoint,
+            read_checkpoint_delta,
+            DocumentRevisionCache,
+            DocumentRevisionManager,
+            QuarantineReport,
+            QuarantinedRevision,
+            RevisionChain,
+            RevisionCheckpoint,
+            RevisionCheckpointTableSql,
+            RevisionDiskWriter,
+            RevisionQuarantineTableSql,
+            RevisionRecord,
+            RevisionRetention,
+            RevisionServer,
+            RevisionSigner,
+        },
+        CollapsedToggleTableSql,
         DocumentWSReceivers,
         DocumentWebSocket,
+        SyntaxToken,
         WSStateReceiver,
     },
     errors::FlowyError,
@@ -12,21 +31,43 @@ use crate::{
 };
 use bytes::Bytes;
 use dashmap::DashMap;
-use flowy_collaboration::entities::{
-    doc::{DocumentDelta, DocumentId, DocumentInfo},
-    revision::RepeatedRevision,
+use flowy_collaboration::{
+    document::CodeBlock,
+    entities::{
+        doc::{DocumentDelta, DocumentId, DocumentInfo},
+        revision::{RepeatedRevision, Revision},
+    },
 };
 use flowy_database::ConnectionPool;
-use flowy_error::FlowyResult;
-use lib_infra::future::FutureResult;
-use std::sync::Arc;
+use flowy_error::{internal_error, FlowyResult};
+use lib_infra::{clock::Clock, future::FutureResult};
+use parking_lot::RwLock;
+use std::{
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+        Weak,
+    },
+    time::Duration,
+};
+
+// The editor of a document that wasn't opened or edited for this long is
+// closed, it's opened again on the next access.
+const IDLE_EDITOR_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+const IDLE_EDITOR_CHECK_INTERVAL: Duration = Duration::from_secs(60);
 
 pub struct DocumentController {
     server: Server,
     ws_receivers: Arc<DocumentWSReceivers>,
     ws_sender: Arc<dyn DocumentWebSocket>,
     open_cache: Arc<OpenDocCache>,
+    disk_writer: RwLock<Option<Arc<RevisionDiskWriter>>>,
     user: Arc<dyn DocumentUser>,
+    clock: Arc<dyn Clock>,
+    signer: Option<Arc<RevisionSigner>>,
+    is_idle_check_started: AtomicBool,
+    open_lock: tokio::sync::Mutex<()>,
 }
 
 impl DocumentController {
@@ -35,6 +76,8 @@ impl DocumentController {
         user: Arc<dyn DocumentUser>,
         ws_receivers: Arc<DocumentWSReceivers>,
         ws_sender: Arc<dyn DocumentWebSocket>,
+        clock: Arc<dyn Clock>,
+        signer: Option<Arc<RevisionSigner>>,
     ) -> Self {
         let open_cache = Arc::new(OpenDocCache::new());
         Self {
@@ -42,13 +85,27 @@ impl DocumentController {
             ws_receivers,
             ws_sender,
             open_cache,
+            disk_writer: RwLock::new(None),
             user,
+            clock,
+            signer,
+            is_idle_check_started: AtomicBool::new(false),
+            open_lock: tokio::sync::Mutex::new(()),
         }
     }
 
     pub(crate) fn init(&self) -> FlowyResult<()> {
         let notify = self.ws_sender.subscribe_state_changed();
         listen_ws_state_changed(notify, self.ws_receivers.clone());
+        // It's initialized again for each user who signs in, but one check
+        // covers the editors of all of them.
+        if !self.is_idle_check_started.swap(true, Ordering::SeqCst) {
+            close_idle_editors_periodically(
+                Arc::downgrade(&self.open_cache),
+                self.ws_receivers.clone(),
+                self.clock.clone(),
+            );
+        }
 
         Ok(())
     }
@@ -57,6 +114,7 @@ impl DocumentController {
     pub async fn open_document<T: AsRef<str>>(&self, doc_id: T) -> Result<Arc<ClientDocumentEditor>, FlowyError> {
         let doc_id = doc_id.as_ref();
         tracing::Span::current().record("doc_id", &doc_id);
+        let _ = self.user.check_read_permission()?;
         self.get_editor(doc_id).await
     }
 
@@ -69,6 +127,17 @@ impl DocumentController {
         Ok(())
     }
 
+    pub fn is_opened<T: AsRef<str>>(&self, doc_id: T) -> bool { self.open_cache.contains(doc_id.as_ref()) }
+
+    // Stops all the opened editors, e.g. when the user signed out.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn close_all_documents(&self) {
+        for doc_id in self.open_cache.doc_ids() {
+            self.open_cache.remove(&doc_id);
+            self.ws_receivers.remove(&doc_id);
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
     pub fn delete<T: AsRef<str>>(&self, doc_id: T) -> Result<(), FlowyError> {
         let doc_id = doc_id.as_ref();
@@ -78,8 +147,42 @@ impl DocumentController {
         Ok(())
     }
 
+    // Deletes the revisions of the documents that were removed for good, e.g.
+    // when the trash is emptied. Nothing of their content is left in the
+    // database after it returns.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn delete_revisions(&self, doc_ids: Vec<String>) -> FlowyResult<()> {
+        if doc_ids.is_empty() {
+            return Ok(());
+        }
+        let pool = self.user.db_pool()?;
+        let _ = CollapsedToggleTableSql::delete_all(&doc_ids, &*pool.get().map_err(internal_error)?)?;
+        self.disk_writer(&pool).delete(doc_ids).await
+    }
+
+    // Squashes the synced revisions of the documents that fall out of the
+    // retention. The opened documents are skipped, their revisions are still
+    // in use. Returns the number of revisions that were removed.
+    #[tracing::instrument(level = "debug", skip(self, doc_ids), err)]
+    pub async fn prune_revisions(&self, doc_ids: Vec<String>, retention: RevisionRetention) -> FlowyResult<usize> {
+        if retention == RevisionRetention::All {
+            return Ok(0);
+        }
+        let pool = self.user.db_pool()?;
+        let disk_writer = self.disk_writer(&pool);
+        let mut pruned = 0;
+        for doc_id in doc_ids {
+            if self.open_cache.contains(&doc_id) {
+                continue;
+            }
+            pruned += disk_writer.prune(&doc_id, retention).await?;
+        }
+        Ok(pruned)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, delta), fields(doc_id = %delta.doc_id), err)]
     pub async fn receive_local_delta(&self, delta: DocumentDelta) -> Result<DocumentDelta, FlowyError> {
+        let _ = self.user.check_read_permission()?;
         let editor = self.get_editor(&delta.doc_id).await?;
         let _ = editor.compose_local_delta(Bytes::from(delta.delta_json)).await?;
         let document_json = editor.document_json().await?;
@@ -97,8 +200,234 @@ impl DocumentController {
         Ok(())
     }
 
+    // Reads the revisions of the document from disk without opening it. The
+    // revisions that are still waiting for the next checkpoint of an opened
+    // document are not included.
+    pub fn read_revision_records<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<Vec<RevisionRecord>> {
+        let _ = self.user.check_read_permission()?;
+        let doc_id = doc_id.as_ref();
+        let user_id = self.user.user_id()?;
+        let pool = self.user.db_pool()?;
+        let cache = DocumentRevisionCache::new(
+            &user_id,
+            doc_id,
+            pool.clone(),
+            self.disk_writer(&pool),
+            self.clock.clone(),
+        );
+        cache.batch_get(doc_id)
+    }
+
+    // The revisions of the document re-stamped for the copy of it, numbered
+    // from 0 again so the copy is synced as a new document. They aren't
+    // signed, the signatures were made for the other document.
+    #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
+    pub async fn copy_revisions<T: AsRef<str>>(&self, doc_id: T, to_doc_id: &str) -> FlowyResult<Vec<Revision>> {
+        let doc_id = doc_id.as_ref();
+        tracing::Span::current().record("doc_id", &doc_id);
+        if let Some(editor) = self.open_cache.get(doc_id) {
+            let _ = editor.rev_manager().flush().await?;
+        }
+        let pool = self.user.db_pool()?;
+        let _ = self.disk_writer(&pool).flush().await?;
+
+        let revisions = self
+            .read_revision_records(doc_id)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, record)| {
+                let revision = record.revision;
+                let rev_id = i as i64;
+                Revision::new(
+                    to_doc_id,
+                    (rev_id - 1).max(0),
+                    rev_id,
+                    Bytes::from(revision.delta_data),
+                    &revision.user_id,
+                    revision.md5,
+                )
+            })
+            .collect();
+        Ok(revisions)
+    }
+
+    // Writes the revisions of the document to a file as they are on the disk,
+    // broken ones included, so they can be replayed with `RevisionChain::replay`
+    // on another machine.
+    #[tracing::instrument(level = "debug", skip(self, doc_id, path), fields(doc_id), err)]
+    pub async fn export_revision_chain<T: AsRef<str>>(&self, doc_id: T, path: &Path) -> FlowyResult<RevisionChain> {
+        let doc_id = doc_id.as_ref();
+        tracing::Span::current().record("doc_id", &doc_id);
+        let _ = self.user.check_read_permission()?;
+        if let Some(editor) = self.open_cache.get(doc_id) {
+            let _ = editor.rev_manager().flush().await?;
+        }
+        let pool = self.user.db_pool()?;
+        let _ = self.disk_writer(&pool).flush().await?;
+
+        let chain = RevisionChain::read(doc_id, &*pool.get().map_err(internal_error)?)?;
+        let _ = chain.write_to_file(path)?;
+        Ok(chain)
+    }
+
+    // The revisions that were taken out of the document when it was loaded,
+    // the oldest first.
+    pub fn read_quarantined_revisions<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<Vec<QuarantinedRevision>> {
+        let _ = self.user.check_read_permission()?;
+        let pool = self.user.db_pool()?;
+        RevisionQuarantineTableSql::read_all(doc_id.as_ref(), &*pool.get().map_err(internal_error)?)
+    }
+
+    // Writes the quarantined revisions of the document to a file with their
+    // bytes as they were on the disk, for support.
+    #[tracing::instrument(level = "debug", skip(self, doc_id, path), fields(doc_id), err)]
+    pub fn export_quarantined_revisions<T: AsRef<str>>(&self, doc_id: T, path: &Path) -> FlowyResult<QuarantineReport> {
+        let doc_id = doc_id.as_ref();
+        tracing::Span::current().record("doc_id", &doc_id);
+        let revisions = self.read_quarantined_revisions(doc_id)?;
+        let report = QuarantineReport::new(doc_id, self.clock.timestamp(), revisions);
+        let _ = report.write_to_file(path)?;
+        Ok(report)
+    }
+
+    // Pins the latest revision of the document under the name. The revisions
+    // of the opened editor are saved first, so it's the one the user sees.
+    #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
+    pub async fn create_checkpoint<T: AsRef<str>>(
+        &self,
+        doc_id: T,
+        id: String,
+        name: String,
+    ) -> FlowyResult<RevisionCheckpoint> {
+        let doc_id = doc_id.as_ref();
+        tracing::Span::current().record("doc_id", &doc_id);
+        let pool = self.flush_document(doc_id).await?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let table = create_checkpoint(doc_id, id, name, self.clock.timestamp(), conn)?;
+        Ok(table.into())
+    }
+
+    pub fn read_checkpoints<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<Vec<RevisionCheckpoint>> {
+        let pool = self.user.db_pool()?;
+        let tables = RevisionCheckpointTableSql::read_all(doc_id.as_ref(), &*pool.get().map_err(internal_error)?)?;
+        Ok(tables.into_iter().map(|table| table.into()).collect())
+    }
+
+    pub fn delete_checkpoint<T: AsRef<str>>(&self, doc_id: T, id: &str) -> FlowyResult<()> {
+        let pool = self.user.db_pool()?;
+        RevisionCheckpointTableSql::delete(doc_id.as_ref(), id, &*pool.get().map_err(internal_error)?)
+    }
+
+    // The document is restored with a new local revision that replaces its
+    // content with the one at the checkpoint, so the history after the
+    // checkpoint is kept and the restore syncs like any other edit. Returns
+    // the restored document.
+    #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
+    pub async fn restore_checkpoint<T: AsRef<str>>(&self, doc_id: T, id: &str) -> FlowyResult<DocumentDelta> {
+        let doc_id = doc_id.as_ref();
+        tracing::Span::current().record("doc_id", &doc_id);
+        let pool = self.flush_document(doc_id).await?;
+        let checkpoint_delta = {
+            let conn = &*pool.get().map_err(internal_error)?;
+            let table = RevisionCheckpointTableSql::read(doc_id, id, conn)?;
+            read_checkpoint_delta(&table, conn)?
+        };
+
+        let editor = self.open_document(doc_id).await?;
+        let current_delta = editor.doc_delta().await?;
+        let mut delta = checkpoint_delta;
+        delta.delete(current_delta.utf16_target_len);
+        let _ = editor.compose_local_delta(delta.to_bytes()).await?;
+        Ok(DocumentDelta {
+            doc_id: doc_id.to_owned(),
+            delta_json: editor.document_json().await?,
+        })
+    }
+
+    // Highlights the code block at the index. The offsets of the tokens are in
+    // the document.
+    pub async fn read_code_block_tokens<T: AsRef<str>>(
+        &self,
+        doc_id: T,
+        index: usize,
+    ) -> FlowyResult<(CodeBlock, Vec<SyntaxToken>)> {
+        let editor = self.open_document(doc_id).await?;
+        let code_block = editor
+            .code_block(index)
+            .await?
+            .ok_or_else(FlowyError::code_block_not_found)?;
+        let tokens = highlight_code(&code_block.text, &code_block.language)
+            .into_iter()
+            .map(|token| SyntaxToken {
+                start: code_block.start + token.start,
+                ..token
+            })
+            .collect();
+        Ok((code_block, tokens))
+    }
+
+    // The ids of the toggles that the user collapsed in the document. The ones
+    // that were removed from the document since are left out.
+    pub async fn read_collapsed_toggles<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<Vec<String>> {
+        let doc_id = doc_id.as_ref();
+        let editor = self.open_document(doc_id).await?;
+        let toggles = editor.read_toggles().await?;
+        let pool = self.user.db_pool()?;
+        let toggle_ids = CollapsedToggleTableSql::read(doc_id, &*pool.get().map_err(internal_error)?)?;
+        Ok(toggle_ids
+            .into_iter()
+            .filter(|toggle_id| toggles.iter().any(|toggle| &toggle.id == toggle_id))
+            .collect())
+    }
+
+    pub fn set_toggle_collapsed<T: AsRef<str>>(&self, doc_id: T, toggle_id: &str, collapsed: bool) -> FlowyResult<()> {
+        let pool = self.user.db_pool()?;
+        CollapsedToggleTableSql::set(
+            doc_id.as_ref(),
+            toggle_id,
+            collapsed,
+            &*pool.get().map_err(internal_error)?,
+        )
+    }
+
+    // Makes the revisions of the document that are in memory or queued readable
+    // from the disk.
+    async fn flush_document(&self, doc_id: &str) -> FlowyResult<Arc<ConnectionPool>> {
+        let _ = self.user.check_read_permission()?;
+        if let Some(editor) = self.open_cache.get(doc_id) {
+            let _ = editor.rev_manager().flush().await?;
+        }
+        let pool = self.user.db_pool()?;
+        let _ = self.disk_writer(&pool).flush().await?;
+        Ok(pool)
+    }
+
+    // Saves the revisions of the opened documents and waits for the queued
+    // writes, it should be called before the app exits.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn flush(&self) -> FlowyResult<()> {
+        for doc_id in self.open_cache.doc_ids() {
+            if let Some(editor) = self.open_cache.get(&doc_id) {
+                let _ = editor.rev_manager().flush().await?;
+            }
+        }
+
+        let disk_writer = self.disk_writer.read().clone();
+        if let Some(disk_writer) = disk_writer {
+            let _ = disk_writer.flush().await?;
+        }
+        Ok(())
+    }
+
     async fn get_editor(&self, doc_id: &str) -> FlowyResult<Arc<ClientDocumentEditor>> {
-        match self.open_cache.get(doc_id) {
+        if let Some(editor) = self.open_cache.touch(doc_id, self.clock.timestamp()) {
+            return Ok(editor);
+        }
+
+        // A document that is opened twice at the same time, e.g. by the preload
+        // at the launch and by the user, gets only one editor.
+        let _open_guard = self.open_lock.lock().await;
+        match self.open_cache.touch(doc_id, self.clock.timestamp()) {
             None => {
                 let db_pool = self.user.db_pool()?;
                 self.make_editor(&doc_id, db_pool).await
@@ -121,17 +450,46 @@ impl DocumentController {
             token,
             server: self.server.clone(),
         });
-        let doc_editor = ClientDocumentEditor::new(doc_id, user, rev_manager, self.ws_sender.clone(), server).await?;
+        let doc_editor = ClientDocumentEditor::new(
+            doc_id,
+            user,
+            rev_manager,
+            self.ws_sender.clone(),
+            server,
+            self.signer.clone(),
+        )
+        .await?;
         self.ws_receivers.add(doc_id, doc_editor.ws_handler());
-        self.open_cache.insert(&doc_id, &doc_editor);
+        self.open_cache.insert(&doc_id, &doc_editor, self.clock.timestamp());
         Ok(doc_editor)
     }
 
     fn make_rev_manager(&self, doc_id: &str, pool: Arc<ConnectionPool>) -> Result<DocumentRevisionManager, FlowyError> {
         let user_id = self.user.user_id()?;
-        let cache = Arc::new(DocumentRevisionCache::new(&user_id, doc_id, pool));
+        let disk_writer = self.disk_writer(&pool);
+        let cache = Arc::new(DocumentRevisionCache::new(
+            &user_id,
+            doc_id,
+            pool,
+            disk_writer,
+            self.clock.clone(),
+        ));
         Ok(DocumentRevisionManager::new(&user_id, doc_id, cache))
     }
+
+    // All the documents of the user share one writer. The writer of the
+    // previous user stops once its documents are dropped.
+    fn disk_writer(&self, pool: &Arc<ConnectionPool>) -> Arc<RevisionDiskWriter> {
+        let mut disk_writer = self.disk_writer.write();
+        match &*disk_writer {
+            Some(writer) if writer.is_writing_to(pool) => writer.clone(),
+            _ => {
+                let writer = Arc::new(RevisionDiskWriter::new(pool.clone(), self.clock.clone()));
+                *disk_writer = Some(writer.clone());
+                writer
+            },
+        }
+    }
 }
 
 struct RevisionServerImpl {
@@ -150,7 +508,7 @@ impl RevisionServer for RevisionServerImpl {
 
         FutureResult::new(async move {
             match server.read_doc(&token, params).await? {
-                None => Err(FlowyError::record_not_found().context("Remote doesn't have this document")),
+                None => Err(FlowyError::document_not_found().context("Remote doesn't have this document")),
                 Some(doc) => Ok(doc),
             }
         })
@@ -158,27 +516,69 @@ impl RevisionServer for RevisionServerImpl {
 }
 
 pub struct OpenDocCache {
-    inner: DashMap<String, Arc<ClientDocumentEditor>>,
+    inner: DashMap<String, OpenedDoc>,
+}
+
+struct OpenedDoc {
+    editor: Arc<ClientDocumentEditor>,
+    accessed_at: i64,
 }
 
 impl OpenDocCache {
     fn new() -> Self { Self { inner: DashMap::new() } }
 
-    pub(crate) fn insert(&self, doc_id: &str, doc: &Arc<ClientDocumentEditor>) {
+    pub(crate) fn insert(&self, doc_id: &str, doc: &Arc<ClientDocumentEditor>, accessed_at: i64) {
         if self.inner.contains_key(doc_id) {
             log::warn!("Doc:{} already exists in cache", doc_id);
         }
-        self.inner.insert(doc_id.to_string(), doc.clone());
+        let opened_doc = OpenedDoc {
+            editor: doc.clone(),
+            accessed_at,
+        };
+        self.inner.insert(doc_id.to_string(), opened_doc);
+    }
+
+    // Returns the editor and marks it as used, so it isn't closed as idle.
+    pub(crate) fn touch(&self, doc_id: &str, accessed_at: i64) -> Option<Arc<ClientDocumentEditor>> {
+        let mut opened_doc = self.inner.get_mut(doc_id)?;
+        opened_doc.accessed_at = accessed_at;
+        Some(opened_doc.editor.clone())
+    }
+
+    pub(crate) fn idle_doc_ids(&self, idle_since: i64) -> Vec<String> {
+        self.inner
+            .iter()
+            .filter(|entry| entry.value().accessed_at <= idle_since)
+            .map(|entry| entry.key().clone())
+            .collect()
+    }
+
+    // Removes the editor unless it was used after `idle_since`. The check and
+    // the removal hold the same lock, so an editor that is returned by `touch`
+    // is never removed as idle.
+    pub(crate) fn remove_if_idle(&self, doc_id: &str, idle_since: i64) -> bool {
+        match self
+            .inner
+            .remove_if(doc_id, |_, opened_doc| opened_doc.accessed_at <= idle_since)
+        {
+            None => false,
+            Some((_, opened_doc)) => {
+                opened_doc.editor.stop();
+                true
+            },
+        }
     }
 
     pub(crate) fn contains(&self, doc_id: &str) -> bool { self.inner.get(doc_id).is_some() }
 
+    pub(crate) fn doc_ids(&self) -> Vec<String> { self.inner.iter().map(|entry| entry.key().clone()).collect() }
+
     pub(crate) fn get(&self, doc_id: &str) -> Option<Arc<ClientDocumentEditor>> {
         if !self.contains(&doc_id) {
             return None;
         }
         let opened_doc = self.inner.get(doc_id).unwrap();
-        Some(opened_doc.clone())
+        Some(opened_doc.editor.clone())
     }
 
     pub(crate) fn remove(&self, id: &str) {
@@ -190,6 +590,39 @@ impl OpenDocCache {
     }
 }
 
+// Flushes the revisions of the idle editors before they are closed. The loop
+// stops once the controller is dropped.
+fn close_idle_editors_periodically(
+    weak_open_cache: Weak<OpenDocCache>,
+    ws_receivers: Arc<DocumentWSReceivers>,
+    clock: Arc<dyn Clock>,
+) {
+    tokio::spawn(async move {
+        loop {
+            clock.sleep(IDLE_EDITOR_CHECK_INTERVAL).await;
+            let open_cache = match weak_open_cache.upgrade() {
+                None => break,
+                Some(open_cache) => open_cache,
+            };
+            let idle_since = clock.timestamp() - IDLE_EDITOR_TIMEOUT.as_secs() as i64;
+            for doc_id in open_cache.idle_doc_ids(idle_since) {
+                let editor = match open_cache.get(&doc_id) {
+                    None => continue,
+                    Some(editor) => editor,
+                };
+                if let Err(e) = editor.rev_manager().flush().await {
+                    tracing::error!("Flush the idle document {} failed: {:?}", doc_id, e);
+                    continue;
+                }
+                if open_cache.remove_if_idle(&doc_id, idle_since) {
+                    tracing::debug!("Closed the idle document {}", doc_id);
+                    ws_receivers.remove(&doc_id);
+                }
+            }
+        }
+    });
+}
+
 #[tracing::instrument(level = "debug", skip(state_receiver, receivers))]
 fn listen_ws_state_changed(mut state_receiver: WSStateReceiver, receivers: Arc<DocumentWSReceivers>) {
     tokio::spawn(async move {
@@ -7,8 +7,12 @@ use crate::{
     errors::FlowyError,
 };
 use bytes::Bytes;
-use flowy_collaboration::errors::CollaborateResult;
+use flowy_collaboration::{
+    document::{CodeBlock, DocumentEmbed, DocumentEquation, DocumentTable, DocumentToggle, EquationEdit, TableEdit},
+    errors::CollaborateResult,
+};
 use flowy_error::{internal_error, FlowyResult};
+use lib_infra::uuid_string;
 use lib_ot::{
     core::Interval,
     rich_text::{RichTextAttribute, RichTextDelta},
@@ -18,7 +22,6 @@ use tokio::sync::{mpsc, mpsc::UnboundedSender, oneshot};
 
 pub struct ClientDocumentEditor {
     pub doc_id: String,
-    #[allow(dead_code)]
     rev_manager: Arc<DocumentRevisionManager>,
     ws_manager: Arc<dyn DocumentWebSocketManager>,
     edit_queue: UnboundedSender<EditorCommand>,
@@ -31,6 +34,7 @@ impl ClientDocumentEditor {
         mut rev_manager: DocumentRevisionManager,
         ws: Arc<dyn DocumentWebSocket>,
         server: Arc<dyn RevisionServer>,
+        signer: Option<Arc<RevisionSigner>>,
     ) -> FlowyResult<Arc<Self>> {
         let delta = rev_manager.load_document(server).await?;
         let rev_manager = Arc::new(rev_manager);
@@ -44,6 +48,7 @@ impl ClientDocumentEditor {
             edit_queue.clone(),
             rev_manager.clone(),
             ws,
+            signer,
         )
         .await;
         let editor = Arc::new(Self {
@@ -99,6 +104,159 @@ impl ClientDocumentEditor {
         Ok(())
     }
 
+    // Inserts an empty table at the index and returns its id. The table starts
+    // on a new line if the index is in the middle of a line.
+    pub async fn insert_table(&self, index: usize, rows: usize, columns: usize) -> FlowyResult<String> {
+        let table_id = uuid_string();
+        let edit = TableEdit::InsertTable {
+            index,
+            table_id: table_id.clone(),
+            row_ids: (0..rows).map(|_| uuid_string()).collect(),
+            column_ids: (0..columns).map(|_| uuid_string()).collect(),
+        };
+        let _ = self.edit_table(edit).await?;
+        Ok(table_id)
+    }
+
+    // The row is appended if the index is past the last row. Returns the id of
+    // the row.
+    pub async fn insert_table_row(&self, table_id: &str, row_index: usize) -> FlowyResult<String> {
+        let row_id = uuid_string();
+        let edit = TableEdit::InsertRow {
+            table_id: table_id.to_owned(),
+            row_index,
+            row_id: row_id.clone(),
+        };
+        let _ = self.edit_table(edit).await?;
+        Ok(row_id)
+    }
+
+    pub async fn delete_table_row(&self, table_id: &str, row_id: &str) -> FlowyResult<()> {
+        let edit = TableEdit::DeleteRow {
+            table_id: table_id.to_owned(),
+            row_id: row_id.to_owned(),
+        };
+        self.edit_table(edit).await
+    }
+
+    pub async fn insert_table_column(&self, table_id: &str, column_index: usize) -> FlowyResult<String> {
+        let column_id = uuid_string();
+        let edit = TableEdit::InsertColumn {
+            table_id: table_id.to_owned(),
+            column_index,
+            column_id: column_id.clone(),
+        };
+        let _ = self.edit_table(edit).await?;
+        Ok(column_id)
+    }
+
+    pub async fn delete_table_column(&self, table_id: &str, column_id: &str) -> FlowyResult<()> {
+        let edit = TableEdit::DeleteColumn {
+            table_id: table_id.to_owned(),
+            column_id: column_id.to_owned(),
+        };
+        self.edit_table(edit).await
+    }
+
+    pub async fn read_tables(&self) -> FlowyResult<Vec<DocumentTable>> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<Vec<DocumentTable>>>();
+        let msg = EditorCommand::ReadTables { ret };
+        let _ = self.edit_queue.send(msg);
+        let tables = rx.await.map_err(internal_error)??;
+        Ok(tables)
+    }
+
+    pub async fn insert_equation(&self, index: usize, latex: &str) -> FlowyResult<()> {
+        let latex = latex.to_owned();
+        self.edit_equation(EquationEdit::Insert { index, latex }).await
+    }
+
+    pub async fn update_equation(&self, index: usize, latex: &str) -> FlowyResult<()> {
+        let latex = latex.to_owned();
+        self.edit_equation(EquationEdit::Update { index, latex }).await
+    }
+
+    pub async fn read_equations(&self) -> FlowyResult<Vec<DocumentEquation>> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<Vec<DocumentEquation>>>();
+        let msg = EditorCommand::ReadEquations { ret };
+        let _ = self.edit_queue.send(msg);
+        let equations = rx.await.map_err(internal_error)??;
+        Ok(equations)
+    }
+
+    // Turns the link in the interval into an embed, or inserts the embed if
+    // the interval is empty.
+    pub async fn embed_link(&self, interval: Interval, url: &str) -> FlowyResult<()> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<()>>();
+        let msg = EditorCommand::EmbedLink {
+            interval,
+            url: url.to_owned(),
+            ret,
+        };
+        let _ = self.edit_queue.send(msg);
+        let _ = rx.await.map_err(internal_error)??;
+        Ok(())
+    }
+
+    pub async fn read_embeds(&self) -> FlowyResult<Vec<DocumentEmbed>> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<Vec<DocumentEmbed>>>();
+        let msg = EditorCommand::ReadEmbeds { ret };
+        let _ = self.edit_queue.send(msg);
+        let embeds = rx.await.map_err(internal_error)??;
+        Ok(embeds)
+    }
+
+    // Makes the lines of the interval a callout, an empty icon turns them back
+    // into plain lines.
+    pub async fn format_callout(&self, interval: Interval, icon: &str, color: Option<String>) -> FlowyResult<()> {
+        let _ = self.format(interval, RichTextAttribute::Callout(icon)).await?;
+        if !icon.is_empty() {
+            let color = RichTextAttribute::CalloutColor(color.unwrap_or_default());
+            let _ = self.format(interval, color).await?;
+        }
+        Ok(())
+    }
+
+    // Makes the lines of the interval a toggle whose summary is the first
+    // line. Returns the id of the toggle.
+    pub async fn insert_toggle(&self, interval: Interval) -> FlowyResult<String> {
+        let toggle_id = uuid_string();
+        let _ = self.format(interval, RichTextAttribute::Toggle(&toggle_id)).await?;
+        Ok(toggle_id)
+    }
+
+    pub async fn read_toggles(&self) -> FlowyResult<Vec<DocumentToggle>> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<Vec<DocumentToggle>>>();
+        let msg = EditorCommand::ReadToggles { ret };
+        let _ = self.edit_queue.send(msg);
+        let toggles = rx.await.map_err(internal_error)??;
+        Ok(toggles)
+    }
+
+    pub async fn code_block(&self, index: usize) -> FlowyResult<Option<CodeBlock>> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<Option<CodeBlock>>>();
+        let msg = EditorCommand::ReadCodeBlock { index, ret };
+        let _ = self.edit_queue.send(msg);
+        let code_block = rx.await.map_err(internal_error)??;
+        Ok(code_block)
+    }
+
+    async fn edit_table(&self, edit: TableEdit) -> FlowyResult<()> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<()>>();
+        let msg = EditorCommand::EditTable { edit, ret };
+        let _ = self.edit_queue.send(msg);
+        let _ = rx.await.map_err(internal_error)??;
+        Ok(())
+    }
+
+    async fn edit_equation(&self, edit: EquationEdit) -> FlowyResult<()> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<()>>();
+        let msg = EditorCommand::EditEquation { edit, ret };
+        let _ = self.edit_queue.send(msg);
+        let _ = rx.await.map_err(internal_error)??;
+        Ok(())
+    }
+
     pub async fn can_undo(&self) -> bool {
         let (ret, rx) = oneshot::channel::<bool>();
         let msg = EditorCommand::CanUndo { ret };
@@ -137,7 +295,31 @@ impl ClientDocumentEditor {
         Ok(json)
     }
 
-    #[tracing::instrument(level = "debug", skip(self, data), err)]
+    pub async fn doc_delta(&self) -> FlowyResult<RichTextDelta> {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<RichTextDelta>>();
+        let msg = EditorCommand::ReadDocDelta { ret };
+        let _ = self.edit_queue.send(msg);
+        let delta = rx.await.map_err(internal_error)??;
+        Ok(delta)
+    }
+
+    // Returns the content of the document without the attributes.
+    pub async fn document_text(&self) -> FlowyResult<String> {
+        let json = self.document_json().await?;
+        let delta = RichTextDelta::from_json(&json)?;
+        let text = delta.apply("")?;
+        Ok(text)
+    }
+
+    // Replaces the whole content of the document with the text in one
+    // revision. The trailing newline of the document is kept.
+    pub async fn reset_text<T: ToString>(&self, text: T) -> FlowyResult<()> {
+        let len = self.document_text().await?.encode_utf16().count();
+        let _ = self.replace(Interval::new(0, len.saturating_sub(1)), text).await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, data), fields(doc_id = %self.doc_id), err)]
     pub(crate) async fn compose_local_delta(&self, data: Bytes) -> Result<(), FlowyError> {
         let delta = RichTextDelta::from_bytes(&data)?;
         let (ret, rx) = oneshot::channel::<CollaborateResult<()>>();
@@ -153,6 +335,8 @@ impl ClientDocumentEditor {
     #[tracing::instrument(level = "debug", skip(self))]
     pub fn stop(&self) { self.ws_manager.stop(); }
 
+    pub fn rev_manager(&self) -> Arc<DocumentRevisionManager> { self.rev_manager.clone() }
+
     pub(crate) fn ws_handler(&self) -> Arc<dyn DocumentWSReceiver> { self.ws_manager.receiver() }
 }
 
@@ -176,14 +360,4 @@ impl ClientDocumentEditor {
         let s = rx.await.map_err(internal_error)??;
         Ok(s)
     }
-
-    pub async fn doc_delta(&self) -> FlowyResult<RichTextDelta> {
-        let (ret, rx) = oneshot::channel::<CollaborateResult<RichTextDelta>>();
-        let msg = EditorCommand::ReadDocDelta { ret };
-        let _ = self.edit_queue.send(msg);
-        let delta = rx.await.map_err(internal_error)??;
-        Ok(delta)
-    }
-
-    pub fn rev_manager(&self) -> Arc<DocumentRevisionManager> { self.rev_manager.clone() }
 }
@@ -1,7 +1,18 @@
 use crate::{context::DocumentUser, core::DocumentRevisionManager};
 use async_stream::stream;
 use flowy_collaboration::{
-    document::{history::UndoResult, Document, NewlineDoc},
+    document::{
+        history::UndoResult,
+        CodeBlock,
+        Document,
+        DocumentEmbed,
+        DocumentEquation,
+        DocumentTable,
+        DocumentToggle,
+        EquationEdit,
+        NewlineDoc,
+        TableEdit,
+    },
     entities::revision::{RepeatedRevision, RevId, Revision},
     errors::CollaborateError,
     util::make_delta_from_revisions,
@@ -163,6 +174,58 @@ impl EditorCommandQueue {
                 let _ = self.save_local_delta(delta, md5).await?;
                 let _ = ret.send(Ok(()));
             },
+            EditorCommand::EditTable { edit, ret } => {
+                let mut write_guard = self.document.write().await;
+                let delta = write_guard.edit_table(edit)?;
+                if !delta.is_empty() {
+                    let md5 = write_guard.md5();
+                    let _ = self.save_local_delta(delta, md5).await?;
+                }
+                let _ = ret.send(Ok(()));
+            },
+            EditorCommand::ReadTables { ret } => {
+                let _ = ret.send(Ok(self.document.read().await.tables()));
+            },
+            EditorCommand::EditEquation { edit, ret } => {
+                // The invalid LaTeX is sent back, so the caller gets why it
+                // was rejected instead of a closed channel.
+                let mut write_guard = self.document.write().await;
+                match write_guard.edit_equation(edit) {
+                    Ok(delta) => {
+                        let md5 = write_guard.md5();
+                        let _ = self.save_local_delta(delta, md5).await?;
+                        let _ = ret.send(Ok(()));
+                    },
+                    Err(e) => {
+                        let _ = ret.send(Err(e));
+                    },
+                }
+            },
+            EditorCommand::ReadEquations { ret } => {
+                let _ = ret.send(Ok(self.document.read().await.equations()));
+            },
+            EditorCommand::EmbedLink { interval, url, ret } => {
+                let mut write_guard = self.document.write().await;
+                match write_guard.embed_link(interval, &url) {
+                    Ok(delta) => {
+                        let md5 = write_guard.md5();
+                        let _ = self.save_local_delta(delta, md5).await?;
+                        let _ = ret.send(Ok(()));
+                    },
+                    Err(e) => {
+                        let _ = ret.send(Err(e));
+                    },
+                }
+            },
+            EditorCommand::ReadEmbeds { ret } => {
+                let _ = ret.send(Ok(self.document.read().await.embeds()));
+            },
+            EditorCommand::ReadToggles { ret } => {
+                let _ = ret.send(Ok(self.document.read().await.toggles()));
+            },
+            EditorCommand::ReadCodeBlock { index, ret } => {
+                let _ = ret.send(Ok(self.document.read().await.code_block(index)));
+            },
             EditorCommand::CanUndo { ret } => {
                 let _ = ret.send(self.document.read().await.can_undo());
             },
@@ -274,6 +337,35 @@ pub(crate) enum EditorCommand {
         data: String,
         ret: Ret<()>,
     },
+    EditTable {
+        edit: TableEdit,
+        ret: Ret<()>,
+    },
+    ReadTables {
+        ret: Ret<Vec<DocumentTable>>,
+    },
+    EditEquation {
+        edit: EquationEdit,
+        ret: Ret<()>,
+    },
+    ReadEquations {
+        ret: Ret<Vec<DocumentEquation>>,
+    },
+    EmbedLink {
+        interval: Interval,
+        url: String,
+        ret: Ret<()>,
+    },
+    ReadEmbeds {
+        ret: Ret<Vec<DocumentEmbed>>,
+    },
+    ReadToggles {
+        ret: Ret<Vec<DocumentToggle>>,
+    },
+    ReadCodeBlock {
+        index: usize,
+        ret: Ret<Option<CodeBlock>>,
+    },
     CanUndo {
         ret: oneshot::Sender<bool>,
     },
@@ -289,7 +381,6 @@ pub(crate) enum EditorCommand {
     ReadDoc {
         ret: Ret<String>,
     },
-    #[allow(dead_code)]
     ReadDocDelta {
         ret: Ret<RichTextDelta>,
     },
@@ -306,6 +397,14 @@ impl std::fmt::Debug for EditorCommand {
             EditorCommand::Delete { .. } => "Delete",
             EditorCommand::Format { .. } => "Format",
             EditorCommand::Replace { .. } => "Replace",
+            EditorCommand::EditTable { .. } => "EditTable",
+            EditorCommand::ReadTables { .. } => "ReadTables",
+            EditorCommand::EditEquation { .. } => "EditEquation",
+            EditorCommand::ReadEquations { .. } => "ReadEquations",
+            EditorCommand::EmbedLink { .. } => "EmbedLink",
+            EditorCommand::ReadEmbeds { .. } => "ReadEmbeds",
+            EditorCommand::ReadToggles { .. } => "ReadToggles",
+            EditorCommand::ReadCodeBlock { .. } => "ReadCodeBlock",
             EditorCommand::CanUndo { .. } => "CanUndo",
             EditorCommand::CanRedo { .. } => "CanRedo",
             EditorCommand::Undo { .. } => "Undo",
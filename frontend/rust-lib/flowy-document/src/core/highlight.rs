@@ -0,0 +1,58 @@
+use lazy_static::lazy_static;
+use lib_ot::core::count_utf16_code_units;
+use syntect::{
+    easy::ScopeRangeIterator,
+    parsing::{ParseState, ScopeStack, SyntaxSet},
+    util::LinesWithEndings,
+};
+
+lazy_static! {
+    static ref SYNTAX_SET: SyntaxSet = SyntaxSet::load_defaults_newlines();
+}
+
+// The offsets are in utf16 code units from the start of the code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxToken {
+    pub start: usize,
+    pub len: usize,
+    pub scope: String,
+}
+
+/// Splits the code into the ranges that are highlighted as one, with the
+/// innermost TextMate scope of each, so all the frontends highlight the same
+/// way with the colors of their theme. The language is matched against the
+/// names and the file extensions of the bundled syntaxes, the code of an
+/// unknown language has no tokens.
+pub fn highlight_code(code: &str, language: &str) -> Vec<SyntaxToken> {
+    let syntax = match SYNTAX_SET.find_syntax_by_token(language.trim()) {
+        None => return vec![],
+        Some(syntax) => syntax,
+    };
+
+    let mut state = ParseState::new(syntax);
+    let mut stack = ScopeStack::new();
+    let mut tokens: Vec<SyntaxToken> = vec![];
+    let mut offset = 0;
+    for line in LinesWithEndings::from(code) {
+        let ops = state.parse_line(line, &SYNTAX_SET);
+        for (range, op) in ScopeRangeIterator::new(&ops, line) {
+            stack.apply(op);
+            if range.is_empty() {
+                continue;
+            }
+            let start = offset + count_utf16_code_units(&line[..range.start]);
+            let len = count_utf16_code_units(&line[range]);
+            let scope = stack
+                .as_slice()
+                .last()
+                .map(|scope| scope.build_string())
+                .unwrap_or_default();
+            match tokens.last_mut() {
+                Some(token) if token.scope == scope && token.start + token.len == start => token.len += len,
+                _ => tokens.push(SyntaxToken { start, len, scope }),
+            }
+        }
+        offset += count_utf16_code_units(line);
+    }
+    tokens
+}
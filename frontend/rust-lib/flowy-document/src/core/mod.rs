@@ -1,9 +1,13 @@
 pub mod edit;
+mod highlight;
 pub mod revision;
+mod toggle;
 mod web_socket;
 
 pub use crate::ws_receivers::*;
 pub use edit::*;
+pub use highlight::*;
 pub use revision::*;
+pub(crate) use toggle::CollapsedToggleTableSql;
 
 pub const SYNC_INTERVAL_IN_MILLIS: u64 = 1000;
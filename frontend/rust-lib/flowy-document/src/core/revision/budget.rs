@@ -0,0 +1,167 @@
+use crate::core::RevisionRecord;
+use dashmap::DashMap;
+use lazy_static::lazy_static;
+use lib_infra::metrics;
+use parking_lot::Mutex;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+        Weak,
+    },
+};
+
+const DEFAULT_CAPACITY: usize = 32 * 1024 * 1024;
+
+lazy_static! {
+    static ref MEMORY_BUDGET: RevisionMemoryBudget = RevisionMemoryBudget::new(DEFAULT_CAPACITY);
+}
+
+static NEXT_CACHE_ID: AtomicU64 = AtomicU64::new(1);
+
+pub(crate) type RevisionMap = DashMap<i64, RevisionRecord>;
+
+/// Sets how many bytes of revisions are kept in memory for all the opened
+/// documents. It's 32MB by default.
+pub fn set_revision_cache_capacity(capacity: usize) {
+    MEMORY_BUDGET.capacity.store(capacity, Ordering::SeqCst);
+    MEMORY_BUDGET.state.lock().evict(capacity);
+}
+
+// Every record in the memory caches is counted, but only the records that
+// were acked and saved to the disk are evicted. They are read from the disk
+// again when they are needed. The records that are not synced yet stay in
+// memory even if the budget is exceeded.
+pub(crate) struct RevisionMemoryBudget {
+    capacity: AtomicUsize,
+    state: Mutex<BudgetState>,
+}
+
+impl RevisionMemoryBudget {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: AtomicUsize::new(capacity),
+            state: Mutex::new(BudgetState::default()),
+        }
+    }
+
+    pub(crate) fn shared() -> &'static RevisionMemoryBudget { &MEMORY_BUDGET }
+
+    pub(crate) fn register(&self, revs_map: &Arc<RevisionMap>) -> u64 {
+        let cache_id = NEXT_CACHE_ID.fetch_add(1, Ordering::SeqCst);
+        self.state.lock().caches.insert(cache_id, Arc::downgrade(revs_map));
+        cache_id
+    }
+
+    pub(crate) fn unregister(&self, cache_id: u64) {
+        let mut state = self.state.lock();
+        state.forget(cache_id);
+        state.caches.remove(&cache_id);
+    }
+
+    pub(crate) fn add(&self, cache_id: u64, record: &RevisionRecord) {
+        let mut state = self.state.lock();
+        let key = (cache_id, record.revision.rev_id);
+        if state.entries.contains_key(&key) {
+            return;
+        }
+        let size = record_size(record);
+        state.entries.insert(key, BudgetEntry { size, tick: None });
+        state.used += size;
+        state.evict(self.capacity.load(Ordering::SeqCst));
+    }
+
+    pub(crate) fn mark_evictable(&self, cache_id: u64, rev_id: i64) {
+        let mut state = self.state.lock();
+        let tick = state.next_tick();
+        match state.entries.get_mut(&(cache_id, rev_id)) {
+            Some(entry) if entry.tick.is_none() => entry.tick = Some(tick),
+            _ => return,
+        }
+        state.lru.insert(tick, (cache_id, rev_id));
+        state.evict(self.capacity.load(Ordering::SeqCst));
+    }
+
+    pub(crate) fn touch(&self, cache_id: u64, rev_id: i64) {
+        let mut state = self.state.lock();
+        let tick = state.next_tick();
+        let old_tick = match state.entries.get_mut(&(cache_id, rev_id)) {
+            Some(BudgetEntry {
+                tick: Some(old_tick), ..
+            }) => std::mem::replace(old_tick, tick),
+            _ => return,
+        };
+        state.lru.remove(&old_tick);
+        state.lru.insert(tick, (cache_id, rev_id));
+    }
+
+    // Forgets the records of the cache after they were cleared from it.
+    pub(crate) fn forget(&self, cache_id: u64) { self.state.lock().forget(cache_id); }
+}
+
+#[derive(Default)]
+struct BudgetState {
+    used: usize,
+    tick: u64,
+    entries: HashMap<(u64, i64), BudgetEntry>,
+    // The evictable records ordered from the least recently used.
+    lru: BTreeMap<u64, (u64, i64)>,
+    caches: HashMap<u64, Weak<RevisionMap>>,
+}
+
+struct BudgetEntry {
+    size: usize,
+    tick: Option<u64>,
+}
+
+impl BudgetState {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn forget(&mut self, cache_id: u64) {
+        let mut freed = 0;
+        let lru = &mut self.lru;
+        self.entries.retain(|(id, _), entry| {
+            if *id != cache_id {
+                return true;
+            }
+            if let Some(tick) = entry.tick {
+                lru.remove(&tick);
+            }
+            freed += entry.size;
+            false
+        });
+        self.used -= freed;
+        metrics::set_gauge(metrics::REVISION_CACHE_SIZE, self.used as i64);
+    }
+
+    fn evict(&mut self, capacity: usize) {
+        while self.used > capacity {
+            let (tick, (cache_id, rev_id)) = match self.lru.iter().next() {
+                None => break,
+                Some((tick, key)) => (*tick, *key),
+            };
+            self.lru.remove(&tick);
+            if let Some(entry) = self.entries.remove(&(cache_id, rev_id)) {
+                self.used -= entry.size;
+            }
+            if let Some(revs_map) = self.caches.get(&cache_id).and_then(|cache| cache.upgrade()) {
+                revs_map.remove(&rev_id);
+            }
+            metrics::increment_counter(metrics::REVISION_CACHE_EVICTION_COUNT);
+        }
+        metrics::set_gauge(metrics::REVISION_CACHE_SIZE, self.used as i64);
+    }
+}
+
+fn record_size(record: &RevisionRecord) -> usize {
+    let revision = &record.revision;
+    std::mem::size_of::<RevisionRecord>()
+        + revision.delta_data.len()
+        + revision.md5.len()
+        + revision.doc_id.len()
+        + revision.user_id.len()
+}
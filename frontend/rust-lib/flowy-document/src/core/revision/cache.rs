@@ -1,13 +1,15 @@
 use crate::{
     core::revision::{
-        disk::{DocumentRevisionDiskCache, RevisionChangeset, RevisionTableState, SQLitePersistence},
-        memory::{DocumentRevisionMemoryCache, RevisionMemoryCacheDelegate},
+        disk::{DocumentRevisionDiskCache, RevisionDiskWriter, SQLitePersistence},
+        memory::DocumentRevisionMemoryCache,
+        QuarantinedRevision,
     },
     errors::FlowyError,
 };
 use flowy_collaboration::entities::revision::{Revision, RevisionRange, RevisionState};
 use flowy_database::ConnectionPool;
 use flowy_error::{internal_error, FlowyResult};
+use lib_infra::clock::Clock;
 use std::{
     borrow::Cow,
     sync::{
@@ -21,18 +23,30 @@ pub struct DocumentRevisionCache {
     doc_id: String,
     disk_cache: Arc<dyn DocumentRevisionDiskCache<Error = FlowyError>>,
     memory_cache: Arc<DocumentRevisionMemoryCache>,
+    disk_writer: Arc<RevisionDiskWriter>,
     latest_rev_id: AtomicI64,
 }
 
 impl DocumentRevisionCache {
-    pub fn new(user_id: &str, doc_id: &str, pool: Arc<ConnectionPool>) -> DocumentRevisionCache {
+    pub(crate) fn new(
+        user_id: &str,
+        doc_id: &str,
+        pool: Arc<ConnectionPool>,
+        disk_writer: Arc<RevisionDiskWriter>,
+        clock: Arc<dyn Clock>,
+    ) -> DocumentRevisionCache {
         let disk_cache = Arc::new(SQLitePersistence::new(user_id, pool));
-        let memory_cache = Arc::new(DocumentRevisionMemoryCache::new(doc_id, Arc::new(disk_cache.clone())));
+        let memory_cache = Arc::new(DocumentRevisionMemoryCache::new(
+            doc_id,
+            Arc::new(disk_writer.clone()),
+            clock,
+        ));
         let doc_id = doc_id.to_owned();
         Self {
             doc_id,
             disk_cache,
             memory_cache,
+            disk_writer,
             latest_rev_id: AtomicI64::new(0),
         }
     }
@@ -44,7 +58,9 @@ impl DocumentRevisionCache {
         write_to_disk: bool,
     ) -> FlowyResult<RevisionRecord> {
         if self.memory_cache.contains(&revision.rev_id) {
-            return Err(FlowyError::internal().context(format!("Duplicate revision: {} {:?}", revision.rev_id, state)));
+            return Err(
+                FlowyError::revision_invalid().context(format!("Duplicate revision: {} {:?}", revision.rev_id, state))
+            );
         }
         let state = state.as_ref().clone();
         let rev_id = revision.rev_id;
@@ -121,41 +137,32 @@ impl DocumentRevisionCache {
             .collect::<Vec<_>>();
 
         let _ = self.memory_cache.reset_with_revisions(&revision_records).await?;
-        let _ = self.disk_cache.reset_document(doc_id, revision_records)?;
+        let _ = self.disk_writer.reset(doc_id, revision_records, vec![]).await?;
         Ok(())
     }
 
-    #[inline]
-    fn set_latest_rev_id(&self, rev_id: i64) {
-        let _ = self.latest_rev_id.fetch_update(SeqCst, SeqCst, |_e| Some(rev_id));
+    // Replaces the records on the disk with the ones that passed the
+    // verification when the document was loaded, and moves the others to the
+    // quarantine in the same transaction.
+    pub(crate) async fn repair(
+        &self,
+        verified_records: Vec<RevisionRecord>,
+        quarantined: Vec<QuarantinedRevision>,
+    ) -> FlowyResult<()> {
+        self.disk_writer
+            .reset(&self.doc_id, verified_records, quarantined)
+            .await
     }
-}
 
-impl RevisionMemoryCacheDelegate for Arc<SQLitePersistence> {
-    #[tracing::instrument(level = "trace", skip(self, records), fields(checkpoint_result), err)]
-    fn checkpoint_tick(&self, mut records: Vec<RevisionRecord>) -> FlowyResult<()> {
-        let conn = &*self.pool.get().map_err(internal_error)?;
-        records.retain(|record| record.write_to_disk);
-        if !records.is_empty() {
-            tracing::Span::current().record(
-                "checkpoint_result",
-                &format!("{} records were saved", records.len()).as_str(),
-            );
-            let _ = self.write_revision_records(records, &conn)?;
-        }
-        Ok(())
+    // Returns after the revisions that were added before are saved to the disk.
+    pub async fn flush(&self) -> FlowyResult<()> {
+        self.memory_cache.flush().await;
+        self.disk_writer.flush().await
     }
 
-    fn receive_ack(&self, doc_id: &str, rev_id: i64) {
-        let changeset = RevisionChangeset {
-            doc_id: doc_id.to_string(),
-            rev_id: rev_id.into(),
-            state: RevisionTableState::Ack,
-        };
-        match self.update_revision_record(vec![changeset]) {
-            Ok(_) => {},
-            Err(e) => tracing::error!("{}", e),
-        }
+    #[inline]
+    fn set_latest_rev_id(&self, rev_id: i64) {
+        let _ = self.latest_rev_id.fetch_update(SeqCst, SeqCst, |_e| Some(rev_id));
     }
 }
 
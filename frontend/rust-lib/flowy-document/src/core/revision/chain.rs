@@ -0,0 +1,150 @@
+use crate::core::revision::disk::{RevTableType, RevisionTable, RevisionTableSql, RevisionTableState};
+use flowy_collaboration::util::md5;
+use flowy_database::{prelude::*, SqliteConnection};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use lib_infra::timestamp;
+use lib_ot::rich_text::RichTextDelta;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const REVISION_CHAIN_VERSION: i64 = 1;
+
+/// The revisions of one document as they are stored on the device, including
+/// the broken ones. The support tools export it from the device of the user
+/// and replay it into a scratch database to reproduce a corrupted document
+/// without the rest of the workspace.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RevisionChain {
+    pub version: i64,
+    pub doc_id: String,
+    pub export_time: i64,
+    pub revisions: Vec<RawRevision>,
+}
+
+/// A row of the revision table. The data is kept as bytes, it may not even be
+/// valid utf8 once it's corrupted.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RawRevision {
+    pub base_rev_id: i64,
+    pub rev_id: i64,
+    pub data: Vec<u8>,
+    pub md5: String,
+    pub state: i32,
+    pub create_time: i64,
+}
+
+/// What the replay of a chain found. The document is composed from the
+/// revisions before the first one that failed.
+#[derive(Debug, Clone)]
+pub struct RevisionReplay {
+    pub doc_id: String,
+    pub revision_count: usize,
+    pub applied_count: usize,
+    pub failed_rev_id: Option<i64>,
+    pub error: Option<String>,
+    pub document_json: String,
+}
+
+impl RevisionChain {
+    pub(crate) fn read(doc_id: &str, conn: &SqliteConnection) -> FlowyResult<Self> {
+        let revisions = RevisionTableSql::read_tables(doc_id, conn)?
+            .into_iter()
+            .map(|table| RawRevision {
+                base_rev_id: table.base_rev_id,
+                rev_id: table.rev_id,
+                data: table.data,
+                md5: table.md5,
+                state: table.state.value(),
+                create_time: table.create_time,
+            })
+            .collect::<Vec<_>>();
+        Ok(RevisionChain {
+            version: REVISION_CHAIN_VERSION,
+            doc_id: doc_id.to_owned(),
+            export_time: timestamp(),
+            revisions,
+        })
+    }
+
+    pub fn write_to_file(&self, path: &Path) -> FlowyResult<()> {
+        let json = serde_json::to_vec_pretty(self)?;
+        let _ = std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    pub fn read_from_file(path: &Path) -> FlowyResult<Self> {
+        let json = std::fs::read(path)?;
+        let chain: RevisionChain = serde_json::from_slice(&json)?;
+        if chain.version > REVISION_CHAIN_VERSION {
+            return Err(FlowyError::internal().context(format!("Unsupported revision chain version {}", chain.version)));
+        }
+        Ok(chain)
+    }
+
+    /// Writes the revisions to the database in the scratch directory, replacing
+    /// the ones of the document that were there, and composes them in order
+    /// the way the document is opened. The directory must not be the one of a
+    /// user.
+    pub fn replay(&self, scratch_dir: &str) -> FlowyResult<RevisionReplay> {
+        let database = flowy_database::init(scratch_dir).map_err(internal_error)?;
+        let conn = &*database.get_connection().map_err(internal_error)?;
+        let tables = self
+            .revisions
+            .iter()
+            .map(|revision| RevisionTable {
+                id: 0,
+                doc_id: self.doc_id.clone(),
+                base_rev_id: revision.base_rev_id,
+                rev_id: revision.rev_id,
+                data: revision.data.clone(),
+                state: RevisionTableState::from(revision.state),
+                ty: RevTableType::Local,
+                md5: revision.md5.clone(),
+                create_time: revision.create_time,
+            })
+            .collect::<Vec<_>>();
+        let _ = conn.timed_transaction::<_, FlowyError, _>("revision.replay_chain", || {
+            let _ = RevisionTableSql::delete(&self.doc_id, None, conn)?;
+            RevisionTableSql::create_tables(&tables, conn)
+        })?;
+
+        let tables = RevisionTableSql::read_tables(&self.doc_id, conn)?;
+        let mut replay = RevisionReplay {
+            doc_id: self.doc_id.clone(),
+            revision_count: tables.len(),
+            applied_count: 0,
+            failed_rev_id: None,
+            error: None,
+            document_json: String::new(),
+        };
+        let mut document = RichTextDelta::new();
+        for table in tables {
+            match compose_table(&document, &table) {
+                Ok(composed) => {
+                    document = composed;
+                    replay.applied_count += 1;
+                },
+                Err(e) => {
+                    replay.failed_rev_id = Some(table.rev_id);
+                    replay.error = Some(e);
+                    break;
+                },
+            }
+        }
+        replay.document_json = document.to_json();
+        Ok(replay)
+    }
+}
+
+// The rows saved before the md5 was stored have an empty one, they are only
+// checked by their delta.
+fn compose_table(document: &RichTextDelta, table: &RevisionTable) -> Result<RichTextDelta, String> {
+    if !table.md5.is_empty() && md5(&table.data) != table.md5 {
+        return Err(format!("The md5 of revision {} doesn't match its data", table.rev_id));
+    }
+    let delta = RichTextDelta::from_bytes(&table.data)
+        .map_err(|e| format!("Revision {} isn't a valid delta: {:?}", table.rev_id, e))?;
+    document
+        .compose(&delta)
+        .map_err(|e| format!("Revision {} can't be composed: {:?}", table.rev_id, e))
+}
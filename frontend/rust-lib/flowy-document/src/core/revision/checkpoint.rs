@@ -0,0 +1,144 @@
+use crate::core::revision::disk::RevisionTableSql;
+use flowy_database::{
+    prelude::*,
+    result::OptionalExtension,
+    schema::{rev_checkpoint_table, rev_checkpoint_table::dsl},
+    SqliteConnection,
+};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use lib_ot::rich_text::RichTextDelta;
+
+/// A revision of a document that the user named, e.g. "Before rewrite", to
+/// restore the document to it later. The pruning keeps the document as it was
+/// at the revision, see `RevisionRetention`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevisionCheckpoint {
+    pub id: String,
+    pub doc_id: String,
+    pub name: String,
+    pub rev_id: i64,
+    pub create_time: i64,
+}
+
+pub(crate) struct RevisionCheckpointTableSql {}
+
+impl RevisionCheckpointTableSql {
+    pub(crate) fn create(table: RevisionCheckpointTable, conn: &SqliteConnection) -> FlowyResult<()> {
+        let _ = diesel::insert_into(rev_checkpoint_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(doc_id: &str, id: &str, conn: &SqliteConnection) -> FlowyResult<RevisionCheckpointTable> {
+        let table = dsl::rev_checkpoint_table
+            .filter(rev_checkpoint_table::id.eq(id))
+            .filter(rev_checkpoint_table::doc_id.eq(doc_id))
+            .first::<RevisionCheckpointTable>(conn)
+            .optional()?;
+        table.ok_or_else(FlowyError::checkpoint_not_found)
+    }
+
+    pub(crate) fn read_all(doc_id: &str, conn: &SqliteConnection) -> FlowyResult<Vec<RevisionCheckpointTable>> {
+        let tables = dsl::rev_checkpoint_table
+            .filter(rev_checkpoint_table::doc_id.eq(doc_id))
+            .order((
+                rev_checkpoint_table::rev_id.asc(),
+                rev_checkpoint_table::create_time.asc(),
+            ))
+            .load::<RevisionCheckpointTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn read_rev_ids(doc_id: &str, conn: &SqliteConnection) -> FlowyResult<Vec<i64>> {
+        let rev_ids = dsl::rev_checkpoint_table
+            .filter(rev_checkpoint_table::doc_id.eq(doc_id))
+            .select(rev_checkpoint_table::rev_id)
+            .load::<i64>(conn)?;
+        Ok(rev_ids)
+    }
+
+    pub(crate) fn delete(doc_id: &str, id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
+        let filter = dsl::rev_checkpoint_table
+            .filter(rev_checkpoint_table::id.eq(id))
+            .filter(rev_checkpoint_table::doc_id.eq(doc_id));
+        let affected_row = diesel::delete(filter).execute(conn)?;
+        if affected_row == 0 {
+            return Err(FlowyError::checkpoint_not_found());
+        }
+        Ok(())
+    }
+
+    pub(crate) fn delete_all(doc_id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
+        let filter = dsl::rev_checkpoint_table.filter(rev_checkpoint_table::doc_id.eq(doc_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "rev_checkpoint_table"]
+pub(crate) struct RevisionCheckpointTable {
+    pub id: String,
+    pub doc_id: String,
+    pub name: String,
+    pub rev_id: i64,
+    pub create_time: i64,
+}
+
+impl std::convert::From<RevisionCheckpointTable> for RevisionCheckpoint {
+    fn from(table: RevisionCheckpointTable) -> Self {
+        RevisionCheckpoint {
+            id: table.id,
+            doc_id: table.doc_id,
+            name: table.name,
+            rev_id: table.rev_id,
+            create_time: table.create_time,
+        }
+    }
+}
+
+pub(crate) fn create_checkpoint(
+    doc_id: &str,
+    id: String,
+    name: String,
+    create_time: i64,
+    conn: &SqliteConnection,
+) -> FlowyResult<RevisionCheckpointTable> {
+    let rev_id = match RevisionTableSql::read_tables(doc_id, conn)?.last() {
+        None => return Err(FlowyError::document_not_found()),
+        Some(table) => table.rev_id,
+    };
+    let table = RevisionCheckpointTable {
+        id,
+        doc_id: doc_id.to_owned(),
+        name,
+        rev_id,
+        create_time,
+    };
+    let _ = RevisionCheckpointTableSql::create(table.clone(), conn)?;
+    Ok(table)
+}
+
+// Composes the revisions up to the one of the checkpoint. It fails if the
+// revision isn't on the disk anymore, e.g. the document was reset by the
+// server.
+pub(crate) fn read_checkpoint_delta(
+    checkpoint: &RevisionCheckpointTable,
+    conn: &SqliteConnection,
+) -> FlowyResult<RichTextDelta> {
+    let tables = RevisionTableSql::read_tables(&checkpoint.doc_id, conn)?;
+    if !tables.iter().any(|table| table.rev_id == checkpoint.rev_id) {
+        return Err(FlowyError::checkpoint_not_found().context(format!(
+            "The revision {} of the checkpoint was removed",
+            checkpoint.rev_id
+        )));
+    }
+
+    let mut delta = RichTextDelta::new();
+    for table in tables.iter().filter(|table| table.rev_id <= checkpoint.rev_id) {
+        let table_delta = RichTextDelta::from_bytes(&table.data).map_err(internal_error)?;
+        delta = delta.compose(&table_delta).map_err(internal_error)?;
+    }
+    Ok(delta)
+}
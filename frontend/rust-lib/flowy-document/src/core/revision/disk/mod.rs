@@ -1,8 +1,10 @@
 mod sql_impl;
+mod writer;
 use crate::core::revision::RevisionRecord;
 use diesel::SqliteConnection;
 use flowy_collaboration::entities::revision::RevisionRange;
 pub use sql_impl::*;
+pub(crate) use writer::*;
 
 use flowy_error::FlowyResult;
 use std::fmt::Debug;
@@ -9,11 +9,16 @@ use flowy_database::{
     insert_or_ignore_into,
     prelude::*,
     schema::{rev_table, rev_table::dsl},
+    slow_log::{log_if_slow, statement_shape},
     ConnectionPool,
 };
 use flowy_error::{internal_error, FlowyError, FlowyResult};
+use lib_infra::timestamp;
+use lib_ot::rich_text::RichTextDelta;
 use std::sync::Arc;
 
+const MAX_ROWS_PER_STATEMENT: usize = 128;
+
 pub struct SQLitePersistence {
     user_id: String,
     pub(crate) pool: Arc<ConnectionPool>,
@@ -27,7 +32,7 @@ impl DocumentRevisionDiskCache for SQLitePersistence {
         revisions: Vec<RevisionRecord>,
         conn: &SqliteConnection,
     ) -> Result<(), Self::Error> {
-        let _ = RevisionTableSql::create(revisions, conn)?;
+        let _ = RevisionTableSql::create(revisions, timestamp(), conn)?;
         Ok(())
     }
 
@@ -53,7 +58,7 @@ impl DocumentRevisionDiskCache for SQLitePersistence {
 
     fn update_revision_record(&self, changesets: Vec<RevisionChangeset>) -> FlowyResult<()> {
         let conn = &*self.pool.get().map_err(internal_error)?;
-        let _ = conn.immediate_transaction::<_, FlowyError, _>(|| {
+        let _ = conn.timed_transaction::<_, FlowyError, _>("revision.update_revision_record", || {
             for changeset in changesets {
                 let _ = RevisionTableSql::update(changeset, conn)?;
             }
@@ -74,7 +79,7 @@ impl DocumentRevisionDiskCache for SQLitePersistence {
 
     fn reset_document(&self, doc_id: &str, revision_records: Vec<RevisionRecord>) -> Result<(), Self::Error> {
         let conn = self.pool.get().map_err(internal_error)?;
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
+        conn.timed_transaction::<_, FlowyError, _>("revision.reset_document", || {
             let _ = self.delete_revision_records(doc_id, None, &*conn)?;
             let _ = self.write_revision_records(revision_records, &*conn)?;
             Ok(())
@@ -94,12 +99,19 @@ impl SQLitePersistence {
 pub struct RevisionTableSql {}
 
 impl RevisionTableSql {
-    pub(crate) fn create(revision_records: Vec<RevisionRecord>, conn: &SqliteConnection) -> Result<(), FlowyError> {
+    pub(crate) fn create(
+        revision_records: Vec<RevisionRecord>,
+        create_time: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
         // Batch insert: https://diesel.rs/guides/all-about-inserts.html
         let records = revision_records
             .into_iter()
             .map(|record| {
                 let rev_state: RevisionTableState = record.state.into();
+                // The md5 of the revision is the one of the whole document, the
+                // stored one is of the data, so each row can be verified alone.
+                let data_md5 = md5(&record.revision.delta_data);
                 (
                     dsl::doc_id.eq(record.revision.doc_id),
                     dsl::base_rev_id.eq(record.revision.base_rev_id),
@@ -107,11 +119,57 @@ impl RevisionTableSql {
                     dsl::data.eq(record.revision.delta_data),
                     dsl::state.eq(rev_state),
                     dsl::ty.eq(RevTableType::Local),
+                    dsl::md5.eq(data_md5),
+                    dsl::create_time.eq(create_time),
                 )
             })
             .collect::<Vec<_>>();
 
-        let _ = insert_or_ignore_into(dsl::rev_table).values(&records).execute(conn)?;
+        // Each row takes 8 variables, the rows are inserted in chunks to stay
+        // under the SQLITE_MAX_VARIABLE_NUMBER. The shape of the batch insert
+        // grows with the records, only its size is logged.
+        for chunk in records.chunks(MAX_ROWS_PER_STATEMENT) {
+            let shape = format!("INSERT OR IGNORE INTO `rev_table` ({} rows)", chunk.len());
+            let _ = log_if_slow(&shape, || {
+                insert_or_ignore_into(dsl::rev_table).values(chunk).execute(conn)
+            })?;
+        }
+        Ok(())
+    }
+
+    // Saves the rows as they are, with their state, md5 and time. The ids are
+    // given by the database.
+    pub(crate) fn create_tables(tables: &[RevisionTable], conn: &SqliteConnection) -> Result<(), FlowyError> {
+        for table in tables {
+            let _ = diesel::insert_into(dsl::rev_table)
+                .values((
+                    dsl::doc_id.eq(&table.doc_id),
+                    dsl::base_rev_id.eq(table.base_rev_id),
+                    dsl::rev_id.eq(table.rev_id),
+                    dsl::data.eq(&table.data),
+                    dsl::state.eq(table.state),
+                    dsl::ty.eq(table.ty),
+                    dsl::md5.eq(&table.md5),
+                    dsl::create_time.eq(table.create_time),
+                ))
+                .execute(conn)?;
+        }
+        Ok(())
+    }
+
+    // Sets the state of many revisions of the document at once.
+    pub(crate) fn update_state(
+        doc_id: &str,
+        rev_ids: Vec<i64>,
+        state: RevisionTableState,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
+        for chunk in rev_ids.chunks(MAX_ROWS_PER_STATEMENT) {
+            let filter = dsl::rev_table
+                .filter(dsl::doc_id.eq(doc_id))
+                .filter(dsl::rev_id.eq_any(chunk));
+            let _ = update(filter).set(dsl::state.eq(state)).execute(conn)?;
+        }
         Ok(())
     }
 
@@ -138,7 +196,8 @@ impl RevisionTableSql {
         if let Some(rev_ids) = rev_ids {
             sql = sql.filter(dsl::rev_id.eq_any(rev_ids));
         }
-        let rows = sql.order(dsl::rev_id.asc()).load::<RevisionTable>(conn)?;
+        let query = sql.order(dsl::rev_id.asc());
+        let rows = log_if_slow(&statement_shape(&query), || query.load::<RevisionTable>(conn))?;
         let records = rows
             .into_iter()
             .map(|row| mk_revision_record_from_table(user_id, row))
@@ -147,18 +206,56 @@ impl RevisionTableSql {
         Ok(records)
     }
 
+    // Replaces the revisions with one that holds their composed delta. It
+    // takes the rev_id of the last one, so the revisions after it still follow.
+    pub(crate) fn squash(tables: &[RevisionTable], conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let (first, last) = match (tables.first(), tables.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Ok(()),
+        };
+        let mut delta = RichTextDelta::new();
+        for table in tables {
+            let table_delta = RichTextDelta::from_bytes(&table.data).map_err(internal_error)?;
+            delta = delta.compose(&table_delta).map_err(internal_error)?;
+        }
+        let data = delta.to_bytes().to_vec();
+        let data_md5 = md5(&data);
+
+        let rev_ids = tables.iter().map(|table| table.rev_id).collect::<Vec<i64>>();
+        let _ = Self::delete(&last.doc_id, Some(rev_ids), conn)?;
+        let _ = diesel::insert_into(dsl::rev_table)
+            .values((
+                dsl::doc_id.eq(&last.doc_id),
+                dsl::base_rev_id.eq(first.base_rev_id),
+                dsl::rev_id.eq(last.rev_id),
+                dsl::data.eq(data),
+                dsl::state.eq(RevisionTableState::Ack),
+                dsl::ty.eq(RevTableType::Local),
+                dsl::md5.eq(data_md5),
+                dsl::create_time.eq(last.create_time),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_tables(doc_id: &str, conn: &SqliteConnection) -> Result<Vec<RevisionTable>, FlowyError> {
+        let query = dsl::rev_table.filter(dsl::doc_id.eq(doc_id)).order(dsl::rev_id.asc());
+        let tables = log_if_slow(&statement_shape(&query), || query.load::<RevisionTable>(conn))?;
+        Ok(tables)
+    }
+
     pub(crate) fn read_with_range(
         user_id: &str,
         doc_id: &str,
         range: RevisionRange,
         conn: &SqliteConnection,
     ) -> Result<Vec<RevisionRecord>, FlowyError> {
-        let rev_tables = dsl::rev_table
+        let query = dsl::rev_table
             .filter(dsl::rev_id.ge(range.start))
             .filter(dsl::rev_id.le(range.end))
             .filter(dsl::doc_id.eq(doc_id))
-            .order(dsl::rev_id.asc())
-            .load::<RevisionTable>(conn)?;
+            .order(dsl::rev_id.asc());
+        let rev_tables = log_if_slow(&statement_shape(&query), || query.load::<RevisionTable>(conn))?;
 
         let revisions = rev_tables
             .into_iter()
@@ -173,7 +270,7 @@ impl RevisionTableSql {
             sql = sql.filter(dsl::rev_id.eq_any(rev_ids));
         }
 
-        let affected_row = sql.execute(conn)?;
+        let affected_row = log_if_slow(&statement_shape(&sql), || sql.execute(conn))?;
         tracing::trace!("[RevisionTable] Delete {} rows", affected_row);
         Ok(())
     }
@@ -182,13 +279,15 @@ impl RevisionTableSql {
 #[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
 #[table_name = "rev_table"]
 pub(crate) struct RevisionTable {
-    id: i32,
+    pub(crate) id: i32,
     pub(crate) doc_id: String,
     pub(crate) base_rev_id: i64,
     pub(crate) rev_id: i64,
     pub(crate) data: Vec<u8>,
     pub(crate) state: RevisionTableState,
     pub(crate) ty: RevTableType, // Deprecated
+    pub(crate) md5: String,
+    pub(crate) create_time: i64,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
@@ -239,8 +338,13 @@ impl std::convert::From<RevisionState> for RevisionTableState {
     }
 }
 
+// The md5 of the record is the stored one, the loader checks it against the
+// data. The rows that were saved before the md5 was stored can't be checked.
 pub(crate) fn mk_revision_record_from_table(user_id: &str, table: RevisionTable) -> RevisionRecord {
-    let md5 = md5(&table.data);
+    let md5 = match table.md5.is_empty() {
+        true => md5(&table.data),
+        false => table.md5,
+    };
     let revision = Revision::new(
         &table.doc_id,
         table.base_rev_id,
@@ -0,0 +1,321 @@
+use crate::core::revision::{
+    disk::{RevisionChangeset, RevisionTableSql, RevisionTableState},
+    memory::RevisionMemoryCacheDelegate,
+    QuarantinedRevision,
+    RevisionCheckpointTableSql,
+    RevisionQuarantineTableSql,
+    RevisionRecord,
+    RevisionRetention,
+};
+use flowy_database::{slow_log::SlowLogTransaction, ConnectionPool};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use futures::FutureExt;
+use lib_infra::{clock::Clock, future::FutureResult};
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::{mpsc, oneshot};
+
+const WRITER_QUEUE_CAPACITY: usize = 1024;
+const MAX_BATCH_LEN: usize = 128;
+
+// Resolves with the result of the transaction that saved the write or the ack.
+pub(crate) type DiskWriteReceipt = oneshot::Receiver<FlowyResult<()>>;
+
+enum DiskWriteCommand {
+    Write {
+        records: Vec<RevisionRecord>,
+        ret: oneshot::Sender<FlowyResult<()>>,
+    },
+    Ack {
+        changeset: RevisionChangeset,
+        ret: oneshot::Sender<FlowyResult<()>>,
+    },
+    Reset {
+        doc_id: String,
+        records: Vec<RevisionRecord>,
+        quarantined: Vec<QuarantinedRevision>,
+        ret: oneshot::Sender<FlowyResult<()>>,
+    },
+    Delete {
+        doc_ids: Vec<String>,
+        ret: oneshot::Sender<FlowyResult<()>>,
+    },
+    Prune {
+        doc_id: String,
+        retention: RevisionRetention,
+        ret: oneshot::Sender<FlowyResult<usize>>,
+    },
+    Flush(oneshot::Sender<()>),
+}
+
+// Writes the revisions of all the documents on a dedicated thread, so the
+// editor never waits for the disk. The commands are applied in order, the
+// writes and the acks that are queued together are committed in one
+// transaction. Once the queue is full, the callers wait for room.
+pub(crate) struct RevisionDiskWriter {
+    pool: Arc<ConnectionPool>,
+    sender: mpsc::Sender<DiskWriteCommand>,
+}
+
+impl RevisionDiskWriter {
+    pub(crate) fn new(pool: Arc<ConnectionPool>, clock: Arc<dyn Clock>) -> Self {
+        let (sender, receiver) = mpsc::channel(WRITER_QUEUE_CAPACITY);
+        let writer_pool = pool.clone();
+        let result = std::thread::Builder::new()
+            .name("revision-writer".to_owned())
+            .spawn(move || run_writer(writer_pool, clock, receiver));
+        if let Err(e) = result {
+            tracing::error!("Start the revision writer failed: {:?}", e);
+        }
+        Self { pool, sender }
+    }
+
+    pub(crate) fn is_writing_to(&self, pool: &Arc<ConnectionPool>) -> bool { Arc::ptr_eq(&self.pool, pool) }
+
+    // Returns once the records are queued, the receipt tells when they were
+    // committed.
+    pub(crate) async fn write(&self, records: Vec<RevisionRecord>) -> FlowyResult<DiskWriteReceipt> {
+        let (ret, rx) = oneshot::channel();
+        let _ = self.send(DiskWriteCommand::Write { records, ret }).await?;
+        Ok(rx)
+    }
+
+    pub(crate) async fn ack(&self, changeset: RevisionChangeset) -> FlowyResult<DiskWriteReceipt> {
+        let (ret, rx) = oneshot::channel();
+        let _ = self.send(DiskWriteCommand::Ack { changeset, ret }).await?;
+        Ok(rx)
+    }
+
+    // Replaces the revisions of the document and quarantines the given ones, it
+    // returns after the revisions were committed.
+    pub(crate) async fn reset(
+        &self,
+        doc_id: &str,
+        records: Vec<RevisionRecord>,
+        quarantined: Vec<QuarantinedRevision>,
+    ) -> FlowyResult<()> {
+        let (ret, rx) = oneshot::channel();
+        let _ = self
+            .send(DiskWriteCommand::Reset {
+                doc_id: doc_id.to_owned(),
+                records,
+                quarantined,
+                ret,
+            })
+            .await?;
+        rx.await.map_err(internal_error)?
+    }
+
+    // Deletes all the revisions of the documents, it returns after they were
+    // deleted. The writes that were queued before are applied first, so they
+    // can't add the revisions back.
+    pub(crate) async fn delete(&self, doc_ids: Vec<String>) -> FlowyResult<()> {
+        let (ret, rx) = oneshot::channel();
+        let _ = self.send(DiskWriteCommand::Delete { doc_ids, ret }).await?;
+        rx.await.map_err(internal_error)?
+    }
+
+    // Squashes the revisions of the document that fall out of the retention,
+    // it returns the number of revisions that were removed.
+    pub(crate) async fn prune(&self, doc_id: &str, retention: RevisionRetention) -> FlowyResult<usize> {
+        let (ret, rx) = oneshot::channel();
+        let _ = self
+            .send(DiskWriteCommand::Prune {
+                doc_id: doc_id.to_owned(),
+                retention,
+                ret,
+            })
+            .await?;
+        rx.await.map_err(internal_error)?
+    }
+
+    // Returns after everything that was queued before is on disk.
+    pub(crate) async fn flush(&self) -> FlowyResult<()> {
+        let (ret, rx) = oneshot::channel();
+        let _ = self.send(DiskWriteCommand::Flush(ret)).await?;
+        rx.await.map_err(internal_error)
+    }
+
+    async fn send(&self, command: DiskWriteCommand) -> FlowyResult<()> {
+        self.sender
+            .send(command)
+            .await
+            .map_err(|_| FlowyError::document_closed().context("The revision writer was stopped"))
+    }
+}
+
+impl RevisionMemoryCacheDelegate for Arc<RevisionDiskWriter> {
+    fn checkpoint_tick(&self, mut records: Vec<RevisionRecord>) -> FutureResult<DiskWriteReceipt, FlowyError> {
+        let writer = self.clone();
+        FutureResult::new(async move {
+            records.retain(|record| record.write_to_disk);
+            if records.is_empty() {
+                // The records were read from the disk, they are there already.
+                let (ret, rx) = oneshot::channel();
+                let _ = ret.send(Ok(()));
+                return Ok(rx);
+            }
+            tracing::trace!("Queue {} records to save", records.len());
+            writer.write(records).await
+        })
+    }
+
+    fn receive_ack(&self, doc_id: &str, rev_id: i64) -> FutureResult<DiskWriteReceipt, FlowyError> {
+        let writer = self.clone();
+        let changeset = RevisionChangeset {
+            doc_id: doc_id.to_string(),
+            rev_id: rev_id.into(),
+            state: RevisionTableState::Ack,
+        };
+        FutureResult::new(async move { writer.ack(changeset).await })
+    }
+}
+
+// The thread stops after all the writers were dropped and the queue is empty.
+fn run_writer(pool: Arc<ConnectionPool>, clock: Arc<dyn Clock>, mut receiver: mpsc::Receiver<DiskWriteCommand>) {
+    while let Some(command) = receiver.blocking_recv() {
+        let mut batch = vec![];
+        let mut barrier = None;
+        match command {
+            DiskWriteCommand::Write { .. } | DiskWriteCommand::Ack { .. } => batch.push(command),
+            _ => barrier = Some(command),
+        }
+
+        while barrier.is_none() && batch.len() < MAX_BATCH_LEN {
+            match receiver.recv().now_or_never() {
+                Some(Some(command @ DiskWriteCommand::Write { .. }))
+                | Some(Some(command @ DiskWriteCommand::Ack { .. })) => batch.push(command),
+                Some(Some(command)) => barrier = Some(command),
+                _ => break,
+            }
+        }
+
+        let now = clock.timestamp();
+        if !batch.is_empty() {
+            let len = batch.len();
+            let (result, rets) = write_batch(&pool, batch, now);
+            if let Err(e) = &result {
+                tracing::error!("Save {} revision commands failed: {}", len, e);
+            }
+            for ret in rets {
+                let _ = ret.send(result.clone());
+            }
+        }
+
+        match barrier {
+            Some(DiskWriteCommand::Reset {
+                doc_id,
+                records,
+                quarantined,
+                ret,
+            }) => {
+                let _ = ret.send(reset_document(&pool, &doc_id, records, &quarantined, now));
+            },
+            Some(DiskWriteCommand::Delete { doc_ids, ret }) => {
+                let _ = ret.send(delete_documents(&pool, &doc_ids));
+            },
+            Some(DiskWriteCommand::Prune { doc_id, retention, ret }) => {
+                let _ = ret.send(prune_document(&pool, &doc_id, retention, now));
+            },
+            Some(DiskWriteCommand::Flush(ret)) => {
+                let _ = ret.send(());
+            },
+            _ => {},
+        }
+    }
+}
+
+// The records of the batch are saved with one multi-row insert, and the acks
+// of each document with one update. The acks are applied after the insert, so
+// a record that was acked while it was queued is saved as acked. The result is
+// returned with the senders of the commands, it's the same for all of them.
+fn write_batch(
+    pool: &Arc<ConnectionPool>,
+    batch: Vec<DiskWriteCommand>,
+    now: i64,
+) -> (FlowyResult<()>, Vec<oneshot::Sender<FlowyResult<()>>>) {
+    let mut records = vec![];
+    let mut acks: HashMap<String, Vec<i64>> = HashMap::new();
+    let mut rets = vec![];
+    for command in batch {
+        match command {
+            DiskWriteCommand::Write {
+                records: mut write_records,
+                ret,
+            } => {
+                records.append(&mut write_records);
+                rets.push(ret);
+            },
+            DiskWriteCommand::Ack { changeset, ret } => {
+                acks.entry(changeset.doc_id)
+                    .or_insert_with(Vec::new)
+                    .push(*changeset.rev_id.as_ref());
+                rets.push(ret);
+            },
+            _ => {},
+        }
+    }
+
+    let result = pool.get().map_err(internal_error).and_then(|conn| {
+        let conn = &*conn;
+        conn.timed_transaction::<_, FlowyError, _>("revision.write_batch", || {
+            if !records.is_empty() {
+                let _ = RevisionTableSql::create(records, now, conn)?;
+            }
+            for (doc_id, rev_ids) in acks {
+                let _ = RevisionTableSql::update_state(&doc_id, rev_ids, RevisionTableState::Ack, conn)?;
+            }
+            Ok(())
+        })
+    });
+    (result, rets)
+}
+
+fn reset_document(
+    pool: &Arc<ConnectionPool>,
+    doc_id: &str,
+    records: Vec<RevisionRecord>,
+    quarantined: &[QuarantinedRevision],
+    now: i64,
+) -> FlowyResult<()> {
+    let conn = &*pool.get().map_err(internal_error)?;
+    conn.timed_transaction::<_, FlowyError, _>("revision.reset_document", || {
+        let _ = RevisionTableSql::delete(doc_id, None, conn)?;
+        let _ = RevisionTableSql::create(records, now, conn)?;
+        if !quarantined.is_empty() {
+            let _ = RevisionQuarantineTableSql::create(quarantined, now, conn)?;
+        }
+        Ok(())
+    })
+}
+
+fn delete_documents(pool: &Arc<ConnectionPool>, doc_ids: &[String]) -> FlowyResult<()> {
+    let conn = &*pool.get().map_err(internal_error)?;
+    conn.timed_transaction::<_, FlowyError, _>("revision.delete_documents", || {
+        for doc_id in doc_ids {
+            let _ = RevisionTableSql::delete(doc_id, None, conn)?;
+            let _ = RevisionCheckpointTableSql::delete_all(doc_id, conn)?;
+            let _ = RevisionQuarantineTableSql::delete_all(doc_id, conn)?;
+        }
+        Ok(())
+    })
+}
+
+// A single revision is kept as it is, it's what the squash would leave anyway.
+fn prune_document(
+    pool: &Arc<ConnectionPool>,
+    doc_id: &str,
+    retention: RevisionRetention,
+    now: i64,
+) -> FlowyResult<usize> {
+    let conn = &*pool.get().map_err(internal_error)?;
+    conn.timed_transaction::<_, FlowyError, _>("revision.prune_document", || {
+        let tables = RevisionTableSql::read_tables(doc_id, conn)?;
+        let pinned_rev_ids = RevisionCheckpointTableSql::read_rev_ids(doc_id, conn)?;
+        let len = retention.prunable_len(&tables, &pinned_rev_ids, now);
+        if len < 2 {
+            return Ok(0);
+        }
+        let _ = RevisionTableSql::squash(&tables[..len], conn)?;
+        Ok(len - 1)
+    })
+}
@@ -1,6 +1,10 @@
 use crate::{
-    core::{revision::DocumentRevisionCache, RevisionRecord},
+    core::{
+        revision::{DocumentRevisionCache, QuarantinedRevision},
+        RevisionRecord,
+    },
     errors::FlowyError,
+    notify::{dart_notify, DocObservable},
 };
 use bytes::Bytes;
 use dashmap::DashMap;
@@ -13,7 +17,7 @@ use flowy_collaboration::{
 };
 use flowy_error::FlowyResult;
 use futures_util::{future, stream, stream::StreamExt};
-use lib_infra::future::FutureResult;
+use lib_infra::{future::FutureResult, metrics};
 use lib_ot::{core::Operation, errors::OTError, rich_text::RichTextDelta};
 use std::{collections::VecDeque, sync::Arc};
 use tokio::sync::RwLock;
@@ -57,7 +61,7 @@ impl DocumentRevisionManager {
         Ok(doc.delta()?)
     }
 
-    #[tracing::instrument(level = "debug", skip(self, revisions), err)]
+    #[tracing::instrument(level = "debug", skip(self, revisions), fields(doc_id = %self.doc_id, user_id = %self.user_id), err)]
     pub async fn reset_document(&self, revisions: RepeatedRevision) -> FlowyResult<()> {
         let rev_id = pair_rev_id_from_revisions(&revisions).1;
         let _ = self
@@ -68,20 +72,20 @@ impl DocumentRevisionManager {
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self, revision), err)]
+    #[tracing::instrument(level = "debug", skip(self, revision), fields(doc_id = %self.doc_id, rev_id = revision.rev_id), err)]
     pub async fn add_remote_revision(&self, revision: &Revision) -> Result<(), FlowyError> {
         if revision.delta_data.is_empty() {
-            return Err(FlowyError::internal().context("Delta data should be empty"));
+            return Err(FlowyError::revision_invalid().context("The delta data of the revision is empty"));
         }
         let _ = self.cache.add(revision.clone(), RevisionState::Ack, true).await?;
         self.rev_id_counter.set(revision.rev_id);
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self, revision))]
+    #[tracing::instrument(level = "debug", skip(self, revision), fields(doc_id = %self.doc_id, rev_id = revision.rev_id))]
     pub async fn add_local_revision(&self, revision: &Revision) -> Result<(), FlowyError> {
         if revision.delta_data.is_empty() {
-            return Err(FlowyError::internal().context("Delta data should be empty"));
+            return Err(FlowyError::revision_invalid().context("The delta data of the revision is empty"));
         }
 
         let record = self.cache.add(revision.clone(), RevisionState::Local, true).await?;
@@ -89,7 +93,7 @@ impl DocumentRevisionManager {
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self), err)]
+    #[tracing::instrument(level = "debug", skip(self), fields(doc_id = %self.doc_id), err)]
     pub async fn ack_revision(&self, rev_id: i64) -> Result<(), FlowyError> {
         if self.sync_seq.ack(&rev_id).await.is_ok() {
             self.cache.ack(rev_id).await;
@@ -129,6 +133,8 @@ impl DocumentRevisionManager {
 
     pub async fn latest_revision(&self) -> Revision { self.cache.latest_revision().await }
 
+    pub async fn flush(&self) -> FlowyResult<()> { self.cache.flush().await }
+
     pub async fn get_revision(&self, rev_id: i64) -> Option<Revision> {
         self.cache.get(rev_id).await.map(|record| record.revision)
     }
@@ -162,6 +168,7 @@ impl RevisionSyncSequence {
         }
         self.local_revs.write().await.push_back(record.revision.rev_id);
         self.revs_map.insert(record.revision.rev_id, record);
+        metrics::add_gauge(metrics::SYNC_QUEUE_DEPTH, 1);
         Ok(())
     }
 
@@ -172,11 +179,13 @@ impl RevisionSyncSequence {
                     "The ack rev_id:{} is not equal to the current rev_id:{}",
                     rev_id, pop_rev_id
                 );
-                return Err(FlowyError::internal().context(desc));
+                return Err(FlowyError::revision_conflict().context(desc));
             }
 
             tracing::trace!("{} revision finish synchronizing", pop_rev_id);
-            self.revs_map.remove(&pop_rev_id);
+            if self.revs_map.remove(&pop_rev_id).is_some() {
+                metrics::add_gauge(metrics::SYNC_QUEUE_DEPTH, -1);
+            }
             let _ = self.local_revs.write().await.pop_front();
         }
         Ok(())
@@ -192,6 +201,12 @@ impl RevisionSyncSequence {
     async fn next_sync_rev_id(&self) -> Option<i64> { self.local_revs.read().await.front().copied() }
 }
 
+// The revisions that are still waiting for the ack are no longer counted once
+// the document is closed, they are loaded again when it's opened.
+impl std::ops::Drop for RevisionSyncSequence {
+    fn drop(&mut self) { metrics::add_gauge(metrics::SYNC_QUEUE_DEPTH, -(self.revs_map.len() as i64)); }
+}
+
 struct RevisionLoader {
     doc_id: String,
     user_id: String,
@@ -201,7 +216,32 @@ struct RevisionLoader {
 
 impl RevisionLoader {
     async fn load(&self) -> Result<Vec<Revision>, FlowyError> {
-        let records = self.cache.batch_get(&self.doc_id)?;
+        // The revisions of the document may still be queued to the writer, e.g.
+        // the document was closed and opened again right away.
+        let _ = self.cache.flush().await?;
+        let mut records = self.cache.batch_get(&self.doc_id)?;
+        let quarantined = QuarantinedRevision::split_off(&mut records);
+        if !quarantined.is_empty() {
+            // The server pushes the quarantined revisions that it received again
+            // once the document is synced, only the local ones are lost. Their
+            // bytes are kept aside, so they can be exported for support.
+            tracing::warn!(
+                "{} revisions of {} failed the verification, the document is rebuilt from the {} before them",
+                quarantined.len(),
+                self.doc_id,
+                records.len()
+            );
+            let range = RevisionRange {
+                doc_id: self.doc_id.clone(),
+                start: quarantined.first().map_or(0, |revision| revision.rev_id),
+                end: quarantined.last().map_or(0, |revision| revision.rev_id),
+            };
+            let _ = self.cache.repair(records.clone(), quarantined).await?;
+            dart_notify(&self.doc_id, DocObservable::DocumentRepaired)
+                .payload(range)
+                .send();
+        }
+
         let revisions: Vec<Revision>;
         if records.is_empty() {
             let doc = self.server.fetch_document(&self.doc_id).await?;
@@ -1,31 +1,46 @@
-use crate::core::RevisionRecord;
+use crate::core::{
+    revision::{
+        budget::{RevisionMap, RevisionMemoryBudget},
+        disk::DiskWriteReceipt,
+    },
+    RevisionRecord,
+};
 use dashmap::DashMap;
-use flowy_collaboration::entities::revision::RevisionRange;
+use flowy_collaboration::entities::revision::{RevisionRange, RevisionState};
 use flowy_error::{FlowyError, FlowyResult};
+use lib_infra::{clock::Clock, future::FutureResult, metrics};
 use std::{borrow::Cow, sync::Arc, time::Duration};
 use tokio::{sync::RwLock, task::JoinHandle};
 
+// Both return once the change is queued, the receipt resolves after it was
+// saved to the disk.
 pub(crate) trait RevisionMemoryCacheDelegate: Send + Sync {
-    fn checkpoint_tick(&self, records: Vec<RevisionRecord>) -> FlowyResult<()>;
-    fn receive_ack(&self, doc_id: &str, rev_id: i64);
+    fn checkpoint_tick(&self, records: Vec<RevisionRecord>) -> FutureResult<DiskWriteReceipt, FlowyError>;
+    fn receive_ack(&self, doc_id: &str, rev_id: i64) -> FutureResult<DiskWriteReceipt, FlowyError>;
 }
 
 pub(crate) struct DocumentRevisionMemoryCache {
     doc_id: String,
-    revs_map: Arc<DashMap<i64, RevisionRecord>>,
+    cache_id: u64,
+    revs_map: Arc<RevisionMap>,
     delegate: Arc<dyn RevisionMemoryCacheDelegate>,
     pending_write_revs: Arc<RwLock<Vec<i64>>>,
     defer_save: RwLock<Option<JoinHandle<()>>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl DocumentRevisionMemoryCache {
-    pub(crate) fn new(doc_id: &str, delegate: Arc<dyn RevisionMemoryCacheDelegate>) -> Self {
+    pub(crate) fn new(doc_id: &str, delegate: Arc<dyn RevisionMemoryCacheDelegate>, clock: Arc<dyn Clock>) -> Self {
+        let revs_map = Arc::new(DashMap::new());
+        let cache_id = RevisionMemoryBudget::shared().register(&revs_map);
         DocumentRevisionMemoryCache {
             doc_id: doc_id.to_owned(),
-            revs_map: Arc::new(DashMap::new()),
+            cache_id,
+            revs_map,
             delegate,
             pending_write_revs: Arc::new(RwLock::new(vec![])),
             defer_save: RwLock::new(None),
+            clock,
         }
     }
 
@@ -48,6 +63,7 @@ impl DocumentRevisionMemoryCache {
                 return;
             }
         }
+        RevisionMemoryBudget::shared().add(self.cache_id, &record);
         self.revs_map.insert(rev_id, record);
         self.pending_write_revs.write().await.push(rev_id);
         self.make_checkpoint().await;
@@ -62,14 +78,22 @@ impl DocumentRevisionMemoryCache {
         if !self.pending_write_revs.read().await.contains(rev_id) {
             // The revision must be saved on disk if the pending_write_revs
             // doesn't contains the rev_id.
-            self.delegate.receive_ack(&self.doc_id, *rev_id);
+            match self.delegate.receive_ack(&self.doc_id, *rev_id).await {
+                Ok(receipt) => mark_evictable_when_saved(self.cache_id, vec![*rev_id], receipt),
+                Err(e) => tracing::error!("{}", e),
+            }
         } else {
             self.make_checkpoint().await;
         }
     }
 
     pub(crate) async fn get(&self, rev_id: &i64) -> Option<RevisionRecord> {
-        self.revs_map.get(&rev_id).map(|r| r.value().clone())
+        let record = self.revs_map.get(&rev_id).map(|r| r.value().clone());
+        self.record_lookup(record.is_some());
+        if record.is_some() {
+            RevisionMemoryBudget::shared().touch(self.cache_id, *rev_id);
+        }
+        record
     }
 
     pub(crate) async fn get_with_range(&self, range: &RevisionRange) -> Result<Vec<RevisionRecord>, FlowyError> {
@@ -77,11 +101,26 @@ impl DocumentRevisionMemoryCache {
             .iter()
             .flat_map(|rev_id| self.revs_map.get(&rev_id).map(|record| record.clone()))
             .collect::<Vec<RevisionRecord>>();
+        self.record_lookup(revs.len() == range.len() as usize);
+        for record in &revs {
+            RevisionMemoryBudget::shared().touch(self.cache_id, record.revision.rev_id);
+        }
         Ok(revs)
     }
 
+    // The hit rate of the cache is the hit count over the sum of the hit and
+    // miss counts.
+    fn record_lookup(&self, is_hit: bool) {
+        if is_hit {
+            metrics::increment_counter(metrics::REVISION_CACHE_HIT_COUNT);
+        } else {
+            metrics::increment_counter(metrics::REVISION_CACHE_MISS_COUNT);
+        }
+    }
+
     pub(crate) async fn reset_with_revisions(&self, revision_records: &[RevisionRecord]) -> FlowyResult<()> {
         self.revs_map.clear();
+        RevisionMemoryBudget::shared().forget(self.cache_id);
         if let Some(handler) = self.defer_save.write().await.take() {
             handler.abort();
         }
@@ -89,6 +128,7 @@ impl DocumentRevisionMemoryCache {
         let mut write_guard = self.pending_write_revs.write().await;
         write_guard.clear();
         for record in revision_records {
+            RevisionMemoryBudget::shared().add(self.cache_id, record);
             self.revs_map.insert(record.revision.rev_id, record.clone());
             write_guard.push(record.revision.rev_id);
         }
@@ -108,31 +148,189 @@ impl DocumentRevisionMemoryCache {
             return;
         }
 
+        let cache_id = self.cache_id;
         let rev_map = self.revs_map.clone();
         let pending_write_revs = self.pending_write_revs.clone();
         let delegate = self.delegate.clone();
+        let checkpoint = self.clock.sleep(Duration::from_millis(600));
 
         *self.defer_save.write().await = Some(tokio::spawn(async move {
-            tokio::time::sleep(Duration::from_millis(600)).await;
-            let mut revs_write_guard = pending_write_revs.write().await;
-            // It may cause performance issues because we hold the write lock of the
-            // rev_order and the lock will be released after the checkpoint has been written
-            // to the disk.
-            //
-            // Use saturating_sub and split_off ?
-            // https://stackoverflow.com/questions/28952411/what-is-the-idiomatic-way-to-pop-the-last-n-elements-in-a-mutable-vec
-            let mut save_records: Vec<RevisionRecord> = vec![];
-            revs_write_guard.iter().for_each(|rev_id| match rev_map.get(rev_id) {
-                None => {},
-                Some(value) => {
-                    save_records.push(value.value().clone());
-                },
-            });
-
-            if delegate.checkpoint_tick(save_records).is_ok() {
-                revs_write_guard.clear();
-                drop(revs_write_guard);
-            }
+            checkpoint.await;
+            save_pending_revs(cache_id, rev_map, pending_write_revs, delegate).await;
         }));
     }
+
+    // Saves the pending revisions right away instead of waiting for the next
+    // checkpoint.
+    pub(crate) async fn flush(&self) {
+        if let Some(handler) = self.defer_save.write().await.take() {
+            handler.abort();
+        }
+        save_pending_revs(
+            self.cache_id,
+            self.revs_map.clone(),
+            self.pending_write_revs.clone(),
+            self.delegate.clone(),
+        )
+        .await;
+    }
+}
+
+impl std::ops::Drop for DocumentRevisionMemoryCache {
+    fn drop(&mut self) { RevisionMemoryBudget::shared().unregister(self.cache_id); }
+}
+
+async fn save_pending_revs(
+    cache_id: u64,
+    rev_map: Arc<RevisionMap>,
+    pending_write_revs: Arc<RwLock<Vec<i64>>>,
+    delegate: Arc<dyn RevisionMemoryCacheDelegate>,
+) {
+    // The write lock of the pending_write_revs is held until the records are
+    // queued to the writer, the writer saves them to the disk in the
+    // background.
+    //
+    // Use saturating_sub and split_off ?
+    // https://stackoverflow.com/questions/28952411/what-is-the-idiomatic-way-to-pop-the-last-n-elements-in-a-mutable-vec
+    let mut revs_write_guard = pending_write_revs.write().await;
+    if revs_write_guard.is_empty() {
+        return;
+    }
+
+    let mut save_records: Vec<RevisionRecord> = vec![];
+    revs_write_guard.iter().for_each(|rev_id| match rev_map.get(rev_id) {
+        None => {},
+        Some(value) => {
+            save_records.push(value.value().clone());
+        },
+    });
+
+    // The records that were acked before they were saved can be evicted once
+    // the writer committed them.
+    let acked_rev_ids = save_records
+        .iter()
+        .filter(|record| record.state == RevisionState::Ack)
+        .map(|record| record.revision.rev_id)
+        .collect::<Vec<i64>>();
+
+    match delegate.checkpoint_tick(save_records).await {
+        Ok(receipt) => {
+            revs_write_guard.clear();
+            mark_evictable_when_saved(cache_id, acked_rev_ids, receipt);
+        },
+        Err(e) => tracing::error!("{}", e),
+    }
+}
+
+// A record that is evicted before the writer committed it would be lost if the
+// write failed, so it stays in memory until then. The ones whose write failed
+// are never evicted.
+fn mark_evictable_when_saved(cache_id: u64, rev_ids: Vec<i64>, receipt: DiskWriteReceipt) {
+    if rev_ids.is_empty() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        match receipt.await {
+            Ok(Ok(_)) => {
+                for rev_id in rev_ids {
+                    RevisionMemoryBudget::shared().mark_evictable(cache_id, rev_id);
+                }
+            },
+            Ok(Err(e)) => tracing::error!("Keep the revisions in memory, saving them failed: {}", e),
+            Err(_) => tracing::error!("Keep the revisions in memory, the writer stopped before saving them"),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DocumentRevisionMemoryCache, RevisionMemoryCacheDelegate};
+    use crate::core::{
+        revision::{budget::set_revision_cache_capacity, disk::DiskWriteReceipt},
+        RevisionRecord,
+    };
+    use bytes::Bytes;
+    use flowy_collaboration::entities::revision::{Revision, RevisionState};
+    use flowy_error::{FlowyError, FlowyResult};
+    use lib_infra::{clock::SystemClock, future::FutureResult};
+    use parking_lot::Mutex;
+    use std::{borrow::Cow, sync::Arc, time::Duration};
+    use tokio::sync::oneshot;
+
+    // Holds the queued writes until the test commits them.
+    #[derive(Default)]
+    struct MockDiskWriter {
+        rets: Mutex<Vec<oneshot::Sender<FlowyResult<()>>>>,
+    }
+
+    impl MockDiskWriter {
+        fn queue(&self) -> FutureResult<DiskWriteReceipt, FlowyError> {
+            let (ret, rx) = oneshot::channel();
+            self.rets.lock().push(ret);
+            FutureResult::new(async move { Ok(rx) })
+        }
+
+        fn commit(&self, result: FlowyResult<()>) {
+            for ret in self.rets.lock().drain(..) {
+                let _ = ret.send(result.clone());
+            }
+        }
+    }
+
+    impl RevisionMemoryCacheDelegate for MockDiskWriter {
+        fn checkpoint_tick(&self, _records: Vec<RevisionRecord>) -> FutureResult<DiskWriteReceipt, FlowyError> {
+            self.queue()
+        }
+
+        fn receive_ack(&self, _doc_id: &str, _rev_id: i64) -> FutureResult<DiskWriteReceipt, FlowyError> {
+            self.queue()
+        }
+    }
+
+    fn record(doc_id: &str, rev_id: i64) -> RevisionRecord {
+        let revision = Revision::new(doc_id, 0, rev_id, Bytes::from("[]"), "user_1", "".to_owned());
+        RevisionRecord {
+            revision,
+            state: RevisionState::Local,
+            write_to_disk: true,
+        }
+    }
+
+    // Lets the task that waits for the receipt run.
+    async fn settle() { tokio::time::sleep(Duration::from_millis(50)).await; }
+
+    async fn acked_cache(doc_id: &str, writer: &Arc<MockDiskWriter>) -> DocumentRevisionMemoryCache {
+        set_revision_cache_capacity(0);
+        let cache = DocumentRevisionMemoryCache::new(doc_id, writer.clone(), Arc::new(SystemClock));
+        cache.add(Cow::Owned(record(doc_id, 1))).await;
+        cache.flush().await;
+        writer.commit(Ok(()));
+        cache.ack(&1).await;
+        cache
+    }
+
+    #[tokio::test]
+    async fn revision_evicted_after_ack_saved() {
+        let writer = Arc::new(MockDiskWriter::default());
+        let cache = acked_cache("doc_1", &writer).await;
+
+        // The writer is slow, the ack is queued but not committed yet
+        settle().await;
+        assert!(cache.contains(&1));
+
+        writer.commit(Ok(()));
+        settle().await;
+        assert!(!cache.contains(&1));
+    }
+
+    #[tokio::test]
+    async fn revision_kept_after_ack_save_failed() {
+        let writer = Arc::new(MockDiskWriter::default());
+        let cache = acked_cache("doc_2", &writer).await;
+
+        writer.commit(Err(FlowyError::internal()));
+        settle().await;
+        assert!(cache.contains(&1));
+    }
 }
@@ -1,8 +1,23 @@
+mod budget;
 mod cache;
+mod chain;
+mod checkpoint;
 mod disk;
 mod manager;
 mod memory;
+mod quarantine;
+mod retention;
+mod signature;
 mod snapshot;
 
+pub use budget::set_revision_cache_capacity;
 pub use cache::*;
+pub use chain::*;
+pub use checkpoint::RevisionCheckpoint;
+pub(crate) use checkpoint::{create_checkpoint, read_checkpoint_delta, RevisionCheckpointTableSql};
+pub(crate) use disk::RevisionDiskWriter;
 pub use manager::*;
+pub(crate) use quarantine::RevisionQuarantineTableSql;
+pub use quarantine::{QuarantineReport, QuarantinedRevision};
+pub use retention::RevisionRetention;
+pub use signature::{RevisionSigner, SignatureCheck};
@@ -0,0 +1,232 @@
+use crate::core::revision::RevisionRecord;
+use flowy_collaboration::util::md5;
+use flowy_database::{
+    prelude::*,
+    schema::{rev_quarantine_table, rev_quarantine_table::dsl},
+    SqliteConnection,
+};
+use flowy_error::FlowyResult;
+use lib_ot::rich_text::RichTextDelta;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const QUARANTINE_REPORT_VERSION: i64 = 1;
+
+/// A revision that was taken out of a document because it couldn't be loaded.
+/// Its bytes are kept as they were on the disk, so they can be sent to support
+/// to find out how it got corrupted.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct QuarantinedRevision {
+    pub doc_id: String,
+    pub base_rev_id: i64,
+    pub rev_id: i64,
+    pub data: Vec<u8>,
+    pub md5: String,
+    pub reason: String,
+    pub quarantine_time: i64,
+}
+
+impl QuarantinedRevision {
+    // Splits off the records from the first one whose data doesn't match its
+    // md5 or isn't a delta, e.g. after a torn write. The records after it were
+    // based on it, they are quarantined too. The time is set when they are
+    // saved.
+    pub(crate) fn split_off(records: &mut Vec<RevisionRecord>) -> Vec<QuarantinedRevision> {
+        let index = match records.iter().position(|record| unverified_reason(record).is_some()) {
+            None => return vec![],
+            Some(index) => index,
+        };
+        records
+            .split_off(index)
+            .into_iter()
+            .enumerate()
+            .map(|(i, record)| {
+                let reason = match i {
+                    0 => unverified_reason(&record).unwrap_or_default(),
+                    _ => "Based on a quarantined revision".to_owned(),
+                };
+                let revision = record.revision;
+                QuarantinedRevision {
+                    doc_id: revision.doc_id,
+                    base_rev_id: revision.base_rev_id,
+                    rev_id: revision.rev_id,
+                    data: revision.delta_data,
+                    md5: revision.md5,
+                    reason,
+                    quarantine_time: 0,
+                }
+            })
+            .collect()
+    }
+}
+
+fn unverified_reason(record: &RevisionRecord) -> Option<String> {
+    let revision = &record.revision;
+    if md5(&revision.delta_data) != revision.md5 {
+        return Some("The md5 doesn't match the data".to_owned());
+    }
+    if let Err(e) = RichTextDelta::from_bytes(&revision.delta_data) {
+        return Some(format!("The data isn't a delta: {:?}", e));
+    }
+    None
+}
+
+/// The quarantined revisions of a document in the file that the user sends to
+/// support.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QuarantineReport {
+    pub version: i64,
+    pub doc_id: String,
+    pub export_time: i64,
+    pub revisions: Vec<QuarantinedRevision>,
+}
+
+impl QuarantineReport {
+    pub(crate) fn new(doc_id: &str, export_time: i64, revisions: Vec<QuarantinedRevision>) -> Self {
+        Self {
+            version: QUARANTINE_REPORT_VERSION,
+            doc_id: doc_id.to_owned(),
+            export_time,
+            revisions,
+        }
+    }
+
+    pub fn write_to_file(&self, path: &Path) -> FlowyResult<()> {
+        let json = serde_json::to_vec_pretty(self)?;
+        let _ = std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    pub fn read_from_file(path: &Path) -> FlowyResult<Self> {
+        let json = std::fs::read(path)?;
+        let report: QuarantineReport = serde_json::from_slice(&json)?;
+        Ok(report)
+    }
+}
+
+pub(crate) struct RevisionQuarantineTableSql {}
+
+impl RevisionQuarantineTableSql {
+    pub(crate) fn create(
+        revisions: &[QuarantinedRevision],
+        quarantine_time: i64,
+        conn: &SqliteConnection,
+    ) -> FlowyResult<()> {
+        let rows = revisions
+            .iter()
+            .map(|revision| {
+                (
+                    dsl::doc_id.eq(&revision.doc_id),
+                    dsl::base_rev_id.eq(revision.base_rev_id),
+                    dsl::rev_id.eq(revision.rev_id),
+                    dsl::data.eq(&revision.data),
+                    dsl::md5.eq(&revision.md5),
+                    dsl::reason.eq(&revision.reason),
+                    dsl::quarantine_time.eq(quarantine_time),
+                )
+            })
+            .collect::<Vec<_>>();
+        let _ = diesel::insert_into(rev_quarantine_table::table)
+            .values(rows)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_all(doc_id: &str, conn: &SqliteConnection) -> FlowyResult<Vec<QuarantinedRevision>> {
+        let tables = dsl::rev_quarantine_table
+            .filter(rev_quarantine_table::doc_id.eq(doc_id))
+            .order(rev_quarantine_table::id.asc())
+            .load::<RevisionQuarantineTable>(conn)?;
+        Ok(tables.into_iter().map(|table| table.into()).collect())
+    }
+
+    pub(crate) fn delete_all(doc_id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
+        let filter = dsl::rev_quarantine_table.filter(rev_quarantine_table::doc_id.eq(doc_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable)]
+pub(crate) struct RevisionQuarantineTable {
+    pub id: i32,
+    pub doc_id: String,
+    pub base_rev_id: i64,
+    pub rev_id: i64,
+    pub data: Vec<u8>,
+    pub md5: String,
+    pub reason: String,
+    pub quarantine_time: i64,
+}
+
+impl std::convert::From<RevisionQuarantineTable> for QuarantinedRevision {
+    fn from(table: RevisionQuarantineTable) -> Self {
+        QuarantinedRevision {
+            doc_id: table.doc_id,
+            base_rev_id: table.base_rev_id,
+            rev_id: table.rev_id,
+            data: table.data,
+            md5: table.md5,
+            reason: table.reason,
+            quarantine_time: table.quarantine_time,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::revision::{QuarantinedRevision, RevisionRecord};
+    use bytes::Bytes;
+    use flowy_collaboration::{
+        entities::revision::{Revision, RevisionState},
+        util::md5,
+    };
+
+    fn record(rev_id: i64, data: &'static str) -> RevisionRecord {
+        let data = Bytes::from(data);
+        let revision = Revision::new("doc", rev_id - 1, rev_id, data.clone(), "user", md5(&data));
+        RevisionRecord {
+            revision,
+            state: RevisionState::Ack,
+            write_to_disk: false,
+        }
+    }
+
+    #[test]
+    fn split_off_verified_records() {
+        let mut records = vec![record(1, r#"[{"insert":"1"}]"#), record(2, r#"[{"insert":"2"}]"#)];
+        assert!(QuarantinedRevision::split_off(&mut records).is_empty());
+        assert_eq!(records.len(), 2);
+    }
+
+    #[test]
+    fn split_off_from_first_unverified_record() {
+        let mut torn = record(2, r#"[{"insert":"2"}]"#);
+        torn.revision.delta_data.truncate(5);
+        let mut records = vec![
+            record(1, r#"[{"insert":"1"}]"#),
+            torn,
+            record(3, r#"[{"insert":"3"}]"#),
+            record(4, "not a delta"),
+        ];
+        let quarantined = QuarantinedRevision::split_off(&mut records);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].revision.rev_id, 1);
+        assert_eq!(
+            quarantined.iter().map(|revision| revision.rev_id).collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+        assert_eq!(quarantined[0].reason, "The md5 doesn't match the data");
+        assert_eq!(quarantined[1].reason, "Based on a quarantined revision");
+        assert_eq!(quarantined[0].data, br#"[{"in"#.to_vec());
+    }
+
+    #[test]
+    fn split_off_record_that_is_not_delta() {
+        let mut records = vec![record(1, r#"[{"insert":"1"}]"#), record(2, "not a delta")];
+        let quarantined = QuarantinedRevision::split_off(&mut records);
+        assert_eq!(records.len(), 1);
+        assert_eq!(quarantined.len(), 1);
+        assert!(quarantined[0].reason.starts_with("The data isn't a delta"));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::core::revision::disk::{RevisionTable, RevisionTableState};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// How much of the history of a document is kept on the disk. The synced
+/// revisions before it are squashed into one revision that holds the document
+/// as it was then, so the document still opens but its older history is gone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RevisionRetention {
+    All,
+    LastRevisions(usize),
+    LastDays(i64),
+}
+
+impl std::default::Default for RevisionRetention {
+    fn default() -> Self { RevisionRetention::All }
+}
+
+impl RevisionRetention {
+    // The number of leading revisions that fall out of the retention. The local
+    // revisions are still needed to sync, so the count stops at the first one.
+    // It also stops after the first pinned revision: the squash takes its
+    // rev_id, so the document at that revision can still be composed.
+    pub(crate) fn prunable_len(&self, tables: &[RevisionTable], pinned_rev_ids: &[i64], now: i64) -> usize {
+        let is_prunable = |index: usize, table: &RevisionTable| {
+            if table.state != RevisionTableState::Ack {
+                return false;
+            }
+            match *self {
+                RevisionRetention::All => false,
+                RevisionRetention::LastRevisions(count) => index + count < tables.len(),
+                RevisionRetention::LastDays(days) => table.create_time < now - days * SECONDS_PER_DAY,
+            }
+        };
+        let len = tables
+            .iter()
+            .enumerate()
+            .take_while(|(index, table)| is_prunable(*index, table))
+            .count();
+        match tables[..len]
+            .iter()
+            .position(|table| pinned_rev_ids.contains(&table.rev_id))
+        {
+            None => len,
+            Some(index) => index + 1,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use ed25519_dalek::{Keypair, PublicKey, Signature, Signer, Verifier};
+use flowy_collaboration::entities::revision::Revision;
+use flowy_database::kv::KV;
+use flowy_error::{internal_error, FlowyResult};
+use parking_lot::RwLock;
+use std::{collections::HashMap, convert::TryFrom};
+
+const DEVICE_KEY: &str = "revision_signing_key";
+const SIGNER_KEYS: &str = "revision_signer_keys";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureCheck {
+    Verified,
+
+    // Signed with another key than the one pinned for the user, e.g. the user
+    // signed in on another device. The key isn't pinned, it's reported like an
+    // invalid signature.
+    NewSigner,
+
+    Unsigned,
+
+    // The signature doesn't match the revision, or the revision of a user who
+    // signed before isn't signed.
+    Invalid(String),
+}
+
+/// Signs the revisions made on this device with a key of the device, and
+/// checks the signatures of the revisions of the other users. The public key
+/// of a user is pinned the first time it's seen, so a server that injects an
+/// edit attributed to someone else can't sign it with their key.
+pub struct RevisionSigner {
+    keypair: Keypair,
+    public_key: String,
+    signer_keys: RwLock<HashMap<String, Vec<String>>>,
+}
+
+impl RevisionSigner {
+    // Generates the key of the device the first time it's called.
+    pub fn load() -> FlowyResult<Self> {
+        let keypair = match KV::get_str(DEVICE_KEY) {
+            Some(encoded) => {
+                let bytes = base64::decode(encoded).map_err(internal_error)?;
+                Keypair::from_bytes(&bytes).map_err(internal_error)?
+            },
+            None => {
+                let keypair = Keypair::generate(&mut rand::rngs::OsRng);
+                KV::set_str(DEVICE_KEY, base64::encode(keypair.to_bytes()));
+                keypair
+            },
+        };
+        let public_key = base64::encode(keypair.public.to_bytes());
+        let signer_keys = KV::get_str(SIGNER_KEYS)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Ok(Self {
+            keypair,
+            public_key,
+            signer_keys: RwLock::new(signer_keys),
+        })
+    }
+
+    // Only the revisions of the user are signed. The ones of the other users
+    // are sent again as they were received, e.g. when the server pulls them.
+    pub fn sign(&self, user_id: &str, revision: &mut Revision) {
+        if revision.user_id != user_id || revision.is_signed() {
+            return;
+        }
+        let signature = self.keypair.sign(&revision.signing_payload());
+        revision.signature = base64::encode(signature.to_bytes());
+        revision.signer_key = self.public_key.clone();
+    }
+
+    pub fn verify(&self, revision: &Revision) -> SignatureCheck {
+        let known_keys = self
+            .signer_keys
+            .read()
+            .get(&revision.user_id)
+            .cloned()
+            .unwrap_or_default();
+        if !revision.is_signed() {
+            return match known_keys.is_empty() {
+                true => SignatureCheck::Unsigned,
+                false => SignatureCheck::Invalid("The user signed the revisions before".to_owned()),
+            };
+        }
+        if let Err(e) = verify_signature(revision) {
+            return SignatureCheck::Invalid(e);
+        }
+        if known_keys.contains(&revision.signer_key) {
+            return SignatureCheck::Verified;
+        }
+        // Pinning another key for the user would let the server sign the edits
+        // it injects with a key of its own.
+        if !known_keys.is_empty() {
+            return SignatureCheck::NewSigner;
+        }
+
+        match self.pin(&revision.user_id, &revision.signer_key) {
+            true => SignatureCheck::Verified,
+            false => SignatureCheck::NewSigner,
+        }
+    }
+
+    // Pins the key if the user has none yet and returns whether it's the key
+    // of the user. It's checked again under the write lock, another revision
+    // of the user may have been verified in the meantime.
+    fn pin(&self, user_id: &str, key: &str) -> bool {
+        let mut signer_keys = self.signer_keys.write();
+        let keys = signer_keys.entry(user_id.to_owned()).or_insert_with(Vec::new);
+        if !keys.is_empty() {
+            return keys.iter().any(|pinned_key| pinned_key == key);
+        }
+
+        keys.push(key.to_owned());
+        match serde_json::to_string(&*signer_keys) {
+            Ok(json) => KV::set_str(SIGNER_KEYS, json),
+            Err(e) => tracing::error!("Save the revision signer keys failed: {:?}", e),
+        }
+        true
+    }
+}
+
+fn verify_signature(revision: &Revision) -> Result<(), String> {
+    let key = base64::decode(&revision.signer_key).map_err(|e| format!("Invalid signer key: {}", e))?;
+    let key = PublicKey::from_bytes(&key).map_err(|e| format!("Invalid signer key: {}", e))?;
+    let signature = base64::decode(&revision.signature).map_err(|e| format!("Invalid signature: {}", e))?;
+    let signature = Signature::try_from(signature.as_slice()).map_err(|e| format!("Invalid signature: {}", e))?;
+    key.verify(&revision.signing_payload(), &signature)
+        .map_err(|_| "The signature doesn't match the revision".to_owned())
+}
@@ -0,0 +1,53 @@
+use flowy_database::{
+    prelude::*,
+    schema::{collapsed_toggle_table, collapsed_toggle_table::dsl},
+    SqliteConnection,
+};
+use flowy_error::FlowyResult;
+
+// The toggles that the user collapsed, the others are expanded. The state is
+// kept on the device and never synced: each reader of a shared document
+// collapses the toggles they don't care about without hiding them from the
+// others.
+pub(crate) struct CollapsedToggleTableSql {}
+
+impl CollapsedToggleTableSql {
+    pub(crate) fn read(doc_id: &str, conn: &SqliteConnection) -> FlowyResult<Vec<String>> {
+        let toggle_ids = dsl::collapsed_toggle_table
+            .filter(collapsed_toggle_table::doc_id.eq(doc_id))
+            .select(collapsed_toggle_table::toggle_id)
+            .load::<String>(conn)?;
+        Ok(toggle_ids)
+    }
+
+    pub(crate) fn set(doc_id: &str, toggle_id: &str, collapsed: bool, conn: &SqliteConnection) -> FlowyResult<()> {
+        let table = CollapsedToggleTable {
+            doc_id: doc_id.to_owned(),
+            toggle_id: toggle_id.to_owned(),
+        };
+        if collapsed {
+            let _ = diesel::replace_into(collapsed_toggle_table::table)
+                .values(&table)
+                .execute(conn)?;
+        } else {
+            let filter = dsl::collapsed_toggle_table
+                .filter(collapsed_toggle_table::doc_id.eq(doc_id))
+                .filter(collapsed_toggle_table::toggle_id.eq(toggle_id));
+            let _ = diesel::delete(filter).execute(conn)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn delete_all(doc_ids: &[String], conn: &SqliteConnection) -> FlowyResult<()> {
+        let filter = dsl::collapsed_toggle_table.filter(collapsed_toggle_table::doc_id.eq_any(doc_ids));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "collapsed_toggle_table"]
+pub(crate) struct CollapsedToggleTable {
+    pub doc_id: String,
+    pub toggle_id: String,
+}
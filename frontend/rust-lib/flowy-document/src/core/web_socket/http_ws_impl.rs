@@ -6,7 +6,7 @@ use async_stream::stream;
 use bytes::Bytes;
 use flowy_collaboration::entities::{
     revision::{RevId, RevisionRange},
-    ws::{DocumentClientWSData, DocumentServerWSData, DocumentServerWSDataType, NewDocumentUser},
+    ws::{DocumentBackpressure, DocumentClientWSData, DocumentServerWSData, DocumentServerWSDataType, NewDocumentUser},
 };
 use flowy_error::{internal_error, FlowyError, FlowyResult};
 use futures::stream::StreamExt;
@@ -115,6 +115,7 @@ pub trait DocumentWSSteamConsumer: Send + Sync {
     fn receive_ack(&self, id: String, ty: DocumentServerWSDataType) -> FutureResult<(), FlowyError>;
     fn receive_new_user_connect(&self, new_user: NewDocumentUser) -> FutureResult<(), FlowyError>;
     fn pull_revisions_in_range(&self, range: RevisionRange) -> FutureResult<(), FlowyError>;
+    fn receive_backpressure(&self, backpressure: DocumentBackpressure) -> FutureResult<(), FlowyError>;
 }
 
 pub struct DocumentWSStream {
@@ -199,6 +200,10 @@ impl DocumentWSStream {
                 let _ = self.consumer.receive_new_user_connect(new_user).await;
                 // Notify the user that someone has connected to this document
             },
+            DocumentServerWSDataType::ServerBackpressure => {
+                let backpressure = DocumentBackpressure::try_from(bytes)?;
+                let _ = self.consumer.receive_backpressure(backpressure).await?;
+            },
         }
         Ok(())
     }
@@ -1,16 +1,21 @@
-use crate::core::{
-    web_socket::{DocumentWSSinkDataProvider, DocumentWSSteamConsumer, HttpWebSocketManager},
-    DocumentRevisionManager,
-    DocumentWSReceiver,
-    DocumentWebSocket,
-    EditorCommand,
-    TransformDeltas,
+use crate::{
+    core::{
+        web_socket::{DocumentWSSinkDataProvider, DocumentWSSteamConsumer, HttpWebSocketManager},
+        DocumentRevisionManager,
+        DocumentWSReceiver,
+        DocumentWebSocket,
+        EditorCommand,
+        RevisionSigner,
+        SignatureCheck,
+        TransformDeltas,
+    },
+    notify::{dart_notify, DocObservable},
 };
 use bytes::Bytes;
 use flowy_collaboration::{
     entities::{
         revision::{RepeatedRevision, Revision, RevisionRange},
-        ws::{DocumentClientWSData, NewDocumentUser},
+        ws::{BackpressureReason, DocumentBackpressure, DocumentClientWSData, NewDocumentUser},
     },
     errors::CollaborateResult,
 };
@@ -21,7 +26,14 @@ use flowy_collaboration::entities::ws::DocumentServerWSDataType;
 
 use lib_ws::WSConnectState;
 use std::{collections::VecDeque, convert::TryFrom, sync::Arc};
-use tokio::sync::{broadcast, mpsc::UnboundedSender, oneshot, RwLock};
+use tokio::{
+    sync::{broadcast, mpsc::UnboundedSender, oneshot, RwLock},
+    time::{Duration, Instant},
+};
+
+// The longest the server can make the client wait, in case it sends a bogus
+// retry time.
+const MAX_BACKPRESSURE_MILLIS: i64 = 60_000;
 
 pub(crate) trait DocumentWebSocketManager: Send + Sync {
     fn stop(&self);
@@ -34,6 +46,7 @@ pub(crate) async fn make_document_ws_manager(
     edit_cmd_tx: UnboundedSender<EditorCommand>,
     rev_manager: Arc<DocumentRevisionManager>,
     ws_conn: Arc<dyn DocumentWebSocket>,
+    signer: Option<Arc<RevisionSigner>>,
 ) -> Arc<dyn DocumentWebSocketManager> {
     // if cfg!(feature = "http_server") {
     //     let shared_sink =
@@ -57,7 +70,10 @@ pub(crate) async fn make_document_ws_manager(
     // } else {
     //     Arc::new(Arc::new(LocalWebSocketManager {}))
     // }
-    let shared_sink = Arc::new(SharedWSSinkDataProvider::new(rev_manager.clone()));
+    let shared_sink = Arc::new(SharedWSSinkDataProvider::new(
+        rev_manager.clone(),
+        RevisionSigning::new(&user_id, signer),
+    ));
     let ws_stream_consumer = Arc::new(DocumentWebSocketSteamConsumerAdapter {
         doc_id: doc_id.clone(),
         edit_cmd_tx,
@@ -107,8 +123,12 @@ impl DocumentWSSteamConsumer for DocumentWebSocketSteamConsumerAdapter {
         let shared_sink = self.shared_sink.clone();
         let doc_id = self.doc_id.clone();
         FutureResult::new(async move {
-            if let Some(server_composed_revision) = handle_remote_revision(edit_cmd_tx, rev_manager, bytes).await? {
-                let data = DocumentClientWSData::from_revisions(&doc_id, vec![server_composed_revision]);
+            let signing = shared_sink.signing.clone();
+            if let Some(server_composed_revision) =
+                handle_remote_revision(edit_cmd_tx, rev_manager, &signing, bytes).await?
+            {
+                let revisions = signing.sign(vec![server_composed_revision]);
+                let data = DocumentClientWSData::from_revisions(&doc_id, revisions);
                 shared_sink.push_back(data).await;
             }
             Ok(())
@@ -131,11 +151,32 @@ impl DocumentWSSteamConsumer for DocumentWebSocketSteamConsumerAdapter {
         let doc_id = self.doc_id.clone();
         FutureResult::new(async move {
             let revisions = rev_manager.get_revisions_in_range(range).await?;
+            let revisions = shared_sink.signing.sign(revisions);
             let data = DocumentClientWSData::from_revisions(&doc_id, revisions);
             shared_sink.push_back(data).await;
             Ok(())
         })
     }
+
+    fn receive_backpressure(&self, backpressure: DocumentBackpressure) -> FutureResult<(), FlowyError> {
+        let shared_sink = self.shared_sink.clone();
+        let doc_id = self.doc_id.clone();
+        FutureResult::new(async move {
+            tracing::warn!("{} receive backpressure: {:?}", doc_id, backpressure);
+            // The server would reject the revision every time it's sent again,
+            // so the document isn't synced anymore until it's opened again.
+            if backpressure.reason == BackpressureReason::PayloadTooLarge {
+                dart_notify(&doc_id, DocObservable::RevisionTooLarge)
+                    .payload(backpressure)
+                    .send();
+                shared_sink.stop().await;
+                return Ok(());
+            }
+            let millis = backpressure.retry_after_millis.clamp(0, MAX_BACKPRESSURE_MILLIS);
+            shared_sink.pause(Duration::from_millis(millis as u64)).await;
+            Ok(())
+        })
+    }
 }
 
 pub(crate) struct DocumentWSSinkDataProviderAdapter(pub(crate) Arc<SharedWSSinkDataProvider>);
@@ -155,16 +196,18 @@ async fn transform_pushed_revisions(
     Ok(rx.await.map_err(internal_error)??)
 }
 
-#[tracing::instrument(level = "debug", skip(edit_cmd_tx, rev_manager, bytes))]
+#[tracing::instrument(level = "debug", skip(edit_cmd_tx, rev_manager, signing, bytes), fields(doc_id = %rev_manager.doc_id))]
 pub(crate) async fn handle_remote_revision(
     edit_cmd_tx: UnboundedSender<EditorCommand>,
     rev_manager: Arc<DocumentRevisionManager>,
+    signing: &RevisionSigning,
     bytes: Bytes,
 ) -> FlowyResult<Option<Revision>> {
     let mut revisions = RepeatedRevision::try_from(bytes)?.into_inner();
     if revisions.is_empty() {
         return Ok(None);
     }
+    signing.verify(&revisions);
 
     let first_revision = revisions.first().unwrap();
     if let Some(local_revision) = rev_manager.get_revision(first_revision.rev_id).await {
@@ -216,19 +259,85 @@ enum SourceType {
     Revision,
 }
 
+// Signs the revisions right before they're sent, the ones on the disk aren't
+// signed. Nothing is signed or verified if the signing is off.
+#[derive(Clone)]
+pub(crate) struct RevisionSigning {
+    user_id: String,
+    signer: Option<Arc<RevisionSigner>>,
+}
+
+impl RevisionSigning {
+    pub(crate) fn new(user_id: &str, signer: Option<Arc<RevisionSigner>>) -> Self {
+        Self {
+            user_id: user_id.to_owned(),
+            signer,
+        }
+    }
+
+    fn sign(&self, mut revisions: Vec<Revision>) -> Vec<Revision> {
+        if let Some(signer) = &self.signer {
+            revisions
+                .iter_mut()
+                .for_each(|revision| signer.sign(&self.user_id, revision));
+        }
+        revisions
+    }
+
+    // The revisions that fail the check are still applied, the server is the
+    // source of truth of the document. The user is told which one it was so
+    // they can look into it, also if it was signed with a key that isn't the
+    // one of its user.
+    fn verify(&self, revisions: &[Revision]) {
+        let signer = match &self.signer {
+            None => return,
+            Some(signer) => signer,
+        };
+        for revision in revisions {
+            let reason = match signer.verify(revision) {
+                SignatureCheck::Verified => continue,
+                SignatureCheck::Unsigned => {
+                    tracing::trace!("The revision {} of {} isn't signed", revision.rev_id, revision.user_id);
+                    continue;
+                },
+                SignatureCheck::NewSigner => "The key isn't the one pinned for the user".to_owned(),
+                SignatureCheck::Invalid(reason) => reason,
+            };
+            tracing::warn!(
+                "The signature of the revision {} of {} is invalid: {}",
+                revision.rev_id,
+                revision.user_id,
+                reason
+            );
+            dart_notify(&revision.doc_id, DocObservable::RevisionSignatureInvalid)
+                .payload(revision.clone())
+                .send();
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct SharedWSSinkDataProvider {
     shared: Arc<RwLock<VecDeque<DocumentClientWSData>>>,
     rev_manager: Arc<DocumentRevisionManager>,
     source_ty: Arc<RwLock<SourceType>>,
+    signing: RevisionSigning,
+    // Nothing is sent before this time after the server asked the client to
+    // back off.
+    resume_at: Arc<RwLock<Option<Instant>>>,
+    // Nothing is sent anymore after the server rejected a revision for good.
+    is_stopped: Arc<RwLock<bool>>,
 }
 
 impl SharedWSSinkDataProvider {
-    pub(crate) fn new(rev_manager: Arc<DocumentRevisionManager>) -> Self {
+    pub(crate) fn new(rev_manager: Arc<DocumentRevisionManager>, signing: RevisionSigning) -> Self {
         SharedWSSinkDataProvider {
             shared: Arc::new(RwLock::new(VecDeque::new())),
             rev_manager,
             source_ty: Arc::new(RwLock::new(SourceType::Shared)),
+            signing,
+            resume_at: Arc::new(RwLock::new(None)),
+            is_stopped: Arc::new(RwLock::new(false)),
         }
     }
 
@@ -237,7 +346,20 @@ impl SharedWSSinkDataProvider {
 
     async fn push_back(&self, data: DocumentClientWSData) { self.shared.write().await.push_back(data); }
 
+    async fn pause(&self, duration: Duration) { *self.resume_at.write().await = Some(Instant::now() + duration); }
+
+    async fn stop(&self) { *self.is_stopped.write().await = true; }
+
     async fn next(&self) -> FlowyResult<Option<DocumentClientWSData>> {
+        if *self.is_stopped.read().await {
+            return Ok(None);
+        }
+        if let Some(resume_at) = *self.resume_at.read().await {
+            if Instant::now() < resume_at {
+                return Ok(None);
+            }
+        }
+
         let source_ty = self.source_ty.read().await.clone();
         match source_ty {
             SourceType::Shared => match self.shared.read().await.front() {
@@ -259,7 +381,8 @@ impl SharedWSSinkDataProvider {
                 match self.rev_manager.next_sync_revision().await? {
                     Some(rev) => {
                         let doc_id = rev.doc_id.clone();
-                        Ok(Some(DocumentClientWSData::from_revisions(&doc_id, vec![rev])))
+                        let revisions = self.signing.sign(vec![rev]);
+                        Ok(Some(DocumentClientWSData::from_revisions(&doc_id, revisions)))
                     },
                     None => {
                         //
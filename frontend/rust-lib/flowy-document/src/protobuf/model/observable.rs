@@ -26,6 +26,9 @@
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum DocObservable {
     UserCreateDoc = 0,
+    DocumentRepaired = 1,
+    RevisionSignatureInvalid = 2,
+    RevisionTooLarge = 3,
 }
 
 impl ::protobuf::ProtobufEnum for DocObservable {
@@ -36,6 +39,9 @@ impl ::protobuf::ProtobufEnum for DocObservable {
     fn from_i32(value: i32) -> ::std::option::Option<DocObservable> {
         match value {
             0 => ::std::option::Option::Some(DocObservable::UserCreateDoc),
+            1 => ::std::option::Option::Some(DocObservable::DocumentRepaired),
+            2 => ::std::option::Option::Some(DocObservable::RevisionSignatureInvalid),
+            3 => ::std::option::Option::Some(DocObservable::RevisionTooLarge),
             _ => ::std::option::Option::None
         }
     }
@@ -43,6 +49,9 @@ impl ::protobuf::ProtobufEnum for DocObservable {
     fn values() -> &'static [Self] {
         static values: &'static [DocObservable] = &[
             DocObservable::UserCreateDoc,
+            DocObservable::DocumentRepaired,
+            DocObservable::RevisionSignatureInvalid,
+            DocObservable::RevisionTooLarge,
         ];
         values
     }
@@ -71,12 +80,9 @@ impl ::protobuf::reflect::ProtobufValue for DocObservable {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10observable.proto*\"\n\rDocObservable\x12\x11\n\rUserCreateDoc\x10\
-    \0JS\n\x06\x12\x04\0\0\x04\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
-    \x05\0\x12\x04\x02\0\x04\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x12\n\
-    \x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x16\n\x0c\n\x05\x05\0\x02\0\x01\
-    \x12\x03\x03\x04\x11\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x14\x15b\
-    \x06proto3\
+    \n\x10observable.proto*n\n\rDocObservable\x12\x11\n\rUserCreateDoc\x10\0\
+    \x12\x14\n\x10DocumentRepaired\x10\x01\x12\x1c\n\x18RevisionSignatureInv\
+    alid\x10\x02\x12\x14\n\x10RevisionTooLarge\x10\x03\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -1,6 +1,7 @@
 mod middleware;
 mod server_api;
 mod server_api_mock;
+mod server_api_supabase;
 
 pub use server_api::*;
 // TODO: ignore mock files in production
@@ -9,6 +10,7 @@ use backend_service::configuration::ClientServerConfiguration;
 use flowy_collaboration::entities::doc::{CreateDocParams, DocumentId, DocumentInfo, ResetDocumentParams};
 use lib_infra::future::FutureResult;
 pub use server_api_mock::*;
+pub use server_api_supabase::*;
 use std::sync::Arc;
 
 pub(crate) type Server = Arc<dyn DocumentServerAPI + Send + Sync>;
@@ -23,7 +25,9 @@ pub trait DocumentServerAPI {
 pub(crate) fn construct_doc_server(
     server_config: &ClientServerConfiguration,
 ) -> Arc<dyn DocumentServerAPI + Send + Sync> {
-    if cfg!(feature = "http_server") {
+    if let Some(supabase) = &server_config.supabase {
+        Arc::new(DocSupabaseServer::new(supabase.clone()))
+    } else if cfg!(feature = "http_server") {
         Arc::new(DocServer::new(server_config.clone()))
     } else {
         Arc::new(DocServerMock {})
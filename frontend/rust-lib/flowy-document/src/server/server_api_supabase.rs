@@ -0,0 +1,80 @@
+use crate::{errors::FlowyError, server::DocumentServerAPI};
+use backend_service::{configuration::SupabaseConfiguration, supabase::SupabaseClient};
+use flowy_collaboration::{
+    entities::{
+        doc::{CreateDocParams, DocumentId, DocumentInfo, ResetDocumentParams},
+        revision::RepeatedRevision,
+    },
+    util::make_delta_from_revisions,
+};
+use lib_infra::future::FutureResult;
+use serde::{Deserialize, Serialize};
+
+const DOCUMENT_TABLE: &str = "af_document";
+
+// Supabase keeps the content of the document, not its revisions. A push
+// replaces the content, there is no realtime sync with the other devices.
+pub struct DocSupabaseServer {
+    client: SupabaseClient,
+}
+
+impl DocSupabaseServer {
+    pub fn new(config: SupabaseConfiguration) -> Self {
+        Self {
+            client: SupabaseClient::new(config),
+        }
+    }
+
+    fn save_doc(&self, token: &str, doc_id: String, revisions: RepeatedRevision) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let revisions = revisions.into_inner();
+            let (base_rev_id, rev_id) = match (revisions.first(), revisions.last()) {
+                (Some(first), Some(last)) => (first.base_rev_id, last.rev_id),
+                _ => (0, 0),
+            };
+            let delta = make_delta_from_revisions(revisions)?;
+            let row = DocumentRow {
+                id: doc_id,
+                data: delta.to_json(),
+                rev_id,
+                base_rev_id,
+            };
+            let _ = client.upsert(&token, DOCUMENT_TABLE, &row).await?;
+            Ok(())
+        })
+    }
+}
+
+impl DocumentServerAPI for DocSupabaseServer {
+    fn create_doc(&self, token: &str, params: CreateDocParams) -> FutureResult<(), FlowyError> {
+        self.save_doc(token, params.id, params.revisions)
+    }
+
+    fn read_doc(&self, token: &str, params: DocumentId) -> FutureResult<Option<DocumentInfo>, FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let query = format!("id=eq.{}", params.doc_id);
+            let mut rows: Vec<DocumentRow> = client.select(&token, DOCUMENT_TABLE, &query).await?;
+            let doc = rows.pop().map(|row| DocumentInfo {
+                doc_id: row.id,
+                text: row.data,
+                rev_id: row.rev_id,
+                base_rev_id: row.base_rev_id,
+            });
+            Ok(doc)
+        })
+    }
+
+    fn update_doc(&self, token: &str, params: ResetDocumentParams) -> FutureResult<(), FlowyError> {
+        self.save_doc(token, params.doc_id, params.revisions)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct DocumentRow {
+    id: String,
+    data: String,
+    rev_id: i64,
+    base_rev_id: i64,
+}
@@ -1,6 +1,25 @@
 use crate::document::edit_script::{EditorScript::*, *};
-use flowy_collaboration::entities::revision::RevisionState;
-use lib_ot::core::{count_utf16_code_units, Interval};
+use bytes::Bytes;
+use ed25519_dalek::{Keypair, Signer};
+use flowy_collaboration::{
+    document::default::initial_delta_string,
+    entities::revision::{Revision, RevisionState},
+    util::md5,
+};
+use flowy_database::{
+    prelude::*,
+    schema::{rev_table, rev_table::dsl},
+};
+use flowy_document::{
+    core::{highlight_code, RevisionSigner, SignatureCheck},
+    errors::ErrorCode,
+};
+use flowy_test::FlowySDKTest;
+use lib_infra::uuid_string;
+use lib_ot::{
+    core::{count_utf16_code_units, Interval},
+    rich_text::RichTextAttribute,
+};
 
 #[tokio::test]
 async fn document_sync_current_rev_id_check() {
@@ -103,3 +122,174 @@ async fn document_sync_replace_test() {
     ];
     EditorTest::new().await.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn document_flush_revisions_test() {
+    let scripts = vec![
+        InsertText("1", 0),
+        InsertText("2", 1),
+        AssertFlushedRevision(1),
+        AssertFlushedRevision(2),
+    ];
+    EditorTest::new().await.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn document_repair_corrupted_revision_test() {
+    let test = EditorTest::new().await;
+    let doc_id = test.editor.doc_id.clone();
+    test.editor.insert(0, "123").await.unwrap();
+    let controller = test.sdk.document_ctx.controller.clone();
+    controller.flush().await.unwrap();
+    controller.close_document(&doc_id).unwrap();
+
+    // A torn write leaves the first revision with data that doesn't match its
+    // md5, the revisions after it are based on it.
+    let records = controller.read_revision_records(&doc_id).unwrap();
+    let first_rev_id = records.first().unwrap().revision.rev_id;
+    let conn = test.sdk.user_session.db_connection().unwrap();
+    let filter = dsl::rev_table
+        .filter(rev_table::doc_id.eq(&doc_id))
+        .filter(rev_table::rev_id.eq(first_rev_id));
+    let _ = diesel::update(filter)
+        .set(rev_table::data.eq(br#"[{"in"#.to_vec()))
+        .execute(&*conn)
+        .unwrap();
+    drop(conn);
+
+    // The document is fetched from the server again since none of the
+    // revisions could be verified.
+    let editor = controller.open_document(&doc_id).await.unwrap();
+    let quarantined = controller.read_quarantined_revisions(&doc_id).unwrap();
+    assert_eq!(quarantined.len(), records.len());
+    assert_eq!(quarantined[0].rev_id, first_rev_id);
+    assert_eq!(quarantined[0].reason, "The md5 doesn't match the data");
+    assert_eq!(editor.doc_delta().await.unwrap().to_json(), initial_delta_string());
+
+    controller.flush().await.unwrap();
+    let records = controller.read_revision_records(&doc_id).unwrap();
+    assert!(!records.is_empty());
+    assert!(records
+        .iter()
+        .all(|record| md5(&record.revision.delta_data) == record.revision.md5));
+}
+
+#[tokio::test]
+async fn document_revision_signature_test() {
+    let _sdk = FlowySDKTest::default();
+    let signer = RevisionSigner::load().unwrap();
+    let user_id = uuid_string();
+    let data = Bytes::from(r#"[{"insert":"123"}]"#);
+    let mut revision = Revision::new("doc", 1, 2, data.clone(), &user_id, md5(&data));
+    signer.sign(&user_id, &mut revision);
+    assert_eq!(signer.verify(&revision), SignatureCheck::Verified);
+
+    let mut tampered = revision.clone();
+    tampered.delta_data = br#"[{"insert":"456"}]"#.to_vec();
+    assert!(matches!(signer.verify(&tampered), SignatureCheck::Invalid(_)));
+
+    let mut unsigned = revision.clone();
+    unsigned.signature = String::new();
+    unsigned.signer_key = String::new();
+    assert!(matches!(signer.verify(&unsigned), SignatureCheck::Invalid(_)));
+
+    // Another key of the user is reported every time, it's never pinned.
+    let other_keypair = Keypair::generate(&mut rand::rngs::OsRng);
+    let mut other_signed = Revision::new("doc", 2, 3, data.clone(), &user_id, md5(&data));
+    other_signed.signature = base64::encode(other_keypair.sign(&other_signed.signing_payload()).to_bytes());
+    other_signed.signer_key = base64::encode(other_keypair.public.to_bytes());
+    assert_eq!(signer.verify(&other_signed), SignatureCheck::NewSigner);
+    assert_eq!(signer.verify(&other_signed), SignatureCheck::NewSigner);
+    assert_eq!(signer.verify(&revision), SignatureCheck::Verified);
+}
+
+#[tokio::test]
+async fn document_table_row_and_column_test() {
+    let test = EditorTest::new().await;
+    test.editor.insert(0, "123").await.unwrap();
+    let table_id = test.editor.insert_table(3, 1, 2).await.unwrap();
+    let row_id = test.editor.insert_table_row(&table_id, 1).await.unwrap();
+    let column_id = test.editor.insert_table_column(&table_id, 0).await.unwrap();
+    let tables = test.editor.read_tables().await.unwrap();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].start, 4);
+    assert_eq!(tables[0].rows.len(), 2);
+    assert_eq!(tables[0].rows[1].id, row_id);
+    assert_eq!(tables[0].column_ids.len(), 3);
+    assert_eq!(tables[0].column_ids[0], column_id);
+
+    test.editor.delete_table_row(&table_id, &row_id).await.unwrap();
+    test.editor.delete_table_column(&table_id, &column_id).await.unwrap();
+    let tables = test.editor.read_tables().await.unwrap();
+    assert_eq!(tables[0].rows.len(), 1);
+    assert_eq!(tables[0].column_ids.len(), 2);
+    assert!(test.editor.insert_table_row("unknown", 0).await.is_err());
+}
+
+#[tokio::test]
+async fn document_code_block_highlight_test() {
+    let test = EditorTest::new().await;
+    let code = "fn main() {}";
+    test.editor.insert(0, code).await.unwrap();
+    let interval = Interval::new(0, code.len());
+    test.editor
+        .format(interval, RichTextAttribute::CodeBlock(true))
+        .await
+        .unwrap();
+    test.editor
+        .format(interval, RichTextAttribute::CodeLanguage("rust"))
+        .await
+        .unwrap();
+
+    let block = test.editor.code_block(3).await.unwrap().unwrap();
+    assert_eq!(block.start, 0);
+    assert_eq!(block.language, "rust");
+    let tokens = highlight_code(&block.text, &block.language);
+    assert!(!tokens.is_empty());
+    assert!(tokens.iter().all(|token| token.start + token.len <= block.text.len()));
+    assert!(highlight_code(&block.text, "unknown").is_empty());
+}
+
+#[tokio::test]
+async fn document_equation_test() {
+    let test = EditorTest::new().await;
+    test.editor.insert(0, "area ").await.unwrap();
+    test.editor.insert_equation(5, "\\pi r^2").await.unwrap();
+    test.editor.insert(6, " m").await.unwrap();
+    test.editor.update_equation(5, "\\pi r^{2}").await.unwrap();
+
+    let equations = test.editor.read_equations().await.unwrap();
+    assert_eq!(equations.len(), 1);
+    assert_eq!(equations[0].index, 5);
+    assert_eq!(equations[0].latex, "\\pi r^{2}");
+
+    let error = test.editor.insert_equation(0, "\\frac{1}{2").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::EquationInvalid.value());
+    assert!(test.editor.update_equation(0, "x").await.is_err());
+}
+
+#[tokio::test]
+async fn document_callout_and_toggle_test() {
+    let test = EditorTest::new().await;
+    test.editor.insert(0, "summary\nbody").await.unwrap();
+    let toggle_id = test.editor.insert_toggle(Interval::new(0, 12)).await.unwrap();
+
+    // The new line after the body is in the toggle, the enter on the empty
+    // line leaves it.
+    test.editor.insert(12, "\n").await.unwrap();
+    assert_eq!(test.editor.read_toggles().await.unwrap()[0].len, 14);
+    test.editor.insert(13, "\n").await.unwrap();
+    let toggles = test.editor.read_toggles().await.unwrap();
+    assert_eq!(toggles.len(), 1);
+    assert_eq!(toggles[0].id, toggle_id);
+    assert_eq!(toggles[0].summary, "summary");
+    assert_eq!(toggles[0].len, 13);
+
+    test.editor
+        .format_callout(Interval::new(0, 7), "💡", Some("yellow".to_owned()))
+        .await
+        .unwrap();
+    assert!(test.editor.document_json().await.unwrap().contains("callout_color"));
+    test.editor.format_callout(Interval::new(0, 7), "", None).await.unwrap();
+    assert!(!test.editor.document_json().await.unwrap().contains("callout"));
+}
@@ -14,6 +14,7 @@ pub enum EditorScript {
     AssertNextRevId(Option<i64>),
     AssertCurrentRevId(i64),
     AssertJson(&'static str),
+    AssertFlushedRevision(i64),
 }
 
 pub struct EditorTest {
@@ -78,6 +79,12 @@ impl EditorTest {
                 }
                 assert_eq!(expected_delta, delta);
             },
+            EditorScript::AssertFlushedRevision(rev_id) => {
+                let controller = self.sdk.document_ctx.controller.clone();
+                controller.flush().await.unwrap();
+                let records = controller.read_revision_records(&self.editor.doc_id).unwrap();
+                assert!(records.iter().any(|record| record.revision.rev_id == rev_id));
+            },
         }
         sleep(Duration::from_millis(SYNC_INTERVAL_IN_MILLIS)).await;
     }
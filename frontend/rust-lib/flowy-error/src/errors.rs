@@ -1,5 +1,5 @@
 use bytes::Bytes;
-use error_code::ErrorCode;
+use error_code::{ErrorCode, RecoveryHint};
 use flowy_derive::ProtoBuf;
 use lib_dispatch::prelude::{EventResponse, ResponseBuilder};
 use std::{convert::TryInto, fmt, fmt::Debug};
@@ -13,6 +13,10 @@ pub struct FlowyError {
 
     #[pb(index = 2)]
     pub msg: String,
+
+    // The value of the `RecoveryHint` of the code.
+    #[pb(index = 3)]
+    pub hint: i32,
 }
 
 macro_rules! static_flowy_error {
@@ -27,8 +31,12 @@ impl FlowyError {
         Self {
             code: code.value(),
             msg: msg.to_owned(),
+            hint: code.recovery_hint().value(),
         }
     }
+
+    pub fn recovery_hint(&self) -> RecoveryHint { RecoveryHint::from_i32(self.hint) }
+
     pub fn context<T: Debug>(mut self, error: T) -> Self {
         self.msg = format!("{:?}", error);
         self
@@ -36,10 +44,15 @@ impl FlowyError {
 
     static_flowy_error!(internal, ErrorCode::Internal);
     static_flowy_error!(record_not_found, ErrorCode::RecordNotFound);
+    static_flowy_error!(database_busy, ErrorCode::DatabaseBusy);
+    static_flowy_error!(database_migration, ErrorCode::DatabaseMigrationRequired);
+    static_flowy_error!(cloud_unsupported, ErrorCode::CloudFeatureUnsupported);
     static_flowy_error!(workspace_name, ErrorCode::WorkspaceNameInvalid);
     static_flowy_error!(workspace_id, ErrorCode::WorkspaceIdInvalid);
     static_flowy_error!(color_style, ErrorCode::AppColorStyleInvalid);
     static_flowy_error!(workspace_desc, ErrorCode::WorkspaceDescTooLong);
+    static_flowy_error!(workspace_permission, ErrorCode::WorkspacePermissionDenied);
+    static_flowy_error!(invitation_id, ErrorCode::InvitationIdInvalid);
     static_flowy_error!(app_name, ErrorCode::AppNameInvalid);
     static_flowy_error!(invalid_app_id, ErrorCode::AppIdInvalid);
     static_flowy_error!(view_name, ErrorCode::ViewNameInvalid);
@@ -47,6 +60,21 @@ impl FlowyError {
     static_flowy_error!(invalid_view_id, ErrorCode::ViewIdInvalid);
     static_flowy_error!(view_desc, ErrorCode::ViewDescTooLong);
     static_flowy_error!(view_data, ErrorCode::ViewDataInvalid);
+    static_flowy_error!(view_access, ErrorCode::ViewAccessDenied);
+    static_flowy_error!(view_move, ErrorCode::ViewMoveInvalid);
+    static_flowy_error!(view_type, ErrorCode::ViewTypeMismatch);
+    static_flowy_error!(folder_name_too_long, ErrorCode::NameTooLong);
+    static_flowy_error!(folder_name_forbidden_char, ErrorCode::NameContainsForbiddenCharacters);
+    static_flowy_error!(folder_name_exists, ErrorCode::NameAlreadyExists);
+    static_flowy_error!(grid_record_not_found, ErrorCode::GridRecordNotFound);
+    static_flowy_error!(cell_data, ErrorCode::CellDataInvalid);
+    static_flowy_error!(formula, ErrorCode::FormulaInvalid);
+    static_flowy_error!(export_path, ErrorCode::ExportPathInvalid);
+    static_flowy_error!(import_file, ErrorCode::ImportFileInvalid);
+    static_flowy_error!(backup_corrupted, ErrorCode::BackupCorrupted);
+    static_flowy_error!(storage_not_configured, ErrorCode::StorageNotConfigured);
+    static_flowy_error!(cloud_operation_queued, ErrorCode::CloudOperationQueued);
+    static_flowy_error!(account_required, ErrorCode::AccountRequired);
     static_flowy_error!(unauthorized, ErrorCode::UserUnauthorized);
     static_flowy_error!(connection, ErrorCode::ConnectError);
     static_flowy_error!(email_empty, ErrorCode::EmailIsEmpty);
@@ -62,6 +90,17 @@ impl FlowyError {
     static_flowy_error!(name_empty, ErrorCode::UserNameIsEmpty);
     static_flowy_error!(user_id, ErrorCode::UserIdInvalid);
     static_flowy_error!(user_not_exist, ErrorCode::UserNotExist);
+    static_flowy_error!(passcode_not_match, ErrorCode::AppPasscodeNotMatch);
+    static_flowy_error!(app_locked, ErrorCode::AppLocked);
+    static_flowy_error!(document_not_found, ErrorCode::DocumentNotFound);
+    static_flowy_error!(revision_invalid, ErrorCode::RevisionInvalid);
+    static_flowy_error!(revision_conflict, ErrorCode::RevisionConflict);
+    static_flowy_error!(document_closed, ErrorCode::DocumentClosed);
+    static_flowy_error!(delta_invalid, ErrorCode::DeltaInvalid);
+    static_flowy_error!(checkpoint_not_found, ErrorCode::CheckpointNotFound);
+    static_flowy_error!(code_block_not_found, ErrorCode::CodeBlockNotFound);
+    static_flowy_error!(equation_invalid, ErrorCode::EquationInvalid);
+    static_flowy_error!(embed_url_invalid, ErrorCode::EmbedUrlInvalid);
 }
 
 impl std::convert::From<ErrorCode> for FlowyError {
@@ -69,6 +108,7 @@ impl std::convert::From<ErrorCode> for FlowyError {
         FlowyError {
             code: code.value(),
             msg: format!("{}", code),
+            hint: code.recovery_hint().value(),
         }
     }
 }
@@ -14,6 +14,7 @@ fn server_error_to_flowy_error(code: ServerErrorCode) -> ErrorCode {
         ServerErrorCode::UserUnauthorized => ErrorCode::UserUnauthorized,
         ServerErrorCode::PasswordNotMatch => ErrorCode::PasswordNotMatch,
         ServerErrorCode::RecordNotFound => ErrorCode::RecordNotFound,
+        ServerErrorCode::PermissionDenied => ErrorCode::WorkspacePermissionDenied,
         ServerErrorCode::ConnectRefused | ServerErrorCode::ConnectTimeout | ServerErrorCode::ConnectClose => {
             ErrorCode::ConnectError
         },
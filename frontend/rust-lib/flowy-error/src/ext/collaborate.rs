@@ -1,5 +1,17 @@
 use crate::FlowyError;
+use flowy_collaboration::errors::{CollaborateError, ErrorCode};
 
-impl std::convert::From<flowy_collaboration::errors::CollaborateError> for FlowyError {
-    fn from(error: flowy_collaboration::errors::CollaborateError) -> Self { FlowyError::internal().context(error) }
+impl std::convert::From<CollaborateError> for FlowyError {
+    fn from(error: CollaborateError) -> Self {
+        let flowy_error = match error.code {
+            ErrorCode::DocIdInvalid | ErrorCode::DocNotfound => FlowyError::document_not_found(),
+            ErrorCode::UndoFail | ErrorCode::RedoFail | ErrorCode::OutOfBound => FlowyError::delta_invalid(),
+            ErrorCode::RevisionConflict => FlowyError::revision_conflict(),
+            ErrorCode::RecordNotFound => FlowyError::record_not_found(),
+            ErrorCode::EquationInvalid => FlowyError::equation_invalid(),
+            ErrorCode::EmbedUrlInvalid => FlowyError::embed_url_invalid(),
+            ErrorCode::InternalError => FlowyError::internal(),
+        };
+        flowy_error.context(error)
+    }
 }
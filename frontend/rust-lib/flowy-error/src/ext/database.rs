@@ -1,15 +1,38 @@
 use crate::FlowyError;
 
 impl std::convert::From<flowy_database::Error> for FlowyError {
-    fn from(error: flowy_database::Error) -> Self { FlowyError::internal().context(error) }
+    fn from(error: flowy_database::Error) -> Self {
+        match &error {
+            flowy_database::Error::NotFound => FlowyError::record_not_found().context(error),
+            flowy_database::Error::DatabaseError(_, info) => {
+                let message = info.message();
+                if message.contains("no such table") || message.contains("no such column") {
+                    // The schema is older than the code, the migrations didn't run.
+                    FlowyError::database_migration().context(error)
+                } else if message.contains("database is locked") {
+                    FlowyError::database_busy().context(error)
+                } else {
+                    FlowyError::internal().context(error)
+                }
+            },
+            _ => FlowyError::internal().context(error),
+        }
+    }
 }
 
+// The pool only fails when it times out waiting for a free connection.
 impl std::convert::From<::r2d2::Error> for FlowyError {
-    fn from(error: r2d2::Error) -> Self { FlowyError::internal().context(error) }
+    fn from(error: r2d2::Error) -> Self { FlowyError::database_busy().context(error) }
 }
 
-// use diesel::result::{Error, DatabaseErrorKind};
-// use lib_sqlite::ErrorKind;
 impl std::convert::From<lib_sqlite::Error> for FlowyError {
-    fn from(error: lib_sqlite::Error) -> Self { FlowyError::internal().context(error) }
+    fn from(error: lib_sqlite::Error) -> Self {
+        match error.0 {
+            lib_sqlite::ErrorKind::R2D2(e) => e.into(),
+            lib_sqlite::ErrorKind::Diesel(e) => e.into(),
+            lib_sqlite::ErrorKind::Migrations(e) => FlowyError::database_migration().context(e),
+            lib_sqlite::ErrorKind::UnknownMigrationExists(version) => FlowyError::database_migration().context(version),
+            kind => FlowyError::internal().context(kind),
+        }
+    }
 }
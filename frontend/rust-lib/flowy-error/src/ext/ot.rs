@@ -1,5 +1,14 @@
 use crate::FlowyError;
+use lib_ot::errors::{OTError, OTErrorCode};
 
-impl std::convert::From<lib_ot::errors::OTError> for FlowyError {
-    fn from(error: lib_ot::errors::OTError) -> Self { FlowyError::internal().context(error) }
+impl std::convert::From<OTError> for FlowyError {
+    fn from(error: OTError) -> Self {
+        let flowy_error = match error.code {
+            OTErrorCode::DuplicatedRevision => FlowyError::revision_invalid(),
+            OTErrorCode::RevisionIDConflict => FlowyError::revision_conflict(),
+            OTErrorCode::Internal => FlowyError::internal(),
+            _ => FlowyError::delta_invalid(),
+        };
+        flowy_error.context(error)
+    }
 }
@@ -2,5 +2,5 @@ mod errors;
 mod ext;
 pub mod protobuf;
 
-pub use error_code::ErrorCode;
+pub use error_code::{ErrorCode, RecoveryHint};
 pub use errors::*;
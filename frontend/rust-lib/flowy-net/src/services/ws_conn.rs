@@ -1,7 +1,7 @@
 use crate::entities::NetworkType;
 
 pub use flowy_error::FlowyError;
-use lib_infra::future::FutureResult;
+use lib_infra::{future::FutureResult, metrics};
 pub use lib_ws::{WSConnectState, WSMessageReceiver, WebSocketRawMessage};
 
 use parking_lot::RwLock;
@@ -26,7 +26,7 @@ pub struct FlowyWebSocketConnect {
     inner: Arc<dyn FlowyRawWebSocket>,
     connect_type: RwLock<NetworkType>,
     status_notifier: broadcast::Sender<NetworkType>,
-    addr: String,
+    addr: RwLock<String>,
 }
 
 impl FlowyWebSocketConnect {
@@ -36,7 +36,7 @@ impl FlowyWebSocketConnect {
             inner: ws,
             connect_type: RwLock::new(NetworkType::default()),
             status_notifier,
-            addr,
+            addr: RwLock::new(addr),
         }
     }
 
@@ -48,7 +48,7 @@ impl FlowyWebSocketConnect {
     }
 
     pub async fn start(&self, token: String, user_id: String) -> Result<(), FlowyError> {
-        let addr = format!("{}/{}", self.addr, &token);
+        let addr = format!("{}/{}", self.addr.read(), &token);
         self.inner.stop_connect().await?;
         let _ = self.inner.start_connect(addr, user_id).await?;
         Ok(())
@@ -56,6 +56,10 @@ impl FlowyWebSocketConnect {
 
     pub async fn stop(&self) { let _ = self.inner.stop_connect().await; }
 
+    // The next start connects to the address, e.g. after the server was
+    // changed.
+    pub fn set_addr(&self, addr: String) { *self.addr.write() = addr; }
+
     pub fn update_network_type(&self, new_type: &NetworkType) {
         tracing::debug!("Network new state: {:?}", new_type);
         let old_type = self.connect_type.read().clone();
@@ -122,6 +126,7 @@ pub fn listen_on_websocket(ws_conn: Arc<FlowyWebSocketConnect>) {
 }
 
 async fn retry_connect(ws: Arc<dyn FlowyRawWebSocket>, count: usize) {
+    metrics::increment_counter(metrics::WS_RECONNECT_COUNT);
     match ws.reconnect(count).await {
         Ok(_) => {},
         Err(e) => {
@@ -58,6 +58,8 @@ impl DocumentUser for DocumentUserImpl {
     fn token(&self) -> Result<String, FlowyError> { self.user.token() }
 
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError> { self.user.db_pool() }
+
+    fn check_read_permission(&self) -> Result<(), FlowyError> { self.user.app_lock.check_unlocked() }
 }
 
 struct DocumentWebSocketAdapter {
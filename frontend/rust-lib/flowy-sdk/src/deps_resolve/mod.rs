@@ -1,5 +1,7 @@
 mod document_deps;
+mod user_deps;
 mod workspace_deps;
 
 pub use document_deps::*;
+pub use user_deps::*;
 pub use workspace_deps::*;
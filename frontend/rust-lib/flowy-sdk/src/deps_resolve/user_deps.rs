@@ -0,0 +1,58 @@
+use bytes::Bytes;
+use flowy_collaboration::entities::revision::{RepeatedRevision, Revision};
+use flowy_document::context::DocumentContext;
+use flowy_user::{errors::FlowyError, services::settings::UserSettingsSync};
+use lib_infra::future::FutureResult;
+use lib_ot::rich_text::RichTextDeltaBuilder;
+use std::sync::Arc;
+
+pub struct UserSettingsSyncImpl {
+    pub document_ctx: Arc<DocumentContext>,
+}
+
+impl UserSettingsSync for UserSettingsSyncImpl {
+    fn create_settings(&self, user_id: &str, json: String) -> FutureResult<(), FlowyError> {
+        let document_ctx = self.document_ctx.clone();
+        let doc_id = settings_doc_id(user_id);
+        let delta_json = RichTextDeltaBuilder::new()
+            .insert(&format!("{}\n", json))
+            .build()
+            .to_json();
+        let repeated_revision: RepeatedRevision =
+            Revision::initial_revision(user_id, &doc_id, Bytes::from(delta_json)).into();
+        FutureResult::new(async move {
+            let _ = document_ctx
+                .controller
+                .save_document(&doc_id, repeated_revision)
+                .await?;
+            Ok(())
+        })
+    }
+
+    fn read_settings(&self, user_id: &str) -> FutureResult<Option<String>, FlowyError> {
+        let document_ctx = self.document_ctx.clone();
+        let doc_id = settings_doc_id(user_id);
+        FutureResult::new(async move {
+            let editor = document_ctx.controller.open_document(&doc_id).await?;
+            let text = editor.document_text().await?;
+            let json = text.trim_end_matches('\n');
+            if json.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(json.to_owned()))
+            }
+        })
+    }
+
+    fn write_settings(&self, user_id: &str, json: String) -> FutureResult<(), FlowyError> {
+        let document_ctx = self.document_ctx.clone();
+        let doc_id = settings_doc_id(user_id);
+        FutureResult::new(async move {
+            let editor = document_ctx.controller.open_document(&doc_id).await?;
+            let _ = editor.reset_text(json).await?;
+            Ok(())
+        })
+    }
+}
+
+fn settings_doc_id(user_id: &str) -> String { format!("{}_settings", user_id) }
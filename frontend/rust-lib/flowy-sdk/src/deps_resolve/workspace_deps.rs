@@ -34,6 +34,8 @@ impl WorkspaceDatabase for Resolver {
             .db_pool()
             .map_err(|e| FlowyError::internal().context(e))
     }
+
+    fn close(&self) -> Result<(), FlowyError> { self.user_session.close_db() }
 }
 
 impl WorkspaceUser for Resolver {
@@ -46,4 +48,11 @@ impl WorkspaceUser for Resolver {
     fn token(&self) -> Result<String, FlowyError> {
         self.user_session.token().map_err(|e| FlowyError::internal().context(e))
     }
+
+    fn is_anonymous(&self) -> bool { self.user_session.is_anonymous() }
+
+    fn settings_json(&self) -> Result<String, FlowyError> {
+        let user_id = self.user_id()?;
+        Ok(self.user_session.settings.settings_json(&user_id))
+    }
 }
@@ -0,0 +1,96 @@
+use flowy_core::errors::{ErrorCode, FlowyError};
+use lib_dispatch::prelude::*;
+use std::{
+    collections::HashSet,
+    convert::TryFrom,
+    panic,
+    sync::{Arc, Once},
+};
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+/// What is sent to the `ErrorReporter`. It never contains the message of the
+/// error or the payload of the panic, they may hold the content of a
+/// document, e.g. the debug print of a delta.
+#[derive(Clone, Debug)]
+pub struct ErrorReport {
+    /// The event that failed, `None` for a panic.
+    pub event: Option<String>,
+
+    /// The value of the `ErrorCode`, `ErrorCode::Internal` for a panic.
+    pub code: i32,
+
+    /// The description of the code, or "panic" for a panic.
+    pub description: String,
+
+    /// The file and the line of the panic.
+    pub location: Option<String>,
+
+    pub thread: Option<String>,
+
+    pub is_panic: bool,
+}
+
+/// Receives the panics and the unexpected errors of the core controllers. It
+/// is opt-in, nothing is reported unless the app registers a reporter with
+/// `FlowySDKConfig::error_reporter`.
+pub trait ErrorReporter: Send + Sync {
+    fn report(&self, report: ErrorReport);
+}
+
+/// The errors that the user can't cause or fix, e.g. an invalid name is
+/// expected but a revision that can't be applied is a bug.
+pub fn is_unexpected(error: &FlowyError) -> bool {
+    matches!(
+        ErrorCode::from_i32(error.code),
+        ErrorCode::Internal | ErrorCode::RevisionInvalid | ErrorCode::DeltaInvalid
+    )
+}
+
+// The panic hook is process-wide, so it's installed once and the reporter of
+// the first `FlowySDK` that has one is kept. The previous hook still runs, the
+// panics are printed as before.
+pub(crate) fn install_panic_hook(reporter: Arc<dyn ErrorReporter>) {
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let report = ErrorReport {
+                event: None,
+                code: ErrorCode::Internal.value(),
+                description: "panic".to_owned(),
+                location: info
+                    .location()
+                    .map(|location| format!("{}:{}", location.file(), location.line())),
+                thread: std::thread::current().name().map(|name| name.to_owned()),
+                is_panic: true,
+            };
+            reporter.report(report);
+            previous_hook(info);
+        }));
+    });
+}
+
+pub(crate) fn core_error_observer(reporter: Arc<dyn ErrorReporter>, core_events: HashSet<Event>) -> ResponseObserver {
+    Arc::new(move |event, response| {
+        if response.status_code != StatusCode::Err || !core_events.contains(event) {
+            return;
+        }
+
+        let error = match <Data<FlowyError>>::try_from(response.payload.clone()) {
+            Ok(error) => error.into_inner(),
+            Err(_) => return,
+        };
+        if !is_unexpected(&error) {
+            return;
+        }
+
+        reporter.report(ErrorReport {
+            event: Some(event.name().to_owned()),
+            code: error.code,
+            description: format!("{}", ErrorCode::from_i32(error.code)),
+            location: None,
+            thread: None,
+            is_panic: false,
+        });
+    })
+}
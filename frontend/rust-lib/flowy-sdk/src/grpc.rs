@@ -0,0 +1,188 @@
+use bytes::{Buf, BufMut};
+use flowy_core::event::WorkspaceEvent;
+use lib_dispatch::prelude::*;
+use std::{net::SocketAddr, sync::Arc};
+use tonic::{
+    body::BoxBody,
+    codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder},
+    codegen::{http, Body, BoxFuture, Context, Never, Poll, Service, StdError},
+    server::{Grpc, UnaryService},
+    transport::{NamedService, Server},
+    Code,
+    Request,
+    Response,
+    Status,
+};
+
+pub const GRPC_SERVICE_NAME: &str = "flowy.Dispatch";
+
+// Only the folders and the documents can be driven through the rpc, the events
+// that manage the members, the sharing, the backups or the app itself aren't
+// served.
+const GRPC_EVENTS: &[WorkspaceEvent] = &[
+    WorkspaceEvent::CreateWorkspace,
+    WorkspaceEvent::ReadCurWorkspace,
+    WorkspaceEvent::ReadWorkspaces,
+    WorkspaceEvent::OpenWorkspace,
+    WorkspaceEvent::ReadWorkspaceApps,
+    WorkspaceEvent::CreateApp,
+    WorkspaceEvent::ReadApp,
+    WorkspaceEvent::UpdateApp,
+    WorkspaceEvent::DeleteApp,
+    WorkspaceEvent::CreateView,
+    WorkspaceEvent::ReadView,
+    WorkspaceEvent::UpdateView,
+    WorkspaceEvent::DeleteView,
+    WorkspaceEvent::OpenView,
+    WorkspaceEvent::CloseView,
+    WorkspaceEvent::ApplyDocDelta,
+    WorkspaceEvent::ReadTrash,
+    WorkspaceEvent::PutbackTrash,
+];
+
+fn exposed_event(path: &str) -> Option<Event> {
+    let name = path.strip_prefix(&format!("/{}/", GRPC_SERVICE_NAME))?;
+    GRPC_EVENTS
+        .iter()
+        .map(|event| Event::from(*event))
+        .find(|event| event.name() == name)
+}
+
+// Each dispatch event is served as an unary rpc of the `flowy.Dispatch`
+// service that is named after the event, e.g.
+// `/flowy.Dispatch/CreateWorkspace`. The messages are the protobuf bytes of the
+// event's input and output, so the clients can generate their stubs from the
+// same proto files as the dart side.
+pub fn spawn_grpc_server(dispatch: Arc<EventDispatcher>, addr: SocketAddr) {
+    let service = DispatchService {
+        dispatch: dispatch.clone(),
+    };
+    dispatch.spawn(async move {
+        tracing::info!("Start the grpc server on {}", addr);
+        if let Err(e) = Server::builder().add_service(service).serve(addr).await {
+            tracing::error!("The grpc server stopped: {:?}", e);
+        }
+    });
+}
+
+#[derive(Clone)]
+struct DispatchService {
+    dispatch: Arc<EventDispatcher>,
+}
+
+impl NamedService for DispatchService {
+    const NAME: &'static str = GRPC_SERVICE_NAME;
+}
+
+impl<B> Service<http::Request<B>> for DispatchService
+where
+    B: Body + Send + Sync + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Never;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> { Poll::Ready(Ok(())) }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let event = match exposed_event(req.uri().path()) {
+            Some(event) => event,
+            None => {
+                let status = Status::unimplemented(format!("{} is not served", req.uri().path()));
+                return Box::pin(async move { Ok(status.to_http()) });
+            },
+        };
+        let handler = EventHandler {
+            event,
+            dispatch: self.dispatch.clone(),
+        };
+        Box::pin(async move {
+            let mut grpc = Grpc::new(BytesCodec);
+            Ok(grpc.unary(handler, req).await)
+        })
+    }
+}
+
+struct EventHandler {
+    event: Event,
+    dispatch: Arc<EventDispatcher>,
+}
+
+impl UnaryService<Vec<u8>> for EventHandler {
+    type Response = Vec<u8>;
+    type Future = BoxFuture<Response<Self::Response>, Status>;
+
+    fn call(&mut self, request: Request<Vec<u8>>) -> Self::Future {
+        let request = ModuleRequest::new(self.event.clone()).payload(request.into_inner());
+        let dispatch = self.dispatch.clone();
+        Box::pin(async move {
+            let response = EventDispatcher::async_send(dispatch, request).await;
+            let payload = match response.payload {
+                Payload::Bytes(bytes) => bytes,
+                Payload::None => bytes::Bytes::new(),
+            };
+
+            // The details of the status carry the same FlowyError bytes that
+            // the dart side receives.
+            match response.status_code {
+                StatusCode::Ok => Ok(Response::new(payload.to_vec())),
+                StatusCode::Err => Err(Status::with_details(Code::Aborted, "The event failed", payload)),
+                StatusCode::Internal => Err(Status::with_details(Code::Internal, "Dispatch failed", payload)),
+            }
+        })
+    }
+}
+
+#[derive(Default)]
+struct BytesCodec;
+
+impl Codec for BytesCodec {
+    type Encode = Vec<u8>;
+    type Decode = Vec<u8>;
+    type Encoder = BytesCodec;
+    type Decoder = BytesCodec;
+
+    fn encoder(&mut self) -> Self::Encoder { BytesCodec }
+
+    fn decoder(&mut self) -> Self::Decoder { BytesCodec }
+}
+
+impl Encoder for BytesCodec {
+    type Item = Vec<u8>;
+    type Error = Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        dst.put_slice(&item);
+        Ok(())
+    }
+}
+
+impl Decoder for BytesCodec {
+    type Item = Vec<u8>;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        let bytes = src.copy_to_bytes(src.remaining());
+        Ok(Some(bytes.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::exposed_event;
+    use flowy_core::event::WorkspaceEvent;
+    use lib_dispatch::prelude::Event;
+
+    #[test]
+    fn grpc_exposed_events() {
+        assert_eq!(
+            exposed_event("/flowy.Dispatch/CreateView"),
+            Some(Event::from(WorkspaceEvent::CreateView))
+        );
+        assert_eq!(exposed_event("/flowy.Dispatch/Shutdown"), None);
+        assert_eq!(exposed_event("/flowy.Dispatch/AddWorkspaceMember"), None);
+        assert_eq!(exposed_event("/flowy.Dispatch/"), None);
+        assert_eq!(exposed_event("/other.Service/CreateView"), None);
+    }
+}
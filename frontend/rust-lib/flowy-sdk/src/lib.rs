@@ -1,14 +1,27 @@
 mod deps_resolve;
+pub mod error_report;
+#[cfg(feature = "grpc_server")]
+pub mod grpc;
+#[cfg(feature = "local_api")]
+pub mod local_api;
 pub mod module;
-use crate::deps_resolve::{DocumentDepsResolver, WorkspaceDepsResolver};
+use crate::{
+    deps_resolve::{DocumentDepsResolver, UserSettingsSyncImpl, WorkspaceDepsResolver},
+    error_report::{core_error_observer, install_panic_hook},
+};
 use backend_service::configuration::ClientServerConfiguration;
+pub use error_report::{ErrorReport, ErrorReporter};
 use flowy_core::{context::CoreContext, errors::FlowyError, module::init_core};
+pub use flowy_core::{
+    name_rules::NameRules,
+    retry::{RetryPolicies, RetryPolicy},
+};
 use flowy_document::context::DocumentContext;
 use flowy_net::{
     entities::NetworkType,
     services::{
         local_ws::LocalWebSocket,
-        ws_conn::{listen_on_websocket, FlowyRawWebSocket, FlowyWebSocketConnect},
+        ws_conn::{listen_on_websocket, FlowyRawWebSocket, FlowyWebSocketConnect, WSConnectState},
     },
 };
 use flowy_user::{
@@ -16,15 +29,19 @@ use flowy_user::{
     services::user::{UserSession, UserSessionConfig},
 };
 use lib_dispatch::prelude::*;
+use lib_infra::clock::RuntimeEnv;
+pub use lib_infra::clock::{Clock, IdGenerator};
+pub use lib_log::{LogRotation, LogSink, RingBufferSink};
 use lib_ws::WSController;
-use module::mk_modules;
 pub use module::*;
+use module::{core_events, mk_modules};
 use std::{
     fmt,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
+    time::Duration,
 };
 use tokio::sync::broadcast;
 
@@ -35,7 +52,24 @@ pub struct FlowySDKConfig {
     name: String,
     root: String,
     log_filter: String,
+    log_rotation: LogRotation,
+    max_log_files: Option<usize>,
+    log_ring_buffer: Option<usize>,
+    log_sinks: Vec<Arc<dyn LogSink>>,
+    slow_operation_threshold: Option<Duration>,
+    revision_cache_capacity: Option<usize>,
+    device_name: String,
+    name_rules: NameRules,
+    retry_policies: RetryPolicies,
+    secure_delete: bool,
+    sign_revisions: bool,
+    error_reporter: Option<Arc<dyn ErrorReporter>>,
+    env: RuntimeEnv,
     server_config: ClientServerConfiguration,
+    #[cfg(feature = "grpc_server")]
+    grpc_addr: Option<std::net::SocketAddr>,
+    #[cfg(feature = "local_api")]
+    local_api_port: Option<u16>,
 }
 
 impl fmt::Debug for FlowySDKConfig {
@@ -54,7 +88,24 @@ impl FlowySDKConfig {
             name: name.to_owned(),
             root: root.to_owned(),
             log_filter: crate_log_filter("info".to_owned()),
+            log_rotation: LogRotation::Daily,
+            max_log_files: None,
+            log_ring_buffer: None,
+            log_sinks: vec![],
+            slow_operation_threshold: None,
+            revision_cache_capacity: None,
+            device_name: std::env::consts::OS.to_owned(),
+            name_rules: NameRules::default(),
+            retry_policies: RetryPolicies::default(),
+            secure_delete: false,
+            sign_revisions: false,
+            error_reporter: None,
+            env: RuntimeEnv::default(),
             server_config,
+            #[cfg(feature = "grpc_server")]
+            grpc_addr: None,
+            #[cfg(feature = "local_api")]
+            local_api_port: None,
         }
     }
 
@@ -62,6 +113,112 @@ impl FlowySDKConfig {
         self.log_filter = crate_log_filter(filter.to_owned());
         self
     }
+
+    pub fn log_rotation(mut self, rotation: LogRotation) -> Self {
+        self.log_rotation = rotation;
+        self
+    }
+
+    pub fn max_log_files(mut self, max_log_files: usize) -> Self {
+        self.max_log_files = Some(max_log_files);
+        self
+    }
+
+    /// Keeps the last `capacity` log lines in memory, the settings page can
+    /// export them with the `ExportLogs` event.
+    pub fn log_ring_buffer(mut self, capacity: usize) -> Self {
+        self.log_ring_buffer = Some(capacity);
+        self
+    }
+
+    pub fn log_sink(mut self, sink: Arc<dyn LogSink>) -> Self {
+        self.log_sinks.push(sink);
+        self
+    }
+
+    /// The database queries and transactions that take longer than the
+    /// threshold are logged as warnings. It's 200ms by default.
+    pub fn slow_operation_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_operation_threshold = Some(threshold);
+        self
+    }
+
+    /// The number of bytes of document revisions that are kept in memory. The
+    /// synced revisions beyond it are dropped and read from the disk again
+    /// when needed. It's 32MB by default.
+    pub fn revision_cache_capacity(mut self, capacity: usize) -> Self {
+        self.revision_cache_capacity = Some(capacity);
+        self
+    }
+
+    pub fn device_name(mut self, device_name: &str) -> Self {
+        self.device_name = device_name.to_owned();
+        self
+    }
+
+    /// The rules that the names of the apps and the views follow, see
+    /// `NameRules`.
+    pub fn name_rules(mut self, rules: NameRules) -> Self {
+        self.name_rules = rules;
+        self
+    }
+
+    /// How the failed calls to the server are retried, per class of call. See
+    /// `RetryPolicies` for the defaults.
+    pub fn retry_policies(mut self, policies: RetryPolicies) -> Self {
+        self.retry_policies = policies;
+        self
+    }
+
+    /// Rebuilds the database file after the trash is emptied or an item of it
+    /// is deleted, so the deleted documents can't be recovered from the free
+    /// pages of the file. It's slow on a big database, off by default.
+    pub fn secure_delete(mut self, enabled: bool) -> Self {
+        self.secure_delete = enabled;
+        self
+    }
+
+    /// Signs the revisions of the documents with a key of the device and
+    /// checks the signatures of the revisions of the other users, so an edit
+    /// that was injected on their behalf is reported. Off by default.
+    pub fn sign_revisions(mut self, enabled: bool) -> Self {
+        self.sign_revisions = enabled;
+        self
+    }
+
+    /// Reports the panics and the unexpected errors of the core controllers,
+    /// without the content of the documents. See `ErrorReport`.
+    pub fn error_reporter(mut self, reporter: Arc<dyn ErrorReporter>) -> Self {
+        self.error_reporter = Some(reporter);
+        self
+    }
+
+    /// Where the controllers and the revisions get the time from, the system
+    /// clock by default. The tests pass a fake clock to control it.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.env.clock = clock;
+        self
+    }
+
+    /// Generates the ids of the objects that the client creates, e.g. a
+    /// duplicated app. Random uuids by default.
+    pub fn id_generator(mut self, id_generator: Arc<dyn IdGenerator>) -> Self {
+        self.env.id_generator = id_generator;
+        self
+    }
+
+    #[cfg(feature = "grpc_server")]
+    pub fn grpc_addr(mut self, addr: std::net::SocketAddr) -> Self {
+        self.grpc_addr = Some(addr);
+        self
+    }
+
+    /// The local api only listens on 127.0.0.1.
+    #[cfg(feature = "local_api")]
+    pub fn local_api_port(mut self, port: u16) -> Self {
+        self.local_api_port = Some(port);
+        self
+    }
 }
 
 fn crate_log_filter(level: String) -> String {
@@ -73,6 +230,8 @@ fn crate_log_filter(level: String) -> String {
     filters.push(format!("flowy_document={}", level));
     filters.push(format!("flowy_collaboration={}", level));
     filters.push(format!("flowy_net={}", level));
+    filters.push(format!("flowy_database={}", level));
+    filters.push(format!("lib_dispatch={}", level));
     filters.push(format!("dart_ffi={}", "info"));
     filters.push(format!("dart_database={}", "info"));
     filters.push(format!("dart_notify={}", level));
@@ -97,20 +256,50 @@ impl FlowySDK {
     pub fn new(config: FlowySDKConfig) -> Self {
         init_log(&config);
         init_kv(&config.root);
+        if let Some(threshold) = config.slow_operation_threshold {
+            flowy_database::slow_log::set_slow_operation_threshold(threshold);
+        }
+        if let Some(capacity) = config.revision_cache_capacity {
+            flowy_document::core::set_revision_cache_capacity(capacity);
+        }
         tracing::debug!("🔥 {:?}", config);
 
+        // The user session applies the server urls that were set at runtime,
+        // so it's made before the websocket reads the address.
+        let user_session = mk_user_session(&config);
         let ws_conn = Arc::new(FlowyWebSocketConnect::new(
             config.server_config.ws_addr(),
             default_web_socket(),
         ));
-        let user_session = mk_user_session(&config);
-        let flowy_document = mk_document(&ws_conn, &user_session, &config.server_config);
-        let core_ctx = mk_core_context(&user_session, &flowy_document, &config.server_config);
+        let flowy_document = mk_document(&ws_conn, &user_session, &config);
+        user_session.settings.set_sync(Arc::new(UserSettingsSyncImpl {
+            document_ctx: flowy_document.clone(),
+        }));
+        let core_ctx = mk_core_context(&user_session, &flowy_document, &config);
 
         //
         let modules = mk_modules(&ws_conn, &core_ctx, &user_session);
-        let dispatcher = Arc::new(EventDispatcher::construct(|| modules));
-        _init(&dispatcher, &ws_conn, &user_session, &core_ctx);
+        let core_events = core_events(&modules);
+        let mut dispatcher = EventDispatcher::construct(|| modules);
+        if let Some(reporter) = &config.error_reporter {
+            install_panic_hook(reporter.clone());
+            let observer = core_error_observer(reporter.clone(), core_events);
+            dispatcher = dispatcher.observe_responses(observer);
+        }
+        let dispatcher = Arc::new(dispatcher);
+        _init(&dispatcher, &ws_conn, &user_session, &core_ctx, &config.server_config);
+
+        #[cfg(feature = "grpc_server")]
+        if let Some(addr) = config.grpc_addr {
+            grpc::spawn_grpc_server(dispatcher.clone(), addr);
+        }
+
+        #[cfg(feature = "local_api")]
+        if let Some(port) = config.local_api_port {
+            if let Err(e) = local_api::spawn_local_api(dispatcher.clone(), &config.root, port) {
+                tracing::error!("Start the local api failed: {:?}", e);
+            }
+        }
 
         Self {
             config,
@@ -123,6 +312,14 @@ impl FlowySDK {
     }
 
     pub fn dispatcher(&self) -> Arc<EventDispatcher> { self.dispatcher.clone() }
+
+    /// Waits until the document revisions are saved to the disk, it should be
+    /// called before the app exits.
+    pub async fn flush(&self) -> Result<(), FlowyError> { self.document_ctx.controller.flush().await }
+
+    /// Flushes the documents like `flush`, and also closes them, posts the
+    /// held notifications and closes the database. Call it when the app quits.
+    pub async fn shutdown(&self) -> Result<(), FlowyError> { self.core.shutdown().await }
 }
 
 fn _init(
@@ -130,40 +327,66 @@ fn _init(
     ws_conn: &Arc<FlowyWebSocketConnect>,
     user_session: &Arc<UserSession>,
     core: &Arc<CoreContext>,
+    server_config: &ClientServerConfiguration,
 ) {
     let subscribe_user_status = user_session.notifier.subscribe_user_status();
     let subscribe_network_type = ws_conn.subscribe_network_ty();
+    let subscribe_ws_state = ws_conn.subscribe_websocket_state();
     let core = core.clone();
     let cloned_core = core.clone();
+    let ws_core = core.clone();
     let user_session = user_session.clone();
     let ws_conn = ws_conn.clone();
+    let server_config = server_config.clone();
 
     dispatch.spawn(async move {
         user_session.init();
         ws_conn.init().await;
         listen_on_websocket(ws_conn.clone());
-        _listen_user_status(ws_conn.clone(), subscribe_user_status, core.clone()).await;
+        _listen_user_status(
+            ws_conn.clone(),
+            subscribe_user_status,
+            user_session.clone(),
+            core.clone(),
+            server_config,
+        )
+        .await;
     });
 
     dispatch.spawn(async move {
         _listen_network_status(subscribe_network_type, cloned_core).await;
     });
+
+    dispatch.spawn(async move {
+        _listen_ws_state(subscribe_ws_state, ws_core).await;
+    });
 }
 
 async fn _listen_user_status(
     ws_conn: Arc<FlowyWebSocketConnect>,
     mut subscribe: broadcast::Receiver<UserStatus>,
+    user_session: Arc<UserSession>,
     core: Arc<CoreContext>,
+    server_config: ClientServerConfiguration,
 ) {
     while let Ok(status) = subscribe.recv().await {
+        // The server may have been changed since the last connection, e.g.
+        // before the sign in.
+        ws_conn.set_addr(server_config.ws_addr());
         let result = || async {
             match status {
                 UserStatus::Login { token, user_id } => {
+                    lib_log::set_log_context("user_id", &user_id);
                     let _ = core.user_did_sign_in(&token).await?;
-                    let _ = ws_conn.start(token, user_id).await?;
+                    // The anonymous user has no account on the server to connect to.
+                    if !user_session.is_anonymous() {
+                        let _ = ws_conn.start(token, user_id).await?;
+                    }
                 },
-                UserStatus::Logout { .. } => {
-                    core.user_did_logout().await;
+                UserStatus::Logout { user_id, .. } => {
+                    lib_log::remove_log_context("user_id");
+                    lib_log::remove_log_context("workspace_id");
+                    core.user_did_logout(&user_id).await;
                     let _ = ws_conn.stop().await;
                 },
                 UserStatus::Expired { .. } => {
@@ -171,10 +394,29 @@ async fn _listen_user_status(
                     let _ = ws_conn.stop().await;
                 },
                 UserStatus::SignUp { profile, ret } => {
+                    lib_log::set_log_context("user_id", &profile.id);
                     let _ = core.user_did_sign_up(&profile.token).await?;
                     let _ = ws_conn.start(profile.token.clone(), profile.id.clone()).await?;
                     let _ = ret.send(());
                 },
+                UserStatus::SignUpAnonymously { profile, ret } => {
+                    lib_log::set_log_context("user_id", &profile.id);
+                    let _ = core.user_did_sign_up_anonymously(&profile.token).await?;
+                    let _ = ret.send(());
+                },
+                UserStatus::Upgraded {
+                    old_user_id,
+                    profile,
+                    ret,
+                } => {
+                    lib_log::set_log_context("user_id", &profile.id);
+                    let _ = core.user_did_upgrade_anonymous(&old_user_id, &profile.token).await?;
+                    let _ = ws_conn.start(profile.token.clone(), profile.id.clone()).await?;
+                    let _ = ret.send(());
+                },
+                UserStatus::ServerChanged { token, user_id } => {
+                    let _ = ws_conn.start(token, user_id).await?;
+                },
             }
             Ok::<(), FlowyError>(())
         };
@@ -192,6 +434,12 @@ async fn _listen_network_status(mut subscribe: broadcast::Receiver<NetworkType>,
     }
 }
 
+async fn _listen_ws_state(mut subscribe: broadcast::Receiver<WSConnectState>, core: Arc<CoreContext>) {
+    while let Ok(state) = subscribe.recv().await {
+        core.ws_state_changed(state);
+    }
+}
+
 fn init_kv(root: &str) {
     match flowy_database::kv::KV::init(root) {
         Ok(_) => {},
@@ -203,26 +451,46 @@ fn init_log(config: &FlowySDKConfig) {
     if !INIT_LOG.load(Ordering::SeqCst) {
         INIT_LOG.store(true, Ordering::SeqCst);
 
-        let _ = lib_log::Builder::new("flowy-client", &config.root)
+        let mut builder = lib_log::Builder::new("flowy-client", &config.root)
             .env_filter(&config.log_filter)
-            .build();
+            .rotation(config.log_rotation);
+        if let Some(max_log_files) = config.max_log_files {
+            builder = builder.max_log_files(max_log_files);
+        }
+        if let Some(capacity) = config.log_ring_buffer {
+            builder = builder.ring_buffer(capacity);
+        }
+        for sink in &config.log_sinks {
+            builder = builder.sink(sink.clone());
+        }
+        let _ = builder.build();
     }
 }
 
 fn mk_user_session(config: &FlowySDKConfig) -> Arc<UserSession> {
     let session_cache_key = format!("{}_session_cache", &config.name);
-    let user_config = UserSessionConfig::new(&config.root, &config.server_config, &session_cache_key);
+    let user_config = UserSessionConfig::new(&config.root, &config.server_config, &session_cache_key)
+        .device_name(&config.device_name);
     Arc::new(UserSession::new(user_config))
 }
 
 fn mk_core_context(
     user_session: &Arc<UserSession>,
     flowy_document: &Arc<DocumentContext>,
-    server_config: &ClientServerConfiguration,
+    config: &FlowySDKConfig,
 ) -> Arc<CoreContext> {
     let workspace_deps = WorkspaceDepsResolver::new(user_session.clone());
     let (user, database) = workspace_deps.split_into();
-    init_core(user, database, flowy_document.clone(), server_config)
+    init_core(
+        user,
+        database,
+        flowy_document.clone(),
+        &config.server_config,
+        config.name_rules.clone(),
+        config.retry_policies.clone(),
+        config.secure_delete,
+        config.env.clone(),
+    )
 }
 
 fn default_web_socket() -> Arc<dyn FlowyRawWebSocket> {
@@ -236,8 +504,15 @@ fn default_web_socket() -> Arc<dyn FlowyRawWebSocket> {
 pub fn mk_document(
     ws_manager: &Arc<FlowyWebSocketConnect>,
     user_session: &Arc<UserSession>,
-    server_config: &ClientServerConfiguration,
+    config: &FlowySDKConfig,
 ) -> Arc<DocumentContext> {
     let (user, ws_receivers, ws_sender) = DocumentDepsResolver::resolve(ws_manager.clone(), user_session.clone());
-    Arc::new(DocumentContext::new(user, ws_receivers, ws_sender, server_config))
+    Arc::new(DocumentContext::new(
+        user,
+        ws_receivers,
+        ws_sender,
+        &config.server_config,
+        config.env.clock.clone(),
+        config.sign_revisions,
+    ))
 }
@@ -0,0 +1,559 @@
+use bytes::Bytes;
+use flowy_collaboration::entities::doc::DocumentDelta;
+use flowy_core::{
+    entities::{
+        app::{App, CreateAppRequest, QueryAppRequest, UpdateAppRequest},
+        view::{CreateViewRequest, QueryViewRequest, UpdateViewRequest, View, ViewType},
+        workspace::{CreateWorkspaceRequest, QueryWorkspaceRequest, RepeatedWorkspace, Workspace},
+    },
+    errors::{ErrorCode, FlowyError},
+    event::WorkspaceEvent,
+};
+use hyper::{
+    header,
+    service::{make_service_fn, service_fn},
+    Body,
+    Method,
+    Request,
+    Response,
+    Server,
+    StatusCode as HttpStatusCode,
+};
+use lib_dispatch::prelude::*;
+use lib_infra::uuid_string;
+use lib_ot::rich_text::{RichTextDelta, RichTextDeltaBuilder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    convert::{Infallible, TryFrom},
+    net::SocketAddr,
+    path::Path,
+    sync::Arc,
+};
+
+/// The token is regenerated on every start and written to this file under the
+/// root of the sdk. The scripts read it and send it as the bearer token.
+pub const LOCAL_API_TOKEN_FILE: &str = "local_api.token";
+
+// Serves the folder and the document events as a JSON api on the loopback
+// interface, so the browser extensions and the scripts can clip content
+// into the current workspace of the user.
+pub fn spawn_local_api(dispatch: Arc<EventDispatcher>, root: &str, port: u16) -> std::io::Result<()> {
+    let token = uuid_string();
+    let _ = write_token_file(&Path::new(root).join(LOCAL_API_TOKEN_FILE), &token)?;
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let server_dispatch = dispatch.clone();
+    dispatch.spawn(async move {
+        let make_service = make_service_fn(move |_| {
+            let dispatch = server_dispatch.clone();
+            let token = token.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle_request(dispatch.clone(), token.clone(), request)
+                }))
+            }
+        });
+
+        match Server::try_bind(&addr) {
+            Ok(builder) => {
+                tracing::info!("Start the local api on {}", addr);
+                if let Err(e) = builder.serve(make_service).await {
+                    tracing::error!("The local api stopped: {:?}", e);
+                }
+            },
+            Err(e) => tracing::error!("Bind the local api on {} failed: {:?}", addr, e),
+        }
+    });
+    Ok(())
+}
+
+// Only the user can read the token, the other accounts of the machine could
+// drive the api otherwise.
+fn write_token_file(path: &Path, token: &str) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // The mode only applies to the new files, the file of a previous
+        // start keeps its permissions.
+        if path.exists() {
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    let mut file = options.open(path)?;
+    std::io::Write::write_all(&mut file, token.as_bytes())
+}
+
+async fn handle_request(
+    dispatch: Arc<EventDispatcher>,
+    token: String,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    if !is_authorized(&request, &token) {
+        return Ok(ApiError::new(
+            HttpStatusCode::UNAUTHORIZED,
+            ErrorCode::UserUnauthorized,
+            "Invalid token",
+        )
+        .into_response());
+    }
+
+    let method = request.method().clone();
+    let path = request.uri().path().trim_matches('/').to_owned();
+    let segments = path.split('/').collect::<Vec<&str>>();
+    let body = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(body) => body,
+        Err(e) => return Ok(ApiError::bad_request(e).into_response()),
+    };
+
+    let dispatch = &dispatch;
+    let result = match (&method, segments.as_slice()) {
+        (&Method::GET, ["api", "workspaces"]) => read_workspaces(dispatch).await,
+        (&Method::POST, ["api", "workspaces"]) => create_workspace(dispatch, body).await,
+        (&Method::DELETE, ["api", "workspaces", id]) => delete_workspace(dispatch, id).await,
+        (&Method::POST, ["api", "apps"]) => create_app(dispatch, body).await,
+        (&Method::GET, ["api", "apps", id]) => read_app(dispatch, id).await,
+        (&Method::PATCH, ["api", "apps", id]) => update_app(dispatch, id, body).await,
+        (&Method::DELETE, ["api", "apps", id]) => delete_app(dispatch, id).await,
+        (&Method::POST, ["api", "views"]) => create_view(dispatch, body).await,
+        (&Method::GET, ["api", "views", id]) => read_view(dispatch, id).await,
+        (&Method::PATCH, ["api", "views", id]) => update_view(dispatch, id, body).await,
+        (&Method::DELETE, ["api", "views", id]) => delete_view(dispatch, id).await,
+        (&Method::GET, ["api", "views", id, "doc"]) => read_document(dispatch, id).await,
+        (&Method::POST, ["api", "views", id, "doc"]) => append_document(dispatch, id, body).await,
+        _ => Err(ApiError::new(
+            HttpStatusCode::NOT_FOUND,
+            ErrorCode::RecordNotFound,
+            "Not found",
+        )),
+    };
+
+    match result {
+        Ok(json) => Ok(json_response(HttpStatusCode::OK, json)),
+        Err(e) => Ok(e.into_response()),
+    }
+}
+
+fn is_authorized(request: &Request<Body>, token: &str) -> bool {
+    match request.headers().get(header::AUTHORIZATION) {
+        None => false,
+        Some(value) => match value.as_bytes().strip_prefix(b"Bearer ") {
+            None => false,
+            Some(bearer) => constant_time_eq(bearer, token.as_bytes()),
+        },
+    }
+}
+
+// Every byte is compared whatever the first mismatch, so the time of the
+// comparison doesn't tell how much of the token was guessed.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+async fn read_workspaces(dispatch: &Arc<EventDispatcher>) -> ApiResult {
+    let request = QueryWorkspaceRequest { workspace_id: None };
+    let workspaces: RepeatedWorkspace = send(dispatch, WorkspaceEvent::ReadWorkspaces, request).await?;
+    to_json(
+        workspaces
+            .items
+            .into_iter()
+            .map(WorkspaceJson::from)
+            .collect::<Vec<_>>(),
+    )
+}
+
+async fn create_workspace(dispatch: &Arc<EventDispatcher>, body: Bytes) -> ApiResult {
+    let params: CreateWorkspaceJson = from_json(&body)?;
+    let request = CreateWorkspaceRequest {
+        name: params.name,
+        desc: params.desc,
+        workspace_id: None,
+    };
+    let workspace: Workspace = send(dispatch, WorkspaceEvent::CreateWorkspace, request).await?;
+    to_json(WorkspaceJson::from(workspace))
+}
+
+async fn delete_workspace(dispatch: &Arc<EventDispatcher>, id: &str) -> ApiResult {
+    let request = QueryWorkspaceRequest {
+        workspace_id: Some(id.to_owned()),
+    };
+    let _ = send_without_output(dispatch, WorkspaceEvent::DeleteWorkspace, request).await?;
+    to_json(())
+}
+
+async fn create_app(dispatch: &Arc<EventDispatcher>, body: Bytes) -> ApiResult {
+    let params: CreateAppJson = from_json(&body)?;
+    let request = CreateAppRequest {
+        workspace_id: params.workspace_id,
+        name: params.name,
+        desc: params.desc,
+        color_style: Default::default(),
+        app_id: params.id,
+    };
+    let app: App = send(dispatch, WorkspaceEvent::CreateApp, request).await?;
+    to_json(AppJson::from(app))
+}
+
+async fn read_app(dispatch: &Arc<EventDispatcher>, id: &str) -> ApiResult {
+    let app: App = send(dispatch, WorkspaceEvent::ReadApp, query_app(id)).await?;
+    to_json(AppJson::from(app))
+}
+
+async fn update_app(dispatch: &Arc<EventDispatcher>, id: &str, body: Bytes) -> ApiResult {
+    let params: UpdateJson = from_json(&body)?;
+    let request = UpdateAppRequest {
+        app_id: id.to_owned(),
+        name: params.name,
+        desc: params.desc,
+        color_style: None,
+        is_trash: None,
+    };
+    let _ = send_without_output(dispatch, WorkspaceEvent::UpdateApp, request).await?;
+    read_app(dispatch, id).await
+}
+
+async fn delete_app(dispatch: &Arc<EventDispatcher>, id: &str) -> ApiResult {
+    let _ = send_without_output(dispatch, WorkspaceEvent::DeleteApp, query_app(id)).await?;
+    to_json(())
+}
+
+async fn create_view(dispatch: &Arc<EventDispatcher>, body: Bytes) -> ApiResult {
+    let params: CreateViewJson = from_json(&body)?;
+    let request = CreateViewRequest {
+        belong_to_id: params.belong_to_id,
+        name: params.name,
+        desc: params.desc,
+        thumbnail: None,
+        view_type: Some(ViewType::Doc),
+        view_id: params.id,
+    };
+    let view: View = send(dispatch, WorkspaceEvent::CreateView, request).await?;
+    to_json(ViewJson::from(view))
+}
+
+async fn read_view(dispatch: &Arc<EventDispatcher>, id: &str) -> ApiResult {
+    let view: View = send(dispatch, WorkspaceEvent::ReadView, query_view(id)).await?;
+    to_json(ViewJson::from(view))
+}
+
+async fn update_view(dispatch: &Arc<EventDispatcher>, id: &str, body: Bytes) -> ApiResult {
+    let params: UpdateJson = from_json(&body)?;
+    let request = UpdateViewRequest {
+        view_id: id.to_owned(),
+        name: params.name,
+        desc: params.desc,
+        thumbnail: None,
+    };
+    let view: View = send(dispatch, WorkspaceEvent::UpdateView, request).await?;
+    to_json(ViewJson::from(view))
+}
+
+async fn delete_view(dispatch: &Arc<EventDispatcher>, id: &str) -> ApiResult {
+    let _ = send_without_output(dispatch, WorkspaceEvent::DeleteView, query_view(id)).await?;
+    to_json(())
+}
+
+async fn read_document(dispatch: &Arc<EventDispatcher>, id: &str) -> ApiResult {
+    let delta: DocumentDelta = send(dispatch, WorkspaceEvent::OpenView, query_view(id)).await?;
+    to_json(DocumentJson::from(delta))
+}
+
+// The text is appended as a new line at the end of the document, in front of
+// the trailing newline that every document keeps.
+async fn append_document(dispatch: &Arc<EventDispatcher>, id: &str, body: Bytes) -> ApiResult {
+    let params: AppendDocumentJson = from_json(&body)?;
+    let document: DocumentDelta = send(dispatch, WorkspaceEvent::OpenView, query_view(id)).await?;
+    let delta = RichTextDelta::from_json(&document.delta_json).map_err(ApiError::internal)?;
+    let retain = delta.utf16_target_len.saturating_sub(1);
+    let text = match retain {
+        0 => params.text,
+        _ => format!("\n{}", params.text),
+    };
+    let append_delta = RichTextDeltaBuilder::new().retain(retain).insert(&text).build();
+
+    let request = DocumentDelta {
+        doc_id: document.doc_id,
+        delta_json: append_delta.to_json(),
+    };
+    let delta: DocumentDelta = send(dispatch, WorkspaceEvent::ApplyDocDelta, request).await?;
+    to_json(DocumentJson::from(delta))
+}
+
+fn query_app(id: &str) -> QueryAppRequest {
+    QueryAppRequest {
+        app_ids: vec![id.to_owned()],
+    }
+}
+
+fn query_view(id: &str) -> QueryViewRequest {
+    QueryViewRequest {
+        view_ids: vec![id.to_owned()],
+    }
+}
+
+async fn send<Req, Res>(dispatch: &Arc<EventDispatcher>, event: WorkspaceEvent, request: Req) -> Result<Res, ApiError>
+where
+    Req: ToBytes,
+    Res: FromBytes,
+{
+    let payload = send_without_output(dispatch, event, request).await?;
+    let data = <Data<Res>>::try_from(payload).map_err(ApiError::internal)?;
+    Ok(data.into_inner())
+}
+
+async fn send_without_output<Req>(
+    dispatch: &Arc<EventDispatcher>,
+    event: WorkspaceEvent,
+    request: Req,
+) -> Result<Payload, ApiError>
+where
+    Req: ToBytes,
+{
+    let bytes = request.into_bytes().map_err(ApiError::bad_request)?;
+    let request = ModuleRequest::new(event).payload(bytes);
+    let response = EventDispatcher::async_send(dispatch.clone(), request).await;
+    match response.status_code {
+        StatusCode::Ok => Ok(response.payload),
+        StatusCode::Err => match <Data<FlowyError>>::try_from(response.payload) {
+            Ok(error) => Err(error.into_inner().into()),
+            Err(e) => Err(ApiError::internal(e)),
+        },
+        StatusCode::Internal => Err(ApiError::new(
+            HttpStatusCode::INTERNAL_SERVER_ERROR,
+            ErrorCode::Internal,
+            "Dispatch failed",
+        )),
+    }
+}
+
+type ApiResult = Result<String, ApiError>;
+
+fn to_json<T: Serialize>(value: T) -> ApiResult { serde_json::to_string(&value).map_err(ApiError::internal) }
+
+fn from_json<T: DeserializeOwned>(body: &Bytes) -> Result<T, ApiError> {
+    serde_json::from_slice(body).map_err(ApiError::bad_request)
+}
+
+fn json_response(status: HttpStatusCode, json: String) -> Response<Body> {
+    let mut response = Response::new(Body::from(json));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+#[derive(Debug, Serialize)]
+struct ApiError {
+    #[serde(skip)]
+    status: HttpStatusCode,
+    code: i32,
+    msg: String,
+}
+
+impl ApiError {
+    fn new(status: HttpStatusCode, code: ErrorCode, msg: &str) -> Self {
+        Self {
+            status,
+            code: code.value(),
+            msg: msg.to_owned(),
+        }
+    }
+
+    fn bad_request<T: std::fmt::Debug>(e: T) -> Self {
+        Self::new(
+            HttpStatusCode::BAD_REQUEST,
+            ErrorCode::LocalApiRequestInvalid,
+            &format!("{:?}", e),
+        )
+    }
+
+    fn internal<T: std::fmt::Debug>(e: T) -> Self {
+        Self::new(
+            HttpStatusCode::INTERNAL_SERVER_ERROR,
+            ErrorCode::Internal,
+            &format!("{:?}", e),
+        )
+    }
+
+    fn into_response(self) -> Response<Body> {
+        let json = serde_json::to_string(&self).unwrap_or_default();
+        json_response(self.status, json)
+    }
+}
+
+impl std::convert::From<FlowyError> for ApiError {
+    fn from(error: FlowyError) -> Self {
+        Self {
+            status: HttpStatusCode::BAD_REQUEST,
+            code: error.code,
+            msg: error.msg,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CreateWorkspaceJson {
+    name: String,
+    #[serde(default)]
+    desc: String,
+}
+
+#[derive(Deserialize)]
+struct CreateAppJson {
+    workspace_id: String,
+    name: String,
+    #[serde(default)]
+    desc: String,
+    id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CreateViewJson {
+    belong_to_id: String,
+    name: String,
+    #[serde(default)]
+    desc: String,
+    id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct UpdateJson {
+    name: Option<String>,
+    desc: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AppendDocumentJson {
+    text: String,
+}
+
+#[derive(Serialize)]
+struct WorkspaceJson {
+    id: String,
+    name: String,
+    desc: String,
+}
+
+impl std::convert::From<Workspace> for WorkspaceJson {
+    fn from(workspace: Workspace) -> Self {
+        Self {
+            id: workspace.id,
+            name: workspace.name,
+            desc: workspace.desc,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AppJson {
+    id: String,
+    workspace_id: String,
+    name: String,
+    desc: String,
+    views: Vec<ViewJson>,
+}
+
+impl std::convert::From<App> for AppJson {
+    fn from(app: App) -> Self {
+        Self {
+            id: app.id,
+            workspace_id: app.workspace_id,
+            name: app.name,
+            desc: app.desc,
+            views: app.belongings.items.into_iter().map(ViewJson::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ViewJson {
+    id: String,
+    belong_to_id: String,
+    name: String,
+    desc: String,
+}
+
+impl std::convert::From<View> for ViewJson {
+    fn from(view: View) -> Self {
+        Self {
+            id: view.id,
+            belong_to_id: view.belong_to_id,
+            name: view.name,
+            desc: view.desc,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DocumentJson {
+    doc_id: String,
+    delta_json: String,
+}
+
+impl std::convert::From<DocumentDelta> for DocumentJson {
+    fn from(delta: DocumentDelta) -> Self {
+        Self {
+            doc_id: delta.doc_id,
+            delta_json: delta.delta_json,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{constant_time_eq, is_authorized, write_token_file, ApiError};
+    use flowy_core::errors::ErrorCode;
+    use hyper::{header, Body, Request};
+    use lib_infra::uuid_string;
+
+    #[test]
+    fn local_api_token_compare() {
+        assert!(constant_time_eq(b"token", b"token"));
+        assert!(!constant_time_eq(b"token", b"tokem"));
+        assert!(!constant_time_eq(b"token", b"token1"));
+        assert!(!constant_time_eq(b"", b"token"));
+
+        let request = |value: &str| {
+            Request::builder()
+                .header(header::AUTHORIZATION, value)
+                .body(Body::empty())
+                .unwrap()
+        };
+        assert!(is_authorized(&request("Bearer token"), "token"));
+        assert!(!is_authorized(&request("Bearer tokem"), "token"));
+        assert!(!is_authorized(&request("token"), "token"));
+        assert!(!is_authorized(&Request::new(Body::empty()), "token"));
+    }
+
+    #[test]
+    fn local_api_error_code() {
+        assert_eq!(
+            ApiError::bad_request("invalid json").code,
+            ErrorCode::LocalApiRequestInvalid.value()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn local_api_token_file_readable_by_user_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(uuid_string());
+        std::fs::write(&path, "old").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_token_file(&path, "token").unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "token");
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -1,8 +1,8 @@
 use flowy_core::context::CoreContext;
 use flowy_net::services::ws_conn::FlowyWebSocketConnect;
 use flowy_user::services::user::UserSession;
-use lib_dispatch::prelude::Module;
-use std::sync::Arc;
+use lib_dispatch::prelude::{Event, Module};
+use std::{collections::HashSet, sync::Arc};
 
 pub fn mk_modules(
     ws_conn: &Arc<FlowyWebSocketConnect>,
@@ -15,6 +15,14 @@ pub fn mk_modules(
     vec![user_module, core_module, network_module]
 }
 
+pub(crate) fn core_events(modules: &[Module]) -> HashSet<Event> {
+    modules
+        .iter()
+        .filter(|module| module.name == flowy_core::module::MODULE_NAME)
+        .flat_map(|module| module.events())
+        .collect()
+}
+
 fn mk_user_module(user_session: Arc<UserSession>) -> Module { flowy_user::module::create(user_session) }
 
 fn mk_core_module(core: Arc<CoreContext>) -> Module { flowy_core::module::create(core) }
@@ -0,0 +1,83 @@
+use chrono::{DateTime, TimeZone, Utc};
+use futures_util::future::BoxFuture;
+use lib_infra::clock::{Clock, IdGenerator};
+use parking_lot::Mutex;
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+use tokio::sync::oneshot;
+
+/// A clock that only moves when the test calls `advance`. A sleep on it, e.g.
+/// the deferred save of the revisions or the wait before a retry, resolves once
+/// the clock is advanced past its deadline.
+pub struct FakeClock {
+    state: Mutex<FakeClockState>,
+}
+
+struct FakeClockState {
+    now: DateTime<Utc>,
+    sleepers: Vec<(DateTime<Utc>, oneshot::Sender<()>)>,
+}
+
+impl std::default::Default for FakeClock {
+    fn default() -> Self { FakeClock::starting_at(Utc.timestamp(1_600_000_000, 0)) }
+}
+
+impl FakeClock {
+    pub fn starting_at(now: DateTime<Utc>) -> Self {
+        FakeClock {
+            state: Mutex::new(FakeClockState { now, sleepers: vec![] }),
+        }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        let due = {
+            let mut state = self.state.lock();
+            state.now = state.now + to_chrono(duration);
+            let now = state.now;
+            let (due, pending): (Vec<_>, Vec<_>) = state.sleepers.drain(..).partition(|(deadline, _)| *deadline <= now);
+            state.sleepers = pending;
+            due
+        };
+        for (_, sender) in due {
+            let _ = sender.send(());
+        }
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> { self.state.lock().now }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        if duration == Duration::from_millis(0) {
+            return Box::pin(async {});
+        }
+
+        let mut state = self.state.lock();
+        let deadline = state.now + to_chrono(duration);
+        let (sender, receiver) = oneshot::channel();
+        state.sleepers.push((deadline, sender));
+        Box::pin(async move {
+            let _ = receiver.await;
+        })
+    }
+}
+
+fn to_chrono(duration: Duration) -> chrono::Duration {
+    chrono::Duration::from_std(duration).unwrap_or_else(|_| chrono::Duration::max_value())
+}
+
+/// Generates "00000000-0000-4000-8000-000000000001", then "...0002" and so on.
+/// They are valid uuids, so they pass the same checks as the random ones.
+#[derive(Default)]
+pub struct SequentialIdGenerator {
+    count: AtomicU64,
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_id(&self) -> String {
+        let n = self.count.fetch_add(1, Ordering::SeqCst) + 1;
+        format!("00000000-0000-4000-8000-{:012x}", n)
+    }
+}
@@ -111,6 +111,7 @@ pub async fn create_workspace(sdk: &FlowySDKTest, name: &str, desc: &str) -> Wor
     let request = CreateWorkspaceRequest {
         name: name.to_owned(),
         desc: desc.to_owned(),
+        workspace_id: None,
     };
 
     let workspace = CoreModuleEventBuilder::new(sdk.clone())
@@ -162,6 +163,7 @@ pub async fn create_app(sdk: &FlowySDKTest, name: &str, desc: &str, workspace_id
         name: name.to_string(),
         desc: desc.to_string(),
         color_style: Default::default(),
+        app_id: None,
     };
 
     let app = CoreModuleEventBuilder::new(sdk.clone())
@@ -220,7 +222,8 @@ pub async fn create_view(sdk: &FlowySDKTest, app_id: &str) -> View {
         name: "View A".to_string(),
         desc: "".to_string(),
         thumbnail: Some("http://1.png".to_string()),
-        view_type: ViewType::Doc,
+        view_type: Some(ViewType::Doc),
+        view_id: None,
     };
 
     create_view_with_request(sdk, request).await
@@ -1,14 +1,17 @@
 pub mod event_builder;
+pub mod fake;
 pub mod helper;
 
 use crate::helper::*;
 use backend_service::configuration::{get_client_server_configuration, ClientServerConfiguration};
-use flowy_sdk::{FlowySDK, FlowySDKConfig};
+use flowy_sdk::{Clock, ErrorReporter, FlowySDK, FlowySDKConfig, IdGenerator, NameRules};
 use flowy_user::entities::UserProfile;
 use lib_infra::uuid_string;
+use std::sync::Arc;
 
 pub mod prelude {
-    pub use crate::{event_builder::*, helper::*, *};
+    pub use crate::{event_builder::*, fake::*, helper::*, *};
+    pub use flowy_sdk::error_report::{is_unexpected, ErrorReport, ErrorReporter};
     pub use lib_dispatch::prelude::*;
 }
 
@@ -40,6 +43,47 @@ impl FlowySDKTest {
         Self { inner: sdk }
     }
 
+    pub fn with_name_rules(rules: NameRules) -> Self {
+        let server_config = get_client_server_configuration().unwrap();
+        let config = FlowySDKConfig::new(&root_dir(), server_config, &uuid_string())
+            .log_filter("trace")
+            .name_rules(rules);
+        let sdk = FlowySDK::new(config);
+        std::mem::forget(sdk.dispatcher());
+        Self { inner: sdk }
+    }
+
+    pub fn with_error_reporter(reporter: Arc<dyn ErrorReporter>) -> Self {
+        let server_config = get_client_server_configuration().unwrap();
+        let config = FlowySDKConfig::new(&root_dir(), server_config, &uuid_string())
+            .log_filter("trace")
+            .error_reporter(reporter);
+        let sdk = FlowySDK::new(config);
+        std::mem::forget(sdk.dispatcher());
+        Self { inner: sdk }
+    }
+
+    pub fn with_secure_delete() -> Self {
+        let server_config = get_client_server_configuration().unwrap();
+        let config = FlowySDKConfig::new(&root_dir(), server_config, &uuid_string())
+            .log_filter("trace")
+            .secure_delete(true);
+        let sdk = FlowySDK::new(config);
+        std::mem::forget(sdk.dispatcher());
+        Self { inner: sdk }
+    }
+
+    pub fn with_clock_and_ids(clock: Arc<dyn Clock>, id_generator: Arc<dyn IdGenerator>) -> Self {
+        let server_config = get_client_server_configuration().unwrap();
+        let config = FlowySDKConfig::new(&root_dir(), server_config, &uuid_string())
+            .log_filter("trace")
+            .clock(clock)
+            .id_generator(id_generator);
+        let sdk = FlowySDK::new(config);
+        std::mem::forget(sdk.dispatcher());
+        Self { inner: sdk }
+    }
+
     pub async fn sign_up(&self) -> SignUpContext {
         let context = async_sign_up(self.inner.dispatcher()).await;
         context
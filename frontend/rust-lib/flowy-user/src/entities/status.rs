@@ -9,6 +9,7 @@ pub enum UserStatus {
     },
     Logout {
         token: String,
+        user_id: String,
     },
     Expired {
         token: String,
@@ -17,4 +18,19 @@ pub enum UserStatus {
         profile: UserProfile,
         ret: mpsc::Sender<()>,
     },
+    SignUpAnonymously {
+        profile: UserProfile,
+        ret: mpsc::Sender<()>,
+    },
+    Upgraded {
+        old_user_id: String,
+        profile: UserProfile,
+        ret: mpsc::Sender<()>,
+    },
+    // The server urls of the signed in user were changed, the connections are
+    // made again.
+    ServerChanged {
+        token: String,
+        user_id: String,
+    },
 }
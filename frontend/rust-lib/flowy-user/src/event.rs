@@ -5,23 +5,80 @@ use strum_macros::Display;
 #[event_err = "FlowyError"]
 pub enum UserEvent {
     #[event()]
-    InitUser       = 0,
+    InitUser             = 0,
 
     #[event(input = "SignInRequest", output = "UserProfile")]
-    SignIn         = 1,
+    SignIn               = 1,
 
     #[event(input = "SignUpRequest", output = "UserProfile")]
-    SignUp         = 2,
+    SignUp               = 2,
 
     #[event(passthrough)]
-    SignOut        = 3,
+    SignOut              = 3,
 
     #[event(input = "UpdateUserRequest")]
-    UpdateUser     = 4,
+    UpdateUser           = 4,
 
     #[event(output = "UserProfile")]
-    GetUserProfile = 5,
+    GetUserProfile       = 5,
 
     #[event(output = "UserProfile")]
-    CheckUser      = 6,
+    CheckUser            = 6,
+
+    #[event(output = "UserProfile")]
+    SignInAnonymously    = 7,
+
+    #[event(input = "SignUpRequest", output = "UserProfile")]
+    UpgradeAnonymousUser = 8,
+
+    #[event(output = "AppLockState")]
+    GetAppLockState      = 9,
+
+    #[event(input = "SetAppPasscodeRequest", output = "AppLockState")]
+    SetAppPasscode       = 10,
+
+    #[event(input = "AppPasscodeRequest", output = "AppLockState")]
+    RemoveAppPasscode    = 11,
+
+    #[event(output = "AppLockState")]
+    LockApp              = 12,
+
+    #[event(input = "AppPasscodeRequest", output = "AppLockState")]
+    UnlockApp            = 13,
+
+    #[event(input = "AppPasscodeRequest")]
+    VerifyAppPasscode    = 14,
+
+    #[event()]
+    RefreshAppLock       = 15,
+
+    #[event(output = "UserSettings")]
+    GetUserSettings      = 16,
+
+    #[event(input = "UpdateUserSettingsRequest", output = "UserSettings")]
+    UpdateUserSettings   = 17,
+
+    #[event(input = "UploadAvatarRequest", output = "UserAvatar")]
+    UploadAvatar         = 18,
+
+    #[event(input = "UserAvatarRequest", output = "UserAvatar")]
+    GetUserAvatar        = 19,
+
+    #[event(passthrough)]
+    DeleteAccount        = 20,
+
+    #[event(output = "RepeatedUserDevice")]
+    GetUserDevices       = 21,
+
+    #[event(input = "RevokeDeviceRequest")]
+    RevokeUserDevice     = 22,
+
+    #[event(output = "ExportedLogs")]
+    ExportLogs           = 23,
+
+    #[event(output = "ServerUrlSetting")]
+    GetServerUrl         = 24,
+
+    #[event(input = "UpdateServerUrlRequest", output = "ServerUrlSetting")]
+    UpdateServerUrl      = 25,
 }
@@ -0,0 +1,61 @@
+use crate::{entities::*, errors::FlowyError, services::lock::AppLock};
+
+use lib_dispatch::prelude::*;
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(skip(app_lock))]
+pub async fn get_app_lock_state_handler(app_lock: Unit<Arc<AppLock>>) -> DataResult<AppLockState, FlowyError> {
+    data_result(app_lock.state())
+}
+
+#[tracing::instrument(skip(data, app_lock), err)]
+pub async fn set_app_passcode_handler(
+    data: Data<SetAppPasscodeRequest>,
+    app_lock: Unit<Arc<AppLock>>,
+) -> DataResult<AppLockState, FlowyError> {
+    let params: SetAppPasscodeParams = data.into_inner().try_into()?;
+    let state = app_lock.set_passcode(params)?;
+    data_result(state)
+}
+
+#[tracing::instrument(skip(data, app_lock), err)]
+pub async fn remove_app_passcode_handler(
+    data: Data<AppPasscodeRequest>,
+    app_lock: Unit<Arc<AppLock>>,
+) -> DataResult<AppLockState, FlowyError> {
+    let params: AppPasscodeParams = data.into_inner().try_into()?;
+    let state = app_lock.remove_passcode(&params.passcode)?;
+    data_result(state)
+}
+
+#[tracing::instrument(skip(app_lock))]
+pub async fn lock_app_handler(app_lock: Unit<Arc<AppLock>>) -> DataResult<AppLockState, FlowyError> {
+    data_result(app_lock.lock())
+}
+
+#[tracing::instrument(skip(data, app_lock), err)]
+pub async fn unlock_app_handler(
+    data: Data<AppPasscodeRequest>,
+    app_lock: Unit<Arc<AppLock>>,
+) -> DataResult<AppLockState, FlowyError> {
+    let params: AppPasscodeParams = data.into_inner().try_into()?;
+    let state = app_lock.unlock(&params.passcode)?;
+    data_result(state)
+}
+
+#[tracing::instrument(skip(data, app_lock), err)]
+pub async fn verify_app_passcode_handler(
+    data: Data<AppPasscodeRequest>,
+    app_lock: Unit<Arc<AppLock>>,
+) -> Result<(), FlowyError> {
+    let params: AppPasscodeParams = data.into_inner().try_into()?;
+    let _ = app_lock.verify(&params.passcode)?;
+    Ok(())
+}
+
+// Called by the frontend on user interaction to postpone the idle lock.
+#[tracing::instrument(skip(app_lock))]
+pub async fn refresh_app_lock_handler(app_lock: Unit<Arc<AppLock>>) -> Result<(), FlowyError> {
+    app_lock.touch();
+    Ok(())
+}
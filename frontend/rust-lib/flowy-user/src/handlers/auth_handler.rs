@@ -31,3 +31,28 @@ pub async fn sign_up(
 
     data_result(user_profile)
 }
+
+#[tracing::instrument(name = "sign_in_anonymously", skip(session), err)]
+pub async fn sign_in_anonymously(session: Unit<Arc<UserSession>>) -> DataResult<UserProfile, FlowyError> {
+    let user_profile = session.sign_in_anonymously().await?;
+    data_result(user_profile)
+}
+
+#[tracing::instrument(
+    name = "upgrade_anonymous_user",
+    skip(data, session),
+    fields(
+        email = %data.email,
+        name = %data.name,
+    ),
+    err
+)]
+pub async fn upgrade_anonymous_user(
+    data: Data<SignUpRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserProfile, FlowyError> {
+    let params: SignUpParams = data.into_inner().try_into()?;
+    let user_profile = session.upgrade_anonymous_user(params).await?;
+
+    data_result(user_profile)
+}
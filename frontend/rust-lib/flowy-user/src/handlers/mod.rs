@@ -1,5 +1,7 @@
+mod app_lock_handler;
 mod auth_handler;
 mod user_handler;
 
+pub use app_lock_handler::*;
 pub use auth_handler::*;
 pub use user_handler::*;
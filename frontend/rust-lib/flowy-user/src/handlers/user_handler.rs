@@ -1,4 +1,8 @@
-use crate::{entities::*, errors::FlowyError, services::user::UserSession};
+use crate::{
+    entities::*,
+    errors::{ErrorCode, FlowyError},
+    services::user::UserSession,
+};
 
 use lib_dispatch::prelude::*;
 use std::{convert::TryInto, sync::Arc};
@@ -27,6 +31,12 @@ pub async fn sign_out(session: Unit<Arc<UserSession>>) -> Result<(), FlowyError>
     Ok(())
 }
 
+#[tracing::instrument(name = "delete_account", skip(session))]
+pub async fn delete_account_handler(session: Unit<Arc<UserSession>>) -> Result<(), FlowyError> {
+    let _ = session.delete_account().await?;
+    Ok(())
+}
+
 #[tracing::instrument(name = "update_user", skip(data, session))]
 pub async fn update_user_handler(
     data: Data<UpdateUserRequest>,
@@ -36,3 +46,80 @@ pub async fn update_user_handler(
     session.update_user(params).await?;
     Ok(())
 }
+
+#[tracing::instrument(skip(session))]
+pub async fn get_user_settings_handler(session: Unit<Arc<UserSession>>) -> DataResult<UserSettings, FlowyError> {
+    let settings = session.user_settings()?;
+    data_result(settings)
+}
+
+#[tracing::instrument(skip(data, session))]
+pub async fn update_user_settings_handler(
+    data: Data<UpdateUserSettingsRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserSettings, FlowyError> {
+    let settings: UserSettings = data.into_inner().try_into()?;
+    let settings = session.update_user_settings(settings)?;
+    data_result(settings)
+}
+
+// Only reads the memory, so it also works when the user is not signed in.
+#[tracing::instrument]
+pub async fn export_logs_handler() -> DataResult<ExportedLogs, FlowyError> {
+    match lib_log::export_ring_buffer() {
+        None => Err(ErrorCode::LogRingBufferDisabled.into()),
+        Some(content) => data_result(ExportedLogs { content }),
+    }
+}
+
+#[tracing::instrument(skip(data, session))]
+pub async fn upload_avatar_handler(
+    data: Data<UploadAvatarRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserAvatar, FlowyError> {
+    let params: UploadAvatarParams = data.into_inner().try_into()?;
+    let avatar = session.upload_avatar(params).await?;
+    data_result(avatar)
+}
+
+#[tracing::instrument(skip(data, session))]
+pub async fn get_user_avatar_handler(
+    data: Data<UserAvatarRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserAvatar, FlowyError> {
+    let params: UserAvatarParams = data.into_inner().try_into()?;
+    let avatar = session.read_avatar(&params.user_id).await?;
+    data_result(avatar)
+}
+
+#[tracing::instrument(skip(session))]
+pub async fn get_user_devices_handler(session: Unit<Arc<UserSession>>) -> DataResult<RepeatedUserDevice, FlowyError> {
+    let devices = session.read_user_devices().await?;
+    data_result(devices)
+}
+
+#[tracing::instrument(skip(data, session))]
+pub async fn revoke_user_device_handler(
+    data: Data<RevokeDeviceRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), FlowyError> {
+    let params: RevokeDeviceParams = data.into_inner().try_into()?;
+    let _ = session.revoke_user_device(params).await?;
+    Ok(())
+}
+
+// Works when the user is not signed in, e.g. to pick the server to sign in to.
+#[tracing::instrument(skip(session))]
+pub async fn get_server_url_handler(session: Unit<Arc<UserSession>>) -> DataResult<ServerUrlSetting, FlowyError> {
+    data_result(session.server_url_setting())
+}
+
+#[tracing::instrument(skip(data, session))]
+pub async fn update_server_url_handler(
+    data: Data<UpdateServerUrlRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<ServerUrlSetting, FlowyError> {
+    let params: UpdateServerUrlParams = data.into_inner().try_into()?;
+    let setting = session.update_server_url(params)?;
+    data_result(setting)
+}
@@ -6,6 +6,7 @@ use std::sync::Arc;
 pub fn create(user_session: Arc<UserSession>) -> Module {
     Module::new()
         .name("Flowy-User")
+        .data(user_session.app_lock.clone())
         .data(user_session)
         .event(UserEvent::SignIn, sign_in)
         .event(UserEvent::SignUp, sign_up)
@@ -14,4 +15,23 @@ pub fn create(user_session: Arc<UserSession>) -> Module {
         .event(UserEvent::SignOut, sign_out)
         .event(UserEvent::UpdateUser, update_user_handler)
         .event(UserEvent::CheckUser, check_user_handler)
+        .event(UserEvent::SignInAnonymously, sign_in_anonymously)
+        .event(UserEvent::UpgradeAnonymousUser, upgrade_anonymous_user)
+        .event(UserEvent::GetAppLockState, get_app_lock_state_handler)
+        .event(UserEvent::SetAppPasscode, set_app_passcode_handler)
+        .event(UserEvent::RemoveAppPasscode, remove_app_passcode_handler)
+        .event(UserEvent::LockApp, lock_app_handler)
+        .event(UserEvent::UnlockApp, unlock_app_handler)
+        .event(UserEvent::VerifyAppPasscode, verify_app_passcode_handler)
+        .event(UserEvent::RefreshAppLock, refresh_app_lock_handler)
+        .event(UserEvent::GetUserSettings, get_user_settings_handler)
+        .event(UserEvent::UpdateUserSettings, update_user_settings_handler)
+        .event(UserEvent::UploadAvatar, upload_avatar_handler)
+        .event(UserEvent::GetUserAvatar, get_user_avatar_handler)
+        .event(UserEvent::DeleteAccount, delete_account_handler)
+        .event(UserEvent::GetUserDevices, get_user_devices_handler)
+        .event(UserEvent::RevokeUserDevice, revoke_user_device_handler)
+        .event(UserEvent::ExportLogs, export_logs_handler)
+        .event(UserEvent::GetServerUrl, get_server_url_handler)
+        .event(UserEvent::UpdateServerUrl, update_server_url_handler)
 }
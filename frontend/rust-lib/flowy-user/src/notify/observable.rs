@@ -4,11 +4,15 @@ const OBSERVABLE_CATEGORY: &str = "User";
 
 #[derive(ProtoBuf_Enum, Debug)]
 pub(crate) enum UserNotification {
-    Unknown            = 0,
-    UserAuthChanged    = 1,
-    UserProfileUpdated = 2,
-    UserUnauthorized   = 3,
+    Unknown             = 0,
+    UserAuthChanged     = 1,
+    UserProfileUpdated  = 2,
+    UserUnauthorized    = 3,
     UserWsConnectStateChanged = 4,
+    AppLockStateChanged = 5,
+    UserSettingsUpdated = 6,
+    UserAvatarUpdated   = 7,
+    DeleteAccountProgress = 8,
 }
 
 impl std::default::Default for UserNotification {
@@ -32,6 +32,25 @@ pub enum UserEvent {
     UpdateUser = 4,
     GetUserProfile = 5,
     CheckUser = 6,
+    SignInAnonymously = 7,
+    UpgradeAnonymousUser = 8,
+    GetAppLockState = 9,
+    SetAppPasscode = 10,
+    RemoveAppPasscode = 11,
+    LockApp = 12,
+    UnlockApp = 13,
+    VerifyAppPasscode = 14,
+    RefreshAppLock = 15,
+    GetUserSettings = 16,
+    UpdateUserSettings = 17,
+    UploadAvatar = 18,
+    GetUserAvatar = 19,
+    DeleteAccount = 20,
+    GetUserDevices = 21,
+    RevokeUserDevice = 22,
+    ExportLogs = 23,
+    GetServerUrl = 24,
+    UpdateServerUrl = 25,
 }
 
 impl ::protobuf::ProtobufEnum for UserEvent {
@@ -48,6 +67,25 @@ impl ::protobuf::ProtobufEnum for UserEvent {
             4 => ::std::option::Option::Some(UserEvent::UpdateUser),
             5 => ::std::option::Option::Some(UserEvent::GetUserProfile),
             6 => ::std::option::Option::Some(UserEvent::CheckUser),
+            7 => ::std::option::Option::Some(UserEvent::SignInAnonymously),
+            8 => ::std::option::Option::Some(UserEvent::UpgradeAnonymousUser),
+            9 => ::std::option::Option::Some(UserEvent::GetAppLockState),
+            10 => ::std::option::Option::Some(UserEvent::SetAppPasscode),
+            11 => ::std::option::Option::Some(UserEvent::RemoveAppPasscode),
+            12 => ::std::option::Option::Some(UserEvent::LockApp),
+            13 => ::std::option::Option::Some(UserEvent::UnlockApp),
+            14 => ::std::option::Option::Some(UserEvent::VerifyAppPasscode),
+            15 => ::std::option::Option::Some(UserEvent::RefreshAppLock),
+            16 => ::std::option::Option::Some(UserEvent::GetUserSettings),
+            17 => ::std::option::Option::Some(UserEvent::UpdateUserSettings),
+            18 => ::std::option::Option::Some(UserEvent::UploadAvatar),
+            19 => ::std::option::Option::Some(UserEvent::GetUserAvatar),
+            20 => ::std::option::Option::Some(UserEvent::DeleteAccount),
+            21 => ::std::option::Option::Some(UserEvent::GetUserDevices),
+            22 => ::std::option::Option::Some(UserEvent::RevokeUserDevice),
+            23 => ::std::option::Option::Some(UserEvent::ExportLogs),
+            24 => ::std::option::Option::Some(UserEvent::GetServerUrl),
+            25 => ::std::option::Option::Some(UserEvent::UpdateServerUrl),
             _ => ::std::option::Option::None
         }
     }
@@ -61,6 +99,25 @@ impl ::protobuf::ProtobufEnum for UserEvent {
             UserEvent::UpdateUser,
             UserEvent::GetUserProfile,
             UserEvent::CheckUser,
+            UserEvent::SignInAnonymously,
+            UserEvent::UpgradeAnonymousUser,
+            UserEvent::GetAppLockState,
+            UserEvent::SetAppPasscode,
+            UserEvent::RemoveAppPasscode,
+            UserEvent::LockApp,
+            UserEvent::UnlockApp,
+            UserEvent::VerifyAppPasscode,
+            UserEvent::RefreshAppLock,
+            UserEvent::GetUserSettings,
+            UserEvent::UpdateUserSettings,
+            UserEvent::UploadAvatar,
+            UserEvent::GetUserAvatar,
+            UserEvent::DeleteAccount,
+            UserEvent::GetUserDevices,
+            UserEvent::RevokeUserDevice,
+            UserEvent::ExportLogs,
+            UserEvent::GetServerUrl,
+            UserEvent::UpdateServerUrl,
         ];
         values
     }
@@ -89,26 +146,20 @@ impl ::protobuf::reflect::ProtobufValue for UserEvent {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0bevent.proto*q\n\tUserEvent\x12\x0c\n\x08InitUser\x10\0\x12\n\n\x06\
-    SignIn\x10\x01\x12\n\n\x06SignUp\x10\x02\x12\x0b\n\x07SignOut\x10\x03\
-    \x12\x0e\n\nUpdateUser\x10\x04\x12\x12\n\x0eGetUserProfile\x10\x05\x12\r\
-    \n\tCheckUser\x10\x06J\xc9\x02\n\x06\x12\x04\0\0\n\x01\n\x08\n\x01\x0c\
-    \x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\n\x01\n\n\n\x03\x05\0\x01\
-    \x12\x03\x02\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0c\n\x0c\n\x05\x05\0\x02\0\x02\x12\
-    \x03\x03\x0f\x10\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x0f\n\x0c\n\
-    \x05\x05\0\x02\x01\x01\x12\x03\x04\x04\n\n\x0c\n\x05\x05\0\x02\x01\x02\
-    \x12\x03\x04\r\x0e\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\x0f\n\x0c\n\
-    \x05\x05\0\x02\x02\x01\x12\x03\x05\x04\n\n\x0c\n\x05\x05\0\x02\x02\x02\
-    \x12\x03\x05\r\x0e\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\x04\x10\n\x0c\n\
-    \x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x0b\n\x0c\n\x05\x05\0\x02\x03\x02\
-    \x12\x03\x06\x0e\x0f\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x07\x04\x13\n\x0c\
-    \n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x0e\n\x0c\n\x05\x05\0\x02\x04\
-    \x02\x12\x03\x07\x11\x12\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x17\n\
-    \x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x12\n\x0c\n\x05\x05\0\x02\
-    \x05\x02\x12\x03\x08\x15\x16\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x12\
-    \n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\r\n\x0c\n\x05\x05\0\x02\x06\
-    \x02\x12\x03\t\x10\x11b\x06proto3\
+    \n\x0bevent.proto*\xf1\x03\n\tUserEvent\x12\x0c\n\x08InitUser\x10\0\x12\
+    \n\n\x06SignIn\x10\x01\x12\n\n\x06SignUp\x10\x02\x12\x0b\n\x07SignOut\
+    \x10\x03\x12\x0e\n\nUpdateUser\x10\x04\x12\x12\n\x0eGetUserProfile\x10\
+    \x05\x12\r\n\tCheckUser\x10\x06\x12\x15\n\x11SignInAnonymously\x10\x07\
+    \x12\x18\n\x14UpgradeAnonymousUser\x10\x08\x12\x13\n\x0fGetAppLockState\
+    \x10\t\x12\x12\n\x0eSetAppPasscode\x10\n\x12\x15\n\x11RemoveAppPasscode\
+    \x10\x0b\x12\x0b\n\x07LockApp\x10\x0c\x12\r\n\tUnlockApp\x10\r\x12\x15\n\
+    \x11VerifyAppPasscode\x10\x0e\x12\x12\n\x0eRefreshAppLock\x10\x0f\x12\
+    \x13\n\x0fGetUserSettings\x10\x10\x12\x16\n\x12UpdateUserSettings\x10\
+    \x11\x12\x10\n\x0cUploadAvatar\x10\x12\x12\x11\n\rGetUserAvatar\x10\x13\
+    \x12\x11\n\rDeleteAccount\x10\x14\x12\x12\n\x0eGetUserDevices\x10\x15\
+    \x12\x14\n\x10RevokeUserDevice\x10\x16\x12\x0e\n\nExportLogs\x10\x17\x12\
+    \x10\n\x0cGetServerUrl\x10\x18\x12\x13\n\x0fUpdateServerUrl\x10\x19\x1a\
+    \0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
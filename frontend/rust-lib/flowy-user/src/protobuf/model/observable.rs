@@ -30,6 +30,10 @@ pub enum UserNotification {
     UserProfileUpdated = 2,
     UserUnauthorized = 3,
     UserWsConnectStateChanged = 4,
+    AppLockStateChanged = 5,
+    UserSettingsUpdated = 6,
+    UserAvatarUpdated = 7,
+    DeleteAccountProgress = 8,
 }
 
 impl ::protobuf::ProtobufEnum for UserNotification {
@@ -44,6 +48,10 @@ impl ::protobuf::ProtobufEnum for UserNotification {
             2 => ::std::option::Option::Some(UserNotification::UserProfileUpdated),
             3 => ::std::option::Option::Some(UserNotification::UserUnauthorized),
             4 => ::std::option::Option::Some(UserNotification::UserWsConnectStateChanged),
+            5 => ::std::option::Option::Some(UserNotification::AppLockStateChanged),
+            6 => ::std::option::Option::Some(UserNotification::UserSettingsUpdated),
+            7 => ::std::option::Option::Some(UserNotification::UserAvatarUpdated),
+            8 => ::std::option::Option::Some(UserNotification::DeleteAccountProgress),
             _ => ::std::option::Option::None
         }
     }
@@ -55,6 +63,10 @@ impl ::protobuf::ProtobufEnum for UserNotification {
             UserNotification::UserProfileUpdated,
             UserNotification::UserUnauthorized,
             UserNotification::UserWsConnectStateChanged,
+            UserNotification::AppLockStateChanged,
+            UserNotification::UserSettingsUpdated,
+            UserNotification::UserAvatarUpdated,
+            UserNotification::DeleteAccountProgress,
         ];
         values
     }
@@ -83,22 +95,12 @@ impl ::protobuf::reflect::ProtobufValue for UserNotification {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10observable.proto*\x81\x01\n\x10UserNotification\x12\x0b\n\x07Unkno\
+    \n\x10observable.proto*\xe7\x01\n\x10UserNotification\x12\x0b\n\x07Unkno\
     wn\x10\0\x12\x13\n\x0fUserAuthChanged\x10\x01\x12\x16\n\x12UserProfileUp\
     dated\x10\x02\x12\x14\n\x10UserUnauthorized\x10\x03\x12\x1d\n\x19UserWsC\
-    onnectStateChanged\x10\x04J\xf7\x01\n\x06\x12\x04\0\0\x08\x01\n\x08\n\
-    \x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x08\x01\n\n\n\x03\
-    \x05\0\x01\x12\x03\x02\x05\x15\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\
-    \x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0b\n\x0c\n\x05\x05\0\
-    \x02\0\x02\x12\x03\x03\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\
-    \x18\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x13\n\x0c\n\x05\x05\0\
-    \x02\x01\x02\x12\x03\x04\x16\x17\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\
-    \x04\x1b\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x16\n\x0c\n\x05\
-    \x05\0\x02\x02\x02\x12\x03\x05\x19\x1a\n\x0b\n\x04\x05\0\x02\x03\x12\x03\
-    \x06\x04\x19\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x14\n\x0c\n\
-    \x05\x05\0\x02\x03\x02\x12\x03\x06\x17\x18\n\x0b\n\x04\x05\0\x02\x04\x12\
-    \x03\x07\x04\"\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x1d\n\x0c\n\
-    \x05\x05\0\x02\x04\x02\x12\x03\x07\x20!b\x06proto3\
+    onnectStateChanged\x10\x04\x12\x17\n\x13AppLockStateChanged\x10\x05\x12\
+    \x17\n\x13UserSettingsUpdated\x10\x06\x12\x15\n\x11UserAvatarUpdated\x10\
+    \x07\x12\x19\n\x15DeleteAccountProgress\x10\x08\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
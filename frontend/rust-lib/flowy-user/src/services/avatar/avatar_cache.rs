@@ -0,0 +1,139 @@
+use crate::{
+    entities::{UploadAvatarParams, UserAvatar},
+    errors::{ErrorCode, FlowyError},
+    notify::*,
+    services::server::Server,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+// The avatars of the other users are fetched again once the cached file is
+// older than this.
+const AVATAR_CACHE_EXPIRATION: Duration = Duration::from_secs(24 * 60 * 60);
+
+pub(crate) struct AvatarUser {
+    pub(crate) user_dir: String,
+    pub(crate) user_id: String,
+    pub(crate) token: String,
+    pub(crate) is_anonymous: bool,
+}
+
+// Avatars are stored as files in the avatar directory of the current user,
+// named by the user id of the avatar owner.
+pub(crate) struct AvatarCache {
+    server: Server,
+}
+
+impl AvatarCache {
+    pub(crate) fn new(server: Server) -> Self { Self { server } }
+
+    // The avatar is saved locally first, so it's shown even if the upload fails.
+    pub(crate) async fn upload_avatar(
+        &self,
+        user: &AvatarUser,
+        params: UploadAvatarParams,
+    ) -> Result<UserAvatar, FlowyError> {
+        let _ = write_avatar(&avatar_path(&user.user_dir, &user.user_id), &params.data)?;
+        let mut avatar = UserAvatar {
+            user_id: user.user_id.clone(),
+            url: "".to_owned(),
+            data: params.data.clone(),
+        };
+
+        if !user.is_anonymous {
+            let _ = self.server.upload_avatar(&user.token, params).await?;
+            avatar.url = self.server.avatar_url(&user.user_id);
+        }
+
+        dart_notify(&user.user_id, UserNotification::UserAvatarUpdated)
+            .payload(avatar.clone())
+            .send();
+        Ok(avatar)
+    }
+
+    pub(crate) async fn read_avatar(&self, user: &AvatarUser, user_id: &str) -> Result<UserAvatar, FlowyError> {
+        // The user id is used as the file name.
+        if user_id.contains(|c| c == '/' || c == '\\') || user_id.contains("..") {
+            return Err(FlowyError::from(ErrorCode::UserIdInvalid));
+        }
+
+        let path = avatar_path(&user.user_dir, user_id);
+        let url = if user.is_anonymous {
+            "".to_owned()
+        } else {
+            self.server.avatar_url(user_id)
+        };
+        let mut avatar = UserAvatar {
+            user_id: user_id.to_owned(),
+            url,
+            data: vec![],
+        };
+
+        // The current user's avatar is only changed through this device or
+        // fetched when it's missing, so it never expires.
+        let cached = read_avatar(&path)?;
+        if let Some((data, modified)) = &cached {
+            if user_id == user.user_id || !is_expired(modified) {
+                avatar.data = data.clone();
+                return Ok(avatar);
+            }
+        }
+
+        if user.is_anonymous {
+            return Ok(avatar);
+        }
+
+        match self.server.read_avatar(&user.token, user_id).await {
+            Ok(Some(remote)) => {
+                let _ = write_avatar(&path, &remote.data)?;
+                avatar.data = remote.data;
+                Ok(avatar)
+            },
+            Ok(None) => {
+                if path.exists() {
+                    let _ = fs::remove_file(&path)?;
+                }
+                Ok(avatar)
+            },
+            Err(e) => match cached {
+                None => Err(e),
+                Some((data, _)) => {
+                    log::warn!("Fetch {}'s avatar failed, use the expired one. {:?}", user_id, e);
+                    avatar.data = data;
+                    Ok(avatar)
+                },
+            },
+        }
+    }
+}
+
+fn avatar_path(user_dir: &str, user_id: &str) -> PathBuf { Path::new(user_dir).join("avatar").join(user_id) }
+
+fn is_expired(modified: &SystemTime) -> bool {
+    match modified.elapsed() {
+        Ok(elapsed) => elapsed > AVATAR_CACHE_EXPIRATION,
+        Err(_) => true,
+    }
+}
+
+fn read_avatar(path: &Path) -> Result<Option<(Vec<u8>, SystemTime)>, FlowyError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let modified = fs::metadata(path)?.modified()?;
+    let data = fs::read(path)?;
+    Ok(Some((data, modified)))
+}
+
+fn write_avatar(path: &Path, data: &[u8]) -> Result<(), FlowyError> {
+    if let Some(dir) = path.parent() {
+        if !dir.exists() {
+            let _ = fs::create_dir_all(dir)?;
+        }
+    }
+    let _ = fs::write(path, data)?;
+    Ok(())
+}
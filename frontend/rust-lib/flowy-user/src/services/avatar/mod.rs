@@ -0,0 +1,3 @@
+mod avatar_cache;
+
+pub use avatar_cache::*;
@@ -0,0 +1,203 @@
+use crate::{
+    entities::{AppLockState, SetAppPasscodeParams},
+    errors::FlowyError,
+    notify::*,
+};
+use flowy_database::kv::KV;
+use lib_infra::timestamp;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicI64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+const APP_LOCK_CACHE_KEY: &str = "app_lock_config";
+const APP_LOCK_OBSERVABLE_ID: &str = "app_lock";
+
+// The lock is shared by all the users of the device, so the config is stored in
+// the KV instead of the user's database.
+pub struct AppLock {
+    config: RwLock<AppLockConfig>,
+    is_locked: AtomicBool,
+    // Timestamp in seconds of the last user activity.
+    last_active: AtomicI64,
+}
+
+impl std::default::Default for AppLock {
+    fn default() -> Self {
+        let config = match KV::get_str(APP_LOCK_CACHE_KEY) {
+            None => AppLockConfig::default(),
+            Some(s) => AppLockConfig::from(s),
+        };
+        // The app starts locked if a passcode was set before.
+        let is_locked = config.passcode_hash.is_some();
+        Self {
+            config: RwLock::new(config),
+            is_locked: AtomicBool::new(is_locked),
+            last_active: AtomicI64::new(timestamp()),
+        }
+    }
+}
+
+impl AppLock {
+    pub fn new() -> Self { AppLock::default() }
+
+    pub fn state(&self) -> AppLockState {
+        let config = self.config.read();
+        AppLockState {
+            has_passcode: config.passcode_hash.is_some(),
+            is_locked: self.is_locked(),
+            idle_timeout: config.idle_timeout,
+        }
+    }
+
+    pub fn is_locked(&self) -> bool { self.is_locked.load(Ordering::SeqCst) }
+
+    // Returns an error if the app is locked. Call it before reading any user
+    // content.
+    pub fn check_unlocked(&self) -> Result<(), FlowyError> {
+        if self.is_locked() {
+            return Err(FlowyError::app_locked());
+        }
+        self.touch();
+        Ok(())
+    }
+
+    pub fn touch(&self) { self.last_active.store(timestamp(), Ordering::SeqCst); }
+
+    #[tracing::instrument(level = "debug", skip(self, params), err)]
+    pub fn set_passcode(&self, params: SetAppPasscodeParams) -> Result<AppLockState, FlowyError> {
+        if self.config.read().passcode_hash.is_some() {
+            let old_passcode = params.old_passcode.unwrap_or_default();
+            let _ = self.verify(&old_passcode)?;
+        }
+
+        let hash =
+            bcrypt::hash(&params.passcode, bcrypt::DEFAULT_COST).map_err(|e| FlowyError::internal().context(e))?;
+        self.save_config(AppLockConfig {
+            passcode_hash: Some(hash),
+            idle_timeout: params.idle_timeout,
+        });
+        self.touch();
+        Ok(self.state())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, passcode), err)]
+    pub fn remove_passcode(&self, passcode: &str) -> Result<AppLockState, FlowyError> {
+        let _ = self.verify(passcode)?;
+        self.save_config(AppLockConfig::default());
+        self.set_locked(false);
+        Ok(self.state())
+    }
+
+    pub fn verify(&self, passcode: &str) -> Result<(), FlowyError> {
+        let config = self.config.read();
+        match &config.passcode_hash {
+            None => Ok(()),
+            Some(hash) => match bcrypt::verify(passcode, hash) {
+                Ok(true) => Ok(()),
+                Ok(false) => Err(FlowyError::passcode_not_match()),
+                Err(e) => Err(FlowyError::internal().context(e)),
+            },
+        }
+    }
+
+    pub fn lock(&self) -> AppLockState {
+        if self.config.read().passcode_hash.is_some() {
+            self.set_locked(true);
+        }
+        self.state()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, passcode), err)]
+    pub fn unlock(&self, passcode: &str) -> Result<AppLockState, FlowyError> {
+        let _ = self.verify(passcode)?;
+        self.touch();
+        self.set_locked(false);
+        Ok(self.state())
+    }
+
+    // Checks the idle time every second and locks the app once it exceeds the
+    // timeout.
+    pub(crate) fn start_idle_timer(self: &Arc<Self>) {
+        let weak_lock = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(1));
+            loop {
+                interval.tick().await;
+                match weak_lock.upgrade() {
+                    None => break,
+                    Some(app_lock) => app_lock.lock_if_idle(),
+                }
+            }
+        });
+    }
+
+    fn lock_if_idle(&self) {
+        if self.is_locked() {
+            return;
+        }
+
+        let idle_timeout = {
+            let config = self.config.read();
+            if config.passcode_hash.is_none() || config.idle_timeout == 0 {
+                return;
+            }
+            config.idle_timeout
+        };
+
+        if timestamp() - self.last_active.load(Ordering::SeqCst) >= idle_timeout {
+            tracing::debug!("Lock the app after being idle for {} seconds", idle_timeout);
+            self.set_locked(true);
+        }
+    }
+
+    fn set_locked(&self, is_locked: bool) {
+        let old = self.is_locked.swap(is_locked, Ordering::SeqCst);
+        if old != is_locked {
+            dart_notify(APP_LOCK_OBSERVABLE_ID, UserNotification::AppLockStateChanged)
+                .payload(self.state())
+                .send();
+        }
+    }
+
+    fn save_config(&self, config: AppLockConfig) {
+        KV::set_str(APP_LOCK_CACHE_KEY, config.clone().into());
+        *self.config.write() = config;
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct AppLockConfig {
+    passcode_hash: Option<String>,
+    #[serde(default)]
+    idle_timeout: i64,
+}
+
+impl std::convert::From<String> for AppLockConfig {
+    fn from(s: String) -> Self {
+        match serde_json::from_str(&s) {
+            Ok(config) => config,
+            Err(e) => {
+                log::error!("Deserialize string to AppLockConfig failed: {:?}", e);
+                AppLockConfig::default()
+            },
+        }
+    }
+}
+
+impl std::convert::From<AppLockConfig> for String {
+    fn from(config: AppLockConfig) -> Self {
+        match serde_json::to_string(&config) {
+            Ok(s) => s,
+            Err(e) => {
+                log::error!("Serialize AppLockConfig to string failed: {:?}", e);
+                "".to_string()
+            },
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod app_lock;
+
+pub use app_lock::*;
@@ -1,2 +1,5 @@
+pub mod avatar;
+pub mod lock;
 pub mod server;
+pub mod settings;
 pub mod user;
@@ -1,13 +1,28 @@
 mod server_api;
 mod server_api_mock;
+mod server_api_supabase;
 
 pub use server_api::*;
 pub use server_api_mock::*;
+pub use server_api_supabase::*;
 
 use std::sync::Arc;
 pub(crate) type Server = Arc<dyn UserServerAPI + Send + Sync>;
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        RefreshTokenParams,
+        RefreshTokenResponse,
+        RepeatedUserDevice,
+        RevokeDeviceParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UploadAvatarParams,
+        UserAvatar,
+        UserProfile,
+    },
     errors::FlowyError,
 };
 use backend_service::configuration::ClientServerConfiguration;
@@ -19,11 +34,20 @@ pub trait UserServerAPI {
     fn sign_out(&self, token: &str) -> FutureResult<(), FlowyError>;
     fn update_user(&self, token: &str, params: UpdateUserParams) -> FutureResult<(), FlowyError>;
     fn get_user(&self, token: &str) -> FutureResult<UserProfile, FlowyError>;
+    fn delete_user(&self, token: &str) -> FutureResult<(), FlowyError>;
+    fn refresh_token(&self, params: RefreshTokenParams) -> FutureResult<RefreshTokenResponse, FlowyError>;
+    fn upload_avatar(&self, token: &str, params: UploadAvatarParams) -> FutureResult<(), FlowyError>;
+    fn read_avatar(&self, token: &str, user_id: &str) -> FutureResult<Option<UserAvatar>, FlowyError>;
+    fn avatar_url(&self, user_id: &str) -> String;
+    fn read_user_devices(&self, token: &str) -> FutureResult<RepeatedUserDevice, FlowyError>;
+    fn revoke_user_device(&self, token: &str, params: RevokeDeviceParams) -> FutureResult<(), FlowyError>;
     fn ws_addr(&self) -> String;
 }
 
 pub(crate) fn construct_user_server(config: &ClientServerConfiguration) -> Arc<dyn UserServerAPI + Send + Sync> {
-    if cfg!(feature = "http_server") {
+    if let Some(supabase) = &config.supabase {
+        Arc::new(UserSupabaseServer::new(config.clone(), supabase.clone()))
+    } else if cfg!(feature = "http_server") {
         Arc::new(UserHttpServer::new(config.clone()))
     } else {
         Arc::new(UserServerMock {})
@@ -1,5 +1,18 @@
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        RefreshTokenParams,
+        RefreshTokenResponse,
+        RepeatedUserDevice,
+        RevokeDeviceParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UploadAvatarParams,
+        UserAvatar,
+        UserProfile,
+    },
     errors::FlowyError,
     services::server::UserServerAPI,
 };
@@ -57,6 +70,61 @@ impl UserServerAPI for UserHttpServer {
         })
     }
 
+    fn delete_user(&self, token: &str) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.user_profile_url();
+        FutureResult::new(async move {
+            let _ = delete_user_request(&token, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn refresh_token(&self, params: RefreshTokenParams) -> FutureResult<RefreshTokenResponse, FlowyError> {
+        let url = self.config.refresh_token_url();
+        FutureResult::new(async move {
+            let resp = user_refresh_token_request(params, &url).await?;
+            Ok(resp)
+        })
+    }
+
+    fn upload_avatar(&self, token: &str, params: UploadAvatarParams) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.upload_avatar_url();
+        FutureResult::new(async move {
+            let _ = upload_avatar_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn read_avatar(&self, token: &str, user_id: &str) -> FutureResult<Option<UserAvatar>, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.avatar_url(user_id);
+        FutureResult::new(async move {
+            let avatar = read_avatar_request(&token, &url).await?;
+            Ok(avatar)
+        })
+    }
+
+    fn avatar_url(&self, user_id: &str) -> String { self.config.avatar_url(user_id) }
+
+    fn read_user_devices(&self, token: &str) -> FutureResult<RepeatedUserDevice, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.user_device_url();
+        FutureResult::new(async move {
+            let devices = read_user_devices_request(&token, &url).await?;
+            Ok(devices)
+        })
+    }
+
+    fn revoke_user_device(&self, token: &str, params: RevokeDeviceParams) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.user_device_url();
+        FutureResult::new(async move {
+            let _ = revoke_user_device_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
     fn ws_addr(&self) -> String { self.config.ws_addr() }
 }
 
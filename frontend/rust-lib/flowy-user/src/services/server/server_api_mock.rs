@@ -1,5 +1,18 @@
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        RefreshTokenParams,
+        RefreshTokenResponse,
+        RepeatedUserDevice,
+        RevokeDeviceParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UploadAvatarParams,
+        UserAvatar,
+        UserProfile,
+    },
     errors::FlowyError,
 };
 
@@ -19,6 +32,7 @@ impl UserServerAPI for UserServerMock {
                 name: params.name,
                 email: params.email,
                 token: uid,
+                refresh_token: uuid_string(),
             })
         })
     }
@@ -31,6 +45,7 @@ impl UserServerAPI for UserServerMock {
                 name: params.name,
                 email: params.email,
                 token: user_id,
+                refresh_token: uuid_string(),
             })
         })
     }
@@ -45,5 +60,34 @@ impl UserServerAPI for UserServerMock {
         FutureResult::new(async { Ok(UserProfile::default()) })
     }
 
+    fn delete_user(&self, _token: &str) -> FutureResult<(), FlowyError> { FutureResult::new(async { Ok(()) }) }
+
+    fn refresh_token(&self, _params: RefreshTokenParams) -> FutureResult<RefreshTokenResponse, FlowyError> {
+        FutureResult::new(async {
+            Ok(RefreshTokenResponse {
+                token: uuid_string(),
+                refresh_token: uuid_string(),
+            })
+        })
+    }
+
+    fn upload_avatar(&self, _token: &str, _params: UploadAvatarParams) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
+    fn read_avatar(&self, _token: &str, _user_id: &str) -> FutureResult<Option<UserAvatar>, FlowyError> {
+        FutureResult::new(async { Ok(None) })
+    }
+
+    fn avatar_url(&self, _user_id: &str) -> String { "".to_owned() }
+
+    fn read_user_devices(&self, _token: &str) -> FutureResult<RepeatedUserDevice, FlowyError> {
+        FutureResult::new(async { Ok(RepeatedUserDevice::default()) })
+    }
+
+    fn revoke_user_device(&self, _token: &str, _params: RevokeDeviceParams) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
     fn ws_addr(&self) -> String { "ws://localhost:8000/ws/".to_owned() }
 }
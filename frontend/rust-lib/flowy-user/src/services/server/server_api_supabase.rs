@@ -0,0 +1,207 @@
+use crate::{
+    entities::{
+        RefreshTokenParams,
+        RefreshTokenResponse,
+        RepeatedUserDevice,
+        RevokeDeviceParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UploadAvatarParams,
+        UserAvatar,
+        UserProfile,
+    },
+    errors::{ErrorCode, FlowyError},
+    services::server::UserServerAPI,
+};
+use backend_service::{
+    configuration::{ClientServerConfiguration, SupabaseConfiguration},
+    errors::ErrorCode as ServerErrorCode,
+    supabase::{Method, SupabaseClient},
+};
+use lib_infra::future::FutureResult;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+// The accounts of GoTrue, the auth service of Supabase. The name of the user
+// is kept in its metadata.
+pub struct UserSupabaseServer {
+    config: ClientServerConfiguration,
+    client: SupabaseClient,
+}
+
+impl UserSupabaseServer {
+    pub fn new(config: ClientServerConfiguration, supabase: SupabaseConfiguration) -> Self {
+        Self {
+            config,
+            client: SupabaseClient::new(supabase),
+        }
+    }
+}
+
+impl UserServerAPI for UserSupabaseServer {
+    fn sign_up(&self, params: SignUpParams) -> FutureResult<SignUpResponse, FlowyError> {
+        let client = self.client.clone();
+        FutureResult::new(async move {
+            let body = json!({
+                "email": params.email,
+                "password": params.password,
+                "data": { "name": params.name },
+            });
+            let session: Session = client.auth(Method::POST, "signup", None, Some(&body)).await?;
+            // The project asks for the email to be confirmed first, there is
+            // no session until then.
+            if session.access_token.is_empty() {
+                return Err(FlowyError::new(
+                    ErrorCode::UserUnauthorized,
+                    "Confirm the email before signing in",
+                ));
+            }
+            Ok(SignUpResponse {
+                user_id: session.user.id,
+                name: params.name,
+                email: params.email,
+                token: session.access_token,
+                refresh_token: session.refresh_token,
+            })
+        })
+    }
+
+    fn sign_in(&self, params: SignInParams) -> FutureResult<SignInResponse, FlowyError> {
+        let client = self.client.clone();
+        FutureResult::new(async move {
+            let body = json!({ "email": params.email, "password": params.password });
+            let session: Session = client
+                .auth(Method::POST, "token?grant_type=password", None, Some(&body))
+                .await
+                .map_err(|e| match e.code == ServerErrorCode::ParamsInvalid {
+                    true => FlowyError::password_not_match(),
+                    false => e.into(),
+                })?;
+            Ok(SignInResponse {
+                user_id: session.user.id.clone(),
+                name: session.user.name(),
+                email: session.user.email,
+                token: session.access_token,
+                refresh_token: session.refresh_token,
+            })
+        })
+    }
+
+    fn sign_out(&self, token: &str) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let _ = client
+                .auth_send::<Value>(Method::POST, "logout", Some(&token), None)
+                .await?;
+            Ok(())
+        })
+    }
+
+    fn update_user(&self, token: &str, params: UpdateUserParams) -> FutureResult<(), FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let mut body = Map::new();
+            if let Some(email) = params.email {
+                body.insert("email".to_owned(), Value::String(email));
+            }
+            if let Some(password) = params.password {
+                body.insert("password".to_owned(), Value::String(password));
+            }
+            if let Some(name) = params.name {
+                body.insert("data".to_owned(), json!({ "name": name }));
+            }
+            let _ = client.auth_send(Method::PUT, "user", Some(&token), Some(&body)).await?;
+            Ok(())
+        })
+    }
+
+    fn get_user(&self, token: &str) -> FutureResult<UserProfile, FlowyError> {
+        let (token, client) = (token.to_owned(), self.client.clone());
+        FutureResult::new(async move {
+            let user: User = client.auth::<Value, _>(Method::GET, "user", Some(&token), None).await?;
+            Ok(UserProfile {
+                id: user.id.clone(),
+                name: user.name(),
+                email: user.email,
+                token,
+            })
+        })
+    }
+
+    fn delete_user(&self, _token: &str) -> FutureResult<(), FlowyError> {
+        unsupported("Supabase only deletes the accounts with the service key")
+    }
+
+    fn refresh_token(&self, params: RefreshTokenParams) -> FutureResult<RefreshTokenResponse, FlowyError> {
+        let client = self.client.clone();
+        FutureResult::new(async move {
+            let body = json!({ "refresh_token": params.refresh_token });
+            let session: Session = client
+                .auth(Method::POST, "token?grant_type=refresh_token", None, Some(&body))
+                .await?;
+            Ok(RefreshTokenResponse {
+                token: session.access_token,
+                refresh_token: session.refresh_token,
+            })
+        })
+    }
+
+    fn upload_avatar(&self, _token: &str, _params: UploadAvatarParams) -> FutureResult<(), FlowyError> {
+        unsupported("Supabase doesn't support the avatars")
+    }
+
+    fn read_avatar(&self, _token: &str, _user_id: &str) -> FutureResult<Option<UserAvatar>, FlowyError> {
+        FutureResult::new(async { Ok(None) })
+    }
+
+    fn avatar_url(&self, _user_id: &str) -> String { "".to_owned() }
+
+    fn read_user_devices(&self, _token: &str) -> FutureResult<RepeatedUserDevice, FlowyError> {
+        unsupported("Supabase doesn't support the devices")
+    }
+
+    fn revoke_user_device(&self, _token: &str, _params: RevokeDeviceParams) -> FutureResult<(), FlowyError> {
+        unsupported("Supabase doesn't support the devices")
+    }
+
+    fn ws_addr(&self) -> String { self.config.ws_addr() }
+}
+
+fn unsupported<T>(msg: &str) -> FutureResult<T, FlowyError>
+where
+    T: Send + Sync + 'static,
+{
+    let error = FlowyError::cloud_unsupported().context(msg);
+    FutureResult::new(async { Err(error) })
+}
+
+#[derive(Deserialize)]
+struct Session {
+    #[serde(default)]
+    access_token: String,
+    #[serde(default)]
+    refresh_token: String,
+    user: User,
+}
+
+#[derive(Deserialize)]
+struct User {
+    id: String,
+    #[serde(default)]
+    email: String,
+    #[serde(default)]
+    user_metadata: Map<String, Value>,
+}
+
+impl User {
+    fn name(&self) -> String {
+        self.user_metadata
+            .get("name")
+            .and_then(|name| name.as_str())
+            .unwrap_or_default()
+            .to_owned()
+    }
+}
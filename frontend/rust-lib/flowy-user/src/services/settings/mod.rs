@@ -0,0 +1,3 @@
+mod user_settings;
+
+pub use user_settings::*;
@@ -0,0 +1,215 @@
+use crate::{
+    entities::{SyncPolicy, Theme, UserSettings},
+    errors::FlowyError,
+    notify::*,
+};
+use flowy_database::kv::KV;
+use lib_infra::future::FutureResult;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+// Stores the settings json in the revisions of a document that belongs to the
+// user, so the settings are synced to the user's other devices in the same way
+// as the documents.
+pub trait UserSettingsSync: Send + Sync {
+    fn create_settings(&self, user_id: &str, json: String) -> FutureResult<(), FlowyError>;
+    fn read_settings(&self, user_id: &str) -> FutureResult<Option<String>, FlowyError>;
+    fn write_settings(&self, user_id: &str, json: String) -> FutureResult<(), FlowyError>;
+}
+
+// The KV keeps a copy of the settings, so they can be read synchronously and
+// before the revisions are loaded.
+pub struct UserSettingsController {
+    sync: RwLock<Option<Arc<dyn UserSettingsSync>>>,
+    // The writes to the settings document are made one at a time, each one
+    // replaces the whole text that the previous one wrote.
+    write_lock: Arc<Mutex<()>>,
+}
+
+impl UserSettingsController {
+    pub(crate) fn new() -> Self {
+        Self {
+            sync: RwLock::new(None),
+            write_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    pub fn set_sync(&self, sync: Arc<dyn UserSettingsSync>) { *self.sync.write() = Some(sync); }
+
+    pub fn settings(&self, user_id: &str) -> UserSettings {
+        match KV::get_str(&settings_cache_key(user_id)) {
+            None => UserSettings::default(),
+            Some(s) => UserSettingsData::from(s).into(),
+        }
+    }
+
+    // Returns the settings in the same JSON format that is synced through the
+    // settings document.
+    pub fn settings_json(&self, user_id: &str) -> String { UserSettingsData::from(self.settings(user_id)).into() }
+
+    pub fn update_settings<F>(&self, user_id: &str, f: F) -> Result<UserSettings, FlowyError>
+    where
+        F: FnOnce(&mut UserSettings),
+    {
+        let mut settings = self.settings(user_id);
+        f(&mut settings);
+        let json: String = UserSettingsData::from(settings.clone()).into();
+        KV::set_str(&settings_cache_key(user_id), json.clone());
+        dart_notify(user_id, UserNotification::UserSettingsUpdated)
+            .payload(settings.clone())
+            .send();
+
+        if let Some(sync) = self.sync() {
+            let user_id = user_id.to_owned();
+            let write_lock = self.write_lock.clone();
+            tokio::spawn(async move {
+                let _write = write_lock.lock().await;
+                // A later update may have changed the settings meanwhile.
+                let json = KV::get_str(&settings_cache_key(&user_id)).unwrap_or(json);
+                match sync.write_settings(&user_id, json).await {
+                    Ok(_) => {},
+                    Err(e) => log::error!("Save user settings to revisions failed: {:?}", e),
+                }
+            });
+        }
+        Ok(settings)
+    }
+
+    // The settings document is created together with the user, it can't be
+    // opened before it has a revision.
+    pub(crate) async fn create_settings_document(
+        &self,
+        user_id: &str,
+        settings: UserSettings,
+    ) -> Result<(), FlowyError> {
+        let json: String = UserSettingsData::from(settings).into();
+        KV::set_str(&settings_cache_key(user_id), json.clone());
+        match self.sync() {
+            None => Ok(()),
+            Some(sync) => sync.create_settings(user_id, json).await,
+        }
+    }
+
+    pub(crate) fn remove_settings(&self, user_id: &str) {
+        if let Err(e) = KV::remove(&settings_cache_key(user_id)) {
+            log::error!("Remove user settings failed: {:?}", e);
+        }
+    }
+
+    // The revisions may contain the settings changed on the other devices. They
+    // override the local copy once loaded.
+    pub(crate) fn load_from_revisions(&self, user_id: &str) {
+        let sync = match self.sync() {
+            None => return,
+            Some(sync) => sync,
+        };
+        let user_id = user_id.to_owned();
+        let write_lock = self.write_lock.clone();
+        tokio::spawn(async move {
+            let _write = write_lock.lock().await;
+            match read_synced_settings(&sync, &user_id).await {
+                Ok(None) => {},
+                Ok(Some((settings, json))) => {
+                    KV::set_str(&settings_cache_key(&user_id), json);
+                    dart_notify(&user_id, UserNotification::UserSettingsUpdated)
+                        .payload(settings)
+                        .send();
+                },
+                Err(e) => log::error!("Read user settings from revisions failed: {:?}", e),
+            }
+        });
+    }
+
+    fn sync(&self) -> Option<Arc<dyn UserSettingsSync>> { self.sync.read().clone() }
+}
+
+fn settings_cache_key(user_id: &str) -> String { format!("{}_user_settings", user_id) }
+
+// The edits of two devices are merged as text, which may leave the json
+// broken, e.g. if both replaced it at the same time. The document is repaired
+// with the local copy then.
+async fn read_synced_settings(
+    sync: &Arc<dyn UserSettingsSync>,
+    user_id: &str,
+) -> Result<Option<(UserSettings, String)>, FlowyError> {
+    let json = match sync.read_settings(user_id).await? {
+        None => return Ok(None),
+        Some(json) => json,
+    };
+    match serde_json::from_str::<UserSettingsData>(&json) {
+        Ok(data) => Ok(Some((data.into(), json))),
+        Err(e) => {
+            log::warn!("The synced user settings are broken, repair them: {:?}", e);
+            let local_json = match KV::get_str(&settings_cache_key(user_id)) {
+                Some(local_json) => local_json,
+                None => UserSettingsData::from(UserSettings::default()).into(),
+            };
+            let _ = sync.write_settings(user_id, local_json).await?;
+            Ok(None)
+        },
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct UserSettingsData {
+    #[serde(default)]
+    theme: i32,
+    #[serde(default)]
+    locale: String,
+    #[serde(default)]
+    sync_policy: i32,
+    #[serde(default)]
+    revision_retention_days: i64,
+}
+
+impl std::convert::From<UserSettings> for UserSettingsData {
+    fn from(settings: UserSettings) -> Self {
+        UserSettingsData {
+            theme: settings.theme as i32,
+            locale: settings.locale,
+            sync_policy: settings.sync_policy as i32,
+            revision_retention_days: settings.revision_retention_days,
+        }
+    }
+}
+
+impl std::convert::From<UserSettingsData> for UserSettings {
+    fn from(data: UserSettingsData) -> Self {
+        let mut settings = UserSettings {
+            theme: Theme::from(data.theme),
+            sync_policy: SyncPolicy::from(data.sync_policy),
+            revision_retention_days: data.revision_retention_days,
+            ..Default::default()
+        };
+        if !data.locale.is_empty() {
+            settings.locale = data.locale;
+        }
+        settings
+    }
+}
+
+impl std::convert::From<String> for UserSettingsData {
+    fn from(s: String) -> Self {
+        match serde_json::from_str(&s) {
+            Ok(data) => data,
+            Err(e) => {
+                log::error!("Deserialize string to UserSettingsData failed: {:?}", e);
+                UserSettingsData::from(UserSettings::default())
+            },
+        }
+    }
+}
+
+impl std::convert::From<UserSettingsData> for String {
+    fn from(data: UserSettingsData) -> Self {
+        match serde_json::to_string(&data) {
+            Ok(s) => s,
+            Err(e) => {
+                log::error!("Serialize UserSettingsData to string failed: {:?}", e);
+                "".to_string()
+            },
+        }
+    }
+}
@@ -4,7 +4,7 @@ use lazy_static::lazy_static;
 use lib_sqlite::ConnectionPool;
 use once_cell::sync::Lazy;
 use parking_lot::{Mutex, RwLock};
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{collections::HashMap, fs, fs::OpenOptions, io::Write, path::Path, sync::Arc, time::Duration};
 lazy_static! {
     static ref DB: RwLock<Option<Database>> = RwLock::new(None);
 }
@@ -52,6 +52,37 @@ impl UserDB {
         }
     }
 
+    // Closes the user's database and renames the user's directory, which contains
+    // the database and the other user files.
+    pub(crate) fn move_user_db(&self, from_user_id: &str, to_user_id: &str) -> Result<(), FlowyError> {
+        let _ = self.close_user_db(from_user_id)?;
+        let from = format!("{}/{}", self.db_dir, from_user_id);
+        let to = format!("{}/{}", self.db_dir, to_user_id);
+        if Path::new(&to).exists() {
+            return Err(FlowyError::internal().context(format!("The directory of user {} already exists", to_user_id)));
+        }
+
+        tracing::info!("move user db {} to {}", from_user_id, to_user_id);
+        let _ = std::fs::rename(&from, &to)?;
+        Ok(())
+    }
+
+    // Closes the user's database and deletes the user's directory. The files are
+    // overwritten with zeros before being removed, so the content can't be
+    // recovered from the freed disk blocks easily.
+    pub(crate) fn delete_user_db(&self, user_id: &str) -> Result<(), FlowyError> {
+        let _ = self.close_user_db(user_id)?;
+        let dir = format!("{}/{}", self.db_dir, user_id);
+        if !Path::new(&dir).exists() {
+            return Ok(());
+        }
+
+        tracing::info!("delete user db {}", user_id);
+        let _ = overwrite_dir(Path::new(&dir))?;
+        let _ = fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
     pub(crate) fn get_connection(&self, user_id: &str) -> Result<DBConnection, FlowyError> {
         let conn = self.get_pool(user_id)?.get()?;
         Ok(conn)
@@ -82,6 +113,21 @@ impl UserDB {
     }
 }
 
+fn overwrite_dir(dir: &Path) -> Result<(), FlowyError> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            let _ = overwrite_dir(&path)?;
+        } else {
+            let len = fs::metadata(&path)?.len() as usize;
+            let mut file = OpenOptions::new().write(true).open(&path)?;
+            let _ = file.write_all(&vec![0; len])?;
+            let _ = file.sync_all()?;
+        }
+    }
+    Ok(())
+}
+
 lazy_static! {
     static ref DB_MAP: RwLock<HashMap<String, Database>> = RwLock::new(HashMap::new());
 }
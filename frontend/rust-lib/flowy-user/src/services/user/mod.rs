@@ -2,4 +2,6 @@ pub use user_session::*;
 
 pub mod database;
 mod notifier;
+mod server_url;
+mod token_refresher;
 mod user_session;
@@ -30,9 +30,38 @@ impl UserNotifier {
         });
     }
 
-    pub(crate) fn notify_logout(&self, token: &str) {
+    pub(crate) fn notify_sign_up_anonymously(&self, ret: mpsc::Sender<()>, user_profile: &UserProfile) {
+        let _ = self.user_status_notifier.send(UserStatus::SignUpAnonymously {
+            profile: user_profile.clone(),
+            ret,
+        });
+    }
+
+    pub(crate) fn notify_upgraded(&self, ret: mpsc::Sender<()>, old_user_id: &str, user_profile: &UserProfile) {
+        let _ = self.user_status_notifier.send(UserStatus::Upgraded {
+            old_user_id: old_user_id.to_owned(),
+            profile: user_profile.clone(),
+            ret,
+        });
+    }
+
+    pub(crate) fn notify_logout(&self, token: &str, user_id: &str) {
         let _ = self.user_status_notifier.send(UserStatus::Logout {
             token: token.to_owned(),
+            user_id: user_id.to_owned(),
+        });
+    }
+
+    pub(crate) fn notify_server_changed(&self, token: &str, user_id: &str) {
+        let _ = self.user_status_notifier.send(UserStatus::ServerChanged {
+            token: token.to_owned(),
+            user_id: user_id.to_owned(),
+        });
+    }
+
+    pub(crate) fn notify_expired(&self, token: &str) {
+        let _ = self.user_status_notifier.send(UserStatus::Expired {
+            token: token.to_owned(),
         });
     }
 
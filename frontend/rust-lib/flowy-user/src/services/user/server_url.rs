@@ -0,0 +1,46 @@
+use backend_service::configuration::ServerUrls;
+use flowy_database::kv::KV;
+
+// The server urls that were set at runtime. The ones of the device are used to
+// sign in, an account keeps the ones it signed in with, so they are applied
+// again when its session is restored. A saved None is the server of the build.
+pub(crate) struct ServerUrlStore {
+    device_key: String,
+}
+
+impl ServerUrlStore {
+    pub(crate) fn new(session_cache_key: &str) -> Self {
+        Self {
+            device_key: format!("{}_server_urls", session_cache_key),
+        }
+    }
+
+    // The urls of the account, or the ones of the device if the account has
+    // none saved yet.
+    pub(crate) fn read(&self, user_id: Option<&str>) -> Option<ServerUrls> {
+        let account_urls = user_id.and_then(|user_id| read_urls(&account_key(user_id)));
+        match account_urls {
+            Some(urls) => urls,
+            None => read_urls(&self.device_key).unwrap_or(None),
+        }
+    }
+
+    pub(crate) fn save(&self, user_id: Option<&str>, urls: &Option<ServerUrls>) {
+        let key = match user_id {
+            Some(user_id) => account_key(user_id),
+            None => self.device_key.clone(),
+        };
+        match serde_json::to_string(urls) {
+            Ok(json) => KV::set_str(&key, json),
+            Err(e) => log::error!("Save the server urls failed: {:?}", e),
+        }
+    }
+
+    pub(crate) fn remove_account(&self, user_id: &str) { let _ = KV::remove(&account_key(user_id)); }
+}
+
+fn account_key(user_id: &str) -> String { format!("server_urls_{}", user_id) }
+
+fn read_urls(key: &str) -> Option<Option<ServerUrls>> {
+    KV::get_str(key).and_then(|json| serde_json::from_str(&json).ok())
+}
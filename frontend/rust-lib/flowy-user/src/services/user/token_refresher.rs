@@ -0,0 +1,24 @@
+use crate::services::user::UserSession;
+use backend_service::{errors::ServerError, request::TokenRefresher};
+use lib_infra::future::FutureResultSend;
+use std::sync::Weak;
+
+pub(crate) struct UserTokenRefresher {
+    pub(crate) user_session: Weak<UserSession>,
+}
+
+impl TokenRefresher for UserTokenRefresher {
+    fn refresh_token(&self, expired_token: &str) -> FutureResultSend<String, ServerError> {
+        let user_session = self.user_session.clone();
+        let expired_token = expired_token.to_owned();
+        FutureResultSend::new(async move {
+            match user_session.upgrade() {
+                None => Err(ServerError::unauthorized().context("The user session was dropped")),
+                Some(user_session) => user_session
+                    .refresh_token(&expired_token)
+                    .await
+                    .map_err(|e| ServerError::unauthorized().context(e)),
+            }
+        })
+    }
+}
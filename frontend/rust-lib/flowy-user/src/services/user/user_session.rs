@@ -1,9 +1,12 @@
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, Mutex};
 
-use backend_service::configuration::ClientServerConfiguration;
+use backend_service::{
+    configuration::{ClientServerConfiguration, ServerUrls},
+    request::set_token_refresher,
+};
 use flowy_database::{
     kv::KV,
     query_dsl::*,
@@ -12,16 +15,39 @@ use flowy_database::{
     ExpressionMethods,
     UserDatabaseConnection,
 };
-use flowy_user_data_model::entities::{SignInResponse, SignUpResponse};
+use flowy_user_data_model::entities::{RefreshTokenParams, SignInResponse, SignUpResponse};
+use lib_infra::uuid_string;
 use lib_sqlite::ConnectionPool;
 
 use crate::{
-    entities::{SignInParams, SignUpParams, UpdateUserParams, UserProfile},
+    entities::{
+        DeleteAccountProgress,
+        DeleteAccountStep,
+        RepeatedUserDevice,
+        RevokeDeviceParams,
+        ServerUrlSetting,
+        SignInParams,
+        SignUpParams,
+        UpdateServerUrlParams,
+        UpdateUserParams,
+        UploadAvatarParams,
+        UserAvatar,
+        UserProfile,
+        UserSettings,
+    },
     errors::{ErrorCode, FlowyError},
     notify::*,
     services::{
+        avatar::{AvatarCache, AvatarUser},
+        lock::AppLock,
         server::{construct_user_server, Server},
-        user::{database::UserDB, notifier::UserNotifier},
+        settings::UserSettingsController,
+        user::{
+            database::UserDB,
+            notifier::UserNotifier,
+            server_url::ServerUrlStore,
+            token_refresher::UserTokenRefresher,
+        },
     },
     sql_tables::{UserTable, UserTableChangeset},
 };
@@ -30,6 +56,7 @@ pub struct UserSessionConfig {
     root_dir: String,
     server_config: ClientServerConfiguration,
     session_cache_key: String,
+    device_name: String,
 }
 
 impl UserSessionConfig {
@@ -38,8 +65,15 @@ impl UserSessionConfig {
             root_dir: root_dir.to_owned(),
             server_config: server_config.clone(),
             session_cache_key: session_cache_key.to_owned(),
+            device_name: std::env::consts::OS.to_owned(),
         }
     }
+
+    // The name shown in the device list of the user, e.g. the host name.
+    pub fn device_name(mut self, device_name: &str) -> Self {
+        self.device_name = device_name.to_owned();
+        self
+    }
 }
 
 pub struct UserSession {
@@ -48,7 +82,12 @@ pub struct UserSession {
     #[allow(dead_code)]
     server: Server,
     session: RwLock<Option<Session>>,
+    server_urls: ServerUrlStore,
+    avatar_cache: AvatarCache,
+    refresh_lock: Mutex<()>,
     pub notifier: UserNotifier,
+    pub app_lock: Arc<AppLock>,
+    pub settings: Arc<UserSettingsController>,
 }
 
 impl UserSession {
@@ -56,18 +95,38 @@ impl UserSession {
         let db = UserDB::new(&config.root_dir);
         let server = construct_user_server(&config.server_config);
         let notifier = UserNotifier::new();
+        let app_lock = Arc::new(AppLock::new());
+        let settings = Arc::new(UserSettingsController::new());
+        let avatar_cache = AvatarCache::new(server.clone());
+        // The server of the restored session is set before anything connects
+        // to it.
+        let server_urls = ServerUrlStore::new(&config.session_cache_key);
+        let user_id = KV::get_str(&config.session_cache_key).map(|s| Session::from(s).user_id);
+        config
+            .server_config
+            .set_custom_urls(server_urls.read(user_id.as_deref()));
         Self {
             database: db,
             config,
             server,
             session: RwLock::new(None),
+            server_urls,
+            avatar_cache,
+            refresh_lock: Mutex::new(()),
             notifier,
+            app_lock,
+            settings,
         }
     }
 
-    pub fn init(&self) {
+    pub fn init(self: &Arc<Self>) {
+        self.app_lock.start_idle_timer();
+        set_token_refresher(Arc::new(UserTokenRefresher {
+            user_session: Arc::downgrade(self),
+        }));
         if let Ok(session) = self.get_session() {
             self.notifier.notify_login(&session.token, &session.user_id);
+            self.settings.load_from_revisions(&session.user_id);
         }
     }
 
@@ -87,31 +146,49 @@ impl UserSession {
         self.database.get_pool(&user_id)
     }
 
+    // Closes the database of the current user, e.g. before the app quits. It's
+    // opened again by the next connection.
+    pub fn close_db(&self) -> Result<(), FlowyError> {
+        let user_id = self.get_session()?.user_id;
+        self.database.close_user_db(&user_id)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
-    pub async fn sign_in(&self, params: SignInParams) -> Result<UserProfile, FlowyError> {
+    pub async fn sign_in(&self, mut params: SignInParams) -> Result<UserProfile, FlowyError> {
         if self.is_login(&params.email) {
             self.user_profile().await
         } else {
+            params.device_name = self.config.device_name.clone();
+            params.platform = std::env::consts::OS.to_owned();
             let resp = self.server.sign_in(params).await?;
+            self.save_account_server_urls(&resp.user_id);
             let session: Session = resp.clone().into();
             let _ = self.set_session(Some(session))?;
             let user_table = self.save_user(resp.into()).await?;
             let user_profile: UserProfile = user_table.into();
             self.notifier.notify_login(&user_profile.token, &user_profile.id);
+            self.settings.load_from_revisions(&user_profile.id);
             Ok(user_profile)
         }
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
-    pub async fn sign_up(&self, params: SignUpParams) -> Result<UserProfile, FlowyError> {
+    pub async fn sign_up(&self, mut params: SignUpParams) -> Result<UserProfile, FlowyError> {
         if self.is_login(&params.email) {
             self.user_profile().await
         } else {
+            params.device_name = self.config.device_name.clone();
+            params.platform = std::env::consts::OS.to_owned();
             let resp = self.server.sign_up(params).await?;
+            self.save_account_server_urls(&resp.user_id);
             let session: Session = resp.clone().into();
             let _ = self.set_session(Some(session))?;
             let user_table = self.save_user(resp.into()).await?;
             let user_profile: UserProfile = user_table.into();
+            let _ = self
+                .settings
+                .create_settings_document(&user_profile.id, UserSettings::default())
+                .await?;
             let (ret, mut tx) = mpsc::channel(1);
             self.notifier.notify_sign_up(ret, &user_profile);
 
@@ -120,16 +197,156 @@ impl UserSession {
         }
     }
 
+    // Anonymous users only exist on this device. The generated user id is also used
+    // as the token because there is no server session to authenticate against.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn sign_in_anonymously(&self) -> Result<UserProfile, FlowyError> {
+        if self.get_session().is_ok() {
+            return self.user_profile().await;
+        }
+
+        let user_id = uuid_string();
+        let session = Session {
+            user_id: user_id.clone(),
+            token: user_id.clone(),
+            is_anonymous: true,
+            ..Default::default()
+        };
+        let _ = self.set_session(Some(session))?;
+        let user_table = self
+            .save_user(UserTable::new(user_id.clone(), "".to_owned(), "".to_owned(), user_id))
+            .await?;
+        let user_profile: UserProfile = user_table.into();
+        let _ = self
+            .settings
+            .create_settings_document(&user_profile.id, UserSettings::default())
+            .await?;
+        let (ret, mut tx) = mpsc::channel(1);
+        self.notifier.notify_sign_up_anonymously(ret, &user_profile);
+
+        let _ = tx.recv().await;
+        Ok(user_profile)
+    }
+
+    // Signs up the anonymous user and hands the local data over to the new account.
+    // The user's directory is renamed to the new user id, and the listener of
+    // UserStatus::Upgraded takes care of re-keying and uploading the folder data.
+    // The server picks the id of the new account, so it's signed up first and
+    // deleted again if the local data can't be handed over to it.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn upgrade_anonymous_user(&self, mut params: SignUpParams) -> Result<UserProfile, FlowyError> {
+        let anonymous = self.get_session()?;
+        if !anonymous.is_anonymous {
+            return Err(FlowyError::internal().context("Only the anonymous user can be upgraded"));
+        }
+
+        params.device_name = self.config.device_name.clone();
+        params.platform = std::env::consts::OS.to_owned();
+        let resp = self.server.sign_up(params).await?;
+        if let Err(e) = self.database.move_user_db(&anonymous.user_id, &resp.user_id) {
+            self.delete_upgraded_account(&resp.token).await;
+            return Err(e);
+        }
+
+        let user_profile = match self.migrate_anonymous_user(&anonymous, &resp).await {
+            Ok(user_profile) => user_profile,
+            Err(e) => {
+                self.revert_anonymous_upgrade(&anonymous, &resp).await;
+                return Err(e);
+            },
+        };
+        let (ret, mut tx) = mpsc::channel(1);
+        self.notifier.notify_upgraded(ret, &anonymous.user_id, &user_profile);
+
+        let _ = tx.recv().await;
+        Ok(user_profile)
+    }
+
+    // The row of the anonymous user is deleted last, so the directory can be
+    // moved back as it was if any of the steps fails.
+    async fn migrate_anonymous_user(
+        &self,
+        anonymous: &Session,
+        resp: &SignUpResponse,
+    ) -> Result<UserProfile, FlowyError> {
+        self.save_account_server_urls(&resp.user_id);
+        let session: Session = resp.clone().into();
+        let _ = self.set_session(Some(session))?;
+
+        let user_table = self.save_user(resp.clone().into()).await?;
+        let settings = self.settings.settings(&anonymous.user_id);
+        let _ = self.settings.create_settings_document(&user_table.id, settings).await?;
+        let _ = diesel::delete(dsl::user_table.filter(dsl::id.eq(&anonymous.user_id)))
+            .execute(&*(self.db_connection()?))?;
+        Ok(user_table.into())
+    }
+
+    // The errors are only logged, the caller returns the error of the migration.
+    async fn revert_anonymous_upgrade(&self, anonymous: &Session, resp: &SignUpResponse) {
+        if let Err(e) = self.set_session(Some(anonymous.clone())) {
+            log::error!("Restore the anonymous session failed: {:?}", e);
+        }
+        self.restore_device_server_urls(&resp.user_id);
+        self.settings.remove_settings(&resp.user_id);
+
+        let deleted = self.database.get_connection(&resp.user_id).and_then(|conn| {
+            let _ = diesel::delete(dsl::user_table.filter(dsl::id.eq(&resp.user_id))).execute(&*conn)?;
+            Ok(())
+        });
+        if let Err(e) = deleted {
+            log::error!("Delete the upgraded user failed: {:?}", e);
+        }
+        if let Err(e) = self.database.move_user_db(&resp.user_id, &anonymous.user_id) {
+            log::error!("Move the user db back to the anonymous user failed: {:?}", e);
+        }
+        self.delete_upgraded_account(&resp.token).await;
+    }
+
+    async fn delete_upgraded_account(&self, token: &str) {
+        if let Err(e) = self.server.delete_user(token).await {
+            log::error!("Delete the account of the failed upgrade failed: {:?}", e);
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn sign_out(&self) -> Result<(), FlowyError> {
         let session = self.get_session()?;
-        let _ =
-            diesel::delete(dsl::user_table.filter(dsl::id.eq(&session.user_id))).execute(&*(self.db_connection()?))?;
-        let _ = self.database.close_user_db(&session.user_id)?;
+        let _ = self.sign_out_locally(&session)?;
+        if !session.is_anonymous {
+            let _ = self.sign_out_on_server(&session.token).await?;
+        }
+
+        Ok(())
+    }
+
+    // The account is deleted on the server first. The local data is only wiped if
+    // that succeeded, otherwise the user could end up with an account that can't
+    // be deleted from this device anymore.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn delete_account(&self) -> Result<(), FlowyError> {
+        let session = self.get_session()?;
+        let notify_progress = |step: DeleteAccountStep| {
+            dart_notify(&session.user_id, UserNotification::DeleteAccountProgress)
+                .payload(DeleteAccountProgress {
+                    user_id: session.user_id.clone(),
+                    step,
+                })
+                .send();
+        };
+
+        if !session.is_anonymous {
+            notify_progress(DeleteAccountStep::DeletingServerData);
+            let _ = self.server.delete_user(&session.token).await?;
+        }
+
+        notify_progress(DeleteAccountStep::DeletingLocalData);
         let _ = self.set_session(None)?;
-        self.notifier.notify_logout(&session.token);
-        let _ = self.sign_out_on_server(&session.token).await?;
+        self.restore_device_server_urls(&session.user_id);
+        self.notifier.notify_logout(&session.token, &session.user_id);
+        let _ = self.database.delete_user_db(&session.user_id)?;
+        self.settings.remove_settings(&session.user_id);
 
+        notify_progress(DeleteAccountStep::Finished);
         Ok(())
     }
 
@@ -139,30 +356,84 @@ impl UserSession {
         let changeset = UserTableChangeset::new(params.clone());
         diesel_update_table!(user_table, changeset, &*self.db_connection()?);
 
-        let _ = self.update_user_on_server(&session.token, params).await?;
+        if !session.is_anonymous {
+            let _ = self.update_user_on_server(&session.token, params).await?;
+        }
         Ok(())
     }
 
+    // Exchanges the refresh token for a new token. Concurrent requests that failed
+    // with the same expired token wait on the lock and reuse the token fetched by
+    // the first one. If the refresh fails, the session is treated as expired. If
+    // the server rejects the refresh token, e.g. the session was revoked from
+    // another device, the user is signed out.
+    #[tracing::instrument(level = "debug", skip(self, expired_token))]
+    pub async fn refresh_token(&self, expired_token: &str) -> Result<String, FlowyError> {
+        let _guard = self.refresh_lock.lock().await;
+        let session = self.get_session()?;
+        if session.token != expired_token {
+            return Ok(session.token);
+        }
+
+        if session.is_anonymous || session.refresh_token.is_empty() {
+            self.notify_session_expired(&session.token);
+            return Err(FlowyError::unauthorized());
+        }
+
+        let params = RefreshTokenParams {
+            refresh_token: session.refresh_token.clone(),
+        };
+        match self.server.refresh_token(params).await {
+            Ok(resp) => {
+                let _ = diesel::update(dsl::user_table.filter(dsl::id.eq(&session.user_id)))
+                    .set(dsl::token.eq(&resp.token))
+                    .execute(&*(self.db_connection()?))?;
+                let token = resp.token.clone();
+                let _ = self.set_session(Some(Session {
+                    token: resp.token,
+                    refresh_token: resp.refresh_token,
+                    ..session
+                }))?;
+                Ok(token)
+            },
+            Err(e) => {
+                self.notify_session_expired(&session.token);
+                if e.code == ErrorCode::UserUnauthorized.value() {
+                    let _ = self.sign_out_locally(&session)?;
+                }
+                Err(e)
+            },
+        }
+    }
+
     pub async fn init_user(&self) -> Result<(), FlowyError> { Ok(()) }
 
     pub async fn check_user(&self) -> Result<UserProfile, FlowyError> {
-        let (user_id, token) = self.get_session()?.into_part();
+        let session = self.get_session()?;
+        let is_anonymous = session.is_anonymous;
+        let (user_id, token) = session.into_part();
 
         let user = dsl::user_table
             .filter(user_table::id.eq(&user_id))
             .first::<UserTable>(&*(self.db_connection()?))?;
 
-        let _ = self.read_user_profile_on_server(&token)?;
+        if !is_anonymous {
+            let _ = self.read_user_profile_on_server(&token)?;
+        }
         Ok(user.into())
     }
 
     pub async fn user_profile(&self) -> Result<UserProfile, FlowyError> {
-        let (user_id, token) = self.get_session()?.into_part();
+        let session = self.get_session()?;
+        let is_anonymous = session.is_anonymous;
+        let (user_id, token) = session.into_part();
         let user = dsl::user_table
             .filter(user_table::id.eq(&user_id))
             .first::<UserTable>(&*(self.db_connection()?))?;
 
-        let _ = self.read_user_profile_on_server(&token)?;
+        if !is_anonymous {
+            let _ = self.read_user_profile_on_server(&token)?;
+        }
         Ok(user.into())
     }
 
@@ -176,6 +447,83 @@ impl UserSession {
     pub fn user_name(&self) -> Result<String, FlowyError> { Ok(self.get_session()?.name) }
 
     pub fn token(&self) -> Result<String, FlowyError> { Ok(self.get_session()?.token) }
+
+    pub fn user_settings(&self) -> Result<UserSettings, FlowyError> {
+        let user_id = self.user_id()?;
+        Ok(self.settings.settings(&user_id))
+    }
+
+    pub fn update_user_settings(&self, settings: UserSettings) -> Result<UserSettings, FlowyError> {
+        let user_id = self.user_id()?;
+        self.settings.update_settings(&user_id, |old| *old = settings)
+    }
+
+    pub async fn upload_avatar(&self, params: UploadAvatarParams) -> Result<UserAvatar, FlowyError> {
+        let user = self.avatar_user()?;
+        self.avatar_cache.upload_avatar(&user, params).await
+    }
+
+    pub async fn read_avatar(&self, user_id: &str) -> Result<UserAvatar, FlowyError> {
+        let user = self.avatar_user()?;
+        self.avatar_cache.read_avatar(&user, user_id).await
+    }
+
+    pub async fn read_user_devices(&self) -> Result<RepeatedUserDevice, FlowyError> {
+        let session = self.get_session()?;
+        if session.is_anonymous {
+            return Ok(RepeatedUserDevice::default());
+        }
+        self.server.read_user_devices(&session.token).await
+    }
+
+    pub async fn revoke_user_device(&self, params: RevokeDeviceParams) -> Result<(), FlowyError> {
+        let session = self.get_session()?;
+        if session.is_anonymous {
+            return Err(FlowyError::unauthorized().context("The anonymous user has no server session"));
+        }
+        self.server.revoke_user_device(&session.token, params).await
+    }
+
+    pub fn server_url_setting(&self) -> ServerUrlSetting {
+        let server_config = &self.config.server_config;
+        ServerUrlSetting {
+            base_url: server_config.base_url(),
+            ws_url: server_config.ws_addr(),
+            is_custom: server_config.custom_urls().is_some(),
+        }
+    }
+
+    // Applies the urls right away. While a user is signed in they are saved
+    // for the account and its websocket connects again, otherwise they are
+    // the ones the next sign in goes to.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn update_server_url(&self, params: UpdateServerUrlParams) -> Result<ServerUrlSetting, FlowyError> {
+        let urls = match params.base_url.is_empty() {
+            true => None,
+            false => Some(ServerUrls {
+                base_url: params.base_url,
+                ws_url: params.ws_url,
+            }),
+        };
+        self.config.server_config.set_custom_urls(urls.clone());
+        match self.get_session() {
+            Ok(session) => {
+                self.server_urls.save(Some(&session.user_id), &urls);
+                if !session.is_anonymous {
+                    self.notifier.notify_server_changed(&session.token, &session.user_id);
+                }
+            },
+            Err(_) => self.server_urls.save(None, &urls),
+        }
+        Ok(self.server_url_setting())
+    }
+
+    pub fn is_anonymous(&self) -> bool {
+        match self.get_session() {
+            Ok(session) => session.is_anonymous,
+            Err(_) => false,
+        }
+    }
 }
 
 impl UserSession {
@@ -228,6 +576,43 @@ impl UserSession {
         Ok(())
     }
 
+    fn avatar_user(&self) -> Result<AvatarUser, FlowyError> {
+        let session = self.get_session()?;
+        Ok(AvatarUser {
+            user_dir: format!("{}/{}", self.config.root_dir, session.user_id),
+            user_id: session.user_id,
+            token: session.token,
+            is_anonymous: session.is_anonymous,
+        })
+    }
+
+    fn sign_out_locally(&self, session: &Session) -> Result<(), FlowyError> {
+        let _ =
+            diesel::delete(dsl::user_table.filter(dsl::id.eq(&session.user_id))).execute(&*(self.db_connection()?))?;
+        let _ = self.database.close_user_db(&session.user_id)?;
+        let _ = self.set_session(None)?;
+        self.restore_device_server_urls(&session.user_id);
+        self.notifier.notify_logout(&session.token, &session.user_id);
+        Ok(())
+    }
+
+    fn save_account_server_urls(&self, user_id: &str) {
+        self.server_urls
+            .save(Some(user_id), &self.config.server_config.custom_urls());
+    }
+
+    fn restore_device_server_urls(&self, user_id: &str) {
+        self.server_urls.remove_account(user_id);
+        self.config.server_config.set_custom_urls(self.server_urls.read(None));
+    }
+
+    fn notify_session_expired(&self, token: &str) {
+        dart_notify(token, UserNotification::UserUnauthorized)
+            .error(FlowyError::unauthorized())
+            .send();
+        self.notifier.notify_expired(token);
+    }
+
     async fn save_user(&self, user: UserTable) -> Result<UserTable, FlowyError> {
         let conn = self.db_connection()?;
         let _ = diesel::insert_into(user_table::table)
@@ -294,6 +679,10 @@ struct Session {
     email: String,
     #[serde(default)]
     name: String,
+    #[serde(default)]
+    is_anonymous: bool,
+    #[serde(default)]
+    refresh_token: String,
 }
 
 impl std::convert::From<SignInResponse> for Session {
@@ -303,6 +692,8 @@ impl std::convert::From<SignInResponse> for Session {
             token: resp.token,
             email: resp.email,
             name: resp.name,
+            is_anonymous: false,
+            refresh_token: resp.refresh_token,
         }
     }
 }
@@ -314,6 +705,8 @@ impl std::convert::From<SignUpResponse> for Session {
             token: resp.token,
             email: resp.email,
             name: resp.name,
+            is_anonymous: false,
+            refresh_token: resp.refresh_token,
         }
     }
 }
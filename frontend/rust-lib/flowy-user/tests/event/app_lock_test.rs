@@ -0,0 +1,80 @@
+use flowy_test::{event_builder::UserModuleEventBuilder, FlowySDKTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+#[tokio::test]
+async fn app_lock_set_invalid_passcode() {
+    let sdk = FlowySDKTest::default();
+    let request = SetAppPasscodeRequest {
+        old_passcode: None,
+        passcode: "12".to_owned(),
+        idle_timeout: 0,
+    };
+
+    assert_eq!(
+        UserModuleEventBuilder::new(sdk)
+            .event(SetAppPasscode)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::AppPasscodeInvalid.value()
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn app_lock_lock_and_unlock() {
+    let sdk = FlowySDKTest::default();
+    let passcode = "1234".to_owned();
+    let state = UserModuleEventBuilder::new(sdk.clone())
+        .event(SetAppPasscode)
+        .request(SetAppPasscodeRequest {
+            old_passcode: None,
+            passcode: passcode.clone(),
+            idle_timeout: 0,
+        })
+        .async_send()
+        .await
+        .parse::<AppLockState>();
+    assert!(state.has_passcode);
+
+    let state = UserModuleEventBuilder::new(sdk.clone())
+        .event(LockApp)
+        .async_send()
+        .await
+        .parse::<AppLockState>();
+    assert!(state.is_locked);
+
+    assert_eq!(
+        UserModuleEventBuilder::new(sdk.clone())
+            .event(UnlockApp)
+            .request(AppPasscodeRequest {
+                passcode: "4321".to_owned(),
+            })
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::AppPasscodeNotMatch.value()
+    );
+
+    let state = UserModuleEventBuilder::new(sdk.clone())
+        .event(UnlockApp)
+        .request(AppPasscodeRequest {
+            passcode: passcode.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<AppLockState>();
+    assert!(!state.is_locked);
+
+    let state = UserModuleEventBuilder::new(sdk)
+        .event(RemoveAppPasscode)
+        .request(AppPasscodeRequest { passcode })
+        .async_send()
+        .await
+        .parse::<AppLockState>();
+    assert!(!state.has_passcode);
+}
@@ -1,6 +1,7 @@
 use crate::helper::*;
 use flowy_test::{event_builder::UserModuleEventBuilder, FlowySDKTest};
 use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
 
 #[tokio::test]
 async fn sign_up_with_invalid_email() {
@@ -106,3 +107,106 @@ async fn sign_in_with_invalid_password() {
             .assert_error();
     }
 }
+
+#[tokio::test]
+async fn sign_in_anonymously_then_upgrade() {
+    let test = FlowySDKTest::default();
+    let _ = UserModuleEventBuilder::new(test.clone()).event(SignOut).sync_send();
+    let anonymous = UserModuleEventBuilder::new(test.clone())
+        .event(SignInAnonymously)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert!(anonymous.email.is_empty());
+
+    let request = SignUpRequest {
+        email: random_email(),
+        name: valid_name(),
+        password: login_password(),
+    };
+    let user_profile = UserModuleEventBuilder::new(test.clone())
+        .event(UpgradeAnonymousUser)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_ne!(anonymous.id, user_profile.id);
+
+    UserModuleEventBuilder::new(test.clone())
+        .event(UpgradeAnonymousUser)
+        .request(SignUpRequest {
+            email: random_email(),
+            name: valid_name(),
+            password: login_password(),
+        })
+        .async_send()
+        .await
+        .assert_error();
+}
+
+#[tokio::test]
+async fn upgrade_anonymous_user_failed_keeps_anonymous_session() {
+    let test = FlowySDKTest::default();
+    let _ = UserModuleEventBuilder::new(test.clone()).event(SignOut).sync_send();
+    let anonymous = UserModuleEventBuilder::new(test.clone())
+        .event(SignInAnonymously)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+
+    // The local data can't be moved to the new account without the directory
+    let user_dir = test.user_session.user_dir().unwrap();
+    std::fs::remove_dir_all(&user_dir).unwrap();
+    UserModuleEventBuilder::new(test.clone())
+        .event(UpgradeAnonymousUser)
+        .request(SignUpRequest {
+            email: random_email(),
+            name: valid_name(),
+            password: login_password(),
+        })
+        .async_send()
+        .await
+        .assert_error();
+    assert_eq!(test.user_session.user_id().unwrap(), anonymous.id);
+    assert_eq!(test.user_session.user_dir().unwrap(), user_dir);
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_account_wipes_local_data() {
+    let test = FlowySDKTest::default();
+    let _ = test.init_user().await;
+    let user_dir = test.user_session.user_dir().unwrap();
+    assert!(std::path::Path::new(&user_dir).exists());
+
+    let _ = UserModuleEventBuilder::new(test.clone())
+        .event(DeleteAccount)
+        .async_send()
+        .await;
+    assert!(!std::path::Path::new(&user_dir).exists());
+
+    UserModuleEventBuilder::new(test.clone())
+        .event(GetUserProfile)
+        .assert_error()
+        .async_send()
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn revoke_device_with_empty_session_id() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    assert_eq!(
+        UserModuleEventBuilder::new(sdk)
+            .event(RevokeUserDevice)
+            .request(RevokeDeviceRequest {
+                session_id: " ".to_owned(),
+            })
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::SessionIdInvalid.value()
+    );
+}
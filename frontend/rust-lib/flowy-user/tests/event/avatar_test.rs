@@ -0,0 +1,43 @@
+use flowy_test::{event_builder::UserModuleEventBuilder, FlowySDKTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+#[tokio::test]
+#[serial]
+async fn avatar_upload_then_read() {
+    let sdk = FlowySDKTest::default();
+    let user = sdk.init_user().await;
+    let data = vec![137, 80, 78, 71, 13, 10, 26, 10];
+    let avatar = UserModuleEventBuilder::new(sdk.clone())
+        .event(UploadAvatar)
+        .request(UploadAvatarRequest { data: data.clone() })
+        .async_send()
+        .await
+        .parse::<UserAvatar>();
+    assert_eq!(avatar.user_id, user.id);
+
+    let avatar = UserModuleEventBuilder::new(sdk)
+        .event(GetUserAvatar)
+        .request(UserAvatarRequest { user_id: user.id })
+        .async_send()
+        .await
+        .parse::<UserAvatar>();
+    assert_eq!(avatar.data, data);
+}
+
+#[tokio::test]
+#[serial]
+async fn avatar_upload_empty_data() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    assert_eq!(
+        UserModuleEventBuilder::new(sdk)
+            .event(UploadAvatar)
+            .request(UploadAvatarRequest { data: vec![] })
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::AvatarIsEmpty.value()
+    );
+}
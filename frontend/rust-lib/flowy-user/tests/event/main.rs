@@ -1,3 +1,7 @@
+mod app_lock_test;
 mod auth_test;
+mod avatar_test;
 mod helper;
+mod server_url_test;
 mod user_profile_test;
+mod user_settings_test;
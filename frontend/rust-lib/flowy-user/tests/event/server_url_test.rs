@@ -0,0 +1,39 @@
+use flowy_test::{event_builder::UserModuleEventBuilder, FlowySDKTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+
+#[tokio::test]
+async fn update_server_url_of_signed_in_user() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let update = |base_url: &str, ws_url: &str| {
+        UserModuleEventBuilder::new(sdk.clone())
+            .event(UpdateServerUrl)
+            .request(UpdateServerUrlRequest {
+                base_url: base_url.to_owned(),
+                ws_url: ws_url.to_owned(),
+            })
+    };
+
+    let setting = update("https://appflowy.example.com/", "")
+        .async_send()
+        .await
+        .parse::<ServerUrlSetting>();
+    assert!(setting.is_custom);
+    assert_eq!(setting.base_url, "https://appflowy.example.com");
+    assert_eq!(setting.ws_url, "wss://appflowy.example.com/ws");
+    let read_setting = UserModuleEventBuilder::new(sdk.clone())
+        .event(GetServerUrl)
+        .async_send()
+        .await
+        .parse::<ServerUrlSetting>();
+    assert_eq!(read_setting, setting);
+
+    let error = update("https://appflowy.example.com", "https://appflowy.example.com/ws")
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ServerUrlInvalid.value());
+
+    let setting = update("", "").async_send().await.parse::<ServerUrlSetting>();
+    assert!(!setting.is_custom);
+}
@@ -0,0 +1,121 @@
+use flowy_test::{event_builder::UserModuleEventBuilder, FlowySDKTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+#[tokio::test]
+#[serial]
+async fn user_settings_get_default() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let settings = UserModuleEventBuilder::new(sdk)
+        .event(GetUserSettings)
+        .async_send()
+        .await
+        .parse::<UserSettings>();
+    assert_eq!(settings, UserSettings::default());
+}
+
+#[tokio::test]
+#[serial]
+async fn user_settings_update() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let request = UpdateUserSettingsRequest {
+        theme: Theme::Dark,
+        locale: "zh-CN".to_owned(),
+        sync_policy: SyncPolicy::WifiOnly,
+        revision_retention_days: 30,
+    };
+    let _ = UserModuleEventBuilder::new(sdk.clone())
+        .event(UpdateUserSettings)
+        .request(request)
+        .async_send()
+        .await;
+
+    let settings = UserModuleEventBuilder::new(sdk)
+        .event(GetUserSettings)
+        .async_send()
+        .await
+        .parse::<UserSettings>();
+    assert_eq!(settings.theme, Theme::Dark);
+    assert_eq!(settings.locale, "zh-CN");
+    assert_eq!(settings.sync_policy, SyncPolicy::WifiOnly);
+    assert_eq!(settings.revision_retention_days, 30);
+}
+
+#[tokio::test]
+#[serial]
+async fn user_settings_update_with_invalid_locale() {
+    let sdk = FlowySDKTest::default();
+    let _ = sdk.init_user().await;
+    let request = UpdateUserSettingsRequest {
+        locale: "english".to_owned(),
+        ..Default::default()
+    };
+    assert_eq!(
+        UserModuleEventBuilder::new(sdk)
+            .event(UpdateUserSettings)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::UserLocaleInvalid.value()
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn user_settings_synced_to_document() {
+    let sdk = FlowySDKTest::default();
+    let user_profile = sdk.init_user().await;
+    assert_eq!(
+        settings_document_theme(&sdk, &user_profile.id).await,
+        Some(Theme::Light as i64)
+    );
+
+    let request = UpdateUserSettingsRequest {
+        theme: Theme::Dark,
+        locale: "en-US".to_owned(),
+        ..Default::default()
+    };
+    let _ = UserModuleEventBuilder::new(sdk.clone())
+        .event(UpdateUserSettings)
+        .request(request)
+        .async_send()
+        .await;
+    assert!(wait_for_document_theme(&sdk, &user_profile.id, Theme::Dark as i64).await);
+}
+
+#[tokio::test]
+#[serial]
+async fn user_settings_broken_document_is_repaired() {
+    let sdk = FlowySDKTest::default();
+    let user_profile = sdk.init_user().await;
+    let doc_id = format!("{}_settings", user_profile.id);
+    let editor = sdk.document_ctx.controller.open_document(&doc_id).await.unwrap();
+    editor.reset_text(r#"{"theme":1}{"theme":"#).await.unwrap();
+    assert_eq!(settings_document_theme(&sdk, &user_profile.id).await, None);
+
+    // Loads the settings from the document like the next start of the app.
+    sdk.user_session.init();
+    assert!(wait_for_document_theme(&sdk, &user_profile.id, Theme::Light as i64).await);
+}
+
+async fn settings_document_theme(sdk: &FlowySDKTest, user_id: &str) -> Option<i64> {
+    let doc_id = format!("{}_settings", user_id);
+    let editor = sdk.document_ctx.controller.open_document(&doc_id).await.unwrap();
+    let text = editor.document_text().await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(text.trim_end_matches('\n')).ok()?;
+    json["theme"].as_i64()
+}
+
+async fn wait_for_document_theme(sdk: &FlowySDKTest, user_id: &str, theme: i64) -> bool {
+    for _ in 0..50 {
+        if settings_document_theme(sdk, user_id).await == Some(theme) {
+            return true;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    false
+}
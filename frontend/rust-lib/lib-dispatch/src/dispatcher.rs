@@ -1,19 +1,28 @@
 use crate::{
     errors::{DispatchError, Error, InternalError},
-    module::{as_module_map, Module, ModuleMap, ModuleRequest},
-    response::EventResponse,
+    module::{as_module_map, Event, Module, ModuleMap, ModuleRequest},
+    response::{EventResponse, StatusCode},
     service::{Service, ServiceFactory},
     util::tokio_default_runtime,
 };
 use derivative::*;
 use futures_core::future::BoxFuture;
 use futures_util::task::Context;
+use lib_infra::metrics;
 use pin_project::pin_project;
-use std::{future::Future, sync::Arc};
+use std::{future::Future, sync::Arc, time::Instant};
 use tokio::macros::support::{Pin, Poll};
+use tracing::Instrument;
+
+/// Called with the event and the response of every request, after the
+/// handler returns. It runs on the dispatcher's runtime, so it should return
+/// quickly.
+pub type ResponseObserver = Arc<dyn Fn(&Event, &EventResponse) + Send + Sync>;
+
 pub struct EventDispatcher {
     module_map: ModuleMap,
     runtime: tokio::runtime::Runtime,
+    observer: Option<ResponseObserver>,
 }
 
 impl EventDispatcher {
@@ -26,7 +35,16 @@ impl EventDispatcher {
         tracing::trace!("{}", module_info(&modules));
         let module_map = as_module_map(modules);
 
-        EventDispatcher { module_map, runtime }
+        EventDispatcher {
+            module_map,
+            runtime,
+            observer: None,
+        }
+    }
+
+    pub fn observe_responses(mut self, observer: ResponseObserver) -> Self {
+        self.observer = Some(observer);
+        self
     }
 
     pub fn async_send<Req>(dispatch: Arc<EventDispatcher>, request: Req) -> DispatchFuture<EventResponse>
@@ -49,16 +67,34 @@ impl EventDispatcher {
         let module_map = dispatch.module_map.clone();
         let service = Box::new(DispatchService { module_map });
         tracing::trace!("Async event: {:?}", &request.event);
+        // Everything that is logged while the handler runs carries the event and
+        // the request id. The tasks spawned by the handler don't inherit it.
+        let span = tracing::info_span!("dispatch", event = %request.event.name(), request_id = %request.id);
+        let latency_name = format!("dispatch.{}", request.event.name());
+        let event = request.event.clone();
+        let observer = dispatch.observer.clone();
         let service_ctx = DispatchContext {
             request,
             callback: Some(Box::new(callback)),
         };
-        let join_handle = dispatch.runtime.spawn(async move {
-            service
-                .call(service_ctx)
-                .await
-                .unwrap_or_else(|e| InternalError::Other(format!("{:?}", e)).as_response())
-        });
+        let join_handle = dispatch.runtime.spawn(
+            async move {
+                let start = Instant::now();
+                let response = service
+                    .call(service_ctx)
+                    .await
+                    .unwrap_or_else(|e| InternalError::Other(format!("{:?}", e)).as_response());
+                metrics::record_latency(&latency_name, start.elapsed());
+                if response.status_code != StatusCode::Ok {
+                    metrics::increment_counter(metrics::DISPATCH_ERROR_COUNT);
+                }
+                if let Some(observer) = observer {
+                    observer(&event, &response);
+                }
+                response
+            }
+            .instrument(span),
+        );
 
         DispatchFuture {
             fut: Box::pin(async move {
@@ -47,6 +47,10 @@ pub(crate) fn as_module_map(modules: Vec<Module>) -> ModuleMap {
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
 pub struct Event(String);
 
+impl Event {
+    pub fn name(&self) -> &str { &self.0 }
+}
+
 impl<T: Display + Eq + Hash + Debug + Clone> std::convert::From<T> for Event {
     fn from(t: T) -> Self { Event(format!("{}", t)) }
 }
@@ -0,0 +1,16 @@
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use std::collections::BTreeMap;
+
+lazy_static! {
+    static ref LOG_CONTEXT: RwLock<BTreeMap<String, String>> = RwLock::new(BTreeMap::new());
+}
+
+/// Adds a field, e.g. the `user_id` or the `workspace_id`, to every log line
+/// until it's removed. The fields of the event or its spans win if they share
+/// the same key.
+pub fn set_log_context(key: &str, value: &str) { LOG_CONTEXT.write().insert(key.to_owned(), value.to_owned()); }
+
+pub fn remove_log_context(key: &str) { LOG_CONTEXT.write().remove(key); }
+
+pub(crate) fn log_context() -> BTreeMap<String, String> { LOG_CONTEXT.read().clone() }
@@ -1,3 +1,4 @@
+use crate::context::log_context;
 use serde::ser::{SerializeMap, Serializer};
 use serde_json::Value;
 use std::{fmt, io::Write};
@@ -59,8 +60,9 @@ impl<W: MakeWriter + 'static> FlowyFormattingLayer<W> {
         map_serializer.serialize_entry("file", &span.metadata().file())?;
 
         let extensions = span.extensions();
-        if let Some(visitor) = extensions.get::<JsonStorage>() {
-            for (key, value) in visitor.values() {
+        let span_values = extensions.get::<JsonStorage>().map(|visitor| visitor.values());
+        if let Some(values) = span_values {
+            for (key, value) in values {
                 if !FLOWY_RESERVED_FIELDS.contains(key) && !IGNORE_FIELDS.contains(key) {
                     map_serializer.serialize_entry(key, value)?;
                 } else {
@@ -68,6 +70,9 @@ impl<W: MakeWriter + 'static> FlowyFormattingLayer<W> {
                 }
             }
         }
+        serialize_log_context(&mut map_serializer, |key| {
+            span_values.map(|values| values.contains_key(key)).unwrap_or(false)
+        })?;
         map_serializer.end()?;
         Ok(buffer)
     }
@@ -105,6 +110,20 @@ fn format_span_context<S: Subscriber + for<'a> tracing_subscriber::registry::Loo
     format!("[⛳ {} - {}]", span.metadata().name().to_uppercase(), ty)
 }
 
+// The fields set with `set_log_context`, except the ones that are already
+// serialized from the event or its span.
+fn serialize_log_context(
+    map_serializer: &mut impl SerializeMap<Error = serde_json::Error>,
+    is_serialized: impl Fn(&str) -> bool,
+) -> Result<(), serde_json::Error> {
+    for (key, value) in log_context() {
+        if !is_serialized(&key) && !FLOWY_RESERVED_FIELDS.contains(&key.as_str()) {
+            map_serializer.serialize_entry(&key, &value)?;
+        }
+    }
+    Ok(())
+}
+
 fn format_event_message<S: Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>>(
     current_span: &Option<SpanRef<S>>,
     event: &Event,
@@ -174,18 +193,25 @@ where
             }
 
             // Add all the fields from the current span, if we have one.
-            if let Some(span) = &current_span {
-                let extensions = span.extensions();
-                if let Some(visitor) = extensions.get::<JsonStorage>() {
-                    for (key, value) in visitor.values() {
-                        if !FLOWY_RESERVED_FIELDS.contains(key) && !IGNORE_FIELDS.contains(key) {
-                            map_serializer.serialize_entry(key, value)?;
-                        } else {
-                            tracing::debug!("{} is a reserved field in the flowy log format. Skipping it.", key);
-                        }
+            let extensions = current_span.as_ref().map(|span| span.extensions());
+            let span_values = extensions
+                .as_ref()
+                .and_then(|extensions| extensions.get::<JsonStorage>())
+                .map(|visitor| visitor.values());
+            if let Some(values) = span_values {
+                for (key, value) in values {
+                    if !FLOWY_RESERVED_FIELDS.contains(key) && !IGNORE_FIELDS.contains(key) {
+                        map_serializer.serialize_entry(key, value)?;
+                    } else {
+                        tracing::debug!("{} is a reserved field in the flowy log format. Skipping it.", key);
                     }
                 }
             }
+
+            serialize_log_context(&mut map_serializer, |key| {
+                event_visitor.values().contains_key(key)
+                    || span_values.map(|values| values.contains_key(key)).unwrap_or(false)
+            })?;
             map_serializer.end()?;
             Ok(buffer)
         };
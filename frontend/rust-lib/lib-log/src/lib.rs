@@ -1,13 +1,21 @@
+mod context;
 mod layer;
+mod sink;
+
+pub use context::{remove_log_context, set_log_context};
+pub use sink::{export_ring_buffer, LogSink, RingBufferSink};
 
 use log::LevelFilter;
 
 use tracing::subscriber::set_global_default;
 
-use crate::layer::*;
+use crate::{layer::*, sink::*};
 use lazy_static::lazy_static;
-use std::sync::RwLock;
-use tracing_appender::{non_blocking::WorkerGuard, rolling::RollingFileAppender};
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+use tracing_appender::non_blocking::WorkerGuard;
 use tracing_bunyan_formatter::JsonStorageLayer;
 use tracing_log::LogTracer;
 use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
@@ -16,22 +24,31 @@ lazy_static! {
     static ref LOG_GUARD: RwLock<Option<WorkerGuard>> = RwLock::new(None);
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogRotation {
+    Hourly,
+    Daily,
+    Never,
+}
+
 pub struct Builder {
-    #[allow(dead_code)]
     name: String,
+    directory: PathBuf,
     env_filter: String,
-    file_appender: RollingFileAppender,
+    rotation: LogRotation,
+    max_log_files: Option<usize>,
+    sinks: Vec<Arc<dyn LogSink>>,
 }
 
 impl Builder {
     pub fn new(name: &str, directory: &str) -> Self {
-        // let directory = directory.as_ref().to_str().unwrap().to_owned();
-        let local_file_name = format!("{}.log", name);
-
         Builder {
             name: name.to_owned(),
+            directory: PathBuf::from(directory),
             env_filter: "Info".to_owned(),
-            file_appender: tracing_appender::rolling::daily(directory, local_file_name),
+            rotation: LogRotation::Daily,
+            max_log_files: None,
+            sinks: vec![],
         }
     }
 
@@ -40,10 +57,43 @@ impl Builder {
         self
     }
 
+    pub fn rotation(mut self, rotation: LogRotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Removes the oldest rotated log files when the logger is built, so at
+    /// most `max_log_files` files are kept in the directory.
+    pub fn max_log_files(mut self, max_log_files: usize) -> Self {
+        self.max_log_files = Some(max_log_files);
+        self
+    }
+
+    pub fn sink(mut self, sink: Arc<dyn LogSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// Keeps the last `capacity` lines in memory, see [`export_ring_buffer`].
+    pub fn ring_buffer(self, capacity: usize) -> Self {
+        let sink = Arc::new(RingBufferSink::new(capacity));
+        set_ring_buffer(sink.clone());
+        self.sink(sink)
+    }
+
     pub fn build(self) -> std::result::Result<(), String> {
         let env_filter = EnvFilter::new(self.env_filter);
+        let local_file_name = format!("{}.log", self.name);
+        if let Some(max_log_files) = self.max_log_files {
+            remove_old_log_files(&self.directory, &local_file_name, max_log_files);
+        }
 
-        let (non_blocking, guard) = tracing_appender::non_blocking(self.file_appender);
+        let file_appender = match self.rotation {
+            LogRotation::Hourly => tracing_appender::rolling::hourly(&self.directory, &local_file_name),
+            LogRotation::Daily => tracing_appender::rolling::daily(&self.directory, &local_file_name),
+            LogRotation::Never => tracing_appender::rolling::never(&self.directory, &local_file_name),
+        };
+        let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
         let subscriber = tracing_subscriber::fmt()
             // .with_span_events(FmtSpan::NEW | FmtSpan::CLOSE)
             .with_ansi(false)
@@ -60,7 +110,8 @@ impl Builder {
             .with(env_filter)
             .with(JsonStorageLayer)
             .with(FlowyFormattingLayer::new(std::io::stdout))
-            .with(FlowyFormattingLayer::new(non_blocking));
+            .with(FlowyFormattingLayer::new(non_blocking))
+            .with(FlowyFormattingLayer::new(SinkWriter::new(self.sinks)));
 
         // if cfg!(feature = "use_bunyan") {
         //     let formatting_layer = BunyanFormattingLayer::new(self.name.clone(),
@@ -81,6 +132,34 @@ impl Builder {
     }
 }
 
+// The rotated files are named after the file name with the date as suffix, so
+// sorting them by name also sorts them by age.
+fn remove_old_log_files(directory: &Path, file_name: &str, max_log_files: usize) {
+    let mut files = match std::fs::read_dir(directory) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map(|name| name.starts_with(file_name))
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<_>>(),
+        Err(_) => return,
+    };
+
+    if files.len() <= max_log_files {
+        return;
+    }
+
+    files.sort();
+    let remove_count = files.len() - max_log_files;
+    for path in files.into_iter().take(remove_count) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,101 @@
+use lazy_static::lazy_static;
+use parking_lot::{Mutex, RwLock};
+use std::{collections::VecDeque, io, sync::Arc};
+use tracing_subscriber::fmt::MakeWriter;
+
+lazy_static! {
+    static ref LOG_RING_BUFFER: RwLock<Option<Arc<RingBufferSink>>> = RwLock::new(None);
+}
+
+/// Receives every formatted log line, including the trailing newline. It's
+/// called on the thread that emits the log, so the implementation should
+/// return quickly.
+pub trait LogSink: Send + Sync {
+    fn write(&self, line: &[u8]);
+}
+
+/// Keeps the last `capacity` log lines in memory, so they can be exported when
+/// the user reports an issue, even if the log files are not reachable.
+pub struct RingBufferSink {
+    capacity: usize,
+    lines: Mutex<VecDeque<String>>,
+}
+
+impl RingBufferSink {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            lines: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    pub fn export(&self) -> String { self.lines.lock().iter().map(|line| line.as_str()).collect() }
+
+    pub fn clear(&self) { self.lines.lock().clear(); }
+}
+
+impl LogSink for RingBufferSink {
+    fn write(&self, line: &[u8]) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut lines = self.lines.lock();
+        while lines.len() >= self.capacity {
+            lines.pop_front();
+        }
+        lines.push_back(String::from_utf8_lossy(line).into_owned());
+    }
+}
+
+pub(crate) fn set_ring_buffer(sink: Arc<RingBufferSink>) { *LOG_RING_BUFFER.write() = Some(sink); }
+
+/// Returns the lines kept by the ring buffer that was installed with
+/// [`Builder::ring_buffer`](crate::Builder::ring_buffer), or `None` if there is
+/// no ring buffer.
+pub fn export_ring_buffer() -> Option<String> { LOG_RING_BUFFER.read().as_ref().map(|sink| sink.export()) }
+
+#[derive(Clone)]
+pub(crate) struct SinkWriter {
+    sinks: Arc<Vec<Arc<dyn LogSink>>>,
+}
+
+impl SinkWriter {
+    pub(crate) fn new(sinks: Vec<Arc<dyn LogSink>>) -> Self { Self { sinks: Arc::new(sinks) } }
+}
+
+// The formatting layer writes one whole line per `write_all`, so each call is
+// forwarded to the sinks as is.
+impl io::Write for SinkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for sink in self.sinks.iter() {
+            sink.write(buf);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+impl MakeWriter for SinkWriter {
+    type Writer = SinkWriter;
+
+    fn make_writer(&self) -> Self::Writer { self.clone() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ring_buffer_keeps_the_last_lines() {
+        let sink = RingBufferSink::new(2);
+        sink.write(b"1\n");
+        sink.write(b"2\n");
+        sink.write(b"3\n");
+        assert_eq!(sink.export(), "2\n3\n");
+
+        sink.clear();
+        assert_eq!(sink.export(), "");
+    }
+}
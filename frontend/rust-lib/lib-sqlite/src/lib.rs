@@ -9,3 +9,4 @@ pub use database::*;
 pub use pool::*;
 
 pub use errors::{Error, ErrorKind, Result};
+pub use pragma::PragmaExtension;
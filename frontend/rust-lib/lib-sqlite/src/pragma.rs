@@ -2,7 +2,7 @@ use crate::errors::{Error, Result};
 use diesel::{
     expression::SqlLiteral,
     query_dsl::load_dsl::LoadQuery,
-    sql_types::{Integer, Text},
+    sql_types::{BigInt, Integer, Text},
     SqliteConnection,
 };
 
@@ -69,6 +69,13 @@ pub trait PragmaExtension: ConnectionExtension {
     fn pragma_get_synchronous(&self, schema: Option<&str>) -> Result<SQLiteSynchronous> {
         self.pragma_get::<Integer, i32>("synchronous", schema)?.try_into()
     }
+
+    // The size in bytes of the database file, without the wal file.
+    fn pragma_get_database_size(&self, schema: Option<&str>) -> Result<i64> {
+        let page_count = self.pragma_get::<BigInt, i64>("page_count", schema)?;
+        let page_size = self.pragma_get::<BigInt, i64>("page_size", schema)?;
+        Ok(page_count * page_size)
+    }
 }
 impl PragmaExtension for SqliteConnection {}
 
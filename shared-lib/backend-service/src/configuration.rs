@@ -1,6 +1,10 @@
 use config::FileFormat;
+use serde::{Deserialize, Serialize};
 use serde_aux::field_attributes::deserialize_number_from_string;
-use std::convert::{TryFrom, TryInto};
+use std::{
+    convert::{TryFrom, TryInto},
+    sync::{Arc, RwLock},
+};
 pub const HOST: &str = "localhost:8000";
 pub const HEADER_TOKEN: &str = "token";
 
@@ -11,6 +15,37 @@ pub struct ClientServerConfiguration {
     pub host: String,
     pub http_scheme: String,
     pub ws_scheme: String,
+    // The urls that were set at runtime replace the ones above. The clones of
+    // the configuration share them, so the servers that were built from it
+    // follow the change.
+    #[serde(skip)]
+    custom_urls: Arc<RwLock<Option<ServerUrls>>>,
+    // The Supabase project that is used instead of the AppFlowy server.
+    #[serde(default)]
+    pub supabase: Option<SupabaseConfiguration>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct SupabaseConfiguration {
+    // e.g. https://<project>.supabase.co
+    pub url: String,
+    // The public key of the project, the tables are protected by the row level
+    // security policies of the schema.
+    pub anon_key: String,
+}
+
+impl SupabaseConfiguration {
+    pub fn rest_url(&self, table: &str) -> String { format!("{}/rest/v1/{}", self.url.trim_end_matches('/'), table) }
+
+    pub fn auth_url(&self, path: &str) -> String { format!("{}/auth/v1/{}", self.url.trim_end_matches('/'), path) }
+}
+
+// The urls of a self-hosted server, e.g. https://appflowy.example.com and
+// wss://appflowy.example.com/ws.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ServerUrls {
+    pub base_url: String,
+    pub ws_url: String,
 }
 
 pub fn get_client_server_configuration() -> Result<ClientServerConfiguration, config::ConfigError> {
@@ -29,7 +64,13 @@ pub fn get_client_server_configuration() -> Result<ClientServerConfiguration, co
     };
 
     settings.merge(config::File::from_str(custom, FileFormat::Yaml).required(true))?;
-    settings.try_into()
+    let mut configuration: ClientServerConfiguration = settings.try_into()?;
+
+    // The Supabase project can be selected without rebuilding the app.
+    if let (Ok(url), Ok(anon_key)) = (std::env::var("SUPABASE_URL"), std::env::var("SUPABASE_ANON_KEY")) {
+        configuration.supabase = Some(SupabaseConfiguration { url, anon_key });
+    }
+    Ok(configuration)
 }
 
 impl ClientServerConfiguration {
@@ -38,7 +79,21 @@ impl ClientServerConfiguration {
         self.port = port;
     }
 
-    pub fn base_url(&self) -> String { format!("{}://{}:{}", self.http_scheme, self.host, self.port) }
+    // None restores the urls of the build.
+    pub fn set_custom_urls(&self, urls: Option<ServerUrls>) {
+        if let Ok(mut custom_urls) = self.custom_urls.write() {
+            *custom_urls = urls;
+        }
+    }
+
+    pub fn custom_urls(&self) -> Option<ServerUrls> { self.custom_urls.read().ok().and_then(|urls| urls.clone()) }
+
+    pub fn base_url(&self) -> String {
+        match self.custom_urls() {
+            Some(urls) => urls.base_url.trim_end_matches('/').to_owned(),
+            None => format!("{}://{}:{}", self.http_scheme, self.host, self.port),
+        }
+    }
 
     pub fn sign_up_url(&self) -> String { format!("{}/api/register", self.base_url()) }
 
@@ -46,19 +101,55 @@ impl ClientServerConfiguration {
 
     pub fn sign_out_url(&self) -> String { format!("{}/api/auth", self.base_url()) }
 
+    pub fn refresh_token_url(&self) -> String { format!("{}/api/auth/refresh", self.base_url()) }
+
     pub fn user_profile_url(&self) -> String { format!("{}/api/user", self.base_url()) }
 
+    pub fn upload_avatar_url(&self) -> String { format!("{}/api/user/avatar", self.base_url()) }
+
+    pub fn avatar_url(&self, user_id: &str) -> String { format!("{}/api/user/avatar/{}", self.base_url(), user_id) }
+
+    pub fn user_device_url(&self) -> String { format!("{}/api/user/device", self.base_url()) }
+
     pub fn workspace_url(&self) -> String { format!("{}/api/workspace", self.base_url()) }
 
+    pub fn workspace_member_url(&self) -> String { format!("{}/api/workspace/member", self.base_url()) }
+
+    pub fn workspace_invitation_url(&self) -> String { format!("{}/api/workspace/invitation", self.base_url()) }
+
+    pub fn share_link_url(&self) -> String { format!("{}/api/share", self.base_url()) }
+
+    // The public url of the shared document, which doesn't require the token.
+    pub fn shared_document_url(&self, link_id: &str) -> String { format!("{}/api/shared/{}", self.base_url(), link_id) }
+
     pub fn app_url(&self) -> String { format!("{}/api/app", self.base_url()) }
 
     pub fn view_url(&self) -> String { format!("{}/api/view", self.base_url()) }
 
+    pub fn view_access_url(&self) -> String { format!("{}/api/view/access", self.base_url()) }
+
+    pub fn view_guest_url(&self) -> String { format!("{}/api/view/guest", self.base_url()) }
+
+    // The urls that accept the guest token, which only resolves a single view.
+    pub fn guest_view_url(&self) -> String { format!("{}/api/guest/view", self.base_url()) }
+
+    pub fn guest_doc_url(&self) -> String { format!("{}/api/guest/doc", self.base_url()) }
+
     pub fn doc_url(&self) -> String { format!("{}/api/doc", self.base_url()) }
 
     pub fn trash_url(&self) -> String { format!("{}/api/trash", self.base_url()) }
 
-    pub fn ws_addr(&self) -> String { format!("{}://{}:{}/ws", self.ws_scheme, self.host, self.port) }
+    // The second version of the folder api, the older servers answer 404.
+    pub fn folder_batch_url(&self) -> String { format!("{}/api/v2/folder/batch", self.base_url()) }
+
+    pub fn folder_sync_url(&self) -> String { format!("{}/api/v2/folder/sync", self.base_url()) }
+
+    pub fn ws_addr(&self) -> String {
+        match self.custom_urls() {
+            Some(urls) => urls.ws_url.trim_end_matches('/').to_owned(),
+            None => format!("{}://{}:{}/ws", self.ws_scheme, self.host, self.port),
+        }
+    }
 }
 
 pub enum Environment {
@@ -36,6 +36,7 @@ impl ServerError {
     static_error!(connect_cancel, ErrorCode::ConnectCancel);
     static_error!(connect_refused, ErrorCode::ConnectRefused);
     static_error!(record_not_found, ErrorCode::RecordNotFound);
+    static_error!(permission_denied, ErrorCode::PermissionDenied);
 
     pub fn new(msg: String, code: ErrorCode) -> Self { Self { code, msg } }
 
@@ -99,6 +100,9 @@ pub enum ErrorCode {
     #[display(fmt = "Username and password do not match")]
     PasswordNotMatch   = 51,
 
+    #[display(fmt = "Permission denied")]
+    PermissionDenied   = 52,
+
     #[display(fmt = "Connect refused")]
     ConnectRefused     = 100,
 
@@ -1,4 +1,5 @@
 use crate::{configuration::HEADER_TOKEN, errors::ServerError, request::HttpRequestBuilder};
+use flowy_collaboration::entities::doc::DocumentInfo;
 use flowy_core_data_model::entities::prelude::*;
 use flowy_user_data_model::entities::prelude::*;
 
@@ -24,6 +25,18 @@ pub async fn user_sign_in_request(params: SignInParams, url: &str) -> Result<Sig
     Ok(response)
 }
 
+pub async fn user_refresh_token_request(
+    params: RefreshTokenParams,
+    url: &str,
+) -> Result<RefreshTokenResponse, ServerError> {
+    let response = request_builder()
+        .post(&url.to_owned())
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(response)
+}
+
 pub async fn user_sign_out_request(token: &str, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .delete(&url.to_owned())
@@ -52,6 +65,53 @@ pub async fn update_user_profile_request(token: &str, params: UpdateUserParams,
     Ok(())
 }
 
+pub async fn delete_user_request(token: &str, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn upload_avatar_request(token: &str, params: UploadAvatarParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn read_avatar_request(token: &str, url: &str) -> Result<Option<UserAvatar>, ServerError> {
+    let avatar = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .option_response()
+        .await?;
+    Ok(avatar)
+}
+
+pub async fn read_user_devices_request(token: &str, url: &str) -> Result<RepeatedUserDevice, ServerError> {
+    let devices = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .response::<RepeatedUserDevice>()
+        .await?;
+    Ok(devices)
+}
+
+pub async fn revoke_user_device_request(token: &str, params: RevokeDeviceParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
 pub async fn create_workspace_request(
     token: &str,
     params: CreateWorkspaceParams,
@@ -105,6 +165,105 @@ pub async fn delete_workspace_request(token: &str, params: WorkspaceId, url: &st
     Ok(())
 }
 
+pub async fn read_workspace_members_request(
+    token: &str,
+    params: QueryWorkspaceMembersParams,
+    url: &str,
+) -> Result<RepeatedWorkspaceMember, ServerError> {
+    let members = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedWorkspaceMember>()
+        .await?;
+    Ok(members)
+}
+
+pub async fn add_workspace_member_request(
+    token: &str,
+    params: AddWorkspaceMemberParams,
+    url: &str,
+) -> Result<WorkspaceMember, ServerError> {
+    let member = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(member)
+}
+
+pub async fn update_workspace_member_request(
+    token: &str,
+    params: UpdateWorkspaceMemberParams,
+    url: &str,
+) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .patch(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn remove_workspace_member_request(
+    token: &str,
+    params: RemoveWorkspaceMemberParams,
+    url: &str,
+) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(url)
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn create_invitation_request(
+    token: &str,
+    params: CreateInvitationParams,
+    url: &str,
+) -> Result<WorkspaceInvitation, ServerError> {
+    let invitation = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(invitation)
+}
+
+pub async fn read_invitations_request(token: &str, url: &str) -> Result<RepeatedWorkspaceInvitation, ServerError> {
+    let repeated_invitation = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .response::<RepeatedWorkspaceInvitation>()
+        .await?;
+    Ok(repeated_invitation)
+}
+
+pub async fn accept_invitation_request(token: &str, params: InvitationId, url: &str) -> Result<Workspace, ServerError> {
+    let workspace = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(workspace)
+}
+
+pub async fn decline_invitation_request(token: &str, params: InvitationId, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
 // App
 pub async fn create_app_request(token: &str, params: CreateAppParams, url: &str) -> Result<App, ServerError> {
     let app = request_builder()
@@ -189,6 +348,149 @@ pub async fn delete_view_request(token: &str, params: RepeatedViewId, url: &str)
     Ok(())
 }
 
+pub async fn read_view_access_request(token: &str, params: ViewId, url: &str) -> Result<ViewAccess, ServerError> {
+    let view_access = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<ViewAccess>()
+        .await?;
+    Ok(view_access)
+}
+
+pub async fn grant_view_access_request(
+    token: &str,
+    params: UpdateViewAccessParams,
+    url: &str,
+) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn revoke_view_access_request(
+    token: &str,
+    params: UpdateViewAccessParams,
+    url: &str,
+) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+// Share link
+pub async fn create_share_link_request(
+    token: &str,
+    params: CreateShareLinkParams,
+    url: &str,
+) -> Result<ShareLink, ServerError> {
+    let link = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(link)
+}
+
+pub async fn read_share_links_request(
+    token: &str,
+    params: ViewId,
+    url: &str,
+) -> Result<RepeatedShareLink, ServerError> {
+    let links = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedShareLink>()
+        .await?;
+    Ok(links)
+}
+
+pub async fn revoke_share_link_request(token: &str, params: ShareLinkId, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+// The shared document is public, so the request doesn't carry the token.
+pub async fn read_shared_document_request(url: &str) -> Result<SharedDocument, ServerError> {
+    let document = request_builder()
+        .get(&url.to_owned())
+        .response::<SharedDocument>()
+        .await?;
+    Ok(document)
+}
+
+// Guest access
+pub async fn create_guest_access_request(
+    token: &str,
+    params: CreateGuestAccessParams,
+    url: &str,
+) -> Result<GuestAccess, ServerError> {
+    let access = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(access)
+}
+
+pub async fn read_guest_accesses_request(
+    token: &str,
+    params: ViewId,
+    url: &str,
+) -> Result<RepeatedGuestAccess, ServerError> {
+    let repeated_access = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedGuestAccess>()
+        .await?;
+    Ok(repeated_access)
+}
+
+pub async fn revoke_guest_access_request(token: &str, params: GuestAccessId, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn read_guest_view_request(guest_token: &str, url: &str) -> Result<View, ServerError> {
+    let view = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, guest_token)
+        .response::<View>()
+        .await?;
+    Ok(view)
+}
+
+pub async fn read_guest_document_request(guest_token: &str, url: &str) -> Result<DocumentInfo, ServerError> {
+    let document = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, guest_token)
+        .response::<DocumentInfo>()
+        .await?;
+    Ok(document)
+}
+
 pub async fn create_trash_request(token: &str, params: RepeatedTrashId, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .post(&url.to_owned())
@@ -217,3 +519,51 @@ pub async fn read_trash_request(token: &str, url: &str) -> Result<RepeatedTrash,
         .await?;
     Ok(repeated_trash)
 }
+
+pub async fn batch_create_request(
+    token: &str,
+    params: BatchCreateParams,
+    url: &str,
+) -> Result<BatchCreateResult, ServerError> {
+    let result = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<BatchCreateResult>()
+        .await?;
+    Ok(result)
+}
+
+pub async fn batch_read_request(
+    token: &str,
+    params: BatchReadParams,
+    url: &str,
+) -> Result<BatchReadResult, ServerError> {
+    let result = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<BatchReadResult>()
+        .await?;
+    Ok(result)
+}
+
+pub async fn batch_update_request(token: &str, params: BatchUpdateParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .patch(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn sync_folder_request(token: &str, params: FolderSyncParams, url: &str) -> Result<FolderDelta, ServerError> {
+    let delta = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<FolderDelta>()
+        .await?;
+    Ok(delta)
+}
@@ -4,3 +4,4 @@ pub mod http_request;
 pub mod middleware;
 pub mod request;
 pub mod response;
+pub mod supabase;
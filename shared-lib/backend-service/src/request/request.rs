@@ -1,11 +1,13 @@
 use crate::{configuration::HEADER_TOKEN, errors::ServerError, response::FlowyResponse};
 use bytes::Bytes;
 use hyper::http;
+use lazy_static::lazy_static;
+use lib_infra::future::FutureResultSend;
 use protobuf::ProtobufError;
 use reqwest::{header::HeaderMap, Client, Method, Response};
 use std::{
     convert::{TryFrom, TryInto},
-    sync::Arc,
+    sync::{Arc, RwLock},
     time::Duration,
 };
 use tokio::sync::oneshot;
@@ -14,6 +16,30 @@ pub trait ResponseMiddleware {
     fn receive_response(&self, token: &Option<String>, response: &FlowyResponse);
 }
 
+// Exchanges an expired token for a new one. The request that failed with the
+// unauthorized error is sent again with the new token.
+pub trait TokenRefresher: Send + Sync {
+    fn refresh_token(&self, expired_token: &str) -> FutureResultSend<String, ServerError>;
+}
+
+lazy_static! {
+    static ref TOKEN_REFRESHER: RwLock<Option<Arc<dyn TokenRefresher>>> = RwLock::new(None);
+}
+
+pub fn set_token_refresher(refresher: Arc<dyn TokenRefresher>) {
+    match TOKEN_REFRESHER.write() {
+        Ok(mut write_guard) => *write_guard = Some(refresher),
+        Err(e) => log::error!("Set token refresher failed: {:?}", e),
+    }
+}
+
+fn token_refresher() -> Option<Arc<dyn TokenRefresher>> {
+    match TOKEN_REFRESHER.read() {
+        Ok(read_guard) => read_guard.clone(),
+        Err(_) => None,
+    }
+}
+
 pub struct HttpRequestBuilder {
     url: String,
     body: Option<Bytes>,
@@ -133,9 +159,51 @@ impl HttpRequestBuilder {
     }
 
     async fn inner_send(mut self) -> Result<Self, ServerError> {
+        let mut flowy_response = self.send_request().await?;
+        if let Some(token) = self.refresh_token_if_need(&flowy_response).await {
+            self.headers.insert(HEADER_TOKEN, token.parse().unwrap());
+            flowy_response = self.send_request().await?;
+        }
+
+        let token = self.token();
+        self.middleware.iter().for_each(|middleware| {
+            middleware.receive_response(&token, &flowy_response);
+        });
+        match flowy_response.error {
+            None => {
+                self.response = Some(flowy_response.data);
+                Ok(self)
+            },
+            Some(error) => Err(error),
+        }
+    }
+
+    // Returns the new token if the request failed because of the expired token
+    // and the token was refreshed successfully.
+    async fn refresh_token_if_need(&self, response: &FlowyResponse) -> Option<String> {
+        let is_unauthorized = match &response.error {
+            None => false,
+            Some(error) => error.is_unauthorized(),
+        };
+        if !is_unauthorized {
+            return None;
+        }
+
+        let token = self.token()?;
+        let refresher = token_refresher()?;
+        match refresher.refresh_token(&token).await {
+            Ok(new_token) => Some(new_token),
+            Err(e) => {
+                log::error!("Refresh token failed: {:?}", e);
+                None
+            },
+        }
+    }
+
+    async fn send_request(&self) -> Result<FlowyResponse, ServerError> {
         let (tx, rx) = oneshot::channel::<Result<Response, _>>();
         let url = self.url.clone();
-        let body = self.body.take();
+        let body = self.body.clone();
         let method = self.method.clone();
         let headers = self.headers.clone();
 
@@ -154,17 +222,7 @@ impl HttpRequestBuilder {
         let response = rx.await??;
         tracing::trace!("Http Response: {:?}", response);
         let flowy_response = flowy_response_from(response).await?;
-        let token = self.token();
-        self.middleware.iter().for_each(|middleware| {
-            middleware.receive_response(&token, &flowy_response);
-        });
-        match flowy_response.error {
-            None => {
-                self.response = Some(flowy_response.data);
-                Ok(self)
-            },
-            Some(error) => Err(error),
-        }
+        Ok(flowy_response)
     }
 }
 
@@ -0,0 +1,158 @@
+use crate::{configuration::SupabaseConfiguration, errors::ServerError};
+use reqwest::{
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    Client,
+    RequestBuilder,
+    Response,
+    StatusCode,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::time::Duration;
+
+pub use reqwest::Method;
+
+// The REST api of a Supabase project: PostgREST for the tables and GoTrue for
+// the accounts. The token of the signed-in user is sent as the bearer, so the
+// row level security only lets the requests touch the rows of that user.
+#[derive(Clone)]
+pub struct SupabaseClient {
+    config: SupabaseConfiguration,
+    client: Client,
+}
+
+impl SupabaseClient {
+    pub fn new(config: SupabaseConfiguration) -> Self {
+        let client = Client::builder()
+            .connect_timeout(Duration::from_millis(500))
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap_or_else(|e| {
+                log::error!("Create the supabase client failed: {}", e);
+                Client::new()
+            });
+        Self { config, client }
+    }
+
+    // The query is in the syntax of PostgREST, e.g. `id=eq.<id>`.
+    pub async fn select<T: DeserializeOwned>(
+        &self,
+        token: &str,
+        table: &str,
+        query: &str,
+    ) -> Result<Vec<T>, ServerError> {
+        let url = format!("{}?{}", self.config.rest_url(table), query);
+        let response = self.request(Method::GET, &url, Some(token)).send().await?;
+        read_json(response).await
+    }
+
+    // Inserts the rows, or updates the ones whose primary key exists already,
+    // so a retried request doesn't fail.
+    pub async fn upsert<T: Serialize>(&self, token: &str, table: &str, rows: &T) -> Result<(), ServerError> {
+        let url = self.config.rest_url(table);
+        let response = self
+            .request(Method::POST, &url, Some(token))
+            .header("Prefer", "resolution=merge-duplicates")
+            .body(serde_json::to_vec(rows)?)
+            .send()
+            .await?;
+        let _ = check_status(response).await?;
+        Ok(())
+    }
+
+    pub async fn update<T: Serialize>(
+        &self,
+        token: &str,
+        table: &str,
+        filter: &str,
+        value: &T,
+    ) -> Result<(), ServerError> {
+        let url = format!("{}?{}", self.config.rest_url(table), filter);
+        let response = self
+            .request(Method::PATCH, &url, Some(token))
+            .body(serde_json::to_vec(value)?)
+            .send()
+            .await?;
+        let _ = check_status(response).await?;
+        Ok(())
+    }
+
+    pub async fn delete(&self, token: &str, table: &str, filter: &str) -> Result<(), ServerError> {
+        let url = format!("{}?{}", self.config.rest_url(table), filter);
+        let response = self.request(Method::DELETE, &url, Some(token)).send().await?;
+        let _ = check_status(response).await?;
+        Ok(())
+    }
+
+    // Calls GoTrue, the token is None for the requests that create a session,
+    // e.g. signing in.
+    pub async fn auth<T: Serialize, R: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        token: Option<&str>,
+        body: Option<&T>,
+    ) -> Result<R, ServerError> {
+        let url = self.config.auth_url(path);
+        let mut builder = self.request(method, &url, token);
+        if let Some(body) = body {
+            builder = builder.body(serde_json::to_vec(body)?);
+        }
+        read_json(builder.send().await?).await
+    }
+
+    pub async fn auth_send<T: Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        token: Option<&str>,
+        body: Option<&T>,
+    ) -> Result<(), ServerError> {
+        let url = self.config.auth_url(path);
+        let mut builder = self.request(method, &url, token);
+        if let Some(body) = body {
+            builder = builder.body(serde_json::to_vec(body)?);
+        }
+        let _ = check_status(builder.send().await?).await?;
+        Ok(())
+    }
+
+    // Succeeds if the project answers at all.
+    pub async fn ping(&self) -> Result<(), ServerError> {
+        let url = self.config.auth_url("health");
+        let _ = self.request(Method::GET, &url, None).send().await?;
+        Ok(())
+    }
+
+    fn request(&self, method: Method, url: &str, token: Option<&str>) -> RequestBuilder {
+        let bearer = token.unwrap_or(&self.config.anon_key);
+        self.client
+            .request(method, url)
+            .header("apikey", &self.config.anon_key)
+            .header(AUTHORIZATION, format!("Bearer {}", bearer))
+            .header(CONTENT_TYPE, "application/json")
+    }
+}
+
+async fn read_json<T: DeserializeOwned>(response: Response) -> Result<T, ServerError> {
+    let response = check_status(response).await?;
+    let bytes = response.bytes().await?;
+    let value = serde_json::from_slice(&bytes)?;
+    Ok(value)
+}
+
+async fn check_status(response: Response) -> Result<Response, ServerError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = response.text().await.unwrap_or_default();
+    let error = match status {
+        StatusCode::UNAUTHORIZED => ServerError::unauthorized(),
+        StatusCode::FORBIDDEN => ServerError::permission_denied(),
+        StatusCode::NOT_FOUND => ServerError::record_not_found(),
+        StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => ServerError::params_invalid(),
+        _ => ServerError::http(),
+    };
+    Err(error.context(format!("{}: {}", status, body)))
+}
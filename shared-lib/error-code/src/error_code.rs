@@ -1,4 +1,4 @@
-use crate::protobuf::ErrorCode as ProtoBufErrorCode;
+use crate::{protobuf::ErrorCode as ProtoBufErrorCode, RecoveryHint};
 use derive_more::Display;
 use flowy_derive::ProtoBuf_Enum;
 use protobuf::ProtobufEnum;
@@ -15,6 +15,15 @@ pub enum ErrorCode {
     #[display(fmt = "RecordNotFound")]
     RecordNotFound       = 3,
 
+    #[display(fmt = "The local database is busy")]
+    DatabaseBusy         = 4,
+
+    #[display(fmt = "The local database needs to be migrated")]
+    DatabaseMigrationRequired = 5,
+
+    #[display(fmt = "The cloud backend doesn't support the operation")]
+    CloudFeatureUnsupported = 6,
+
     #[display(fmt = "Workspace name can not be empty or whitespace")]
     WorkspaceNameInvalid = 100,
 
@@ -30,6 +39,12 @@ pub enum ErrorCode {
     #[display(fmt = "Workspace description too long")]
     WorkspaceNameTooLong = 104,
 
+    #[display(fmt = "The workspace role doesn't allow the operation")]
+    WorkspacePermissionDenied = 105,
+
+    #[display(fmt = "Invitation id can not be empty or whitespace")]
+    InvitationIdInvalid  = 106,
+
     #[display(fmt = "App id can not be empty or whitespace")]
     AppIdInvalid         = 110,
 
@@ -54,6 +69,135 @@ pub enum ErrorCode {
     #[display(fmt = "View name too long")]
     ViewNameTooLong      = 125,
 
+    #[display(fmt = "The view is restricted to the other members of the workspace")]
+    ViewAccessDenied     = 126,
+
+    #[display(fmt = "Export path should be an existing directory")]
+    ExportPathInvalid    = 130,
+
+    #[display(fmt = "Share link id can not be empty or whitespace")]
+    ShareLinkIdInvalid   = 131,
+
+    #[display(fmt = "The expire time of the share link should be in the future")]
+    ShareLinkExpireTimeInvalid = 132,
+
+    #[display(fmt = "Guest access id can not be empty or whitespace")]
+    GuestAccessIdInvalid = 133,
+
+    #[display(fmt = "Webhook id can not be empty or whitespace")]
+    WebhookIdInvalid     = 134,
+
+    #[display(fmt = "Webhook url should be an http or https url")]
+    WebhookUrlInvalid    = 135,
+
+    #[display(fmt = "Event log cursor should not be negative")]
+    EventLogCursorInvalid = 136,
+
+    #[display(fmt = "Event log limit should be between 1 and 1000")]
+    EventLogLimitInvalid = 137,
+
+    #[display(fmt = "The view can't be moved into itself or its nested views")]
+    ViewMoveInvalid      = 138,
+
+    #[display(fmt = "The view doesn't support the operation of its type")]
+    ViewTypeMismatch     = 139,
+
+    #[display(fmt = "Field id can not be empty or whitespace")]
+    FieldIdInvalid       = 140,
+
+    #[display(fmt = "Field name can not be empty or whitespace")]
+    FieldNameInvalid     = 141,
+
+    #[display(fmt = "Row id can not be empty or whitespace")]
+    RowIdInvalid         = 142,
+
+    #[display(fmt = "The field or the row doesn't exist in the grid")]
+    GridRecordNotFound   = 143,
+
+    #[display(fmt = "The cell data doesn't match the type of the field")]
+    CellDataInvalid      = 144,
+
+    #[display(fmt = "The start of the date range can't be after its end")]
+    DateRangeInvalid     = 145,
+
+    #[display(fmt = "Checklist item id can not be empty or whitespace")]
+    ChecklistItemIdInvalid = 146,
+
+    #[display(fmt = "Checklist item title can not be empty or longer than 1000 characters")]
+    ChecklistItemTitleInvalid = 147,
+
+    #[display(fmt = "The formula is invalid or references itself")]
+    FormulaInvalid       = 148,
+
+    #[display(fmt = "The import file doesn't exist or its format is not supported")]
+    ImportFileInvalid    = 149,
+
+    #[display(fmt = "The delimiter must be a single character other than a quote or a line break")]
+    DelimiterInvalid     = 150,
+
+    #[display(fmt = "View tag can not be empty or longer than 100 characters")]
+    ViewTagInvalid       = 151,
+
+    #[display(fmt = "Attachment id can not be empty or whitespace")]
+    AttachmentIdInvalid  = 152,
+
+    #[display(fmt = "The name is longer than the name rules allow")]
+    NameTooLong          = 153,
+
+    #[display(fmt = "The name contains characters that the name rules forbid")]
+    NameContainsForbiddenCharacters = 154,
+
+    #[display(fmt = "The name is used by another page in the same place")]
+    NameAlreadyExists    = 155,
+
+    #[display(fmt = "Search query can not be empty or longer than 256 characters")]
+    SearchQueryInvalid   = 156,
+
+    #[display(fmt = "The revision retention should keep at least one revision or one day")]
+    RevisionRetentionValueInvalid = 157,
+
+    #[display(fmt = "Checkpoint name can not be empty or longer than 100 characters")]
+    CheckpointNameInvalid = 158,
+
+    #[display(fmt = "Checkpoint id can not be empty or whitespace")]
+    CheckpointIdInvalid  = 159,
+
+    #[display(fmt = "The index can not be negative")]
+    TextIndexInvalid     = 160,
+
+    #[display(fmt = "The url of an embed must be an http or https url")]
+    EmbedUrlInvalid      = 161,
+
+    #[display(fmt = "Toggle id can not be empty or whitespace")]
+    ToggleIdInvalid      = 162,
+
+    #[display(fmt = "The number of backups to keep must be between 1 and 100")]
+    BackupKeepCountInvalid = 163,
+
+    #[display(fmt = "The backup can not be read back")]
+    BackupCorrupted      = 164,
+
+    #[display(fmt = "The storage endpoint must be an http or https url")]
+    StorageEndpointInvalid = 165,
+
+    #[display(fmt = "The bucket name is not valid")]
+    StorageBucketInvalid = 166,
+
+    #[display(fmt = "The attachment storage of the workspace is not set")]
+    StorageNotConfigured = 167,
+
+    #[display(fmt = "The server can't be reached, it's sent to the server once it can")]
+    CloudOperationQueued = 168,
+
+    #[display(fmt = "The new members can't be the owners of the workspace")]
+    DefaultMemberRoleInvalid = 169,
+
+    #[display(fmt = "The anonymous user has to sign up first")]
+    AccountRequired      = 170,
+
+    #[display(fmt = "The request to the local api is malformed")]
+    LocalApiRequestInvalid = 171,
+
     #[display(fmt = "Connection error")]
     ConnectError         = 200,
 
@@ -83,6 +227,52 @@ pub enum ErrorCode {
     UserIdInvalid        = 311,
     #[display(fmt = "User not exist")]
     UserNotExist         = 312,
+    #[display(fmt = "Passcode should contain 4 to 16 characters without whitespace")]
+    AppPasscodeInvalid   = 313,
+    #[display(fmt = "Passcode not match")]
+    AppPasscodeNotMatch  = 314,
+    #[display(fmt = "Idle timeout of the app lock is invalid")]
+    AppLockIdleTimeoutInvalid = 315,
+    #[display(fmt = "The app is locked")]
+    AppLocked            = 316,
+    #[display(fmt = "Locale should be a language tag, e.g. en or zh-CN")]
+    UserLocaleInvalid    = 317,
+    #[display(fmt = "Revision retention days should not be negative")]
+    RevisionRetentionInvalid = 318,
+    #[display(fmt = "Avatar is empty")]
+    AvatarIsEmpty        = 319,
+    #[display(fmt = "Avatar should not be larger than 200KB")]
+    AvatarTooLarge       = 320,
+    #[display(fmt = "Session id is empty or whitespace")]
+    SessionIdInvalid     = 321,
+    #[display(fmt = "The log ring buffer is not enabled")]
+    LogRingBufferDisabled = 322,
+    #[display(fmt = "The server url should be an http or https url, and the websocket url a ws or wss url")]
+    ServerUrlInvalid     = 323,
+
+    #[display(fmt = "The document doesn't exist")]
+    DocumentNotFound     = 400,
+
+    #[display(fmt = "The revision of the document is invalid")]
+    RevisionInvalid      = 401,
+
+    #[display(fmt = "The revisions of the document are out of sync")]
+    RevisionConflict     = 402,
+
+    #[display(fmt = "The document was closed")]
+    DocumentClosed       = 403,
+
+    #[display(fmt = "The change can't be applied to the document")]
+    DeltaInvalid         = 404,
+
+    #[display(fmt = "The checkpoint doesn't exist or its revision was removed")]
+    CheckpointNotFound   = 405,
+
+    #[display(fmt = "There is no code block at the index")]
+    CodeBlockNotFound    = 406,
+
+    #[display(fmt = "The equation is not valid LaTeX")]
+    EquationInvalid      = 407,
 }
 
 impl ErrorCode {
@@ -97,4 +287,16 @@ impl ErrorCode {
             Some(code) => ErrorCode::try_from(&code).unwrap(),
         }
     }
+
+    pub fn recovery_hint(&self) -> RecoveryHint {
+        match self {
+            ErrorCode::ConnectError
+            | ErrorCode::DatabaseBusy
+            | ErrorCode::RevisionConflict
+            | ErrorCode::DocumentClosed => RecoveryHint::Retryable,
+            ErrorCode::UserUnauthorized => RecoveryHint::RequiresReLogin,
+            ErrorCode::DatabaseMigrationRequired => RecoveryHint::RequiresMigration,
+            _ => RecoveryHint::NoRecovery,
+        }
+    }
 }
@@ -1,4 +1,6 @@
 mod error_code;
 mod protobuf;
+mod recovery_hint;
 
 pub use error_code::*;
+pub use recovery_hint::*;
@@ -28,11 +28,16 @@ pub enum ErrorCode {
     Internal = 0,
     UserUnauthorized = 2,
     RecordNotFound = 3,
+    DatabaseBusy = 4,
+    DatabaseMigrationRequired = 5,
+    CloudFeatureUnsupported = 6,
     WorkspaceNameInvalid = 100,
     WorkspaceIdInvalid = 101,
     AppColorStyleInvalid = 102,
     WorkspaceDescTooLong = 103,
     WorkspaceNameTooLong = 104,
+    WorkspacePermissionDenied = 105,
+    InvitationIdInvalid = 106,
     AppIdInvalid = 110,
     AppNameInvalid = 111,
     ViewNameInvalid = 120,
@@ -41,6 +46,49 @@ pub enum ErrorCode {
     ViewDescTooLong = 123,
     ViewDataInvalid = 124,
     ViewNameTooLong = 125,
+    ViewAccessDenied = 126,
+    ExportPathInvalid = 130,
+    ShareLinkIdInvalid = 131,
+    ShareLinkExpireTimeInvalid = 132,
+    GuestAccessIdInvalid = 133,
+    WebhookIdInvalid = 134,
+    WebhookUrlInvalid = 135,
+    EventLogCursorInvalid = 136,
+    EventLogLimitInvalid = 137,
+    ViewMoveInvalid = 138,
+    ViewTypeMismatch = 139,
+    FieldIdInvalid = 140,
+    FieldNameInvalid = 141,
+    RowIdInvalid = 142,
+    GridRecordNotFound = 143,
+    CellDataInvalid = 144,
+    DateRangeInvalid = 145,
+    ChecklistItemIdInvalid = 146,
+    ChecklistItemTitleInvalid = 147,
+    FormulaInvalid = 148,
+    ImportFileInvalid = 149,
+    DelimiterInvalid = 150,
+    ViewTagInvalid = 151,
+    AttachmentIdInvalid = 152,
+    NameTooLong = 153,
+    NameContainsForbiddenCharacters = 154,
+    NameAlreadyExists = 155,
+    SearchQueryInvalid = 156,
+    RevisionRetentionValueInvalid = 157,
+    CheckpointNameInvalid = 158,
+    CheckpointIdInvalid = 159,
+    TextIndexInvalid = 160,
+    EmbedUrlInvalid = 161,
+    ToggleIdInvalid = 162,
+    BackupKeepCountInvalid = 163,
+    BackupCorrupted = 164,
+    StorageEndpointInvalid = 165,
+    StorageBucketInvalid = 166,
+    StorageNotConfigured = 167,
+    CloudOperationQueued = 168,
+    DefaultMemberRoleInvalid = 169,
+    AccountRequired = 170,
+    LocalApiRequestInvalid = 171,
     ConnectError = 200,
     EmailIsEmpty = 300,
     EmailFormatInvalid = 301,
@@ -55,6 +103,25 @@ pub enum ErrorCode {
     UserNameIsEmpty = 310,
     UserIdInvalid = 311,
     UserNotExist = 312,
+    AppPasscodeInvalid = 313,
+    AppPasscodeNotMatch = 314,
+    AppLockIdleTimeoutInvalid = 315,
+    AppLocked = 316,
+    UserLocaleInvalid = 317,
+    RevisionRetentionInvalid = 318,
+    AvatarIsEmpty = 319,
+    AvatarTooLarge = 320,
+    SessionIdInvalid = 321,
+    LogRingBufferDisabled = 322,
+    ServerUrlInvalid = 323,
+    DocumentNotFound = 400,
+    RevisionInvalid = 401,
+    RevisionConflict = 402,
+    DocumentClosed = 403,
+    DeltaInvalid = 404,
+    CheckpointNotFound = 405,
+    CodeBlockNotFound = 406,
+    EquationInvalid = 407,
 }
 
 impl ::protobuf::ProtobufEnum for ErrorCode {
@@ -67,11 +134,16 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             0 => ::std::option::Option::Some(ErrorCode::Internal),
             2 => ::std::option::Option::Some(ErrorCode::UserUnauthorized),
             3 => ::std::option::Option::Some(ErrorCode::RecordNotFound),
+            4 => ::std::option::Option::Some(ErrorCode::DatabaseBusy),
+            5 => ::std::option::Option::Some(ErrorCode::DatabaseMigrationRequired),
+            6 => ::std::option::Option::Some(ErrorCode::CloudFeatureUnsupported),
             100 => ::std::option::Option::Some(ErrorCode::WorkspaceNameInvalid),
             101 => ::std::option::Option::Some(ErrorCode::WorkspaceIdInvalid),
             102 => ::std::option::Option::Some(ErrorCode::AppColorStyleInvalid),
             103 => ::std::option::Option::Some(ErrorCode::WorkspaceDescTooLong),
             104 => ::std::option::Option::Some(ErrorCode::WorkspaceNameTooLong),
+            105 => ::std::option::Option::Some(ErrorCode::WorkspacePermissionDenied),
+            106 => ::std::option::Option::Some(ErrorCode::InvitationIdInvalid),
             110 => ::std::option::Option::Some(ErrorCode::AppIdInvalid),
             111 => ::std::option::Option::Some(ErrorCode::AppNameInvalid),
             120 => ::std::option::Option::Some(ErrorCode::ViewNameInvalid),
@@ -80,6 +152,49 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             123 => ::std::option::Option::Some(ErrorCode::ViewDescTooLong),
             124 => ::std::option::Option::Some(ErrorCode::ViewDataInvalid),
             125 => ::std::option::Option::Some(ErrorCode::ViewNameTooLong),
+            126 => ::std::option::Option::Some(ErrorCode::ViewAccessDenied),
+            130 => ::std::option::Option::Some(ErrorCode::ExportPathInvalid),
+            131 => ::std::option::Option::Some(ErrorCode::ShareLinkIdInvalid),
+            132 => ::std::option::Option::Some(ErrorCode::ShareLinkExpireTimeInvalid),
+            133 => ::std::option::Option::Some(ErrorCode::GuestAccessIdInvalid),
+            134 => ::std::option::Option::Some(ErrorCode::WebhookIdInvalid),
+            135 => ::std::option::Option::Some(ErrorCode::WebhookUrlInvalid),
+            136 => ::std::option::Option::Some(ErrorCode::EventLogCursorInvalid),
+            137 => ::std::option::Option::Some(ErrorCode::EventLogLimitInvalid),
+            138 => ::std::option::Option::Some(ErrorCode::ViewMoveInvalid),
+            139 => ::std::option::Option::Some(ErrorCode::ViewTypeMismatch),
+            140 => ::std::option::Option::Some(ErrorCode::FieldIdInvalid),
+            141 => ::std::option::Option::Some(ErrorCode::FieldNameInvalid),
+            142 => ::std::option::Option::Some(ErrorCode::RowIdInvalid),
+            143 => ::std::option::Option::Some(ErrorCode::GridRecordNotFound),
+            144 => ::std::option::Option::Some(ErrorCode::CellDataInvalid),
+            145 => ::std::option::Option::Some(ErrorCode::DateRangeInvalid),
+            146 => ::std::option::Option::Some(ErrorCode::ChecklistItemIdInvalid),
+            147 => ::std::option::Option::Some(ErrorCode::ChecklistItemTitleInvalid),
+            148 => ::std::option::Option::Some(ErrorCode::FormulaInvalid),
+            149 => ::std::option::Option::Some(ErrorCode::ImportFileInvalid),
+            150 => ::std::option::Option::Some(ErrorCode::DelimiterInvalid),
+            151 => ::std::option::Option::Some(ErrorCode::ViewTagInvalid),
+            152 => ::std::option::Option::Some(ErrorCode::AttachmentIdInvalid),
+            153 => ::std::option::Option::Some(ErrorCode::NameTooLong),
+            154 => ::std::option::Option::Some(ErrorCode::NameContainsForbiddenCharacters),
+            155 => ::std::option::Option::Some(ErrorCode::NameAlreadyExists),
+            156 => ::std::option::Option::Some(ErrorCode::SearchQueryInvalid),
+            157 => ::std::option::Option::Some(ErrorCode::RevisionRetentionValueInvalid),
+            158 => ::std::option::Option::Some(ErrorCode::CheckpointNameInvalid),
+            159 => ::std::option::Option::Some(ErrorCode::CheckpointIdInvalid),
+            160 => ::std::option::Option::Some(ErrorCode::TextIndexInvalid),
+            161 => ::std::option::Option::Some(ErrorCode::EmbedUrlInvalid),
+            162 => ::std::option::Option::Some(ErrorCode::ToggleIdInvalid),
+            163 => ::std::option::Option::Some(ErrorCode::BackupKeepCountInvalid),
+            164 => ::std::option::Option::Some(ErrorCode::BackupCorrupted),
+            165 => ::std::option::Option::Some(ErrorCode::StorageEndpointInvalid),
+            166 => ::std::option::Option::Some(ErrorCode::StorageBucketInvalid),
+            167 => ::std::option::Option::Some(ErrorCode::StorageNotConfigured),
+            168 => ::std::option::Option::Some(ErrorCode::CloudOperationQueued),
+            169 => ::std::option::Option::Some(ErrorCode::DefaultMemberRoleInvalid),
+            170 => ::std::option::Option::Some(ErrorCode::AccountRequired),
+            171 => ::std::option::Option::Some(ErrorCode::LocalApiRequestInvalid),
             200 => ::std::option::Option::Some(ErrorCode::ConnectError),
             300 => ::std::option::Option::Some(ErrorCode::EmailIsEmpty),
             301 => ::std::option::Option::Some(ErrorCode::EmailFormatInvalid),
@@ -94,6 +209,25 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             310 => ::std::option::Option::Some(ErrorCode::UserNameIsEmpty),
             311 => ::std::option::Option::Some(ErrorCode::UserIdInvalid),
             312 => ::std::option::Option::Some(ErrorCode::UserNotExist),
+            313 => ::std::option::Option::Some(ErrorCode::AppPasscodeInvalid),
+            314 => ::std::option::Option::Some(ErrorCode::AppPasscodeNotMatch),
+            315 => ::std::option::Option::Some(ErrorCode::AppLockIdleTimeoutInvalid),
+            316 => ::std::option::Option::Some(ErrorCode::AppLocked),
+            317 => ::std::option::Option::Some(ErrorCode::UserLocaleInvalid),
+            318 => ::std::option::Option::Some(ErrorCode::RevisionRetentionInvalid),
+            319 => ::std::option::Option::Some(ErrorCode::AvatarIsEmpty),
+            320 => ::std::option::Option::Some(ErrorCode::AvatarTooLarge),
+            321 => ::std::option::Option::Some(ErrorCode::SessionIdInvalid),
+            322 => ::std::option::Option::Some(ErrorCode::LogRingBufferDisabled),
+            323 => ::std::option::Option::Some(ErrorCode::ServerUrlInvalid),
+            400 => ::std::option::Option::Some(ErrorCode::DocumentNotFound),
+            401 => ::std::option::Option::Some(ErrorCode::RevisionInvalid),
+            402 => ::std::option::Option::Some(ErrorCode::RevisionConflict),
+            403 => ::std::option::Option::Some(ErrorCode::DocumentClosed),
+            404 => ::std::option::Option::Some(ErrorCode::DeltaInvalid),
+            405 => ::std::option::Option::Some(ErrorCode::CheckpointNotFound),
+            406 => ::std::option::Option::Some(ErrorCode::CodeBlockNotFound),
+            407 => ::std::option::Option::Some(ErrorCode::EquationInvalid),
             _ => ::std::option::Option::None
         }
     }
@@ -103,11 +237,16 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::Internal,
             ErrorCode::UserUnauthorized,
             ErrorCode::RecordNotFound,
+            ErrorCode::DatabaseBusy,
+            ErrorCode::DatabaseMigrationRequired,
+            ErrorCode::CloudFeatureUnsupported,
             ErrorCode::WorkspaceNameInvalid,
             ErrorCode::WorkspaceIdInvalid,
             ErrorCode::AppColorStyleInvalid,
             ErrorCode::WorkspaceDescTooLong,
             ErrorCode::WorkspaceNameTooLong,
+            ErrorCode::WorkspacePermissionDenied,
+            ErrorCode::InvitationIdInvalid,
             ErrorCode::AppIdInvalid,
             ErrorCode::AppNameInvalid,
             ErrorCode::ViewNameInvalid,
@@ -116,6 +255,49 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::ViewDescTooLong,
             ErrorCode::ViewDataInvalid,
             ErrorCode::ViewNameTooLong,
+            ErrorCode::ViewAccessDenied,
+            ErrorCode::ExportPathInvalid,
+            ErrorCode::ShareLinkIdInvalid,
+            ErrorCode::ShareLinkExpireTimeInvalid,
+            ErrorCode::GuestAccessIdInvalid,
+            ErrorCode::WebhookIdInvalid,
+            ErrorCode::WebhookUrlInvalid,
+            ErrorCode::EventLogCursorInvalid,
+            ErrorCode::EventLogLimitInvalid,
+            ErrorCode::ViewMoveInvalid,
+            ErrorCode::ViewTypeMismatch,
+            ErrorCode::FieldIdInvalid,
+            ErrorCode::FieldNameInvalid,
+            ErrorCode::RowIdInvalid,
+            ErrorCode::GridRecordNotFound,
+            ErrorCode::CellDataInvalid,
+            ErrorCode::DateRangeInvalid,
+            ErrorCode::ChecklistItemIdInvalid,
+            ErrorCode::ChecklistItemTitleInvalid,
+            ErrorCode::FormulaInvalid,
+            ErrorCode::ImportFileInvalid,
+            ErrorCode::DelimiterInvalid,
+            ErrorCode::ViewTagInvalid,
+            ErrorCode::AttachmentIdInvalid,
+            ErrorCode::NameTooLong,
+            ErrorCode::NameContainsForbiddenCharacters,
+            ErrorCode::NameAlreadyExists,
+            ErrorCode::SearchQueryInvalid,
+            ErrorCode::RevisionRetentionValueInvalid,
+            ErrorCode::CheckpointNameInvalid,
+            ErrorCode::CheckpointIdInvalid,
+            ErrorCode::TextIndexInvalid,
+            ErrorCode::EmbedUrlInvalid,
+            ErrorCode::ToggleIdInvalid,
+            ErrorCode::BackupKeepCountInvalid,
+            ErrorCode::BackupCorrupted,
+            ErrorCode::StorageEndpointInvalid,
+            ErrorCode::StorageBucketInvalid,
+            ErrorCode::StorageNotConfigured,
+            ErrorCode::CloudOperationQueued,
+            ErrorCode::DefaultMemberRoleInvalid,
+            ErrorCode::AccountRequired,
+            ErrorCode::LocalApiRequestInvalid,
             ErrorCode::ConnectError,
             ErrorCode::EmailIsEmpty,
             ErrorCode::EmailFormatInvalid,
@@ -130,6 +312,25 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::UserNameIsEmpty,
             ErrorCode::UserIdInvalid,
             ErrorCode::UserNotExist,
+            ErrorCode::AppPasscodeInvalid,
+            ErrorCode::AppPasscodeNotMatch,
+            ErrorCode::AppLockIdleTimeoutInvalid,
+            ErrorCode::AppLocked,
+            ErrorCode::UserLocaleInvalid,
+            ErrorCode::RevisionRetentionInvalid,
+            ErrorCode::AvatarIsEmpty,
+            ErrorCode::AvatarTooLarge,
+            ErrorCode::SessionIdInvalid,
+            ErrorCode::LogRingBufferDisabled,
+            ErrorCode::ServerUrlInvalid,
+            ErrorCode::DocumentNotFound,
+            ErrorCode::RevisionInvalid,
+            ErrorCode::RevisionConflict,
+            ErrorCode::DocumentClosed,
+            ErrorCode::DeltaInvalid,
+            ErrorCode::CheckpointNotFound,
+            ErrorCode::CodeBlockNotFound,
+            ErrorCode::EquationInvalid,
         ];
         values
     }
@@ -158,86 +359,65 @@ impl ::protobuf::reflect::ProtobufValue for ErrorCode {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10error_code.proto*\xc4\x05\n\tErrorCode\x12\x0c\n\x08Internal\x10\0\
+    \n\x10error_code.proto*\xd0\x12\n\tErrorCode\x12\x0c\n\x08Internal\x10\0\
     \x12\x14\n\x10UserUnauthorized\x10\x02\x12\x12\n\x0eRecordNotFound\x10\
-    \x03\x12\x18\n\x14WorkspaceNameInvalid\x10d\x12\x16\n\x12WorkspaceIdInva\
-    lid\x10e\x12\x18\n\x14AppColorStyleInvalid\x10f\x12\x18\n\x14WorkspaceDe\
-    scTooLong\x10g\x12\x18\n\x14WorkspaceNameTooLong\x10h\x12\x10\n\x0cAppId\
-    Invalid\x10n\x12\x12\n\x0eAppNameInvalid\x10o\x12\x13\n\x0fViewNameInval\
-    id\x10x\x12\x18\n\x14ViewThumbnailInvalid\x10y\x12\x11\n\rViewIdInvalid\
-    \x10z\x12\x13\n\x0fViewDescTooLong\x10{\x12\x13\n\x0fViewDataInvalid\x10\
-    |\x12\x13\n\x0fViewNameTooLong\x10}\x12\x11\n\x0cConnectError\x10\xc8\
-    \x01\x12\x11\n\x0cEmailIsEmpty\x10\xac\x02\x12\x17\n\x12EmailFormatInval\
-    id\x10\xad\x02\x12\x17\n\x12EmailAlreadyExists\x10\xae\x02\x12\x14\n\x0f\
-    PasswordIsEmpty\x10\xaf\x02\x12\x14\n\x0fPasswordTooLong\x10\xb0\x02\x12\
-    %\n\x20PasswordContainsForbidCharacters\x10\xb1\x02\x12\x1a\n\x15Passwor\
-    dFormatInvalid\x10\xb2\x02\x12\x15\n\x10PasswordNotMatch\x10\xb3\x02\x12\
-    \x14\n\x0fUserNameTooLong\x10\xb4\x02\x12'\n\"UserNameContainForbiddenCh\
-    aracters\x10\xb5\x02\x12\x14\n\x0fUserNameIsEmpty\x10\xb6\x02\x12\x12\n\
-    \rUserIdInvalid\x10\xb7\x02\x12\x11\n\x0cUserNotExist\x10\xb8\x02J\xf8\t\
-    \n\x06\x12\x04\0\0!\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\
-    \x12\x04\x02\0!\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x0e\n\x0b\n\x04\
-    \x05\0\x02\0\x12\x03\x03\x04\x11\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\
-    \x04\x0c\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x0f\x10\n\x0b\n\x04\x05\
-    \0\x02\x01\x12\x03\x04\x04\x19\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\
-    \x04\x14\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\
-    \x05\0\x02\x02\x12\x03\x05\x04\x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
-    \x05\x04\x12\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\
-    \x04\x05\0\x02\x03\x12\x03\x06\x04\x1f\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
-    \x03\x06\x04\x18\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x1b\x1e\n\x0b\
-    \n\x04\x05\0\x02\x04\x12\x03\x07\x04\x1d\n\x0c\n\x05\x05\0\x02\x04\x01\
-    \x12\x03\x07\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x19\x1c\n\
-    \x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x1f\n\x0c\n\x05\x05\0\x02\x05\
-    \x01\x12\x03\x08\x04\x18\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x1f\n\x0c\n\x05\x05\0\x02\
-    \x06\x01\x12\x03\t\x04\x18\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\x1f\n\x0c\n\x05\x05\0\x02\
-    \x07\x01\x12\x03\n\x04\x18\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\x17\n\x0c\n\x05\x05\0\x02\
-    \x08\x01\x12\x03\x0b\x04\x10\n\x0c\n\x05\x05\0\x02\x08\x02\x12\x03\x0b\
-    \x13\x16\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\x19\n\x0c\n\x05\x05\0\
-    \x02\t\x01\x12\x03\x0c\x04\x12\n\x0c\n\x05\x05\0\x02\t\x02\x12\x03\x0c\
-    \x15\x18\n\x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x1a\n\x0c\n\x05\x05\0\x02\
-    \n\x01\x12\x03\r\x04\x13\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\r\x16\x19\n\
-    \x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\x1f\n\x0c\n\x05\x05\0\x02\x0b\
-    \x01\x12\x03\x0e\x04\x18\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\x04\x18\n\x0c\n\x05\x05\0\x02\
-    \x0c\x01\x12\x03\x0f\x04\x11\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\
-    \x14\x17\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\x1a\n\x0c\n\x05\x05\0\
-    \x02\r\x01\x12\x03\x10\x04\x13\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\
-    \x16\x19\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\x1a\n\x0c\n\x05\x05\0\
-    \x02\x0e\x01\x12\x03\x11\x04\x13\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\
-    \x11\x16\x19\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x1a\n\x0c\n\x05\
-    \x05\0\x02\x0f\x01\x12\x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\
-    \x03\x12\x16\x19\n\x0b\n\x04\x05\0\x02\x10\x12\x03\x13\x04\x17\n\x0c\n\
-    \x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x10\n\x0c\n\x05\x05\0\x02\x10\x02\
-    \x12\x03\x13\x13\x16\n\x0b\n\x04\x05\0\x02\x11\x12\x03\x14\x04\x17\n\x0c\
-    \n\x05\x05\0\x02\x11\x01\x12\x03\x14\x04\x10\n\x0c\n\x05\x05\0\x02\x11\
-    \x02\x12\x03\x14\x13\x16\n\x0b\n\x04\x05\0\x02\x12\x12\x03\x15\x04\x1d\n\
-    \x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x15\x04\x16\n\x0c\n\x05\x05\0\x02\
-    \x12\x02\x12\x03\x15\x19\x1c\n\x0b\n\x04\x05\0\x02\x13\x12\x03\x16\x04\
-    \x1d\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x16\x04\x16\n\x0c\n\x05\x05\0\
-    \x02\x13\x02\x12\x03\x16\x19\x1c\n\x0b\n\x04\x05\0\x02\x14\x12\x03\x17\
-    \x04\x1a\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\x17\x04\x13\n\x0c\n\x05\
-    \x05\0\x02\x14\x02\x12\x03\x17\x16\x19\n\x0b\n\x04\x05\0\x02\x15\x12\x03\
-    \x18\x04\x1a\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x03\x18\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x15\x02\x12\x03\x18\x16\x19\n\x0b\n\x04\x05\0\x02\x16\x12\
-    \x03\x19\x04+\n\x0c\n\x05\x05\0\x02\x16\x01\x12\x03\x19\x04$\n\x0c\n\x05\
-    \x05\0\x02\x16\x02\x12\x03\x19'*\n\x0b\n\x04\x05\0\x02\x17\x12\x03\x1a\
-    \x04\x20\n\x0c\n\x05\x05\0\x02\x17\x01\x12\x03\x1a\x04\x19\n\x0c\n\x05\
-    \x05\0\x02\x17\x02\x12\x03\x1a\x1c\x1f\n\x0b\n\x04\x05\0\x02\x18\x12\x03\
-    \x1b\x04\x1b\n\x0c\n\x05\x05\0\x02\x18\x01\x12\x03\x1b\x04\x14\n\x0c\n\
-    \x05\x05\0\x02\x18\x02\x12\x03\x1b\x17\x1a\n\x0b\n\x04\x05\0\x02\x19\x12\
-    \x03\x1c\x04\x1a\n\x0c\n\x05\x05\0\x02\x19\x01\x12\x03\x1c\x04\x13\n\x0c\
-    \n\x05\x05\0\x02\x19\x02\x12\x03\x1c\x16\x19\n\x0b\n\x04\x05\0\x02\x1a\
-    \x12\x03\x1d\x04-\n\x0c\n\x05\x05\0\x02\x1a\x01\x12\x03\x1d\x04&\n\x0c\n\
-    \x05\x05\0\x02\x1a\x02\x12\x03\x1d),\n\x0b\n\x04\x05\0\x02\x1b\x12\x03\
-    \x1e\x04\x1a\n\x0c\n\x05\x05\0\x02\x1b\x01\x12\x03\x1e\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x1b\x02\x12\x03\x1e\x16\x19\n\x0b\n\x04\x05\0\x02\x1c\x12\
-    \x03\x1f\x04\x18\n\x0c\n\x05\x05\0\x02\x1c\x01\x12\x03\x1f\x04\x11\n\x0c\
-    \n\x05\x05\0\x02\x1c\x02\x12\x03\x1f\x14\x17\n\x0b\n\x04\x05\0\x02\x1d\
-    \x12\x03\x20\x04\x17\n\x0c\n\x05\x05\0\x02\x1d\x01\x12\x03\x20\x04\x10\n\
-    \x0c\n\x05\x05\0\x02\x1d\x02\x12\x03\x20\x13\x16b\x06proto3\
+    \x03\x12\x10\n\x0cDatabaseBusy\x10\x04\x12\x1d\n\x19DatabaseMigrationReq\
+    uired\x10\x05\x12\x1b\n\x17CloudFeatureUnsupported\x10\x06\x12\x18\n\x14\
+    WorkspaceNameInvalid\x10d\x12\x16\n\x12WorkspaceIdInvalid\x10e\x12\x18\n\
+    \x14AppColorStyleInvalid\x10f\x12\x18\n\x14WorkspaceDescTooLong\x10g\x12\
+    \x18\n\x14WorkspaceNameTooLong\x10h\x12\x1d\n\x19WorkspacePermissionDeni\
+    ed\x10i\x12\x17\n\x13InvitationIdInvalid\x10j\x12\x10\n\x0cAppIdInvalid\
+    \x10n\x12\x12\n\x0eAppNameInvalid\x10o\x12\x13\n\x0fViewNameInvalid\x10x\
+    \x12\x18\n\x14ViewThumbnailInvalid\x10y\x12\x11\n\rViewIdInvalid\x10z\
+    \x12\x13\n\x0fViewDescTooLong\x10{\x12\x13\n\x0fViewDataInvalid\x10|\x12\
+    \x13\n\x0fViewNameTooLong\x10}\x12\x14\n\x10ViewAccessDenied\x10~\x12\
+    \x16\n\x11ExportPathInvalid\x10\x82\x01\x12\x17\n\x12ShareLinkIdInvalid\
+    \x10\x83\x01\x12\x1f\n\x1aShareLinkExpireTimeInvalid\x10\x84\x01\x12\x19\
+    \n\x14GuestAccessIdInvalid\x10\x85\x01\x12\x15\n\x10WebhookIdInvalid\x10\
+    \x86\x01\x12\x16\n\x11WebhookUrlInvalid\x10\x87\x01\x12\x1a\n\x15EventLo\
+    gCursorInvalid\x10\x88\x01\x12\x19\n\x14EventLogLimitInvalid\x10\x89\x01\
+    \x12\x14\n\x0fViewMoveInvalid\x10\x8a\x01\x12\x15\n\x10ViewTypeMismatch\
+    \x10\x8b\x01\x12\x13\n\x0eFieldIdInvalid\x10\x8c\x01\x12\x15\n\x10FieldN\
+    ameInvalid\x10\x8d\x01\x12\x11\n\x0cRowIdInvalid\x10\x8e\x01\x12\x17\n\
+    \x12GridRecordNotFound\x10\x8f\x01\x12\x14\n\x0fCellDataInvalid\x10\x90\
+    \x01\x12\x15\n\x10DateRangeInvalid\x10\x91\x01\x12\x1b\n\x16ChecklistIte\
+    mIdInvalid\x10\x92\x01\x12\x1e\n\x19ChecklistItemTitleInvalid\x10\x93\
+    \x01\x12\x13\n\x0eFormulaInvalid\x10\x94\x01\x12\x16\n\x11ImportFileInva\
+    lid\x10\x95\x01\x12\x15\n\x10DelimiterInvalid\x10\x96\x01\x12\x13\n\x0eV\
+    iewTagInvalid\x10\x97\x01\x12\x18\n\x13AttachmentIdInvalid\x10\x98\x01\
+    \x12\x10\n\x0bNameTooLong\x10\x99\x01\x12$\n\x1fNameContainsForbiddenCha\
+    racters\x10\x9a\x01\x12\x16\n\x11NameAlreadyExists\x10\x9b\x01\x12\x17\n\
+    \x12SearchQueryInvalid\x10\x9c\x01\x12\"\n\x1dRevisionRetentionValueInva\
+    lid\x10\x9d\x01\x12\x1a\n\x15CheckpointNameInvalid\x10\x9e\x01\x12\x18\n\
+    \x13CheckpointIdInvalid\x10\x9f\x01\x12\x15\n\x10TextIndexInvalid\x10\
+    \xa0\x01\x12\x14\n\x0fEmbedUrlInvalid\x10\xa1\x01\x12\x14\n\x0fToggleIdI\
+    nvalid\x10\xa2\x01\x12\x1b\n\x16BackupKeepCountInvalid\x10\xa3\x01\x12\
+    \x14\n\x0fBackupCorrupted\x10\xa4\x01\x12\x1b\n\x16StorageEndpointInvali\
+    d\x10\xa5\x01\x12\x19\n\x14StorageBucketInvalid\x10\xa6\x01\x12\x19\n\
+    \x14StorageNotConfigured\x10\xa7\x01\x12\x19\n\x14CloudOperationQueued\
+    \x10\xa8\x01\x12\x1d\n\x18DefaultMemberRoleInvalid\x10\xa9\x01\x12\x14\n\
+    \x0fAccountRequired\x10\xaa\x01\x12\x1b\n\x16LocalApiRequestInvalid\x10\
+    \xab\x01\x12\x11\n\x0cConnectError\x10\xc8\x01\x12\x11\n\x0cEmailIsEmpty\
+    \x10\xac\x02\x12\x17\n\x12EmailFormatInvalid\x10\xad\x02\x12\x17\n\x12Em\
+    ailAlreadyExists\x10\xae\x02\x12\x14\n\x0fPasswordIsEmpty\x10\xaf\x02\
+    \x12\x14\n\x0fPasswordTooLong\x10\xb0\x02\x12%\n\x20PasswordContainsForb\
+    idCharacters\x10\xb1\x02\x12\x1a\n\x15PasswordFormatInvalid\x10\xb2\x02\
+    \x12\x15\n\x10PasswordNotMatch\x10\xb3\x02\x12\x14\n\x0fUserNameTooLong\
+    \x10\xb4\x02\x12'\n\"UserNameContainForbiddenCharacters\x10\xb5\x02\x12\
+    \x14\n\x0fUserNameIsEmpty\x10\xb6\x02\x12\x12\n\rUserIdInvalid\x10\xb7\
+    \x02\x12\x11\n\x0cUserNotExist\x10\xb8\x02\x12\x17\n\x12AppPasscodeInval\
+    id\x10\xb9\x02\x12\x18\n\x13AppPasscodeNotMatch\x10\xba\x02\x12\x1e\n\
+    \x19AppLockIdleTimeoutInvalid\x10\xbb\x02\x12\x0e\n\tAppLocked\x10\xbc\
+    \x02\x12\x16\n\x11UserLocaleInvalid\x10\xbd\x02\x12\x1d\n\x18RevisionRet\
+    entionInvalid\x10\xbe\x02\x12\x12\n\rAvatarIsEmpty\x10\xbf\x02\x12\x13\n\
+    \x0eAvatarTooLarge\x10\xc0\x02\x12\x15\n\x10SessionIdInvalid\x10\xc1\x02\
+    \x12\x1a\n\x15LogRingBufferDisabled\x10\xc2\x02\x12\x15\n\x10ServerUrlIn\
+    valid\x10\xc3\x02\x12\x15\n\x10DocumentNotFound\x10\x90\x03\x12\x14\n\
+    \x0fRevisionInvalid\x10\x91\x03\x12\x15\n\x10RevisionConflict\x10\x92\
+    \x03\x12\x13\n\x0eDocumentClosed\x10\x93\x03\x12\x11\n\x0cDeltaInvalid\
+    \x10\x94\x03\x12\x17\n\x12CheckpointNotFound\x10\x95\x03\x12\x16\n\x11Co\
+    deBlockNotFound\x10\x96\x03\x12\x14\n\x0fEquationInvalid\x10\x97\x03\x1a\
+    \0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -3,3 +3,6 @@
 
 mod error_code;
 pub use error_code::*;
+
+mod recovery_hint;
+pub use recovery_hint::*;
@@ -0,0 +1,98 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `recovery_hint.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum RecoveryHint {
+    NoRecovery = 0,
+    Retryable = 1,
+    RequiresReLogin = 2,
+    RequiresMigration = 3,
+}
+
+impl ::protobuf::ProtobufEnum for RecoveryHint {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<RecoveryHint> {
+        match value {
+            0 => ::std::option::Option::Some(RecoveryHint::NoRecovery),
+            1 => ::std::option::Option::Some(RecoveryHint::Retryable),
+            2 => ::std::option::Option::Some(RecoveryHint::RequiresReLogin),
+            3 => ::std::option::Option::Some(RecoveryHint::RequiresMigration),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [RecoveryHint] = &[
+            RecoveryHint::NoRecovery,
+            RecoveryHint::Retryable,
+            RecoveryHint::RequiresReLogin,
+            RecoveryHint::RequiresMigration,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<RecoveryHint>("RecoveryHint", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for RecoveryHint {
+}
+
+impl ::std::default::Default for RecoveryHint {
+    fn default() -> Self {
+        RecoveryHint::NoRecovery
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RecoveryHint {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x13recovery_hint.proto*[\n\x0cRecoveryHint\x12\x0e\n\nNoRecovery\x10\
+    \0\x12\r\n\tRetryable\x10\x01\x12\x13\n\x0fRequiresReLogin\x10\x02\x12\
+    \x15\n\x11RequiresMigration\x10\x03\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,28 @@
+use flowy_derive::ProtoBuf_Enum;
+
+// Tells the client what the user can do about an error, so it can offer an
+// action instead of showing the message of the error.
+#[derive(Debug, Clone, Copy, ProtoBuf_Enum, PartialEq, Eq)]
+pub enum RecoveryHint {
+    NoRecovery        = 0,
+    Retryable         = 1,
+    RequiresReLogin   = 2,
+    RequiresMigration = 3,
+}
+
+impl std::default::Default for RecoveryHint {
+    fn default() -> Self { RecoveryHint::NoRecovery }
+}
+
+impl RecoveryHint {
+    pub fn value(&self) -> i32 { *self as i32 }
+
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            1 => RecoveryHint::Retryable,
+            2 => RecoveryHint::RequiresReLogin,
+            3 => RecoveryHint::RequiresMigration,
+            _ => RecoveryHint::NoRecovery,
+        }
+    }
+}
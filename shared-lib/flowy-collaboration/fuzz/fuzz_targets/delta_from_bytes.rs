@@ -0,0 +1,22 @@
+#![no_main]
+use lib_ot::{core::OperationTransformable, rich_text::RichTextDelta};
+use libfuzzer_sys::fuzz_target;
+
+// The deltas that come from the server or the disk are parsed from bytes. A
+// delta that is parsed must survive the round trip through json, and applying
+// or composing it must fail with an error instead of a panic.
+fuzz_target!(|data: &[u8]| {
+    let delta = match RichTextDelta::from_bytes(data) {
+        Ok(delta) => delta,
+        Err(_) => return,
+    };
+
+    let parsed = RichTextDelta::from_json(&delta.to_json()).expect("A serialized delta must be parsed again");
+    assert_eq!(parsed.ops, delta.ops);
+
+    let base = "a".repeat(delta.utf16_base_len);
+    if let Ok(s) = delta.apply(&base) {
+        assert_eq!(s.encode_utf16().count(), delta.utf16_target_len);
+    }
+    let _ = delta.compose(&delta);
+});
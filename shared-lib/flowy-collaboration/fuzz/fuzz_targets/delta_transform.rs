@@ -0,0 +1,57 @@
+#![no_main]
+use flowy_collaboration::document::{Document, NewlineDoc};
+use flowy_collaboration_fuzz::{apply_edit, Edit};
+use lib_ot::core::OperationTransformable;
+use libfuzzer_sys::{arbitrary, fuzz_target};
+
+#[derive(arbitrary::Arbitrary, Debug)]
+struct Input {
+    base: Vec<Edit>,
+    local: Edit,
+    remote: Edit,
+}
+
+// Two clients edit the same document at the same time. After each one applies
+// the transformed edit of the other, they must end up with the same text.
+// Inverting an edit must also bring the document back.
+fuzz_target!(|input: Input| {
+    let mut document = Document::new::<NewlineDoc>();
+    for edit in input.base {
+        let _ = apply_edit(&mut document, edit);
+    }
+    let base = document.delta().clone();
+
+    let mut local_document = Document::from_delta(base.clone());
+    let mut remote_document = Document::from_delta(base.clone());
+    let local = match apply_edit(&mut local_document, input.local) {
+        Ok(Some(delta)) => delta,
+        _ => return,
+    };
+    let remote = match apply_edit(&mut remote_document, input.remote) {
+        Ok(Some(delta)) => delta,
+        _ => return,
+    };
+
+    if let Ok((local_prime, remote_prime)) = local.transform(&remote) {
+        let local_then_remote = base
+            .compose(&local)
+            .and_then(|delta| delta.compose(&remote_prime))
+            .and_then(|delta| delta.apply(""));
+        let remote_then_local = base
+            .compose(&remote)
+            .and_then(|delta| delta.compose(&local_prime))
+            .and_then(|delta| delta.apply(""));
+        if let (Ok(a), Ok(b)) = (local_then_remote, remote_then_local) {
+            assert_eq!(a, b, "The clients diverged");
+        }
+    }
+
+    let inverted = local.invert(&base);
+    let restored = base
+        .compose(&local)
+        .and_then(|delta| delta.compose(&inverted))
+        .and_then(|delta| delta.apply(""));
+    if let Ok(restored) = restored {
+        assert_eq!(restored, base.apply("").unwrap(), "Inverting the edit lost text");
+    }
+});
@@ -0,0 +1,23 @@
+#![no_main]
+use flowy_collaboration::document::{Document, NewlineDoc};
+use flowy_collaboration_fuzz::{apply_edit, check_document, compose_text, Edit};
+use libfuzzer_sys::fuzz_target;
+
+// Runs a sequence of edits on a document. An edit may be rejected with an
+// error, but it must never panic or leave the document in a state that
+// breaks the invariants, e.g. a delta whose length doesn't match its text.
+fuzz_target!(|edits: Vec<Edit>| {
+    let mut document = Document::new::<NewlineDoc>();
+    for edit in edits {
+        let base = document.delta().clone();
+        match apply_edit(&mut document, edit) {
+            Ok(Some(delta)) if !delta.is_empty() => {
+                let text = compose_text(&base, &delta).expect("The delta of an edit must compose onto the document");
+                assert_eq!(text, document.to_plain_string());
+            },
+            Ok(_) => {},
+            Err(_) => {},
+        }
+        check_document(&document);
+    }
+});
@@ -0,0 +1,112 @@
+use flowy_collaboration::{document::Document, errors::CollaborateResult};
+use lib_ot::{
+    core::{Interval, OperationTransformable},
+    rich_text::{RichTextAttribute, RichTextDelta},
+};
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+
+/// An edit that the editor can send. The positions are mapped onto the
+/// character boundaries of the document, like the positions that come from
+/// the editor.
+#[derive(Arbitrary, Debug)]
+pub enum Edit {
+    Insert { at: u16, text: String },
+    Delete { at: u16, len: u8 },
+    Format { at: u16, len: u8, attribute: Attribute },
+    Replace { at: u16, len: u8, text: String },
+    Undo,
+    Redo,
+}
+
+#[derive(Arbitrary, Debug)]
+pub enum Attribute {
+    Bold(bool),
+    Italic(bool),
+    Header(u8),
+    Bullet(bool),
+    Link(String),
+}
+
+impl Attribute {
+    fn into_rich_text(self) -> RichTextAttribute {
+        match self {
+            Attribute::Bold(value) => RichTextAttribute::Bold(value),
+            Attribute::Italic(value) => RichTextAttribute::Italic(value),
+            Attribute::Header(level) => RichTextAttribute::Header(level as usize % 7),
+            Attribute::Bullet(value) => RichTextAttribute::Bullet(value),
+            Attribute::Link(url) => RichTextAttribute::Link(&url),
+        }
+    }
+}
+
+/// Applies the edit to the document and returns the delta that the edit
+/// composed onto it, `None` if nothing was composed.
+pub fn apply_edit(document: &mut Document, edit: Edit) -> CollaborateResult<Option<RichTextDelta>> {
+    let text = document.to_plain_string();
+    let delta = match edit {
+        Edit::Insert { at, text: data } => Some(document.insert(boundary(&text, at), data)?),
+        Edit::Delete { at, len } => match interval(&text, at, len) {
+            None => None,
+            Some(interval) => Some(document.delete(interval)?),
+        },
+        Edit::Format { at, len, attribute } => match interval(&text, at, len) {
+            None => None,
+            Some(interval) => Some(document.format(interval, attribute.into_rich_text())?),
+        },
+        Edit::Replace { at, len, text: data } => {
+            let start = boundary(&text, at);
+            let interval = interval(&text, at, len).unwrap_or_else(|| Interval::new(start, start));
+            Some(document.replace(interval, data)?)
+        },
+        Edit::Undo if document.can_undo() => Some(document.undo()?.delta),
+        Edit::Redo if document.can_redo() => Some(document.redo()?.delta),
+        Edit::Undo | Edit::Redo => None,
+    };
+    Ok(delta)
+}
+
+/// Checks the invariants that every document delta keeps: it only inserts,
+/// its length matches its text and it survives the round trip through json.
+pub fn check_document(document: &Document) {
+    let delta = document.delta();
+    assert_eq!(delta.utf16_base_len, 0, "The document delta must only insert");
+
+    let text = document.to_plain_string();
+    assert_eq!(text.encode_utf16().count(), delta.utf16_target_len);
+
+    let parsed = Document::from_json(&document.to_json()).expect("The document json must be parsed again");
+    assert_eq!(parsed.to_plain_string(), text);
+}
+
+/// The text that the delta produces from the previous document delta.
+pub fn compose_text(base: &RichTextDelta, delta: &RichTextDelta) -> CollaborateResult<String> {
+    let composed = base.compose(delta)?;
+    Ok(composed.apply("")?)
+}
+
+// The utf16 offsets of the character boundaries, the editor never sends a
+// position in the middle of a surrogate pair.
+fn boundaries(text: &str) -> Vec<usize> {
+    let mut offsets = vec![0];
+    let mut offset = 0;
+    for c in text.chars() {
+        offset += c.len_utf16();
+        offsets.push(offset);
+    }
+    offsets
+}
+
+fn boundary(text: &str, at: u16) -> usize {
+    let offsets = boundaries(text);
+    offsets[at as usize % offsets.len()]
+}
+
+fn interval(text: &str, at: u16, len: u8) -> Option<Interval> {
+    let offsets = boundaries(text);
+    let start = at as usize % offsets.len();
+    let end = (start + len as usize).min(offsets.len() - 1);
+    if start == end {
+        return None;
+    }
+    Some(Interval::new(offsets[start], offsets[end]))
+}
@@ -0,0 +1,154 @@
+use crate::document::table::delta_lines;
+use lib_ot::rich_text::{RichTextAttributeKey, RichTextAttributes, RichTextDelta};
+
+/// A callout is made of the consecutive lines with the same icon and color in
+/// their callout attributes. The color is the name of one of the colors of the
+/// theme, e.g. "yellow", or None for the default one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentCallout {
+    pub start: usize,
+    pub len: usize,
+    pub icon: String,
+    pub color: Option<String>,
+}
+
+/// A toggle is made of the consecutive lines with its id in their toggle
+/// attribute. The first line is the summary that stays visible, the others
+/// are hidden while it's collapsed. Whether it's collapsed is a preference of
+/// each user, it's not part of the document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentToggle {
+    pub id: String,
+    pub start: usize,
+    pub len: usize,
+    pub summary: String,
+}
+
+pub fn read_callouts(delta: &RichTextDelta) -> Vec<DocumentCallout> {
+    let mut callouts: Vec<DocumentCallout> = vec![];
+    for line in delta_lines(delta) {
+        let (icon, color) = match callout_key(&line.attributes) {
+            None => continue,
+            Some(key) => key,
+        };
+        match callouts.last_mut() {
+            Some(callout)
+                if callout.start + callout.len == line.start
+                    && callout.icon == icon
+                    && callout.color.as_deref() == color =>
+            {
+                callout.len += line.len;
+            },
+            _ => callouts.push(DocumentCallout {
+                start: line.start,
+                len: line.len,
+                icon: icon.to_owned(),
+                color: color.map(|color| color.to_owned()),
+            }),
+        }
+    }
+    callouts
+}
+
+pub fn read_toggles(delta: &RichTextDelta) -> Vec<DocumentToggle> {
+    let mut toggles: Vec<DocumentToggle> = vec![];
+    for line in delta_lines(delta) {
+        let id = match toggle_id(&line.attributes) {
+            None => continue,
+            Some(id) => id,
+        };
+        match toggles.last_mut() {
+            Some(toggle) if toggle.start + toggle.len == line.start && toggle.id == id => toggle.len += line.len,
+            _ => toggles.push(DocumentToggle {
+                id: id.to_owned(),
+                start: line.start,
+                len: line.len,
+                summary: line.text,
+            }),
+        }
+    }
+    toggles
+}
+
+// The icon and the color of the callout that the line is in.
+pub(crate) fn callout_key(attributes: &RichTextAttributes) -> Option<(&str, Option<&str>)> {
+    let value = |key: &RichTextAttributeKey| attributes.get(key).and_then(|value| value.0.as_deref());
+    Some((
+        value(&RichTextAttributeKey::Callout)?,
+        value(&RichTextAttributeKey::CalloutColor),
+    ))
+}
+
+// The block that the exporters write around the blocks of the consecutive
+// lines of a callout or a toggle. A toggle in a callout is written as a toggle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum BlockWrapper {
+    Callout { icon: String, color: Option<String> },
+    Toggle(String),
+}
+
+pub(crate) fn block_wrapper(attributes: &RichTextAttributes) -> Option<BlockWrapper> {
+    if let Some(id) = toggle_id(attributes) {
+        return Some(BlockWrapper::Toggle(id.to_owned()));
+    }
+    callout_key(attributes).map(|(icon, color)| BlockWrapper::Callout {
+        icon: icon.to_owned(),
+        color: color.map(|color| color.to_owned()),
+    })
+}
+
+pub(crate) fn toggle_id(attributes: &RichTextAttributes) -> Option<&str> {
+    attributes
+        .get(&RichTextAttributeKey::Toggle)
+        .and_then(|value| value.0.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::block::{read_callouts, read_toggles};
+    use lib_ot::{
+        core::DeltaBuilder,
+        rich_text::{RichTextAttribute, RichTextAttributes},
+    };
+
+    fn attributes(attributes: Vec<RichTextAttribute>) -> RichTextAttributes {
+        let mut result = RichTextAttributes::new();
+        attributes.into_iter().for_each(|attribute| result.add(attribute));
+        result
+    }
+
+    #[test]
+    fn block_read_callouts_and_toggles() {
+        let tip = attributes(vec![
+            RichTextAttribute::Callout("💡"),
+            RichTextAttribute::CalloutColor("yellow".to_owned()),
+        ]);
+        let delta = DeltaBuilder::new()
+            .insert("tip")
+            .insert_with_attributes("\n", tip.clone())
+            .insert("more")
+            .insert_with_attributes("\n", tip)
+            .insert("warning")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Callout("⚠️")]))
+            .insert("summary")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Toggle("a")]))
+            .insert("body")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Toggle("a")]))
+            .insert("other")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Toggle("b")]))
+            .build();
+
+        let callouts = read_callouts(&delta);
+        assert_eq!(callouts.len(), 2);
+        assert_eq!((callouts[0].start, callouts[0].len), (0, 9));
+        assert_eq!(callouts[0].color.as_deref(), Some("yellow"));
+        assert_eq!(callouts[1].color, None);
+
+        let toggles = read_toggles(&delta);
+        assert_eq!(toggles.len(), 2);
+        assert_eq!(toggles[0].id, "a");
+        assert_eq!(toggles[0].summary, "summary");
+        assert_eq!((toggles[0].start, toggles[0].len), (17, 13));
+        assert_eq!(toggles[1].summary, "other");
+    }
+}
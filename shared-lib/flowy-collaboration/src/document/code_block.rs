@@ -0,0 +1,94 @@
+use crate::document::table::{delta_lines, DeltaLine};
+use lib_ot::rich_text::{RichTextAttributeKey, RichTextDelta};
+
+/// The lines around an index that are formatted as code. The text includes
+/// the newline of each line, the offsets are in utf16 code units.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    pub start: usize,
+    pub len: usize,
+    pub text: String,
+    pub language: String,
+}
+
+// The language of the block is the one of its first line that has one, the
+// lines that were added to the block keep the language of the line they were
+// split from.
+pub fn read_code_block(delta: &RichTextDelta, index: usize) -> Option<CodeBlock> {
+    let lines = delta_lines(delta);
+    let position = lines
+        .iter()
+        .position(|line| line.start <= index && index < line.start + line.len)?;
+    if !is_code(&lines[position]) {
+        return None;
+    }
+
+    let first = lines[..position]
+        .iter()
+        .rposition(|line| !is_code(line))
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let last = lines[position..]
+        .iter()
+        .position(|line| !is_code(line))
+        .map(|i| position + i)
+        .unwrap_or_else(|| lines.len());
+    let block = &lines[first..last];
+    let language = block
+        .iter()
+        .find_map(|line| {
+            line.attributes
+                .get(&RichTextAttributeKey::CodeLanguage)
+                .and_then(|value| value.0.clone())
+        })
+        .unwrap_or_default();
+    let text = block.iter().fold(String::new(), |mut text, line| {
+        text.push_str(&line.text);
+        text.push('\n');
+        text
+    });
+    Some(CodeBlock {
+        start: block[0].start,
+        len: block.iter().map(|line| line.len).sum(),
+        text,
+        language,
+    })
+}
+
+fn is_code(line: &DeltaLine) -> bool {
+    line.attributes
+        .get(&RichTextAttributeKey::CodeBlock)
+        .map(|value| value.0.is_some())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::code_block::read_code_block;
+    use lib_ot::{
+        core::DeltaBuilder,
+        rich_text::{RichTextAttribute, RichTextAttributes},
+    };
+
+    #[test]
+    fn code_block_read_around_index() {
+        let mut code = RichTextAttributes::new();
+        code.add(RichTextAttribute::CodeBlock(true));
+        code.add(RichTextAttribute::CodeLanguage("rust"));
+        let delta = DeltaBuilder::new()
+            .insert("text\n")
+            .insert("fn a() {}")
+            .insert_with_attributes("\n", code.clone())
+            .insert("}")
+            .insert_with_attributes("\n", code)
+            .insert("text\n")
+            .build();
+
+        assert!(read_code_block(&delta, 2).is_none());
+        let block = read_code_block(&delta, 16).unwrap();
+        assert_eq!(block.start, 5);
+        assert_eq!(block.len, 12);
+        assert_eq!(block.text, "fn a() {}\n}\n");
+        assert_eq!(block.language, "rust");
+    }
+}
@@ -1,7 +1,12 @@
 use crate::{
     document::{
+        block::{read_callouts, read_toggles, DocumentCallout, DocumentToggle},
+        code_block::{read_code_block, CodeBlock},
         default::initial_delta,
+        embed::{embed_delta, read_embeds, DocumentEmbed},
+        equation::{equation_delta, read_equations, DocumentEquation, EquationEdit},
         history::{History, UndoResult},
+        table::{read_tables, table_delta, DocumentTable, TableEdit},
         view::{View, RECORD_THRESHOLD},
     },
     errors::CollaborateError,
@@ -154,6 +159,42 @@ impl Document {
         Ok(delta)
     }
 
+    pub fn edit_table(&mut self, edit: TableEdit) -> Result<RichTextDelta, CollaborateError> {
+        let delta = table_delta(&self.delta, &edit)?;
+        if !delta.is_empty() {
+            let _ = self.compose_delta(delta.clone())?;
+        }
+        Ok(delta)
+    }
+
+    pub fn tables(&self) -> Vec<DocumentTable> { read_tables(&self.delta) }
+
+    pub fn edit_equation(&mut self, edit: EquationEdit) -> Result<RichTextDelta, CollaborateError> {
+        if let EquationEdit::Insert { index, .. } = &edit {
+            let _ = validate_interval(&self.delta, &Interval::new(*index, *index))?;
+        }
+        let delta = equation_delta(&self.delta, &edit)?;
+        let _ = self.compose_delta(delta.clone())?;
+        Ok(delta)
+    }
+
+    pub fn equations(&self) -> Vec<DocumentEquation> { read_equations(&self.delta) }
+
+    pub fn embed_link(&mut self, interval: Interval, url: &str) -> Result<RichTextDelta, CollaborateError> {
+        let _ = validate_interval(&self.delta, &interval)?;
+        let delta = embed_delta(interval, url)?;
+        let _ = self.compose_delta(delta.clone())?;
+        Ok(delta)
+    }
+
+    pub fn embeds(&self) -> Vec<DocumentEmbed> { read_embeds(&self.delta) }
+
+    pub fn code_block(&self, index: usize) -> Option<CodeBlock> { read_code_block(&self.delta, index) }
+
+    pub fn callouts(&self) -> Vec<DocumentCallout> { read_callouts(&self.delta) }
+
+    pub fn toggles(&self) -> Vec<DocumentToggle> { read_toggles(&self.delta) }
+
     pub fn can_undo(&self) -> bool { self.history.can_undo() }
 
     pub fn can_redo(&self) -> bool { self.history.can_redo() }
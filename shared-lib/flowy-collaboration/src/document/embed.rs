@@ -0,0 +1,71 @@
+use crate::{
+    document::equation::{read_placeholders, EQUATION_PLACEHOLDER},
+    errors::{CollaborateError, CollaborateResult},
+};
+use lib_ot::{
+    core::{DeltaBuilder, Interval},
+    rich_text::{RichTextAttribute, RichTextAttributeKey, RichTextDelta},
+};
+use url::Url;
+
+// An embed is a placeholder like an equation, with the url in its embed
+// attribute. The preview of the url isn't part of the document, each device
+// fetches it, so the users who turned the previews off never request the url.
+pub const EMBED_PLACEHOLDER: char = EQUATION_PLACEHOLDER;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentEmbed {
+    pub index: usize,
+    pub url: String,
+}
+
+pub fn validate_embed_url(url: &str) -> CollaborateResult<()> {
+    let parsed = Url::parse(url).map_err(|e| CollaborateError::invalid_embed_url().context(e))?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err(CollaborateError::invalid_embed_url().context(format!("{} isn't an http or https url", url)));
+    }
+    Ok(())
+}
+
+pub fn read_embeds(delta: &RichTextDelta) -> Vec<DocumentEmbed> {
+    read_placeholders(delta, &RichTextAttributeKey::Embed)
+        .into_iter()
+        .map(|(index, url)| DocumentEmbed { index, url })
+        .collect()
+}
+
+// Replaces the text in the interval, e.g. the link that was pasted, with the
+// embed of the url.
+pub(crate) fn embed_delta(interval: Interval, url: &str) -> CollaborateResult<RichTextDelta> {
+    let _ = validate_embed_url(url)?;
+    let mut builder = DeltaBuilder::new().retain(interval.start());
+    if !interval.is_empty() {
+        builder = builder.delete(interval.size());
+    }
+    Ok(builder
+        .insert_with_attributes(&EMBED_PLACEHOLDER.to_string(), RichTextAttribute::Embed(url).into())
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::embed::{embed_delta, read_embeds, validate_embed_url};
+    use lib_ot::core::{DeltaBuilder, Interval};
+
+    #[test]
+    fn embed_replace_the_pasted_link() {
+        let url = "https://appflowy.io";
+        let delta = DeltaBuilder::new().insert(&format!("see {}\n", url)).build();
+        let embed = embed_delta(Interval::new(4, 4 + url.len()), url).unwrap();
+        let delta = delta.compose(&embed).unwrap();
+        assert_eq!(delta.utf16_target_len, 6);
+
+        let embeds = read_embeds(&delta);
+        assert_eq!(embeds.len(), 1);
+        assert_eq!(embeds[0].index, 4);
+        assert_eq!(embeds[0].url, url);
+
+        assert!(validate_embed_url("ftp://appflowy.io").is_err());
+        assert!(validate_embed_url("appflowy.io").is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::errors::{CollaborateError, CollaborateResult};
+use lib_ot::{
+    core::{DeltaBuilder, Operation},
+    rich_text::{RichTextAttribute, RichTextAttributeKey, RichTextDelta},
+};
+
+// An equation is this character with the LaTeX in its equation attribute. It's
+// one code unit, so the concurrent edits can't split it, and the edits of the
+// LaTeX replace the attribute as a whole instead of merging the text of two
+// users into an invalid equation.
+pub const EQUATION_PLACEHOLDER: char = '\u{FFFC}';
+
+pub const MAX_EQUATION_LEN: usize = 4096;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentEquation {
+    pub index: usize,
+    pub latex: String,
+}
+
+#[derive(Clone, Debug)]
+pub enum EquationEdit {
+    Insert { index: usize, latex: String },
+    Update { index: usize, latex: String },
+}
+
+// Checks the LaTeX that the exporters can't write otherwise: the braces and the
+// environments must be closed and a $ must be escaped, or the Markdown would
+// end the equation there.
+pub fn validate_equation(latex: &str) -> CollaborateResult<()> {
+    let invalid = |msg: &str| Err(CollaborateError::invalid_equation().context(msg));
+    if latex.trim().is_empty() {
+        return invalid("The equation is empty");
+    }
+    if latex.len() > MAX_EQUATION_LEN {
+        return invalid("The equation is too long");
+    }
+
+    let mut groups: Vec<String> = vec![];
+    let mut chars = latex.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let mut command = String::new();
+                while let Some(c) = chars.peek().filter(|c| c.is_ascii_alphabetic()) {
+                    command.push(*c);
+                    chars.next();
+                }
+                if command.is_empty() {
+                    // A control symbol, e.g. \{ or \$.
+                    if chars.next().is_none() {
+                        return invalid("The equation ends with a backslash");
+                    }
+                    continue;
+                }
+                if command == "begin" || command == "end" {
+                    let name = read_group(&mut chars).ok_or_else(|| {
+                        CollaborateError::invalid_equation().context(format!("\\{} has no environment", command))
+                    })?;
+                    if command == "begin" {
+                        groups.push(name);
+                    } else if groups.pop().as_ref() != Some(&name) {
+                        return invalid(&format!("\\end{{{}}} doesn't close the last environment", name));
+                    }
+                }
+            },
+            '{' => groups.push("{".to_owned()),
+            '}' => {
+                if groups.pop().as_deref() != Some("{") {
+                    return invalid("The braces aren't balanced");
+                }
+            },
+            '$' => return invalid("The $ must be escaped"),
+            EQUATION_PLACEHOLDER => return invalid("The equation contains a placeholder"),
+            _ => {},
+        }
+    }
+    match groups.last() {
+        None => Ok(()),
+        Some(group) if group == "{" => invalid("The braces aren't balanced"),
+        Some(group) => invalid(&format!("The environment {} isn't closed", group)),
+    }
+}
+
+fn read_group<I: Iterator<Item = char>>(chars: &mut std::iter::Peekable<I>) -> Option<String> {
+    if chars.next()? != '{' {
+        return None;
+    }
+    let name = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+    match name.trim().is_empty() {
+        true => None,
+        false => Some(name),
+    }
+}
+
+pub fn read_equations(delta: &RichTextDelta) -> Vec<DocumentEquation> {
+    read_placeholders(delta, &RichTextAttributeKey::Equation)
+        .into_iter()
+        .map(|(index, latex)| DocumentEquation { index, latex })
+        .collect()
+}
+
+// Returns the index and the value of the key of each placeholder that has the
+// key. The placeholders that were merged into one op, e.g. two equal equations
+// next to each other, are read as one each.
+pub(crate) fn read_placeholders(delta: &RichTextDelta, key: &RichTextAttributeKey) -> Vec<(usize, String)> {
+    let mut placeholders = vec![];
+    let mut index = 0;
+    for op in &delta.ops {
+        if let Operation::Insert(insert) = op {
+            let value = insert.attributes.get(key).and_then(|value| value.0.clone());
+            for c in insert.s.chars() {
+                if let (Some(value), EQUATION_PLACEHOLDER) = (&value, c) {
+                    placeholders.push((index, value.clone()));
+                }
+                index += c.len_utf16();
+            }
+        }
+    }
+    placeholders
+}
+
+pub(crate) fn equation_delta(delta: &RichTextDelta, edit: &EquationEdit) -> CollaborateResult<RichTextDelta> {
+    match edit {
+        EquationEdit::Insert { index, latex } => {
+            let _ = validate_equation(latex)?;
+            Ok(DeltaBuilder::new()
+                .retain(*index)
+                .insert_with_attributes(
+                    &EQUATION_PLACEHOLDER.to_string(),
+                    RichTextAttribute::Equation(latex).into(),
+                )
+                .build())
+        },
+        EquationEdit::Update { index, latex } => {
+            let _ = validate_equation(latex)?;
+            if !read_equations(delta).iter().any(|equation| equation.index == *index) {
+                return Err(CollaborateError::record_not_found().context(format!("There is no equation at {}", index)));
+            }
+            Ok(DeltaBuilder::new()
+                .retain(*index)
+                .retain_with_attributes(1, RichTextAttribute::Equation(latex).into())
+                .build())
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::equation::{equation_delta, read_equations, validate_equation, EquationEdit};
+    use lib_ot::core::DeltaBuilder;
+
+    #[test]
+    fn equation_validate_latex() {
+        assert!(validate_equation("\\frac{a}{b} + \\{x\\}").is_ok());
+        assert!(validate_equation("\\begin{matrix} a & b \\end{matrix}").is_ok());
+        assert!(validate_equation("  ").is_err());
+        assert!(validate_equation("\\frac{a}{b").is_err());
+        assert!(validate_equation("a}").is_err());
+        assert!(validate_equation("\\begin{matrix} a \\end{cases}").is_err());
+        assert!(validate_equation("a $ b").is_err());
+        assert!(validate_equation("a \\").is_err());
+    }
+
+    #[test]
+    fn equation_insert_and_update() {
+        let mut delta = DeltaBuilder::new().insert("ab\n").build();
+        let insert = EquationEdit::Insert {
+            index: 1,
+            latex: "x^2".to_owned(),
+        };
+        delta = delta.compose(&equation_delta(&delta, &insert).unwrap()).unwrap();
+        let update = EquationEdit::Update {
+            index: 1,
+            latex: "y^2".to_owned(),
+        };
+        delta = delta.compose(&equation_delta(&delta, &update).unwrap()).unwrap();
+
+        let equations = read_equations(&delta);
+        assert_eq!(equations.len(), 1);
+        assert_eq!(equations[0].index, 1);
+        assert_eq!(equations[0].latex, "y^2");
+
+        let update_text = EquationEdit::Update {
+            index: 0,
+            latex: "z".to_owned(),
+        };
+        assert!(equation_delta(&delta, &update_text).is_err());
+    }
+}
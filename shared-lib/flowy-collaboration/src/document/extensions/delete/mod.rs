@@ -1,5 +1,7 @@
 mod default_delete;
 mod preserve_line_format_merge;
+mod preserve_table_cell;
 
 pub use default_delete::*;
 pub use preserve_line_format_merge::*;
+pub use preserve_table_cell::*;
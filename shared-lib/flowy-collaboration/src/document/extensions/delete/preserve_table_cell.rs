@@ -0,0 +1,53 @@
+use crate::document::{table::delta_lines, DeleteExt};
+use lib_ot::{
+    core::{DeltaBuilder, Interval},
+    rich_text::{plain_attributes, RichTextDelta},
+};
+
+// The newline that ends a table cell isn't deleted with the text around it,
+// otherwise the cell would be merged into the next one. The rows and the
+// columns are removed with the table edits.
+pub struct PreserveTableCellOnDelete {}
+impl DeleteExt for PreserveTableCellOnDelete {
+    fn ext_name(&self) -> &str { "PreserveTableCellOnDelete" }
+
+    fn apply(&self, delta: &RichTextDelta, interval: Interval) -> Option<RichTextDelta> {
+        if interval.is_empty() {
+            return None;
+        }
+
+        let lines = delta_lines(delta);
+        let cell_ends = lines
+            .iter()
+            .enumerate()
+            .filter(|(i, line)| {
+                let newline = line.start + line.len - 1;
+                if newline < interval.start || newline >= interval.end {
+                    return false;
+                }
+                match line.cell_key() {
+                    None => false,
+                    Some(key) => lines.get(i + 1).and_then(|next| next.cell_key()) != Some(key),
+                }
+            })
+            .map(|(_, line)| line.start + line.len - 1)
+            .collect::<Vec<_>>();
+        if cell_ends.is_empty() {
+            return None;
+        }
+
+        let mut new_delta = DeltaBuilder::new().retain(interval.start).build();
+        let mut offset = interval.start;
+        for newline in cell_ends {
+            if newline > offset {
+                new_delta.delete(newline - offset);
+            }
+            new_delta.retain(1, plain_attributes());
+            offset = newline + 1;
+        }
+        if interval.end > offset {
+            new_delta.delete(interval.end - offset);
+        }
+        Some(new_delta)
+    }
+}
@@ -4,7 +4,10 @@ use lib_ot::{
 };
 
 use crate::{
-    document::{extensions::helper::line_break, FormatExt},
+    document::{
+        extensions::helper::{block_format_attributes, line_break},
+        FormatExt,
+    },
     util::find_newline,
 };
 
@@ -41,7 +44,7 @@ impl FormatExt for ResolveBlockFormat {
                 None => new_delta.retain(op.len(), plain_attributes()),
                 Some(line_break) => {
                     new_delta.retain(line_break, plain_attributes());
-                    new_delta.retain(1, attribute.clone().into());
+                    new_delta.retain(1, block_format_attributes(attribute));
                     break;
                 },
             }
@@ -1,9 +1,26 @@
 use crate::util::find_newline;
 use lib_ot::{
     core::RichTextOperation,
-    rich_text::{plain_attributes, AttributeScope, RichTextAttribute, RichTextDelta},
+    rich_text::{
+        plain_attributes,
+        AttributeScope,
+        RichTextAttribute,
+        RichTextAttributeKey,
+        RichTextAttributes,
+        RichTextDelta,
+    },
 };
 
+// The attributes that the block format sets on the newlines. Removing the
+// callout removes its color too, the color means nothing without it.
+pub(crate) fn block_format_attributes(attribute: &RichTextAttribute) -> RichTextAttributes {
+    let mut attributes: RichTextAttributes = attribute.clone().into();
+    if attribute.key == RichTextAttributeKey::Callout && attribute.value.0.is_none() {
+        attributes.delete(&RichTextAttributeKey::CalloutColor);
+    }
+    attributes
+}
+
 pub(crate) fn line_break(
     op: &RichTextOperation,
     attribute: &RichTextAttribute,
@@ -22,7 +39,7 @@ pub(crate) fn line_break(
             },
             AttributeScope::Block => {
                 new_delta.retain(line_break - start, plain_attributes());
-                new_delta.retain(1, attribute.clone().into());
+                new_delta.retain(1, block_format_attributes(attribute));
             },
             _ => {
                 log::error!("Unsupported parser line break for {:?}", scope);
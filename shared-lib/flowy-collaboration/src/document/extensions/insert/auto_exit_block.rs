@@ -23,8 +23,9 @@ impl InsertExt for AutoExitBlock {
         let next = iter.next_op()?;
         let mut attributes = next.get_attributes();
 
+        // The empty lines of a table cell are kept, the cell can't be left.
         let block_attributes = attributes_except_header(&next);
-        if block_attributes.is_empty() {
+        if block_attributes.is_empty() || block_attributes.contains_key(&RichTextAttributeKey::Table) {
             return None;
         }
 
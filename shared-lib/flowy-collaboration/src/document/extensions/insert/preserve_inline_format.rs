@@ -22,7 +22,10 @@ impl InsertExt for PreserveInlineFormat {
             return None;
         }
 
+        // The text typed next to an equation or an embed isn't part of it.
         let mut attributes = prev.get_attributes();
+        attributes.remove(RichTextAttributeKey::Equation);
+        attributes.remove(RichTextAttributeKey::Embed);
         if attributes.is_empty() || !attributes.contains_key(&RichTextAttributeKey::Link) {
             return Some(
                 DeltaBuilder::new()
@@ -0,0 +1,384 @@
+use crate::document::{
+    block::{block_wrapper, BlockWrapper},
+    embed::EMBED_PLACEHOLDER,
+    equation::EQUATION_PLACEHOLDER,
+};
+use latex2mathml::{latex_to_mathml, DisplayStyle};
+use lib_ot::{
+    core::Operation,
+    rich_text::{RichTextAttributeKey, RichTextAttributes, RichTextDelta},
+};
+
+// Converts the document to HTML. The href of each link goes through
+// `rewrite_link`, which returns the href to write, or None to write the text
+// without the link, e.g. when the linked page is not exported with it.
+pub fn delta_to_html<F>(delta: &RichTextDelta, rewrite_link: F) -> String
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut writer = HtmlWriter {
+        rewrite_link,
+        output: String::new(),
+        segments: vec![],
+        container: None,
+        wrapper: None,
+    };
+    for op in &delta.ops {
+        if let Operation::Insert(insert) = op {
+            let mut lines = insert.s.split('\n').peekable();
+            while let Some(text) = lines.next() {
+                if !text.is_empty() {
+                    writer.segments.push((text.to_owned(), insert.attributes.clone()));
+                }
+
+                // The block attributes of a line are stored in its trailing newline.
+                if lines.peek().is_some() {
+                    writer.end_line(&insert.attributes);
+                }
+            }
+        }
+    }
+    writer.finish()
+}
+
+// The element that the consecutive lines of a list, a quote or a code block
+// are written in.
+#[derive(Clone, Copy, PartialEq)]
+enum Container {
+    UnorderedList,
+    OrderedList,
+    Quote,
+    Code,
+}
+
+impl Container {
+    fn start_tag(&self) -> &'static str {
+        match self {
+            Container::UnorderedList => "<ul>\n",
+            Container::OrderedList => "<ol>\n",
+            Container::Quote => "<blockquote>\n",
+            Container::Code => "<pre><code>",
+        }
+    }
+
+    fn end_tag(&self) -> &'static str {
+        match self {
+            Container::UnorderedList => "</ul>\n",
+            Container::OrderedList => "</ol>\n",
+            Container::Quote => "</blockquote>\n",
+            Container::Code => "</code></pre>\n",
+        }
+    }
+}
+
+struct HtmlWriter<F> {
+    rewrite_link: F,
+    output: String,
+    segments: Vec<(String, RichTextAttributes)>,
+    container: Option<Container>,
+    wrapper: Option<BlockWrapper>,
+}
+
+impl<F> HtmlWriter<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn end_line(&mut self, attributes: &RichTextAttributes) {
+        let segments = std::mem::take(&mut self.segments);
+        let wrapper = block_wrapper(attributes);
+        if wrapper != self.wrapper {
+            self.close_wrapper();
+            if let Some(BlockWrapper::Callout { icon, color }) = &wrapper {
+                let class = match color {
+                    None => "callout".to_owned(),
+                    Some(color) => format!("callout callout-{}", escape_html(color)),
+                };
+                self.output.push_str(&format!(
+                    "<aside class=\"{}\">\n<span class=\"callout-icon\">{}</span>\n",
+                    class,
+                    escape_html(icon)
+                ));
+            }
+            let is_toggle = matches!(wrapper, Some(BlockWrapper::Toggle(_)));
+            self.wrapper = wrapper;
+
+            // The first line of a toggle is its summary.
+            if is_toggle {
+                let summary = segments
+                    .iter()
+                    .map(|(text, attributes)| self.format_inline(text, attributes))
+                    .collect::<String>();
+                self.output
+                    .push_str(&format!("<details>\n<summary>{}</summary>\n", summary));
+                return;
+            }
+        }
+
+        if attribute_value(attributes, &RichTextAttributeKey::CodeBlock).is_some() {
+            let text = segments.into_iter().map(|(text, _)| text).collect::<String>();
+            self.open(Container::Code);
+            self.output.push_str(&escape_html(&text));
+            self.output.push('\n');
+            return;
+        }
+
+        let text = segments
+            .iter()
+            .map(|(text, attributes)| self.format_inline(text, attributes))
+            .collect::<String>();
+        if let Some(level) = attribute_value(attributes, &RichTextAttributeKey::Header) {
+            let level = parse_usize(level).clamp(1, 6);
+            self.close();
+            self.output.push_str(&format!("<h{}>{}</h{}>\n", level, text, level));
+        } else if let Some(list) = attribute_value(attributes, &RichTextAttributeKey::List) {
+            let indent = attribute_value(attributes, &RichTextAttributeKey::Indent).map_or(0, parse_usize);
+            let style = match indent {
+                0 => "".to_owned(),
+                _ => format!(" style=\"margin-left: {}em\"", indent * 2),
+            };
+            let (container, item) = match list {
+                "ordered" => (Container::OrderedList, format!("<li{}>{}</li>", style, text)),
+                "checked" => (
+                    Container::UnorderedList,
+                    format!(
+                        "<li class=\"checked\"{}><input type=\"checkbox\" checked disabled> {}</li>",
+                        style, text
+                    ),
+                ),
+                "unchecked" => (
+                    Container::UnorderedList,
+                    format!(
+                        "<li class=\"unchecked\"{}><input type=\"checkbox\" disabled> {}</li>",
+                        style, text
+                    ),
+                ),
+                _ => (Container::UnorderedList, format!("<li{}>{}</li>", style, text)),
+            };
+            self.open(container);
+            self.output.push_str(&item);
+            self.output.push('\n');
+        } else if attribute_value(attributes, &RichTextAttributeKey::BlockQuote).is_some() {
+            self.open(Container::Quote);
+            self.output.push_str(&format!("<p>{}</p>\n", text));
+        } else {
+            // The empty lines only separate the blocks.
+            self.close();
+            if !text.trim().is_empty() {
+                self.output.push_str(&format!("<p>{}</p>\n", text));
+            }
+        }
+    }
+
+    fn format_inline(&self, text: &str, attributes: &RichTextAttributes) -> String {
+        let mut content = match attribute_value(attributes, &RichTextAttributeKey::Equation) {
+            None => escape_html(text),
+            Some(latex) => text
+                .split(EQUATION_PLACEHOLDER)
+                .map(escape_html)
+                .collect::<Vec<_>>()
+                .join(&equation_to_mathml(latex)),
+        };
+        if let Some(url) = attribute_value(attributes, &RichTextAttributeKey::Embed) {
+            let bookmark = match (self.rewrite_link)(url) {
+                Some(href) => format!(
+                    "<a class=\"bookmark\" href=\"{}\">{}</a>",
+                    escape_html(&href),
+                    escape_html(url)
+                ),
+                None => escape_html(url),
+            };
+            content = text
+                .split(EMBED_PLACEHOLDER)
+                .map(escape_html)
+                .collect::<Vec<_>>()
+                .join(&bookmark);
+        }
+        if attribute_value(attributes, &RichTextAttributeKey::InlineCode).is_some() {
+            content = format!("<code>{}</code>", content);
+        }
+        if attribute_value(attributes, &RichTextAttributeKey::StrikeThrough).is_some() {
+            content = format!("<s>{}</s>", content);
+        }
+        if attribute_value(attributes, &RichTextAttributeKey::Underline).is_some() {
+            content = format!("<u>{}</u>", content);
+        }
+        if attribute_value(attributes, &RichTextAttributeKey::Italic).is_some() {
+            content = format!("<em>{}</em>", content);
+        }
+        if attribute_value(attributes, &RichTextAttributeKey::Bold).is_some() {
+            content = format!("<strong>{}</strong>", content);
+        }
+
+        let mut styles = vec![];
+        if let Some(color) = attribute_value(attributes, &RichTextAttributeKey::Color) {
+            styles.push(format!("color: {}", escape_html(color)));
+        }
+        if let Some(background) = attribute_value(attributes, &RichTextAttributeKey::Background) {
+            styles.push(format!("background-color: {}", escape_html(background)));
+        }
+        if !styles.is_empty() {
+            content = format!("<span style=\"{}\">{}</span>", styles.join("; "), content);
+        }
+
+        let href = attribute_value(attributes, &RichTextAttributeKey::Link).and_then(|link| (self.rewrite_link)(link));
+        if let Some(href) = href {
+            content = format!("<a href=\"{}\">{}</a>", escape_html(&href), content);
+        }
+        content
+    }
+
+    fn open(&mut self, container: Container) {
+        if self.container != Some(container) {
+            self.close();
+            self.output.push_str(container.start_tag());
+            self.container = Some(container);
+        }
+    }
+
+    fn close(&mut self) {
+        if let Some(container) = self.container.take() {
+            self.output.push_str(container.end_tag());
+        }
+    }
+
+    fn close_wrapper(&mut self) {
+        self.close();
+        match self.wrapper.take() {
+            None => {},
+            Some(BlockWrapper::Callout { .. }) => self.output.push_str("</aside>\n"),
+            Some(BlockWrapper::Toggle(_)) => self.output.push_str("</details>\n"),
+        }
+    }
+
+    fn finish(mut self) -> String {
+        // The text after the last newline doesn't belong to any block, which
+        // happens only if the document is not terminated by a newline.
+        if !self.segments.is_empty() {
+            self.end_line(&RichTextAttributes::default());
+        }
+        self.close_wrapper();
+        self.output
+    }
+}
+
+// Escapes the text of an element or of an attribute.
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+// The LaTeX that the converter doesn't support is written as it is, the
+// readers with a math script, e.g. MathJax, still render it.
+fn equation_to_mathml(latex: &str) -> String {
+    match latex_to_mathml(latex, DisplayStyle::Inline) {
+        Ok(mathml) => mathml,
+        Err(e) => {
+            tracing::trace!("Convert the equation to MathML failed: {:?}", e);
+            format!("<span class=\"math\">\\({}\\)</span>", escape_html(latex))
+        },
+    }
+}
+
+fn attribute_value<'a>(attributes: &'a RichTextAttributes, key: &RichTextAttributeKey) -> Option<&'a str> {
+    attributes.get(key).and_then(|value| value.0.as_deref())
+}
+
+fn parse_usize(value: &str) -> usize { value.parse::<usize>().unwrap_or(0) }
+
+#[cfg(test)]
+mod tests {
+    use crate::document::html::delta_to_html;
+    use lib_ot::{
+        core::DeltaBuilder,
+        rich_text::{RichTextAttribute, RichTextAttributes},
+    };
+
+    fn attributes(attributes: Vec<RichTextAttribute>) -> RichTextAttributes {
+        let mut result = RichTextAttributes::new();
+        attributes.into_iter().for_each(|attribute| result.add(attribute));
+        result
+    }
+
+    #[test]
+    fn html_inline_format() {
+        let delta = DeltaBuilder::new()
+            .insert("1 < 2 ")
+            .insert_with_attributes("bold", attributes(vec![RichTextAttribute::Bold(true)]))
+            .insert(" ")
+            .insert_with_attributes("page", attributes(vec![RichTextAttribute::Link("appflowy://view/1")]))
+            .insert(" ")
+            .insert_with_attributes("gone", attributes(vec![RichTextAttribute::Link("appflowy://view/2")]))
+            .insert("\n")
+            .build();
+        let html = delta_to_html(&delta, |link| match link {
+            "appflowy://view/1" => Some("1.html".to_owned()),
+            _ => None,
+        });
+        assert_eq!(
+            html,
+            "<p>1 &lt; 2 <strong>bold</strong> <a href=\"1.html\">page</a> gone</p>\n"
+        );
+    }
+
+    #[test]
+    fn html_equation() {
+        let delta = DeltaBuilder::new()
+            .insert("Area ")
+            .insert_with_attributes("\u{FFFC}", attributes(vec![RichTextAttribute::Equation("\\pi r^2")]))
+            .insert("\n")
+            .build();
+        let html = delta_to_html(&delta, |link| Some(link.to_owned()));
+        assert!(html.starts_with("<p>Area <math"));
+        assert!(!html.contains('\u{FFFC}'));
+    }
+
+    #[test]
+    fn html_callout_and_toggle() {
+        let delta = DeltaBuilder::new()
+            .insert("Tip")
+            .insert_with_attributes(
+                "\n",
+                attributes(vec![
+                    RichTextAttribute::Callout("💡"),
+                    RichTextAttribute::CalloutColor("yellow".to_owned()),
+                ]),
+            )
+            .insert("Summary")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Toggle("a")]))
+            .insert("one")
+            .insert_with_attributes(
+                "\n",
+                attributes(vec![RichTextAttribute::Toggle("a"), RichTextAttribute::Bullet(true)]),
+            )
+            .build();
+        assert_eq!(
+            delta_to_html(&delta, |link| Some(link.to_owned())),
+            "<aside class=\"callout callout-yellow\">\n<span class=\"callout-icon\">💡</span>\n<p>Tip</p>\n</aside>\n\
+             <details>\n<summary>Summary</summary>\n<ul>\n<li>one</li>\n</ul>\n</details>\n"
+        );
+    }
+
+    #[test]
+    fn html_block_format() {
+        let delta = DeltaBuilder::new()
+            .insert("Title")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Header(1)]))
+            .insert("one")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Ordered(true)]))
+            .insert("two")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Ordered(true)]))
+            .insert("done")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Checked(true)]))
+            .insert("let a = 1;")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::CodeBlock(true)]))
+            .build();
+        assert_eq!(
+            delta_to_html(&delta, |link| Some(link.to_owned())),
+            "<h1>Title</h1>\n<ol>\n<li>one</li>\n<li>two</li>\n</ol>\n<ul>\n<li class=\"checked\"><input \
+             type=\"checkbox\" checked disabled> done</li>\n</ul>\n<pre><code>let a = 1;\n</code></pre>\n"
+        );
+    }
+}
@@ -0,0 +1,612 @@
+use crate::document::{
+    block::{block_wrapper, BlockWrapper},
+    embed::EMBED_PLACEHOLDER,
+    equation::{validate_equation, EQUATION_PLACEHOLDER},
+};
+use lib_infra::uuid_string;
+use lib_ot::{
+    core::Operation,
+    rich_text::{RichTextAttribute, RichTextAttributeKey, RichTextAttributes, RichTextDelta},
+};
+
+// Converts the document to Markdown. The formats that Markdown can't express,
+// e.g. the color or the alignment, are dropped.
+pub fn delta_to_markdown(delta: &RichTextDelta) -> String {
+    let mut writer = MarkdownWriter::default();
+    for op in &delta.ops {
+        if let Operation::Insert(insert) = op {
+            let mut lines = insert.s.split('\n').peekable();
+            while let Some(text) = lines.next() {
+                if !text.is_empty() {
+                    writer.segments.push((text.to_owned(), insert.attributes.clone()));
+                }
+
+                // The block attributes of a line are stored in its trailing newline.
+                if lines.peek().is_some() {
+                    writer.end_line(&insert.attributes);
+                }
+            }
+        }
+    }
+    writer.finish()
+}
+
+// Converts Markdown to a document, it reads back what delta_to_markdown
+// writes. The syntax that the document can't express, e.g. the tables or the
+// images, is kept as text. A callout is read back as a quote, Markdown can't
+// tell them apart.
+pub fn markdown_to_delta(markdown: &str) -> RichTextDelta {
+    let mut delta = RichTextDelta::new();
+    let mut is_in_code = false;
+    let mut toggle: Option<String> = None;
+    for line in markdown.lines() {
+        let mut trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            is_in_code = !is_in_code;
+            continue;
+        }
+        if is_in_code {
+            delta.insert(line, RichTextAttributes::default());
+            delta.insert(
+                "\n",
+                with_toggle(block_attributes(vec![RichTextAttribute::CodeBlock(true)]), &toggle),
+            );
+            continue;
+        }
+
+        // The toggles are written as the <details> of GitHub, the summary may
+        // be on the line of the <details>.
+        if let Some(rest) = trimmed.strip_prefix("<details>") {
+            toggle = Some(uuid_string());
+            trimmed = rest.trim_start();
+        }
+        if trimmed.starts_with("</details>") {
+            toggle = None;
+            continue;
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let summary = trimmed
+            .strip_prefix("<summary>")
+            .and_then(|text| text.trim_end().strip_suffix("</summary>"))
+            .filter(|_| toggle.is_some());
+        let (text, mut attributes) = match summary {
+            Some(summary) => (summary, RichTextAttributes::default()),
+            None => parse_block(trimmed),
+        };
+        let indent = (line.len() - line.trim_start().len()) / 2;
+        if indent > 0 && attributes.contains_key(&RichTextAttributeKey::List) {
+            attributes.add(RichTextAttribute::Indent(indent));
+        }
+        let mut segments = vec![];
+        parse_inline(text, &RichTextAttributes::default(), None, &mut segments);
+        for (text, attributes) in segments {
+            delta.insert(&text, attributes);
+        }
+        delta.insert("\n", with_toggle(attributes, &toggle));
+    }
+
+    if delta.is_empty() {
+        delta.insert("\n", RichTextAttributes::default());
+    }
+    delta
+}
+
+fn block_attributes(attributes: Vec<RichTextAttribute>) -> RichTextAttributes {
+    let mut result = RichTextAttributes::new();
+    attributes.into_iter().for_each(|attribute| result.add(attribute));
+    result
+}
+
+fn with_toggle(mut attributes: RichTextAttributes, toggle: &Option<String>) -> RichTextAttributes {
+    if let Some(id) = toggle {
+        attributes.add(RichTextAttribute::Toggle(id));
+    }
+    attributes
+}
+
+// Returns the text of the line without its block marker, and the attributes
+// of its trailing newline.
+fn parse_block(line: &str) -> (&str, RichTextAttributes) {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if (1..=6).contains(&level) && line[level..].starts_with(' ') {
+        return (&line[level + 1..], block_attributes(vec![RichTextAttribute::Header(level)]));
+    }
+    if let Some(text) = line.strip_prefix('>') {
+        return (text.trim_start(), block_attributes(vec![RichTextAttribute::BlockQuote(true)]));
+    }
+
+    for marker in ["- ", "* ", "+ "].iter() {
+        if let Some(text) = line.strip_prefix(marker) {
+            if let Some(text) = text.strip_prefix("[ ] ") {
+                return (text, block_attributes(vec![RichTextAttribute::UnChecked(true)]));
+            }
+            if let Some(text) = text.strip_prefix("[x] ").or_else(|| text.strip_prefix("[X] ")) {
+                return (text, block_attributes(vec![RichTextAttribute::Checked(true)]));
+            }
+            return (text, block_attributes(vec![RichTextAttribute::Bullet(true)]));
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && line[digits..].starts_with(". ") {
+        return (&line[digits + 2..], block_attributes(vec![RichTextAttribute::Ordered(true)]));
+    }
+    (line, RichTextAttributes::default())
+}
+
+#[derive(Clone, Copy)]
+enum Emphasis {
+    Bold,
+    Italic,
+    StrikeThrough,
+    InlineCode,
+}
+
+impl Emphasis {
+    fn attribute(&self) -> RichTextAttribute {
+        match self {
+            Emphasis::Bold => RichTextAttribute::Bold(true),
+            Emphasis::Italic => RichTextAttribute::Italic(true),
+            Emphasis::StrikeThrough => RichTextAttribute::StrikeThrough(true),
+            Emphasis::InlineCode => RichTextAttribute::InlineCode(true),
+        }
+    }
+}
+
+// The longer markers are tried first, so that ** is not read as two *.
+const EMPHASIS_MARKERS: [(&str, Emphasis); 6] = [
+    ("**", Emphasis::Bold),
+    ("__", Emphasis::Bold),
+    ("~~", Emphasis::StrikeThrough),
+    ("`", Emphasis::InlineCode),
+    ("*", Emphasis::Italic),
+    ("_", Emphasis::Italic),
+];
+
+// Splits the text into segments by their inline formats. The underscores in
+// the middle of a word, e.g. snake_case, are not read as markers.
+fn parse_inline(
+    text: &str,
+    attributes: &RichTextAttributes,
+    mut prev: Option<char>,
+    segments: &mut Vec<(String, RichTextAttributes)>,
+) {
+    let mut plain = String::new();
+    let mut rest = text;
+    'outer: while let Some(c) = rest.chars().next() {
+        if c == '\\' {
+            if let Some(escaped) = rest[1..].chars().next().filter(|c| is_escapable(*c)) {
+                plain.push(escaped);
+                rest = &rest[1 + escaped.len_utf8()..];
+                prev = Some(escaped);
+                continue;
+            }
+        }
+
+        if let Some((latex, len)) = parse_equation(rest) {
+            let mut equation_attributes = attributes.clone();
+            equation_attributes.add(RichTextAttribute::Equation(latex));
+            flush(&mut plain, attributes, segments);
+            segments.push((EQUATION_PLACEHOLDER.to_string(), equation_attributes));
+            rest = &rest[len..];
+            prev = Some('$');
+            continue;
+        }
+
+        for (marker, emphasis) in EMPHASIS_MARKERS.iter() {
+            let is_intraword = marker.starts_with('_') && prev.map_or(false, char::is_alphanumeric);
+            let content = match rest.strip_prefix(marker) {
+                Some(after) if !is_intraword => after.find(marker).map(|end| &after[..end]),
+                _ => None,
+            };
+            if let Some(content) = content.filter(|content| !content.trim().is_empty()) {
+                let mut span_attributes = attributes.clone();
+                span_attributes.add(emphasis.attribute());
+                flush(&mut plain, attributes, segments);
+                match emphasis {
+                    Emphasis::InlineCode => segments.push((content.to_owned(), span_attributes)),
+                    _ => parse_inline(content, &span_attributes, prev, segments),
+                }
+                rest = &rest[marker.len() * 2 + content.len()..];
+                prev = content.chars().last();
+                continue 'outer;
+            }
+        }
+
+        if let Some((label, link, len)) = parse_link(rest) {
+            let mut link_attributes = attributes.clone();
+            link_attributes.add(RichTextAttribute::Link(link));
+            flush(&mut plain, attributes, segments);
+            parse_inline(label, &link_attributes, prev, segments);
+            rest = &rest[len..];
+            prev = Some(')');
+            continue;
+        }
+
+        plain.push(c);
+        rest = &rest[c.len_utf8()..];
+        prev = Some(c);
+    }
+    flush(&mut plain, attributes, segments);
+}
+
+// Returns the label, the link and the length of [label](link).
+fn parse_link(text: &str) -> Option<(&str, &str, usize)> {
+    let after = text.strip_prefix('[')?;
+    let label_end = after.find("](")?;
+    let link_start = label_end + 2;
+    let link_len = after[link_start..].find(')')?;
+    let label = &after[..label_end];
+    let link = &after[link_start..link_start + link_len];
+    if label.is_empty() || link.is_empty() {
+        return None;
+    }
+    Some((label, link, 1 + link_start + link_len + 1))
+}
+
+// Returns the LaTeX and the length of $latex$. As in Pandoc, the LaTeX can't
+// start or end with a whitespace, so the prices, e.g. $5 and $6, stay text.
+fn parse_equation(text: &str) -> Option<(&str, usize)> {
+    let after = text.strip_prefix('$')?;
+    let mut is_escaped = false;
+    let end = after.char_indices().find_map(|(i, c)| {
+        let is_end = c == '$' && !is_escaped;
+        is_escaped = c == '\\' && !is_escaped;
+        match is_end {
+            true => Some(i),
+            false => None,
+        }
+    })?;
+    let latex = &after[..end];
+    if latex.starts_with(char::is_whitespace) || latex.ends_with(char::is_whitespace) {
+        return None;
+    }
+    validate_equation(latex).ok()?;
+    Some((latex, 1 + end + 1))
+}
+
+fn flush(plain: &mut String, attributes: &RichTextAttributes, segments: &mut Vec<(String, RichTextAttributes)>) {
+    if !plain.is_empty() {
+        segments.push((std::mem::take(plain), attributes.clone()));
+    }
+}
+
+fn is_escapable(c: char) -> bool { matches!(c, '\\' | '`' | '*' | '_' | '~' | '[' | ']' | '#' | '$') }
+
+#[derive(Clone, Copy, PartialEq)]
+enum BlockKind {
+    Paragraph,
+    Header,
+    List,
+    Quote,
+    Code,
+}
+
+#[derive(Default)]
+struct MarkdownWriter {
+    output: String,
+    segments: Vec<(String, RichTextAttributes)>,
+    prev_kind: Option<BlockKind>,
+    ordered_index: usize,
+    wrapper: Option<BlockWrapper>,
+}
+
+impl MarkdownWriter {
+    fn end_line(&mut self, attributes: &RichTextAttributes) {
+        let segments = std::mem::take(&mut self.segments);
+        let kind = block_kind(attributes);
+
+        // The code blocks can't be quoted line by line, the code in a callout
+        // is written after it.
+        let wrapper = block_wrapper(attributes)
+            .filter(|wrapper| kind != BlockKind::Code || matches!(wrapper, BlockWrapper::Toggle(_)));
+        let is_first_line = wrapper != self.wrapper;
+        if is_first_line {
+            self.close_wrapper();
+            self.wrapper = wrapper;
+        }
+        if is_first_line && matches!(self.wrapper, Some(BlockWrapper::Toggle(_))) {
+            let summary = segments
+                .iter()
+                .map(|(text, attributes)| format_inline(text, attributes))
+                .collect::<String>();
+            if !self.output.is_empty() {
+                self.output.push('\n');
+            }
+            self.output
+                .push_str(&format!("<details>\n<summary>{}</summary>\n", summary.trim()));
+            return;
+        }
+
+        if kind == BlockKind::Code {
+            let text = segments.into_iter().map(|(text, _)| text).collect::<String>();
+            self.ordered_index = 0;
+            self.write_line(kind, text);
+            return;
+        }
+
+        let text = segments
+            .iter()
+            .map(|(text, attributes)| format_inline(text, attributes))
+            .collect::<String>();
+        let callout_icon = match &self.wrapper {
+            Some(BlockWrapper::Callout { icon, .. }) => Some(icon.clone()),
+            _ => None,
+        };
+        if text.trim().is_empty() && kind == BlockKind::Paragraph && callout_icon.is_none() {
+            return;
+        }
+        if kind != BlockKind::List {
+            self.ordered_index = 0;
+        }
+
+        let indent = "  ".repeat(attribute_value(attributes, &RichTextAttributeKey::Indent).map_or(0, parse_usize));
+        let line = match kind {
+            BlockKind::Header => {
+                let level = attribute_value(attributes, &RichTextAttributeKey::Header)
+                    .map_or(1, parse_usize)
+                    .clamp(1, 6);
+                format!("{} {}", "#".repeat(level), text)
+            },
+            BlockKind::List => match attribute_value(attributes, &RichTextAttributeKey::List) {
+                Some("ordered") => {
+                    self.ordered_index += 1;
+                    format!("{}{}. {}", indent, self.ordered_index, text)
+                },
+                Some("checked") => format!("{}- [x] {}", indent, text),
+                Some("unchecked") => format!("{}- [ ] {}", indent, text),
+                _ => format!("{}- {}", indent, text),
+            },
+            BlockKind::Quote if callout_icon.is_none() => format!("> {}", text),
+            _ => text,
+        };
+
+        // A callout is a quote that starts with its icon, its lines are all
+        // quoted so they stay in one quote.
+        match callout_icon {
+            Some(icon) if is_first_line => {
+                self.write_line(BlockKind::Quote, format!("> {} {}", icon, line).trim_end().to_owned())
+            },
+            Some(_) => self.write_line(BlockKind::Quote, format!("> {}", line).trim_end().to_owned()),
+            None => self.write_line(kind, line),
+        }
+    }
+
+    fn close_wrapper(&mut self) {
+        self.close_code();
+        if let Some(BlockWrapper::Toggle(_)) = self.wrapper.take() {
+            self.output.push_str("\n</details>\n");
+        }
+        // The next block is not continued into the wrapper, e.g. a quote after
+        // a callout.
+        self.prev_kind = None;
+        self.ordered_index = 0;
+    }
+
+    fn close_code(&mut self) {
+        if self.prev_kind == Some(BlockKind::Code) {
+            self.output.push_str("```\n");
+            self.prev_kind = None;
+        }
+    }
+
+    fn write_line(&mut self, kind: BlockKind, line: String) {
+        let continues_block = self.prev_kind == Some(kind) && kind != BlockKind::Paragraph && kind != BlockKind::Header;
+        if !continues_block {
+            self.close_code();
+            if !self.output.is_empty() {
+                self.output.push('\n');
+            }
+            if kind == BlockKind::Code {
+                self.output.push_str("```\n");
+            }
+        }
+
+        self.output.push_str(&line);
+        self.output.push('\n');
+        self.prev_kind = Some(kind);
+    }
+
+    fn finish(mut self) -> String {
+        // The text after the last newline doesn't belong to any block, which
+        // happens only if the document is not terminated by a newline.
+        if !self.segments.is_empty() {
+            self.end_line(&RichTextAttributes::default());
+        }
+
+        self.close_wrapper();
+        self.output
+    }
+}
+
+fn block_kind(attributes: &RichTextAttributes) -> BlockKind {
+    if attribute_value(attributes, &RichTextAttributeKey::CodeBlock).is_some() {
+        BlockKind::Code
+    } else if attribute_value(attributes, &RichTextAttributeKey::Header).is_some() {
+        BlockKind::Header
+    } else if attribute_value(attributes, &RichTextAttributeKey::List).is_some() {
+        BlockKind::List
+    } else if attribute_value(attributes, &RichTextAttributeKey::BlockQuote).is_some() {
+        BlockKind::Quote
+    } else {
+        BlockKind::Paragraph
+    }
+}
+
+fn format_inline(text: &str, attributes: &RichTextAttributes) -> String {
+    if let Some(latex) = attribute_value(attributes, &RichTextAttributeKey::Equation) {
+        return text
+            .split(EQUATION_PLACEHOLDER)
+            .map(escape)
+            .collect::<Vec<_>>()
+            .join(&format!("${}$", latex));
+    }
+    // Markdown has no embeds, the url is written as a link.
+    if let Some(url) = attribute_value(attributes, &RichTextAttributeKey::Embed) {
+        return text
+            .split(EMBED_PLACEHOLDER)
+            .map(escape)
+            .collect::<Vec<_>>()
+            .join(&format!("[{}]({})", escape(url), url));
+    }
+    if attribute_value(attributes, &RichTextAttributeKey::InlineCode).is_some() {
+        return format!("`{}`", text);
+    }
+
+    // Markdown doesn't allow the emphasis markers to be next to a whitespace, so
+    // the leading and trailing whitespaces are moved out of the markers.
+    let content = text.trim();
+    if content.is_empty() {
+        return text.to_owned();
+    }
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+
+    let mut content = escape(content);
+    if attribute_value(attributes, &RichTextAttributeKey::StrikeThrough).is_some() {
+        content = format!("~~{}~~", content);
+    }
+    if attribute_value(attributes, &RichTextAttributeKey::Italic).is_some() {
+        content = format!("_{}_", content);
+    }
+    if attribute_value(attributes, &RichTextAttributeKey::Bold).is_some() {
+        content = format!("**{}**", content);
+    }
+    if let Some(link) = attribute_value(attributes, &RichTextAttributeKey::Link) {
+        content = format!("[{}]({})", content, link);
+    }
+    format!("{}{}{}", leading, content, trailing)
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if is_escapable(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn attribute_value<'a>(attributes: &'a RichTextAttributes, key: &RichTextAttributeKey) -> Option<&'a str> {
+    attributes.get(key).and_then(|value| value.0.as_deref())
+}
+
+fn parse_usize(value: &str) -> usize { value.parse::<usize>().unwrap_or(0) }
+
+#[cfg(test)]
+mod tests {
+    use crate::document::{
+        block::read_toggles,
+        markdown::{delta_to_markdown, markdown_to_delta},
+    };
+    use lib_ot::{
+        core::DeltaBuilder,
+        rich_text::{RichTextAttribute, RichTextAttributes},
+    };
+
+    fn attributes(attributes: Vec<RichTextAttribute>) -> RichTextAttributes {
+        let mut result = RichTextAttributes::new();
+        attributes.into_iter().for_each(|attribute| result.add(attribute));
+        result
+    }
+
+    #[test]
+    fn markdown_inline_format() {
+        let delta = DeltaBuilder::new()
+            .insert("Hello ")
+            .insert_with_attributes("world ", attributes(vec![RichTextAttribute::Bold(true)]))
+            .insert_with_attributes("code", attributes(vec![RichTextAttribute::InlineCode(true)]))
+            .insert(" and ")
+            .insert_with_attributes("link", attributes(vec![RichTextAttribute::Link("https://appflowy.io")]))
+            .insert("\n")
+            .build();
+        assert_eq!(
+            delta_to_markdown(&delta),
+            "Hello **world** `code` and [link](https://appflowy.io)\n"
+        );
+    }
+
+    #[test]
+    fn markdown_block_format() {
+        let delta = DeltaBuilder::new()
+            .insert("Title")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Header(1)]))
+            .insert("one")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Ordered(true)]))
+            .insert("two")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Ordered(true)]))
+            .insert("done")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Checked(true)]))
+            .insert("let a = 1;")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::CodeBlock(true)]))
+            .build();
+        assert_eq!(
+            delta_to_markdown(&delta),
+            "# Title\n\n1. one\n2. two\n- [x] done\n\n```\nlet a = 1;\n```\n"
+        );
+    }
+
+    #[test]
+    fn markdown_to_delta_reads_back_the_markdown() {
+        let markdown = "# Title\n\nHello **world** `code` and [_link_](https://appflowy.io)\n\n1. one\n2. two\n  - \
+                        nested\n- [x] done\n- [ ] todo\n\n> quote\n\n```\nlet a = 1;\n```\n";
+        assert_eq!(delta_to_markdown(&markdown_to_delta(markdown)), markdown);
+    }
+
+    #[test]
+    fn markdown_equation() {
+        let delta = DeltaBuilder::new()
+            .insert("Area ")
+            .insert_with_attributes("\u{FFFC}", attributes(vec![RichTextAttribute::Equation("\\pi r^2")]))
+            .insert(" costs $5\n")
+            .build();
+        let markdown = delta_to_markdown(&delta);
+        assert_eq!(markdown, "Area $\\pi r^2$ costs \\$5\n");
+        assert_eq!(markdown_to_delta(&markdown), delta);
+    }
+
+    #[test]
+    fn markdown_callout_and_toggle() {
+        let tip = attributes(vec![
+            RichTextAttribute::Callout("💡"),
+            RichTextAttribute::CalloutColor("yellow".to_owned()),
+        ]);
+        let delta = DeltaBuilder::new()
+            .insert("Intro\nTip")
+            .insert_with_attributes("\n", tip.clone())
+            .insert("more")
+            .insert_with_attributes("\n", tip)
+            .insert("Summary")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Toggle("a")]))
+            .insert("body")
+            .insert_with_attributes("\n", attributes(vec![RichTextAttribute::Toggle("a")]))
+            .insert("after\n")
+            .build();
+        let markdown = delta_to_markdown(&delta);
+        assert_eq!(
+            markdown,
+            "Intro\n\n> 💡 Tip\n> more\n\n<details>\n<summary>Summary</summary>\n\nbody\n\n</details>\n\nafter\n"
+        );
+
+        let read = markdown_to_delta(&markdown);
+        assert_eq!(delta_to_markdown(&read), markdown);
+        let toggles = read_toggles(&read);
+        assert_eq!(toggles.len(), 1);
+        assert_eq!(toggles[0].summary, "Summary");
+        assert_eq!(toggles[0].len, 13);
+    }
+
+    #[test]
+    fn markdown_to_delta_unescapes_the_text() {
+        let delta = markdown_to_delta("\\# not a header \\*not italic\\* in snake_case\n");
+        assert_eq!(
+            delta.to_json(),
+            r#"[{"insert":"# not a header *not italic* in snake_case\n"}]"#
+        );
+    }
+}
@@ -1,12 +1,26 @@
 #![allow(clippy::module_inception)]
 
+pub use block::*;
+pub use code_block::*;
 pub use document::*;
+pub use embed::*;
+pub use equation::*;
 pub(crate) use extensions::*;
+pub use html::*;
+pub use markdown::*;
+pub use table::*;
 pub use view::*;
 
+mod block;
+mod code_block;
 mod data;
 pub mod default;
 mod document;
+mod embed;
+mod equation;
 mod extensions;
 pub mod history;
+mod html;
+mod markdown;
+mod table;
 mod view;
@@ -0,0 +1,460 @@
+use crate::errors::{CollaborateError, CollaborateResult};
+use lib_ot::{
+    core::{count_utf16_code_units, trim, Operation, NEW_LINE},
+    rich_text::{plain_attributes, RichTextAttribute, RichTextAttributeKey, RichTextAttributes, RichTextDelta},
+};
+
+/// A table of the document. Each line of a cell ends with a newline whose
+/// attributes hold the ids of the table, the row and the column of the cell,
+/// so the table is made of plain lines and the edits of it transform like any
+/// other text: two rows inserted at the same time both end up in the table,
+/// and a cell that is typed in while its row is deleted is deleted with it.
+///
+/// A row is made of the lines with its row id, in the order of its first line,
+/// so the cells that were inserted into a row at the same time as another row
+/// still belong to their own row. The order of the columns is merged from the
+/// order of the cells of the rows: a row that was inserted while a column was
+/// inserted is only missing the cell of the new column, see `cell`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentTable {
+    pub id: String,
+    pub start: usize,
+    pub len: usize,
+    pub column_ids: Vec<String>,
+    pub rows: Vec<DocumentTableRow>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentTableRow {
+    pub id: String,
+    pub cells: Vec<DocumentTableCell>,
+}
+
+// The offsets are in utf16 code units like the ones of the delta. The text of
+// a cell doesn't include its trailing newline.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentTableCell {
+    pub column_id: String,
+    pub start: usize,
+    pub len: usize,
+    pub text: String,
+    pub row_span: usize,
+    pub col_span: usize,
+}
+
+impl DocumentTable {
+    pub fn cell(&self, row_id: &str, column_id: &str) -> Option<&DocumentTableCell> {
+        self.rows
+            .iter()
+            .find(|row| row.id == row_id)
+            .and_then(|row| row.cells.iter().find(|cell| cell.column_id == column_id))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TableEdit {
+    InsertTable {
+        index: usize,
+        table_id: String,
+        row_ids: Vec<String>,
+        column_ids: Vec<String>,
+    },
+    InsertRow {
+        table_id: String,
+        row_index: usize,
+        row_id: String,
+    },
+    DeleteRow {
+        table_id: String,
+        row_id: String,
+    },
+    InsertColumn {
+        table_id: String,
+        column_index: usize,
+        column_id: String,
+    },
+    DeleteColumn {
+        table_id: String,
+        column_id: String,
+    },
+}
+
+pub fn read_tables(delta: &RichTextDelta) -> Vec<DocumentTable> {
+    let mut tables: Vec<DocumentTable> = vec![];
+    for line in delta_lines(delta) {
+        let (table_id, row_id, column_id) = match line.cell_key() {
+            None => continue,
+            Some(key) => key,
+        };
+
+        let is_same_table = matches!(
+            tables.last(),
+            Some(table) if table.id == table_id && table.start + table.len == line.start
+        );
+        if !is_same_table {
+            tables.push(DocumentTable {
+                id: table_id.to_owned(),
+                start: line.start,
+                len: 0,
+                column_ids: vec![],
+                rows: vec![],
+            });
+        }
+        let table = tables.last_mut().unwrap();
+        table.len += line.len;
+
+        let row = match table.rows.iter().position(|row| row.id == row_id) {
+            Some(position) => &mut table.rows[position],
+            None => {
+                table.rows.push(DocumentTableRow {
+                    id: row_id.to_owned(),
+                    cells: vec![],
+                });
+                table.rows.last_mut().unwrap()
+            },
+        };
+        match row.cells.last_mut() {
+            Some(cell) if cell.column_id == column_id && cell.start + cell.len == line.start => {
+                cell.text.push_str(NEW_LINE);
+                cell.text.push_str(&line.text);
+                cell.len += line.len;
+            },
+            _ => row.cells.push(DocumentTableCell {
+                column_id: column_id.to_owned(),
+                start: line.start,
+                len: line.len,
+                text: line.text.clone(),
+                row_span: 1,
+                col_span: 1,
+            }),
+        }
+        let cell = row.cells.last_mut().unwrap();
+        if let Some(span) = line.usize_attribute(&RichTextAttributeKey::RowSpan) {
+            cell.row_span = span;
+        }
+        if let Some(span) = line.usize_attribute(&RichTextAttributeKey::ColSpan) {
+            cell.col_span = span;
+        }
+    }
+
+    tables.iter_mut().for_each(merge_column_ids);
+    tables
+}
+
+// The delta that applies the edit to the document. It fails if the table of
+// the edit isn't in the document.
+pub fn table_delta(delta: &RichTextDelta, edit: &TableEdit) -> CollaborateResult<RichTextDelta> {
+    let mut edits = vec![];
+    match edit {
+        TableEdit::InsertTable {
+            index,
+            table_id,
+            row_ids,
+            column_ids,
+        } => {
+            if *index > delta.utf16_target_len {
+                return Err(CollaborateError::out_of_bound());
+            }
+            // The table starts on a line of its own.
+            let is_line_start = *index == 0 || delta_lines(delta).iter().any(|line| line.start + line.len == *index);
+            if !is_line_start {
+                edits.push(LineEdit::Insert(*index, NEW_LINE.to_owned()));
+            }
+            for row_id in row_ids {
+                for column_id in column_ids {
+                    edits.push(LineEdit::InsertCell(
+                        *index,
+                        cell_attributes(table_id, row_id, column_id),
+                    ));
+                }
+            }
+        },
+        TableEdit::InsertRow {
+            table_id,
+            row_index,
+            row_id,
+        } => {
+            let table = find_table(delta, table_id)?;
+            let index = match table.rows.get(*row_index).and_then(|row| row.cells.first()) {
+                None => table.start + table.len,
+                Some(cell) => cell.start,
+            };
+            for column_id in &table.column_ids {
+                edits.push(LineEdit::InsertCell(
+                    index,
+                    cell_attributes(table_id, row_id, column_id),
+                ));
+            }
+        },
+        TableEdit::DeleteRow { table_id, row_id } => {
+            let table = find_table(delta, table_id)?;
+            for row in table.rows.iter().filter(|row| &row.id == row_id) {
+                for cell in &row.cells {
+                    edits.push(LineEdit::Delete(cell.start, cell.len));
+                }
+            }
+        },
+        TableEdit::InsertColumn {
+            table_id,
+            column_index,
+            column_id,
+        } => {
+            let table = find_table(delta, table_id)?;
+            let next_column_ids = table.column_ids.iter().skip(*column_index).collect::<Vec<_>>();
+            for row in &table.rows {
+                let index = row
+                    .cells
+                    .iter()
+                    .find(|cell| next_column_ids.contains(&&cell.column_id))
+                    .or_else(|| row.cells.last())
+                    .map(|cell| match next_column_ids.contains(&&cell.column_id) {
+                        true => cell.start,
+                        false => cell.start + cell.len,
+                    })
+                    .unwrap_or(table.start + table.len);
+                edits.push(LineEdit::InsertCell(
+                    index,
+                    cell_attributes(table_id, &row.id, column_id),
+                ));
+            }
+        },
+        TableEdit::DeleteColumn { table_id, column_id } => {
+            let table = find_table(delta, table_id)?;
+            for row in &table.rows {
+                for cell in row.cells.iter().filter(|cell| &cell.column_id == column_id) {
+                    edits.push(LineEdit::Delete(cell.start, cell.len));
+                }
+            }
+        },
+    }
+    Ok(build_delta(edits))
+}
+
+fn find_table(delta: &RichTextDelta, table_id: &str) -> CollaborateResult<DocumentTable> {
+    read_tables(delta)
+        .into_iter()
+        .find(|table| table.id == table_id)
+        .ok_or_else(|| CollaborateError::record_not_found().context(format!("The table {} doesn't exist", table_id)))
+}
+
+fn cell_attributes(table_id: &str, row_id: &str, column_id: &str) -> RichTextAttributes {
+    let mut attributes = RichTextAttributes::new();
+    attributes.add(RichTextAttribute::Table(table_id));
+    attributes.add(RichTextAttribute::TableRow(row_id));
+    attributes.add(RichTextAttribute::TableCell(column_id));
+    attributes
+}
+
+// A column that isn't in the order yet goes after the column of the cell
+// before it in the row.
+fn merge_column_ids(table: &mut DocumentTable) {
+    let mut column_ids: Vec<String> = vec![];
+    for row in &table.rows {
+        let mut previous: Option<usize> = None;
+        for cell in &row.cells {
+            match column_ids.iter().position(|id| id == &cell.column_id) {
+                Some(position) => previous = Some(position),
+                None => {
+                    let position = previous.map(|position| position + 1).unwrap_or(0);
+                    column_ids.insert(position, cell.column_id.clone());
+                    previous = Some(position);
+                },
+            }
+        }
+    }
+    table.column_ids = column_ids;
+}
+
+enum LineEdit {
+    Insert(usize, String),
+    InsertCell(usize, RichTextAttributes),
+    Delete(usize, usize),
+}
+
+impl LineEdit {
+    fn index(&self) -> usize {
+        match self {
+            LineEdit::Insert(index, _) | LineEdit::InsertCell(index, _) | LineEdit::Delete(index, _) => *index,
+        }
+    }
+}
+
+// The edits at the same index are applied in the order they were added.
+fn build_delta(mut edits: Vec<LineEdit>) -> RichTextDelta {
+    edits.sort_by_key(|edit| edit.index());
+    let mut delta = RichTextDelta::new();
+    let mut offset = 0;
+    for edit in edits {
+        if edit.index() > offset {
+            delta.retain(edit.index() - offset, plain_attributes());
+            offset = edit.index();
+        }
+        match edit {
+            LineEdit::Insert(_, text) => {
+                if !text.is_empty() {
+                    delta.insert(&text, plain_attributes());
+                }
+            },
+            LineEdit::InsertCell(_, attributes) => delta.insert(NEW_LINE, attributes),
+            LineEdit::Delete(_, len) => {
+                delta.delete(len);
+                offset += len;
+            },
+        }
+    }
+    trim(&mut delta);
+    delta
+}
+
+// A line of the document, its len includes the trailing newline.
+pub(crate) struct DeltaLine {
+    pub(crate) start: usize,
+    pub(crate) len: usize,
+    pub(crate) text: String,
+    pub(crate) attributes: RichTextAttributes,
+}
+
+impl DeltaLine {
+    pub(crate) fn cell_key(&self) -> Option<(&str, &str, &str)> {
+        let value = |key: &RichTextAttributeKey| self.attributes.get(key).and_then(|value| value.0.as_deref());
+        Some((
+            value(&RichTextAttributeKey::Table)?,
+            value(&RichTextAttributeKey::TableRow)?,
+            value(&RichTextAttributeKey::TableCell)?,
+        ))
+    }
+
+    fn usize_attribute(&self, key: &RichTextAttributeKey) -> Option<usize> {
+        self.attributes
+            .get(key)
+            .and_then(|value| value.0.as_ref())
+            .and_then(|value| value.parse::<usize>().ok())
+    }
+}
+
+pub(crate) fn delta_lines(delta: &RichTextDelta) -> Vec<DeltaLine> {
+    let mut lines = vec![];
+    let mut start = 0;
+    let mut text = String::new();
+    for op in &delta.ops {
+        if let Operation::Insert(insert) = op {
+            let mut segments = insert.s.split(NEW_LINE).peekable();
+            while let Some(segment) = segments.next() {
+                text.push_str(segment);
+                // The block attributes of a line are stored in its trailing newline.
+                if segments.peek().is_some() {
+                    let len = count_utf16_code_units(&text) + 1;
+                    lines.push(DeltaLine {
+                        start,
+                        len,
+                        text: std::mem::take(&mut text),
+                        attributes: insert.attributes.clone(),
+                    });
+                    start += len;
+                }
+            }
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::table::{read_tables, table_delta, TableEdit};
+    use lib_ot::{
+        core::{DeltaBuilder, OperationTransformable},
+        rich_text::RichTextDelta,
+    };
+
+    fn ids(ids: &[&str]) -> Vec<String> { ids.iter().map(|id| id.to_string()).collect() }
+
+    fn apply(delta: &RichTextDelta, edit: TableEdit) -> RichTextDelta {
+        delta.compose(&table_delta(delta, &edit).unwrap()).unwrap()
+    }
+
+    fn document_with_table() -> RichTextDelta {
+        let delta = DeltaBuilder::new().insert("Title\n").build();
+        apply(
+            &delta,
+            TableEdit::InsertTable {
+                index: 6,
+                table_id: "t".to_owned(),
+                row_ids: ids(&["r1", "r2"]),
+                column_ids: ids(&["c1", "c2"]),
+            },
+        )
+    }
+
+    #[test]
+    fn table_insert_and_read() {
+        let delta = document_with_table();
+        let tables = read_tables(&delta);
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].start, 6);
+        assert_eq!(tables[0].len, 4);
+        assert_eq!(tables[0].column_ids, ids(&["c1", "c2"]));
+        assert_eq!(tables[0].rows.len(), 2);
+        assert_eq!(tables[0].cell("r2", "c1").unwrap().start, 8);
+    }
+
+    #[test]
+    fn table_insert_and_delete_column() {
+        let delta = apply(
+            &document_with_table(),
+            TableEdit::InsertColumn {
+                table_id: "t".to_owned(),
+                column_index: 1,
+                column_id: "c3".to_owned(),
+            },
+        );
+        assert_eq!(read_tables(&delta)[0].column_ids, ids(&["c1", "c3", "c2"]));
+
+        let delta = apply(
+            &delta,
+            TableEdit::DeleteColumn {
+                table_id: "t".to_owned(),
+                column_id: "c1".to_owned(),
+            },
+        );
+        let table = &read_tables(&delta)[0];
+        assert_eq!(table.column_ids, ids(&["c3", "c2"]));
+        assert!(table.cell("r1", "c1").is_none());
+    }
+
+    #[test]
+    fn table_concurrent_row_and_column_converge() {
+        let base = document_with_table();
+        let insert_row = table_delta(
+            &base,
+            &TableEdit::InsertRow {
+                table_id: "t".to_owned(),
+                row_index: 1,
+                row_id: "r3".to_owned(),
+            },
+        )
+        .unwrap();
+        let insert_column = table_delta(
+            &base,
+            &TableEdit::InsertColumn {
+                table_id: "t".to_owned(),
+                column_index: 2,
+                column_id: "c3".to_owned(),
+            },
+        )
+        .unwrap();
+
+        let (row_prime, column_prime) = insert_row.transform(&insert_column).unwrap();
+        let a = base.compose(&insert_row).unwrap().compose(&column_prime).unwrap();
+        let b = base.compose(&insert_column).unwrap().compose(&row_prime).unwrap();
+        assert_eq!(a, b);
+
+        let table = &read_tables(&a)[0];
+        assert_eq!(table.column_ids, ids(&["c1", "c2", "c3"]));
+        assert_eq!(
+            table.rows.iter().map(|row| row.id.clone()).collect::<Vec<_>>(),
+            ids(&["r1", "r3", "r2"])
+        );
+        assert!(table.cell("r1", "c3").is_some());
+        assert!(table.cell("r3", "c3").is_none());
+    }
+}
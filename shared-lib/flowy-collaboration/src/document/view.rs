@@ -107,5 +107,9 @@ fn construct_format_exts() -> Vec<FormatExtension> {
 }
 
 fn construct_delete_exts() -> Vec<DeleteExtension> {
-    vec![Box::new(PreserveLineFormatOnMerge {}), Box::new(DefaultDelete {})]
+    vec![
+        Box::new(PreserveTableCellOnDelete {}),
+        Box::new(PreserveLineFormatOnMerge {}),
+        Box::new(DefaultDelete {}),
+    ]
 }
@@ -25,6 +25,14 @@ pub struct Revision {
 
     #[pb(index = 7)]
     pub user_id: String,
+
+    // The base64 ed25519 signature of the `signing_payload` and the public key
+    // of the device that made it. Both are empty if the revision isn't signed.
+    #[pb(index = 8)]
+    pub signature: String,
+
+    #[pb(index = 9)]
+    pub signer_key: String,
 }
 
 impl std::convert::From<Vec<u8>> for Revision {
@@ -65,8 +73,21 @@ impl Revision {
             doc_id,
             ty: RevType::DeprecatedLocal,
             user_id,
+            signature: String::new(),
+            signer_key: String::new(),
         }
     }
+
+    pub fn is_signed(&self) -> bool { !self.signature.is_empty() }
+
+    /// The bytes that the signature covers: who made the revision, where it
+    /// goes in the document and what it changes.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        let mut payload =
+            format!("{}|{}|{}|{}|", self.doc_id, self.base_rev_id, self.rev_id, self.user_id).into_bytes();
+        payload.extend_from_slice(&self.delta_data);
+        payload
+    }
 }
 
 impl std::convert::From<Revision> for RepeatedRevision {
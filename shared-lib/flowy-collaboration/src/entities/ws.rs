@@ -69,10 +69,11 @@ impl DocumentClientWSData {
 
 #[derive(Debug, Clone, ProtoBuf_Enum, Eq, PartialEq, Hash)]
 pub enum DocumentServerWSDataType {
-    ServerAck     = 0,
-    ServerPushRev = 1,
-    ServerPullRev = 2,
-    UserConnect   = 3,
+    ServerAck          = 0,
+    ServerPushRev      = 1,
+    ServerPullRev      = 2,
+    UserConnect        = 3,
+    ServerBackpressure = 4,
 }
 
 impl std::default::Default for DocumentServerWSDataType {
@@ -120,6 +121,15 @@ impl DocumentServerWSDataBuilder {
             data: bytes.to_vec(),
         }
     }
+
+    pub fn build_backpressure_message(doc_id: &str, backpressure: DocumentBackpressure) -> DocumentServerWSData {
+        let bytes: Bytes = backpressure.try_into().unwrap();
+        DocumentServerWSData {
+            doc_id: doc_id.to_string(),
+            ty: DocumentServerWSDataType::ServerBackpressure,
+            data: bytes.to_vec(),
+        }
+    }
 }
 
 #[derive(ProtoBuf, Default, Debug, Clone)]
@@ -134,3 +144,31 @@ pub struct NewDocumentUser {
     #[pb(index = 3)]
     pub revision_data: Vec<u8>,
 }
+
+#[derive(Debug, Clone, ProtoBuf_Enum, Eq, PartialEq)]
+pub enum BackpressureReason {
+    TooManyRevisions = 0,
+    PayloadTooLarge  = 1,
+}
+
+impl std::default::Default for BackpressureReason {
+    fn default() -> Self { BackpressureReason::TooManyRevisions }
+}
+
+// The server dropped the revisions of the client instead of applying them.
+// The client sends nothing of the document until the retry_after_millis
+// passed, and then resends the revisions that weren't acked. A revision that
+// is too large is never accepted, the client stops syncing the document
+// instead, the retry_after_millis is for the older clients.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocumentBackpressure {
+    #[pb(index = 1)]
+    pub reason: BackpressureReason,
+
+    #[pb(index = 2)]
+    pub retry_after_millis: i64,
+
+    // The revisions per second or the bytes of a message that are allowed.
+    #[pb(index = 3)]
+    pub limit: i64,
+}
@@ -40,6 +40,8 @@ impl CollaborateError {
     static_doc_error!(out_of_bound, ErrorCode::OutOfBound);
     static_doc_error!(record_not_found, ErrorCode::RecordNotFound);
     static_doc_error!(revision_conflict, ErrorCode::RevisionConflict);
+    static_doc_error!(invalid_equation, ErrorCode::EquationInvalid);
+    static_doc_error!(invalid_embed_url, ErrorCode::EmbedUrlInvalid);
 }
 
 impl fmt::Display for CollaborateError {
@@ -54,6 +56,8 @@ pub enum ErrorCode {
     RedoFail         = 201,
     OutOfBound       = 202,
     RevisionConflict = 203,
+    EquationInvalid  = 204,
+    EmbedUrlInvalid  = 205,
     RecordNotFound   = 300,
     InternalError    = 1000,
 }
@@ -33,6 +33,8 @@ pub struct Revision {
     pub doc_id: ::std::string::String,
     pub ty: RevType,
     pub user_id: ::std::string::String,
+    pub signature: ::std::string::String,
+    pub signer_key: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -197,6 +199,58 @@ impl Revision {
     pub fn take_user_id(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
     }
+
+    // string signature = 8;
+
+
+    pub fn get_signature(&self) -> &str {
+        &self.signature
+    }
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::string::String) {
+        self.signature = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::string::String {
+        &mut self.signature
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.signature, ::std::string::String::new())
+    }
+
+    // string signer_key = 9;
+
+
+    pub fn get_signer_key(&self) -> &str {
+        &self.signer_key
+    }
+    pub fn clear_signer_key(&mut self) {
+        self.signer_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signer_key(&mut self, v: ::std::string::String) {
+        self.signer_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signer_key(&mut self) -> &mut ::std::string::String {
+        &mut self.signer_key
+    }
+
+    // Take field
+    pub fn take_signer_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.signer_key, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for Revision {
@@ -237,6 +291,12 @@ impl ::protobuf::Message for Revision {
                 7 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
                 },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.signature)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.signer_key)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -270,6 +330,12 @@ impl ::protobuf::Message for Revision {
         if !self.user_id.is_empty() {
             my_size += ::protobuf::rt::string_size(7, &self.user_id);
         }
+        if !self.signature.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.signature);
+        }
+        if !self.signer_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.signer_key);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -297,6 +363,12 @@ impl ::protobuf::Message for Revision {
         if !self.user_id.is_empty() {
             os.write_string(7, &self.user_id)?;
         }
+        if !self.signature.is_empty() {
+            os.write_string(8, &self.signature)?;
+        }
+        if !self.signer_key.is_empty() {
+            os.write_string(9, &self.signer_key)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -370,6 +442,16 @@ impl ::protobuf::Message for Revision {
                 |m: &Revision| { &m.user_id },
                 |m: &mut Revision| { &mut m.user_id },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "signature",
+                |m: &Revision| { &m.signature },
+                |m: &mut Revision| { &mut m.signature },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "signer_key",
+                |m: &Revision| { &m.signer_key },
+                |m: &mut Revision| { &mut m.signer_key },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Revision>(
                 "Revision",
                 fields,
@@ -393,6 +475,8 @@ impl ::protobuf::Clear for Revision {
         self.doc_id.clear();
         self.ty = RevType::DeprecatedLocal;
         self.user_id.clear();
+        self.signature.clear();
+        self.signer_key.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1057,68 +1141,21 @@ impl ::protobuf::reflect::ProtobufValue for RevType {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0erevision.proto\"\xbc\x01\n\x08Revision\x12\x1e\n\x0bbase_rev_id\
-    \x18\x01\x20\x01(\x03R\tbaseRevId\x12\x15\n\x06rev_id\x18\x02\x20\x01(\
-    \x03R\x05revId\x12\x1d\n\ndelta_data\x18\x03\x20\x01(\x0cR\tdeltaData\
-    \x12\x10\n\x03md5\x18\x04\x20\x01(\tR\x03md5\x12\x15\n\x06doc_id\x18\x05\
-    \x20\x01(\tR\x05docId\x12\x18\n\x02ty\x18\x06\x20\x01(\x0e2\x08.RevTypeR\
-    \x02ty\x12\x17\n\x07user_id\x18\x07\x20\x01(\tR\x06userId\"3\n\x10Repeat\
-    edRevision\x12\x1f\n\x05items\x18\x01\x20\x03(\x0b2\t.RevisionR\x05items\
-    \"\x1d\n\x05RevId\x12\x14\n\x05value\x18\x01\x20\x01(\x03R\x05value\"N\n\
-    \rRevisionRange\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x14\
-    \n\x05start\x18\x02\x20\x01(\x03R\x05start\x12\x10\n\x03end\x18\x03\x20\
-    \x01(\x03R\x03end*#\n\rRevisionState\x12\t\n\x05Local\x10\0\x12\x07\n\
-    \x03Ack\x10\x01*4\n\x07RevType\x12\x13\n\x0fDeprecatedLocal\x10\0\x12\
-    \x14\n\x10DeprecatedRemote\x10\x01J\xe8\x07\n\x06\x12\x04\0\0\x1d\x01\n\
-    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\n\x01\n\n\n\
-    \x03\x04\0\x01\x12\x03\x02\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
-    \x04\x1a\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\n\x0c\n\x05\x04\0\
-    \x02\0\x01\x12\x03\x03\n\x15\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x18\
-    \x19\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x15\n\x0c\n\x05\x04\0\x02\
-    \x01\x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\n\
-    \x10\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x13\x14\n\x0b\n\x04\x04\0\
-    \x02\x02\x12\x03\x05\x04\x19\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\
-    \x04\t\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\n\x14\n\x0c\n\x05\x04\0\
-    \x02\x02\x03\x12\x03\x05\x17\x18\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\
-    \x04\x13\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\
-    \0\x02\x03\x01\x12\x03\x06\x0b\x0e\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\
-    \x06\x11\x12\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x16\n\x0c\n\x05\
-    \x04\0\x02\x04\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\0\x02\x04\x01\x12\
-    \x03\x07\x0b\x11\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x14\x15\n\x0b\
-    \n\x04\x04\0\x02\x05\x12\x03\x08\x04\x13\n\x0c\n\x05\x04\0\x02\x05\x06\
-    \x12\x03\x08\x04\x0b\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x08\x0c\x0e\n\
-    \x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x08\x11\x12\n\x0b\n\x04\x04\0\x02\
-    \x06\x12\x03\t\x04\x17\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03\t\x04\n\n\
-    \x0c\n\x05\x04\0\x02\x06\x01\x12\x03\t\x0b\x12\n\x0c\n\x05\x04\0\x02\x06\
-    \x03\x12\x03\t\x15\x16\n\n\n\x02\x04\x01\x12\x04\x0b\0\r\x01\n\n\n\x03\
-    \x04\x01\x01\x12\x03\x0b\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0c\
-    \x04\x20\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x0c\x04\x0c\n\x0c\n\x05\
-    \x04\x01\x02\0\x06\x12\x03\x0c\r\x15\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
-    \x03\x0c\x16\x1b\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0c\x1e\x1f\n\n\n\
-    \x02\x04\x02\x12\x04\x0e\0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0e\x08\
-    \r\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0f\x04\x14\n\x0c\n\x05\x04\x02\x02\
-    \0\x05\x12\x03\x0f\x04\t\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0f\n\x0f\
-    \n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0f\x12\x13\n\n\n\x02\x04\x03\x12\
-    \x04\x11\0\x15\x01\n\n\n\x03\x04\x03\x01\x12\x03\x11\x08\x15\n\x0b\n\x04\
-    \x04\x03\x02\0\x12\x03\x12\x04\x16\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\
-    \x12\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x12\x0b\x11\n\x0c\n\x05\
-    \x04\x03\x02\0\x03\x12\x03\x12\x14\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\
-    \x03\x13\x04\x14\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x13\x04\t\n\x0c\
-    \n\x05\x04\x03\x02\x01\x01\x12\x03\x13\n\x0f\n\x0c\n\x05\x04\x03\x02\x01\
-    \x03\x12\x03\x13\x12\x13\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x14\x04\x12\
-    \n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x14\x04\t\n\x0c\n\x05\x04\x03\
-    \x02\x02\x01\x12\x03\x14\n\r\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x14\
-    \x10\x11\n\n\n\x02\x05\0\x12\x04\x16\0\x19\x01\n\n\n\x03\x05\0\x01\x12\
-    \x03\x16\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x17\x04\x0e\n\x0c\n\x05\
-    \x05\0\x02\0\x01\x12\x03\x17\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\
-    \x17\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x18\x04\x0c\n\x0c\n\x05\x05\
-    \0\x02\x01\x01\x12\x03\x18\x04\x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
-    \x18\n\x0b\n\n\n\x02\x05\x01\x12\x04\x1a\0\x1d\x01\n\n\n\x03\x05\x01\x01\
-    \x12\x03\x1a\x05\x0c\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x1b\x04\x18\n\x0c\
-    \n\x05\x05\x01\x02\0\x01\x12\x03\x1b\x04\x13\n\x0c\n\x05\x05\x01\x02\0\
-    \x02\x12\x03\x1b\x16\x17\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\x1c\x04\x19\
-    \n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\x1c\x04\x14\n\x0c\n\x05\x05\x01\
-    \x02\x01\x02\x12\x03\x1c\x17\x18b\x06proto3\
+    \n\x0erevision.proto\"\x8d\x02\n\x08Revision\x12\x20\n\x0bbase_rev_id\
+    \x18\x01\x20\x01(\x03R\tbaseRevIdB\0\x12\x17\n\x06rev_id\x18\x02\x20\x01\
+    (\x03R\x05revIdB\0\x12\x1f\n\ndelta_data\x18\x03\x20\x01(\x0cR\tdeltaDat\
+    aB\0\x12\x12\n\x03md5\x18\x04\x20\x01(\tR\x03md5B\0\x12\x17\n\x06doc_id\
+    \x18\x05\x20\x01(\tR\x05docIdB\0\x12\x1a\n\x02ty\x18\x06\x20\x01(\x0e2\
+    \x08.RevTypeR\x02tyB\0\x12\x19\n\x07user_id\x18\x07\x20\x01(\tR\x06userI\
+    dB\0\x12\x1e\n\tsignature\x18\x08\x20\x01(\tR\tsignatureB\0\x12\x1f\n\ns\
+    igner_key\x18\t\x20\x01(\tR\tsignerKeyB\0:\0\"7\n\x10RepeatedRevision\
+    \x12!\n\x05items\x18\x01\x20\x03(\x0b2\t.RevisionR\x05itemsB\0:\0\"!\n\
+    \x05RevId\x12\x16\n\x05value\x18\x01\x20\x01(\x03R\x05valueB\0:\0\"V\n\r\
+    RevisionRange\x12\x17\n\x06doc_id\x18\x01\x20\x01(\tR\x05docIdB\0\x12\
+    \x16\n\x05start\x18\x02\x20\x01(\x03R\x05startB\0\x12\x12\n\x03end\x18\
+    \x03\x20\x01(\x03R\x03endB\0:\0*%\n\rRevisionState\x12\t\n\x05Local\x10\
+    \0\x12\x07\n\x03Ack\x10\x01\x1a\0*6\n\x07RevType\x12\x13\n\x0fDeprecated\
+    Local\x10\0\x12\x14\n\x10DeprecatedRemote\x10\x01\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -787,6 +787,224 @@ impl ::protobuf::reflect::ProtobufValue for NewDocumentUser {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentBackpressure {
+    // message fields
+    pub reason: BackpressureReason,
+    pub retry_after_millis: i64,
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentBackpressure {
+    fn default() -> &'a DocumentBackpressure {
+        <DocumentBackpressure as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentBackpressure {
+    pub fn new() -> DocumentBackpressure {
+        ::std::default::Default::default()
+    }
+
+    // .BackpressureReason reason = 1;
+
+
+    pub fn get_reason(&self) -> BackpressureReason {
+        self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason = BackpressureReason::TooManyRevisions;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: BackpressureReason) {
+        self.reason = v;
+    }
+
+    // int64 retry_after_millis = 2;
+
+
+    pub fn get_retry_after_millis(&self) -> i64 {
+        self.retry_after_millis
+    }
+    pub fn clear_retry_after_millis(&mut self) {
+        self.retry_after_millis = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retry_after_millis(&mut self, v: i64) {
+        self.retry_after_millis = v;
+    }
+
+    // int64 limit = 3;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for DocumentBackpressure {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.reason, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.retry_after_millis = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.reason != BackpressureReason::TooManyRevisions {
+            my_size += ::protobuf::rt::enum_size(1, self.reason);
+        }
+        if self.retry_after_millis != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.retry_after_millis, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.reason != BackpressureReason::TooManyRevisions {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.reason))?;
+        }
+        if self.retry_after_millis != 0 {
+            os.write_int64(2, self.retry_after_millis)?;
+        }
+        if self.limit != 0 {
+            os.write_int64(3, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentBackpressure {
+        DocumentBackpressure::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<BackpressureReason>>(
+                "reason",
+                |m: &DocumentBackpressure| { &m.reason },
+                |m: &mut DocumentBackpressure| { &mut m.reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "retry_after_millis",
+                |m: &DocumentBackpressure| { &m.retry_after_millis },
+                |m: &mut DocumentBackpressure| { &mut m.retry_after_millis },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &DocumentBackpressure| { &m.limit },
+                |m: &mut DocumentBackpressure| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentBackpressure>(
+                "DocumentBackpressure",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentBackpressure {
+        static instance: ::protobuf::rt::LazyV2<DocumentBackpressure> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentBackpressure::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentBackpressure {
+    fn clear(&mut self) {
+        self.reason = BackpressureReason::TooManyRevisions;
+        self.retry_after_millis = 0;
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentBackpressure {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentBackpressure {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum DocumentClientWSDataType {
     ClientPushRev = 0,
@@ -843,6 +1061,7 @@ pub enum DocumentServerWSDataType {
     ServerPushRev = 1,
     ServerPullRev = 2,
     UserConnect = 3,
+    ServerBackpressure = 4,
 }
 
 impl ::protobuf::ProtobufEnum for DocumentServerWSDataType {
@@ -856,6 +1075,7 @@ impl ::protobuf::ProtobufEnum for DocumentServerWSDataType {
             1 => ::std::option::Option::Some(DocumentServerWSDataType::ServerPushRev),
             2 => ::std::option::Option::Some(DocumentServerWSDataType::ServerPullRev),
             3 => ::std::option::Option::Some(DocumentServerWSDataType::UserConnect),
+            4 => ::std::option::Option::Some(DocumentServerWSDataType::ServerBackpressure),
             _ => ::std::option::Option::None
         }
     }
@@ -866,6 +1086,7 @@ impl ::protobuf::ProtobufEnum for DocumentServerWSDataType {
             DocumentServerWSDataType::ServerPushRev,
             DocumentServerWSDataType::ServerPullRev,
             DocumentServerWSDataType::UserConnect,
+            DocumentServerWSDataType::ServerBackpressure,
         ];
         values
     }
@@ -893,67 +1114,77 @@ impl ::protobuf::reflect::ProtobufValue for DocumentServerWSDataType {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum BackpressureReason {
+    TooManyRevisions = 0,
+    PayloadTooLarge = 1,
+}
+
+impl ::protobuf::ProtobufEnum for BackpressureReason {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<BackpressureReason> {
+        match value {
+            0 => ::std::option::Option::Some(BackpressureReason::TooManyRevisions),
+            1 => ::std::option::Option::Some(BackpressureReason::PayloadTooLarge),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [BackpressureReason] = &[
+            BackpressureReason::TooManyRevisions,
+            BackpressureReason::PayloadTooLarge,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<BackpressureReason>("BackpressureReason", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for BackpressureReason {
+}
+
+impl ::std::default::Default for BackpressureReason {
+    fn default() -> Self {
+        BackpressureReason::TooManyRevisions
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BackpressureReason {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x08ws.proto\x1a\x0erevision.proto\"\x99\x01\n\x14DocumentClientWSData\
-    \x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12)\n\x02ty\x18\x02\
-    \x20\x01(\x0e2\x19.DocumentClientWSDataTypeR\x02ty\x12/\n\trevisions\x18\
-    \x03\x20\x01(\x0b2\x11.RepeatedRevisionR\trevisions\x12\x0e\n\x02id\x18\
-    \x04\x20\x01(\tR\x02id\"l\n\x14DocumentServerWSData\x12\x15\n\x06doc_id\
-    \x18\x01\x20\x01(\tR\x05docId\x12)\n\x02ty\x18\x02\x20\x01(\x0e2\x19.Doc\
-    umentServerWSDataTypeR\x02ty\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04\
-    data\"f\n\x0fNewDocumentUser\x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\
-    \x06userId\x12\x15\n\x06doc_id\x18\x02\x20\x01(\tR\x05docId\x12#\n\rrevi\
-    sion_data\x18\x03\x20\x01(\x0cR\x0crevisionData*=\n\x18DocumentClientWSD\
-    ataType\x12\x11\n\rClientPushRev\x10\0\x12\x0e\n\nClientPing\x10\x01*`\n\
-    \x18DocumentServerWSDataType\x12\r\n\tServerAck\x10\0\x12\x11\n\rServerP\
-    ushRev\x10\x01\x12\x11\n\rServerPullRev\x10\x02\x12\x0f\n\x0bUserConnect\
-    \x10\x03J\xb1\x07\n\x06\x12\x04\0\0\x1c\x01\n\x08\n\x01\x0c\x12\x03\0\0\
-    \x12\n\t\n\x02\x03\0\x12\x03\x01\0\x18\n\n\n\x02\x04\0\x12\x04\x03\0\x08\
-    \x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x1c\n\x0b\n\x04\x04\0\x02\0\x12\
-    \x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\
-    \x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\
-    \x03\x04\x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04$\n\x0c\n\x05\
-    \x04\0\x02\x01\x06\x12\x03\x05\x04\x1c\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
-    \x03\x05\x1d\x1f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\"#\n\x0b\n\
-    \x04\x04\0\x02\x02\x12\x03\x06\x04#\n\x0c\n\x05\x04\0\x02\x02\x06\x12\
-    \x03\x06\x04\x14\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\x15\x1e\n\x0c\
-    \n\x05\x04\0\x02\x02\x03\x12\x03\x06!\"\n\x0b\n\x04\x04\0\x02\x03\x12\
-    \x03\x07\x04\x12\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x07\x04\n\n\x0c\n\
-    \x05\x04\0\x02\x03\x01\x12\x03\x07\x0b\r\n\x0c\n\x05\x04\0\x02\x03\x03\
-    \x12\x03\x07\x10\x11\n\n\n\x02\x04\x01\x12\x04\t\0\r\x01\n\n\n\x03\x04\
-    \x01\x01\x12\x03\t\x08\x1c\n\x0b\n\x04\x04\x01\x02\0\x12\x03\n\x04\x16\n\
-    \x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\x02\0\
-    \x01\x12\x03\n\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\n\x14\x15\n\
-    \x0b\n\x04\x04\x01\x02\x01\x12\x03\x0b\x04$\n\x0c\n\x05\x04\x01\x02\x01\
-    \x06\x12\x03\x0b\x04\x1c\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0b\x1d\
-    \x1f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b\"#\n\x0b\n\x04\x04\x01\
-    \x02\x02\x12\x03\x0c\x04\x13\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x0c\
-    \x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x0c\n\x0e\n\x0c\n\x05\x04\
-    \x01\x02\x02\x03\x12\x03\x0c\x11\x12\n\n\n\x02\x04\x02\x12\x04\x0e\0\x12\
-    \x01\n\n\n\x03\x04\x02\x01\x12\x03\x0e\x08\x17\n\x0b\n\x04\x04\x02\x02\0\
-    \x12\x03\x0f\x04\x17\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0f\x04\n\n\
-    \x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0f\x0b\x12\n\x0c\n\x05\x04\x02\x02\
-    \0\x03\x12\x03\x0f\x15\x16\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x10\x04\
-    \x16\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\x01\x01\x12\x03\x10\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
-    \x03\x10\x14\x15\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x11\x04\x1c\n\x0c\n\
-    \x05\x04\x02\x02\x02\x05\x12\x03\x11\x04\t\n\x0c\n\x05\x04\x02\x02\x02\
-    \x01\x12\x03\x11\n\x17\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x11\x1a\
-    \x1b\n\n\n\x02\x05\0\x12\x04\x13\0\x16\x01\n\n\n\x03\x05\0\x01\x12\x03\
-    \x13\x05\x1d\n\x0b\n\x04\x05\0\x02\0\x12\x03\x14\x04\x16\n\x0c\n\x05\x05\
-    \0\x02\0\x01\x12\x03\x14\x04\x11\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x14\
-    \x14\x15\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x15\x04\x13\n\x0c\n\x05\x05\0\
-    \x02\x01\x01\x12\x03\x15\x04\x0e\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
-    \x15\x11\x12\n\n\n\x02\x05\x01\x12\x04\x17\0\x1c\x01\n\n\n\x03\x05\x01\
-    \x01\x12\x03\x17\x05\x1d\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x18\x04\x12\n\
-    \x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x18\x04\r\n\x0c\n\x05\x05\x01\x02\0\
-    \x02\x12\x03\x18\x10\x11\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\x19\x04\x16\
-    \n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\x19\x04\x11\n\x0c\n\x05\x05\x01\
-    \x02\x01\x02\x12\x03\x19\x14\x15\n\x0b\n\x04\x05\x01\x02\x02\x12\x03\x1a\
-    \x04\x16\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03\x1a\x04\x11\n\x0c\n\x05\
-    \x05\x01\x02\x02\x02\x12\x03\x1a\x14\x15\n\x0b\n\x04\x05\x01\x02\x03\x12\
-    \x03\x1b\x04\x14\n\x0c\n\x05\x05\x01\x02\x03\x01\x12\x03\x1b\x04\x0f\n\
-    \x0c\n\x05\x05\x01\x02\x03\x02\x12\x03\x1b\x12\x13b\x06proto3\
+    \n\x08ws.proto\x1a\x0erevision.proto\"\xa3\x01\n\x14DocumentClientWSData\
+    \x12\x17\n\x06doc_id\x18\x01\x20\x01(\tR\x05docIdB\0\x12+\n\x02ty\x18\
+    \x02\x20\x01(\x0e2\x19.DocumentClientWSDataTypeR\x02tyB\0\x121\n\trevisi\
+    ons\x18\x03\x20\x01(\x0b2\x11.RepeatedRevisionR\trevisionsB\0\x12\x10\n\
+    \x02id\x18\x04\x20\x01(\tR\x02idB\0:\0\"t\n\x14DocumentServerWSData\x12\
+    \x17\n\x06doc_id\x18\x01\x20\x01(\tR\x05docIdB\0\x12+\n\x02ty\x18\x02\
+    \x20\x01(\x0e2\x19.DocumentServerWSDataTypeR\x02tyB\0\x12\x14\n\x04data\
+    \x18\x03\x20\x01(\x0cR\x04dataB\0:\0\"n\n\x0fNewDocumentUser\x12\x19\n\
+    \x07user_id\x18\x01\x20\x01(\tR\x06userIdB\0\x12\x17\n\x06doc_id\x18\x02\
+    \x20\x01(\tR\x05docIdB\0\x12%\n\rrevision_data\x18\x03\x20\x01(\x0cR\x0c\
+    revisionDataB\0:\0\"\x8f\x01\n\x14DocumentBackpressure\x12-\n\x06reason\
+    \x18\x01\x20\x01(\x0e2\x13.BackpressureReasonR\x06reasonB\0\x12.\n\x12re\
+    try_after_millis\x18\x02\x20\x01(\x03R\x10retryAfterMillisB\0\x12\x16\n\
+    \x05limit\x18\x03\x20\x01(\x03R\x05limitB\0:\0*?\n\x18DocumentClientWSDa\
+    taType\x12\x11\n\rClientPushRev\x10\0\x12\x0e\n\nClientPing\x10\x01\x1a\
+    \0*z\n\x18DocumentServerWSDataType\x12\r\n\tServerAck\x10\0\x12\x11\n\rS\
+    erverPushRev\x10\x01\x12\x11\n\rServerPullRev\x10\x02\x12\x0f\n\x0bUserC\
+    onnect\x10\x03\x12\x16\n\x12ServerBackpressure\x10\x04\x1a\0*A\n\x12Back\
+    pressureReason\x12\x14\n\x10TooManyRevisions\x10\0\x12\x13\n\x0fPayloadT\
+    ooLarge\x10\x01\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -1,5 +1,7 @@
 mod server;
+mod store;
 mod synchronizer;
 
 pub use server::*;
+pub use store::*;
 pub use synchronizer::*;
@@ -117,6 +117,14 @@ impl ServerDocumentManager {
         }
     }
 
+    // Drops the opened document, it's read from the persistence again the next
+    // time it's used. E.g. another server may have changed it in the meantime.
+    pub async fn close_document(&self, doc_id: &str) {
+        if self.open_doc_map.write().await.remove(doc_id).is_some() {
+            tracing::debug!("Document:{} was closed", doc_id);
+        }
+    }
+
     async fn get_document_handler(&self, doc_id: &str) -> Option<Arc<OpenDocHandle>> {
         if let Some(handler) = self.open_doc_map.read().await.get(doc_id).cloned() {
             return Some(handler);
@@ -0,0 +1,216 @@
+#[cfg(feature = "postgres_store")]
+mod postgres_store;
+#[cfg(feature = "sled_store")]
+mod sled_store;
+
+#[cfg(feature = "postgres_store")]
+pub use postgres_store::*;
+#[cfg(feature = "sled_store")]
+pub use sled_store::*;
+
+use crate::{
+    entities::doc::DocumentInfo,
+    errors::CollaborateError,
+    protobuf::{RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
+    sync::DocumentPersistence,
+    util::repeated_revision_from_revision_pbs,
+};
+use lib_infra::future::BoxResultFuture;
+use std::{fmt::Debug, sync::Arc};
+
+// Where the server keeps the revisions of the documents. A document is the
+// composition of its revisions, so a store only needs to keep them by the id
+// of the document and the rev_id. The reference stores are behind the
+// postgres_store and the sled_store features.
+pub trait RevisionStore: Send + Sync + Debug {
+    // Replaces the revisions with the same doc_id and rev_id.
+    fn set_revisions(&self, revisions: Vec<RevisionPB>) -> BoxResultFuture<(), CollaborateError>;
+
+    // All the revisions of the document if the rev_ids are None. They are
+    // sorted by the rev_id, the missing ones are skipped.
+    fn get_revisions(
+        &self,
+        doc_id: &str,
+        rev_ids: Option<Vec<i64>>,
+    ) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError>;
+
+    // All the revisions of the document if the rev_ids are None.
+    fn delete_revisions(&self, doc_id: &str, rev_ids: Option<Vec<i64>>) -> BoxResultFuture<(), CollaborateError>;
+}
+
+// The DocumentPersistence of the ServerDocumentManager on top of any store.
+// The new revisions that are sent to the RevisionUser are saved with
+// `set_revisions` of the same store.
+#[derive(Debug)]
+pub struct RevisionStorePersistence {
+    store: Arc<dyn RevisionStore>,
+}
+
+impl RevisionStorePersistence {
+    pub fn new(store: Arc<dyn RevisionStore>) -> Self { Self { store } }
+
+    pub fn store(&self) -> Arc<dyn RevisionStore> { self.store.clone() }
+}
+
+impl DocumentPersistence for RevisionStorePersistence {
+    fn read_doc(&self, doc_id: &str) -> BoxResultFuture<DocumentInfo, CollaborateError> {
+        let store = self.store.clone();
+        let doc_id = doc_id.to_owned();
+        Box::pin(async move {
+            let revisions = store.get_revisions(&doc_id, None).await?;
+            if revisions.is_empty() {
+                return Err(CollaborateError::record_not_found().context(format!("{} not exist", doc_id)));
+            }
+            let revisions = repeated_revision_from_revision_pbs(revisions)?.into_inner();
+            DocumentInfo::from_revisions(&doc_id, revisions)
+        })
+    }
+
+    fn create_doc(
+        &self,
+        doc_id: &str,
+        mut repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<DocumentInfo, CollaborateError> {
+        let store = self.store.clone();
+        let doc_id = doc_id.to_owned();
+        Box::pin(async move {
+            let revisions = repeated_revision.take_items().into_vec();
+            let doc = DocumentInfo::from_revisions(
+                &doc_id,
+                repeated_revision_from_revision_pbs(revisions.clone())?.into_inner(),
+            )?;
+            let _ = store.set_revisions(revisions).await?;
+            Ok(doc)
+        })
+    }
+
+    fn get_revisions(&self, doc_id: &str, rev_ids: Vec<i64>) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError> {
+        let store = self.store.clone();
+        let doc_id = doc_id.to_owned();
+        Box::pin(async move { store.get_revisions(&doc_id, Some(rev_ids)).await })
+    }
+
+    fn get_doc_revisions(&self, doc_id: &str) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError> {
+        let store = self.store.clone();
+        let doc_id = doc_id.to_owned();
+        Box::pin(async move { store.get_revisions(&doc_id, None).await })
+    }
+
+    fn reset_document(
+        &self,
+        doc_id: &str,
+        mut repeated_revision: RepeatedRevisionPB,
+    ) -> BoxResultFuture<(), CollaborateError> {
+        let store = self.store.clone();
+        let doc_id = doc_id.to_owned();
+        Box::pin(async move {
+            let _ = store.delete_revisions(&doc_id, None).await?;
+            store.set_revisions(repeated_revision.take_items().into_vec()).await
+        })
+    }
+}
+
+// The key of a revision in the key value stores, the same as the one of the
+// kv_table of the backend.
+#[cfg(any(feature = "postgres_store", feature = "sled_store"))]
+#[inline]
+fn revision_key(doc_id: &str, rev_id: i64) -> String { format!("{}:{}", doc_id, rev_id) }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::CollaborateError,
+        protobuf::{RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
+        sync::{DocumentPersistence, RevisionStore, RevisionStorePersistence},
+    };
+    use futures::executor::block_on;
+    use lib_infra::future::BoxResultFuture;
+    use lib_ot::{core::DeltaBuilder, rich_text::RichTextDelta};
+    use parking_lot::Mutex;
+    use std::{collections::BTreeMap, sync::Arc};
+
+    #[derive(Debug, Default)]
+    struct MemoryRevisionStore(Arc<Mutex<BTreeMap<(String, i64), RevisionPB>>>);
+
+    impl RevisionStore for MemoryRevisionStore {
+        fn set_revisions(&self, revisions: Vec<RevisionPB>) -> BoxResultFuture<(), CollaborateError> {
+            for revision in revisions {
+                self.0
+                    .lock()
+                    .insert((revision.doc_id.clone(), revision.rev_id), revision);
+            }
+            Box::pin(async { Ok(()) })
+        }
+
+        fn get_revisions(
+            &self,
+            doc_id: &str,
+            rev_ids: Option<Vec<i64>>,
+        ) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError> {
+            let revisions = self
+                .0
+                .lock()
+                .values()
+                .filter(|revision| revision.doc_id == doc_id)
+                .filter(|revision| {
+                    rev_ids
+                        .as_ref()
+                        .map_or(true, |rev_ids| rev_ids.contains(&revision.rev_id))
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            Box::pin(async move { Ok(revisions) })
+        }
+
+        fn delete_revisions(&self, doc_id: &str, rev_ids: Option<Vec<i64>>) -> BoxResultFuture<(), CollaborateError> {
+            self.0.lock().retain(|(id, rev_id), _| {
+                id != doc_id || rev_ids.as_ref().map_or(false, |rev_ids| !rev_ids.contains(rev_id))
+            });
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    fn revision(base_rev_id: i64, rev_id: i64, delta: RichTextDelta) -> RevisionPB {
+        let mut revision = RevisionPB::new();
+        revision.set_doc_id("doc".to_owned());
+        revision.set_base_rev_id(base_rev_id);
+        revision.set_rev_id(rev_id);
+        revision.set_delta_data(delta.to_bytes().to_vec());
+        revision
+    }
+
+    fn repeated_revision(revisions: Vec<RevisionPB>) -> RepeatedRevisionPB {
+        let mut repeated_revision = RepeatedRevisionPB::new();
+        repeated_revision.set_items(revisions.into());
+        repeated_revision
+    }
+
+    #[test]
+    fn store_persistence_reads_the_composed_document() {
+        let persistence = RevisionStorePersistence::new(Arc::new(MemoryRevisionStore::default()));
+        let _ = block_on(persistence.create_doc(
+            "doc",
+            repeated_revision(vec![revision(0, 1, DeltaBuilder::new().insert("a").build())]),
+        ))
+        .unwrap();
+        let _ = block_on(persistence.store().set_revisions(vec![revision(
+            1,
+            2,
+            DeltaBuilder::new().retain(1).insert("b").build(),
+        )]))
+        .unwrap();
+
+        let doc = block_on(persistence.read_doc("doc")).unwrap();
+        assert_eq!(doc.rev_id, 2);
+        assert_eq!(doc.delta().unwrap().to_json(), r#"[{"insert":"ab"}]"#);
+        assert_eq!(block_on(persistence.get_revisions("doc", vec![2])).unwrap().len(), 1);
+
+        let _ = block_on(persistence.reset_document(
+            "doc",
+            repeated_revision(vec![revision(0, 1, DeltaBuilder::new().insert("c").build())]),
+        ))
+        .unwrap();
+        assert_eq!(block_on(persistence.get_doc_revisions("doc")).unwrap().len(), 1);
+        assert!(block_on(persistence.read_doc("unknown")).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    errors::{internal_error, CollaborateError},
+    protobuf::Revision as RevisionPB,
+    sync::store::{revision_key, RevisionStore},
+};
+use lib_infra::future::BoxResultFuture;
+use protobuf::Message;
+use sqlx::{PgPool, Row};
+
+// The table of the backend, so a server that used it keeps its documents:
+//   CREATE TABLE IF NOT EXISTS kv_table(id TEXT NOT NULL PRIMARY KEY, blob
+// bytea);
+const KV_TABLE: &str = "kv_table";
+
+#[derive(Debug, Clone)]
+pub struct PostgresRevisionStore {
+    pg_pool: PgPool,
+}
+
+impl PostgresRevisionStore {
+    pub fn new(pg_pool: PgPool) -> Self { Self { pg_pool } }
+}
+
+impl RevisionStore for PostgresRevisionStore {
+    fn set_revisions(&self, revisions: Vec<RevisionPB>) -> BoxResultFuture<(), CollaborateError> {
+        let pg_pool = self.pg_pool.clone();
+        Box::pin(async move {
+            let sql = format!(
+                "INSERT INTO {} (id, blob) VALUES ($1, $2) ON CONFLICT (id) DO UPDATE SET blob = EXCLUDED.blob",
+                KV_TABLE
+            );
+            let mut transaction = pg_pool.begin().await.map_err(internal_error)?;
+            for revision in revisions {
+                let _ = sqlx::query(&sql)
+                    .bind(revision_key(&revision.doc_id, revision.rev_id))
+                    .bind(revision.write_to_bytes()?)
+                    .execute(&mut transaction)
+                    .await
+                    .map_err(internal_error)?;
+            }
+            transaction.commit().await.map_err(internal_error)
+        })
+    }
+
+    fn get_revisions(
+        &self,
+        doc_id: &str,
+        rev_ids: Option<Vec<i64>>,
+    ) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError> {
+        let pg_pool = self.pg_pool.clone();
+        let doc_id = doc_id.to_owned();
+        Box::pin(async move {
+            let rows = match rev_ids {
+                None => {
+                    let sql = format!("SELECT blob FROM {} WHERE id LIKE $1", KV_TABLE);
+                    sqlx::query(&sql)
+                        .bind(format!("{}:%", doc_id))
+                        .fetch_all(&pg_pool)
+                        .await
+                },
+                Some(rev_ids) => {
+                    let keys = rev_ids
+                        .into_iter()
+                        .map(|rev_id| revision_key(&doc_id, rev_id))
+                        .collect::<Vec<String>>();
+                    let sql = format!("SELECT blob FROM {} WHERE id = ANY($1)", KV_TABLE);
+                    sqlx::query(&sql).bind(keys).fetch_all(&pg_pool).await
+                },
+            }
+            .map_err(internal_error)?;
+
+            let mut revisions = vec![];
+            for row in rows {
+                let blob: Vec<u8> = row.try_get("blob").map_err(internal_error)?;
+                revisions.push(RevisionPB::parse_from_bytes(&blob)?);
+            }
+            revisions.sort_by(|a, b| a.rev_id.cmp(&b.rev_id));
+            Ok(revisions)
+        })
+    }
+
+    fn delete_revisions(&self, doc_id: &str, rev_ids: Option<Vec<i64>>) -> BoxResultFuture<(), CollaborateError> {
+        let pg_pool = self.pg_pool.clone();
+        let doc_id = doc_id.to_owned();
+        Box::pin(async move {
+            let _ = match rev_ids {
+                None => {
+                    let sql = format!("DELETE FROM {} WHERE id LIKE $1", KV_TABLE);
+                    sqlx::query(&sql).bind(format!("{}:%", doc_id)).execute(&pg_pool).await
+                },
+                Some(rev_ids) => {
+                    let keys = rev_ids
+                        .into_iter()
+                        .map(|rev_id| revision_key(&doc_id, rev_id))
+                        .collect::<Vec<String>>();
+                    let sql = format!("DELETE FROM {} WHERE id = ANY($1)", KV_TABLE);
+                    sqlx::query(&sql).bind(keys).execute(&pg_pool).await
+                },
+            }
+            .map_err(internal_error)?;
+            Ok(())
+        })
+    }
+}
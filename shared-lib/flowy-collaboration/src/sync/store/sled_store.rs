@@ -0,0 +1,91 @@
+use crate::{
+    errors::{internal_error, CollaborateError},
+    protobuf::Revision as RevisionPB,
+    sync::store::{revision_key, RevisionStore},
+};
+use lib_infra::future::BoxResultFuture;
+use protobuf::Message;
+use std::path::Path;
+
+// Keeps the revisions in an embedded sled database, for a server that runs on
+// a single machine.
+#[derive(Debug, Clone)]
+pub struct SledRevisionStore {
+    db: sled::Db,
+}
+
+impl SledRevisionStore {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, CollaborateError> {
+        let db = sled::open(path).map_err(internal_error)?;
+        Ok(Self { db })
+    }
+
+    fn read_revisions(&self, doc_id: &str, rev_ids: Option<Vec<i64>>) -> Result<Vec<RevisionPB>, CollaborateError> {
+        let mut revisions = vec![];
+        match rev_ids {
+            None => {
+                for item in self.db.scan_prefix(format!("{}:", doc_id)) {
+                    let (_, value) = item.map_err(internal_error)?;
+                    revisions.push(RevisionPB::parse_from_bytes(&value)?);
+                }
+            },
+            Some(rev_ids) => {
+                for rev_id in rev_ids {
+                    if let Some(value) = self.db.get(revision_key(doc_id, rev_id)).map_err(internal_error)? {
+                        revisions.push(RevisionPB::parse_from_bytes(&value)?);
+                    }
+                }
+            },
+        }
+        revisions.sort_by(|a, b| a.rev_id.cmp(&b.rev_id));
+        Ok(revisions)
+    }
+
+    fn remove_revisions(&self, doc_id: &str, rev_ids: Option<Vec<i64>>) -> Result<(), CollaborateError> {
+        let mut batch = sled::Batch::default();
+        match rev_ids {
+            None => {
+                for item in self.db.scan_prefix(format!("{}:", doc_id)) {
+                    let (key, _) = item.map_err(internal_error)?;
+                    batch.remove(key);
+                }
+            },
+            Some(rev_ids) => {
+                for rev_id in rev_ids {
+                    batch.remove(revision_key(doc_id, rev_id).as_bytes());
+                }
+            },
+        }
+        self.db.apply_batch(batch).map_err(internal_error)
+    }
+}
+
+impl RevisionStore for SledRevisionStore {
+    fn set_revisions(&self, revisions: Vec<RevisionPB>) -> BoxResultFuture<(), CollaborateError> {
+        let db = self.db.clone();
+        Box::pin(async move {
+            let mut batch = sled::Batch::default();
+            for revision in revisions {
+                let value = revision.write_to_bytes()?;
+                batch.insert(revision_key(&revision.doc_id, revision.rev_id).as_bytes(), value);
+            }
+            db.apply_batch(batch).map_err(internal_error)
+        })
+    }
+
+    fn get_revisions(
+        &self,
+        doc_id: &str,
+        rev_ids: Option<Vec<i64>>,
+    ) -> BoxResultFuture<Vec<RevisionPB>, CollaborateError> {
+        let store = self.clone();
+        let doc_id = doc_id.to_owned();
+        Box::pin(async move { store.read_revisions(&doc_id, rev_ids) })
+    }
+
+    fn delete_revisions(&self, doc_id: &str, rev_ids: Option<Vec<i64>>) -> BoxResultFuture<(), CollaborateError> {
+        let store = self.clone();
+        let doc_id = doc_id.to_owned();
+        Box::pin(async move { store.remove_revisions(&doc_id, rev_ids) })
+    }
+}
@@ -0,0 +1,86 @@
+use bytes::Bytes;
+use flowy_collaboration::{entities::revision::Revision, util::md5};
+use lib_ot::rich_text::{AttributeBuilder, RichTextAttribute, RichTextAttributes, RichTextDelta};
+use proptest::prelude::*;
+
+pub const DOC_ID: &str = "doc";
+pub const BASE_REV_ID: i64 = 1;
+
+#[derive(Clone, Debug)]
+pub enum OpSpec {
+    Retain(usize, Option<bool>),
+    Delete(usize),
+    Insert(String, Option<bool>),
+}
+
+// Besides the ascii characters, 'é' and '中' check that the lengths are
+// counted in utf16 units.
+pub fn text(max_len: usize) -> impl Strategy<Value = String> {
+    proptest::string::string_regex(&format!("[a-z0-9 é中\n]{{0,{}}}", max_len)).unwrap()
+}
+
+fn op_spec(formatted: bool) -> impl Strategy<Value = OpSpec> {
+    let bold = move || {
+        proptest::option::of(any::<bool>()).prop_map(move |bold| match formatted {
+            true => bold,
+            false => None,
+        })
+    };
+    prop_oneof![
+        (1..8usize, bold()).prop_map(|(n, bold)| OpSpec::Retain(n, bold)),
+        (1..8usize).prop_map(OpSpec::Delete),
+        (text(6).prop_filter("Empty insert", |s| !s.is_empty()), bold()).prop_map(|(s, bold)| OpSpec::Insert(s, bold)),
+    ]
+}
+
+/// The edits of a client, the retains and the inserts may set the bold
+/// attribute.
+pub fn op_specs() -> impl Strategy<Value = Vec<OpSpec>> { proptest::collection::vec(op_spec(true), 0..8) }
+
+/// The edits of a client that only change the text.
+pub fn plain_op_specs() -> impl Strategy<Value = Vec<OpSpec>> { proptest::collection::vec(op_spec(false), 0..8) }
+
+/// Builds a delta that applies to `base`: the retains and the deletes are
+/// clamped to the rest of the text and the end of the text is retained.
+pub fn make_delta(base: &str, specs: &[OpSpec]) -> RichTextDelta {
+    let mut remaining = base.encode_utf16().count();
+    let mut delta = RichTextDelta::default();
+    for spec in specs {
+        match spec {
+            OpSpec::Retain(n, bold) => {
+                let n = (*n).min(remaining);
+                if n > 0 {
+                    delta.retain(n, attributes(*bold));
+                    remaining -= n;
+                }
+            },
+            OpSpec::Delete(n) => {
+                let n = (*n).min(remaining);
+                if n > 0 {
+                    delta.delete(n);
+                    remaining -= n;
+                }
+            },
+            OpSpec::Insert(s, bold) => delta.insert(s, attributes(*bold)),
+        }
+    }
+    if remaining > 0 {
+        delta.retain(remaining, RichTextAttributes::default());
+    }
+    delta
+}
+
+/// The revision that a client sends for `delta`, both clients of a pair use
+/// the same base revision.
+pub fn make_revision(user_id: &str, rev_id: i64, delta: &RichTextDelta) -> Revision {
+    let bytes = delta.to_bytes();
+    let md5 = md5(&bytes);
+    Revision::new(DOC_ID, BASE_REV_ID, rev_id, Bytes::from(bytes.to_vec()), user_id, md5)
+}
+
+fn attributes(bold: Option<bool>) -> RichTextAttributes {
+    match bold {
+        None => RichTextAttributes::default(),
+        Some(bold) => AttributeBuilder::new().add_attr(RichTextAttribute::Bold(bold)).build(),
+    }
+}
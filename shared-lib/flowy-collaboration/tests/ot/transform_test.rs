@@ -0,0 +1,64 @@
+use crate::strategy::*;
+use lib_ot::{core::OperationTransformable, rich_text::RichTextDelta};
+use proptest::prelude::*;
+
+proptest! {
+    // TP1: applying a then b' gives the same document as applying b then a',
+    // where (a', b') = a.transform(b).
+    #[test]
+    fn transform_converges(base in text(20), a in plain_op_specs(), b in plain_op_specs()) {
+        let a = make_delta(&base, &a);
+        let b = make_delta(&base, &b);
+        let (a_prime, b_prime) = a.transform(&b).unwrap();
+
+        let ab_prime = a.compose(&b_prime).unwrap();
+        let ba_prime = b.compose(&a_prime).unwrap();
+        prop_assert_eq!(&ab_prime, &ba_prime);
+        prop_assert_eq!(ab_prime.apply(&base).unwrap(), ba_prime.apply(&base).unwrap());
+    }
+
+    // With the formats, only the text is compared. The transform gives both
+    // sides the same attributes when the two clients format the same range,
+    // so the attributes of a conflicting format may differ.
+    #[test]
+    fn transform_converges_with_formats(base in text(20), a in op_specs(), b in op_specs()) {
+        let a = make_delta(&base, &a);
+        let b = make_delta(&base, &b);
+        let (a_prime, b_prime) = a.transform(&b).unwrap();
+
+        let ab_prime = a.compose(&b_prime).unwrap();
+        let ba_prime = b.compose(&a_prime).unwrap();
+        prop_assert_eq!(ab_prime.apply(&base).unwrap(), ba_prime.apply(&base).unwrap());
+    }
+
+    // The same as above, but the deltas go through the revisions that the
+    // clients send, and the document delta is composed like the server does.
+    #[test]
+    fn concurrent_revisions_converge(base in text(20), a in op_specs(), b in op_specs()) {
+        let mut document = RichTextDelta::default();
+        document.insert(&base, Default::default());
+
+        let local = make_revision("user_a", 2, &make_delta(&base, &a));
+        let remote = make_revision("user_b", 2, &make_delta(&base, &b));
+        let local_delta = RichTextDelta::from_bytes(&local.delta_data).unwrap();
+        let remote_delta = RichTextDelta::from_bytes(&remote.delta_data).unwrap();
+        let (local_prime, remote_prime) = local_delta.transform(&remote_delta).unwrap();
+
+        let local_then_remote = document.compose(&local_delta).unwrap().compose(&remote_prime).unwrap();
+        let remote_then_local = document.compose(&remote_delta).unwrap().compose(&local_prime).unwrap();
+        prop_assert_eq!(local_then_remote.apply("").unwrap(), remote_then_local.apply("").unwrap());
+        prop_assert_eq!(local_then_remote.utf16_target_len, remote_then_local.utf16_target_len);
+    }
+
+    // Composing a delta with its inverse gives back the base text.
+    #[test]
+    fn invert_restores_base(base in text(20), a in op_specs()) {
+        let mut document = RichTextDelta::default();
+        document.insert(&base, Default::default());
+        let a = make_delta(&base, &a);
+        let inverted = a.invert(&document);
+
+        let restored = document.compose(&a).unwrap().compose(&inverted).unwrap();
+        prop_assert_eq!(restored.apply("").unwrap(), base);
+    }
+}
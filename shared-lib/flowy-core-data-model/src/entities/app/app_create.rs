@@ -23,6 +23,11 @@ pub struct CreateAppRequest {
 
     #[pb(index = 4)]
     pub color_style: ColorStyle,
+
+    // Generated by the client. If an app with this id exists, the create is
+    // a retry and returns that app.
+    #[pb(index = 5, one_of)]
+    pub app_id: Option<String>,
 }
 
 #[derive(ProtoBuf, Default, Debug, Clone)]
@@ -31,7 +36,7 @@ pub struct ColorStyle {
     pub theme_color: String,
 }
 
-#[derive(ProtoBuf, Default, Debug)]
+#[derive(ProtoBuf, Default, Debug, Clone)]
 pub struct CreateAppParams {
     #[pb(index = 1)]
     pub workspace_id: String,
@@ -44,6 +49,10 @@ pub struct CreateAppParams {
 
     #[pb(index = 4)]
     pub color_style: ColorStyle,
+
+    // Empty when the server generates the id, i.e. for the older clients.
+    #[pb(index = 5)]
+    pub app_id: String,
 }
 
 impl TryInto<CreateAppParams> for CreateAppRequest {
@@ -53,12 +62,19 @@ impl TryInto<CreateAppParams> for CreateAppRequest {
         let name = AppName::parse(self.name)?;
         let id = WorkspaceIdentify::parse(self.workspace_id)?;
         let color_style = AppColorStyle::parse(self.color_style.theme_color.clone())?;
+        let app_id = match self.app_id {
+            None => uuid::Uuid::new_v4().to_string(),
+            Some(app_id) => uuid::Uuid::parse_str(&app_id)
+                .map_err(|_| ErrorCode::AppIdInvalid)?
+                .to_string(),
+        };
 
         Ok(CreateAppParams {
             workspace_id: id.0,
             name: name.0,
             desc: self.desc,
             color_style: color_style.into(),
+            app_id,
         })
     }
 }
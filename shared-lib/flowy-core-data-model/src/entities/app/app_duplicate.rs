@@ -0,0 +1,13 @@
+use crate::entities::app::App;
+use flowy_derive::ProtoBuf;
+
+// The payload of the `AppDuplicated` notification. The belongings of the app
+// are the copied views.
+#[derive(PartialEq, Default, ProtoBuf, Clone, Debug)]
+pub struct AppDuplicated {
+    #[pb(index = 1)]
+    pub source_app_id: String,
+
+    #[pb(index = 2)]
+    pub app: App,
+}
@@ -1,6 +1,8 @@
 mod app_create;
+mod app_duplicate;
 mod app_query;
 mod app_update;
 pub use app_create::*;
+pub use app_duplicate::*;
 pub use app_query::*;
 pub use app_update::*;
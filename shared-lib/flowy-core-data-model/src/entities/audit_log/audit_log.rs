@@ -0,0 +1,93 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{
+        event_log::{EventLogCursor, EventLogLimit},
+        workspace::WorkspaceIdentify,
+    },
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// A change that a user made to a workspace, an app or a view. The `before` and
+// the `after` are the json of the object around the change, empty if it didn't
+// exist before or after it.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct AuditLogEntry {
+    #[pb(index = 1)]
+    pub seq: i64,
+
+    #[pb(index = 2)]
+    pub workspace_id: String,
+
+    #[pb(index = 3)]
+    pub actor_id: String,
+
+    #[pb(index = 4)]
+    pub action: String,
+
+    #[pb(index = 5)]
+    pub object_type: String,
+
+    #[pb(index = 6)]
+    pub object_id: String,
+
+    #[pb(index = 7)]
+    pub before: String,
+
+    #[pb(index = 8)]
+    pub after: String,
+
+    #[pb(index = 9)]
+    pub create_time: i64,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct AuditLogPage {
+    #[pb(index = 1)]
+    pub items: Vec<AuditLogEntry>,
+
+    #[pb(index = 2)]
+    pub next_cursor: i64,
+
+    #[pb(index = 3)]
+    pub has_more: bool,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ReadAuditLogRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub cursor: i64,
+
+    #[pb(index = 3)]
+    pub limit: i64,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct ReadAuditLogParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub cursor: i64,
+
+    #[pb(index = 3)]
+    pub limit: i64,
+}
+
+impl TryInto<ReadAuditLogParams> for ReadAuditLogRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ReadAuditLogParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        let cursor = EventLogCursor::parse(self.cursor)?;
+        let limit = EventLogLimit::parse(self.limit)?;
+        Ok(ReadAuditLogParams {
+            workspace_id: workspace_id.0,
+            cursor: cursor.0,
+            limit: limit.0,
+        })
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::backup::{BackupDir, BackupKeepCount},
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum BackupFrequency {
+    Daily  = 0,
+    Weekly = 1,
+}
+
+impl BackupFrequency {
+    // The seconds between two scheduled backups.
+    pub fn period(&self) -> i64 {
+        match self {
+            BackupFrequency::Daily => 24 * 60 * 60,
+            BackupFrequency::Weekly => 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+impl std::default::Default for BackupFrequency {
+    fn default() -> Self { BackupFrequency::Daily }
+}
+
+impl std::convert::From<i32> for BackupFrequency {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => BackupFrequency::Daily,
+            1 => BackupFrequency::Weekly,
+            _ => {
+                log::error!("Invalid backup frequency: {}", val);
+                BackupFrequency::Daily
+            },
+        }
+    }
+}
+
+// The scheduled backups of the current user. The last backup time and error
+// are set by the scheduler, the last error is empty if the last backup
+// succeeded.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct BackupSetting {
+    #[pb(index = 1)]
+    pub enabled: bool,
+
+    #[pb(index = 2)]
+    pub dir: String,
+
+    #[pb(index = 3)]
+    pub frequency: BackupFrequency,
+
+    #[pb(index = 4)]
+    pub keep_count: i64,
+
+    #[pb(index = 5)]
+    pub last_backup_time: i64,
+
+    #[pb(index = 6)]
+    pub last_error: String,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct UpdateBackupSettingRequest {
+    #[pb(index = 1)]
+    pub enabled: bool,
+
+    // It may be empty while the backups are turned off.
+    #[pb(index = 2)]
+    pub dir: String,
+
+    #[pb(index = 3)]
+    pub frequency: BackupFrequency,
+
+    // The number of backups that are kept in the directory, the oldest one is
+    // removed after a new one was written.
+    #[pb(index = 4)]
+    pub keep_count: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct BackupSettingParams {
+    pub enabled: bool,
+    pub dir: String,
+    pub frequency: BackupFrequency,
+    pub keep_count: i64,
+}
+
+impl TryInto<BackupSettingParams> for UpdateBackupSettingRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<BackupSettingParams, Self::Error> {
+        let dir = match self.enabled {
+            true => BackupDir::parse(self.dir)?.0,
+            false => self.dir.trim().to_owned(),
+        };
+        let keep_count = BackupKeepCount::parse(self.keep_count)?;
+        Ok(BackupSettingParams {
+            enabled: self.enabled,
+            dir,
+            frequency: self.frequency,
+            keep_count: keep_count.0,
+        })
+    }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct BackupResult {
+    #[pb(index = 1)]
+    pub path: String,
+
+    #[pb(index = 2)]
+    pub document_count: i64,
+
+    #[pb(index = 3)]
+    pub backup_time: i64,
+}
+
+// The payload of the notification that a scheduled backup failed. A backup
+// that can't be read back is removed, so the rotation never drops the last
+// good one for it.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct BackupFailure {
+    #[pb(index = 1)]
+    pub dir: String,
+
+    #[pb(index = 2)]
+    pub error: String,
+
+    #[pb(index = 3)]
+    pub time: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryBackupsRequest {
+    #[pb(index = 1)]
+    pub dir: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct QueryBackupsParams {
+    pub dir: String,
+}
+
+impl TryInto<QueryBackupsParams> for QueryBackupsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<QueryBackupsParams, Self::Error> {
+        let dir = BackupDir::parse(self.dir)?;
+        Ok(QueryBackupsParams { dir: dir.0 })
+    }
+}
+
+// The manifest of an archive that can be restored, e.g. a backup of another
+// device. The user id is the one of the account that was backed up.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct BackupManifest {
+    #[pb(index = 1)]
+    pub path: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub export_time: i64,
+
+    #[pb(index = 4)]
+    pub document_count: i64,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedBackupManifest {
+    #[pb(index = 1)]
+    pub items: Vec<BackupManifest>,
+}
+
+impl_def_and_def_mut!(RepeatedBackupManifest, BackupManifest);
+
+// The archive that the folder of the next new user is restored from, instead
+// of the default workspace. An empty path clears the selection.
+#[derive(Default, ProtoBuf)]
+pub struct SelectBackupRestoreRequest {
+    #[pb(index = 1)]
+    pub path: String,
+}
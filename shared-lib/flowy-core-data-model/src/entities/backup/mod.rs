@@ -0,0 +1,3 @@
+mod backup;
+
+pub use backup::*;
@@ -0,0 +1,67 @@
+use crate::{errors::ErrorCode, parser::view::ViewIdentify};
+use flowy_derive::ProtoBuf;
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+// Like the grid, the checklist is kept as json in the text of its view's
+// document. The items are in the order they are shown.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct Checklist {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub items: Vec<ChecklistItem>,
+}
+
+impl Checklist {
+    pub fn new(checklist_id: &str) -> Self {
+        Self {
+            id: checklist_id.to_owned(),
+            items: vec![],
+        }
+    }
+
+    // The delta that the document of a new checklist starts with.
+    pub fn to_delta_string(&self) -> String {
+        let json = serde_json::to_string(self).unwrap_or_default();
+        serde_json::json!([{ "insert": format!("{}\n", json) }]).to_string()
+    }
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub title: String,
+
+    #[pb(index = 3)]
+    pub done: bool,
+
+    // The unix timestamp in seconds, 0 if the item has no due date.
+    #[pb(index = 4)]
+    pub due_date: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryChecklistRequest {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ChecklistId {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+}
+
+impl TryInto<ChecklistId> for QueryChecklistRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ChecklistId, Self::Error> {
+        let checklist_id = ViewIdentify::parse(self.checklist_id)?.0;
+        Ok(ChecklistId { checklist_id })
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{
+        checklist::{ChecklistItemIdentify, ChecklistItemTitle},
+        view::ViewIdentify,
+    },
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The item is appended to the checklist.
+#[derive(Default, ProtoBuf)]
+pub struct CreateChecklistItemRequest {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+
+    #[pb(index = 2)]
+    pub title: String,
+
+    #[pb(index = 3)]
+    pub due_date: i64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct CreateChecklistItemParams {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+
+    #[pb(index = 2)]
+    pub title: String,
+
+    #[pb(index = 3)]
+    pub due_date: i64,
+}
+
+impl TryInto<CreateChecklistItemParams> for CreateChecklistItemRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateChecklistItemParams, Self::Error> {
+        let checklist_id = ViewIdentify::parse(self.checklist_id)?.0;
+        let title = ChecklistItemTitle::parse(self.title)?.0;
+        Ok(CreateChecklistItemParams {
+            checklist_id,
+            title,
+            due_date: self.due_date,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateChecklistItemRequest {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+
+    #[pb(index = 2)]
+    pub item_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub title: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub due_date: Option<i64>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct UpdateChecklistItemParams {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+
+    #[pb(index = 2)]
+    pub item_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub title: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub due_date: Option<i64>,
+}
+
+impl TryInto<UpdateChecklistItemParams> for UpdateChecklistItemRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateChecklistItemParams, Self::Error> {
+        let checklist_id = ViewIdentify::parse(self.checklist_id)?.0;
+        let item_id = ChecklistItemIdentify::parse(self.item_id)?.0;
+        let title = match self.title {
+            None => None,
+            Some(title) => Some(ChecklistItemTitle::parse(title)?.0),
+        };
+        Ok(UpdateChecklistItemParams {
+            checklist_id,
+            item_id,
+            title,
+            due_date: self.due_date,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryChecklistItemRequest {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+
+    #[pb(index = 2)]
+    pub item_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ChecklistItemId {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+
+    #[pb(index = 2)]
+    pub item_id: String,
+}
+
+impl TryInto<ChecklistItemId> for QueryChecklistItemRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ChecklistItemId, Self::Error> {
+        let checklist_id = ViewIdentify::parse(self.checklist_id)?.0;
+        let item_id = ChecklistItemIdentify::parse(self.item_id)?.0;
+        Ok(ChecklistItemId { checklist_id, item_id })
+    }
+}
+
+// The index is clamped to the bounds of the checklist.
+#[derive(Default, ProtoBuf)]
+pub struct MoveChecklistItemRequest {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+
+    #[pb(index = 2)]
+    pub item_id: String,
+
+    #[pb(index = 3)]
+    pub to_index: i32,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct MoveChecklistItemParams {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+
+    #[pb(index = 2)]
+    pub item_id: String,
+
+    #[pb(index = 3)]
+    pub to_index: i32,
+}
+
+impl TryInto<MoveChecklistItemParams> for MoveChecklistItemRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<MoveChecklistItemParams, Self::Error> {
+        let checklist_id = ViewIdentify::parse(self.checklist_id)?.0;
+        let item_id = ChecklistItemIdentify::parse(self.item_id)?.0;
+        Ok(MoveChecklistItemParams {
+            checklist_id,
+            item_id,
+            to_index: self.to_index,
+        })
+    }
+}
+
+// All the items of the checklist are completed if the item_ids is empty.
+#[derive(Default, ProtoBuf)]
+pub struct CompleteChecklistItemsRequest {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+
+    #[pb(index = 2)]
+    pub item_ids: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct CompleteChecklistItemsParams {
+    #[pb(index = 1)]
+    pub checklist_id: String,
+
+    #[pb(index = 2)]
+    pub item_ids: Vec<String>,
+}
+
+impl TryInto<CompleteChecklistItemsParams> for CompleteChecklistItemsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CompleteChecklistItemsParams, Self::Error> {
+        let checklist_id = ViewIdentify::parse(self.checklist_id)?.0;
+        let mut item_ids = vec![];
+        for item_id in self.item_ids {
+            item_ids.push(ChecklistItemIdentify::parse(item_id)?.0);
+        }
+        Ok(CompleteChecklistItemsParams { checklist_id, item_ids })
+    }
+}
@@ -0,0 +1,5 @@
+pub use checklist::*;
+pub use checklist_item::*;
+
+mod checklist;
+mod checklist_item;
@@ -0,0 +1,32 @@
+use crate::errors::ErrorCode;
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportDiagnosticsRequest {
+    // The directory that the bundle will be written to.
+    #[pb(index = 1)]
+    pub dir: String,
+}
+
+#[derive(Default, Debug)]
+pub struct ExportDiagnosticsParams {
+    pub dir: String,
+}
+
+impl TryInto<ExportDiagnosticsParams> for ExportDiagnosticsRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ExportDiagnosticsParams, Self::Error> {
+        if self.dir.trim().is_empty() {
+            return Err(ErrorCode::ExportPathInvalid);
+        }
+
+        Ok(ExportDiagnosticsParams { dir: self.dir })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportDiagnosticsResult {
+    #[pb(index = 1)]
+    pub path: String,
+}
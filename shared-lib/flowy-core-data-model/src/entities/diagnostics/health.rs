@@ -0,0 +1,69 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum HealthStatus {
+    Healthy   = 0,
+    Degraded  = 1,
+    Unhealthy = 2,
+    // The check doesn't apply, e.g. the web socket check without a server.
+    Skipped   = 3,
+}
+
+impl std::default::Default for HealthStatus {
+    fn default() -> Self { HealthStatus::Healthy }
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct HealthCheck {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub status: HealthStatus,
+
+    #[pb(index = 3)]
+    pub detail: String,
+}
+
+impl HealthCheck {
+    pub fn new<T: ToString>(name: &str, status: HealthStatus, detail: T) -> Self {
+        Self {
+            name: name.to_owned(),
+            status,
+            detail: detail.to_string(),
+        }
+    }
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct HealthReport {
+    // The worst status of the checks that were not skipped.
+    #[pb(index = 1)]
+    pub status: HealthStatus,
+
+    #[pb(index = 2)]
+    pub checks: Vec<HealthCheck>,
+
+    #[pb(index = 3)]
+    pub pending_revisions: i64,
+
+    #[pb(index = 4)]
+    pub create_time: i64,
+}
+
+impl HealthReport {
+    pub fn new(checks: Vec<HealthCheck>, pending_revisions: i64, create_time: i64) -> Self {
+        let status = checks
+            .iter()
+            .map(|check| check.status)
+            .filter(|status| *status != HealthStatus::Skipped)
+            .max_by_key(|status| *status as i32)
+            .unwrap_or(HealthStatus::Healthy);
+        Self {
+            status,
+            checks,
+            pending_revisions,
+            create_time,
+        }
+    }
+}
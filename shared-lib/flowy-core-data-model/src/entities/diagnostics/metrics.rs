@@ -0,0 +1,9 @@
+use flowy_derive::ProtoBuf;
+
+// The counters, gauges and latencies of the client serialized as json, the
+// names of the metrics change more often than the clients are released.
+#[derive(Default, ProtoBuf)]
+pub struct Metrics {
+    #[pb(index = 1)]
+    pub json: String,
+}
@@ -0,0 +1,7 @@
+mod bundle;
+mod health;
+mod metrics;
+
+pub use bundle::*;
+pub use health::*;
+pub use metrics::*;
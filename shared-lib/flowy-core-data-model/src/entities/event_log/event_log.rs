@@ -0,0 +1,75 @@
+use crate::{
+    errors::ErrorCode,
+    parser::event_log::{EventLogCursor, EventLogLimit},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The payload is the json of the changed object at the time of the event. The
+// content of the documents is not logged, it's read from the document itself.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct EventLogEntry {
+    #[pb(index = 1)]
+    pub seq: i64,
+
+    #[pb(index = 2)]
+    pub ty: String,
+
+    #[pb(index = 3)]
+    pub object_id: String,
+
+    #[pb(index = 4)]
+    pub payload: String,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+}
+
+// The consumer passes the `next_cursor` to the next read. If its cursor is
+// behind the `first_seq`, the entries in between were pruned and it should
+// reload the folder instead of replaying the log.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct EventLogPage {
+    #[pb(index = 1)]
+    pub items: Vec<EventLogEntry>,
+
+    #[pb(index = 2)]
+    pub next_cursor: i64,
+
+    #[pb(index = 3)]
+    pub first_seq: i64,
+
+    #[pb(index = 4)]
+    pub has_more: bool,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ReadEventLogRequest {
+    #[pb(index = 1)]
+    pub cursor: i64,
+
+    #[pb(index = 2)]
+    pub limit: i64,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct ReadEventLogParams {
+    #[pb(index = 1)]
+    pub cursor: i64,
+
+    #[pb(index = 2)]
+    pub limit: i64,
+}
+
+impl TryInto<ReadEventLogParams> for ReadEventLogRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ReadEventLogParams, Self::Error> {
+        let cursor = EventLogCursor::parse(self.cursor)?;
+        let limit = EventLogLimit::parse(self.limit)?;
+        Ok(ReadEventLogParams {
+            cursor: cursor.0,
+            limit: limit.0,
+        })
+    }
+}
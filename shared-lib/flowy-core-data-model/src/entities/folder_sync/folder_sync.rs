@@ -0,0 +1,95 @@
+use crate::entities::{
+    app::{CreateAppParams, RepeatedApp, UpdateAppParams},
+    trash::RepeatedTrash,
+    view::{CreateViewParams, RepeatedView, UpdateViewParams},
+    workspace::RepeatedWorkspace,
+};
+use flowy_derive::ProtoBuf;
+
+// The apps and the views are created in one request. Their ids come from the
+// client, so the views may belong to the apps of the same batch.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct BatchCreateParams {
+    #[pb(index = 1)]
+    pub apps: Vec<CreateAppParams>,
+
+    #[pb(index = 2)]
+    pub views: Vec<CreateViewParams>,
+}
+
+impl BatchCreateParams {
+    pub fn is_empty(&self) -> bool { self.apps.is_empty() && self.views.is_empty() }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct BatchCreateResult {
+    #[pb(index = 1)]
+    pub apps: RepeatedApp,
+
+    #[pb(index = 2)]
+    pub views: RepeatedView,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct BatchReadParams {
+    #[pb(index = 1)]
+    pub app_ids: Vec<String>,
+
+    #[pb(index = 2)]
+    pub view_ids: Vec<String>,
+}
+
+// The ids that aren't found on the server are left out.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct BatchReadResult {
+    #[pb(index = 1)]
+    pub apps: RepeatedApp,
+
+    #[pb(index = 2)]
+    pub views: RepeatedView,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct BatchUpdateParams {
+    #[pb(index = 1)]
+    pub apps: Vec<UpdateAppParams>,
+
+    #[pb(index = 2)]
+    pub views: Vec<UpdateViewParams>,
+}
+
+impl BatchUpdateParams {
+    pub fn is_empty(&self) -> bool { self.apps.is_empty() && self.views.is_empty() }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct FolderSyncParams {
+    // The cursor of the last delta that was applied, 0 to get a snapshot.
+    #[pb(index = 1)]
+    pub cursor: i64,
+}
+
+// What changed in the folder of the user since the cursor of the request. A
+// snapshot has every workspace and the whole trash instead, e.g. for the first
+// sync or when the server dropped the changes of the cursor.
+#[derive(PartialEq, ProtoBuf, Default, Debug)]
+pub struct FolderDelta {
+    #[pb(index = 1)]
+    pub cursor: i64,
+
+    #[pb(index = 2)]
+    pub is_snapshot: bool,
+
+    // The workspaces with the apps and the views that changed.
+    #[pb(index = 3)]
+    pub workspaces: RepeatedWorkspace,
+
+    #[pb(index = 4)]
+    pub deleted_app_ids: Vec<String>,
+
+    #[pb(index = 5)]
+    pub deleted_view_ids: Vec<String>,
+
+    #[pb(index = 6)]
+    pub trash: RepeatedTrash,
+}
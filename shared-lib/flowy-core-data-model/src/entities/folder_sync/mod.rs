@@ -0,0 +1,3 @@
+mod folder_sync;
+
+pub use folder_sync::*;
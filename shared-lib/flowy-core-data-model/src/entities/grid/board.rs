@@ -0,0 +1,109 @@
+use crate::{
+    entities::grid::Row,
+    errors::ErrorCode,
+    parser::{grid::RowIdentify, view::ViewIdentify},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The rows of the grid grouped by the options of a single select field. The
+// rows without an option are in the group whose id is empty, which comes first.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct Board {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub group_field_id: String,
+
+    #[pb(index = 3)]
+    pub groups: Vec<BoardGroup>,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct BoardGroup {
+    // The id of the select option
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub color: String,
+
+    #[pb(index = 4)]
+    pub rows: Vec<Row>,
+}
+
+// Moves the card into the group, in front of `before_row_id` or at the end of
+// the group if it's None. The group id is empty for the group of the rows
+// without an option.
+#[derive(Default, ProtoBuf)]
+pub struct MoveCardRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub to_group_id: String,
+
+    #[pb(index = 4, one_of)]
+    pub before_row_id: Option<String>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct MoveCardParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub to_group_id: String,
+
+    #[pb(index = 4, one_of)]
+    pub before_row_id: Option<String>,
+}
+
+impl TryInto<MoveCardParams> for MoveCardRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<MoveCardParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let row_id = RowIdentify::parse(self.row_id)?.0;
+        let before_row_id = match self.before_row_id {
+            None => None,
+            Some(before_row_id) => Some(RowIdentify::parse(before_row_id)?.0),
+        };
+        Ok(MoveCardParams {
+            grid_id,
+            row_id,
+            to_group_id: self.to_group_id,
+            before_row_id,
+        })
+    }
+}
+
+// The payload of the `BoardCardMoved` notification. The index is the position
+// of the card in its new group.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct CardMoved {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub from_group_id: String,
+
+    #[pb(index = 4)]
+    pub to_group_id: String,
+
+    #[pb(index = 5)]
+    pub to_index: i32,
+}
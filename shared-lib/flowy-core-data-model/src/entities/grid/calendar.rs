@@ -0,0 +1,129 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{
+        grid::{DateRange, RowIdentify},
+        view::ViewIdentify,
+    },
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// A row of the grid that has a date. The title is the text of the first rich
+// text field.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct CalendarEvent {
+    #[pb(index = 1)]
+    pub row_id: String,
+
+    // The unix timestamp in seconds
+    #[pb(index = 2)]
+    pub timestamp: i64,
+
+    #[pb(index = 3)]
+    pub title: String,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedCalendarEvent {
+    #[pb(index = 1)]
+    pub items: Vec<CalendarEvent>,
+}
+
+// Reads the events whose timestamp is in [start, end). The range is the one
+// that the calendar shows, the changes inside it are sent with the
+// `CalendarEventsChanged` notification until another range is read.
+#[derive(Default, ProtoBuf)]
+pub struct QueryCalendarEventsRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub start: i64,
+
+    #[pb(index = 3)]
+    pub end: i64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug, PartialEq)]
+pub struct CalendarRange {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub start: i64,
+
+    #[pb(index = 3)]
+    pub end: i64,
+}
+
+impl CalendarRange {
+    pub fn contains(&self, timestamp: i64) -> bool { self.start <= timestamp && timestamp < self.end }
+}
+
+impl TryInto<CalendarRange> for QueryCalendarEventsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CalendarRange, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let range = DateRange::parse(self.start, self.end)?;
+        Ok(CalendarRange {
+            grid_id,
+            start: range.start,
+            end: range.end,
+        })
+    }
+}
+
+// Sets the date of the row. The client keeps the time of the day when it moves
+// the event to another day.
+#[derive(Default, ProtoBuf)]
+pub struct MoveCalendarEventRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub timestamp: i64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct MoveCalendarEventParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub timestamp: i64,
+}
+
+impl TryInto<MoveCalendarEventParams> for MoveCalendarEventRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<MoveCalendarEventParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let row_id = RowIdentify::parse(self.row_id)?.0;
+        Ok(MoveCalendarEventParams {
+            grid_id,
+            row_id,
+            timestamp: self.timestamp,
+        })
+    }
+}
+
+// The payload of the `CalendarEventsChanged` notification. The events that
+// left the range are deleted.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct CalendarChangeset {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub updated_events: Vec<CalendarEvent>,
+
+    #[pb(index = 3)]
+    pub deleted_row_ids: Vec<String>,
+}
@@ -0,0 +1,310 @@
+use crate::entities::grid::GridSetting;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use serde::{Deserialize, Serialize};
+
+// The grid is kept as json in the text of its view's document, so its edits
+// are synced and saved as revisions like any other document.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct Grid {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub fields: Vec<Field>,
+
+    #[pb(index = 3)]
+    pub rows: Vec<Row>,
+
+    // The single select field that the board groups the rows by. The first
+    // single select field is used if it's empty.
+    #[pb(index = 4)]
+    #[serde(default)]
+    pub group_field_id: String,
+
+    // The date time field that places the rows on the calendar. The first
+    // date time field is used if it's empty.
+    #[pb(index = 5)]
+    #[serde(default)]
+    pub date_field_id: String,
+
+    #[pb(index = 6)]
+    #[serde(default)]
+    pub setting: GridSetting,
+
+    // The grids that have a relation field to this grid. Their relations and
+    // rollups follow the edits of the rows of this grid.
+    #[pb(index = 7)]
+    #[serde(default)]
+    pub linked_grid_ids: Vec<String>,
+}
+
+impl Grid {
+    pub fn new(grid_id: &str) -> Self {
+        let name_field = Field::new("Name", FieldType::RichText);
+        let rows = (0..3).map(|_| Row::new()).collect::<Vec<Row>>();
+        Self {
+            id: grid_id.to_owned(),
+            fields: vec![name_field],
+            rows,
+            group_field_id: "".to_owned(),
+            date_field_id: "".to_owned(),
+            setting: GridSetting::default(),
+            linked_grid_ids: vec![],
+        }
+    }
+
+    // A board is a grid whose rows are grouped by the options of its status
+    // field.
+    pub fn new_board(grid_id: &str) -> Self {
+        let mut grid = Grid::new(grid_id);
+        let mut status_field = Field::new("Status", FieldType::SingleSelect);
+        status_field.select_options = ["To Do", "Doing", "Done"]
+            .iter()
+            .map(|name| SelectOption::new(name))
+            .collect();
+        grid.group_field_id = status_field.id.clone();
+        grid.fields.push(status_field);
+        grid
+    }
+
+    // A calendar is a grid whose rows are placed on the days of its date field.
+    pub fn new_calendar(grid_id: &str) -> Self {
+        let mut grid = Grid::new(grid_id);
+        let date_field = Field::new("Date", FieldType::DateTime);
+        grid.date_field_id = date_field.id.clone();
+        grid.fields.push(date_field);
+        grid
+    }
+
+    // The delta that the document of a new grid starts with.
+    pub fn to_delta_string(&self) -> String {
+        let json = serde_json::to_string(self).unwrap_or_default();
+        serde_json::json!([{ "insert": format!("{}\n", json) }]).to_string()
+    }
+}
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Serialize, Deserialize)]
+pub enum FieldType {
+    RichText     = 0,
+    Number       = 1,
+    DateTime     = 2,
+    SingleSelect = 3,
+    Formula      = 4,
+    Relation     = 5,
+    Rollup       = 6,
+}
+
+impl std::default::Default for FieldType {
+    fn default() -> Self { FieldType::RichText }
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct Field {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub field_type: FieldType,
+
+    // The options that the cells of a SingleSelect field choose from.
+    #[pb(index = 4)]
+    pub select_options: Vec<SelectOption>,
+
+    // The expression that computes the cells of a Formula field. The other
+    // fields are referenced by their names in braces, e.g. {Price} * 2.
+    #[pb(index = 5)]
+    #[serde(default)]
+    pub formula: String,
+
+    // The grid whose rows the cells of a Relation field link to.
+    #[pb(index = 6)]
+    #[serde(default)]
+    pub related_grid_id: String,
+
+    #[pb(index = 7)]
+    #[serde(default)]
+    pub rollup: RollupSetting,
+}
+
+impl Field {
+    pub fn new(name: &str, field_type: FieldType) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_owned(),
+            field_type,
+            select_options: vec![],
+            formula: "".to_owned(),
+            related_grid_id: "".to_owned(),
+            rollup: RollupSetting::default(),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct SelectOption {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub color: String,
+}
+
+impl SelectOption {
+    pub fn new(name: &str) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_owned(),
+            color: "".to_owned(),
+        }
+    }
+}
+
+// A Rollup field computes its cells from the rows that the cells of its
+// relation field link to. The target field is in the related grid and is
+// ignored by Count.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct RollupSetting {
+    #[pb(index = 1)]
+    pub relation_field_id: String,
+
+    #[pb(index = 2)]
+    pub target_field_id: String,
+
+    #[pb(index = 3)]
+    pub function: RollupFunction,
+}
+
+// The cells of the target field that are not numbers are skipped by Sum, Min
+// and Max.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Serialize, Deserialize)]
+pub enum RollupFunction {
+    Count = 0,
+    Sum   = 1,
+    Min   = 2,
+    Max   = 3,
+}
+
+impl std::default::Default for RollupFunction {
+    fn default() -> Self { RollupFunction::Count }
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedSelectOption {
+    #[pb(index = 1)]
+    pub items: Vec<SelectOption>,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct Row {
+    #[pb(index = 1)]
+    pub id: String,
+
+    // The cells of the fields that were never set are not stored.
+    #[pb(index = 2)]
+    pub cells: Vec<Cell>,
+}
+
+impl Row {
+    pub fn new() -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            cells: vec![],
+        }
+    }
+
+    pub fn cell(&self, field_id: &str) -> Option<&Cell> { self.cells.iter().find(|cell| cell.field_id == field_id) }
+}
+
+// The data of the cell is a string whatever the type of its field is:
+//  RichText      the text
+//  Number        a decimal number, e.g. 12.5
+//  DateTime      a unix timestamp in seconds
+//  SingleSelect  the id of the selected option
+//  Formula       the result of the formula, a number or a text. The cell is
+//                empty if the formula can't be evaluated for the row
+//  Relation      the ids of the related rows separated by commas
+//  Rollup        the number computed over the related rows
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct Cell {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub data: String,
+}
+
+// The payload of the `GridUpdated` notification, it only carries what the
+// edit changed.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct GridChangeset {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub updated_fields: Vec<Field>,
+
+    #[pb(index = 3)]
+    pub deleted_field_ids: Vec<String>,
+
+    #[pb(index = 4)]
+    pub inserted_rows: Vec<IndexedRow>,
+
+    #[pb(index = 5)]
+    pub updated_rows: Vec<Row>,
+
+    #[pb(index = 6)]
+    pub deleted_row_ids: Vec<String>,
+}
+
+impl GridChangeset {
+    pub fn new(grid_id: &str) -> Self {
+        Self {
+            grid_id: grid_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.updated_fields.is_empty()
+            && self.deleted_field_ids.is_empty()
+            && self.inserted_rows.is_empty()
+            && self.updated_rows.is_empty()
+            && self.deleted_row_ids.is_empty()
+    }
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct IndexedRow {
+    #[pb(index = 1)]
+    pub row: Row,
+
+    #[pb(index = 2)]
+    pub index: i32,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedRow {
+    #[pb(index = 1)]
+    pub items: Vec<Row>,
+}
+
+// The payload of the `GridRowsVisibilityChanged` notification. The visible
+// rows are the rows that were shown or moved by the filter and the sorts, the
+// index is their position among the visible rows.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RowVisibilityChangeset {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub visible_rows: Vec<IndexedRow>,
+
+    #[pb(index = 3)]
+    pub hidden_row_ids: Vec<String>,
+}
@@ -0,0 +1,56 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{
+        grid::{FieldIdentify, RowIdentify},
+        view::ViewIdentify,
+    },
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// An empty data clears the cell.
+#[derive(Default, ProtoBuf)]
+pub struct UpdateCellRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub field_id: String,
+
+    #[pb(index = 4)]
+    pub data: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct UpdateCellParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub field_id: String,
+
+    #[pb(index = 4)]
+    pub data: String,
+}
+
+impl TryInto<UpdateCellParams> for UpdateCellRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateCellParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let row_id = RowIdentify::parse(self.row_id)?.0;
+        let field_id = FieldIdentify::parse(self.field_id)?.0;
+        Ok(UpdateCellParams {
+            grid_id,
+            row_id,
+            field_id,
+            data: self.data,
+        })
+    }
+}
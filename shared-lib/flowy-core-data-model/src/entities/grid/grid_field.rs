@@ -0,0 +1,197 @@
+use crate::{
+    entities::grid::{FieldType, RepeatedSelectOption, RollupSetting, SelectOption},
+    errors::ErrorCode,
+    parser::{
+        grid::{FieldFormula, FieldIdentify, FieldName},
+        view::ViewIdentify,
+    },
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateFieldRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub field_type: FieldType,
+
+    #[pb(index = 4)]
+    pub select_options: Vec<SelectOption>,
+
+    #[pb(index = 5)]
+    pub formula: String,
+
+    #[pb(index = 6)]
+    pub related_grid_id: String,
+
+    #[pb(index = 7)]
+    pub rollup: RollupSetting,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct CreateFieldParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub field_type: FieldType,
+
+    #[pb(index = 4)]
+    pub select_options: Vec<SelectOption>,
+
+    #[pb(index = 5)]
+    pub formula: String,
+
+    #[pb(index = 6)]
+    pub related_grid_id: String,
+
+    #[pb(index = 7)]
+    pub rollup: RollupSetting,
+}
+
+impl TryInto<CreateFieldParams> for CreateFieldRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateFieldParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let name = FieldName::parse(self.name)?.0;
+        let formula = FieldFormula::parse(self.formula)?.0;
+        let related_grid_id = match self.field_type {
+            FieldType::Relation => ViewIdentify::parse(self.related_grid_id)?.0,
+            _ => self.related_grid_id,
+        };
+        Ok(CreateFieldParams {
+            grid_id,
+            name,
+            field_type: self.field_type,
+            select_options: self.select_options,
+            formula,
+            related_grid_id,
+            rollup: self.rollup,
+        })
+    }
+}
+
+// The cells that don't match the new type of the field are cleared. The cells
+// of a Formula or a Rollup field are computed again when they're updated, and
+// the cells of a Relation field are cleared when its related grid changes.
+#[derive(Default, ProtoBuf)]
+pub struct UpdateFieldRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub name: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub field_type: Option<FieldType>,
+
+    #[pb(index = 5, one_of)]
+    pub select_options: Option<RepeatedSelectOption>,
+
+    #[pb(index = 6, one_of)]
+    pub formula: Option<String>,
+
+    #[pb(index = 7, one_of)]
+    pub related_grid_id: Option<String>,
+
+    #[pb(index = 8, one_of)]
+    pub rollup: Option<RollupSetting>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct UpdateFieldParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub name: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub field_type: Option<FieldType>,
+
+    #[pb(index = 5, one_of)]
+    pub select_options: Option<RepeatedSelectOption>,
+
+    #[pb(index = 6, one_of)]
+    pub formula: Option<String>,
+
+    #[pb(index = 7, one_of)]
+    pub related_grid_id: Option<String>,
+
+    #[pb(index = 8, one_of)]
+    pub rollup: Option<RollupSetting>,
+}
+
+impl TryInto<UpdateFieldParams> for UpdateFieldRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateFieldParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let field_id = FieldIdentify::parse(self.field_id)?.0;
+        let name = match self.name {
+            None => None,
+            Some(name) => Some(FieldName::parse(name)?.0),
+        };
+        let formula = match self.formula {
+            None => None,
+            Some(formula) => Some(FieldFormula::parse(formula)?.0),
+        };
+        let related_grid_id = match self.related_grid_id {
+            None => None,
+            Some(related_grid_id) => Some(ViewIdentify::parse(related_grid_id)?.0),
+        };
+        Ok(UpdateFieldParams {
+            grid_id,
+            field_id,
+            name,
+            field_type: self.field_type,
+            select_options: self.select_options,
+            formula,
+            related_grid_id,
+            rollup: self.rollup,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryFieldRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct FieldId {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+}
+
+impl TryInto<FieldId> for QueryFieldRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<FieldId, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let field_id = FieldIdentify::parse(self.field_id)?.0;
+        Ok(FieldId { grid_id, field_id })
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{errors::ErrorCode, parser::view::ViewIdentify};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryGridRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct GridId {
+    #[pb(index = 1)]
+    pub grid_id: String,
+}
+
+impl TryInto<GridId> for QueryGridRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<GridId, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        Ok(GridId { grid_id })
+    }
+}
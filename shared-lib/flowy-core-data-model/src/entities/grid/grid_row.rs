@@ -0,0 +1,66 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{grid::RowIdentify, view::ViewIdentify},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The row is inserted after the `start_row_id`, or appended if it's None.
+#[derive(Default, ProtoBuf)]
+pub struct CreateRowRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub start_row_id: Option<String>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct CreateRowParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub start_row_id: Option<String>,
+}
+
+impl TryInto<CreateRowParams> for CreateRowRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateRowParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let start_row_id = match self.start_row_id {
+            None => None,
+            Some(row_id) => Some(RowIdentify::parse(row_id)?.0),
+        };
+        Ok(CreateRowParams { grid_id, start_row_id })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryRowRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct RowId {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+}
+
+impl TryInto<RowId> for QueryRowRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RowId, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let row_id = RowIdentify::parse(self.row_id)?.0;
+        Ok(RowId { grid_id, row_id })
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{grid::FieldIdentify, view::ViewIdentify},
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+// The filter and the sorts of the grid. They are saved with the grid, so the
+// rows are shown the same way wherever the grid is opened.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct GridSetting {
+    #[pb(index = 1)]
+    pub filter: FilterGroup,
+
+    // The rows are sorted by the first sort, the ties by the next one and so on.
+    #[pb(index = 2)]
+    pub sorts: Vec<Sort>,
+}
+
+// An empty group matches every row.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct FilterGroup {
+    #[pb(index = 1)]
+    pub operator: FilterOperator,
+
+    #[pb(index = 2)]
+    pub filters: Vec<Filter>,
+
+    #[pb(index = 3)]
+    pub groups: Vec<FilterGroup>,
+}
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Serialize, Deserialize)]
+pub enum FilterOperator {
+    And = 0,
+    Or  = 1,
+}
+
+impl std::default::Default for FilterOperator {
+    fn default() -> Self { FilterOperator::And }
+}
+
+// The filters of a field that doesn't exist anymore match every row.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct Filter {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub condition: FilterCondition,
+
+    // Compared with the data of the cells, it's ignored by IsEmpty and
+    // IsNotEmpty.
+    #[pb(index = 3)]
+    pub value: String,
+}
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Serialize, Deserialize)]
+pub enum FilterCondition {
+    Is             = 0,
+    IsNot          = 1,
+    Contains       = 2,
+    DoesNotContain = 3,
+    IsEmpty        = 4,
+    IsNotEmpty     = 5,
+    GreaterThan    = 6,
+    LessThan       = 7,
+}
+
+impl std::default::Default for FilterCondition {
+    fn default() -> Self { FilterCondition::Is }
+}
+
+// The empty cells are put last whatever the direction is.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone, Serialize, Deserialize)]
+pub struct Sort {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub descending: bool,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateGridSettingRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub setting: GridSetting,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct UpdateGridSettingParams {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub setting: GridSetting,
+}
+
+impl TryInto<UpdateGridSettingParams> for UpdateGridSettingRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateGridSettingParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let _ = validate_filter_group(&self.setting.filter)?;
+        for sort in self.setting.sorts.iter() {
+            let _ = FieldIdentify::parse(sort.field_id.clone())?;
+        }
+        Ok(UpdateGridSettingParams {
+            grid_id,
+            setting: self.setting,
+        })
+    }
+}
+
+fn validate_filter_group(group: &FilterGroup) -> Result<(), ErrorCode> {
+    for filter in group.filters.iter() {
+        let _ = FieldIdentify::parse(filter.field_id.clone())?;
+    }
+    for group in group.groups.iter() {
+        let _ = validate_filter_group(group)?;
+    }
+    Ok(())
+}
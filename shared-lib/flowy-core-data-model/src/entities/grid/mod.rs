@@ -0,0 +1,17 @@
+pub use board::*;
+pub use calendar::*;
+pub use grid::*;
+pub use grid_cell::*;
+pub use grid_field::*;
+pub use grid_query::*;
+pub use grid_row::*;
+pub use grid_setting::*;
+
+mod board;
+mod calendar;
+mod grid;
+mod grid_cell;
+mod grid_field;
+mod grid_query;
+mod grid_row;
+mod grid_setting;
@@ -1,9 +1,37 @@
 pub mod app;
+pub mod audit_log;
+pub mod backup;
+pub mod checklist;
+pub mod diagnostics;
+pub mod event_log;
+pub mod folder_sync;
+pub mod grid;
+pub mod search;
 pub mod share;
+pub mod storage;
 pub mod trash;
+pub mod unfurl;
 pub mod view;
+pub mod webhook;
 pub mod workspace;
 
 pub mod prelude {
-    pub use crate::entities::{app::*, share::*, trash::*, view::*, workspace::*};
+    pub use crate::entities::{
+        app::*,
+        audit_log::*,
+        backup::*,
+        checklist::*,
+        diagnostics::*,
+        event_log::*,
+        folder_sync::*,
+        grid::*,
+        search::*,
+        share::*,
+        storage::*,
+        trash::*,
+        unfurl::*,
+        view::*,
+        webhook::*,
+        workspace::*,
+    };
 }
@@ -0,0 +1,73 @@
+use crate::{entities::view::ViewType, errors::ErrorCode, parser::search::SearchQuery};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// Only the current workspace is searched unless `all_workspaces` is set.
+#[derive(Default, ProtoBuf)]
+pub struct SearchRequest {
+    #[pb(index = 1)]
+    pub query: String,
+
+    #[pb(index = 2)]
+    pub all_workspaces: bool,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SearchParams {
+    pub query: String,
+    pub all_workspaces: bool,
+}
+
+impl TryInto<SearchParams> for SearchRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SearchParams, Self::Error> {
+        let query = SearchQuery::parse(self.query)?.0;
+        Ok(SearchParams {
+            query,
+            all_workspaces: self.all_workspaces,
+        })
+    }
+}
+
+// The snippet is the name of the view if the name matches, otherwise it's
+// the part of the document around the first match.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SearchResultItem {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub view_name: String,
+
+    #[pb(index = 3)]
+    pub view_type: ViewType,
+
+    #[pb(index = 4)]
+    pub belong_to_id: String,
+
+    #[pb(index = 5)]
+    pub name_matched: bool,
+
+    #[pb(index = 6)]
+    pub snippet: String,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceSearchResult {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub workspace_name: String,
+
+    #[pb(index = 3)]
+    pub items: Vec<SearchResultItem>,
+}
+
+// The workspaces without any match are left out.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedWorkspaceSearchResult {
+    #[pb(index = 1)]
+    pub items: Vec<WorkspaceSearchResult>,
+}
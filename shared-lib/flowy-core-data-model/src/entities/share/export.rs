@@ -1,4 +1,13 @@
-use crate::errors::ErrorCode;
+use crate::{
+    errors::ErrorCode,
+    parser::{
+        app::AppIdentify,
+        grid::FieldIdentify,
+        share::CsvDelimiter,
+        view::ViewIdentify,
+        workspace::WorkspaceIdentify,
+    },
+};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use std::convert::TryInto;
 
@@ -60,3 +69,240 @@ pub struct ExportData {
     #[pb(index = 2)]
     pub export_type: ExportType,
 }
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportUserDataRequest {
+    // The directory that the archive will be written to.
+    #[pb(index = 1)]
+    pub dir: String,
+}
+
+#[derive(Default, Debug)]
+pub struct ExportUserDataParams {
+    pub dir: String,
+}
+
+impl TryInto<ExportUserDataParams> for ExportUserDataRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ExportUserDataParams, Self::Error> {
+        if self.dir.trim().is_empty() {
+            return Err(ErrorCode::ExportPathInvalid);
+        }
+
+        Ok(ExportUserDataParams { dir: self.dir })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportUserDataResult {
+    #[pb(index = 1)]
+    pub path: String,
+
+    #[pb(index = 2)]
+    pub document_count: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportGridRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    // The comma is used if it's empty, e.g. "\t" for TSV files.
+    #[pb(index = 2)]
+    pub delimiter: String,
+
+    // The fields that are exported, in this order. All the fields of the grid
+    // are exported if it's empty.
+    #[pb(index = 3)]
+    pub field_ids: Vec<String>,
+
+    // The file that the rows are written to. They are returned in the result
+    // if it's not set.
+    #[pb(index = 4, one_of)]
+    pub path: Option<String>,
+}
+
+#[derive(Default, Debug)]
+pub struct ExportGridParams {
+    pub grid_id: String,
+    pub delimiter: char,
+    pub field_ids: Vec<String>,
+    pub path: Option<String>,
+}
+
+impl TryInto<ExportGridParams> for ExportGridRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ExportGridParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let delimiter = CsvDelimiter::parse(self.delimiter)?.0;
+        let field_ids = self
+            .field_ids
+            .into_iter()
+            .map(|field_id| FieldIdentify::parse(field_id).map(|field_id| field_id.0))
+            .collect::<Result<Vec<String>, ErrorCode>>()?;
+        let path = match self.path {
+            Some(path) if path.trim().is_empty() => return Err(ErrorCode::ExportPathInvalid),
+            path => path,
+        };
+
+        Ok(ExportGridParams {
+            grid_id,
+            delimiter,
+            field_ids,
+            path,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportGridResult {
+    // The exported rows, it's empty if they were written to a file.
+    #[pb(index = 1)]
+    pub data: Vec<u8>,
+
+    #[pb(index = 2)]
+    pub path: String,
+
+    #[pb(index = 3)]
+    pub row_count: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportOpmlRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    // The file that the outline is written to. It's returned in the result if
+    // it's not set.
+    #[pb(index = 2, one_of)]
+    pub path: Option<String>,
+}
+
+#[derive(Default, Debug)]
+pub struct ExportOpmlParams {
+    pub workspace_id: String,
+    pub path: Option<String>,
+}
+
+impl TryInto<ExportOpmlParams> for ExportOpmlRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ExportOpmlParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?.0;
+        let path = match self.path {
+            Some(path) if path.trim().is_empty() => return Err(ErrorCode::ExportPathInvalid),
+            path => path,
+        };
+
+        Ok(ExportOpmlParams { workspace_id, path })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportOpmlResult {
+    // The OPML outline, it's empty if it was written to a file.
+    #[pb(index = 1)]
+    pub data: Vec<u8>,
+
+    #[pb(index = 2)]
+    pub path: String,
+
+    // The apps and the views that were exported.
+    #[pb(index = 3)]
+    pub outline_count: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportCalendarRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    // The .ics file that the events are written to. They are returned in the
+    // result if it's not set.
+    #[pb(index = 2, one_of)]
+    pub path: Option<String>,
+
+    // The file is written again after each edit of the calendar if it's true,
+    // until the calendar is unsubscribed or exported again without it.
+    #[pb(index = 3)]
+    pub subscribe: bool,
+}
+
+#[derive(Default, Debug)]
+pub struct ExportCalendarParams {
+    pub grid_id: String,
+    pub path: Option<String>,
+    pub subscribe: bool,
+}
+
+impl TryInto<ExportCalendarParams> for ExportCalendarRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ExportCalendarParams, Self::Error> {
+        let grid_id = ViewIdentify::parse(self.grid_id)?.0;
+        let path = match self.path {
+            Some(path) if path.trim().is_empty() => return Err(ErrorCode::ExportPathInvalid),
+            None if self.subscribe => return Err(ErrorCode::ExportPathInvalid),
+            path => path,
+        };
+
+        Ok(ExportCalendarParams {
+            grid_id,
+            path,
+            subscribe: self.subscribe,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportCalendarResult {
+    // The iCalendar data, it's empty if it was written to a file.
+    #[pb(index = 1)]
+    pub data: Vec<u8>,
+
+    #[pb(index = 2)]
+    pub path: String,
+
+    #[pb(index = 3)]
+    pub event_count: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportSiteRequest {
+    #[pb(index = 1)]
+    pub app_id: String,
+
+    // The directory that the folder of the site is written to. The folder is
+    // named after the app, and it replaces the one of a previous export.
+    #[pb(index = 2)]
+    pub dir: String,
+}
+
+#[derive(Default, Debug)]
+pub struct ExportSiteParams {
+    pub app_id: String,
+    pub dir: String,
+}
+
+impl TryInto<ExportSiteParams> for ExportSiteRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ExportSiteParams, Self::Error> {
+        let app_id = AppIdentify::parse(self.app_id)?.0;
+        if self.dir.trim().is_empty() {
+            return Err(ErrorCode::ExportPathInvalid);
+        }
+
+        Ok(ExportSiteParams { app_id, dir: self.dir })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportSiteResult {
+    // The folder of the site, its index.html is the home page.
+    #[pb(index = 1)]
+    pub path: String,
+
+    #[pb(index = 2)]
+    pub page_count: i64,
+
+    #[pb(index = 3)]
+    pub attachment_count: i64,
+}
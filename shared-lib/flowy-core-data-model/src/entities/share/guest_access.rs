@@ -0,0 +1,99 @@
+use crate::{
+    entities::share::SharePermission,
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::{share::GuestAccessIdentify, view::ViewIdentify, workspace::WorkspaceMemberEmail},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// Grants someone outside the workspace access to exactly one view. The token
+// is only returned when the access is created, and it can't read anything but
+// the view and its document.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct GuestAccess {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub view_id: String,
+
+    #[pb(index = 3)]
+    pub email: String,
+
+    #[pb(index = 4)]
+    pub permission: SharePermission,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+
+    #[pb(index = 6)]
+    pub token: String,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedGuestAccess {
+    #[pb(index = 1)]
+    pub items: Vec<GuestAccess>,
+}
+
+impl_def_and_def_mut!(RepeatedGuestAccess, GuestAccess);
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateGuestAccessRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    #[pb(index = 3)]
+    pub permission: SharePermission,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct CreateGuestAccessParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    #[pb(index = 3)]
+    pub permission: SharePermission,
+}
+
+impl TryInto<CreateGuestAccessParams> for CreateGuestAccessRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateGuestAccessParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?;
+        let email = WorkspaceMemberEmail::parse(self.email)?;
+        Ok(CreateGuestAccessParams {
+            view_id: view_id.0,
+            email: email.0,
+            permission: self.permission,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryGuestAccessRequest {
+    #[pb(index = 1)]
+    pub access_id: String,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct GuestAccessId {
+    #[pb(index = 1)]
+    pub access_id: String,
+}
+
+impl TryInto<GuestAccessId> for QueryGuestAccessRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<GuestAccessId, Self::Error> {
+        let access_id = GuestAccessIdentify::parse(self.access_id)?;
+        Ok(GuestAccessId { access_id: access_id.0 })
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{app::AppIdentify, share::CsvDelimiter, workspace::WorkspaceIdentify},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The payload of the `ImportProgress` notification, it's sent after each item
+// of the import is written.
+#[derive(PartialEq, Default, ProtoBuf, Clone, Debug)]
+pub struct ImportProgress {
+    #[pb(index = 1)]
+    pub import_id: String,
+
+    #[pb(index = 2)]
+    pub finished: i64,
+
+    #[pb(index = 3)]
+    pub total: i64,
+
+    #[pb(index = 4)]
+    pub current_name: String,
+}
+
+#[derive(PartialEq, Default, ProtoBuf, Clone, Debug)]
+pub struct CsvImportOptions {
+    // The comma is used if it's empty, e.g. "\t" for TSV files.
+    #[pb(index = 1)]
+    pub delimiter: String,
+
+    // The fields are named after the first record if it's true, otherwise
+    // they are named Column 1, Column 2 and so on.
+    #[pb(index = 2)]
+    pub has_header: bool,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ImportCsvRequest {
+    // The app that the grid will be created in.
+    #[pb(index = 1)]
+    pub app_id: String,
+
+    #[pb(index = 2)]
+    pub path: String,
+
+    #[pb(index = 3)]
+    pub options: CsvImportOptions,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportCsvParams {
+    pub app_id: String,
+    pub path: String,
+    pub delimiter: char,
+    pub has_header: bool,
+}
+
+impl TryInto<ImportCsvParams> for ImportCsvRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ImportCsvParams, Self::Error> {
+        let app_id = AppIdentify::parse(self.app_id)?.0;
+        if self.path.trim().is_empty() {
+            return Err(ErrorCode::ImportFileInvalid);
+        }
+        let delimiter = CsvDelimiter::parse(self.options.delimiter)?.0;
+
+        Ok(ImportCsvParams {
+            app_id,
+            path: self.path,
+            delimiter,
+            has_header: self.options.has_header,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ImportNotionRequest {
+    // The workspace that the top-level pages are imported in, each of them as
+    // an app.
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    // The zip file of the Notion export, in Markdown & CSV or in HTML.
+    #[pb(index = 2)]
+    pub path: String,
+
+    // The import to resume. It's the import id of the progress notifications
+    // of the import that was interrupted.
+    #[pb(index = 3, one_of)]
+    pub import_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportNotionParams {
+    pub workspace_id: String,
+    pub path: String,
+    pub import_id: Option<String>,
+}
+
+impl TryInto<ImportNotionParams> for ImportNotionRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ImportNotionParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?.0;
+        if self.path.trim().is_empty() {
+            return Err(ErrorCode::ImportFileInvalid);
+        }
+
+        Ok(ImportNotionParams {
+            workspace_id,
+            path: self.path,
+            import_id: self.import_id,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ImportNotionResult {
+    #[pb(index = 1)]
+    pub import_id: String,
+
+    // The pages that were imported, including the ones that were imported
+    // before the import was resumed.
+    #[pb(index = 2)]
+    pub page_count: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ImportEnexRequest {
+    // The app that the notes will be created in.
+    #[pb(index = 1)]
+    pub app_id: String,
+
+    // The .enex file exported from Evernote.
+    #[pb(index = 2)]
+    pub path: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportEnexParams {
+    pub app_id: String,
+    pub path: String,
+}
+
+impl TryInto<ImportEnexParams> for ImportEnexRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ImportEnexParams, Self::Error> {
+        let app_id = AppIdentify::parse(self.app_id)?.0;
+        if self.path.trim().is_empty() {
+            return Err(ErrorCode::ImportFileInvalid);
+        }
+
+        Ok(ImportEnexParams {
+            app_id,
+            path: self.path,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ImportVaultRequest {
+    // The workspace that the folders of the vault are imported in, each of
+    // them as an app.
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    // The folder of the vault, e.g. an Obsidian vault.
+    #[pb(index = 2)]
+    pub path: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportVaultParams {
+    pub workspace_id: String,
+    pub path: String,
+}
+
+impl TryInto<ImportVaultParams> for ImportVaultRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ImportVaultParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?.0;
+        if self.path.trim().is_empty() {
+            return Err(ErrorCode::ImportFileInvalid);
+        }
+
+        Ok(ImportVaultParams {
+            workspace_id,
+            path: self.path,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ImportOpmlRequest {
+    // The workspace that the top-level outlines are imported in, each of them
+    // as an app.
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub path: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportOpmlParams {
+    pub workspace_id: String,
+    pub path: String,
+}
+
+impl TryInto<ImportOpmlParams> for ImportOpmlRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ImportOpmlParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?.0;
+        if self.path.trim().is_empty() {
+            return Err(ErrorCode::ImportFileInvalid);
+        }
+
+        Ok(ImportOpmlParams {
+            workspace_id,
+            path: self.path,
+        })
+    }
+}
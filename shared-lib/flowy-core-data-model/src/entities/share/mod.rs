@@ -1,3 +1,9 @@
 mod export;
+mod guest_access;
+mod import;
+mod share_link;
 
 pub use export::*;
+pub use guest_access::*;
+pub use import::*;
+pub use share_link::*;
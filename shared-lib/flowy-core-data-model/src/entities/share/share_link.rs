@@ -0,0 +1,141 @@
+use crate::{
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::{
+        share::{ShareLinkExpireTime, ShareLinkIdentify},
+        view::ViewIdentify,
+    },
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+// The permission is granted to anyone who has the link. The shared document is
+// served read-only for now, the editable links are reserved for the clients
+// that can join the collaboration without an account.
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum SharePermission {
+    ReadOnly = 0,
+    Editable = 1,
+}
+
+impl std::default::Default for SharePermission {
+    fn default() -> Self { SharePermission::ReadOnly }
+}
+
+impl std::convert::From<i32> for SharePermission {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => SharePermission::ReadOnly,
+            1 => SharePermission::Editable,
+            _ => {
+                log::error!("Invalid share permission: {}", val);
+                SharePermission::ReadOnly
+            },
+        }
+    }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ShareLink {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub view_id: String,
+
+    #[pb(index = 3)]
+    pub permission: SharePermission,
+
+    #[pb(index = 4)]
+    pub url: String,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+
+    #[pb(index = 6, one_of)]
+    pub expire_time: Option<i64>,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedShareLink {
+    #[pb(index = 1)]
+    pub items: Vec<ShareLink>,
+}
+
+impl_def_and_def_mut!(RepeatedShareLink, ShareLink);
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateShareLinkRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub permission: SharePermission,
+
+    // Unix timestamp in seconds
+    #[pb(index = 3, one_of)]
+    pub expire_time: Option<i64>,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct CreateShareLinkParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub permission: SharePermission,
+
+    #[pb(index = 3, one_of)]
+    pub expire_time: Option<i64>,
+}
+
+impl TryInto<CreateShareLinkParams> for CreateShareLinkRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateShareLinkParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?;
+        let expire_time = ShareLinkExpireTime::parse(self.expire_time)?;
+        Ok(CreateShareLinkParams {
+            view_id: view_id.0,
+            permission: self.permission,
+            expire_time: expire_time.0,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryShareLinkRequest {
+    #[pb(index = 1)]
+    pub link_id: String,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct ShareLinkId {
+    #[pb(index = 1)]
+    pub link_id: String,
+}
+
+impl TryInto<ShareLinkId> for QueryShareLinkRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ShareLinkId, Self::Error> {
+        let link_id = ShareLinkIdentify::parse(self.link_id)?;
+        Ok(ShareLinkId { link_id: link_id.0 })
+    }
+}
+
+// The content of the shared view that is served to anyone with the link.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SharedDocument {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub permission: SharePermission,
+
+    #[pb(index = 4)]
+    pub delta_json: String,
+}
@@ -0,0 +1,120 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{
+        storage::{StorageBucket, StorageEndpoint, DEFAULT_STORAGE_REGION},
+        workspace::WorkspaceIdentify,
+    },
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The S3-compatible bucket that the attachments of a workspace are uploaded
+// to. The secret key isn't sent back, `has_secret` tells whether it's set.
+// The endpoint is empty if the workspace has no storage.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct AttachmentStorage {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub endpoint: String,
+
+    #[pb(index = 3)]
+    pub bucket: String,
+
+    #[pb(index = 4)]
+    pub region: String,
+
+    #[pb(index = 5)]
+    pub access_key_id: String,
+
+    #[pb(index = 6)]
+    pub has_secret: bool,
+
+    #[pb(index = 7)]
+    pub prefix: String,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct UpdateAttachmentStorageRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    // An empty endpoint removes the storage of the workspace.
+    #[pb(index = 2)]
+    pub endpoint: String,
+
+    #[pb(index = 3)]
+    pub bucket: String,
+
+    // Defaults to us-east-1, which most of the S3-compatible services accept.
+    #[pb(index = 4)]
+    pub region: String,
+
+    #[pb(index = 5)]
+    pub access_key_id: String,
+
+    // An empty secret keeps the one that is set.
+    #[pb(index = 6)]
+    pub secret_access_key: String,
+
+    // The objects are put under it, e.g. appflowy/<workspace id>/<attachment id>.
+    #[pb(index = 7)]
+    pub prefix: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AttachmentStorageParams {
+    pub workspace_id: String,
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub prefix: String,
+}
+
+impl AttachmentStorageParams {
+    pub fn is_removed(&self) -> bool { self.endpoint.is_empty() }
+}
+
+impl TryInto<AttachmentStorageParams> for UpdateAttachmentStorageRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<AttachmentStorageParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?.0;
+        if self.endpoint.trim().is_empty() {
+            return Ok(AttachmentStorageParams {
+                workspace_id,
+                ..Default::default()
+            });
+        }
+
+        let endpoint = StorageEndpoint::parse(self.endpoint)?.0;
+        let bucket = StorageBucket::parse(self.bucket)?.0;
+        let region = match self.region.trim() {
+            "" => DEFAULT_STORAGE_REGION.to_owned(),
+            region => region.to_owned(),
+        };
+        Ok(AttachmentStorageParams {
+            workspace_id,
+            endpoint,
+            bucket,
+            region,
+            access_key_id: self.access_key_id.trim().to_owned(),
+            secret_access_key: self.secret_access_key.trim().to_owned(),
+            prefix: self.prefix.trim().trim_matches('/').to_owned(),
+        })
+    }
+}
+
+// The attachments that were uploaded by a sync, and the ones that are left
+// for the next one, e.g. after a part of a large file failed.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct AttachmentSyncResult {
+    #[pb(index = 1)]
+    pub uploaded_count: i64,
+
+    #[pb(index = 2)]
+    pub pending_count: i64,
+}
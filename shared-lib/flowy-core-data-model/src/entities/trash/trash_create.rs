@@ -4,9 +4,10 @@ use std::fmt::Formatter;
 
 #[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
 pub enum TrashType {
-    Unknown = 0,
-    View    = 1,
-    App     = 2,
+    Unknown   = 0,
+    View      = 1,
+    App       = 2,
+    Workspace = 3,
 }
 
 impl std::convert::TryFrom<i32> for TrashType {
@@ -17,6 +18,7 @@ impl std::convert::TryFrom<i32> for TrashType {
             0 => Ok(TrashType::Unknown),
             1 => Ok(TrashType::View),
             2 => Ok(TrashType::App),
+            3 => Ok(TrashType::Workspace),
             _ => Err(format!("Invalid trash type: {}", value)),
         }
     }
@@ -114,6 +116,20 @@ pub struct Trash {
 
     #[pb(index = 5)]
     pub ty: TrashType,
+
+    // The id of the user who deleted it.
+    #[pb(index = 6)]
+    pub deleted_by: String,
+
+    // The app or the view that a view belonged to, or the workspace of an app.
+    // It's empty for a workspace.
+    #[pb(index = 7)]
+    pub parent_id: String,
+
+    // The index in the parent when it was deleted, the items that were in the
+    // trash already aren't counted.
+    #[pb(index = 8)]
+    pub position: i32,
 }
 
 #[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
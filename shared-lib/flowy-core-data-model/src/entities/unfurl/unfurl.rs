@@ -0,0 +1,55 @@
+use crate::{errors::ErrorCode, parser::unfurl::UnfurlUrl};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The card of an embedded link. The fields that the page doesn't have are
+// empty, all of them are if the previews are turned off or the fetch failed.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct LinkPreview {
+    #[pb(index = 1)]
+    pub url: String,
+
+    #[pb(index = 2)]
+    pub title: String,
+
+    #[pb(index = 3)]
+    pub description: String,
+
+    #[pb(index = 4)]
+    pub image_url: String,
+
+    #[pb(index = 5)]
+    pub site_name: String,
+
+    #[pb(index = 6)]
+    pub fetch_time: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UnfurlRequest {
+    #[pb(index = 1)]
+    pub url: String,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct UnfurlParams {
+    #[pb(index = 1)]
+    pub url: String,
+}
+
+impl TryInto<UnfurlParams> for UnfurlRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UnfurlParams, Self::Error> {
+        let url = UnfurlUrl::parse(self.url)?;
+        Ok(UnfurlParams { url: url.0 })
+    }
+}
+
+// The link previews are fetched from the sites, which learn the address of
+// the device. Turning them off also clears the cached previews.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct UnfurlSetting {
+    #[pb(index = 1)]
+    pub enabled: bool,
+}
@@ -1,7 +1,25 @@
+pub use view_access::*;
+pub use view_attachment::*;
+pub use view_checkpoint::*;
+pub use view_code_block::*;
 pub use view_create::*;
+pub use view_duplicate::*;
+pub use view_move::*;
+pub use view_quarantine::*;
 pub use view_query::*;
+pub use view_tag::*;
+pub use view_toggle::*;
 pub use view_update::*;
 
+mod view_access;
+mod view_attachment;
+mod view_checkpoint;
+mod view_code_block;
 mod view_create;
+mod view_duplicate;
+mod view_move;
+mod view_quarantine;
 mod view_query;
+mod view_tag;
+mod view_toggle;
 mod view_update;
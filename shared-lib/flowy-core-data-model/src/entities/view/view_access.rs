@@ -0,0 +1,53 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{view::ViewIdentify, workspace::WorkspaceMemberId},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The members that can access the view. The view without any member is open
+// to everyone in the workspace, and the owner of the workspace can always
+// access it.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ViewAccess {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub user_ids: Vec<String>,
+}
+
+impl ViewAccess {
+    pub fn is_restricted(&self) -> bool { !self.user_ids.is_empty() }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct UpdateViewAccessRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct UpdateViewAccessParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+}
+
+impl TryInto<UpdateViewAccessParams> for UpdateViewAccessRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateViewAccessParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?;
+        let user_id = WorkspaceMemberId::parse(self.user_id)?;
+        Ok(UpdateViewAccessParams {
+            view_id: view_id.0,
+            user_id: user_id.0,
+        })
+    }
+}
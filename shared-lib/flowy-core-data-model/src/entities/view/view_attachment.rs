@@ -0,0 +1,48 @@
+use crate::{errors::ErrorCode, parser::view::AttachmentIdentify};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// A file embedded in a view, e.g. an image of an imported note. The document
+// links to it with appflowy://attachment/<id>. The attachments are kept in
+// the local database, and uploaded to the storage of the workspace if it has
+// one.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct Attachment {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub view_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub mime_type: String,
+
+    #[pb(index = 5)]
+    pub data: Vec<u8>,
+
+    #[pb(index = 6)]
+    pub create_time: i64,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct QueryAttachmentRequest {
+    #[pb(index = 1)]
+    pub attachment_id: String,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AttachmentId {
+    pub attachment_id: String,
+}
+
+impl TryInto<AttachmentId> for QueryAttachmentRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<AttachmentId, Self::Error> {
+        let attachment_id = AttachmentIdentify::parse(self.attachment_id)?.0;
+        Ok(AttachmentId { attachment_id })
+    }
+}
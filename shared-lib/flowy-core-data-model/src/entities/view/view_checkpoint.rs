@@ -0,0 +1,85 @@
+use crate::{
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::view::{CheckpointIdentify, CheckpointName, ViewIdentify},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// A named revision of the document of a view. The checkpoints are only kept
+// in the local database.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ViewCheckpoint {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub view_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub rev_id: i64,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedViewCheckpoint {
+    #[pb(index = 1)]
+    pub items: Vec<ViewCheckpoint>,
+}
+
+impl_def_and_def_mut!(RepeatedViewCheckpoint, ViewCheckpoint);
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateViewCheckpointRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct CreateViewCheckpointParams {
+    pub view_id: String,
+    pub name: String,
+}
+
+impl TryInto<CreateViewCheckpointParams> for CreateViewCheckpointRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateViewCheckpointParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?.0;
+        let name = CheckpointName::parse(self.name)?.0;
+        Ok(CreateViewCheckpointParams { view_id, name })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryViewCheckpointRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub checkpoint_id: String,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ViewCheckpointId {
+    pub view_id: String,
+    pub checkpoint_id: String,
+}
+
+impl TryInto<ViewCheckpointId> for QueryViewCheckpointRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ViewCheckpointId, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?.0;
+        let checkpoint_id = CheckpointIdentify::parse(self.checkpoint_id)?.0;
+        Ok(ViewCheckpointId { view_id, checkpoint_id })
+    }
+}
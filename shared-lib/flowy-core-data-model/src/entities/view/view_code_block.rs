@@ -0,0 +1,73 @@
+use crate::{
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::view::{TextIndex, ViewIdentify},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryCodeBlockRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    // The utf16 offset of any character of the code block.
+    #[pb(index = 2)]
+    pub index: i64,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct CodeBlockParams {
+    pub view_id: String,
+    pub index: usize,
+}
+
+impl TryInto<CodeBlockParams> for QueryCodeBlockRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CodeBlockParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?.0;
+        let index = TextIndex::parse(self.index)?.0;
+        Ok(CodeBlockParams { view_id, index })
+    }
+}
+
+// A range of the code block that is highlighted as one. The scope is the
+// TextMate scope of the range, e.g. "keyword.control.rust", the frontends map
+// it to the colors of their theme. The offsets are in the document.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SyntaxToken {
+    #[pb(index = 1)]
+    pub start: i64,
+
+    #[pb(index = 2)]
+    pub len: i64,
+
+    #[pb(index = 3)]
+    pub scope: String,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedSyntaxToken {
+    #[pb(index = 1)]
+    pub items: Vec<SyntaxToken>,
+}
+
+impl_def_and_def_mut!(RepeatedSyntaxToken, SyntaxToken);
+
+// The tokens of the block at the index of the request. The language is the
+// one the block was highlighted with, it's empty for the plain text.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct CodeBlockTokens {
+    #[pb(index = 1)]
+    pub start: i64,
+
+    #[pb(index = 2)]
+    pub len: i64,
+
+    #[pb(index = 3)]
+    pub language: String,
+
+    #[pb(index = 4)]
+    pub tokens: RepeatedSyntaxToken,
+}
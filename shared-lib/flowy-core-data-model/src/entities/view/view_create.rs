@@ -1,5 +1,10 @@
 use crate::{
-    entities::trash::{Trash, TrashType},
+    entities::{
+        checklist::Checklist,
+        grid::Grid,
+        trash::{Trash, TrashType},
+        workspace::WorkspaceSettings,
+    },
     errors::ErrorCode,
     impl_def_and_def_mut,
     parser::{
@@ -11,10 +16,14 @@ use flowy_collaboration::document::default::initial_delta_string;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use std::convert::TryInto;
 
-#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+#[derive(PartialEq, Eq, Hash, Debug, ProtoBuf_Enum, Clone)]
 pub enum ViewType {
-    Blank = 0,
-    Doc   = 1,
+    Blank     = 0,
+    Doc       = 1,
+    Grid      = 2,
+    Board     = 3,
+    Calendar  = 4,
+    Checklist = 5,
 }
 
 impl std::default::Default for ViewType {
@@ -24,6 +33,10 @@ impl std::default::Default for ViewType {
 impl std::convert::From<i32> for ViewType {
     fn from(val: i32) -> Self {
         match val {
+            5 => ViewType::Checklist,
+            4 => ViewType::Calendar,
+            3 => ViewType::Board,
+            2 => ViewType::Grid,
             1 => ViewType::Doc,
             0 => ViewType::Blank,
             _ => {
@@ -48,8 +61,14 @@ pub struct CreateViewRequest {
     #[pb(index = 4, one_of)]
     pub thumbnail: Option<String>,
 
-    #[pb(index = 5)]
-    pub view_type: ViewType,
+    // The default view type of the workspace if it's not set.
+    #[pb(index = 5, one_of)]
+    pub view_type: Option<ViewType>,
+
+    // The id that the client generated, so that creating the view again after
+    // a failed attempt returns the view instead of adding another one.
+    #[pb(index = 6, one_of)]
+    pub view_id: Option<String>,
 }
 
 #[derive(Default, ProtoBuf, Debug, Clone)]
@@ -105,8 +124,22 @@ impl TryInto<CreateViewParams> for CreateViewRequest {
     fn try_into(self) -> Result<CreateViewParams, Self::Error> {
         let name = ViewName::parse(self.name)?.0;
         let belong_to_id = AppIdentify::parse(self.belong_to_id)?.0;
-        let view_data = initial_delta_string();
-        let view_id = uuid::Uuid::new_v4().to_string();
+        let view_id = match self.view_id {
+            None => uuid::Uuid::new_v4().to_string(),
+            Some(view_id) => uuid::Uuid::parse_str(&view_id)
+                .map_err(|_| ErrorCode::ViewIdInvalid)?
+                .to_string(),
+        };
+        let view_type = self
+            .view_type
+            .unwrap_or_else(|| WorkspaceSettings::default().default_view_type);
+        let view_data = match view_type {
+            ViewType::Grid => Grid::new(&view_id).to_delta_string(),
+            ViewType::Board => Grid::new_board(&view_id).to_delta_string(),
+            ViewType::Calendar => Grid::new_calendar(&view_id).to_delta_string(),
+            ViewType::Checklist => Checklist::new(&view_id).to_delta_string(),
+            _ => initial_delta_string(),
+        };
         let thumbnail = match self.thumbnail {
             None => "".to_string(),
             Some(thumbnail) => ViewThumbnail::parse(thumbnail)?.0,
@@ -116,7 +149,7 @@ impl TryInto<CreateViewParams> for CreateViewRequest {
             belong_to_id,
             name,
             self.desc,
-            self.view_type,
+            view_type,
             thumbnail,
             view_data,
             view_id,
@@ -170,6 +203,9 @@ impl std::convert::From<View> for Trash {
             modified_time: view.modified_time,
             create_time: view.create_time,
             ty: TrashType::View,
+            deleted_by: "".to_owned(),
+            parent_id: view.belong_to_id,
+            position: 0,
         }
     }
 }
@@ -0,0 +1,34 @@
+use crate::{errors::ErrorCode, parser::view::ViewIdentify};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The view id takes the index of the ids of the `QueryViewRequest` that was
+// sent before, a request with one id parses the same.
+#[derive(Default, ProtoBuf)]
+pub struct DuplicateViewRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    // Copies the revisions of the document instead of its content, so the
+    // history of the copy goes back as far as the one of the view.
+    #[pb(index = 2)]
+    pub with_history: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct DuplicateViewParams {
+    pub view_id: String,
+    pub with_history: bool,
+}
+
+impl TryInto<DuplicateViewParams> for DuplicateViewRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<DuplicateViewParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?.0;
+        Ok(DuplicateViewParams {
+            view_id,
+            with_history: self.with_history,
+        })
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{app::AppIdentify, view::ViewIdentify, workspace::WorkspaceIdentify},
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::{collections::HashSet, convert::TryInto};
+
+// The view is moved into an app or nested into another view, the
+// `to_belong_to_id` is the id of either of them.
+#[derive(Default, ProtoBuf)]
+pub struct MoveViewRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub to_belong_to_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct MoveViewParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub to_belong_to_id: String,
+}
+
+impl TryInto<MoveViewParams> for MoveViewRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<MoveViewParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?.0;
+        let to_belong_to_id = AppIdentify::parse(self.to_belong_to_id)?.0;
+        Ok(MoveViewParams {
+            view_id,
+            to_belong_to_id,
+        })
+    }
+}
+
+// The payload of the `ViewMoved` notification. Both parents are sent, so the
+// client updates the two lists without reading the folder again.
+#[derive(PartialEq, Default, ProtoBuf, Clone, Debug)]
+pub struct ViewMoved {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub from_belong_to_id: String,
+
+    #[pb(index = 3)]
+    pub to_belong_to_id: String,
+}
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum MoveItemType {
+    View = 0,
+    App  = 1,
+}
+
+impl std::default::Default for MoveItemType {
+    fn default() -> Self { MoveItemType::View }
+}
+
+// An app or a view of a bulk move. A view is moved into an app or nested into
+// a view, an app is only reordered in its workspace, so its `to_parent_id` is
+// the id of the workspace it's in already.
+#[derive(PartialEq, Default, ProtoBuf, Clone, Debug)]
+pub struct MoveItem {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub ty: MoveItemType,
+
+    #[pb(index = 3)]
+    pub to_parent_id: String,
+
+    // The position among the other apps or views of the parent, the item is
+    // put last if it's past the end.
+    #[pb(index = 4)]
+    pub index: i32,
+}
+
+// The items are moved one after another, in the order of the request.
+#[derive(Default, ProtoBuf)]
+pub struct MoveItemsRequest {
+    #[pb(index = 1)]
+    pub items: Vec<MoveItem>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct MoveItemsParams {
+    #[pb(index = 1)]
+    pub items: Vec<MoveItem>,
+}
+
+impl TryInto<MoveItemsParams> for MoveItemsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<MoveItemsParams, Self::Error> {
+        if self.items.is_empty() {
+            return Err(ErrorCode::ViewMoveInvalid);
+        }
+
+        let mut ids = HashSet::new();
+        let mut items = vec![];
+        for item in self.items {
+            let (id, to_parent_id) = match item.ty {
+                MoveItemType::View => (
+                    ViewIdentify::parse(item.id)?.0,
+                    AppIdentify::parse(item.to_parent_id)?.0,
+                ),
+                MoveItemType::App => (
+                    AppIdentify::parse(item.id)?.0,
+                    WorkspaceIdentify::parse(item.to_parent_id)?.0,
+                ),
+            };
+            // Moving an item twice in one request is most likely a mistake of
+            // the caller.
+            if item.index < 0 || !ids.insert(id.clone()) {
+                return Err(ErrorCode::ViewMoveInvalid);
+            }
+            items.push(MoveItem {
+                id,
+                ty: item.ty,
+                to_parent_id,
+                index: item.index,
+            });
+        }
+        Ok(MoveItemsParams { items })
+    }
+}
+
+// An item that a bulk move moved, `index` is where it ended up in its parent.
+#[derive(PartialEq, Default, ProtoBuf, Clone, Debug)]
+pub struct ItemMoved {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub ty: MoveItemType,
+
+    #[pb(index = 3)]
+    pub from_parent_id: String,
+
+    #[pb(index = 4)]
+    pub to_parent_id: String,
+
+    #[pb(index = 5)]
+    pub index: i32,
+}
+
+// The result of a bulk move and the payload of its `ItemsMoved` notification.
+#[derive(PartialEq, Default, ProtoBuf, Clone, Debug)]
+pub struct RepeatedItemMoved {
+    #[pb(index = 1)]
+    pub items: Vec<ItemMoved>,
+}
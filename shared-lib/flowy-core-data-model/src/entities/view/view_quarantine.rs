@@ -0,0 +1,44 @@
+use crate::{errors::ErrorCode, parser::view::ViewIdentify};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The revisions of a document that couldn't be loaded are quarantined, the
+// document is rebuilt without them. They are exported to a file that the
+// user sends to support.
+#[derive(Default, ProtoBuf)]
+pub struct ExportQuarantinedRevisionsRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    // The directory that the file is written to.
+    #[pb(index = 2)]
+    pub dir: String,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ExportQuarantinedRevisionsParams {
+    pub view_id: String,
+    pub dir: String,
+}
+
+impl TryInto<ExportQuarantinedRevisionsParams> for ExportQuarantinedRevisionsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ExportQuarantinedRevisionsParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?.0;
+        if self.dir.trim().is_empty() {
+            return Err(ErrorCode::ExportPathInvalid);
+        }
+
+        Ok(ExportQuarantinedRevisionsParams { view_id, dir: self.dir })
+    }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ExportQuarantinedRevisionsResult {
+    #[pb(index = 1)]
+    pub path: String,
+
+    #[pb(index = 2)]
+    pub revision_count: i64,
+}
@@ -0,0 +1,49 @@
+use crate::{
+    errors::ErrorCode,
+    parser::view::{ViewIdentify, ViewTag},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The tags are only kept in the local database, they are not synced to the
+// server.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ViewTags {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub tags: Vec<String>,
+}
+
+// Replaces all the tags of the view.
+#[derive(ProtoBuf, Default)]
+pub struct UpdateViewTagsRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UpdateViewTagsParams {
+    pub view_id: String,
+    pub tags: Vec<String>,
+}
+
+impl TryInto<UpdateViewTagsParams> for UpdateViewTagsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateViewTagsParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?.0;
+        let mut tags: Vec<String> = vec![];
+        for tag in self.tags {
+            let tag = ViewTag::parse(tag)?.0;
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        Ok(UpdateViewTagsParams { view_id, tags })
+    }
+}
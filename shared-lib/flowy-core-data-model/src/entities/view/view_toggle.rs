@@ -0,0 +1,47 @@
+use crate::{
+    errors::ErrorCode,
+    parser::view::{ToggleIdentify, ViewIdentify},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateToggleStateRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub toggle_id: String,
+
+    #[pb(index = 3)]
+    pub collapsed: bool,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ToggleStateParams {
+    pub view_id: String,
+    pub toggle_id: String,
+    pub collapsed: bool,
+}
+
+impl TryInto<ToggleStateParams> for UpdateToggleStateRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ToggleStateParams, Self::Error> {
+        let view_id = ViewIdentify::parse(self.view_id)?.0;
+        let toggle_id = ToggleIdentify::parse(self.toggle_id)?.0;
+        Ok(ToggleStateParams {
+            view_id,
+            toggle_id,
+            collapsed: self.collapsed,
+        })
+    }
+}
+
+// The ids of the toggles of the document that the user collapsed. It's kept
+// on the device, the other users of the document have their own.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct CollapsedToggles {
+    #[pb(index = 1)]
+    pub toggle_ids: Vec<String>,
+}
@@ -33,6 +33,10 @@ pub struct UpdateViewParams {
 
     #[pb(index = 4, one_of)]
     pub thumbnail: Option<String>,
+
+    // Only set by moving the view, see `MoveViewParams`.
+    #[pb(index = 5, one_of)]
+    pub belong_to_id: Option<String>,
 }
 
 impl UpdateViewParams {
@@ -80,6 +84,7 @@ impl TryInto<UpdateViewParams> for UpdateViewRequest {
             name,
             desc,
             thumbnail,
+            belong_to_id: None,
         })
     }
 }
@@ -0,0 +1,108 @@
+use crate::{
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::webhook::{WebhookIdentify, WebhookUrl},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The payloads posted to the url are signed with the secret, the receiver
+// checks the `X-Flowy-Signature` header to know they come from AppFlowy.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct Webhook {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub url: String,
+
+    #[pb(index = 3)]
+    pub secret: String,
+
+    #[pb(index = 4)]
+    pub on_view_created: bool,
+
+    #[pb(index = 5)]
+    pub on_document_updated: bool,
+
+    #[pb(index = 6)]
+    pub on_trash_emptied: bool,
+
+    #[pb(index = 7)]
+    pub create_time: i64,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedWebhook {
+    #[pb(index = 1)]
+    pub items: Vec<Webhook>,
+}
+
+impl_def_and_def_mut!(RepeatedWebhook, Webhook);
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateWebhookRequest {
+    #[pb(index = 1)]
+    pub url: String,
+
+    #[pb(index = 2)]
+    pub on_view_created: bool,
+
+    #[pb(index = 3)]
+    pub on_document_updated: bool,
+
+    #[pb(index = 4)]
+    pub on_trash_emptied: bool,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct CreateWebhookParams {
+    #[pb(index = 1)]
+    pub url: String,
+
+    #[pb(index = 2)]
+    pub on_view_created: bool,
+
+    #[pb(index = 3)]
+    pub on_document_updated: bool,
+
+    #[pb(index = 4)]
+    pub on_trash_emptied: bool,
+}
+
+impl TryInto<CreateWebhookParams> for CreateWebhookRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateWebhookParams, Self::Error> {
+        let url = WebhookUrl::parse(self.url)?;
+        Ok(CreateWebhookParams {
+            url: url.0,
+            on_view_created: self.on_view_created,
+            on_document_updated: self.on_document_updated,
+            on_trash_emptied: self.on_trash_emptied,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryWebhookRequest {
+    #[pb(index = 1)]
+    pub webhook_id: String,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct WebhookId {
+    #[pb(index = 1)]
+    pub webhook_id: String,
+}
+
+impl TryInto<WebhookId> for QueryWebhookRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<WebhookId, Self::Error> {
+        let webhook_id = WebhookIdentify::parse(self.webhook_id)?;
+        Ok(WebhookId {
+            webhook_id: webhook_id.0,
+        })
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    entities::app::RepeatedApp,
+    entities::{
+        app::RepeatedApp,
+        workspace::{WorkspaceRole, WorkspaceSettings},
+    },
     errors::*,
     impl_def_and_def_mut,
     parser::workspace::{WorkspaceDesc, WorkspaceName},
@@ -14,6 +17,11 @@ pub struct CreateWorkspaceRequest {
 
     #[pb(index = 2)]
     pub desc: String,
+
+    // Generated by the client. If a workspace with this id exists, the create
+    // is a retry and returns that workspace.
+    #[pb(index = 3, one_of)]
+    pub workspace_id: Option<String>,
 }
 
 #[derive(Clone, ProtoBuf, Default, Debug)]
@@ -23,6 +31,10 @@ pub struct CreateWorkspaceParams {
 
     #[pb(index = 2)]
     pub desc: String,
+
+    // Empty when the server generates the id, i.e. for the older clients.
+    #[pb(index = 3)]
+    pub workspace_id: String,
 }
 
 impl TryInto<CreateWorkspaceParams> for CreateWorkspaceRequest {
@@ -31,10 +43,17 @@ impl TryInto<CreateWorkspaceParams> for CreateWorkspaceRequest {
     fn try_into(self) -> Result<CreateWorkspaceParams, Self::Error> {
         let name = WorkspaceName::parse(self.name)?;
         let desc = WorkspaceDesc::parse(self.desc)?;
+        let workspace_id = match self.workspace_id {
+            None => uuid::Uuid::new_v4().to_string(),
+            Some(workspace_id) => uuid::Uuid::parse_str(&workspace_id)
+                .map_err(|_| ErrorCode::WorkspaceIdInvalid)?
+                .to_string(),
+        };
 
         Ok(CreateWorkspaceParams {
             name: name.0,
             desc: desc.0,
+            workspace_id,
         })
     }
 }
@@ -58,6 +77,15 @@ pub struct Workspace {
 
     #[pb(index = 6)]
     pub create_time: i64,
+
+    // The role of the current user in the workspace.
+    #[pb(index = 7)]
+    pub role: WorkspaceRole,
+
+    // Only sent by the servers that keep the settings of the workspaces, the
+    // clients read them with ReadWorkspaceSettings.
+    #[pb(index = 8, one_of)]
+    pub settings: Option<WorkspaceSettings>,
 }
 
 impl Workspace {
@@ -0,0 +1,131 @@
+use crate::{
+    entities::workspace::WorkspaceRole,
+    errors::*,
+    impl_def_and_def_mut,
+    parser::workspace::{InvitationIdentify, WorkspaceIdentify, WorkspaceMemberEmail},
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum InvitationStatus {
+    Pending  = 0,
+    Accepted = 1,
+    Declined = 2,
+}
+
+impl std::default::Default for InvitationStatus {
+    fn default() -> Self { InvitationStatus::Pending }
+}
+
+impl std::convert::From<i32> for InvitationStatus {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => InvitationStatus::Pending,
+            1 => InvitationStatus::Accepted,
+            2 => InvitationStatus::Declined,
+            _ => {
+                log::error!("Invalid invitation status: {}", val);
+                InvitationStatus::Pending
+            },
+        }
+    }
+}
+
+// The invitation is sent to an email instead of a user, so the person can
+// accept it after signing up with that email.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceInvitation {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub workspace_id: String,
+
+    #[pb(index = 3)]
+    pub workspace_name: String,
+
+    #[pb(index = 4)]
+    pub inviter_name: String,
+
+    #[pb(index = 5)]
+    pub email: String,
+
+    #[pb(index = 6)]
+    pub role: WorkspaceRole,
+
+    #[pb(index = 7)]
+    pub status: InvitationStatus,
+
+    #[pb(index = 8)]
+    pub create_time: i64,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedWorkspaceInvitation {
+    #[pb(index = 1)]
+    pub items: Vec<WorkspaceInvitation>,
+}
+
+impl_def_and_def_mut!(RepeatedWorkspaceInvitation, WorkspaceInvitation);
+
+#[derive(ProtoBuf, Default)]
+pub struct CreateInvitationRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct CreateInvitationParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+}
+
+impl TryInto<CreateInvitationParams> for CreateInvitationRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateInvitationParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        let email = WorkspaceMemberEmail::parse(self.email)?;
+        Ok(CreateInvitationParams {
+            workspace_id: workspace_id.0,
+            email: email.0,
+            role: self.role,
+        })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct QueryInvitationRequest {
+    #[pb(index = 1)]
+    pub invitation_id: String,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct InvitationId {
+    #[pb(index = 1)]
+    pub invitation_id: String,
+}
+
+impl TryInto<InvitationId> for QueryInvitationRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InvitationId, Self::Error> {
+        let invitation_id = InvitationIdentify::parse(self.invitation_id)?;
+        Ok(InvitationId {
+            invitation_id: invitation_id.0,
+        })
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    entities::workspace::WorkspaceSettings,
+    errors::*,
+    impl_def_and_def_mut,
+    parser::workspace::{WorkspaceIdentify, WorkspaceMemberEmail, WorkspaceMemberId},
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+// The role of a user in a workspace. The owner manages the members, the editor
+// changes the apps and views, and the viewer can only read them.
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum WorkspaceRole {
+    Owner  = 0,
+    Editor = 1,
+    Viewer = 2,
+}
+
+impl WorkspaceRole {
+    pub fn can_edit(&self) -> bool { matches!(self, WorkspaceRole::Owner | WorkspaceRole::Editor) }
+
+    pub fn can_manage_members(&self) -> bool { *self == WorkspaceRole::Owner }
+}
+
+// The workspaces created on this device belong to the user, so the default is
+// the owner.
+impl std::default::Default for WorkspaceRole {
+    fn default() -> Self { WorkspaceRole::Owner }
+}
+
+impl std::convert::From<i32> for WorkspaceRole {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => WorkspaceRole::Owner,
+            1 => WorkspaceRole::Editor,
+            2 => WorkspaceRole::Viewer,
+            _ => {
+                log::error!("Invalid workspace role: {}", val);
+                WorkspaceRole::Viewer
+            },
+        }
+    }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceMember {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub email: String,
+
+    #[pb(index = 5)]
+    pub role: WorkspaceRole,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedWorkspaceMember {
+    #[pb(index = 1)]
+    pub items: Vec<WorkspaceMember>,
+}
+
+impl_def_and_def_mut!(RepeatedWorkspaceMember, WorkspaceMember);
+
+#[derive(ProtoBuf, Default)]
+pub struct QueryWorkspaceMembersRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct QueryWorkspaceMembersParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+}
+
+impl TryInto<QueryWorkspaceMembersParams> for QueryWorkspaceMembersRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<QueryWorkspaceMembersParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        Ok(QueryWorkspaceMembersParams {
+            workspace_id: workspace_id.0,
+        })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct AddWorkspaceMemberRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    // The default member role of the workspace if it's not set.
+    #[pb(index = 3, one_of)]
+    pub role: Option<WorkspaceRole>,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct AddWorkspaceMemberParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+}
+
+impl TryInto<AddWorkspaceMemberParams> for AddWorkspaceMemberRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<AddWorkspaceMemberParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        let email = WorkspaceMemberEmail::parse(self.email)?;
+        Ok(AddWorkspaceMemberParams {
+            workspace_id: workspace_id.0,
+            email: email.0,
+            role: self
+                .role
+                .unwrap_or_else(|| WorkspaceSettings::default().default_member_role),
+        })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct UpdateWorkspaceMemberRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct UpdateWorkspaceMemberParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+}
+
+impl TryInto<UpdateWorkspaceMemberParams> for UpdateWorkspaceMemberRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateWorkspaceMemberParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        let user_id = WorkspaceMemberId::parse(self.user_id)?;
+        Ok(UpdateWorkspaceMemberParams {
+            workspace_id: workspace_id.0,
+            user_id: user_id.0,
+            role: self.role,
+        })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct RemoveWorkspaceMemberRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct RemoveWorkspaceMemberParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+}
+
+impl TryInto<RemoveWorkspaceMemberParams> for RemoveWorkspaceMemberRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RemoveWorkspaceMemberParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        let user_id = WorkspaceMemberId::parse(self.user_id)?;
+        Ok(RemoveWorkspaceMemberParams {
+            workspace_id: workspace_id.0,
+            user_id: user_id.0,
+        })
+    }
+}
@@ -1,5 +1,13 @@
-use crate::entities::{view::View, workspace::Workspace};
+use crate::{
+    entities::{
+        view::View,
+        workspace::{RetentionKind, Workspace},
+    },
+    errors::ErrorCode,
+    parser::workspace::{RetentionValue, WorkspaceIdentify},
+};
 use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
 
 #[derive(Default, ProtoBuf, Clone)]
 pub struct CurrentWorkspaceSetting {
@@ -9,3 +17,65 @@ pub struct CurrentWorkspaceSetting {
     #[pb(index = 2, one_of)]
     pub latest_view: Option<View>,
 }
+
+// The `value` is the number of revisions or days to keep, zero for all.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RevisionRetentionSetting {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub kind: RetentionKind,
+
+    #[pb(index = 3)]
+    pub value: i64,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct QueryRevisionRetentionRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct QueryRevisionRetentionParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+}
+
+impl TryInto<QueryRevisionRetentionParams> for QueryRevisionRetentionRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<QueryRevisionRetentionParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        Ok(QueryRevisionRetentionParams {
+            workspace_id: workspace_id.0,
+        })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct UpdateRevisionRetentionRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub kind: RetentionKind,
+
+    #[pb(index = 3)]
+    pub value: i64,
+}
+
+impl TryInto<RevisionRetentionSetting> for UpdateRevisionRetentionRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RevisionRetentionSetting, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        let value = RetentionValue::parse(self.kind, self.value)?;
+        Ok(RevisionRetentionSetting {
+            workspace_id: workspace_id.0,
+            kind: self.kind,
+            value: value.0,
+        })
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    entities::{view::ViewType, workspace::WorkspaceRole},
+    errors::ErrorCode,
+    parser::workspace::{DefaultMemberRole, RetentionValue, WorkspaceIdentify},
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+// How much history of the documents of a workspace is kept on the device.
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum RetentionKind {
+    All           = 0,
+    LastRevisions = 1,
+    LastDays      = 2,
+}
+
+impl std::default::Default for RetentionKind {
+    fn default() -> Self { RetentionKind::All }
+}
+
+impl std::convert::From<i32> for RetentionKind {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => RetentionKind::All,
+            1 => RetentionKind::LastRevisions,
+            2 => RetentionKind::LastDays,
+            _ => {
+                log::error!("Invalid retention kind: {}", val);
+                RetentionKind::All
+            },
+        }
+    }
+}
+
+// How the dates are shown to the members of a workspace.
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum DateFormat {
+    Local    = 0,
+    US       = 1,
+    ISO      = 2,
+    Friendly = 3,
+}
+
+impl std::default::Default for DateFormat {
+    fn default() -> Self { DateFormat::Local }
+}
+
+impl std::convert::From<i32> for DateFormat {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => DateFormat::Local,
+            1 => DateFormat::US,
+            2 => DateFormat::ISO,
+            3 => DateFormat::Friendly,
+            _ => {
+                log::error!("Invalid date format: {}", val);
+                DateFormat::Local
+            },
+        }
+    }
+}
+
+// The settings that every member of a workspace shares, they are synced with
+// the folder instead of kept as the preferences of one device. The clients
+// preselect the default view type and member role in their dialogs.
+#[derive(PartialEq, ProtoBuf, Debug, Clone)]
+pub struct WorkspaceSettings {
+    #[pb(index = 1)]
+    pub default_view_type: ViewType,
+
+    #[pb(index = 2)]
+    pub date_format: DateFormat,
+
+    #[pb(index = 3)]
+    pub retention_kind: RetentionKind,
+
+    #[pb(index = 4)]
+    pub retention_value: i64,
+
+    #[pb(index = 5)]
+    pub default_member_role: WorkspaceRole,
+}
+
+impl std::default::Default for WorkspaceSettings {
+    fn default() -> Self {
+        WorkspaceSettings {
+            default_view_type: ViewType::Doc,
+            date_format: DateFormat::Local,
+            retention_kind: RetentionKind::All,
+            retention_value: 0,
+            default_member_role: WorkspaceRole::Editor,
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct QueryWorkspaceSettingsRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct QueryWorkspaceSettingsParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+}
+
+impl TryInto<QueryWorkspaceSettingsParams> for QueryWorkspaceSettingsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<QueryWorkspaceSettingsParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        Ok(QueryWorkspaceSettingsParams {
+            workspace_id: workspace_id.0,
+        })
+    }
+}
+
+// Only the settings that are set are changed. The retention value is only
+// read with the retention kind.
+#[derive(ProtoBuf, Default)]
+pub struct UpdateWorkspaceSettingsRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub default_view_type: Option<ViewType>,
+
+    #[pb(index = 3, one_of)]
+    pub date_format: Option<DateFormat>,
+
+    #[pb(index = 4, one_of)]
+    pub retention_kind: Option<RetentionKind>,
+
+    #[pb(index = 5, one_of)]
+    pub retention_value: Option<i64>,
+
+    #[pb(index = 6, one_of)]
+    pub default_member_role: Option<WorkspaceRole>,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct UpdateWorkspaceSettingsParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub default_view_type: Option<ViewType>,
+
+    #[pb(index = 3, one_of)]
+    pub date_format: Option<DateFormat>,
+
+    #[pb(index = 4, one_of)]
+    pub retention_kind: Option<RetentionKind>,
+
+    #[pb(index = 5, one_of)]
+    pub retention_value: Option<i64>,
+
+    #[pb(index = 6, one_of)]
+    pub default_member_role: Option<WorkspaceRole>,
+}
+
+impl UpdateWorkspaceSettingsParams {
+    pub fn apply_to(&self, settings: &mut WorkspaceSettings) {
+        if let Some(default_view_type) = &self.default_view_type {
+            settings.default_view_type = default_view_type.clone();
+        }
+        if let Some(date_format) = self.date_format {
+            settings.date_format = date_format;
+        }
+        if let Some(retention_kind) = self.retention_kind {
+            settings.retention_kind = retention_kind;
+            settings.retention_value = self.retention_value.unwrap_or(0);
+        }
+        if let Some(default_member_role) = self.default_member_role {
+            settings.default_member_role = default_member_role;
+        }
+    }
+}
+
+impl TryInto<UpdateWorkspaceSettingsParams> for UpdateWorkspaceSettingsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateWorkspaceSettingsParams, Self::Error> {
+        let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+        let retention_value = match self.retention_kind {
+            None => None,
+            Some(kind) => Some(RetentionValue::parse(kind, self.retention_value.unwrap_or(0))?.0),
+        };
+        let default_member_role = match self.default_member_role {
+            None => None,
+            Some(role) => Some(DefaultMemberRole::parse(role)?.0),
+        };
+
+        Ok(UpdateWorkspaceSettingsParams {
+            workspace_id: workspace_id.0,
+            default_view_type: self.default_view_type,
+            date_format: self.date_format,
+            retention_kind: self.retention_kind,
+            retention_value,
+            default_member_role,
+        })
+    }
+}
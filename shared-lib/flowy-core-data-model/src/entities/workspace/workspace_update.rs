@@ -1,4 +1,5 @@
 use crate::{
+    entities::workspace::WorkspaceSettings,
     errors::*,
     parser::workspace::{WorkspaceIdentify, WorkspaceName},
 };
@@ -27,6 +28,10 @@ pub struct UpdateWorkspaceParams {
 
     #[pb(index = 3, one_of)]
     pub desc: Option<String>,
+
+    // Sent by the owner when the settings of the workspace change.
+    #[pb(index = 4, one_of)]
+    pub settings: Option<WorkspaceSettings>,
 }
 
 impl TryInto<UpdateWorkspaceParams> for UpdateWorkspaceRequest {
@@ -43,6 +48,7 @@ impl TryInto<UpdateWorkspaceParams> for UpdateWorkspaceRequest {
             id: id.0,
             name,
             desc: self.desc,
+            settings: None,
         })
     }
 }
@@ -0,0 +1,31 @@
+use crate::errors::ErrorCode;
+
+pub const MAX_BACKUP_KEEP_COUNT: i64 = 100;
+
+// The directory that the backups are written to. Whether it exists is checked
+// when a backup runs, e.g. a removable drive may not be mounted yet.
+#[derive(Debug)]
+pub struct BackupDir(pub String);
+
+impl BackupDir {
+    pub fn parse(s: String) -> Result<BackupDir, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::ExportPathInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+#[derive(Debug)]
+pub struct BackupKeepCount(pub i64);
+
+impl BackupKeepCount {
+    pub fn parse(count: i64) -> Result<BackupKeepCount, ErrorCode> {
+        if !(1..=MAX_BACKUP_KEEP_COUNT).contains(&count) {
+            return Err(ErrorCode::BackupKeepCountInvalid);
+        }
+
+        Ok(Self(count))
+    }
+}
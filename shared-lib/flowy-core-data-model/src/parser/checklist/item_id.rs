@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct ChecklistItemIdentify(pub String);
+
+impl ChecklistItemIdentify {
+    pub fn parse(s: String) -> Result<ChecklistItemIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::ChecklistItemIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for ChecklistItemIdentify {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -0,0 +1,23 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct ChecklistItemTitle(pub String);
+
+impl ChecklistItemTitle {
+    pub fn parse(s: String) -> Result<ChecklistItemTitle, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::ChecklistItemTitleInvalid);
+        }
+
+        if s.graphemes(true).count() > 1000 {
+            return Err(ErrorCode::ChecklistItemTitleInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for ChecklistItemTitle {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -0,0 +1,5 @@
+mod item_id;
+mod item_title;
+
+pub use item_id::*;
+pub use item_title::*;
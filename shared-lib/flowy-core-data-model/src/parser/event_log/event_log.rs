@@ -0,0 +1,56 @@
+use crate::errors::ErrorCode;
+
+pub const MAX_EVENT_LOG_LIMIT: i64 = 1000;
+
+// The cursor is the seq of the last entry that the consumer has read, zero
+// reads the log from its beginning.
+#[derive(Debug)]
+pub struct EventLogCursor(pub i64);
+
+impl EventLogCursor {
+    pub fn parse(cursor: i64) -> Result<EventLogCursor, ErrorCode> {
+        if cursor < 0 {
+            return Err(ErrorCode::EventLogCursorInvalid);
+        }
+
+        Ok(Self(cursor))
+    }
+}
+
+#[derive(Debug)]
+pub struct EventLogLimit(pub i64);
+
+impl EventLogLimit {
+    pub fn parse(limit: i64) -> Result<EventLogLimit, ErrorCode> {
+        if limit <= 0 || limit > MAX_EVENT_LOG_LIMIT {
+            return Err(ErrorCode::EventLogLimitInvalid);
+        }
+
+        Ok(Self(limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::ErrorCode,
+        parser::event_log::{EventLogCursor, EventLogLimit, MAX_EVENT_LOG_LIMIT},
+    };
+
+    #[test]
+    fn event_log_limit_parse() {
+        assert!(EventLogLimit::parse(1).is_ok());
+        assert!(EventLogLimit::parse(MAX_EVENT_LOG_LIMIT).is_ok());
+        assert_eq!(EventLogLimit::parse(0).unwrap_err(), ErrorCode::EventLogLimitInvalid);
+        assert_eq!(
+            EventLogLimit::parse(MAX_EVENT_LOG_LIMIT + 1).unwrap_err(),
+            ErrorCode::EventLogLimitInvalid
+        );
+    }
+
+    #[test]
+    fn event_log_cursor_parse() {
+        assert!(EventLogCursor::parse(0).is_ok());
+        assert_eq!(EventLogCursor::parse(-1).unwrap_err(), ErrorCode::EventLogCursorInvalid);
+    }
+}
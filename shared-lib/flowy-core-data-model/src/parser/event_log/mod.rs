@@ -0,0 +1,3 @@
+mod event_log;
+
+pub use event_log::*;
@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+
+// The unix timestamps in seconds, the start is included and the end is not.
+#[derive(Debug)]
+pub struct DateRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl DateRange {
+    pub fn parse(start: i64, end: i64) -> Result<DateRange, ErrorCode> {
+        if start > end {
+            return Err(ErrorCode::DateRangeInvalid);
+        }
+
+        Ok(Self { start, end })
+    }
+}
@@ -0,0 +1,21 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+// Only the length is checked here, the formula is parsed when it's applied to
+// the grid because its references are resolved against the fields.
+#[derive(Debug)]
+pub struct FieldFormula(pub String);
+
+impl FieldFormula {
+    pub fn parse(s: String) -> Result<FieldFormula, ErrorCode> {
+        if s.graphemes(true).count() > 1000 {
+            return Err(ErrorCode::FormulaInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for FieldFormula {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -0,0 +1,11 @@
+mod date_range;
+mod field_formula;
+mod field_id;
+mod field_name;
+mod row_id;
+
+pub use date_range::*;
+pub use field_formula::*;
+pub use field_id::*;
+pub use field_name::*;
+pub use row_id::*;
@@ -1,4 +1,13 @@
 pub mod app;
+pub mod backup;
+pub mod checklist;
+pub mod event_log;
+pub mod grid;
+pub mod search;
+pub mod share;
+pub mod storage;
 pub mod trash;
+pub mod unfurl;
 pub mod view;
+pub mod webhook;
 pub mod workspace;
@@ -0,0 +1,3 @@
+mod search_query;
+
+pub use search_query::*;
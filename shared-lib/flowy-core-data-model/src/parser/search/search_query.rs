@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct SearchQuery(pub String);
+
+impl SearchQuery {
+    pub fn parse(s: String) -> Result<SearchQuery, ErrorCode> {
+        let s = s.trim();
+        if s.is_empty() || s.graphemes(true).count() > 256 {
+            return Err(ErrorCode::SearchQueryInvalid);
+        }
+
+        Ok(Self(s.to_owned()))
+    }
+}
+
+impl AsRef<str> for SearchQuery {
+    fn as_ref(&self) -> &str { &self.0 }
+}
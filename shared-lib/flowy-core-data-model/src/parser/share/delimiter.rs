@@ -0,0 +1,36 @@
+use crate::errors::ErrorCode;
+
+// The delimiter of the fields of a CSV file, the comma is used if it's empty.
+// It can't be a quote or a line break, they have a meaning of their own.
+#[derive(Debug)]
+pub struct CsvDelimiter(pub char);
+
+impl CsvDelimiter {
+    pub fn parse(s: String) -> Result<CsvDelimiter, ErrorCode> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Ok(Self(',')),
+            (Some(c), None) if c != '"' && c != '\n' && c != '\r' => Ok(Self(c)),
+            _ => Err(ErrorCode::DelimiterInvalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{errors::ErrorCode, parser::share::CsvDelimiter};
+
+    #[test]
+    fn csv_delimiter_parse() {
+        assert_eq!(CsvDelimiter::parse("".to_owned()).unwrap().0, ',');
+        assert_eq!(CsvDelimiter::parse("\t".to_owned()).unwrap().0, '\t');
+        assert_eq!(
+            CsvDelimiter::parse("\"".to_owned()).unwrap_err(),
+            ErrorCode::DelimiterInvalid
+        );
+        assert_eq!(
+            CsvDelimiter::parse(";;".to_owned()).unwrap_err(),
+            ErrorCode::DelimiterInvalid
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct GuestAccessIdentify(pub String);
+
+impl GuestAccessIdentify {
+    pub fn parse(s: String) -> Result<GuestAccessIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::GuestAccessIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for GuestAccessIdentify {
+    fn as_ref(&self) -> &str { &self.0 }
+}
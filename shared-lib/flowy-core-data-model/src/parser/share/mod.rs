@@ -0,0 +1,7 @@
+mod delimiter;
+mod guest_access;
+mod share_link;
+
+pub use delimiter::*;
+pub use guest_access::*;
+pub use share_link::*;
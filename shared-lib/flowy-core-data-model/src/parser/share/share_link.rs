@@ -0,0 +1,50 @@
+use crate::errors::ErrorCode;
+use chrono::Utc;
+
+#[derive(Debug)]
+pub struct ShareLinkIdentify(pub String);
+
+impl ShareLinkIdentify {
+    pub fn parse(s: String) -> Result<ShareLinkIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::ShareLinkIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for ShareLinkIdentify {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+// The expire time is a unix timestamp in seconds. None means the link never
+// expires.
+#[derive(Debug)]
+pub struct ShareLinkExpireTime(pub Option<i64>);
+
+impl ShareLinkExpireTime {
+    pub fn parse(expire_time: Option<i64>) -> Result<ShareLinkExpireTime, ErrorCode> {
+        match expire_time {
+            Some(time) if time <= Utc::now().timestamp() => Err(ErrorCode::ShareLinkExpireTimeInvalid),
+            _ => Ok(Self(expire_time)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{errors::ErrorCode, parser::share::ShareLinkExpireTime};
+    use chrono::Utc;
+
+    #[test]
+    fn share_link_expire_time_parse() {
+        let now = Utc::now().timestamp();
+        assert!(ShareLinkExpireTime::parse(None).is_ok());
+        assert!(ShareLinkExpireTime::parse(Some(now + 60)).is_ok());
+        assert_eq!(
+            ShareLinkExpireTime::parse(Some(now - 60)).unwrap_err(),
+            ErrorCode::ShareLinkExpireTimeInvalid
+        );
+    }
+}
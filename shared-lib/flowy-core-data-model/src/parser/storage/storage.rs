@@ -0,0 +1,83 @@
+use crate::errors::ErrorCode;
+
+pub const DEFAULT_STORAGE_REGION: &str = "us-east-1";
+
+// The endpoint of an S3-compatible service, e.g. https://s3.amazonaws.com or
+// the url of a MinIO server. The bucket is addressed in the path, so the
+// endpoint is the same for every bucket.
+#[derive(Debug)]
+pub struct StorageEndpoint(pub String);
+
+impl StorageEndpoint {
+    pub fn parse(s: String) -> Result<StorageEndpoint, ErrorCode> {
+        let url = s.trim().trim_end_matches('/');
+        let host = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .ok_or(ErrorCode::StorageEndpointInvalid)?;
+
+        if host.is_empty() || host.starts_with('/') || url.chars().any(char::is_whitespace) {
+            return Err(ErrorCode::StorageEndpointInvalid);
+        }
+
+        Ok(Self(url.to_owned()))
+    }
+}
+
+// Follows the naming rules of S3: 3 to 63 lowercase letters, digits, dots
+// and hyphens, beginning and ending with a letter or a digit.
+#[derive(Debug)]
+pub struct StorageBucket(pub String);
+
+impl StorageBucket {
+    pub fn parse(s: String) -> Result<StorageBucket, ErrorCode> {
+        let bucket = s.trim();
+        if !(3..=63).contains(&bucket.len()) {
+            return Err(ErrorCode::StorageBucketInvalid);
+        }
+
+        let is_valid_char = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.' || c == '-';
+        let is_valid_end = |c: Option<char>| c.map_or(false, |c| c.is_ascii_lowercase() || c.is_ascii_digit());
+        if !bucket.chars().all(is_valid_char)
+            || !is_valid_end(bucket.chars().next())
+            || !is_valid_end(bucket.chars().last())
+        {
+            return Err(ErrorCode::StorageBucketInvalid);
+        }
+
+        Ok(Self(bucket.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::ErrorCode,
+        parser::storage::{StorageBucket, StorageEndpoint},
+    };
+
+    #[test]
+    fn storage_endpoint_parse() {
+        assert_eq!(
+            StorageEndpoint::parse("https://s3.example.com/".to_owned()).unwrap().0,
+            "https://s3.example.com"
+        );
+        assert!(StorageEndpoint::parse("http://localhost:9000".to_owned()).is_ok());
+        assert_eq!(
+            StorageEndpoint::parse("s3.example.com".to_owned()).unwrap_err(),
+            ErrorCode::StorageEndpointInvalid
+        );
+    }
+
+    #[test]
+    fn storage_bucket_parse() {
+        assert!(StorageBucket::parse("appflowy-attachments".to_owned()).is_ok());
+        assert!(StorageBucket::parse("my.bucket.1".to_owned()).is_ok());
+        for bucket in vec!["ab", "Upper", "-bucket", "bucket-", "under_score"] {
+            assert_eq!(
+                StorageBucket::parse(bucket.to_owned()).unwrap_err(),
+                ErrorCode::StorageBucketInvalid
+            );
+        }
+    }
+}
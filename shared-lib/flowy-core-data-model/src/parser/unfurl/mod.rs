@@ -0,0 +1,3 @@
+mod unfurl;
+
+pub use unfurl::*;
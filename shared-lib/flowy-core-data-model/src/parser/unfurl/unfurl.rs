@@ -0,0 +1,26 @@
+use crate::errors::ErrorCode;
+
+// The url of a link preview. Only the http and the https urls with a host are
+// fetched, the other schemes can point at the files of the device.
+#[derive(Debug)]
+pub struct UnfurlUrl(pub String);
+
+impl UnfurlUrl {
+    pub fn parse(s: String) -> Result<UnfurlUrl, ErrorCode> {
+        let url = s.trim();
+        let host = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .ok_or(ErrorCode::EmbedUrlInvalid)?;
+
+        if host.is_empty() || host.starts_with('/') || url.chars().any(char::is_whitespace) {
+            return Err(ErrorCode::EmbedUrlInvalid);
+        }
+
+        Ok(Self(url.to_owned()))
+    }
+}
+
+impl AsRef<str> for UnfurlUrl {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct AttachmentIdentify(pub String);
+
+impl AttachmentIdentify {
+    pub fn parse(s: String) -> Result<AttachmentIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::AttachmentIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for AttachmentIdentify {
+    fn as_ref(&self) -> &str { &self.0 }
+}
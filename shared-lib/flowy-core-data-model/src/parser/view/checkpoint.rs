@@ -0,0 +1,37 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct CheckpointName(pub String);
+
+impl CheckpointName {
+    pub fn parse(s: String) -> Result<CheckpointName, ErrorCode> {
+        let s = s.trim();
+        if s.is_empty() || s.graphemes(true).count() > 100 {
+            return Err(ErrorCode::CheckpointNameInvalid);
+        }
+
+        Ok(Self(s.to_owned()))
+    }
+}
+
+impl AsRef<str> for CheckpointName {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+#[derive(Debug)]
+pub struct CheckpointIdentify(pub String);
+
+impl CheckpointIdentify {
+    pub fn parse(s: String) -> Result<CheckpointIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::CheckpointIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for CheckpointIdentify {
+    fn as_ref(&self) -> &str { &self.0 }
+}
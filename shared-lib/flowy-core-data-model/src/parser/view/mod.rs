@@ -1,11 +1,21 @@
+mod attachment_id;
+mod checkpoint;
 mod delta_data;
+mod text_index;
+mod toggle_id;
 mod view_desc;
 mod view_id;
 mod view_name;
+mod view_tag;
 mod view_thumbnail;
 
+pub use attachment_id::*;
+pub use checkpoint::*;
 pub use delta_data::*;
+pub use text_index::*;
+pub use toggle_id::*;
 pub use view_desc::*;
 pub use view_id::*;
 pub use view_name::*;
+pub use view_tag::*;
 pub use view_thumbnail::*;
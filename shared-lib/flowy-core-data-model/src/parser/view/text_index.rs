@@ -0,0 +1,15 @@
+use crate::errors::ErrorCode;
+
+// An offset in the document, in utf16 code units.
+#[derive(Debug)]
+pub struct TextIndex(pub usize);
+
+impl TextIndex {
+    pub fn parse(index: i64) -> Result<TextIndex, ErrorCode> {
+        if index < 0 {
+            return Err(ErrorCode::TextIndexInvalid);
+        }
+
+        Ok(Self(index as usize))
+    }
+}
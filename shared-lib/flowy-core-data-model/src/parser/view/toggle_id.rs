@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct ToggleIdentify(pub String);
+
+impl ToggleIdentify {
+    pub fn parse(s: String) -> Result<ToggleIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::ToggleIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for ToggleIdentify {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct ViewTag(pub String);
+
+impl ViewTag {
+    pub fn parse(s: String) -> Result<ViewTag, ErrorCode> {
+        let s = s.trim();
+        if s.is_empty() || s.graphemes(true).count() > 100 {
+            return Err(ErrorCode::ViewTagInvalid);
+        }
+
+        Ok(Self(s.to_owned()))
+    }
+}
+
+impl AsRef<str> for ViewTag {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -0,0 +1,66 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct WebhookIdentify(pub String);
+
+impl WebhookIdentify {
+    pub fn parse(s: String) -> Result<WebhookIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::WebhookIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for WebhookIdentify {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+// Only the http and the https urls with a host are accepted, the payloads are
+// posted to them as they are.
+#[derive(Debug)]
+pub struct WebhookUrl(pub String);
+
+impl WebhookUrl {
+    pub fn parse(s: String) -> Result<WebhookUrl, ErrorCode> {
+        let url = s.trim();
+        let host = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .ok_or(ErrorCode::WebhookUrlInvalid)?;
+
+        if host.is_empty() || host.starts_with('/') || url.chars().any(char::is_whitespace) {
+            return Err(ErrorCode::WebhookUrlInvalid);
+        }
+
+        Ok(Self(url.to_owned()))
+    }
+}
+
+impl AsRef<str> for WebhookUrl {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{errors::ErrorCode, parser::webhook::WebhookUrl};
+
+    #[test]
+    fn webhook_url_parse() {
+        assert!(WebhookUrl::parse("https://example.com/hooks".to_owned()).is_ok());
+        assert!(WebhookUrl::parse("http://localhost:8080".to_owned()).is_ok());
+        assert_eq!(
+            WebhookUrl::parse("ftp://example.com".to_owned()).unwrap_err(),
+            ErrorCode::WebhookUrlInvalid
+        );
+        assert_eq!(
+            WebhookUrl::parse("https://".to_owned()).unwrap_err(),
+            ErrorCode::WebhookUrlInvalid
+        );
+        assert_eq!(
+            WebhookUrl::parse("https://example.com/a b".to_owned()).unwrap_err(),
+            ErrorCode::WebhookUrlInvalid
+        );
+    }
+}
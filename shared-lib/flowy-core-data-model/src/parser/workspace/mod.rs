@@ -1,7 +1,13 @@
+mod revision_retention;
 mod workspace_desc;
 mod workspace_id;
+mod workspace_invitation;
+mod workspace_member;
 mod workspace_name;
 
+pub use revision_retention::*;
 pub use workspace_desc::*;
 pub use workspace_id::*;
+pub use workspace_invitation::*;
+pub use workspace_member::*;
 pub use workspace_name::*;
@@ -0,0 +1,19 @@
+use crate::{entities::workspace::RetentionKind, errors::ErrorCode};
+
+// The number of revisions or days to keep, it's zero when everything is kept.
+#[derive(Debug)]
+pub struct RetentionValue(pub i64);
+
+impl RetentionValue {
+    pub fn parse(kind: RetentionKind, value: i64) -> Result<RetentionValue, ErrorCode> {
+        match kind {
+            RetentionKind::All => Ok(Self(0)),
+            RetentionKind::LastRevisions | RetentionKind::LastDays => {
+                if value < 1 {
+                    return Err(ErrorCode::RevisionRetentionValueInvalid);
+                }
+                Ok(Self(value))
+            },
+        }
+    }
+}
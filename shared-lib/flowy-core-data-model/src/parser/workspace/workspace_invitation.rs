@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct InvitationIdentify(pub String);
+
+impl InvitationIdentify {
+    pub fn parse(s: String) -> Result<InvitationIdentify, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::InvitationIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for InvitationIdentify {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -0,0 +1,76 @@
+use crate::{entities::workspace::WorkspaceRole, errors::ErrorCode};
+
+// The member is looked up by the email on the server, so only the obviously
+// invalid ones are rejected here.
+#[derive(Debug)]
+pub struct WorkspaceMemberEmail(pub String);
+
+impl WorkspaceMemberEmail {
+    pub fn parse(s: String) -> Result<WorkspaceMemberEmail, ErrorCode> {
+        let s = s.trim().to_owned();
+        if s.is_empty() {
+            return Err(ErrorCode::EmailIsEmpty);
+        }
+
+        match s.split_once('@') {
+            Some((name, domain)) if !name.is_empty() && !domain.is_empty() => Ok(Self(s)),
+            _ => Err(ErrorCode::EmailFormatInvalid),
+        }
+    }
+}
+
+impl AsRef<str> for WorkspaceMemberEmail {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+#[derive(Debug)]
+pub struct WorkspaceMemberId(pub String);
+
+impl WorkspaceMemberId {
+    pub fn parse(s: String) -> Result<WorkspaceMemberId, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::UserIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for WorkspaceMemberId {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+// A workspace has one owner, so the new members can't be given that role by
+// default.
+#[derive(Debug)]
+pub struct DefaultMemberRole(pub WorkspaceRole);
+
+impl DefaultMemberRole {
+    pub fn parse(role: WorkspaceRole) -> Result<DefaultMemberRole, ErrorCode> {
+        match role {
+            WorkspaceRole::Owner => Err(ErrorCode::DefaultMemberRoleInvalid),
+            WorkspaceRole::Editor | WorkspaceRole::Viewer => Ok(Self(role)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{errors::ErrorCode, parser::workspace::WorkspaceMemberEmail};
+
+    #[test]
+    fn member_email_parse() {
+        assert_eq!(
+            WorkspaceMemberEmail::parse(" annie@appflowy.io ".to_owned()).unwrap().0,
+            "annie@appflowy.io"
+        );
+        assert_eq!(
+            WorkspaceMemberEmail::parse(" ".to_owned()).unwrap_err(),
+            ErrorCode::EmailIsEmpty
+        );
+        assert_eq!(
+            WorkspaceMemberEmail::parse("annie@".to_owned()).unwrap_err(),
+            ErrorCode::EmailFormatInvalid
+        );
+    }
+}
@@ -30,6 +30,8 @@ pub struct CreateAppRequest {
     pub name: ::std::string::String,
     pub desc: ::std::string::String,
     pub color_style: ::protobuf::SingularPtrField<ColorStyle>,
+    // message oneof groups
+    pub one_of_app_id: ::std::option::Option<CreateAppRequest_oneof_one_of_app_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -41,6 +43,11 @@ impl<'a> ::std::default::Default for &'a CreateAppRequest {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateAppRequest_oneof_one_of_app_id {
+    app_id(::std::string::String),
+}
+
 impl CreateAppRequest {
     pub fn new() -> CreateAppRequest {
         ::std::default::Default::default()
@@ -156,6 +163,55 @@ impl CreateAppRequest {
     pub fn take_color_style(&mut self) -> ColorStyle {
         self.color_style.take().unwrap_or_else(|| ColorStyle::new())
     }
+
+    // string app_id = 5;
+
+
+    pub fn get_app_id(&self) -> &str {
+        match self.one_of_app_id {
+            ::std::option::Option::Some(CreateAppRequest_oneof_one_of_app_id::app_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_app_id(&mut self) {
+        self.one_of_app_id = ::std::option::Option::None;
+    }
+
+    pub fn has_app_id(&self) -> bool {
+        match self.one_of_app_id {
+            ::std::option::Option::Some(CreateAppRequest_oneof_one_of_app_id::app_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_id(&mut self, v: ::std::string::String) {
+        self.one_of_app_id = ::std::option::Option::Some(CreateAppRequest_oneof_one_of_app_id::app_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_app_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateAppRequest_oneof_one_of_app_id::app_id(_)) = self.one_of_app_id {
+        } else {
+            self.one_of_app_id = ::std::option::Option::Some(CreateAppRequest_oneof_one_of_app_id::app_id(::std::string::String::new()));
+        }
+        match self.one_of_app_id {
+            ::std::option::Option::Some(CreateAppRequest_oneof_one_of_app_id::app_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_app_id(&mut self) -> ::std::string::String {
+        if self.has_app_id() {
+            match self.one_of_app_id.take() {
+                ::std::option::Option::Some(CreateAppRequest_oneof_one_of_app_id::app_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for CreateAppRequest {
@@ -184,6 +240,12 @@ impl ::protobuf::Message for CreateAppRequest {
                 4 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.color_style)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_app_id = ::std::option::Option::Some(CreateAppRequest_oneof_one_of_app_id::app_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -209,6 +271,13 @@ impl ::protobuf::Message for CreateAppRequest {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_app_id {
+            match v {
+                &CreateAppRequest_oneof_one_of_app_id::app_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -229,6 +298,13 @@ impl ::protobuf::Message for CreateAppRequest {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_app_id {
+            match v {
+                &CreateAppRequest_oneof_one_of_app_id::app_id(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -287,6 +363,11 @@ impl ::protobuf::Message for CreateAppRequest {
                 |m: &CreateAppRequest| { &m.color_style },
                 |m: &mut CreateAppRequest| { &mut m.color_style },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "app_id",
+                CreateAppRequest::has_app_id,
+                CreateAppRequest::get_app_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateAppRequest>(
                 "CreateAppRequest",
                 fields,
@@ -307,6 +388,7 @@ impl ::protobuf::Clear for CreateAppRequest {
         self.name.clear();
         self.desc.clear();
         self.color_style.clear();
+        self.one_of_app_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -489,6 +571,7 @@ pub struct CreateAppParams {
     pub name: ::std::string::String,
     pub desc: ::std::string::String,
     pub color_style: ::protobuf::SingularPtrField<ColorStyle>,
+    pub app_id: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -615,6 +698,32 @@ impl CreateAppParams {
     pub fn take_color_style(&mut self) -> ColorStyle {
         self.color_style.take().unwrap_or_else(|| ColorStyle::new())
     }
+
+    // string app_id = 5;
+
+
+    pub fn get_app_id(&self) -> &str {
+        &self.app_id
+    }
+    pub fn clear_app_id(&mut self) {
+        self.app_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_id(&mut self, v: ::std::string::String) {
+        self.app_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_app_id(&mut self) -> &mut ::std::string::String {
+        &mut self.app_id
+    }
+
+    // Take field
+    pub fn take_app_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.app_id, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for CreateAppParams {
@@ -643,6 +752,9 @@ impl ::protobuf::Message for CreateAppParams {
                 4 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.color_style)?;
                 },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.app_id)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -668,6 +780,9 @@ impl ::protobuf::Message for CreateAppParams {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if !self.app_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.app_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -688,6 +803,9 @@ impl ::protobuf::Message for CreateAppParams {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if !self.app_id.is_empty() {
+            os.write_string(5, &self.app_id)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -746,6 +864,11 @@ impl ::protobuf::Message for CreateAppParams {
                 |m: &CreateAppParams| { &m.color_style },
                 |m: &mut CreateAppParams| { &mut m.color_style },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "app_id",
+                |m: &CreateAppParams| { &m.app_id },
+                |m: &mut CreateAppParams| { &mut m.app_id },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateAppParams>(
                 "CreateAppParams",
                 fields,
@@ -766,6 +889,7 @@ impl ::protobuf::Clear for CreateAppParams {
         self.name.clear();
         self.desc.clear();
         self.color_style.clear();
+        self.app_id.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1396,80 +1520,26 @@ impl ::protobuf::reflect::ProtobufValue for RepeatedApp {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10app_create.proto\x1a\x11view_create.proto\"\x8b\x01\n\x10CreateApp\
-    Request\x12!\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceId\x12\
-    \x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\
-    \x01(\tR\x04desc\x12,\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\x0b.ColorSt\
-    yleR\ncolorStyle\"-\n\nColorStyle\x12\x1f\n\x0btheme_color\x18\x01\x20\
-    \x01(\tR\nthemeColor\"\x8a\x01\n\x0fCreateAppParams\x12!\n\x0cworkspace_\
-    id\x18\x01\x20\x01(\tR\x0bworkspaceId\x12\x12\n\x04name\x18\x02\x20\x01(\
-    \tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\x12,\n\x0bcolo\
-    r_style\x18\x04\x20\x01(\x0b2\x0b.ColorStyleR\ncolorStyle\"\xef\x01\n\
-    \x03App\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12!\n\x0cworkspace_id\
-    \x18\x02\x20\x01(\tR\x0bworkspaceId\x12\x12\n\x04name\x18\x03\x20\x01(\t\
-    R\x04name\x12\x12\n\x04desc\x18\x04\x20\x01(\tR\x04desc\x12-\n\nbelongin\
-    gs\x18\x05\x20\x01(\x0b2\r.RepeatedViewR\nbelongings\x12\x18\n\x07versio\
-    n\x18\x06\x20\x01(\x03R\x07version\x12#\n\rmodified_time\x18\x07\x20\x01\
-    (\x03R\x0cmodifiedTime\x12\x1f\n\x0bcreate_time\x18\x08\x20\x01(\x03R\nc\
-    reateTime\")\n\x0bRepeatedApp\x12\x1a\n\x05items\x18\x01\x20\x03(\x0b2\
-    \x04.AppR\x05itemsJ\x81\t\n\x06\x12\x04\0\0\x1e\x01\n\x08\n\x01\x0c\x12\
-    \x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x1b\n\n\n\x02\x04\0\x12\x04\
-    \x03\0\x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x18\n\x0b\n\x04\x04\0\
-    \x02\0\x12\x03\x04\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\
-    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x17\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x04\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\x14\n\
-    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x01\
-    \x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\x12\
-    \x13\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x06\x04\x14\n\x0c\n\x05\x04\0\x02\
-    \x02\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\x0b\
-    \x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x06\x12\x13\n\x0b\n\x04\x04\0\
-    \x02\x03\x12\x03\x07\x04\x1f\n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03\x07\
-    \x04\x0e\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x07\x0f\x1a\n\x0c\n\x05\
-    \x04\0\x02\x03\x03\x12\x03\x07\x1d\x1e\n\n\n\x02\x04\x01\x12\x04\t\0\x0b\
-    \x01\n\n\n\x03\x04\x01\x01\x12\x03\t\x08\x12\n\x0b\n\x04\x04\x01\x02\0\
-    \x12\x03\n\x04\x1b\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\
-    \x05\x04\x01\x02\0\x01\x12\x03\n\x0b\x16\n\x0c\n\x05\x04\x01\x02\0\x03\
-    \x12\x03\n\x19\x1a\n\n\n\x02\x04\x02\x12\x04\x0c\0\x11\x01\n\n\n\x03\x04\
-    \x02\x01\x12\x03\x0c\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x1c\
-    \n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\
-    \x01\x12\x03\r\x0b\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x1a\x1b\n\
-    \x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x04\x14\n\x0c\n\x05\x04\x02\x02\
-    \x01\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0e\
-    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0e\x12\x13\n\x0b\n\x04\
-    \x04\x02\x02\x02\x12\x03\x0f\x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\
-    \x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x0b\x0f\n\x0c\
-    \n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x12\x13\n\x0b\n\x04\x04\x02\x02\
-    \x03\x12\x03\x10\x04\x1f\n\x0c\n\x05\x04\x02\x02\x03\x06\x12\x03\x10\x04\
-    \x0e\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x10\x0f\x1a\n\x0c\n\x05\x04\
-    \x02\x02\x03\x03\x12\x03\x10\x1d\x1e\n\n\n\x02\x04\x03\x12\x04\x12\0\x1b\
-    \x01\n\n\n\x03\x04\x03\x01\x12\x03\x12\x08\x0b\n\x0b\n\x04\x04\x03\x02\0\
-    \x12\x03\x13\x04\x12\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x13\x04\n\n\
-    \x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x13\x0b\r\n\x0c\n\x05\x04\x03\x02\0\
-    \x03\x12\x03\x13\x10\x11\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x14\x04\x1c\
-    \n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x03\
-    \x02\x01\x01\x12\x03\x14\x0b\x17\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\
-    \x14\x1a\x1b\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x15\x04\x14\n\x0c\n\x05\
-    \x04\x03\x02\x02\x05\x12\x03\x15\x04\n\n\x0c\n\x05\x04\x03\x02\x02\x01\
-    \x12\x03\x15\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x15\x12\x13\
-    \n\x0b\n\x04\x04\x03\x02\x03\x12\x03\x16\x04\x14\n\x0c\n\x05\x04\x03\x02\
-    \x03\x05\x12\x03\x16\x04\n\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x16\
-    \x0b\x0f\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\x16\x12\x13\n\x0b\n\x04\
-    \x04\x03\x02\x04\x12\x03\x17\x04\x20\n\x0c\n\x05\x04\x03\x02\x04\x06\x12\
-    \x03\x17\x04\x10\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x03\x17\x11\x1b\n\
-    \x0c\n\x05\x04\x03\x02\x04\x03\x12\x03\x17\x1e\x1f\n\x0b\n\x04\x04\x03\
-    \x02\x05\x12\x03\x18\x04\x16\n\x0c\n\x05\x04\x03\x02\x05\x05\x12\x03\x18\
-    \x04\t\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\x03\x18\n\x11\n\x0c\n\x05\x04\
-    \x03\x02\x05\x03\x12\x03\x18\x14\x15\n\x0b\n\x04\x04\x03\x02\x06\x12\x03\
-    \x19\x04\x1c\n\x0c\n\x05\x04\x03\x02\x06\x05\x12\x03\x19\x04\t\n\x0c\n\
-    \x05\x04\x03\x02\x06\x01\x12\x03\x19\n\x17\n\x0c\n\x05\x04\x03\x02\x06\
-    \x03\x12\x03\x19\x1a\x1b\n\x0b\n\x04\x04\x03\x02\x07\x12\x03\x1a\x04\x1a\
-    \n\x0c\n\x05\x04\x03\x02\x07\x05\x12\x03\x1a\x04\t\n\x0c\n\x05\x04\x03\
-    \x02\x07\x01\x12\x03\x1a\n\x15\n\x0c\n\x05\x04\x03\x02\x07\x03\x12\x03\
-    \x1a\x18\x19\n\n\n\x02\x04\x04\x12\x04\x1c\0\x1e\x01\n\n\n\x03\x04\x04\
-    \x01\x12\x03\x1c\x08\x13\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1d\x04\x1b\n\
-    \x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x1d\x04\x0c\n\x0c\n\x05\x04\x04\x02\
-    \0\x06\x12\x03\x1d\r\x10\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1d\x11\
-    \x16\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1d\x19\x1ab\x06proto3\
+    \n\x10app_create.proto\x1a\x11view_create.proto\"\xc1\x01\n\x10CreateApp\
+    Request\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x12\
+    \x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\x14\n\x04desc\x18\x03\
+    \x20\x01(\tR\x04descB\0\x12.\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\x0b.\
+    ColorStyleR\ncolorStyleB\0\x12\x19\n\x06app_id\x18\x05\x20\x01(\tH\0R\
+    \x05appIdB\0B\x0f\n\rone_of_app_id:\0\"1\n\nColorStyle\x12!\n\x0btheme_c\
+    olor\x18\x01\x20\x01(\tR\nthemeColorB\0:\0\"\xad\x01\n\x0fCreateAppParam\
+    s\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x12\x14\n\
+    \x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\x14\n\x04desc\x18\x03\x20\
+    \x01(\tR\x04descB\0\x12.\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\x0b.Colo\
+    rStyleR\ncolorStyleB\0\x12\x17\n\x06app_id\x18\x05\x20\x01(\tR\x05appIdB\
+    \0:\0\"\x81\x02\n\x03App\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\
+    \x12#\n\x0cworkspace_id\x18\x02\x20\x01(\tR\x0bworkspaceIdB\0\x12\x14\n\
+    \x04name\x18\x03\x20\x01(\tR\x04nameB\0\x12\x14\n\x04desc\x18\x04\x20\
+    \x01(\tR\x04descB\0\x12/\n\nbelongings\x18\x05\x20\x01(\x0b2\r.RepeatedV\
+    iewR\nbelongingsB\0\x12\x1a\n\x07version\x18\x06\x20\x01(\x03R\x07versio\
+    nB\0\x12%\n\rmodified_time\x18\x07\x20\x01(\x03R\x0cmodifiedTimeB\0\x12!\
+    \n\x0bcreate_time\x18\x08\x20\x01(\x03R\ncreateTimeB\0:\0\"-\n\x0bRepeat\
+    edApp\x12\x1c\n\x05items\x18\x01\x20\x03(\x0b2\x04.AppR\x05itemsB\0:\0B\
+    \0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
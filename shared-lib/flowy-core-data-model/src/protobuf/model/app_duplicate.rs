@@ -0,0 +1,258 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `app_duplicate.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AppDuplicated {
+    // message fields
+    pub source_app_id: ::std::string::String,
+    pub app: ::protobuf::SingularPtrField<super::app_create::App>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AppDuplicated {
+    fn default() -> &'a AppDuplicated {
+        <AppDuplicated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AppDuplicated {
+    pub fn new() -> AppDuplicated {
+        ::std::default::Default::default()
+    }
+
+    // string source_app_id = 1;
+
+
+    pub fn get_source_app_id(&self) -> &str {
+        &self.source_app_id
+    }
+    pub fn clear_source_app_id(&mut self) {
+        self.source_app_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_app_id(&mut self, v: ::std::string::String) {
+        self.source_app_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_app_id(&mut self) -> &mut ::std::string::String {
+        &mut self.source_app_id
+    }
+
+    // Take field
+    pub fn take_source_app_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source_app_id, ::std::string::String::new())
+    }
+
+    // .App app = 2;
+
+
+    pub fn get_app(&self) -> &super::app_create::App {
+        self.app.as_ref().unwrap_or_else(|| <super::app_create::App as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_app(&mut self) {
+        self.app.clear();
+    }
+
+    pub fn has_app(&self) -> bool {
+        self.app.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app(&mut self, v: super::app_create::App) {
+        self.app = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_app(&mut self) -> &mut super::app_create::App {
+        if self.app.is_none() {
+            self.app.set_default();
+        }
+        self.app.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_app(&mut self) -> super::app_create::App {
+        self.app.take().unwrap_or_else(|| super::app_create::App::new())
+    }
+}
+
+impl ::protobuf::Message for AppDuplicated {
+    fn is_initialized(&self) -> bool {
+        for v in &self.app {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source_app_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.app)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.source_app_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.source_app_id);
+        }
+        if let Some(ref v) = self.app.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.source_app_id.is_empty() {
+            os.write_string(1, &self.source_app_id)?;
+        }
+        if let Some(ref v) = self.app.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AppDuplicated {
+        AppDuplicated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "source_app_id",
+                |m: &AppDuplicated| { &m.source_app_id },
+                |m: &mut AppDuplicated| { &mut m.source_app_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::app_create::App>>(
+                "app",
+                |m: &AppDuplicated| { &m.app },
+                |m: &mut AppDuplicated| { &mut m.app },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AppDuplicated>(
+                "AppDuplicated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AppDuplicated {
+        static instance: ::protobuf::rt::LazyV2<AppDuplicated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AppDuplicated::new)
+    }
+}
+
+impl ::protobuf::Clear for AppDuplicated {
+    fn clear(&mut self) {
+        self.source_app_id.clear();
+        self.app.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AppDuplicated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AppDuplicated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x13app_duplicate.proto\x1a\x10app_create.proto\"Q\n\rAppDuplicated\
+    \x12$\n\rsource_app_id\x18\x01\x20\x01(\tR\x0bsourceAppIdB\0\x12\x18\n\
+    \x03app\x18\x02\x20\x01(\x0b2\x04.AppR\x03appB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,1231 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `audit_log.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AuditLogEntry {
+    // message fields
+    pub seq: i64,
+    pub workspace_id: ::std::string::String,
+    pub actor_id: ::std::string::String,
+    pub action: ::std::string::String,
+    pub object_type: ::std::string::String,
+    pub object_id: ::std::string::String,
+    pub before: ::std::string::String,
+    pub after: ::std::string::String,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AuditLogEntry {
+    fn default() -> &'a AuditLogEntry {
+        <AuditLogEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AuditLogEntry {
+    pub fn new() -> AuditLogEntry {
+        ::std::default::Default::default()
+    }
+
+    // int64 seq = 1;
+
+
+    pub fn get_seq(&self) -> i64 {
+        self.seq
+    }
+    pub fn clear_seq(&mut self) {
+        self.seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: i64) {
+        self.seq = v;
+    }
+
+    // string workspace_id = 2;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string actor_id = 3;
+
+
+    pub fn get_actor_id(&self) -> &str {
+        &self.actor_id
+    }
+    pub fn clear_actor_id(&mut self) {
+        self.actor_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_actor_id(&mut self, v: ::std::string::String) {
+        self.actor_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_actor_id(&mut self) -> &mut ::std::string::String {
+        &mut self.actor_id
+    }
+
+    // Take field
+    pub fn take_actor_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.actor_id, ::std::string::String::new())
+    }
+
+    // string action = 4;
+
+
+    pub fn get_action(&self) -> &str {
+        &self.action
+    }
+    pub fn clear_action(&mut self) {
+        self.action.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_action(&mut self, v: ::std::string::String) {
+        self.action = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_action(&mut self) -> &mut ::std::string::String {
+        &mut self.action
+    }
+
+    // Take field
+    pub fn take_action(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.action, ::std::string::String::new())
+    }
+
+    // string object_type = 5;
+
+
+    pub fn get_object_type(&self) -> &str {
+        &self.object_type
+    }
+    pub fn clear_object_type(&mut self) {
+        self.object_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_type(&mut self, v: ::std::string::String) {
+        self.object_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_type(&mut self) -> &mut ::std::string::String {
+        &mut self.object_type
+    }
+
+    // Take field
+    pub fn take_object_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_type, ::std::string::String::new())
+    }
+
+    // string object_id = 6;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // string before = 7;
+
+
+    pub fn get_before(&self) -> &str {
+        &self.before
+    }
+    pub fn clear_before(&mut self) {
+        self.before.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_before(&mut self, v: ::std::string::String) {
+        self.before = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_before(&mut self) -> &mut ::std::string::String {
+        &mut self.before
+    }
+
+    // Take field
+    pub fn take_before(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.before, ::std::string::String::new())
+    }
+
+    // string after = 8;
+
+
+    pub fn get_after(&self) -> &str {
+        &self.after
+    }
+    pub fn clear_after(&mut self) {
+        self.after.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_after(&mut self, v: ::std::string::String) {
+        self.after = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_after(&mut self) -> &mut ::std::string::String {
+        &mut self.after
+    }
+
+    // Take field
+    pub fn take_after(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.after, ::std::string::String::new())
+    }
+
+    // int64 create_time = 9;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for AuditLogEntry {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.seq = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.actor_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.action)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_type)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.before)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.after)?;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.workspace_id);
+        }
+        if !self.actor_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.actor_id);
+        }
+        if !self.action.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.action);
+        }
+        if !self.object_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.object_type);
+        }
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.object_id);
+        }
+        if !self.before.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.before);
+        }
+        if !self.after.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.after);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.seq != 0 {
+            os.write_int64(1, self.seq)?;
+        }
+        if !self.workspace_id.is_empty() {
+            os.write_string(2, &self.workspace_id)?;
+        }
+        if !self.actor_id.is_empty() {
+            os.write_string(3, &self.actor_id)?;
+        }
+        if !self.action.is_empty() {
+            os.write_string(4, &self.action)?;
+        }
+        if !self.object_type.is_empty() {
+            os.write_string(5, &self.object_type)?;
+        }
+        if !self.object_id.is_empty() {
+            os.write_string(6, &self.object_id)?;
+        }
+        if !self.before.is_empty() {
+            os.write_string(7, &self.before)?;
+        }
+        if !self.after.is_empty() {
+            os.write_string(8, &self.after)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(9, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AuditLogEntry {
+        AuditLogEntry::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "seq",
+                |m: &AuditLogEntry| { &m.seq },
+                |m: &mut AuditLogEntry| { &mut m.seq },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &AuditLogEntry| { &m.workspace_id },
+                |m: &mut AuditLogEntry| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "actor_id",
+                |m: &AuditLogEntry| { &m.actor_id },
+                |m: &mut AuditLogEntry| { &mut m.actor_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "action",
+                |m: &AuditLogEntry| { &m.action },
+                |m: &mut AuditLogEntry| { &mut m.action },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_type",
+                |m: &AuditLogEntry| { &m.object_type },
+                |m: &mut AuditLogEntry| { &mut m.object_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &AuditLogEntry| { &m.object_id },
+                |m: &mut AuditLogEntry| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "before",
+                |m: &AuditLogEntry| { &m.before },
+                |m: &mut AuditLogEntry| { &mut m.before },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "after",
+                |m: &AuditLogEntry| { &m.after },
+                |m: &mut AuditLogEntry| { &mut m.after },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &AuditLogEntry| { &m.create_time },
+                |m: &mut AuditLogEntry| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AuditLogEntry>(
+                "AuditLogEntry",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AuditLogEntry {
+        static instance: ::protobuf::rt::LazyV2<AuditLogEntry> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AuditLogEntry::new)
+    }
+}
+
+impl ::protobuf::Clear for AuditLogEntry {
+    fn clear(&mut self) {
+        self.seq = 0;
+        self.workspace_id.clear();
+        self.actor_id.clear();
+        self.action.clear();
+        self.object_type.clear();
+        self.object_id.clear();
+        self.before.clear();
+        self.after.clear();
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AuditLogEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AuditLogEntry {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AuditLogPage {
+    // message fields
+    pub items: ::protobuf::RepeatedField<AuditLogEntry>,
+    pub next_cursor: i64,
+    pub has_more: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AuditLogPage {
+    fn default() -> &'a AuditLogPage {
+        <AuditLogPage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AuditLogPage {
+    pub fn new() -> AuditLogPage {
+        ::std::default::Default::default()
+    }
+
+    // repeated .AuditLogEntry items = 1;
+
+
+    pub fn get_items(&self) -> &[AuditLogEntry] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<AuditLogEntry>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<AuditLogEntry> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<AuditLogEntry> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+
+    // int64 next_cursor = 2;
+
+
+    pub fn get_next_cursor(&self) -> i64 {
+        self.next_cursor
+    }
+    pub fn clear_next_cursor(&mut self) {
+        self.next_cursor = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_cursor(&mut self, v: i64) {
+        self.next_cursor = v;
+    }
+
+    // bool has_more = 3;
+
+
+    pub fn get_has_more(&self) -> bool {
+        self.has_more
+    }
+    pub fn clear_has_more(&mut self) {
+        self.has_more = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_more(&mut self, v: bool) {
+        self.has_more = v;
+    }
+}
+
+impl ::protobuf::Message for AuditLogPage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.next_cursor = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.has_more = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.next_cursor != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.next_cursor, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.has_more != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.next_cursor != 0 {
+            os.write_int64(2, self.next_cursor)?;
+        }
+        if self.has_more != false {
+            os.write_bool(3, self.has_more)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AuditLogPage {
+        AuditLogPage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AuditLogEntry>>(
+                "items",
+                |m: &AuditLogPage| { &m.items },
+                |m: &mut AuditLogPage| { &mut m.items },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "next_cursor",
+                |m: &AuditLogPage| { &m.next_cursor },
+                |m: &mut AuditLogPage| { &mut m.next_cursor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "has_more",
+                |m: &AuditLogPage| { &m.has_more },
+                |m: &mut AuditLogPage| { &mut m.has_more },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AuditLogPage>(
+                "AuditLogPage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AuditLogPage {
+        static instance: ::protobuf::rt::LazyV2<AuditLogPage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AuditLogPage::new)
+    }
+}
+
+impl ::protobuf::Clear for AuditLogPage {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.next_cursor = 0;
+        self.has_more = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AuditLogPage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AuditLogPage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReadAuditLogRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub cursor: i64,
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReadAuditLogRequest {
+    fn default() -> &'a ReadAuditLogRequest {
+        <ReadAuditLogRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReadAuditLogRequest {
+    pub fn new() -> ReadAuditLogRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // int64 cursor = 2;
+
+
+    pub fn get_cursor(&self) -> i64 {
+        self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: i64) {
+        self.cursor = v;
+    }
+
+    // int64 limit = 3;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for ReadAuditLogRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cursor = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if self.cursor != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.cursor, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if self.cursor != 0 {
+            os.write_int64(2, self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_int64(3, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReadAuditLogRequest {
+        ReadAuditLogRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &ReadAuditLogRequest| { &m.workspace_id },
+                |m: &mut ReadAuditLogRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cursor",
+                |m: &ReadAuditLogRequest| { &m.cursor },
+                |m: &mut ReadAuditLogRequest| { &mut m.cursor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &ReadAuditLogRequest| { &m.limit },
+                |m: &mut ReadAuditLogRequest| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReadAuditLogRequest>(
+                "ReadAuditLogRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReadAuditLogRequest {
+        static instance: ::protobuf::rt::LazyV2<ReadAuditLogRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReadAuditLogRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ReadAuditLogRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.cursor = 0;
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReadAuditLogRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadAuditLogRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReadAuditLogParams {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub cursor: i64,
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReadAuditLogParams {
+    fn default() -> &'a ReadAuditLogParams {
+        <ReadAuditLogParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReadAuditLogParams {
+    pub fn new() -> ReadAuditLogParams {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // int64 cursor = 2;
+
+
+    pub fn get_cursor(&self) -> i64 {
+        self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: i64) {
+        self.cursor = v;
+    }
+
+    // int64 limit = 3;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for ReadAuditLogParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cursor = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if self.cursor != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.cursor, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if self.cursor != 0 {
+            os.write_int64(2, self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_int64(3, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReadAuditLogParams {
+        ReadAuditLogParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &ReadAuditLogParams| { &m.workspace_id },
+                |m: &mut ReadAuditLogParams| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cursor",
+                |m: &ReadAuditLogParams| { &m.cursor },
+                |m: &mut ReadAuditLogParams| { &mut m.cursor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &ReadAuditLogParams| { &m.limit },
+                |m: &mut ReadAuditLogParams| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReadAuditLogParams>(
+                "ReadAuditLogParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReadAuditLogParams {
+        static instance: ::protobuf::rt::LazyV2<ReadAuditLogParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReadAuditLogParams::new)
+    }
+}
+
+impl ::protobuf::Clear for ReadAuditLogParams {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.cursor = 0;
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReadAuditLogParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadAuditLogParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0faudit_log.proto\"\x98\x02\n\rAuditLogEntry\x12\x12\n\x03seq\x18\
+    \x01\x20\x01(\x03R\x03seqB\0\x12#\n\x0cworkspace_id\x18\x02\x20\x01(\tR\
+    \x0bworkspaceIdB\0\x12\x1b\n\x08actor_id\x18\x03\x20\x01(\tR\x07actorIdB\
+    \0\x12\x18\n\x06action\x18\x04\x20\x01(\tR\x06actionB\0\x12!\n\x0bobject\
+    _type\x18\x05\x20\x01(\tR\nobjectTypeB\0\x12\x1d\n\tobject_id\x18\x06\
+    \x20\x01(\tR\x08objectIdB\0\x12\x18\n\x06before\x18\x07\x20\x01(\tR\x06b\
+    eforeB\0\x12\x16\n\x05after\x18\x08\x20\x01(\tR\x05afterB\0\x12!\n\x0bcr\
+    eate_time\x18\t\x20\x01(\x03R\ncreateTimeB\0:\0\"x\n\x0cAuditLogPage\x12\
+    &\n\x05items\x18\x01\x20\x03(\x0b2\x0e.AuditLogEntryR\x05itemsB\0\x12!\n\
+    \x0bnext_cursor\x18\x02\x20\x01(\x03R\nnextCursorB\0\x12\x1b\n\x08has_mo\
+    re\x18\x03\x20\x01(\x08R\x07hasMoreB\0:\0\"n\n\x13ReadAuditLogRequest\
+    \x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x12\x18\n\
+    \x06cursor\x18\x02\x20\x01(\x03R\x06cursorB\0\x12\x16\n\x05limit\x18\x03\
+    \x20\x01(\x03R\x05limitB\0:\0\"m\n\x12ReadAuditLogParams\x12#\n\x0cworks\
+    pace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x12\x18\n\x06cursor\x18\
+    \x02\x20\x01(\x03R\x06cursorB\0\x12\x16\n\x05limit\x18\x03\x20\x01(\x03R\
+    \x05limitB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
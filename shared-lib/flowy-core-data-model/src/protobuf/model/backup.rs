@@ -0,0 +1,1930 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `backup.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BackupSetting {
+    // message fields
+    pub enabled: bool,
+    pub dir: ::std::string::String,
+    pub frequency: BackupFrequency,
+    pub keep_count: i64,
+    pub last_backup_time: i64,
+    pub last_error: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BackupSetting {
+    fn default() -> &'a BackupSetting {
+        <BackupSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BackupSetting {
+    pub fn new() -> BackupSetting {
+        ::std::default::Default::default()
+    }
+
+    // bool enabled = 1;
+
+
+    pub fn get_enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn clear_enabled(&mut self) {
+        self.enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.enabled = v;
+    }
+
+    // string dir = 2;
+
+
+    pub fn get_dir(&self) -> &str {
+        &self.dir
+    }
+    pub fn clear_dir(&mut self) {
+        self.dir.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir(&mut self, v: ::std::string::String) {
+        self.dir = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_dir(&mut self) -> &mut ::std::string::String {
+        &mut self.dir
+    }
+
+    // Take field
+    pub fn take_dir(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.dir, ::std::string::String::new())
+    }
+
+    // .BackupFrequency frequency = 3;
+
+
+    pub fn get_frequency(&self) -> BackupFrequency {
+        self.frequency
+    }
+    pub fn clear_frequency(&mut self) {
+        self.frequency = BackupFrequency::Daily;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_frequency(&mut self, v: BackupFrequency) {
+        self.frequency = v;
+    }
+
+    // int64 keep_count = 4;
+
+
+    pub fn get_keep_count(&self) -> i64 {
+        self.keep_count
+    }
+    pub fn clear_keep_count(&mut self) {
+        self.keep_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keep_count(&mut self, v: i64) {
+        self.keep_count = v;
+    }
+
+    // int64 last_backup_time = 5;
+
+
+    pub fn get_last_backup_time(&self) -> i64 {
+        self.last_backup_time
+    }
+    pub fn clear_last_backup_time(&mut self) {
+        self.last_backup_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_backup_time(&mut self, v: i64) {
+        self.last_backup_time = v;
+    }
+
+    // string last_error = 6;
+
+
+    pub fn get_last_error(&self) -> &str {
+        &self.last_error
+    }
+    pub fn clear_last_error(&mut self) {
+        self.last_error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_error(&mut self, v: ::std::string::String) {
+        self.last_error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_last_error(&mut self) -> &mut ::std::string::String {
+        &mut self.last_error
+    }
+
+    // Take field
+    pub fn take_last_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.last_error, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for BackupSetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enabled = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.dir)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.frequency, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.keep_count = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_backup_time = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.last_error)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.enabled != false {
+            my_size += 2;
+        }
+        if !self.dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.dir);
+        }
+        if self.frequency != BackupFrequency::Daily {
+            my_size += ::protobuf::rt::enum_size(3, self.frequency);
+        }
+        if self.keep_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.keep_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_backup_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.last_backup_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.last_error.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.last_error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.enabled != false {
+            os.write_bool(1, self.enabled)?;
+        }
+        if !self.dir.is_empty() {
+            os.write_string(2, &self.dir)?;
+        }
+        if self.frequency != BackupFrequency::Daily {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.frequency))?;
+        }
+        if self.keep_count != 0 {
+            os.write_int64(4, self.keep_count)?;
+        }
+        if self.last_backup_time != 0 {
+            os.write_int64(5, self.last_backup_time)?;
+        }
+        if !self.last_error.is_empty() {
+            os.write_string(6, &self.last_error)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BackupSetting {
+        BackupSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "enabled",
+                |m: &BackupSetting| { &m.enabled },
+                |m: &mut BackupSetting| { &mut m.enabled },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "dir",
+                |m: &BackupSetting| { &m.dir },
+                |m: &mut BackupSetting| { &mut m.dir },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<BackupFrequency>>(
+                "frequency",
+                |m: &BackupSetting| { &m.frequency },
+                |m: &mut BackupSetting| { &mut m.frequency },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "keep_count",
+                |m: &BackupSetting| { &m.keep_count },
+                |m: &mut BackupSetting| { &mut m.keep_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_backup_time",
+                |m: &BackupSetting| { &m.last_backup_time },
+                |m: &mut BackupSetting| { &mut m.last_backup_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "last_error",
+                |m: &BackupSetting| { &m.last_error },
+                |m: &mut BackupSetting| { &mut m.last_error },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BackupSetting>(
+                "BackupSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BackupSetting {
+        static instance: ::protobuf::rt::LazyV2<BackupSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BackupSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for BackupSetting {
+    fn clear(&mut self) {
+        self.enabled = false;
+        self.dir.clear();
+        self.frequency = BackupFrequency::Daily;
+        self.keep_count = 0;
+        self.last_backup_time = 0;
+        self.last_error.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BackupSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BackupSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateBackupSettingRequest {
+    // message fields
+    pub enabled: bool,
+    pub dir: ::std::string::String,
+    pub frequency: BackupFrequency,
+    pub keep_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateBackupSettingRequest {
+    fn default() -> &'a UpdateBackupSettingRequest {
+        <UpdateBackupSettingRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateBackupSettingRequest {
+    pub fn new() -> UpdateBackupSettingRequest {
+        ::std::default::Default::default()
+    }
+
+    // bool enabled = 1;
+
+
+    pub fn get_enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn clear_enabled(&mut self) {
+        self.enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.enabled = v;
+    }
+
+    // string dir = 2;
+
+
+    pub fn get_dir(&self) -> &str {
+        &self.dir
+    }
+    pub fn clear_dir(&mut self) {
+        self.dir.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir(&mut self, v: ::std::string::String) {
+        self.dir = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_dir(&mut self) -> &mut ::std::string::String {
+        &mut self.dir
+    }
+
+    // Take field
+    pub fn take_dir(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.dir, ::std::string::String::new())
+    }
+
+    // .BackupFrequency frequency = 3;
+
+
+    pub fn get_frequency(&self) -> BackupFrequency {
+        self.frequency
+    }
+    pub fn clear_frequency(&mut self) {
+        self.frequency = BackupFrequency::Daily;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_frequency(&mut self, v: BackupFrequency) {
+        self.frequency = v;
+    }
+
+    // int64 keep_count = 4;
+
+
+    pub fn get_keep_count(&self) -> i64 {
+        self.keep_count
+    }
+    pub fn clear_keep_count(&mut self) {
+        self.keep_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keep_count(&mut self, v: i64) {
+        self.keep_count = v;
+    }
+}
+
+impl ::protobuf::Message for UpdateBackupSettingRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enabled = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.dir)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.frequency, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.keep_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.enabled != false {
+            my_size += 2;
+        }
+        if !self.dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.dir);
+        }
+        if self.frequency != BackupFrequency::Daily {
+            my_size += ::protobuf::rt::enum_size(3, self.frequency);
+        }
+        if self.keep_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.keep_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.enabled != false {
+            os.write_bool(1, self.enabled)?;
+        }
+        if !self.dir.is_empty() {
+            os.write_string(2, &self.dir)?;
+        }
+        if self.frequency != BackupFrequency::Daily {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.frequency))?;
+        }
+        if self.keep_count != 0 {
+            os.write_int64(4, self.keep_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateBackupSettingRequest {
+        UpdateBackupSettingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "enabled",
+                |m: &UpdateBackupSettingRequest| { &m.enabled },
+                |m: &mut UpdateBackupSettingRequest| { &mut m.enabled },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "dir",
+                |m: &UpdateBackupSettingRequest| { &m.dir },
+                |m: &mut UpdateBackupSettingRequest| { &mut m.dir },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<BackupFrequency>>(
+                "frequency",
+                |m: &UpdateBackupSettingRequest| { &m.frequency },
+                |m: &mut UpdateBackupSettingRequest| { &mut m.frequency },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "keep_count",
+                |m: &UpdateBackupSettingRequest| { &m.keep_count },
+                |m: &mut UpdateBackupSettingRequest| { &mut m.keep_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateBackupSettingRequest>(
+                "UpdateBackupSettingRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateBackupSettingRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateBackupSettingRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateBackupSettingRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateBackupSettingRequest {
+    fn clear(&mut self) {
+        self.enabled = false;
+        self.dir.clear();
+        self.frequency = BackupFrequency::Daily;
+        self.keep_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateBackupSettingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateBackupSettingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BackupResult {
+    // message fields
+    pub path: ::std::string::String,
+    pub document_count: i64,
+    pub backup_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BackupResult {
+    fn default() -> &'a BackupResult {
+        <BackupResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BackupResult {
+    pub fn new() -> BackupResult {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // int64 document_count = 2;
+
+
+    pub fn get_document_count(&self) -> i64 {
+        self.document_count
+    }
+    pub fn clear_document_count(&mut self) {
+        self.document_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_document_count(&mut self, v: i64) {
+        self.document_count = v;
+    }
+
+    // int64 backup_time = 3;
+
+
+    pub fn get_backup_time(&self) -> i64 {
+        self.backup_time
+    }
+    pub fn clear_backup_time(&mut self) {
+        self.backup_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_backup_time(&mut self, v: i64) {
+        self.backup_time = v;
+    }
+}
+
+impl ::protobuf::Message for BackupResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.document_count = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.backup_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if self.document_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.document_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.backup_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.backup_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if self.document_count != 0 {
+            os.write_int64(2, self.document_count)?;
+        }
+        if self.backup_time != 0 {
+            os.write_int64(3, self.backup_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BackupResult {
+        BackupResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &BackupResult| { &m.path },
+                |m: &mut BackupResult| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "document_count",
+                |m: &BackupResult| { &m.document_count },
+                |m: &mut BackupResult| { &mut m.document_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "backup_time",
+                |m: &BackupResult| { &m.backup_time },
+                |m: &mut BackupResult| { &mut m.backup_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BackupResult>(
+                "BackupResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BackupResult {
+        static instance: ::protobuf::rt::LazyV2<BackupResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BackupResult::new)
+    }
+}
+
+impl ::protobuf::Clear for BackupResult {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.document_count = 0;
+        self.backup_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BackupResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BackupResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BackupFailure {
+    // message fields
+    pub dir: ::std::string::String,
+    pub error: ::std::string::String,
+    pub time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BackupFailure {
+    fn default() -> &'a BackupFailure {
+        <BackupFailure as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BackupFailure {
+    pub fn new() -> BackupFailure {
+        ::std::default::Default::default()
+    }
+
+    // string dir = 1;
+
+
+    pub fn get_dir(&self) -> &str {
+        &self.dir
+    }
+    pub fn clear_dir(&mut self) {
+        self.dir.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir(&mut self, v: ::std::string::String) {
+        self.dir = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_dir(&mut self) -> &mut ::std::string::String {
+        &mut self.dir
+    }
+
+    // Take field
+    pub fn take_dir(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.dir, ::std::string::String::new())
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // int64 time = 3;
+
+
+    pub fn get_time(&self) -> i64 {
+        self.time
+    }
+    pub fn clear_time(&mut self) {
+        self.time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_time(&mut self, v: i64) {
+        self.time = v;
+    }
+}
+
+impl ::protobuf::Message for BackupFailure {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.dir)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.dir);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.dir.is_empty() {
+            os.write_string(1, &self.dir)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.time != 0 {
+            os.write_int64(3, self.time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BackupFailure {
+        BackupFailure::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "dir",
+                |m: &BackupFailure| { &m.dir },
+                |m: &mut BackupFailure| { &mut m.dir },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "error",
+                |m: &BackupFailure| { &m.error },
+                |m: &mut BackupFailure| { &mut m.error },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "time",
+                |m: &BackupFailure| { &m.time },
+                |m: &mut BackupFailure| { &mut m.time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BackupFailure>(
+                "BackupFailure",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BackupFailure {
+        static instance: ::protobuf::rt::LazyV2<BackupFailure> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BackupFailure::new)
+    }
+}
+
+impl ::protobuf::Clear for BackupFailure {
+    fn clear(&mut self) {
+        self.dir.clear();
+        self.error.clear();
+        self.time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BackupFailure {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BackupFailure {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryBackupsRequest {
+    // message fields
+    pub dir: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryBackupsRequest {
+    fn default() -> &'a QueryBackupsRequest {
+        <QueryBackupsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryBackupsRequest {
+    pub fn new() -> QueryBackupsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string dir = 1;
+
+
+    pub fn get_dir(&self) -> &str {
+        &self.dir
+    }
+    pub fn clear_dir(&mut self) {
+        self.dir.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir(&mut self, v: ::std::string::String) {
+        self.dir = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_dir(&mut self) -> &mut ::std::string::String {
+        &mut self.dir
+    }
+
+    // Take field
+    pub fn take_dir(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.dir, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryBackupsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.dir)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.dir);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.dir.is_empty() {
+            os.write_string(1, &self.dir)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryBackupsRequest {
+        QueryBackupsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "dir",
+                |m: &QueryBackupsRequest| { &m.dir },
+                |m: &mut QueryBackupsRequest| { &mut m.dir },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryBackupsRequest>(
+                "QueryBackupsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryBackupsRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryBackupsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryBackupsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryBackupsRequest {
+    fn clear(&mut self) {
+        self.dir.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryBackupsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryBackupsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BackupManifest {
+    // message fields
+    pub path: ::std::string::String,
+    pub user_id: ::std::string::String,
+    pub export_time: i64,
+    pub document_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BackupManifest {
+    fn default() -> &'a BackupManifest {
+        <BackupManifest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BackupManifest {
+    pub fn new() -> BackupManifest {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // string user_id = 2;
+
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    // int64 export_time = 3;
+
+
+    pub fn get_export_time(&self) -> i64 {
+        self.export_time
+    }
+    pub fn clear_export_time(&mut self) {
+        self.export_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_export_time(&mut self, v: i64) {
+        self.export_time = v;
+    }
+
+    // int64 document_count = 4;
+
+
+    pub fn get_document_count(&self) -> i64 {
+        self.document_count
+    }
+    pub fn clear_document_count(&mut self) {
+        self.document_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_document_count(&mut self, v: i64) {
+        self.document_count = v;
+    }
+}
+
+impl ::protobuf::Message for BackupManifest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.export_time = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.document_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_id);
+        }
+        if self.export_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.export_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.document_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.document_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(2, &self.user_id)?;
+        }
+        if self.export_time != 0 {
+            os.write_int64(3, self.export_time)?;
+        }
+        if self.document_count != 0 {
+            os.write_int64(4, self.document_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BackupManifest {
+        BackupManifest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &BackupManifest| { &m.path },
+                |m: &mut BackupManifest| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "user_id",
+                |m: &BackupManifest| { &m.user_id },
+                |m: &mut BackupManifest| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "export_time",
+                |m: &BackupManifest| { &m.export_time },
+                |m: &mut BackupManifest| { &mut m.export_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "document_count",
+                |m: &BackupManifest| { &m.document_count },
+                |m: &mut BackupManifest| { &mut m.document_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BackupManifest>(
+                "BackupManifest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BackupManifest {
+        static instance: ::protobuf::rt::LazyV2<BackupManifest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BackupManifest::new)
+    }
+}
+
+impl ::protobuf::Clear for BackupManifest {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.user_id.clear();
+        self.export_time = 0;
+        self.document_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BackupManifest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BackupManifest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedBackupManifest {
+    // message fields
+    pub items: ::protobuf::RepeatedField<BackupManifest>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedBackupManifest {
+    fn default() -> &'a RepeatedBackupManifest {
+        <RepeatedBackupManifest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedBackupManifest {
+    pub fn new() -> RepeatedBackupManifest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .BackupManifest items = 1;
+
+
+    pub fn get_items(&self) -> &[BackupManifest] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<BackupManifest>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<BackupManifest> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<BackupManifest> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedBackupManifest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedBackupManifest {
+        RepeatedBackupManifest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BackupManifest>>(
+                "items",
+                |m: &RepeatedBackupManifest| { &m.items },
+                |m: &mut RepeatedBackupManifest| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedBackupManifest>(
+                "RepeatedBackupManifest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedBackupManifest {
+        static instance: ::protobuf::rt::LazyV2<RepeatedBackupManifest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedBackupManifest::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedBackupManifest {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedBackupManifest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedBackupManifest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SelectBackupRestoreRequest {
+    // message fields
+    pub path: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SelectBackupRestoreRequest {
+    fn default() -> &'a SelectBackupRestoreRequest {
+        <SelectBackupRestoreRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SelectBackupRestoreRequest {
+    pub fn new() -> SelectBackupRestoreRequest {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SelectBackupRestoreRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SelectBackupRestoreRequest {
+        SelectBackupRestoreRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &SelectBackupRestoreRequest| { &m.path },
+                |m: &mut SelectBackupRestoreRequest| { &mut m.path },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SelectBackupRestoreRequest>(
+                "SelectBackupRestoreRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SelectBackupRestoreRequest {
+        static instance: ::protobuf::rt::LazyV2<SelectBackupRestoreRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SelectBackupRestoreRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SelectBackupRestoreRequest {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SelectBackupRestoreRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SelectBackupRestoreRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum BackupFrequency {
+    Daily = 0,
+    Weekly = 1,
+}
+
+impl ::protobuf::ProtobufEnum for BackupFrequency {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<BackupFrequency> {
+        match value {
+            0 => ::std::option::Option::Some(BackupFrequency::Daily),
+            1 => ::std::option::Option::Some(BackupFrequency::Weekly),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [BackupFrequency] = &[
+            BackupFrequency::Daily,
+            BackupFrequency::Weekly,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<BackupFrequency>("BackupFrequency", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for BackupFrequency {
+}
+
+impl ::std::default::Default for BackupFrequency {
+    fn default() -> Self {
+        BackupFrequency::Daily
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BackupFrequency {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0cbackup.proto\"\xe1\x01\n\rBackupSetting\x12\x1a\n\x07enabled\x18\
+    \x01\x20\x01(\x08R\x07enabledB\0\x12\x12\n\x03dir\x18\x02\x20\x01(\tR\
+    \x03dirB\0\x120\n\tfrequency\x18\x03\x20\x01(\x0e2\x10.BackupFrequencyR\
+    \tfrequencyB\0\x12\x1f\n\nkeep_count\x18\x04\x20\x01(\x03R\tkeepCountB\0\
+    \x12*\n\x10last_backup_time\x18\x05\x20\x01(\x03R\x0elastBackupTimeB\0\
+    \x12\x1f\n\nlast_error\x18\x06\x20\x01(\tR\tlastErrorB\0:\0\"\xa1\x01\n\
+    \x1aUpdateBackupSettingRequest\x12\x1a\n\x07enabled\x18\x01\x20\x01(\x08\
+    R\x07enabledB\0\x12\x12\n\x03dir\x18\x02\x20\x01(\tR\x03dirB\0\x120\n\tf\
+    requency\x18\x03\x20\x01(\x0e2\x10.BackupFrequencyR\tfrequencyB\0\x12\
+    \x1f\n\nkeep_count\x18\x04\x20\x01(\x03R\tkeepCountB\0:\0\"r\n\x0cBackup\
+    Result\x12\x14\n\x04path\x18\x01\x20\x01(\tR\x04pathB\0\x12'\n\x0edocume\
+    nt_count\x18\x02\x20\x01(\x03R\rdocumentCountB\0\x12!\n\x0bbackup_time\
+    \x18\x03\x20\x01(\x03R\nbackupTimeB\0:\0\"S\n\rBackupFailure\x12\x12\n\
+    \x03dir\x18\x01\x20\x01(\tR\x03dirB\0\x12\x16\n\x05error\x18\x02\x20\x01\
+    (\tR\x05errorB\0\x12\x14\n\x04time\x18\x03\x20\x01(\x03R\x04timeB\0:\0\"\
+    +\n\x13QueryBackupsRequest\x12\x12\n\x03dir\x18\x01\x20\x01(\tR\x03dirB\
+    \0:\0\"\x8f\x01\n\x0eBackupManifest\x12\x14\n\x04path\x18\x01\x20\x01(\t\
+    R\x04pathB\0\x12\x19\n\x07user_id\x18\x02\x20\x01(\tR\x06userIdB\0\x12!\
+    \n\x0bexport_time\x18\x03\x20\x01(\x03R\nexportTimeB\0\x12'\n\x0edocumen\
+    t_count\x18\x04\x20\x01(\x03R\rdocumentCountB\0:\0\"C\n\x16RepeatedBacku\
+    pManifest\x12'\n\x05items\x18\x01\x20\x03(\x0b2\x0f.BackupManifestR\x05i\
+    temsB\0:\0\"4\n\x1aSelectBackupRestoreRequest\x12\x14\n\x04path\x18\x01\
+    \x20\x01(\tR\x04pathB\0:\0**\n\x0fBackupFrequency\x12\t\n\x05Daily\x10\0\
+    \x12\n\n\x06Weekly\x10\x01\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
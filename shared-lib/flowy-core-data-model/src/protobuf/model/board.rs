@@ -0,0 +1,1571 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `board.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Board {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub group_field_id: ::std::string::String,
+    pub groups: ::protobuf::RepeatedField<BoardGroup>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Board {
+    fn default() -> &'a Board {
+        <Board as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Board {
+    pub fn new() -> Board {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string group_field_id = 2;
+
+
+    pub fn get_group_field_id(&self) -> &str {
+        &self.group_field_id
+    }
+    pub fn clear_group_field_id(&mut self) {
+        self.group_field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group_field_id(&mut self, v: ::std::string::String) {
+        self.group_field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_group_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.group_field_id
+    }
+
+    // Take field
+    pub fn take_group_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.group_field_id, ::std::string::String::new())
+    }
+
+    // repeated .BoardGroup groups = 3;
+
+
+    pub fn get_groups(&self) -> &[BoardGroup] {
+        &self.groups
+    }
+    pub fn clear_groups(&mut self) {
+        self.groups.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_groups(&mut self, v: ::protobuf::RepeatedField<BoardGroup>) {
+        self.groups = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_groups(&mut self) -> &mut ::protobuf::RepeatedField<BoardGroup> {
+        &mut self.groups
+    }
+
+    // Take field
+    pub fn take_groups(&mut self) -> ::protobuf::RepeatedField<BoardGroup> {
+        ::std::mem::replace(&mut self.groups, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Board {
+    fn is_initialized(&self) -> bool {
+        for v in &self.groups {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.group_field_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.groups)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.group_field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.group_field_id);
+        }
+        for value in &self.groups {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.group_field_id.is_empty() {
+            os.write_string(2, &self.group_field_id)?;
+        }
+        for v in &self.groups {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Board {
+        Board::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &Board| { &m.grid_id },
+                |m: &mut Board| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "group_field_id",
+                |m: &Board| { &m.group_field_id },
+                |m: &mut Board| { &mut m.group_field_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BoardGroup>>(
+                "groups",
+                |m: &Board| { &m.groups },
+                |m: &mut Board| { &mut m.groups },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Board>(
+                "Board",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Board {
+        static instance: ::protobuf::rt::LazyV2<Board> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Board::new)
+    }
+}
+
+impl ::protobuf::Clear for Board {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.group_field_id.clear();
+        self.groups.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Board {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Board {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BoardGroup {
+    // message fields
+    pub id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub color: ::std::string::String,
+    pub rows: ::protobuf::RepeatedField<super::grid::Row>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BoardGroup {
+    fn default() -> &'a BoardGroup {
+        <BoardGroup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BoardGroup {
+    pub fn new() -> BoardGroup {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string color = 3;
+
+
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+    pub fn clear_color(&mut self) {
+        self.color.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: ::std::string::String) {
+        self.color = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color(&mut self) -> &mut ::std::string::String {
+        &mut self.color
+    }
+
+    // Take field
+    pub fn take_color(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.color, ::std::string::String::new())
+    }
+
+    // repeated .Row rows = 4;
+
+
+    pub fn get_rows(&self) -> &[super::grid::Row] {
+        &self.rows
+    }
+    pub fn clear_rows(&mut self) {
+        self.rows.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rows(&mut self, v: ::protobuf::RepeatedField<super::grid::Row>) {
+        self.rows = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rows(&mut self) -> &mut ::protobuf::RepeatedField<super::grid::Row> {
+        &mut self.rows
+    }
+
+    // Take field
+    pub fn take_rows(&mut self) -> ::protobuf::RepeatedField<super::grid::Row> {
+        ::std::mem::replace(&mut self.rows, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BoardGroup {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rows {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.color)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.rows)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if !self.color.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.color);
+        }
+        for value in &self.rows {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if !self.color.is_empty() {
+            os.write_string(3, &self.color)?;
+        }
+        for v in &self.rows {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BoardGroup {
+        BoardGroup::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &BoardGroup| { &m.id },
+                |m: &mut BoardGroup| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &BoardGroup| { &m.name },
+                |m: &mut BoardGroup| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "color",
+                |m: &BoardGroup| { &m.color },
+                |m: &mut BoardGroup| { &mut m.color },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::grid::Row>>(
+                "rows",
+                |m: &BoardGroup| { &m.rows },
+                |m: &mut BoardGroup| { &mut m.rows },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BoardGroup>(
+                "BoardGroup",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BoardGroup {
+        static instance: ::protobuf::rt::LazyV2<BoardGroup> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BoardGroup::new)
+    }
+}
+
+impl ::protobuf::Clear for BoardGroup {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.name.clear();
+        self.color.clear();
+        self.rows.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BoardGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BoardGroup {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveCardRequest {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub row_id: ::std::string::String,
+    pub to_group_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_before_row_id: ::std::option::Option<MoveCardRequest_oneof_one_of_before_row_id>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveCardRequest {
+    fn default() -> &'a MoveCardRequest {
+        <MoveCardRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum MoveCardRequest_oneof_one_of_before_row_id {
+    before_row_id(::std::string::String),
+}
+
+impl MoveCardRequest {
+    pub fn new() -> MoveCardRequest {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string row_id = 2;
+
+
+    pub fn get_row_id(&self) -> &str {
+        &self.row_id
+    }
+    pub fn clear_row_id(&mut self) {
+        self.row_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row_id(&mut self, v: ::std::string::String) {
+        self.row_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_row_id(&mut self) -> &mut ::std::string::String {
+        &mut self.row_id
+    }
+
+    // Take field
+    pub fn take_row_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.row_id, ::std::string::String::new())
+    }
+
+    // string to_group_id = 3;
+
+
+    pub fn get_to_group_id(&self) -> &str {
+        &self.to_group_id
+    }
+    pub fn clear_to_group_id(&mut self) {
+        self.to_group_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_group_id(&mut self, v: ::std::string::String) {
+        self.to_group_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to_group_id(&mut self) -> &mut ::std::string::String {
+        &mut self.to_group_id
+    }
+
+    // Take field
+    pub fn take_to_group_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.to_group_id, ::std::string::String::new())
+    }
+
+    // string before_row_id = 4;
+
+
+    pub fn get_before_row_id(&self) -> &str {
+        match self.one_of_before_row_id {
+            ::std::option::Option::Some(MoveCardRequest_oneof_one_of_before_row_id::before_row_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_before_row_id(&mut self) {
+        self.one_of_before_row_id = ::std::option::Option::None;
+    }
+
+    pub fn has_before_row_id(&self) -> bool {
+        match self.one_of_before_row_id {
+            ::std::option::Option::Some(MoveCardRequest_oneof_one_of_before_row_id::before_row_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_before_row_id(&mut self, v: ::std::string::String) {
+        self.one_of_before_row_id = ::std::option::Option::Some(MoveCardRequest_oneof_one_of_before_row_id::before_row_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_before_row_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(MoveCardRequest_oneof_one_of_before_row_id::before_row_id(_)) = self.one_of_before_row_id {
+        } else {
+            self.one_of_before_row_id = ::std::option::Option::Some(MoveCardRequest_oneof_one_of_before_row_id::before_row_id(::std::string::String::new()));
+        }
+        match self.one_of_before_row_id {
+            ::std::option::Option::Some(MoveCardRequest_oneof_one_of_before_row_id::before_row_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_before_row_id(&mut self) -> ::std::string::String {
+        if self.has_before_row_id() {
+            match self.one_of_before_row_id.take() {
+                ::std::option::Option::Some(MoveCardRequest_oneof_one_of_before_row_id::before_row_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for MoveCardRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.row_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.to_group_id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_before_row_id = ::std::option::Option::Some(MoveCardRequest_oneof_one_of_before_row_id::before_row_id(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.row_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.row_id);
+        }
+        if !self.to_group_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.to_group_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_before_row_id {
+            match v {
+                &MoveCardRequest_oneof_one_of_before_row_id::before_row_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.row_id.is_empty() {
+            os.write_string(2, &self.row_id)?;
+        }
+        if !self.to_group_id.is_empty() {
+            os.write_string(3, &self.to_group_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_before_row_id {
+            match v {
+                &MoveCardRequest_oneof_one_of_before_row_id::before_row_id(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveCardRequest {
+        MoveCardRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &MoveCardRequest| { &m.grid_id },
+                |m: &mut MoveCardRequest| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "row_id",
+                |m: &MoveCardRequest| { &m.row_id },
+                |m: &mut MoveCardRequest| { &mut m.row_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "to_group_id",
+                |m: &MoveCardRequest| { &m.to_group_id },
+                |m: &mut MoveCardRequest| { &mut m.to_group_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "before_row_id",
+                MoveCardRequest::has_before_row_id,
+                MoveCardRequest::get_before_row_id,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveCardRequest>(
+                "MoveCardRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveCardRequest {
+        static instance: ::protobuf::rt::LazyV2<MoveCardRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveCardRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveCardRequest {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.row_id.clear();
+        self.to_group_id.clear();
+        self.one_of_before_row_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveCardRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveCardRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveCardParams {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub row_id: ::std::string::String,
+    pub to_group_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_before_row_id: ::std::option::Option<MoveCardParams_oneof_one_of_before_row_id>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveCardParams {
+    fn default() -> &'a MoveCardParams {
+        <MoveCardParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum MoveCardParams_oneof_one_of_before_row_id {
+    before_row_id(::std::string::String),
+}
+
+impl MoveCardParams {
+    pub fn new() -> MoveCardParams {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string row_id = 2;
+
+
+    pub fn get_row_id(&self) -> &str {
+        &self.row_id
+    }
+    pub fn clear_row_id(&mut self) {
+        self.row_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row_id(&mut self, v: ::std::string::String) {
+        self.row_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_row_id(&mut self) -> &mut ::std::string::String {
+        &mut self.row_id
+    }
+
+    // Take field
+    pub fn take_row_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.row_id, ::std::string::String::new())
+    }
+
+    // string to_group_id = 3;
+
+
+    pub fn get_to_group_id(&self) -> &str {
+        &self.to_group_id
+    }
+    pub fn clear_to_group_id(&mut self) {
+        self.to_group_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_group_id(&mut self, v: ::std::string::String) {
+        self.to_group_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to_group_id(&mut self) -> &mut ::std::string::String {
+        &mut self.to_group_id
+    }
+
+    // Take field
+    pub fn take_to_group_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.to_group_id, ::std::string::String::new())
+    }
+
+    // string before_row_id = 4;
+
+
+    pub fn get_before_row_id(&self) -> &str {
+        match self.one_of_before_row_id {
+            ::std::option::Option::Some(MoveCardParams_oneof_one_of_before_row_id::before_row_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_before_row_id(&mut self) {
+        self.one_of_before_row_id = ::std::option::Option::None;
+    }
+
+    pub fn has_before_row_id(&self) -> bool {
+        match self.one_of_before_row_id {
+            ::std::option::Option::Some(MoveCardParams_oneof_one_of_before_row_id::before_row_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_before_row_id(&mut self, v: ::std::string::String) {
+        self.one_of_before_row_id = ::std::option::Option::Some(MoveCardParams_oneof_one_of_before_row_id::before_row_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_before_row_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(MoveCardParams_oneof_one_of_before_row_id::before_row_id(_)) = self.one_of_before_row_id {
+        } else {
+            self.one_of_before_row_id = ::std::option::Option::Some(MoveCardParams_oneof_one_of_before_row_id::before_row_id(::std::string::String::new()));
+        }
+        match self.one_of_before_row_id {
+            ::std::option::Option::Some(MoveCardParams_oneof_one_of_before_row_id::before_row_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_before_row_id(&mut self) -> ::std::string::String {
+        if self.has_before_row_id() {
+            match self.one_of_before_row_id.take() {
+                ::std::option::Option::Some(MoveCardParams_oneof_one_of_before_row_id::before_row_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for MoveCardParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.row_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.to_group_id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_before_row_id = ::std::option::Option::Some(MoveCardParams_oneof_one_of_before_row_id::before_row_id(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.row_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.row_id);
+        }
+        if !self.to_group_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.to_group_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_before_row_id {
+            match v {
+                &MoveCardParams_oneof_one_of_before_row_id::before_row_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.row_id.is_empty() {
+            os.write_string(2, &self.row_id)?;
+        }
+        if !self.to_group_id.is_empty() {
+            os.write_string(3, &self.to_group_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_before_row_id {
+            match v {
+                &MoveCardParams_oneof_one_of_before_row_id::before_row_id(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveCardParams {
+        MoveCardParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &MoveCardParams| { &m.grid_id },
+                |m: &mut MoveCardParams| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "row_id",
+                |m: &MoveCardParams| { &m.row_id },
+                |m: &mut MoveCardParams| { &mut m.row_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "to_group_id",
+                |m: &MoveCardParams| { &m.to_group_id },
+                |m: &mut MoveCardParams| { &mut m.to_group_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "before_row_id",
+                MoveCardParams::has_before_row_id,
+                MoveCardParams::get_before_row_id,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveCardParams>(
+                "MoveCardParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveCardParams {
+        static instance: ::protobuf::rt::LazyV2<MoveCardParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveCardParams::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveCardParams {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.row_id.clear();
+        self.to_group_id.clear();
+        self.one_of_before_row_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveCardParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveCardParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CardMoved {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub row_id: ::std::string::String,
+    pub from_group_id: ::std::string::String,
+    pub to_group_id: ::std::string::String,
+    pub to_index: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CardMoved {
+    fn default() -> &'a CardMoved {
+        <CardMoved as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CardMoved {
+    pub fn new() -> CardMoved {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string row_id = 2;
+
+
+    pub fn get_row_id(&self) -> &str {
+        &self.row_id
+    }
+    pub fn clear_row_id(&mut self) {
+        self.row_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row_id(&mut self, v: ::std::string::String) {
+        self.row_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_row_id(&mut self) -> &mut ::std::string::String {
+        &mut self.row_id
+    }
+
+    // Take field
+    pub fn take_row_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.row_id, ::std::string::String::new())
+    }
+
+    // string from_group_id = 3;
+
+
+    pub fn get_from_group_id(&self) -> &str {
+        &self.from_group_id
+    }
+    pub fn clear_from_group_id(&mut self) {
+        self.from_group_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from_group_id(&mut self, v: ::std::string::String) {
+        self.from_group_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from_group_id(&mut self) -> &mut ::std::string::String {
+        &mut self.from_group_id
+    }
+
+    // Take field
+    pub fn take_from_group_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.from_group_id, ::std::string::String::new())
+    }
+
+    // string to_group_id = 4;
+
+
+    pub fn get_to_group_id(&self) -> &str {
+        &self.to_group_id
+    }
+    pub fn clear_to_group_id(&mut self) {
+        self.to_group_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_group_id(&mut self, v: ::std::string::String) {
+        self.to_group_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to_group_id(&mut self) -> &mut ::std::string::String {
+        &mut self.to_group_id
+    }
+
+    // Take field
+    pub fn take_to_group_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.to_group_id, ::std::string::String::new())
+    }
+
+    // int32 to_index = 5;
+
+
+    pub fn get_to_index(&self) -> i32 {
+        self.to_index
+    }
+    pub fn clear_to_index(&mut self) {
+        self.to_index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_index(&mut self, v: i32) {
+        self.to_index = v;
+    }
+}
+
+impl ::protobuf::Message for CardMoved {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.row_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.from_group_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.to_group_id)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.to_index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.row_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.row_id);
+        }
+        if !self.from_group_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.from_group_id);
+        }
+        if !self.to_group_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.to_group_id);
+        }
+        if self.to_index != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.to_index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.row_id.is_empty() {
+            os.write_string(2, &self.row_id)?;
+        }
+        if !self.from_group_id.is_empty() {
+            os.write_string(3, &self.from_group_id)?;
+        }
+        if !self.to_group_id.is_empty() {
+            os.write_string(4, &self.to_group_id)?;
+        }
+        if self.to_index != 0 {
+            os.write_int32(5, self.to_index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CardMoved {
+        CardMoved::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &CardMoved| { &m.grid_id },
+                |m: &mut CardMoved| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "row_id",
+                |m: &CardMoved| { &m.row_id },
+                |m: &mut CardMoved| { &mut m.row_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "from_group_id",
+                |m: &CardMoved| { &m.from_group_id },
+                |m: &mut CardMoved| { &mut m.from_group_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "to_group_id",
+                |m: &CardMoved| { &m.to_group_id },
+                |m: &mut CardMoved| { &mut m.to_group_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "to_index",
+                |m: &CardMoved| { &m.to_index },
+                |m: &mut CardMoved| { &mut m.to_index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CardMoved>(
+                "CardMoved",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CardMoved {
+        static instance: ::protobuf::rt::LazyV2<CardMoved> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CardMoved::new)
+    }
+}
+
+impl ::protobuf::Clear for CardMoved {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.row_id.clear();
+        self.from_group_id.clear();
+        self.to_group_id.clear();
+        self.to_index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CardMoved {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CardMoved {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0bboard.proto\x1a\ngrid.proto\"s\n\x05Board\x12\x19\n\x07grid_id\x18\
+    \x01\x20\x01(\tR\x06gridIdB\0\x12&\n\x0egroup_field_id\x18\x02\x20\x01(\
+    \tR\x0cgroupFieldIdB\0\x12%\n\x06groups\x18\x03\x20\x03(\x0b2\x0b.BoardG\
+    roupR\x06groupsB\0:\0\"j\n\nBoardGroup\x12\x10\n\x02id\x18\x01\x20\x01(\
+    \tR\x02idB\0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\x16\n\
+    \x05color\x18\x03\x20\x01(\tR\x05colorB\0\x12\x1a\n\x04rows\x18\x04\x20\
+    \x03(\x0b2\x04.RowR\x04rowsB\0:\0\"\xa9\x01\n\x0fMoveCardRequest\x12\x19\
+    \n\x07grid_id\x18\x01\x20\x01(\tR\x06gridIdB\0\x12\x17\n\x06row_id\x18\
+    \x02\x20\x01(\tR\x05rowIdB\0\x12\x20\n\x0bto_group_id\x18\x03\x20\x01(\t\
+    R\ttoGroupIdB\0\x12&\n\rbefore_row_id\x18\x04\x20\x01(\tH\0R\x0bbeforeRo\
+    wIdB\0B\x16\n\x14one_of_before_row_id:\0\"\xa8\x01\n\x0eMoveCardParams\
+    \x12\x19\n\x07grid_id\x18\x01\x20\x01(\tR\x06gridIdB\0\x12\x17\n\x06row_\
+    id\x18\x02\x20\x01(\tR\x05rowIdB\0\x12\x20\n\x0bto_group_id\x18\x03\x20\
+    \x01(\tR\ttoGroupIdB\0\x12&\n\rbefore_row_id\x18\x04\x20\x01(\tH\0R\x0bb\
+    eforeRowIdB\0B\x16\n\x14one_of_before_row_id:\0\"\xa6\x01\n\tCardMoved\
+    \x12\x19\n\x07grid_id\x18\x01\x20\x01(\tR\x06gridIdB\0\x12\x17\n\x06row_\
+    id\x18\x02\x20\x01(\tR\x05rowIdB\0\x12$\n\rfrom_group_id\x18\x03\x20\x01\
+    (\tR\x0bfromGroupIdB\0\x12\x20\n\x0bto_group_id\x18\x04\x20\x01(\tR\ttoG\
+    roupIdB\0\x12\x1b\n\x08to_index\x18\x05\x20\x01(\x05R\x07toIndexB\0:\0B\
+    \0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
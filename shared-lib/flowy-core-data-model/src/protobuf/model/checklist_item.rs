@@ -0,0 +1,2495 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `checklist_item.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateChecklistItemRequest {
+    // message fields
+    pub checklist_id: ::std::string::String,
+    pub title: ::std::string::String,
+    pub due_date: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateChecklistItemRequest {
+    fn default() -> &'a CreateChecklistItemRequest {
+        <CreateChecklistItemRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateChecklistItemRequest {
+    pub fn new() -> CreateChecklistItemRequest {
+        ::std::default::Default::default()
+    }
+
+    // string checklist_id = 1;
+
+
+    pub fn get_checklist_id(&self) -> &str {
+        &self.checklist_id
+    }
+    pub fn clear_checklist_id(&mut self) {
+        self.checklist_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checklist_id(&mut self, v: ::std::string::String) {
+        self.checklist_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checklist_id(&mut self) -> &mut ::std::string::String {
+        &mut self.checklist_id
+    }
+
+    // Take field
+    pub fn take_checklist_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checklist_id, ::std::string::String::new())
+    }
+
+    // string title = 2;
+
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+    pub fn clear_title(&mut self) {
+        self.title.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.title = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        &mut self.title
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.title, ::std::string::String::new())
+    }
+
+    // int64 due_date = 3;
+
+
+    pub fn get_due_date(&self) -> i64 {
+        self.due_date
+    }
+    pub fn clear_due_date(&mut self) {
+        self.due_date = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_due_date(&mut self, v: i64) {
+        self.due_date = v;
+    }
+}
+
+impl ::protobuf::Message for CreateChecklistItemRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checklist_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.title)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.due_date = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.checklist_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.checklist_id);
+        }
+        if !self.title.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.title);
+        }
+        if self.due_date != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.due_date, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.checklist_id.is_empty() {
+            os.write_string(1, &self.checklist_id)?;
+        }
+        if !self.title.is_empty() {
+            os.write_string(2, &self.title)?;
+        }
+        if self.due_date != 0 {
+            os.write_int64(3, self.due_date)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateChecklistItemRequest {
+        CreateChecklistItemRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checklist_id",
+                |m: &CreateChecklistItemRequest| { &m.checklist_id },
+                |m: &mut CreateChecklistItemRequest| { &mut m.checklist_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "title",
+                |m: &CreateChecklistItemRequest| { &m.title },
+                |m: &mut CreateChecklistItemRequest| { &mut m.title },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "due_date",
+                |m: &CreateChecklistItemRequest| { &m.due_date },
+                |m: &mut CreateChecklistItemRequest| { &mut m.due_date },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateChecklistItemRequest>(
+                "CreateChecklistItemRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateChecklistItemRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateChecklistItemRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateChecklistItemRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateChecklistItemRequest {
+    fn clear(&mut self) {
+        self.checklist_id.clear();
+        self.title.clear();
+        self.due_date = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateChecklistItemRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateChecklistItemRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateChecklistItemParams {
+    // message fields
+    pub checklist_id: ::std::string::String,
+    pub title: ::std::string::String,
+    pub due_date: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateChecklistItemParams {
+    fn default() -> &'a CreateChecklistItemParams {
+        <CreateChecklistItemParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateChecklistItemParams {
+    pub fn new() -> CreateChecklistItemParams {
+        ::std::default::Default::default()
+    }
+
+    // string checklist_id = 1;
+
+
+    pub fn get_checklist_id(&self) -> &str {
+        &self.checklist_id
+    }
+    pub fn clear_checklist_id(&mut self) {
+        self.checklist_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checklist_id(&mut self, v: ::std::string::String) {
+        self.checklist_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checklist_id(&mut self) -> &mut ::std::string::String {
+        &mut self.checklist_id
+    }
+
+    // Take field
+    pub fn take_checklist_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checklist_id, ::std::string::String::new())
+    }
+
+    // string title = 2;
+
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+    pub fn clear_title(&mut self) {
+        self.title.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.title = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        &mut self.title
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.title, ::std::string::String::new())
+    }
+
+    // int64 due_date = 3;
+
+
+    pub fn get_due_date(&self) -> i64 {
+        self.due_date
+    }
+    pub fn clear_due_date(&mut self) {
+        self.due_date = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_due_date(&mut self, v: i64) {
+        self.due_date = v;
+    }
+}
+
+impl ::protobuf::Message for CreateChecklistItemParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checklist_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.title)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.due_date = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.checklist_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.checklist_id);
+        }
+        if !self.title.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.title);
+        }
+        if self.due_date != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.due_date, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.checklist_id.is_empty() {
+            os.write_string(1, &self.checklist_id)?;
+        }
+        if !self.title.is_empty() {
+            os.write_string(2, &self.title)?;
+        }
+        if self.due_date != 0 {
+            os.write_int64(3, self.due_date)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateChecklistItemParams {
+        CreateChecklistItemParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checklist_id",
+                |m: &CreateChecklistItemParams| { &m.checklist_id },
+                |m: &mut CreateChecklistItemParams| { &mut m.checklist_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "title",
+                |m: &CreateChecklistItemParams| { &m.title },
+                |m: &mut CreateChecklistItemParams| { &mut m.title },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "due_date",
+                |m: &CreateChecklistItemParams| { &m.due_date },
+                |m: &mut CreateChecklistItemParams| { &mut m.due_date },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateChecklistItemParams>(
+                "CreateChecklistItemParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateChecklistItemParams {
+        static instance: ::protobuf::rt::LazyV2<CreateChecklistItemParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateChecklistItemParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateChecklistItemParams {
+    fn clear(&mut self) {
+        self.checklist_id.clear();
+        self.title.clear();
+        self.due_date = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateChecklistItemParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateChecklistItemParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateChecklistItemRequest {
+    // message fields
+    pub checklist_id: ::std::string::String,
+    pub item_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_title: ::std::option::Option<UpdateChecklistItemRequest_oneof_one_of_title>,
+    pub one_of_due_date: ::std::option::Option<UpdateChecklistItemRequest_oneof_one_of_due_date>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateChecklistItemRequest {
+    fn default() -> &'a UpdateChecklistItemRequest {
+        <UpdateChecklistItemRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateChecklistItemRequest_oneof_one_of_title {
+    title(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateChecklistItemRequest_oneof_one_of_due_date {
+    due_date(i64),
+}
+
+impl UpdateChecklistItemRequest {
+    pub fn new() -> UpdateChecklistItemRequest {
+        ::std::default::Default::default()
+    }
+
+    // string checklist_id = 1;
+
+
+    pub fn get_checklist_id(&self) -> &str {
+        &self.checklist_id
+    }
+    pub fn clear_checklist_id(&mut self) {
+        self.checklist_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checklist_id(&mut self, v: ::std::string::String) {
+        self.checklist_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checklist_id(&mut self) -> &mut ::std::string::String {
+        &mut self.checklist_id
+    }
+
+    // Take field
+    pub fn take_checklist_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checklist_id, ::std::string::String::new())
+    }
+
+    // string item_id = 2;
+
+
+    pub fn get_item_id(&self) -> &str {
+        &self.item_id
+    }
+    pub fn clear_item_id(&mut self) {
+        self.item_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_id(&mut self, v: ::std::string::String) {
+        self.item_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_item_id(&mut self) -> &mut ::std::string::String {
+        &mut self.item_id
+    }
+
+    // Take field
+    pub fn take_item_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.item_id, ::std::string::String::new())
+    }
+
+    // string title = 3;
+
+
+    pub fn get_title(&self) -> &str {
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_title::title(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_title(&mut self) {
+        self.one_of_title = ::std::option::Option::None;
+    }
+
+    pub fn has_title(&self) -> bool {
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_title::title(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.one_of_title = ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_title::title(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_title::title(_)) = self.one_of_title {
+        } else {
+            self.one_of_title = ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_title::title(::std::string::String::new()));
+        }
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_title::title(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        if self.has_title() {
+            match self.one_of_title.take() {
+                ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_title::title(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // int64 due_date = 4;
+
+
+    pub fn get_due_date(&self) -> i64 {
+        match self.one_of_due_date {
+            ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_due_date::due_date(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_due_date(&mut self) {
+        self.one_of_due_date = ::std::option::Option::None;
+    }
+
+    pub fn has_due_date(&self) -> bool {
+        match self.one_of_due_date {
+            ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_due_date::due_date(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_due_date(&mut self, v: i64) {
+        self.one_of_due_date = ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_due_date::due_date(v))
+    }
+}
+
+impl ::protobuf::Message for UpdateChecklistItemRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checklist_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.item_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_title = ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_title::title(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_due_date = ::std::option::Option::Some(UpdateChecklistItemRequest_oneof_one_of_due_date::due_date(is.read_int64()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.checklist_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.checklist_id);
+        }
+        if !self.item_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.item_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_title {
+            match v {
+                &UpdateChecklistItemRequest_oneof_one_of_title::title(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_due_date {
+            match v {
+                &UpdateChecklistItemRequest_oneof_one_of_due_date::due_date(v) => {
+                    my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.checklist_id.is_empty() {
+            os.write_string(1, &self.checklist_id)?;
+        }
+        if !self.item_id.is_empty() {
+            os.write_string(2, &self.item_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_title {
+            match v {
+                &UpdateChecklistItemRequest_oneof_one_of_title::title(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_due_date {
+            match v {
+                &UpdateChecklistItemRequest_oneof_one_of_due_date::due_date(v) => {
+                    os.write_int64(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateChecklistItemRequest {
+        UpdateChecklistItemRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checklist_id",
+                |m: &UpdateChecklistItemRequest| { &m.checklist_id },
+                |m: &mut UpdateChecklistItemRequest| { &mut m.checklist_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "item_id",
+                |m: &UpdateChecklistItemRequest| { &m.item_id },
+                |m: &mut UpdateChecklistItemRequest| { &mut m.item_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "title",
+                UpdateChecklistItemRequest::has_title,
+                UpdateChecklistItemRequest::get_title,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "due_date",
+                UpdateChecklistItemRequest::has_due_date,
+                UpdateChecklistItemRequest::get_due_date,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateChecklistItemRequest>(
+                "UpdateChecklistItemRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateChecklistItemRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateChecklistItemRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateChecklistItemRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateChecklistItemRequest {
+    fn clear(&mut self) {
+        self.checklist_id.clear();
+        self.item_id.clear();
+        self.one_of_title = ::std::option::Option::None;
+        self.one_of_due_date = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateChecklistItemRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateChecklistItemRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateChecklistItemParams {
+    // message fields
+    pub checklist_id: ::std::string::String,
+    pub item_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_title: ::std::option::Option<UpdateChecklistItemParams_oneof_one_of_title>,
+    pub one_of_due_date: ::std::option::Option<UpdateChecklistItemParams_oneof_one_of_due_date>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateChecklistItemParams {
+    fn default() -> &'a UpdateChecklistItemParams {
+        <UpdateChecklistItemParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateChecklistItemParams_oneof_one_of_title {
+    title(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateChecklistItemParams_oneof_one_of_due_date {
+    due_date(i64),
+}
+
+impl UpdateChecklistItemParams {
+    pub fn new() -> UpdateChecklistItemParams {
+        ::std::default::Default::default()
+    }
+
+    // string checklist_id = 1;
+
+
+    pub fn get_checklist_id(&self) -> &str {
+        &self.checklist_id
+    }
+    pub fn clear_checklist_id(&mut self) {
+        self.checklist_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checklist_id(&mut self, v: ::std::string::String) {
+        self.checklist_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checklist_id(&mut self) -> &mut ::std::string::String {
+        &mut self.checklist_id
+    }
+
+    // Take field
+    pub fn take_checklist_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checklist_id, ::std::string::String::new())
+    }
+
+    // string item_id = 2;
+
+
+    pub fn get_item_id(&self) -> &str {
+        &self.item_id
+    }
+    pub fn clear_item_id(&mut self) {
+        self.item_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_id(&mut self, v: ::std::string::String) {
+        self.item_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_item_id(&mut self) -> &mut ::std::string::String {
+        &mut self.item_id
+    }
+
+    // Take field
+    pub fn take_item_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.item_id, ::std::string::String::new())
+    }
+
+    // string title = 3;
+
+
+    pub fn get_title(&self) -> &str {
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_title::title(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_title(&mut self) {
+        self.one_of_title = ::std::option::Option::None;
+    }
+
+    pub fn has_title(&self) -> bool {
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_title::title(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.one_of_title = ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_title::title(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_title::title(_)) = self.one_of_title {
+        } else {
+            self.one_of_title = ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_title::title(::std::string::String::new()));
+        }
+        match self.one_of_title {
+            ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_title::title(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        if self.has_title() {
+            match self.one_of_title.take() {
+                ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_title::title(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // int64 due_date = 4;
+
+
+    pub fn get_due_date(&self) -> i64 {
+        match self.one_of_due_date {
+            ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_due_date::due_date(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_due_date(&mut self) {
+        self.one_of_due_date = ::std::option::Option::None;
+    }
+
+    pub fn has_due_date(&self) -> bool {
+        match self.one_of_due_date {
+            ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_due_date::due_date(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_due_date(&mut self, v: i64) {
+        self.one_of_due_date = ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_due_date::due_date(v))
+    }
+}
+
+impl ::protobuf::Message for UpdateChecklistItemParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checklist_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.item_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_title = ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_title::title(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_due_date = ::std::option::Option::Some(UpdateChecklistItemParams_oneof_one_of_due_date::due_date(is.read_int64()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.checklist_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.checklist_id);
+        }
+        if !self.item_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.item_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_title {
+            match v {
+                &UpdateChecklistItemParams_oneof_one_of_title::title(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_due_date {
+            match v {
+                &UpdateChecklistItemParams_oneof_one_of_due_date::due_date(v) => {
+                    my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.checklist_id.is_empty() {
+            os.write_string(1, &self.checklist_id)?;
+        }
+        if !self.item_id.is_empty() {
+            os.write_string(2, &self.item_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_title {
+            match v {
+                &UpdateChecklistItemParams_oneof_one_of_title::title(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_due_date {
+            match v {
+                &UpdateChecklistItemParams_oneof_one_of_due_date::due_date(v) => {
+                    os.write_int64(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateChecklistItemParams {
+        UpdateChecklistItemParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checklist_id",
+                |m: &UpdateChecklistItemParams| { &m.checklist_id },
+                |m: &mut UpdateChecklistItemParams| { &mut m.checklist_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "item_id",
+                |m: &UpdateChecklistItemParams| { &m.item_id },
+                |m: &mut UpdateChecklistItemParams| { &mut m.item_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "title",
+                UpdateChecklistItemParams::has_title,
+                UpdateChecklistItemParams::get_title,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "due_date",
+                UpdateChecklistItemParams::has_due_date,
+                UpdateChecklistItemParams::get_due_date,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateChecklistItemParams>(
+                "UpdateChecklistItemParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateChecklistItemParams {
+        static instance: ::protobuf::rt::LazyV2<UpdateChecklistItemParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateChecklistItemParams::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateChecklistItemParams {
+    fn clear(&mut self) {
+        self.checklist_id.clear();
+        self.item_id.clear();
+        self.one_of_title = ::std::option::Option::None;
+        self.one_of_due_date = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateChecklistItemParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateChecklistItemParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryChecklistItemRequest {
+    // message fields
+    pub checklist_id: ::std::string::String,
+    pub item_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryChecklistItemRequest {
+    fn default() -> &'a QueryChecklistItemRequest {
+        <QueryChecklistItemRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryChecklistItemRequest {
+    pub fn new() -> QueryChecklistItemRequest {
+        ::std::default::Default::default()
+    }
+
+    // string checklist_id = 1;
+
+
+    pub fn get_checklist_id(&self) -> &str {
+        &self.checklist_id
+    }
+    pub fn clear_checklist_id(&mut self) {
+        self.checklist_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checklist_id(&mut self, v: ::std::string::String) {
+        self.checklist_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checklist_id(&mut self) -> &mut ::std::string::String {
+        &mut self.checklist_id
+    }
+
+    // Take field
+    pub fn take_checklist_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checklist_id, ::std::string::String::new())
+    }
+
+    // string item_id = 2;
+
+
+    pub fn get_item_id(&self) -> &str {
+        &self.item_id
+    }
+    pub fn clear_item_id(&mut self) {
+        self.item_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_id(&mut self, v: ::std::string::String) {
+        self.item_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_item_id(&mut self) -> &mut ::std::string::String {
+        &mut self.item_id
+    }
+
+    // Take field
+    pub fn take_item_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.item_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryChecklistItemRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checklist_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.item_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.checklist_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.checklist_id);
+        }
+        if !self.item_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.item_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.checklist_id.is_empty() {
+            os.write_string(1, &self.checklist_id)?;
+        }
+        if !self.item_id.is_empty() {
+            os.write_string(2, &self.item_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryChecklistItemRequest {
+        QueryChecklistItemRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checklist_id",
+                |m: &QueryChecklistItemRequest| { &m.checklist_id },
+                |m: &mut QueryChecklistItemRequest| { &mut m.checklist_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "item_id",
+                |m: &QueryChecklistItemRequest| { &m.item_id },
+                |m: &mut QueryChecklistItemRequest| { &mut m.item_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryChecklistItemRequest>(
+                "QueryChecklistItemRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryChecklistItemRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryChecklistItemRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryChecklistItemRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryChecklistItemRequest {
+    fn clear(&mut self) {
+        self.checklist_id.clear();
+        self.item_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryChecklistItemRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryChecklistItemRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ChecklistItemId {
+    // message fields
+    pub checklist_id: ::std::string::String,
+    pub item_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ChecklistItemId {
+    fn default() -> &'a ChecklistItemId {
+        <ChecklistItemId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChecklistItemId {
+    pub fn new() -> ChecklistItemId {
+        ::std::default::Default::default()
+    }
+
+    // string checklist_id = 1;
+
+
+    pub fn get_checklist_id(&self) -> &str {
+        &self.checklist_id
+    }
+    pub fn clear_checklist_id(&mut self) {
+        self.checklist_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checklist_id(&mut self, v: ::std::string::String) {
+        self.checklist_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checklist_id(&mut self) -> &mut ::std::string::String {
+        &mut self.checklist_id
+    }
+
+    // Take field
+    pub fn take_checklist_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checklist_id, ::std::string::String::new())
+    }
+
+    // string item_id = 2;
+
+
+    pub fn get_item_id(&self) -> &str {
+        &self.item_id
+    }
+    pub fn clear_item_id(&mut self) {
+        self.item_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_id(&mut self, v: ::std::string::String) {
+        self.item_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_item_id(&mut self) -> &mut ::std::string::String {
+        &mut self.item_id
+    }
+
+    // Take field
+    pub fn take_item_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.item_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ChecklistItemId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checklist_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.item_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.checklist_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.checklist_id);
+        }
+        if !self.item_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.item_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.checklist_id.is_empty() {
+            os.write_string(1, &self.checklist_id)?;
+        }
+        if !self.item_id.is_empty() {
+            os.write_string(2, &self.item_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ChecklistItemId {
+        ChecklistItemId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checklist_id",
+                |m: &ChecklistItemId| { &m.checklist_id },
+                |m: &mut ChecklistItemId| { &mut m.checklist_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "item_id",
+                |m: &ChecklistItemId| { &m.item_id },
+                |m: &mut ChecklistItemId| { &mut m.item_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ChecklistItemId>(
+                "ChecklistItemId",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ChecklistItemId {
+        static instance: ::protobuf::rt::LazyV2<ChecklistItemId> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ChecklistItemId::new)
+    }
+}
+
+impl ::protobuf::Clear for ChecklistItemId {
+    fn clear(&mut self) {
+        self.checklist_id.clear();
+        self.item_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ChecklistItemId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChecklistItemId {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveChecklistItemRequest {
+    // message fields
+    pub checklist_id: ::std::string::String,
+    pub item_id: ::std::string::String,
+    pub to_index: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveChecklistItemRequest {
+    fn default() -> &'a MoveChecklistItemRequest {
+        <MoveChecklistItemRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveChecklistItemRequest {
+    pub fn new() -> MoveChecklistItemRequest {
+        ::std::default::Default::default()
+    }
+
+    // string checklist_id = 1;
+
+
+    pub fn get_checklist_id(&self) -> &str {
+        &self.checklist_id
+    }
+    pub fn clear_checklist_id(&mut self) {
+        self.checklist_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checklist_id(&mut self, v: ::std::string::String) {
+        self.checklist_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checklist_id(&mut self) -> &mut ::std::string::String {
+        &mut self.checklist_id
+    }
+
+    // Take field
+    pub fn take_checklist_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checklist_id, ::std::string::String::new())
+    }
+
+    // string item_id = 2;
+
+
+    pub fn get_item_id(&self) -> &str {
+        &self.item_id
+    }
+    pub fn clear_item_id(&mut self) {
+        self.item_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_id(&mut self, v: ::std::string::String) {
+        self.item_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_item_id(&mut self) -> &mut ::std::string::String {
+        &mut self.item_id
+    }
+
+    // Take field
+    pub fn take_item_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.item_id, ::std::string::String::new())
+    }
+
+    // int32 to_index = 3;
+
+
+    pub fn get_to_index(&self) -> i32 {
+        self.to_index
+    }
+    pub fn clear_to_index(&mut self) {
+        self.to_index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_index(&mut self, v: i32) {
+        self.to_index = v;
+    }
+}
+
+impl ::protobuf::Message for MoveChecklistItemRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checklist_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.item_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.to_index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.checklist_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.checklist_id);
+        }
+        if !self.item_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.item_id);
+        }
+        if self.to_index != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.to_index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.checklist_id.is_empty() {
+            os.write_string(1, &self.checklist_id)?;
+        }
+        if !self.item_id.is_empty() {
+            os.write_string(2, &self.item_id)?;
+        }
+        if self.to_index != 0 {
+            os.write_int32(3, self.to_index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveChecklistItemRequest {
+        MoveChecklistItemRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checklist_id",
+                |m: &MoveChecklistItemRequest| { &m.checklist_id },
+                |m: &mut MoveChecklistItemRequest| { &mut m.checklist_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "item_id",
+                |m: &MoveChecklistItemRequest| { &m.item_id },
+                |m: &mut MoveChecklistItemRequest| { &mut m.item_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "to_index",
+                |m: &MoveChecklistItemRequest| { &m.to_index },
+                |m: &mut MoveChecklistItemRequest| { &mut m.to_index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveChecklistItemRequest>(
+                "MoveChecklistItemRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveChecklistItemRequest {
+        static instance: ::protobuf::rt::LazyV2<MoveChecklistItemRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveChecklistItemRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveChecklistItemRequest {
+    fn clear(&mut self) {
+        self.checklist_id.clear();
+        self.item_id.clear();
+        self.to_index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveChecklistItemRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveChecklistItemRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveChecklistItemParams {
+    // message fields
+    pub checklist_id: ::std::string::String,
+    pub item_id: ::std::string::String,
+    pub to_index: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveChecklistItemParams {
+    fn default() -> &'a MoveChecklistItemParams {
+        <MoveChecklistItemParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveChecklistItemParams {
+    pub fn new() -> MoveChecklistItemParams {
+        ::std::default::Default::default()
+    }
+
+    // string checklist_id = 1;
+
+
+    pub fn get_checklist_id(&self) -> &str {
+        &self.checklist_id
+    }
+    pub fn clear_checklist_id(&mut self) {
+        self.checklist_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checklist_id(&mut self, v: ::std::string::String) {
+        self.checklist_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checklist_id(&mut self) -> &mut ::std::string::String {
+        &mut self.checklist_id
+    }
+
+    // Take field
+    pub fn take_checklist_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checklist_id, ::std::string::String::new())
+    }
+
+    // string item_id = 2;
+
+
+    pub fn get_item_id(&self) -> &str {
+        &self.item_id
+    }
+    pub fn clear_item_id(&mut self) {
+        self.item_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_id(&mut self, v: ::std::string::String) {
+        self.item_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_item_id(&mut self) -> &mut ::std::string::String {
+        &mut self.item_id
+    }
+
+    // Take field
+    pub fn take_item_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.item_id, ::std::string::String::new())
+    }
+
+    // int32 to_index = 3;
+
+
+    pub fn get_to_index(&self) -> i32 {
+        self.to_index
+    }
+    pub fn clear_to_index(&mut self) {
+        self.to_index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_index(&mut self, v: i32) {
+        self.to_index = v;
+    }
+}
+
+impl ::protobuf::Message for MoveChecklistItemParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checklist_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.item_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.to_index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.checklist_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.checklist_id);
+        }
+        if !self.item_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.item_id);
+        }
+        if self.to_index != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.to_index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.checklist_id.is_empty() {
+            os.write_string(1, &self.checklist_id)?;
+        }
+        if !self.item_id.is_empty() {
+            os.write_string(2, &self.item_id)?;
+        }
+        if self.to_index != 0 {
+            os.write_int32(3, self.to_index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveChecklistItemParams {
+        MoveChecklistItemParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checklist_id",
+                |m: &MoveChecklistItemParams| { &m.checklist_id },
+                |m: &mut MoveChecklistItemParams| { &mut m.checklist_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "item_id",
+                |m: &MoveChecklistItemParams| { &m.item_id },
+                |m: &mut MoveChecklistItemParams| { &mut m.item_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "to_index",
+                |m: &MoveChecklistItemParams| { &m.to_index },
+                |m: &mut MoveChecklistItemParams| { &mut m.to_index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveChecklistItemParams>(
+                "MoveChecklistItemParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveChecklistItemParams {
+        static instance: ::protobuf::rt::LazyV2<MoveChecklistItemParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveChecklistItemParams::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveChecklistItemParams {
+    fn clear(&mut self) {
+        self.checklist_id.clear();
+        self.item_id.clear();
+        self.to_index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveChecklistItemParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveChecklistItemParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CompleteChecklistItemsRequest {
+    // message fields
+    pub checklist_id: ::std::string::String,
+    pub item_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CompleteChecklistItemsRequest {
+    fn default() -> &'a CompleteChecklistItemsRequest {
+        <CompleteChecklistItemsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CompleteChecklistItemsRequest {
+    pub fn new() -> CompleteChecklistItemsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string checklist_id = 1;
+
+
+    pub fn get_checklist_id(&self) -> &str {
+        &self.checklist_id
+    }
+    pub fn clear_checklist_id(&mut self) {
+        self.checklist_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checklist_id(&mut self, v: ::std::string::String) {
+        self.checklist_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checklist_id(&mut self) -> &mut ::std::string::String {
+        &mut self.checklist_id
+    }
+
+    // Take field
+    pub fn take_checklist_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checklist_id, ::std::string::String::new())
+    }
+
+    // repeated string item_ids = 2;
+
+
+    pub fn get_item_ids(&self) -> &[::std::string::String] {
+        &self.item_ids
+    }
+    pub fn clear_item_ids(&mut self) {
+        self.item_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.item_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_item_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.item_ids
+    }
+
+    // Take field
+    pub fn take_item_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.item_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CompleteChecklistItemsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checklist_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.item_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.checklist_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.checklist_id);
+        }
+        for value in &self.item_ids {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.checklist_id.is_empty() {
+            os.write_string(1, &self.checklist_id)?;
+        }
+        for v in &self.item_ids {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CompleteChecklistItemsRequest {
+        CompleteChecklistItemsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checklist_id",
+                |m: &CompleteChecklistItemsRequest| { &m.checklist_id },
+                |m: &mut CompleteChecklistItemsRequest| { &mut m.checklist_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "item_ids",
+                |m: &CompleteChecklistItemsRequest| { &m.item_ids },
+                |m: &mut CompleteChecklistItemsRequest| { &mut m.item_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CompleteChecklistItemsRequest>(
+                "CompleteChecklistItemsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CompleteChecklistItemsRequest {
+        static instance: ::protobuf::rt::LazyV2<CompleteChecklistItemsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CompleteChecklistItemsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CompleteChecklistItemsRequest {
+    fn clear(&mut self) {
+        self.checklist_id.clear();
+        self.item_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CompleteChecklistItemsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompleteChecklistItemsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CompleteChecklistItemsParams {
+    // message fields
+    pub checklist_id: ::std::string::String,
+    pub item_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CompleteChecklistItemsParams {
+    fn default() -> &'a CompleteChecklistItemsParams {
+        <CompleteChecklistItemsParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CompleteChecklistItemsParams {
+    pub fn new() -> CompleteChecklistItemsParams {
+        ::std::default::Default::default()
+    }
+
+    // string checklist_id = 1;
+
+
+    pub fn get_checklist_id(&self) -> &str {
+        &self.checklist_id
+    }
+    pub fn clear_checklist_id(&mut self) {
+        self.checklist_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checklist_id(&mut self, v: ::std::string::String) {
+        self.checklist_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checklist_id(&mut self) -> &mut ::std::string::String {
+        &mut self.checklist_id
+    }
+
+    // Take field
+    pub fn take_checklist_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.checklist_id, ::std::string::String::new())
+    }
+
+    // repeated string item_ids = 2;
+
+
+    pub fn get_item_ids(&self) -> &[::std::string::String] {
+        &self.item_ids
+    }
+    pub fn clear_item_ids(&mut self) {
+        self.item_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.item_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_item_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.item_ids
+    }
+
+    // Take field
+    pub fn take_item_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.item_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CompleteChecklistItemsParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.checklist_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.item_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.checklist_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.checklist_id);
+        }
+        for value in &self.item_ids {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.checklist_id.is_empty() {
+            os.write_string(1, &self.checklist_id)?;
+        }
+        for v in &self.item_ids {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CompleteChecklistItemsParams {
+        CompleteChecklistItemsParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "checklist_id",
+                |m: &CompleteChecklistItemsParams| { &m.checklist_id },
+                |m: &mut CompleteChecklistItemsParams| { &mut m.checklist_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "item_ids",
+                |m: &CompleteChecklistItemsParams| { &m.item_ids },
+                |m: &mut CompleteChecklistItemsParams| { &mut m.item_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CompleteChecklistItemsParams>(
+                "CompleteChecklistItemsParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CompleteChecklistItemsParams {
+        static instance: ::protobuf::rt::LazyV2<CompleteChecklistItemsParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CompleteChecklistItemsParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CompleteChecklistItemsParams {
+    fn clear(&mut self) {
+        self.checklist_id.clear();
+        self.item_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CompleteChecklistItemsParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompleteChecklistItemsParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x14checklist_item.proto\"x\n\x1aCreateChecklistItemRequest\x12#\n\x0c\
+    checklist_id\x18\x01\x20\x01(\tR\x0bchecklistIdB\0\x12\x16\n\x05title\
+    \x18\x02\x20\x01(\tR\x05titleB\0\x12\x1b\n\x08due_date\x18\x03\x20\x01(\
+    \x03R\x07dueDateB\0:\0\"w\n\x19CreateChecklistItemParams\x12#\n\x0ccheck\
+    list_id\x18\x01\x20\x01(\tR\x0bchecklistIdB\0\x12\x16\n\x05title\x18\x02\
+    \x20\x01(\tR\x05titleB\0\x12\x1b\n\x08due_date\x18\x03\x20\x01(\x03R\x07\
+    dueDateB\0:\0\"\xba\x01\n\x1aUpdateChecklistItemRequest\x12#\n\x0ccheckl\
+    ist_id\x18\x01\x20\x01(\tR\x0bchecklistIdB\0\x12\x19\n\x07item_id\x18\
+    \x02\x20\x01(\tR\x06itemIdB\0\x12\x18\n\x05title\x18\x03\x20\x01(\tH\0R\
+    \x05titleB\0\x12\x1d\n\x08due_date\x18\x04\x20\x01(\x03H\x01R\x07dueDate\
+    B\0B\x0e\n\x0cone_of_titleB\x11\n\x0fone_of_due_date:\0\"\xb9\x01\n\x19U\
+    pdateChecklistItemParams\x12#\n\x0cchecklist_id\x18\x01\x20\x01(\tR\x0bc\
+    hecklistIdB\0\x12\x19\n\x07item_id\x18\x02\x20\x01(\tR\x06itemIdB\0\x12\
+    \x18\n\x05title\x18\x03\x20\x01(\tH\0R\x05titleB\0\x12\x1d\n\x08due_date\
+    \x18\x04\x20\x01(\x03H\x01R\x07dueDateB\0B\x0e\n\x0cone_of_titleB\x11\n\
+    \x0fone_of_due_date:\0\"]\n\x19QueryChecklistItemRequest\x12#\n\x0ccheck\
+    list_id\x18\x01\x20\x01(\tR\x0bchecklistIdB\0\x12\x19\n\x07item_id\x18\
+    \x02\x20\x01(\tR\x06itemIdB\0:\0\"S\n\x0fChecklistItemId\x12#\n\x0ccheck\
+    list_id\x18\x01\x20\x01(\tR\x0bchecklistIdB\0\x12\x19\n\x07item_id\x18\
+    \x02\x20\x01(\tR\x06itemIdB\0:\0\"y\n\x18MoveChecklistItemRequest\x12#\n\
+    \x0cchecklist_id\x18\x01\x20\x01(\tR\x0bchecklistIdB\0\x12\x19\n\x07item\
+    _id\x18\x02\x20\x01(\tR\x06itemIdB\0\x12\x1b\n\x08to_index\x18\x03\x20\
+    \x01(\x05R\x07toIndexB\0:\0\"x\n\x17MoveChecklistItemParams\x12#\n\x0cch\
+    ecklist_id\x18\x01\x20\x01(\tR\x0bchecklistIdB\0\x12\x19\n\x07item_id\
+    \x18\x02\x20\x01(\tR\x06itemIdB\0\x12\x1b\n\x08to_index\x18\x03\x20\x01(\
+    \x05R\x07toIndexB\0:\0\"c\n\x1dCompleteChecklistItemsRequest\x12#\n\x0cc\
+    hecklist_id\x18\x01\x20\x01(\tR\x0bchecklistIdB\0\x12\x1b\n\x08item_ids\
+    \x18\x02\x20\x03(\tR\x07itemIdsB\0:\0\"b\n\x1cCompleteChecklistItemsPara\
+    ms\x12#\n\x0cchecklist_id\x18\x01\x20\x01(\tR\x0bchecklistIdB\0\x12\x1b\
+    \n\x08item_ids\x18\x02\x20\x03(\tR\x07itemIdsB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
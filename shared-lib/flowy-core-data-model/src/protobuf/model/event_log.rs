@@ -0,0 +1,1010 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `event_log.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EventLogEntry {
+    // message fields
+    pub seq: i64,
+    pub ty: ::std::string::String,
+    pub object_id: ::std::string::String,
+    pub payload: ::std::string::String,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EventLogEntry {
+    fn default() -> &'a EventLogEntry {
+        <EventLogEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EventLogEntry {
+    pub fn new() -> EventLogEntry {
+        ::std::default::Default::default()
+    }
+
+    // int64 seq = 1;
+
+
+    pub fn get_seq(&self) -> i64 {
+        self.seq
+    }
+    pub fn clear_seq(&mut self) {
+        self.seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: i64) {
+        self.seq = v;
+    }
+
+    // string ty = 2;
+
+
+    pub fn get_ty(&self) -> &str {
+        &self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: ::std::string::String) {
+        self.ty = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ty(&mut self) -> &mut ::std::string::String {
+        &mut self.ty
+    }
+
+    // Take field
+    pub fn take_ty(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ty, ::std::string::String::new())
+    }
+
+    // string object_id = 3;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // string payload = 4;
+
+
+    pub fn get_payload(&self) -> &str {
+        &self.payload
+    }
+    pub fn clear_payload(&mut self) {
+        self.payload.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_payload(&mut self, v: ::std::string::String) {
+        self.payload = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_payload(&mut self) -> &mut ::std::string::String {
+        &mut self.payload
+    }
+
+    // Take field
+    pub fn take_payload(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.payload, ::std::string::String::new())
+    }
+
+    // int64 create_time = 5;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for EventLogEntry {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.seq = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ty)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.payload)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.ty.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.ty);
+        }
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.object_id);
+        }
+        if !self.payload.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.payload);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.seq != 0 {
+            os.write_int64(1, self.seq)?;
+        }
+        if !self.ty.is_empty() {
+            os.write_string(2, &self.ty)?;
+        }
+        if !self.object_id.is_empty() {
+            os.write_string(3, &self.object_id)?;
+        }
+        if !self.payload.is_empty() {
+            os.write_string(4, &self.payload)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(5, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EventLogEntry {
+        EventLogEntry::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "seq",
+                |m: &EventLogEntry| { &m.seq },
+                |m: &mut EventLogEntry| { &mut m.seq },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ty",
+                |m: &EventLogEntry| { &m.ty },
+                |m: &mut EventLogEntry| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &EventLogEntry| { &m.object_id },
+                |m: &mut EventLogEntry| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "payload",
+                |m: &EventLogEntry| { &m.payload },
+                |m: &mut EventLogEntry| { &mut m.payload },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &EventLogEntry| { &m.create_time },
+                |m: &mut EventLogEntry| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EventLogEntry>(
+                "EventLogEntry",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EventLogEntry {
+        static instance: ::protobuf::rt::LazyV2<EventLogEntry> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EventLogEntry::new)
+    }
+}
+
+impl ::protobuf::Clear for EventLogEntry {
+    fn clear(&mut self) {
+        self.seq = 0;
+        self.ty.clear();
+        self.object_id.clear();
+        self.payload.clear();
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EventLogEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EventLogEntry {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EventLogPage {
+    // message fields
+    pub items: ::protobuf::RepeatedField<EventLogEntry>,
+    pub next_cursor: i64,
+    pub first_seq: i64,
+    pub has_more: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EventLogPage {
+    fn default() -> &'a EventLogPage {
+        <EventLogPage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EventLogPage {
+    pub fn new() -> EventLogPage {
+        ::std::default::Default::default()
+    }
+
+    // repeated .EventLogEntry items = 1;
+
+
+    pub fn get_items(&self) -> &[EventLogEntry] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<EventLogEntry>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<EventLogEntry> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<EventLogEntry> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+
+    // int64 next_cursor = 2;
+
+
+    pub fn get_next_cursor(&self) -> i64 {
+        self.next_cursor
+    }
+    pub fn clear_next_cursor(&mut self) {
+        self.next_cursor = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_cursor(&mut self, v: i64) {
+        self.next_cursor = v;
+    }
+
+    // int64 first_seq = 3;
+
+
+    pub fn get_first_seq(&self) -> i64 {
+        self.first_seq
+    }
+    pub fn clear_first_seq(&mut self) {
+        self.first_seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_first_seq(&mut self, v: i64) {
+        self.first_seq = v;
+    }
+
+    // bool has_more = 4;
+
+
+    pub fn get_has_more(&self) -> bool {
+        self.has_more
+    }
+    pub fn clear_has_more(&mut self) {
+        self.has_more = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_more(&mut self, v: bool) {
+        self.has_more = v;
+    }
+}
+
+impl ::protobuf::Message for EventLogPage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.next_cursor = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.first_seq = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.has_more = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.next_cursor != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.next_cursor, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.first_seq != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.first_seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.has_more != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.next_cursor != 0 {
+            os.write_int64(2, self.next_cursor)?;
+        }
+        if self.first_seq != 0 {
+            os.write_int64(3, self.first_seq)?;
+        }
+        if self.has_more != false {
+            os.write_bool(4, self.has_more)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EventLogPage {
+        EventLogPage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EventLogEntry>>(
+                "items",
+                |m: &EventLogPage| { &m.items },
+                |m: &mut EventLogPage| { &mut m.items },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "next_cursor",
+                |m: &EventLogPage| { &m.next_cursor },
+                |m: &mut EventLogPage| { &mut m.next_cursor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "first_seq",
+                |m: &EventLogPage| { &m.first_seq },
+                |m: &mut EventLogPage| { &mut m.first_seq },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "has_more",
+                |m: &EventLogPage| { &m.has_more },
+                |m: &mut EventLogPage| { &mut m.has_more },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EventLogPage>(
+                "EventLogPage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EventLogPage {
+        static instance: ::protobuf::rt::LazyV2<EventLogPage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EventLogPage::new)
+    }
+}
+
+impl ::protobuf::Clear for EventLogPage {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.next_cursor = 0;
+        self.first_seq = 0;
+        self.has_more = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EventLogPage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EventLogPage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReadEventLogRequest {
+    // message fields
+    pub cursor: i64,
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReadEventLogRequest {
+    fn default() -> &'a ReadEventLogRequest {
+        <ReadEventLogRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReadEventLogRequest {
+    pub fn new() -> ReadEventLogRequest {
+        ::std::default::Default::default()
+    }
+
+    // int64 cursor = 1;
+
+
+    pub fn get_cursor(&self) -> i64 {
+        self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: i64) {
+        self.cursor = v;
+    }
+
+    // int64 limit = 2;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for ReadEventLogRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cursor = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cursor != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.cursor, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.cursor != 0 {
+            os.write_int64(1, self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_int64(2, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReadEventLogRequest {
+        ReadEventLogRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cursor",
+                |m: &ReadEventLogRequest| { &m.cursor },
+                |m: &mut ReadEventLogRequest| { &mut m.cursor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &ReadEventLogRequest| { &m.limit },
+                |m: &mut ReadEventLogRequest| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReadEventLogRequest>(
+                "ReadEventLogRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReadEventLogRequest {
+        static instance: ::protobuf::rt::LazyV2<ReadEventLogRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReadEventLogRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ReadEventLogRequest {
+    fn clear(&mut self) {
+        self.cursor = 0;
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReadEventLogRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadEventLogRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReadEventLogParams {
+    // message fields
+    pub cursor: i64,
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReadEventLogParams {
+    fn default() -> &'a ReadEventLogParams {
+        <ReadEventLogParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReadEventLogParams {
+    pub fn new() -> ReadEventLogParams {
+        ::std::default::Default::default()
+    }
+
+    // int64 cursor = 1;
+
+
+    pub fn get_cursor(&self) -> i64 {
+        self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: i64) {
+        self.cursor = v;
+    }
+
+    // int64 limit = 2;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for ReadEventLogParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cursor = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cursor != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.cursor, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.cursor != 0 {
+            os.write_int64(1, self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_int64(2, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReadEventLogParams {
+        ReadEventLogParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cursor",
+                |m: &ReadEventLogParams| { &m.cursor },
+                |m: &mut ReadEventLogParams| { &mut m.cursor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &ReadEventLogParams| { &m.limit },
+                |m: &mut ReadEventLogParams| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReadEventLogParams>(
+                "ReadEventLogParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReadEventLogParams {
+        static instance: ::protobuf::rt::LazyV2<ReadEventLogParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReadEventLogParams::new)
+    }
+}
+
+impl ::protobuf::Clear for ReadEventLogParams {
+    fn clear(&mut self) {
+        self.cursor = 0;
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReadEventLogParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadEventLogParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0fevent_log.proto\"\x95\x01\n\rEventLogEntry\x12\x12\n\x03seq\x18\
+    \x01\x20\x01(\x03R\x03seqB\0\x12\x10\n\x02ty\x18\x02\x20\x01(\tR\x02tyB\
+    \0\x12\x1d\n\tobject_id\x18\x03\x20\x01(\tR\x08objectIdB\0\x12\x1a\n\x07\
+    payload\x18\x04\x20\x01(\tR\x07payloadB\0\x12!\n\x0bcreate_time\x18\x05\
+    \x20\x01(\x03R\ncreateTimeB\0:\0\"\x97\x01\n\x0cEventLogPage\x12&\n\x05i\
+    tems\x18\x01\x20\x03(\x0b2\x0e.EventLogEntryR\x05itemsB\0\x12!\n\x0bnext\
+    _cursor\x18\x02\x20\x01(\x03R\nnextCursorB\0\x12\x1d\n\tfirst_seq\x18\
+    \x03\x20\x01(\x03R\x08firstSeqB\0\x12\x1b\n\x08has_more\x18\x04\x20\x01(\
+    \x08R\x07hasMoreB\0:\0\"I\n\x13ReadEventLogRequest\x12\x18\n\x06cursor\
+    \x18\x01\x20\x01(\x03R\x06cursorB\0\x12\x16\n\x05limit\x18\x02\x20\x01(\
+    \x03R\x05limitB\0:\0\"H\n\x12ReadEventLogParams\x12\x18\n\x06cursor\x18\
+    \x01\x20\x01(\x03R\x06cursorB\0\x12\x16\n\x05limit\x18\x02\x20\x01(\x03R\
+    \x05limitB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -403,6 +403,2338 @@ impl ::protobuf::reflect::ProtobufValue for ExportData {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportUserDataRequest {
+    // message fields
+    pub dir: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportUserDataRequest {
+    fn default() -> &'a ExportUserDataRequest {
+        <ExportUserDataRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportUserDataRequest {
+    pub fn new() -> ExportUserDataRequest {
+        ::std::default::Default::default()
+    }
+
+    // string dir = 1;
+
+
+    pub fn get_dir(&self) -> &str {
+        &self.dir
+    }
+    pub fn clear_dir(&mut self) {
+        self.dir.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir(&mut self, v: ::std::string::String) {
+        self.dir = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_dir(&mut self) -> &mut ::std::string::String {
+        &mut self.dir
+    }
+
+    // Take field
+    pub fn take_dir(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.dir, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ExportUserDataRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.dir)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.dir);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.dir.is_empty() {
+            os.write_string(1, &self.dir)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportUserDataRequest {
+        ExportUserDataRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "dir",
+                |m: &ExportUserDataRequest| { &m.dir },
+                |m: &mut ExportUserDataRequest| { &mut m.dir },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportUserDataRequest>(
+                "ExportUserDataRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportUserDataRequest {
+        static instance: ::protobuf::rt::LazyV2<ExportUserDataRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportUserDataRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportUserDataRequest {
+    fn clear(&mut self) {
+        self.dir.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportUserDataRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportUserDataRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportUserDataResult {
+    // message fields
+    pub path: ::std::string::String,
+    pub document_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportUserDataResult {
+    fn default() -> &'a ExportUserDataResult {
+        <ExportUserDataResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportUserDataResult {
+    pub fn new() -> ExportUserDataResult {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // int64 document_count = 2;
+
+
+    pub fn get_document_count(&self) -> i64 {
+        self.document_count
+    }
+    pub fn clear_document_count(&mut self) {
+        self.document_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_document_count(&mut self, v: i64) {
+        self.document_count = v;
+    }
+}
+
+impl ::protobuf::Message for ExportUserDataResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.document_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if self.document_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.document_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if self.document_count != 0 {
+            os.write_int64(2, self.document_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportUserDataResult {
+        ExportUserDataResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &ExportUserDataResult| { &m.path },
+                |m: &mut ExportUserDataResult| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "document_count",
+                |m: &ExportUserDataResult| { &m.document_count },
+                |m: &mut ExportUserDataResult| { &mut m.document_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportUserDataResult>(
+                "ExportUserDataResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportUserDataResult {
+        static instance: ::protobuf::rt::LazyV2<ExportUserDataResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportUserDataResult::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportUserDataResult {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.document_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportUserDataResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportUserDataResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportGridRequest {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub delimiter: ::std::string::String,
+    pub field_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // message oneof groups
+    pub one_of_path: ::std::option::Option<ExportGridRequest_oneof_one_of_path>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportGridRequest {
+    fn default() -> &'a ExportGridRequest {
+        <ExportGridRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ExportGridRequest_oneof_one_of_path {
+    path(::std::string::String),
+}
+
+impl ExportGridRequest {
+    pub fn new() -> ExportGridRequest {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string delimiter = 2;
+
+
+    pub fn get_delimiter(&self) -> &str {
+        &self.delimiter
+    }
+    pub fn clear_delimiter(&mut self) {
+        self.delimiter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delimiter(&mut self, v: ::std::string::String) {
+        self.delimiter = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_delimiter(&mut self) -> &mut ::std::string::String {
+        &mut self.delimiter
+    }
+
+    // Take field
+    pub fn take_delimiter(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.delimiter, ::std::string::String::new())
+    }
+
+    // repeated string field_ids = 3;
+
+
+    pub fn get_field_ids(&self) -> &[::std::string::String] {
+        &self.field_ids
+    }
+    pub fn clear_field_ids(&mut self) {
+        self.field_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.field_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_field_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.field_ids
+    }
+
+    // Take field
+    pub fn take_field_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.field_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // string path = 4;
+
+
+    pub fn get_path(&self) -> &str {
+        match self.one_of_path {
+            ::std::option::Option::Some(ExportGridRequest_oneof_one_of_path::path(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_path(&mut self) {
+        self.one_of_path = ::std::option::Option::None;
+    }
+
+    pub fn has_path(&self) -> bool {
+        match self.one_of_path {
+            ::std::option::Option::Some(ExportGridRequest_oneof_one_of_path::path(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.one_of_path = ::std::option::Option::Some(ExportGridRequest_oneof_one_of_path::path(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ExportGridRequest_oneof_one_of_path::path(_)) = self.one_of_path {
+        } else {
+            self.one_of_path = ::std::option::Option::Some(ExportGridRequest_oneof_one_of_path::path(::std::string::String::new()));
+        }
+        match self.one_of_path {
+            ::std::option::Option::Some(ExportGridRequest_oneof_one_of_path::path(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        if self.has_path() {
+            match self.one_of_path.take() {
+                ::std::option::Option::Some(ExportGridRequest_oneof_one_of_path::path(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ExportGridRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.delimiter)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.field_ids)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_path = ::std::option::Option::Some(ExportGridRequest_oneof_one_of_path::path(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.delimiter.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.delimiter);
+        }
+        for value in &self.field_ids {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if let ::std::option::Option::Some(ref v) = self.one_of_path {
+            match v {
+                &ExportGridRequest_oneof_one_of_path::path(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.delimiter.is_empty() {
+            os.write_string(2, &self.delimiter)?;
+        }
+        for v in &self.field_ids {
+            os.write_string(3, &v)?;
+        };
+        if let ::std::option::Option::Some(ref v) = self.one_of_path {
+            match v {
+                &ExportGridRequest_oneof_one_of_path::path(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportGridRequest {
+        ExportGridRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &ExportGridRequest| { &m.grid_id },
+                |m: &mut ExportGridRequest| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "delimiter",
+                |m: &ExportGridRequest| { &m.delimiter },
+                |m: &mut ExportGridRequest| { &mut m.delimiter },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field_ids",
+                |m: &ExportGridRequest| { &m.field_ids },
+                |m: &mut ExportGridRequest| { &mut m.field_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "path",
+                ExportGridRequest::has_path,
+                ExportGridRequest::get_path,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportGridRequest>(
+                "ExportGridRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportGridRequest {
+        static instance: ::protobuf::rt::LazyV2<ExportGridRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportGridRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportGridRequest {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.delimiter.clear();
+        self.field_ids.clear();
+        self.one_of_path = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportGridRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportGridRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportGridResult {
+    // message fields
+    pub data: ::std::vec::Vec<u8>,
+    pub path: ::std::string::String,
+    pub row_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportGridResult {
+    fn default() -> &'a ExportGridResult {
+        <ExportGridResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportGridResult {
+    pub fn new() -> ExportGridResult {
+        ::std::default::Default::default()
+    }
+
+    // bytes data = 1;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+
+    // string path = 2;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // int64 row_count = 3;
+
+
+    pub fn get_row_count(&self) -> i64 {
+        self.row_count
+    }
+    pub fn clear_row_count(&mut self) {
+        self.row_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row_count(&mut self, v: i64) {
+        self.row_count = v;
+    }
+}
+
+impl ::protobuf::Message for ExportGridResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.row_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.data);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.path);
+        }
+        if self.row_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.row_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.data.is_empty() {
+            os.write_bytes(1, &self.data)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(2, &self.path)?;
+        }
+        if self.row_count != 0 {
+            os.write_int64(3, self.row_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportGridResult {
+        ExportGridResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "data",
+                |m: &ExportGridResult| { &m.data },
+                |m: &mut ExportGridResult| { &mut m.data },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &ExportGridResult| { &m.path },
+                |m: &mut ExportGridResult| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "row_count",
+                |m: &ExportGridResult| { &m.row_count },
+                |m: &mut ExportGridResult| { &mut m.row_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportGridResult>(
+                "ExportGridResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportGridResult {
+        static instance: ::protobuf::rt::LazyV2<ExportGridResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportGridResult::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportGridResult {
+    fn clear(&mut self) {
+        self.data.clear();
+        self.path.clear();
+        self.row_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportGridResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportGridResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportOpmlRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_path: ::std::option::Option<ExportOpmlRequest_oneof_one_of_path>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportOpmlRequest {
+    fn default() -> &'a ExportOpmlRequest {
+        <ExportOpmlRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ExportOpmlRequest_oneof_one_of_path {
+    path(::std::string::String),
+}
+
+impl ExportOpmlRequest {
+    pub fn new() -> ExportOpmlRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string path = 2;
+
+
+    pub fn get_path(&self) -> &str {
+        match self.one_of_path {
+            ::std::option::Option::Some(ExportOpmlRequest_oneof_one_of_path::path(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_path(&mut self) {
+        self.one_of_path = ::std::option::Option::None;
+    }
+
+    pub fn has_path(&self) -> bool {
+        match self.one_of_path {
+            ::std::option::Option::Some(ExportOpmlRequest_oneof_one_of_path::path(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.one_of_path = ::std::option::Option::Some(ExportOpmlRequest_oneof_one_of_path::path(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ExportOpmlRequest_oneof_one_of_path::path(_)) = self.one_of_path {
+        } else {
+            self.one_of_path = ::std::option::Option::Some(ExportOpmlRequest_oneof_one_of_path::path(::std::string::String::new()));
+        }
+        match self.one_of_path {
+            ::std::option::Option::Some(ExportOpmlRequest_oneof_one_of_path::path(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        if self.has_path() {
+            match self.one_of_path.take() {
+                ::std::option::Option::Some(ExportOpmlRequest_oneof_one_of_path::path(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ExportOpmlRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_path = ::std::option::Option::Some(ExportOpmlRequest_oneof_one_of_path::path(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_path {
+            match v {
+                &ExportOpmlRequest_oneof_one_of_path::path(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_path {
+            match v {
+                &ExportOpmlRequest_oneof_one_of_path::path(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportOpmlRequest {
+        ExportOpmlRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &ExportOpmlRequest| { &m.workspace_id },
+                |m: &mut ExportOpmlRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "path",
+                ExportOpmlRequest::has_path,
+                ExportOpmlRequest::get_path,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportOpmlRequest>(
+                "ExportOpmlRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportOpmlRequest {
+        static instance: ::protobuf::rt::LazyV2<ExportOpmlRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportOpmlRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportOpmlRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.one_of_path = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportOpmlRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportOpmlRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportOpmlResult {
+    // message fields
+    pub data: ::std::vec::Vec<u8>,
+    pub path: ::std::string::String,
+    pub outline_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportOpmlResult {
+    fn default() -> &'a ExportOpmlResult {
+        <ExportOpmlResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportOpmlResult {
+    pub fn new() -> ExportOpmlResult {
+        ::std::default::Default::default()
+    }
+
+    // bytes data = 1;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+
+    // string path = 2;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // int64 outline_count = 3;
+
+
+    pub fn get_outline_count(&self) -> i64 {
+        self.outline_count
+    }
+    pub fn clear_outline_count(&mut self) {
+        self.outline_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_outline_count(&mut self, v: i64) {
+        self.outline_count = v;
+    }
+}
+
+impl ::protobuf::Message for ExportOpmlResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.outline_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.data);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.path);
+        }
+        if self.outline_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.outline_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.data.is_empty() {
+            os.write_bytes(1, &self.data)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(2, &self.path)?;
+        }
+        if self.outline_count != 0 {
+            os.write_int64(3, self.outline_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportOpmlResult {
+        ExportOpmlResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "data",
+                |m: &ExportOpmlResult| { &m.data },
+                |m: &mut ExportOpmlResult| { &mut m.data },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &ExportOpmlResult| { &m.path },
+                |m: &mut ExportOpmlResult| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "outline_count",
+                |m: &ExportOpmlResult| { &m.outline_count },
+                |m: &mut ExportOpmlResult| { &mut m.outline_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportOpmlResult>(
+                "ExportOpmlResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportOpmlResult {
+        static instance: ::protobuf::rt::LazyV2<ExportOpmlResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportOpmlResult::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportOpmlResult {
+    fn clear(&mut self) {
+        self.data.clear();
+        self.path.clear();
+        self.outline_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportOpmlResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportOpmlResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportCalendarRequest {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub subscribe: bool,
+    // message oneof groups
+    pub one_of_path: ::std::option::Option<ExportCalendarRequest_oneof_one_of_path>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportCalendarRequest {
+    fn default() -> &'a ExportCalendarRequest {
+        <ExportCalendarRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ExportCalendarRequest_oneof_one_of_path {
+    path(::std::string::String),
+}
+
+impl ExportCalendarRequest {
+    pub fn new() -> ExportCalendarRequest {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string path = 2;
+
+
+    pub fn get_path(&self) -> &str {
+        match self.one_of_path {
+            ::std::option::Option::Some(ExportCalendarRequest_oneof_one_of_path::path(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_path(&mut self) {
+        self.one_of_path = ::std::option::Option::None;
+    }
+
+    pub fn has_path(&self) -> bool {
+        match self.one_of_path {
+            ::std::option::Option::Some(ExportCalendarRequest_oneof_one_of_path::path(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.one_of_path = ::std::option::Option::Some(ExportCalendarRequest_oneof_one_of_path::path(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ExportCalendarRequest_oneof_one_of_path::path(_)) = self.one_of_path {
+        } else {
+            self.one_of_path = ::std::option::Option::Some(ExportCalendarRequest_oneof_one_of_path::path(::std::string::String::new()));
+        }
+        match self.one_of_path {
+            ::std::option::Option::Some(ExportCalendarRequest_oneof_one_of_path::path(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        if self.has_path() {
+            match self.one_of_path.take() {
+                ::std::option::Option::Some(ExportCalendarRequest_oneof_one_of_path::path(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // bool subscribe = 3;
+
+
+    pub fn get_subscribe(&self) -> bool {
+        self.subscribe
+    }
+    pub fn clear_subscribe(&mut self) {
+        self.subscribe = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subscribe(&mut self, v: bool) {
+        self.subscribe = v;
+    }
+}
+
+impl ::protobuf::Message for ExportCalendarRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_path = ::std::option::Option::Some(ExportCalendarRequest_oneof_one_of_path::path(is.read_string()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.subscribe = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if self.subscribe != false {
+            my_size += 2;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_path {
+            match v {
+                &ExportCalendarRequest_oneof_one_of_path::path(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if self.subscribe != false {
+            os.write_bool(3, self.subscribe)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_path {
+            match v {
+                &ExportCalendarRequest_oneof_one_of_path::path(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportCalendarRequest {
+        ExportCalendarRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &ExportCalendarRequest| { &m.grid_id },
+                |m: &mut ExportCalendarRequest| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "path",
+                ExportCalendarRequest::has_path,
+                ExportCalendarRequest::get_path,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "subscribe",
+                |m: &ExportCalendarRequest| { &m.subscribe },
+                |m: &mut ExportCalendarRequest| { &mut m.subscribe },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportCalendarRequest>(
+                "ExportCalendarRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportCalendarRequest {
+        static instance: ::protobuf::rt::LazyV2<ExportCalendarRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportCalendarRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportCalendarRequest {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.one_of_path = ::std::option::Option::None;
+        self.subscribe = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportCalendarRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportCalendarRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportCalendarResult {
+    // message fields
+    pub data: ::std::vec::Vec<u8>,
+    pub path: ::std::string::String,
+    pub event_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportCalendarResult {
+    fn default() -> &'a ExportCalendarResult {
+        <ExportCalendarResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportCalendarResult {
+    pub fn new() -> ExportCalendarResult {
+        ::std::default::Default::default()
+    }
+
+    // bytes data = 1;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+
+    // string path = 2;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // int64 event_count = 3;
+
+
+    pub fn get_event_count(&self) -> i64 {
+        self.event_count
+    }
+    pub fn clear_event_count(&mut self) {
+        self.event_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_event_count(&mut self, v: i64) {
+        self.event_count = v;
+    }
+}
+
+impl ::protobuf::Message for ExportCalendarResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.event_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.data);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.path);
+        }
+        if self.event_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.event_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.data.is_empty() {
+            os.write_bytes(1, &self.data)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(2, &self.path)?;
+        }
+        if self.event_count != 0 {
+            os.write_int64(3, self.event_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportCalendarResult {
+        ExportCalendarResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "data",
+                |m: &ExportCalendarResult| { &m.data },
+                |m: &mut ExportCalendarResult| { &mut m.data },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &ExportCalendarResult| { &m.path },
+                |m: &mut ExportCalendarResult| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "event_count",
+                |m: &ExportCalendarResult| { &m.event_count },
+                |m: &mut ExportCalendarResult| { &mut m.event_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportCalendarResult>(
+                "ExportCalendarResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportCalendarResult {
+        static instance: ::protobuf::rt::LazyV2<ExportCalendarResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportCalendarResult::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportCalendarResult {
+    fn clear(&mut self) {
+        self.data.clear();
+        self.path.clear();
+        self.event_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportCalendarResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportCalendarResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportSiteRequest {
+    // message fields
+    pub app_id: ::std::string::String,
+    pub dir: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportSiteRequest {
+    fn default() -> &'a ExportSiteRequest {
+        <ExportSiteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportSiteRequest {
+    pub fn new() -> ExportSiteRequest {
+        ::std::default::Default::default()
+    }
+
+    // string app_id = 1;
+
+
+    pub fn get_app_id(&self) -> &str {
+        &self.app_id
+    }
+    pub fn clear_app_id(&mut self) {
+        self.app_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_id(&mut self, v: ::std::string::String) {
+        self.app_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_app_id(&mut self) -> &mut ::std::string::String {
+        &mut self.app_id
+    }
+
+    // Take field
+    pub fn take_app_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.app_id, ::std::string::String::new())
+    }
+
+    // string dir = 2;
+
+
+    pub fn get_dir(&self) -> &str {
+        &self.dir
+    }
+    pub fn clear_dir(&mut self) {
+        self.dir.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir(&mut self, v: ::std::string::String) {
+        self.dir = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_dir(&mut self) -> &mut ::std::string::String {
+        &mut self.dir
+    }
+
+    // Take field
+    pub fn take_dir(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.dir, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ExportSiteRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.app_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.dir)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.app_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.app_id);
+        }
+        if !self.dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.dir);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.app_id.is_empty() {
+            os.write_string(1, &self.app_id)?;
+        }
+        if !self.dir.is_empty() {
+            os.write_string(2, &self.dir)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportSiteRequest {
+        ExportSiteRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "app_id",
+                |m: &ExportSiteRequest| { &m.app_id },
+                |m: &mut ExportSiteRequest| { &mut m.app_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "dir",
+                |m: &ExportSiteRequest| { &m.dir },
+                |m: &mut ExportSiteRequest| { &mut m.dir },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportSiteRequest>(
+                "ExportSiteRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportSiteRequest {
+        static instance: ::protobuf::rt::LazyV2<ExportSiteRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportSiteRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportSiteRequest {
+    fn clear(&mut self) {
+        self.app_id.clear();
+        self.dir.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportSiteRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportSiteRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportSiteResult {
+    // message fields
+    pub path: ::std::string::String,
+    pub page_count: i64,
+    pub attachment_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportSiteResult {
+    fn default() -> &'a ExportSiteResult {
+        <ExportSiteResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportSiteResult {
+    pub fn new() -> ExportSiteResult {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // int64 page_count = 2;
+
+
+    pub fn get_page_count(&self) -> i64 {
+        self.page_count
+    }
+    pub fn clear_page_count(&mut self) {
+        self.page_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_count(&mut self, v: i64) {
+        self.page_count = v;
+    }
+
+    // int64 attachment_count = 3;
+
+
+    pub fn get_attachment_count(&self) -> i64 {
+        self.attachment_count
+    }
+    pub fn clear_attachment_count(&mut self) {
+        self.attachment_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachment_count(&mut self, v: i64) {
+        self.attachment_count = v;
+    }
+}
+
+impl ::protobuf::Message for ExportSiteResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.page_count = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.attachment_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if self.page_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.page_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.attachment_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.attachment_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if self.page_count != 0 {
+            os.write_int64(2, self.page_count)?;
+        }
+        if self.attachment_count != 0 {
+            os.write_int64(3, self.attachment_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportSiteResult {
+        ExportSiteResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &ExportSiteResult| { &m.path },
+                |m: &mut ExportSiteResult| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "page_count",
+                |m: &ExportSiteResult| { &m.page_count },
+                |m: &mut ExportSiteResult| { &mut m.page_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "attachment_count",
+                |m: &ExportSiteResult| { &m.attachment_count },
+                |m: &mut ExportSiteResult| { &mut m.attachment_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportSiteResult>(
+                "ExportSiteResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportSiteResult {
+        static instance: ::protobuf::rt::LazyV2<ExportSiteResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportSiteResult::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportSiteResult {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.page_count = 0;
+        self.attachment_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportSiteResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportSiteResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum ExportType {
     Text = 0,
@@ -457,33 +2789,38 @@ impl ::protobuf::reflect::ProtobufValue for ExportType {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cexport.proto\"T\n\rExportRequest\x12\x15\n\x06doc_id\x18\x01\x20\
-    \x01(\tR\x05docId\x12,\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.Export\
-    TypeR\nexportType\"N\n\nExportData\x12\x12\n\x04data\x18\x01\x20\x01(\tR\
-    \x04data\x12,\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.ExportTypeR\nex\
-    portType*.\n\nExportType\x12\x08\n\x04Text\x10\0\x12\x0c\n\x08Markdown\
-    \x10\x01\x12\x08\n\x04Link\x10\x02J\xb1\x03\n\x06\x12\x04\0\0\x0e\x01\n\
-    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\
-    \n\x03\x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
-    \x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\
-    \x02\0\x01\x12\x03\x03\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\
-    \x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x1f\n\x0c\n\x05\x04\0\
-    \x02\x01\x06\x12\x03\x04\x04\x0e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\
-    \x04\x0f\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x1d\x1e\n\n\n\x02\
-    \x04\x01\x12\x04\x06\0\t\x01\n\n\n\x03\x04\x01\x01\x12\x03\x06\x08\x12\n\
-    \x0b\n\x04\x04\x01\x02\0\x12\x03\x07\x04\x14\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x07\x0b\x0f\
-    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x07\x12\x13\n\x0b\n\x04\x04\x01\
-    \x02\x01\x12\x03\x08\x04\x1f\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\x08\
-    \x04\x0e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x08\x0f\x1a\n\x0c\n\x05\
-    \x04\x01\x02\x01\x03\x12\x03\x08\x1d\x1e\n\n\n\x02\x05\0\x12\x04\n\0\x0e\
-    \x01\n\n\n\x03\x05\0\x01\x12\x03\n\x05\x0f\n\x0b\n\x04\x05\0\x02\0\x12\
-    \x03\x0b\x04\r\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x0b\x04\x08\n\x0c\n\
-    \x05\x05\0\x02\0\x02\x12\x03\x0b\x0b\x0c\n\x0b\n\x04\x05\0\x02\x01\x12\
-    \x03\x0c\x04\x11\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x0c\x04\x0c\n\x0c\
-    \n\x05\x05\0\x02\x01\x02\x12\x03\x0c\x0f\x10\n\x0b\n\x04\x05\0\x02\x02\
-    \x12\x03\r\x04\r\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\r\x04\x08\n\x0c\n\
-    \x05\x05\0\x02\x02\x02\x12\x03\r\x0b\x0cb\x06proto3\
+    \n\x0cexport.proto\"Z\n\rExportRequest\x12\x17\n\x06doc_id\x18\x01\x20\
+    \x01(\tR\x05docIdB\0\x12.\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.Exp\
+    ortTypeR\nexportTypeB\0:\0\"T\n\nExportData\x12\x14\n\x04data\x18\x01\
+    \x20\x01(\tR\x04dataB\0\x12.\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.\
+    ExportTypeR\nexportTypeB\0:\0\"-\n\x15ExportUserDataRequest\x12\x12\n\
+    \x03dir\x18\x01\x20\x01(\tR\x03dirB\0:\0\"W\n\x14ExportUserDataResult\
+    \x12\x14\n\x04path\x18\x01\x20\x01(\tR\x04pathB\0\x12'\n\x0edocument_cou\
+    nt\x18\x02\x20\x01(\x03R\rdocumentCountB\0:\0\"\x96\x01\n\x11ExportGridR\
+    equest\x12\x19\n\x07grid_id\x18\x01\x20\x01(\tR\x06gridIdB\0\x12\x1e\n\t\
+    delimiter\x18\x02\x20\x01(\tR\tdelimiterB\0\x12\x1d\n\tfield_ids\x18\x03\
+    \x20\x03(\tR\x08fieldIdsB\0\x12\x16\n\x04path\x18\x04\x20\x01(\tH\0R\x04\
+    pathB\0B\r\n\x0bone_of_path:\0\"_\n\x10ExportGridResult\x12\x14\n\x04dat\
+    a\x18\x01\x20\x01(\x0cR\x04dataB\0\x12\x14\n\x04path\x18\x02\x20\x01(\tR\
+    \x04pathB\0\x12\x1d\n\trow_count\x18\x03\x20\x01(\x03R\x08rowCountB\0:\0\
+    \"a\n\x11ExportOpmlRequest\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\
+    \x0bworkspaceIdB\0\x12\x16\n\x04path\x18\x02\x20\x01(\tH\0R\x04pathB\0B\
+    \r\n\x0bone_of_path:\0\"g\n\x10ExportOpmlResult\x12\x14\n\x04data\x18\
+    \x01\x20\x01(\x0cR\x04dataB\0\x12\x14\n\x04path\x18\x02\x20\x01(\tR\x04p\
+    athB\0\x12%\n\routline_count\x18\x03\x20\x01(\x03R\x0coutlineCountB\0:\0\
+    \"{\n\x15ExportCalendarRequest\x12\x19\n\x07grid_id\x18\x01\x20\x01(\tR\
+    \x06gridIdB\0\x12\x16\n\x04path\x18\x02\x20\x01(\tH\0R\x04pathB\0\x12\
+    \x1e\n\tsubscribe\x18\x03\x20\x01(\x08R\tsubscribeB\0B\r\n\x0bone_of_pat\
+    h:\0\"g\n\x14ExportCalendarResult\x12\x14\n\x04data\x18\x01\x20\x01(\x0c\
+    R\x04dataB\0\x12\x14\n\x04path\x18\x02\x20\x01(\tR\x04pathB\0\x12!\n\x0b\
+    event_count\x18\x03\x20\x01(\x03R\neventCountB\0:\0\"B\n\x11ExportSiteRe\
+    quest\x12\x17\n\x06app_id\x18\x01\x20\x01(\tR\x05appIdB\0\x12\x12\n\x03d\
+    ir\x18\x02\x20\x01(\tR\x03dirB\0:\0\"x\n\x10ExportSiteResult\x12\x14\n\
+    \x04path\x18\x01\x20\x01(\tR\x04pathB\0\x12\x1f\n\npage_count\x18\x02\
+    \x20\x01(\x03R\tpageCountB\0\x12+\n\x10attachment_count\x18\x03\x20\x01(\
+    \x03R\x0fattachmentCountB\0:\0*0\n\nExportType\x12\x08\n\x04Text\x10\0\
+    \x12\x0c\n\x08Markdown\x10\x01\x12\x08\n\x04Link\x10\x02\x1a\0B\0b\x06pr\
+    oto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,1686 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `folder_sync.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchCreateParams {
+    // message fields
+    pub apps: ::protobuf::RepeatedField<super::app_create::CreateAppParams>,
+    pub views: ::protobuf::RepeatedField<super::view_create::CreateViewParams>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchCreateParams {
+    fn default() -> &'a BatchCreateParams {
+        <BatchCreateParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchCreateParams {
+    pub fn new() -> BatchCreateParams {
+        ::std::default::Default::default()
+    }
+
+    // repeated .CreateAppParams apps = 1;
+
+
+    pub fn get_apps(&self) -> &[super::app_create::CreateAppParams] {
+        &self.apps
+    }
+    pub fn clear_apps(&mut self) {
+        self.apps.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_apps(&mut self, v: ::protobuf::RepeatedField<super::app_create::CreateAppParams>) {
+        self.apps = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_apps(&mut self) -> &mut ::protobuf::RepeatedField<super::app_create::CreateAppParams> {
+        &mut self.apps
+    }
+
+    // Take field
+    pub fn take_apps(&mut self) -> ::protobuf::RepeatedField<super::app_create::CreateAppParams> {
+        ::std::mem::replace(&mut self.apps, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .CreateViewParams views = 2;
+
+
+    pub fn get_views(&self) -> &[super::view_create::CreateViewParams] {
+        &self.views
+    }
+    pub fn clear_views(&mut self) {
+        self.views.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_views(&mut self, v: ::protobuf::RepeatedField<super::view_create::CreateViewParams>) {
+        self.views = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_views(&mut self) -> &mut ::protobuf::RepeatedField<super::view_create::CreateViewParams> {
+        &mut self.views
+    }
+
+    // Take field
+    pub fn take_views(&mut self) -> ::protobuf::RepeatedField<super::view_create::CreateViewParams> {
+        ::std::mem::replace(&mut self.views, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchCreateParams {
+    fn is_initialized(&self) -> bool {
+        for v in &self.apps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.views {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.apps)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.views)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.apps {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.views {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.apps {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.views {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchCreateParams {
+        BatchCreateParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::app_create::CreateAppParams>>(
+                "apps",
+                |m: &BatchCreateParams| { &m.apps },
+                |m: &mut BatchCreateParams| { &mut m.apps },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::CreateViewParams>>(
+                "views",
+                |m: &BatchCreateParams| { &m.views },
+                |m: &mut BatchCreateParams| { &mut m.views },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchCreateParams>(
+                "BatchCreateParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BatchCreateParams {
+        static instance: ::protobuf::rt::LazyV2<BatchCreateParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BatchCreateParams::new)
+    }
+}
+
+impl ::protobuf::Clear for BatchCreateParams {
+    fn clear(&mut self) {
+        self.apps.clear();
+        self.views.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchCreateParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchCreateParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchCreateResult {
+    // message fields
+    pub apps: ::protobuf::SingularPtrField<super::app_create::RepeatedApp>,
+    pub views: ::protobuf::SingularPtrField<super::view_create::RepeatedView>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchCreateResult {
+    fn default() -> &'a BatchCreateResult {
+        <BatchCreateResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchCreateResult {
+    pub fn new() -> BatchCreateResult {
+        ::std::default::Default::default()
+    }
+
+    // .RepeatedApp apps = 1;
+
+
+    pub fn get_apps(&self) -> &super::app_create::RepeatedApp {
+        self.apps.as_ref().unwrap_or_else(|| <super::app_create::RepeatedApp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_apps(&mut self) {
+        self.apps.clear();
+    }
+
+    pub fn has_apps(&self) -> bool {
+        self.apps.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_apps(&mut self, v: super::app_create::RepeatedApp) {
+        self.apps = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_apps(&mut self) -> &mut super::app_create::RepeatedApp {
+        if self.apps.is_none() {
+            self.apps.set_default();
+        }
+        self.apps.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_apps(&mut self) -> super::app_create::RepeatedApp {
+        self.apps.take().unwrap_or_else(|| super::app_create::RepeatedApp::new())
+    }
+
+    // .RepeatedView views = 2;
+
+
+    pub fn get_views(&self) -> &super::view_create::RepeatedView {
+        self.views.as_ref().unwrap_or_else(|| <super::view_create::RepeatedView as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_views(&mut self) {
+        self.views.clear();
+    }
+
+    pub fn has_views(&self) -> bool {
+        self.views.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_views(&mut self, v: super::view_create::RepeatedView) {
+        self.views = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_views(&mut self) -> &mut super::view_create::RepeatedView {
+        if self.views.is_none() {
+            self.views.set_default();
+        }
+        self.views.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_views(&mut self) -> super::view_create::RepeatedView {
+        self.views.take().unwrap_or_else(|| super::view_create::RepeatedView::new())
+    }
+}
+
+impl ::protobuf::Message for BatchCreateResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.apps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.views {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.apps)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.views)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.apps.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.views.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.apps.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.views.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchCreateResult {
+        BatchCreateResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::app_create::RepeatedApp>>(
+                "apps",
+                |m: &BatchCreateResult| { &m.apps },
+                |m: &mut BatchCreateResult| { &mut m.apps },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::RepeatedView>>(
+                "views",
+                |m: &BatchCreateResult| { &m.views },
+                |m: &mut BatchCreateResult| { &mut m.views },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchCreateResult>(
+                "BatchCreateResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BatchCreateResult {
+        static instance: ::protobuf::rt::LazyV2<BatchCreateResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BatchCreateResult::new)
+    }
+}
+
+impl ::protobuf::Clear for BatchCreateResult {
+    fn clear(&mut self) {
+        self.apps.clear();
+        self.views.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchCreateResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchCreateResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchReadParams {
+    // message fields
+    pub app_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub view_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchReadParams {
+    fn default() -> &'a BatchReadParams {
+        <BatchReadParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchReadParams {
+    pub fn new() -> BatchReadParams {
+        ::std::default::Default::default()
+    }
+
+    // repeated string app_ids = 1;
+
+
+    pub fn get_app_ids(&self) -> &[::std::string::String] {
+        &self.app_ids
+    }
+    pub fn clear_app_ids(&mut self) {
+        self.app_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.app_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_app_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.app_ids
+    }
+
+    // Take field
+    pub fn take_app_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.app_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string view_ids = 2;
+
+
+    pub fn get_view_ids(&self) -> &[::std::string::String] {
+        &self.view_ids
+    }
+    pub fn clear_view_ids(&mut self) {
+        self.view_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.view_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_view_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.view_ids
+    }
+
+    // Take field
+    pub fn take_view_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.view_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchReadParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.app_ids)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.view_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.app_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.view_ids {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.app_ids {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.view_ids {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchReadParams {
+        BatchReadParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "app_ids",
+                |m: &BatchReadParams| { &m.app_ids },
+                |m: &mut BatchReadParams| { &mut m.app_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_ids",
+                |m: &BatchReadParams| { &m.view_ids },
+                |m: &mut BatchReadParams| { &mut m.view_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchReadParams>(
+                "BatchReadParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BatchReadParams {
+        static instance: ::protobuf::rt::LazyV2<BatchReadParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BatchReadParams::new)
+    }
+}
+
+impl ::protobuf::Clear for BatchReadParams {
+    fn clear(&mut self) {
+        self.app_ids.clear();
+        self.view_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchReadParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchReadParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchReadResult {
+    // message fields
+    pub apps: ::protobuf::SingularPtrField<super::app_create::RepeatedApp>,
+    pub views: ::protobuf::SingularPtrField<super::view_create::RepeatedView>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchReadResult {
+    fn default() -> &'a BatchReadResult {
+        <BatchReadResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchReadResult {
+    pub fn new() -> BatchReadResult {
+        ::std::default::Default::default()
+    }
+
+    // .RepeatedApp apps = 1;
+
+
+    pub fn get_apps(&self) -> &super::app_create::RepeatedApp {
+        self.apps.as_ref().unwrap_or_else(|| <super::app_create::RepeatedApp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_apps(&mut self) {
+        self.apps.clear();
+    }
+
+    pub fn has_apps(&self) -> bool {
+        self.apps.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_apps(&mut self, v: super::app_create::RepeatedApp) {
+        self.apps = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_apps(&mut self) -> &mut super::app_create::RepeatedApp {
+        if self.apps.is_none() {
+            self.apps.set_default();
+        }
+        self.apps.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_apps(&mut self) -> super::app_create::RepeatedApp {
+        self.apps.take().unwrap_or_else(|| super::app_create::RepeatedApp::new())
+    }
+
+    // .RepeatedView views = 2;
+
+
+    pub fn get_views(&self) -> &super::view_create::RepeatedView {
+        self.views.as_ref().unwrap_or_else(|| <super::view_create::RepeatedView as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_views(&mut self) {
+        self.views.clear();
+    }
+
+    pub fn has_views(&self) -> bool {
+        self.views.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_views(&mut self, v: super::view_create::RepeatedView) {
+        self.views = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_views(&mut self) -> &mut super::view_create::RepeatedView {
+        if self.views.is_none() {
+            self.views.set_default();
+        }
+        self.views.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_views(&mut self) -> super::view_create::RepeatedView {
+        self.views.take().unwrap_or_else(|| super::view_create::RepeatedView::new())
+    }
+}
+
+impl ::protobuf::Message for BatchReadResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.apps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.views {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.apps)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.views)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.apps.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.views.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.apps.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.views.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchReadResult {
+        BatchReadResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::app_create::RepeatedApp>>(
+                "apps",
+                |m: &BatchReadResult| { &m.apps },
+                |m: &mut BatchReadResult| { &mut m.apps },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::RepeatedView>>(
+                "views",
+                |m: &BatchReadResult| { &m.views },
+                |m: &mut BatchReadResult| { &mut m.views },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchReadResult>(
+                "BatchReadResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BatchReadResult {
+        static instance: ::protobuf::rt::LazyV2<BatchReadResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BatchReadResult::new)
+    }
+}
+
+impl ::protobuf::Clear for BatchReadResult {
+    fn clear(&mut self) {
+        self.apps.clear();
+        self.views.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchReadResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchReadResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchUpdateParams {
+    // message fields
+    pub apps: ::protobuf::RepeatedField<super::app_update::UpdateAppParams>,
+    pub views: ::protobuf::RepeatedField<super::view_update::UpdateViewParams>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchUpdateParams {
+    fn default() -> &'a BatchUpdateParams {
+        <BatchUpdateParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchUpdateParams {
+    pub fn new() -> BatchUpdateParams {
+        ::std::default::Default::default()
+    }
+
+    // repeated .UpdateAppParams apps = 1;
+
+
+    pub fn get_apps(&self) -> &[super::app_update::UpdateAppParams] {
+        &self.apps
+    }
+    pub fn clear_apps(&mut self) {
+        self.apps.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_apps(&mut self, v: ::protobuf::RepeatedField<super::app_update::UpdateAppParams>) {
+        self.apps = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_apps(&mut self) -> &mut ::protobuf::RepeatedField<super::app_update::UpdateAppParams> {
+        &mut self.apps
+    }
+
+    // Take field
+    pub fn take_apps(&mut self) -> ::protobuf::RepeatedField<super::app_update::UpdateAppParams> {
+        ::std::mem::replace(&mut self.apps, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .UpdateViewParams views = 2;
+
+
+    pub fn get_views(&self) -> &[super::view_update::UpdateViewParams] {
+        &self.views
+    }
+    pub fn clear_views(&mut self) {
+        self.views.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_views(&mut self, v: ::protobuf::RepeatedField<super::view_update::UpdateViewParams>) {
+        self.views = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_views(&mut self) -> &mut ::protobuf::RepeatedField<super::view_update::UpdateViewParams> {
+        &mut self.views
+    }
+
+    // Take field
+    pub fn take_views(&mut self) -> ::protobuf::RepeatedField<super::view_update::UpdateViewParams> {
+        ::std::mem::replace(&mut self.views, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchUpdateParams {
+    fn is_initialized(&self) -> bool {
+        for v in &self.apps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.views {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.apps)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.views)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.apps {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.views {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.apps {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.views {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchUpdateParams {
+        BatchUpdateParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::app_update::UpdateAppParams>>(
+                "apps",
+                |m: &BatchUpdateParams| { &m.apps },
+                |m: &mut BatchUpdateParams| { &mut m.apps },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_update::UpdateViewParams>>(
+                "views",
+                |m: &BatchUpdateParams| { &m.views },
+                |m: &mut BatchUpdateParams| { &mut m.views },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchUpdateParams>(
+                "BatchUpdateParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BatchUpdateParams {
+        static instance: ::protobuf::rt::LazyV2<BatchUpdateParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BatchUpdateParams::new)
+    }
+}
+
+impl ::protobuf::Clear for BatchUpdateParams {
+    fn clear(&mut self) {
+        self.apps.clear();
+        self.views.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchUpdateParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchUpdateParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FolderSyncParams {
+    // message fields
+    pub cursor: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FolderSyncParams {
+    fn default() -> &'a FolderSyncParams {
+        <FolderSyncParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FolderSyncParams {
+    pub fn new() -> FolderSyncParams {
+        ::std::default::Default::default()
+    }
+
+    // int64 cursor = 1;
+
+
+    pub fn get_cursor(&self) -> i64 {
+        self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: i64) {
+        self.cursor = v;
+    }
+}
+
+impl ::protobuf::Message for FolderSyncParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cursor = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cursor != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.cursor, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.cursor != 0 {
+            os.write_int64(1, self.cursor)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FolderSyncParams {
+        FolderSyncParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cursor",
+                |m: &FolderSyncParams| { &m.cursor },
+                |m: &mut FolderSyncParams| { &mut m.cursor },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FolderSyncParams>(
+                "FolderSyncParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FolderSyncParams {
+        static instance: ::protobuf::rt::LazyV2<FolderSyncParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FolderSyncParams::new)
+    }
+}
+
+impl ::protobuf::Clear for FolderSyncParams {
+    fn clear(&mut self) {
+        self.cursor = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FolderSyncParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FolderSyncParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FolderDelta {
+    // message fields
+    pub cursor: i64,
+    pub is_snapshot: bool,
+    pub workspaces: ::protobuf::SingularPtrField<super::workspace_create::RepeatedWorkspace>,
+    pub deleted_app_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub deleted_view_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub trash: ::protobuf::SingularPtrField<super::trash_create::RepeatedTrash>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FolderDelta {
+    fn default() -> &'a FolderDelta {
+        <FolderDelta as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FolderDelta {
+    pub fn new() -> FolderDelta {
+        ::std::default::Default::default()
+    }
+
+    // int64 cursor = 1;
+
+
+    pub fn get_cursor(&self) -> i64 {
+        self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: i64) {
+        self.cursor = v;
+    }
+
+    // bool is_snapshot = 2;
+
+
+    pub fn get_is_snapshot(&self) -> bool {
+        self.is_snapshot
+    }
+    pub fn clear_is_snapshot(&mut self) {
+        self.is_snapshot = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_snapshot(&mut self, v: bool) {
+        self.is_snapshot = v;
+    }
+
+    // .RepeatedWorkspace workspaces = 3;
+
+
+    pub fn get_workspaces(&self) -> &super::workspace_create::RepeatedWorkspace {
+        self.workspaces.as_ref().unwrap_or_else(|| <super::workspace_create::RepeatedWorkspace as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_workspaces(&mut self) {
+        self.workspaces.clear();
+    }
+
+    pub fn has_workspaces(&self) -> bool {
+        self.workspaces.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspaces(&mut self, v: super::workspace_create::RepeatedWorkspace) {
+        self.workspaces = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspaces(&mut self) -> &mut super::workspace_create::RepeatedWorkspace {
+        if self.workspaces.is_none() {
+            self.workspaces.set_default();
+        }
+        self.workspaces.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_workspaces(&mut self) -> super::workspace_create::RepeatedWorkspace {
+        self.workspaces.take().unwrap_or_else(|| super::workspace_create::RepeatedWorkspace::new())
+    }
+
+    // repeated string deleted_app_ids = 4;
+
+
+    pub fn get_deleted_app_ids(&self) -> &[::std::string::String] {
+        &self.deleted_app_ids
+    }
+    pub fn clear_deleted_app_ids(&mut self) {
+        self.deleted_app_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_app_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.deleted_app_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deleted_app_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.deleted_app_ids
+    }
+
+    // Take field
+    pub fn take_deleted_app_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.deleted_app_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string deleted_view_ids = 5;
+
+
+    pub fn get_deleted_view_ids(&self) -> &[::std::string::String] {
+        &self.deleted_view_ids
+    }
+    pub fn clear_deleted_view_ids(&mut self) {
+        self.deleted_view_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_view_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.deleted_view_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deleted_view_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.deleted_view_ids
+    }
+
+    // Take field
+    pub fn take_deleted_view_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.deleted_view_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // .RepeatedTrash trash = 6;
+
+
+    pub fn get_trash(&self) -> &super::trash_create::RepeatedTrash {
+        self.trash.as_ref().unwrap_or_else(|| <super::trash_create::RepeatedTrash as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_trash(&mut self) {
+        self.trash.clear();
+    }
+
+    pub fn has_trash(&self) -> bool {
+        self.trash.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trash(&mut self, v: super::trash_create::RepeatedTrash) {
+        self.trash = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_trash(&mut self) -> &mut super::trash_create::RepeatedTrash {
+        if self.trash.is_none() {
+            self.trash.set_default();
+        }
+        self.trash.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_trash(&mut self) -> super::trash_create::RepeatedTrash {
+        self.trash.take().unwrap_or_else(|| super::trash_create::RepeatedTrash::new())
+    }
+}
+
+impl ::protobuf::Message for FolderDelta {
+    fn is_initialized(&self) -> bool {
+        for v in &self.workspaces {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.trash {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.cursor = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_snapshot = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.workspaces)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.deleted_app_ids)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.deleted_view_ids)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.trash)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cursor != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.cursor, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_snapshot != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.workspaces.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.deleted_app_ids {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in &self.deleted_view_ids {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        if let Some(ref v) = self.trash.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.cursor != 0 {
+            os.write_int64(1, self.cursor)?;
+        }
+        if self.is_snapshot != false {
+            os.write_bool(2, self.is_snapshot)?;
+        }
+        if let Some(ref v) = self.workspaces.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.deleted_app_ids {
+            os.write_string(4, &v)?;
+        };
+        for v in &self.deleted_view_ids {
+            os.write_string(5, &v)?;
+        };
+        if let Some(ref v) = self.trash.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FolderDelta {
+        FolderDelta::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "cursor",
+                |m: &FolderDelta| { &m.cursor },
+                |m: &mut FolderDelta| { &mut m.cursor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_snapshot",
+                |m: &FolderDelta| { &m.is_snapshot },
+                |m: &mut FolderDelta| { &mut m.is_snapshot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::workspace_create::RepeatedWorkspace>>(
+                "workspaces",
+                |m: &FolderDelta| { &m.workspaces },
+                |m: &mut FolderDelta| { &mut m.workspaces },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deleted_app_ids",
+                |m: &FolderDelta| { &m.deleted_app_ids },
+                |m: &mut FolderDelta| { &mut m.deleted_app_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deleted_view_ids",
+                |m: &FolderDelta| { &m.deleted_view_ids },
+                |m: &mut FolderDelta| { &mut m.deleted_view_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::trash_create::RepeatedTrash>>(
+                "trash",
+                |m: &FolderDelta| { &m.trash },
+                |m: &mut FolderDelta| { &mut m.trash },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FolderDelta>(
+                "FolderDelta",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FolderDelta {
+        static instance: ::protobuf::rt::LazyV2<FolderDelta> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FolderDelta::new)
+    }
+}
+
+impl ::protobuf::Clear for FolderDelta {
+    fn clear(&mut self) {
+        self.cursor = 0;
+        self.is_snapshot = false;
+        self.workspaces.clear();
+        self.deleted_app_ids.clear();
+        self.deleted_view_ids.clear();
+        self.trash.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FolderDelta {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FolderDelta {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x11folder_sync.proto\x1a\x10app_create.proto\x1a\x10app_update.proto\
+    \x1a\x12trash_create.proto\x1a\x11view_create.proto\x1a\x11view_update.p\
+    roto\x1a\x16workspace_create.proto\"h\n\x11BatchCreateParams\x12&\n\x04a\
+    pps\x18\x01\x20\x03(\x0b2\x10.CreateAppParamsR\x04appsB\0\x12)\n\x05view\
+    s\x18\x02\x20\x03(\x0b2\x11.CreateViewParamsR\x05viewsB\0:\0\"`\n\x11Bat\
+    chCreateResult\x12\"\n\x04apps\x18\x01\x20\x01(\x0b2\x0c.RepeatedAppR\
+    \x04appsB\0\x12%\n\x05views\x18\x02\x20\x01(\x0b2\r.RepeatedViewR\x05vie\
+    wsB\0:\0\"K\n\x0fBatchReadParams\x12\x19\n\x07app_ids\x18\x01\x20\x03(\t\
+    R\x06appIdsB\0\x12\x1b\n\x08view_ids\x18\x02\x20\x03(\tR\x07viewIdsB\0:\
+    \0\"^\n\x0fBatchReadResult\x12\"\n\x04apps\x18\x01\x20\x01(\x0b2\x0c.Rep\
+    eatedAppR\x04appsB\0\x12%\n\x05views\x18\x02\x20\x01(\x0b2\r.RepeatedVie\
+    wR\x05viewsB\0:\0\"h\n\x11BatchUpdateParams\x12&\n\x04apps\x18\x01\x20\
+    \x03(\x0b2\x10.UpdateAppParamsR\x04appsB\0\x12)\n\x05views\x18\x02\x20\
+    \x03(\x0b2\x11.UpdateViewParamsR\x05viewsB\0:\0\".\n\x10FolderSyncParams\
+    \x12\x18\n\x06cursor\x18\x01\x20\x01(\x03R\x06cursorB\0:\0\"\x80\x02\n\
+    \x0bFolderDelta\x12\x18\n\x06cursor\x18\x01\x20\x01(\x03R\x06cursorB\0\
+    \x12!\n\x0bis_snapshot\x18\x02\x20\x01(\x08R\nisSnapshotB\0\x124\n\nwork\
+    spaces\x18\x03\x20\x01(\x0b2\x12.RepeatedWorkspaceR\nworkspacesB\0\x12(\
+    \n\x0fdeleted_app_ids\x18\x04\x20\x03(\tR\rdeletedAppIdsB\0\x12*\n\x10de\
+    leted_view_ids\x18\x05\x20\x03(\tR\x0edeletedViewIdsB\0\x12&\n\x05trash\
+    \x18\x06\x20\x01(\x0b2\x0e.RepeatedTrashR\x05trashB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
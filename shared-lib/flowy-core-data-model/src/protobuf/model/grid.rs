@@ -0,0 +1,3125 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `grid.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Grid {
+    // message fields
+    pub id: ::std::string::String,
+    pub fields: ::protobuf::RepeatedField<Field>,
+    pub rows: ::protobuf::RepeatedField<Row>,
+    pub group_field_id: ::std::string::String,
+    pub date_field_id: ::std::string::String,
+    pub setting: ::protobuf::SingularPtrField<super::grid_setting::GridSetting>,
+    pub linked_grid_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Grid {
+    fn default() -> &'a Grid {
+        <Grid as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Grid {
+    pub fn new() -> Grid {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // repeated .Field fields = 2;
+
+
+    pub fn get_fields(&self) -> &[Field] {
+        &self.fields
+    }
+    pub fn clear_fields(&mut self) {
+        self.fields.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fields(&mut self, v: ::protobuf::RepeatedField<Field>) {
+        self.fields = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fields(&mut self) -> &mut ::protobuf::RepeatedField<Field> {
+        &mut self.fields
+    }
+
+    // Take field
+    pub fn take_fields(&mut self) -> ::protobuf::RepeatedField<Field> {
+        ::std::mem::replace(&mut self.fields, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .Row rows = 3;
+
+
+    pub fn get_rows(&self) -> &[Row] {
+        &self.rows
+    }
+    pub fn clear_rows(&mut self) {
+        self.rows.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rows(&mut self, v: ::protobuf::RepeatedField<Row>) {
+        self.rows = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rows(&mut self) -> &mut ::protobuf::RepeatedField<Row> {
+        &mut self.rows
+    }
+
+    // Take field
+    pub fn take_rows(&mut self) -> ::protobuf::RepeatedField<Row> {
+        ::std::mem::replace(&mut self.rows, ::protobuf::RepeatedField::new())
+    }
+
+    // string group_field_id = 4;
+
+
+    pub fn get_group_field_id(&self) -> &str {
+        &self.group_field_id
+    }
+    pub fn clear_group_field_id(&mut self) {
+        self.group_field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group_field_id(&mut self, v: ::std::string::String) {
+        self.group_field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_group_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.group_field_id
+    }
+
+    // Take field
+    pub fn take_group_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.group_field_id, ::std::string::String::new())
+    }
+
+    // string date_field_id = 5;
+
+
+    pub fn get_date_field_id(&self) -> &str {
+        &self.date_field_id
+    }
+    pub fn clear_date_field_id(&mut self) {
+        self.date_field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date_field_id(&mut self, v: ::std::string::String) {
+        self.date_field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_date_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.date_field_id
+    }
+
+    // Take field
+    pub fn take_date_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.date_field_id, ::std::string::String::new())
+    }
+
+    // .GridSetting setting = 6;
+
+
+    pub fn get_setting(&self) -> &super::grid_setting::GridSetting {
+        self.setting.as_ref().unwrap_or_else(|| <super::grid_setting::GridSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_setting(&mut self) {
+        self.setting.clear();
+    }
+
+    pub fn has_setting(&self) -> bool {
+        self.setting.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_setting(&mut self, v: super::grid_setting::GridSetting) {
+        self.setting = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_setting(&mut self) -> &mut super::grid_setting::GridSetting {
+        if self.setting.is_none() {
+            self.setting.set_default();
+        }
+        self.setting.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_setting(&mut self) -> super::grid_setting::GridSetting {
+        self.setting.take().unwrap_or_else(|| super::grid_setting::GridSetting::new())
+    }
+
+    // repeated string linked_grid_ids = 7;
+
+
+    pub fn get_linked_grid_ids(&self) -> &[::std::string::String] {
+        &self.linked_grid_ids
+    }
+    pub fn clear_linked_grid_ids(&mut self) {
+        self.linked_grid_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_linked_grid_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.linked_grid_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_linked_grid_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.linked_grid_ids
+    }
+
+    // Take field
+    pub fn take_linked_grid_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.linked_grid_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Grid {
+    fn is_initialized(&self) -> bool {
+        for v in &self.fields {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rows {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.setting {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.fields)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.rows)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.group_field_id)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.date_field_id)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.setting)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.linked_grid_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        for value in &self.fields {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.rows {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.group_field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.group_field_id);
+        }
+        if !self.date_field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.date_field_id);
+        }
+        if let Some(ref v) = self.setting.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.linked_grid_ids {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        for v in &self.fields {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.rows {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.group_field_id.is_empty() {
+            os.write_string(4, &self.group_field_id)?;
+        }
+        if !self.date_field_id.is_empty() {
+            os.write_string(5, &self.date_field_id)?;
+        }
+        if let Some(ref v) = self.setting.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.linked_grid_ids {
+            os.write_string(7, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Grid {
+        Grid::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &Grid| { &m.id },
+                |m: &mut Grid| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Field>>(
+                "fields",
+                |m: &Grid| { &m.fields },
+                |m: &mut Grid| { &mut m.fields },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Row>>(
+                "rows",
+                |m: &Grid| { &m.rows },
+                |m: &mut Grid| { &mut m.rows },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "group_field_id",
+                |m: &Grid| { &m.group_field_id },
+                |m: &mut Grid| { &mut m.group_field_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "date_field_id",
+                |m: &Grid| { &m.date_field_id },
+                |m: &mut Grid| { &mut m.date_field_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::grid_setting::GridSetting>>(
+                "setting",
+                |m: &Grid| { &m.setting },
+                |m: &mut Grid| { &mut m.setting },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "linked_grid_ids",
+                |m: &Grid| { &m.linked_grid_ids },
+                |m: &mut Grid| { &mut m.linked_grid_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Grid>(
+                "Grid",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Grid {
+        static instance: ::protobuf::rt::LazyV2<Grid> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Grid::new)
+    }
+}
+
+impl ::protobuf::Clear for Grid {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.fields.clear();
+        self.rows.clear();
+        self.group_field_id.clear();
+        self.date_field_id.clear();
+        self.setting.clear();
+        self.linked_grid_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Grid {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Grid {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Field {
+    // message fields
+    pub id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub field_type: FieldType,
+    pub select_options: ::protobuf::RepeatedField<SelectOption>,
+    pub formula: ::std::string::String,
+    pub related_grid_id: ::std::string::String,
+    pub rollup: ::protobuf::SingularPtrField<RollupSetting>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Field {
+    fn default() -> &'a Field {
+        <Field as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Field {
+    pub fn new() -> Field {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // .FieldType field_type = 3;
+
+
+    pub fn get_field_type(&self) -> FieldType {
+        self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type = FieldType::RichText;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: FieldType) {
+        self.field_type = v;
+    }
+
+    // repeated .SelectOption select_options = 4;
+
+
+    pub fn get_select_options(&self) -> &[SelectOption] {
+        &self.select_options
+    }
+    pub fn clear_select_options(&mut self) {
+        self.select_options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_select_options(&mut self, v: ::protobuf::RepeatedField<SelectOption>) {
+        self.select_options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_select_options(&mut self) -> &mut ::protobuf::RepeatedField<SelectOption> {
+        &mut self.select_options
+    }
+
+    // Take field
+    pub fn take_select_options(&mut self) -> ::protobuf::RepeatedField<SelectOption> {
+        ::std::mem::replace(&mut self.select_options, ::protobuf::RepeatedField::new())
+    }
+
+    // string formula = 5;
+
+
+    pub fn get_formula(&self) -> &str {
+        &self.formula
+    }
+    pub fn clear_formula(&mut self) {
+        self.formula.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_formula(&mut self, v: ::std::string::String) {
+        self.formula = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_formula(&mut self) -> &mut ::std::string::String {
+        &mut self.formula
+    }
+
+    // Take field
+    pub fn take_formula(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.formula, ::std::string::String::new())
+    }
+
+    // string related_grid_id = 6;
+
+
+    pub fn get_related_grid_id(&self) -> &str {
+        &self.related_grid_id
+    }
+    pub fn clear_related_grid_id(&mut self) {
+        self.related_grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_related_grid_id(&mut self, v: ::std::string::String) {
+        self.related_grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_related_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.related_grid_id
+    }
+
+    // Take field
+    pub fn take_related_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.related_grid_id, ::std::string::String::new())
+    }
+
+    // .RollupSetting rollup = 7;
+
+
+    pub fn get_rollup(&self) -> &RollupSetting {
+        self.rollup.as_ref().unwrap_or_else(|| <RollupSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rollup(&mut self) {
+        self.rollup.clear();
+    }
+
+    pub fn has_rollup(&self) -> bool {
+        self.rollup.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rollup(&mut self, v: RollupSetting) {
+        self.rollup = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rollup(&mut self) -> &mut RollupSetting {
+        if self.rollup.is_none() {
+            self.rollup.set_default();
+        }
+        self.rollup.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rollup(&mut self) -> RollupSetting {
+        self.rollup.take().unwrap_or_else(|| RollupSetting::new())
+    }
+}
+
+impl ::protobuf::Message for Field {
+    fn is_initialized(&self) -> bool {
+        for v in &self.select_options {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rollup {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.select_options)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.formula)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.related_grid_id)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rollup)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.field_type != FieldType::RichText {
+            my_size += ::protobuf::rt::enum_size(3, self.field_type);
+        }
+        for value in &self.select_options {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.formula.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.formula);
+        }
+        if !self.related_grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.related_grid_id);
+        }
+        if let Some(ref v) = self.rollup.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.field_type != FieldType::RichText {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.field_type))?;
+        }
+        for v in &self.select_options {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.formula.is_empty() {
+            os.write_string(5, &self.formula)?;
+        }
+        if !self.related_grid_id.is_empty() {
+            os.write_string(6, &self.related_grid_id)?;
+        }
+        if let Some(ref v) = self.rollup.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Field {
+        Field::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &Field| { &m.id },
+                |m: &mut Field| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Field| { &m.name },
+                |m: &mut Field| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FieldType>>(
+                "field_type",
+                |m: &Field| { &m.field_type },
+                |m: &mut Field| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SelectOption>>(
+                "select_options",
+                |m: &Field| { &m.select_options },
+                |m: &mut Field| { &mut m.select_options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "formula",
+                |m: &Field| { &m.formula },
+                |m: &mut Field| { &mut m.formula },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "related_grid_id",
+                |m: &Field| { &m.related_grid_id },
+                |m: &mut Field| { &mut m.related_grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RollupSetting>>(
+                "rollup",
+                |m: &Field| { &m.rollup },
+                |m: &mut Field| { &mut m.rollup },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Field>(
+                "Field",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Field {
+        static instance: ::protobuf::rt::LazyV2<Field> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Field::new)
+    }
+}
+
+impl ::protobuf::Clear for Field {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.name.clear();
+        self.field_type = FieldType::RichText;
+        self.select_options.clear();
+        self.formula.clear();
+        self.related_grid_id.clear();
+        self.rollup.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Field {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Field {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SelectOption {
+    // message fields
+    pub id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub color: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SelectOption {
+    fn default() -> &'a SelectOption {
+        <SelectOption as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SelectOption {
+    pub fn new() -> SelectOption {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string color = 3;
+
+
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+    pub fn clear_color(&mut self) {
+        self.color.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: ::std::string::String) {
+        self.color = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color(&mut self) -> &mut ::std::string::String {
+        &mut self.color
+    }
+
+    // Take field
+    pub fn take_color(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.color, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SelectOption {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.color)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if !self.color.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.color);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if !self.color.is_empty() {
+            os.write_string(3, &self.color)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SelectOption {
+        SelectOption::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &SelectOption| { &m.id },
+                |m: &mut SelectOption| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &SelectOption| { &m.name },
+                |m: &mut SelectOption| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "color",
+                |m: &SelectOption| { &m.color },
+                |m: &mut SelectOption| { &mut m.color },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SelectOption>(
+                "SelectOption",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SelectOption {
+        static instance: ::protobuf::rt::LazyV2<SelectOption> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SelectOption::new)
+    }
+}
+
+impl ::protobuf::Clear for SelectOption {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.name.clear();
+        self.color.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SelectOption {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SelectOption {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RollupSetting {
+    // message fields
+    pub relation_field_id: ::std::string::String,
+    pub target_field_id: ::std::string::String,
+    pub function: RollupFunction,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RollupSetting {
+    fn default() -> &'a RollupSetting {
+        <RollupSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RollupSetting {
+    pub fn new() -> RollupSetting {
+        ::std::default::Default::default()
+    }
+
+    // string relation_field_id = 1;
+
+
+    pub fn get_relation_field_id(&self) -> &str {
+        &self.relation_field_id
+    }
+    pub fn clear_relation_field_id(&mut self) {
+        self.relation_field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_relation_field_id(&mut self, v: ::std::string::String) {
+        self.relation_field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_relation_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.relation_field_id
+    }
+
+    // Take field
+    pub fn take_relation_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.relation_field_id, ::std::string::String::new())
+    }
+
+    // string target_field_id = 2;
+
+
+    pub fn get_target_field_id(&self) -> &str {
+        &self.target_field_id
+    }
+    pub fn clear_target_field_id(&mut self) {
+        self.target_field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_field_id(&mut self, v: ::std::string::String) {
+        self.target_field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.target_field_id
+    }
+
+    // Take field
+    pub fn take_target_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_field_id, ::std::string::String::new())
+    }
+
+    // .RollupFunction function = 3;
+
+
+    pub fn get_function(&self) -> RollupFunction {
+        self.function
+    }
+    pub fn clear_function(&mut self) {
+        self.function = RollupFunction::Count;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_function(&mut self, v: RollupFunction) {
+        self.function = v;
+    }
+}
+
+impl ::protobuf::Message for RollupSetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.relation_field_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_field_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.function, 3, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.relation_field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.relation_field_id);
+        }
+        if !self.target_field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.target_field_id);
+        }
+        if self.function != RollupFunction::Count {
+            my_size += ::protobuf::rt::enum_size(3, self.function);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.relation_field_id.is_empty() {
+            os.write_string(1, &self.relation_field_id)?;
+        }
+        if !self.target_field_id.is_empty() {
+            os.write_string(2, &self.target_field_id)?;
+        }
+        if self.function != RollupFunction::Count {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.function))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RollupSetting {
+        RollupSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "relation_field_id",
+                |m: &RollupSetting| { &m.relation_field_id },
+                |m: &mut RollupSetting| { &mut m.relation_field_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_field_id",
+                |m: &RollupSetting| { &m.target_field_id },
+                |m: &mut RollupSetting| { &mut m.target_field_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RollupFunction>>(
+                "function",
+                |m: &RollupSetting| { &m.function },
+                |m: &mut RollupSetting| { &mut m.function },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RollupSetting>(
+                "RollupSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RollupSetting {
+        static instance: ::protobuf::rt::LazyV2<RollupSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RollupSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for RollupSetting {
+    fn clear(&mut self) {
+        self.relation_field_id.clear();
+        self.target_field_id.clear();
+        self.function = RollupFunction::Count;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RollupSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RollupSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedSelectOption {
+    // message fields
+    pub items: ::protobuf::RepeatedField<SelectOption>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedSelectOption {
+    fn default() -> &'a RepeatedSelectOption {
+        <RepeatedSelectOption as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedSelectOption {
+    pub fn new() -> RepeatedSelectOption {
+        ::std::default::Default::default()
+    }
+
+    // repeated .SelectOption items = 1;
+
+
+    pub fn get_items(&self) -> &[SelectOption] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<SelectOption>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<SelectOption> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<SelectOption> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedSelectOption {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedSelectOption {
+        RepeatedSelectOption::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SelectOption>>(
+                "items",
+                |m: &RepeatedSelectOption| { &m.items },
+                |m: &mut RepeatedSelectOption| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedSelectOption>(
+                "RepeatedSelectOption",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedSelectOption {
+        static instance: ::protobuf::rt::LazyV2<RepeatedSelectOption> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedSelectOption::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedSelectOption {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedSelectOption {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedSelectOption {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Row {
+    // message fields
+    pub id: ::std::string::String,
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Row {
+    fn default() -> &'a Row {
+        <Row as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Row {
+    pub fn new() -> Row {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // repeated .Cell cells = 2;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Row {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        for v in &self.cells {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Row {
+        Row::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &Row| { &m.id },
+                |m: &mut Row| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &Row| { &m.cells },
+                |m: &mut Row| { &mut m.cells },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Row>(
+                "Row",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Row {
+        static instance: ::protobuf::rt::LazyV2<Row> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Row::new)
+    }
+}
+
+impl ::protobuf::Clear for Row {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.cells.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Row {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Row {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Cell {
+    // message fields
+    pub field_id: ::std::string::String,
+    pub data: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Cell {
+    fn default() -> &'a Cell {
+        <Cell as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Cell {
+    pub fn new() -> Cell {
+        ::std::default::Default::default()
+    }
+
+    // string field_id = 1;
+
+
+    pub fn get_field_id(&self) -> &str {
+        &self.field_id
+    }
+    pub fn clear_field_id(&mut self) {
+        self.field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_id(&mut self, v: ::std::string::String) {
+        self.field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.field_id
+    }
+
+    // Take field
+    pub fn take_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_id, ::std::string::String::new())
+    }
+
+    // string data = 2;
+
+
+    pub fn get_data(&self) -> &str {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::string::String) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::string::String {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.data, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Cell {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_id);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field_id.is_empty() {
+            os.write_string(1, &self.field_id)?;
+        }
+        if !self.data.is_empty() {
+            os.write_string(2, &self.data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Cell {
+        Cell::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field_id",
+                |m: &Cell| { &m.field_id },
+                |m: &mut Cell| { &mut m.field_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "data",
+                |m: &Cell| { &m.data },
+                |m: &mut Cell| { &mut m.data },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Cell>(
+                "Cell",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Cell {
+        static instance: ::protobuf::rt::LazyV2<Cell> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Cell::new)
+    }
+}
+
+impl ::protobuf::Clear for Cell {
+    fn clear(&mut self) {
+        self.field_id.clear();
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Cell {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Cell {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GridChangeset {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub updated_fields: ::protobuf::RepeatedField<Field>,
+    pub deleted_field_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub inserted_rows: ::protobuf::RepeatedField<IndexedRow>,
+    pub updated_rows: ::protobuf::RepeatedField<Row>,
+    pub deleted_row_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GridChangeset {
+    fn default() -> &'a GridChangeset {
+        <GridChangeset as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GridChangeset {
+    pub fn new() -> GridChangeset {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // repeated .Field updated_fields = 2;
+
+
+    pub fn get_updated_fields(&self) -> &[Field] {
+        &self.updated_fields
+    }
+    pub fn clear_updated_fields(&mut self) {
+        self.updated_fields.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_updated_fields(&mut self, v: ::protobuf::RepeatedField<Field>) {
+        self.updated_fields = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_updated_fields(&mut self) -> &mut ::protobuf::RepeatedField<Field> {
+        &mut self.updated_fields
+    }
+
+    // Take field
+    pub fn take_updated_fields(&mut self) -> ::protobuf::RepeatedField<Field> {
+        ::std::mem::replace(&mut self.updated_fields, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string deleted_field_ids = 3;
+
+
+    pub fn get_deleted_field_ids(&self) -> &[::std::string::String] {
+        &self.deleted_field_ids
+    }
+    pub fn clear_deleted_field_ids(&mut self) {
+        self.deleted_field_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_field_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.deleted_field_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deleted_field_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.deleted_field_ids
+    }
+
+    // Take field
+    pub fn take_deleted_field_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.deleted_field_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .IndexedRow inserted_rows = 4;
+
+
+    pub fn get_inserted_rows(&self) -> &[IndexedRow] {
+        &self.inserted_rows
+    }
+    pub fn clear_inserted_rows(&mut self) {
+        self.inserted_rows.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_inserted_rows(&mut self, v: ::protobuf::RepeatedField<IndexedRow>) {
+        self.inserted_rows = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_inserted_rows(&mut self) -> &mut ::protobuf::RepeatedField<IndexedRow> {
+        &mut self.inserted_rows
+    }
+
+    // Take field
+    pub fn take_inserted_rows(&mut self) -> ::protobuf::RepeatedField<IndexedRow> {
+        ::std::mem::replace(&mut self.inserted_rows, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .Row updated_rows = 5;
+
+
+    pub fn get_updated_rows(&self) -> &[Row] {
+        &self.updated_rows
+    }
+    pub fn clear_updated_rows(&mut self) {
+        self.updated_rows.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_updated_rows(&mut self, v: ::protobuf::RepeatedField<Row>) {
+        self.updated_rows = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_updated_rows(&mut self) -> &mut ::protobuf::RepeatedField<Row> {
+        &mut self.updated_rows
+    }
+
+    // Take field
+    pub fn take_updated_rows(&mut self) -> ::protobuf::RepeatedField<Row> {
+        ::std::mem::replace(&mut self.updated_rows, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string deleted_row_ids = 6;
+
+
+    pub fn get_deleted_row_ids(&self) -> &[::std::string::String] {
+        &self.deleted_row_ids
+    }
+    pub fn clear_deleted_row_ids(&mut self) {
+        self.deleted_row_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_row_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.deleted_row_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deleted_row_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.deleted_row_ids
+    }
+
+    // Take field
+    pub fn take_deleted_row_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.deleted_row_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GridChangeset {
+    fn is_initialized(&self) -> bool {
+        for v in &self.updated_fields {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.inserted_rows {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.updated_rows {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.updated_fields)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.deleted_field_ids)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.inserted_rows)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.updated_rows)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.deleted_row_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        for value in &self.updated_fields {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.deleted_field_ids {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.inserted_rows {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.updated_rows {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.deleted_row_ids {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        for v in &self.updated_fields {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.deleted_field_ids {
+            os.write_string(3, &v)?;
+        };
+        for v in &self.inserted_rows {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.updated_rows {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.deleted_row_ids {
+            os.write_string(6, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GridChangeset {
+        GridChangeset::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &GridChangeset| { &m.grid_id },
+                |m: &mut GridChangeset| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Field>>(
+                "updated_fields",
+                |m: &GridChangeset| { &m.updated_fields },
+                |m: &mut GridChangeset| { &mut m.updated_fields },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deleted_field_ids",
+                |m: &GridChangeset| { &m.deleted_field_ids },
+                |m: &mut GridChangeset| { &mut m.deleted_field_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<IndexedRow>>(
+                "inserted_rows",
+                |m: &GridChangeset| { &m.inserted_rows },
+                |m: &mut GridChangeset| { &mut m.inserted_rows },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Row>>(
+                "updated_rows",
+                |m: &GridChangeset| { &m.updated_rows },
+                |m: &mut GridChangeset| { &mut m.updated_rows },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deleted_row_ids",
+                |m: &GridChangeset| { &m.deleted_row_ids },
+                |m: &mut GridChangeset| { &mut m.deleted_row_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GridChangeset>(
+                "GridChangeset",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GridChangeset {
+        static instance: ::protobuf::rt::LazyV2<GridChangeset> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GridChangeset::new)
+    }
+}
+
+impl ::protobuf::Clear for GridChangeset {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.updated_fields.clear();
+        self.deleted_field_ids.clear();
+        self.inserted_rows.clear();
+        self.updated_rows.clear();
+        self.deleted_row_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GridChangeset {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GridChangeset {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct IndexedRow {
+    // message fields
+    pub row: ::protobuf::SingularPtrField<Row>,
+    pub index: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a IndexedRow {
+    fn default() -> &'a IndexedRow {
+        <IndexedRow as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl IndexedRow {
+    pub fn new() -> IndexedRow {
+        ::std::default::Default::default()
+    }
+
+    // .Row row = 1;
+
+
+    pub fn get_row(&self) -> &Row {
+        self.row.as_ref().unwrap_or_else(|| <Row as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_row(&mut self) {
+        self.row.clear();
+    }
+
+    pub fn has_row(&self) -> bool {
+        self.row.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row(&mut self, v: Row) {
+        self.row = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_row(&mut self) -> &mut Row {
+        if self.row.is_none() {
+            self.row.set_default();
+        }
+        self.row.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_row(&mut self) -> Row {
+        self.row.take().unwrap_or_else(|| Row::new())
+    }
+
+    // int32 index = 2;
+
+
+    pub fn get_index(&self) -> i32 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i32) {
+        self.index = v;
+    }
+}
+
+impl ::protobuf::Message for IndexedRow {
+    fn is_initialized(&self) -> bool {
+        for v in &self.row {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.row)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.row.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.row.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.index != 0 {
+            os.write_int32(2, self.index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> IndexedRow {
+        IndexedRow::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Row>>(
+                "row",
+                |m: &IndexedRow| { &m.row },
+                |m: &mut IndexedRow| { &mut m.row },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "index",
+                |m: &IndexedRow| { &m.index },
+                |m: &mut IndexedRow| { &mut m.index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<IndexedRow>(
+                "IndexedRow",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static IndexedRow {
+        static instance: ::protobuf::rt::LazyV2<IndexedRow> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(IndexedRow::new)
+    }
+}
+
+impl ::protobuf::Clear for IndexedRow {
+    fn clear(&mut self) {
+        self.row.clear();
+        self.index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for IndexedRow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for IndexedRow {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedRow {
+    // message fields
+    pub items: ::protobuf::RepeatedField<Row>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedRow {
+    fn default() -> &'a RepeatedRow {
+        <RepeatedRow as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedRow {
+    pub fn new() -> RepeatedRow {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Row items = 1;
+
+
+    pub fn get_items(&self) -> &[Row] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<Row>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<Row> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<Row> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedRow {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedRow {
+        RepeatedRow::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Row>>(
+                "items",
+                |m: &RepeatedRow| { &m.items },
+                |m: &mut RepeatedRow| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedRow>(
+                "RepeatedRow",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedRow {
+        static instance: ::protobuf::rt::LazyV2<RepeatedRow> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedRow::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedRow {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedRow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedRow {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RowVisibilityChangeset {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub visible_rows: ::protobuf::RepeatedField<IndexedRow>,
+    pub hidden_row_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RowVisibilityChangeset {
+    fn default() -> &'a RowVisibilityChangeset {
+        <RowVisibilityChangeset as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RowVisibilityChangeset {
+    pub fn new() -> RowVisibilityChangeset {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // repeated .IndexedRow visible_rows = 2;
+
+
+    pub fn get_visible_rows(&self) -> &[IndexedRow] {
+        &self.visible_rows
+    }
+    pub fn clear_visible_rows(&mut self) {
+        self.visible_rows.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_visible_rows(&mut self, v: ::protobuf::RepeatedField<IndexedRow>) {
+        self.visible_rows = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_visible_rows(&mut self) -> &mut ::protobuf::RepeatedField<IndexedRow> {
+        &mut self.visible_rows
+    }
+
+    // Take field
+    pub fn take_visible_rows(&mut self) -> ::protobuf::RepeatedField<IndexedRow> {
+        ::std::mem::replace(&mut self.visible_rows, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string hidden_row_ids = 3;
+
+
+    pub fn get_hidden_row_ids(&self) -> &[::std::string::String] {
+        &self.hidden_row_ids
+    }
+    pub fn clear_hidden_row_ids(&mut self) {
+        self.hidden_row_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hidden_row_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.hidden_row_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_hidden_row_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.hidden_row_ids
+    }
+
+    // Take field
+    pub fn take_hidden_row_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.hidden_row_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RowVisibilityChangeset {
+    fn is_initialized(&self) -> bool {
+        for v in &self.visible_rows {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.visible_rows)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.hidden_row_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        for value in &self.visible_rows {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.hidden_row_ids {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        for v in &self.visible_rows {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.hidden_row_ids {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RowVisibilityChangeset {
+        RowVisibilityChangeset::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &RowVisibilityChangeset| { &m.grid_id },
+                |m: &mut RowVisibilityChangeset| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<IndexedRow>>(
+                "visible_rows",
+                |m: &RowVisibilityChangeset| { &m.visible_rows },
+                |m: &mut RowVisibilityChangeset| { &mut m.visible_rows },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "hidden_row_ids",
+                |m: &RowVisibilityChangeset| { &m.hidden_row_ids },
+                |m: &mut RowVisibilityChangeset| { &mut m.hidden_row_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RowVisibilityChangeset>(
+                "RowVisibilityChangeset",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RowVisibilityChangeset {
+        static instance: ::protobuf::rt::LazyV2<RowVisibilityChangeset> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RowVisibilityChangeset::new)
+    }
+}
+
+impl ::protobuf::Clear for RowVisibilityChangeset {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.visible_rows.clear();
+        self.hidden_row_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RowVisibilityChangeset {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RowVisibilityChangeset {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum FieldType {
+    RichText = 0,
+    Number = 1,
+    DateTime = 2,
+    SingleSelect = 3,
+    Formula = 4,
+    Relation = 5,
+    Rollup = 6,
+}
+
+impl ::protobuf::ProtobufEnum for FieldType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<FieldType> {
+        match value {
+            0 => ::std::option::Option::Some(FieldType::RichText),
+            1 => ::std::option::Option::Some(FieldType::Number),
+            2 => ::std::option::Option::Some(FieldType::DateTime),
+            3 => ::std::option::Option::Some(FieldType::SingleSelect),
+            4 => ::std::option::Option::Some(FieldType::Formula),
+            5 => ::std::option::Option::Some(FieldType::Relation),
+            6 => ::std::option::Option::Some(FieldType::Rollup),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [FieldType] = &[
+            FieldType::RichText,
+            FieldType::Number,
+            FieldType::DateTime,
+            FieldType::SingleSelect,
+            FieldType::Formula,
+            FieldType::Relation,
+            FieldType::Rollup,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<FieldType>("FieldType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for FieldType {
+}
+
+impl ::std::default::Default for FieldType {
+    fn default() -> Self {
+        FieldType::RichText
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FieldType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum RollupFunction {
+    Count = 0,
+    Sum = 1,
+    Min = 2,
+    Max = 3,
+}
+
+impl ::protobuf::ProtobufEnum for RollupFunction {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<RollupFunction> {
+        match value {
+            0 => ::std::option::Option::Some(RollupFunction::Count),
+            1 => ::std::option::Option::Some(RollupFunction::Sum),
+            2 => ::std::option::Option::Some(RollupFunction::Min),
+            3 => ::std::option::Option::Some(RollupFunction::Max),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [RollupFunction] = &[
+            RollupFunction::Count,
+            RollupFunction::Sum,
+            RollupFunction::Min,
+            RollupFunction::Max,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<RollupFunction>("RollupFunction", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for RollupFunction {
+}
+
+impl ::std::default::Default for RollupFunction {
+    fn default() -> Self {
+        RollupFunction::Count
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RollupFunction {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\ngrid.proto\x1a\x12grid_setting.proto\"\xfa\x01\n\x04Grid\x12\x10\n\
+    \x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x20\n\x06fields\x18\x02\x20\x03(\
+    \x0b2\x06.FieldR\x06fieldsB\0\x12\x1a\n\x04rows\x18\x03\x20\x03(\x0b2\
+    \x04.RowR\x04rowsB\0\x12&\n\x0egroup_field_id\x18\x04\x20\x01(\tR\x0cgro\
+    upFieldIdB\0\x12$\n\rdate_field_id\x18\x05\x20\x01(\tR\x0bdateFieldIdB\0\
+    \x12(\n\x07setting\x18\x06\x20\x01(\x0b2\x0c.GridSettingR\x07settingB\0\
+    \x12(\n\x0flinked_grid_ids\x18\x07\x20\x03(\tR\rlinkedGridIdsB\0:\0\"\
+    \x86\x02\n\x05Field\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x14\
+    \n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12+\n\nfield_type\x18\x03\x20\
+    \x01(\x0e2\n.FieldTypeR\tfieldTypeB\0\x126\n\x0eselect_options\x18\x04\
+    \x20\x03(\x0b2\r.SelectOptionR\rselectOptionsB\0\x12\x1a\n\x07formula\
+    \x18\x05\x20\x01(\tR\x07formulaB\0\x12(\n\x0frelated_grid_id\x18\x06\x20\
+    \x01(\tR\rrelatedGridIdB\0\x12(\n\x06rollup\x18\x07\x20\x01(\x0b2\x0e.Ro\
+    llupSettingR\x06rollupB\0:\0\"P\n\x0cSelectOption\x12\x10\n\x02id\x18\
+    \x01\x20\x01(\tR\x02idB\0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\
+    \0\x12\x16\n\x05color\x18\x03\x20\x01(\tR\x05colorB\0:\0\"\x98\x01\n\rRo\
+    llupSetting\x12,\n\x11relation_field_id\x18\x01\x20\x01(\tR\x0frelationF\
+    ieldIdB\0\x12(\n\x0ftarget_field_id\x18\x02\x20\x01(\tR\rtargetFieldIdB\
+    \0\x12-\n\x08function\x18\x03\x20\x01(\x0e2\x0f.RollupFunctionR\x08funct\
+    ionB\0:\0\"?\n\x14RepeatedSelectOption\x12%\n\x05items\x18\x01\x20\x03(\
+    \x0b2\r.SelectOptionR\x05itemsB\0:\0\"8\n\x03Row\x12\x10\n\x02id\x18\x01\
+    \x20\x01(\tR\x02idB\0\x12\x1d\n\x05cells\x18\x02\x20\x03(\x0b2\x05.CellR\
+    \x05cellsB\0:\0\";\n\x04Cell\x12\x1b\n\x08field_id\x18\x01\x20\x01(\tR\
+    \x07fieldIdB\0\x12\x14\n\x04data\x18\x02\x20\x01(\tR\x04dataB\0:\0\"\x94\
+    \x02\n\rGridChangeset\x12\x19\n\x07grid_id\x18\x01\x20\x01(\tR\x06gridId\
+    B\0\x12/\n\x0eupdated_fields\x18\x02\x20\x03(\x0b2\x06.FieldR\rupdatedFi\
+    eldsB\0\x12,\n\x11deleted_field_ids\x18\x03\x20\x03(\tR\x0fdeletedFieldI\
+    dsB\0\x122\n\rinserted_rows\x18\x04\x20\x03(\x0b2\x0b.IndexedRowR\x0cins\
+    ertedRowsB\0\x12)\n\x0cupdated_rows\x18\x05\x20\x03(\x0b2\x04.RowR\x0bup\
+    datedRowsB\0\x12(\n\x0fdeleted_row_ids\x18\x06\x20\x03(\tR\rdeletedRowId\
+    sB\0:\0\"@\n\nIndexedRow\x12\x18\n\x03row\x18\x01\x20\x01(\x0b2\x04.RowR\
+    \x03rowB\0\x12\x16\n\x05index\x18\x02\x20\x01(\x05R\x05indexB\0:\0\"-\n\
+    \x0bRepeatedRow\x12\x1c\n\x05items\x18\x01\x20\x03(\x0b2\x04.RowR\x05ite\
+    msB\0:\0\"\x8f\x01\n\x16RowVisibilityChangeset\x12\x19\n\x07grid_id\x18\
+    \x01\x20\x01(\tR\x06gridIdB\0\x120\n\x0cvisible_rows\x18\x02\x20\x03(\
+    \x0b2\x0b.IndexedRowR\x0bvisibleRowsB\0\x12&\n\x0ehidden_row_ids\x18\x03\
+    \x20\x03(\tR\x0chiddenRowIdsB\0:\0*n\n\tFieldType\x12\x0c\n\x08RichText\
+    \x10\0\x12\n\n\x06Number\x10\x01\x12\x0c\n\x08DateTime\x10\x02\x12\x10\n\
+    \x0cSingleSelect\x10\x03\x12\x0b\n\x07Formula\x10\x04\x12\x0c\n\x08Relat\
+    ion\x10\x05\x12\n\n\x06Rollup\x10\x06\x1a\0*8\n\x0eRollupFunction\x12\t\
+    \n\x05Count\x10\0\x12\x07\n\x03Sum\x10\x01\x12\x07\n\x03Min\x10\x02\x12\
+    \x07\n\x03Max\x10\x03\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
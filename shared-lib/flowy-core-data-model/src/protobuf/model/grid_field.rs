@@ -0,0 +1,2684 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `grid_field.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateFieldRequest {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub field_type: super::grid::FieldType,
+    pub select_options: ::protobuf::RepeatedField<super::grid::SelectOption>,
+    pub formula: ::std::string::String,
+    pub related_grid_id: ::std::string::String,
+    pub rollup: ::protobuf::SingularPtrField<super::grid::RollupSetting>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateFieldRequest {
+    fn default() -> &'a CreateFieldRequest {
+        <CreateFieldRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateFieldRequest {
+    pub fn new() -> CreateFieldRequest {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // .FieldType field_type = 3;
+
+
+    pub fn get_field_type(&self) -> super::grid::FieldType {
+        self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type = super::grid::FieldType::RichText;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: super::grid::FieldType) {
+        self.field_type = v;
+    }
+
+    // repeated .SelectOption select_options = 4;
+
+
+    pub fn get_select_options(&self) -> &[super::grid::SelectOption] {
+        &self.select_options
+    }
+    pub fn clear_select_options(&mut self) {
+        self.select_options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_select_options(&mut self, v: ::protobuf::RepeatedField<super::grid::SelectOption>) {
+        self.select_options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_select_options(&mut self) -> &mut ::protobuf::RepeatedField<super::grid::SelectOption> {
+        &mut self.select_options
+    }
+
+    // Take field
+    pub fn take_select_options(&mut self) -> ::protobuf::RepeatedField<super::grid::SelectOption> {
+        ::std::mem::replace(&mut self.select_options, ::protobuf::RepeatedField::new())
+    }
+
+    // string formula = 5;
+
+
+    pub fn get_formula(&self) -> &str {
+        &self.formula
+    }
+    pub fn clear_formula(&mut self) {
+        self.formula.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_formula(&mut self, v: ::std::string::String) {
+        self.formula = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_formula(&mut self) -> &mut ::std::string::String {
+        &mut self.formula
+    }
+
+    // Take field
+    pub fn take_formula(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.formula, ::std::string::String::new())
+    }
+
+    // string related_grid_id = 6;
+
+
+    pub fn get_related_grid_id(&self) -> &str {
+        &self.related_grid_id
+    }
+    pub fn clear_related_grid_id(&mut self) {
+        self.related_grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_related_grid_id(&mut self, v: ::std::string::String) {
+        self.related_grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_related_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.related_grid_id
+    }
+
+    // Take field
+    pub fn take_related_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.related_grid_id, ::std::string::String::new())
+    }
+
+    // .RollupSetting rollup = 7;
+
+
+    pub fn get_rollup(&self) -> &super::grid::RollupSetting {
+        self.rollup.as_ref().unwrap_or_else(|| <super::grid::RollupSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rollup(&mut self) {
+        self.rollup.clear();
+    }
+
+    pub fn has_rollup(&self) -> bool {
+        self.rollup.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rollup(&mut self, v: super::grid::RollupSetting) {
+        self.rollup = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rollup(&mut self) -> &mut super::grid::RollupSetting {
+        if self.rollup.is_none() {
+            self.rollup.set_default();
+        }
+        self.rollup.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rollup(&mut self) -> super::grid::RollupSetting {
+        self.rollup.take().unwrap_or_else(|| super::grid::RollupSetting::new())
+    }
+}
+
+impl ::protobuf::Message for CreateFieldRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.select_options {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rollup {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.select_options)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.formula)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.related_grid_id)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rollup)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.field_type != super::grid::FieldType::RichText {
+            my_size += ::protobuf::rt::enum_size(3, self.field_type);
+        }
+        for value in &self.select_options {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.formula.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.formula);
+        }
+        if !self.related_grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.related_grid_id);
+        }
+        if let Some(ref v) = self.rollup.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.field_type != super::grid::FieldType::RichText {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.field_type))?;
+        }
+        for v in &self.select_options {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.formula.is_empty() {
+            os.write_string(5, &self.formula)?;
+        }
+        if !self.related_grid_id.is_empty() {
+            os.write_string(6, &self.related_grid_id)?;
+        }
+        if let Some(ref v) = self.rollup.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateFieldRequest {
+        CreateFieldRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &CreateFieldRequest| { &m.grid_id },
+                |m: &mut CreateFieldRequest| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &CreateFieldRequest| { &m.name },
+                |m: &mut CreateFieldRequest| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::grid::FieldType>>(
+                "field_type",
+                |m: &CreateFieldRequest| { &m.field_type },
+                |m: &mut CreateFieldRequest| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::grid::SelectOption>>(
+                "select_options",
+                |m: &CreateFieldRequest| { &m.select_options },
+                |m: &mut CreateFieldRequest| { &mut m.select_options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "formula",
+                |m: &CreateFieldRequest| { &m.formula },
+                |m: &mut CreateFieldRequest| { &mut m.formula },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "related_grid_id",
+                |m: &CreateFieldRequest| { &m.related_grid_id },
+                |m: &mut CreateFieldRequest| { &mut m.related_grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::grid::RollupSetting>>(
+                "rollup",
+                |m: &CreateFieldRequest| { &m.rollup },
+                |m: &mut CreateFieldRequest| { &mut m.rollup },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateFieldRequest>(
+                "CreateFieldRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateFieldRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateFieldRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateFieldRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateFieldRequest {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.name.clear();
+        self.field_type = super::grid::FieldType::RichText;
+        self.select_options.clear();
+        self.formula.clear();
+        self.related_grid_id.clear();
+        self.rollup.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateFieldRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateFieldRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateFieldParams {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub field_type: super::grid::FieldType,
+    pub select_options: ::protobuf::RepeatedField<super::grid::SelectOption>,
+    pub formula: ::std::string::String,
+    pub related_grid_id: ::std::string::String,
+    pub rollup: ::protobuf::SingularPtrField<super::grid::RollupSetting>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateFieldParams {
+    fn default() -> &'a CreateFieldParams {
+        <CreateFieldParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateFieldParams {
+    pub fn new() -> CreateFieldParams {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // .FieldType field_type = 3;
+
+
+    pub fn get_field_type(&self) -> super::grid::FieldType {
+        self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type = super::grid::FieldType::RichText;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: super::grid::FieldType) {
+        self.field_type = v;
+    }
+
+    // repeated .SelectOption select_options = 4;
+
+
+    pub fn get_select_options(&self) -> &[super::grid::SelectOption] {
+        &self.select_options
+    }
+    pub fn clear_select_options(&mut self) {
+        self.select_options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_select_options(&mut self, v: ::protobuf::RepeatedField<super::grid::SelectOption>) {
+        self.select_options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_select_options(&mut self) -> &mut ::protobuf::RepeatedField<super::grid::SelectOption> {
+        &mut self.select_options
+    }
+
+    // Take field
+    pub fn take_select_options(&mut self) -> ::protobuf::RepeatedField<super::grid::SelectOption> {
+        ::std::mem::replace(&mut self.select_options, ::protobuf::RepeatedField::new())
+    }
+
+    // string formula = 5;
+
+
+    pub fn get_formula(&self) -> &str {
+        &self.formula
+    }
+    pub fn clear_formula(&mut self) {
+        self.formula.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_formula(&mut self, v: ::std::string::String) {
+        self.formula = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_formula(&mut self) -> &mut ::std::string::String {
+        &mut self.formula
+    }
+
+    // Take field
+    pub fn take_formula(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.formula, ::std::string::String::new())
+    }
+
+    // string related_grid_id = 6;
+
+
+    pub fn get_related_grid_id(&self) -> &str {
+        &self.related_grid_id
+    }
+    pub fn clear_related_grid_id(&mut self) {
+        self.related_grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_related_grid_id(&mut self, v: ::std::string::String) {
+        self.related_grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_related_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.related_grid_id
+    }
+
+    // Take field
+    pub fn take_related_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.related_grid_id, ::std::string::String::new())
+    }
+
+    // .RollupSetting rollup = 7;
+
+
+    pub fn get_rollup(&self) -> &super::grid::RollupSetting {
+        self.rollup.as_ref().unwrap_or_else(|| <super::grid::RollupSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rollup(&mut self) {
+        self.rollup.clear();
+    }
+
+    pub fn has_rollup(&self) -> bool {
+        self.rollup.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rollup(&mut self, v: super::grid::RollupSetting) {
+        self.rollup = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rollup(&mut self) -> &mut super::grid::RollupSetting {
+        if self.rollup.is_none() {
+            self.rollup.set_default();
+        }
+        self.rollup.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rollup(&mut self) -> super::grid::RollupSetting {
+        self.rollup.take().unwrap_or_else(|| super::grid::RollupSetting::new())
+    }
+}
+
+impl ::protobuf::Message for CreateFieldParams {
+    fn is_initialized(&self) -> bool {
+        for v in &self.select_options {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rollup {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.select_options)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.formula)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.related_grid_id)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rollup)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.field_type != super::grid::FieldType::RichText {
+            my_size += ::protobuf::rt::enum_size(3, self.field_type);
+        }
+        for value in &self.select_options {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.formula.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.formula);
+        }
+        if !self.related_grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.related_grid_id);
+        }
+        if let Some(ref v) = self.rollup.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.field_type != super::grid::FieldType::RichText {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.field_type))?;
+        }
+        for v in &self.select_options {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.formula.is_empty() {
+            os.write_string(5, &self.formula)?;
+        }
+        if !self.related_grid_id.is_empty() {
+            os.write_string(6, &self.related_grid_id)?;
+        }
+        if let Some(ref v) = self.rollup.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateFieldParams {
+        CreateFieldParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &CreateFieldParams| { &m.grid_id },
+                |m: &mut CreateFieldParams| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &CreateFieldParams| { &m.name },
+                |m: &mut CreateFieldParams| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::grid::FieldType>>(
+                "field_type",
+                |m: &CreateFieldParams| { &m.field_type },
+                |m: &mut CreateFieldParams| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::grid::SelectOption>>(
+                "select_options",
+                |m: &CreateFieldParams| { &m.select_options },
+                |m: &mut CreateFieldParams| { &mut m.select_options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "formula",
+                |m: &CreateFieldParams| { &m.formula },
+                |m: &mut CreateFieldParams| { &mut m.formula },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "related_grid_id",
+                |m: &CreateFieldParams| { &m.related_grid_id },
+                |m: &mut CreateFieldParams| { &mut m.related_grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::grid::RollupSetting>>(
+                "rollup",
+                |m: &CreateFieldParams| { &m.rollup },
+                |m: &mut CreateFieldParams| { &mut m.rollup },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateFieldParams>(
+                "CreateFieldParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateFieldParams {
+        static instance: ::protobuf::rt::LazyV2<CreateFieldParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateFieldParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateFieldParams {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.name.clear();
+        self.field_type = super::grid::FieldType::RichText;
+        self.select_options.clear();
+        self.formula.clear();
+        self.related_grid_id.clear();
+        self.rollup.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateFieldParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateFieldParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateFieldRequest {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub field_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_name: ::std::option::Option<UpdateFieldRequest_oneof_one_of_name>,
+    pub one_of_field_type: ::std::option::Option<UpdateFieldRequest_oneof_one_of_field_type>,
+    pub one_of_select_options: ::std::option::Option<UpdateFieldRequest_oneof_one_of_select_options>,
+    pub one_of_formula: ::std::option::Option<UpdateFieldRequest_oneof_one_of_formula>,
+    pub one_of_related_grid_id: ::std::option::Option<UpdateFieldRequest_oneof_one_of_related_grid_id>,
+    pub one_of_rollup: ::std::option::Option<UpdateFieldRequest_oneof_one_of_rollup>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateFieldRequest {
+    fn default() -> &'a UpdateFieldRequest {
+        <UpdateFieldRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldRequest_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldRequest_oneof_one_of_field_type {
+    field_type(super::grid::FieldType),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldRequest_oneof_one_of_select_options {
+    select_options(super::grid::RepeatedSelectOption),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldRequest_oneof_one_of_formula {
+    formula(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldRequest_oneof_one_of_related_grid_id {
+    related_grid_id(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldRequest_oneof_one_of_rollup {
+    rollup(super::grid::RollupSetting),
+}
+
+impl UpdateFieldRequest {
+    pub fn new() -> UpdateFieldRequest {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string field_id = 2;
+
+
+    pub fn get_field_id(&self) -> &str {
+        &self.field_id
+    }
+    pub fn clear_field_id(&mut self) {
+        self.field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_id(&mut self, v: ::std::string::String) {
+        self.field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.field_id
+    }
+
+    // Take field
+    pub fn take_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_id, ::std::string::String::new())
+    }
+
+    // string name = 3;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .FieldType field_type = 4;
+
+
+    pub fn get_field_type(&self) -> super::grid::FieldType {
+        match self.one_of_field_type {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_field_type::field_type(v)) => v,
+            _ => super::grid::FieldType::RichText,
+        }
+    }
+    pub fn clear_field_type(&mut self) {
+        self.one_of_field_type = ::std::option::Option::None;
+    }
+
+    pub fn has_field_type(&self) -> bool {
+        match self.one_of_field_type {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_field_type::field_type(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: super::grid::FieldType) {
+        self.one_of_field_type = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_field_type::field_type(v))
+    }
+
+    // .RepeatedSelectOption select_options = 5;
+
+
+    pub fn get_select_options(&self) -> &super::grid::RepeatedSelectOption {
+        match self.one_of_select_options {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_select_options::select_options(ref v)) => v,
+            _ => <super::grid::RepeatedSelectOption as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_select_options(&mut self) {
+        self.one_of_select_options = ::std::option::Option::None;
+    }
+
+    pub fn has_select_options(&self) -> bool {
+        match self.one_of_select_options {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_select_options::select_options(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_select_options(&mut self, v: super::grid::RepeatedSelectOption) {
+        self.one_of_select_options = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_select_options::select_options(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_select_options(&mut self) -> &mut super::grid::RepeatedSelectOption {
+        if let ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_select_options::select_options(_)) = self.one_of_select_options {
+        } else {
+            self.one_of_select_options = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_select_options::select_options(super::grid::RepeatedSelectOption::new()));
+        }
+        match self.one_of_select_options {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_select_options::select_options(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_select_options(&mut self) -> super::grid::RepeatedSelectOption {
+        if self.has_select_options() {
+            match self.one_of_select_options.take() {
+                ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_select_options::select_options(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::grid::RepeatedSelectOption::new()
+        }
+    }
+
+    // string formula = 6;
+
+
+    pub fn get_formula(&self) -> &str {
+        match self.one_of_formula {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_formula::formula(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_formula(&mut self) {
+        self.one_of_formula = ::std::option::Option::None;
+    }
+
+    pub fn has_formula(&self) -> bool {
+        match self.one_of_formula {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_formula::formula(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_formula(&mut self, v: ::std::string::String) {
+        self.one_of_formula = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_formula::formula(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_formula(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_formula::formula(_)) = self.one_of_formula {
+        } else {
+            self.one_of_formula = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_formula::formula(::std::string::String::new()));
+        }
+        match self.one_of_formula {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_formula::formula(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_formula(&mut self) -> ::std::string::String {
+        if self.has_formula() {
+            match self.one_of_formula.take() {
+                ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_formula::formula(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string related_grid_id = 7;
+
+
+    pub fn get_related_grid_id(&self) -> &str {
+        match self.one_of_related_grid_id {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_related_grid_id::related_grid_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_related_grid_id(&mut self) {
+        self.one_of_related_grid_id = ::std::option::Option::None;
+    }
+
+    pub fn has_related_grid_id(&self) -> bool {
+        match self.one_of_related_grid_id {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_related_grid_id::related_grid_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_related_grid_id(&mut self, v: ::std::string::String) {
+        self.one_of_related_grid_id = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_related_grid_id::related_grid_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_related_grid_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_related_grid_id::related_grid_id(_)) = self.one_of_related_grid_id {
+        } else {
+            self.one_of_related_grid_id = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_related_grid_id::related_grid_id(::std::string::String::new()));
+        }
+        match self.one_of_related_grid_id {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_related_grid_id::related_grid_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_related_grid_id(&mut self) -> ::std::string::String {
+        if self.has_related_grid_id() {
+            match self.one_of_related_grid_id.take() {
+                ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_related_grid_id::related_grid_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .RollupSetting rollup = 8;
+
+
+    pub fn get_rollup(&self) -> &super::grid::RollupSetting {
+        match self.one_of_rollup {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_rollup::rollup(ref v)) => v,
+            _ => <super::grid::RollupSetting as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_rollup(&mut self) {
+        self.one_of_rollup = ::std::option::Option::None;
+    }
+
+    pub fn has_rollup(&self) -> bool {
+        match self.one_of_rollup {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_rollup::rollup(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rollup(&mut self, v: super::grid::RollupSetting) {
+        self.one_of_rollup = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_rollup::rollup(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rollup(&mut self) -> &mut super::grid::RollupSetting {
+        if let ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_rollup::rollup(_)) = self.one_of_rollup {
+        } else {
+            self.one_of_rollup = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_rollup::rollup(super::grid::RollupSetting::new()));
+        }
+        match self.one_of_rollup {
+            ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_rollup::rollup(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_rollup(&mut self) -> super::grid::RollupSetting {
+        if self.has_rollup() {
+            match self.one_of_rollup.take() {
+                ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_rollup::rollup(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::grid::RollupSetting::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for UpdateFieldRequest {
+    fn is_initialized(&self) -> bool {
+        if let Some(UpdateFieldRequest_oneof_one_of_select_options::select_options(ref v)) = self.one_of_select_options {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(UpdateFieldRequest_oneof_one_of_rollup::rollup(ref v)) = self.one_of_rollup {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_name::name(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_field_type = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_field_type::field_type(is.read_enum()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_select_options = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_select_options::select_options(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_formula = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_formula::formula(is.read_string()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_related_grid_id = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_related_grid_id::related_grid_id(is.read_string()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_rollup = ::std::option::Option::Some(UpdateFieldRequest_oneof_one_of_rollup::rollup(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.field_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_field_type {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_field_type::field_type(v) => {
+                    my_size += ::protobuf::rt::enum_size(4, v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_select_options {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_select_options::select_options(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_formula {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_formula::formula(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_related_grid_id {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_related_grid_id::related_grid_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(7, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_rollup {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_rollup::rollup(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.field_id.is_empty() {
+            os.write_string(2, &self.field_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_name::name(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_field_type {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_field_type::field_type(v) => {
+                    os.write_enum(4, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_select_options {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_select_options::select_options(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_formula {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_formula::formula(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_related_grid_id {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_related_grid_id::related_grid_id(ref v) => {
+                    os.write_string(7, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_rollup {
+            match v {
+                &UpdateFieldRequest_oneof_one_of_rollup::rollup(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateFieldRequest {
+        UpdateFieldRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &UpdateFieldRequest| { &m.grid_id },
+                |m: &mut UpdateFieldRequest| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field_id",
+                |m: &UpdateFieldRequest| { &m.field_id },
+                |m: &mut UpdateFieldRequest| { &mut m.field_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                UpdateFieldRequest::has_name,
+                UpdateFieldRequest::get_name,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, super::grid::FieldType>(
+                "field_type",
+                UpdateFieldRequest::has_field_type,
+                UpdateFieldRequest::get_field_type,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::grid::RepeatedSelectOption>(
+                "select_options",
+                UpdateFieldRequest::has_select_options,
+                UpdateFieldRequest::get_select_options,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "formula",
+                UpdateFieldRequest::has_formula,
+                UpdateFieldRequest::get_formula,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "related_grid_id",
+                UpdateFieldRequest::has_related_grid_id,
+                UpdateFieldRequest::get_related_grid_id,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::grid::RollupSetting>(
+                "rollup",
+                UpdateFieldRequest::has_rollup,
+                UpdateFieldRequest::get_rollup,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateFieldRequest>(
+                "UpdateFieldRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateFieldRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateFieldRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateFieldRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateFieldRequest {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.field_id.clear();
+        self.one_of_name = ::std::option::Option::None;
+        self.one_of_field_type = ::std::option::Option::None;
+        self.one_of_select_options = ::std::option::Option::None;
+        self.one_of_formula = ::std::option::Option::None;
+        self.one_of_related_grid_id = ::std::option::Option::None;
+        self.one_of_rollup = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateFieldRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateFieldRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateFieldParams {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub field_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_name: ::std::option::Option<UpdateFieldParams_oneof_one_of_name>,
+    pub one_of_field_type: ::std::option::Option<UpdateFieldParams_oneof_one_of_field_type>,
+    pub one_of_select_options: ::std::option::Option<UpdateFieldParams_oneof_one_of_select_options>,
+    pub one_of_formula: ::std::option::Option<UpdateFieldParams_oneof_one_of_formula>,
+    pub one_of_related_grid_id: ::std::option::Option<UpdateFieldParams_oneof_one_of_related_grid_id>,
+    pub one_of_rollup: ::std::option::Option<UpdateFieldParams_oneof_one_of_rollup>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateFieldParams {
+    fn default() -> &'a UpdateFieldParams {
+        <UpdateFieldParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldParams_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldParams_oneof_one_of_field_type {
+    field_type(super::grid::FieldType),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldParams_oneof_one_of_select_options {
+    select_options(super::grid::RepeatedSelectOption),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldParams_oneof_one_of_formula {
+    formula(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldParams_oneof_one_of_related_grid_id {
+    related_grid_id(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateFieldParams_oneof_one_of_rollup {
+    rollup(super::grid::RollupSetting),
+}
+
+impl UpdateFieldParams {
+    pub fn new() -> UpdateFieldParams {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string field_id = 2;
+
+
+    pub fn get_field_id(&self) -> &str {
+        &self.field_id
+    }
+    pub fn clear_field_id(&mut self) {
+        self.field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_id(&mut self, v: ::std::string::String) {
+        self.field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.field_id
+    }
+
+    // Take field
+    pub fn take_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_id, ::std::string::String::new())
+    }
+
+    // string name = 3;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .FieldType field_type = 4;
+
+
+    pub fn get_field_type(&self) -> super::grid::FieldType {
+        match self.one_of_field_type {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_field_type::field_type(v)) => v,
+            _ => super::grid::FieldType::RichText,
+        }
+    }
+    pub fn clear_field_type(&mut self) {
+        self.one_of_field_type = ::std::option::Option::None;
+    }
+
+    pub fn has_field_type(&self) -> bool {
+        match self.one_of_field_type {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_field_type::field_type(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: super::grid::FieldType) {
+        self.one_of_field_type = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_field_type::field_type(v))
+    }
+
+    // .RepeatedSelectOption select_options = 5;
+
+
+    pub fn get_select_options(&self) -> &super::grid::RepeatedSelectOption {
+        match self.one_of_select_options {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_select_options::select_options(ref v)) => v,
+            _ => <super::grid::RepeatedSelectOption as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_select_options(&mut self) {
+        self.one_of_select_options = ::std::option::Option::None;
+    }
+
+    pub fn has_select_options(&self) -> bool {
+        match self.one_of_select_options {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_select_options::select_options(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_select_options(&mut self, v: super::grid::RepeatedSelectOption) {
+        self.one_of_select_options = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_select_options::select_options(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_select_options(&mut self) -> &mut super::grid::RepeatedSelectOption {
+        if let ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_select_options::select_options(_)) = self.one_of_select_options {
+        } else {
+            self.one_of_select_options = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_select_options::select_options(super::grid::RepeatedSelectOption::new()));
+        }
+        match self.one_of_select_options {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_select_options::select_options(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_select_options(&mut self) -> super::grid::RepeatedSelectOption {
+        if self.has_select_options() {
+            match self.one_of_select_options.take() {
+                ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_select_options::select_options(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::grid::RepeatedSelectOption::new()
+        }
+    }
+
+    // string formula = 6;
+
+
+    pub fn get_formula(&self) -> &str {
+        match self.one_of_formula {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_formula::formula(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_formula(&mut self) {
+        self.one_of_formula = ::std::option::Option::None;
+    }
+
+    pub fn has_formula(&self) -> bool {
+        match self.one_of_formula {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_formula::formula(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_formula(&mut self, v: ::std::string::String) {
+        self.one_of_formula = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_formula::formula(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_formula(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_formula::formula(_)) = self.one_of_formula {
+        } else {
+            self.one_of_formula = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_formula::formula(::std::string::String::new()));
+        }
+        match self.one_of_formula {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_formula::formula(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_formula(&mut self) -> ::std::string::String {
+        if self.has_formula() {
+            match self.one_of_formula.take() {
+                ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_formula::formula(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string related_grid_id = 7;
+
+
+    pub fn get_related_grid_id(&self) -> &str {
+        match self.one_of_related_grid_id {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_related_grid_id::related_grid_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_related_grid_id(&mut self) {
+        self.one_of_related_grid_id = ::std::option::Option::None;
+    }
+
+    pub fn has_related_grid_id(&self) -> bool {
+        match self.one_of_related_grid_id {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_related_grid_id::related_grid_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_related_grid_id(&mut self, v: ::std::string::String) {
+        self.one_of_related_grid_id = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_related_grid_id::related_grid_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_related_grid_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_related_grid_id::related_grid_id(_)) = self.one_of_related_grid_id {
+        } else {
+            self.one_of_related_grid_id = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_related_grid_id::related_grid_id(::std::string::String::new()));
+        }
+        match self.one_of_related_grid_id {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_related_grid_id::related_grid_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_related_grid_id(&mut self) -> ::std::string::String {
+        if self.has_related_grid_id() {
+            match self.one_of_related_grid_id.take() {
+                ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_related_grid_id::related_grid_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .RollupSetting rollup = 8;
+
+
+    pub fn get_rollup(&self) -> &super::grid::RollupSetting {
+        match self.one_of_rollup {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_rollup::rollup(ref v)) => v,
+            _ => <super::grid::RollupSetting as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_rollup(&mut self) {
+        self.one_of_rollup = ::std::option::Option::None;
+    }
+
+    pub fn has_rollup(&self) -> bool {
+        match self.one_of_rollup {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_rollup::rollup(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rollup(&mut self, v: super::grid::RollupSetting) {
+        self.one_of_rollup = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_rollup::rollup(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rollup(&mut self) -> &mut super::grid::RollupSetting {
+        if let ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_rollup::rollup(_)) = self.one_of_rollup {
+        } else {
+            self.one_of_rollup = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_rollup::rollup(super::grid::RollupSetting::new()));
+        }
+        match self.one_of_rollup {
+            ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_rollup::rollup(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_rollup(&mut self) -> super::grid::RollupSetting {
+        if self.has_rollup() {
+            match self.one_of_rollup.take() {
+                ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_rollup::rollup(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::grid::RollupSetting::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for UpdateFieldParams {
+    fn is_initialized(&self) -> bool {
+        if let Some(UpdateFieldParams_oneof_one_of_select_options::select_options(ref v)) = self.one_of_select_options {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(UpdateFieldParams_oneof_one_of_rollup::rollup(ref v)) = self.one_of_rollup {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_name::name(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_field_type = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_field_type::field_type(is.read_enum()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_select_options = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_select_options::select_options(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_formula = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_formula::formula(is.read_string()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_related_grid_id = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_related_grid_id::related_grid_id(is.read_string()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_rollup = ::std::option::Option::Some(UpdateFieldParams_oneof_one_of_rollup::rollup(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.field_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &UpdateFieldParams_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_field_type {
+            match v {
+                &UpdateFieldParams_oneof_one_of_field_type::field_type(v) => {
+                    my_size += ::protobuf::rt::enum_size(4, v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_select_options {
+            match v {
+                &UpdateFieldParams_oneof_one_of_select_options::select_options(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_formula {
+            match v {
+                &UpdateFieldParams_oneof_one_of_formula::formula(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_related_grid_id {
+            match v {
+                &UpdateFieldParams_oneof_one_of_related_grid_id::related_grid_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(7, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_rollup {
+            match v {
+                &UpdateFieldParams_oneof_one_of_rollup::rollup(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.field_id.is_empty() {
+            os.write_string(2, &self.field_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &UpdateFieldParams_oneof_one_of_name::name(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_field_type {
+            match v {
+                &UpdateFieldParams_oneof_one_of_field_type::field_type(v) => {
+                    os.write_enum(4, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_select_options {
+            match v {
+                &UpdateFieldParams_oneof_one_of_select_options::select_options(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_formula {
+            match v {
+                &UpdateFieldParams_oneof_one_of_formula::formula(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_related_grid_id {
+            match v {
+                &UpdateFieldParams_oneof_one_of_related_grid_id::related_grid_id(ref v) => {
+                    os.write_string(7, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_rollup {
+            match v {
+                &UpdateFieldParams_oneof_one_of_rollup::rollup(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateFieldParams {
+        UpdateFieldParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &UpdateFieldParams| { &m.grid_id },
+                |m: &mut UpdateFieldParams| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field_id",
+                |m: &UpdateFieldParams| { &m.field_id },
+                |m: &mut UpdateFieldParams| { &mut m.field_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                UpdateFieldParams::has_name,
+                UpdateFieldParams::get_name,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, super::grid::FieldType>(
+                "field_type",
+                UpdateFieldParams::has_field_type,
+                UpdateFieldParams::get_field_type,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::grid::RepeatedSelectOption>(
+                "select_options",
+                UpdateFieldParams::has_select_options,
+                UpdateFieldParams::get_select_options,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "formula",
+                UpdateFieldParams::has_formula,
+                UpdateFieldParams::get_formula,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "related_grid_id",
+                UpdateFieldParams::has_related_grid_id,
+                UpdateFieldParams::get_related_grid_id,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::grid::RollupSetting>(
+                "rollup",
+                UpdateFieldParams::has_rollup,
+                UpdateFieldParams::get_rollup,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateFieldParams>(
+                "UpdateFieldParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateFieldParams {
+        static instance: ::protobuf::rt::LazyV2<UpdateFieldParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateFieldParams::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateFieldParams {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.field_id.clear();
+        self.one_of_name = ::std::option::Option::None;
+        self.one_of_field_type = ::std::option::Option::None;
+        self.one_of_select_options = ::std::option::Option::None;
+        self.one_of_formula = ::std::option::Option::None;
+        self.one_of_related_grid_id = ::std::option::Option::None;
+        self.one_of_rollup = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateFieldParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateFieldParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryFieldRequest {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub field_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryFieldRequest {
+    fn default() -> &'a QueryFieldRequest {
+        <QueryFieldRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryFieldRequest {
+    pub fn new() -> QueryFieldRequest {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string field_id = 2;
+
+
+    pub fn get_field_id(&self) -> &str {
+        &self.field_id
+    }
+    pub fn clear_field_id(&mut self) {
+        self.field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_id(&mut self, v: ::std::string::String) {
+        self.field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.field_id
+    }
+
+    // Take field
+    pub fn take_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryFieldRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.field_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.field_id.is_empty() {
+            os.write_string(2, &self.field_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryFieldRequest {
+        QueryFieldRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &QueryFieldRequest| { &m.grid_id },
+                |m: &mut QueryFieldRequest| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field_id",
+                |m: &QueryFieldRequest| { &m.field_id },
+                |m: &mut QueryFieldRequest| { &mut m.field_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryFieldRequest>(
+                "QueryFieldRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryFieldRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryFieldRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryFieldRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryFieldRequest {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.field_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryFieldRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryFieldRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FieldId {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub field_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FieldId {
+    fn default() -> &'a FieldId {
+        <FieldId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FieldId {
+    pub fn new() -> FieldId {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // string field_id = 2;
+
+
+    pub fn get_field_id(&self) -> &str {
+        &self.field_id
+    }
+    pub fn clear_field_id(&mut self) {
+        self.field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_id(&mut self, v: ::std::string::String) {
+        self.field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.field_id
+    }
+
+    // Take field
+    pub fn take_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for FieldId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if !self.field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.field_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if !self.field_id.is_empty() {
+            os.write_string(2, &self.field_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FieldId {
+        FieldId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &FieldId| { &m.grid_id },
+                |m: &mut FieldId| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field_id",
+                |m: &FieldId| { &m.field_id },
+                |m: &mut FieldId| { &mut m.field_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FieldId>(
+                "FieldId",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FieldId {
+        static instance: ::protobuf::rt::LazyV2<FieldId> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FieldId::new)
+    }
+}
+
+impl ::protobuf::Clear for FieldId {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.field_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FieldId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FieldId {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10grid_field.proto\x1a\ngrid.proto\"\x9c\x02\n\x12CreateFieldRequest\
+    \x12\x19\n\x07grid_id\x18\x01\x20\x01(\tR\x06gridIdB\0\x12\x14\n\x04name\
+    \x18\x02\x20\x01(\tR\x04nameB\0\x12+\n\nfield_type\x18\x03\x20\x01(\x0e2\
+    \n.FieldTypeR\tfieldTypeB\0\x126\n\x0eselect_options\x18\x04\x20\x03(\
+    \x0b2\r.SelectOptionR\rselectOptionsB\0\x12\x1a\n\x07formula\x18\x05\x20\
+    \x01(\tR\x07formulaB\0\x12(\n\x0frelated_grid_id\x18\x06\x20\x01(\tR\rre\
+    latedGridIdB\0\x12(\n\x06rollup\x18\x07\x20\x01(\x0b2\x0e.RollupSettingR\
+    \x06rollupB\0:\0\"\x9b\x02\n\x11CreateFieldParams\x12\x19\n\x07grid_id\
+    \x18\x01\x20\x01(\tR\x06gridIdB\0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\
+    \x04nameB\0\x12+\n\nfield_type\x18\x03\x20\x01(\x0e2\n.FieldTypeR\tfield\
+    TypeB\0\x126\n\x0eselect_options\x18\x04\x20\x03(\x0b2\r.SelectOptionR\r\
+    selectOptionsB\0\x12\x1a\n\x07formula\x18\x05\x20\x01(\tR\x07formulaB\0\
+    \x12(\n\x0frelated_grid_id\x18\x06\x20\x01(\tR\rrelatedGridIdB\0\x12(\n\
+    \x06rollup\x18\x07\x20\x01(\x0b2\x0e.RollupSettingR\x06rollupB\0:\0\"\
+    \xc7\x03\n\x12UpdateFieldRequest\x12\x19\n\x07grid_id\x18\x01\x20\x01(\t\
+    R\x06gridIdB\0\x12\x1b\n\x08field_id\x18\x02\x20\x01(\tR\x07fieldIdB\0\
+    \x12\x16\n\x04name\x18\x03\x20\x01(\tH\0R\x04nameB\0\x12-\n\nfield_type\
+    \x18\x04\x20\x01(\x0e2\n.FieldTypeH\x01R\tfieldTypeB\0\x12@\n\x0eselect_\
+    options\x18\x05\x20\x01(\x0b2\x15.RepeatedSelectOptionH\x02R\rselectOpti\
+    onsB\0\x12\x1c\n\x07formula\x18\x06\x20\x01(\tH\x03R\x07formulaB\0\x12*\
+    \n\x0frelated_grid_id\x18\x07\x20\x01(\tH\x04R\rrelatedGridIdB\0\x12*\n\
+    \x06rollup\x18\x08\x20\x01(\x0b2\x0e.RollupSettingH\x05R\x06rollupB\0B\r\
+    \n\x0bone_of_nameB\x13\n\x11one_of_field_typeB\x17\n\x15one_of_select_op\
+    tionsB\x10\n\x0eone_of_formulaB\x18\n\x16one_of_related_grid_idB\x0f\n\r\
+    one_of_rollup:\0\"\xc6\x03\n\x11UpdateFieldParams\x12\x19\n\x07grid_id\
+    \x18\x01\x20\x01(\tR\x06gridIdB\0\x12\x1b\n\x08field_id\x18\x02\x20\x01(\
+    \tR\x07fieldIdB\0\x12\x16\n\x04name\x18\x03\x20\x01(\tH\0R\x04nameB\0\
+    \x12-\n\nfield_type\x18\x04\x20\x01(\x0e2\n.FieldTypeH\x01R\tfieldTypeB\
+    \0\x12@\n\x0eselect_options\x18\x05\x20\x01(\x0b2\x15.RepeatedSelectOpti\
+    onH\x02R\rselectOptionsB\0\x12\x1c\n\x07formula\x18\x06\x20\x01(\tH\x03R\
+    \x07formulaB\0\x12*\n\x0frelated_grid_id\x18\x07\x20\x01(\tH\x04R\rrelat\
+    edGridIdB\0\x12*\n\x06rollup\x18\x08\x20\x01(\x0b2\x0e.RollupSettingH\
+    \x05R\x06rollupB\0B\r\n\x0bone_of_nameB\x13\n\x11one_of_field_typeB\x17\
+    \n\x15one_of_select_optionsB\x10\n\x0eone_of_formulaB\x18\n\x16one_of_re\
+    lated_grid_idB\x0f\n\rone_of_rollup:\0\"M\n\x11QueryFieldRequest\x12\x19\
+    \n\x07grid_id\x18\x01\x20\x01(\tR\x06gridIdB\0\x12\x1b\n\x08field_id\x18\
+    \x02\x20\x01(\tR\x07fieldIdB\0:\0\"C\n\x07FieldId\x12\x19\n\x07grid_id\
+    \x18\x01\x20\x01(\tR\x06gridIdB\0\x12\x1b\n\x08field_id\x18\x02\x20\x01(\
+    \tR\x07fieldIdB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,1505 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `grid_setting.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GridSetting {
+    // message fields
+    pub filter: ::protobuf::SingularPtrField<FilterGroup>,
+    pub sorts: ::protobuf::RepeatedField<Sort>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GridSetting {
+    fn default() -> &'a GridSetting {
+        <GridSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GridSetting {
+    pub fn new() -> GridSetting {
+        ::std::default::Default::default()
+    }
+
+    // .FilterGroup filter = 1;
+
+
+    pub fn get_filter(&self) -> &FilterGroup {
+        self.filter.as_ref().unwrap_or_else(|| <FilterGroup as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+    }
+
+    pub fn has_filter(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filter(&mut self, v: FilterGroup) {
+        self.filter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_filter(&mut self) -> &mut FilterGroup {
+        if self.filter.is_none() {
+            self.filter.set_default();
+        }
+        self.filter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_filter(&mut self) -> FilterGroup {
+        self.filter.take().unwrap_or_else(|| FilterGroup::new())
+    }
+
+    // repeated .Sort sorts = 2;
+
+
+    pub fn get_sorts(&self) -> &[Sort] {
+        &self.sorts
+    }
+    pub fn clear_sorts(&mut self) {
+        self.sorts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sorts(&mut self, v: ::protobuf::RepeatedField<Sort>) {
+        self.sorts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sorts(&mut self) -> &mut ::protobuf::RepeatedField<Sort> {
+        &mut self.sorts
+    }
+
+    // Take field
+    pub fn take_sorts(&mut self) -> ::protobuf::RepeatedField<Sort> {
+        ::std::mem::replace(&mut self.sorts, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GridSetting {
+    fn is_initialized(&self) -> bool {
+        for v in &self.filter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.sorts {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.filter)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.sorts)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.filter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.sorts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.filter.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.sorts {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GridSetting {
+        GridSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FilterGroup>>(
+                "filter",
+                |m: &GridSetting| { &m.filter },
+                |m: &mut GridSetting| { &mut m.filter },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Sort>>(
+                "sorts",
+                |m: &GridSetting| { &m.sorts },
+                |m: &mut GridSetting| { &mut m.sorts },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GridSetting>(
+                "GridSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GridSetting {
+        static instance: ::protobuf::rt::LazyV2<GridSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GridSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for GridSetting {
+    fn clear(&mut self) {
+        self.filter.clear();
+        self.sorts.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GridSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GridSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FilterGroup {
+    // message fields
+    pub operator: FilterOperator,
+    pub filters: ::protobuf::RepeatedField<Filter>,
+    pub groups: ::protobuf::RepeatedField<FilterGroup>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FilterGroup {
+    fn default() -> &'a FilterGroup {
+        <FilterGroup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FilterGroup {
+    pub fn new() -> FilterGroup {
+        ::std::default::Default::default()
+    }
+
+    // .FilterOperator operator = 1;
+
+
+    pub fn get_operator(&self) -> FilterOperator {
+        self.operator
+    }
+    pub fn clear_operator(&mut self) {
+        self.operator = FilterOperator::And;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_operator(&mut self, v: FilterOperator) {
+        self.operator = v;
+    }
+
+    // repeated .Filter filters = 2;
+
+
+    pub fn get_filters(&self) -> &[Filter] {
+        &self.filters
+    }
+    pub fn clear_filters(&mut self) {
+        self.filters.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filters(&mut self, v: ::protobuf::RepeatedField<Filter>) {
+        self.filters = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_filters(&mut self) -> &mut ::protobuf::RepeatedField<Filter> {
+        &mut self.filters
+    }
+
+    // Take field
+    pub fn take_filters(&mut self) -> ::protobuf::RepeatedField<Filter> {
+        ::std::mem::replace(&mut self.filters, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .FilterGroup groups = 3;
+
+
+    pub fn get_groups(&self) -> &[FilterGroup] {
+        &self.groups
+    }
+    pub fn clear_groups(&mut self) {
+        self.groups.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_groups(&mut self, v: ::protobuf::RepeatedField<FilterGroup>) {
+        self.groups = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_groups(&mut self) -> &mut ::protobuf::RepeatedField<FilterGroup> {
+        &mut self.groups
+    }
+
+    // Take field
+    pub fn take_groups(&mut self) -> ::protobuf::RepeatedField<FilterGroup> {
+        ::std::mem::replace(&mut self.groups, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for FilterGroup {
+    fn is_initialized(&self) -> bool {
+        for v in &self.filters {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.groups {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.operator, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.filters)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.groups)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.operator != FilterOperator::And {
+            my_size += ::protobuf::rt::enum_size(1, self.operator);
+        }
+        for value in &self.filters {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.groups {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.operator != FilterOperator::And {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.operator))?;
+        }
+        for v in &self.filters {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.groups {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FilterGroup {
+        FilterGroup::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FilterOperator>>(
+                "operator",
+                |m: &FilterGroup| { &m.operator },
+                |m: &mut FilterGroup| { &mut m.operator },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Filter>>(
+                "filters",
+                |m: &FilterGroup| { &m.filters },
+                |m: &mut FilterGroup| { &mut m.filters },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FilterGroup>>(
+                "groups",
+                |m: &FilterGroup| { &m.groups },
+                |m: &mut FilterGroup| { &mut m.groups },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FilterGroup>(
+                "FilterGroup",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FilterGroup {
+        static instance: ::protobuf::rt::LazyV2<FilterGroup> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FilterGroup::new)
+    }
+}
+
+impl ::protobuf::Clear for FilterGroup {
+    fn clear(&mut self) {
+        self.operator = FilterOperator::And;
+        self.filters.clear();
+        self.groups.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FilterGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FilterGroup {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Filter {
+    // message fields
+    pub field_id: ::std::string::String,
+    pub condition: FilterCondition,
+    pub value: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Filter {
+    fn default() -> &'a Filter {
+        <Filter as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Filter {
+    pub fn new() -> Filter {
+        ::std::default::Default::default()
+    }
+
+    // string field_id = 1;
+
+
+    pub fn get_field_id(&self) -> &str {
+        &self.field_id
+    }
+    pub fn clear_field_id(&mut self) {
+        self.field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_id(&mut self, v: ::std::string::String) {
+        self.field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.field_id
+    }
+
+    // Take field
+    pub fn take_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_id, ::std::string::String::new())
+    }
+
+    // .FilterCondition condition = 2;
+
+
+    pub fn get_condition(&self) -> FilterCondition {
+        self.condition
+    }
+    pub fn clear_condition(&mut self) {
+        self.condition = FilterCondition::Is;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_condition(&mut self, v: FilterCondition) {
+        self.condition = v;
+    }
+
+    // string value = 3;
+
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Filter {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.condition, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_id);
+        }
+        if self.condition != FilterCondition::Is {
+            my_size += ::protobuf::rt::enum_size(2, self.condition);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field_id.is_empty() {
+            os.write_string(1, &self.field_id)?;
+        }
+        if self.condition != FilterCondition::Is {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.condition))?;
+        }
+        if !self.value.is_empty() {
+            os.write_string(3, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Filter {
+        Filter::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field_id",
+                |m: &Filter| { &m.field_id },
+                |m: &mut Filter| { &mut m.field_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FilterCondition>>(
+                "condition",
+                |m: &Filter| { &m.condition },
+                |m: &mut Filter| { &mut m.condition },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "value",
+                |m: &Filter| { &m.value },
+                |m: &mut Filter| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Filter>(
+                "Filter",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Filter {
+        static instance: ::protobuf::rt::LazyV2<Filter> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Filter::new)
+    }
+}
+
+impl ::protobuf::Clear for Filter {
+    fn clear(&mut self) {
+        self.field_id.clear();
+        self.condition = FilterCondition::Is;
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Filter {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Filter {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Sort {
+    // message fields
+    pub field_id: ::std::string::String,
+    pub descending: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Sort {
+    fn default() -> &'a Sort {
+        <Sort as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Sort {
+    pub fn new() -> Sort {
+        ::std::default::Default::default()
+    }
+
+    // string field_id = 1;
+
+
+    pub fn get_field_id(&self) -> &str {
+        &self.field_id
+    }
+    pub fn clear_field_id(&mut self) {
+        self.field_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_id(&mut self, v: ::std::string::String) {
+        self.field_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_id(&mut self) -> &mut ::std::string::String {
+        &mut self.field_id
+    }
+
+    // Take field
+    pub fn take_field_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_id, ::std::string::String::new())
+    }
+
+    // bool descending = 2;
+
+
+    pub fn get_descending(&self) -> bool {
+        self.descending
+    }
+    pub fn clear_descending(&mut self) {
+        self.descending = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_descending(&mut self, v: bool) {
+        self.descending = v;
+    }
+}
+
+impl ::protobuf::Message for Sort {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.descending = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_id);
+        }
+        if self.descending != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field_id.is_empty() {
+            os.write_string(1, &self.field_id)?;
+        }
+        if self.descending != false {
+            os.write_bool(2, self.descending)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Sort {
+        Sort::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field_id",
+                |m: &Sort| { &m.field_id },
+                |m: &mut Sort| { &mut m.field_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "descending",
+                |m: &Sort| { &m.descending },
+                |m: &mut Sort| { &mut m.descending },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Sort>(
+                "Sort",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Sort {
+        static instance: ::protobuf::rt::LazyV2<Sort> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Sort::new)
+    }
+}
+
+impl ::protobuf::Clear for Sort {
+    fn clear(&mut self) {
+        self.field_id.clear();
+        self.descending = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Sort {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Sort {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateGridSettingRequest {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub setting: ::protobuf::SingularPtrField<GridSetting>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateGridSettingRequest {
+    fn default() -> &'a UpdateGridSettingRequest {
+        <UpdateGridSettingRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateGridSettingRequest {
+    pub fn new() -> UpdateGridSettingRequest {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // .GridSetting setting = 2;
+
+
+    pub fn get_setting(&self) -> &GridSetting {
+        self.setting.as_ref().unwrap_or_else(|| <GridSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_setting(&mut self) {
+        self.setting.clear();
+    }
+
+    pub fn has_setting(&self) -> bool {
+        self.setting.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_setting(&mut self, v: GridSetting) {
+        self.setting = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_setting(&mut self) -> &mut GridSetting {
+        if self.setting.is_none() {
+            self.setting.set_default();
+        }
+        self.setting.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_setting(&mut self) -> GridSetting {
+        self.setting.take().unwrap_or_else(|| GridSetting::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateGridSettingRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.setting {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.setting)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if let Some(ref v) = self.setting.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if let Some(ref v) = self.setting.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateGridSettingRequest {
+        UpdateGridSettingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &UpdateGridSettingRequest| { &m.grid_id },
+                |m: &mut UpdateGridSettingRequest| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<GridSetting>>(
+                "setting",
+                |m: &UpdateGridSettingRequest| { &m.setting },
+                |m: &mut UpdateGridSettingRequest| { &mut m.setting },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateGridSettingRequest>(
+                "UpdateGridSettingRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateGridSettingRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateGridSettingRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateGridSettingRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateGridSettingRequest {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.setting.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateGridSettingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateGridSettingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateGridSettingParams {
+    // message fields
+    pub grid_id: ::std::string::String,
+    pub setting: ::protobuf::SingularPtrField<GridSetting>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateGridSettingParams {
+    fn default() -> &'a UpdateGridSettingParams {
+        <UpdateGridSettingParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateGridSettingParams {
+    pub fn new() -> UpdateGridSettingParams {
+        ::std::default::Default::default()
+    }
+
+    // string grid_id = 1;
+
+
+    pub fn get_grid_id(&self) -> &str {
+        &self.grid_id
+    }
+    pub fn clear_grid_id(&mut self) {
+        self.grid_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grid_id(&mut self, v: ::std::string::String) {
+        self.grid_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_grid_id(&mut self) -> &mut ::std::string::String {
+        &mut self.grid_id
+    }
+
+    // Take field
+    pub fn take_grid_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.grid_id, ::std::string::String::new())
+    }
+
+    // .GridSetting setting = 2;
+
+
+    pub fn get_setting(&self) -> &GridSetting {
+        self.setting.as_ref().unwrap_or_else(|| <GridSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_setting(&mut self) {
+        self.setting.clear();
+    }
+
+    pub fn has_setting(&self) -> bool {
+        self.setting.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_setting(&mut self, v: GridSetting) {
+        self.setting = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_setting(&mut self) -> &mut GridSetting {
+        if self.setting.is_none() {
+            self.setting.set_default();
+        }
+        self.setting.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_setting(&mut self) -> GridSetting {
+        self.setting.take().unwrap_or_else(|| GridSetting::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateGridSettingParams {
+    fn is_initialized(&self) -> bool {
+        for v in &self.setting {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.grid_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.setting)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.grid_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.grid_id);
+        }
+        if let Some(ref v) = self.setting.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.grid_id.is_empty() {
+            os.write_string(1, &self.grid_id)?;
+        }
+        if let Some(ref v) = self.setting.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateGridSettingParams {
+        UpdateGridSettingParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "grid_id",
+                |m: &UpdateGridSettingParams| { &m.grid_id },
+                |m: &mut UpdateGridSettingParams| { &mut m.grid_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<GridSetting>>(
+                "setting",
+                |m: &UpdateGridSettingParams| { &m.setting },
+                |m: &mut UpdateGridSettingParams| { &mut m.setting },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateGridSettingParams>(
+                "UpdateGridSettingParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateGridSettingParams {
+        static instance: ::protobuf::rt::LazyV2<UpdateGridSettingParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateGridSettingParams::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateGridSettingParams {
+    fn clear(&mut self) {
+        self.grid_id.clear();
+        self.setting.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateGridSettingParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateGridSettingParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum FilterOperator {
+    And = 0,
+    Or = 1,
+}
+
+impl ::protobuf::ProtobufEnum for FilterOperator {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<FilterOperator> {
+        match value {
+            0 => ::std::option::Option::Some(FilterOperator::And),
+            1 => ::std::option::Option::Some(FilterOperator::Or),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [FilterOperator] = &[
+            FilterOperator::And,
+            FilterOperator::Or,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<FilterOperator>("FilterOperator", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for FilterOperator {
+}
+
+impl ::std::default::Default for FilterOperator {
+    fn default() -> Self {
+        FilterOperator::And
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FilterOperator {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum FilterCondition {
+    Is = 0,
+    IsNot = 1,
+    Contains = 2,
+    DoesNotContain = 3,
+    IsEmpty = 4,
+    IsNotEmpty = 5,
+    GreaterThan = 6,
+    LessThan = 7,
+}
+
+impl ::protobuf::ProtobufEnum for FilterCondition {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<FilterCondition> {
+        match value {
+            0 => ::std::option::Option::Some(FilterCondition::Is),
+            1 => ::std::option::Option::Some(FilterCondition::IsNot),
+            2 => ::std::option::Option::Some(FilterCondition::Contains),
+            3 => ::std::option::Option::Some(FilterCondition::DoesNotContain),
+            4 => ::std::option::Option::Some(FilterCondition::IsEmpty),
+            5 => ::std::option::Option::Some(FilterCondition::IsNotEmpty),
+            6 => ::std::option::Option::Some(FilterCondition::GreaterThan),
+            7 => ::std::option::Option::Some(FilterCondition::LessThan),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [FilterCondition] = &[
+            FilterCondition::Is,
+            FilterCondition::IsNot,
+            FilterCondition::Contains,
+            FilterCondition::DoesNotContain,
+            FilterCondition::IsEmpty,
+            FilterCondition::IsNotEmpty,
+            FilterCondition::GreaterThan,
+            FilterCondition::LessThan,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<FilterCondition>("FilterCondition", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for FilterCondition {
+}
+
+impl ::std::default::Default for FilterCondition {
+    fn default() -> Self {
+        FilterCondition::Is
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FilterCondition {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x12grid_setting.proto\"V\n\x0bGridSetting\x12&\n\x06filter\x18\x01\
+    \x20\x01(\x0b2\x0c.FilterGroupR\x06filterB\0\x12\x1d\n\x05sorts\x18\x02\
+    \x20\x03(\x0b2\x05.SortR\x05sortsB\0:\0\"\x8b\x01\n\x0bFilterGroup\x12-\
+    \n\x08operator\x18\x01\x20\x01(\x0e2\x0f.FilterOperatorR\x08operatorB\0\
+    \x12#\n\x07filters\x18\x02\x20\x03(\x0b2\x07.FilterR\x07filtersB\0\x12&\
+    \n\x06groups\x18\x03\x20\x03(\x0b2\x0c.FilterGroupR\x06groupsB\0:\0\"q\n\
+    \x06Filter\x12\x1b\n\x08field_id\x18\x01\x20\x01(\tR\x07fieldIdB\0\x120\
+    \n\tcondition\x18\x02\x20\x01(\x0e2\x10.FilterConditionR\tconditionB\0\
+    \x12\x16\n\x05value\x18\x03\x20\x01(\tR\x05valueB\0:\0\"G\n\x04Sort\x12\
+    \x1b\n\x08field_id\x18\x01\x20\x01(\tR\x07fieldIdB\0\x12\x20\n\ndescendi\
+    ng\x18\x02\x20\x01(\x08R\ndescendingB\0:\0\"a\n\x18UpdateGridSettingRequ\
+    est\x12\x19\n\x07grid_id\x18\x01\x20\x01(\tR\x06gridIdB\0\x12(\n\x07sett\
+    ing\x18\x02\x20\x01(\x0b2\x0c.GridSettingR\x07settingB\0:\0\"`\n\x17Upda\
+    teGridSettingParams\x12\x19\n\x07grid_id\x18\x01\x20\x01(\tR\x06gridIdB\
+    \0\x12(\n\x07setting\x18\x02\x20\x01(\x0b2\x0c.GridSettingR\x07settingB\
+    \0:\0*#\n\x0eFilterOperator\x12\x07\n\x03And\x10\0\x12\x06\n\x02Or\x10\
+    \x01\x1a\0*\x84\x01\n\x0fFilterCondition\x12\x06\n\x02Is\x10\0\x12\t\n\
+    \x05IsNot\x10\x01\x12\x0c\n\x08Contains\x10\x02\x12\x12\n\x0eDoesNotCont\
+    ain\x10\x03\x12\x0b\n\x07IsEmpty\x10\x04\x12\x0e\n\nIsNotEmpty\x10\x05\
+    \x12\x0f\n\x0bGreaterThan\x10\x06\x12\x0c\n\x08LessThan\x10\x07\x1a\0B\0\
+    b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,604 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `health.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HealthCheck {
+    // message fields
+    pub name: ::std::string::String,
+    pub status: HealthStatus,
+    pub detail: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HealthCheck {
+    fn default() -> &'a HealthCheck {
+        <HealthCheck as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HealthCheck {
+    pub fn new() -> HealthCheck {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // .HealthStatus status = 2;
+
+
+    pub fn get_status(&self) -> HealthStatus {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = HealthStatus::Healthy;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: HealthStatus) {
+        self.status = v;
+    }
+
+    // string detail = 3;
+
+
+    pub fn get_detail(&self) -> &str {
+        &self.detail
+    }
+    pub fn clear_detail(&mut self) {
+        self.detail.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_detail(&mut self, v: ::std::string::String) {
+        self.detail = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_detail(&mut self) -> &mut ::std::string::String {
+        &mut self.detail
+    }
+
+    // Take field
+    pub fn take_detail(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.detail, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for HealthCheck {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.detail)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.status != HealthStatus::Healthy {
+            my_size += ::protobuf::rt::enum_size(2, self.status);
+        }
+        if !self.detail.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.detail);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.status != HealthStatus::Healthy {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.status))?;
+        }
+        if !self.detail.is_empty() {
+            os.write_string(3, &self.detail)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HealthCheck {
+        HealthCheck::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &HealthCheck| { &m.name },
+                |m: &mut HealthCheck| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<HealthStatus>>(
+                "status",
+                |m: &HealthCheck| { &m.status },
+                |m: &mut HealthCheck| { &mut m.status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "detail",
+                |m: &HealthCheck| { &m.detail },
+                |m: &mut HealthCheck| { &mut m.detail },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HealthCheck>(
+                "HealthCheck",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HealthCheck {
+        static instance: ::protobuf::rt::LazyV2<HealthCheck> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HealthCheck::new)
+    }
+}
+
+impl ::protobuf::Clear for HealthCheck {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.status = HealthStatus::Healthy;
+        self.detail.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HealthCheck {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HealthCheck {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HealthReport {
+    // message fields
+    pub status: HealthStatus,
+    pub checks: ::protobuf::RepeatedField<HealthCheck>,
+    pub pending_revisions: i64,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HealthReport {
+    fn default() -> &'a HealthReport {
+        <HealthReport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HealthReport {
+    pub fn new() -> HealthReport {
+        ::std::default::Default::default()
+    }
+
+    // .HealthStatus status = 1;
+
+
+    pub fn get_status(&self) -> HealthStatus {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = HealthStatus::Healthy;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: HealthStatus) {
+        self.status = v;
+    }
+
+    // repeated .HealthCheck checks = 2;
+
+
+    pub fn get_checks(&self) -> &[HealthCheck] {
+        &self.checks
+    }
+    pub fn clear_checks(&mut self) {
+        self.checks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checks(&mut self, v: ::protobuf::RepeatedField<HealthCheck>) {
+        self.checks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_checks(&mut self) -> &mut ::protobuf::RepeatedField<HealthCheck> {
+        &mut self.checks
+    }
+
+    // Take field
+    pub fn take_checks(&mut self) -> ::protobuf::RepeatedField<HealthCheck> {
+        ::std::mem::replace(&mut self.checks, ::protobuf::RepeatedField::new())
+    }
+
+    // int64 pending_revisions = 3;
+
+
+    pub fn get_pending_revisions(&self) -> i64 {
+        self.pending_revisions
+    }
+    pub fn clear_pending_revisions(&mut self) {
+        self.pending_revisions = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending_revisions(&mut self, v: i64) {
+        self.pending_revisions = v;
+    }
+
+    // int64 create_time = 4;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for HealthReport {
+    fn is_initialized(&self) -> bool {
+        for v in &self.checks {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.checks)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pending_revisions = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != HealthStatus::Healthy {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        for value in &self.checks {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.pending_revisions != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.pending_revisions, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.status != HealthStatus::Healthy {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.status))?;
+        }
+        for v in &self.checks {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.pending_revisions != 0 {
+            os.write_int64(3, self.pending_revisions)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(4, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HealthReport {
+        HealthReport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<HealthStatus>>(
+                "status",
+                |m: &HealthReport| { &m.status },
+                |m: &mut HealthReport| { &mut m.status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HealthCheck>>(
+                "checks",
+                |m: &HealthReport| { &m.checks },
+                |m: &mut HealthReport| { &mut m.checks },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pending_revisions",
+                |m: &HealthReport| { &m.pending_revisions },
+                |m: &mut HealthReport| { &mut m.pending_revisions },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &HealthReport| { &m.create_time },
+                |m: &mut HealthReport| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HealthReport>(
+                "HealthReport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HealthReport {
+        static instance: ::protobuf::rt::LazyV2<HealthReport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HealthReport::new)
+    }
+}
+
+impl ::protobuf::Clear for HealthReport {
+    fn clear(&mut self) {
+        self.status = HealthStatus::Healthy;
+        self.checks.clear();
+        self.pending_revisions = 0;
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HealthReport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HealthReport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum HealthStatus {
+    Healthy = 0,
+    Degraded = 1,
+    Unhealthy = 2,
+    Skipped = 3,
+}
+
+impl ::protobuf::ProtobufEnum for HealthStatus {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<HealthStatus> {
+        match value {
+            0 => ::std::option::Option::Some(HealthStatus::Healthy),
+            1 => ::std::option::Option::Some(HealthStatus::Degraded),
+            2 => ::std::option::Option::Some(HealthStatus::Unhealthy),
+            3 => ::std::option::Option::Some(HealthStatus::Skipped),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [HealthStatus] = &[
+            HealthStatus::Healthy,
+            HealthStatus::Degraded,
+            HealthStatus::Unhealthy,
+            HealthStatus::Skipped,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<HealthStatus>("HealthStatus", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for HealthStatus {
+}
+
+impl ::std::default::Default for HealthStatus {
+    fn default() -> Self {
+        HealthStatus::Healthy
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HealthStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0chealth.proto\"h\n\x0bHealthCheck\x12\x14\n\x04name\x18\x01\x20\x01\
+    (\tR\x04nameB\0\x12'\n\x06status\x18\x02\x20\x01(\x0e2\r.HealthStatusR\
+    \x06statusB\0\x12\x18\n\x06detail\x18\x03\x20\x01(\tR\x06detailB\0:\0\"\
+    \xb3\x01\n\x0cHealthReport\x12'\n\x06status\x18\x01\x20\x01(\x0e2\r.Heal\
+    thStatusR\x06statusB\0\x12&\n\x06checks\x18\x02\x20\x03(\x0b2\x0c.Health\
+    CheckR\x06checksB\0\x12-\n\x11pending_revisions\x18\x03\x20\x01(\x03R\
+    \x10pendingRevisionsB\0\x12!\n\x0bcreate_time\x18\x04\x20\x01(\x03R\ncre\
+    ateTimeB\0:\0*G\n\x0cHealthStatus\x12\x0b\n\x07Healthy\x10\0\x12\x0c\n\
+    \x08Degraded\x10\x01\x12\r\n\tUnhealthy\x10\x02\x12\x0b\n\x07Skipped\x10\
+    \x03\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -39,3 +39,114 @@ pub use trash_create::*;
 
 mod export;
 pub use export::*;
+
+mod workspace_member;
+pub use workspace_member::*;
+
+mod share_link;
+pub use share_link::*;
+
+mod view_access;
+pub use view_access::*;
+
+mod workspace_invitation;
+pub use workspace_invitation::*;
+
+mod guest_access;
+pub use guest_access::*;
+
+mod webhook;
+pub use webhook::*;
+
+mod event_log;
+pub use event_log::*;
+
+mod view_move;
+pub use view_move::*;
+
+mod app_duplicate;
+pub use app_duplicate::*;
+
+mod import;
+pub use import::*;
+
+mod metrics;
+pub use metrics::*;
+
+mod health;
+pub use health::*;
+
+mod bundle;
+pub use bundle::*;
+
+mod grid_row;
+pub use grid_row::*;
+
+mod grid;
+pub use grid::*;
+
+mod grid_field;
+pub use grid_field::*;
+
+mod grid_query;
+pub use grid_query::*;
+
+mod grid_cell;
+pub use grid_cell::*;
+
+mod board;
+pub use board::*;
+
+mod calendar;
+pub use calendar::*;
+
+mod checklist;
+pub use checklist::*;
+
+mod checklist_item;
+pub use checklist_item::*;
+
+mod grid_setting;
+pub use grid_setting::*;
+
+mod view_attachment;
+pub use view_attachment::*;
+
+mod view_tag;
+pub use view_tag::*;
+
+mod search;
+pub use search::*;
+
+mod audit_log;
+pub use audit_log::*;
+
+mod view_checkpoint;
+pub use view_checkpoint::*;
+
+mod view_code_block;
+pub use view_code_block::*;
+
+mod unfurl;
+pub use unfurl::*;
+
+mod view_toggle;
+pub use view_toggle::*;
+
+mod backup;
+pub use backup::*;
+
+mod view_quarantine;
+pub use view_quarantine::*;
+
+mod folder_sync;
+pub use folder_sync::*;
+
+mod storage;
+pub use storage::*;
+
+mod view_duplicate;
+pub use view_duplicate::*;
+
+mod workspace_settings;
+pub use workspace_settings::*;
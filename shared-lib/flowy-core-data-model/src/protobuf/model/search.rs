@@ -0,0 +1,1014 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `search.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SearchRequest {
+    // message fields
+    pub query: ::std::string::String,
+    pub all_workspaces: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SearchRequest {
+    fn default() -> &'a SearchRequest {
+        <SearchRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SearchRequest {
+    pub fn new() -> SearchRequest {
+        ::std::default::Default::default()
+    }
+
+    // string query = 1;
+
+
+    pub fn get_query(&self) -> &str {
+        &self.query
+    }
+    pub fn clear_query(&mut self) {
+        self.query.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query(&mut self, v: ::std::string::String) {
+        self.query = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_query(&mut self) -> &mut ::std::string::String {
+        &mut self.query
+    }
+
+    // Take field
+    pub fn take_query(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.query, ::std::string::String::new())
+    }
+
+    // bool all_workspaces = 2;
+
+
+    pub fn get_all_workspaces(&self) -> bool {
+        self.all_workspaces
+    }
+    pub fn clear_all_workspaces(&mut self) {
+        self.all_workspaces = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_all_workspaces(&mut self, v: bool) {
+        self.all_workspaces = v;
+    }
+}
+
+impl ::protobuf::Message for SearchRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.query)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.all_workspaces = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.query.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.query);
+        }
+        if self.all_workspaces != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.query.is_empty() {
+            os.write_string(1, &self.query)?;
+        }
+        if self.all_workspaces != false {
+            os.write_bool(2, self.all_workspaces)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SearchRequest {
+        SearchRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "query",
+                |m: &SearchRequest| { &m.query },
+                |m: &mut SearchRequest| { &mut m.query },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "all_workspaces",
+                |m: &SearchRequest| { &m.all_workspaces },
+                |m: &mut SearchRequest| { &mut m.all_workspaces },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SearchRequest>(
+                "SearchRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SearchRequest {
+        static instance: ::protobuf::rt::LazyV2<SearchRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SearchRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SearchRequest {
+    fn clear(&mut self) {
+        self.query.clear();
+        self.all_workspaces = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SearchRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SearchRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SearchResultItem {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub view_name: ::std::string::String,
+    pub view_type: super::view_create::ViewType,
+    pub belong_to_id: ::std::string::String,
+    pub name_matched: bool,
+    pub snippet: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SearchResultItem {
+    fn default() -> &'a SearchResultItem {
+        <SearchResultItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SearchResultItem {
+    pub fn new() -> SearchResultItem {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string view_name = 2;
+
+
+    pub fn get_view_name(&self) -> &str {
+        &self.view_name
+    }
+    pub fn clear_view_name(&mut self) {
+        self.view_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_name(&mut self, v: ::std::string::String) {
+        self.view_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_name(&mut self) -> &mut ::std::string::String {
+        &mut self.view_name
+    }
+
+    // Take field
+    pub fn take_view_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_name, ::std::string::String::new())
+    }
+
+    // .ViewType view_type = 3;
+
+
+    pub fn get_view_type(&self) -> super::view_create::ViewType {
+        self.view_type
+    }
+    pub fn clear_view_type(&mut self) {
+        self.view_type = super::view_create::ViewType::Blank;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_type(&mut self, v: super::view_create::ViewType) {
+        self.view_type = v;
+    }
+
+    // string belong_to_id = 4;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // bool name_matched = 5;
+
+
+    pub fn get_name_matched(&self) -> bool {
+        self.name_matched
+    }
+    pub fn clear_name_matched(&mut self) {
+        self.name_matched = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name_matched(&mut self, v: bool) {
+        self.name_matched = v;
+    }
+
+    // string snippet = 6;
+
+
+    pub fn get_snippet(&self) -> &str {
+        &self.snippet
+    }
+    pub fn clear_snippet(&mut self) {
+        self.snippet.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snippet(&mut self, v: ::std::string::String) {
+        self.snippet = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_snippet(&mut self) -> &mut ::std::string::String {
+        &mut self.snippet
+    }
+
+    // Take field
+    pub fn take_snippet(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.snippet, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SearchResultItem {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.view_type, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.name_matched = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.snippet)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.view_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.view_name);
+        }
+        if self.view_type != super::view_create::ViewType::Blank {
+            my_size += ::protobuf::rt::enum_size(3, self.view_type);
+        }
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.belong_to_id);
+        }
+        if self.name_matched != false {
+            my_size += 2;
+        }
+        if !self.snippet.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.snippet);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.view_name.is_empty() {
+            os.write_string(2, &self.view_name)?;
+        }
+        if self.view_type != super::view_create::ViewType::Blank {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.view_type))?;
+        }
+        if !self.belong_to_id.is_empty() {
+            os.write_string(4, &self.belong_to_id)?;
+        }
+        if self.name_matched != false {
+            os.write_bool(5, self.name_matched)?;
+        }
+        if !self.snippet.is_empty() {
+            os.write_string(6, &self.snippet)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SearchResultItem {
+        SearchResultItem::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &SearchResultItem| { &m.view_id },
+                |m: &mut SearchResultItem| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_name",
+                |m: &SearchResultItem| { &m.view_name },
+                |m: &mut SearchResultItem| { &mut m.view_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::view_create::ViewType>>(
+                "view_type",
+                |m: &SearchResultItem| { &m.view_type },
+                |m: &mut SearchResultItem| { &mut m.view_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &SearchResultItem| { &m.belong_to_id },
+                |m: &mut SearchResultItem| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "name_matched",
+                |m: &SearchResultItem| { &m.name_matched },
+                |m: &mut SearchResultItem| { &mut m.name_matched },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "snippet",
+                |m: &SearchResultItem| { &m.snippet },
+                |m: &mut SearchResultItem| { &mut m.snippet },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SearchResultItem>(
+                "SearchResultItem",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SearchResultItem {
+        static instance: ::protobuf::rt::LazyV2<SearchResultItem> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SearchResultItem::new)
+    }
+}
+
+impl ::protobuf::Clear for SearchResultItem {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.view_name.clear();
+        self.view_type = super::view_create::ViewType::Blank;
+        self.belong_to_id.clear();
+        self.name_matched = false;
+        self.snippet.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SearchResultItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SearchResultItem {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceSearchResult {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub workspace_name: ::std::string::String,
+    pub items: ::protobuf::RepeatedField<SearchResultItem>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceSearchResult {
+    fn default() -> &'a WorkspaceSearchResult {
+        <WorkspaceSearchResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceSearchResult {
+    pub fn new() -> WorkspaceSearchResult {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string workspace_name = 2;
+
+
+    pub fn get_workspace_name(&self) -> &str {
+        &self.workspace_name
+    }
+    pub fn clear_workspace_name(&mut self) {
+        self.workspace_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_name(&mut self, v: ::std::string::String) {
+        self.workspace_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_name(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_name
+    }
+
+    // Take field
+    pub fn take_workspace_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_name, ::std::string::String::new())
+    }
+
+    // repeated .SearchResultItem items = 3;
+
+
+    pub fn get_items(&self) -> &[SearchResultItem] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<SearchResultItem>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<SearchResultItem> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<SearchResultItem> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for WorkspaceSearchResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.workspace_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.workspace_name);
+        }
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.workspace_name.is_empty() {
+            os.write_string(2, &self.workspace_name)?;
+        }
+        for v in &self.items {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceSearchResult {
+        WorkspaceSearchResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &WorkspaceSearchResult| { &m.workspace_id },
+                |m: &mut WorkspaceSearchResult| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_name",
+                |m: &WorkspaceSearchResult| { &m.workspace_name },
+                |m: &mut WorkspaceSearchResult| { &mut m.workspace_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SearchResultItem>>(
+                "items",
+                |m: &WorkspaceSearchResult| { &m.items },
+                |m: &mut WorkspaceSearchResult| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceSearchResult>(
+                "WorkspaceSearchResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceSearchResult {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceSearchResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceSearchResult::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceSearchResult {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.workspace_name.clear();
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceSearchResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceSearchResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedWorkspaceSearchResult {
+    // message fields
+    pub items: ::protobuf::RepeatedField<WorkspaceSearchResult>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedWorkspaceSearchResult {
+    fn default() -> &'a RepeatedWorkspaceSearchResult {
+        <RepeatedWorkspaceSearchResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedWorkspaceSearchResult {
+    pub fn new() -> RepeatedWorkspaceSearchResult {
+        ::std::default::Default::default()
+    }
+
+    // repeated .WorkspaceSearchResult items = 1;
+
+
+    pub fn get_items(&self) -> &[WorkspaceSearchResult] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<WorkspaceSearchResult>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<WorkspaceSearchResult> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<WorkspaceSearchResult> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedWorkspaceSearchResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedWorkspaceSearchResult {
+        RepeatedWorkspaceSearchResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkspaceSearchResult>>(
+                "items",
+                |m: &RepeatedWorkspaceSearchResult| { &m.items },
+                |m: &mut RepeatedWorkspaceSearchResult| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedWorkspaceSearchResult>(
+                "RepeatedWorkspaceSearchResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedWorkspaceSearchResult {
+        static instance: ::protobuf::rt::LazyV2<RepeatedWorkspaceSearchResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedWorkspaceSearchResult::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedWorkspaceSearchResult {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedWorkspaceSearchResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedWorkspaceSearchResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0csearch.proto\x1a\x11view_create.proto\"R\n\rSearchRequest\x12\x16\
+    \n\x05query\x18\x01\x20\x01(\tR\x05queryB\0\x12'\n\x0eall_workspaces\x18\
+    \x02\x20\x01(\x08R\rallWorkspacesB\0:\0\"\xdd\x01\n\x10SearchResultItem\
+    \x12\x19\n\x07view_id\x18\x01\x20\x01(\tR\x06viewIdB\0\x12\x1d\n\tview_n\
+    ame\x18\x02\x20\x01(\tR\x08viewNameB\0\x12(\n\tview_type\x18\x03\x20\x01\
+    (\x0e2\t.ViewTypeR\x08viewTypeB\0\x12\"\n\x0cbelong_to_id\x18\x04\x20\
+    \x01(\tR\nbelongToIdB\0\x12#\n\x0cname_matched\x18\x05\x20\x01(\x08R\x0b\
+    nameMatchedB\0\x12\x1a\n\x07snippet\x18\x06\x20\x01(\tR\x07snippetB\0:\0\
+    \"\x92\x01\n\x15WorkspaceSearchResult\x12#\n\x0cworkspace_id\x18\x01\x20\
+    \x01(\tR\x0bworkspaceIdB\0\x12'\n\x0eworkspace_name\x18\x02\x20\x01(\tR\
+    \rworkspaceNameB\0\x12)\n\x05items\x18\x03\x20\x03(\x0b2\x11.SearchResul\
+    tItemR\x05itemsB\0:\0\"Q\n\x1dRepeatedWorkspaceSearchResult\x12.\n\x05it\
+    ems\x18\x01\x20\x03(\x0b2\x16.WorkspaceSearchResultR\x05itemsB\0:\0B\0b\
+    \x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
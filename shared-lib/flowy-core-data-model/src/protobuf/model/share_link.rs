@@ -0,0 +1,1733 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `share_link.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShareLink {
+    // message fields
+    pub id: ::std::string::String,
+    pub view_id: ::std::string::String,
+    pub permission: SharePermission,
+    pub url: ::std::string::String,
+    pub create_time: i64,
+    // message oneof groups
+    pub one_of_expire_time: ::std::option::Option<ShareLink_oneof_one_of_expire_time>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShareLink {
+    fn default() -> &'a ShareLink {
+        <ShareLink as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ShareLink_oneof_one_of_expire_time {
+    expire_time(i64),
+}
+
+impl ShareLink {
+    pub fn new() -> ShareLink {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string view_id = 2;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // .SharePermission permission = 3;
+
+
+    pub fn get_permission(&self) -> SharePermission {
+        self.permission
+    }
+    pub fn clear_permission(&mut self) {
+        self.permission = SharePermission::ReadOnly;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_permission(&mut self, v: SharePermission) {
+        self.permission = v;
+    }
+
+    // string url = 4;
+
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        &mut self.url
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.url, ::std::string::String::new())
+    }
+
+    // int64 create_time = 5;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+
+    // int64 expire_time = 6;
+
+
+    pub fn get_expire_time(&self) -> i64 {
+        match self.one_of_expire_time {
+            ::std::option::Option::Some(ShareLink_oneof_one_of_expire_time::expire_time(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_expire_time(&mut self) {
+        self.one_of_expire_time = ::std::option::Option::None;
+    }
+
+    pub fn has_expire_time(&self) -> bool {
+        match self.one_of_expire_time {
+            ::std::option::Option::Some(ShareLink_oneof_one_of_expire_time::expire_time(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expire_time(&mut self, v: i64) {
+        self.one_of_expire_time = ::std::option::Option::Some(ShareLink_oneof_one_of_expire_time::expire_time(v))
+    }
+}
+
+impl ::protobuf::Message for ShareLink {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.permission, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.url)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_expire_time = ::std::option::Option::Some(ShareLink_oneof_one_of_expire_time::expire_time(is.read_int64()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.view_id);
+        }
+        if self.permission != SharePermission::ReadOnly {
+            my_size += ::protobuf::rt::enum_size(3, self.permission);
+        }
+        if !self.url.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.url);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_expire_time {
+            match v {
+                &ShareLink_oneof_one_of_expire_time::expire_time(v) => {
+                    my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.view_id.is_empty() {
+            os.write_string(2, &self.view_id)?;
+        }
+        if self.permission != SharePermission::ReadOnly {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.permission))?;
+        }
+        if !self.url.is_empty() {
+            os.write_string(4, &self.url)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(5, self.create_time)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_expire_time {
+            match v {
+                &ShareLink_oneof_one_of_expire_time::expire_time(v) => {
+                    os.write_int64(6, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShareLink {
+        ShareLink::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &ShareLink| { &m.id },
+                |m: &mut ShareLink| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &ShareLink| { &m.view_id },
+                |m: &mut ShareLink| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SharePermission>>(
+                "permission",
+                |m: &ShareLink| { &m.permission },
+                |m: &mut ShareLink| { &mut m.permission },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "url",
+                |m: &ShareLink| { &m.url },
+                |m: &mut ShareLink| { &mut m.url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &ShareLink| { &m.create_time },
+                |m: &mut ShareLink| { &mut m.create_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "expire_time",
+                ShareLink::has_expire_time,
+                ShareLink::get_expire_time,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShareLink>(
+                "ShareLink",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShareLink {
+        static instance: ::protobuf::rt::LazyV2<ShareLink> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShareLink::new)
+    }
+}
+
+impl ::protobuf::Clear for ShareLink {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.view_id.clear();
+        self.permission = SharePermission::ReadOnly;
+        self.url.clear();
+        self.create_time = 0;
+        self.one_of_expire_time = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShareLink {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShareLink {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedShareLink {
+    // message fields
+    pub items: ::protobuf::RepeatedField<ShareLink>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedShareLink {
+    fn default() -> &'a RepeatedShareLink {
+        <RepeatedShareLink as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedShareLink {
+    pub fn new() -> RepeatedShareLink {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ShareLink items = 1;
+
+
+    pub fn get_items(&self) -> &[ShareLink] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<ShareLink>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<ShareLink> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<ShareLink> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedShareLink {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedShareLink {
+        RepeatedShareLink::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShareLink>>(
+                "items",
+                |m: &RepeatedShareLink| { &m.items },
+                |m: &mut RepeatedShareLink| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedShareLink>(
+                "RepeatedShareLink",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedShareLink {
+        static instance: ::protobuf::rt::LazyV2<RepeatedShareLink> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedShareLink::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedShareLink {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedShareLink {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedShareLink {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateShareLinkRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub permission: SharePermission,
+    // message oneof groups
+    pub one_of_expire_time: ::std::option::Option<CreateShareLinkRequest_oneof_one_of_expire_time>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateShareLinkRequest {
+    fn default() -> &'a CreateShareLinkRequest {
+        <CreateShareLinkRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateShareLinkRequest_oneof_one_of_expire_time {
+    expire_time(i64),
+}
+
+impl CreateShareLinkRequest {
+    pub fn new() -> CreateShareLinkRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // .SharePermission permission = 2;
+
+
+    pub fn get_permission(&self) -> SharePermission {
+        self.permission
+    }
+    pub fn clear_permission(&mut self) {
+        self.permission = SharePermission::ReadOnly;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_permission(&mut self, v: SharePermission) {
+        self.permission = v;
+    }
+
+    // int64 expire_time = 3;
+
+
+    pub fn get_expire_time(&self) -> i64 {
+        match self.one_of_expire_time {
+            ::std::option::Option::Some(CreateShareLinkRequest_oneof_one_of_expire_time::expire_time(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_expire_time(&mut self) {
+        self.one_of_expire_time = ::std::option::Option::None;
+    }
+
+    pub fn has_expire_time(&self) -> bool {
+        match self.one_of_expire_time {
+            ::std::option::Option::Some(CreateShareLinkRequest_oneof_one_of_expire_time::expire_time(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expire_time(&mut self, v: i64) {
+        self.one_of_expire_time = ::std::option::Option::Some(CreateShareLinkRequest_oneof_one_of_expire_time::expire_time(v))
+    }
+}
+
+impl ::protobuf::Message for CreateShareLinkRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.permission, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_expire_time = ::std::option::Option::Some(CreateShareLinkRequest_oneof_one_of_expire_time::expire_time(is.read_int64()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.permission != SharePermission::ReadOnly {
+            my_size += ::protobuf::rt::enum_size(2, self.permission);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_expire_time {
+            match v {
+                &CreateShareLinkRequest_oneof_one_of_expire_time::expire_time(v) => {
+                    my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.permission != SharePermission::ReadOnly {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.permission))?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_expire_time {
+            match v {
+                &CreateShareLinkRequest_oneof_one_of_expire_time::expire_time(v) => {
+                    os.write_int64(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateShareLinkRequest {
+        CreateShareLinkRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &CreateShareLinkRequest| { &m.view_id },
+                |m: &mut CreateShareLinkRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SharePermission>>(
+                "permission",
+                |m: &CreateShareLinkRequest| { &m.permission },
+                |m: &mut CreateShareLinkRequest| { &mut m.permission },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "expire_time",
+                CreateShareLinkRequest::has_expire_time,
+                CreateShareLinkRequest::get_expire_time,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateShareLinkRequest>(
+                "CreateShareLinkRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateShareLinkRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateShareLinkRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateShareLinkRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateShareLinkRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.permission = SharePermission::ReadOnly;
+        self.one_of_expire_time = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateShareLinkRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateShareLinkRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateShareLinkParams {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub permission: SharePermission,
+    // message oneof groups
+    pub one_of_expire_time: ::std::option::Option<CreateShareLinkParams_oneof_one_of_expire_time>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateShareLinkParams {
+    fn default() -> &'a CreateShareLinkParams {
+        <CreateShareLinkParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateShareLinkParams_oneof_one_of_expire_time {
+    expire_time(i64),
+}
+
+impl CreateShareLinkParams {
+    pub fn new() -> CreateShareLinkParams {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // .SharePermission permission = 2;
+
+
+    pub fn get_permission(&self) -> SharePermission {
+        self.permission
+    }
+    pub fn clear_permission(&mut self) {
+        self.permission = SharePermission::ReadOnly;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_permission(&mut self, v: SharePermission) {
+        self.permission = v;
+    }
+
+    // int64 expire_time = 3;
+
+
+    pub fn get_expire_time(&self) -> i64 {
+        match self.one_of_expire_time {
+            ::std::option::Option::Some(CreateShareLinkParams_oneof_one_of_expire_time::expire_time(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_expire_time(&mut self) {
+        self.one_of_expire_time = ::std::option::Option::None;
+    }
+
+    pub fn has_expire_time(&self) -> bool {
+        match self.one_of_expire_time {
+            ::std::option::Option::Some(CreateShareLinkParams_oneof_one_of_expire_time::expire_time(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expire_time(&mut self, v: i64) {
+        self.one_of_expire_time = ::std::option::Option::Some(CreateShareLinkParams_oneof_one_of_expire_time::expire_time(v))
+    }
+}
+
+impl ::protobuf::Message for CreateShareLinkParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.permission, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_expire_time = ::std::option::Option::Some(CreateShareLinkParams_oneof_one_of_expire_time::expire_time(is.read_int64()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.permission != SharePermission::ReadOnly {
+            my_size += ::protobuf::rt::enum_size(2, self.permission);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_expire_time {
+            match v {
+                &CreateShareLinkParams_oneof_one_of_expire_time::expire_time(v) => {
+                    my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.permission != SharePermission::ReadOnly {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.permission))?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_expire_time {
+            match v {
+                &CreateShareLinkParams_oneof_one_of_expire_time::expire_time(v) => {
+                    os.write_int64(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateShareLinkParams {
+        CreateShareLinkParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &CreateShareLinkParams| { &m.view_id },
+                |m: &mut CreateShareLinkParams| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SharePermission>>(
+                "permission",
+                |m: &CreateShareLinkParams| { &m.permission },
+                |m: &mut CreateShareLinkParams| { &mut m.permission },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "expire_time",
+                CreateShareLinkParams::has_expire_time,
+                CreateShareLinkParams::get_expire_time,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateShareLinkParams>(
+                "CreateShareLinkParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateShareLinkParams {
+        static instance: ::protobuf::rt::LazyV2<CreateShareLinkParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateShareLinkParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateShareLinkParams {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.permission = SharePermission::ReadOnly;
+        self.one_of_expire_time = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateShareLinkParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateShareLinkParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryShareLinkRequest {
+    // message fields
+    pub link_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryShareLinkRequest {
+    fn default() -> &'a QueryShareLinkRequest {
+        <QueryShareLinkRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryShareLinkRequest {
+    pub fn new() -> QueryShareLinkRequest {
+        ::std::default::Default::default()
+    }
+
+    // string link_id = 1;
+
+
+    pub fn get_link_id(&self) -> &str {
+        &self.link_id
+    }
+    pub fn clear_link_id(&mut self) {
+        self.link_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_link_id(&mut self, v: ::std::string::String) {
+        self.link_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_link_id(&mut self) -> &mut ::std::string::String {
+        &mut self.link_id
+    }
+
+    // Take field
+    pub fn take_link_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.link_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryShareLinkRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.link_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.link_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.link_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.link_id.is_empty() {
+            os.write_string(1, &self.link_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryShareLinkRequest {
+        QueryShareLinkRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "link_id",
+                |m: &QueryShareLinkRequest| { &m.link_id },
+                |m: &mut QueryShareLinkRequest| { &mut m.link_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryShareLinkRequest>(
+                "QueryShareLinkRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryShareLinkRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryShareLinkRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryShareLinkRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryShareLinkRequest {
+    fn clear(&mut self) {
+        self.link_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryShareLinkRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryShareLinkRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShareLinkId {
+    // message fields
+    pub link_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShareLinkId {
+    fn default() -> &'a ShareLinkId {
+        <ShareLinkId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShareLinkId {
+    pub fn new() -> ShareLinkId {
+        ::std::default::Default::default()
+    }
+
+    // string link_id = 1;
+
+
+    pub fn get_link_id(&self) -> &str {
+        &self.link_id
+    }
+    pub fn clear_link_id(&mut self) {
+        self.link_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_link_id(&mut self, v: ::std::string::String) {
+        self.link_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_link_id(&mut self) -> &mut ::std::string::String {
+        &mut self.link_id
+    }
+
+    // Take field
+    pub fn take_link_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.link_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ShareLinkId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.link_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.link_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.link_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.link_id.is_empty() {
+            os.write_string(1, &self.link_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShareLinkId {
+        ShareLinkId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "link_id",
+                |m: &ShareLinkId| { &m.link_id },
+                |m: &mut ShareLinkId| { &mut m.link_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShareLinkId>(
+                "ShareLinkId",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShareLinkId {
+        static instance: ::protobuf::rt::LazyV2<ShareLinkId> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShareLinkId::new)
+    }
+}
+
+impl ::protobuf::Clear for ShareLinkId {
+    fn clear(&mut self) {
+        self.link_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShareLinkId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShareLinkId {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SharedDocument {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub permission: SharePermission,
+    pub delta_json: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SharedDocument {
+    fn default() -> &'a SharedDocument {
+        <SharedDocument as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SharedDocument {
+    pub fn new() -> SharedDocument {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // .SharePermission permission = 3;
+
+
+    pub fn get_permission(&self) -> SharePermission {
+        self.permission
+    }
+    pub fn clear_permission(&mut self) {
+        self.permission = SharePermission::ReadOnly;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_permission(&mut self, v: SharePermission) {
+        self.permission = v;
+    }
+
+    // string delta_json = 4;
+
+
+    pub fn get_delta_json(&self) -> &str {
+        &self.delta_json
+    }
+    pub fn clear_delta_json(&mut self) {
+        self.delta_json.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delta_json(&mut self, v: ::std::string::String) {
+        self.delta_json = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_delta_json(&mut self) -> &mut ::std::string::String {
+        &mut self.delta_json
+    }
+
+    // Take field
+    pub fn take_delta_json(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.delta_json, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SharedDocument {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.permission, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.delta_json)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.permission != SharePermission::ReadOnly {
+            my_size += ::protobuf::rt::enum_size(3, self.permission);
+        }
+        if !self.delta_json.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.delta_json);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.permission != SharePermission::ReadOnly {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.permission))?;
+        }
+        if !self.delta_json.is_empty() {
+            os.write_string(4, &self.delta_json)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SharedDocument {
+        SharedDocument::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &SharedDocument| { &m.view_id },
+                |m: &mut SharedDocument| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &SharedDocument| { &m.name },
+                |m: &mut SharedDocument| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SharePermission>>(
+                "permission",
+                |m: &SharedDocument| { &m.permission },
+                |m: &mut SharedDocument| { &mut m.permission },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "delta_json",
+                |m: &SharedDocument| { &m.delta_json },
+                |m: &mut SharedDocument| { &mut m.delta_json },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SharedDocument>(
+                "SharedDocument",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SharedDocument {
+        static instance: ::protobuf::rt::LazyV2<SharedDocument> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SharedDocument::new)
+    }
+}
+
+impl ::protobuf::Clear for SharedDocument {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.name.clear();
+        self.permission = SharePermission::ReadOnly;
+        self.delta_json.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SharedDocument {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SharedDocument {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SharePermission {
+    ReadOnly = 0,
+    Editable = 1,
+}
+
+impl ::protobuf::ProtobufEnum for SharePermission {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SharePermission> {
+        match value {
+            0 => ::std::option::Option::Some(SharePermission::ReadOnly),
+            1 => ::std::option::Option::Some(SharePermission::Editable),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SharePermission] = &[
+            SharePermission::ReadOnly,
+            SharePermission::Editable,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SharePermission>("SharePermission", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SharePermission {
+}
+
+impl ::std::default::Default for SharePermission {
+    fn default() -> Self {
+        SharePermission::ReadOnly
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SharePermission {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10share_link.proto\"\xe0\x01\n\tShareLink\x12\x10\n\x02id\x18\x01\
+    \x20\x01(\tR\x02idB\0\x12\x19\n\x07view_id\x18\x02\x20\x01(\tR\x06viewId\
+    B\0\x122\n\npermission\x18\x03\x20\x01(\x0e2\x10.SharePermissionR\npermi\
+    ssionB\0\x12\x12\n\x03url\x18\x04\x20\x01(\tR\x03urlB\0\x12!\n\x0bcreate\
+    _time\x18\x05\x20\x01(\x03R\ncreateTimeB\0\x12#\n\x0bexpire_time\x18\x06\
+    \x20\x01(\x03H\0R\nexpireTimeB\0B\x14\n\x12one_of_expire_time:\0\"9\n\
+    \x11RepeatedShareLink\x12\"\n\x05items\x18\x01\x20\x03(\x0b2\n.ShareLink\
+    R\x05itemsB\0:\0\"\xa4\x01\n\x16CreateShareLinkRequest\x12\x19\n\x07view\
+    _id\x18\x01\x20\x01(\tR\x06viewIdB\0\x122\n\npermission\x18\x02\x20\x01(\
+    \x0e2\x10.SharePermissionR\npermissionB\0\x12#\n\x0bexpire_time\x18\x03\
+    \x20\x01(\x03H\0R\nexpireTimeB\0B\x14\n\x12one_of_expire_time:\0\"\xa3\
+    \x01\n\x15CreateShareLinkParams\x12\x19\n\x07view_id\x18\x01\x20\x01(\tR\
+    \x06viewIdB\0\x122\n\npermission\x18\x02\x20\x01(\x0e2\x10.SharePermissi\
+    onR\npermissionB\0\x12#\n\x0bexpire_time\x18\x03\x20\x01(\x03H\0R\nexpir\
+    eTimeB\0B\x14\n\x12one_of_expire_time:\0\"4\n\x15QueryShareLinkRequest\
+    \x12\x19\n\x07link_id\x18\x01\x20\x01(\tR\x06linkIdB\0:\0\"*\n\x0bShareL\
+    inkId\x12\x19\n\x07link_id\x18\x01\x20\x01(\tR\x06linkIdB\0:\0\"\x98\x01\
+    \n\x0eSharedDocument\x12\x19\n\x07view_id\x18\x01\x20\x01(\tR\x06viewIdB\
+    \0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x122\n\npermission\
+    \x18\x03\x20\x01(\x0e2\x10.SharePermissionR\npermissionB\0\x12\x1f\n\nde\
+    lta_json\x18\x04\x20\x01(\tR\tdeltaJsonB\0:\0*/\n\x0fSharePermission\x12\
+    \x0c\n\x08ReadOnly\x10\0\x12\x0c\n\x08Editable\x10\x01\x1a\0B\0b\x06prot\
+    o3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,1057 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `storage.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AttachmentStorage {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub endpoint: ::std::string::String,
+    pub bucket: ::std::string::String,
+    pub region: ::std::string::String,
+    pub access_key_id: ::std::string::String,
+    pub has_secret: bool,
+    pub prefix: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AttachmentStorage {
+    fn default() -> &'a AttachmentStorage {
+        <AttachmentStorage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AttachmentStorage {
+    pub fn new() -> AttachmentStorage {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string endpoint = 2;
+
+
+    pub fn get_endpoint(&self) -> &str {
+        &self.endpoint
+    }
+    pub fn clear_endpoint(&mut self) {
+        self.endpoint.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_endpoint(&mut self, v: ::std::string::String) {
+        self.endpoint = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_endpoint(&mut self) -> &mut ::std::string::String {
+        &mut self.endpoint
+    }
+
+    // Take field
+    pub fn take_endpoint(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.endpoint, ::std::string::String::new())
+    }
+
+    // string bucket = 3;
+
+
+    pub fn get_bucket(&self) -> &str {
+        &self.bucket
+    }
+    pub fn clear_bucket(&mut self) {
+        self.bucket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bucket(&mut self, v: ::std::string::String) {
+        self.bucket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bucket(&mut self) -> &mut ::std::string::String {
+        &mut self.bucket
+    }
+
+    // Take field
+    pub fn take_bucket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.bucket, ::std::string::String::new())
+    }
+
+    // string region = 4;
+
+
+    pub fn get_region(&self) -> &str {
+        &self.region
+    }
+    pub fn clear_region(&mut self) {
+        self.region.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_region(&mut self, v: ::std::string::String) {
+        self.region = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_region(&mut self) -> &mut ::std::string::String {
+        &mut self.region
+    }
+
+    // Take field
+    pub fn take_region(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.region, ::std::string::String::new())
+    }
+
+    // string access_key_id = 5;
+
+
+    pub fn get_access_key_id(&self) -> &str {
+        &self.access_key_id
+    }
+    pub fn clear_access_key_id(&mut self) {
+        self.access_key_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_access_key_id(&mut self, v: ::std::string::String) {
+        self.access_key_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_access_key_id(&mut self) -> &mut ::std::string::String {
+        &mut self.access_key_id
+    }
+
+    // Take field
+    pub fn take_access_key_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.access_key_id, ::std::string::String::new())
+    }
+
+    // bool has_secret = 6;
+
+
+    pub fn get_has_secret(&self) -> bool {
+        self.has_secret
+    }
+    pub fn clear_has_secret(&mut self) {
+        self.has_secret = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_secret(&mut self, v: bool) {
+        self.has_secret = v;
+    }
+
+    // string prefix = 7;
+
+
+    pub fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+    pub fn clear_prefix(&mut self) {
+        self.prefix.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prefix(&mut self, v: ::std::string::String) {
+        self.prefix = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prefix(&mut self) -> &mut ::std::string::String {
+        &mut self.prefix
+    }
+
+    // Take field
+    pub fn take_prefix(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.prefix, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for AttachmentStorage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.endpoint)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.bucket)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.region)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.access_key_id)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.has_secret = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.prefix)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.endpoint.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.endpoint);
+        }
+        if !self.bucket.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.bucket);
+        }
+        if !self.region.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.region);
+        }
+        if !self.access_key_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.access_key_id);
+        }
+        if self.has_secret != false {
+            my_size += 2;
+        }
+        if !self.prefix.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.prefix);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.endpoint.is_empty() {
+            os.write_string(2, &self.endpoint)?;
+        }
+        if !self.bucket.is_empty() {
+            os.write_string(3, &self.bucket)?;
+        }
+        if !self.region.is_empty() {
+            os.write_string(4, &self.region)?;
+        }
+        if !self.access_key_id.is_empty() {
+            os.write_string(5, &self.access_key_id)?;
+        }
+        if self.has_secret != false {
+            os.write_bool(6, self.has_secret)?;
+        }
+        if !self.prefix.is_empty() {
+            os.write_string(7, &self.prefix)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AttachmentStorage {
+        AttachmentStorage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &AttachmentStorage| { &m.workspace_id },
+                |m: &mut AttachmentStorage| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "endpoint",
+                |m: &AttachmentStorage| { &m.endpoint },
+                |m: &mut AttachmentStorage| { &mut m.endpoint },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "bucket",
+                |m: &AttachmentStorage| { &m.bucket },
+                |m: &mut AttachmentStorage| { &mut m.bucket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "region",
+                |m: &AttachmentStorage| { &m.region },
+                |m: &mut AttachmentStorage| { &mut m.region },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "access_key_id",
+                |m: &AttachmentStorage| { &m.access_key_id },
+                |m: &mut AttachmentStorage| { &mut m.access_key_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "has_secret",
+                |m: &AttachmentStorage| { &m.has_secret },
+                |m: &mut AttachmentStorage| { &mut m.has_secret },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "prefix",
+                |m: &AttachmentStorage| { &m.prefix },
+                |m: &mut AttachmentStorage| { &mut m.prefix },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AttachmentStorage>(
+                "AttachmentStorage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AttachmentStorage {
+        static instance: ::protobuf::rt::LazyV2<AttachmentStorage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AttachmentStorage::new)
+    }
+}
+
+impl ::protobuf::Clear for AttachmentStorage {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.endpoint.clear();
+        self.bucket.clear();
+        self.region.clear();
+        self.access_key_id.clear();
+        self.has_secret = false;
+        self.prefix.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AttachmentStorage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AttachmentStorage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateAttachmentStorageRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub endpoint: ::std::string::String,
+    pub bucket: ::std::string::String,
+    pub region: ::std::string::String,
+    pub access_key_id: ::std::string::String,
+    pub secret_access_key: ::std::string::String,
+    pub prefix: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateAttachmentStorageRequest {
+    fn default() -> &'a UpdateAttachmentStorageRequest {
+        <UpdateAttachmentStorageRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateAttachmentStorageRequest {
+    pub fn new() -> UpdateAttachmentStorageRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string endpoint = 2;
+
+
+    pub fn get_endpoint(&self) -> &str {
+        &self.endpoint
+    }
+    pub fn clear_endpoint(&mut self) {
+        self.endpoint.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_endpoint(&mut self, v: ::std::string::String) {
+        self.endpoint = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_endpoint(&mut self) -> &mut ::std::string::String {
+        &mut self.endpoint
+    }
+
+    // Take field
+    pub fn take_endpoint(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.endpoint, ::std::string::String::new())
+    }
+
+    // string bucket = 3;
+
+
+    pub fn get_bucket(&self) -> &str {
+        &self.bucket
+    }
+    pub fn clear_bucket(&mut self) {
+        self.bucket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bucket(&mut self, v: ::std::string::String) {
+        self.bucket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bucket(&mut self) -> &mut ::std::string::String {
+        &mut self.bucket
+    }
+
+    // Take field
+    pub fn take_bucket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.bucket, ::std::string::String::new())
+    }
+
+    // string region = 4;
+
+
+    pub fn get_region(&self) -> &str {
+        &self.region
+    }
+    pub fn clear_region(&mut self) {
+        self.region.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_region(&mut self, v: ::std::string::String) {
+        self.region = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_region(&mut self) -> &mut ::std::string::String {
+        &mut self.region
+    }
+
+    // Take field
+    pub fn take_region(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.region, ::std::string::String::new())
+    }
+
+    // string access_key_id = 5;
+
+
+    pub fn get_access_key_id(&self) -> &str {
+        &self.access_key_id
+    }
+    pub fn clear_access_key_id(&mut self) {
+        self.access_key_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_access_key_id(&mut self, v: ::std::string::String) {
+        self.access_key_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_access_key_id(&mut self) -> &mut ::std::string::String {
+        &mut self.access_key_id
+    }
+
+    // Take field
+    pub fn take_access_key_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.access_key_id, ::std::string::String::new())
+    }
+
+    // string secret_access_key = 6;
+
+
+    pub fn get_secret_access_key(&self) -> &str {
+        &self.secret_access_key
+    }
+    pub fn clear_secret_access_key(&mut self) {
+        self.secret_access_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret_access_key(&mut self, v: ::std::string::String) {
+        self.secret_access_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret_access_key(&mut self) -> &mut ::std::string::String {
+        &mut self.secret_access_key
+    }
+
+    // Take field
+    pub fn take_secret_access_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.secret_access_key, ::std::string::String::new())
+    }
+
+    // string prefix = 7;
+
+
+    pub fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+    pub fn clear_prefix(&mut self) {
+        self.prefix.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prefix(&mut self, v: ::std::string::String) {
+        self.prefix = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prefix(&mut self) -> &mut ::std::string::String {
+        &mut self.prefix
+    }
+
+    // Take field
+    pub fn take_prefix(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.prefix, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateAttachmentStorageRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.endpoint)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.bucket)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.region)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.access_key_id)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret_access_key)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.prefix)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.endpoint.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.endpoint);
+        }
+        if !self.bucket.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.bucket);
+        }
+        if !self.region.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.region);
+        }
+        if !self.access_key_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.access_key_id);
+        }
+        if !self.secret_access_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.secret_access_key);
+        }
+        if !self.prefix.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.prefix);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.endpoint.is_empty() {
+            os.write_string(2, &self.endpoint)?;
+        }
+        if !self.bucket.is_empty() {
+            os.write_string(3, &self.bucket)?;
+        }
+        if !self.region.is_empty() {
+            os.write_string(4, &self.region)?;
+        }
+        if !self.access_key_id.is_empty() {
+            os.write_string(5, &self.access_key_id)?;
+        }
+        if !self.secret_access_key.is_empty() {
+            os.write_string(6, &self.secret_access_key)?;
+        }
+        if !self.prefix.is_empty() {
+            os.write_string(7, &self.prefix)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateAttachmentStorageRequest {
+        UpdateAttachmentStorageRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &UpdateAttachmentStorageRequest| { &m.workspace_id },
+                |m: &mut UpdateAttachmentStorageRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "endpoint",
+                |m: &UpdateAttachmentStorageRequest| { &m.endpoint },
+                |m: &mut UpdateAttachmentStorageRequest| { &mut m.endpoint },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "bucket",
+                |m: &UpdateAttachmentStorageRequest| { &m.bucket },
+                |m: &mut UpdateAttachmentStorageRequest| { &mut m.bucket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "region",
+                |m: &UpdateAttachmentStorageRequest| { &m.region },
+                |m: &mut UpdateAttachmentStorageRequest| { &mut m.region },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "access_key_id",
+                |m: &UpdateAttachmentStorageRequest| { &m.access_key_id },
+                |m: &mut UpdateAttachmentStorageRequest| { &mut m.access_key_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "secret_access_key",
+                |m: &UpdateAttachmentStorageRequest| { &m.secret_access_key },
+                |m: &mut UpdateAttachmentStorageRequest| { &mut m.secret_access_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "prefix",
+                |m: &UpdateAttachmentStorageRequest| { &m.prefix },
+                |m: &mut UpdateAttachmentStorageRequest| { &mut m.prefix },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateAttachmentStorageRequest>(
+                "UpdateAttachmentStorageRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateAttachmentStorageRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateAttachmentStorageRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateAttachmentStorageRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateAttachmentStorageRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.endpoint.clear();
+        self.bucket.clear();
+        self.region.clear();
+        self.access_key_id.clear();
+        self.secret_access_key.clear();
+        self.prefix.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateAttachmentStorageRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateAttachmentStorageRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AttachmentSyncResult {
+    // message fields
+    pub uploaded_count: i64,
+    pub pending_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AttachmentSyncResult {
+    fn default() -> &'a AttachmentSyncResult {
+        <AttachmentSyncResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AttachmentSyncResult {
+    pub fn new() -> AttachmentSyncResult {
+        ::std::default::Default::default()
+    }
+
+    // int64 uploaded_count = 1;
+
+
+    pub fn get_uploaded_count(&self) -> i64 {
+        self.uploaded_count
+    }
+    pub fn clear_uploaded_count(&mut self) {
+        self.uploaded_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uploaded_count(&mut self, v: i64) {
+        self.uploaded_count = v;
+    }
+
+    // int64 pending_count = 2;
+
+
+    pub fn get_pending_count(&self) -> i64 {
+        self.pending_count
+    }
+    pub fn clear_pending_count(&mut self) {
+        self.pending_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending_count(&mut self, v: i64) {
+        self.pending_count = v;
+    }
+}
+
+impl ::protobuf::Message for AttachmentSyncResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.uploaded_count = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pending_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.uploaded_count != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.uploaded_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.pending_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.pending_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.uploaded_count != 0 {
+            os.write_int64(1, self.uploaded_count)?;
+        }
+        if self.pending_count != 0 {
+            os.write_int64(2, self.pending_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AttachmentSyncResult {
+        AttachmentSyncResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "uploaded_count",
+                |m: &AttachmentSyncResult| { &m.uploaded_count },
+                |m: &mut AttachmentSyncResult| { &mut m.uploaded_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pending_count",
+                |m: &AttachmentSyncResult| { &m.pending_count },
+                |m: &mut AttachmentSyncResult| { &mut m.pending_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AttachmentSyncResult>(
+                "AttachmentSyncResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AttachmentSyncResult {
+        static instance: ::protobuf::rt::LazyV2<AttachmentSyncResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AttachmentSyncResult::new)
+    }
+}
+
+impl ::protobuf::Clear for AttachmentSyncResult {
+    fn clear(&mut self) {
+        self.uploaded_count = 0;
+        self.pending_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AttachmentSyncResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AttachmentSyncResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\rstorage.proto\"\xed\x01\n\x11AttachmentStorage\x12#\n\x0cworkspace_i\
+    d\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x12\x1c\n\x08endpoint\x18\x02\
+    \x20\x01(\tR\x08endpointB\0\x12\x18\n\x06bucket\x18\x03\x20\x01(\tR\x06b\
+    ucketB\0\x12\x18\n\x06region\x18\x04\x20\x01(\tR\x06regionB\0\x12$\n\rac\
+    cess_key_id\x18\x05\x20\x01(\tR\x0baccessKeyIdB\0\x12\x1f\n\nhas_secret\
+    \x18\x06\x20\x01(\x08R\thasSecretB\0\x12\x18\n\x06prefix\x18\x07\x20\x01\
+    (\tR\x06prefixB\0:\0\"\x87\x02\n\x1eUpdateAttachmentStorageRequest\x12#\
+    \n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x12\x1c\n\x08en\
+    dpoint\x18\x02\x20\x01(\tR\x08endpointB\0\x12\x18\n\x06bucket\x18\x03\
+    \x20\x01(\tR\x06bucketB\0\x12\x18\n\x06region\x18\x04\x20\x01(\tR\x06reg\
+    ionB\0\x12$\n\raccess_key_id\x18\x05\x20\x01(\tR\x0baccessKeyIdB\0\x12,\
+    \n\x11secret_access_key\x18\x06\x20\x01(\tR\x0fsecretAccessKeyB\0\x12\
+    \x18\n\x06prefix\x18\x07\x20\x01(\tR\x06prefixB\0:\0\"h\n\x14AttachmentS\
+    yncResult\x12'\n\x0euploaded_count\x18\x01\x20\x01(\x03R\ruploadedCountB\
+    \0\x12%\n\rpending_count\x18\x02\x20\x01(\x03R\x0cpendingCountB\0:\0B\0b\
+    \x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -422,6 +422,9 @@ pub struct Trash {
     pub modified_time: i64,
     pub create_time: i64,
     pub ty: TrashType,
+    pub deleted_by: ::std::string::String,
+    pub parent_id: ::std::string::String,
+    pub position: i32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -534,6 +537,73 @@ impl Trash {
     pub fn set_ty(&mut self, v: TrashType) {
         self.ty = v;
     }
+
+    // string deleted_by = 6;
+
+
+    pub fn get_deleted_by(&self) -> &str {
+        &self.deleted_by
+    }
+    pub fn clear_deleted_by(&mut self) {
+        self.deleted_by.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_by(&mut self, v: ::std::string::String) {
+        self.deleted_by = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_deleted_by(&mut self) -> &mut ::std::string::String {
+        &mut self.deleted_by
+    }
+
+    // Take field
+    pub fn take_deleted_by(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.deleted_by, ::std::string::String::new())
+    }
+
+    // string parent_id = 7;
+
+
+    pub fn get_parent_id(&self) -> &str {
+        &self.parent_id
+    }
+    pub fn clear_parent_id(&mut self) {
+        self.parent_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: ::std::string::String) {
+        self.parent_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_parent_id(&mut self) -> &mut ::std::string::String {
+        &mut self.parent_id
+    }
+
+    // Take field
+    pub fn take_parent_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.parent_id, ::std::string::String::new())
+    }
+
+    // int32 position = 8;
+
+
+    pub fn get_position(&self) -> i32 {
+        self.position
+    }
+    pub fn clear_position(&mut self) {
+        self.position = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: i32) {
+        self.position = v;
+    }
 }
 
 impl ::protobuf::Message for Trash {
@@ -568,6 +638,19 @@ impl ::protobuf::Message for Trash {
                 5 => {
                     ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 5, &mut self.unknown_fields)?
                 },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.deleted_by)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.parent_id)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.position = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -595,6 +678,15 @@ impl ::protobuf::Message for Trash {
         if self.ty != TrashType::Unknown {
             my_size += ::protobuf::rt::enum_size(5, self.ty);
         }
+        if !self.deleted_by.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.deleted_by);
+        }
+        if !self.parent_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.parent_id);
+        }
+        if self.position != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.position, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -616,6 +708,15 @@ impl ::protobuf::Message for Trash {
         if self.ty != TrashType::Unknown {
             os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.ty))?;
         }
+        if !self.deleted_by.is_empty() {
+            os.write_string(6, &self.deleted_by)?;
+        }
+        if !self.parent_id.is_empty() {
+            os.write_string(7, &self.parent_id)?;
+        }
+        if self.position != 0 {
+            os.write_int32(8, self.position)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -679,6 +780,21 @@ impl ::protobuf::Message for Trash {
                 |m: &Trash| { &m.ty },
                 |m: &mut Trash| { &mut m.ty },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deleted_by",
+                |m: &Trash| { &m.deleted_by },
+                |m: &mut Trash| { &mut m.deleted_by },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "parent_id",
+                |m: &Trash| { &m.parent_id },
+                |m: &mut Trash| { &mut m.parent_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "position",
+                |m: &Trash| { &m.position },
+                |m: &mut Trash| { &mut m.position },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Trash>(
                 "Trash",
                 fields,
@@ -700,6 +816,9 @@ impl ::protobuf::Clear for Trash {
         self.modified_time = 0;
         self.create_time = 0;
         self.ty = TrashType::Unknown;
+        self.deleted_by.clear();
+        self.parent_id.clear();
+        self.position = 0;
         self.unknown_fields.clear();
     }
 }
@@ -887,6 +1006,7 @@ pub enum TrashType {
     Unknown = 0,
     View = 1,
     App = 2,
+    Workspace = 3,
 }
 
 impl ::protobuf::ProtobufEnum for TrashType {
@@ -899,6 +1019,7 @@ impl ::protobuf::ProtobufEnum for TrashType {
             0 => ::std::option::Option::Some(TrashType::Unknown),
             1 => ::std::option::Option::Some(TrashType::View),
             2 => ::std::option::Option::Some(TrashType::App),
+            3 => ::std::option::Option::Some(TrashType::Workspace),
             _ => ::std::option::Option::None
         }
     }
@@ -908,6 +1029,7 @@ impl ::protobuf::ProtobufEnum for TrashType {
             TrashType::Unknown,
             TrashType::View,
             TrashType::App,
+            TrashType::Workspace,
         ];
         values
     }
@@ -936,58 +1058,20 @@ impl ::protobuf::reflect::ProtobufValue for TrashType {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x12trash_create.proto\"P\n\x0fRepeatedTrashId\x12\x1e\n\x05items\x18\
-    \x01\x20\x03(\x0b2\x08.TrashIdR\x05items\x12\x1d\n\ndelete_all\x18\x02\
-    \x20\x01(\x08R\tdeleteAll\"5\n\x07TrashId\x12\x0e\n\x02id\x18\x01\x20\
-    \x01(\tR\x02id\x12\x1a\n\x02ty\x18\x02\x20\x01(\x0e2\n.TrashTypeR\x02ty\
-    \"\x8d\x01\n\x05Trash\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x12\
-    \n\x04name\x18\x02\x20\x01(\tR\x04name\x12#\n\rmodified_time\x18\x03\x20\
-    \x01(\x03R\x0cmodifiedTime\x12\x1f\n\x0bcreate_time\x18\x04\x20\x01(\x03\
-    R\ncreateTime\x12\x1a\n\x02ty\x18\x05\x20\x01(\x0e2\n.TrashTypeR\x02ty\"\
-    -\n\rRepeatedTrash\x12\x1c\n\x05items\x18\x01\x20\x03(\x0b2\x06.TrashR\
-    \x05items*+\n\tTrashType\x12\x0b\n\x07Unknown\x10\0\x12\x08\n\x04View\
-    \x10\x01\x12\x07\n\x03App\x10\x02J\xc7\x06\n\x06\x12\x04\0\0\x18\x01\n\
-    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\
-    \n\x03\x04\0\x01\x12\x03\x02\x08\x17\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
-    \x04\x1f\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x03\x04\x0c\n\x0c\n\x05\x04\
-    \0\x02\0\x06\x12\x03\x03\r\x14\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\
-    \x15\x1a\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x1d\x1e\n\x0b\n\x04\x04\
-    \0\x02\x01\x12\x03\x04\x04\x18\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\
-    \x04\x08\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\t\x13\n\x0c\n\x05\x04\
-    \0\x02\x01\x03\x12\x03\x04\x16\x17\n\n\n\x02\x04\x01\x12\x04\x06\0\t\x01\
-    \n\n\n\x03\x04\x01\x01\x12\x03\x06\x08\x0f\n\x0b\n\x04\x04\x01\x02\0\x12\
-    \x03\x07\x04\x12\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x07\x04\n\n\x0c\n\
-    \x05\x04\x01\x02\0\x01\x12\x03\x07\x0b\r\n\x0c\n\x05\x04\x01\x02\0\x03\
-    \x12\x03\x07\x10\x11\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x08\x04\x15\n\
-    \x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\x08\x04\r\n\x0c\n\x05\x04\x01\x02\
-    \x01\x01\x12\x03\x08\x0e\x10\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x08\
-    \x13\x14\n\n\n\x02\x04\x02\x12\x04\n\0\x10\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\n\x08\r\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0b\x04\x12\n\x0c\n\x05\
-    \x04\x02\x02\0\x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
-    \x03\x0b\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0b\x10\x11\n\x0b\n\
-    \x04\x04\x02\x02\x01\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\
-    \x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0c\x0b\x0f\n\
-    \x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0c\x12\x13\n\x0b\n\x04\x04\x02\
-    \x02\x02\x12\x03\r\x04\x1c\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\r\x04\
-    \t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\r\n\x17\n\x0c\n\x05\x04\x02\
-    \x02\x02\x03\x12\x03\r\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x0e\
-    \x04\x1a\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x0e\x04\t\n\x0c\n\x05\
-    \x04\x02\x02\x03\x01\x12\x03\x0e\n\x15\n\x0c\n\x05\x04\x02\x02\x03\x03\
-    \x12\x03\x0e\x18\x19\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x0f\x04\x15\n\
-    \x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x0f\x04\r\n\x0c\n\x05\x04\x02\x02\
-    \x04\x01\x12\x03\x0f\x0e\x10\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x0f\
-    \x13\x14\n\n\n\x02\x04\x03\x12\x04\x11\0\x13\x01\n\n\n\x03\x04\x03\x01\
-    \x12\x03\x11\x08\x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\x04\x1d\n\x0c\
-    \n\x05\x04\x03\x02\0\x04\x12\x03\x12\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\
-    \x06\x12\x03\x12\r\x12\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x12\x13\x18\
-    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\x1b\x1c\n\n\n\x02\x05\0\x12\
-    \x04\x14\0\x18\x01\n\n\n\x03\x05\0\x01\x12\x03\x14\x05\x0e\n\x0b\n\x04\
-    \x05\0\x02\0\x12\x03\x15\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x15\
-    \x04\x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x15\x0e\x0f\n\x0b\n\x04\x05\
-    \0\x02\x01\x12\x03\x16\x04\r\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x16\
-    \x04\x08\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x16\x0b\x0c\n\x0b\n\x04\
-    \x05\0\x02\x02\x12\x03\x17\x04\x0c\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
-    \x17\x04\x07\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x17\n\x0bb\x06proto3\
+    \n\x12trash_create.proto\"V\n\x0fRepeatedTrashId\x12\x20\n\x05items\x18\
+    \x01\x20\x03(\x0b2\x08.TrashIdR\x05itemsB\0\x12\x1f\n\ndelete_all\x18\
+    \x02\x20\x01(\x08R\tdeleteAllB\0:\0\";\n\x07TrashId\x12\x10\n\x02id\x18\
+    \x01\x20\x01(\tR\x02idB\0\x12\x1c\n\x02ty\x18\x02\x20\x01(\x0e2\n.TrashT\
+    ypeR\x02tyB\0:\0\"\xf7\x01\n\x05Trash\x12\x10\n\x02id\x18\x01\x20\x01(\t\
+    R\x02idB\0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12%\n\rmodi\
+    fied_time\x18\x03\x20\x01(\x03R\x0cmodifiedTimeB\0\x12!\n\x0bcreate_time\
+    \x18\x04\x20\x01(\x03R\ncreateTimeB\0\x12\x1c\n\x02ty\x18\x05\x20\x01(\
+    \x0e2\n.TrashTypeR\x02tyB\0\x12\x1f\n\ndeleted_by\x18\x06\x20\x01(\tR\td\
+    eletedByB\0\x12\x1d\n\tparent_id\x18\x07\x20\x01(\tR\x08parentIdB\0\x12\
+    \x1c\n\x08position\x18\x08\x20\x01(\x05R\x08positionB\0:\0\"1\n\rRepeate\
+    dTrash\x12\x1e\n\x05items\x18\x01\x20\x03(\x0b2\x06.TrashR\x05itemsB\0:\
+    \0*<\n\tTrashType\x12\x0b\n\x07Unknown\x10\0\x12\x08\n\x04View\x10\x01\
+    \x12\x07\n\x03App\x10\x02\x12\r\n\tWorkspace\x10\x03\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
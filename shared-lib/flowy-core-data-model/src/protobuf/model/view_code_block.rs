@@ -0,0 +1,925 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_code_block.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryCodeBlockRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub index: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryCodeBlockRequest {
+    fn default() -> &'a QueryCodeBlockRequest {
+        <QueryCodeBlockRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryCodeBlockRequest {
+    pub fn new() -> QueryCodeBlockRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // int64 index = 2;
+
+
+    pub fn get_index(&self) -> i64 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i64) {
+        self.index = v;
+    }
+}
+
+impl ::protobuf::Message for QueryCodeBlockRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.index != 0 {
+            os.write_int64(2, self.index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryCodeBlockRequest {
+        QueryCodeBlockRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &QueryCodeBlockRequest| { &m.view_id },
+                |m: &mut QueryCodeBlockRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "index",
+                |m: &QueryCodeBlockRequest| { &m.index },
+                |m: &mut QueryCodeBlockRequest| { &mut m.index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryCodeBlockRequest>(
+                "QueryCodeBlockRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryCodeBlockRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryCodeBlockRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryCodeBlockRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryCodeBlockRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryCodeBlockRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryCodeBlockRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyntaxToken {
+    // message fields
+    pub start: i64,
+    pub len: i64,
+    pub scope: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyntaxToken {
+    fn default() -> &'a SyntaxToken {
+        <SyntaxToken as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyntaxToken {
+    pub fn new() -> SyntaxToken {
+        ::std::default::Default::default()
+    }
+
+    // int64 start = 1;
+
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+    pub fn clear_start(&mut self) {
+        self.start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.start = v;
+    }
+
+    // int64 len = 2;
+
+
+    pub fn get_len(&self) -> i64 {
+        self.len
+    }
+    pub fn clear_len(&mut self) {
+        self.len = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_len(&mut self, v: i64) {
+        self.len = v;
+    }
+
+    // string scope = 3;
+
+
+    pub fn get_scope(&self) -> &str {
+        &self.scope
+    }
+    pub fn clear_scope(&mut self) {
+        self.scope.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scope(&mut self, v: ::std::string::String) {
+        self.scope = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_scope(&mut self) -> &mut ::std::string::String {
+        &mut self.scope
+    }
+
+    // Take field
+    pub fn take_scope(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.scope, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SyntaxToken {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.len = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.scope)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.start != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.len != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.len, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.scope.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.scope);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.start != 0 {
+            os.write_int64(1, self.start)?;
+        }
+        if self.len != 0 {
+            os.write_int64(2, self.len)?;
+        }
+        if !self.scope.is_empty() {
+            os.write_string(3, &self.scope)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyntaxToken {
+        SyntaxToken::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start",
+                |m: &SyntaxToken| { &m.start },
+                |m: &mut SyntaxToken| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "len",
+                |m: &SyntaxToken| { &m.len },
+                |m: &mut SyntaxToken| { &mut m.len },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "scope",
+                |m: &SyntaxToken| { &m.scope },
+                |m: &mut SyntaxToken| { &mut m.scope },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyntaxToken>(
+                "SyntaxToken",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyntaxToken {
+        static instance: ::protobuf::rt::LazyV2<SyntaxToken> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyntaxToken::new)
+    }
+}
+
+impl ::protobuf::Clear for SyntaxToken {
+    fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+        self.scope.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyntaxToken {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyntaxToken {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedSyntaxToken {
+    // message fields
+    pub items: ::protobuf::RepeatedField<SyntaxToken>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedSyntaxToken {
+    fn default() -> &'a RepeatedSyntaxToken {
+        <RepeatedSyntaxToken as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedSyntaxToken {
+    pub fn new() -> RepeatedSyntaxToken {
+        ::std::default::Default::default()
+    }
+
+    // repeated .SyntaxToken items = 1;
+
+
+    pub fn get_items(&self) -> &[SyntaxToken] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<SyntaxToken>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<SyntaxToken> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<SyntaxToken> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedSyntaxToken {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedSyntaxToken {
+        RepeatedSyntaxToken::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SyntaxToken>>(
+                "items",
+                |m: &RepeatedSyntaxToken| { &m.items },
+                |m: &mut RepeatedSyntaxToken| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedSyntaxToken>(
+                "RepeatedSyntaxToken",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedSyntaxToken {
+        static instance: ::protobuf::rt::LazyV2<RepeatedSyntaxToken> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedSyntaxToken::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedSyntaxToken {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedSyntaxToken {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedSyntaxToken {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CodeBlockTokens {
+    // message fields
+    pub start: i64,
+    pub len: i64,
+    pub language: ::std::string::String,
+    pub tokens: ::protobuf::SingularPtrField<RepeatedSyntaxToken>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CodeBlockTokens {
+    fn default() -> &'a CodeBlockTokens {
+        <CodeBlockTokens as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CodeBlockTokens {
+    pub fn new() -> CodeBlockTokens {
+        ::std::default::Default::default()
+    }
+
+    // int64 start = 1;
+
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+    pub fn clear_start(&mut self) {
+        self.start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.start = v;
+    }
+
+    // int64 len = 2;
+
+
+    pub fn get_len(&self) -> i64 {
+        self.len
+    }
+    pub fn clear_len(&mut self) {
+        self.len = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_len(&mut self, v: i64) {
+        self.len = v;
+    }
+
+    // string language = 3;
+
+
+    pub fn get_language(&self) -> &str {
+        &self.language
+    }
+    pub fn clear_language(&mut self) {
+        self.language.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_language(&mut self, v: ::std::string::String) {
+        self.language = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_language(&mut self) -> &mut ::std::string::String {
+        &mut self.language
+    }
+
+    // Take field
+    pub fn take_language(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.language, ::std::string::String::new())
+    }
+
+    // .RepeatedSyntaxToken tokens = 4;
+
+
+    pub fn get_tokens(&self) -> &RepeatedSyntaxToken {
+        self.tokens.as_ref().unwrap_or_else(|| <RepeatedSyntaxToken as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_tokens(&mut self) {
+        self.tokens.clear();
+    }
+
+    pub fn has_tokens(&self) -> bool {
+        self.tokens.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tokens(&mut self, v: RepeatedSyntaxToken) {
+        self.tokens = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tokens(&mut self) -> &mut RepeatedSyntaxToken {
+        if self.tokens.is_none() {
+            self.tokens.set_default();
+        }
+        self.tokens.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_tokens(&mut self) -> RepeatedSyntaxToken {
+        self.tokens.take().unwrap_or_else(|| RepeatedSyntaxToken::new())
+    }
+}
+
+impl ::protobuf::Message for CodeBlockTokens {
+    fn is_initialized(&self) -> bool {
+        for v in &self.tokens {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.len = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.language)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.tokens)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.start != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.len != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.len, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.language.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.language);
+        }
+        if let Some(ref v) = self.tokens.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.start != 0 {
+            os.write_int64(1, self.start)?;
+        }
+        if self.len != 0 {
+            os.write_int64(2, self.len)?;
+        }
+        if !self.language.is_empty() {
+            os.write_string(3, &self.language)?;
+        }
+        if let Some(ref v) = self.tokens.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CodeBlockTokens {
+        CodeBlockTokens::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start",
+                |m: &CodeBlockTokens| { &m.start },
+                |m: &mut CodeBlockTokens| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "len",
+                |m: &CodeBlockTokens| { &m.len },
+                |m: &mut CodeBlockTokens| { &mut m.len },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "language",
+                |m: &CodeBlockTokens| { &m.language },
+                |m: &mut CodeBlockTokens| { &mut m.language },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RepeatedSyntaxToken>>(
+                "tokens",
+                |m: &CodeBlockTokens| { &m.tokens },
+                |m: &mut CodeBlockTokens| { &mut m.tokens },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CodeBlockTokens>(
+                "CodeBlockTokens",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CodeBlockTokens {
+        static instance: ::protobuf::rt::LazyV2<CodeBlockTokens> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CodeBlockTokens::new)
+    }
+}
+
+impl ::protobuf::Clear for CodeBlockTokens {
+    fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+        self.language.clear();
+        self.tokens.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CodeBlockTokens {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CodeBlockTokens {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x15view_code_block.proto\"L\n\x15QueryCodeBlockRequest\x12\x19\n\x07v\
+    iew_id\x18\x01\x20\x01(\tR\x06viewIdB\0\x12\x16\n\x05index\x18\x02\x20\
+    \x01(\x03R\x05indexB\0:\0\"S\n\x0bSyntaxToken\x12\x16\n\x05start\x18\x01\
+    \x20\x01(\x03R\x05startB\0\x12\x12\n\x03len\x18\x02\x20\x01(\x03R\x03len\
+    B\0\x12\x16\n\x05scope\x18\x03\x20\x01(\tR\x05scopeB\0:\0\"=\n\x13Repeat\
+    edSyntaxToken\x12$\n\x05items\x18\x01\x20\x03(\x0b2\x0c.SyntaxTokenR\x05\
+    itemsB\0:\0\"\x8d\x01\n\x0fCodeBlockTokens\x12\x16\n\x05start\x18\x01\
+    \x20\x01(\x03R\x05startB\0\x12\x12\n\x03len\x18\x02\x20\x01(\x03R\x03len\
+    B\0\x12\x1c\n\x08language\x18\x03\x20\x01(\tR\x08languageB\0\x12.\n\x06t\
+    okens\x18\x04\x20\x01(\x0b2\x14.RepeatedSyntaxTokenR\x06tokensB\0:\0B\0b\
+    \x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
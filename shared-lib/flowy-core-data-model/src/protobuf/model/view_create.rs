@@ -29,9 +29,10 @@ pub struct CreateViewRequest {
     pub belong_to_id: ::std::string::String,
     pub name: ::std::string::String,
     pub desc: ::std::string::String,
-    pub view_type: ViewType,
     // message oneof groups
     pub one_of_thumbnail: ::std::option::Option<CreateViewRequest_oneof_one_of_thumbnail>,
+    pub one_of_view_type: ::std::option::Option<CreateViewRequest_oneof_one_of_view_type>,
+    pub one_of_view_id: ::std::option::Option<CreateViewRequest_oneof_one_of_view_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -48,6 +49,16 @@ pub enum CreateViewRequest_oneof_one_of_thumbnail {
     thumbnail(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewRequest_oneof_one_of_view_type {
+    view_type(ViewType),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewRequest_oneof_one_of_view_id {
+    view_id(::std::string::String),
+}
+
 impl CreateViewRequest {
     pub fn new() -> CreateViewRequest {
         ::std::default::Default::default()
@@ -184,15 +195,74 @@ impl CreateViewRequest {
 
 
     pub fn get_view_type(&self) -> ViewType {
-        self.view_type
+        match self.one_of_view_type {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_type::view_type(v)) => v,
+            _ => ViewType::Blank,
+        }
     }
     pub fn clear_view_type(&mut self) {
-        self.view_type = ViewType::Blank;
+        self.one_of_view_type = ::std::option::Option::None;
+    }
+
+    pub fn has_view_type(&self) -> bool {
+        match self.one_of_view_type {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_type::view_type(..)) => true,
+            _ => false,
+        }
     }
 
     // Param is passed by value, moved
     pub fn set_view_type(&mut self, v: ViewType) {
-        self.view_type = v;
+        self.one_of_view_type = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_type::view_type(v))
+    }
+
+    // string view_id = 6;
+
+
+    pub fn get_view_id(&self) -> &str {
+        match self.one_of_view_id {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_id::view_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_view_id(&mut self) {
+        self.one_of_view_id = ::std::option::Option::None;
+    }
+
+    pub fn has_view_id(&self) -> bool {
+        match self.one_of_view_id {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_id::view_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.one_of_view_id = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_id::view_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_id::view_id(_)) = self.one_of_view_id {
+        } else {
+            self.one_of_view_id = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_id::view_id(::std::string::String::new()));
+        }
+        match self.one_of_view_id {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_id::view_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        if self.has_view_id() {
+            match self.one_of_view_id.take() {
+                ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_id::view_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
     }
 }
 
@@ -221,7 +291,16 @@ impl ::protobuf::Message for CreateViewRequest {
                     self.one_of_thumbnail = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_thumbnail::thumbnail(is.read_string()?));
                 },
                 5 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.view_type, 5, &mut self.unknown_fields)?
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_view_type = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_type::view_type(is.read_enum()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_view_id = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_view_id::view_id(is.read_string()?));
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -244,9 +323,6 @@ impl ::protobuf::Message for CreateViewRequest {
         if !self.desc.is_empty() {
             my_size += ::protobuf::rt::string_size(3, &self.desc);
         }
-        if self.view_type != ViewType::Blank {
-            my_size += ::protobuf::rt::enum_size(5, self.view_type);
-        }
         if let ::std::option::Option::Some(ref v) = self.one_of_thumbnail {
             match v {
                 &CreateViewRequest_oneof_one_of_thumbnail::thumbnail(ref v) => {
@@ -254,6 +330,20 @@ impl ::protobuf::Message for CreateViewRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_view_type {
+            match v {
+                &CreateViewRequest_oneof_one_of_view_type::view_type(v) => {
+                    my_size += ::protobuf::rt::enum_size(5, v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_view_id {
+            match v {
+                &CreateViewRequest_oneof_one_of_view_id::view_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -269,9 +359,6 @@ impl ::protobuf::Message for CreateViewRequest {
         if !self.desc.is_empty() {
             os.write_string(3, &self.desc)?;
         }
-        if self.view_type != ViewType::Blank {
-            os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.view_type))?;
-        }
         if let ::std::option::Option::Some(ref v) = self.one_of_thumbnail {
             match v {
                 &CreateViewRequest_oneof_one_of_thumbnail::thumbnail(ref v) => {
@@ -279,6 +366,20 @@ impl ::protobuf::Message for CreateViewRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_view_type {
+            match v {
+                &CreateViewRequest_oneof_one_of_view_type::view_type(v) => {
+                    os.write_enum(5, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_view_id {
+            match v {
+                &CreateViewRequest_oneof_one_of_view_id::view_id(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -337,10 +438,15 @@ impl ::protobuf::Message for CreateViewRequest {
                 CreateViewRequest::has_thumbnail,
                 CreateViewRequest::get_thumbnail,
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ViewType>>(
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, ViewType>(
                 "view_type",
-                |m: &CreateViewRequest| { &m.view_type },
-                |m: &mut CreateViewRequest| { &mut m.view_type },
+                CreateViewRequest::has_view_type,
+                CreateViewRequest::get_view_type,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "view_id",
+                CreateViewRequest::has_view_id,
+                CreateViewRequest::get_view_id,
             ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateViewRequest>(
                 "CreateViewRequest",
@@ -362,7 +468,8 @@ impl ::protobuf::Clear for CreateViewRequest {
         self.name.clear();
         self.desc.clear();
         self.one_of_thumbnail = ::std::option::Option::None;
-        self.view_type = ViewType::Blank;
+        self.one_of_view_type = ::std::option::Option::None;
+        self.one_of_view_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -1427,6 +1534,10 @@ impl ::protobuf::reflect::ProtobufValue for RepeatedView {
 pub enum ViewType {
     Blank = 0,
     Doc = 1,
+    Grid = 2,
+    Board = 3,
+    Calendar = 4,
+    Checklist = 5,
 }
 
 impl ::protobuf::ProtobufEnum for ViewType {
@@ -1438,6 +1549,10 @@ impl ::protobuf::ProtobufEnum for ViewType {
         match value {
             0 => ::std::option::Option::Some(ViewType::Blank),
             1 => ::std::option::Option::Some(ViewType::Doc),
+            2 => ::std::option::Option::Some(ViewType::Grid),
+            3 => ::std::option::Option::Some(ViewType::Board),
+            4 => ::std::option::Option::Some(ViewType::Calendar),
+            5 => ::std::option::Option::Some(ViewType::Checklist),
             _ => ::std::option::Option::None
         }
     }
@@ -1446,6 +1561,10 @@ impl ::protobuf::ProtobufEnum for ViewType {
         static values: &'static [ViewType] = &[
             ViewType::Blank,
             ViewType::Doc,
+            ViewType::Grid,
+            ViewType::Board,
+            ViewType::Calendar,
+            ViewType::Checklist,
         ];
         values
     }
@@ -1474,99 +1593,31 @@ impl ::protobuf::reflect::ProtobufValue for ViewType {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x11view_create.proto\"\xb9\x01\n\x11CreateViewRequest\x12\x20\n\x0cbe\
-    long_to_id\x18\x01\x20\x01(\tR\nbelongToId\x12\x12\n\x04name\x18\x02\x20\
-    \x01(\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\x12\x1e\n\
-    \tthumbnail\x18\x04\x20\x01(\tH\0R\tthumbnail\x12&\n\tview_type\x18\x05\
-    \x20\x01(\x0e2\t.ViewTypeR\x08viewTypeB\x12\n\x10one_of_thumbnail\"\xd8\
-    \x01\n\x10CreateViewParams\x12\x20\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\
-    \nbelongToId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04\
-    desc\x18\x03\x20\x01(\tR\x04desc\x12\x1c\n\tthumbnail\x18\x04\x20\x01(\t\
-    R\tthumbnail\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08view\
-    Type\x12\x1b\n\tview_data\x18\x06\x20\x01(\tR\x08viewData\x12\x17\n\x07v\
-    iew_id\x18\x07\x20\x01(\tR\x06viewId\"\x97\x02\n\x04View\x12\x0e\n\x02id\
-    \x18\x01\x20\x01(\tR\x02id\x12\x20\n\x0cbelong_to_id\x18\x02\x20\x01(\tR\
-    \nbelongToId\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x12\n\x04\
-    desc\x18\x04\x20\x01(\tR\x04desc\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\
-    \t.ViewTypeR\x08viewType\x12\x18\n\x07version\x18\x06\x20\x01(\x03R\x07v\
-    ersion\x12-\n\nbelongings\x18\x07\x20\x01(\x0b2\r.RepeatedViewR\nbelongi\
-    ngs\x12#\n\rmodified_time\x18\x08\x20\x01(\x03R\x0cmodifiedTime\x12\x1f\
-    \n\x0bcreate_time\x18\t\x20\x01(\x03R\ncreateTime\"+\n\x0cRepeatedView\
-    \x12\x1b\n\x05items\x18\x01\x20\x03(\x0b2\x05.ViewR\x05items*\x1e\n\x08V\
-    iewType\x12\t\n\x05Blank\x10\0\x12\x07\n\x03Doc\x10\x01J\xbf\x0b\n\x06\
-    \x12\x04\0\0#\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\
-    \x04\x02\0\x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\x0b\n\x04\
-    \x04\0\x02\0\x12\x03\x03\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\
-    \x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x17\n\x0c\n\x05\x04\0\
-    \x02\0\x03\x12\x03\x03\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\
-    \x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\
-    \x02\x01\x01\x12\x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
-    \x04\x12\x13\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\n\x05\
-    \x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\
-    \x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\x0b\
-    \n\x04\x04\0\x08\0\x12\x03\x06\x044\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\
-    \x06\n\x1a\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x1d2\n\x0c\n\x05\x04\0\
-    \x02\x03\x05\x12\x03\x06\x1d#\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06$\
-    -\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x0601\n\x0b\n\x04\x04\0\x02\x04\
-    \x12\x03\x07\x04\x1b\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\x07\x04\x0c\n\
-    \x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\r\x16\n\x0c\n\x05\x04\0\x02\x04\
-    \x03\x12\x03\x07\x19\x1a\n\n\n\x02\x04\x01\x12\x04\t\0\x11\x01\n\n\n\x03\
-    \x04\x01\x01\x12\x03\t\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\n\x04\
-    \x1c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\
-    \x02\0\x01\x12\x03\n\x0b\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\n\x1a\
-    \x1b\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0b\x04\x14\n\x0c\n\x05\x04\x01\
-    \x02\x01\x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\
-    \x0b\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b\x12\x13\n\x0b\n\
-    \x04\x04\x01\x02\x02\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x01\x02\x02\x05\
-    \x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x0c\x0b\x0f\n\
-    \x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0c\x12\x13\n\x0b\n\x04\x04\x01\
-    \x02\x03\x12\x03\r\x04\x19\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\r\x04\
-    \n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\r\x0b\x14\n\x0c\n\x05\x04\x01\
-    \x02\x03\x03\x12\x03\r\x17\x18\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\x0e\
-    \x04\x1b\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\x0e\x04\x0c\n\x0c\n\x05\
-    \x04\x01\x02\x04\x01\x12\x03\x0e\r\x16\n\x0c\n\x05\x04\x01\x02\x04\x03\
-    \x12\x03\x0e\x19\x1a\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\x0f\x04\x19\n\
-    \x0c\n\x05\x04\x01\x02\x05\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x01\x02\
-    \x05\x01\x12\x03\x0f\x0b\x14\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03\x0f\
-    \x17\x18\n\x0b\n\x04\x04\x01\x02\x06\x12\x03\x10\x04\x17\n\x0c\n\x05\x04\
-    \x01\x02\x06\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\x01\x02\x06\x01\x12\
-    \x03\x10\x0b\x12\n\x0c\n\x05\x04\x01\x02\x06\x03\x12\x03\x10\x15\x16\n\n\
-    \n\x02\x04\x02\x12\x04\x12\0\x1c\x01\n\n\n\x03\x04\x02\x01\x12\x03\x12\
-    \x08\x0c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x13\x04\x12\n\x0c\n\x05\x04\
-    \x02\x02\0\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
-    \x13\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x13\x10\x11\n\x0b\n\x04\
-    \x04\x02\x02\x01\x12\x03\x14\x04\x1c\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\
-    \x03\x14\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x14\x0b\x17\n\x0c\
-    \n\x05\x04\x02\x02\x01\x03\x12\x03\x14\x1a\x1b\n\x0b\n\x04\x04\x02\x02\
-    \x02\x12\x03\x15\x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x15\x04\
-    \n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x15\x0b\x0f\n\x0c\n\x05\x04\
-    \x02\x02\x02\x03\x12\x03\x15\x12\x13\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\
-    \x16\x04\x14\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x16\x04\n\n\x0c\n\
-    \x05\x04\x02\x02\x03\x01\x12\x03\x16\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x03\
-    \x03\x12\x03\x16\x12\x13\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x17\x04\x1b\
-    \n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x17\x04\x0c\n\x0c\n\x05\x04\x02\
-    \x02\x04\x01\x12\x03\x17\r\x16\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\
-    \x17\x19\x1a\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x18\x04\x16\n\x0c\n\x05\
-    \x04\x02\x02\x05\x05\x12\x03\x18\x04\t\n\x0c\n\x05\x04\x02\x02\x05\x01\
-    \x12\x03\x18\n\x11\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\x18\x14\x15\n\
-    \x0b\n\x04\x04\x02\x02\x06\x12\x03\x19\x04\x20\n\x0c\n\x05\x04\x02\x02\
-    \x06\x06\x12\x03\x19\x04\x10\n\x0c\n\x05\x04\x02\x02\x06\x01\x12\x03\x19\
-    \x11\x1b\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03\x19\x1e\x1f\n\x0b\n\x04\
-    \x04\x02\x02\x07\x12\x03\x1a\x04\x1c\n\x0c\n\x05\x04\x02\x02\x07\x05\x12\
-    \x03\x1a\x04\t\n\x0c\n\x05\x04\x02\x02\x07\x01\x12\x03\x1a\n\x17\n\x0c\n\
-    \x05\x04\x02\x02\x07\x03\x12\x03\x1a\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x08\
-    \x12\x03\x1b\x04\x1a\n\x0c\n\x05\x04\x02\x02\x08\x05\x12\x03\x1b\x04\t\n\
-    \x0c\n\x05\x04\x02\x02\x08\x01\x12\x03\x1b\n\x15\n\x0c\n\x05\x04\x02\x02\
-    \x08\x03\x12\x03\x1b\x18\x19\n\n\n\x02\x04\x03\x12\x04\x1d\0\x1f\x01\n\n\
-    \n\x03\x04\x03\x01\x12\x03\x1d\x08\x14\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
-    \x1e\x04\x1c\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x1e\x04\x0c\n\x0c\n\
-    \x05\x04\x03\x02\0\x06\x12\x03\x1e\r\x11\n\x0c\n\x05\x04\x03\x02\0\x01\
-    \x12\x03\x1e\x12\x17\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x1e\x1a\x1b\n\
-    \n\n\x02\x05\0\x12\x04\x20\0#\x01\n\n\n\x03\x05\0\x01\x12\x03\x20\x05\r\
-    \n\x0b\n\x04\x05\0\x02\0\x12\x03!\x04\x0e\n\x0c\n\x05\x05\0\x02\0\x01\
-    \x12\x03!\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03!\x0c\r\n\x0b\n\x04\
-    \x05\0\x02\x01\x12\x03\"\x04\x0c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\"\
-    \x04\x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\"\n\x0bb\x06proto3\
+    \n\x11view_create.proto\"\x8a\x02\n\x11CreateViewRequest\x12\"\n\x0cbelo\
+    ng_to_id\x18\x01\x20\x01(\tR\nbelongToIdB\0\x12\x14\n\x04name\x18\x02\
+    \x20\x01(\tR\x04nameB\0\x12\x14\n\x04desc\x18\x03\x20\x01(\tR\x04descB\0\
+    \x12\x20\n\tthumbnail\x18\x04\x20\x01(\tH\0R\tthumbnailB\0\x12*\n\tview_\
+    type\x18\x05\x20\x01(\x0e2\t.ViewTypeH\x01R\x08viewTypeB\0\x12\x1b\n\x07\
+    view_id\x18\x06\x20\x01(\tH\x02R\x06viewIdB\0B\x12\n\x10one_of_thumbnail\
+    B\x12\n\x10one_of_view_typeB\x10\n\x0eone_of_view_id:\0\"\xe8\x01\n\x10C\
+    reateViewParams\x12\"\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\nbelongToIdB\
+    \0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\x14\n\x04desc\
+    \x18\x03\x20\x01(\tR\x04descB\0\x12\x1e\n\tthumbnail\x18\x04\x20\x01(\tR\
+    \tthumbnailB\0\x12(\n\tview_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08vi\
+    ewTypeB\0\x12\x1d\n\tview_data\x18\x06\x20\x01(\tR\x08viewDataB\0\x12\
+    \x19\n\x07view_id\x18\x07\x20\x01(\tR\x06viewIdB\0:\0\"\xab\x02\n\x04Vie\
+    w\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\"\n\x0cbelong_to_id\
+    \x18\x02\x20\x01(\tR\nbelongToIdB\0\x12\x14\n\x04name\x18\x03\x20\x01(\t\
+    R\x04nameB\0\x12\x14\n\x04desc\x18\x04\x20\x01(\tR\x04descB\0\x12(\n\tvi\
+    ew_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08viewTypeB\0\x12\x1a\n\x07ve\
+    rsion\x18\x06\x20\x01(\x03R\x07versionB\0\x12/\n\nbelongings\x18\x07\x20\
+    \x01(\x0b2\r.RepeatedViewR\nbelongingsB\0\x12%\n\rmodified_time\x18\x08\
+    \x20\x01(\x03R\x0cmodifiedTimeB\0\x12!\n\x0bcreate_time\x18\t\x20\x01(\
+    \x03R\ncreateTimeB\0:\0\"/\n\x0cRepeatedView\x12\x1d\n\x05items\x18\x01\
+    \x20\x03(\x0b2\x05.ViewR\x05itemsB\0:\0*R\n\x08ViewType\x12\t\n\x05Blank\
+    \x10\0\x12\x07\n\x03Doc\x10\x01\x12\x08\n\x04Grid\x10\x02\x12\t\n\x05Boa\
+    rd\x10\x03\x12\x0c\n\x08Calendar\x10\x04\x12\r\n\tChecklist\x10\x05\x1a\
+    \0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,1829 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_move.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveViewRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub to_belong_to_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveViewRequest {
+    fn default() -> &'a MoveViewRequest {
+        <MoveViewRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveViewRequest {
+    pub fn new() -> MoveViewRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string to_belong_to_id = 2;
+
+
+    pub fn get_to_belong_to_id(&self) -> &str {
+        &self.to_belong_to_id
+    }
+    pub fn clear_to_belong_to_id(&mut self) {
+        self.to_belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_belong_to_id(&mut self, v: ::std::string::String) {
+        self.to_belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.to_belong_to_id
+    }
+
+    // Take field
+    pub fn take_to_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.to_belong_to_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for MoveViewRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.to_belong_to_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.to_belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.to_belong_to_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.to_belong_to_id.is_empty() {
+            os.write_string(2, &self.to_belong_to_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveViewRequest {
+        MoveViewRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &MoveViewRequest| { &m.view_id },
+                |m: &mut MoveViewRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "to_belong_to_id",
+                |m: &MoveViewRequest| { &m.to_belong_to_id },
+                |m: &mut MoveViewRequest| { &mut m.to_belong_to_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveViewRequest>(
+                "MoveViewRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveViewRequest {
+        static instance: ::protobuf::rt::LazyV2<MoveViewRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveViewRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveViewRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.to_belong_to_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveViewRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveViewRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveViewParams {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub to_belong_to_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveViewParams {
+    fn default() -> &'a MoveViewParams {
+        <MoveViewParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveViewParams {
+    pub fn new() -> MoveViewParams {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string to_belong_to_id = 2;
+
+
+    pub fn get_to_belong_to_id(&self) -> &str {
+        &self.to_belong_to_id
+    }
+    pub fn clear_to_belong_to_id(&mut self) {
+        self.to_belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_belong_to_id(&mut self, v: ::std::string::String) {
+        self.to_belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.to_belong_to_id
+    }
+
+    // Take field
+    pub fn take_to_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.to_belong_to_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for MoveViewParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.to_belong_to_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.to_belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.to_belong_to_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.to_belong_to_id.is_empty() {
+            os.write_string(2, &self.to_belong_to_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveViewParams {
+        MoveViewParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &MoveViewParams| { &m.view_id },
+                |m: &mut MoveViewParams| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "to_belong_to_id",
+                |m: &MoveViewParams| { &m.to_belong_to_id },
+                |m: &mut MoveViewParams| { &mut m.to_belong_to_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveViewParams>(
+                "MoveViewParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveViewParams {
+        static instance: ::protobuf::rt::LazyV2<MoveViewParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveViewParams::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveViewParams {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.to_belong_to_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveViewParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveViewParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewMoved {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub from_belong_to_id: ::std::string::String,
+    pub to_belong_to_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ViewMoved {
+    fn default() -> &'a ViewMoved {
+        <ViewMoved as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ViewMoved {
+    pub fn new() -> ViewMoved {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string from_belong_to_id = 2;
+
+
+    pub fn get_from_belong_to_id(&self) -> &str {
+        &self.from_belong_to_id
+    }
+    pub fn clear_from_belong_to_id(&mut self) {
+        self.from_belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from_belong_to_id(&mut self, v: ::std::string::String) {
+        self.from_belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.from_belong_to_id
+    }
+
+    // Take field
+    pub fn take_from_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.from_belong_to_id, ::std::string::String::new())
+    }
+
+    // string to_belong_to_id = 3;
+
+
+    pub fn get_to_belong_to_id(&self) -> &str {
+        &self.to_belong_to_id
+    }
+    pub fn clear_to_belong_to_id(&mut self) {
+        self.to_belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_belong_to_id(&mut self, v: ::std::string::String) {
+        self.to_belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.to_belong_to_id
+    }
+
+    // Take field
+    pub fn take_to_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.to_belong_to_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ViewMoved {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.from_belong_to_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.to_belong_to_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.from_belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.from_belong_to_id);
+        }
+        if !self.to_belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.to_belong_to_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.from_belong_to_id.is_empty() {
+            os.write_string(2, &self.from_belong_to_id)?;
+        }
+        if !self.to_belong_to_id.is_empty() {
+            os.write_string(3, &self.to_belong_to_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewMoved {
+        ViewMoved::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &ViewMoved| { &m.view_id },
+                |m: &mut ViewMoved| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "from_belong_to_id",
+                |m: &ViewMoved| { &m.from_belong_to_id },
+                |m: &mut ViewMoved| { &mut m.from_belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "to_belong_to_id",
+                |m: &ViewMoved| { &m.to_belong_to_id },
+                |m: &mut ViewMoved| { &mut m.to_belong_to_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewMoved>(
+                "ViewMoved",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ViewMoved {
+        static instance: ::protobuf::rt::LazyV2<ViewMoved> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewMoved::new)
+    }
+}
+
+impl ::protobuf::Clear for ViewMoved {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.from_belong_to_id.clear();
+        self.to_belong_to_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ViewMoved {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewMoved {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveItem {
+    // message fields
+    pub id: ::std::string::String,
+    pub ty: MoveItemType,
+    pub to_parent_id: ::std::string::String,
+    pub index: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveItem {
+    fn default() -> &'a MoveItem {
+        <MoveItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveItem {
+    pub fn new() -> MoveItem {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // .MoveItemType ty = 2;
+
+
+    pub fn get_ty(&self) -> MoveItemType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = MoveItemType::View;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: MoveItemType) {
+        self.ty = v;
+    }
+
+    // string to_parent_id = 3;
+
+
+    pub fn get_to_parent_id(&self) -> &str {
+        &self.to_parent_id
+    }
+    pub fn clear_to_parent_id(&mut self) {
+        self.to_parent_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_parent_id(&mut self, v: ::std::string::String) {
+        self.to_parent_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to_parent_id(&mut self) -> &mut ::std::string::String {
+        &mut self.to_parent_id
+    }
+
+    // Take field
+    pub fn take_to_parent_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.to_parent_id, ::std::string::String::new())
+    }
+
+    // int32 index = 4;
+
+
+    pub fn get_index(&self) -> i32 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i32) {
+        self.index = v;
+    }
+}
+
+impl ::protobuf::Message for MoveItem {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.to_parent_id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.ty != MoveItemType::View {
+            my_size += ::protobuf::rt::enum_size(2, self.ty);
+        }
+        if !self.to_parent_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.to_parent_id);
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.ty != MoveItemType::View {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if !self.to_parent_id.is_empty() {
+            os.write_string(3, &self.to_parent_id)?;
+        }
+        if self.index != 0 {
+            os.write_int32(4, self.index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveItem {
+        MoveItem::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &MoveItem| { &m.id },
+                |m: &mut MoveItem| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<MoveItemType>>(
+                "ty",
+                |m: &MoveItem| { &m.ty },
+                |m: &mut MoveItem| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "to_parent_id",
+                |m: &MoveItem| { &m.to_parent_id },
+                |m: &mut MoveItem| { &mut m.to_parent_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "index",
+                |m: &MoveItem| { &m.index },
+                |m: &mut MoveItem| { &mut m.index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveItem>(
+                "MoveItem",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveItem {
+        static instance: ::protobuf::rt::LazyV2<MoveItem> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveItem::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveItem {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.ty = MoveItemType::View;
+        self.to_parent_id.clear();
+        self.index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveItem {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveItemsRequest {
+    // message fields
+    pub items: ::protobuf::RepeatedField<MoveItem>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveItemsRequest {
+    fn default() -> &'a MoveItemsRequest {
+        <MoveItemsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveItemsRequest {
+    pub fn new() -> MoveItemsRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .MoveItem items = 1;
+
+
+    pub fn get_items(&self) -> &[MoveItem] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<MoveItem>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<MoveItem> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<MoveItem> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for MoveItemsRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveItemsRequest {
+        MoveItemsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MoveItem>>(
+                "items",
+                |m: &MoveItemsRequest| { &m.items },
+                |m: &mut MoveItemsRequest| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveItemsRequest>(
+                "MoveItemsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveItemsRequest {
+        static instance: ::protobuf::rt::LazyV2<MoveItemsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveItemsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveItemsRequest {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveItemsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveItemsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveItemsParams {
+    // message fields
+    pub items: ::protobuf::RepeatedField<MoveItem>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveItemsParams {
+    fn default() -> &'a MoveItemsParams {
+        <MoveItemsParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveItemsParams {
+    pub fn new() -> MoveItemsParams {
+        ::std::default::Default::default()
+    }
+
+    // repeated .MoveItem items = 1;
+
+
+    pub fn get_items(&self) -> &[MoveItem] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<MoveItem>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<MoveItem> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<MoveItem> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for MoveItemsParams {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveItemsParams {
+        MoveItemsParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MoveItem>>(
+                "items",
+                |m: &MoveItemsParams| { &m.items },
+                |m: &mut MoveItemsParams| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveItemsParams>(
+                "MoveItemsParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveItemsParams {
+        static instance: ::protobuf::rt::LazyV2<MoveItemsParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveItemsParams::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveItemsParams {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveItemsParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveItemsParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ItemMoved {
+    // message fields
+    pub id: ::std::string::String,
+    pub ty: MoveItemType,
+    pub from_parent_id: ::std::string::String,
+    pub to_parent_id: ::std::string::String,
+    pub index: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ItemMoved {
+    fn default() -> &'a ItemMoved {
+        <ItemMoved as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ItemMoved {
+    pub fn new() -> ItemMoved {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // .MoveItemType ty = 2;
+
+
+    pub fn get_ty(&self) -> MoveItemType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = MoveItemType::View;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: MoveItemType) {
+        self.ty = v;
+    }
+
+    // string from_parent_id = 3;
+
+
+    pub fn get_from_parent_id(&self) -> &str {
+        &self.from_parent_id
+    }
+    pub fn clear_from_parent_id(&mut self) {
+        self.from_parent_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from_parent_id(&mut self, v: ::std::string::String) {
+        self.from_parent_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from_parent_id(&mut self) -> &mut ::std::string::String {
+        &mut self.from_parent_id
+    }
+
+    // Take field
+    pub fn take_from_parent_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.from_parent_id, ::std::string::String::new())
+    }
+
+    // string to_parent_id = 4;
+
+
+    pub fn get_to_parent_id(&self) -> &str {
+        &self.to_parent_id
+    }
+    pub fn clear_to_parent_id(&mut self) {
+        self.to_parent_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_parent_id(&mut self, v: ::std::string::String) {
+        self.to_parent_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to_parent_id(&mut self) -> &mut ::std::string::String {
+        &mut self.to_parent_id
+    }
+
+    // Take field
+    pub fn take_to_parent_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.to_parent_id, ::std::string::String::new())
+    }
+
+    // int32 index = 5;
+
+
+    pub fn get_index(&self) -> i32 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i32) {
+        self.index = v;
+    }
+}
+
+impl ::protobuf::Message for ItemMoved {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.from_parent_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.to_parent_id)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.ty != MoveItemType::View {
+            my_size += ::protobuf::rt::enum_size(2, self.ty);
+        }
+        if !self.from_parent_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.from_parent_id);
+        }
+        if !self.to_parent_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.to_parent_id);
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.ty != MoveItemType::View {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if !self.from_parent_id.is_empty() {
+            os.write_string(3, &self.from_parent_id)?;
+        }
+        if !self.to_parent_id.is_empty() {
+            os.write_string(4, &self.to_parent_id)?;
+        }
+        if self.index != 0 {
+            os.write_int32(5, self.index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ItemMoved {
+        ItemMoved::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &ItemMoved| { &m.id },
+                |m: &mut ItemMoved| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<MoveItemType>>(
+                "ty",
+                |m: &ItemMoved| { &m.ty },
+                |m: &mut ItemMoved| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "from_parent_id",
+                |m: &ItemMoved| { &m.from_parent_id },
+                |m: &mut ItemMoved| { &mut m.from_parent_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "to_parent_id",
+                |m: &ItemMoved| { &m.to_parent_id },
+                |m: &mut ItemMoved| { &mut m.to_parent_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "index",
+                |m: &ItemMoved| { &m.index },
+                |m: &mut ItemMoved| { &mut m.index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ItemMoved>(
+                "ItemMoved",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ItemMoved {
+        static instance: ::protobuf::rt::LazyV2<ItemMoved> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ItemMoved::new)
+    }
+}
+
+impl ::protobuf::Clear for ItemMoved {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.ty = MoveItemType::View;
+        self.from_parent_id.clear();
+        self.to_parent_id.clear();
+        self.index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ItemMoved {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ItemMoved {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedItemMoved {
+    // message fields
+    pub items: ::protobuf::RepeatedField<ItemMoved>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedItemMoved {
+    fn default() -> &'a RepeatedItemMoved {
+        <RepeatedItemMoved as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedItemMoved {
+    pub fn new() -> RepeatedItemMoved {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ItemMoved items = 1;
+
+
+    pub fn get_items(&self) -> &[ItemMoved] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<ItemMoved>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<ItemMoved> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<ItemMoved> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedItemMoved {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedItemMoved {
+        RepeatedItemMoved::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ItemMoved>>(
+                "items",
+                |m: &RepeatedItemMoved| { &m.items },
+                |m: &mut RepeatedItemMoved| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedItemMoved>(
+                "RepeatedItemMoved",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedItemMoved {
+        static instance: ::protobuf::rt::LazyV2<RepeatedItemMoved> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedItemMoved::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedItemMoved {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedItemMoved {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedItemMoved {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum MoveItemType {
+    View = 0,
+    App = 1,
+}
+
+impl ::protobuf::ProtobufEnum for MoveItemType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<MoveItemType> {
+        match value {
+            0 => ::std::option::Option::Some(MoveItemType::View),
+            1 => ::std::option::Option::Some(MoveItemType::App),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [MoveItemType] = &[
+            MoveItemType::View,
+            MoveItemType::App,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<MoveItemType>("MoveItemType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for MoveItemType {
+}
+
+impl ::std::default::Default for MoveItemType {
+    fn default() -> Self {
+        MoveItemType::View
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveItemType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0fview_move.proto\"W\n\x0fMoveViewRequest\x12\x19\n\x07view_id\x18\
+    \x01\x20\x01(\tR\x06viewIdB\0\x12'\n\x0fto_belong_to_id\x18\x02\x20\x01(\
+    \tR\x0ctoBelongToIdB\0:\0\"V\n\x0eMoveViewParams\x12\x19\n\x07view_id\
+    \x18\x01\x20\x01(\tR\x06viewIdB\0\x12'\n\x0fto_belong_to_id\x18\x02\x20\
+    \x01(\tR\x0ctoBelongToIdB\0:\0\"~\n\tViewMoved\x12\x19\n\x07view_id\x18\
+    \x01\x20\x01(\tR\x06viewIdB\0\x12+\n\x11from_belong_to_id\x18\x02\x20\
+    \x01(\tR\x0efromBelongToIdB\0\x12'\n\x0fto_belong_to_id\x18\x03\x20\x01(\
+    \tR\x0ctoBelongToIdB\0:\0\"{\n\x08MoveItem\x12\x10\n\x02id\x18\x01\x20\
+    \x01(\tR\x02idB\0\x12\x1f\n\x02ty\x18\x02\x20\x01(\x0e2\r.MoveItemTypeR\
+    \x02tyB\0\x12\"\n\x0cto_parent_id\x18\x03\x20\x01(\tR\ntoParentIdB\0\x12\
+    \x16\n\x05index\x18\x04\x20\x01(\x05R\x05indexB\0:\0\"7\n\x10MoveItemsRe\
+    quest\x12!\n\x05items\x18\x01\x20\x03(\x0b2\t.MoveItemR\x05itemsB\0:\0\"\
+    6\n\x0fMoveItemsParams\x12!\n\x05items\x18\x01\x20\x03(\x0b2\t.MoveItemR\
+    \x05itemsB\0:\0\"\xa4\x01\n\tItemMoved\x12\x10\n\x02id\x18\x01\x20\x01(\
+    \tR\x02idB\0\x12\x1f\n\x02ty\x18\x02\x20\x01(\x0e2\r.MoveItemTypeR\x02ty\
+    B\0\x12&\n\x0efrom_parent_id\x18\x03\x20\x01(\tR\x0cfromParentIdB\0\x12\
+    \"\n\x0cto_parent_id\x18\x04\x20\x01(\tR\ntoParentIdB\0\x12\x16\n\x05ind\
+    ex\x18\x05\x20\x01(\x05R\x05indexB\0:\0\"9\n\x11RepeatedItemMoved\x12\"\
+    \n\x05items\x18\x01\x20\x03(\x0b2\n.ItemMovedR\x05itemsB\0:\0*#\n\x0cMov\
+    eItemType\x12\x08\n\x04View\x10\0\x12\x07\n\x03App\x10\x01\x1a\0B\0b\x06\
+    proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,438 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_toggle.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateToggleStateRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub toggle_id: ::std::string::String,
+    pub collapsed: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateToggleStateRequest {
+    fn default() -> &'a UpdateToggleStateRequest {
+        <UpdateToggleStateRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateToggleStateRequest {
+    pub fn new() -> UpdateToggleStateRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string toggle_id = 2;
+
+
+    pub fn get_toggle_id(&self) -> &str {
+        &self.toggle_id
+    }
+    pub fn clear_toggle_id(&mut self) {
+        self.toggle_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_toggle_id(&mut self, v: ::std::string::String) {
+        self.toggle_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_toggle_id(&mut self) -> &mut ::std::string::String {
+        &mut self.toggle_id
+    }
+
+    // Take field
+    pub fn take_toggle_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.toggle_id, ::std::string::String::new())
+    }
+
+    // bool collapsed = 3;
+
+
+    pub fn get_collapsed(&self) -> bool {
+        self.collapsed
+    }
+    pub fn clear_collapsed(&mut self) {
+        self.collapsed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_collapsed(&mut self, v: bool) {
+        self.collapsed = v;
+    }
+}
+
+impl ::protobuf::Message for UpdateToggleStateRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.toggle_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.collapsed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.toggle_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.toggle_id);
+        }
+        if self.collapsed != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.toggle_id.is_empty() {
+            os.write_string(2, &self.toggle_id)?;
+        }
+        if self.collapsed != false {
+            os.write_bool(3, self.collapsed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateToggleStateRequest {
+        UpdateToggleStateRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &UpdateToggleStateRequest| { &m.view_id },
+                |m: &mut UpdateToggleStateRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "toggle_id",
+                |m: &UpdateToggleStateRequest| { &m.toggle_id },
+                |m: &mut UpdateToggleStateRequest| { &mut m.toggle_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "collapsed",
+                |m: &UpdateToggleStateRequest| { &m.collapsed },
+                |m: &mut UpdateToggleStateRequest| { &mut m.collapsed },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateToggleStateRequest>(
+                "UpdateToggleStateRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateToggleStateRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateToggleStateRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateToggleStateRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateToggleStateRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.toggle_id.clear();
+        self.collapsed = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateToggleStateRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateToggleStateRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CollapsedToggles {
+    // message fields
+    pub toggle_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CollapsedToggles {
+    fn default() -> &'a CollapsedToggles {
+        <CollapsedToggles as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CollapsedToggles {
+    pub fn new() -> CollapsedToggles {
+        ::std::default::Default::default()
+    }
+
+    // repeated string toggle_ids = 1;
+
+
+    pub fn get_toggle_ids(&self) -> &[::std::string::String] {
+        &self.toggle_ids
+    }
+    pub fn clear_toggle_ids(&mut self) {
+        self.toggle_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_toggle_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.toggle_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_toggle_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.toggle_ids
+    }
+
+    // Take field
+    pub fn take_toggle_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.toggle_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CollapsedToggles {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.toggle_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.toggle_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.toggle_ids {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CollapsedToggles {
+        CollapsedToggles::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "toggle_ids",
+                |m: &CollapsedToggles| { &m.toggle_ids },
+                |m: &mut CollapsedToggles| { &mut m.toggle_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CollapsedToggles>(
+                "CollapsedToggles",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CollapsedToggles {
+        static instance: ::protobuf::rt::LazyV2<CollapsedToggles> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CollapsedToggles::new)
+    }
+}
+
+impl ::protobuf::Clear for CollapsedToggles {
+    fn clear(&mut self) {
+        self.toggle_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CollapsedToggles {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CollapsedToggles {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x11view_toggle.proto\"v\n\x18UpdateToggleStateRequest\x12\x19\n\x07vi\
+    ew_id\x18\x01\x20\x01(\tR\x06viewIdB\0\x12\x1d\n\ttoggle_id\x18\x02\x20\
+    \x01(\tR\x08toggleIdB\0\x12\x1e\n\tcollapsed\x18\x03\x20\x01(\x08R\tcoll\
+    apsedB\0:\0\"5\n\x10CollapsedToggles\x12\x1f\n\ntoggle_ids\x18\x01\x20\
+    \x03(\tR\ttoggleIdsB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
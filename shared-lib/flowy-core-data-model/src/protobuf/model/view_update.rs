@@ -434,6 +434,7 @@ pub struct UpdateViewParams {
     pub one_of_name: ::std::option::Option<UpdateViewParams_oneof_one_of_name>,
     pub one_of_desc: ::std::option::Option<UpdateViewParams_oneof_one_of_desc>,
     pub one_of_thumbnail: ::std::option::Option<UpdateViewParams_oneof_one_of_thumbnail>,
+    pub one_of_belong_to_id: ::std::option::Option<UpdateViewParams_oneof_one_of_belong_to_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -460,6 +461,11 @@ pub enum UpdateViewParams_oneof_one_of_thumbnail {
     thumbnail(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewParams_oneof_one_of_belong_to_id {
+    belong_to_id(::std::string::String),
+}
+
 impl UpdateViewParams {
     pub fn new() -> UpdateViewParams {
         ::std::default::Default::default()
@@ -637,6 +643,55 @@ impl UpdateViewParams {
             ::std::string::String::new()
         }
     }
+
+    // string belong_to_id = 5;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_belong_to_id::belong_to_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.one_of_belong_to_id = ::std::option::Option::None;
+    }
+
+    pub fn has_belong_to_id(&self) -> bool {
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_belong_to_id::belong_to_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.one_of_belong_to_id = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_belong_to_id::belong_to_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateViewParams_oneof_one_of_belong_to_id::belong_to_id(_)) = self.one_of_belong_to_id {
+        } else {
+            self.one_of_belong_to_id = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_belong_to_id::belong_to_id(::std::string::String::new()));
+        }
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_belong_to_id::belong_to_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        if self.has_belong_to_id() {
+            match self.one_of_belong_to_id.take() {
+                ::std::option::Option::Some(UpdateViewParams_oneof_one_of_belong_to_id::belong_to_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for UpdateViewParams {
@@ -669,6 +724,12 @@ impl ::protobuf::Message for UpdateViewParams {
                     }
                     self.one_of_thumbnail = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_thumbnail::thumbnail(is.read_string()?));
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_belong_to_id = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_belong_to_id::belong_to_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -705,6 +766,13 @@ impl ::protobuf::Message for UpdateViewParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_belong_to_id {
+            match v {
+                &UpdateViewParams_oneof_one_of_belong_to_id::belong_to_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -735,6 +803,13 @@ impl ::protobuf::Message for UpdateViewParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_belong_to_id {
+            match v {
+                &UpdateViewParams_oneof_one_of_belong_to_id::belong_to_id(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -793,6 +868,11 @@ impl ::protobuf::Message for UpdateViewParams {
                 UpdateViewParams::has_thumbnail,
                 UpdateViewParams::get_thumbnail,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "belong_to_id",
+                UpdateViewParams::has_belong_to_id,
+                UpdateViewParams::get_belong_to_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateViewParams>(
                 "UpdateViewParams",
                 fields,
@@ -813,6 +893,7 @@ impl ::protobuf::Clear for UpdateViewParams {
         self.one_of_name = ::std::option::Option::None;
         self.one_of_desc = ::std::option::Option::None;
         self.one_of_thumbnail = ::std::option::Option::None;
+        self.one_of_belong_to_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -830,47 +911,17 @@ impl ::protobuf::reflect::ProtobufValue for UpdateViewParams {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x11view_update.proto\"\xaa\x01\n\x11UpdateViewRequest\x12\x17\n\x07vi\
-    ew_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\n\x04name\x18\x02\x20\x01(\t\
-    H\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04desc\x12\x1e\
-    \n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthumbnailB\r\n\x0bone_of_nameB\r\
-    \n\x0bone_of_descB\x12\n\x10one_of_thumbnail\"\xa9\x01\n\x10UpdateViewPa\
-    rams\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\n\x04nam\
-    e\x18\x02\x20\x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\
-    \x01R\x04desc\x12\x1e\n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthumbnailB\
-    \r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x12\n\x10one_of_thumbnailJ\x9c\
-    \x05\n\x06\x12\x04\0\0\r\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
-    \x04\0\x12\x04\x02\0\x07\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\
-    \x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x17\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x12\n\x0c\
-    \n\x05\x04\0\x02\0\x03\x12\x03\x03\x15\x16\n\x0b\n\x04\x04\0\x08\0\x12\
-    \x03\x04\x04*\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x04\n\x15\n\x0b\n\x04\
-    \x04\0\x02\x01\x12\x03\x04\x18(\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\
-    \x04\x18\x1e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x1f#\n\x0c\n\x05\
-    \x04\0\x02\x01\x03\x12\x03\x04&'\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x05\
-    \x04*\n\x0c\n\x05\x04\0\x08\x01\x01\x12\x03\x05\n\x15\n\x0b\n\x04\x04\0\
-    \x02\x02\x12\x03\x05\x18(\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x18\
-    \x1e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x1f#\n\x0c\n\x05\x04\0\
-    \x02\x02\x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x08\x02\x12\x03\x06\x044\n\
-    \x0c\n\x05\x04\0\x08\x02\x01\x12\x03\x06\n\x1a\n\x0b\n\x04\x04\0\x02\x03\
-    \x12\x03\x06\x1d2\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x1d#\n\x0c\n\
-    \x05\x04\0\x02\x03\x01\x12\x03\x06$-\n\x0c\n\x05\x04\0\x02\x03\x03\x12\
-    \x03\x0601\n\n\n\x02\x04\x01\x12\x04\x08\0\r\x01\n\n\n\x03\x04\x01\x01\
-    \x12\x03\x08\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\t\x04\x17\n\x0c\n\
-    \x05\x04\x01\x02\0\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
-    \x03\t\x0b\x12\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\t\x15\x16\n\x0b\n\
-    \x04\x04\x01\x08\0\x12\x03\n\x04*\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\
-    \n\n\x15\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\n\x18(\n\x0c\n\x05\x04\x01\
-    \x02\x01\x05\x12\x03\n\x18\x1e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\n\
-    \x1f#\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\n&'\n\x0b\n\x04\x04\x01\
-    \x08\x01\x12\x03\x0b\x04*\n\x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\x0b\n\
-    \x15\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0b\x18(\n\x0c\n\x05\x04\x01\
-    \x02\x02\x05\x12\x03\x0b\x18\x1e\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\
-    \x0b\x1f#\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0b&'\n\x0b\n\x04\x04\
-    \x01\x08\x02\x12\x03\x0c\x044\n\x0c\n\x05\x04\x01\x08\x02\x01\x12\x03\
-    \x0c\n\x1a\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x0c\x1d2\n\x0c\n\x05\x04\
-    \x01\x02\x03\x05\x12\x03\x0c\x1d#\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\
-    \x03\x0c$-\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x0c01b\x06proto3\
+    \n\x11view_update.proto\"\xb4\x01\n\x11UpdateViewRequest\x12\x19\n\x07vi\
+    ew_id\x18\x01\x20\x01(\tR\x06viewIdB\0\x12\x16\n\x04name\x18\x02\x20\x01\
+    (\tH\0R\x04nameB\0\x12\x16\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04descB\0\
+    \x12\x20\n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthumbnailB\0B\r\n\x0bone\
+    _of_nameB\r\n\x0bone_of_descB\x12\n\x10one_of_thumbnail:\0\"\xf0\x01\n\
+    \x10UpdateViewParams\x12\x19\n\x07view_id\x18\x01\x20\x01(\tR\x06viewIdB\
+    \0\x12\x16\n\x04name\x18\x02\x20\x01(\tH\0R\x04nameB\0\x12\x16\n\x04desc\
+    \x18\x03\x20\x01(\tH\x01R\x04descB\0\x12\x20\n\tthumbnail\x18\x04\x20\
+    \x01(\tH\x02R\tthumbnailB\0\x12$\n\x0cbelong_to_id\x18\x05\x20\x01(\tH\
+    \x03R\nbelongToIdB\0B\r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x12\n\x10o\
+    ne_of_thumbnailB\x15\n\x13one_of_belong_to_id:\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
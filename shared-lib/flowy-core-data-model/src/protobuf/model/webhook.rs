@@ -0,0 +1,1453 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `webhook.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Webhook {
+    // message fields
+    pub id: ::std::string::String,
+    pub url: ::std::string::String,
+    pub secret: ::std::string::String,
+    pub on_view_created: bool,
+    pub on_document_updated: bool,
+    pub on_trash_emptied: bool,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Webhook {
+    fn default() -> &'a Webhook {
+        <Webhook as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Webhook {
+    pub fn new() -> Webhook {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string url = 2;
+
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        &mut self.url
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.url, ::std::string::String::new())
+    }
+
+    // string secret = 3;
+
+
+    pub fn get_secret(&self) -> &str {
+        &self.secret
+    }
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::string::String) {
+        self.secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.secret
+    }
+
+    // Take field
+    pub fn take_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.secret, ::std::string::String::new())
+    }
+
+    // bool on_view_created = 4;
+
+
+    pub fn get_on_view_created(&self) -> bool {
+        self.on_view_created
+    }
+    pub fn clear_on_view_created(&mut self) {
+        self.on_view_created = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_on_view_created(&mut self, v: bool) {
+        self.on_view_created = v;
+    }
+
+    // bool on_document_updated = 5;
+
+
+    pub fn get_on_document_updated(&self) -> bool {
+        self.on_document_updated
+    }
+    pub fn clear_on_document_updated(&mut self) {
+        self.on_document_updated = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_on_document_updated(&mut self, v: bool) {
+        self.on_document_updated = v;
+    }
+
+    // bool on_trash_emptied = 6;
+
+
+    pub fn get_on_trash_emptied(&self) -> bool {
+        self.on_trash_emptied
+    }
+    pub fn clear_on_trash_emptied(&mut self) {
+        self.on_trash_emptied = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_on_trash_emptied(&mut self, v: bool) {
+        self.on_trash_emptied = v;
+    }
+
+    // int64 create_time = 7;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for Webhook {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.url)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.on_view_created = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.on_document_updated = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.on_trash_emptied = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.url.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.url);
+        }
+        if !self.secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.secret);
+        }
+        if self.on_view_created != false {
+            my_size += 2;
+        }
+        if self.on_document_updated != false {
+            my_size += 2;
+        }
+        if self.on_trash_emptied != false {
+            my_size += 2;
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.url.is_empty() {
+            os.write_string(2, &self.url)?;
+        }
+        if !self.secret.is_empty() {
+            os.write_string(3, &self.secret)?;
+        }
+        if self.on_view_created != false {
+            os.write_bool(4, self.on_view_created)?;
+        }
+        if self.on_document_updated != false {
+            os.write_bool(5, self.on_document_updated)?;
+        }
+        if self.on_trash_emptied != false {
+            os.write_bool(6, self.on_trash_emptied)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(7, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Webhook {
+        Webhook::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &Webhook| { &m.id },
+                |m: &mut Webhook| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "url",
+                |m: &Webhook| { &m.url },
+                |m: &mut Webhook| { &mut m.url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "secret",
+                |m: &Webhook| { &m.secret },
+                |m: &mut Webhook| { &mut m.secret },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "on_view_created",
+                |m: &Webhook| { &m.on_view_created },
+                |m: &mut Webhook| { &mut m.on_view_created },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "on_document_updated",
+                |m: &Webhook| { &m.on_document_updated },
+                |m: &mut Webhook| { &mut m.on_document_updated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "on_trash_emptied",
+                |m: &Webhook| { &m.on_trash_emptied },
+                |m: &mut Webhook| { &mut m.on_trash_emptied },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &Webhook| { &m.create_time },
+                |m: &mut Webhook| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Webhook>(
+                "Webhook",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Webhook {
+        static instance: ::protobuf::rt::LazyV2<Webhook> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Webhook::new)
+    }
+}
+
+impl ::protobuf::Clear for Webhook {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.url.clear();
+        self.secret.clear();
+        self.on_view_created = false;
+        self.on_document_updated = false;
+        self.on_trash_emptied = false;
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Webhook {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Webhook {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedWebhook {
+    // message fields
+    pub items: ::protobuf::RepeatedField<Webhook>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedWebhook {
+    fn default() -> &'a RepeatedWebhook {
+        <RepeatedWebhook as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedWebhook {
+    pub fn new() -> RepeatedWebhook {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Webhook items = 1;
+
+
+    pub fn get_items(&self) -> &[Webhook] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<Webhook>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<Webhook> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<Webhook> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedWebhook {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedWebhook {
+        RepeatedWebhook::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Webhook>>(
+                "items",
+                |m: &RepeatedWebhook| { &m.items },
+                |m: &mut RepeatedWebhook| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedWebhook>(
+                "RepeatedWebhook",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedWebhook {
+        static instance: ::protobuf::rt::LazyV2<RepeatedWebhook> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedWebhook::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedWebhook {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedWebhook {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedWebhook {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateWebhookRequest {
+    // message fields
+    pub url: ::std::string::String,
+    pub on_view_created: bool,
+    pub on_document_updated: bool,
+    pub on_trash_emptied: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateWebhookRequest {
+    fn default() -> &'a CreateWebhookRequest {
+        <CreateWebhookRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateWebhookRequest {
+    pub fn new() -> CreateWebhookRequest {
+        ::std::default::Default::default()
+    }
+
+    // string url = 1;
+
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        &mut self.url
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.url, ::std::string::String::new())
+    }
+
+    // bool on_view_created = 2;
+
+
+    pub fn get_on_view_created(&self) -> bool {
+        self.on_view_created
+    }
+    pub fn clear_on_view_created(&mut self) {
+        self.on_view_created = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_on_view_created(&mut self, v: bool) {
+        self.on_view_created = v;
+    }
+
+    // bool on_document_updated = 3;
+
+
+    pub fn get_on_document_updated(&self) -> bool {
+        self.on_document_updated
+    }
+    pub fn clear_on_document_updated(&mut self) {
+        self.on_document_updated = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_on_document_updated(&mut self, v: bool) {
+        self.on_document_updated = v;
+    }
+
+    // bool on_trash_emptied = 4;
+
+
+    pub fn get_on_trash_emptied(&self) -> bool {
+        self.on_trash_emptied
+    }
+    pub fn clear_on_trash_emptied(&mut self) {
+        self.on_trash_emptied = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_on_trash_emptied(&mut self, v: bool) {
+        self.on_trash_emptied = v;
+    }
+}
+
+impl ::protobuf::Message for CreateWebhookRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.url)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.on_view_created = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.on_document_updated = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.on_trash_emptied = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.url.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.url);
+        }
+        if self.on_view_created != false {
+            my_size += 2;
+        }
+        if self.on_document_updated != false {
+            my_size += 2;
+        }
+        if self.on_trash_emptied != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.url.is_empty() {
+            os.write_string(1, &self.url)?;
+        }
+        if self.on_view_created != false {
+            os.write_bool(2, self.on_view_created)?;
+        }
+        if self.on_document_updated != false {
+            os.write_bool(3, self.on_document_updated)?;
+        }
+        if self.on_trash_emptied != false {
+            os.write_bool(4, self.on_trash_emptied)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateWebhookRequest {
+        CreateWebhookRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "url",
+                |m: &CreateWebhookRequest| { &m.url },
+                |m: &mut CreateWebhookRequest| { &mut m.url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "on_view_created",
+                |m: &CreateWebhookRequest| { &m.on_view_created },
+                |m: &mut CreateWebhookRequest| { &mut m.on_view_created },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "on_document_updated",
+                |m: &CreateWebhookRequest| { &m.on_document_updated },
+                |m: &mut CreateWebhookRequest| { &mut m.on_document_updated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "on_trash_emptied",
+                |m: &CreateWebhookRequest| { &m.on_trash_emptied },
+                |m: &mut CreateWebhookRequest| { &mut m.on_trash_emptied },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateWebhookRequest>(
+                "CreateWebhookRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateWebhookRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateWebhookRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateWebhookRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateWebhookRequest {
+    fn clear(&mut self) {
+        self.url.clear();
+        self.on_view_created = false;
+        self.on_document_updated = false;
+        self.on_trash_emptied = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateWebhookRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateWebhookRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateWebhookParams {
+    // message fields
+    pub url: ::std::string::String,
+    pub on_view_created: bool,
+    pub on_document_updated: bool,
+    pub on_trash_emptied: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateWebhookParams {
+    fn default() -> &'a CreateWebhookParams {
+        <CreateWebhookParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateWebhookParams {
+    pub fn new() -> CreateWebhookParams {
+        ::std::default::Default::default()
+    }
+
+    // string url = 1;
+
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        &mut self.url
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.url, ::std::string::String::new())
+    }
+
+    // bool on_view_created = 2;
+
+
+    pub fn get_on_view_created(&self) -> bool {
+        self.on_view_created
+    }
+    pub fn clear_on_view_created(&mut self) {
+        self.on_view_created = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_on_view_created(&mut self, v: bool) {
+        self.on_view_created = v;
+    }
+
+    // bool on_document_updated = 3;
+
+
+    pub fn get_on_document_updated(&self) -> bool {
+        self.on_document_updated
+    }
+    pub fn clear_on_document_updated(&mut self) {
+        self.on_document_updated = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_on_document_updated(&mut self, v: bool) {
+        self.on_document_updated = v;
+    }
+
+    // bool on_trash_emptied = 4;
+
+
+    pub fn get_on_trash_emptied(&self) -> bool {
+        self.on_trash_emptied
+    }
+    pub fn clear_on_trash_emptied(&mut self) {
+        self.on_trash_emptied = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_on_trash_emptied(&mut self, v: bool) {
+        self.on_trash_emptied = v;
+    }
+}
+
+impl ::protobuf::Message for CreateWebhookParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.url)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.on_view_created = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.on_document_updated = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.on_trash_emptied = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.url.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.url);
+        }
+        if self.on_view_created != false {
+            my_size += 2;
+        }
+        if self.on_document_updated != false {
+            my_size += 2;
+        }
+        if self.on_trash_emptied != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.url.is_empty() {
+            os.write_string(1, &self.url)?;
+        }
+        if self.on_view_created != false {
+            os.write_bool(2, self.on_view_created)?;
+        }
+        if self.on_document_updated != false {
+            os.write_bool(3, self.on_document_updated)?;
+        }
+        if self.on_trash_emptied != false {
+            os.write_bool(4, self.on_trash_emptied)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateWebhookParams {
+        CreateWebhookParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "url",
+                |m: &CreateWebhookParams| { &m.url },
+                |m: &mut CreateWebhookParams| { &mut m.url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "on_view_created",
+                |m: &CreateWebhookParams| { &m.on_view_created },
+                |m: &mut CreateWebhookParams| { &mut m.on_view_created },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "on_document_updated",
+                |m: &CreateWebhookParams| { &m.on_document_updated },
+                |m: &mut CreateWebhookParams| { &mut m.on_document_updated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "on_trash_emptied",
+                |m: &CreateWebhookParams| { &m.on_trash_emptied },
+                |m: &mut CreateWebhookParams| { &mut m.on_trash_emptied },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateWebhookParams>(
+                "CreateWebhookParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateWebhookParams {
+        static instance: ::protobuf::rt::LazyV2<CreateWebhookParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateWebhookParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateWebhookParams {
+    fn clear(&mut self) {
+        self.url.clear();
+        self.on_view_created = false;
+        self.on_document_updated = false;
+        self.on_trash_emptied = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateWebhookParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateWebhookParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryWebhookRequest {
+    // message fields
+    pub webhook_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryWebhookRequest {
+    fn default() -> &'a QueryWebhookRequest {
+        <QueryWebhookRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryWebhookRequest {
+    pub fn new() -> QueryWebhookRequest {
+        ::std::default::Default::default()
+    }
+
+    // string webhook_id = 1;
+
+
+    pub fn get_webhook_id(&self) -> &str {
+        &self.webhook_id
+    }
+    pub fn clear_webhook_id(&mut self) {
+        self.webhook_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_webhook_id(&mut self, v: ::std::string::String) {
+        self.webhook_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_webhook_id(&mut self) -> &mut ::std::string::String {
+        &mut self.webhook_id
+    }
+
+    // Take field
+    pub fn take_webhook_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.webhook_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryWebhookRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.webhook_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.webhook_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.webhook_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.webhook_id.is_empty() {
+            os.write_string(1, &self.webhook_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryWebhookRequest {
+        QueryWebhookRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "webhook_id",
+                |m: &QueryWebhookRequest| { &m.webhook_id },
+                |m: &mut QueryWebhookRequest| { &mut m.webhook_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryWebhookRequest>(
+                "QueryWebhookRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryWebhookRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryWebhookRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryWebhookRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryWebhookRequest {
+    fn clear(&mut self) {
+        self.webhook_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryWebhookRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryWebhookRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WebhookId {
+    // message fields
+    pub webhook_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WebhookId {
+    fn default() -> &'a WebhookId {
+        <WebhookId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WebhookId {
+    pub fn new() -> WebhookId {
+        ::std::default::Default::default()
+    }
+
+    // string webhook_id = 1;
+
+
+    pub fn get_webhook_id(&self) -> &str {
+        &self.webhook_id
+    }
+    pub fn clear_webhook_id(&mut self) {
+        self.webhook_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_webhook_id(&mut self, v: ::std::string::String) {
+        self.webhook_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_webhook_id(&mut self) -> &mut ::std::string::String {
+        &mut self.webhook_id
+    }
+
+    // Take field
+    pub fn take_webhook_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.webhook_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for WebhookId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.webhook_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.webhook_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.webhook_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.webhook_id.is_empty() {
+            os.write_string(1, &self.webhook_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WebhookId {
+        WebhookId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "webhook_id",
+                |m: &WebhookId| { &m.webhook_id },
+                |m: &mut WebhookId| { &mut m.webhook_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WebhookId>(
+                "WebhookId",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WebhookId {
+        static instance: ::protobuf::rt::LazyV2<WebhookId> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WebhookId::new)
+    }
+}
+
+impl ::protobuf::Clear for WebhookId {
+    fn clear(&mut self) {
+        self.webhook_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WebhookId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WebhookId {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\rwebhook.proto\"\xf6\x01\n\x07Webhook\x12\x10\n\x02id\x18\x01\x20\x01\
+    (\tR\x02idB\0\x12\x12\n\x03url\x18\x02\x20\x01(\tR\x03urlB\0\x12\x18\n\
+    \x06secret\x18\x03\x20\x01(\tR\x06secretB\0\x12(\n\x0fon_view_created\
+    \x18\x04\x20\x01(\x08R\ronViewCreatedB\0\x120\n\x13on_document_updated\
+    \x18\x05\x20\x01(\x08R\x11onDocumentUpdatedB\0\x12*\n\x10on_trash_emptie\
+    d\x18\x06\x20\x01(\x08R\x0eonTrashEmptiedB\0\x12!\n\x0bcreate_time\x18\
+    \x07\x20\x01(\x03R\ncreateTimeB\0:\0\"5\n\x0fRepeatedWebhook\x12\x20\n\
+    \x05items\x18\x01\x20\x03(\x0b2\x08.WebhookR\x05itemsB\0:\0\"\xb4\x01\n\
+    \x14CreateWebhookRequest\x12\x12\n\x03url\x18\x01\x20\x01(\tR\x03urlB\0\
+    \x12(\n\x0fon_view_created\x18\x02\x20\x01(\x08R\ronViewCreatedB\0\x120\
+    \n\x13on_document_updated\x18\x03\x20\x01(\x08R\x11onDocumentUpdatedB\0\
+    \x12*\n\x10on_trash_emptied\x18\x04\x20\x01(\x08R\x0eonTrashEmptiedB\0:\
+    \0\"\xb3\x01\n\x13CreateWebhookParams\x12\x12\n\x03url\x18\x01\x20\x01(\
+    \tR\x03urlB\0\x12(\n\x0fon_view_created\x18\x02\x20\x01(\x08R\ronViewCre\
+    atedB\0\x120\n\x13on_document_updated\x18\x03\x20\x01(\x08R\x11onDocumen\
+    tUpdatedB\0\x12*\n\x10on_trash_emptied\x18\x04\x20\x01(\x08R\x0eonTrashE\
+    mptiedB\0:\0\"8\n\x13QueryWebhookRequest\x12\x1f\n\nwebhook_id\x18\x01\
+    \x20\x01(\tR\twebhookIdB\0:\0\".\n\tWebhookId\x12\x1f\n\nwebhook_id\x18\
+    \x01\x20\x01(\tR\twebhookIdB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
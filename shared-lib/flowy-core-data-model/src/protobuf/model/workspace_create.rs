@@ -28,6 +28,8 @@ pub struct CreateWorkspaceRequest {
     // message fields
     pub name: ::std::string::String,
     pub desc: ::std::string::String,
+    // message oneof groups
+    pub one_of_workspace_id: ::std::option::Option<CreateWorkspaceRequest_oneof_one_of_workspace_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -39,6 +41,11 @@ impl<'a> ::std::default::Default for &'a CreateWorkspaceRequest {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateWorkspaceRequest_oneof_one_of_workspace_id {
+    workspace_id(::std::string::String),
+}
+
 impl CreateWorkspaceRequest {
     pub fn new() -> CreateWorkspaceRequest {
         ::std::default::Default::default()
@@ -95,6 +102,55 @@ impl CreateWorkspaceRequest {
     pub fn take_desc(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.desc, ::std::string::String::new())
     }
+
+    // string workspace_id = 3;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        match self.one_of_workspace_id {
+            ::std::option::Option::Some(CreateWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.one_of_workspace_id = ::std::option::Option::None;
+    }
+
+    pub fn has_workspace_id(&self) -> bool {
+        match self.one_of_workspace_id {
+            ::std::option::Option::Some(CreateWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.one_of_workspace_id = ::std::option::Option::Some(CreateWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(_)) = self.one_of_workspace_id {
+        } else {
+            self.one_of_workspace_id = ::std::option::Option::Some(CreateWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(::std::string::String::new()));
+        }
+        match self.one_of_workspace_id {
+            ::std::option::Option::Some(CreateWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        if self.has_workspace_id() {
+            match self.one_of_workspace_id.take() {
+                ::std::option::Option::Some(CreateWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for CreateWorkspaceRequest {
@@ -112,6 +168,12 @@ impl ::protobuf::Message for CreateWorkspaceRequest {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.desc)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_workspace_id = ::std::option::Option::Some(CreateWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -130,6 +192,13 @@ impl ::protobuf::Message for CreateWorkspaceRequest {
         if !self.desc.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.desc);
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_workspace_id {
+            match v {
+                &CreateWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -142,6 +211,13 @@ impl ::protobuf::Message for CreateWorkspaceRequest {
         if !self.desc.is_empty() {
             os.write_string(2, &self.desc)?;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_workspace_id {
+            match v {
+                &CreateWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -190,6 +266,11 @@ impl ::protobuf::Message for CreateWorkspaceRequest {
                 |m: &CreateWorkspaceRequest| { &m.desc },
                 |m: &mut CreateWorkspaceRequest| { &mut m.desc },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "workspace_id",
+                CreateWorkspaceRequest::has_workspace_id,
+                CreateWorkspaceRequest::get_workspace_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateWorkspaceRequest>(
                 "CreateWorkspaceRequest",
                 fields,
@@ -208,6 +289,7 @@ impl ::protobuf::Clear for CreateWorkspaceRequest {
     fn clear(&mut self) {
         self.name.clear();
         self.desc.clear();
+        self.one_of_workspace_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -229,6 +311,7 @@ pub struct CreateWorkspaceParams {
     // message fields
     pub name: ::std::string::String,
     pub desc: ::std::string::String,
+    pub workspace_id: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -296,6 +379,32 @@ impl CreateWorkspaceParams {
     pub fn take_desc(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.desc, ::std::string::String::new())
     }
+
+    // string workspace_id = 3;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for CreateWorkspaceParams {
@@ -313,6 +422,9 @@ impl ::protobuf::Message for CreateWorkspaceParams {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.desc)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -331,6 +443,9 @@ impl ::protobuf::Message for CreateWorkspaceParams {
         if !self.desc.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.desc);
         }
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.workspace_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -343,6 +458,9 @@ impl ::protobuf::Message for CreateWorkspaceParams {
         if !self.desc.is_empty() {
             os.write_string(2, &self.desc)?;
         }
+        if !self.workspace_id.is_empty() {
+            os.write_string(3, &self.workspace_id)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -391,6 +509,11 @@ impl ::protobuf::Message for CreateWorkspaceParams {
                 |m: &CreateWorkspaceParams| { &m.desc },
                 |m: &mut CreateWorkspaceParams| { &mut m.desc },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &CreateWorkspaceParams| { &m.workspace_id },
+                |m: &mut CreateWorkspaceParams| { &mut m.workspace_id },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateWorkspaceParams>(
                 "CreateWorkspaceParams",
                 fields,
@@ -409,6 +532,7 @@ impl ::protobuf::Clear for CreateWorkspaceParams {
     fn clear(&mut self) {
         self.name.clear();
         self.desc.clear();
+        self.workspace_id.clear();
         self.unknown_fields.clear();
     }
 }
@@ -434,6 +558,9 @@ pub struct Workspace {
     pub apps: ::protobuf::SingularPtrField<super::app_create::RepeatedApp>,
     pub modified_time: i64,
     pub create_time: i64,
+    pub role: super::workspace_member::WorkspaceRole,
+    // message oneof groups
+    pub one_of_settings: ::std::option::Option<Workspace_oneof_one_of_settings>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -445,6 +572,11 @@ impl<'a> ::std::default::Default for &'a Workspace {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum Workspace_oneof_one_of_settings {
+    settings(super::workspace_settings::WorkspaceSettings),
+}
+
 impl Workspace {
     pub fn new() -> Workspace {
         ::std::default::Default::default()
@@ -590,6 +722,70 @@ impl Workspace {
     pub fn set_create_time(&mut self, v: i64) {
         self.create_time = v;
     }
+
+    // .WorkspaceRole role = 7;
+
+
+    pub fn get_role(&self) -> super::workspace_member::WorkspaceRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.role = v;
+    }
+
+    // .WorkspaceSettings settings = 8;
+
+
+    pub fn get_settings(&self) -> &super::workspace_settings::WorkspaceSettings {
+        match self.one_of_settings {
+            ::std::option::Option::Some(Workspace_oneof_one_of_settings::settings(ref v)) => v,
+            _ => <super::workspace_settings::WorkspaceSettings as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_settings(&mut self) {
+        self.one_of_settings = ::std::option::Option::None;
+    }
+
+    pub fn has_settings(&self) -> bool {
+        match self.one_of_settings {
+            ::std::option::Option::Some(Workspace_oneof_one_of_settings::settings(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_settings(&mut self, v: super::workspace_settings::WorkspaceSettings) {
+        self.one_of_settings = ::std::option::Option::Some(Workspace_oneof_one_of_settings::settings(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_settings(&mut self) -> &mut super::workspace_settings::WorkspaceSettings {
+        if let ::std::option::Option::Some(Workspace_oneof_one_of_settings::settings(_)) = self.one_of_settings {
+        } else {
+            self.one_of_settings = ::std::option::Option::Some(Workspace_oneof_one_of_settings::settings(super::workspace_settings::WorkspaceSettings::new()));
+        }
+        match self.one_of_settings {
+            ::std::option::Option::Some(Workspace_oneof_one_of_settings::settings(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_settings(&mut self) -> super::workspace_settings::WorkspaceSettings {
+        if self.has_settings() {
+            match self.one_of_settings.take() {
+                ::std::option::Option::Some(Workspace_oneof_one_of_settings::settings(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::workspace_settings::WorkspaceSettings::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for Workspace {
@@ -599,6 +795,11 @@ impl ::protobuf::Message for Workspace {
                 return false;
             }
         };
+        if let Some(Workspace_oneof_one_of_settings::settings(ref v)) = self.one_of_settings {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -632,6 +833,15 @@ impl ::protobuf::Message for Workspace {
                     let tmp = is.read_int64()?;
                     self.create_time = tmp;
                 },
+                7 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 7, &mut self.unknown_fields)?
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_settings = ::std::option::Option::Some(Workspace_oneof_one_of_settings::settings(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -663,6 +873,17 @@ impl ::protobuf::Message for Workspace {
         if self.create_time != 0 {
             my_size += ::protobuf::rt::value_size(6, self.create_time, ::protobuf::wire_format::WireTypeVarint);
         }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            my_size += ::protobuf::rt::enum_size(7, self.role);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_settings {
+            match v {
+                &Workspace_oneof_one_of_settings::settings(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -689,6 +910,18 @@ impl ::protobuf::Message for Workspace {
         if self.create_time != 0 {
             os.write_int64(6, self.create_time)?;
         }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            os.write_enum(7, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_settings {
+            match v {
+                &Workspace_oneof_one_of_settings::settings(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -757,6 +990,16 @@ impl ::protobuf::Message for Workspace {
                 |m: &Workspace| { &m.create_time },
                 |m: &mut Workspace| { &mut m.create_time },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::workspace_member::WorkspaceRole>>(
+                "role",
+                |m: &Workspace| { &m.role },
+                |m: &mut Workspace| { &mut m.role },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::workspace_settings::WorkspaceSettings>(
+                "settings",
+                Workspace::has_settings,
+                Workspace::get_settings,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Workspace>(
                 "Workspace",
                 fields,
@@ -779,6 +1022,8 @@ impl ::protobuf::Clear for Workspace {
         self.apps.clear();
         self.modified_time = 0;
         self.create_time = 0;
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+        self.one_of_settings = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -962,54 +1207,23 @@ impl ::protobuf::reflect::ProtobufValue for RepeatedWorkspace {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x16workspace_create.proto\x1a\x10app_create.proto\"@\n\x16CreateWorks\
-    paceRequest\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x12\n\x04d\
-    esc\x18\x02\x20\x01(\tR\x04desc\"?\n\x15CreateWorkspaceParams\x12\x12\n\
-    \x04name\x18\x01\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\x02\x20\x01(\
-    \tR\x04desc\"\xab\x01\n\tWorkspace\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\
-    \x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04desc\
-    \x18\x03\x20\x01(\tR\x04desc\x12\x20\n\x04apps\x18\x04\x20\x01(\x0b2\x0c\
-    .RepeatedAppR\x04apps\x12#\n\rmodified_time\x18\x05\x20\x01(\x03R\x0cmod\
-    ifiedTime\x12\x1f\n\x0bcreate_time\x18\x06\x20\x01(\x03R\ncreateTime\"5\
-    \n\x11RepeatedWorkspace\x12\x20\n\x05items\x18\x01\x20\x03(\x0b2\n.Works\
-    paceR\x05itemsJ\xe8\x05\n\x06\x12\x04\0\0\x15\x01\n\x08\n\x01\x0c\x12\
-    \x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x1a\n\n\n\x02\x04\0\x12\x04\
-    \x03\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x1e\n\x0b\n\x04\x04\0\
-    \x02\0\x12\x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\
-    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x04\x12\x13\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\x14\n\
-    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x01\
-    \x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\x12\
-    \x13\n\n\n\x02\x04\x01\x12\x04\x07\0\n\x01\n\n\n\x03\x04\x01\x01\x12\x03\
-    \x07\x08\x1d\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\x14\n\x0c\n\x05\
-    \x04\x01\x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
-    \x03\x08\x0b\x0f\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x12\x13\n\x0b\
-    \n\x04\x04\x01\x02\x01\x12\x03\t\x04\x14\n\x0c\n\x05\x04\x01\x02\x01\x05\
-    \x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\x0b\x0f\n\x0c\
-    \n\x05\x04\x01\x02\x01\x03\x12\x03\t\x12\x13\n\n\n\x02\x04\x02\x12\x04\
-    \x0b\0\x12\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0b\x08\x11\n\x0b\n\x04\x04\
-    \x02\x02\0\x12\x03\x0c\x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0c\
-    \x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0c\x0b\r\n\x0c\n\x05\x04\
-    \x02\x02\0\x03\x12\x03\x0c\x10\x11\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\r\
-    \x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\x01\x01\x12\x03\r\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
-    \x03\r\x12\x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0e\x04\x14\n\x0c\n\
-    \x05\x04\x02\x02\x02\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x02\
-    \x01\x12\x03\x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0e\x12\
-    \x13\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x0f\x04\x19\n\x0c\n\x05\x04\x02\
-    \x02\x03\x06\x12\x03\x0f\x04\x0f\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\
-    \x0f\x10\x14\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x0f\x17\x18\n\x0b\n\
-    \x04\x04\x02\x02\x04\x12\x03\x10\x04\x1c\n\x0c\n\x05\x04\x02\x02\x04\x05\
-    \x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x10\n\x17\n\
-    \x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x10\x1a\x1b\n\x0b\n\x04\x04\x02\
-    \x02\x05\x12\x03\x11\x04\x1a\n\x0c\n\x05\x04\x02\x02\x05\x05\x12\x03\x11\
-    \x04\t\n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x03\x11\n\x15\n\x0c\n\x05\x04\
-    \x02\x02\x05\x03\x12\x03\x11\x18\x19\n\n\n\x02\x04\x03\x12\x04\x13\0\x15\
-    \x01\n\n\n\x03\x04\x03\x01\x12\x03\x13\x08\x19\n\x0b\n\x04\x04\x03\x02\0\
-    \x12\x03\x14\x04!\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x14\x04\x0c\n\
-    \x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x14\r\x16\n\x0c\n\x05\x04\x03\x02\0\
-    \x01\x12\x03\x14\x17\x1c\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x14\x1f\
-    \x20b\x06proto3\
+    \n\x16workspace_create.proto\x1a\x10app_create.proto\x1a\x16workspace_me\
+    mber.proto\x1a\x18workspace_settings.proto\"\x84\x01\n\x16CreateWorkspac\
+    eRequest\x12\x14\n\x04name\x18\x01\x20\x01(\tR\x04nameB\0\x12\x14\n\x04d\
+    esc\x18\x02\x20\x01(\tR\x04descB\0\x12%\n\x0cworkspace_id\x18\x03\x20\
+    \x01(\tH\0R\x0bworkspaceIdB\0B\x15\n\x13one_of_workspace_id:\0\"j\n\x15C\
+    reateWorkspaceParams\x12\x14\n\x04name\x18\x01\x20\x01(\tR\x04nameB\0\
+    \x12\x14\n\x04desc\x18\x02\x20\x01(\tR\x04descB\0\x12#\n\x0cworkspace_id\
+    \x18\x03\x20\x01(\tR\x0bworkspaceIdB\0:\0\"\xa6\x02\n\tWorkspace\x12\x10\
+    \n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x14\n\x04name\x18\x02\x20\x01(\
+    \tR\x04nameB\0\x12\x14\n\x04desc\x18\x03\x20\x01(\tR\x04descB\0\x12\"\n\
+    \x04apps\x18\x04\x20\x01(\x0b2\x0c.RepeatedAppR\x04appsB\0\x12%\n\rmodif\
+    ied_time\x18\x05\x20\x01(\x03R\x0cmodifiedTimeB\0\x12!\n\x0bcreate_time\
+    \x18\x06\x20\x01(\x03R\ncreateTimeB\0\x12$\n\x04role\x18\x07\x20\x01(\
+    \x0e2\x0e.WorkspaceRoleR\x04roleB\0\x122\n\x08settings\x18\x08\x20\x01(\
+    \x0b2\x12.WorkspaceSettingsH\0R\x08settingsB\0B\x11\n\x0fone_of_settings\
+    :\0\"9\n\x11RepeatedWorkspace\x12\"\n\x05items\x18\x01\x20\x03(\x0b2\n.W\
+    orkspaceR\x05itemsB\0:\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
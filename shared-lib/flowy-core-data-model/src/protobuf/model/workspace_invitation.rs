@@ -0,0 +1,1485 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_invitation.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceInvitation {
+    // message fields
+    pub id: ::std::string::String,
+    pub workspace_id: ::std::string::String,
+    pub workspace_name: ::std::string::String,
+    pub inviter_name: ::std::string::String,
+    pub email: ::std::string::String,
+    pub role: super::workspace_member::WorkspaceRole,
+    pub status: InvitationStatus,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceInvitation {
+    fn default() -> &'a WorkspaceInvitation {
+        <WorkspaceInvitation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceInvitation {
+    pub fn new() -> WorkspaceInvitation {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string workspace_id = 2;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string workspace_name = 3;
+
+
+    pub fn get_workspace_name(&self) -> &str {
+        &self.workspace_name
+    }
+    pub fn clear_workspace_name(&mut self) {
+        self.workspace_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_name(&mut self, v: ::std::string::String) {
+        self.workspace_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_name(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_name
+    }
+
+    // Take field
+    pub fn take_workspace_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_name, ::std::string::String::new())
+    }
+
+    // string inviter_name = 4;
+
+
+    pub fn get_inviter_name(&self) -> &str {
+        &self.inviter_name
+    }
+    pub fn clear_inviter_name(&mut self) {
+        self.inviter_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_inviter_name(&mut self, v: ::std::string::String) {
+        self.inviter_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_inviter_name(&mut self) -> &mut ::std::string::String {
+        &mut self.inviter_name
+    }
+
+    // Take field
+    pub fn take_inviter_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.inviter_name, ::std::string::String::new())
+    }
+
+    // string email = 5;
+
+
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        &mut self.email
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.email, ::std::string::String::new())
+    }
+
+    // .WorkspaceRole role = 6;
+
+
+    pub fn get_role(&self) -> super::workspace_member::WorkspaceRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.role = v;
+    }
+
+    // .InvitationStatus status = 7;
+
+
+    pub fn get_status(&self) -> InvitationStatus {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = InvitationStatus::Pending;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: InvitationStatus) {
+        self.status = v;
+    }
+
+    // int64 create_time = 8;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for WorkspaceInvitation {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.inviter_name)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.email)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 6, &mut self.unknown_fields)?
+                },
+                7 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 7, &mut self.unknown_fields)?
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.workspace_id);
+        }
+        if !self.workspace_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.workspace_name);
+        }
+        if !self.inviter_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.inviter_name);
+        }
+        if !self.email.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.email);
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            my_size += ::protobuf::rt::enum_size(6, self.role);
+        }
+        if self.status != InvitationStatus::Pending {
+            my_size += ::protobuf::rt::enum_size(7, self.status);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.workspace_id.is_empty() {
+            os.write_string(2, &self.workspace_id)?;
+        }
+        if !self.workspace_name.is_empty() {
+            os.write_string(3, &self.workspace_name)?;
+        }
+        if !self.inviter_name.is_empty() {
+            os.write_string(4, &self.inviter_name)?;
+        }
+        if !self.email.is_empty() {
+            os.write_string(5, &self.email)?;
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            os.write_enum(6, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        if self.status != InvitationStatus::Pending {
+            os.write_enum(7, ::protobuf::ProtobufEnum::value(&self.status))?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(8, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceInvitation {
+        WorkspaceInvitation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &WorkspaceInvitation| { &m.id },
+                |m: &mut WorkspaceInvitation| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &WorkspaceInvitation| { &m.workspace_id },
+                |m: &mut WorkspaceInvitation| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_name",
+                |m: &WorkspaceInvitation| { &m.workspace_name },
+                |m: &mut WorkspaceInvitation| { &mut m.workspace_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "inviter_name",
+                |m: &WorkspaceInvitation| { &m.inviter_name },
+                |m: &mut WorkspaceInvitation| { &mut m.inviter_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "email",
+                |m: &WorkspaceInvitation| { &m.email },
+                |m: &mut WorkspaceInvitation| { &mut m.email },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::workspace_member::WorkspaceRole>>(
+                "role",
+                |m: &WorkspaceInvitation| { &m.role },
+                |m: &mut WorkspaceInvitation| { &mut m.role },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<InvitationStatus>>(
+                "status",
+                |m: &WorkspaceInvitation| { &m.status },
+                |m: &mut WorkspaceInvitation| { &mut m.status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &WorkspaceInvitation| { &m.create_time },
+                |m: &mut WorkspaceInvitation| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceInvitation>(
+                "WorkspaceInvitation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceInvitation {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceInvitation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceInvitation::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceInvitation {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.workspace_id.clear();
+        self.workspace_name.clear();
+        self.inviter_name.clear();
+        self.email.clear();
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+        self.status = InvitationStatus::Pending;
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceInvitation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceInvitation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedWorkspaceInvitation {
+    // message fields
+    pub items: ::protobuf::RepeatedField<WorkspaceInvitation>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedWorkspaceInvitation {
+    fn default() -> &'a RepeatedWorkspaceInvitation {
+        <RepeatedWorkspaceInvitation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedWorkspaceInvitation {
+    pub fn new() -> RepeatedWorkspaceInvitation {
+        ::std::default::Default::default()
+    }
+
+    // repeated .WorkspaceInvitation items = 1;
+
+
+    pub fn get_items(&self) -> &[WorkspaceInvitation] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<WorkspaceInvitation>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<WorkspaceInvitation> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<WorkspaceInvitation> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedWorkspaceInvitation {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedWorkspaceInvitation {
+        RepeatedWorkspaceInvitation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkspaceInvitation>>(
+                "items",
+                |m: &RepeatedWorkspaceInvitation| { &m.items },
+                |m: &mut RepeatedWorkspaceInvitation| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedWorkspaceInvitation>(
+                "RepeatedWorkspaceInvitation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedWorkspaceInvitation {
+        static instance: ::protobuf::rt::LazyV2<RepeatedWorkspaceInvitation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedWorkspaceInvitation::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedWorkspaceInvitation {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedWorkspaceInvitation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedWorkspaceInvitation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateInvitationRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub email: ::std::string::String,
+    pub role: super::workspace_member::WorkspaceRole,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateInvitationRequest {
+    fn default() -> &'a CreateInvitationRequest {
+        <CreateInvitationRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateInvitationRequest {
+    pub fn new() -> CreateInvitationRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string email = 2;
+
+
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        &mut self.email
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.email, ::std::string::String::new())
+    }
+
+    // .WorkspaceRole role = 3;
+
+
+    pub fn get_role(&self) -> super::workspace_member::WorkspaceRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.role = v;
+    }
+}
+
+impl ::protobuf::Message for CreateInvitationRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.email)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 3, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.email.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.email);
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            my_size += ::protobuf::rt::enum_size(3, self.role);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.email.is_empty() {
+            os.write_string(2, &self.email)?;
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateInvitationRequest {
+        CreateInvitationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &CreateInvitationRequest| { &m.workspace_id },
+                |m: &mut CreateInvitationRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "email",
+                |m: &CreateInvitationRequest| { &m.email },
+                |m: &mut CreateInvitationRequest| { &mut m.email },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::workspace_member::WorkspaceRole>>(
+                "role",
+                |m: &CreateInvitationRequest| { &m.role },
+                |m: &mut CreateInvitationRequest| { &mut m.role },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateInvitationRequest>(
+                "CreateInvitationRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateInvitationRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateInvitationRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateInvitationRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateInvitationRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.email.clear();
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateInvitationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateInvitationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateInvitationParams {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub email: ::std::string::String,
+    pub role: super::workspace_member::WorkspaceRole,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateInvitationParams {
+    fn default() -> &'a CreateInvitationParams {
+        <CreateInvitationParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateInvitationParams {
+    pub fn new() -> CreateInvitationParams {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string email = 2;
+
+
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        &mut self.email
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.email, ::std::string::String::new())
+    }
+
+    // .WorkspaceRole role = 3;
+
+
+    pub fn get_role(&self) -> super::workspace_member::WorkspaceRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.role = v;
+    }
+}
+
+impl ::protobuf::Message for CreateInvitationParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.email)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 3, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.email.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.email);
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            my_size += ::protobuf::rt::enum_size(3, self.role);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.email.is_empty() {
+            os.write_string(2, &self.email)?;
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateInvitationParams {
+        CreateInvitationParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &CreateInvitationParams| { &m.workspace_id },
+                |m: &mut CreateInvitationParams| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "email",
+                |m: &CreateInvitationParams| { &m.email },
+                |m: &mut CreateInvitationParams| { &mut m.email },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::workspace_member::WorkspaceRole>>(
+                "role",
+                |m: &CreateInvitationParams| { &m.role },
+                |m: &mut CreateInvitationParams| { &mut m.role },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateInvitationParams>(
+                "CreateInvitationParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateInvitationParams {
+        static instance: ::protobuf::rt::LazyV2<CreateInvitationParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateInvitationParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateInvitationParams {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.email.clear();
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateInvitationParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateInvitationParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryInvitationRequest {
+    // message fields
+    pub invitation_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryInvitationRequest {
+    fn default() -> &'a QueryInvitationRequest {
+        <QueryInvitationRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryInvitationRequest {
+    pub fn new() -> QueryInvitationRequest {
+        ::std::default::Default::default()
+    }
+
+    // string invitation_id = 1;
+
+
+    pub fn get_invitation_id(&self) -> &str {
+        &self.invitation_id
+    }
+    pub fn clear_invitation_id(&mut self) {
+        self.invitation_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invitation_id(&mut self, v: ::std::string::String) {
+        self.invitation_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_invitation_id(&mut self) -> &mut ::std::string::String {
+        &mut self.invitation_id
+    }
+
+    // Take field
+    pub fn take_invitation_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.invitation_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryInvitationRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.invitation_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.invitation_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.invitation_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.invitation_id.is_empty() {
+            os.write_string(1, &self.invitation_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryInvitationRequest {
+        QueryInvitationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "invitation_id",
+                |m: &QueryInvitationRequest| { &m.invitation_id },
+                |m: &mut QueryInvitationRequest| { &mut m.invitation_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryInvitationRequest>(
+                "QueryInvitationRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryInvitationRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryInvitationRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryInvitationRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryInvitationRequest {
+    fn clear(&mut self) {
+        self.invitation_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryInvitationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryInvitationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InvitationId {
+    // message fields
+    pub invitation_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InvitationId {
+    fn default() -> &'a InvitationId {
+        <InvitationId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InvitationId {
+    pub fn new() -> InvitationId {
+        ::std::default::Default::default()
+    }
+
+    // string invitation_id = 1;
+
+
+    pub fn get_invitation_id(&self) -> &str {
+        &self.invitation_id
+    }
+    pub fn clear_invitation_id(&mut self) {
+        self.invitation_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invitation_id(&mut self, v: ::std::string::String) {
+        self.invitation_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_invitation_id(&mut self) -> &mut ::std::string::String {
+        &mut self.invitation_id
+    }
+
+    // Take field
+    pub fn take_invitation_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.invitation_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for InvitationId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.invitation_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.invitation_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.invitation_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.invitation_id.is_empty() {
+            os.write_string(1, &self.invitation_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InvitationId {
+        InvitationId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "invitation_id",
+                |m: &InvitationId| { &m.invitation_id },
+                |m: &mut InvitationId| { &mut m.invitation_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<InvitationId>(
+                "InvitationId",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static InvitationId {
+        static instance: ::protobuf::rt::LazyV2<InvitationId> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(InvitationId::new)
+    }
+}
+
+impl ::protobuf::Clear for InvitationId {
+    fn clear(&mut self) {
+        self.invitation_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InvitationId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InvitationId {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum InvitationStatus {
+    Pending = 0,
+    Accepted = 1,
+    Declined = 2,
+}
+
+impl ::protobuf::ProtobufEnum for InvitationStatus {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<InvitationStatus> {
+        match value {
+            0 => ::std::option::Option::Some(InvitationStatus::Pending),
+            1 => ::std::option::Option::Some(InvitationStatus::Accepted),
+            2 => ::std::option::Option::Some(InvitationStatus::Declined),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [InvitationStatus] = &[
+            InvitationStatus::Pending,
+            InvitationStatus::Accepted,
+            InvitationStatus::Declined,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<InvitationStatus>("InvitationStatus", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for InvitationStatus {
+}
+
+impl ::std::default::Default for InvitationStatus {
+    fn default() -> Self {
+        InvitationStatus::Pending
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InvitationStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1aworkspace_invitation.proto\x1a\x16workspace_member.proto\"\xaa\x02\
+    \n\x13WorkspaceInvitation\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\
+    \x12#\n\x0cworkspace_id\x18\x02\x20\x01(\tR\x0bworkspaceIdB\0\x12'\n\x0e\
+    workspace_name\x18\x03\x20\x01(\tR\rworkspaceNameB\0\x12#\n\x0cinviter_n\
+    ame\x18\x04\x20\x01(\tR\x0binviterNameB\0\x12\x16\n\x05email\x18\x05\x20\
+    \x01(\tR\x05emailB\0\x12$\n\x04role\x18\x06\x20\x01(\x0e2\x0e.WorkspaceR\
+    oleR\x04roleB\0\x12+\n\x06status\x18\x07\x20\x01(\x0e2\x11.InvitationSta\
+    tusR\x06statusB\0\x12!\n\x0bcreate_time\x18\x08\x20\x01(\x03R\ncreateTim\
+    eB\0:\0\"M\n\x1bRepeatedWorkspaceInvitation\x12,\n\x05items\x18\x01\x20\
+    \x03(\x0b2\x14.WorkspaceInvitationR\x05itemsB\0:\0\"~\n\x17CreateInvitat\
+    ionRequest\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\
+    \x12\x16\n\x05email\x18\x02\x20\x01(\tR\x05emailB\0\x12$\n\x04role\x18\
+    \x03\x20\x01(\x0e2\x0e.WorkspaceRoleR\x04roleB\0:\0\"}\n\x16CreateInvita\
+    tionParams\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\
+    \x12\x16\n\x05email\x18\x02\x20\x01(\tR\x05emailB\0\x12$\n\x04role\x18\
+    \x03\x20\x01(\x0e2\x0e.WorkspaceRoleR\x04roleB\0:\0\"A\n\x16QueryInvitat\
+    ionRequest\x12%\n\rinvitation_id\x18\x01\x20\x01(\tR\x0cinvitationIdB\0:\
+    \0\"7\n\x0cInvitationId\x12%\n\rinvitation_id\x18\x01\x20\x01(\tR\x0cinv\
+    itationIdB\0:\0*=\n\x10InvitationStatus\x12\x0b\n\x07Pending\x10\0\x12\
+    \x0c\n\x08Accepted\x10\x01\x12\x0c\n\x08Declined\x10\x02\x1a\0B\0b\x06pr\
+    oto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,1666 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_settings.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceSettings {
+    // message fields
+    pub default_view_type: super::view_create::ViewType,
+    pub date_format: DateFormat,
+    pub retention_kind: RetentionKind,
+    pub retention_value: i64,
+    pub default_member_role: super::workspace_member::WorkspaceRole,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceSettings {
+    fn default() -> &'a WorkspaceSettings {
+        <WorkspaceSettings as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceSettings {
+    pub fn new() -> WorkspaceSettings {
+        ::std::default::Default::default()
+    }
+
+    // .ViewType default_view_type = 1;
+
+
+    pub fn get_default_view_type(&self) -> super::view_create::ViewType {
+        self.default_view_type
+    }
+    pub fn clear_default_view_type(&mut self) {
+        self.default_view_type = super::view_create::ViewType::Blank;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_view_type(&mut self, v: super::view_create::ViewType) {
+        self.default_view_type = v;
+    }
+
+    // .DateFormat date_format = 2;
+
+
+    pub fn get_date_format(&self) -> DateFormat {
+        self.date_format
+    }
+    pub fn clear_date_format(&mut self) {
+        self.date_format = DateFormat::Local;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date_format(&mut self, v: DateFormat) {
+        self.date_format = v;
+    }
+
+    // .RetentionKind retention_kind = 3;
+
+
+    pub fn get_retention_kind(&self) -> RetentionKind {
+        self.retention_kind
+    }
+    pub fn clear_retention_kind(&mut self) {
+        self.retention_kind = RetentionKind::All;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retention_kind(&mut self, v: RetentionKind) {
+        self.retention_kind = v;
+    }
+
+    // int64 retention_value = 4;
+
+
+    pub fn get_retention_value(&self) -> i64 {
+        self.retention_value
+    }
+    pub fn clear_retention_value(&mut self) {
+        self.retention_value = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retention_value(&mut self, v: i64) {
+        self.retention_value = v;
+    }
+
+    // .WorkspaceRole default_member_role = 5;
+
+
+    pub fn get_default_member_role(&self) -> super::workspace_member::WorkspaceRole {
+        self.default_member_role
+    }
+    pub fn clear_default_member_role(&mut self) {
+        self.default_member_role = super::workspace_member::WorkspaceRole::Owner;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_member_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.default_member_role = v;
+    }
+}
+
+impl ::protobuf::Message for WorkspaceSettings {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.default_view_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.date_format, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.retention_kind, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.retention_value = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.default_member_role, 5, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.default_view_type != super::view_create::ViewType::Blank {
+            my_size += ::protobuf::rt::enum_size(1, self.default_view_type);
+        }
+        if self.date_format != DateFormat::Local {
+            my_size += ::protobuf::rt::enum_size(2, self.date_format);
+        }
+        if self.retention_kind != RetentionKind::All {
+            my_size += ::protobuf::rt::enum_size(3, self.retention_kind);
+        }
+        if self.retention_value != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.retention_value, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.default_member_role != super::workspace_member::WorkspaceRole::Owner {
+            my_size += ::protobuf::rt::enum_size(5, self.default_member_role);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.default_view_type != super::view_create::ViewType::Blank {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.default_view_type))?;
+        }
+        if self.date_format != DateFormat::Local {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.date_format))?;
+        }
+        if self.retention_kind != RetentionKind::All {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.retention_kind))?;
+        }
+        if self.retention_value != 0 {
+            os.write_int64(4, self.retention_value)?;
+        }
+        if self.default_member_role != super::workspace_member::WorkspaceRole::Owner {
+            os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.default_member_role))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceSettings {
+        WorkspaceSettings::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::view_create::ViewType>>(
+                "default_view_type",
+                |m: &WorkspaceSettings| { &m.default_view_type },
+                |m: &mut WorkspaceSettings| { &mut m.default_view_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<DateFormat>>(
+                "date_format",
+                |m: &WorkspaceSettings| { &m.date_format },
+                |m: &mut WorkspaceSettings| { &mut m.date_format },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RetentionKind>>(
+                "retention_kind",
+                |m: &WorkspaceSettings| { &m.retention_kind },
+                |m: &mut WorkspaceSettings| { &mut m.retention_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "retention_value",
+                |m: &WorkspaceSettings| { &m.retention_value },
+                |m: &mut WorkspaceSettings| { &mut m.retention_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::workspace_member::WorkspaceRole>>(
+                "default_member_role",
+                |m: &WorkspaceSettings| { &m.default_member_role },
+                |m: &mut WorkspaceSettings| { &mut m.default_member_role },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceSettings>(
+                "WorkspaceSettings",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceSettings {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceSettings> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceSettings::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceSettings {
+    fn clear(&mut self) {
+        self.default_view_type = super::view_create::ViewType::Blank;
+        self.date_format = DateFormat::Local;
+        self.retention_kind = RetentionKind::All;
+        self.retention_value = 0;
+        self.default_member_role = super::workspace_member::WorkspaceRole::Owner;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceSettings {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceSettings {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryWorkspaceSettingsRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryWorkspaceSettingsRequest {
+    fn default() -> &'a QueryWorkspaceSettingsRequest {
+        <QueryWorkspaceSettingsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryWorkspaceSettingsRequest {
+    pub fn new() -> QueryWorkspaceSettingsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryWorkspaceSettingsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryWorkspaceSettingsRequest {
+        QueryWorkspaceSettingsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &QueryWorkspaceSettingsRequest| { &m.workspace_id },
+                |m: &mut QueryWorkspaceSettingsRequest| { &mut m.workspace_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryWorkspaceSettingsRequest>(
+                "QueryWorkspaceSettingsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryWorkspaceSettingsRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryWorkspaceSettingsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryWorkspaceSettingsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryWorkspaceSettingsRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryWorkspaceSettingsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryWorkspaceSettingsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryWorkspaceSettingsParams {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryWorkspaceSettingsParams {
+    fn default() -> &'a QueryWorkspaceSettingsParams {
+        <QueryWorkspaceSettingsParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryWorkspaceSettingsParams {
+    pub fn new() -> QueryWorkspaceSettingsParams {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryWorkspaceSettingsParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryWorkspaceSettingsParams {
+        QueryWorkspaceSettingsParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &QueryWorkspaceSettingsParams| { &m.workspace_id },
+                |m: &mut QueryWorkspaceSettingsParams| { &mut m.workspace_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryWorkspaceSettingsParams>(
+                "QueryWorkspaceSettingsParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryWorkspaceSettingsParams {
+        static instance: ::protobuf::rt::LazyV2<QueryWorkspaceSettingsParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryWorkspaceSettingsParams::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryWorkspaceSettingsParams {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryWorkspaceSettingsParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryWorkspaceSettingsParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateWorkspaceSettingsRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_default_view_type: ::std::option::Option<UpdateWorkspaceSettingsRequest_oneof_one_of_default_view_type>,
+    pub one_of_date_format: ::std::option::Option<UpdateWorkspaceSettingsRequest_oneof_one_of_date_format>,
+    pub one_of_retention_kind: ::std::option::Option<UpdateWorkspaceSettingsRequest_oneof_one_of_retention_kind>,
+    pub one_of_retention_value: ::std::option::Option<UpdateWorkspaceSettingsRequest_oneof_one_of_retention_value>,
+    pub one_of_default_member_role: ::std::option::Option<UpdateWorkspaceSettingsRequest_oneof_one_of_default_member_role>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateWorkspaceSettingsRequest {
+    fn default() -> &'a UpdateWorkspaceSettingsRequest {
+        <UpdateWorkspaceSettingsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceSettingsRequest_oneof_one_of_default_view_type {
+    default_view_type(super::view_create::ViewType),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceSettingsRequest_oneof_one_of_date_format {
+    date_format(DateFormat),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceSettingsRequest_oneof_one_of_retention_kind {
+    retention_kind(RetentionKind),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceSettingsRequest_oneof_one_of_retention_value {
+    retention_value(i64),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceSettingsRequest_oneof_one_of_default_member_role {
+    default_member_role(super::workspace_member::WorkspaceRole),
+}
+
+impl UpdateWorkspaceSettingsRequest {
+    pub fn new() -> UpdateWorkspaceSettingsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // .ViewType default_view_type = 2;
+
+
+    pub fn get_default_view_type(&self) -> super::view_create::ViewType {
+        match self.one_of_default_view_type {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_default_view_type::default_view_type(v)) => v,
+            _ => super::view_create::ViewType::Blank,
+        }
+    }
+    pub fn clear_default_view_type(&mut self) {
+        self.one_of_default_view_type = ::std::option::Option::None;
+    }
+
+    pub fn has_default_view_type(&self) -> bool {
+        match self.one_of_default_view_type {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_default_view_type::default_view_type(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_view_type(&mut self, v: super::view_create::ViewType) {
+        self.one_of_default_view_type = ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_default_view_type::default_view_type(v))
+    }
+
+    // .DateFormat date_format = 3;
+
+
+    pub fn get_date_format(&self) -> DateFormat {
+        match self.one_of_date_format {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_date_format::date_format(v)) => v,
+            _ => DateFormat::Local,
+        }
+    }
+    pub fn clear_date_format(&mut self) {
+        self.one_of_date_format = ::std::option::Option::None;
+    }
+
+    pub fn has_date_format(&self) -> bool {
+        match self.one_of_date_format {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_date_format::date_format(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date_format(&mut self, v: DateFormat) {
+        self.one_of_date_format = ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_date_format::date_format(v))
+    }
+
+    // .RetentionKind retention_kind = 4;
+
+
+    pub fn get_retention_kind(&self) -> RetentionKind {
+        match self.one_of_retention_kind {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_retention_kind::retention_kind(v)) => v,
+            _ => RetentionKind::All,
+        }
+    }
+    pub fn clear_retention_kind(&mut self) {
+        self.one_of_retention_kind = ::std::option::Option::None;
+    }
+
+    pub fn has_retention_kind(&self) -> bool {
+        match self.one_of_retention_kind {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_retention_kind::retention_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retention_kind(&mut self, v: RetentionKind) {
+        self.one_of_retention_kind = ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_retention_kind::retention_kind(v))
+    }
+
+    // int64 retention_value = 5;
+
+
+    pub fn get_retention_value(&self) -> i64 {
+        match self.one_of_retention_value {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_retention_value::retention_value(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_retention_value(&mut self) {
+        self.one_of_retention_value = ::std::option::Option::None;
+    }
+
+    pub fn has_retention_value(&self) -> bool {
+        match self.one_of_retention_value {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_retention_value::retention_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retention_value(&mut self, v: i64) {
+        self.one_of_retention_value = ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_retention_value::retention_value(v))
+    }
+
+    // .WorkspaceRole default_member_role = 6;
+
+
+    pub fn get_default_member_role(&self) -> super::workspace_member::WorkspaceRole {
+        match self.one_of_default_member_role {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_default_member_role::default_member_role(v)) => v,
+            _ => super::workspace_member::WorkspaceRole::Owner,
+        }
+    }
+    pub fn clear_default_member_role(&mut self) {
+        self.one_of_default_member_role = ::std::option::Option::None;
+    }
+
+    pub fn has_default_member_role(&self) -> bool {
+        match self.one_of_default_member_role {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_default_member_role::default_member_role(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_member_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.one_of_default_member_role = ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_default_member_role::default_member_role(v))
+    }
+}
+
+impl ::protobuf::Message for UpdateWorkspaceSettingsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_default_view_type = ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_default_view_type::default_view_type(is.read_enum()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_date_format = ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_date_format::date_format(is.read_enum()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_retention_kind = ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_retention_kind::retention_kind(is.read_enum()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_retention_value = ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_retention_value::retention_value(is.read_int64()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_default_member_role = ::std::option::Option::Some(UpdateWorkspaceSettingsRequest_oneof_one_of_default_member_role::default_member_role(is.read_enum()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_default_view_type {
+            match v {
+                &UpdateWorkspaceSettingsRequest_oneof_one_of_default_view_type::default_view_type(v) => {
+                    my_size += ::protobuf::rt::enum_size(2, v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_date_format {
+            match v {
+                &UpdateWorkspaceSettingsRequest_oneof_one_of_date_format::date_format(v) => {
+                    my_size += ::protobuf::rt::enum_size(3, v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_retention_kind {
+            match v {
+                &UpdateWorkspaceSettingsRequest_oneof_one_of_retention_kind::retention_kind(v) => {
+                    my_size += ::protobuf::rt::enum_size(4, v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_retention_value {
+            match v {
+                &UpdateWorkspaceSettingsRequest_oneof_one_of_retention_value::retention_value(v) => {
+                    my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_default_member_role {
+            match v {
+                &UpdateWorkspaceSettingsRequest_oneof_one_of_default_member_role::default_member_role(v) => {
+                    my_size += ::protobuf::rt::enum_size(6, v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_default_view_type {
+            match v {
+                &UpdateWorkspaceSettingsRequest_oneof_one_of_default_view_type::default_view_type(v) => {
+                    os.write_enum(2, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_date_format {
+            match v {
+                &UpdateWorkspaceSettingsRequest_oneof_one_of_date_format::date_format(v) => {
+                    os.write_enum(3, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_retention_kind {
+            match v {
+                &UpdateWorkspaceSettingsRequest_oneof_one_of_retention_kind::retention_kind(v) => {
+                    os.write_enum(4, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_retention_value {
+            match v {
+                &UpdateWorkspaceSettingsRequest_oneof_one_of_retention_value::retention_value(v) => {
+                    os.write_int64(5, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_default_member_role {
+            match v {
+                &UpdateWorkspaceSettingsRequest_oneof_one_of_default_member_role::default_member_role(v) => {
+                    os.write_enum(6, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateWorkspaceSettingsRequest {
+        UpdateWorkspaceSettingsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &UpdateWorkspaceSettingsRequest| { &m.workspace_id },
+                |m: &mut UpdateWorkspaceSettingsRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, super::view_create::ViewType>(
+                "default_view_type",
+                UpdateWorkspaceSettingsRequest::has_default_view_type,
+                UpdateWorkspaceSettingsRequest::get_default_view_type,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, DateFormat>(
+                "date_format",
+                UpdateWorkspaceSettingsRequest::has_date_format,
+                UpdateWorkspaceSettingsRequest::get_date_format,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, RetentionKind>(
+                "retention_kind",
+                UpdateWorkspaceSettingsRequest::has_retention_kind,
+                UpdateWorkspaceSettingsRequest::get_retention_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "retention_value",
+                UpdateWorkspaceSettingsRequest::has_retention_value,
+                UpdateWorkspaceSettingsRequest::get_retention_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, super::workspace_member::WorkspaceRole>(
+                "default_member_role",
+                UpdateWorkspaceSettingsRequest::has_default_member_role,
+                UpdateWorkspaceSettingsRequest::get_default_member_role,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateWorkspaceSettingsRequest>(
+                "UpdateWorkspaceSettingsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateWorkspaceSettingsRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateWorkspaceSettingsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateWorkspaceSettingsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateWorkspaceSettingsRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.one_of_default_view_type = ::std::option::Option::None;
+        self.one_of_date_format = ::std::option::Option::None;
+        self.one_of_retention_kind = ::std::option::Option::None;
+        self.one_of_retention_value = ::std::option::Option::None;
+        self.one_of_default_member_role = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateWorkspaceSettingsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateWorkspaceSettingsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateWorkspaceSettingsParams {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_default_view_type: ::std::option::Option<UpdateWorkspaceSettingsParams_oneof_one_of_default_view_type>,
+    pub one_of_date_format: ::std::option::Option<UpdateWorkspaceSettingsParams_oneof_one_of_date_format>,
+    pub one_of_retention_kind: ::std::option::Option<UpdateWorkspaceSettingsParams_oneof_one_of_retention_kind>,
+    pub one_of_retention_value: ::std::option::Option<UpdateWorkspaceSettingsParams_oneof_one_of_retention_value>,
+    pub one_of_default_member_role: ::std::option::Option<UpdateWorkspaceSettingsParams_oneof_one_of_default_member_role>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateWorkspaceSettingsParams {
+    fn default() -> &'a UpdateWorkspaceSettingsParams {
+        <UpdateWorkspaceSettingsParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceSettingsParams_oneof_one_of_default_view_type {
+    default_view_type(super::view_create::ViewType),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceSettingsParams_oneof_one_of_date_format {
+    date_format(DateFormat),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceSettingsParams_oneof_one_of_retention_kind {
+    retention_kind(RetentionKind),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceSettingsParams_oneof_one_of_retention_value {
+    retention_value(i64),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceSettingsParams_oneof_one_of_default_member_role {
+    default_member_role(super::workspace_member::WorkspaceRole),
+}
+
+impl UpdateWorkspaceSettingsParams {
+    pub fn new() -> UpdateWorkspaceSettingsParams {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // .ViewType default_view_type = 2;
+
+
+    pub fn get_default_view_type(&self) -> super::view_create::ViewType {
+        match self.one_of_default_view_type {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_default_view_type::default_view_type(v)) => v,
+            _ => super::view_create::ViewType::Blank,
+        }
+    }
+    pub fn clear_default_view_type(&mut self) {
+        self.one_of_default_view_type = ::std::option::Option::None;
+    }
+
+    pub fn has_default_view_type(&self) -> bool {
+        match self.one_of_default_view_type {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_default_view_type::default_view_type(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_view_type(&mut self, v: super::view_create::ViewType) {
+        self.one_of_default_view_type = ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_default_view_type::default_view_type(v))
+    }
+
+    // .DateFormat date_format = 3;
+
+
+    pub fn get_date_format(&self) -> DateFormat {
+        match self.one_of_date_format {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_date_format::date_format(v)) => v,
+            _ => DateFormat::Local,
+        }
+    }
+    pub fn clear_date_format(&mut self) {
+        self.one_of_date_format = ::std::option::Option::None;
+    }
+
+    pub fn has_date_format(&self) -> bool {
+        match self.one_of_date_format {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_date_format::date_format(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date_format(&mut self, v: DateFormat) {
+        self.one_of_date_format = ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_date_format::date_format(v))
+    }
+
+    // .RetentionKind retention_kind = 4;
+
+
+    pub fn get_retention_kind(&self) -> RetentionKind {
+        match self.one_of_retention_kind {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_retention_kind::retention_kind(v)) => v,
+            _ => RetentionKind::All,
+        }
+    }
+    pub fn clear_retention_kind(&mut self) {
+        self.one_of_retention_kind = ::std::option::Option::None;
+    }
+
+    pub fn has_retention_kind(&self) -> bool {
+        match self.one_of_retention_kind {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_retention_kind::retention_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retention_kind(&mut self, v: RetentionKind) {
+        self.one_of_retention_kind = ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_retention_kind::retention_kind(v))
+    }
+
+    // int64 retention_value = 5;
+
+
+    pub fn get_retention_value(&self) -> i64 {
+        match self.one_of_retention_value {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_retention_value::retention_value(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_retention_value(&mut self) {
+        self.one_of_retention_value = ::std::option::Option::None;
+    }
+
+    pub fn has_retention_value(&self) -> bool {
+        match self.one_of_retention_value {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_retention_value::retention_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retention_value(&mut self, v: i64) {
+        self.one_of_retention_value = ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_retention_value::retention_value(v))
+    }
+
+    // .WorkspaceRole default_member_role = 6;
+
+
+    pub fn get_default_member_role(&self) -> super::workspace_member::WorkspaceRole {
+        match self.one_of_default_member_role {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_default_member_role::default_member_role(v)) => v,
+            _ => super::workspace_member::WorkspaceRole::Owner,
+        }
+    }
+    pub fn clear_default_member_role(&mut self) {
+        self.one_of_default_member_role = ::std::option::Option::None;
+    }
+
+    pub fn has_default_member_role(&self) -> bool {
+        match self.one_of_default_member_role {
+            ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_default_member_role::default_member_role(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_member_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.one_of_default_member_role = ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_default_member_role::default_member_role(v))
+    }
+}
+
+impl ::protobuf::Message for UpdateWorkspaceSettingsParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_default_view_type = ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_default_view_type::default_view_type(is.read_enum()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_date_format = ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_date_format::date_format(is.read_enum()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_retention_kind = ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_retention_kind::retention_kind(is.read_enum()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_retention_value = ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_retention_value::retention_value(is.read_int64()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_default_member_role = ::std::option::Option::Some(UpdateWorkspaceSettingsParams_oneof_one_of_default_member_role::default_member_role(is.read_enum()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_default_view_type {
+            match v {
+                &UpdateWorkspaceSettingsParams_oneof_one_of_default_view_type::default_view_type(v) => {
+                    my_size += ::protobuf::rt::enum_size(2, v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_date_format {
+            match v {
+                &UpdateWorkspaceSettingsParams_oneof_one_of_date_format::date_format(v) => {
+                    my_size += ::protobuf::rt::enum_size(3, v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_retention_kind {
+            match v {
+                &UpdateWorkspaceSettingsParams_oneof_one_of_retention_kind::retention_kind(v) => {
+                    my_size += ::protobuf::rt::enum_size(4, v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_retention_value {
+            match v {
+                &UpdateWorkspaceSettingsParams_oneof_one_of_retention_value::retention_value(v) => {
+                    my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_default_member_role {
+            match v {
+                &UpdateWorkspaceSettingsParams_oneof_one_of_default_member_role::default_member_role(v) => {
+                    my_size += ::protobuf::rt::enum_size(6, v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_default_view_type {
+            match v {
+                &UpdateWorkspaceSettingsParams_oneof_one_of_default_view_type::default_view_type(v) => {
+                    os.write_enum(2, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_date_format {
+            match v {
+                &UpdateWorkspaceSettingsParams_oneof_one_of_date_format::date_format(v) => {
+                    os.write_enum(3, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_retention_kind {
+            match v {
+                &UpdateWorkspaceSettingsParams_oneof_one_of_retention_kind::retention_kind(v) => {
+                    os.write_enum(4, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_retention_value {
+            match v {
+                &UpdateWorkspaceSettingsParams_oneof_one_of_retention_value::retention_value(v) => {
+                    os.write_int64(5, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_default_member_role {
+            match v {
+                &UpdateWorkspaceSettingsParams_oneof_one_of_default_member_role::default_member_role(v) => {
+                    os.write_enum(6, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateWorkspaceSettingsParams {
+        UpdateWorkspaceSettingsParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &UpdateWorkspaceSettingsParams| { &m.workspace_id },
+                |m: &mut UpdateWorkspaceSettingsParams| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, super::view_create::ViewType>(
+                "default_view_type",
+                UpdateWorkspaceSettingsParams::has_default_view_type,
+                UpdateWorkspaceSettingsParams::get_default_view_type,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, DateFormat>(
+                "date_format",
+                UpdateWorkspaceSettingsParams::has_date_format,
+                UpdateWorkspaceSettingsParams::get_date_format,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, RetentionKind>(
+                "retention_kind",
+                UpdateWorkspaceSettingsParams::has_retention_kind,
+                UpdateWorkspaceSettingsParams::get_retention_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "retention_value",
+                UpdateWorkspaceSettingsParams::has_retention_value,
+                UpdateWorkspaceSettingsParams::get_retention_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, super::workspace_member::WorkspaceRole>(
+                "default_member_role",
+                UpdateWorkspaceSettingsParams::has_default_member_role,
+                UpdateWorkspaceSettingsParams::get_default_member_role,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateWorkspaceSettingsParams>(
+                "UpdateWorkspaceSettingsParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateWorkspaceSettingsParams {
+        static instance: ::protobuf::rt::LazyV2<UpdateWorkspaceSettingsParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateWorkspaceSettingsParams::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateWorkspaceSettingsParams {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.one_of_default_view_type = ::std::option::Option::None;
+        self.one_of_date_format = ::std::option::Option::None;
+        self.one_of_retention_kind = ::std::option::Option::None;
+        self.one_of_retention_value = ::std::option::Option::None;
+        self.one_of_default_member_role = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateWorkspaceSettingsParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateWorkspaceSettingsParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum RetentionKind {
+    All = 0,
+    LastRevisions = 1,
+    LastDays = 2,
+}
+
+impl ::protobuf::ProtobufEnum for RetentionKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<RetentionKind> {
+        match value {
+            0 => ::std::option::Option::Some(RetentionKind::All),
+            1 => ::std::option::Option::Some(RetentionKind::LastRevisions),
+            2 => ::std::option::Option::Some(RetentionKind::LastDays),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [RetentionKind] = &[
+            RetentionKind::All,
+            RetentionKind::LastRevisions,
+            RetentionKind::LastDays,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<RetentionKind>("RetentionKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for RetentionKind {
+}
+
+impl ::std::default::Default for RetentionKind {
+    fn default() -> Self {
+        RetentionKind::All
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RetentionKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum DateFormat {
+    Local = 0,
+    US = 1,
+    ISO = 2,
+    Friendly = 3,
+}
+
+impl ::protobuf::ProtobufEnum for DateFormat {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<DateFormat> {
+        match value {
+            0 => ::std::option::Option::Some(DateFormat::Local),
+            1 => ::std::option::Option::Some(DateFormat::US),
+            2 => ::std::option::Option::Some(DateFormat::ISO),
+            3 => ::std::option::Option::Some(DateFormat::Friendly),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [DateFormat] = &[
+            DateFormat::Local,
+            DateFormat::US,
+            DateFormat::ISO,
+            DateFormat::Friendly,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<DateFormat>("DateFormat", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for DateFormat {
+}
+
+impl ::std::default::Default for DateFormat {
+    fn default() -> Self {
+        DateFormat::Local
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DateFormat {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x18workspace_settings.proto\x1a\x11view_create.proto\x1a\x16workspace\
+    _member.proto\"\xa4\x02\n\x11WorkspaceSettings\x127\n\x11default_view_ty\
+    pe\x18\x01\x20\x01(\x0e2\t.ViewTypeR\x0fdefaultViewTypeB\0\x12.\n\x0bdat\
+    e_format\x18\x02\x20\x01(\x0e2\x0b.DateFormatR\ndateFormatB\0\x127\n\x0e\
+    retention_kind\x18\x03\x20\x01(\x0e2\x0e.RetentionKindR\rretentionKindB\
+    \0\x12)\n\x0fretention_value\x18\x04\x20\x01(\x03R\x0eretentionValueB\0\
+    \x12@\n\x13default_member_role\x18\x05\x20\x01(\x0e2\x0e.WorkspaceRoleR\
+    \x11defaultMemberRoleB\0:\0\"F\n\x1dQueryWorkspaceSettingsRequest\x12#\n\
+    \x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0:\0\"E\n\x1cQueryW\
+    orkspaceSettingsParams\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bwor\
+    kspaceIdB\0:\0\"\xe3\x03\n\x1eUpdateWorkspaceSettingsRequest\x12#\n\x0cw\
+    orkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x129\n\x11default_view\
+    _type\x18\x02\x20\x01(\x0e2\t.ViewTypeH\0R\x0fdefaultViewTypeB\0\x120\n\
+    \x0bdate_format\x18\x03\x20\x01(\x0e2\x0b.DateFormatH\x01R\ndateFormatB\
+    \0\x129\n\x0eretention_kind\x18\x04\x20\x01(\x0e2\x0e.RetentionKindH\x02\
+    R\rretentionKindB\0\x12+\n\x0fretention_value\x18\x05\x20\x01(\x03H\x03R\
+    \x0eretentionValueB\0\x12B\n\x13default_member_role\x18\x06\x20\x01(\x0e\
+    2\x0e.WorkspaceRoleH\x04R\x11defaultMemberRoleB\0B\x1a\n\x18one_of_defau\
+    lt_view_typeB\x14\n\x12one_of_date_formatB\x17\n\x15one_of_retention_kin\
+    dB\x18\n\x16one_of_retention_valueB\x1c\n\x1aone_of_default_member_role:\
+    \0\"\xe2\x03\n\x1dUpdateWorkspaceSettingsParams\x12#\n\x0cworkspace_id\
+    \x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x129\n\x11default_view_type\x18\
+    \x02\x20\x01(\x0e2\t.ViewTypeH\0R\x0fdefaultViewTypeB\0\x120\n\x0bdate_f\
+    ormat\x18\x03\x20\x01(\x0e2\x0b.DateFormatH\x01R\ndateFormatB\0\x129\n\
+    \x0eretention_kind\x18\x04\x20\x01(\x0e2\x0e.RetentionKindH\x02R\rretent\
+    ionKindB\0\x12+\n\x0fretention_value\x18\x05\x20\x01(\x03H\x03R\x0ereten\
+    tionValueB\0\x12B\n\x13default_member_role\x18\x06\x20\x01(\x0e2\x0e.Wor\
+    kspaceRoleH\x04R\x11defaultMemberRoleB\0B\x1a\n\x18one_of_default_view_t\
+    ypeB\x14\n\x12one_of_date_formatB\x17\n\x15one_of_retention_kindB\x18\n\
+    \x16one_of_retention_valueB\x1c\n\x1aone_of_default_member_role:\0*;\n\r\
+    RetentionKind\x12\x07\n\x03All\x10\0\x12\x11\n\rLastRevisions\x10\x01\
+    \x12\x0c\n\x08LastDays\x10\x02\x1a\0*8\n\nDateFormat\x12\t\n\x05Local\
+    \x10\0\x12\x06\n\x02US\x10\x01\x12\x07\n\x03ISO\x10\x02\x12\x0c\n\x08Fri\
+    endly\x10\x03\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
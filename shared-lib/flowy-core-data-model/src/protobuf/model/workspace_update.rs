@@ -352,6 +352,7 @@ pub struct UpdateWorkspaceParams {
     // message oneof groups
     pub one_of_name: ::std::option::Option<UpdateWorkspaceParams_oneof_one_of_name>,
     pub one_of_desc: ::std::option::Option<UpdateWorkspaceParams_oneof_one_of_desc>,
+    pub one_of_settings: ::std::option::Option<UpdateWorkspaceParams_oneof_one_of_settings>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -373,6 +374,11 @@ pub enum UpdateWorkspaceParams_oneof_one_of_desc {
     desc(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateWorkspaceParams_oneof_one_of_settings {
+    settings(super::workspace_settings::WorkspaceSettings),
+}
+
 impl UpdateWorkspaceParams {
     pub fn new() -> UpdateWorkspaceParams {
         ::std::default::Default::default()
@@ -501,10 +507,64 @@ impl UpdateWorkspaceParams {
             ::std::string::String::new()
         }
     }
+
+    // .WorkspaceSettings settings = 4;
+
+
+    pub fn get_settings(&self) -> &super::workspace_settings::WorkspaceSettings {
+        match self.one_of_settings {
+            ::std::option::Option::Some(UpdateWorkspaceParams_oneof_one_of_settings::settings(ref v)) => v,
+            _ => <super::workspace_settings::WorkspaceSettings as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_settings(&mut self) {
+        self.one_of_settings = ::std::option::Option::None;
+    }
+
+    pub fn has_settings(&self) -> bool {
+        match self.one_of_settings {
+            ::std::option::Option::Some(UpdateWorkspaceParams_oneof_one_of_settings::settings(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_settings(&mut self, v: super::workspace_settings::WorkspaceSettings) {
+        self.one_of_settings = ::std::option::Option::Some(UpdateWorkspaceParams_oneof_one_of_settings::settings(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_settings(&mut self) -> &mut super::workspace_settings::WorkspaceSettings {
+        if let ::std::option::Option::Some(UpdateWorkspaceParams_oneof_one_of_settings::settings(_)) = self.one_of_settings {
+        } else {
+            self.one_of_settings = ::std::option::Option::Some(UpdateWorkspaceParams_oneof_one_of_settings::settings(super::workspace_settings::WorkspaceSettings::new()));
+        }
+        match self.one_of_settings {
+            ::std::option::Option::Some(UpdateWorkspaceParams_oneof_one_of_settings::settings(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_settings(&mut self) -> super::workspace_settings::WorkspaceSettings {
+        if self.has_settings() {
+            match self.one_of_settings.take() {
+                ::std::option::Option::Some(UpdateWorkspaceParams_oneof_one_of_settings::settings(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::workspace_settings::WorkspaceSettings::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for UpdateWorkspaceParams {
     fn is_initialized(&self) -> bool {
+        if let Some(UpdateWorkspaceParams_oneof_one_of_settings::settings(ref v)) = self.one_of_settings {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -527,6 +587,12 @@ impl ::protobuf::Message for UpdateWorkspaceParams {
                     }
                     self.one_of_desc = ::std::option::Option::Some(UpdateWorkspaceParams_oneof_one_of_desc::desc(is.read_string()?));
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_settings = ::std::option::Option::Some(UpdateWorkspaceParams_oneof_one_of_settings::settings(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -556,6 +622,14 @@ impl ::protobuf::Message for UpdateWorkspaceParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_settings {
+            match v {
+                &UpdateWorkspaceParams_oneof_one_of_settings::settings(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -579,6 +653,15 @@ impl ::protobuf::Message for UpdateWorkspaceParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_settings {
+            match v {
+                &UpdateWorkspaceParams_oneof_one_of_settings::settings(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -632,6 +715,11 @@ impl ::protobuf::Message for UpdateWorkspaceParams {
                 UpdateWorkspaceParams::has_desc,
                 UpdateWorkspaceParams::get_desc,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::workspace_settings::WorkspaceSettings>(
+                "settings",
+                UpdateWorkspaceParams::has_settings,
+                UpdateWorkspaceParams::get_settings,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateWorkspaceParams>(
                 "UpdateWorkspaceParams",
                 fields,
@@ -651,6 +739,7 @@ impl ::protobuf::Clear for UpdateWorkspaceParams {
         self.id.clear();
         self.one_of_name = ::std::option::Option::None;
         self.one_of_desc = ::std::option::Option::None;
+        self.one_of_settings = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -668,37 +757,15 @@ impl ::protobuf::reflect::ProtobufValue for UpdateWorkspaceParams {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x16workspace_update.proto\"r\n\x16UpdateWorkspaceRequest\x12\x0e\n\
-    \x02id\x18\x01\x20\x01(\tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0\
-    R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04descB\r\n\x0bone\
-    _of_nameB\r\n\x0bone_of_desc\"q\n\x15UpdateWorkspaceParams\x12\x0e\n\x02\
-    id\x18\x01\x20\x01(\tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\
-    \x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04descB\r\n\x0bone_\
-    of_nameB\r\n\x0bone_of_descJ\xf8\x03\n\x06\x12\x04\0\0\x0b\x01\n\x08\n\
-    \x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x06\x01\n\n\n\x03\
-    \x04\0\x01\x12\x03\x02\x08\x1e\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\
-    \x12\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\
-    \0\x01\x12\x03\x03\x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\
-    \n\x0b\n\x04\x04\0\x08\0\x12\x03\x04\x04*\n\x0c\n\x05\x04\0\x08\0\x01\
-    \x12\x03\x04\n\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x18(\n\x0c\n\
-    \x05\x04\0\x02\x01\x05\x12\x03\x04\x18\x1e\n\x0c\n\x05\x04\0\x02\x01\x01\
-    \x12\x03\x04\x1f#\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04&'\n\x0b\n\
-    \x04\x04\0\x08\x01\x12\x03\x05\x04*\n\x0c\n\x05\x04\0\x08\x01\x01\x12\
-    \x03\x05\n\x15\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x18(\n\x0c\n\x05\
-    \x04\0\x02\x02\x05\x12\x03\x05\x18\x1e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\
-    \x03\x05\x1f#\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05&'\n\n\n\x02\x04\
-    \x01\x12\x04\x07\0\x0b\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\x1d\n\
-    \x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\x12\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x0b\r\n\
-    \x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x10\x11\n\x0b\n\x04\x04\x01\x08\
-    \0\x12\x03\t\x04*\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\t\n\x15\n\x0b\n\
-    \x04\x04\x01\x02\x01\x12\x03\t\x18(\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\
-    \x03\t\x18\x1e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\x1f#\n\x0c\n\
-    \x05\x04\x01\x02\x01\x03\x12\x03\t&'\n\x0b\n\x04\x04\x01\x08\x01\x12\x03\
-    \n\x04*\n\x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\n\n\x15\n\x0b\n\x04\x04\
-    \x01\x02\x02\x12\x03\n\x18(\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\n\
-    \x18\x1e\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\n\x1f#\n\x0c\n\x05\x04\
-    \x01\x02\x02\x03\x12\x03\n&'b\x06proto3\
+    \n\x16workspace_update.proto\x1a\x18workspace_settings.proto\"z\n\x16Upd\
+    ateWorkspaceRequest\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x16\
+    \n\x04name\x18\x02\x20\x01(\tH\0R\x04nameB\0\x12\x16\n\x04desc\x18\x03\
+    \x20\x01(\tH\x01R\x04descB\0B\r\n\x0bone_of_nameB\r\n\x0bone_of_desc:\0\
+    \"\xc0\x01\n\x15UpdateWorkspaceParams\x12\x10\n\x02id\x18\x01\x20\x01(\t\
+    R\x02idB\0\x12\x16\n\x04name\x18\x02\x20\x01(\tH\0R\x04nameB\0\x12\x16\n\
+    \x04desc\x18\x03\x20\x01(\tH\x01R\x04descB\0\x122\n\x08settings\x18\x04\
+    \x20\x01(\x0b2\x12.WorkspaceSettingsH\x02R\x08settingsB\0B\r\n\x0bone_of\
+    _nameB\r\n\x0bone_of_descB\x11\n\x0fone_of_settings:\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
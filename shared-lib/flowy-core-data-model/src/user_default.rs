@@ -1,7 +1,7 @@
 use crate::entities::{
     app::{App, RepeatedApp},
     view::{RepeatedView, View, ViewType},
-    workspace::Workspace,
+    workspace::{Workspace, WorkspaceRole},
 };
 use chrono::Utc;
 
@@ -21,6 +21,8 @@ pub fn create_default_workspace(time: chrono::DateTime<Utc>) -> Workspace {
         apps,
         modified_time: time.timestamp(),
         create_time: time.timestamp(),
+        role: WorkspaceRole::Owner,
+        settings: None,
     }
 }
 
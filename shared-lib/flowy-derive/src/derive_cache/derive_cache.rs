@@ -79,6 +79,214 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "ExportData"
         | "WSError"
         | "WebSocketRawMessage"
+        | "AppPasscodeRequest"
+        | "SetAppPasscodeRequest"
+        | "AppLockState"
+        | "RefreshTokenParams"
+        | "RefreshTokenResponse"
+        | "UserSettings"
+        | "UpdateUserSettingsRequest"
+        | "UploadAvatarRequest"
+        | "UploadAvatarParams"
+        | "UserAvatarRequest"
+        | "UserAvatar"
+        | "DeleteAccountProgress"
+        | "ExportUserDataRequest"
+        | "ExportUserDataResult"
+        | "UserDevice"
+        | "RepeatedUserDevice"
+        | "RevokeDeviceRequest"
+        | "RevokeDeviceParams"
+        | "WorkspaceMember"
+        | "RepeatedWorkspaceMember"
+        | "QueryWorkspaceMembersRequest"
+        | "QueryWorkspaceMembersParams"
+        | "AddWorkspaceMemberRequest"
+        | "AddWorkspaceMemberParams"
+        | "UpdateWorkspaceMemberRequest"
+        | "UpdateWorkspaceMemberParams"
+        | "RemoveWorkspaceMemberRequest"
+        | "RemoveWorkspaceMemberParams"
+        | "ShareLink"
+        | "RepeatedShareLink"
+        | "CreateShareLinkRequest"
+        | "CreateShareLinkParams"
+        | "QueryShareLinkRequest"
+        | "ShareLinkId"
+        | "SharedDocument"
+        | "ViewAccess"
+        | "UpdateViewAccessRequest"
+        | "UpdateViewAccessParams"
+        | "WorkspaceInvitation"
+        | "RepeatedWorkspaceInvitation"
+        | "CreateInvitationRequest"
+        | "CreateInvitationParams"
+        | "QueryInvitationRequest"
+        | "InvitationId"
+        | "GuestAccess"
+        | "RepeatedGuestAccess"
+        | "CreateGuestAccessRequest"
+        | "CreateGuestAccessParams"
+        | "QueryGuestAccessRequest"
+        | "GuestAccessId"
+        | "BatchedChange"
+        | "Webhook"
+        | "RepeatedWebhook"
+        | "CreateWebhookRequest"
+        | "CreateWebhookParams"
+        | "QueryWebhookRequest"
+        | "WebhookId"
+        | "EventLogEntry"
+        | "EventLogPage"
+        | "ReadEventLogRequest"
+        | "ReadEventLogParams"
+        | "AppDuplicated"
+        | "ImportProgress"
+        | "MoveViewRequest"
+        | "MoveViewParams"
+        | "ViewMoved"
+        | "ExportedLogs"
+        | "Metrics"
+        | "HealthCheck"
+        | "HealthReport"
+        | "ExportDiagnosticsRequest"
+        | "ExportDiagnosticsResult"
+        | "Grid"
+        | "Field"
+        | "SelectOption"
+        | "RepeatedSelectOption"
+        | "Row"
+        | "Cell"
+        | "GridChangeset"
+        | "IndexedRow"
+        | "UpdateCellRequest"
+        | "UpdateCellParams"
+        | "CreateRowRequest"
+        | "CreateRowParams"
+        | "QueryRowRequest"
+        | "RowId"
+        | "CreateFieldRequest"
+        | "CreateFieldParams"
+        | "UpdateFieldRequest"
+        | "UpdateFieldParams"
+        | "QueryFieldRequest"
+        | "FieldId"
+        | "QueryGridRequest"
+        | "GridId"
+        | "Board"
+        | "BoardGroup"
+        | "MoveCardRequest"
+        | "MoveCardParams"
+        | "CardMoved"
+        | "CalendarEvent"
+        | "RepeatedCalendarEvent"
+        | "QueryCalendarEventsRequest"
+        | "CalendarRange"
+        | "MoveCalendarEventRequest"
+        | "MoveCalendarEventParams"
+        | "CalendarChangeset"
+        | "CreateChecklistItemRequest"
+        | "CreateChecklistItemParams"
+        | "UpdateChecklistItemRequest"
+        | "UpdateChecklistItemParams"
+        | "QueryChecklistItemRequest"
+        | "ChecklistItemId"
+        | "MoveChecklistItemRequest"
+        | "MoveChecklistItemParams"
+        | "CompleteChecklistItemsRequest"
+        | "CompleteChecklistItemsParams"
+        | "Checklist"
+        | "ChecklistItem"
+        | "QueryChecklistRequest"
+        | "ChecklistId"
+        | "RepeatedRow"
+        | "RowVisibilityChangeset"
+        | "GridSetting"
+        | "FilterGroup"
+        | "Filter"
+        | "Sort"
+        | "UpdateGridSettingRequest"
+        | "UpdateGridSettingParams"
+        | "RollupSetting"
+        | "CsvImportOptions"
+        | "ImportCsvRequest"
+        | "ExportGridRequest"
+        | "ExportGridResult"
+        | "ImportNotionRequest"
+        | "ImportNotionResult"
+        | "ImportEnexRequest"
+        | "Attachment"
+        | "QueryAttachmentRequest"
+        | "ViewTags"
+        | "UpdateViewTagsRequest"
+        | "ImportVaultRequest"
+        | "ExportOpmlRequest"
+        | "ExportOpmlResult"
+        | "ImportOpmlRequest"
+        | "ExportCalendarRequest"
+        | "ExportCalendarResult"
+        | "ExportSiteRequest"
+        | "ExportSiteResult"
+        | "MoveItem"
+        | "MoveItemsRequest"
+        | "MoveItemsParams"
+        | "ItemMoved"
+        | "RepeatedItemMoved"
+        | "SearchRequest"
+        | "SearchResultItem"
+        | "WorkspaceSearchResult"
+        | "RepeatedWorkspaceSearchResult"
+        | "AuditLogEntry"
+        | "AuditLogPage"
+        | "ReadAuditLogRequest"
+        | "ReadAuditLogParams"
+        | "RevisionRetentionSetting"
+        | "QueryRevisionRetentionRequest"
+        | "QueryRevisionRetentionParams"
+        | "UpdateRevisionRetentionRequest"
+        | "ViewCheckpoint"
+        | "RepeatedViewCheckpoint"
+        | "CreateViewCheckpointRequest"
+        | "QueryViewCheckpointRequest"
+        | "QueryCodeBlockRequest"
+        | "SyntaxToken"
+        | "RepeatedSyntaxToken"
+        | "CodeBlockTokens"
+        | "LinkPreview"
+        | "UnfurlRequest"
+        | "UnfurlParams"
+        | "UnfurlSetting"
+        | "UpdateToggleStateRequest"
+        | "CollapsedToggles"
+        | "BackupSetting"
+        | "UpdateBackupSettingRequest"
+        | "BackupResult"
+        | "BackupFailure"
+        | "QueryBackupsRequest"
+        | "BackupManifest"
+        | "RepeatedBackupManifest"
+        | "SelectBackupRestoreRequest"
+        | "ExportQuarantinedRevisionsRequest"
+        | "ExportQuarantinedRevisionsResult"
+        | "ServerUrlSetting"
+        | "UpdateServerUrlRequest"
+        | "BatchCreateParams"
+        | "BatchCreateResult"
+        | "BatchReadParams"
+        | "BatchReadResult"
+        | "BatchUpdateParams"
+        | "FolderSyncParams"
+        | "FolderDelta"
+        | "AttachmentStorage"
+        | "UpdateAttachmentStorageRequest"
+        | "AttachmentSyncResult"
+        | "DocumentBackpressure"
+        | "DuplicateViewRequest"
+        | "WorkspaceSettings"
+        | "QueryWorkspaceSettingsRequest"
+        | "QueryWorkspaceSettingsParams"
+        | "UpdateWorkspaceSettingsRequest"
+        | "UpdateWorkspaceSettingsParams"
         => TypeCategory::Protobuf,
         "WorkspaceEvent"
         | "WorkspaceNotification"
@@ -97,6 +305,23 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "ExportType"
         | "ErrorCode"
         | "WSModule"
+        | "Theme"
+        | "SyncPolicy"
+        | "DeleteAccountStep"
+        | "WorkspaceRole"
+        | "SharePermission"
+        | "InvitationStatus"
+        | "HealthStatus"
+        | "FieldType"
+        | "FilterOperator"
+        | "FilterCondition"
+        | "RollupFunction"
+        | "MoveItemType"
+        | "RecoveryHint"
+        | "RetentionKind"
+        | "BackupFrequency"
+        | "BackpressureReason"
+        | "DateFormat"
         => TypeCategory::Enum,
 
         "Option" => TypeCategory::Opt,
@@ -0,0 +1,21 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum DeleteAccountStep {
+    DeletingServerData = 0,
+    DeletingLocalData  = 1,
+    Finished           = 2,
+}
+
+impl std::default::Default for DeleteAccountStep {
+    fn default() -> Self { DeleteAccountStep::DeletingServerData }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DeleteAccountProgress {
+    #[pb(index = 1)]
+    pub user_id: String,
+
+    #[pb(index = 2)]
+    pub step: DeleteAccountStep,
+}
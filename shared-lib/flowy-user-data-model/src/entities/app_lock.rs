@@ -0,0 +1,74 @@
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+use crate::{errors::ErrorCode, parser::AppPasscode};
+
+#[derive(ProtoBuf, Default)]
+pub struct AppPasscodeRequest {
+    #[pb(index = 1)]
+    pub passcode: String,
+}
+
+#[derive(Debug)]
+pub struct AppPasscodeParams {
+    pub passcode: String,
+}
+
+impl TryInto<AppPasscodeParams> for AppPasscodeRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<AppPasscodeParams, Self::Error> {
+        let passcode = AppPasscode::parse(self.passcode)?;
+        Ok(AppPasscodeParams { passcode: passcode.0 })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct SetAppPasscodeRequest {
+    #[pb(index = 1, one_of)]
+    pub old_passcode: Option<String>,
+
+    #[pb(index = 2)]
+    pub passcode: String,
+
+    // Lock the app automatically after it was idle for the given seconds. Zero means the
+    // app is only locked manually.
+    #[pb(index = 3)]
+    pub idle_timeout: i64,
+}
+
+#[derive(Debug)]
+pub struct SetAppPasscodeParams {
+    pub old_passcode: Option<String>,
+    pub passcode: String,
+    pub idle_timeout: i64,
+}
+
+impl TryInto<SetAppPasscodeParams> for SetAppPasscodeRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SetAppPasscodeParams, Self::Error> {
+        let passcode = AppPasscode::parse(self.passcode)?;
+        if self.idle_timeout < 0 {
+            return Err(ErrorCode::AppLockIdleTimeoutInvalid);
+        }
+
+        Ok(SetAppPasscodeParams {
+            old_passcode: self.old_passcode,
+            passcode: passcode.0,
+            idle_timeout: self.idle_timeout,
+        })
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq, Eq)]
+pub struct AppLockState {
+    #[pb(index = 1)]
+    pub has_passcode: bool,
+
+    #[pb(index = 2)]
+    pub is_locked: bool,
+
+    #[pb(index = 3)]
+    pub idle_timeout: i64,
+}
@@ -26,6 +26,13 @@ pub struct SignInParams {
 
     #[pb(index = 3)]
     pub name: String,
+
+    // Filled by the client before sending, the user doesn't need to provide it.
+    #[pb(index = 4)]
+    pub device_name: String,
+
+    #[pb(index = 5)]
+    pub platform: String,
 }
 
 #[derive(Debug, Default, ProtoBuf, Clone)]
@@ -41,6 +48,9 @@ pub struct SignInResponse {
 
     #[pb(index = 4)]
     pub token: String,
+
+    #[pb(index = 5)]
+    pub refresh_token: String,
 }
 
 impl TryInto<SignInParams> for SignInRequest {
@@ -54,6 +64,8 @@ impl TryInto<SignInParams> for SignInRequest {
             email: email.0,
             password: password.0,
             name: self.name,
+            device_name: "".to_owned(),
+            platform: "".to_owned(),
         })
     }
 }
@@ -81,6 +93,8 @@ impl TryInto<SignUpParams> for SignUpRequest {
             email: email.0,
             name: name.0,
             password: password.0,
+            device_name: "".to_owned(),
+            platform: "".to_owned(),
         })
     }
 }
@@ -95,6 +109,12 @@ pub struct SignUpParams {
 
     #[pb(index = 3)]
     pub password: String,
+
+    #[pb(index = 4)]
+    pub device_name: String,
+
+    #[pb(index = 5)]
+    pub platform: String,
 }
 
 #[derive(ProtoBuf, Debug, Default, Clone)]
@@ -110,4 +130,22 @@ pub struct SignUpResponse {
 
     #[pb(index = 4)]
     pub token: String,
+
+    #[pb(index = 5)]
+    pub refresh_token: String,
+}
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct RefreshTokenParams {
+    #[pb(index = 1)]
+    pub refresh_token: String,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct RefreshTokenResponse {
+    #[pb(index = 1)]
+    pub token: String,
+
+    #[pb(index = 2)]
+    pub refresh_token: String,
 }
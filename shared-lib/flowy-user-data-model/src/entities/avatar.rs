@@ -0,0 +1,68 @@
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+use crate::{errors::ErrorCode, parser::UserId};
+
+// The avatar is uploaded as it is, so the client should resize the image before
+// uploading. It must fit in the payload limit of the server.
+pub const MAX_AVATAR_SIZE: usize = 200 * 1024;
+
+#[derive(ProtoBuf, Default)]
+pub struct UploadAvatarRequest {
+    #[pb(index = 1)]
+    pub data: Vec<u8>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct UploadAvatarParams {
+    #[pb(index = 1)]
+    pub data: Vec<u8>,
+}
+
+impl TryInto<UploadAvatarParams> for UploadAvatarRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UploadAvatarParams, Self::Error> {
+        if self.data.is_empty() {
+            return Err(ErrorCode::AvatarIsEmpty);
+        }
+
+        if self.data.len() > MAX_AVATAR_SIZE {
+            return Err(ErrorCode::AvatarTooLarge);
+        }
+
+        Ok(UploadAvatarParams { data: self.data })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct UserAvatarRequest {
+    #[pb(index = 1)]
+    pub user_id: String,
+}
+
+pub struct UserAvatarParams {
+    pub user_id: String,
+}
+
+impl TryInto<UserAvatarParams> for UserAvatarRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UserAvatarParams, Self::Error> {
+        let user_id = UserId::parse(self.user_id)?;
+        Ok(UserAvatarParams { user_id: user_id.0 })
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct UserAvatar {
+    #[pb(index = 1)]
+    pub user_id: String,
+
+    // Empty if the avatar wasn't uploaded to the server yet.
+    #[pb(index = 2)]
+    pub url: String,
+
+    #[pb(index = 3)]
+    pub data: Vec<u8>,
+}
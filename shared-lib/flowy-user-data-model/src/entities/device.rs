@@ -0,0 +1,58 @@
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+use crate::errors::ErrorCode;
+
+// Every sign in creates a session on the server, which is shown to the user as
+// a device.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct UserDevice {
+    #[pb(index = 1)]
+    pub session_id: String,
+
+    #[pb(index = 2)]
+    pub device_name: String,
+
+    #[pb(index = 3)]
+    pub platform: String,
+
+    // Timestamp in seconds.
+    #[pb(index = 4)]
+    pub last_seen: i64,
+
+    // True if it's the session that sent the request.
+    #[pb(index = 5)]
+    pub is_current: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedUserDevice {
+    #[pb(index = 1)]
+    pub items: Vec<UserDevice>,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct RevokeDeviceRequest {
+    #[pb(index = 1)]
+    pub session_id: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RevokeDeviceParams {
+    #[pb(index = 1)]
+    pub session_id: String,
+}
+
+impl TryInto<RevokeDeviceParams> for RevokeDeviceRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RevokeDeviceParams, Self::Error> {
+        if self.session_id.trim().is_empty() {
+            return Err(ErrorCode::SessionIdInvalid);
+        }
+
+        Ok(RevokeDeviceParams {
+            session_id: self.session_id,
+        })
+    }
+}
@@ -1,9 +1,30 @@
+pub use account::*;
+pub use app_lock::*;
 pub use auth::*;
+pub use avatar::*;
+pub use device::*;
+pub use server_url::*;
 pub use user_profile::*;
+pub use user_setting::*;
 
+mod account;
+mod app_lock;
 pub mod auth;
+mod avatar;
+mod device;
+mod server_url;
 mod user_profile;
+mod user_setting;
 
 pub mod prelude {
-    pub use crate::entities::{auth::*, user_profile::*};
+    pub use crate::entities::{
+        account::*,
+        app_lock::*,
+        auth::*,
+        avatar::*,
+        device::*,
+        server_url::*,
+        user_profile::*,
+        user_setting::*,
+    };
 }
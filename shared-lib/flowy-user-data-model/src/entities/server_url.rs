@@ -0,0 +1,62 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{ServerBaseUrl, ServerWsUrl},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The server that the app connects to. It's the one of the build unless a
+// self-hosted one was set. While a user is signed in, it's the server of the
+// account, otherwise the one that the next sign in goes to.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct ServerUrlSetting {
+    #[pb(index = 1)]
+    pub base_url: String,
+
+    #[pb(index = 2)]
+    pub ws_url: String,
+
+    #[pb(index = 3)]
+    pub is_custom: bool,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct UpdateServerUrlRequest {
+    // An empty url restores the server of the build.
+    #[pb(index = 1)]
+    pub base_url: String,
+
+    // It's derived from the base url if it's empty, e.g. wss://<host>/ws.
+    #[pb(index = 2)]
+    pub ws_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateServerUrlParams {
+    // Both are empty to restore the server of the build.
+    pub base_url: String,
+    pub ws_url: String,
+}
+
+impl TryInto<UpdateServerUrlParams> for UpdateServerUrlRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateServerUrlParams, Self::Error> {
+        if self.base_url.trim().is_empty() {
+            return Ok(UpdateServerUrlParams {
+                base_url: String::new(),
+                ws_url: String::new(),
+            });
+        }
+
+        let base_url = ServerBaseUrl::parse(self.base_url)?;
+        let ws_url = match self.ws_url.trim().is_empty() {
+            true => ServerWsUrl::from_base_url(&base_url),
+            false => ServerWsUrl::parse(self.ws_url)?,
+        };
+        Ok(UpdateServerUrlParams {
+            base_url: base_url.0,
+            ws_url: ws_url.0,
+        })
+    }
+}
@@ -0,0 +1,121 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+use crate::{errors::ErrorCode, parser::UserLocale};
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum Theme {
+    Light = 0,
+    Dark  = 1,
+}
+
+impl std::default::Default for Theme {
+    fn default() -> Self { Theme::Light }
+}
+
+impl std::convert::From<i32> for Theme {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => Theme::Light,
+            1 => Theme::Dark,
+            _ => {
+                log::error!("Invalid theme: {}", val);
+                Theme::Light
+            },
+        }
+    }
+}
+
+// Decides when the local revisions are pushed to the server.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum SyncPolicy {
+    Always   = 0,
+    WifiOnly = 1,
+    Manual   = 2,
+}
+
+impl std::default::Default for SyncPolicy {
+    fn default() -> Self { SyncPolicy::Always }
+}
+
+impl std::convert::From<i32> for SyncPolicy {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => SyncPolicy::Always,
+            1 => SyncPolicy::WifiOnly,
+            2 => SyncPolicy::Manual,
+            _ => {
+                log::error!("Invalid sync policy: {}", val);
+                SyncPolicy::Always
+            },
+        }
+    }
+}
+
+#[derive(ProtoBuf, Debug, Clone, PartialEq)]
+pub struct UserSettings {
+    #[pb(index = 1)]
+    pub theme: Theme,
+
+    #[pb(index = 2)]
+    pub locale: String,
+
+    #[pb(index = 3)]
+    pub sync_policy: SyncPolicy,
+
+    // Revisions older than the given days are merged into the snapshot. Zero means
+    // the revisions are kept forever.
+    #[pb(index = 4)]
+    pub revision_retention_days: i64,
+}
+
+impl std::default::Default for UserSettings {
+    fn default() -> Self {
+        UserSettings {
+            theme: Theme::default(),
+            locale: "en".to_owned(),
+            sync_policy: SyncPolicy::default(),
+            revision_retention_days: 0,
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct UpdateUserSettingsRequest {
+    #[pb(index = 1)]
+    pub theme: Theme,
+
+    #[pb(index = 2)]
+    pub locale: String,
+
+    #[pb(index = 3)]
+    pub sync_policy: SyncPolicy,
+
+    #[pb(index = 4)]
+    pub revision_retention_days: i64,
+}
+
+impl TryInto<UserSettings> for UpdateUserSettingsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UserSettings, Self::Error> {
+        let locale = UserLocale::parse(self.locale)?;
+        if self.revision_retention_days < 0 {
+            return Err(ErrorCode::RevisionRetentionInvalid);
+        }
+
+        Ok(UserSettings {
+            theme: self.theme,
+            locale: locale.0,
+            sync_policy: self.sync_policy,
+            revision_retention_days: self.revision_retention_days,
+        })
+    }
+}
+
+// The lines kept in memory by the log ring buffer, each line is a json object.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ExportedLogs {
+    #[pb(index = 1)]
+    pub content: String,
+}
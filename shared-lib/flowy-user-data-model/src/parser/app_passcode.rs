@@ -0,0 +1,23 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct AppPasscode(pub String);
+
+impl AppPasscode {
+    pub fn parse(s: String) -> Result<AppPasscode, ErrorCode> {
+        let len = s.chars().count();
+        if !(4..=16).contains(&len) {
+            return Err(ErrorCode::AppPasscodeInvalid);
+        }
+
+        if s.chars().any(|c| c.is_whitespace()) {
+            return Err(ErrorCode::AppPasscodeInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for AppPasscode {
+    fn as_ref(&self) -> &str { &self.0 }
+}
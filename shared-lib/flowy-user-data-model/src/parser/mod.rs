@@ -1,12 +1,18 @@
 // https://lexi-lambda.github.io/blog/2019/11/05/parse-don-t-validate/
+mod app_passcode;
+mod server_url;
 mod user_email;
 mod user_id;
+mod user_locale;
 mod user_name;
 mod user_password;
 mod user_workspace;
 
+pub use app_passcode::*;
+pub use server_url::*;
 pub use user_email::*;
 pub use user_id::*;
+pub use user_locale::*;
 pub use user_name::*;
 pub use user_password::*;
 pub use user_workspace::*;
@@ -0,0 +1,74 @@
+use crate::errors::ErrorCode;
+
+// The url that the http requests are sent to, without the trailing slash.
+#[derive(Debug)]
+pub struct ServerBaseUrl(pub String);
+
+impl ServerBaseUrl {
+    pub fn parse(s: String) -> Result<ServerBaseUrl, ErrorCode> {
+        let url = parse_url(&s, &["http://", "https://"])?;
+        Ok(Self(url))
+    }
+}
+
+#[derive(Debug)]
+pub struct ServerWsUrl(pub String);
+
+impl ServerWsUrl {
+    pub fn parse(s: String) -> Result<ServerWsUrl, ErrorCode> {
+        let url = parse_url(&s, &["ws://", "wss://"])?;
+        Ok(Self(url))
+    }
+
+    // The websocket of the server is served under /ws of the same host, the
+    // scheme follows the one of the base url.
+    pub fn from_base_url(base_url: &ServerBaseUrl) -> ServerWsUrl {
+        let url = match base_url.0.strip_prefix("https://") {
+            Some(rest) => format!("wss://{}/ws", rest),
+            None => format!("ws://{}/ws", base_url.0.trim_start_matches("http://")),
+        };
+        Self(url)
+    }
+}
+
+fn parse_url(s: &str, schemes: &[&str]) -> Result<String, ErrorCode> {
+    let url = s.trim().trim_end_matches('/');
+    let host = schemes
+        .iter()
+        .find_map(|scheme| url.strip_prefix(scheme))
+        .ok_or(ErrorCode::ServerUrlInvalid)?;
+    if host.is_empty() || host.chars().any(|c| c.is_whitespace()) {
+        return Err(ErrorCode::ServerUrlInvalid);
+    }
+
+    Ok(url.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ServerBaseUrl, ServerWsUrl};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn http_url_is_accepted() {
+        assert_ok!(ServerBaseUrl::parse("http://localhost:8000".to_string()));
+        assert_ok!(ServerBaseUrl::parse("https://appflowy.example.com/".to_string()));
+    }
+
+    #[test]
+    fn invalid_url_is_rejected() {
+        assert_err!(ServerBaseUrl::parse("".to_string()));
+        assert_err!(ServerBaseUrl::parse("appflowy.example.com".to_string()));
+        assert_err!(ServerBaseUrl::parse("https://".to_string()));
+        assert_err!(ServerBaseUrl::parse("ws://appflowy.example.com".to_string()));
+        assert_err!(ServerWsUrl::parse("https://appflowy.example.com".to_string()));
+    }
+
+    #[test]
+    fn ws_url_follows_base_url() {
+        let base_url = ServerBaseUrl::parse("https://appflowy.example.com/".to_string()).unwrap();
+        assert_eq!(ServerWsUrl::from_base_url(&base_url).0, "wss://appflowy.example.com/ws");
+        let base_url = ServerBaseUrl::parse("http://localhost:8000".to_string()).unwrap();
+        assert_eq!(ServerWsUrl::from_base_url(&base_url).0, "ws://localhost:8000/ws");
+    }
+}
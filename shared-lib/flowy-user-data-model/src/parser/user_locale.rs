@@ -0,0 +1,48 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct UserLocale(pub String);
+
+impl UserLocale {
+    // Accepts the language tag like "en", "zh-CN" or "zh_Hans_CN".
+    pub fn parse(s: String) -> Result<UserLocale, ErrorCode> {
+        let mut parts = s.split(|c| c == '-' || c == '_');
+        let language = parts.next().unwrap_or("");
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(ErrorCode::UserLocaleInvalid);
+        }
+
+        for part in parts {
+            if !(2..=8).contains(&part.len()) || !part.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(ErrorCode::UserLocaleInvalid);
+            }
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for UserLocale {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserLocale;
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn language_tag_is_accepted() {
+        assert_ok!(UserLocale::parse("en".to_string()));
+        assert_ok!(UserLocale::parse("zh-CN".to_string()));
+        assert_ok!(UserLocale::parse("zh_Hans_CN".to_string()));
+    }
+
+    #[test]
+    fn invalid_language_tag_is_rejected() {
+        assert_err!(UserLocale::parse("".to_string()));
+        assert_err!(UserLocale::parse("english".to_string()));
+        assert_err!(UserLocale::parse("en-".to_string()));
+        assert_err!(UserLocale::parse("en US".to_string()));
+    }
+}
@@ -0,0 +1,287 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `account.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeleteAccountProgress {
+    // message fields
+    pub user_id: ::std::string::String,
+    pub step: DeleteAccountStep,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteAccountProgress {
+    fn default() -> &'a DeleteAccountProgress {
+        <DeleteAccountProgress as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteAccountProgress {
+    pub fn new() -> DeleteAccountProgress {
+        ::std::default::Default::default()
+    }
+
+    // string user_id = 1;
+
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    // .DeleteAccountStep step = 2;
+
+
+    pub fn get_step(&self) -> DeleteAccountStep {
+        self.step
+    }
+    pub fn clear_step(&mut self) {
+        self.step = DeleteAccountStep::DeletingServerData;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_step(&mut self, v: DeleteAccountStep) {
+        self.step = v;
+    }
+}
+
+impl ::protobuf::Message for DeleteAccountProgress {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.step, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        }
+        if self.step != DeleteAccountStep::DeletingServerData {
+            my_size += ::protobuf::rt::enum_size(2, self.step);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.user_id.is_empty() {
+            os.write_string(1, &self.user_id)?;
+        }
+        if self.step != DeleteAccountStep::DeletingServerData {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.step))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteAccountProgress {
+        DeleteAccountProgress::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "user_id",
+                |m: &DeleteAccountProgress| { &m.user_id },
+                |m: &mut DeleteAccountProgress| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<DeleteAccountStep>>(
+                "step",
+                |m: &DeleteAccountProgress| { &m.step },
+                |m: &mut DeleteAccountProgress| { &mut m.step },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteAccountProgress>(
+                "DeleteAccountProgress",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DeleteAccountProgress {
+        static instance: ::protobuf::rt::LazyV2<DeleteAccountProgress> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DeleteAccountProgress::new)
+    }
+}
+
+impl ::protobuf::Clear for DeleteAccountProgress {
+    fn clear(&mut self) {
+        self.user_id.clear();
+        self.step = DeleteAccountStep::DeletingServerData;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteAccountProgress {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteAccountProgress {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum DeleteAccountStep {
+    DeletingServerData = 0,
+    DeletingLocalData = 1,
+    Finished = 2,
+}
+
+impl ::protobuf::ProtobufEnum for DeleteAccountStep {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<DeleteAccountStep> {
+        match value {
+            0 => ::std::option::Option::Some(DeleteAccountStep::DeletingServerData),
+            1 => ::std::option::Option::Some(DeleteAccountStep::DeletingLocalData),
+            2 => ::std::option::Option::Some(DeleteAccountStep::Finished),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [DeleteAccountStep] = &[
+            DeleteAccountStep::DeletingServerData,
+            DeleteAccountStep::DeletingLocalData,
+            DeleteAccountStep::Finished,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<DeleteAccountStep>("DeleteAccountStep", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for DeleteAccountStep {
+}
+
+impl ::std::default::Default for DeleteAccountStep {
+    fn default() -> Self {
+        DeleteAccountStep::DeletingServerData
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteAccountStep {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\raccount.proto\"^\n\x15DeleteAccountProgress\x12\x19\n\x07user_id\x18\
+    \x01\x20\x01(\tR\x06userIdB\0\x12(\n\x04step\x18\x02\x20\x01(\x0e2\x12.D\
+    eleteAccountStepR\x04stepB\0:\0*R\n\x11DeleteAccountStep\x12\x16\n\x12De\
+    letingServerData\x10\0\x12\x15\n\x11DeletingLocalData\x10\x01\x12\x0c\n\
+    \x08Finished\x10\x02\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
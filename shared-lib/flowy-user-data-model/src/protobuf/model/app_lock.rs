@@ -0,0 +1,705 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `app_lock.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AppPasscodeRequest {
+    // message fields
+    pub passcode: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AppPasscodeRequest {
+    fn default() -> &'a AppPasscodeRequest {
+        <AppPasscodeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AppPasscodeRequest {
+    pub fn new() -> AppPasscodeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string passcode = 1;
+
+
+    pub fn get_passcode(&self) -> &str {
+        &self.passcode
+    }
+    pub fn clear_passcode(&mut self) {
+        self.passcode.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_passcode(&mut self, v: ::std::string::String) {
+        self.passcode = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_passcode(&mut self) -> &mut ::std::string::String {
+        &mut self.passcode
+    }
+
+    // Take field
+    pub fn take_passcode(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.passcode, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for AppPasscodeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.passcode)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.passcode.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.passcode);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.passcode.is_empty() {
+            os.write_string(1, &self.passcode)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AppPasscodeRequest {
+        AppPasscodeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "passcode",
+                |m: &AppPasscodeRequest| { &m.passcode },
+                |m: &mut AppPasscodeRequest| { &mut m.passcode },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AppPasscodeRequest>(
+                "AppPasscodeRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AppPasscodeRequest {
+        static instance: ::protobuf::rt::LazyV2<AppPasscodeRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AppPasscodeRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for AppPasscodeRequest {
+    fn clear(&mut self) {
+        self.passcode.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AppPasscodeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AppPasscodeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetAppPasscodeRequest {
+    // message fields
+    pub passcode: ::std::string::String,
+    pub idle_timeout: i64,
+    // message oneof groups
+    pub one_of_old_passcode: ::std::option::Option<SetAppPasscodeRequest_oneof_one_of_old_passcode>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetAppPasscodeRequest {
+    fn default() -> &'a SetAppPasscodeRequest {
+        <SetAppPasscodeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum SetAppPasscodeRequest_oneof_one_of_old_passcode {
+    old_passcode(::std::string::String),
+}
+
+impl SetAppPasscodeRequest {
+    pub fn new() -> SetAppPasscodeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string old_passcode = 1;
+
+
+    pub fn get_old_passcode(&self) -> &str {
+        match self.one_of_old_passcode {
+            ::std::option::Option::Some(SetAppPasscodeRequest_oneof_one_of_old_passcode::old_passcode(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_old_passcode(&mut self) {
+        self.one_of_old_passcode = ::std::option::Option::None;
+    }
+
+    pub fn has_old_passcode(&self) -> bool {
+        match self.one_of_old_passcode {
+            ::std::option::Option::Some(SetAppPasscodeRequest_oneof_one_of_old_passcode::old_passcode(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_passcode(&mut self, v: ::std::string::String) {
+        self.one_of_old_passcode = ::std::option::Option::Some(SetAppPasscodeRequest_oneof_one_of_old_passcode::old_passcode(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_old_passcode(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(SetAppPasscodeRequest_oneof_one_of_old_passcode::old_passcode(_)) = self.one_of_old_passcode {
+        } else {
+            self.one_of_old_passcode = ::std::option::Option::Some(SetAppPasscodeRequest_oneof_one_of_old_passcode::old_passcode(::std::string::String::new()));
+        }
+        match self.one_of_old_passcode {
+            ::std::option::Option::Some(SetAppPasscodeRequest_oneof_one_of_old_passcode::old_passcode(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_old_passcode(&mut self) -> ::std::string::String {
+        if self.has_old_passcode() {
+            match self.one_of_old_passcode.take() {
+                ::std::option::Option::Some(SetAppPasscodeRequest_oneof_one_of_old_passcode::old_passcode(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string passcode = 2;
+
+
+    pub fn get_passcode(&self) -> &str {
+        &self.passcode
+    }
+    pub fn clear_passcode(&mut self) {
+        self.passcode.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_passcode(&mut self, v: ::std::string::String) {
+        self.passcode = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_passcode(&mut self) -> &mut ::std::string::String {
+        &mut self.passcode
+    }
+
+    // Take field
+    pub fn take_passcode(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.passcode, ::std::string::String::new())
+    }
+
+    // int64 idle_timeout = 3;
+
+
+    pub fn get_idle_timeout(&self) -> i64 {
+        self.idle_timeout
+    }
+    pub fn clear_idle_timeout(&mut self) {
+        self.idle_timeout = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_idle_timeout(&mut self, v: i64) {
+        self.idle_timeout = v;
+    }
+}
+
+impl ::protobuf::Message for SetAppPasscodeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_old_passcode = ::std::option::Option::Some(SetAppPasscodeRequest_oneof_one_of_old_passcode::old_passcode(is.read_string()?));
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.passcode)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.idle_timeout = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.passcode.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.passcode);
+        }
+        if self.idle_timeout != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.idle_timeout, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_old_passcode {
+            match v {
+                &SetAppPasscodeRequest_oneof_one_of_old_passcode::old_passcode(ref v) => {
+                    my_size += ::protobuf::rt::string_size(1, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.passcode.is_empty() {
+            os.write_string(2, &self.passcode)?;
+        }
+        if self.idle_timeout != 0 {
+            os.write_int64(3, self.idle_timeout)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_old_passcode {
+            match v {
+                &SetAppPasscodeRequest_oneof_one_of_old_passcode::old_passcode(ref v) => {
+                    os.write_string(1, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetAppPasscodeRequest {
+        SetAppPasscodeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "old_passcode",
+                SetAppPasscodeRequest::has_old_passcode,
+                SetAppPasscodeRequest::get_old_passcode,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "passcode",
+                |m: &SetAppPasscodeRequest| { &m.passcode },
+                |m: &mut SetAppPasscodeRequest| { &mut m.passcode },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "idle_timeout",
+                |m: &SetAppPasscodeRequest| { &m.idle_timeout },
+                |m: &mut SetAppPasscodeRequest| { &mut m.idle_timeout },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetAppPasscodeRequest>(
+                "SetAppPasscodeRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetAppPasscodeRequest {
+        static instance: ::protobuf::rt::LazyV2<SetAppPasscodeRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetAppPasscodeRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetAppPasscodeRequest {
+    fn clear(&mut self) {
+        self.one_of_old_passcode = ::std::option::Option::None;
+        self.passcode.clear();
+        self.idle_timeout = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetAppPasscodeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetAppPasscodeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AppLockState {
+    // message fields
+    pub has_passcode: bool,
+    pub is_locked: bool,
+    pub idle_timeout: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AppLockState {
+    fn default() -> &'a AppLockState {
+        <AppLockState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AppLockState {
+    pub fn new() -> AppLockState {
+        ::std::default::Default::default()
+    }
+
+    // bool has_passcode = 1;
+
+
+    pub fn get_has_passcode(&self) -> bool {
+        self.has_passcode
+    }
+    pub fn clear_has_passcode(&mut self) {
+        self.has_passcode = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_passcode(&mut self, v: bool) {
+        self.has_passcode = v;
+    }
+
+    // bool is_locked = 2;
+
+
+    pub fn get_is_locked(&self) -> bool {
+        self.is_locked
+    }
+    pub fn clear_is_locked(&mut self) {
+        self.is_locked = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_locked(&mut self, v: bool) {
+        self.is_locked = v;
+    }
+
+    // int64 idle_timeout = 3;
+
+
+    pub fn get_idle_timeout(&self) -> i64 {
+        self.idle_timeout
+    }
+    pub fn clear_idle_timeout(&mut self) {
+        self.idle_timeout = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_idle_timeout(&mut self, v: i64) {
+        self.idle_timeout = v;
+    }
+}
+
+impl ::protobuf::Message for AppLockState {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.has_passcode = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_locked = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.idle_timeout = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.has_passcode != false {
+            my_size += 2;
+        }
+        if self.is_locked != false {
+            my_size += 2;
+        }
+        if self.idle_timeout != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.idle_timeout, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.has_passcode != false {
+            os.write_bool(1, self.has_passcode)?;
+        }
+        if self.is_locked != false {
+            os.write_bool(2, self.is_locked)?;
+        }
+        if self.idle_timeout != 0 {
+            os.write_int64(3, self.idle_timeout)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AppLockState {
+        AppLockState::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "has_passcode",
+                |m: &AppLockState| { &m.has_passcode },
+                |m: &mut AppLockState| { &mut m.has_passcode },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_locked",
+                |m: &AppLockState| { &m.is_locked },
+                |m: &mut AppLockState| { &mut m.is_locked },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "idle_timeout",
+                |m: &AppLockState| { &m.idle_timeout },
+                |m: &mut AppLockState| { &mut m.idle_timeout },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AppLockState>(
+                "AppLockState",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AppLockState {
+        static instance: ::protobuf::rt::LazyV2<AppLockState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AppLockState::new)
+    }
+}
+
+impl ::protobuf::Clear for AppLockState {
+    fn clear(&mut self) {
+        self.has_passcode = false;
+        self.is_locked = false;
+        self.idle_timeout = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AppLockState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AppLockState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0eapp_lock.proto\"4\n\x12AppPasscodeRequest\x12\x1c\n\x08passcode\
+    \x18\x01\x20\x01(\tR\x08passcodeB\0:\0\"\x9a\x01\n\x15SetAppPasscodeRequ\
+    est\x12%\n\x0cold_passcode\x18\x01\x20\x01(\tH\0R\x0boldPasscodeB\0\x12\
+    \x1c\n\x08passcode\x18\x02\x20\x01(\tR\x08passcodeB\0\x12#\n\x0cidle_tim\
+    eout\x18\x03\x20\x01(\x03R\x0bidleTimeoutB\0B\x15\n\x13one_of_old_passco\
+    de:\0\"y\n\x0cAppLockState\x12#\n\x0chas_passcode\x18\x01\x20\x01(\x08R\
+    \x0bhasPasscodeB\0\x12\x1d\n\tis_locked\x18\x02\x20\x01(\x08R\x08isLocke\
+    dB\0\x12#\n\x0cidle_timeout\x18\x03\x20\x01(\x03R\x0bidleTimeoutB\0:\0B\
+    \0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
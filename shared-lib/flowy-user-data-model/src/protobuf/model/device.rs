@@ -0,0 +1,845 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `device.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UserDevice {
+    // message fields
+    pub session_id: ::std::string::String,
+    pub device_name: ::std::string::String,
+    pub platform: ::std::string::String,
+    pub last_seen: i64,
+    pub is_current: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UserDevice {
+    fn default() -> &'a UserDevice {
+        <UserDevice as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UserDevice {
+    pub fn new() -> UserDevice {
+        ::std::default::Default::default()
+    }
+
+    // string session_id = 1;
+
+
+    pub fn get_session_id(&self) -> &str {
+        &self.session_id
+    }
+    pub fn clear_session_id(&mut self) {
+        self.session_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_id(&mut self, v: ::std::string::String) {
+        self.session_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_id(&mut self) -> &mut ::std::string::String {
+        &mut self.session_id
+    }
+
+    // Take field
+    pub fn take_session_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.session_id, ::std::string::String::new())
+    }
+
+    // string device_name = 2;
+
+
+    pub fn get_device_name(&self) -> &str {
+        &self.device_name
+    }
+    pub fn clear_device_name(&mut self) {
+        self.device_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_name(&mut self, v: ::std::string::String) {
+        self.device_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_name(&mut self) -> &mut ::std::string::String {
+        &mut self.device_name
+    }
+
+    // Take field
+    pub fn take_device_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_name, ::std::string::String::new())
+    }
+
+    // string platform = 3;
+
+
+    pub fn get_platform(&self) -> &str {
+        &self.platform
+    }
+    pub fn clear_platform(&mut self) {
+        self.platform.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_platform(&mut self, v: ::std::string::String) {
+        self.platform = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_platform(&mut self) -> &mut ::std::string::String {
+        &mut self.platform
+    }
+
+    // Take field
+    pub fn take_platform(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.platform, ::std::string::String::new())
+    }
+
+    // int64 last_seen = 4;
+
+
+    pub fn get_last_seen(&self) -> i64 {
+        self.last_seen
+    }
+    pub fn clear_last_seen(&mut self) {
+        self.last_seen = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_seen(&mut self, v: i64) {
+        self.last_seen = v;
+    }
+
+    // bool is_current = 5;
+
+
+    pub fn get_is_current(&self) -> bool {
+        self.is_current
+    }
+    pub fn clear_is_current(&mut self) {
+        self.is_current = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_current(&mut self, v: bool) {
+        self.is_current = v;
+    }
+}
+
+impl ::protobuf::Message for UserDevice {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.session_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.platform)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_seen = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_current = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.session_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.session_id);
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device_name);
+        }
+        if !self.platform.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.platform);
+        }
+        if self.last_seen != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.last_seen, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_current != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.session_id.is_empty() {
+            os.write_string(1, &self.session_id)?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(2, &self.device_name)?;
+        }
+        if !self.platform.is_empty() {
+            os.write_string(3, &self.platform)?;
+        }
+        if self.last_seen != 0 {
+            os.write_int64(4, self.last_seen)?;
+        }
+        if self.is_current != false {
+            os.write_bool(5, self.is_current)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UserDevice {
+        UserDevice::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "session_id",
+                |m: &UserDevice| { &m.session_id },
+                |m: &mut UserDevice| { &mut m.session_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_name",
+                |m: &UserDevice| { &m.device_name },
+                |m: &mut UserDevice| { &mut m.device_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "platform",
+                |m: &UserDevice| { &m.platform },
+                |m: &mut UserDevice| { &mut m.platform },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_seen",
+                |m: &UserDevice| { &m.last_seen },
+                |m: &mut UserDevice| { &mut m.last_seen },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_current",
+                |m: &UserDevice| { &m.is_current },
+                |m: &mut UserDevice| { &mut m.is_current },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UserDevice>(
+                "UserDevice",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UserDevice {
+        static instance: ::protobuf::rt::LazyV2<UserDevice> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UserDevice::new)
+    }
+}
+
+impl ::protobuf::Clear for UserDevice {
+    fn clear(&mut self) {
+        self.session_id.clear();
+        self.device_name.clear();
+        self.platform.clear();
+        self.last_seen = 0;
+        self.is_current = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UserDevice {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UserDevice {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedUserDevice {
+    // message fields
+    pub items: ::protobuf::RepeatedField<UserDevice>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedUserDevice {
+    fn default() -> &'a RepeatedUserDevice {
+        <RepeatedUserDevice as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedUserDevice {
+    pub fn new() -> RepeatedUserDevice {
+        ::std::default::Default::default()
+    }
+
+    // repeated .UserDevice items = 1;
+
+
+    pub fn get_items(&self) -> &[UserDevice] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<UserDevice>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<UserDevice> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<UserDevice> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedUserDevice {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedUserDevice {
+        RepeatedUserDevice::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UserDevice>>(
+                "items",
+                |m: &RepeatedUserDevice| { &m.items },
+                |m: &mut RepeatedUserDevice| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedUserDevice>(
+                "RepeatedUserDevice",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedUserDevice {
+        static instance: ::protobuf::rt::LazyV2<RepeatedUserDevice> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedUserDevice::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedUserDevice {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedUserDevice {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedUserDevice {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RevokeDeviceRequest {
+    // message fields
+    pub session_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RevokeDeviceRequest {
+    fn default() -> &'a RevokeDeviceRequest {
+        <RevokeDeviceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RevokeDeviceRequest {
+    pub fn new() -> RevokeDeviceRequest {
+        ::std::default::Default::default()
+    }
+
+    // string session_id = 1;
+
+
+    pub fn get_session_id(&self) -> &str {
+        &self.session_id
+    }
+    pub fn clear_session_id(&mut self) {
+        self.session_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_id(&mut self, v: ::std::string::String) {
+        self.session_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_id(&mut self) -> &mut ::std::string::String {
+        &mut self.session_id
+    }
+
+    // Take field
+    pub fn take_session_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.session_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RevokeDeviceRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.session_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.session_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.session_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.session_id.is_empty() {
+            os.write_string(1, &self.session_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RevokeDeviceRequest {
+        RevokeDeviceRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "session_id",
+                |m: &RevokeDeviceRequest| { &m.session_id },
+                |m: &mut RevokeDeviceRequest| { &mut m.session_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RevokeDeviceRequest>(
+                "RevokeDeviceRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RevokeDeviceRequest {
+        static instance: ::protobuf::rt::LazyV2<RevokeDeviceRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RevokeDeviceRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for RevokeDeviceRequest {
+    fn clear(&mut self) {
+        self.session_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RevokeDeviceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RevokeDeviceRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RevokeDeviceParams {
+    // message fields
+    pub session_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RevokeDeviceParams {
+    fn default() -> &'a RevokeDeviceParams {
+        <RevokeDeviceParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RevokeDeviceParams {
+    pub fn new() -> RevokeDeviceParams {
+        ::std::default::Default::default()
+    }
+
+    // string session_id = 1;
+
+
+    pub fn get_session_id(&self) -> &str {
+        &self.session_id
+    }
+    pub fn clear_session_id(&mut self) {
+        self.session_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_id(&mut self, v: ::std::string::String) {
+        self.session_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_id(&mut self) -> &mut ::std::string::String {
+        &mut self.session_id
+    }
+
+    // Take field
+    pub fn take_session_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.session_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RevokeDeviceParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.session_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.session_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.session_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.session_id.is_empty() {
+            os.write_string(1, &self.session_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RevokeDeviceParams {
+        RevokeDeviceParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "session_id",
+                |m: &RevokeDeviceParams| { &m.session_id },
+                |m: &mut RevokeDeviceParams| { &mut m.session_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RevokeDeviceParams>(
+                "RevokeDeviceParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RevokeDeviceParams {
+        static instance: ::protobuf::rt::LazyV2<RevokeDeviceParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RevokeDeviceParams::new)
+    }
+}
+
+impl ::protobuf::Clear for RevokeDeviceParams {
+    fn clear(&mut self) {
+        self.session_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RevokeDeviceParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RevokeDeviceParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0cdevice.proto\"\xb0\x01\n\nUserDevice\x12\x1f\n\nsession_id\x18\x01\
+    \x20\x01(\tR\tsessionIdB\0\x12!\n\x0bdevice_name\x18\x02\x20\x01(\tR\nde\
+    viceNameB\0\x12\x1c\n\x08platform\x18\x03\x20\x01(\tR\x08platformB\0\x12\
+    \x1d\n\tlast_seen\x18\x04\x20\x01(\x03R\x08lastSeenB\0\x12\x1f\n\nis_cur\
+    rent\x18\x05\x20\x01(\x08R\tisCurrentB\0:\0\";\n\x12RepeatedUserDevice\
+    \x12#\n\x05items\x18\x01\x20\x03(\x0b2\x0b.UserDeviceR\x05itemsB\0:\0\"8\
+    \n\x13RevokeDeviceRequest\x12\x1f\n\nsession_id\x18\x01\x20\x01(\tR\tses\
+    sionIdB\0:\0\"7\n\x12RevokeDeviceParams\x12\x1f\n\nsession_id\x18\x01\
+    \x20\x01(\tR\tsessionIdB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
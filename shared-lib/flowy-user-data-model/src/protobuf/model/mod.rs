@@ -9,3 +9,21 @@ pub use user_profile::*;
 
 mod auth;
 pub use auth::*;
+
+mod app_lock;
+pub use app_lock::*;
+
+mod user_setting;
+pub use user_setting::*;
+
+mod avatar;
+pub use avatar::*;
+
+mod account;
+pub use account::*;
+
+mod device;
+pub use device::*;
+
+mod server_url;
+pub use server_url::*;
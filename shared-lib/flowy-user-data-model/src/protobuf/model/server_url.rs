@@ -0,0 +1,482 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `server_url.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ServerUrlSetting {
+    // message fields
+    pub base_url: ::std::string::String,
+    pub ws_url: ::std::string::String,
+    pub is_custom: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ServerUrlSetting {
+    fn default() -> &'a ServerUrlSetting {
+        <ServerUrlSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ServerUrlSetting {
+    pub fn new() -> ServerUrlSetting {
+        ::std::default::Default::default()
+    }
+
+    // string base_url = 1;
+
+
+    pub fn get_base_url(&self) -> &str {
+        &self.base_url
+    }
+    pub fn clear_base_url(&mut self) {
+        self.base_url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base_url(&mut self, v: ::std::string::String) {
+        self.base_url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_base_url(&mut self) -> &mut ::std::string::String {
+        &mut self.base_url
+    }
+
+    // Take field
+    pub fn take_base_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.base_url, ::std::string::String::new())
+    }
+
+    // string ws_url = 2;
+
+
+    pub fn get_ws_url(&self) -> &str {
+        &self.ws_url
+    }
+    pub fn clear_ws_url(&mut self) {
+        self.ws_url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ws_url(&mut self, v: ::std::string::String) {
+        self.ws_url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ws_url(&mut self) -> &mut ::std::string::String {
+        &mut self.ws_url
+    }
+
+    // Take field
+    pub fn take_ws_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ws_url, ::std::string::String::new())
+    }
+
+    // bool is_custom = 3;
+
+
+    pub fn get_is_custom(&self) -> bool {
+        self.is_custom
+    }
+    pub fn clear_is_custom(&mut self) {
+        self.is_custom = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_custom(&mut self, v: bool) {
+        self.is_custom = v;
+    }
+}
+
+impl ::protobuf::Message for ServerUrlSetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.base_url)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ws_url)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_custom = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.base_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.base_url);
+        }
+        if !self.ws_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.ws_url);
+        }
+        if self.is_custom != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.base_url.is_empty() {
+            os.write_string(1, &self.base_url)?;
+        }
+        if !self.ws_url.is_empty() {
+            os.write_string(2, &self.ws_url)?;
+        }
+        if self.is_custom != false {
+            os.write_bool(3, self.is_custom)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ServerUrlSetting {
+        ServerUrlSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "base_url",
+                |m: &ServerUrlSetting| { &m.base_url },
+                |m: &mut ServerUrlSetting| { &mut m.base_url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ws_url",
+                |m: &ServerUrlSetting| { &m.ws_url },
+                |m: &mut ServerUrlSetting| { &mut m.ws_url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_custom",
+                |m: &ServerUrlSetting| { &m.is_custom },
+                |m: &mut ServerUrlSetting| { &mut m.is_custom },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ServerUrlSetting>(
+                "ServerUrlSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ServerUrlSetting {
+        static instance: ::protobuf::rt::LazyV2<ServerUrlSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ServerUrlSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for ServerUrlSetting {
+    fn clear(&mut self) {
+        self.base_url.clear();
+        self.ws_url.clear();
+        self.is_custom = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ServerUrlSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ServerUrlSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateServerUrlRequest {
+    // message fields
+    pub base_url: ::std::string::String,
+    pub ws_url: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateServerUrlRequest {
+    fn default() -> &'a UpdateServerUrlRequest {
+        <UpdateServerUrlRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateServerUrlRequest {
+    pub fn new() -> UpdateServerUrlRequest {
+        ::std::default::Default::default()
+    }
+
+    // string base_url = 1;
+
+
+    pub fn get_base_url(&self) -> &str {
+        &self.base_url
+    }
+    pub fn clear_base_url(&mut self) {
+        self.base_url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base_url(&mut self, v: ::std::string::String) {
+        self.base_url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_base_url(&mut self) -> &mut ::std::string::String {
+        &mut self.base_url
+    }
+
+    // Take field
+    pub fn take_base_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.base_url, ::std::string::String::new())
+    }
+
+    // string ws_url = 2;
+
+
+    pub fn get_ws_url(&self) -> &str {
+        &self.ws_url
+    }
+    pub fn clear_ws_url(&mut self) {
+        self.ws_url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ws_url(&mut self, v: ::std::string::String) {
+        self.ws_url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ws_url(&mut self) -> &mut ::std::string::String {
+        &mut self.ws_url
+    }
+
+    // Take field
+    pub fn take_ws_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ws_url, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateServerUrlRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.base_url)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ws_url)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.base_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.base_url);
+        }
+        if !self.ws_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.ws_url);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.base_url.is_empty() {
+            os.write_string(1, &self.base_url)?;
+        }
+        if !self.ws_url.is_empty() {
+            os.write_string(2, &self.ws_url)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateServerUrlRequest {
+        UpdateServerUrlRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "base_url",
+                |m: &UpdateServerUrlRequest| { &m.base_url },
+                |m: &mut UpdateServerUrlRequest| { &mut m.base_url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ws_url",
+                |m: &UpdateServerUrlRequest| { &m.ws_url },
+                |m: &mut UpdateServerUrlRequest| { &mut m.ws_url },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateServerUrlRequest>(
+                "UpdateServerUrlRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateServerUrlRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateServerUrlRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateServerUrlRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateServerUrlRequest {
+    fn clear(&mut self) {
+        self.base_url.clear();
+        self.ws_url.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateServerUrlRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateServerUrlRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10server_url.proto\"i\n\x10ServerUrlSetting\x12\x1b\n\x08base_url\
+    \x18\x01\x20\x01(\tR\x07baseUrlB\0\x12\x17\n\x06ws_url\x18\x02\x20\x01(\
+    \tR\x05wsUrlB\0\x12\x1d\n\tis_custom\x18\x03\x20\x01(\x08R\x08isCustomB\
+    \0:\0\"P\n\x16UpdateServerUrlRequest\x12\x1b\n\x08base_url\x18\x01\x20\
+    \x01(\tR\x07baseUrlB\0\x12\x17\n\x06ws_url\x18\x02\x20\x01(\tR\x05wsUrlB\
+    \0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
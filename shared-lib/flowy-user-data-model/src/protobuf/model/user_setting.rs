@@ -0,0 +1,826 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `user_setting.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UserSettings {
+    // message fields
+    pub theme: Theme,
+    pub locale: ::std::string::String,
+    pub sync_policy: SyncPolicy,
+    pub revision_retention_days: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UserSettings {
+    fn default() -> &'a UserSettings {
+        <UserSettings as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UserSettings {
+    pub fn new() -> UserSettings {
+        ::std::default::Default::default()
+    }
+
+    // .Theme theme = 1;
+
+
+    pub fn get_theme(&self) -> Theme {
+        self.theme
+    }
+    pub fn clear_theme(&mut self) {
+        self.theme = Theme::Light;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_theme(&mut self, v: Theme) {
+        self.theme = v;
+    }
+
+    // string locale = 2;
+
+
+    pub fn get_locale(&self) -> &str {
+        &self.locale
+    }
+    pub fn clear_locale(&mut self) {
+        self.locale.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locale(&mut self, v: ::std::string::String) {
+        self.locale = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_locale(&mut self) -> &mut ::std::string::String {
+        &mut self.locale
+    }
+
+    // Take field
+    pub fn take_locale(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.locale, ::std::string::String::new())
+    }
+
+    // .SyncPolicy sync_policy = 3;
+
+
+    pub fn get_sync_policy(&self) -> SyncPolicy {
+        self.sync_policy
+    }
+    pub fn clear_sync_policy(&mut self) {
+        self.sync_policy = SyncPolicy::Always;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sync_policy(&mut self, v: SyncPolicy) {
+        self.sync_policy = v;
+    }
+
+    // int64 revision_retention_days = 4;
+
+
+    pub fn get_revision_retention_days(&self) -> i64 {
+        self.revision_retention_days
+    }
+    pub fn clear_revision_retention_days(&mut self) {
+        self.revision_retention_days = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision_retention_days(&mut self, v: i64) {
+        self.revision_retention_days = v;
+    }
+}
+
+impl ::protobuf::Message for UserSettings {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.theme, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.locale)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.sync_policy, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.revision_retention_days = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.theme != Theme::Light {
+            my_size += ::protobuf::rt::enum_size(1, self.theme);
+        }
+        if !self.locale.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.locale);
+        }
+        if self.sync_policy != SyncPolicy::Always {
+            my_size += ::protobuf::rt::enum_size(3, self.sync_policy);
+        }
+        if self.revision_retention_days != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.revision_retention_days, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.theme != Theme::Light {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.theme))?;
+        }
+        if !self.locale.is_empty() {
+            os.write_string(2, &self.locale)?;
+        }
+        if self.sync_policy != SyncPolicy::Always {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.sync_policy))?;
+        }
+        if self.revision_retention_days != 0 {
+            os.write_int64(4, self.revision_retention_days)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UserSettings {
+        UserSettings::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Theme>>(
+                "theme",
+                |m: &UserSettings| { &m.theme },
+                |m: &mut UserSettings| { &mut m.theme },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "locale",
+                |m: &UserSettings| { &m.locale },
+                |m: &mut UserSettings| { &mut m.locale },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SyncPolicy>>(
+                "sync_policy",
+                |m: &UserSettings| { &m.sync_policy },
+                |m: &mut UserSettings| { &mut m.sync_policy },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "revision_retention_days",
+                |m: &UserSettings| { &m.revision_retention_days },
+                |m: &mut UserSettings| { &mut m.revision_retention_days },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UserSettings>(
+                "UserSettings",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UserSettings {
+        static instance: ::protobuf::rt::LazyV2<UserSettings> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UserSettings::new)
+    }
+}
+
+impl ::protobuf::Clear for UserSettings {
+    fn clear(&mut self) {
+        self.theme = Theme::Light;
+        self.locale.clear();
+        self.sync_policy = SyncPolicy::Always;
+        self.revision_retention_days = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UserSettings {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UserSettings {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateUserSettingsRequest {
+    // message fields
+    pub theme: Theme,
+    pub locale: ::std::string::String,
+    pub sync_policy: SyncPolicy,
+    pub revision_retention_days: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateUserSettingsRequest {
+    fn default() -> &'a UpdateUserSettingsRequest {
+        <UpdateUserSettingsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateUserSettingsRequest {
+    pub fn new() -> UpdateUserSettingsRequest {
+        ::std::default::Default::default()
+    }
+
+    // .Theme theme = 1;
+
+
+    pub fn get_theme(&self) -> Theme {
+        self.theme
+    }
+    pub fn clear_theme(&mut self) {
+        self.theme = Theme::Light;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_theme(&mut self, v: Theme) {
+        self.theme = v;
+    }
+
+    // string locale = 2;
+
+
+    pub fn get_locale(&self) -> &str {
+        &self.locale
+    }
+    pub fn clear_locale(&mut self) {
+        self.locale.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locale(&mut self, v: ::std::string::String) {
+        self.locale = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_locale(&mut self) -> &mut ::std::string::String {
+        &mut self.locale
+    }
+
+    // Take field
+    pub fn take_locale(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.locale, ::std::string::String::new())
+    }
+
+    // .SyncPolicy sync_policy = 3;
+
+
+    pub fn get_sync_policy(&self) -> SyncPolicy {
+        self.sync_policy
+    }
+    pub fn clear_sync_policy(&mut self) {
+        self.sync_policy = SyncPolicy::Always;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sync_policy(&mut self, v: SyncPolicy) {
+        self.sync_policy = v;
+    }
+
+    // int64 revision_retention_days = 4;
+
+
+    pub fn get_revision_retention_days(&self) -> i64 {
+        self.revision_retention_days
+    }
+    pub fn clear_revision_retention_days(&mut self) {
+        self.revision_retention_days = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision_retention_days(&mut self, v: i64) {
+        self.revision_retention_days = v;
+    }
+}
+
+impl ::protobuf::Message for UpdateUserSettingsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.theme, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.locale)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.sync_policy, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.revision_retention_days = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.theme != Theme::Light {
+            my_size += ::protobuf::rt::enum_size(1, self.theme);
+        }
+        if !self.locale.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.locale);
+        }
+        if self.sync_policy != SyncPolicy::Always {
+            my_size += ::protobuf::rt::enum_size(3, self.sync_policy);
+        }
+        if self.revision_retention_days != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.revision_retention_days, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.theme != Theme::Light {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.theme))?;
+        }
+        if !self.locale.is_empty() {
+            os.write_string(2, &self.locale)?;
+        }
+        if self.sync_policy != SyncPolicy::Always {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.sync_policy))?;
+        }
+        if self.revision_retention_days != 0 {
+            os.write_int64(4, self.revision_retention_days)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateUserSettingsRequest {
+        UpdateUserSettingsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Theme>>(
+                "theme",
+                |m: &UpdateUserSettingsRequest| { &m.theme },
+                |m: &mut UpdateUserSettingsRequest| { &mut m.theme },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "locale",
+                |m: &UpdateUserSettingsRequest| { &m.locale },
+                |m: &mut UpdateUserSettingsRequest| { &mut m.locale },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SyncPolicy>>(
+                "sync_policy",
+                |m: &UpdateUserSettingsRequest| { &m.sync_policy },
+                |m: &mut UpdateUserSettingsRequest| { &mut m.sync_policy },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "revision_retention_days",
+                |m: &UpdateUserSettingsRequest| { &m.revision_retention_days },
+                |m: &mut UpdateUserSettingsRequest| { &mut m.revision_retention_days },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateUserSettingsRequest>(
+                "UpdateUserSettingsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateUserSettingsRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateUserSettingsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateUserSettingsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateUserSettingsRequest {
+    fn clear(&mut self) {
+        self.theme = Theme::Light;
+        self.locale.clear();
+        self.sync_policy = SyncPolicy::Always;
+        self.revision_retention_days = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateUserSettingsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateUserSettingsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportedLogs {
+    // message fields
+    pub content: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportedLogs {
+    fn default() -> &'a ExportedLogs {
+        <ExportedLogs as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportedLogs {
+    pub fn new() -> ExportedLogs {
+        ::std::default::Default::default()
+    }
+
+    // string content = 1;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ExportedLogs {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.content);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.content.is_empty() {
+            os.write_string(1, &self.content)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportedLogs {
+        ExportedLogs::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &ExportedLogs| { &m.content },
+                |m: &mut ExportedLogs| { &mut m.content },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportedLogs>(
+                "ExportedLogs",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportedLogs {
+        static instance: ::protobuf::rt::LazyV2<ExportedLogs> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportedLogs::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportedLogs {
+    fn clear(&mut self) {
+        self.content.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportedLogs {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportedLogs {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Theme {
+    Light = 0,
+    Dark = 1,
+}
+
+impl ::protobuf::ProtobufEnum for Theme {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Theme> {
+        match value {
+            0 => ::std::option::Option::Some(Theme::Light),
+            1 => ::std::option::Option::Some(Theme::Dark),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Theme] = &[
+            Theme::Light,
+            Theme::Dark,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Theme>("Theme", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Theme {
+}
+
+impl ::std::default::Default for Theme {
+    fn default() -> Self {
+        Theme::Light
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Theme {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SyncPolicy {
+    Always = 0,
+    WifiOnly = 1,
+    Manual = 2,
+}
+
+impl ::protobuf::ProtobufEnum for SyncPolicy {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SyncPolicy> {
+        match value {
+            0 => ::std::option::Option::Some(SyncPolicy::Always),
+            1 => ::std::option::Option::Some(SyncPolicy::WifiOnly),
+            2 => ::std::option::Option::Some(SyncPolicy::Manual),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SyncPolicy] = &[
+            SyncPolicy::Always,
+            SyncPolicy::WifiOnly,
+            SyncPolicy::Manual,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SyncPolicy>("SyncPolicy", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SyncPolicy {
+}
+
+impl ::std::default::Default for SyncPolicy {
+    fn default() -> Self {
+        SyncPolicy::Always
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncPolicy {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x12user_setting.proto\"\xb4\x01\n\x0cUserSettings\x12\x1e\n\x05theme\
+    \x18\x01\x20\x01(\x0e2\x06.ThemeR\x05themeB\0\x12\x18\n\x06locale\x18\
+    \x02\x20\x01(\tR\x06localeB\0\x12.\n\x0bsync_policy\x18\x03\x20\x01(\x0e\
+    2\x0b.SyncPolicyR\nsyncPolicyB\0\x128\n\x17revision_retention_days\x18\
+    \x04\x20\x01(\x03R\x15revisionRetentionDaysB\0:\0\"\xc1\x01\n\x19UpdateU\
+    serSettingsRequest\x12\x1e\n\x05theme\x18\x01\x20\x01(\x0e2\x06.ThemeR\
+    \x05themeB\0\x12\x18\n\x06locale\x18\x02\x20\x01(\tR\x06localeB\0\x12.\n\
+    \x0bsync_policy\x18\x03\x20\x01(\x0e2\x0b.SyncPolicyR\nsyncPolicyB\0\x12\
+    8\n\x17revision_retention_days\x18\x04\x20\x01(\x03R\x15revisionRetentio\
+    nDaysB\0:\0\",\n\x0cExportedLogs\x12\x1a\n\x07content\x18\x01\x20\x01(\t\
+    R\x07contentB\0:\0*\x1e\n\x05Theme\x12\t\n\x05Light\x10\0\x12\x08\n\x04D\
+    ark\x10\x01\x1a\0*4\n\nSyncPolicy\x12\n\n\x06Always\x10\0\x12\x0c\n\x08W\
+    ifiOnly\x10\x01\x12\n\n\x06Manual\x10\x02\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
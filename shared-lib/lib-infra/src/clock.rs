@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use futures_core::future::BoxFuture;
+use std::{sync::Arc, time::Duration};
+
+/// Where the current time comes from. The tests replace it with a clock they
+/// can move forward, to run a deferred save or a retry without waiting for it.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Resolves once `duration` has passed on this clock.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+    fn timestamp(&self) -> i64 { self.now().timestamp() }
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> { Utc::now() }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> { Box::pin(tokio::time::sleep(duration)) }
+}
+
+/// Generates the ids of the objects that are created by the client, e.g. a
+/// duplicated view. The ids must be valid uuids.
+pub trait IdGenerator: Send + Sync {
+    fn next_id(&self) -> String;
+}
+
+pub struct UuidGenerator;
+
+impl IdGenerator for UuidGenerator {
+    fn next_id(&self) -> String { crate::uuid_string() }
+}
+
+/// The clock and the id generator that are passed to the controllers.
+#[derive(Clone)]
+pub struct RuntimeEnv {
+    pub clock: Arc<dyn Clock>,
+    pub id_generator: Arc<dyn IdGenerator>,
+}
+
+impl std::default::Default for RuntimeEnv {
+    fn default() -> Self {
+        RuntimeEnv {
+            clock: Arc::new(SystemClock),
+            id_generator: Arc::new(UuidGenerator),
+        }
+    }
+}
+
+impl RuntimeEnv {
+    pub fn timestamp(&self) -> i64 { self.clock.timestamp() }
+
+    pub fn next_id(&self) -> String { self.id_generator.next_id() }
+}
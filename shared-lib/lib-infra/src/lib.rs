@@ -1,4 +1,6 @@
+pub mod clock;
 pub mod future;
+pub mod metrics;
 pub mod retry;
 
 #[allow(dead_code)]
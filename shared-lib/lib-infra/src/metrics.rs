@@ -0,0 +1,151 @@
+use lazy_static::lazy_static;
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+pub const DISPATCH_ERROR_COUNT: &str = "dispatch.error_count";
+pub const WS_RECONNECT_COUNT: &str = "ws.reconnect_count";
+pub const SYNC_QUEUE_DEPTH: &str = "document.sync_queue_depth";
+pub const REVISION_CACHE_SIZE: &str = "document.revision_cache.size_bytes";
+pub const REVISION_CACHE_HIT_COUNT: &str = "document.revision_cache.hit_count";
+pub const REVISION_CACHE_MISS_COUNT: &str = "document.revision_cache.miss_count";
+pub const REVISION_CACHE_EVICTION_COUNT: &str = "document.revision_cache.eviction_count";
+pub const DATABASE_SIZE: &str = "database.size_bytes";
+
+// The percentiles are computed from the latest samples only, so a slow start
+// doesn't hide the current numbers.
+const MAX_LATENCY_SAMPLES: usize = 256;
+
+lazy_static! {
+    static ref METRICS: Mutex<MetricsRegistry> = Mutex::new(MetricsRegistry::default());
+}
+
+#[derive(Default)]
+struct MetricsRegistry {
+    counters: HashMap<String, u64>,
+    gauges: HashMap<String, i64>,
+    latencies: HashMap<String, Latency>,
+}
+
+#[derive(Default)]
+struct Latency {
+    count: u64,
+    total: Duration,
+    max: Duration,
+    samples: VecDeque<Duration>,
+}
+
+impl Latency {
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+        self.max = self.max.max(duration);
+        if self.samples.len() >= MAX_LATENCY_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(duration);
+    }
+
+    fn snapshot(&self) -> LatencySnapshot {
+        let mut samples = self.samples.iter().copied().collect::<Vec<_>>();
+        samples.sort();
+        let percentile = |p: usize| -> f64 {
+            if samples.is_empty() {
+                return 0.0;
+            }
+            let index = ((samples.len() - 1) * p) / 100;
+            as_millis(samples[index])
+        };
+
+        LatencySnapshot {
+            count: self.count,
+            mean_ms: if self.count == 0 {
+                0.0
+            } else {
+                as_millis(self.total) / self.count as f64
+            },
+            p50_ms: percentile(50),
+            p95_ms: percentile(95),
+            max_ms: as_millis(self.max),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencySnapshot {
+    pub count: u64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricsSnapshot {
+    pub timestamp: i64,
+    pub counters: BTreeMap<String, u64>,
+    pub gauges: BTreeMap<String, i64>,
+    pub latencies: BTreeMap<String, LatencySnapshot>,
+}
+
+pub fn increment_counter(name: &str) { *registry().counters.entry(name.to_owned()).or_insert(0) += 1; }
+
+pub fn set_gauge(name: &str, value: i64) { registry().gauges.insert(name.to_owned(), value); }
+
+pub fn add_gauge(name: &str, delta: i64) { *registry().gauges.entry(name.to_owned()).or_insert(0) += delta; }
+
+pub fn gauge(name: &str) -> i64 { registry().gauges.get(name).copied().unwrap_or(0) }
+
+pub fn record_latency(name: &str, duration: Duration) {
+    registry()
+        .latencies
+        .entry(name.to_owned())
+        .or_insert_with(Latency::default)
+        .record(duration);
+}
+
+pub fn snapshot() -> MetricsSnapshot {
+    let registry = registry();
+    MetricsSnapshot {
+        timestamp: crate::timestamp(),
+        counters: registry.counters.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+        gauges: registry.gauges.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+        latencies: registry
+            .latencies
+            .iter()
+            .map(|(k, v)| (k.clone(), v.snapshot()))
+            .collect(),
+    }
+}
+
+// A panic while the lock is held only leaves a metric half updated, so the
+// poisoned registry is still used.
+fn registry() -> MutexGuard<'static, MetricsRegistry> {
+    match METRICS.lock() {
+        Ok(registry) => registry,
+        Err(e) => e.into_inner(),
+    }
+}
+
+fn as_millis(duration: Duration) -> f64 { duration.as_secs_f64() * 1000.0 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latency_percentiles() {
+        let mut latency = Latency::default();
+        for ms in 1..=100 {
+            latency.record(Duration::from_millis(ms));
+        }
+        let snapshot = latency.snapshot();
+        assert_eq!(snapshot.count, 100);
+        assert_eq!(snapshot.p50_ms, 50.0);
+        assert_eq!(snapshot.p95_ms, 95.0);
+        assert_eq!(snapshot.max_ms, 100.0);
+    }
+}
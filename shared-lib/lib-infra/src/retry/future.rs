@@ -1,17 +1,19 @@
 #![allow(clippy::large_enum_variant)]
 #![allow(clippy::type_complexity)]
-use crate::retry::FixedInterval;
+use crate::{
+    clock::{Clock, SystemClock},
+    retry::FixedInterval,
+};
+use futures_core::future::BoxFuture;
 use pin_project::pin_project;
 use std::{
     future::Future,
     iter::{IntoIterator, Iterator},
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
-use tokio::{
-    task::JoinHandle,
-    time::{sleep_until, Duration, Instant, Sleep},
-};
+use tokio::{task::JoinHandle, time::Duration};
 
 #[pin_project(project = RetryStateProj)]
 enum RetryState<A>
@@ -19,7 +21,7 @@ where
     A: Action,
 {
     Running(#[pin] A::Future),
-    Sleeping(#[pin] Sleep),
+    Sleeping(#[pin] BoxFuture<'static, ()>),
 }
 
 impl<A: Action> RetryState<A> {
@@ -60,6 +62,16 @@ where
             retry_if: RetryIf::spawn(strategy, action, (|_| true) as fn(&A::Error) -> bool),
         }
     }
+
+    pub fn spawn_with_clock<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+        clock: Arc<dyn Clock>,
+    ) -> Retry<I, A> {
+        Retry {
+            retry_if: RetryIf::spawn_with_clock(strategy, action, (|_| true) as fn(&A::Error) -> bool, clock),
+        }
+    }
 }
 
 impl<I, A> Future for Retry<I, A>
@@ -90,6 +102,7 @@ where
     state: RetryState<A>,
     action: A,
     condition: C,
+    clock: Arc<dyn Clock>,
 }
 
 impl<I, A, C> RetryIf<I, A, C>
@@ -99,15 +112,26 @@ where
     C: Condition<A::Error>,
 {
     pub fn spawn<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+        condition: C,
+    ) -> RetryIf<I, A, C> {
+        RetryIf::spawn_with_clock(strategy, action, condition, Arc::new(SystemClock))
+    }
+
+    /// Waits between the attempts on the `clock` instead of the system clock.
+    pub fn spawn_with_clock<T: IntoIterator<IntoIter = I, Item = Duration>>(
         strategy: T,
         mut action: A,
         condition: C,
+        clock: Arc<dyn Clock>,
     ) -> RetryIf<I, A, C> {
         RetryIf {
             strategy: strategy.into_iter(),
             state: RetryState::Running(action.run()),
             action,
             condition,
+            clock,
         }
     }
 
@@ -128,8 +152,7 @@ where
         match self.as_mut().project().strategy.next() {
             None => Err(err),
             Some(duration) => {
-                let deadline = Instant::now() + duration;
-                let future = sleep_until(deadline);
+                let future = self.clock.sleep(duration);
                 self.as_mut().project().state.set(RetryState::Sleeping(future));
                 Ok(self.poll(cx))
             },
@@ -193,6 +193,8 @@ impl RichTextAttribute {
     inline_attribute!(Size, usize);
     inline_attribute!(Background, String);
     inline_attribute!(InlineCode, bool);
+    inline_attribute!(Equation, &str);
+    inline_attribute!(Embed, &str);
 
     // block
     block_attribute!(Header, usize);
@@ -200,7 +202,16 @@ impl RichTextAttribute {
     block_attribute!(Align, String);
     block_attribute!(List, &str);
     block_attribute!(CodeBlock, bool);
+    block_attribute!(CodeLanguage, &str);
     block_attribute!(BlockQuote, bool);
+    block_attribute!(Table, &str);
+    block_attribute!(TableRow, &str);
+    block_attribute!(TableCell, &str);
+    block_attribute!(RowSpan, usize);
+    block_attribute!(ColSpan, usize);
+    block_attribute!(Callout, &str);
+    block_attribute!(CalloutColor, String);
+    block_attribute!(Toggle, &str);
 
     // ignore
     ignore_attribute!(Width, usize);
@@ -266,8 +277,14 @@ pub enum RichTextAttributeKey {
     Align,
     #[serde(rename = "code_block")]
     CodeBlock,
+    #[serde(rename = "code_language")]
+    CodeLanguage,
     #[serde(rename = "code")]
     InlineCode,
+    #[serde(rename = "equation")]
+    Equation,
+    #[serde(rename = "embed")]
+    Embed,
     #[serde(rename = "list")]
     List,
     #[serde(rename = "blockquote")]
@@ -278,6 +295,22 @@ pub enum RichTextAttributeKey {
     Height,
     #[serde(rename = "header")]
     Header,
+    #[serde(rename = "table")]
+    Table,
+    #[serde(rename = "table_row")]
+    TableRow,
+    #[serde(rename = "table_cell")]
+    TableCell,
+    #[serde(rename = "rowspan")]
+    RowSpan,
+    #[serde(rename = "colspan")]
+    ColSpan,
+    #[serde(rename = "callout")]
+    Callout,
+    #[serde(rename = "callout_color")]
+    CalloutColor,
+    #[serde(rename = "toggle")]
+    Toggle,
 }
 
 // pub trait AttributeValueData<'a>: Serialize + Deserialize<'a> {}
@@ -339,8 +372,17 @@ lazy_static! {
         RichTextAttributeKey::Indent,
         RichTextAttributeKey::Align,
         RichTextAttributeKey::CodeBlock,
+        RichTextAttributeKey::CodeLanguage,
         RichTextAttributeKey::List,
         RichTextAttributeKey::BlockQuote,
+        RichTextAttributeKey::Table,
+        RichTextAttributeKey::TableRow,
+        RichTextAttributeKey::TableCell,
+        RichTextAttributeKey::RowSpan,
+        RichTextAttributeKey::ColSpan,
+        RichTextAttributeKey::Callout,
+        RichTextAttributeKey::CalloutColor,
+        RichTextAttributeKey::Toggle,
     ]);
     static ref INLINE_KEYS: HashSet<RichTextAttributeKey> = HashSet::from_iter(vec![
         RichTextAttributeKey::Bold,
@@ -353,6 +395,8 @@ lazy_static! {
         RichTextAttributeKey::Size,
         RichTextAttributeKey::Background,
         RichTextAttributeKey::InlineCode,
+        RichTextAttributeKey::Equation,
+        RichTextAttributeKey::Embed,
     ]);
     static ref INGORE_KEYS: HashSet<RichTextAttributeKey> =
         HashSet::from_iter(vec![RichTextAttributeKey::Width, RichTextAttributeKey::Height,]);
@@ -66,7 +66,9 @@ where
             | RichTextAttributeKey::Header
             | RichTextAttributeKey::Indent
             | RichTextAttributeKey::Width
-            | RichTextAttributeKey::Height => match &v.parse::<i32>() {
+            | RichTextAttributeKey::Height
+            | RichTextAttributeKey::RowSpan
+            | RichTextAttributeKey::ColSpan => match &v.parse::<i32>() {
                 Ok(value) => map_serializer.serialize_entry(&key, value)?,
                 Err(e) => log::error!("Serial {:?} failed. {:?}", &key, e),
             },
@@ -75,7 +77,16 @@ where
             | RichTextAttributeKey::Color
             | RichTextAttributeKey::Background
             | RichTextAttributeKey::Align
-            | RichTextAttributeKey::List => {
+            | RichTextAttributeKey::List
+            | RichTextAttributeKey::CodeLanguage
+            | RichTextAttributeKey::Equation
+            | RichTextAttributeKey::Embed
+            | RichTextAttributeKey::Table
+            | RichTextAttributeKey::TableRow
+            | RichTextAttributeKey::TableCell
+            | RichTextAttributeKey::Callout
+            | RichTextAttributeKey::CalloutColor
+            | RichTextAttributeKey::Toggle => {
                 map_serializer.serialize_entry(&key, v)?;
             },
         }